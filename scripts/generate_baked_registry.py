@@ -116,7 +116,7 @@ UNSCORED_SENTINEL = -1.0
 
 SingleEntry = Tuple[str, str, float]
 FlipPair = Tuple[str, str, float, str, str, float, str]
-SplitPair = Tuple[str, str, float, str, str, float, str, bool]
+SplitPair = Tuple[str, str, float, str, str, float, str, bool, bool]
 AliasEntry = Tuple[str, str]
 
 
@@ -141,7 +141,9 @@ def collect_unique_blocks(
                 return score
         return None
 
-    def parse_block_for_face(card: dict, face: dict, *, strip_fuse: bool) -> Tuple[str, str] | None:
+    def parse_block_for_face(
+        card: dict, face: dict, *, strip_fuse: bool, strip_aftermath: bool = False
+    ) -> Tuple[str, str] | None:
         name = (face.get("name") or "").strip()
         mana_cost = face.get("mana_cost")
         type_line = face.get("type_line")
@@ -164,6 +166,16 @@ def collect_unique_blocks(
             if not oracle_text:
                 return None
 
+        if strip_aftermath:
+            oracle_lines = [
+                line
+                for line in oracle_text.splitlines()
+                if not line.strip().startswith("Aftermath")
+            ]
+            oracle_text = "\n".join(oracle_lines).strip()
+            if not oracle_text:
+                return None
+
         if is_non_playable(card, type_line, oracle_text):
             return None
 
@@ -237,7 +249,7 @@ def collect_unique_blocks(
         layout = (card.get("layout") or "").strip().lower()
         faces = card.get("card_faces") or []
 
-        if layout == "split" and isinstance(faces, list) and len(faces) >= 2:
+        if layout in {"split", "aftermath"} and isinstance(faces, list) and len(faces) >= 2:
             front = faces[0]
             back = faces[1]
             combined_name = (card.get("name") or "").strip()
@@ -250,8 +262,12 @@ def collect_unique_blocks(
                 "Fuse" in (face.get("oracle_text") or "") for face in (front, back)
             )
 
+            has_aftermath = layout == "aftermath"
+
             front_pair = parse_block_for_face(card, front, strip_fuse=has_fuse)
-            back_pair = parse_block_for_face(card, back, strip_fuse=has_fuse)
+            back_pair = parse_block_for_face(
+                card, back, strip_fuse=has_fuse, strip_aftermath=has_aftermath
+            )
             if not front_pair or not back_pair:
                 continue
 
@@ -283,6 +299,7 @@ def collect_unique_blocks(
                     back_score,
                     combined_name,
                     has_fuse,
+                    has_aftermath,
                 )
             )
             register_root_print_aliases(card, front_name)
@@ -443,6 +460,7 @@ def write_generated_source(
     lines.append("    back_score: f32,")
     lines.append("    combined_name: String,")
     lines.append("    has_fuse: bool,")
+    lines.append("    has_aftermath: bool,")
     lines.append("}")
     lines.append("")
     lines.append("struct GeneratedCardTexts {")
@@ -543,6 +561,9 @@ def write_generated_source(
         '        let combined_name = read_string(bytes, &mut cursor).expect("missing split combined name");'
     )
     lines.append('        let has_fuse = read_u32(bytes, &mut cursor).expect("missing split fuse flag") != 0;')
+    lines.append(
+        '        let has_aftermath = read_u32(bytes, &mut cursor).expect("missing split aftermath flag") != 0;'
+    )
     lines.append("        splits.push(SplitCardText {")
     lines.append("            front_name,")
     lines.append("            front_block,")
@@ -552,6 +573,7 @@ def write_generated_source(
     lines.append("            back_score,")
     lines.append("            combined_name,")
     lines.append("            has_fuse,")
+    lines.append("            has_aftermath,")
     lines.append("        });")
     lines.append("    }")
     lines.append("")
@@ -755,6 +777,7 @@ def write_generated_source(
     lines.append("    back_name: &str,")
     lines.append("    back_block: &str,")
     lines.append("    has_fuse: bool,")
+    lines.append("    has_aftermath: bool,")
     lines.append(") {")
     lines.append("    let front_id = CardId::new();")
     lines.append("    let back_id = CardId::new();")
@@ -772,6 +795,8 @@ def write_generated_source(
     lines.append("    back.card.linked_face_layout = crate::card::LinkedFaceLayout::Split;")
     lines.append("    front.has_fuse = has_fuse;")
     lines.append("    back.has_fuse = has_fuse;")
+    lines.append("    front.has_aftermath = has_aftermath;")
+    lines.append("    back.has_aftermath = has_aftermath;")
     lines.append("    cards.push(front);")
     lines.append("    cards.push(back);")
     lines.append("}")
@@ -791,7 +816,7 @@ def write_generated_source(
     lines.append("        }")
     lines.append("        for entry in &texts.splits {")
     lines.append(
-        "            parse_generated_split_card(&mut cards, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse);"
+        "            parse_generated_split_card(&mut cards, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse, entry.has_aftermath);"
     )
     lines.append("        }")
     lines.append("        cards")
@@ -869,7 +894,7 @@ def write_generated_source(
     lines.append("            let entry = &texts.splits[index - singles_len - flips_len];")
     lines.append("            let mut parsed = Vec::new();")
     lines.append(
-        "            parse_generated_split_card(&mut parsed, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse);"
+        "            parse_generated_split_card(&mut parsed, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse, entry.has_aftermath);"
     )
     lines.append("            register_parsed_cards(registry, parsed);")
     lines.append(
@@ -923,7 +948,7 @@ def write_generated_source(
     lines.append("        }")
     lines.append("        let mut parsed = Vec::new();")
     lines.append(
-        "        parse_generated_split_card(&mut parsed, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse);"
+        "        parse_generated_split_card(&mut parsed, entry.front_name.as_str(), entry.front_block.as_str(), entry.back_name.as_str(), entry.back_block.as_str(), entry.has_fuse, entry.has_aftermath);"
     )
     lines.append("        register_parsed_cards(registry, parsed);")
     lines.append(
@@ -1080,6 +1105,8 @@ def write_generated_source(
     lines.append("            back.card.linked_face_layout = crate::card::LinkedFaceLayout::Split;")
     lines.append("            front.has_fuse = entry.has_fuse;")
     lines.append("            back.has_fuse = entry.has_fuse;")
+    lines.append("            front.has_aftermath = entry.has_aftermath;")
+    lines.append("            back.has_aftermath = entry.has_aftermath;")
     lines.append(
         "            if let Some(detail) = super::generated_definition_unsupported_mechanics_message(&front) {"
     )
@@ -1161,6 +1188,7 @@ def write_generated_payload(
         back_score,
         combined_name,
         has_fuse,
+        has_aftermath,
     ) in splits_ordered:
         append_string(payload, front_name)
         append_string(payload, front_block)
@@ -1170,6 +1198,7 @@ def write_generated_payload(
         append_f32(payload, back_score)
         append_string(payload, combined_name)
         append_u32(payload, 1 if has_fuse else 0)
+        append_u32(payload, 1 if has_aftermath else 0)
 
     append_u32(payload, len(aliases_ordered))
     for alias, canonical in aliases_ordered:
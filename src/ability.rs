@@ -9,12 +9,23 @@ use crate::cost::TotalCost;
 use crate::effect::Effect;
 use crate::ids::{ObjectId, PlayerId};
 use crate::mana::ManaSymbol;
+use crate::object::CounterType;
 use crate::static_abilities::StaticAbility as NewStaticAbility;
 use crate::target::{ChooseSpec, ObjectFilter, PlayerFilter};
 use crate::triggers::Trigger;
 use crate::types::{CardType, Subtype};
 use crate::zone::Zone;
 
+/// Whether any of `effects` targets.
+pub(crate) fn effects_have_targets(effects: &[Effect]) -> bool {
+    effects.iter().any(|effect| {
+        effect
+            .0
+            .get_target_spec()
+            .is_some_and(ChooseSpec::is_target)
+    })
+}
+
 /// Extract static abilities from a heterogeneous ability list.
 pub fn extract_static_abilities(abilities: &[Ability]) -> Vec<NewStaticAbility> {
     abilities
@@ -306,6 +317,149 @@ impl LevelAbility {
     }
 }
 
+// === Counter Countdowns ===
+
+/// Whether a countdown adds or removes a counter each upkeep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountdownDirection {
+    /// "put a [kind] counter on this"
+    Add,
+    /// "remove a [kind] counter from this"
+    Remove,
+}
+
+/// When a countdown's resulting effect happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountdownThreshold {
+    /// No counters of the kind remain on the source.
+    NoneRemain,
+    /// The source has at least this many counters of the kind.
+    AtLeast(u32),
+}
+
+/// An upkeep counter engine: "At the beginning of your upkeep, remove a time
+/// counter from this. When the last is removed, sacrifice it."
+///
+/// Vanishing and fading are built from this, as are non-keyword templates such
+/// as "put a filibuster counter on this. Then if it has five or more filibuster
+/// counters on it, you win the game."
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterCountdown {
+    /// The kind of counter being counted.
+    pub counter_type: CounterType,
+    /// Whether a counter is added or removed each upkeep.
+    pub direction: CountdownDirection,
+    /// Whose upkeep ticks the countdown.
+    pub upkeep_of: PlayerFilter,
+    /// When the resulting effects happen.
+    pub threshold: CountdownThreshold,
+    /// What happens once the threshold is reached.
+    pub effects: Vec<Effect>,
+    /// Counters the permanent enters with, if any.
+    pub starting_counters: Option<u32>,
+}
+
+impl CounterCountdown {
+    /// Remove a counter each upkeep; when the last is removed, run `effects`.
+    pub fn remove(counter_type: CounterType, effects: Vec<Effect>) -> Self {
+        Self {
+            counter_type,
+            direction: CountdownDirection::Remove,
+            upkeep_of: PlayerFilter::You,
+            threshold: CountdownThreshold::NoneRemain,
+            effects,
+            starting_counters: None,
+        }
+    }
+
+    /// Add a counter each upkeep; once there are `threshold` or more, run `effects`.
+    pub fn add(counter_type: CounterType, threshold: u32, effects: Vec<Effect>) -> Self {
+        Self {
+            counter_type,
+            direction: CountdownDirection::Add,
+            upkeep_of: PlayerFilter::You,
+            threshold: CountdownThreshold::AtLeast(threshold),
+            effects,
+            starting_counters: None,
+        }
+    }
+
+    /// Tick during the upkeeps of players matching `player` instead of only yours.
+    pub fn during_upkeep_of(mut self, player: PlayerFilter) -> Self {
+        self.upkeep_of = player;
+        self
+    }
+
+    /// Have the permanent enter with `amount` counters of the countdown's kind.
+    pub fn starting_with(mut self, amount: u32) -> Self {
+        self.starting_counters = Some(amount);
+        self
+    }
+
+    fn threshold_condition(&self) -> crate::ConditionExpr {
+        match self.threshold {
+            CountdownThreshold::NoneRemain => {
+                crate::ConditionExpr::SourceHasNoCounter(self.counter_type)
+            }
+            CountdownThreshold::AtLeast(count) => crate::ConditionExpr::SourceHasCounterAtLeast {
+                counter_type: self.counter_type,
+                count,
+            },
+        }
+    }
+
+    /// Lower the countdown into the abilities that implement it.
+    ///
+    /// `text` labels the enters-with-counters ability, which is where keyword
+    /// countdowns print their reminder.
+    ///
+    /// Removing down to none uses a separate "when the last is removed" trigger,
+    /// so counters removed by other effects also finish the countdown. Every
+    /// other shape checks the threshold right after the upkeep tick.
+    pub fn into_abilities(self, text: Option<&str>) -> Vec<Ability> {
+        let mut abilities = Vec::new();
+        if let Some(amount) = self.starting_counters {
+            let mut enters_with = Ability::static_ability(NewStaticAbility::enters_with_counters(
+                self.counter_type,
+                amount,
+            ));
+            enters_with.text = text.map(str::to_string);
+            abilities.push(enters_with);
+        }
+
+        let tick = match self.direction {
+            CountdownDirection::Add => Effect::put_counters_on_source(self.counter_type, 1),
+            CountdownDirection::Remove => {
+                Effect::remove_counters(self.counter_type, 1, ChooseSpec::Source)
+            }
+        };
+        let condition = self.threshold_condition();
+        let upkeep = Trigger::beginning_of_upkeep(self.upkeep_of.clone());
+
+        if self.direction == CountdownDirection::Remove
+            && self.threshold == CountdownThreshold::NoneRemain
+        {
+            abilities.push(Ability::triggered(upkeep, vec![tick]));
+            abilities.push(Ability {
+                kind: AbilityKind::Triggered(TriggeredAbility {
+                    trigger: Trigger::counter_removed_from(ObjectFilter::source()),
+                    effects: self.effects,
+                    choices: vec![],
+                    intervening_if: Some(condition),
+                }),
+                functional_zones: vec![Zone::Battlefield],
+                text: None,
+            });
+        } else {
+            abilities.push(Ability::triggered(
+                upkeep,
+                vec![tick, Effect::conditional_only(condition, self.effects)],
+            ));
+        }
+        abilities
+    }
+}
+
 // === Triggered Abilities ===
 
 /// A triggered ability that fires when a condition is met.
@@ -407,8 +561,32 @@ pub struct RestrictedManaUnit {
 
 impl ActivatedAbility {
     /// Returns true if this is a mana ability.
+    ///
+    /// Per CR 605.1a an ability that could add mana is only a mana ability if
+    /// it doesn't target. Loyalty abilities are never given `mana_output`
+    /// when cards are built, so they use the stack too.
     pub fn is_mana_ability(&self) -> bool {
-        self.mana_output.is_some()
+        self.mana_output.is_some() && !self.has_targets()
+    }
+
+    /// Returns true if activating this ability requires choosing targets.
+    pub fn has_targets(&self) -> bool {
+        self.choices.iter().any(ChooseSpec::is_target) || effects_have_targets(&self.effects)
+    }
+
+    /// Effects to run when this ability resolves from the stack.
+    ///
+    /// Fixed mana output is normally added without using the stack; for an
+    /// ability that adds mana but isn't a mana ability (it targets), it is
+    /// folded in ahead of the other effects.
+    pub fn resolution_effects(&self) -> Vec<Effect> {
+        let fixed = self.mana_symbols();
+        if fixed.is_empty() || self.is_mana_ability() {
+            return self.effects.clone();
+        }
+        let mut effects = vec![Effect::add_mana(fixed.to_vec())];
+        effects.extend(self.effects.iter().cloned());
+        effects
     }
 
     /// Returns the fixed mana symbols produced, or empty if variable.
@@ -928,6 +1106,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_counter_countdown_remove_finishes_on_last_counter_removed() {
+        let abilities =
+            CounterCountdown::remove(CounterType::Time, vec![Effect::sacrifice_source()])
+                .starting_with(3)
+                .into_abilities(Some("Vanishing 3"));
+
+        assert_eq!(abilities.len(), 3);
+        assert_eq!(abilities[0].text.as_deref(), Some("Vanishing 3"));
+        let AbilityKind::Triggered(upkeep) = &abilities[1].kind else {
+            panic!("Expected upkeep trigger");
+        };
+        assert_eq!(
+            format!("{:?}", upkeep.effects),
+            format!(
+                "{:?}",
+                vec![Effect::remove_counters(
+                    CounterType::Time,
+                    1,
+                    ChooseSpec::Source
+                )]
+            )
+        );
+        let AbilityKind::Triggered(last_removed) = &abilities[2].kind else {
+            panic!("Expected last-counter trigger");
+        };
+        assert_eq!(
+            last_removed.intervening_if,
+            Some(crate::ConditionExpr::SourceHasNoCounter(CounterType::Time))
+        );
+        assert_eq!(
+            format!("{:?}", last_removed.effects),
+            format!("{:?}", vec![Effect::sacrifice_source()])
+        );
+    }
+
+    #[test]
+    fn test_counter_countdown_add_checks_threshold_after_each_tick() {
+        let abilities = CounterCountdown::add(CounterType::Age, 3, vec![Effect::draw(1)])
+            .during_upkeep_of(PlayerFilter::Any)
+            .into_abilities(None);
+
+        assert_eq!(abilities.len(), 1, "no enters-with-counters ability");
+        let AbilityKind::Triggered(upkeep) = &abilities[0].kind else {
+            panic!("Expected upkeep trigger");
+        };
+        assert_eq!(
+            upkeep.trigger,
+            Trigger::beginning_of_upkeep(PlayerFilter::Any)
+        );
+        let expected = vec![
+            Effect::put_counters_on_source(CounterType::Age, 1),
+            Effect::conditional_only(
+                crate::ConditionExpr::SourceHasCounterAtLeast {
+                    counter_type: CounterType::Age,
+                    count: 3,
+                },
+                vec![Effect::draw(1)],
+            ),
+        ];
+        assert_eq!(format!("{:?}", upkeep.effects), format!("{expected:?}"));
+    }
+
     #[test]
     fn test_dies_trigger() {
         // When this creature dies, each opponent loses 2 life
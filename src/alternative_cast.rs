@@ -155,11 +155,11 @@ impl AlternativeCastingMethod {
     }
 
     /// Returns true if the spell should be exiled after resolution.
+    ///
+    /// Escape has no such replacement (CR 702.138): an escaped instant or
+    /// sorcery goes back to the graveyard and can escape again.
     pub fn exiles_after_resolution(&self) -> bool {
-        matches!(
-            self,
-            Self::Flashback { .. } | Self::JumpStart | Self::Escape { .. }
-        )
+        matches!(self, Self::Flashback { .. } | Self::JumpStart)
     }
 
     /// Returns the mana cost for this alternative casting method.
@@ -417,6 +417,10 @@ impl AlternativeCastingMethod {
 
 /// Which method is being used to cast a spell.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum CastingMethod {
     /// Normal casting from hand with normal mana cost.
     #[default]
@@ -459,7 +463,7 @@ impl CastingMethod {
 
     /// Returns true if the spell should be exiled after resolution.
     pub fn exiles_after_resolution(&self) -> bool {
-        matches!(self, Self::GrantedFlashback | Self::GrantedEscape { .. })
+        matches!(self, Self::GrantedFlashback)
     }
 }
 
@@ -505,7 +509,7 @@ mod tests {
         };
 
         assert_eq!(escape.cast_from_zone(), Zone::Graveyard);
-        assert!(escape.exiles_after_resolution());
+        assert!(!escape.exiles_after_resolution());
         assert!(escape.mana_cost().is_some());
         assert_eq!(escape.name(), "Escape");
     }
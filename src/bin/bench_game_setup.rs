@@ -0,0 +1,105 @@
+use std::env;
+use std::time::{Duration, Instant};
+
+use maigus::GameState;
+use maigus::cards::{
+    CardDefinition, basic_forest, basic_island, blood_artist, counterspell, demonic_tutor,
+    fireball, force_of_will, geist_of_saint_traft, lightning_bolt, lightning_greaves,
+    llanowar_elves, serra_angel, white_knight,
+};
+use maigus::ids::PlayerId;
+use maigus::zone::Zone;
+
+fn usage() {
+    eprintln!(
+        "Usage: cargo run --release -p maigus-tools --bin bench_game_setup -- \\
+  [--deck-size <cards>] [--iterations <n>]"
+    );
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("{flag} requires a value"))?;
+    raw.parse::<usize>()
+        .map_err(|err| format!("invalid {flag} '{raw}': {err}"))
+}
+
+/// A Commander-sized deck: basics plus spells and creatures with real abilities.
+fn build_deck(deck_size: usize) -> Vec<CardDefinition> {
+    let nonbasics = [
+        blood_artist(),
+        counterspell(),
+        demonic_tutor(),
+        fireball(),
+        force_of_will(),
+        geist_of_saint_traft(),
+        lightning_bolt(),
+        lightning_greaves(),
+        llanowar_elves(),
+        serra_angel(),
+        white_knight(),
+    ];
+    let lands = [basic_forest(), basic_island()];
+    let land_count = deck_size * 2 / 5;
+    lands
+        .iter()
+        .cycle()
+        .take(land_count)
+        .chain(nonbasics.iter().cycle().take(deck_size - land_count))
+        .cloned()
+        .collect()
+}
+
+fn new_game() -> GameState {
+    GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 40)
+}
+
+fn fill_libraries(game: &mut GameState, deck: &[CardDefinition]) {
+    for player in [PlayerId::from_index(0), PlayerId::from_index(1)] {
+        for definition in deck {
+            game.create_object_from_definition(definition, player, Zone::Library);
+        }
+    }
+}
+
+fn main() -> Result<(), String> {
+    let mut deck_size = 100usize;
+    let mut iterations = 200usize;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--deck-size" => deck_size = parse_count(&mut args, "--deck-size")?,
+            "--iterations" => iterations = parse_count(&mut args, "--iterations")?,
+            "-h" | "--help" => {
+                usage();
+                return Ok(());
+            }
+            other => {
+                usage();
+                return Err(format!("unknown argument: {other}"));
+            }
+        }
+    }
+
+    let deck = build_deck(deck_size);
+
+    let mut games: Vec<GameState> = (0..iterations).map(|_| new_game()).collect();
+    let start = Instant::now();
+    for game in &mut games {
+        fill_libraries(game, &deck);
+    }
+    let setup_time = start.elapsed();
+
+    let start = Instant::now();
+    let clones: Vec<GameState> = games.iter().map(GameState::clone).collect();
+    let clone_time = start.elapsed();
+    drop(clones);
+
+    let per_game = |total: Duration| total / iterations.max(1) as u32;
+    println!("2 players x {deck_size} cards, {iterations} games");
+    println!("library setup: {:?} per game", per_game(setup_time));
+    println!("clone: {:?} per game", per_game(clone_time));
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::env;
+use std::time::{Duration, Instant};
+
+use maigus::cards::{
+    basic_forest, basic_plains, crusade, grizzly_bears, llanowar_elves, savannah_lions,
+    serra_angel, white_knight,
+};
+use maigus::decision::compute_legal_actions;
+use maigus::ids::PlayerId;
+use maigus::legal_action_cache::compute_legal_actions_cached;
+use maigus::zone::Zone;
+use maigus::{GameState, Phase};
+
+fn usage() {
+    eprintln!(
+        "Usage: cargo run --release -p maigus-tools --bin bench_legal_actions -- \\
+  [--permanents <per player>] [--iterations <n>]"
+    );
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("{flag} requires a value"))?;
+    raw.parse::<usize>()
+        .map_err(|err| format!("invalid {flag} '{raw}': {err}"))
+}
+
+/// A midgame-sized board in the first main phase, with castable cards in hand.
+fn build_board(permanents_per_player: usize) -> GameState {
+    let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    let permanents = [
+        basic_plains(),
+        basic_forest(),
+        savannah_lions(),
+        llanowar_elves(),
+        white_knight(),
+        serra_angel(),
+        crusade(),
+    ];
+    let hand = [grizzly_bears(), serra_angel(), white_knight(), crusade()];
+    for player in [PlayerId::from_index(0), PlayerId::from_index(1)] {
+        for definition in permanents.iter().cycle().take(permanents_per_player) {
+            game.create_object_from_definition(definition, player, Zone::Battlefield);
+        }
+        for definition in &hand {
+            game.create_object_from_definition(definition, player, Zone::Hand);
+        }
+    }
+    game
+}
+
+fn time_computations(game: &mut GameState, player: PlayerId, iterations: usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        compute_legal_actions_cached(game, player);
+    }
+    start.elapsed()
+}
+
+fn main() -> Result<(), String> {
+    let mut permanents = 40usize;
+    let mut iterations = 2000usize;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--permanents" => permanents = parse_count(&mut args, "--permanents")?,
+            "--iterations" => iterations = parse_count(&mut args, "--iterations")?,
+            "-h" | "--help" => {
+                usage();
+                return Ok(());
+            }
+            other => {
+                usage();
+                return Err(format!("unknown argument: {other}"));
+            }
+        }
+    }
+
+    let alice = PlayerId::from_index(0);
+    let mut uncached = build_board(permanents);
+    uncached.set_legal_action_caching(false);
+    let mut cached = uncached.clone();
+    cached.set_legal_action_caching(true);
+
+    let first_start = Instant::now();
+    let first = compute_legal_actions_cached(&mut cached, alice);
+    let first_time = first_start.elapsed();
+    let second_start = Instant::now();
+    let second = compute_legal_actions_cached(&mut cached, alice);
+    let second_time = second_start.elapsed();
+    if first != second || first != compute_legal_actions(&uncached, alice) {
+        return Err("cached and computed legal actions differ".to_string());
+    }
+
+    let uncached_time = time_computations(&mut uncached, alice, iterations);
+    let cached_time = time_computations(&mut cached, alice, iterations);
+
+    let per_pass = |total: Duration| total / iterations.max(1) as u32;
+    println!(
+        "{} permanents, {} legal actions, {iterations} priority passes",
+        permanents * 2,
+        first.len()
+    );
+    println!("first computation:  {first_time:?}");
+    println!("second computation: {second_time:?}");
+    println!("uncached: {:?} per pass", per_pass(uncached_time));
+    println!("cached:   {:?} per pass", per_pass(cached_time));
+    println!(
+        "cache hits: {}, misses: {}",
+        cached.legal_action_cache().hits(),
+        cached.legal_action_cache().misses()
+    );
+    if !cached_time.is_zero() {
+        println!(
+            "speedup: {:.2}x",
+            uncached_time.as_secs_f64() / cached_time.as_secs_f64()
+        );
+    }
+    Ok(())
+}
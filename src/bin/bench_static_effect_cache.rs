@@ -0,0 +1,102 @@
+use std::env;
+use std::time::{Duration, Instant};
+
+use maigus::GameState;
+use maigus::cards::{
+    basic_plains, crusade, grizzly_bears, savannah_lions, serra_angel, white_knight,
+};
+use maigus::ids::PlayerId;
+use maigus::zone::Zone;
+
+fn usage() {
+    eprintln!(
+        "Usage: cargo run --release -p maigus-tools --bin bench_static_effect_cache -- \\
+  [--permanents <per player>] [--iterations <n>]"
+    );
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("{flag} requires a value"))?;
+    raw.parse::<usize>()
+        .map_err(|err| format!("invalid {flag} '{raw}': {err}"))
+}
+
+/// A midgame-sized board: lands, vanilla and keyword creatures, and anthems.
+fn build_board(permanents_per_player: usize) -> GameState {
+    let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+    let definitions = [
+        basic_plains(),
+        savannah_lions(),
+        white_knight(),
+        grizzly_bears(),
+        serra_angel(),
+        crusade(),
+    ];
+    for player in [PlayerId::from_index(0), PlayerId::from_index(1)] {
+        for definition in definitions.iter().cycle().take(permanents_per_player) {
+            game.create_object_from_definition(definition, player, Zone::Battlefield);
+        }
+    }
+    game
+}
+
+fn time_refreshes(game: &mut GameState, iterations: usize) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        game.update_static_ability_effects();
+    }
+    start.elapsed()
+}
+
+fn main() -> Result<(), String> {
+    let mut permanents = 40usize;
+    let mut iterations = 2000usize;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--permanents" => permanents = parse_count(&mut args, "--permanents")?,
+            "--iterations" => iterations = parse_count(&mut args, "--iterations")?,
+            "-h" | "--help" => {
+                usage();
+                return Ok(());
+            }
+            other => {
+                usage();
+                return Err(format!("unknown argument: {other}"));
+            }
+        }
+    }
+
+    let mut uncached = build_board(permanents);
+    uncached.set_static_effect_caching(false);
+    let mut cached = uncached.clone();
+    cached.set_static_effect_caching(true);
+
+    let uncached_time = time_refreshes(&mut uncached, iterations);
+    let cached_time = time_refreshes(&mut cached, iterations);
+    if cached.continuous_effects.static_ability_effects()
+        != uncached.continuous_effects.static_ability_effects()
+    {
+        return Err("cached and rebuilt static ability effects differ".to_string());
+    }
+
+    let per_refresh = |total: Duration| total / iterations.max(1) as u32;
+    println!("{} permanents, {iterations} refreshes", permanents * 2);
+    println!("full rebuild: {:?} per refresh", per_refresh(uncached_time));
+    println!("cached:       {:?} per refresh", per_refresh(cached_time));
+    println!(
+        "cache hits: {}, misses: {}",
+        cached.static_effect_cache().hits(),
+        cached.static_effect_cache().misses()
+    );
+    if !cached_time.is_zero() {
+        println!(
+            "speedup: {:.2}x",
+            uncached_time.as_secs_f64() / cached_time.as_secs_f64()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::env;
+use std::time::{Duration, Instant};
+
+use maigus::GameState;
+use maigus::cards::{CardDefinition, CardDefinitionBuilder, blood_artist, grizzly_bears};
+use maigus::events::{EnterBattlefieldEvent, ZoneChangeEvent};
+use maigus::ids::{CardId, PlayerId};
+use maigus::provenance::ProvNodeId;
+use maigus::triggers::{TriggerEvent, check_triggers, check_triggers_for_events};
+use maigus::zone::Zone;
+
+fn usage() {
+    eprintln!(
+        "Usage: cargo run --release -p maigus-tools --bin bench_trigger_checks -- \\
+  [--tokens <n>] [--watchers <n>] [--iterations <n>]"
+    );
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("{flag} requires a value"))?;
+    raw.parse::<usize>()
+        .map_err(|err| format!("invalid {flag} '{raw}': {err}"))
+}
+
+fn etb_watcher() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Bench Watcher")
+        .parse_text(
+            "Type: Creature — Cleric\n\
+             Power/Toughness: 1/1\n\
+             Whenever another creature you control enters, you gain 1 life.",
+        )
+        .expect("watcher text should parse")
+}
+
+/// ETB watchers and dies watchers in play, plus `tokens` creatures that just
+/// entered, and the zone-change and enter events for each of them.
+fn build_board(tokens: usize, watchers: usize) -> (GameState, Vec<TriggerEvent>) {
+    let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+    let alice = PlayerId::from_index(0);
+    let watcher = etb_watcher();
+    for _ in 0..watchers {
+        game.create_object_from_definition(&watcher, alice, Zone::Battlefield);
+    }
+    for _ in 0..watchers {
+        game.create_object_from_definition(&blood_artist(), alice, Zone::Battlefield);
+    }
+    let bears = grizzly_bears();
+    let mut events = Vec::new();
+    for _ in 0..tokens {
+        let id = game.create_object_from_definition(&bears, alice, Zone::Battlefield);
+        events.push(TriggerEvent::new_with_provenance(
+            ZoneChangeEvent::new(id, Zone::Stack, Zone::Battlefield, None),
+            ProvNodeId::default(),
+        ));
+        events.push(TriggerEvent::new_with_provenance(
+            EnterBattlefieldEvent::new(id, Zone::Stack),
+            ProvNodeId::default(),
+        ));
+    }
+    (game, events)
+}
+
+fn time_checks(
+    iterations: usize,
+    mut check: impl FnMut() -> usize,
+) -> Result<(Duration, usize), String> {
+    let expected = check();
+    let start = Instant::now();
+    for _ in 0..iterations {
+        if check() != expected {
+            return Err("trigger checks are not deterministic".to_string());
+        }
+    }
+    Ok((start.elapsed(), expected))
+}
+
+fn main() -> Result<(), String> {
+    let mut tokens = 50usize;
+    let mut watchers = 5usize;
+    let mut iterations = 50usize;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tokens" => tokens = parse_count(&mut args, "--tokens")?,
+            "--watchers" => watchers = parse_count(&mut args, "--watchers")?,
+            "--iterations" => iterations = parse_count(&mut args, "--iterations")?,
+            "-h" | "--help" => {
+                usage();
+                return Ok(());
+            }
+            other => {
+                usage();
+                return Err(format!("unknown argument: {other}"));
+            }
+        }
+    }
+
+    let (game, events) = build_board(tokens, watchers);
+    let (per_event_time, per_event_count) = time_checks(iterations, || {
+        events
+            .iter()
+            .map(|event| check_triggers(&game, event).len())
+            .sum()
+    })?;
+    let (batched_time, batched_count) = time_checks(iterations, || {
+        check_triggers_for_events(&game, &events).len()
+    })?;
+    if per_event_count != batched_count {
+        return Err(format!(
+            "per-event checks found {per_event_count} triggers, batched checks found {batched_count}"
+        ));
+    }
+
+    let per_batch = |total: Duration| total / iterations.max(1) as u32;
+    println!(
+        "{tokens} tokens entering, {watchers} ETB watchers, {} events, {batched_count} triggers",
+        events.len()
+    );
+    println!("per event: {:?} per batch", per_batch(per_event_time));
+    println!("batched:   {:?} per batch", per_batch(batched_time));
+    if !batched_time.is_zero() {
+        println!(
+            "speedup: {:.2}x",
+            per_event_time.as_secs_f64() / batched_time.as_secs_f64()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use std::env;
+use std::time::{Duration, Instant};
+
+use maigus::GameState;
+use maigus::cards::grizzly_bears;
+use maigus::filter::{FilterContext, ObjectFilter, PlayerFilter};
+use maigus::ids::PlayerId;
+use maigus::zone::Zone;
+
+const ZONES: [Zone; 4] = [
+    Zone::Battlefield,
+    Zone::Graveyard,
+    Zone::Exile,
+    Zone::Library,
+];
+
+fn usage() {
+    eprintln!(
+        "Usage: cargo run --release -p maigus-tools --bin bench_zone_queries -- \\
+  [--players <n>] [--cards <n>] [--iterations <n>]"
+    );
+}
+
+fn parse_count(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<usize, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("{flag} requires a value"))?;
+    raw.parse::<usize>()
+        .map_err(|err| format!("invalid {flag} '{raw}': {err}"))
+}
+
+/// `cards` creatures per player in each of the battlefield, graveyard, exile,
+/// and library.
+fn build_board(players: usize, cards: usize) -> GameState {
+    let names = (0..players)
+        .map(|index| format!("Player {index}"))
+        .collect();
+    let mut game = GameState::new(names, 20);
+    let bears = grizzly_bears();
+    for index in 0..players {
+        let owner = PlayerId::from_index(index as u8);
+        for zone in ZONES {
+            for _ in 0..cards {
+                game.create_object_from_definition(&bears, owner, zone);
+            }
+        }
+    }
+    game
+}
+
+/// "Creature cards you own in <zone>", evaluated for every player and zone.
+fn count_matches(game: &GameState, count: impl Fn(Zone, PlayerId) -> usize) -> usize {
+    let mut total = 0;
+    for player in &game.players {
+        for zone in ZONES {
+            total += count(zone, player.id);
+        }
+    }
+    total
+}
+
+fn time_queries(
+    iterations: usize,
+    mut query: impl FnMut() -> usize,
+) -> Result<(Duration, usize), String> {
+    let expected = query();
+    let start = Instant::now();
+    for _ in 0..iterations {
+        if query() != expected {
+            return Err("zone queries are not deterministic".to_string());
+        }
+    }
+    Ok((start.elapsed(), expected))
+}
+
+fn main() -> Result<(), String> {
+    let mut players = 4usize;
+    let mut cards = 50usize;
+    let mut iterations = 200usize;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--players" => players = parse_count(&mut args, "--players")?,
+            "--cards" => cards = parse_count(&mut args, "--cards")?,
+            "--iterations" => iterations = parse_count(&mut args, "--iterations")?,
+            "-h" | "--help" => {
+                usage();
+                return Ok(());
+            }
+            other => {
+                usage();
+                return Err(format!("unknown argument: {other}"));
+            }
+        }
+    }
+
+    let game = build_board(players.max(1), cards);
+    let filter_for = |zone: Zone| {
+        ObjectFilter::creature()
+            .in_zone(zone)
+            .owned_by(PlayerFilter::You)
+    };
+    let (scan_time, scan_count) = time_queries(iterations, || {
+        count_matches(&game, |zone, player| {
+            let filter = filter_for(zone);
+            let ctx = FilterContext::new(player);
+            game.objects_iter()
+                .filter(|obj| filter.matches(obj, &ctx, &game))
+                .count()
+        })
+    })?;
+    let (indexed_time, indexed_count) = time_queries(iterations, || {
+        count_matches(&game, |zone, player| {
+            let filter = filter_for(zone);
+            let ctx = FilterContext::new(player);
+            game.objects_in(zone, player)
+                .filter_map(|id| game.object(id))
+                .filter(|obj| filter.matches(obj, &ctx, &game))
+                .count()
+        })
+    })?;
+    if scan_count != indexed_count {
+        return Err(format!(
+            "full scans matched {scan_count} objects, indexed queries matched {indexed_count}"
+        ));
+    }
+
+    let per_pass = |total: Duration| total / iterations.max(1) as u32;
+    println!(
+        "{} players, {cards} cards per zone, {} objects, {indexed_count} matches per pass",
+        game.players.len(),
+        game.objects_iter().count()
+    );
+    println!("full scan: {:?} per pass", per_pass(scan_time));
+    println!("indexed:   {:?} per pass", per_pass(indexed_time));
+    if !indexed_time.is_zero() {
+        println!(
+            "speedup: {:.2}x",
+            scan_time.as_secs_f64() / indexed_time.as_secs_f64()
+        );
+    }
+    Ok(())
+}
@@ -269,10 +269,18 @@ pub struct CardBuilder {
 }
 
 impl CardBuilder {
+    /// Start a card. A placeholder `CardId::new()` becomes the ID derived
+    /// from the card's name.
     pub fn new(id: CardId, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let id = if id == CardId::new() {
+            CardId::from_name(&name)
+        } else {
+            id
+        };
         Self {
             id,
-            name: name.into(),
+            name,
             ..Default::default()
         }
     }
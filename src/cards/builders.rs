@@ -5,10 +5,10 @@
 
 use crate::ConditionExpr;
 use crate::ability::{
-    self, Ability, AbilityKind, ActivationTiming, LevelAbility, TriggeredAbility,
+    self, Ability, AbilityKind, ActivationTiming, CounterCountdown, LevelAbility, TriggeredAbility,
 };
 use crate::alternative_cast::AlternativeCastingMethod;
-use crate::card::{CardBuilder, PowerToughness, PtValue};
+use crate::card::{CardBuilder, LinkedFaceLayout, PowerToughness, PtValue};
 use crate::color::ColorSet;
 use crate::cost::{OptionalCost, TotalCost};
 use crate::effect::{
@@ -18,6 +18,7 @@ use crate::effect::{
 use crate::ids::CardId;
 use crate::mana::{ManaCost, ManaSymbol};
 use crate::object::CounterType;
+use crate::shared::SharedVec;
 use crate::static_abilities::StaticAbility;
 use crate::tag::TagKey;
 use crate::target::{ChooseSpec, ObjectFilter, PlayerFilter};
@@ -171,7 +172,7 @@ fn finalize_overload_definitions(
 
     for method in &mut definition.alternative_casts {
         if let AlternativeCastingMethod::Overload { effects, .. } = method {
-            *effects = overloaded_effects.clone();
+            *effects = overloaded_effects.to_vec();
         }
     }
 
@@ -258,7 +259,7 @@ fn finalize_cipher_effects(mut definition: CardDefinition) -> CardDefinition {
         .retain(|ability| !is_cipher_placeholder(ability));
     definition
         .spell_effect
-        .get_or_insert_with(Vec::new)
+        .get_or_insert_with(SharedVec::new)
         .push(Effect::cipher());
     definition
 }
@@ -401,11 +402,11 @@ fn finalize_nonpermanent_delayed_triggered_abilities(
         }
     }
 
-    definition.abilities = remaining_abilities;
+    definition.abilities = remaining_abilities.into();
     if !rewritten_effects.is_empty() {
         definition
             .spell_effect
-            .get_or_insert_with(Vec::new)
+            .get_or_insert_with(SharedVec::new)
             .extend(rewritten_effects);
     }
     definition
@@ -1323,10 +1324,16 @@ pub(crate) enum PredicateAst {
     CreatureDiedThisTurn,
     PermanentLeftBattlefieldUnderYourControlThisTurn,
     YouAttackedThisTurn,
+    YouGainedLifeThisTurn,
+    OpponentLostLifeThisTurn,
     SourceWasCast,
     NoSpellsWereCastLastTurn,
     /// The current resolving spell was kicked (not a target predicate).
     ThisSpellWasKicked,
+    /// The current resolving spell was cast from the given zone.
+    ThisSpellWasCastFromZone(Zone),
+    /// You cast another spell with this name this game.
+    YouCastAnotherSpellNamedThisGame(String),
     TargetWasKicked,
     TargetSpellCastOrderThisTurn(u32),
     TargetSpellControllerIsPoisoned,
@@ -1402,6 +1409,10 @@ pub(crate) enum EffectAst {
         amount: Value,
         target: TargetAst,
     },
+    DealDamageDivided {
+        amount: Value,
+        target: TargetAst,
+    },
     DealDamageEqualToPower {
         source: TargetAst,
         target: TargetAst,
@@ -2376,6 +2387,12 @@ pub struct CardDefinitionBuilder {
     /// Alternative casting methods (flashback, escape, etc.)
     alternative_casts: Vec<AlternativeCastingMethod>,
 
+    /// Whether this split card has fuse
+    has_fuse: bool,
+
+    /// Whether this split card's second half has aftermath
+    has_aftermath: bool,
+
     /// Optional costs (kicker, buyback, etc.)
     optional_costs: Vec<OptionalCost>,
 
@@ -2476,6 +2493,8 @@ impl CardDefinitionBuilder {
             abilities: Vec::new(),
             spell_effect: None,
             alternative_casts: Vec::new(),
+            has_fuse: false,
+            has_aftermath: false,
             optional_costs: Vec::new(),
             max_saga_chapter: None,
             additional_cost: TotalCost::free(),
@@ -4070,31 +4089,10 @@ impl CardDefinitionBuilder {
     /// If you can't, sacrifice it."
     pub fn fading(self, amount: u32) -> Self {
         let text = format!("Fading {amount}");
-        self.with_ability(
-            Ability::static_ability(StaticAbility::enters_with_counters(
-                CounterType::Fade,
-                amount,
-            ))
-            .with_text(&text),
-        )
-        .with_ability(Ability::triggered(
-            Trigger::beginning_of_upkeep(PlayerFilter::You),
-            vec![Effect::remove_counters(
-                CounterType::Fade,
-                1,
-                ChooseSpec::Source,
-            )],
-        ))
-        .with_ability(Ability {
-            kind: AbilityKind::Triggered(TriggeredAbility {
-                trigger: Trigger::counter_removed_from(ObjectFilter::source()),
-                effects: vec![Effect::sacrifice_source()],
-                choices: vec![],
-                intervening_if: Some(Condition::SourceHasNoCounter(CounterType::Fade)),
-            }),
-            functional_zones: vec![Zone::Battlefield],
-            text: None,
-        })
+        let countdown =
+            CounterCountdown::remove(CounterType::Fade, vec![Effect::sacrifice_source()])
+                .starting_with(amount);
+        self.with_abilities(countdown.into_abilities(Some(&text)))
     }
 
     /// Add vanishing N.
@@ -4103,40 +4101,20 @@ impl CardDefinitionBuilder {
     /// At the beginning of your upkeep, remove a time counter from it.
     /// When the last is removed, sacrifice it."
     pub fn vanishing(self, amount: u32) -> Self {
-        let text = if amount == 0 {
-            "Vanishing".to_string()
-        } else {
-            format!("Vanishing {amount}")
-        };
-        let mut builder = self;
+        let text = format!("Vanishing {amount}");
+        let mut countdown =
+            CounterCountdown::remove(CounterType::Time, vec![Effect::sacrifice_source()]);
         if amount > 0 {
-            builder = builder.with_ability(
-                Ability::static_ability(StaticAbility::enters_with_counters(
-                    CounterType::Time,
-                    amount,
-                ))
-                .with_text(&text),
-            );
+            countdown = countdown.starting_with(amount);
         }
-        builder
-            .with_ability(Ability::triggered(
-                Trigger::beginning_of_upkeep(PlayerFilter::You),
-                vec![Effect::remove_counters(
-                    CounterType::Time,
-                    1,
-                    ChooseSpec::Source,
-                )],
-            ))
-            .with_ability(Ability {
-                kind: AbilityKind::Triggered(TriggeredAbility {
-                    trigger: Trigger::counter_removed_from(ObjectFilter::source()),
-                    effects: vec![Effect::sacrifice_source()],
-                    choices: vec![],
-                    intervening_if: Some(Condition::SourceHasNoCounter(CounterType::Time)),
-                }),
-                functional_zones: vec![Zone::Battlefield],
-                text: None,
-            })
+        self.with_abilities(countdown.into_abilities(Some(&text)))
+    }
+
+    /// Add an upkeep counter countdown, such as "At the beginning of your
+    /// upkeep, put an age counter on this. Then if it has three or more age
+    /// counters on it, ...".
+    pub fn counter_countdown(self, countdown: CounterCountdown) -> Self {
+        self.with_abilities(countdown.into_abilities(None))
     }
 
     /// Add backup N as a placeholder printed ability. This is finalized into
@@ -4743,6 +4721,30 @@ impl CardDefinitionBuilder {
         self
     }
 
+    // === Split Cards ===
+
+    /// Make this card one half of a split card whose other half is named `other_half`.
+    pub fn split_with(mut self, other_half: impl Into<String>) -> Self {
+        self.card_builder = self
+            .card_builder
+            .other_face_name(other_half)
+            .linked_face_layout(LinkedFaceLayout::Split);
+        self
+    }
+
+    /// Add fuse (both halves of this split card may be cast together from hand).
+    pub fn fuse(mut self) -> Self {
+        self.has_fuse = true;
+        self
+    }
+
+    /// Give this split card's second half aftermath (cast it only from a graveyard,
+    /// then exile it).
+    pub fn aftermath(mut self) -> Self {
+        self.has_aftermath = true;
+        self
+    }
+
     // === Alternative Casting Methods ===
 
     /// Add flashback with the given cost.
@@ -5118,12 +5120,13 @@ impl CardDefinitionBuilder {
     pub fn build(self) -> CardDefinition {
         let definition = finalize_backup_abilities(CardDefinition {
             card: self.card_builder.build(),
-            abilities: self.abilities,
-            spell_effect: self.spell_effect,
+            abilities: self.abilities.into(),
+            spell_effect: self.spell_effect.map(Into::into),
             aura_attach_filter: self.aura_attach_filter,
-            alternative_casts: self.alternative_casts,
-            has_fuse: false,
-            optional_costs: self.optional_costs,
+            alternative_casts: self.alternative_casts.into(),
+            has_fuse: self.has_fuse,
+            has_aftermath: self.has_aftermath,
+            optional_costs: self.optional_costs.into(),
             max_saga_chapter: self.max_saga_chapter,
             additional_cost: self.additional_cost,
         });
@@ -5141,7 +5144,7 @@ mod delayed_trigger_finalization_tests {
             CardDefinitionBuilder::new(CardId::new(), "Delayed Safety Net Probe")
                 .card_types(vec![CardType::Instant]);
         let mut definition = original_builder.clone().build();
-        definition.spell_effect = Some(vec![Effect::draw(1)]);
+        definition.spell_effect = Some(vec![Effect::draw(1)].into());
         definition.abilities.push(
             Ability::triggered(
                 Trigger::beginning_of_upkeep(PlayerFilter::You),
@@ -5176,7 +5179,7 @@ mod delayed_trigger_finalization_tests {
         let original_builder = CardDefinitionBuilder::new(CardId::new(), "Stack Trigger Probe")
             .card_types(vec![CardType::Instant]);
         let mut definition = original_builder.clone().build();
-        definition.spell_effect = Some(vec![Effect::draw(1)]);
+        definition.spell_effect = Some(vec![Effect::draw(1)].into());
         definition.abilities.push(
             Ability::triggered(Trigger::you_cast_this_spell(), vec![Effect::draw(1)])
                 .in_zones(vec![Zone::Stack])
@@ -7264,9 +7267,15 @@ If a card would be put into your graveyard from anywhere this turn, exile that c
         let mut game =
             crate::game_state::GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let alice = PlayerId::from_index(0);
+        let night_side = crate::cards::builtin_registry()
+            .get("Grizzly Bears")
+            .expect("night side should exist in builtin registry");
         let source_card = crate::card::CardBuilder::new(CardId::from_raw(70140), "Werewolf")
             .card_types(vec![CardType::Creature])
             .power_toughness(PowerToughness::fixed(2, 2))
+            .other_face(night_side.card.id)
+            .other_face_name("Grizzly Bears")
+            .linked_face_layout(crate::card::LinkedFaceLayout::TransformLike)
             .build();
         let source = game.create_object_from_card(&source_card, alice, Zone::Battlefield);
 
@@ -8402,6 +8411,33 @@ If a card would be put into your graveyard from anywhere this turn, exile that c
         );
     }
 
+    #[test]
+    fn parse_strive_line_keeps_colored_per_target_cost() {
+        let def = CardDefinitionBuilder::new(CardId::new(), "Strive Variant")
+            .parse_text(
+                "Strive — This spell costs {1}{G} more to cast for each target beyond the first.",
+            )
+            .expect("strive line should parse");
+
+        let increase = def
+            .abilities
+            .iter()
+            .find_map(|ability| match &ability.kind {
+                AbilityKind::Static(static_ability) => static_ability
+                    .cost_increase_per_additional_target()
+                    .cloned(),
+                _ => None,
+            })
+            .expect("expected additional-target cost increase ability");
+        assert_eq!(
+            increase,
+            crate::mana::ManaCost::from_pips(vec![
+                vec![crate::mana::ManaSymbol::Generic(1)],
+                vec![crate::mana::ManaSymbol::Green],
+            ])
+        );
+    }
+
     #[test]
     fn parse_object_filter_rejects_controller_only_phrase() {
         let tokens = tokenize_line("you control", 0);
@@ -9186,14 +9222,29 @@ If a card would be put into your graveyard from anywhere this turn, exile that c
     }
 
     #[test]
-    fn parse_rejects_divided_damage_distribution_clause() {
-        let result = CardDefinitionBuilder::new(CardId::new(), "Fire at Will Variant").parse_text(
-            "Fire at Will deals 3 damage divided as you choose among one, two, or three target attacking or blocking creatures.",
-        );
-        assert!(
-            result.is_err(),
-            "unsupported divided-damage distribution should fail parse instead of collapsing into a single target damage effect"
-        );
+    fn parse_divided_damage_distribution_clause() {
+        let def = CardDefinitionBuilder::new(CardId::new(), "Fire at Will Variant")
+            .parse_text(
+                "Fire at Will deals 3 damage divided as you choose among one, two, or three target attacking or blocking creatures.",
+            )
+            .expect("divided-damage distribution should parse");
+        let effects = def.spell_effect.as_ref().expect("spell effects");
+        let deal = effects
+            .iter()
+            .find_map(|effect| {
+                effect
+                    .downcast_ref::<crate::effects::TaggedEffect>()
+                    .and_then(|tagged| {
+                        tagged
+                            .effect
+                            .downcast_ref::<crate::effects::DealDamageEffect>()
+                    })
+            })
+            .expect("expected a tagged deal damage effect");
+        assert!(deal.divided, "expected damage to be divided");
+        assert_eq!(deal.amount, Value::Fixed(3));
+        let count = deal.target.count();
+        assert_eq!((count.min, count.max), (1, Some(3)));
     }
 
     #[test]
@@ -9497,7 +9548,7 @@ If a card would be put into your graveyard from anywhere this turn, exile that c
             game.object(prior_id).expect("prior spell should exist"),
             &game,
         );
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
 
         let spell = game.object(spell_id).expect("spell should exist");
@@ -10283,15 +10334,15 @@ If a card would be put into your graveyard from anywhere this turn, exile that c
 
     #[test]
     fn parse_commander_creatures_have_granted_cost_reduction() {
-        let err = CardDefinitionBuilder::new(CardId::new(), "Acolyte of Bahamut Variant")
+        let def = CardDefinitionBuilder::new(CardId::new(), "Acolyte of Bahamut Variant")
             .parse_text(
                 "Commander creatures you own have \"The first Dragon spell you cast each turn costs {2} less to cast.\"",
             )
-            .expect_err("unsupported first-spell-each-turn granted cost reduction should fail");
-        let joined = format!("{err:?}").to_ascii_lowercase();
+            .expect("first-spell-each-turn granted cost reduction should parse");
+        let debug = format!("{:?}", def.abilities);
         assert!(
-            joined.contains("unsupported first-spell-each-turn cost modifier"),
-            "expected strict first-spell-each-turn rejection, got {joined}"
+            debug.contains("uses_per_turn: Some(1)"),
+            "expected granted once-per-turn cost reduction, got {debug}"
         );
     }
 
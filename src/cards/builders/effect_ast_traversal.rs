@@ -105,6 +105,7 @@ macro_rules! nested_effects_variants {
 pub(super) fn assert_effect_ast_variant_coverage(effect: &EffectAst) {
     match effect {
         EffectAst::DealDamage { .. } => {}
+        EffectAst::DealDamageDivided { .. } => {}
         EffectAst::DealDamageEqualToPower { .. } => {}
         EffectAst::Fight { .. } => {}
         EffectAst::FightIterated { .. } => {}
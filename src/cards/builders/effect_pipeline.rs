@@ -330,16 +330,18 @@ pub(crate) fn prepare_triggered_effects_for_lowering(
     let normalized = normalize_effects_ast(effects);
     let mut body_effects = normalized.clone();
     let mut intervening_if = None;
-    if normalized.len() == 1
-        && let EffectAst::Conditional {
-            predicate,
-            if_true,
-            if_false,
-        } = &normalized[0]
+    // An "if" right after the trigger condition governs the whole ability
+    // (rule 603.4), including any sentences that follow it.
+    if let Some(EffectAst::Conditional {
+        predicate,
+        if_true,
+        if_false,
+    }) = normalized.first()
         && if_false.is_empty()
         && !if_true.is_empty()
     {
         body_effects = if_true.clone();
+        body_effects.extend(normalized[1..].iter().cloned());
         intervening_if = Some(predicate.clone());
     }
 
@@ -491,6 +491,8 @@ pub(crate) fn trigger_supports_event_value(trigger: &TriggerSpec, spec: &EventVa
             | TriggerSpec::DealsCombatDamageToPlayer { .. }
             | TriggerSpec::DealsCombatDamageToPlayerOneOrMore { .. }
             | TriggerSpec::CounterPutOn { .. } => true,
+            TriggerSpec::KeywordAction { action, .. }
+            | TriggerSpec::KeywordActionFromSource { action, .. } => action.has_amount(),
             TriggerSpec::Either(left, right) => {
                 trigger_supports_event_value(left, spec)
                     && trigger_supports_event_value(right, spec)
@@ -796,9 +798,15 @@ pub(crate) fn compile_condition_from_predicate_ast(
         PredicateAst::SourcePowerAtLeast(count) => Condition::SourcePowerAtLeast(*count),
         PredicateAst::SourceIsInZone(zone) => Condition::SourceIsInZone(*zone),
         PredicateAst::YouAttackedThisTurn => Condition::AttackedThisTurn,
+        PredicateAst::YouGainedLifeThisTurn => Condition::YouGainedLifeThisTurn,
+        PredicateAst::OpponentLostLifeThisTurn => Condition::OpponentLostLifeThisTurn,
         PredicateAst::SourceWasCast => Condition::SourceWasCast,
         PredicateAst::NoSpellsWereCastLastTurn => Condition::NoSpellsWereCastLastTurn,
         PredicateAst::ThisSpellWasKicked => Condition::ThisSpellWasKicked,
+        PredicateAst::ThisSpellWasCastFromZone(zone) => Condition::ThisSpellWasCastFromZone(*zone),
+        PredicateAst::YouCastAnotherSpellNamedThisGame(name) => {
+            Condition::YouCastAnotherSpellNamedThisGame(name.clone())
+        }
         PredicateAst::TargetWasKicked => Condition::TargetWasKicked,
         PredicateAst::TargetSpellCastOrderThisTurn(order) => {
             Condition::TargetSpellCastOrderThisTurn(*order)
@@ -852,6 +860,9 @@ macro_rules! direct_target_effect_variants {
         EffectAst::DealDamage {
             target: $target,
             ..
+        } | EffectAst::DealDamageDivided {
+            target: $target,
+            ..
         } | EffectAst::Counter { target: $target }
             | EffectAst::CounterUnlessPays {
                 target: $target,
@@ -1298,6 +1309,7 @@ pub(crate) fn effect_references_event_derived_amount(effect: &EffectAst) -> bool
     assert_effect_ast_variant_coverage(effect);
     match effect {
         EffectAst::DealDamage { amount, .. }
+        | EffectAst::DealDamageDivided { amount, .. }
         | EffectAst::DealDamageEach { amount, .. }
         | EffectAst::Draw { count: amount, .. }
         | EffectAst::LoseLife { amount, .. }
@@ -1486,7 +1498,9 @@ pub(crate) fn effect_references_it_tag(effect: &EffectAst) -> bool {
     }
 
     match effect {
-        EffectAst::DealDamage { amount, .. } => value_references_tag(amount, IT_TAG),
+        EffectAst::DealDamage { amount, .. } | EffectAst::DealDamageDivided { amount, .. } => {
+            value_references_tag(amount, IT_TAG)
+        }
         EffectAst::DealDamageEach { amount, filter } => {
             value_references_tag(amount, IT_TAG) || filter_references_tag(filter, IT_TAG)
         }
@@ -1607,6 +1621,10 @@ pub(crate) fn restriction_references_tag(
         | Restriction::HaveCountersPlaced(filter)
         | Restriction::BeTargeted(filter)
         | Restriction::BeCountered(filter)
+        | Restriction::BeCounteredBy { spells: filter, .. }
+        | Restriction::AttackPlayer {
+            attackers: filter, ..
+        }
         | Restriction::Transform(filter)
         | Restriction::AttackOrBlock(filter)
         | Restriction::ActivateAbilitiesOf(filter)
@@ -1674,6 +1692,16 @@ pub(crate) fn compile_annotated_effects_with_context(
     let mut choices = Vec::new();
     let mut idx = 0;
 
+    // Ids handed out while annotating must not be reissued to nested
+    // sequences lowered below.
+    for id in annotated
+        .effects
+        .iter()
+        .filter_map(|effect| effect.assigned_effect_id)
+    {
+        ctx.reserve_effect_id(id);
+    }
+
     while idx < annotated.effects.len() {
         let current = &annotated.effects[idx];
         ctx.apply_reference_env(&current.in_env);
@@ -3349,6 +3377,21 @@ fn try_compile_combat_and_damage_effect(
             }
             (effects, choices)
         }
+        EffectAst::DealDamageDivided { amount, target } => {
+            let resolved_amount = resolve_value_it_tag(amount, &current_reference_env(ctx))?;
+            let (spec, _) = resolve_target_spec_with_choices(target, &current_reference_env(ctx))?;
+            let effect = Effect::new(
+                crate::effects::DealDamageEffect::new(resolved_amount, spec.clone())
+                    .with_divided(true),
+            );
+            let effect = tag_object_target_effect(effect, &spec, ctx, "damaged");
+            let choices = if spec.is_target() {
+                vec![spec]
+            } else {
+                Vec::new()
+            };
+            (vec![effect], choices)
+        }
         EffectAst::DealDamageEqualToPower { source, target } => {
             let (source_spec, mut choices) =
                 resolve_target_spec_with_choices(source, &current_reference_env(ctx))?;
@@ -7273,8 +7316,16 @@ fn try_compile_object_zone_and_exchange_effect(
                 }
                 Err(err) => return Err(err),
             };
-            if resolved_filter.controller.is_none() && resolved_filter.tagged_constraints.is_empty()
-            {
+            // Only a direct reference ("sacrifice it") may name a permanent the
+            // chooser doesn't control; relational constraints like "that shares
+            // a card type with it" still pick among the chooser's permanents.
+            let names_tagged_object = resolved_filter.tagged_constraints.iter().any(|constraint| {
+                matches!(
+                    constraint.relation,
+                    TaggedOpbjectRelation::IsTaggedObject | TaggedOpbjectRelation::SameStableId
+                )
+            });
+            if resolved_filter.controller.is_none() && !names_tagged_object {
                 resolved_filter.controller = Some(chooser.clone());
             }
             if resolved_filter.source {
@@ -8755,11 +8806,7 @@ pub(crate) fn token_definition_for(name: &str) -> Option<CardDefinition> {
         return Some(eldrazi_scion_token_definition());
     }
     if has_word("food") && !words.contains(&"creature") {
-        let builder = CardDefinitionBuilder::new(CardId::new(), "Food")
-            .token()
-            .card_types(vec![CardType::Artifact])
-            .subtypes(vec![Subtype::Food]);
-        return Some(builder.build());
+        return Some(crate::cards::tokens::food_token_definition());
     }
     if has_word("wicked") && has_word("role") {
         return Some(crate::cards::tokens::wicked_role_token_definition());
@@ -8780,10 +8827,7 @@ pub(crate) fn token_definition_for(name: &str) -> Option<CardDefinition> {
         return Some(crate::cards::tokens::cursed_role_token_definition());
     }
     if has_word("blood") && !words.contains(&"creature") {
-        let builder = CardDefinitionBuilder::new(CardId::new(), "Blood")
-            .token()
-            .card_types(vec![CardType::Artifact]);
-        return Some(builder.build());
+        return Some(crate::cards::tokens::blood_token_definition());
     }
     if has_word("powerstone") && !words.contains(&"creature") {
         let builder = CardDefinitionBuilder::new(CardId::new(), "Powerstone")
@@ -9715,6 +9759,43 @@ mod parse_compile_tests {
         assert_eq!(investigate.count, Value::Fixed(2));
     }
 
+    #[test]
+    fn parse_scry_and_surveil_payoffs_accept_that_many() {
+        for text in [
+            "Whenever you scry, put that many +1/+1 counters on this creature.",
+            "Whenever you surveil, you gain that much life.",
+        ] {
+            let def = CardDefinitionBuilder::new(CardId::new(), "Keyword Payoff")
+                .card_types(vec![CardType::Creature])
+                .parse_text(text)
+                .unwrap_or_else(|err| panic!("parse {text:?}: {err:?}"));
+            let effects = def
+                .abilities
+                .iter()
+                .find_map(|ability| match &ability.kind {
+                    crate::ability::AbilityKind::Triggered(triggered) => {
+                        Some(triggered.effects.clone())
+                    }
+                    _ => None,
+                })
+                .expect("triggered ability");
+            assert!(
+                format!("{effects:?}").contains("EventValue(Amount)"),
+                "{text}: {effects:?}"
+            );
+        }
+
+        // Investigating always makes one Clue, so there is no amount to refer to.
+        assert!(
+            CardDefinitionBuilder::new(CardId::new(), "Investigate Payoff")
+                .card_types(vec![CardType::Creature])
+                .parse_text(
+                    "Whenever you investigate, put that many +1/+1 counters on this creature."
+                )
+                .is_err()
+        );
+    }
+
     fn test_ctx(line: &str) -> NormalizedLine {
         NormalizedLine {
             original: line.to_string(),
@@ -219,6 +219,13 @@ pub(crate) fn parse_activated_line_with_raw(
             };
             let loyalty_restrictions =
                 loyalty_additional_restrictions(loyalty_shorthand_cost.is_some());
+            // Loyalty abilities are never mana abilities (CR 605.1a), so they
+            // add their mana through effects and use the stack.
+            let variable_mana_output = if loyalty_shorthand_cost.is_some() {
+                None
+            } else {
+                Some(vec![])
+            };
             let build_additional_restrictions = || {
                 let mut restrictions = loyalty_restrictions.clone();
                 restrictions.extend(additional_activation_restrictions.clone());
@@ -244,7 +251,7 @@ pub(crate) fn parse_activated_line_with_raw(
                         timing: loyalty_timing.clone(),
                         additional_restrictions: build_additional_restrictions(),
                         activation_restrictions: vec![],
-                        mana_output: Some(vec![]),
+                        mana_output: variable_mana_output.clone(),
                         activation_condition: mana_activation_condition.clone(),
                         mana_usage_restrictions: mana_usage_restrictions.clone(),
                     }),
@@ -276,7 +283,10 @@ pub(crate) fn parse_activated_line_with_raw(
             }
 
             if !mana.is_empty() {
-                if dynamic_amount.is_none() && extra_effects_ast.is_empty() {
+                if dynamic_amount.is_none()
+                    && extra_effects_ast.is_empty()
+                    && loyalty_shorthand_cost.is_none()
+                {
                     let mut ability = Ability {
                         kind: AbilityKind::Activated(ActivatedAbility {
                             mana_cost,
@@ -314,7 +324,7 @@ pub(crate) fn parse_activated_line_with_raw(
                         timing: loyalty_timing,
                         additional_restrictions: build_additional_restrictions(),
                         activation_restrictions: vec![],
-                        mana_output: Some(vec![]),
+                        mana_output: variable_mana_output.clone(),
                         activation_condition: mana_activation_condition.clone(),
                         mana_usage_restrictions: mana_usage_restrictions.clone(),
                     }),
@@ -4968,6 +4978,10 @@ pub(crate) fn parse_negated_object_restriction_clause(
     let restriction = match remainder_words.as_slice() {
         ["attack"] => Restriction::attack(filter),
         ["attack", "this", "turn"] => Restriction::attack(filter),
+        ["attack", "you"] => Restriction::attack_player(filter, PlayerFilter::You),
+        ["attack", "you", "or", "planeswalkers", "you", "control"] => {
+            Restriction::attack_player_or_planeswalkers(filter, PlayerFilter::You)
+        }
         ["attack", "or", "block"] => Restriction::attack_or_block(filter),
         ["attack", "or", "block", "this", "turn"] => Restriction::attack_or_block(filter),
         ["block"] => Restriction::block(filter),
@@ -4979,6 +4993,17 @@ pub(crate) fn parse_negated_object_restriction_clause(
         ["be", "regenerated", "this", "turn"] => Restriction::be_regenerated(filter),
         ["be", "sacrificed"] => Restriction::be_sacrificed(filter),
         ["be", "countered"] => Restriction::be_countered(filter),
+        [
+            "be",
+            "countered",
+            "by",
+            "spells",
+            "or",
+            "abilities",
+            "your",
+            "opponents",
+            "control",
+        ] => Restriction::be_countered_by(filter, PlayerFilter::Opponent),
         ["be", "activated"] | ["be", "activated", "this", "turn"] => match ability_scope {
             Some(ActivatedAbilityScope::All) => Restriction::activate_abilities_of(filter),
             Some(ActivatedAbilityScope::TapCostOnly) => {
@@ -5001,7 +5026,11 @@ pub(crate) fn parse_negated_object_restriction_clause(
             }
         },
         ["transform"] => Restriction::transform(filter),
-        ["be", "targeted"] => Restriction::be_targeted(filter),
+        ["be", "targeted"]
+        | ["be", "the", "target", "of", "spells", "or", "abilities"]
+        | ["be", "the", "targets", "of", "spells", "or", "abilities"] => {
+            Restriction::be_targeted(filter)
+        }
         _ if remainder_words.first() == Some(&"block") && remainder_words.len() > 1 => {
             let attacker_tokens = trim_commas(&remainder_tokens[1..]);
             let attacker_filter = parse_subject_object_filter(&attacker_tokens)?
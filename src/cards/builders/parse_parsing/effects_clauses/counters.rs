@@ -42,6 +42,10 @@ pub(crate) fn parse_counter_type_word(word: &str) -> Option<CounterType> {
         "age" => Some(CounterType::Age),
         "finality" => Some(CounterType::Finality),
         "time" => Some(CounterType::Time),
+        "fade" => Some(CounterType::Fade),
+        "quest" => Some(CounterType::Quest),
+        "hour" => Some(CounterType::Hour),
+        "filibuster" => Some(CounterType::Filibuster),
         "brain" => Some(CounterType::Brain),
         "burden" => Some(CounterType::Named(intern_counter_name("burden"))),
         "level" => Some(CounterType::Level),
@@ -2,11 +2,11 @@ use crate::cards::builders::effect_ast_traversal::for_each_nested_effects_mut;
 use crate::cards::builders::{
     CardTextError, ChoiceCount, EffectAst, IT_TAG, PlayerAst, PredicateAst, TagKey, TargetAst,
     Token, bind_implicit_player_context, find_verb, is_until_end_of_turn,
-    negated_action_word_index, parse_effect_chain, parse_effect_chain_inner, parse_number,
-    parse_object_filter, parse_pt_modifier, parse_pt_modifier_values,
-    parse_target_count_range_prefix, parse_target_phrase, parse_value, parse_where_x_value_clause,
-    remove_first_word, starts_with_until_end_of_turn, token_index_for_word_index, trim_commas,
-    words,
+    negated_action_word_index, parse_distinct_among_value_words, parse_effect_chain,
+    parse_effect_chain_inner, parse_number, parse_object_filter, parse_pt_modifier,
+    parse_pt_modifier_values, parse_target_count_range_prefix, parse_target_phrase, parse_value,
+    parse_where_x_value_clause, remove_first_word, starts_with_until_end_of_turn,
+    token_index_for_word_index, trim_commas, words,
 };
 use crate::effect::Until;
 use crate::effect::Value;
@@ -421,6 +421,10 @@ pub(crate) fn parse_get_for_each_count_value(
         return Ok(Some(Value::ColorsAmong(filter)));
     }
 
+    if let Some(value) = parse_distinct_among_value_words(&filter_words) {
+        return Ok(Some(value));
+    }
+
     Ok(Some(Value::Count(parse_object_filter(
         filter_tokens,
         other,
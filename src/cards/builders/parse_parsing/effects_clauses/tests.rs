@@ -278,17 +278,36 @@ fn parse_deal_damage_equal_to_fixed_plus_sacrificed_mana_value() {
 }
 
 #[test]
-fn parse_divided_damage_distribution_still_fails_loudly() {
+fn parse_divided_damage_distribution_keeps_target_count() {
     let tokens = tokenize_line(
         "this creature deals 4 damage divided as you choose among any number of targets",
         0,
     );
-    let err = parse_effect_clause(&tokens)
-        .expect_err("divided-damage distribution should remain unsupported");
-    let message = card_text_error_message(err);
+    let effect = parse_effect_clause(&tokens).expect("divided damage should parse");
+    let EffectAst::DealDamageDivided { amount, target } = effect else {
+        panic!("expected divided damage effect, got {effect:?}");
+    };
+    assert_eq!(amount, Value::Fixed(4));
     assert!(
-        message.contains("unsupported divided-damage distribution clause"),
-        "expected strict divided-damage failure, got {message}"
+        matches!(
+            target,
+            TargetAst::WithCount(ref inner, count)
+                if matches!(**inner, TargetAst::AnyTarget(_))
+                    && count == crate::effect::ChoiceCount::any_number()
+        ),
+        "expected any number of any targets, got {target:?}"
+    );
+}
+
+#[test]
+fn parse_divided_damage_evenly_still_fails_loudly() {
+    let tokens = tokenize_line(
+        "this creature deals 4 damage divided evenly, rounded down, among any number of targets",
+        0,
+    );
+    assert!(
+        parse_effect_clause(&tokens).is_err(),
+        "evenly divided damage should remain unsupported"
     );
 }
 
@@ -1116,6 +1135,20 @@ fn parse_predicate_creature_died_this_turn() {
     assert!(matches!(predicate, PredicateAst::CreatureDiedThisTurn));
 }
 
+#[test]
+fn parse_predicate_you_gained_life_this_turn() {
+    let tokens = tokenize_line("you gained life this turn", 0);
+    let predicate = parse_predicate(&tokens).expect("parse life-gained predicate");
+    assert!(matches!(predicate, PredicateAst::YouGainedLifeThisTurn));
+}
+
+#[test]
+fn parse_predicate_opponent_lost_life_this_turn() {
+    let tokens = tokenize_line("an opponent lost life this turn", 0);
+    let predicate = parse_predicate(&tokens).expect("parse life-lost predicate");
+    assert!(matches!(predicate, PredicateAst::OpponentLostLifeThisTurn));
+}
+
 #[test]
 fn parse_predicate_you_had_land_enter_battlefield_under_your_control_this_turn() {
     let tokens = tokenize_line(
@@ -1983,6 +2016,34 @@ fn parse_object_filter_exiled_card_opponent_owns_with_void_counter_stays_in_exil
     );
 }
 
+#[test]
+fn parse_object_filter_counter_count_and_counter_presence() {
+    let tokens = tokenize_line("creature with three or more +1/+1 counters on it", 0);
+    let filter = parse_object_filter(&tokens, false).expect("parse counter-count filter");
+    assert_eq!(
+        filter.with_counter_count,
+        Some((
+            crate::filter::CounterConstraint::Typed(CounterType::PlusOnePlusOne),
+            crate::filter::Comparison::GreaterThanOrEqual(3)
+        ))
+    );
+    assert_eq!(filter.with_counter, None);
+
+    let tokens = tokenize_line("creature with a counter on it", 0);
+    let filter = parse_object_filter(&tokens, false).expect("parse any-counter filter");
+    assert_eq!(
+        filter.with_counter,
+        Some(crate::filter::CounterConstraint::Any)
+    );
+
+    let tokens = tokenize_line("creature with no counters on it", 0);
+    let filter = parse_object_filter(&tokens, false).expect("parse no-counter filter");
+    assert_eq!(
+        filter.without_counter,
+        Some(crate::filter::CounterConstraint::Any)
+    );
+}
+
 #[test]
 fn parse_target_phrase_spell_cast_from_graveyard_uses_spell_origin_zone() {
     let tokens = tokenize_line("target spell cast from a graveyard", 0);
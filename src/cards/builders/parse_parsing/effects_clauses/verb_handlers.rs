@@ -1,7 +1,7 @@
 use crate::cards::builders::parse_parsing::effects_clauses::{
-    parse_add_mana, parse_become, parse_create, parse_delayed_return_timing_words, parse_destroy,
-    parse_discard, parse_equal_to_aggregate_filter_value,
-    parse_equal_to_number_of_counters_on_reference_value,
+    parse_add_mana, parse_become, parse_counter_target_count_prefix, parse_create,
+    parse_delayed_return_timing_words, parse_destroy, parse_discard,
+    parse_equal_to_aggregate_filter_value, parse_equal_to_number_of_counters_on_reference_value,
     parse_equal_to_number_of_filter_plus_or_minus_fixed_value,
     parse_equal_to_number_of_filter_value, parse_equal_to_number_of_opponents_you_have_value,
     parse_exchange, parse_exile, parse_flip, parse_get, parse_investigate, parse_mill, parse_pay,
@@ -123,6 +123,8 @@ fn parse_library_nth_from_top_destination(tokens: &[Token]) -> Option<Value> {
         ["third", "from", "top"] => Some(3),
         ["fourth", "from", "top"] => Some(4),
         ["fifth", "from", "top"] => Some(5),
+        ["sixth", "from", "top"] => Some(6),
+        ["seventh", "from", "top"] => Some(7),
         _ => None,
     };
     if let Some(position) = fixed_position {
@@ -593,10 +595,7 @@ pub(crate) fn parse_deal_damage(tokens: &[Token]) -> Result<EffectAst, CardTextE
         && clause_words.contains(&"choose")
         && clause_words.contains(&"among");
     if is_divided_as_you_choose_clause {
-        return Err(CardTextError::ParseError(format!(
-            "unsupported divided-damage distribution clause (clause: '{}')",
-            clause_words.join(" ")
-        )));
+        return parse_deal_damage_divided(tokens);
     }
     if let Some(effect) = parse_deal_damage_equal_to_clause(tokens)? {
         return Ok(effect);
@@ -632,6 +631,41 @@ pub(crate) fn parse_deal_damage(tokens: &[Token]) -> Result<EffectAst, CardTextE
     )))
 }
 
+fn parse_deal_damage_divided(tokens: &[Token]) -> Result<EffectAst, CardTextError> {
+    let clause_words = words(tokens);
+    let unsupported = || {
+        CardTextError::ParseError(format!(
+            "unsupported divided-damage distribution clause (clause: '{}')",
+            clause_words.join(" ")
+        ))
+    };
+
+    let (amount, used) = parse_value(tokens).ok_or_else(unsupported)?;
+    let rest = &tokens[used..];
+    let rest_words = words(rest);
+    if !rest_words.starts_with(&["damage", "divided", "as", "you", "choose", "among"]) {
+        return Err(unsupported());
+    }
+    let Some(among_idx) = rest.iter().position(|token| token.is_word("among")) else {
+        return Err(unsupported());
+    };
+    let among_tail = trim_commas(&rest[among_idx + 1..]);
+    let Some((target_count, used_count)) = parse_counter_target_count_prefix(&among_tail)? else {
+        return Err(unsupported());
+    };
+    let target_tokens = trim_commas(&among_tail[used_count..]);
+    let target = match words(&target_tokens).as_slice() {
+        [] => return Err(unsupported()),
+        ["targets"] | ["target"] => TargetAst::AnyTarget(span_from_tokens(&target_tokens)),
+        _ => parse_target_phrase(&target_tokens)?,
+    };
+
+    Ok(EffectAst::DealDamageDivided {
+        amount,
+        target: wrap_target_count(target, Some(target_count)),
+    })
+}
+
 pub(crate) fn parse_deal_damage_to_target_equal_to_clause(
     tokens: &[Token],
 ) -> Result<Option<EffectAst>, CardTextError> {
@@ -2250,6 +2284,23 @@ pub(crate) fn remap_source_stat_value_to_it(value: Value) -> Value {
     }
 }
 
+/// In a life clause, "its power/toughness/mana value" refers back to the
+/// object the previous sentence acted on ("Destroy target creature. You gain
+/// life equal to its toughness."), not to the spell or ability itself.
+fn remap_its_stat_life_amount(amount: Value, clause_words: &[&str]) -> Value {
+    let refers_to_its_stat = clause_words
+        .windows(2)
+        .any(|window| window == ["its", "power"] || window == ["its", "toughness"])
+        || clause_words
+            .windows(3)
+            .any(|window| window == ["its", "mana", "value"]);
+    if refers_to_its_stat {
+        remap_source_stat_value_to_it(amount)
+    } else {
+        amount
+    }
+}
+
 fn player_filter_for_life_reference(player: PlayerAst) -> Option<PlayerFilter> {
     match player {
         PlayerAst::You | PlayerAst::Implicit => Some(PlayerFilter::You),
@@ -2292,20 +2343,8 @@ pub(crate) fn parse_lose_life(
     let player = extract_subject_player(subject).unwrap_or(PlayerAst::Implicit);
 
     let clause_words = words(tokens);
-    if let Some(mut amount) = parse_life_equal_to_value(tokens)? {
-        if matches!(player, PlayerAst::ItsController | PlayerAst::ItsOwner)
-            && (clause_words
-                .windows(2)
-                .any(|window| window == ["its", "power"])
-                || clause_words
-                    .windows(2)
-                    .any(|window| window == ["its", "toughness"])
-                || clause_words
-                    .windows(3)
-                    .any(|window| window == ["its", "mana", "value"]))
-        {
-            amount = remap_source_stat_value_to_it(amount);
-        }
+    if let Some(amount) = parse_life_equal_to_value(tokens)? {
+        let amount = remap_its_stat_life_amount(amount, &clause_words);
         return Ok(EffectAst::LoseLife { amount, player });
     }
     if clause_words.as_slice() == ["the", "game"] {
@@ -2342,6 +2381,7 @@ pub(crate) fn parse_gain_life(
     let player = extract_subject_player(subject).unwrap_or(PlayerAst::Implicit);
 
     if let Some(amount) = parse_life_equal_to_value(tokens)? {
+        let amount = remap_its_stat_life_amount(amount, &words(tokens));
         return Ok(EffectAst::GainLife { amount, player });
     }
 
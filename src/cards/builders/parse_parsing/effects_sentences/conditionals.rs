@@ -343,6 +343,7 @@ pub(crate) fn parse_subtype_word(word: &str) -> Option<Subtype> {
         "yeti" => Some(Subtype::Yeti),
         "zombie" => Some(Subtype::Zombie),
         "zubera" => Some(Subtype::Zubera),
+        "blood" => Some(Subtype::Blood),
         "clue" => Some(Subtype::Clue),
         "contraption" => Some(Subtype::Contraption),
         "equipment" => Some(Subtype::Equipment),
@@ -1018,6 +1019,16 @@ pub(crate) fn parse_if_result_predicate(tokens: &[Token]) -> Option<IfResultPred
     if is_exact_negated_result("they") || is_negated_this_way_result("they") {
         return Some(IfResultPredicate::DidNot);
     }
+    if (words.len() == 2
+        && (words[0] == "player" || words[0] == "players")
+        && matches!(words[1], "doesnt" | "dont" | "didnt"))
+        || (words.len() == 3
+            && (words[0] == "player" || words[0] == "players")
+            && matches!(words[1], "does" | "do" | "did")
+            && words[2] == "not")
+    {
+        return Some(IfResultPredicate::DidNot);
+    }
 
     None
 }
@@ -1152,14 +1163,15 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
     }
 
     // Handle simple conjunction predicates like "... and have no cards in hand".
-    if let Some(and_idx) = filtered.iter().position(|word| *word == "and")
+    // Split on the unfiltered words so names on either side keep their articles.
+    if let Some(and_idx) = raw_words.iter().position(|word| *word == "and")
         && and_idx > 0
-        && and_idx + 1 < filtered.len()
+        && and_idx + 1 < raw_words.len()
     {
-        let right_first = filtered.get(and_idx + 1).copied();
-        if matches!(right_first, Some("have") | Some("you")) {
-            let left_words = &filtered[..and_idx];
-            let mut right_words = filtered[and_idx + 1..].to_vec();
+        let right_first = raw_words.get(and_idx + 1).copied();
+        if matches!(right_first, Some("have") | Some("you") | Some("youve")) {
+            let left_words = &raw_words[..and_idx];
+            let mut right_words = raw_words[and_idx + 1..].to_vec();
             // Inherit the subject when omitted ("... and have ...").
             if right_words.first().copied() == Some("have") {
                 right_words.insert(0, "you");
@@ -1225,6 +1237,51 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
         return Ok(PredicateAst::SourceHasNoCounter(counter_type));
     }
 
+    // "this/it has no <counter> counters on it"
+    // "this/it has <N> or more <counter> counters on it"
+    let source_counter_subject_len = match filtered.as_slice() {
+        ["this" | "it", "has", ..] => Some(2),
+        [
+            "this",
+            "creature" | "permanent" | "artifact" | "enchantment",
+            "has",
+            ..,
+        ] => Some(3),
+        ["this" | "it", ..] => Some(1),
+        _ => None,
+    };
+    if let Some(subject_len) = source_counter_subject_len
+        && let Some(rest) = filtered.get(subject_len..)
+        && matches!(rest.last().copied(), Some("it" | "this" | "itself"))
+        && rest.get(rest.len().saturating_sub(2)).copied() == Some("on")
+    {
+        let counter_words = &rest[..rest.len() - 2];
+        match counter_words {
+            ["no", counter_word, "counters" | "counter"] => {
+                if let Some(counter_type) = parse_counter_type_word(counter_word) {
+                    return Ok(PredicateAst::SourceHasNoCounter(counter_type));
+                }
+            }
+            [
+                count_word,
+                "or",
+                "more",
+                counter_word,
+                "counters" | "counter",
+            ] => {
+                if let Some(count) = parse_named_number(count_word)
+                    && let Some(counter_type) = parse_counter_type_word(counter_word)
+                {
+                    return Ok(PredicateAst::SourceHasCounterAtLeast {
+                        counter_type,
+                        count,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
     let triggering_object_had_no_counter_prefix_len = if raw_words.starts_with(&["it", "had", "no"])
     {
         Some(3)
@@ -1575,6 +1632,14 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
             "control",
             "this",
             "turn"
+        ] | [
+            "permanent",
+            "you",
+            "controlled",
+            "left",
+            "battlefield",
+            "this",
+            "turn"
         ]
     ) {
         return Ok(PredicateAst::PermanentLeftBattlefieldUnderYourControlThisTurn);
@@ -1637,6 +1702,19 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
         return Ok(PredicateAst::YouAttackedThisTurn);
     }
 
+    if filtered.as_slice() == ["you", "gained", "life", "this", "turn"] {
+        return Ok(PredicateAst::YouGainedLifeThisTurn);
+    }
+
+    if matches!(
+        filtered.as_slice(),
+        ["opponent", "lost", "life", "this", "turn"]
+            | ["opponents", "lost", "life", "this", "turn"]
+            | ["an", "opponent", "lost", "life", "this", "turn"]
+    ) {
+        return Ok(PredicateAst::OpponentLostLifeThisTurn);
+    }
+
     if filtered.as_slice() == ["you", "cast", "it"]
         || filtered.as_slice() == ["you", "cast", "this", "spell"]
     {
@@ -1651,6 +1729,29 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
     if filtered.as_slice() == ["this", "spell", "was", "kicked"] {
         return Ok(PredicateAst::ThisSpellWasKicked);
     }
+    if let ["this", "spell", "was", "cast", "from", "your", zone_word] = filtered.as_slice() {
+        let zone = match *zone_word {
+            "hand" => Some(Zone::Hand),
+            "graveyard" => Some(Zone::Graveyard),
+            "library" => Some(Zone::Library),
+            _ => None,
+        };
+        if let Some(zone) = zone {
+            return Ok(PredicateAst::ThisSpellWasCastFromZone(zone));
+        }
+    }
+    // "you've cast another spell named <name> this game"
+    if let Some(named_idx) = raw_words.iter().position(|word| *word == "named")
+        && matches!(
+            &raw_words[..named_idx],
+            ["youve", "cast", "another", "spell"] | ["you", "have", "cast", "another", "spell"]
+        )
+        && raw_words.ends_with(&["this", "game"])
+        && named_idx + 1 < raw_words.len() - 2
+    {
+        let name = raw_words[named_idx + 1..raw_words.len() - 2].join(" ");
+        return Ok(PredicateAst::YouCastAnotherSpellNamedThisGame(name));
+    }
     if filtered.as_slice() == ["it", "was", "kicked"]
         || filtered.as_slice() == ["that", "was", "kicked"]
     {
@@ -2269,11 +2370,7 @@ pub(crate) fn parse_predicate(tokens: &[Token]) -> Result<PredicateAst, CardText
         });
     }
 
-    let unsupported_unmodeled = filtered.as_slice() == ["you", "gained", "life", "this", "turn"]
-        || filtered.as_slice() == ["opponent", "lost", "life", "this", "turn"]
-        || filtered.as_slice() == ["opponents", "lost", "life", "this", "turn"]
-        || filtered.as_slice() == ["an", "opponent", "lost", "life", "this", "turn"]
-        || filtered.as_slice() == ["this", "card", "in", "your", "graveyard"]
+    let unsupported_unmodeled = filtered.as_slice() == ["this", "card", "in", "your", "graveyard"]
         || filtered.as_slice() == ["this", "artifact", "untapped"]
         || filtered.as_slice() == ["this", "has", "luck", "counter", "on", "it"]
         || filtered.as_slice() == ["it", "had", "revival", "counter", "on", "it"]
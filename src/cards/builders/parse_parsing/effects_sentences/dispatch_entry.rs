@@ -1732,6 +1732,16 @@ pub(crate) fn parse_effect_sentences(tokens: &[Token]) -> Result<Vec<EffectAst>,
             } else {
                 parse_effect_sentence(&sentence_tokens)?
             };
+        if wraps_as_if_did_not
+            && let Some(EffectAst::Conditional { if_false, .. }) = effects.last_mut()
+            && if_false.is_empty()
+            && !sentence_effects.is_empty()
+        {
+            // "If X, A. Otherwise, B." belongs to the preceding conditional.
+            *if_false = sentence_effects;
+            sentence_idx += 1;
+            continue;
+        }
         if wraps_as_if_did_not {
             sentence_effects = vec![EffectAst::IfResult {
                 predicate: IfResultPredicate::DidNot,
@@ -1929,9 +1939,9 @@ fn apply_cant_be_regenerated_to_effects_tail(effects: &mut [EffectAst]) -> bool
 
 pub(crate) fn primary_damage_target_from_effect(effect: &EffectAst) -> Option<TargetAst> {
     match effect {
-        EffectAst::DealDamage { target, .. } | EffectAst::DealDamageEqualToPower { target, .. } => {
-            Some(target.clone())
-        }
+        EffectAst::DealDamage { target, .. }
+        | EffectAst::DealDamageDivided { target, .. }
+        | EffectAst::DealDamageEqualToPower { target, .. } => Some(target.clone()),
         _ => {
             let mut found = None;
             for_each_nested_effects(effect, false, |nested| {
@@ -1947,6 +1957,7 @@ pub(crate) fn primary_damage_target_from_effect(effect: &EffectAst) -> Option<Ta
 pub(crate) fn primary_target_from_effect(effect: &EffectAst) -> Option<TargetAst> {
     match effect {
         EffectAst::DealDamage { target, .. }
+        | EffectAst::DealDamageDivided { target, .. }
         | EffectAst::DealDamageEqualToPower { target, .. }
         | EffectAst::Counter { target }
         | EffectAst::CounterUnlessPays { target, .. }
@@ -2064,6 +2075,7 @@ pub(crate) fn replace_unbound_x_in_damage_effect(
 ) -> Result<(), CardTextError> {
     match effect {
         EffectAst::DealDamage { amount, .. }
+        | EffectAst::DealDamageDivided { amount, .. }
         | EffectAst::DealDamageEach { amount, .. }
         | EffectAst::GainLife { amount, .. }
         | EffectAst::LoseLife { amount, .. } => {
@@ -2109,6 +2121,7 @@ pub(crate) fn replace_unbound_x_in_effect_anywhere(
 
     match effect {
         EffectAst::DealDamage { amount, .. }
+        | EffectAst::DealDamageDivided { amount, .. }
         | EffectAst::DealDamageEach { amount, .. }
         | EffectAst::Draw { count: amount, .. }
         | EffectAst::LoseLife { amount, .. }
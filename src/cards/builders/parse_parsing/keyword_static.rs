@@ -15,8 +15,8 @@ use crate::cards::builders::parse_parsing::{
     parse_all_creatures_able_to_block_source_line, parse_cant_clauses, parse_card_type,
     parse_color, parse_cost_reduction_line, parse_counter_type_from_tokens,
     parse_counter_type_word, parse_cycling_line, parse_devotion_value_from_add_clause,
-    parse_enters_tapped_line, parse_equal_to_aggregate_filter_value,
-    parse_equal_to_number_of_counters_on_reference_value,
+    parse_distinct_among_value_words, parse_enters_tapped_line,
+    parse_equal_to_aggregate_filter_value, parse_equal_to_number_of_counters_on_reference_value,
     parse_equal_to_number_of_filter_plus_or_minus_fixed_value,
     parse_equal_to_number_of_filter_value, parse_equal_to_number_of_opponents_you_have_value,
     parse_flashback_keyword_line, parse_granted_activated_or_triggered_ability_for_gain,
@@ -237,7 +237,11 @@ fn static_ability_ast_line_rules() -> &'static [StaticAbilityLineRuleDef] {
         single_static_ability_ast_rule!(parse_reduced_maximum_hand_size_line),
         single_static_ability_ast_rule!(parse_library_of_leng_discard_replacement_line),
         single_static_ability_ast_rule!(parse_draw_replace_exile_top_face_down_line),
+        single_static_ability_ast_rule!(parse_draw_from_empty_library_win_instead_line),
         single_static_ability_ast_rule!(parse_exile_to_countered_exile_instead_of_graveyard_line),
+        single_static_ability_ast_rule!(parse_exile_instead_of_graveyard_line),
+        single_static_ability_ast_rule!(parse_token_creation_replacement_line),
+        single_static_ability_ast_rule!(parse_counter_placement_replacement_line),
         single_static_ability_ast_rule!(parse_toph_first_metalbender_line),
         single_static_ability_ast_rule!(parse_discard_or_redirect_replacement_line),
         single_static_ability_ast_rule!(parse_pay_life_or_enter_tapped_line),
@@ -369,7 +373,7 @@ fn static_ability_ast_line_rules() -> &'static [StaticAbilityLineRuleDef] {
         single_static_ability_ast_rule!(parse_flying_restriction_line),
         single_static_ability_ast_rule!(parse_can_block_only_flying_line),
         single_static_ability_ast_rule!(parse_assign_damage_as_unblocked_line),
-        single_static_ability_ast_rule!(parse_grant_flash_to_noncreature_spells_line),
+        single_static_ability_ast_rule!(parse_grant_flash_to_spells_line),
         single_static_ability_ast_rule!(parse_prevent_all_combat_damage_to_source_line),
         single_static_ability_ast_rule!(parse_prevent_all_damage_to_source_by_creatures_line),
         single_static_ability_ast_rule!(parse_prevent_all_damage_dealt_to_creatures_line),
@@ -1612,6 +1616,16 @@ pub(crate) fn parse_damage_redirect_to_source_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
     let words = words(tokens);
+    // A card's own name normalizes to "this", so accept "this" and "this creature".
+    if let Some(rest) = words.strip_prefix(
+        &[
+            "all", "damage", "that", "would", "be", "dealt", "to", "you", "is", "dealt", "to",
+            "this",
+        ][..],
+    ) && (rest == ["instead"] || rest == ["creature", "instead"])
+    {
+        return Ok(Some(StaticAbility::redirect_damage_from_you_to_source()));
+    }
     if words.len() != 19 {
         return Ok(None);
     }
@@ -2169,6 +2183,12 @@ pub(crate) fn parse_buyback_cost_reduction_line(
 pub(crate) fn parse_spell_cost_increase_per_target_beyond_first_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
+    // "Strive — This spell costs ..." keeps its ability word.
+    let tokens = if tokens.first().is_some_and(|token| token.is_word("strive")) {
+        &tokens[1..]
+    } else {
+        tokens
+    };
     let words = words(tokens);
     if !words.starts_with(&["this", "spell", "costs"]) {
         return Ok(None);
@@ -2182,16 +2202,24 @@ pub(crate) fn parse_spell_cost_increase_per_target_beyond_first_line(
         .position(|token| token.is_word("costs"))
         .ok_or_else(|| CardTextError::ParseError("missing costs keyword".to_string()))?;
     let amount_tokens = &tokens[costs_idx + 1..];
-    let (amount_value, _) =
-        parse_cost_modifier_amount(amount_tokens).unwrap_or((Value::Fixed(1), 0));
-    let amount = if let Value::Fixed(v) = amount_value {
-        v.max(0) as u32
-    } else {
-        1
+    let increase = match parse_cost_modifier_mana_cost(amount_tokens) {
+        Some((increase, _)) => increase,
+        None => {
+            let (amount_value, _) =
+                parse_cost_modifier_amount(amount_tokens).unwrap_or((Value::Fixed(1), 0));
+            let amount = if let Value::Fixed(v) = amount_value {
+                v.max(0) as u32
+            } else {
+                1
+            };
+            crate::mana::ManaCost::from_pips(vec![vec![crate::mana::ManaSymbol::Generic(
+                amount as u8,
+            )]])
+        }
     };
 
     Ok(Some(StaticAbility::cost_increase_per_target_beyond_first(
-        amount,
+        increase,
     )))
 }
 
@@ -2791,6 +2819,50 @@ pub(crate) fn parse_cost_modifier_prefix_condition(
     Ok((None, 0))
 }
 
+/// Parse "The first <spell> you cast each turn costs {N} less to cast." as a
+/// cost reduction limited to one use per turn.
+fn parse_first_spell_each_turn_cost_reduction_line(
+    tokens: &[Token],
+    clause_words: &[&str],
+) -> Result<Option<StaticAbility>, CardTextError> {
+    let unsupported = || {
+        CardTextError::ParseError(format!(
+            "unsupported first-spell-each-turn cost modifier (clause: '{}')",
+            clause_words.join(" ")
+        ))
+    };
+    if !clause_words.starts_with(&["the", "first"]) {
+        return Err(unsupported());
+    }
+    let Some(each_turn_idx) = clause_words
+        .windows(2)
+        .position(|window| window == ["each", "turn"])
+    else {
+        return Err(unsupported());
+    };
+    if !clause_words[..each_turn_idx].ends_with(&["you", "cast"]) {
+        return Err(unsupported());
+    }
+    let (Some(first_end), Some(each_start), Some(turn_end)) = (
+        token_index_for_word_index(tokens, 2),
+        token_index_for_word_index(tokens, each_turn_idx),
+        token_index_for_word_index(tokens, each_turn_idx + 2),
+    ) else {
+        return Err(unsupported());
+    };
+    let mut stripped = tokens[first_end..each_start].to_vec();
+    stripped.extend_from_slice(&tokens[turn_end..]);
+    let Some(ability) = parse_spells_cost_modifier_line(&stripped)? else {
+        return Err(unsupported());
+    };
+    let Some(reduction) = ability.cost_reduction() else {
+        return Err(unsupported());
+    };
+    Ok(Some(StaticAbility::new(
+        reduction.clone().with_uses_per_turn(1),
+    )))
+}
+
 pub(crate) fn parse_spells_cost_modifier_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
@@ -2802,10 +2874,7 @@ pub(crate) fn parse_spells_cost_modifier_line(
         && clause_words.contains(&"each")
         && clause_words.contains(&"turn")
     {
-        return Err(CardTextError::ParseError(format!(
-            "unsupported first-spell-each-turn cost modifier (clause: '{}')",
-            clause_words.join(" ")
-        )));
+        return parse_first_spell_each_turn_cost_reduction_line(tokens, &clause_words);
     }
 
     let Some(spells_token_idx) = tokens
@@ -3406,6 +3475,12 @@ pub(crate) fn parse_dynamic_cost_modifier_value(
         }
     }
 
+    if (filter_words.starts_with(&["card", "type", "among"])
+        || filter_words.starts_with(&["card", "types", "among"]))
+        && let Some(value) = parse_distinct_among_value_words(&filter_words)
+    {
+        return Ok(Some(value));
+    }
     if filter_words.windows(2).any(|pair| pair == ["card", "type"])
         && filter_words.contains(&"graveyard")
     {
@@ -3626,6 +3701,27 @@ pub(crate) fn parse_add_mana_equal_amount_value(tokens: &[Token]) -> Option<Valu
             || segment.starts_with(&["sacrificed", "artifacts", "mana", "value"])
             || segment.starts_with(&["sacrificed", "permanents", "mana", "value"])
             || segment.starts_with(&["its", "mana", "value"])
+            || segment.starts_with(&["the", "exiled", "card", "mana", "value"])
+            || segment.starts_with(&["the", "exiled", "cards", "mana", "value"])
+            || segment.starts_with(&["the", "mana", "value", "of", "the", "exiled", "card"])
+            || matches!(
+                segment,
+                [
+                    "that",
+                    "permanent"
+                        | "permanents"
+                        | "creature"
+                        | "creatures"
+                        | "artifact"
+                        | "artifacts"
+                        | "enchantment"
+                        | "enchantments"
+                        | "object"
+                        | "objects",
+                    "mana",
+                    "value",
+                ]
+            )
         {
             return Some(Value::ManaValueOf(Box::new(ChooseSpec::Tagged(
                 TagKey::from(IT_TAG),
@@ -4733,7 +4829,7 @@ pub(crate) fn parse_assign_damage_as_unblocked_line(
     Ok(None)
 }
 
-pub(crate) fn parse_grant_flash_to_noncreature_spells_line(
+pub(crate) fn parse_grant_flash_to_spells_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
     let normalized = words(tokens)
@@ -4755,33 +4851,31 @@ pub(crate) fn parse_grant_flash_to_noncreature_spells_line(
     idx += 1;
 
     let tail = &normalized[idx..];
-    let matches =
-        tail == [
-            "noncreature",
-            "spells",
-            "as",
-            "though",
-            "they",
-            "had",
-            "flash",
-        ] || tail
-            == [
-                "noncreature",
-                "spells",
-                "as",
-                "though",
-                "they",
-                "have",
-                "flash",
-            ];
-
-    if matches {
-        return Ok(Some(StaticAbility::grants(
-            crate::grant::GrantSpec::flash_to_noncreature_spells(),
-        )));
+    let Some(spells_idx) = tail.iter().position(|word| *word == "spells") else {
+        return Ok(None);
+    };
+    if !matches!(
+        &tail[spells_idx + 1..],
+        ["as", "though", "they", "had" | "have", "flash"]
+    ) {
+        return Ok(None);
     }
 
-    Ok(None)
+    let filter = match &tail[..spells_idx] {
+        [] => crate::target::ObjectFilter::nonland(),
+        ["noncreature"] => crate::target::ObjectFilter::noncreature_spell(),
+        [card_type] => match parse_card_type(card_type) {
+            Some(card_type) if card_type != crate::types::CardType::Land => {
+                crate::target::ObjectFilter::default().with_type(card_type)
+            }
+            _ => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some(StaticAbility::grants(
+        crate::grant::GrantSpec::flash_to_spells(filter),
+    )))
 }
 
 pub(crate) fn parse_attacks_each_combat_if_able_line(
@@ -5294,6 +5388,22 @@ pub(crate) fn parse_draw_replace_exile_top_face_down_line(
     Ok(None)
 }
 
+pub(crate) fn parse_draw_from_empty_library_win_instead_line(
+    tokens: &[Token],
+) -> Result<Option<StaticAbility>, CardTextError> {
+    let words = words(tokens);
+    if words.as_slice()
+        == [
+            "if", "you", "would", "draw", "a", "card", "while", "your", "library", "has", "no",
+            "cards", "in", "it", "you", "win", "the", "game", "instead",
+        ]
+    {
+        return Ok(Some(StaticAbility::draw_from_empty_library_win_instead()));
+    }
+
+    Ok(None)
+}
+
 pub(crate) fn parse_exile_to_countered_exile_instead_of_graveyard_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
@@ -5348,6 +5458,190 @@ pub(crate) fn parse_exile_to_countered_exile_instead_of_graveyard_line(
     ))
 }
 
+pub(crate) fn parse_exile_instead_of_graveyard_line(
+    tokens: &[Token],
+) -> Result<Option<StaticAbility>, CardTextError> {
+    let words = words(tokens);
+    let Some(would_idx) = words.iter().position(|word| *word == "would") else {
+        return Ok(None);
+    };
+    let include_tokens = match &words[..would_idx] {
+        ["if", "a", "card"] => false,
+        ["if", "a", "card", "or", "token"] => true,
+        _ => return Ok(None),
+    };
+
+    let rest = &words[would_idx..];
+    let Some(from_idx) = rest.iter().position(|word| *word == "from") else {
+        return Ok(None);
+    };
+    if !rest.starts_with(&["would", "be", "put", "into"]) {
+        return Ok(None);
+    }
+    let player = match &rest[4..from_idx] {
+        ["a", "graveyard"] => PlayerFilter::Any,
+        ["your", "graveyard"] => PlayerFilter::You,
+        ["an", "opponents", "graveyard"] => PlayerFilter::Opponent,
+        _ => return Ok(None),
+    };
+    if !matches!(
+        &rest[from_idx..],
+        ["from", "anywhere", "exile", "it", "instead"]
+            | ["from", "anywhere", "instead", "exile", "it"]
+    ) {
+        return Ok(None);
+    }
+
+    Ok(Some(StaticAbility::exile_instead_of_graveyard(
+        player,
+        include_tokens,
+    )))
+}
+
+/// Splits a leading "twice that many" / "that many plus one" off `words`.
+fn split_replaced_count_words<'a>(
+    words: &'a [&'a str],
+) -> Option<(crate::replacement::EventModification, &'a [&'a str])> {
+    use crate::replacement::EventModification;
+
+    match words {
+        ["twice", "that", "many", rest @ ..] => Some((EventModification::Multiply(2), rest)),
+        ["three", "times", "that", "many", rest @ ..] => {
+            Some((EventModification::Multiply(3), rest))
+        }
+        ["that", "many", "plus", amount, rest @ ..] => {
+            let amount = parse_number_word_i32(amount)?;
+            Some((EventModification::Add(amount), rest))
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_token_creation_replacement_line(
+    tokens: &[Token],
+) -> Result<Option<StaticAbility>, CardTextError> {
+    let words = words(tokens);
+    let (player, count_words) = match words.as_slice() {
+        [
+            "if",
+            "an",
+            "effect",
+            "would",
+            "create",
+            "one",
+            "or",
+            "more",
+            "tokens",
+            "under",
+            "your",
+            "control",
+            "it",
+            "creates",
+            rest @ ..,
+        ] => (PlayerFilter::You, rest),
+        [
+            "if",
+            "one",
+            "or",
+            "more",
+            "tokens",
+            "would",
+            "be",
+            "created",
+            rest @ ..,
+        ] => (PlayerFilter::Any, rest),
+        _ => return Ok(None),
+    };
+    let Some((modification, tail)) = split_replaced_count_words(count_words) else {
+        return Ok(None);
+    };
+    if !matches!(
+        tail,
+        ["of", "those", "tokens", "instead"]
+            | ["of", "those", "tokens", "are", "created", "instead"]
+    ) {
+        return Ok(None);
+    }
+
+    Ok(Some(StaticAbility::token_creation_replacement(
+        player,
+        modification,
+    )))
+}
+
+pub(crate) fn parse_counter_placement_replacement_line(
+    tokens: &[Token],
+) -> Result<Option<StaticAbility>, CardTextError> {
+    let words = words(tokens);
+    if words.last() != Some(&"instead") {
+        return Ok(None);
+    }
+    // "If an effect would put one or more counters on <filter>, it puts ..." or
+    // "If one or more +1/+1 counters would be put on <filter>, twice that many ...".
+    let (counter_words, filter_start) = match words.as_slice() {
+        [
+            "if",
+            "an",
+            "effect",
+            "would",
+            "put",
+            "one",
+            "or",
+            "more",
+            rest @ ..,
+        ] => {
+            let Some(on_idx) = rest.iter().position(|word| *word == "on") else {
+                return Ok(None);
+            };
+            (&rest[..on_idx], 8 + on_idx + 1)
+        }
+        ["if", "one", "or", "more", rest @ ..] => {
+            let Some(would_idx) = rest.iter().position(|word| *word == "would") else {
+                return Ok(None);
+            };
+            if !rest[would_idx..].starts_with(&["would", "be", "put", "on"]) {
+                return Ok(None);
+            }
+            (&rest[..would_idx], 4 + would_idx + 4)
+        }
+        _ => return Ok(None),
+    };
+    let counter_type = match counter_words {
+        ["counters"] => None,
+        [counter, "counters"] => match parse_counter_type_word(counter) {
+            Some(counter_type) => Some(counter_type),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let Some((count_idx, modification, tail)) = (filter_start..words.len()).find_map(|idx| {
+        let rest = words[idx..]
+            .strip_prefix(&["it", "puts"][..])
+            .unwrap_or(&words[idx..]);
+        split_replaced_count_words(rest).map(|(modification, tail)| (idx, modification, tail))
+    }) else {
+        return Ok(None);
+    };
+    if !tail.contains(&"counters") {
+        return Ok(None);
+    }
+
+    let (Some(start), Some(end)) = (
+        token_index_for_word_index(tokens, filter_start),
+        token_index_for_word_index(tokens, count_idx),
+    ) else {
+        return Ok(None);
+    };
+    let filter = parse_object_filter(trim_commas(&tokens[start..end]).as_slice(), false)?;
+
+    Ok(Some(StaticAbility::counter_placement_replacement(
+        filter,
+        counter_type,
+        modification,
+    )))
+}
+
 pub(crate) fn parse_toph_first_metalbender_line(
     tokens: &[Token],
 ) -> Result<Option<StaticAbility>, CardTextError> {
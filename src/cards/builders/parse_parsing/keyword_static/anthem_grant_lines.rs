@@ -1384,6 +1384,9 @@ pub(crate) fn parse_static_condition_clause(
     if clause_words == ["it", "is", "your", "turn"] || clause_words == ["its", "your", "turn"] {
         return Ok(crate::ConditionExpr::YourTurn);
     }
+    if let Some(condition) = parse_turn_history_condition_words(&clause_words) {
+        return Ok(condition);
+    }
     if clause_words == ["it", "is", "not", "your", "turn"]
         || clause_words == ["its", "not", "your", "turn"]
     {
@@ -383,24 +383,14 @@ fn parse_unless_enters_with_counter_condition_display(tokens: &[Token]) -> Optio
     None
 }
 
-fn parse_enters_with_counter_condition_clause(tokens: &[Token]) -> Option<crate::ConditionExpr> {
-    let condition_tokens = trim_edge_punctuation(tokens);
-    let condition_words = words(&condition_tokens);
-    if condition_words.is_empty() {
-        return None;
-    }
-
+/// Conditions on what happened earlier in the turn, as used by ability words
+/// such as raid, morbid, and revolt.
+fn parse_turn_history_condition_words(condition_words: &[&str]) -> Option<crate::ConditionExpr> {
     if condition_words == ["you", "attacked", "this", "turn"]
         || condition_words == ["youve", "attacked", "this", "turn"]
     {
         return Some(crate::ConditionExpr::AttackedThisTurn);
     }
-    if condition_words == ["you", "cast", "it"]
-        || condition_words == ["you", "cast", "this"]
-        || condition_words == ["you", "cast", "this", "spell"]
-    {
-        return Some(crate::ConditionExpr::SourceWasCast);
-    }
     if condition_words == ["a", "creature", "died", "this", "turn"]
         || condition_words == ["one", "or", "more", "creatures", "died", "this", "turn"]
     {
@@ -449,9 +439,40 @@ fn parse_enters_with_counter_condition_clause(tokens: &[Token]) -> Option<crate:
                 "this",
                 "turn",
             ]
+        || condition_words
+            == [
+                "a",
+                "permanent",
+                "you",
+                "controlled",
+                "left",
+                "the",
+                "battlefield",
+                "this",
+                "turn",
+            ]
     {
         return Some(crate::ConditionExpr::PermanentLeftBattlefieldUnderYourControlThisTurn);
     }
+    None
+}
+
+fn parse_enters_with_counter_condition_clause(tokens: &[Token]) -> Option<crate::ConditionExpr> {
+    let condition_tokens = trim_edge_punctuation(tokens);
+    let condition_words = words(&condition_tokens);
+    if condition_words.is_empty() {
+        return None;
+    }
+
+    if let Some(condition) = parse_turn_history_condition_words(&condition_words) {
+        return Some(condition);
+    }
+    if condition_words == ["you", "cast", "it"]
+        || condition_words == ["you", "cast", "this"]
+        || condition_words == ["you", "cast", "this", "spell"]
+    {
+        return Some(crate::ConditionExpr::SourceWasCast);
+    }
     if condition_words
         == [
             "it", "wasnt", "cast", "or", "no", "mana", "was", "spent", "to", "cast", "it",
@@ -674,6 +695,20 @@ pub(crate) fn parse_where_x_value_clause(tokens: &[Token]) -> Option<Value> {
             TagKey::from(IT_TAG),
         ))));
     }
+    match words.get(3..) {
+        Some(["the", "exiled", "card", "power"]) | Some(["the", "exiled", "cards", "power"]) => {
+            return Some(Value::PowerOf(Box::new(ChooseSpec::Tagged(TagKey::from(
+                IT_TAG,
+            )))));
+        }
+        Some(["the", "exiled", "card", "toughness"])
+        | Some(["the", "exiled", "cards", "toughness"]) => {
+            return Some(Value::ToughnessOf(Box::new(ChooseSpec::Tagged(
+                TagKey::from(IT_TAG),
+            ))));
+        }
+        _ => {}
+    }
 
     // where X is the number of cards in your hand
     if words.contains(&"cards")
@@ -1066,24 +1101,8 @@ pub(crate) fn parse_where_x_is_number_of_filter_value(tokens: &[Token]) -> Optio
         let scope_filter = parse_object_filter(scope_tokens, false).ok()?;
         return Some(Value::ColorsAmong(scope_filter));
     }
-    if (filter_words.starts_with(&["card", "type", "among", "cards"])
-        || filter_words.starts_with(&["card", "types", "among", "cards"]))
-        && filter_words.contains(&"graveyard")
-    {
-        let player = if filter_words
-            .windows(2)
-            .any(|pair| pair == ["your", "graveyard"])
-        {
-            PlayerFilter::You
-        } else if filter_words
-            .windows(2)
-            .any(|pair| pair == ["opponents", "graveyard"] || pair == ["opponent", "graveyard"])
-        {
-            PlayerFilter::Opponent
-        } else {
-            PlayerFilter::You
-        };
-        return Some(Value::CardTypesInGraveyard(player));
+    if let Some(value) = parse_distinct_among_value_words(&filter_words) {
+        return Some(value);
     }
     let filter = parse_object_filter(filter_tokens, false).ok()?;
     Some(Value::Count(filter))
@@ -20,7 +20,7 @@ use crate::cards::builders::{
 use crate::costs::Cost;
 use crate::{AlternativeCastingMethod, OptionalCost, TotalCost};
 
-const PRE_TOKEN_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 22] = [
+const PRE_TOKEN_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 21] = [
     UnsupportedRuleDef {
         id: "commander-cast-count",
         priority: 100,
@@ -101,14 +101,6 @@ const PRE_TOKEN_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 22] = [
         message: "unsupported defending-players-choice clause",
         predicate: line_has_defending_players_choice_clause,
     },
-    UnsupportedRuleDef {
-        id: "first-spell-cost-modifier",
-        priority: 190,
-        heads: &["the"],
-        shape_mask: 0,
-        message: "unsupported first-spell cost modifier mechanic",
-        predicate: line_has_first_spell_cost_modifier_clause,
-    },
     UnsupportedRuleDef {
         id: "spent-to-cast-conditional",
         priority: 200,
@@ -250,7 +242,7 @@ const STATIC_LINE_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 6] = [
     },
 ];
 
-const KNOWN_STATIC_LINE_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 16] = [
+const KNOWN_STATIC_LINE_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 14] = [
     UnsupportedRuleDef {
         id: "known-static-clause",
         priority: 300,
@@ -363,22 +355,6 @@ const KNOWN_STATIC_LINE_DIAGNOSTIC_RULES: [UnsupportedRuleDef; 16] = [
         message: "unsupported static clause",
         predicate: line_has_enchanted_creature_gets_negative_x_clause,
     },
-    UnsupportedRuleDef {
-        id: "known-static-clause",
-        priority: 440,
-        heads: &["if"],
-        shape_mask: 0,
-        message: "unsupported static clause",
-        predicate: line_has_plus_one_counter_replacement_clause,
-    },
-    UnsupportedRuleDef {
-        id: "known-static-clause",
-        priority: 450,
-        heads: &["if"],
-        shape_mask: 0,
-        message: "unsupported static clause",
-        predicate: line_has_token_replacement_clause,
-    },
 ];
 
 const PRE_TOKEN_DIAGNOSER: UnsupportedDiagnoser =
@@ -535,12 +511,6 @@ fn line_has_defending_players_choice_clause(view: &ClauseView<'_>) -> bool {
         || (normalized.contains("defending player") && normalized.contains("choice"))
 }
 
-fn line_has_first_spell_cost_modifier_clause(view: &ClauseView<'_>) -> bool {
-    let normalized = normalized_line(view);
-    normalized.starts_with("the first creature spell you cast each turn costs")
-        && normalized.contains("less to cast")
-}
-
 fn line_has_non_mana_additional_cost_modifier_clause(view: &ClauseView<'_>) -> bool {
     is_non_mana_additional_cost_modifier_line(normalized_line(view))
 }
@@ -668,16 +638,6 @@ fn line_has_enchanted_creature_gets_negative_x_clause(view: &ClauseView<'_>) ->
     normalized_line_without_braces(view).starts_with("enchanted creature gets -x/-x")
 }
 
-fn line_has_plus_one_counter_replacement_clause(view: &ClauseView<'_>) -> bool {
-    normalized_line_without_braces(view)
-        .starts_with("if one or more +1/+1 counters would be put on")
-}
-
-fn line_has_token_replacement_clause(view: &ClauseView<'_>) -> bool {
-    normalized_line_without_braces(view)
-        .starts_with("if an effect would create one or more tokens under your control")
-}
-
 fn parse_first_parsed_ability_rule(
     tokens: &[Token],
 ) -> Result<Option<(&'static str, ParsedAbility)>, CardTextError> {
@@ -3,10 +3,11 @@ use crate::cards::builders::{
     is_demonstrative_object_head, is_non_outlaw_word, is_outlaw_word, is_permanent_type,
     is_source_reference_words, parse_alternative_cast_words, parse_card_type, parse_color,
     parse_counter_type_word, parse_filter_comparison_tokens, parse_filter_counter_constraint_words,
-    parse_filter_keyword_constraint_words, parse_non_color, parse_non_subtype, parse_non_supertype,
-    parse_non_type, parse_pt_modifier, parse_subtype_flexible, parse_subtype_word,
-    parse_supertype_word, parse_unsigned_pt_word, parse_zone_word, push_outlaw_subtypes,
-    split_on_and, token_index_for_word_index, trim_commas, words,
+    parse_filter_counter_count_words, parse_filter_keyword_constraint_words, parse_non_color,
+    parse_non_subtype, parse_non_supertype, parse_non_type, parse_pt_modifier,
+    parse_subtype_flexible, parse_subtype_word, parse_supertype_word, parse_unsigned_pt_word,
+    parse_zone_word, push_outlaw_subtypes, split_on_and, token_index_for_word_index, trim_commas,
+    words,
 };
 use crate::{
     CardType, Color, ColorSet, ObjectFilter, PlayerFilter, Supertype, TagKey,
@@ -1565,6 +1566,13 @@ pub(crate) fn parse_object_filter(
             with_idx += 1 + consumed;
             continue;
         }
+        if let Some((counters, cmp, consumed)) =
+            parse_filter_counter_count_words(&all_words[with_idx + 1..])
+        {
+            filter.with_counter_count = Some((counters, cmp));
+            with_idx += 1 + consumed;
+            continue;
+        }
         if let Some((counter_constraint, consumed)) =
             parse_filter_counter_constraint_words(&all_words[with_idx + 1..])
         {
@@ -2496,6 +2504,7 @@ pub(crate) fn parse_object_filter(
         || filter.source
         || filter.with_counter.is_some()
         || filter.without_counter.is_some()
+        || filter.with_counter_count.is_some()
         || filter.alternative_cast.is_some()
         || !filter.static_abilities.is_empty()
         || !filter.excluded_static_abilities.is_empty()
@@ -2553,6 +2562,7 @@ pub(crate) fn parse_object_filter(
         || filter.source
         || filter.with_counter.is_some()
         || filter.without_counter.is_some()
+        || filter.with_counter_count.is_some()
         || filter.alternative_cast.is_some()
         || !filter.static_abilities.is_empty()
         || !filter.excluded_static_abilities.is_empty()
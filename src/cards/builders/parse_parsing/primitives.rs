@@ -347,6 +347,60 @@ pub(crate) fn parse_filter_counter_constraint_words(
     ))
 }
 
+/// Parse counter-count qualifiers such as "three or more +1/+1 counters on it"
+/// or "exactly two counters on them". Plain "one or more" is left to
+/// [`parse_filter_counter_constraint_words`].
+pub(crate) fn parse_filter_counter_count_words(
+    words: &[&str],
+) -> Option<(
+    crate::filter::CounterConstraint,
+    crate::filter::Comparison,
+    usize,
+)> {
+    use crate::filter::Comparison;
+
+    let (cmp, prefix_len) = match words {
+        ["exactly", amount, ..] => (Comparison::Equal(parse_number_word_i32(amount)?), 2),
+        [amount, "or", "more", ..] => {
+            let amount = parse_number_word_i32(amount)?;
+            if amount <= 1 {
+                return None;
+            }
+            (Comparison::GreaterThanOrEqual(amount), 3)
+        }
+        [amount, "or", "fewer" | "less", ..] => (
+            Comparison::LessThanOrEqual(parse_number_word_i32(amount)?),
+            3,
+        ),
+        _ => return None,
+    };
+    let rest = &words[prefix_len..];
+    let counter_idx = rest
+        .iter()
+        .position(|word| *word == "counters" || *word == "counter")?;
+    if rest.get(counter_idx + 1) != Some(&"on")
+        || !rest
+            .get(counter_idx + 2)
+            .is_some_and(|word| matches!(*word, "it" | "them"))
+    {
+        return None;
+    }
+    let counters = match &rest[..counter_idx] {
+        [] => crate::filter::CounterConstraint::Any,
+        [word] => crate::filter::CounterConstraint::Typed(parse_counter_type_word(word)?),
+        descriptor => {
+            let descriptor_tokens = descriptor
+                .iter()
+                .map(|word| Token::Word((*word).to_string(), TextSpan::synthetic()))
+                .collect::<Vec<_>>();
+            crate::filter::CounterConstraint::Typed(parse_counter_type_from_tokens(
+                &descriptor_tokens,
+            )?)
+        }
+    };
+    Some((counters, cmp, prefix_len + counter_idx + 3))
+}
+
 pub(crate) fn apply_filter_keyword_constraint(
     filter: &mut ObjectFilter,
     constraint: FilterKeywordConstraint,
@@ -431,6 +485,48 @@ pub(crate) fn parse_number_or_x_value(tokens: &[Token]) -> Option<(crate::effect
     }
 }
 
+/// Parse "<characteristic> among <objects>" into a distinct-count value.
+///
+/// Covers Domain ("basic land types among lands you control"), Delirium
+/// ("card types among cards in your graveyard"), colors among a group, and
+/// "different mana values among" a group. `words` must not contain trailing
+/// arithmetic; the whole slice is consumed.
+pub(crate) fn parse_distinct_among_value_words(words: &[&str]) -> Option<Value> {
+    let among_idx = words.iter().position(|word| *word == "among")?;
+    let characteristic = &words[..among_idx];
+    let mut scope = &words[among_idx + 1..];
+    if scope.first().copied() == Some("the") {
+        scope = &scope[1..];
+    }
+    if scope.is_empty() {
+        return None;
+    }
+    let scope_tokens = scope
+        .iter()
+        .map(|word| Token::Word((*word).to_string(), TextSpan::synthetic()))
+        .collect::<Vec<_>>();
+    let filter = parse_object_filter(&scope_tokens, false).ok()?;
+
+    match characteristic {
+        ["basic", "land", "type" | "types"] => Some(Value::BasicLandTypesAmong(filter)),
+        ["color" | "colors"] => Some(Value::ColorsAmong(filter)),
+        ["card", "type" | "types"] => {
+            let your_graveyard = ObjectFilter::default()
+                .in_zone(Zone::Graveyard)
+                .owned_by(PlayerFilter::You);
+            if filter == your_graveyard {
+                Some(Value::CardTypesInGraveyard(PlayerFilter::You))
+            } else {
+                Some(Value::CardTypesAmong(filter))
+            }
+        }
+        ["mana", "value" | "values"] | ["different", "mana", "value" | "values"] => {
+            Some(Value::ManaValuesAmong(filter))
+        }
+        _ => None,
+    }
+}
+
 fn parse_value_expr_term_words(words: &[&str]) -> Option<(Value, usize)> {
     if words.is_empty() {
         return None;
@@ -575,6 +671,9 @@ fn parse_value_expr_term_words(words: &[&str]) -> Option<(Value, usize)> {
         return None;
     }
     let filter_words = &words[filter_start..filter_end];
+    if let Some(value) = parse_distinct_among_value_words(filter_words) {
+        return Some((value, filter_end));
+    }
     if (filter_words.contains(&"spell") || filter_words.contains(&"spells"))
         && (filter_words.contains(&"cast") || filter_words.contains(&"casts"))
         && filter_words.contains(&"this")
@@ -733,6 +832,10 @@ impl CompileContext {
         id
     }
 
+    pub(crate) fn reserve_effect_id(&mut self, id: EffectId) {
+        self.next_effect_id = self.next_effect_id.max(id.0 + 1);
+    }
+
     pub(crate) fn next_tag(&mut self, prefix: &str) -> String {
         let tag = format!("{prefix}_{}", self.next_tag_id);
         self.next_tag_id += 1;
@@ -812,6 +915,10 @@ impl EffectLoweringContext {
         self.ids.next_effect_id()
     }
 
+    pub(crate) fn reserve_effect_id(&mut self, id: EffectId) {
+        self.ids.reserve_effect_id(id);
+    }
+
     pub(crate) fn next_tag(&mut self, prefix: &str) -> String {
         self.ids.next_tag(prefix)
     }
@@ -885,6 +885,7 @@ fn replace_modal_header_x_in_effect_ast(
 ) -> Result<(), CardTextError> {
     match effect {
         EffectAst::DealDamage { amount, .. }
+        | EffectAst::DealDamageDivided { amount, .. }
         | EffectAst::DealDamageEach { amount, .. }
         | EffectAst::Draw { count: amount, .. }
         | EffectAst::LoseLife { amount, .. }
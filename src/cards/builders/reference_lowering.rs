@@ -334,6 +334,18 @@ pub(crate) fn resolve_restriction_it_tag(
         Restriction::BeCountered(filter) => {
             Restriction::be_countered(resolve_it_tag(filter, refs)?)
         }
+        Restriction::BeCounteredBy { spells, counterers } => {
+            Restriction::be_countered_by(resolve_it_tag(spells, refs)?, counterers.clone())
+        }
+        Restriction::AttackPlayer {
+            attackers,
+            defenders,
+            include_planeswalkers,
+        } => Restriction::AttackPlayer {
+            attackers: resolve_it_tag(attackers, refs)?,
+            defenders: defenders.clone(),
+            include_planeswalkers: *include_planeswalkers,
+        },
         Restriction::Transform(filter) => Restriction::transform(resolve_it_tag(filter, refs)?),
         Restriction::AttackOrBlock(filter) => {
             Restriction::attack_or_block(resolve_it_tag(filter, refs)?)
@@ -447,6 +459,8 @@ pub(crate) fn resolve_value_it_tag(
             Ok(Value::BasicLandTypesAmong(resolve_it_tag(filter, refs)?))
         }
         Value::ColorsAmong(filter) => Ok(Value::ColorsAmong(resolve_it_tag(filter, refs)?)),
+        Value::CardTypesAmong(filter) => Ok(Value::CardTypesAmong(resolve_it_tag(filter, refs)?)),
+        Value::ManaValuesAmong(filter) => Ok(Value::ManaValuesAmong(resolve_it_tag(filter, refs)?)),
         Value::PowerOf(spec) => Ok(Value::PowerOf(Box::new(resolve_choose_spec_it_tag(
             spec, refs,
         )?))),
@@ -308,7 +308,9 @@ fn advance_reference_frame_for_effect(
         | EffectAst::Flip { target } => {
             maybe_tag_target(&target, frame, id_gen, "targeted")?;
         }
-        EffectAst::DealDamage { target, .. } | EffectAst::DealDamageEqualToPower { target, .. } => {
+        EffectAst::DealDamage { target, .. }
+        | EffectAst::DealDamageDivided { target, .. }
+        | EffectAst::DealDamageEqualToPower { target, .. } => {
             maybe_tag_target(&target, frame, id_gen, "damaged")?;
         }
         EffectAst::PutCounters { target, .. }
@@ -957,9 +959,21 @@ fn resolve_effect_sequence_references_with_state(
     mut state: EffectReferenceResolutionState,
 ) -> Result<Vec<EffectAst>, CardTextError> {
     let mut resolved = Vec::with_capacity(effects.len());
+    let mut last_id_is_local = false;
 
     for (idx, effect) in effects.iter().enumerate() {
-        let effect = resolve_effect_references_in_effect(effect.clone(), id_gen, state)?;
+        // A result gate keyed to an effect earlier in this same nested
+        // sequence is left for the sequence's own annotation pass, which
+        // assigns the matching id when the sequence is lowered.
+        let effect = if last_id_is_local
+            && matches!(
+                effect,
+                EffectAst::IfResult { .. } | EffectAst::WhenResult { .. }
+            ) {
+            effect.clone()
+        } else {
+            resolve_effect_references_in_effect(effect.clone(), id_gen, state)?
+        };
         let remaining = if idx + 1 < effects.len() {
             &effects[idx + 1..]
         } else {
@@ -969,6 +983,7 @@ fn resolve_effect_sequence_references_with_state(
         let assigned_effect_id =
             maybe_assign_effect_result_id(effects, idx, id_gen, state.allow_life_event_value);
         state.last_effect_id = assigned_effect_id;
+        last_id_is_local = assigned_effect_id.is_some();
         resolved.push(effect);
     }
 
@@ -1052,6 +1067,7 @@ fn resolve_effect_result_values_in_fields(
 ) -> Result<(), CardTextError> {
     match effect {
         EffectAst::DealDamage { amount, .. }
+        | EffectAst::DealDamageDivided { amount, .. }
         | EffectAst::DealDamageEach { amount, .. }
         | EffectAst::Draw { count: amount, .. }
         | EffectAst::LoseLife { amount, .. }
@@ -1236,7 +1252,8 @@ fn bind_unresolved_it_in_effect(effect: &mut EffectAst, seed_tag: &TagKey) -> us
 #[cfg(test)]
 fn bind_unresolved_it_in_effect_fields(effect: &mut EffectAst, seed_tag: &TagKey) -> usize {
     match effect {
-        EffectAst::DealDamage { amount, target } => {
+        EffectAst::DealDamage { amount, target }
+        | EffectAst::DealDamageDivided { amount, target } => {
             bind_unresolved_it_in_value(amount, seed_tag)
                 + bind_unresolved_it_in_target(target, seed_tag)
         }
@@ -1678,7 +1695,9 @@ fn bind_unresolved_it_in_value(value: &mut Value, seed_tag: &TagKey) -> usize {
         | Value::GreatestPower(filter)
         | Value::GreatestManaValue(filter)
         | Value::BasicLandTypesAmong(filter)
-        | Value::ColorsAmong(filter) => bind_unresolved_it_in_filter(filter, seed_tag),
+        | Value::ColorsAmong(filter)
+        | Value::CardTypesAmong(filter)
+        | Value::ManaValuesAmong(filter) => bind_unresolved_it_in_filter(filter, seed_tag),
         Value::PowerOf(spec)
         | Value::ToughnessOf(spec)
         | Value::ManaValueOf(spec)
@@ -1743,6 +1762,10 @@ fn bind_unresolved_it_in_restriction(
         | Restriction::HaveCountersPlaced(filter)
         | Restriction::BeTargeted(filter)
         | Restriction::BeCountered(filter)
+        | Restriction::BeCounteredBy { spells: filter, .. }
+        | Restriction::AttackPlayer {
+            attackers: filter, ..
+        }
         | Restriction::Transform(filter)
         | Restriction::AttackOrBlock(filter)
         | Restriction::ActivateAbilitiesOf(filter)
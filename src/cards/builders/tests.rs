@@ -487,6 +487,64 @@ fn test_builder_vanishing_creates_counter_upkeep_and_sacrifice_triggers() {
     );
 }
 
+fn ability_kinds(abilities: &[Ability]) -> String {
+    let kinds: Vec<_> = abilities.iter().map(|ability| &ability.kind).collect();
+    format!("{kinds:?}")
+}
+
+#[test]
+fn test_parse_blastoderm_fading_is_a_fade_counter_countdown() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Blastoderm")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(5, 5))
+        .parse_text("Shroud\nFading 3")
+        .expect("parse Blastoderm");
+
+    let expected = crate::ability::CounterCountdown::remove(
+        CounterType::Fade,
+        vec![Effect::sacrifice_source()],
+    )
+    .starting_with(3)
+    .into_abilities(None);
+    assert_eq!(ability_kinds(&def.abilities[1..]), ability_kinds(&expected));
+}
+
+#[test]
+fn test_parse_calciderm_vanishing_is_a_time_counter_countdown() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Calciderm")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(5, 5))
+        .parse_text("Shroud\nVanishing 4")
+        .expect("parse Calciderm");
+
+    let expected = crate::ability::CounterCountdown::remove(
+        CounterType::Time,
+        vec![Effect::sacrifice_source()],
+    )
+    .starting_with(4)
+    .into_abilities(None);
+    assert_eq!(ability_kinds(&def.abilities[1..]), ability_kinds(&expected));
+}
+
+#[test]
+fn test_parse_azors_elocutors_upkeep_is_a_filibuster_counter_countdown() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Azor's Elocutors")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(3, 5))
+        .parse_text(
+            "At the beginning of your upkeep, put a filibuster counter on Azor's Elocutors. Then if Azor's Elocutors has five or more filibuster counters on it, you win the game.",
+        )
+        .expect("parse Azor's Elocutors upkeep ability");
+
+    let expected = crate::ability::CounterCountdown::add(
+        CounterType::Filibuster,
+        5,
+        vec![Effect::win_the_game()],
+    )
+    .into_abilities(None);
+    assert_eq!(ability_kinds(&def.abilities), ability_kinds(&expected));
+}
+
 #[test]
 fn test_builder_devour_creates_etb_triggered_effect_without_marker_fallback() {
     let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Devour Test")
@@ -1718,6 +1776,78 @@ fn test_parse_labeled_leading_condition_with_gets_and_has() {
     );
 }
 
+#[test]
+fn test_parse_leading_if_you_control_spell_checks_condition_on_resolution() {
+    use crate::executor::{ExecutionContext, execute_effect};
+    use crate::tests::test_helpers::setup_two_player_game;
+    use crate::zone::Zone;
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Conditional Blessing")
+        .card_types(vec![CardType::Instant])
+        .parse_text("If you control a creature, you gain 3 life.")
+        .expect("parse leading if-you-control spell conditional");
+    let effects = def.spell_effect.clone().expect("spell effects");
+    assert!(
+        effects.iter().any(|effect| effect
+            .downcast_ref::<crate::effects::ConditionalEffect>()
+            .is_some()),
+        "expected a conditional spell effect, got {effects:?}"
+    );
+
+    let mut game = setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let source = game.create_object_from_definition(&def, alice, Zone::Stack);
+    let resolve = |game: &mut crate::game_state::GameState| {
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        for effect in &effects {
+            execute_effect(game, effect, &mut ctx).expect("spell effect should resolve");
+        }
+    };
+
+    resolve(&mut game);
+    assert_eq!(game.player(alice).unwrap().life, 20, "no creature, no life");
+
+    let creature = crate::card::CardBuilder::new(CardId::new(), "Test Bear")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+        .build();
+    game.create_object_from_card(&creature, alice, Zone::Battlefield);
+    resolve(&mut game);
+    assert_eq!(game.player(alice).unwrap().life, 23);
+}
+
+#[test]
+fn test_metalcraft_creature_buff_follows_artifact_count() {
+    use crate::tests::test_helpers::setup_two_player_game;
+    use crate::zone::Zone;
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Metalcraft Bear")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+        .parse_text(
+            "Metalcraft — As long as you control three or more artifacts, this creature gets +2/+2.",
+        )
+        .expect("parse metalcraft creature");
+
+    let mut game = setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bear = game.create_object_from_definition(&def, alice, Zone::Battlefield);
+    let artifact = crate::card::CardBuilder::new(CardId::new(), "Test Trinket")
+        .card_types(vec![CardType::Artifact])
+        .build();
+    let trinkets: Vec<_> = (0..3)
+        .map(|_| game.create_object_from_card(&artifact, alice, Zone::Battlefield))
+        .collect();
+    assert_eq!(game.calculated_power(bear), Some(4));
+
+    game.move_object(trinkets[0], Zone::Graveyard);
+    assert_eq!(
+        game.calculated_power(bear),
+        Some(2),
+        "the bonus stops applying as soon as the condition is false"
+    );
+}
+
 #[test]
 fn test_parse_coven_condition_uses_different_power_predicate() {
     let def = CardDefinitionBuilder::new(CardId::new(), "Coven Variant")
@@ -2947,6 +3077,23 @@ Unearth {U} ({U}: Return this card from your graveyard to the battlefield. It ga
     );
 }
 
+#[test]
+fn test_parse_scryfall_mana_cost_snow_twobrid_and_colorless_hybrid() {
+    use crate::mana::ManaSymbol;
+
+    let cost = parse_scryfall_mana_cost("{2/W}{C/R}{S}").expect("mana cost should parse");
+    assert_eq!(
+        cost.pips(),
+        &[
+            vec![ManaSymbol::Generic(2), ManaSymbol::White],
+            vec![ManaSymbol::Colorless, ManaSymbol::Red],
+            vec![ManaSymbol::Snow],
+        ]
+    );
+    assert_eq!(cost.mana_value(), 4);
+    assert_eq!(cost.to_oracle(), "{2/W}{C/R}{S}");
+}
+
 #[test]
 fn test_parse_echo_keyword_line_with_mana_cost() {
     let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Echo Probe")
@@ -4369,6 +4516,232 @@ fn test_parse_raid_conditional_with_attacked_this_turn_without_fallback() {
     );
 }
 
+#[test]
+fn test_parse_morbid_conditional_compiles_creature_died_condition() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Morbid Probe")
+        .card_types(vec![CardType::Creature])
+        .parse_text(
+            "Morbid — When this creature enters, if a creature died this turn, draw a card.",
+        )
+        .expect("morbid conditional should parse");
+
+    let debug = format!("{:?}", def.abilities);
+    assert!(
+        debug.contains("CreatureDiedThisTurn"),
+        "expected creature-died condition, got {debug}"
+    );
+    let rendered = oracle_like_lines(&def).join(" ").to_ascii_lowercase();
+    assert!(
+        !rendered.contains("morbid"),
+        "ability word should not leak into rendered text: {rendered}"
+    );
+}
+
+#[test]
+fn test_parse_revolt_conditional_compiles_permanent_left_condition() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Revolt Probe")
+        .card_types(vec![CardType::Creature])
+        .parse_text(
+            "Revolt — When this creature enters, if a permanent you controlled left the battlefield this turn, draw a card.",
+        )
+        .expect("revolt conditional should parse");
+
+    let debug = format!("{:?}", def.abilities);
+    assert!(
+        debug.contains("PermanentLeftBattlefieldUnderYourControlThisTurn"),
+        "expected permanent-left condition, got {debug}"
+    );
+}
+
+fn ability_word_card(name: &str, text: &str) -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::from_raw(1), name)
+        .card_types(vec![CardType::Creature])
+        .parse_text(text)
+        .unwrap_or_else(|err| panic!("{name} should parse: {err:?}"))
+}
+
+fn assert_single_intervening_if_trigger(def: &CardDefinition, expected: crate::ConditionExpr) {
+    let triggers = def
+        .abilities
+        .iter()
+        .filter_map(|ability| match &ability.kind {
+            AbilityKind::Triggered(triggered) => Some(triggered),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        triggers.len(),
+        1,
+        "expected one triggered ability for {}",
+        def.name()
+    );
+    assert_eq!(
+        triggers[0].intervening_if,
+        Some(expected),
+        "expected {} to recheck its ability-word condition on resolution",
+        def.name()
+    );
+}
+
+#[test]
+fn test_parse_raid_cards_compile_to_intervening_if_triggers() {
+    let heart_piercer = ability_word_card(
+        "Mardu Heart-Piercer",
+        "Raid — When this creature enters, if you attacked this turn, it deals 2 damage to any target.",
+    );
+    assert_single_intervening_if_trigger(&heart_piercer, crate::ConditionExpr::AttackedThisTurn);
+    assert_eq!(
+        oracle_like_lines(&heart_piercer),
+        vec![
+            "When this creature enters, if you attacked this turn, this creature deals 2 damage to any target."
+        ]
+    );
+
+    let looter = ability_word_card(
+        "Marauding Looter",
+        "Raid — At the beginning of your end step, if you attacked this turn, you may draw a card. If you do, discard a card.",
+    );
+    assert_single_intervening_if_trigger(&looter, crate::ConditionExpr::AttackedThisTurn);
+    assert_eq!(
+        oracle_like_lines(&looter),
+        vec![
+            "At the beginning of your end step, if you attacked this turn, you may draw a card. If you do, discard a card."
+        ]
+    );
+
+    let roc = ability_word_card(
+        "Wingmate Roc",
+        "Flying\nRaid — When this creature enters, if you attacked this turn, create a 3/4 white Bird creature token with flying.\nWhenever this creature attacks, you gain 1 life for each attacking creature.",
+    );
+    let raid_trigger = roc
+        .abilities
+        .iter()
+        .find_map(|ability| match &ability.kind {
+            AbilityKind::Triggered(triggered) if triggered.intervening_if.is_some() => {
+                Some(triggered)
+            }
+            _ => None,
+        })
+        .expect("Wingmate Roc should have a raid trigger");
+    assert_eq!(
+        raid_trigger.intervening_if,
+        Some(crate::ConditionExpr::AttackedThisTurn)
+    );
+    assert_eq!(
+        oracle_like_lines(&roc),
+        vec![
+            "Flying",
+            "When this creature enters, if you attacked this turn, create a 3/4 white Bird creature token with flying.",
+            "Whenever this creature attacks, you gain 1 life for each attacking creature.",
+        ]
+    );
+}
+
+#[test]
+fn test_parse_morbid_cards_compile_to_intervening_if_triggers() {
+    let banshee = ability_word_card(
+        "Morkrut Banshee",
+        "Morbid — When this creature enters, if a creature died this turn, target creature gets -4/-4 until end of turn.",
+    );
+    assert_single_intervening_if_trigger(&banshee, crate::ConditionExpr::CreatureDiedThisTurn);
+    assert_eq!(
+        oracle_like_lines(&banshee),
+        vec![
+            "When this creature enters, if a creature died this turn, target creature gets -4/-4 until end of turn."
+        ]
+    );
+
+    let reaper = ability_word_card(
+        "Reaper from the Abyss",
+        "Flying\nMorbid — At the beginning of each end step, if a creature died this turn, destroy target non-Demon creature.",
+    );
+    assert_single_intervening_if_trigger(&reaper, crate::ConditionExpr::CreatureDiedThisTurn);
+    assert_eq!(
+        oracle_like_lines(&reaper),
+        vec![
+            "Flying",
+            "At the beginning of each end step, if a creature died this turn, destroy target non-demon creature.",
+        ]
+    );
+
+    let scavenger = ability_word_card(
+        "Hollowhenge Scavenger",
+        "Morbid — When this creature enters, if a creature died this turn, you gain 5 life.",
+    );
+    assert_single_intervening_if_trigger(&scavenger, crate::ConditionExpr::CreatureDiedThisTurn);
+    assert_eq!(
+        oracle_like_lines(&scavenger),
+        vec!["When this creature enters, if a creature died this turn, you gain 5 life."]
+    );
+}
+
+#[test]
+fn test_parse_turn_history_ability_words_as_conditional_statics() {
+    let raid = ability_word_card(
+        "Raid Static Probe",
+        "Raid — This creature gets +1/+0 as long as you attacked this turn.",
+    );
+    assert_eq!(
+        oracle_like_lines(&raid),
+        vec!["This creature gets +1/+0 as long as you attacked this turn."]
+    );
+
+    let morbid = ability_word_card(
+        "Morbid Static Probe",
+        "Morbid — This creature gets +2/+2 as long as a creature died this turn.",
+    );
+    let debug = format!("{:?}", morbid.abilities);
+    assert!(
+        debug.contains("CreatureDiedThisTurn"),
+        "expected creature-died static condition, got {debug}"
+    );
+
+    let revolt = ability_word_card(
+        "Narnam Renegade",
+        "Revolt — This creature enters with a +1/+1 counter on it if a permanent you controlled left the battlefield this turn.",
+    );
+    let debug = format!("{:?}", revolt.abilities);
+    assert!(
+        debug.contains("PermanentLeftBattlefieldUnderYourControlThisTurn"),
+        "expected permanent-left enters-with-counter condition, got {debug}"
+    );
+}
+
+#[test]
+fn test_parse_gained_life_conditional_compiles_life_gained_condition() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Life Gain Probe")
+        .card_types(vec![CardType::Creature])
+        .parse_text("At the beginning of your end step, if you gained life this turn, draw a card.")
+        .expect("gained-life conditional should parse");
+
+    let debug = format!("{:?}", def.abilities);
+    assert!(
+        debug.contains("YouGainedLifeThisTurn"),
+        "expected life-gained condition, got {debug}"
+    );
+    let rendered = oracle_like_lines(&def).join(" ").to_ascii_lowercase();
+    assert!(
+        rendered.contains("if you gained life this turn"),
+        "expected life-gained predicate in rendered text, got {rendered}"
+    );
+}
+
+#[test]
+fn test_parse_opponent_lost_life_conditional_compiles_life_lost_condition() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Life Loss Probe")
+        .card_types(vec![CardType::Creature])
+        .parse_text(
+            "At the beginning of your end step, if an opponent lost life this turn, draw a card.",
+        )
+        .expect("opponent-lost-life conditional should parse");
+
+    let debug = format!("{:?}", def.abilities);
+    assert!(
+        debug.contains("OpponentLostLifeThisTurn"),
+        "expected opponent-lost-life condition, got {debug}"
+    );
+}
+
 #[test]
 fn test_parse_x_target_lands_clause_without_fallback() {
     let def = CardDefinitionBuilder::new(CardId::from_raw(1), "X Untap Probe")
@@ -5652,16 +6025,23 @@ fn test_parse_assign_damage_as_unblocked_with_this_creature() {
 }
 
 #[test]
-fn test_parse_first_spell_cost_modifier_marker_errors() {
-    let err = CardDefinitionBuilder::new(CardId::from_raw(1), "First Spell Cost Probe")
+fn test_parse_first_spell_cost_modifier_limits_reduction_to_one_use_per_turn() {
+    let def = CardDefinitionBuilder::new(CardId::from_raw(1), "First Spell Cost Probe")
         .card_types(vec![CardType::Enchantment])
         .parse_text("The first creature spell you cast each turn costs {2} less to cast.")
-        .expect_err("first-spell cost marker should fail parsing");
-    let message = format!("{err:?}").to_ascii_lowercase();
-    assert!(
-        message.contains("unsupported first-spell cost modifier mechanic"),
-        "expected explicit unsupported first-spell marker error, got {message}"
-    );
+        .expect("parse first-spell cost reduction");
+    let reduction = def
+        .abilities
+        .iter()
+        .find_map(|ability| match &ability.kind {
+            AbilityKind::Static(static_ability) => static_ability.cost_reduction().cloned(),
+            _ => None,
+        })
+        .expect("expected a cost reduction");
+    assert_eq!(reduction.uses_per_turn, Some(1));
+    assert_eq!(reduction.filter.card_types, vec![CardType::Creature]);
+    assert_eq!(reduction.filter.cast_by, Some(PlayerFilter::You));
+    assert!(matches!(reduction.reduction, Value::Fixed(2)));
 }
 
 #[test]
@@ -5709,6 +6089,46 @@ fn test_peek_targets_player_hand() {
     );
 }
 
+#[test]
+fn test_trailing_simple_sentences_are_kept_as_cantrip_riders() {
+    let cases = [
+        ("Tap target creature. Draw a card.", "DrawCardsEffect"),
+        ("Counter target spell. Draw a card.", "DrawCardsEffect"),
+        (
+            "Return target creature card from your graveyard to your hand. Scry 1.",
+            "ScryEffect",
+        ),
+        (
+            "Destroy target artifact. You gain 3 life.",
+            "GainLifeEffect",
+        ),
+        (
+            "Destroy target creature. It can't be regenerated. Draw a card.",
+            "DrawCardsEffect",
+        ),
+        (
+            "Choose one —\n• Destroy target artifact.\n• Destroy target enchantment.\nDraw a card.",
+            "DrawCardsEffect",
+        ),
+    ];
+    for (text, rider) in cases {
+        let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Cantrip Probe")
+            .card_types(vec![CardType::Instant])
+            .parse_text(text)
+            .unwrap_or_else(|err| panic!("parse {text:?}: {err:?}"));
+        let effects = def.spell_effect.as_ref().expect("spell effects");
+        assert!(
+            effects.len() >= 2,
+            "expected the rider to be a separate effect for {text:?}, got {effects:?}"
+        );
+        let last = format!("{:?}", effects.last().unwrap());
+        assert!(
+            last.contains(rider),
+            "expected {text:?} to end with {rider}, got {last}"
+        );
+    }
+}
+
 #[test]
 fn test_peek_targets_opponent_hand() {
     let def = CardDefinitionBuilder::new(CardId::from_raw(1), "Opponent Peek Probe")
@@ -11288,6 +11708,47 @@ fn parse_where_x_is_fixed_plus_number_of_filter_value() {
     );
 }
 
+#[test]
+fn parse_distinct_among_values_for_card_types_and_mana_values() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Goyf Burst Variant")
+        .parse_text(
+            "Target creature gets +X/+X until end of turn, where X is the number of card types among cards in all graveyards.",
+        )
+        .expect("card-types-among where-X clause should parse");
+    let debug = format!("{:?}", def.spell_effect);
+    assert!(
+        debug.contains("CardTypesAmong(ObjectFilter { zone: Some(Graveyard), controller: None, cast_by: None, owner: None"),
+        "expected card types among all graveyards, got {debug}"
+    );
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Delirium Burst Variant")
+        .parse_text(
+            "Target creature gets +1/+1 until end of turn for each card type among cards in your graveyard.",
+        )
+        .expect("card-type-among for-each gets clause should parse");
+    let debug = format!("{:?}", def.spell_effect);
+    assert!(
+        debug.contains("CardTypesInGraveyard(You)"),
+        "expected delirium card type count, got {debug}"
+    );
+    let rendered = compiled_lines(&def).join(" ");
+    assert!(
+        rendered.contains("for each card type among cards in your graveyard"),
+        "expected delirium phrasing to round-trip, got {rendered}"
+    );
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Spectrum Burst Variant")
+        .parse_text(
+            "You gain life equal to the number of different mana values among nonland permanents you control.",
+        )
+        .expect("mana-values-among clause should parse");
+    let debug = format!("{:?}", def.spell_effect);
+    assert!(
+        debug.contains("ManaValuesAmong(") && debug.contains("excluded_card_types: [Land]"),
+        "expected distinct mana values among nonland permanents, got {debug}"
+    );
+}
+
 #[test]
 fn parse_search_filter_artifact_with_mana_ability_or_basic_land() {
     let def = CardDefinitionBuilder::new(CardId::new(), "Moonsilver Key Variant")
@@ -11477,6 +11938,32 @@ fn parse_damage_redirect_to_source_line() {
     );
 }
 
+#[test]
+fn parse_palisade_giant_redirects_only_damage_to_you() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Palisade Giant")
+        .parse_text(
+            "Mana cost: {4}{W}{W}\nType: Creature — Giant Soldier\nPower/Toughness: 2/7\nAll damage that would be dealt to you is dealt to Palisade Giant instead.",
+        )
+        .expect("palisade giant redirect should parse");
+    let redirect = def
+        .abilities
+        .iter()
+        .find_map(|ability| match &ability.kind {
+            AbilityKind::Static(static_ability)
+                if static_ability.id()
+                    == crate::static_abilities::StaticAbilityId::RedirectDamageToSource =>
+            {
+                Some(static_ability)
+            }
+            _ => None,
+        })
+        .expect("expected damage redirect static ability");
+    assert_eq!(
+        redirect.display(),
+        "All damage that would be dealt to you is dealt to this creature instead."
+    );
+}
+
 #[test]
 fn parse_no_more_than_creatures_can_attack_or_block_each_combat_lines() {
     let def = CardDefinitionBuilder::new(CardId::new(), "Silent Arbiter Variant")
@@ -17094,6 +17581,94 @@ fn parse_dauthi_voidwalker_full_text_without_parser_fallback() {
     );
 }
 
+#[test]
+fn parse_graveyard_hate_statics_without_parser_fallback() {
+    let rest_in_peace = CardDefinitionBuilder::new(CardId::new(), "Rest in Peace")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "If a card or token would be put into a graveyard from anywhere, exile it instead.",
+        )
+        .expect("Rest in Peace replacement should parse");
+    let leyline = CardDefinitionBuilder::new(CardId::new(), "Leyline of the Void")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "If a card would be put into an opponent's graveyard from anywhere, exile it instead.",
+        )
+        .expect("Leyline of the Void replacement should parse");
+    let ground_seal = CardDefinitionBuilder::new(CardId::new(), "Ground Seal")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("Cards in graveyards can't be the targets of spells or abilities.")
+        .expect("Ground Seal restriction should parse");
+
+    for def in [&rest_in_peace, &leyline] {
+        assert!(
+            def.abilities.iter().any(|ability| matches!(
+                &ability.kind,
+                AbilityKind::Static(static_ability)
+                    if static_ability.id() == StaticAbilityId::ExileInsteadOfGraveyard
+            )),
+            "expected {} to lower to an exile-instead static, got {:?}",
+            def.name(),
+            def.abilities
+        );
+    }
+    assert_eq!(
+        oracle_like_lines(&rest_in_peace),
+        vec!["If a card or token would be put into a graveyard from anywhere, exile it instead."]
+    );
+    assert_eq!(
+        oracle_like_lines(&leyline),
+        vec![
+            "If a card would be put into an opponent's graveyard from anywhere, exile it instead."
+        ]
+    );
+
+    let debug = format!("{:?}", ground_seal.abilities);
+    assert!(
+        debug.contains("BeTargeted") && debug.contains("Graveyard"),
+        "expected a graveyard targeting restriction, got {debug}"
+    );
+}
+
+#[test]
+fn parse_counter_and_token_doublers_without_parser_fallback() {
+    let doubling_season = CardDefinitionBuilder::new(CardId::new(), "Doubling Season")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "If an effect would create one or more tokens under your control, it creates twice that many of those tokens instead.\nIf an effect would put one or more counters on a permanent you control, it puts twice that many of those counters on that permanent instead.",
+        )
+        .expect("Doubling Season replacements should parse");
+    let hardened_scales = CardDefinitionBuilder::new(CardId::new(), "Hardened Scales")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "If one or more +1/+1 counters would be put on a creature you control, that many plus one +1/+1 counters are put on it instead.",
+        )
+        .expect("Hardened Scales replacement should parse");
+
+    let static_ids = |def: &CardDefinition| {
+        def.abilities
+            .iter()
+            .filter_map(|ability| match &ability.kind {
+                AbilityKind::Static(static_ability) => Some(static_ability.id()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        static_ids(&doubling_season),
+        vec![
+            StaticAbilityId::TokenCreationReplacement,
+            StaticAbilityId::CounterPlacementReplacement
+        ]
+    );
+    assert_eq!(
+        oracle_like_lines(&hardened_scales),
+        vec![
+            "If one or more +1/+1 counters would be put on a creature you control, that many plus one +1/+1 counters are put on it instead."
+        ]
+    );
+}
+
 #[test]
 fn parse_vaultborn_tyrant_nontoken_followup_condition() {
     CardDefinitionBuilder::new(CardId::new(), "Vaultborn Tyrant Variant")
@@ -17317,3 +17892,177 @@ fn sacrificed_creature_was_hamster_with_extra_tail_still_fails_loudly() {
         "expected loud failure for unsupported sacrificed-creature tail, got {rendered}"
     );
 }
+
+fn resolve_parsed_spell_with_targets(
+    game: &mut crate::game_state::GameState,
+    def: &CardDefinition,
+    caster: PlayerId,
+    targets: Vec<crate::game_state::Target>,
+) {
+    let spell = game.create_object_from_definition(def, caster, crate::zone::Zone::Stack);
+    game.push_to_stack(crate::game_state::StackEntry::new(spell, caster).with_targets(targets));
+    crate::game_loop::resolve_stack_entry(game).expect("spell should resolve");
+}
+
+fn pronoun_test_creature(
+    game: &mut crate::game_state::GameState,
+    owner: PlayerId,
+    power: i32,
+    toughness: i32,
+    zone: crate::zone::Zone,
+) -> crate::ids::ObjectId {
+    let card = crate::card::CardBuilder::new(CardId::new(), "Pronoun Target")
+        .card_types(vec![CardType::Creature])
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(3)],
+            vec![ManaSymbol::Green],
+        ]))
+        .power_toughness(crate::card::PowerToughness::fixed(power, toughness))
+        .build();
+    game.create_object_from_card(&card, owner, zone)
+}
+
+fn mark_attacking(game: &mut crate::game_state::GameState, attacker: crate::ids::ObjectId) {
+    let defender = PlayerId::from_index(0);
+    let mut combat = crate::combat_state::CombatState::default();
+    combat.attackers.push(crate::combat_state::AttackerInfo {
+        creature: attacker,
+        target: crate::combat_state::AttackTarget::Player(defender),
+    });
+    game.combat = Some(combat);
+}
+
+#[test]
+fn sever_soul_gains_life_equal_to_destroyed_creatures_toughness() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Sever Soul")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Destroy target nonblack creature. It can't be regenerated. You gain life equal to its toughness.",
+        )
+        .expect("parse Sever Soul");
+    let rendered = crate::compiled_text::compiled_lines(&def).join(" ");
+    assert!(
+        rendered.contains("you gain life equal to its toughness"),
+        "expected toughness of the destroyed creature, got {rendered}"
+    );
+
+    let mut game = crate::tests::test_helpers::setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let victim = pronoun_test_creature(&mut game, bob, 2, 5, crate::zone::Zone::Battlefield);
+    resolve_parsed_spell_with_targets(
+        &mut game,
+        &def,
+        alice,
+        vec![crate::game_state::Target::Object(victim)],
+    );
+
+    assert!(!game.battlefield.contains(&victim));
+    assert_eq!(game.player(alice).unwrap().life, 25);
+}
+
+#[test]
+fn exile_gains_life_equal_to_exiled_attackers_toughness() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Exile")
+        .card_types(vec![CardType::Instant])
+        .parse_text(
+            "Exile target nonwhite attacking creature. You gain life equal to its toughness.",
+        )
+        .expect("parse Exile");
+
+    let mut game = crate::tests::test_helpers::setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let attacker = pronoun_test_creature(&mut game, bob, 3, 4, crate::zone::Zone::Battlefield);
+    mark_attacking(&mut game, attacker);
+    resolve_parsed_spell_with_targets(
+        &mut game,
+        &def,
+        alice,
+        vec![crate::game_state::Target::Object(attacker)],
+    );
+
+    assert!(!game.battlefield.contains(&attacker));
+    assert_eq!(game.player(alice).unwrap().life, 24);
+}
+
+#[test]
+fn condemn_gives_attackers_controller_life_equal_to_its_toughness() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Condemn")
+        .card_types(vec![CardType::Instant])
+        .parse_text(
+            "Put target attacking creature on the bottom of its owner's library. Its controller gains life equal to its toughness.",
+        )
+        .expect("parse Condemn");
+
+    let mut game = crate::tests::test_helpers::setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let attacker = pronoun_test_creature(&mut game, bob, 5, 3, crate::zone::Zone::Battlefield);
+    mark_attacking(&mut game, attacker);
+    resolve_parsed_spell_with_targets(
+        &mut game,
+        &def,
+        alice,
+        vec![crate::game_state::Target::Object(attacker)],
+    );
+
+    assert!(!game.battlefield.contains(&attacker));
+    assert_eq!(game.player(bob).unwrap().life, 23);
+    assert_eq!(game.player(alice).unwrap().life, 20);
+}
+
+#[test]
+fn feed_the_swarm_loses_life_equal_to_that_permanents_mana_value() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Feed the Swarm")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Destroy target creature or enchantment an opponent controls. You lose life equal to that permanent's mana value.",
+        )
+        .expect("parse Feed the Swarm");
+
+    let mut game = crate::tests::test_helpers::setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let victim = pronoun_test_creature(&mut game, bob, 2, 2, crate::zone::Zone::Battlefield);
+    resolve_parsed_spell_with_targets(
+        &mut game,
+        &def,
+        alice,
+        vec![crate::game_state::Target::Object(victim)],
+    );
+
+    assert!(!game.battlefield.contains(&victim));
+    assert_eq!(game.player(alice).unwrap().life, 16);
+}
+
+#[test]
+fn morbid_bloom_creates_tokens_equal_to_exiled_cards_toughness() {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Morbid Bloom")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Exile target creature card from a graveyard, then create X 1/1 green Saproling creature tokens, where X is the exiled card's toughness.",
+        )
+        .expect("parse Morbid Bloom");
+
+    let mut game = crate::tests::test_helpers::setup_two_player_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let card = pronoun_test_creature(&mut game, bob, 1, 3, crate::zone::Zone::Graveyard);
+    resolve_parsed_spell_with_targets(
+        &mut game,
+        &def,
+        alice,
+        vec![crate::game_state::Target::Object(card)],
+    );
+
+    let saprolings = game
+        .battlefield
+        .iter()
+        .filter(|&&id| {
+            game.object(id)
+                .is_some_and(|obj| obj.controller == alice && obj.name.contains("Saproling"))
+        })
+        .count();
+    assert_eq!(saprolings, 3);
+}
@@ -0,0 +1,584 @@
+//! Data-defined cards.
+//!
+//! Downstream tools can author cards as JSON (or any other serde format)
+//! instead of Rust, then build a [`CardDefinition`] with
+//! [`CardDefinition::from_data`]. Only the subset of effects, triggers, and
+//! static abilities whose parameters are plain data is covered; anything
+//! else still needs a Rust definition or oracle text.
+//!
+//! # Schema (version 1)
+//!
+//! A [`DataCard`] is an object with these fields:
+//!
+//! | field            | type                   | notes                                        |
+//! |------------------|------------------------|----------------------------------------------|
+//! | `schema_version` | integer                | defaults to 1; newer versions are rejected   |
+//! | `name`           | string                 | required                                     |
+//! | `mana_cost`      | string                 | Scryfall style, e.g. `"{1}{G}"`              |
+//! | `type_line`      | string                 | e.g. `"Legendary Creature — Elf Druid"`      |
+//! | `power`          | integer                | creatures only, together with `toughness`    |
+//! | `toughness`      | integer                |                                              |
+//! | `oracle_text`    | string                 | display only; never parsed                   |
+//! | `statics`        | array of [`DataStatic`]  | tagged by `"kind"`                           |
+//! | `triggers`       | array of [`DataTrigger`] | `{"event": {...}, "effects": [...]}`         |
+//! | `spell_effects`  | array of [`DataEffect`]  | instants and sorceries                       |
+//!
+//! Data-only wrapper types ([`DataStatic`], [`DataTriggerEvent`],
+//! [`DataEffect`], [`DataKeyword`]) are internally tagged with snake_case
+//! names. Engine types embedded in them use serde's default representation:
+//!
+//! - [`ObjectFilter`] is an object whose fields all default, so
+//!   `{"zone": "Battlefield", "card_types": ["Creature"]}` is a complete filter.
+//! - [`PlayerFilter`], [`ChooseSpec`], [`Value`], and [`Until`] are
+//!   externally tagged enums: `"You"`, `"AnyTarget"`,
+//!   `{"Target": {"Player": "Opponent"}}`, `{"Fixed": 2}`, `"EndOfTurn"`.
+//! - Color sets are arrays of color names, e.g. `["White", "Blue"]`.
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "name": "Grove Provisioner",
+//!   "mana_cost": "{G}",
+//!   "type_line": "Creature — Elf Druid",
+//!   "power": 1,
+//!   "toughness": 2,
+//!   "statics": [{"kind": "keyword", "keyword": "vigilance"}],
+//!   "triggers": [{
+//!     "event": {"kind": "this_enters_battlefield"},
+//!     "effects": [
+//!       {"kind": "gain_life", "amount": {"Fixed": 2}},
+//!       {"kind": "create_tokens", "token": "Food", "count": {"Fixed": 1}}
+//!     ]
+//!   }]
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::ability::Ability;
+use crate::card::PowerToughness;
+use crate::cards::CardDefinition;
+use crate::cards::builders::{
+    CardDefinitionBuilder, CardTextError, parse_scryfall_mana_cost, parse_type_line,
+};
+use crate::cards::tokens::TokenRegistry;
+use crate::effect::{Effect, Until, Value};
+use crate::ids::CardId;
+use crate::object::CounterType;
+use crate::static_abilities::StaticAbility;
+use crate::target::{ChooseSpec, ObjectFilter, PlayerFilter};
+use crate::triggers::Trigger;
+
+/// Current version of the card data schema.
+pub const CARD_DATA_SCHEMA_VERSION: u32 = 1;
+
+fn default_schema_version() -> u32 {
+    CARD_DATA_SCHEMA_VERSION
+}
+
+/// A card described as data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DataCard {
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mana_cost: Option<String>,
+    pub type_line: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toughness: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oracle_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statics: Vec<DataStatic>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub triggers: Vec<DataTrigger>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spell_effects: Vec<DataEffect>,
+}
+
+/// Keyword abilities that take no parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataKeyword {
+    Flying,
+    FirstStrike,
+    DoubleStrike,
+    Deathtouch,
+    Defender,
+    Flash,
+    Haste,
+    Hexproof,
+    Indestructible,
+    Lifelink,
+    Menace,
+    Reach,
+    Shroud,
+    Trample,
+    Vigilance,
+}
+
+impl DataKeyword {
+    fn to_static_ability(self) -> StaticAbility {
+        match self {
+            DataKeyword::Flying => StaticAbility::flying(),
+            DataKeyword::FirstStrike => StaticAbility::first_strike(),
+            DataKeyword::DoubleStrike => StaticAbility::double_strike(),
+            DataKeyword::Deathtouch => StaticAbility::deathtouch(),
+            DataKeyword::Defender => StaticAbility::defender(),
+            DataKeyword::Flash => StaticAbility::flash(),
+            DataKeyword::Haste => StaticAbility::haste(),
+            DataKeyword::Hexproof => StaticAbility::hexproof(),
+            DataKeyword::Indestructible => StaticAbility::indestructible(),
+            DataKeyword::Lifelink => StaticAbility::lifelink(),
+            DataKeyword::Menace => StaticAbility::menace(),
+            DataKeyword::Reach => StaticAbility::reach(),
+            DataKeyword::Shroud => StaticAbility::shroud(),
+            DataKeyword::Trample => StaticAbility::trample(),
+            DataKeyword::Vigilance => StaticAbility::vigilance(),
+        }
+    }
+}
+
+/// Static abilities with data-friendly parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DataStatic {
+    /// This object has a keyword.
+    Keyword {
+        keyword: DataKeyword,
+    },
+    /// Objects matching `filter` get +power/+toughness.
+    Anthem {
+        filter: ObjectFilter,
+        power: i32,
+        toughness: i32,
+    },
+    /// Objects matching `filter` have a keyword.
+    GrantKeyword {
+        filter: ObjectFilter,
+        keyword: DataKeyword,
+    },
+    /// Hexproof from objects matching `filter`.
+    HexproofFrom {
+        filter: ObjectFilter,
+    },
+    CantAttack,
+    CantBlock,
+    Unblockable,
+}
+
+impl DataStatic {
+    fn to_static_ability(&self) -> StaticAbility {
+        match self {
+            DataStatic::Keyword { keyword } => keyword.to_static_ability(),
+            DataStatic::Anthem {
+                filter,
+                power,
+                toughness,
+            } => StaticAbility::anthem(filter.clone(), *power, *toughness),
+            DataStatic::GrantKeyword { filter, keyword } => {
+                StaticAbility::grant_ability(filter.clone(), keyword.to_static_ability())
+            }
+            DataStatic::HexproofFrom { filter } => StaticAbility::hexproof_from(filter.clone()),
+            DataStatic::CantAttack => StaticAbility::cant_attack(),
+            DataStatic::CantBlock => StaticAbility::cant_block(),
+            DataStatic::Unblockable => StaticAbility::unblockable(),
+        }
+    }
+}
+
+/// A triggered ability: when `event` happens, do `effects`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DataTrigger {
+    pub event: DataTriggerEvent,
+    pub effects: Vec<DataEffect>,
+}
+
+/// Trigger events with data-friendly parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DataTriggerEvent {
+    ThisEntersBattlefield,
+    ThisDies,
+    ThisAttacks,
+    EntersBattlefield { filter: ObjectFilter },
+    Dies { filter: ObjectFilter },
+    BeginningOfUpkeep { player: PlayerFilter },
+    BeginningOfEndStep { player: PlayerFilter },
+}
+
+impl DataTriggerEvent {
+    fn to_trigger(&self) -> Trigger {
+        match self {
+            DataTriggerEvent::ThisEntersBattlefield => Trigger::this_enters_battlefield(),
+            DataTriggerEvent::ThisDies => Trigger::this_dies(),
+            DataTriggerEvent::ThisAttacks => Trigger::this_attacks(),
+            DataTriggerEvent::EntersBattlefield { filter } => {
+                Trigger::enters_battlefield(filter.clone())
+            }
+            DataTriggerEvent::Dies { filter } => Trigger::dies(filter.clone()),
+            DataTriggerEvent::BeginningOfUpkeep { player } => {
+                Trigger::beginning_of_upkeep(player.clone())
+            }
+            DataTriggerEvent::BeginningOfEndStep { player } => {
+                Trigger::beginning_of_end_step(player.clone())
+            }
+        }
+    }
+}
+
+fn source_controller() -> ChooseSpec {
+    ChooseSpec::SourceController
+}
+
+fn you() -> PlayerFilter {
+    PlayerFilter::You
+}
+
+/// Effects with data-friendly parameters.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DataEffect {
+    DealDamage {
+        amount: Value,
+        target: ChooseSpec,
+    },
+    Draw {
+        count: Value,
+        #[serde(default = "you")]
+        player: PlayerFilter,
+    },
+    GainLife {
+        amount: Value,
+        #[serde(default = "source_controller")]
+        player: ChooseSpec,
+    },
+    LoseLife {
+        amount: Value,
+        #[serde(default = "source_controller")]
+        player: ChooseSpec,
+    },
+    Destroy {
+        target: ChooseSpec,
+    },
+    Exile {
+        target: ChooseSpec,
+    },
+    Tap {
+        target: ChooseSpec,
+    },
+    Untap {
+        target: ChooseSpec,
+    },
+    Counter {
+        target: ChooseSpec,
+    },
+    /// Create tokens registered in [`TokenRegistry`] by name.
+    CreateTokens {
+        token: String,
+        count: Value,
+    },
+    PutCounters {
+        counter_type: CounterType,
+        count: Value,
+        target: ChooseSpec,
+    },
+    Pump {
+        power: Value,
+        toughness: Value,
+        target: ChooseSpec,
+        #[serde(default = "until_end_of_turn")]
+        duration: Until,
+    },
+    Sacrifice {
+        filter: ObjectFilter,
+        count: Value,
+    },
+    Scry {
+        count: Value,
+    },
+    Mill {
+        count: Value,
+    },
+    Discard {
+        count: Value,
+    },
+}
+
+fn until_end_of_turn() -> Until {
+    Until::EndOfTurn
+}
+
+impl DataEffect {
+    fn to_effect(&self) -> Result<Effect, CardTextError> {
+        use crate::effects::{GainLifeEffect, LoseLifeEffect};
+
+        Ok(match self {
+            DataEffect::DealDamage { amount, target } => {
+                Effect::deal_damage(amount.clone(), target.clone())
+            }
+            DataEffect::Draw { count, player } => {
+                Effect::target_draws(count.clone(), player.clone())
+            }
+            DataEffect::GainLife { amount, player } => {
+                Effect::new(GainLifeEffect::new(amount.clone(), player.clone()))
+            }
+            DataEffect::LoseLife { amount, player } => {
+                Effect::new(LoseLifeEffect::new(amount.clone(), player.clone()))
+            }
+            DataEffect::Destroy { target } => Effect::destroy(target.clone()),
+            DataEffect::Exile { target } => Effect::exile(target.clone()),
+            DataEffect::Tap { target } => Effect::tap(target.clone()),
+            DataEffect::Untap { target } => Effect::untap(target.clone()),
+            DataEffect::Counter { target } => Effect::counter(target.clone()),
+            DataEffect::CreateTokens { token, count } => {
+                let definition = TokenRegistry::get(token)
+                    .ok_or_else(|| CardTextError::ParseError(format!("unknown token '{token}'")))?;
+                Effect::create_tokens(definition, count.clone())
+            }
+            DataEffect::PutCounters {
+                counter_type,
+                count,
+                target,
+            } => Effect::put_counters(*counter_type, count.clone(), target.clone()),
+            DataEffect::Pump {
+                power,
+                toughness,
+                target,
+                duration,
+            } => Effect::pump(
+                power.clone(),
+                toughness.clone(),
+                target.clone(),
+                duration.clone(),
+            ),
+            DataEffect::Sacrifice { filter, count } => {
+                Effect::sacrifice(filter.clone(), count.clone())
+            }
+            DataEffect::Scry { count } => Effect::scry(count.clone()),
+            DataEffect::Mill { count } => Effect::mill(count.clone()),
+            DataEffect::Discard { count } => Effect::discard(count.clone()),
+        })
+    }
+}
+
+fn to_effects(effects: &[DataEffect]) -> Result<Vec<Effect>, CardTextError> {
+    effects.iter().map(DataEffect::to_effect).collect()
+}
+
+impl CardDefinition {
+    /// Build a card definition from its data description.
+    pub fn from_data(data: &DataCard) -> Result<Self, CardTextError> {
+        if data.schema_version > CARD_DATA_SCHEMA_VERSION {
+            return Err(CardTextError::ParseError(format!(
+                "card data schema version {} is newer than supported version {}",
+                data.schema_version, CARD_DATA_SCHEMA_VERSION
+            )));
+        }
+
+        let (supertypes, card_types, subtypes) = parse_type_line(&data.type_line)?;
+        let mut builder = CardDefinitionBuilder::new(CardId::new(), data.name.clone())
+            .supertypes(supertypes)
+            .card_types(card_types)
+            .subtypes(subtypes);
+        if let Some(cost) = &data.mana_cost {
+            builder = builder.mana_cost(parse_scryfall_mana_cost(cost)?);
+        }
+        match (data.power, data.toughness) {
+            (Some(power), Some(toughness)) => {
+                builder = builder.power_toughness(PowerToughness::fixed(power, toughness));
+            }
+            (None, None) => {}
+            _ => {
+                return Err(CardTextError::ParseError(
+                    "power and toughness must be given together".to_string(),
+                ));
+            }
+        }
+        if let Some(text) = &data.oracle_text {
+            builder = builder.oracle_text(text.clone());
+        }
+
+        for data_static in &data.statics {
+            builder =
+                builder.with_ability(Ability::static_ability(data_static.to_static_ability()));
+        }
+        for trigger in &data.triggers {
+            let effects = to_effects(&trigger.effects)?;
+            let mut choices: Vec<ChooseSpec> = Vec::new();
+            for spec in effects
+                .iter()
+                .filter_map(|effect| effect.0.get_target_spec())
+            {
+                if spec.is_target() && !choices.contains(spec) {
+                    choices.push(spec.clone());
+                }
+            }
+            let mut ability = Ability::triggered(trigger.event.to_trigger(), effects);
+            if let crate::ability::AbilityKind::Triggered(triggered) = &mut ability.kind {
+                triggered.choices = choices;
+            }
+            builder = builder.with_ability(ability);
+        }
+        if !data.spell_effects.is_empty() {
+            builder = builder.with_spell_effect(to_effects(&data.spell_effects)?);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorSet;
+    use crate::effect::ChoiceCount;
+    use crate::tag::TagKey;
+    use crate::target::Comparison;
+    use crate::types::{CardType, Subtype, Supertype};
+    use crate::zone::Zone;
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let json = serde_json::to_string(value).expect("serialize");
+        serde_json::from_str(&json).unwrap_or_else(|err| panic!("deserialize {json}: {err}"))
+    }
+
+    #[test]
+    fn object_filters_round_trip_through_json() {
+        let filters = vec![
+            ObjectFilter::default(),
+            ObjectFilter::creature(),
+            ObjectFilter::creature().you_control().other(),
+            ObjectFilter::nonland_permanent().opponent_controls(),
+            ObjectFilter::creature()
+                .with_colors(ColorSet::WHITE.union(ColorSet::BLUE))
+                .untapped(),
+            ObjectFilter::land().with_supertype(Supertype::Basic),
+            ObjectFilter::creature().with_subtype(Subtype::Elf).token(),
+            ObjectFilter::spell().targeting_object(ObjectFilter::creature().you_control()),
+            ObjectFilter::creature().with_power(Comparison::GreaterThanOrEqual(4)),
+            ObjectFilter::permanent()
+                .with_counter_type(CounterType::Named("spore"))
+                .with_all_type(CardType::Artifact),
+            ObjectFilter::instant_or_sorcery().in_zone(Zone::Graveyard),
+            ObjectFilter::tagged(TagKey::from("exiled")),
+            ObjectFilter::spell().with_target_count(ChoiceCount::exactly(1)),
+        ];
+        for filter in filters {
+            assert_eq!(round_trip(&filter), filter);
+        }
+    }
+
+    #[test]
+    fn choose_specs_and_values_round_trip_through_json() {
+        let specs = vec![
+            ChooseSpec::AnyTarget,
+            ChooseSpec::target(ChooseSpec::Object(ObjectFilter::creature())),
+            ChooseSpec::target(ChooseSpec::Player(PlayerFilter::Opponent)),
+            ChooseSpec::SourceController,
+        ];
+        for spec in specs {
+            assert_eq!(round_trip(&spec), spec);
+        }
+
+        let values = vec![
+            Value::Fixed(3),
+            Value::Count(ObjectFilter::creature().you_control()),
+            Value::CountersOn(
+                Box::new(ChooseSpec::Source),
+                Some(CounterType::PlusOnePlusOne),
+            ),
+        ];
+        for value in values {
+            assert_eq!(round_trip(&value), value);
+        }
+    }
+
+    #[test]
+    fn filter_fields_default_when_omitted() {
+        let filter: ObjectFilter =
+            serde_json::from_str(r#"{"zone": "Battlefield", "card_types": ["Creature"]}"#)
+                .expect("partial filter");
+        assert_eq!(filter, ObjectFilter::creature());
+
+        let colors: ColorSet = serde_json::from_str(r#"["Green", "White"]"#).expect("colors");
+        assert_eq!(colors, ColorSet::WHITE.union(ColorSet::GREEN));
+    }
+
+    #[test]
+    fn from_data_builds_card_with_abilities() {
+        let data: DataCard = serde_json::from_str(
+            r#"{
+                "name": "Cinder Warden",
+                "mana_cost": "{1}{R}",
+                "type_line": "Creature — Human Shaman",
+                "power": 2,
+                "toughness": 1,
+                "statics": [{"kind": "keyword", "keyword": "haste"}],
+                "triggers": [{
+                    "event": {"kind": "this_enters_battlefield"},
+                    "effects": [{
+                        "kind": "deal_damage",
+                        "amount": {"Fixed": 1},
+                        "target": "AnyTarget"
+                    }]
+                }]
+            }"#,
+        )
+        .expect("valid card data");
+        let definition = CardDefinition::from_data(&data).expect("buildable card");
+
+        assert_eq!(definition.name(), "Cinder Warden");
+        assert_eq!(definition.card.mana_cost.as_ref().unwrap().mana_value(), 2);
+        assert_eq!(
+            definition.card.subtypes,
+            vec![Subtype::Human, Subtype::Shaman]
+        );
+        assert_eq!(definition.abilities.len(), 2);
+        let crate::ability::AbilityKind::Triggered(triggered) = &definition.abilities[1].kind
+        else {
+            panic!("expected a triggered ability");
+        };
+        assert_eq!(triggered.choices, vec![ChooseSpec::AnyTarget]);
+    }
+
+    #[test]
+    fn from_data_rejects_bad_input() {
+        let mut data = DataCard {
+            schema_version: CARD_DATA_SCHEMA_VERSION + 1,
+            name: "Future Card".to_string(),
+            mana_cost: None,
+            type_line: "Sorcery".to_string(),
+            power: None,
+            toughness: None,
+            oracle_text: None,
+            statics: Vec::new(),
+            triggers: Vec::new(),
+            spell_effects: Vec::new(),
+        };
+        assert!(CardDefinition::from_data(&data).is_err());
+
+        data.schema_version = CARD_DATA_SCHEMA_VERSION;
+        data.spell_effects = vec![DataEffect::CreateTokens {
+            token: "Unobtainium".to_string(),
+            count: Value::Fixed(1),
+        }];
+        assert!(CardDefinition::from_data(&data).is_err());
+
+        assert!(
+            serde_json::from_str::<DataCard>(
+                r#"{"name": "X", "type_line": "Sorcery", "colour": 1}"#
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Approach of the Second Sun card definition.
+
+use super::CardDefinitionBuilder;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::CardType;
+
+/// Approach of the Second Sun - {6}{W}
+/// Sorcery
+/// If this spell was cast from your hand and you've cast another spell named Approach of the
+/// Second Sun this game, you win the game. Otherwise, put Approach of the Second Sun into its
+/// owner's library seventh from the top and you gain 7 life.
+pub fn approach_of_the_second_sun() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Approach of the Second Sun")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(6)],
+            vec![ManaSymbol::White],
+        ]))
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "If this spell was cast from your hand and you've cast another spell named \
+Approach of the Second Sun this game, you win the game. Otherwise, put Approach of the Second \
+Sun into its owner's library seventh from the top and you gain 7 life.",
+        )
+        .expect("Card text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::{DecisionMaker, LegalAction};
+    use crate::decisions::context::PriorityContext;
+    use crate::game_loop::run_priority_loop_with;
+    use crate::game_state::GameState;
+    use crate::ids::{ObjectId, PlayerId};
+    use crate::triggers::TriggerQueue;
+    use crate::zone::Zone;
+
+    /// Casts Approach of the Second Sun whenever it can, otherwise passes.
+    struct CastApproach;
+
+    impl DecisionMaker for CastApproach {
+        fn decide_priority(&mut self, game: &GameState, ctx: &PriorityContext) -> LegalAction {
+            ctx.actions
+                .iter()
+                .find(|action| {
+                    matches!(action, LegalAction::CastSpell { spell_id, .. }
+                        if game.object(*spell_id).is_some_and(|obj| obj.name == "Approach of the Second Sun"))
+                })
+                .cloned()
+                .unwrap_or(LegalAction::PassPriority)
+        }
+    }
+
+    /// Alice has Approach in hand, seven Plains and ten cards in her library,
+    /// with priority in her main phase.
+    fn setup() -> (GameState, Vec<ObjectId>) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+
+        for _ in 0..10 {
+            game.create_object_from_definition(
+                &crate::cards::definitions::grizzly_bears(),
+                alice,
+                Zone::Library,
+            );
+        }
+        let plains = (0..7)
+            .map(|_| {
+                game.create_object_from_definition(
+                    &crate::cards::definitions::basic_plains(),
+                    alice,
+                    Zone::Battlefield,
+                )
+            })
+            .collect();
+        game.create_object_from_definition(&approach_of_the_second_sun(), alice, Zone::Hand);
+
+        game.turn.active_player = alice;
+        game.turn.priority_player = Some(alice);
+        game.turn.phase = crate::Phase::FirstMain;
+        game.turn.step = None;
+        (game, plains)
+    }
+
+    fn cast_and_resolve(game: &mut GameState) {
+        let mut trigger_queue = TriggerQueue::new();
+        run_priority_loop_with(game, &mut trigger_queue, &mut CastApproach).unwrap();
+    }
+
+    fn approach_in_library(game: &GameState) -> Option<(usize, ObjectId)> {
+        let library = &game.player(PlayerId::from_index(0)).unwrap().library;
+        // The top of the library is the last element.
+        library.iter().rev().enumerate().find_map(|(depth, &id)| {
+            game.object(id)
+                .filter(|obj| obj.name == "Approach of the Second Sun")
+                .map(|_| (depth + 1, id))
+        })
+    }
+
+    #[test]
+    fn first_approach_goes_seventh_from_the_top_and_gains_seven_life() {
+        let (mut game, _) = setup();
+
+        cast_and_resolve(&mut game);
+
+        let (depth, _) = approach_in_library(&game).expect("Approach should be in the library");
+        assert_eq!(depth, 7);
+        assert_eq!(game.player(PlayerId::from_index(0)).unwrap().life, 27);
+        assert!(!game.player(PlayerId::from_index(1)).unwrap().has_lost);
+    }
+
+    #[test]
+    fn second_approach_cast_from_hand_wins_the_game() {
+        let (mut game, plains) = setup();
+
+        cast_and_resolve(&mut game);
+        let (_, approach) = approach_in_library(&game).expect("Approach should be in the library");
+        game.move_object(approach, Zone::Hand);
+        for id in plains {
+            game.untap(id);
+        }
+
+        cast_and_resolve(&mut game);
+
+        assert_eq!(game.spells_cast_this_game.len(), 2);
+        assert!(game.player(PlayerId::from_index(1)).unwrap().has_lost);
+        assert!(!game.player(PlayerId::from_index(0)).unwrap().has_lost);
+    }
+}
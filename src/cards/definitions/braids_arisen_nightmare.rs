@@ -416,4 +416,98 @@ mod tests {
             "expected lose-life and draw effects in fallback branch, got {debug}"
         );
     }
+
+    /// Alice sacrifices her Bears; Bob answers with a creature of his own while
+    /// Carol declines and is punished.
+    struct BraidsTableDecisions {
+        bears: crate::ids::ObjectId,
+    }
+
+    impl crate::decision::DecisionMaker for BraidsTableDecisions {
+        fn decide_boolean(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::BooleanContext,
+        ) -> bool {
+            ctx.player != PlayerId::from_index(2)
+        }
+
+        fn decide_objects(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::SelectObjectsContext,
+        ) -> Vec<crate::ids::ObjectId> {
+            let legal: Vec<_> = ctx
+                .candidates
+                .iter()
+                .filter(|candidate| candidate.legal)
+                .map(|candidate| candidate.id)
+                .collect();
+            if legal.contains(&self.bears) {
+                return vec![self.bears];
+            }
+            legal.into_iter().take(ctx.min.max(1)).collect()
+        }
+    }
+
+    #[test]
+    fn test_braids_punishes_only_the_opponent_who_declines() {
+        use crate::cards::definitions::{basic_swamp, grizzly_bears};
+        use crate::events::phase::BeginningOfEndStepEvent;
+        use crate::game_loop::{put_triggers_on_stack, resolve_stack_entry_with};
+        use crate::triggers::{TriggerEvent, TriggerQueue, check_triggers};
+
+        let mut game = GameState::new(
+            vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+            20,
+        );
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let carol = PlayerId::from_index(2);
+
+        game.create_object_from_definition(&braids_arisen_nightmare(), alice, Zone::Battlefield);
+        let bears = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        game.create_object_from_definition(&basic_swamp(), alice, Zone::Library);
+        game.create_object_from_definition(&basic_swamp(), alice, Zone::Library);
+
+        // Bob has a land and a creature; only the creature shares a type.
+        let bob_land = game.create_object_from_definition(&basic_swamp(), bob, Zone::Battlefield);
+        let bob_bears =
+            game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+        let carol_bears =
+            game.create_object_from_definition(&grizzly_bears(), carol, Zone::Battlefield);
+
+        let event = TriggerEvent::new_with_provenance(
+            BeginningOfEndStepEvent::new(alice),
+            crate::provenance::ProvNodeId::default(),
+        );
+        let mut trigger_queue = TriggerQueue::new();
+        for entry in check_triggers(&game, &event) {
+            trigger_queue.add(entry);
+        }
+        put_triggers_on_stack(&mut game, &mut trigger_queue).unwrap();
+        let hand_before = game.player(alice).unwrap().hand.len();
+
+        let mut dm = BraidsTableDecisions { bears };
+        resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert!(game.object(bears).is_none(), "Alice sacrificed her Bears");
+        assert!(
+            game.object(bob_bears).is_none(),
+            "Bob sacrificed a creature"
+        );
+        assert!(
+            game.object(bob_land).is_some(),
+            "a land doesn't share a type"
+        );
+        assert!(
+            game.object(carol_bears).is_some(),
+            "Carol kept her creature"
+        );
+
+        assert_eq!(game.player(bob).unwrap().life, 20);
+        assert_eq!(game.player(carol).unwrap().life, 18);
+        assert_eq!(game.player(alice).unwrap().life, 20);
+        assert_eq!(game.player(alice).unwrap().hand.len(), hand_before + 1);
+    }
 }
@@ -66,6 +66,18 @@ impl CardDefinitionBuilder {
         Self(self.0.saga(max_chapters))
     }
 
+    pub(crate) fn split_with(self, other_half: impl Into<String>) -> Self {
+        Self(self.0.split_with(other_half))
+    }
+
+    pub(crate) fn fuse(self) -> Self {
+        Self(self.0.fuse())
+    }
+
+    pub(crate) fn aftermath(self) -> Self {
+        Self(self.0.aftermath())
+    }
+
     pub(crate) fn parse_text(
         self,
         text: impl Into<String>,
@@ -0,0 +1,135 @@
+//! Cut // Ribbons card definition.
+//!
+//! This is a split card with aftermath. Cut is cast from hand as normal;
+//! Ribbons can only be cast from the graveyard, and is exiled afterwards.
+
+use super::CardDefinitionBuilder;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::CardType;
+
+/// Cut (first half) - {1}{R}
+/// Sorcery
+/// Cut deals 4 damage to target creature.
+pub fn cut() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Cut")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(1)],
+            vec![ManaSymbol::Red],
+        ]))
+        .card_types(vec![CardType::Sorcery])
+        .split_with("Ribbons")
+        .aftermath()
+        .parse_text("Cut deals 4 damage to target creature.")
+        .expect("Cut text should be supported")
+}
+
+/// Ribbons (aftermath half) - {X}{B}{B}
+/// Sorcery
+/// Aftermath (Cast this spell only from your graveyard. Then exile it.)
+/// Each opponent loses X life.
+pub fn ribbons() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Ribbons")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::X],
+            vec![ManaSymbol::Black],
+            vec![ManaSymbol::Black],
+        ]))
+        .card_types(vec![CardType::Sorcery])
+        .split_with("Cut")
+        .aftermath()
+        .parse_text("Each opponent loses X life.")
+        .expect("Ribbons text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alternative_cast::CastingMethod;
+    use crate::decision::{LegalAction, compute_legal_actions};
+    use crate::ids::PlayerId;
+    use crate::zone::Zone;
+
+    fn casts_of(actions: &[LegalAction]) -> Vec<(Zone, CastingMethod)> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                LegalAction::CastSpell {
+                    from_zone,
+                    casting_method,
+                    ..
+                } => Some((*from_zone, casting_method.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn setup_with_black_and_red_mana() -> crate::game_state::GameState {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let pool = &mut game.player_mut(alice).unwrap().mana_pool;
+        pool.add(ManaSymbol::Black, 3);
+        pool.add(ManaSymbol::Red, 2);
+        game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            bob,
+            Zone::Battlefield,
+        );
+
+        // Start in Alice's main phase so sorceries are castable.
+        game.turn.phase = crate::Phase::FirstMain;
+        game.turn.step = None;
+        game.turn.active_player = alice;
+        game.turn.priority_player = Some(alice);
+        game
+    }
+
+    #[test]
+    fn test_cut_ribbons_halves_are_linked_with_aftermath() {
+        let cut = cut();
+        let ribbons = ribbons();
+
+        assert_eq!(cut.card.other_face_name.as_deref(), Some("Ribbons"));
+        assert_eq!(ribbons.card.other_face_name.as_deref(), Some("Cut"));
+        assert!(cut.has_aftermath && ribbons.has_aftermath);
+        assert!(!cut.has_fuse);
+    }
+
+    #[test]
+    fn test_ribbons_cannot_be_cast_from_hand() {
+        let mut game = setup_with_black_and_red_mana();
+        let alice = PlayerId::from_index(0);
+        game.create_object_from_definition(&cut(), alice, Zone::Hand);
+
+        assert_eq!(
+            casts_of(&compute_legal_actions(&game, alice)),
+            vec![(Zone::Hand, CastingMethod::Normal)],
+            "only Cut should be castable from hand"
+        );
+    }
+
+    #[test]
+    fn test_ribbons_can_be_cast_from_graveyard_but_cut_cannot() {
+        let mut game = setup_with_black_and_red_mana();
+        let alice = PlayerId::from_index(0);
+        game.create_object_from_definition(&cut(), alice, Zone::Graveyard);
+
+        assert_eq!(
+            casts_of(&compute_legal_actions(&game, alice)),
+            vec![(Zone::Graveyard, CastingMethod::SplitOtherHalf)],
+            "only Ribbons should be castable from the graveyard"
+        );
+    }
+
+    #[test]
+    fn test_ribbons_in_an_opponents_graveyard_is_not_castable() {
+        let mut game = setup_with_black_and_red_mana();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.create_object_from_definition(&cut(), bob, Zone::Graveyard);
+
+        assert!(casts_of(&compute_legal_actions(&game, alice)).is_empty());
+    }
+}
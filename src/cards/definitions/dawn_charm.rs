@@ -98,6 +98,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
         id
@@ -0,0 +1,132 @@
+//! Laboratory Maniac card definition.
+
+use super::CardDefinitionBuilder;
+use crate::card::PowerToughness;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::{CardType, Subtype};
+
+/// Laboratory Maniac - {2}{U}
+/// Creature — Human Wizard
+/// 2/2
+/// If you would draw a card while your library has no cards in it, you win the game instead.
+pub fn laboratory_maniac() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Laboratory Maniac")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(2)],
+            vec![ManaSymbol::Blue],
+        ]))
+        .card_types(vec![CardType::Creature])
+        .subtypes(vec![Subtype::Human, Subtype::Wizard])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .parse_text(
+            "If you would draw a card while your library has no cards in it, you win the game instead.",
+        )
+        .expect("Card text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effect::Effect;
+    use crate::executor::{ExecutionContext, execute_effect};
+    use crate::game_state::GameState;
+    use crate::ids::PlayerId;
+    use crate::zone::Zone;
+
+    /// Alice controls Laboratory Maniac and has `library_size` cards left.
+    fn setup(library_size: usize) -> GameState {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+
+        game.create_object_from_definition(&laboratory_maniac(), alice, Zone::Battlefield);
+        for _ in 0..library_size {
+            game.create_object_from_definition(
+                &crate::cards::definitions::grizzly_bears(),
+                alice,
+                Zone::Library,
+            );
+        }
+        game
+    }
+
+    fn alice_draws(game: &mut GameState, count: u32) {
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        execute_effect(game, &Effect::draw(count), &mut ctx).unwrap();
+    }
+
+    #[test]
+    fn laboratory_maniac_wins_instead_of_drawing_from_an_empty_library() {
+        let mut game = setup(0);
+
+        alice_draws(&mut game, 1);
+
+        let alice = game.player(PlayerId::from_index(0)).unwrap();
+        assert!(!alice.has_lost);
+        assert!(!alice.drew_from_empty_library);
+        assert!(game.player(PlayerId::from_index(1)).unwrap().has_lost);
+    }
+
+    #[test]
+    fn laboratory_maniac_draws_the_last_card_before_winning() {
+        let mut game = setup(1);
+
+        alice_draws(&mut game, 2);
+
+        let alice = game.player(PlayerId::from_index(0)).unwrap();
+        assert_eq!(alice.hand.len(), 1, "the last card is drawn normally");
+        assert!(!alice.drew_from_empty_library);
+        assert!(game.player(PlayerId::from_index(1)).unwrap().has_lost);
+    }
+
+    #[test]
+    fn laboratory_maniac_does_nothing_while_cards_remain() {
+        let mut game = setup(2);
+
+        alice_draws(&mut game, 1);
+
+        assert_eq!(game.player(PlayerId::from_index(0)).unwrap().hand.len(), 1);
+        assert!(!game.player(PlayerId::from_index(1)).unwrap().has_lost);
+    }
+
+    #[test]
+    fn laboratory_maniac_wins_from_the_draw_step() {
+        let mut game = setup(0);
+        game.turn.active_player = PlayerId::from_index(0);
+
+        crate::turn::execute_draw_step(&mut game);
+
+        assert!(
+            !game
+                .player(PlayerId::from_index(0))
+                .unwrap()
+                .drew_from_empty_library
+        );
+        assert!(game.player(PlayerId::from_index(1)).unwrap().has_lost);
+    }
+
+    #[test]
+    fn laboratory_maniac_is_stopped_by_an_opponents_platinum_angel() {
+        let mut game = setup(0);
+        let bob = PlayerId::from_index(1);
+        game.create_object_from_definition(
+            &crate::cards::definitions::platinum_angel(),
+            bob,
+            Zone::Battlefield,
+        );
+        game.update_cant_effects();
+
+        alice_draws(&mut game, 1);
+
+        let alice = game.player(PlayerId::from_index(0)).unwrap();
+        assert!(!game.player(bob).unwrap().has_lost);
+        assert!(!alice.has_lost);
+        assert!(
+            !alice.drew_from_empty_library,
+            "the draw is still replaced, so Alice doesn't lose for drawing either"
+        );
+    }
+}
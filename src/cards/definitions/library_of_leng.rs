@@ -273,6 +273,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
@@ -359,6 +360,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
@@ -486,6 +488,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
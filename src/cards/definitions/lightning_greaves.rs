@@ -202,17 +202,15 @@ mod tests {
 
     #[test]
     fn test_equipped_creature_has_shroud() {
-        let mut game = setup_game();
-        let alice = PlayerId::from_index(0);
-
-        // Create a creature and attach the greaves
-        let creature_id = create_creature(&mut game, "Soldier", vec![Subtype::Soldier], alice);
-        let equipment_id = create_equipment(&mut game, alice);
-        attach_equipment(&mut game, equipment_id, creature_id);
+        let (game, objects) = crate::GameStateBuilder::new()
+            .player("Alice")
+            .battlefield(["Grizzly Bears", "Lightning Greaves -> Grizzly Bears"])
+            .build()
+            .expect("game should build");
 
         // Get calculated characteristics through the continuous effect system
         let chars = game
-            .calculated_characteristics(creature_id)
+            .calculated_characteristics(objects.id("Grizzly Bears"))
             .expect("Should calculate characteristics");
 
         // The creature should have shroud
@@ -230,17 +228,15 @@ mod tests {
 
     #[test]
     fn test_equipped_creature_has_haste() {
-        let mut game = setup_game();
-        let alice = PlayerId::from_index(0);
-
-        // Create a creature and attach the greaves
-        let creature_id = create_creature(&mut game, "Soldier", vec![Subtype::Soldier], alice);
-        let equipment_id = create_equipment(&mut game, alice);
-        attach_equipment(&mut game, equipment_id, creature_id);
+        let (game, objects) = crate::GameStateBuilder::new()
+            .player("Alice")
+            .battlefield(["Grizzly Bears", "Lightning Greaves -> Grizzly Bears"])
+            .build()
+            .expect("game should build");
 
         // Get calculated characteristics through the continuous effect system
         let chars = game
-            .calculated_characteristics(creature_id)
+            .calculated_characteristics(objects.id("Grizzly Bears"))
             .expect("Should calculate characteristics");
 
         // The creature should have haste
@@ -16,3 +16,89 @@ pub fn mana_tithe() -> CardDefinition {
         .parse_text("Counter target spell unless its controller pays {1}.")
         .expect("Card text should be supported")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{BooleanContext, SelectOptionsContext};
+    use crate::game_state::{GameState, StackEntry, Target};
+    use crate::ids::{ObjectId, PlayerId};
+    use crate::zone::Zone;
+
+    /// Answers the "pay?" prompt, then taps whatever it's offered until it can pay.
+    struct TithePayer {
+        pays: bool,
+    }
+
+    impl DecisionMaker for TithePayer {
+        fn decide_boolean(&mut self, _game: &GameState, _ctx: &BooleanContext) -> bool {
+            self.pays
+        }
+
+        fn decide_options(&mut self, _game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+            let pay = ctx
+                .options
+                .iter()
+                .find(|opt| opt.description == "Pay mana cost")
+                .unwrap_or(&ctx.options[0]);
+            vec![pay.index]
+        }
+    }
+
+    /// Bob's Grizzly Bears is on the stack with Alice's Mana Tithe above it.
+    /// Bob has an untapped Plains and no floating mana.
+    fn setup() -> (GameState, ObjectId, ObjectId) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let plains = game.create_object_from_definition(
+            &crate::cards::definitions::basic_plains(),
+            bob,
+            Zone::Battlefield,
+        );
+        let bears = game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            bob,
+            Zone::Stack,
+        );
+        game.stack.push(StackEntry::new(bears, bob));
+        let tithe = game.create_object_from_definition(&mana_tithe(), alice, Zone::Stack);
+        game.stack
+            .push(StackEntry::new(tithe, alice).with_targets(vec![Target::Object(bears)]));
+
+        (game, bears, plains)
+    }
+
+    #[test]
+    fn mana_tithe_is_paid_by_tapping_a_land_during_resolution() {
+        let (mut game, bears, plains) = setup();
+
+        let mut dm = TithePayer { pays: true };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert!(game.is_tapped(plains), "Bob should tap the Plains to pay");
+        assert_eq!(game.stack.len(), 1);
+        assert_eq!(
+            game.stack[0].object_id, bears,
+            "the Bears should resolve later"
+        );
+        assert_eq!(game.object(bears).unwrap().zone, Zone::Stack);
+    }
+
+    #[test]
+    fn mana_tithe_counters_when_the_player_declines() {
+        let (mut game, bears, plains) = setup();
+
+        let mut dm = TithePayer { pays: false };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert!(!game.is_tapped(plains));
+        assert!(game.stack.is_empty());
+        assert!(
+            game.object(bears).is_none(),
+            "the Bears should be countered"
+        );
+    }
+}
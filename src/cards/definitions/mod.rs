@@ -7,6 +7,7 @@ mod accursed_marauder;
 mod akromas_will;
 mod amulet_of_vigor;
 mod ancient_tomb;
+mod approach_of_the_second_sun;
 mod arcane_signet;
 mod arid_mesa;
 mod ashnods_altar;
@@ -41,6 +42,7 @@ mod command_tower;
 mod counterspell;
 mod crusade;
 mod culling_the_weak;
+mod cut_ribbons;
 mod darksteel_colossus;
 mod dauthi_slayer;
 mod dawn_charm;
@@ -74,6 +76,7 @@ mod humility;
 mod innocent_blood;
 mod invisible_stalker;
 mod kami_of_false_hope;
+mod laboratory_maniac;
 mod library_of_leng;
 mod lightning_bolt;
 mod lightning_greaves;
@@ -95,10 +98,12 @@ mod mycosynth_lattice;
 mod nest_of_scarabs;
 mod ornithopter;
 mod phyrexian_tower;
+mod platinum_angel;
 mod polluted_delta;
 mod rebuff_the_wicked;
 mod reverse_engineer;
 mod rex_cyber_hound;
+mod rhystic_study;
 mod savannah_lions;
 pub mod saw_in_half;
 mod scrubland;
@@ -110,6 +115,7 @@ mod shattered_sanctum;
 mod shelter;
 mod sightless_ghoul;
 mod silhana_ledgewalker;
+mod smothering_tithe;
 mod snapcaster_mage;
 mod sol_ring;
 mod squirrel_nest;
@@ -137,6 +143,7 @@ mod verdant_catacombs;
 mod village_rites;
 mod wall_of_omens;
 mod wall_of_roots;
+mod wear_tear;
 mod white_knight;
 mod windswept_heath;
 mod yawgmoth_thran_physician;
@@ -151,6 +158,7 @@ pub use accursed_marauder::accursed_marauder;
 pub use akromas_will::akromas_will;
 pub use amulet_of_vigor::amulet_of_vigor;
 pub use ancient_tomb::ancient_tomb;
+pub use approach_of_the_second_sun::approach_of_the_second_sun;
 pub use arcane_signet::arcane_signet;
 pub use arid_mesa::arid_mesa;
 pub use ashnods_altar::ashnods_altar;
@@ -184,6 +192,7 @@ pub use command_tower::command_tower;
 pub use counterspell::counterspell;
 pub use crusade::crusade;
 pub use culling_the_weak::culling_the_weak;
+pub use cut_ribbons::{cut, ribbons};
 pub use darksteel_colossus::darksteel_colossus;
 pub use dauthi_slayer::dauthi_slayer;
 pub use dawn_charm::dawn_charm;
@@ -217,6 +226,7 @@ pub use humility::humility;
 pub use innocent_blood::innocent_blood;
 pub use invisible_stalker::invisible_stalker;
 pub use kami_of_false_hope::kami_of_false_hope;
+pub use laboratory_maniac::laboratory_maniac;
 pub use library_of_leng::library_of_leng;
 pub use lightning_bolt::lightning_bolt;
 pub use lightning_greaves::lightning_greaves;
@@ -238,10 +248,12 @@ pub use mycosynth_lattice::mycosynth_lattice;
 pub use nest_of_scarabs::nest_of_scarabs;
 pub use ornithopter::ornithopter;
 pub use phyrexian_tower::phyrexian_tower;
+pub use platinum_angel::platinum_angel;
 pub use polluted_delta::polluted_delta;
 pub use rebuff_the_wicked::rebuff_the_wicked;
 pub use reverse_engineer::reverse_engineer;
 pub use rex_cyber_hound::rex_cyber_hound;
+pub use rhystic_study::rhystic_study;
 pub use savannah_lions::savannah_lions;
 pub use saw_in_half::saw_in_half;
 pub use scrubland::scrubland;
@@ -253,6 +265,7 @@ pub use shattered_sanctum::shattered_sanctum;
 pub use shelter::shelter;
 pub use sightless_ghoul::sightless_ghoul;
 pub use silhana_ledgewalker::silhana_ledgewalker;
+pub use smothering_tithe::smothering_tithe;
 pub use snapcaster_mage::snapcaster_mage;
 pub use sol_ring::sol_ring;
 pub use squirrel_nest::squirrel_nest;
@@ -280,6 +293,7 @@ pub use verdant_catacombs::verdant_catacombs;
 pub use village_rites::village_rites;
 pub use wall_of_omens::wall_of_omens;
 pub use wall_of_roots::wall_of_roots;
+pub use wear_tear::{tear, wear};
 pub use white_knight::white_knight;
 pub use windswept_heath::windswept_heath;
 pub use yawgmoth_thran_physician::yawgmoth_thran_physician;
@@ -284,6 +284,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
@@ -354,6 +355,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
@@ -423,6 +425,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
@@ -485,6 +488,7 @@ mod tests {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             tagged_objects: std::collections::HashMap::new(),
+            divided_amounts: Vec::new(),
             chosen_modes: None,
         });
 
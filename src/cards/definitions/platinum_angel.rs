@@ -0,0 +1,98 @@
+//! Platinum Angel card definition.
+
+use super::CardDefinitionBuilder;
+use crate::card::PowerToughness;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::{CardType, Subtype};
+
+/// Platinum Angel - {7}
+/// Artifact Creature — Angel
+/// 4/4
+/// Flying
+/// You can't lose the game and your opponents can't win the game.
+pub fn platinum_angel() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Platinum Angel")
+        .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(7)]]))
+        .card_types(vec![CardType::Artifact, CardType::Creature])
+        .subtypes(vec![Subtype::Angel])
+        .power_toughness(PowerToughness::fixed(4, 4))
+        .parse_text("Flying\nYou can't lose the game and your opponents can't win the game.")
+        .expect("Card text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::PlayerId;
+    use crate::zone::Zone;
+
+    #[test]
+    fn platinum_angel_keeps_its_controller_in_the_game_at_zero_life() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+
+        game.create_object_from_definition(&platinum_angel(), alice, Zone::Battlefield);
+        game.update_cant_effects();
+        game.player_mut(alice).unwrap().life = 0;
+
+        crate::rules::apply_state_based_actions(&mut game);
+
+        assert!(!game.player(alice).unwrap().has_lost);
+        assert!(!game.can_win_game(PlayerId::from_index(1)));
+    }
+
+    #[test]
+    fn controller_loses_as_soon_as_a_dying_platinum_angel_leaves() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+
+        let angel = game.create_object_from_definition(&platinum_angel(), alice, Zone::Battlefield);
+        game.player_mut(alice).unwrap().life = 0;
+        game.mark_damage(angel, 4);
+
+        let mut trigger_queue = crate::triggers::TriggerQueue::new();
+        crate::game_loop::check_and_apply_sbas(&mut game, &mut trigger_queue).unwrap();
+
+        assert!(
+            game.object(angel).is_none(),
+            "lethal damage destroys the Angel"
+        );
+        assert!(
+            game.player(alice).unwrap().has_lost,
+            "the next state-based action check sees no protection"
+        );
+    }
+
+    #[test]
+    fn opponents_win_effects_are_suppressed_only_while_the_angel_is_out() {
+        use crate::effects::{EffectExecutor, WinTheGameEffect};
+        use crate::executor::ExecutionContext;
+
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let angel = game.create_object_from_definition(&platinum_angel(), alice, Zone::Battlefield);
+        game.update_cant_effects();
+
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, bob);
+        WinTheGameEffect::you()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+        assert!(!game.player(alice).unwrap().has_lost);
+        assert!(game.game_result().is_none());
+
+        game.move_object(angel, Zone::Graveyard);
+        game.update_cant_effects();
+        WinTheGameEffect::you()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+        assert!(game.player(alice).unwrap().has_lost);
+        assert_eq!(
+            game.game_result(),
+            Some(crate::decision::GameResult::Winner(bob))
+        );
+    }
+}
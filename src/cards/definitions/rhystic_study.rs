@@ -0,0 +1,130 @@
+//! Rhystic Study card definition.
+
+use super::CardDefinitionBuilder;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::CardType;
+
+/// Rhystic Study - {2}{U}
+/// Enchantment
+/// Whenever an opponent casts a spell, you may draw a card unless that player pays {1}.
+pub fn rhystic_study() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Rhystic Study")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(2)],
+            vec![ManaSymbol::Blue],
+        ]))
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "Whenever an opponent casts a spell, you may draw a card unless that player pays {1}.",
+        )
+        .expect("Card text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{BooleanContext, SelectOptionsContext};
+    use crate::events::spells::SpellCastEvent;
+    use crate::game_state::GameState;
+    use crate::ids::{ObjectId, PlayerId};
+    use crate::triggers::{TriggerEvent, TriggerQueue, check_triggers};
+    use crate::zone::Zone;
+
+    /// Bob answers the tax prompt with `bob_pays`; Alice always takes the draw.
+    struct TaxAnswers {
+        bob_pays: bool,
+        asked: Vec<PlayerId>,
+    }
+
+    impl DecisionMaker for TaxAnswers {
+        fn decide_boolean(&mut self, _game: &GameState, ctx: &BooleanContext) -> bool {
+            self.asked.push(ctx.player);
+            ctx.player != PlayerId::from_index(1) || self.bob_pays
+        }
+
+        fn decide_options(&mut self, _game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+            let pay = ctx
+                .options
+                .iter()
+                .find(|opt| opt.description == "Pay mana cost")
+                .unwrap_or(&ctx.options[0]);
+            vec![pay.index]
+        }
+    }
+
+    /// Alice controls Rhystic Study; Bob has cast Grizzly Bears with an
+    /// untapped Plains left over. Returns Bob's Plains with the trigger on the stack.
+    fn setup() -> (GameState, ObjectId) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        game.create_object_from_definition(&rhystic_study(), alice, Zone::Battlefield);
+        game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            alice,
+            Zone::Library,
+        );
+        let plains = game.create_object_from_definition(
+            &crate::cards::definitions::basic_plains(),
+            bob,
+            Zone::Battlefield,
+        );
+        let bears = game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            bob,
+            Zone::Stack,
+        );
+
+        let event = TriggerEvent::new_with_provenance(
+            SpellCastEvent::new(bears, bob, Zone::Hand),
+            crate::provenance::ProvNodeId::default(),
+        );
+        let mut trigger_queue = TriggerQueue::new();
+        for entry in check_triggers(&game, &event) {
+            trigger_queue.add(entry);
+        }
+        assert_eq!(trigger_queue.entries.len(), 1);
+        crate::game_loop::put_triggers_on_stack(&mut game, &mut trigger_queue).unwrap();
+        assert_eq!(game.stack.len(), 1);
+
+        (game, plains)
+    }
+
+    #[test]
+    fn rhystic_study_does_not_draw_when_the_caster_pays() {
+        let (mut game, plains) = setup();
+        let alice = PlayerId::from_index(0);
+        let hand_before = game.player(alice).unwrap().hand.len();
+
+        let mut dm = TaxAnswers {
+            bob_pays: true,
+            asked: Vec::new(),
+        };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert_eq!(dm.asked, vec![PlayerId::from_index(1)]);
+        assert!(game.is_tapped(plains), "Bob should tap the Plains to pay");
+        assert_eq!(game.player(alice).unwrap().hand.len(), hand_before);
+    }
+
+    #[test]
+    fn rhystic_study_draws_when_the_caster_declines() {
+        let (mut game, plains) = setup();
+        let alice = PlayerId::from_index(0);
+        let hand_before = game.player(alice).unwrap().hand.len();
+
+        let mut dm = TaxAnswers {
+            bob_pays: false,
+            asked: Vec::new(),
+        };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert_eq!(dm.asked.first(), Some(&PlayerId::from_index(1)));
+        assert!(!game.is_tapped(plains));
+        assert_eq!(game.player(alice).unwrap().hand.len(), hand_before + 1);
+    }
+}
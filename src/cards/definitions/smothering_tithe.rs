@@ -0,0 +1,138 @@
+//! Smothering Tithe card definition.
+
+use super::CardDefinitionBuilder;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::CardType;
+
+/// Smothering Tithe - {3}{W}
+/// Enchantment
+/// Whenever an opponent draws a card, that player may pay {2}. If the player
+/// doesn't, you create a Treasure token.
+pub fn smothering_tithe() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Smothering Tithe")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(3)],
+            vec![ManaSymbol::White],
+        ]))
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "Whenever an opponent draws a card, that player may pay {2}. If the player doesn't, you create a Treasure token.",
+        )
+        .expect("Card text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{BooleanContext, SelectOptionsContext};
+    use crate::events::CardsDrawnEvent;
+    use crate::game_state::GameState;
+    use crate::ids::{ObjectId, PlayerId};
+    use crate::triggers::{TriggerEvent, TriggerQueue, check_triggers};
+    use crate::zone::Zone;
+
+    /// Bob answers the tax prompt with `bob_pays`; nobody else is asked.
+    struct TaxAnswers {
+        bob_pays: bool,
+        asked: Vec<PlayerId>,
+    }
+
+    impl DecisionMaker for TaxAnswers {
+        fn decide_boolean(&mut self, _game: &GameState, ctx: &BooleanContext) -> bool {
+            self.asked.push(ctx.player);
+            self.bob_pays
+        }
+
+        fn decide_options(&mut self, _game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+            let pay = ctx
+                .options
+                .iter()
+                .find(|opt| opt.description == "Pay mana cost")
+                .unwrap_or(&ctx.options[0]);
+            vec![pay.index]
+        }
+    }
+
+    fn treasures(game: &GameState, controller: PlayerId) -> usize {
+        game.battlefield
+            .iter()
+            .filter_map(|&id| game.object(id))
+            .filter(|obj| obj.controller == controller && obj.name == "Treasure")
+            .count()
+    }
+
+    /// Alice controls Smothering Tithe; Bob has just drawn a card and has two
+    /// untapped Plains. Returns the Plains with the trigger on the stack.
+    fn setup() -> (GameState, Vec<ObjectId>) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        game.create_object_from_definition(&smothering_tithe(), alice, Zone::Battlefield);
+        let plains: Vec<_> = (0..2)
+            .map(|_| {
+                game.create_object_from_definition(
+                    &crate::cards::definitions::basic_plains(),
+                    bob,
+                    Zone::Battlefield,
+                )
+            })
+            .collect();
+        let drawn = game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            bob,
+            Zone::Hand,
+        );
+
+        let event = TriggerEvent::new_with_provenance(
+            CardsDrawnEvent::single(bob, drawn, false),
+            crate::provenance::ProvNodeId::default(),
+        );
+        let mut trigger_queue = TriggerQueue::new();
+        for entry in check_triggers(&game, &event) {
+            trigger_queue.add(entry);
+        }
+        assert_eq!(trigger_queue.entries.len(), 1);
+        crate::game_loop::put_triggers_on_stack(&mut game, &mut trigger_queue).unwrap();
+        assert_eq!(game.stack.len(), 1);
+
+        (game, plains)
+    }
+
+    #[test]
+    fn smothering_tithe_makes_no_treasure_when_the_drawer_pays() {
+        let (mut game, plains) = setup();
+
+        let mut dm = TaxAnswers {
+            bob_pays: true,
+            asked: Vec::new(),
+        };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert_eq!(dm.asked, vec![PlayerId::from_index(1)]);
+        assert!(
+            plains.iter().all(|&id| game.is_tapped(id)),
+            "Bob should tap both Plains to pay {{2}}"
+        );
+        assert_eq!(treasures(&game, PlayerId::from_index(0)), 0);
+    }
+
+    #[test]
+    fn smothering_tithe_makes_a_treasure_when_the_drawer_declines() {
+        let (mut game, plains) = setup();
+
+        let mut dm = TaxAnswers {
+            bob_pays: false,
+            asked: Vec::new(),
+        };
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).unwrap();
+
+        assert_eq!(dm.asked, vec![PlayerId::from_index(1)]);
+        assert!(plains.iter().all(|&id| !game.is_tapped(id)));
+        assert_eq!(treasures(&game, PlayerId::from_index(0)), 1);
+        assert_eq!(treasures(&game, PlayerId::from_index(1)), 0);
+    }
+}
@@ -0,0 +1,125 @@
+//! Wear // Tear card definition.
+//!
+//! This is a split card with fuse. Each half is defined separately and linked
+//! to the other by name; casting either half, or both fused, is handled by the
+//! game loop's split-card casting methods.
+
+use super::CardDefinitionBuilder;
+use crate::cards::CardDefinition;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::CardType;
+
+/// Wear (left half) - {1}{R}
+/// Instant
+/// Destroy target artifact.
+/// Fuse
+pub fn wear() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Wear")
+        .mana_cost(ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(1)],
+            vec![ManaSymbol::Red],
+        ]))
+        .card_types(vec![CardType::Instant])
+        .split_with("Tear")
+        .fuse()
+        .parse_text("Destroy target artifact.")
+        .expect("Wear text should be supported")
+}
+
+/// Tear (right half) - {W}
+/// Instant
+/// Destroy target enchantment.
+/// Fuse
+pub fn tear() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Tear")
+        .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::White]]))
+        .card_types(vec![CardType::Instant])
+        .split_with("Wear")
+        .fuse()
+        .parse_text("Destroy target enchantment.")
+        .expect("Tear text should be supported")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alternative_cast::CastingMethod;
+    use crate::card::LinkedFaceLayout;
+    use crate::decision::{LegalAction, compute_legal_actions};
+    use crate::ids::PlayerId;
+    use crate::zone::Zone;
+
+    fn split_casts_from(actions: &[LegalAction], zone: Zone) -> Vec<CastingMethod> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                LegalAction::CastSpell {
+                    from_zone,
+                    casting_method,
+                    ..
+                } if *from_zone == zone => Some(casting_method.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wear_tear_halves_are_linked_with_fuse() {
+        let wear = wear();
+        let tear = tear();
+
+        assert_eq!(wear.card.linked_face_layout, LinkedFaceLayout::Split);
+        assert_eq!(wear.card.other_face_name.as_deref(), Some("Tear"));
+        assert_eq!(tear.card.other_face_name.as_deref(), Some("Wear"));
+        assert!(wear.has_fuse && tear.has_fuse);
+        assert!(!wear.has_aftermath);
+        assert!(wear.spell_effect.is_some());
+        assert!(tear.spell_effect.is_some());
+    }
+
+    #[test]
+    fn test_wear_tear_offers_the_other_half_and_fuse_from_hand() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.create_object_from_definition(&wear(), alice, Zone::Hand);
+        game.create_object_from_definition(
+            &crate::cards::definitions::sol_ring(),
+            bob,
+            Zone::Battlefield,
+        );
+        game.create_object_from_definition(
+            &crate::cards::definitions::crusade(),
+            bob,
+            Zone::Battlefield,
+        );
+
+        // With no mana, none of the casts are affordable.
+        let actions = compute_legal_actions(&game, alice);
+        assert!(split_casts_from(&actions, Zone::Hand).is_empty());
+
+        // Tear alone is affordable with {W}; Wear and the fused spell are not.
+        game.player_mut(alice)
+            .unwrap()
+            .mana_pool
+            .add(ManaSymbol::White, 1);
+        let actions = compute_legal_actions(&game, alice);
+        assert_eq!(
+            split_casts_from(&actions, Zone::Hand),
+            vec![CastingMethod::SplitOtherHalf]
+        );
+
+        // Once both halves are affordable the normal cast covers the prompt for
+        // picking Wear, Tear or both fused.
+        game.player_mut(alice)
+            .unwrap()
+            .mana_pool
+            .add(ManaSymbol::Red, 2);
+        let actions = compute_legal_actions(&game, alice);
+        assert_eq!(
+            split_casts_from(&actions, Zone::Hand),
+            vec![CastingMethod::Normal]
+        );
+    }
+}
@@ -6,10 +6,14 @@
 //! Each card is defined in its own file under `definitions/` for easy tracking.
 
 pub mod builders;
+#[cfg(feature = "serialization")]
+pub mod data;
 pub mod definitions;
 pub mod tokens;
 
 pub use builders::{CardDefinitionBuilder, ParseAnnotations, TextSpan};
+#[cfg(feature = "serialization")]
+pub use data::{CARD_DATA_SCHEMA_VERSION, DataCard};
 pub use definitions::*;
 
 #[cfg(test)]
@@ -26,6 +30,7 @@ use crate::card::Card;
 use crate::cost::{OptionalCost, TotalCost};
 use crate::effect::Effect;
 use crate::ids::CardId;
+use crate::shared::SharedVec;
 use crate::static_abilities::StaticAbilityId;
 use crate::target::ObjectFilter;
 use std::collections::HashMap;
@@ -41,22 +46,26 @@ pub struct CardDefinition {
     pub card: Card,
 
     /// The abilities this card has
-    pub abilities: Vec<Ability>,
+    pub abilities: SharedVec<Ability>,
 
     /// For instants/sorceries: the effects when the spell resolves
-    pub spell_effect: Option<Vec<Effect>>,
+    pub spell_effect: Option<SharedVec<Effect>>,
 
     /// For Auras: what this card can enchant (used for non-target attachments)
     pub aura_attach_filter: Option<ObjectFilter>,
 
     /// Alternative casting methods (flashback, escape, etc.)
-    pub alternative_casts: Vec<AlternativeCastingMethod>,
+    pub alternative_casts: SharedVec<AlternativeCastingMethod>,
 
     /// True if this split card has fuse and may be cast as a fused spell from hand.
     pub has_fuse: bool,
 
+    /// True if this split card's second half has aftermath: it may only be cast
+    /// from a graveyard, and is exiled instead of going anywhere else afterwards.
+    pub has_aftermath: bool,
+
     /// Optional costs (kicker, buyback, etc.)
-    pub optional_costs: Vec<OptionalCost>,
+    pub optional_costs: SharedVec<OptionalCost>,
 
     /// For sagas: the maximum chapter number (typically 3)
     pub max_saga_chapter: Option<u32>,
@@ -64,7 +73,11 @@ pub struct CardDefinition {
     /// Additional non-printed costs paid while casting this spell.
     ///
     /// This is modeled as a full `TotalCost` so non-mana components can be paid
-    /// through the unified cost pipeline.
+    /// through the unified cost pipeline. Effect-backed components run while
+    /// costs are paid (Rule 601.2h), with their events caused by the cost; any
+    /// abilities they trigger wait in the trigger queue and go on the stack
+    /// above the spell once it has been cast. If the cast is cancelled, those
+    /// triggers are rolled back along with the payment itself.
     pub additional_cost: TotalCost,
 }
 
@@ -73,12 +86,13 @@ impl CardDefinition {
     pub fn new(card: Card) -> Self {
         Self {
             card,
-            abilities: Vec::new(),
+            abilities: SharedVec::new(),
             spell_effect: None,
             aura_attach_filter: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             max_saga_chapter: None,
             additional_cost: TotalCost::free(),
         }
@@ -88,12 +102,13 @@ impl CardDefinition {
     pub fn with_abilities(card: Card, abilities: Vec<Ability>) -> Self {
         Self {
             card,
-            abilities,
+            abilities: abilities.into(),
             spell_effect: None,
             aura_attach_filter: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             max_saga_chapter: None,
             additional_cost: TotalCost::free(),
         }
@@ -103,12 +118,13 @@ impl CardDefinition {
     pub fn spell(card: Card, effects: Vec<Effect>) -> Self {
         Self {
             card,
-            abilities: Vec::new(),
-            spell_effect: Some(effects),
+            abilities: SharedVec::new(),
+            spell_effect: Some(effects.into()),
             aura_attach_filter: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             max_saga_chapter: None,
             additional_cost: TotalCost::free(),
         }
@@ -118,12 +134,13 @@ impl CardDefinition {
     pub fn spell_with_abilities(card: Card, effects: Vec<Effect>, abilities: Vec<Ability>) -> Self {
         Self {
             card,
-            abilities,
-            spell_effect: Some(effects),
+            abilities: abilities.into(),
+            spell_effect: Some(effects.into()),
             aura_attach_filter: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             max_saga_chapter: None,
             additional_cost: TotalCost::free(),
         }
@@ -437,6 +454,11 @@ impl CardRegistry {
         maybe_register!(vampiric_tutor);
         maybe_register!(flooded_strand);
         maybe_register!(mana_tithe);
+        maybe_register!(rhystic_study);
+        maybe_register!(smothering_tithe);
+        maybe_register!(laboratory_maniac);
+        maybe_register!(approach_of_the_second_sun);
+        maybe_register!(platinum_angel);
         maybe_register!(marsh_flats);
         maybe_register!(polluted_delta);
         maybe_register!(rebuff_the_wicked);
@@ -484,6 +506,10 @@ impl CardRegistry {
         maybe_register!(rex_cyber_hound);
         maybe_register!(tivit_seller_of_secrets);
         maybe_register!(wall_of_roots);
+        maybe_register!(wear);
+        maybe_register!(tear);
+        maybe_register!(cut);
+        maybe_register!(ribbons);
     }
 
     /// Register a card definition.
@@ -655,6 +681,10 @@ pub fn linked_face_definition_by_name_or_id(
         return Some(definition);
     }
 
+    if let Some(definition) = name.and_then(|name| builtin_registry().get(name)) {
+        return Some(definition.clone());
+    }
+
     id.and_then(|card_id| builtin_registry().get_by_id(card_id).cloned())
 }
 
@@ -864,6 +894,39 @@ mod tests {
     #[cfg(feature = "generated-registry")]
     use crate::{game_state::GameState, ids::PlayerId};
 
+    /// Cards whose oracle text ends a paragraph with a cantrip rider ("...
+    /// Draw a card.", "... Scry 1.", "... You gain 3 life.") must keep it in
+    /// their compiled text. With `generated-registry` this audits every
+    /// generated card as well as the hand-written ones.
+    #[test]
+    fn test_registry_cards_keep_trailing_cantrip_riders() {
+        const RIDERS: [&str; 3] = ["draw a card", "scry ", "you gain "];
+        let mut audited = Vec::new();
+        let mut lost = Vec::new();
+        for def in builtin_registry().all() {
+            let compiled = crate::compiled_text::compiled_lines(def)
+                .join("\n")
+                .to_ascii_lowercase();
+            for paragraph in def.card.oracle_text.to_ascii_lowercase().lines() {
+                let Some((_, rider)) = paragraph.trim_end_matches('.').rsplit_once(". ") else {
+                    continue;
+                };
+                if !RIDERS.iter().any(|prefix| rider.starts_with(prefix)) {
+                    continue;
+                }
+                audited.push(def.card.name.clone());
+                if !compiled.contains(rider) {
+                    lost.push(format!("{}: {rider}", def.card.name));
+                }
+            }
+        }
+        assert!(
+            audited.iter().any(|name| name == "Shelter"),
+            "audit should see Shelter's draw rider, saw {audited:?}"
+        );
+        assert!(lost.is_empty(), "cantrip riders lost: {lost:#?}");
+    }
+
     #[test]
     fn test_card_definition_creation() {
         let def = llanowar_elves();
@@ -996,6 +1059,23 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "generated-registry")]
+    #[test]
+    fn generated_registry_marks_aftermath_split_cards() {
+        let mut registry = CardRegistry::new();
+        registry.ensure_cards_loaded(["Cut // Ribbons"]);
+
+        let ribbons = registry
+            .get("Ribbons")
+            .expect("aftermath half should load from generated registry");
+        assert_eq!(
+            ribbons.card.linked_face_layout,
+            crate::card::LinkedFaceLayout::Split
+        );
+        assert!(ribbons.has_aftermath);
+        assert!(!ribbons.has_fuse);
+    }
+
     #[cfg(feature = "generated-registry")]
     #[test]
     fn generated_registry_includes_flavor_name_aliases() {
@@ -17,7 +17,7 @@ fn render_definition(def: &CardDefinition) -> String {
         }
     }
 
-    let spell_effects = def.spell_effect.as_deref().unwrap_or(&[]);
+    let spell_effects = def.spell_effect.as_deref().map_or(&[][..], Vec::as_slice);
     if spell_effects.is_empty() {
         let _ = writeln!(out, "spell_effects: <none>");
     } else {
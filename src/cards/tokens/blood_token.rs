@@ -0,0 +1,44 @@
+//! Blood token definition.
+
+use crate::ability::{Ability, AbilityKind, ActivatedAbility, ActivationTiming};
+use crate::cards::{CardDefinition, CardDefinitionBuilder};
+use crate::cost::TotalCost;
+use crate::costs::Cost;
+use crate::effect::Effect;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::{CardType, Subtype};
+use crate::zone::Zone;
+
+/// Creates a Blood token.
+/// A Blood is an artifact token with
+/// "{1}, {T}, Discard a card, Sacrifice this artifact: Draw a card."
+pub fn blood_token_definition() -> CardDefinition {
+    let rummage_ability = Ability {
+        kind: AbilityKind::Activated(ActivatedAbility {
+            mana_cost: TotalCost::from_costs(vec![
+                Cost::mana(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(1)]])),
+                Cost::tap(),
+                Cost::discard(1, None),
+                Cost::sacrifice_self(),
+            ]),
+            effects: vec![Effect::draw(1)],
+            choices: vec![],
+            timing: ActivationTiming::AnyTime,
+            additional_restrictions: vec![],
+            activation_restrictions: vec![],
+            mana_output: None,
+            activation_condition: None,
+            mana_usage_restrictions: vec![],
+        }),
+        functional_zones: vec![Zone::Battlefield],
+        text: Some("{1}, {T}, Discard a card, Sacrifice this artifact: Draw a card.".to_string()),
+    };
+
+    CardDefinitionBuilder::new(CardId::new(), "Blood")
+        .token()
+        .card_types(vec![CardType::Artifact])
+        .subtypes(vec![Subtype::Blood])
+        .with_ability(rummage_ability)
+        .build()
+}
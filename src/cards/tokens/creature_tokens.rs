@@ -0,0 +1,29 @@
+//! Common vanilla creature token definitions.
+
+use crate::card::PowerToughness;
+use crate::cards::{CardDefinition, CardDefinitionBuilder};
+use crate::color::ColorSet;
+use crate::ids::CardId;
+use crate::types::{CardType, Subtype};
+
+/// Creates a 1/1 white Soldier creature token.
+pub fn soldier_token_definition() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Soldier")
+        .token()
+        .card_types(vec![CardType::Creature])
+        .subtypes(vec![Subtype::Soldier])
+        .color_indicator(ColorSet::WHITE)
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .build()
+}
+
+/// Creates a 2/2 black Zombie creature token.
+pub fn zombie_token_definition() -> CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Zombie")
+        .token()
+        .card_types(vec![CardType::Creature])
+        .subtypes(vec![Subtype::Zombie])
+        .color_indicator(ColorSet::BLACK)
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .build()
+}
@@ -0,0 +1,42 @@
+//! Food token definition.
+
+use crate::ability::{Ability, AbilityKind, ActivatedAbility, ActivationTiming};
+use crate::cards::{CardDefinition, CardDefinitionBuilder};
+use crate::cost::TotalCost;
+use crate::costs::Cost;
+use crate::effect::Effect;
+use crate::ids::CardId;
+use crate::mana::{ManaCost, ManaSymbol};
+use crate::types::{CardType, Subtype};
+use crate::zone::Zone;
+
+/// Creates a Food token.
+/// A Food is an artifact token with "{2}, {T}, Sacrifice this artifact: You gain 3 life."
+pub fn food_token_definition() -> CardDefinition {
+    let gain_life_ability = Ability {
+        kind: AbilityKind::Activated(ActivatedAbility {
+            mana_cost: TotalCost::from_costs(vec![
+                Cost::mana(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2)]])),
+                Cost::tap(),
+                Cost::sacrifice_self(),
+            ]),
+            effects: vec![Effect::gain_life(3)],
+            choices: vec![],
+            timing: ActivationTiming::AnyTime,
+            additional_restrictions: vec![],
+            activation_restrictions: vec![],
+            mana_output: None,
+            activation_condition: None,
+            mana_usage_restrictions: vec![],
+        }),
+        functional_zones: vec![Zone::Battlefield],
+        text: Some("{2}, {T}, Sacrifice this artifact: You gain 3 life.".to_string()),
+    };
+
+    CardDefinitionBuilder::new(CardId::new(), "Food")
+        .token()
+        .card_types(vec![CardType::Artifact])
+        .subtypes(vec![Subtype::Food])
+        .with_ability(gain_life_ability)
+        .build()
+}
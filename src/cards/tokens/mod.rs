@@ -1,20 +1,28 @@
 //! Shared token definitions.
 
+mod blood_token;
 mod clue_token;
+mod creature_tokens;
+mod food_token;
 mod gold_token;
 mod junk_token;
 mod lander_token;
 mod map_token;
+mod registry;
 mod role_token;
 mod shard_token;
 mod treasure_token;
 mod walker_token;
 
+pub use blood_token::blood_token_definition;
 pub use clue_token::clue_token_definition;
+pub use creature_tokens::{soldier_token_definition, zombie_token_definition};
+pub use food_token::food_token_definition;
 pub use gold_token::gold_token_definition;
 pub use junk_token::junk_token_definition;
 pub use lander_token::lander_token_definition;
 pub use map_token::map_token_definition;
+pub use registry::TokenRegistry;
 pub use role_token::{
     cursed_role_token_definition, monster_role_token_definition, royal_role_token_definition,
     sorcerer_role_token_definition, wicked_role_token_definition, young_hero_role_token_definition,
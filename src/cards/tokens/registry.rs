@@ -0,0 +1,85 @@
+//! Lookup of predefined tokens by name.
+
+use crate::cards::CardDefinition;
+
+use super::{
+    blood_token_definition, clue_token_definition, food_token_definition, gold_token_definition,
+    junk_token_definition, lander_token_definition, map_token_definition, shard_token_definition,
+    soldier_token_definition, treasure_token_definition, walker_token_definition,
+    zombie_token_definition,
+};
+
+type TokenEntry = (&'static str, fn() -> CardDefinition);
+
+/// Registry of commonly created tokens, keyed by token name.
+///
+/// Token-making effects can look up "Treasure", "Food", "Clue", etc. here
+/// instead of hand-building the definition (and its abilities) each time.
+pub struct TokenRegistry;
+
+impl TokenRegistry {
+    const ENTRIES: &'static [TokenEntry] = &[
+        ("Blood", blood_token_definition),
+        ("Clue", clue_token_definition),
+        ("Food", food_token_definition),
+        ("Gold", gold_token_definition),
+        ("Junk", junk_token_definition),
+        ("Lander", lander_token_definition),
+        ("Map", map_token_definition),
+        ("Shard", shard_token_definition),
+        ("Soldier", soldier_token_definition),
+        ("Treasure", treasure_token_definition),
+        ("Walker", walker_token_definition),
+        ("Zombie", zombie_token_definition),
+    ];
+
+    /// Build the predefined token with the given name (case-insensitive).
+    pub fn get(name: &str) -> Option<CardDefinition> {
+        let name = name.trim();
+        Self::ENTRIES
+            .iter()
+            .find(|(entry, _)| entry.eq_ignore_ascii_case(name))
+            .map(|(_, definition)| definition())
+    }
+
+    /// Names of all registered tokens.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        Self::ENTRIES.iter().map(|(name, _)| *name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenRegistry;
+    use crate::ability::AbilityKind;
+    use crate::types::{CardType, Subtype};
+
+    #[test]
+    fn registry_builds_each_named_token() {
+        for name in TokenRegistry::names() {
+            let token = TokenRegistry::get(name).expect("registered token");
+            assert_eq!(token.name(), name);
+            assert!(token.card.is_token, "{name} should be a token");
+        }
+        assert!(TokenRegistry::get("treasure").is_some());
+        assert!(TokenRegistry::get("Dragon").is_none());
+    }
+
+    #[test]
+    fn food_and_blood_have_real_activated_abilities() {
+        for (name, subtype) in [("Food", Subtype::Food), ("Blood", Subtype::Blood)] {
+            let token = TokenRegistry::get(name).expect("registered token");
+            assert!(token.card.card_types.contains(&CardType::Artifact));
+            assert!(token.card.subtypes.contains(&subtype));
+            let [ability] = token.abilities.as_slice() else {
+                panic!("{name} should have exactly one ability");
+            };
+            let AbilityKind::Activated(activated) = &ability.kind else {
+                panic!("{name} ability should be activated");
+            };
+            assert!(!activated.is_mana_ability());
+            assert!(activated.has_tap_cost());
+            assert!(activated.has_sacrifice_self_cost());
+        }
+    }
+}
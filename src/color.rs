@@ -1,4 +1,8 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Color {
     White,
     Blue,
@@ -51,6 +55,11 @@ impl Color {
 
 /// A set of colors represented as bitflags for efficient operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "Vec<Color>", from = "Vec<Color>")
+)]
 pub struct ColorSet(u8);
 
 impl ColorSet {
@@ -131,6 +140,21 @@ impl FromIterator<Color> for ColorSet {
     }
 }
 
+impl From<Vec<Color>> for ColorSet {
+    fn from(colors: Vec<Color>) -> Self {
+        colors.into_iter().collect()
+    }
+}
+
+impl From<ColorSet> for Vec<Color> {
+    fn from(set: ColorSet) -> Self {
+        Color::ALL
+            .into_iter()
+            .filter(|color| set.contains(*color))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
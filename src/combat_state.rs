@@ -39,6 +39,10 @@ pub struct AttackerInfo {
 
 /// The target of an attack.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum AttackTarget {
     /// Attacking a player.
     Player(PlayerId),
@@ -265,6 +269,32 @@ pub fn end_combat(combat: &mut CombatState) {
     combat.damage_assignment_order.clear();
 }
 
+/// Removes a creature from combat, whether it is attacking or blocking (rule 506.4).
+///
+/// Attackers lose their blocker assignments; blockers are dropped from every
+/// attacker's blocker list and damage assignment order. Returns true if the
+/// creature was in combat.
+pub fn remove_from_combat(combat: &mut CombatState, creature: ObjectId) -> bool {
+    let was_attacking = is_attacking(combat, creature);
+    let was_blocking = is_blocking(combat, creature);
+
+    if was_attacking {
+        combat.attackers.retain(|info| info.creature != creature);
+        combat.blockers.remove(&creature);
+        combat.damage_assignment_order.remove(&creature);
+    }
+    if was_blocking {
+        for blockers in combat.blockers.values_mut() {
+            blockers.retain(|id| *id != creature);
+        }
+        for order in combat.damage_assignment_order.values_mut() {
+            order.retain(|id| *id != creature);
+        }
+    }
+
+    was_attacking || was_blocking
+}
+
 fn battlefield_static_abilities(game: &GameState) -> Vec<StaticAbility> {
     let all_effects = game.all_continuous_effects();
     let mut out = Vec::new();
@@ -422,7 +452,7 @@ pub fn declare_attackers(
         // Must be able to attack (no defender, no summoning sickness unless haste, etc.)
         // Check both rules-based restrictions and effect-based restrictions.
         if !can_attack_defending_player(creature, defending_player, game)
-            || !game.can_attack(*creature_id)
+            || !game.can_attack_target(*creature_id, target)
         {
             return Err(CombatError::CreatureCannotAttack(*creature_id));
         }
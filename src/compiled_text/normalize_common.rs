@@ -38,6 +38,7 @@ pub(super) fn describe_player_filter(filter: &PlayerFilter) -> String {
         PlayerFilter::Defending => "the defending player".to_string(),
         PlayerFilter::Attacking => "the attacking player".to_string(),
         PlayerFilter::DamagedPlayer => "the damaged player".to_string(),
+        PlayerFilter::TriggeringPlayer => "that player".to_string(),
         PlayerFilter::EffectController => "the player who cast this spell".to_string(),
         PlayerFilter::Teammate => "a teammate".to_string(),
         PlayerFilter::IteratedPlayer => "that player".to_string(),
@@ -5767,6 +5768,14 @@ pub(crate) fn describe_value(value: &Value) -> String {
         Value::ColorsAmong(filter) => {
             format!("the number of {}", describe_colors_among(filter))
         }
+        Value::CardTypesAmong(filter) => format!(
+            "the number of card types among {}",
+            describe_count_filter_value_subject(filter)
+        ),
+        Value::ManaValuesAmong(filter) => format!(
+            "the number of different mana values among {}",
+            describe_count_filter_value_subject(filter)
+        ),
         Value::DistinctNames(filter) => format!(
             "the number of differently named {}",
             describe_count_filter_value_subject(filter)
@@ -5980,6 +5989,7 @@ pub(crate) fn describe_value(value: &Value) -> String {
             format!("how many times optional cost '{label}' was paid")
         }
         Value::KickCount => "how many times this spell was kicked".to_string(),
+        Value::TargetCount => "the number of targets".to_string(),
         Value::CountersOnSource(counter_type) => format!(
             "the number of {} counter(s) on this source",
             counter_type.description()
@@ -6700,6 +6710,7 @@ pub(super) fn describe_until(until: &Until) -> String {
         Until::Forever => "forever".to_string(),
         Until::EndOfTurn => "until end of turn".to_string(),
         Until::YourNextTurn => "until your next turn".to_string(),
+        Until::YourNextEndStep => "until your next end step".to_string(),
         Until::ControllersNextUntapStep => "during its controller's next untap step".to_string(),
         Until::EndOfCombat => "until end of combat".to_string(),
         Until::ThisLeavesTheBattlefield => {
@@ -6859,6 +6870,21 @@ pub(super) fn describe_restriction(restriction: &crate::effect::Restriction) ->
         crate::effect::Restriction::Attack(filter) => {
             format!("{} can't attack", filter.description())
         }
+        crate::effect::Restriction::AttackPlayer {
+            attackers,
+            defenders,
+            include_planeswalkers,
+        } => {
+            let players = describe_player_set_filter(defenders);
+            if *include_planeswalkers {
+                format!(
+                    "{} can't attack {players} or planeswalkers {players} control",
+                    attackers.description()
+                )
+            } else {
+                format!("{} can't attack {players}", attackers.description())
+            }
+        }
         crate::effect::Restriction::AttackAlone(filter) => {
             format!("{} can't attack alone", filter.description())
         }
@@ -6909,6 +6935,13 @@ pub(super) fn describe_restriction(restriction: &crate::effect::Restriction) ->
         crate::effect::Restriction::BeCountered(filter) => {
             format!("{} can't be countered", filter.description())
         }
+        crate::effect::Restriction::BeCounteredBy { spells, counterers } => {
+            format!(
+                "{} can't be countered by spells or abilities {} control",
+                spells.description(),
+                describe_player_set_filter(counterers)
+            )
+        }
         crate::effect::Restriction::Transform(filter) => {
             format!("{} can't transform", filter.description())
         }
@@ -6977,6 +7010,13 @@ pub(super) fn describe_colors_among(filter: &ObjectFilter) -> String {
     format!("color among {}", describe_for_each_filter(filter))
 }
 
+pub(super) fn describe_card_types_among(filter: &ObjectFilter) -> String {
+    format!(
+        "card type among {}",
+        describe_count_filter_value_subject(filter)
+    )
+}
+
 pub(super) fn describe_effect_predicate(predicate: &EffectPredicate) -> String {
     match predicate {
         EffectPredicate::Succeeded => "succeeded".to_string(),
@@ -7407,10 +7447,17 @@ pub(super) fn describe_condition(condition: &Condition) -> String {
         }
         Condition::AttackedThisTurn => "you attacked this turn".to_string(),
         Condition::OpponentLostLifeThisTurn => "an opponent lost life this turn".to_string(),
+        Condition::YouGainedLifeThisTurn => "you gained life this turn".to_string(),
         Condition::PermanentLeftBattlefieldUnderYourControlThisTurn => {
             "a permanent left the battlefield under your control this turn".to_string()
         }
         Condition::SourceWasCast => "you cast it".to_string(),
+        Condition::ThisSpellWasCastFromZone(zone) => {
+            format!("this spell was cast from your {zone}")
+        }
+        Condition::YouCastAnotherSpellNamedThisGame(name) => {
+            format!("you've cast another spell named {name} this game")
+        }
         Condition::PlayerTappedLandForManaThisTurn { player } => {
             format!(
                 "{} tapped a land for mana this turn",
@@ -1518,7 +1518,8 @@ pub(super) fn pluralize_noun_phrase(phrase: &str) -> String {
         base = stripped.trim_end();
         trailing = ".";
     }
-    if base.contains(" or ") {
+    // "or more"/"or fewer" belong to a numeric qualifier, not a list of nouns.
+    if base.contains(" or ") && !base.contains(" or more ") && !base.contains(" or fewer ") {
         let parts = base
             .split(" or ")
             .map(str::trim)
@@ -2252,6 +2253,10 @@ pub(super) fn describe_draw_for_each(draw: &crate::effects::DrawCardsEffect) ->
             "{player} {verb} a card for each {}",
             describe_colors_among(filter)
         )),
+        Value::CardTypesAmong(filter) => Some(format!(
+            "{player} {verb} a card for each {}",
+            describe_card_types_among(filter)
+        )),
         _ => None,
     }
 }
@@ -2261,6 +2266,7 @@ pub(super) fn describe_create_for_each_count(value: &Value) -> Option<String> {
         Value::Count(filter) => Some(describe_for_each_filter(filter)),
         Value::BasicLandTypesAmong(filter) => Some(describe_basic_land_types_among(filter)),
         Value::ColorsAmong(filter) => Some(describe_colors_among(filter)),
+        Value::CardTypesAmong(filter) => Some(describe_card_types_among(filter)),
         Value::ColorsOfManaSpentToCastThisSpell => {
             Some("color of mana spent to cast this spell".to_string())
         }
@@ -4867,6 +4873,13 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
         return format!("Destroy {}", describe_choose_spec(&destroy.spec));
     }
     if let Some(deal_damage) = effect.downcast_ref::<crate::effects::DealDamageEffect>() {
+        if deal_damage.divided {
+            return format!(
+                "Deal {} damage divided as you choose among {}",
+                describe_value(&deal_damage.amount),
+                describe_choose_spec(&deal_damage.target)
+            );
+        }
         if let Value::PowerOf(source) | Value::ToughnessOf(source) = &deal_damage.amount {
             let mut subject = describe_choose_spec(source);
             if subject == "this source" {
@@ -5243,12 +5256,17 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
         effect.downcast_ref::<crate::effects::ReturnAllToBattlefieldEffect>()
     {
         return format!(
-            "Return all {} to the battlefield{}",
+            "Return all {} to the battlefield{}{}",
             describe_for_each_filter(&return_all_to_battlefield.filter),
             if return_all_to_battlefield.tapped {
                 " tapped"
             } else {
                 ""
+            },
+            if return_all_to_battlefield.controller == crate::effects::BattlefieldController::You {
+                " under your control"
+            } else {
+                ""
             }
         );
     }
@@ -6088,6 +6106,11 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
             Value::Count(filter) => describe_for_each_count_filter(filter),
             Value::BasicLandTypesAmong(filter) => describe_basic_land_types_among(filter),
             Value::ColorsAmong(filter) => describe_colors_among(filter),
+            Value::CardTypesAmong(filter) => describe_card_types_among(filter),
+            Value::CardTypesInGraveyard(player) => format!(
+                "card type among cards in {} graveyard",
+                describe_possessive_player_filter(player)
+            ),
             _ => describe_value(&modify_pt_each.count),
         };
         return format!(
@@ -6101,6 +6124,18 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
         );
     }
     if let Some(gain_control) = effect.downcast_ref::<crate::effects::GainControlEffect>() {
+        if gain_control.player != PlayerFilter::You {
+            let mut text = format!(
+                "{} gains control of {}",
+                describe_player_filter(&gain_control.player),
+                describe_choose_spec(&gain_control.target)
+            );
+            if !matches!(gain_control.duration, Until::Forever) {
+                text.push(' ');
+                text.push_str(&describe_until(&gain_control.duration));
+            }
+            return text;
+        }
         return format!(
             "Gain control of {} {}",
             describe_choose_spec(&gain_control.target),
@@ -6774,6 +6809,25 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
             describe_choose_spec(&remove_up_to_any.target)
         );
     }
+    if let Some(piles) = effect.downcast_ref::<crate::effects::FactOrFictionEffect>() {
+        let (count_text, noun, _) = describe_look_count_and_noun(&piles.count);
+        let separator = describe_player_filter(&piles.separator);
+        let put = if piles.chooser == PlayerFilter::You {
+            "Put one pile into your hand".to_string()
+        } else {
+            let chooser = describe_player_filter(&piles.chooser);
+            format!(
+                "{} {} one of those piles. Put that pile into your hand",
+                capitalize_first(&chooser),
+                player_verb(&chooser, "choose", "chooses")
+            )
+        };
+        return format!(
+            "Reveal the top {count_text} {noun} of your library. {} {} those cards into two piles. {put} and the other into your graveyard",
+            capitalize_first(&separator),
+            player_verb(&separator, "separate", "separates")
+        );
+    }
     if let Some(surveil) = effect.downcast_ref::<crate::effects::SurveilEffect>() {
         if surveil.player == PlayerFilter::You {
             return format!("Surveil {}", describe_value(&surveil.count));
@@ -6925,7 +6979,14 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
         };
     }
     if let Some(connive) = effect.downcast_ref::<crate::effects::ConniveEffect>() {
-        return format!("{} connives", describe_choose_spec(&connive.target));
+        if matches!(connive.count, Value::Fixed(1)) {
+            return format!("{} connives", describe_choose_spec(&connive.target));
+        }
+        return format!(
+            "{} connives {}",
+            describe_choose_spec(&connive.target),
+            describe_value(&connive.count)
+        );
     }
     if let Some(goad) = effect.downcast_ref::<crate::effects::GoadEffect>() {
         return format!("Goad {}", describe_goad_target(&goad.target));
@@ -6993,33 +7054,53 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
         return format!("Monstrosity {}", describe_value(&monstrosity.n));
     }
     if let Some(copy_spell) = effect.downcast_ref::<crate::effects::CopySpellEffect>() {
-        if matches!(copy_spell.target, ChooseSpec::Source)
+        let mut text = if matches!(copy_spell.target, ChooseSpec::Source)
             && matches!(
                 copy_spell.count,
                 Value::SpellsCastBeforeThisTurn(PlayerFilter::You)
-            )
-        {
-            return "Copy this spell for each spell cast before it this turn".to_string();
-        }
-        if matches!(copy_spell.target, ChooseSpec::Source)
+            ) {
+            "Copy this spell for each spell cast before it this turn".to_string()
+        } else if matches!(copy_spell.target, ChooseSpec::Source)
             && matches!(copy_spell.count, Value::Fixed(1))
         {
-            return "Copy this spell".to_string();
-        }
-        if matches!(copy_spell.target, ChooseSpec::Source)
+            "Copy this spell".to_string()
+        } else if matches!(copy_spell.target, ChooseSpec::Source)
             && let Value::Count(filter) = &copy_spell.count
         {
             let mut each_filter = filter.description();
             if each_filter.ends_with('s') {
                 each_filter = each_filter.trim_end_matches('s').to_string();
             }
-            return format!("Copy this spell for each {each_filter}");
+            format!("Copy this spell for each {each_filter}")
+        } else {
+            format!(
+                "Copy {} {} time(s)",
+                describe_choose_spec(&copy_spell.target),
+                describe_value(&copy_spell.count)
+            )
+        };
+        if copy_spell.may_choose_new_targets {
+            text.push_str(". You may choose new targets for the copy");
         }
-        return format!(
-            "Copy {} {} time(s)",
-            describe_choose_spec(&copy_spell.target),
-            describe_value(&copy_spell.count)
-        );
+        return text;
+    }
+    if let Some(copy_ability) = effect.downcast_ref::<crate::effects::CopyAbilityEffect>() {
+        let copied = match &copy_ability.ability {
+            crate::effects::AbilityToCopy::Target(spec) => describe_choose_spec(spec),
+            crate::effects::AbilityToCopy::TriggeringAbility => "it".to_string(),
+        };
+        let mut text = if matches!(copy_ability.count, Value::Fixed(1)) {
+            format!("Copy {copied}")
+        } else {
+            format!(
+                "Copy {copied} {} time(s)",
+                describe_value(&copy_ability.count)
+            )
+        };
+        if copy_ability.may_choose_new_targets {
+            text.push_str(". You may choose new targets for the copy");
+        }
+        return text;
     }
     if let Some(choose_new) = effect.downcast_ref::<crate::effects::ChooseNewTargetsEffect>() {
         let chooser_text = choose_new
@@ -7291,6 +7372,19 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
             "Prevent the next time {source_text} would deal damage to {target_text} this turn"
         );
     }
+    if let Some(redirect) = effect.downcast_ref::<crate::effects::RedirectDamageEffect>() {
+        let destination = match &redirect.redirect_to {
+            crate::replacement::RedirectTarget::ToEventSourceController => "its controller",
+            crate::replacement::RedirectTarget::ToController => "you",
+            crate::replacement::RedirectTarget::ToSource => "this permanent",
+            crate::replacement::RedirectTarget::ToPlayer(_) => "that player",
+            crate::replacement::RedirectTarget::ToObject(_) => "that permanent",
+        };
+        return format!(
+            "If a source would deal damage to {} this turn, it deals that damage to {destination} instead",
+            describe_choose_spec(&redirect.target)
+        );
+    }
     if let Some(redirect_next) =
         effect.downcast_ref::<crate::effects::RedirectNextDamageToTargetEffect>()
     {
@@ -7603,6 +7697,14 @@ pub(super) fn describe_effect_impl(effect: &Effect) -> String {
             describe_player_filter(&exchange_life.player2)
         );
     }
+    if let Some(exchange_zones) = effect.downcast_ref::<crate::effects::ExchangeZonesEffect>() {
+        return format!(
+            "Exchange the {}s of {} and {}",
+            exchange_zones.zone,
+            describe_player_filter(&exchange_zones.player1),
+            describe_player_filter(&exchange_zones.player2)
+        );
+    }
     if let Some(exile_top) = effect.downcast_ref::<crate::effects::ExileTopOfLibraryEffect>() {
         return describe_exile_top_of_library(&exile_top.player, &exile_top.count, false);
     }
@@ -4,7 +4,9 @@ use crate::effects::helpers::resolve_value;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::ids::{ObjectId, PlayerId, StableId};
+use crate::object::CounterType;
 use crate::object_query::candidate_ids_for_zone;
+use crate::snapshot::ObjectSnapshot;
 use crate::target::PlayerFilter;
 use crate::zone::Zone;
 
@@ -60,6 +62,27 @@ struct SharedConditionContext<'a> {
     source: ObjectId,
     filter_source: Option<ObjectId>,
     triggering_event: Option<&'a TriggerEvent>,
+    source_snapshot: Option<&'a ObjectSnapshot>,
+}
+
+impl SharedConditionContext<'_> {
+    /// Counters on the source, falling back to last known information.
+    fn source_counter_count(&self, game: &GameState, counter_type: &CounterType) -> Option<u32> {
+        match game.object(self.source) {
+            Some(obj) => Some(obj.counters.get(counter_type).copied().unwrap_or(0)),
+            None => self
+                .source_snapshot
+                .map(|snapshot| snapshot.counters.get(counter_type).copied().unwrap_or(0)),
+        }
+    }
+
+    /// Power of the source, falling back to last known information.
+    fn source_power(&self, game: &GameState) -> Option<i32> {
+        if let Some(obj) = game.object(self.source) {
+            return game.calculated_power(self.source).or_else(|| obj.power());
+        }
+        self.source_snapshot.and_then(|snapshot| snapshot.power)
+    }
 }
 
 fn evaluate_condition_shared_core(
@@ -90,29 +113,48 @@ fn evaluate_condition_shared_core(
             ctx.filter_source,
         )),
         Condition::YourTurn => Some(game.turn.active_player == ctx.controller),
-        Condition::CreatureDiedThisTurn => Some(game.creatures_died_this_turn > 0),
-        Condition::CastSpellThisTurn => {
-            Some(game.spells_cast_this_turn.values().any(|&count| count > 0))
-        }
-        Condition::AttackedThisTurn => {
-            Some(game.players_attacked_this_turn.contains(&ctx.controller))
-        }
+        Condition::CreatureDiedThisTurn => Some(game.turn_trackers.creatures_died > 0),
+        Condition::CastSpellThisTurn => Some(
+            game.turn_trackers
+                .spells_cast
+                .values()
+                .any(|&count| count > 0),
+        ),
+        Condition::AttackedThisTurn => Some(
+            game.turn_trackers
+                .players_attacked
+                .contains(&ctx.controller),
+        ),
         Condition::OpponentLostLifeThisTurn => {
             let filter_ctx = game.filter_context_for(ctx.controller, ctx.filter_source);
             Some(
-                filter_ctx.opponents.iter().any(|opponent| {
-                    game.life_lost_this_turn.get(opponent).copied().unwrap_or(0) > 0
-                }),
+                filter_ctx
+                    .opponents
+                    .iter()
+                    .any(|&opponent| game.turn_trackers.life_lost_by(opponent) > 0),
             )
         }
+        Condition::YouGainedLifeThisTurn => {
+            Some(game.turn_trackers.life_gained_by(ctx.controller) > 0)
+        }
         Condition::PermanentLeftBattlefieldUnderYourControlThisTurn => Some(
-            game.permanents_left_battlefield_under_controller_this_turn
-                .get(&ctx.controller)
-                .copied()
-                .unwrap_or(0)
+            game.turn_trackers
+                .permanents_left_battlefield_under(ctx.controller)
                 > 0,
         ),
         Condition::SourceWasCast => Some(source_was_cast(game, ctx.source, ctx.triggering_event)),
+        Condition::ThisSpellWasCastFromZone(zone) => Some(
+            game.spells_cast_this_game
+                .iter()
+                .any(|record| record.spell == ctx.source && record.from_zone == *zone),
+        ),
+        Condition::YouCastAnotherSpellNamedThisGame(name) => {
+            Some(game.spells_cast_this_game.iter().any(|record| {
+                record.caster == ctx.controller
+                    && record.spell != ctx.source
+                    && crate::filter::names_match(&record.name, name)
+            }))
+        }
         Condition::NoSpellsWereCastLastTurn => Some(game.spells_cast_last_turn_total == 0),
         Condition::SpellsWereCastLastTurnOrMore(count) => {
             Some(game.spells_cast_last_turn_total >= *count)
@@ -146,21 +188,18 @@ fn evaluate_condition_shared_core(
             Some(distinct_colors >= *amount)
         }
         Condition::SourceHasNoCounter(counter_type) => Some(
-            game.object(ctx.source)
-                .map(|obj| obj.counters.get(counter_type).copied().unwrap_or(0) == 0)
-                .unwrap_or(false),
+            ctx.source_counter_count(game, counter_type)
+                .is_some_and(|count| count == 0),
         ),
         Condition::SourceHasCounterAtLeast {
             counter_type,
             count,
         } => Some(
-            game.object(ctx.source)
-                .map(|obj| obj.counters.get(counter_type).copied().unwrap_or(0) >= *count)
-                .unwrap_or(false),
+            ctx.source_counter_count(game, counter_type)
+                .is_some_and(|current| current >= *count),
         ),
         Condition::SourcePowerAtLeast(min_power) => Some(
-            game.calculated_power(ctx.source)
-                .or_else(|| game.object(ctx.source).and_then(|obj| obj.power()))
+            ctx.source_power(game)
                 .is_some_and(|power| power >= *min_power as i32),
         ),
         Condition::SourceIsInZone(zone) => Some(
@@ -221,8 +260,11 @@ fn assert_condition_variant_coverage(condition: &Condition) {
         Condition::CastSpellThisTurn => {}
         Condition::AttackedThisTurn => {}
         Condition::OpponentLostLifeThisTurn => {}
+        Condition::YouGainedLifeThisTurn => {}
         Condition::PermanentLeftBattlefieldUnderYourControlThisTurn => {}
         Condition::SourceWasCast => {}
+        Condition::ThisSpellWasCastFromZone(_) => {}
+        Condition::YouCastAnotherSpellNamedThisGame(_) => {}
         Condition::NoSpellsWereCastLastTurn => {}
         Condition::SpellsWereCastLastTurnOrMore(..) => {}
         Condition::TargetIsTapped => {}
@@ -334,6 +376,9 @@ pub struct ExternalEvaluationContext<'a> {
     /// - Most other checks should pass `Some(source)`.
     pub filter_source: Option<ObjectId>,
     pub triggering_event: Option<&'a TriggerEvent>,
+    /// Last known information for `source`, used by source-state conditions
+    /// once the source has left its zone.
+    pub source_snapshot: Option<&'a ObjectSnapshot>,
     pub trigger_identity: Option<TriggerIdentity>,
     pub ability_index: Option<usize>,
     pub options: ExternalEvaluationOptions,
@@ -367,6 +412,7 @@ pub fn evaluate_condition_external(
             source: ctx.source,
             filter_source: ctx.filter_source,
             triggering_event: ctx.triggering_event,
+            source_snapshot: ctx.source_snapshot,
         },
     ) {
         return result;
@@ -413,7 +459,13 @@ pub fn evaluate_condition_external(
             };
             let cast_count: u32 = players
                 .iter()
-                .map(|pid| game.spells_cast_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| {
+                    game.turn_trackers
+                        .spells_cast
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0)
+                })
                 .sum();
             cast_count >= *count
         }
@@ -600,7 +652,7 @@ pub fn evaluate_condition_external(
             if *player == PlayerFilter::IteratedPlayer {
                 filter_ctx = filter_ctx.with_iterated_player(Some(player_id));
             }
-            condition_candidate_ids_for_zone(game, filter.zone)
+            condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -626,7 +678,7 @@ pub fn evaluate_condition_external(
             if *player == PlayerFilter::IteratedPlayer {
                 filter_ctx = filter_ctx.with_iterated_player(Some(player_id));
             }
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -654,7 +706,7 @@ pub fn evaluate_condition_external(
             if *player == PlayerFilter::IteratedPlayer {
                 filter_ctx = filter_ctx.with_iterated_player(Some(player_id));
             }
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -700,7 +752,7 @@ pub fn evaluate_condition_external(
             if *player == PlayerFilter::IteratedPlayer {
                 filter_ctx = filter_ctx.with_iterated_player(Some(player_id));
             }
-            let your_count = condition_candidate_ids_for_zone(game, filter.zone)
+            let your_count = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -720,12 +772,15 @@ pub fn evaluate_condition_external(
                 if *player == PlayerFilter::IteratedPlayer {
                     other_ctx = other_ctx.with_iterated_player(Some(other_id));
                 }
-                let other_count = condition_candidate_ids_for_zone(game, filter.zone)
-                    .iter()
-                    .filter_map(|&id| game.object(id))
-                    .filter(|obj| condition_object_matches_player_zone(obj, other_id, filter.zone))
-                    .filter(|obj| filter.matches(obj, &other_ctx, game))
-                    .count();
+                let other_count =
+                    condition_candidate_ids_for_player_zone(game, other_id, filter.zone)
+                        .iter()
+                        .filter_map(|&id| game.object(id))
+                        .filter(|obj| {
+                            condition_object_matches_player_zone(obj, other_id, filter.zone)
+                        })
+                        .filter(|obj| filter.matches(obj, &other_ctx, game))
+                        .count();
                 your_count >= other_count
             })
         }
@@ -743,7 +798,7 @@ pub fn evaluate_condition_external(
                 if *player == PlayerFilter::IteratedPlayer {
                     filter_ctx = filter_ctx.with_iterated_player(Some(candidate));
                 }
-                condition_candidate_ids_for_zone(game, filter.zone)
+                condition_candidate_ids_for_player_zone(game, candidate, filter.zone)
                     .iter()
                     .filter_map(|&id| game.object(id))
                     .filter(|obj| condition_object_matches_player_zone(obj, candidate, filter.zone))
@@ -933,8 +988,11 @@ pub fn evaluate_condition_external(
         | Condition::CastSpellThisTurn
         | Condition::AttackedThisTurn
         | Condition::OpponentLostLifeThisTurn
+        | Condition::YouGainedLifeThisTurn
         | Condition::PermanentLeftBattlefieldUnderYourControlThisTurn
         | Condition::SourceWasCast
+        | Condition::ThisSpellWasCastFromZone(_)
+        | Condition::YouCastAnotherSpellNamedThisGame(_)
         | Condition::NoSpellsWereCastLastTurn
         | Condition::SpellsWereCastLastTurnOrMore(_)
         | Condition::SourceHasNoCounter(_)
@@ -1006,6 +1064,24 @@ fn condition_candidate_ids_for_zone(game: &GameState, zone: Option<Zone>) -> Vec
     candidate_ids_for_zone(game, zone)
 }
 
+/// Candidates for a "player has ... in zone" check, narrowed to the zones
+/// `condition_object_matches_player_zone` matches by owner.
+///
+/// Hands are matched by owner there but listed by holder, so they keep the
+/// full scan.
+fn condition_candidate_ids_for_player_zone(
+    game: &GameState,
+    player_id: PlayerId,
+    zone: Option<Zone>,
+) -> Vec<ObjectId> {
+    match zone {
+        Some(zone @ (Zone::Graveyard | Zone::Library | Zone::Exile | Zone::Command)) => {
+            game.objects_in(zone, player_id).collect()
+        }
+        _ => candidate_ids_for_zone(game, zone),
+    }
+}
+
 fn condition_object_matches_player_zone(
     obj: &crate::object::Object,
     player_id: PlayerId,
@@ -1042,7 +1118,7 @@ fn count_distinct_matching_powers(
     use std::collections::HashSet;
 
     let mut seen_powers = HashSet::new();
-    for obj in condition_candidate_ids_for_zone(game, filter.zone)
+    for obj in condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
         .iter()
         .filter_map(|&id| game.object(id))
         .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1110,6 +1186,7 @@ fn evaluate_condition_simple(
             source,
             filter_source: Some(source),
             triggering_event: None,
+            source_snapshot: None,
         },
     ) {
         return result;
@@ -1150,7 +1227,7 @@ fn evaluate_condition_simple(
             if *player == PlayerFilter::IteratedPlayer {
                 ctx = ctx.with_iterated_player(Some(player_id));
             }
-            condition_candidate_ids_for_zone(game, filter.zone)
+            condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1215,7 +1292,7 @@ fn evaluate_condition_simple(
             if *player == PlayerFilter::IteratedPlayer {
                 ctx = ctx.with_iterated_player(Some(player_id));
             }
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1279,7 +1356,7 @@ fn evaluate_condition_simple(
             if *player == PlayerFilter::IteratedPlayer {
                 ctx = ctx.with_iterated_player(Some(player_id));
             }
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1327,7 +1404,7 @@ fn evaluate_condition_simple(
                 if *player == PlayerFilter::IteratedPlayer {
                     ctx = ctx.with_iterated_player(Some(candidate));
                 }
-                condition_candidate_ids_for_zone(game, filter.zone)
+                condition_candidate_ids_for_player_zone(game, candidate, filter.zone)
                     .iter()
                     .filter_map(|&id| game.object(id))
                     .filter(|obj| condition_object_matches_player_zone(obj, candidate, filter.zone))
@@ -1358,7 +1435,7 @@ fn evaluate_condition_simple(
                 if *player == PlayerFilter::IteratedPlayer {
                     ctx = ctx.with_iterated_player(Some(candidate));
                 }
-                condition_candidate_ids_for_zone(game, filter.zone)
+                condition_candidate_ids_for_player_zone(game, candidate, filter.zone)
                     .iter()
                     .filter_map(|&id| game.object(id))
                     .filter(|obj| condition_object_matches_player_zone(obj, candidate, filter.zone))
@@ -1448,7 +1525,13 @@ fn evaluate_condition_simple(
             };
             let cast_count: u32 = players
                 .iter()
-                .map(|pid| game.spells_cast_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| {
+                    game.turn_trackers
+                        .spells_cast
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0)
+                })
                 .sum();
             cast_count >= *count
         }
@@ -1521,8 +1604,11 @@ fn evaluate_condition_simple(
         | Condition::CastSpellThisTurn
         | Condition::AttackedThisTurn
         | Condition::OpponentLostLifeThisTurn
+        | Condition::YouGainedLifeThisTurn
         | Condition::PermanentLeftBattlefieldUnderYourControlThisTurn
         | Condition::SourceWasCast
+        | Condition::ThisSpellWasCastFromZone(_)
+        | Condition::YouCastAnotherSpellNamedThisGame(_)
         | Condition::NoSpellsWereCastLastTurn
         | Condition::SpellsWereCastLastTurnOrMore(_)
         | Condition::SourceHasNoCounter(_)
@@ -1569,6 +1655,7 @@ fn resolve_condition_player_simple(
         | PlayerFilter::Attacking
         | PlayerFilter::Defending
         | PlayerFilter::DamagedPlayer
+        | PlayerFilter::TriggeringPlayer
         | PlayerFilter::EffectController
         | PlayerFilter::IteratedPlayer
         | PlayerFilter::TargetPlayerOrControllerOfTarget
@@ -1686,6 +1773,7 @@ fn evaluate_condition(
             source: ctx.source,
             filter_source: Some(ctx.source),
             triggering_event: ctx.triggering_event.as_ref(),
+            source_snapshot: ctx.source_snapshot.as_ref(),
         },
     ) {
         return Ok(result);
@@ -1721,11 +1809,12 @@ fn evaluate_condition(
             let player_id = crate::effects::helpers::resolve_player_filter(game, player, ctx)?;
             let mut filter_ctx = ctx.filter_context(game);
             filter_ctx.iterated_player = Some(player_id);
-            let has_matching = condition_candidate_ids_for_zone(game, filter.zone)
-                .iter()
-                .filter_map(|&id| game.object(id))
-                .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
-                .any(|obj| filter.matches(obj, &filter_ctx, game));
+            let has_matching =
+                condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
+                    .iter()
+                    .filter_map(|&id| game.object(id))
+                    .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
+                    .any(|obj| filter.matches(obj, &filter_ctx, game));
             Ok(has_matching)
         }
         Condition::PlayerOwnsCardNamedInZones {
@@ -1764,7 +1853,7 @@ fn evaluate_condition(
             let player_id = crate::effects::helpers::resolve_player_filter(game, player, ctx)?;
             let mut filter_ctx = ctx.filter_context(game);
             filter_ctx.iterated_player = Some(player_id);
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1811,7 +1900,7 @@ fn evaluate_condition(
             let player_id = crate::effects::helpers::resolve_player_filter(game, player, ctx)?;
             let mut filter_ctx = ctx.filter_context(game);
             filter_ctx.iterated_player = Some(player_id);
-            let matches = condition_candidate_ids_for_zone(game, filter.zone)
+            let matches = condition_candidate_ids_for_player_zone(game, player_id, filter.zone)
                 .iter()
                 .filter_map(|&id| game.object(id))
                 .filter(|obj| condition_object_matches_player_zone(obj, player_id, filter.zone))
@@ -1835,7 +1924,7 @@ fn evaluate_condition(
             let count_for = |candidate: PlayerId| {
                 let mut filter_ctx = ctx.filter_context(game);
                 filter_ctx.iterated_player = Some(candidate);
-                condition_candidate_ids_for_zone(game, filter.zone)
+                condition_candidate_ids_for_player_zone(game, candidate, filter.zone)
                     .iter()
                     .filter_map(|&id| game.object(id))
                     .filter(|obj| condition_object_matches_player_zone(obj, candidate, filter.zone))
@@ -1855,7 +1944,7 @@ fn evaluate_condition(
             let count_for = |candidate: PlayerId| {
                 let mut filter_ctx = ctx.filter_context(game);
                 filter_ctx.iterated_player = Some(candidate);
-                condition_candidate_ids_for_zone(game, filter.zone)
+                condition_candidate_ids_for_player_zone(game, candidate, filter.zone)
                     .iter()
                     .filter_map(|&id| game.object(id))
                     .filter(|obj| condition_object_matches_player_zone(obj, candidate, filter.zone))
@@ -1933,7 +2022,13 @@ fn evaluate_condition(
             };
             let cast_count: u32 = player_ids
                 .iter()
-                .map(|pid| game.spells_cast_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| {
+                    game.turn_trackers
+                        .spells_cast
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0)
+                })
                 .sum();
             Ok(cast_count >= *count)
         }
@@ -2342,8 +2437,11 @@ fn evaluate_condition(
         | Condition::CastSpellThisTurn
         | Condition::AttackedThisTurn
         | Condition::OpponentLostLifeThisTurn
+        | Condition::YouGainedLifeThisTurn
         | Condition::PermanentLeftBattlefieldUnderYourControlThisTurn
         | Condition::SourceWasCast
+        | Condition::ThisSpellWasCastFromZone(_)
+        | Condition::YouCastAnotherSpellNamedThisGame(_)
         | Condition::NoSpellsWereCastLastTurn
         | Condition::SpellsWereCastLastTurnOrMore(_)
         | Condition::SourceHasNoCounter(_)
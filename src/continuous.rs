@@ -11,6 +11,7 @@ use crate::effect::{Until, Value};
 use crate::ids::{ObjectId, PlayerId};
 use crate::object::{CounterType, Object};
 use crate::object_query::candidate_ids_for_filter;
+use crate::shared::SharedVec;
 use crate::static_abilities::StaticAbility;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::types::{CardType, Subtype, Supertype};
@@ -476,12 +477,52 @@ impl ContinuousEffectManager {
         self.effects.retain(|e| e.source != source);
     }
 
+    /// End every effect that gives `player` control of something.
+    pub fn remove_control_effects_for(&mut self, player: PlayerId) {
+        self.effects.retain(|e| {
+            !matches!(e.modification, Modification::ChangeController(controller) if controller == player)
+        });
+    }
+
     /// Remove all effects with duration UntilEndOfTurn.
     pub fn cleanup_end_of_turn(&mut self) {
         self.effects
             .retain(|e| !matches!(e.duration, Until::EndOfTurn));
     }
 
+    /// Remove effects whose `until` duration has just ended.
+    ///
+    /// See [`GameState::expire_duration`](crate::game_state::GameState::expire_duration)
+    /// for when each duration is expired. Source-bound durations are checked
+    /// against `game`.
+    pub fn expire(&mut self, until: &Until, game: &crate::game_state::GameState) {
+        let active_player = game.turn.active_player;
+        self.effects.retain(|effect| {
+            if effect.duration != *until {
+                return true;
+            }
+            match until {
+                Until::ControllersNextUntapStep => {
+                    // Measured from the affected permanent's controller.
+                    let controller = match &effect.applies_to {
+                        EffectTarget::Specific(id) => game
+                            .object(*id)
+                            .map_or(effect.controller, |object| object.controller),
+                        _ => effect.controller,
+                    };
+                    controller != active_player
+                }
+                Until::ThisLeavesTheBattlefield => game
+                    .object(effect.source)
+                    .is_some_and(|object| object.zone == Zone::Battlefield),
+                Until::YouStopControllingThis => game.object(effect.source).is_some_and(|object| {
+                    object.zone == Zone::Battlefield && object.controller == effect.controller
+                }),
+                _ => !until.ends_at(until, effect.controller, active_player),
+            }
+        });
+    }
+
     /// Get all effects that apply to a specific object.
     pub fn effects_for_object(&self, object_id: ObjectId) -> Vec<&ContinuousEffect> {
         self.effects
@@ -798,7 +839,7 @@ pub struct CalculatedCharacteristics {
     pub subtypes: Vec<Subtype>,
     pub supertypes: Vec<Supertype>,
     pub colors: ColorSet,
-    pub abilities: Vec<Ability>,
+    pub abilities: SharedVec<Ability>,
     /// Static abilities that this object currently has (including from effects)
     pub static_abilities: Vec<StaticAbility>,
     pub controller: PlayerId,
@@ -1109,6 +1150,7 @@ fn effect_applies_to_direct(
             attacking_player: None,
             filter_source: Some(effect.source),
             triggering_event: None,
+            source_snapshot: None,
             trigger_identity: None,
             ability_index: None,
             options: crate::condition_eval::ExternalEvaluationOptions::default(),
@@ -1402,7 +1444,7 @@ fn apply_modification_to_chars(
             chars.abilities.push(bound_ability);
         }
         Modification::SetAbilities(abilities) => {
-            chars.abilities = abilities.clone();
+            chars.abilities = abilities.clone().into();
             chars.static_abilities.clear();
             for ability in abilities {
                 if let AbilityKind::Static(ref sa) = ability.kind {
@@ -1885,7 +1927,7 @@ fn calculate_with_layers(object: &Object, ctx: &CalculationContext) -> Calculate
                     }
                 }
                 Modification::SetAbilities(abilities) => {
-                    chars.abilities = abilities.clone();
+                    chars.abilities = abilities.clone().into();
                     chars.static_abilities = extract_static_abilities(abilities);
                 }
                 Modification::CopyActivatedAbilities {
@@ -2293,6 +2335,7 @@ fn continuous_filter_context(
         attacking_player: None,
         your_commanders: Vec::new(),
         iterated_player: None,
+        triggering_player: None,
         target_players: Vec::new(),
         target_objects: Vec::new(),
         tagged_objects: std::collections::HashMap::new(),
@@ -2432,54 +2475,16 @@ fn resolve_value_with_context(
             count * *multiplier
         }
         Value::BasicLandTypesAmong(filter) => {
-            use std::collections::HashSet;
-
-            let filter_ctx = continuous_filter_context(controller, source);
-
-            let mut seen = HashSet::new();
-            for_each_filter_candidate(ctx, filter, |obj| {
-                if filter.matches_non_recursive(obj, &filter_ctx, ctx.game) {
-                    for subtype in &obj.subtypes {
-                        if matches!(
-                            subtype,
-                            Subtype::Plains
-                                | Subtype::Island
-                                | Subtype::Swamp
-                                | Subtype::Mountain
-                                | Subtype::Forest
-                        ) {
-                            seen.insert(subtype.clone());
-                        }
-                    }
-                }
-            });
-            seen.len() as i32
+            distinct_among_with_context(filter, ctx, source, controller).basic_land_types()
         }
         Value::ColorsAmong(filter) => {
-            let filter_ctx = continuous_filter_context(controller, source);
-
-            let mut has_white = false;
-            let mut has_blue = false;
-            let mut has_black = false;
-            let mut has_red = false;
-            let mut has_green = false;
-
-            for_each_filter_candidate(ctx, filter, |obj| {
-                if filter.matches_non_recursive(obj, &filter_ctx, ctx.game) {
-                    let colors = obj.colors();
-                    has_white |= colors.contains(crate::color::Color::White);
-                    has_blue |= colors.contains(crate::color::Color::Blue);
-                    has_black |= colors.contains(crate::color::Color::Black);
-                    has_red |= colors.contains(crate::color::Color::Red);
-                    has_green |= colors.contains(crate::color::Color::Green);
-                }
-            });
-
-            (has_white as i32)
-                + (has_blue as i32)
-                + (has_black as i32)
-                + (has_red as i32)
-                + (has_green as i32)
+            distinct_among_with_context(filter, ctx, source, controller).colors()
+        }
+        Value::CardTypesAmong(filter) => {
+            distinct_among_with_context(filter, ctx, source, controller).card_types()
+        }
+        Value::ManaValuesAmong(filter) => {
+            distinct_among_with_context(filter, ctx, source, controller).mana_values()
         }
         Value::DistinctNames(filter) => {
             use std::collections::HashSet;
@@ -2494,7 +2499,7 @@ fn resolve_value_with_context(
             });
             seen.len() as i32
         }
-        Value::CreaturesDiedThisTurn => ctx.game.creatures_died_this_turn as i32,
+        Value::CreaturesDiedThisTurn => ctx.game.turn_trackers.creatures_died as i32,
         Value::CreaturesDiedThisTurnControlledBy(player_filter) => {
             let filter_ctx = continuous_filter_context(controller, source);
             let mut total = 0i32;
@@ -2504,7 +2509,8 @@ fn resolve_value_with_context(
                 }
                 total += ctx
                     .game
-                    .creatures_died_under_controller_this_turn
+                    .turn_trackers
+                    .creatures_died_under_controller
                     .get(&player.id)
                     .copied()
                     .unwrap_or(0) as i32;
@@ -2606,6 +2612,7 @@ fn resolve_value_with_context(
         | Value::TimesPaidLabel(_)
         | Value::TimesPaid(_)
         | Value::KickCount
+        | Value::TargetCount
         | Value::MagicGamesLostToOpponentsSinceLastWin
         | Value::TaggedCount
         | Value::EventValue(_)
@@ -2613,6 +2620,22 @@ fn resolve_value_with_context(
     }
 }
 
+fn distinct_among_with_context(
+    filter: &ObjectFilter,
+    ctx: &CalculationContext<'_>,
+    source: ObjectId,
+    controller: PlayerId,
+) -> crate::object_query::DistinctAmong {
+    let filter_ctx = continuous_filter_context(controller, source);
+    let mut among = crate::object_query::DistinctAmong::default();
+    for_each_filter_candidate(ctx, filter, |obj| {
+        if filter.matches_non_recursive(obj, &filter_ctx, ctx.game) {
+            among.add(obj);
+        }
+    });
+    among
+}
+
 fn build_layer_baseline(
     objects: &HashMap<ObjectId, Object>,
     effects: &[ContinuousEffect],
@@ -2989,7 +3012,7 @@ mod tests {
             subtypes: Vec::new(),
             supertypes: Vec::new(),
             colors: ColorSet::COLORLESS,
-            abilities: Vec::new(),
+            abilities: SharedVec::new(),
             static_abilities: Vec::new(),
             controller: creature.controller,
         };
@@ -3044,7 +3067,7 @@ mod tests {
             subtypes: Vec::new(),
             supertypes: Vec::new(),
             colors: ColorSet::COLORLESS,
-            abilities: Vec::new(),
+            abilities: SharedVec::new(),
             static_abilities: vec![StaticAbility::flying()], // Already has flying
             controller: creature.controller,
         };
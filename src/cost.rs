@@ -161,6 +161,11 @@ impl OptionalCost {
         }
     }
 
+    /// Whether this is an entwine cost.
+    pub fn is_entwine(&self) -> bool {
+        self.label == "Entwine"
+    }
+
     /// Create a squad cost (may be paid any number of times).
     pub fn squad(cost: TotalCost) -> Self {
         Self {
@@ -167,7 +167,7 @@ pub fn can_pay_with_check_context(
     ctx: &CostCheckContext,
 ) -> Result<(), crate::cost::CostPaymentError> {
     // Create a temporary AutoPass decision maker just for the check
-    let mut auto_dm = crate::decision::CliDecisionMaker;
+    let mut auto_dm = crate::decision::CliDecisionMaker::default();
     let cost_ctx = ctx.as_cost_context(&mut auto_dm);
     cost.can_pay(game, &cost_ctx)
 }
@@ -178,7 +178,7 @@ pub fn can_potentially_pay_with_check_context(
     game: &crate::game_state::GameState,
     ctx: &CostCheckContext,
 ) -> Result<(), crate::cost::CostPaymentError> {
-    let mut auto_dm = crate::decision::CliDecisionMaker;
+    let mut auto_dm = crate::decision::CliDecisionMaker::default();
     let cost_ctx = ctx.as_cost_context(&mut auto_dm);
     cost.can_potentially_pay(game, &cost_ctx)
 }
@@ -19,9 +19,8 @@ use crate::zone::Zone;
 use crate::{CounterType, ManaSymbol, Step};
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs::File;
 use std::io;
-use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::io::Write;
 
 // ============================================================================
 // Fallback Strategies
@@ -61,6 +60,10 @@ pub enum FallbackStrategy {
 
 /// A legal action a player can take when they have priority.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum LegalAction {
     /// Pass priority to the next player.
     PassPriority,
@@ -136,6 +139,72 @@ pub struct AttackerDeclaration {
     pub target: AttackTarget,
 }
 
+/// A shortcut response for declaring attackers.
+///
+/// Shortcuts are expanded against the current legal attackers into concrete
+/// `AttackerDeclaration`s before they are applied, so the usual declaration
+/// validation (must-attack, goad, attack costs) still runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttackShortcut {
+    /// Every legal attacker attacks the chosen target. Creatures that can't
+    /// attack it (e.g., goaded away from it) attack their first legal target.
+    AllAttack(AttackTarget),
+    /// No creatures attack.
+    NoAttacks,
+    /// Repeat the player's previous attack where it is still legal.
+    RepeatLastAttack,
+}
+
+impl AttackShortcut {
+    /// Expand this shortcut into concrete declarations.
+    ///
+    /// `last_attack` is the player's previous declaration, used by
+    /// `RepeatLastAttack`. Creatures that must attack are always included, and
+    /// `NoAttacks` is rejected if any legal attacker must attack.
+    pub fn expand(
+        &self,
+        options: &[AttackerOption],
+        last_attack: &[AttackerDeclaration],
+    ) -> Result<Vec<AttackerDeclaration>, crate::combat_state::CombatError> {
+        let declare = |opt: &AttackerOption, preferred: Option<&AttackTarget>| {
+            preferred
+                .filter(|target| opt.valid_targets.contains(target))
+                .or_else(|| opt.valid_targets.first())
+                .map(|target| AttackerDeclaration {
+                    creature: opt.creature,
+                    target: target.clone(),
+                })
+        };
+
+        match self {
+            AttackShortcut::AllAttack(target) => Ok(options
+                .iter()
+                .filter_map(|opt| declare(opt, Some(target)))
+                .collect()),
+            AttackShortcut::NoAttacks => {
+                if let Some(opt) = options.iter().find(|opt| opt.must_attack) {
+                    return Err(crate::combat_state::CombatError::MustAttackNotDeclared(
+                        opt.creature,
+                    ));
+                }
+                Ok(Vec::new())
+            }
+            AttackShortcut::RepeatLastAttack => Ok(options
+                .iter()
+                .filter_map(|opt| {
+                    let previous = last_attack
+                        .iter()
+                        .find(|decl| decl.creature == opt.creature);
+                    if previous.is_none() && !opt.must_attack {
+                        return None;
+                    }
+                    declare(opt, previous.map(|decl| &decl.target))
+                })
+                .collect()),
+        }
+    }
+}
+
 /// Options for blocking a specific attacker.
 #[derive(Debug, Clone)]
 pub struct BlockerOption {
@@ -354,7 +423,7 @@ pub enum GameProgress {
 }
 
 /// Result of a completed game.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameResult {
     /// A player won the game.
     Winner(PlayerId),
@@ -497,6 +566,19 @@ fn append_native_alternative_cast_actions_for_card_from_zone(
     from_zone: Zone,
     view: &DerivedGameView<'_>,
 ) {
+    // An aftermath half can only be cast from its owner's graveyard.
+    if from_zone == Zone::Graveyard
+        && card.has_aftermath
+        && card.owner == player
+        && can_cast_spell_with_view(game, player, card, &CastingMethod::SplitOtherHalf, view)
+    {
+        actions.push(LegalAction::CastSpell {
+            spell_id: card_id,
+            from_zone,
+            casting_method: CastingMethod::SplitOtherHalf,
+        });
+    }
+
     for (idx, alt_cast) in card.alternative_casts.iter().enumerate() {
         if alt_cast.cast_from_zone() == from_zone
             && can_cast_with_alternative_with_view(game, player, card, alt_cast, view)
@@ -525,6 +607,9 @@ fn append_graveyard_granted_alternative_cast_actions_for_card(
         player,
     );
 
+    // Several permanents can grant the same method (two Underworld Breaches);
+    // the card still gets a single action per distinct cost.
+    let mut offered: Vec<CastingMethod> = Vec::new();
     for grant in granted_casts {
         let method = &grant.method;
         let requirements = build_requirements_for_method(method);
@@ -555,6 +640,13 @@ fn append_graveyard_granted_alternative_cast_actions_for_card(
             }
             _ => continue,
         };
+        if offered
+            .iter()
+            .any(|prior| same_granted_graveyard_cast(prior, &casting_method))
+        {
+            continue;
+        }
+        offered.push(casting_method.clone());
 
         actions.push(LegalAction::CastSpell {
             spell_id: card_id,
@@ -564,6 +656,17 @@ fn append_graveyard_granted_alternative_cast_actions_for_card(
     }
 }
 
+fn same_granted_graveyard_cast(a: &CastingMethod, b: &CastingMethod) -> bool {
+    match (a, b) {
+        (
+            CastingMethod::GrantedEscape { exile_count: a, .. },
+            CastingMethod::GrantedEscape { exile_count: b, .. },
+        ) => a == b,
+        (CastingMethod::GrantedFlashback, CastingMethod::GrantedFlashback) => true,
+        _ => false,
+    }
+}
+
 fn append_hand_granted_alternative_cast_actions_for_card(
     game: &GameState,
     actions: &mut Vec<LegalAction>,
@@ -655,7 +758,17 @@ pub fn compute_legal_actions(game: &GameState, player: PlayerId) -> Vec<LegalAct
             }
         }
     }
-    for &card_id in &game.exile {
+    // Lands in other zones can be played through "play from" grants
+    // (Yawgmoth's Will for the graveyard, impulse draw for exile).
+    let graveyard = game
+        .player(player)
+        .map(|player_obj| player_obj.graveyard.as_slice())
+        .unwrap_or_default();
+    let granted_land_zones = graveyard
+        .iter()
+        .map(|&card_id| (card_id, Zone::Graveyard))
+        .chain(game.exile.iter().map(|&card_id| (card_id, Zone::Exile)));
+    for (card_id, zone) in granted_land_zones {
         let Some(card) = game.object(card_id) else {
             continue;
         };
@@ -664,7 +777,7 @@ pub fn compute_legal_actions(game: &GameState, player: PlayerId) -> Vec<LegalAct
         }
         if game
             .grant_registry
-            .granted_play_from_for_card(game, card_id, Zone::Exile, player)
+            .granted_play_from_for_card(game, card_id, zone, player)
             .is_empty()
         {
             continue;
@@ -776,7 +889,9 @@ pub fn compute_legal_actions(game: &GameState, player: PlayerId) -> Vec<LegalAct
 
                 // Only add alternative casts if Normal is not available
                 if !has_normal_cast {
-                    if card.linked_face_layout == crate::card::LinkedFaceLayout::Split {
+                    if card.linked_face_layout == crate::card::LinkedFaceLayout::Split
+                        && !card.has_aftermath
+                    {
                         if can_cast_spell_with_view(
                             game,
                             player,
@@ -978,6 +1093,7 @@ pub(crate) fn can_activate_ability_with_restrictions(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: Some(ability_index),
         options: Default::default(),
@@ -1321,7 +1437,8 @@ fn spells_cast_this_turn_matching_filter(
 ) -> u32 {
     if spell_filter == &crate::target::ObjectFilter::default() {
         return game
-            .spells_cast_this_turn
+            .turn_trackers
+            .spells_cast
             .get(&player)
             .copied()
             .unwrap_or(0);
@@ -1384,7 +1501,9 @@ fn is_sorcery_speed_spell(spell: &crate::object::Object) -> bool {
         || spell.has_card_type(CardType::Planeswalker)
 }
 
-fn spell_has_active_flash(
+/// Whether a spell may be cast as though it had flash: it has flash itself, or
+/// a grant covering the zone it's being cast from gives it flash.
+pub(crate) fn spell_has_active_flash(
     game: &GameState,
     player: PlayerId,
     spell: &crate::object::Object,
@@ -1407,7 +1526,7 @@ fn spell_has_active_flash(
     }) || game.grant_registry.card_has_granted_ability(
         game,
         spell_id,
-        Zone::Hand,
+        spell.zone,
         player,
         &crate::static_abilities::StaticAbility::flash(),
     )
@@ -1737,6 +1856,27 @@ pub fn spell_mana_cost_for_cast(
     }
 }
 
+/// Add the mana components of paid optional costs to a spell's mana cost.
+///
+/// Each optional cost (kicker, multikicker, entwine, ...) contributes its mana
+/// once per time it was paid (Rule 601.2f).
+pub fn add_optional_costs_mana(
+    spell: &crate::object::Object,
+    cost: &crate::mana::ManaCost,
+    optional_costs_paid: &crate::cost::OptionalCostsPaid,
+) -> crate::mana::ManaCost {
+    let mut total = cost.clone();
+    for (idx, optional_cost) in spell.optional_costs.iter().enumerate() {
+        let Some(mana) = optional_cost.cost.mana_cost() else {
+            continue;
+        };
+        for _ in 0..optional_costs_paid.times_paid(idx) {
+            total = add_mana_cost(&total, mana);
+        }
+    }
+    total
+}
+
 /// Check if a spell can be cast by a player using the given casting method.
 pub fn can_cast_spell(
     game: &GameState,
@@ -1818,6 +1958,7 @@ fn can_cast_spell_with_view(
         .as_ref()
         .and_then(|view| view.spell_effect.as_deref())
         .or(spell.spell_effect.as_deref())
+        .map(Vec::as_slice)
         .unwrap_or(&[]);
     if !crate::game_loop::spell_has_legal_targets_with_modes_and_view(
         game,
@@ -1952,7 +2093,7 @@ fn can_cast_with_cost_with_view(
     let effects = effects_override.unwrap_or_else(|| {
         synthesized_aura_effects
             .as_deref()
-            .or(spell.spell_effect.as_deref())
+            .or(spell.spell_effect.as_deref().map(Vec::as_slice))
             .unwrap_or(&[])
     });
     if !crate::game_loop::spell_has_legal_targets_with_modes_and_view(
@@ -2059,6 +2200,7 @@ fn can_cast_with_alternative_with_view(
         disturbed_view
             .as_ref()
             .and_then(|view| view.spell_effect.as_deref())
+            .map(Vec::as_slice)
     });
     let free_plot_cost = crate::mana::ManaCost::new();
     let mana_cost = match method {
@@ -2165,7 +2307,7 @@ fn can_cast_with_alternative_from_hand_with_view(
                     return false;
                 }
             }
-            let effects = spell.spell_effect.as_deref().unwrap_or(&[]);
+            let effects = spell.spell_effect.as_deref().map_or(&[][..], Vec::as_slice);
             if !crate::game_loop::spell_has_legal_targets_with_modes_and_view(
                 game,
                 effects,
@@ -2258,9 +2400,14 @@ fn is_trap_condition_met(
     match condition {
         TrapCondition::OpponentCastSpells { count } => {
             // Check if any opponent cast N or more spells this turn
-            opponents
-                .iter()
-                .any(|&opp| game.spells_cast_this_turn.get(&opp).copied().unwrap_or(0) >= *count)
+            opponents.iter().any(|&opp| {
+                game.turn_trackers
+                    .spells_cast
+                    .get(&opp)
+                    .copied()
+                    .unwrap_or(0)
+                    >= *count
+            })
         }
         TrapCondition::OpponentSearchedLibrary => {
             // Check if any opponent searched their library this turn
@@ -2310,7 +2457,7 @@ fn can_pay_cost_with_spell_exclusion(
         return false;
     };
 
-    let mut dm = crate::decision::CliDecisionMaker;
+    let mut dm = crate::decision::CliDecisionMaker::default();
     let ctx = crate::costs::CostContext::new(source, player, &mut dm);
 
     match cost.processing_mode() {
@@ -2419,7 +2566,7 @@ pub fn calculate_effective_mana_cost_with_chosen_targets(
 }
 
 /// Calculate effective cost for payment stage where Convoke/Improvise are handled
-/// as pip alternatives instead of up-front reductions.
+/// as pip alternatives and Delve as a graveyard choice instead of up-front reductions.
 pub fn calculate_effective_mana_cost_for_payment_with_targets(
     game: &GameState,
     player: PlayerId,
@@ -2441,6 +2588,9 @@ pub fn calculate_effective_mana_cost_for_payment_with_targets(
 }
 
 /// Calculate payment-stage effective cost using exact chosen targets.
+///
+/// Like `calculate_effective_mana_cost_for_payment_with_targets`, this leaves
+/// Convoke, Improvise, and Delve for the player to choose during payment.
 pub fn calculate_effective_mana_cost_for_payment_with_chosen_targets(
     game: &GameState,
     player: PlayerId,
@@ -2468,7 +2618,7 @@ fn calculate_effective_mana_cost_with_targets_internal(
     base_cost: &crate::mana::ManaCost,
     chosen_target_count: usize,
     chosen_targets: &[Target],
-    include_convoke_improvise_reductions: bool,
+    include_payment_choice_reductions: bool,
     view: &DerivedGameView<'_>,
 ) -> crate::mana::ManaCost {
     use crate::ability::AbilityKind;
@@ -2510,16 +2660,16 @@ fn calculate_effective_mana_cost_with_targets_internal(
         view,
     );
 
-    // Check for Delve
-    let has_delve_ability = has_delve(spell);
+    if include_payment_choice_reductions {
+        // Check for Delve
+        let has_delve_ability = has_delve(spell);
 
-    if has_delve_ability {
-        // For Delve, we assume maximum usage (exile all cards up to generic cost remaining)
-        let graveyard_count = count_cards_in_graveyard(game, player);
-        current_cost = current_cost.reduce_generic(graveyard_count);
-    }
+        if has_delve_ability {
+            // For Delve, we assume maximum usage (exile all cards up to generic cost remaining)
+            let graveyard_count = count_cards_in_graveyard(game, player);
+            current_cost = current_cost.reduce_generic(graveyard_count);
+        }
 
-    if include_convoke_improvise_reductions {
         // Check for Convoke
         let has_convoke_ability = has_convoke(spell);
         if has_convoke_ability {
@@ -2621,6 +2771,13 @@ fn apply_spell_cost_modifiers(
                 reduction_pips.extend(reduction.reduction.pips().iter().cloned());
             }
         }
+        // "This spell costs {1}{G} more for each target beyond the first" (Strive)
+        // applies while casting, wherever the spell is cast from.
+        if let Some(per_target) = static_ability.cost_increase_per_additional_target() {
+            for _ in 1..chosen_target_count {
+                increase_pips.extend(per_target.pips().iter().cloned());
+            }
+        }
         if !functions_in_current_zone {
             continue;
         }
@@ -2653,13 +2810,6 @@ fn apply_spell_cost_modifiers(
         {
             reduction_pips.extend(reduction.reduction.pips().iter().cloned());
         }
-        if let Some(per_target_amount) = static_ability.cost_increase_per_additional_target() {
-            let additional_targets = chosen_target_count.saturating_sub(1);
-            if additional_targets > 0 {
-                let extra = (per_target_amount as i32).saturating_mul(additional_targets as i32);
-                total_increase = total_increase.saturating_add(extra);
-            }
-        }
     }
 
     let mut adjusted = cost.clone();
@@ -2752,6 +2902,9 @@ fn apply_battlefield_spell_cost_modifiers(
 
         for static_ability in static_abilities {
             if let Some(reduction) = static_ability.cost_reduction()
+                && reduction
+                    .uses_per_turn
+                    .is_none_or(|uses| game.cost_reduction_uses_this_turn(perm_id) < uses)
                 && spell_matches_filter(
                     game,
                     spell,
@@ -2834,6 +2987,60 @@ fn apply_battlefield_spell_cost_modifiers(
     adjusted
 }
 
+/// Use up per-turn-limited cost reductions ("the first creature spell you cast
+/// each turn costs {2} less") that applied to a spell whose cast just completed.
+pub(crate) fn consume_limited_spell_cost_reductions(
+    game: &mut GameState,
+    caster: PlayerId,
+    spell_id: ObjectId,
+) {
+    use crate::filter::FilterContext;
+
+    let Some(spell) = game.object(spell_id) else {
+        return;
+    };
+    let view = DerivedGameView::new(game);
+    let mut used_sources = Vec::new();
+    for &perm_id in &game.battlefield {
+        let Some(static_abilities) = view
+            .calculated_characteristics(perm_id)
+            .map(|c| c.static_abilities)
+        else {
+            continue;
+        };
+        let Some(perm) = game.object(perm_id) else {
+            continue;
+        };
+        let opponents = game
+            .turn_order
+            .iter()
+            .copied()
+            .filter(|p| *p != perm.controller)
+            .collect();
+        let ctx = FilterContext::new(perm.controller)
+            .with_source(perm_id)
+            .with_active_player(game.turn.active_player)
+            .with_opponents(opponents)
+            .with_caster(Some(caster));
+        for static_ability in static_abilities {
+            let Some(reduction) = static_ability.cost_reduction() else {
+                continue;
+            };
+            let Some(uses) = reduction.uses_per_turn else {
+                continue;
+            };
+            if game.cost_reduction_uses_this_turn(perm_id) < uses
+                && reduction.filter.matches(spell, &ctx, game)
+            {
+                used_sources.push(perm_id);
+            }
+        }
+    }
+    for source in used_sources {
+        game.record_cost_reduction_use(source);
+    }
+}
+
 fn resolve_this_spell_cost_reduction_value(
     game: &GameState,
     player: PlayerId,
@@ -3131,13 +3338,19 @@ pub fn compute_potential_mana(game: &GameState, player: PlayerId) -> crate::play
                 if can_activate && condition_met {
                     // Add the mana this ability could produce, preserving
                     // multiplicity for effects like Black Lotus.
+                    let snow_source =
+                        game.object_has_supertype(perm_id, crate::types::Supertype::Snow);
                     for mana in inferred_potential_mana_symbols_for_ability(
                         game,
                         perm_id,
                         player,
                         mana_ability,
                     ) {
-                        potential.add(mana, 1);
+                        if snow_source {
+                            potential.add_snow(mana, 1);
+                        } else {
+                            potential.add(mana, 1);
+                        }
                     }
                     // Only count one mana ability per permanent (can only tap once)
                     break;
@@ -3253,6 +3466,7 @@ fn check_mana_ability_condition_for_potential(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: Some(ability_index),
         options: crate::condition_eval::ExternalEvaluationOptions::default(),
@@ -3672,14 +3886,14 @@ pub fn compute_legal_attackers(game: &GameState, _combat: &CombatState) -> Vec<A
 
         for opponent in &game.players {
             if opponent.id != active_player && opponent.is_in_game() {
+                let target = AttackTarget::Player(opponent.id);
                 let can_attack = crate::rules::combat::can_attack_defending_player_with_view(
                     perm,
                     opponent.id,
                     game,
                     &view,
-                );
+                ) && game.can_attack_target(perm_id, &target);
                 if can_attack {
-                    let target = AttackTarget::Player(opponent.id);
                     if goaded_by.contains(&opponent.id) {
                         goad_targets.push(target);
                     } else {
@@ -3700,6 +3914,7 @@ pub fn compute_legal_attackers(game: &GameState, _combat: &CombatState) -> Vec<A
                     game,
                     &view,
                 )
+                && game.can_attack_target(perm_id, &AttackTarget::Planeswalker(other_perm_id))
             {
                 let target = AttackTarget::Planeswalker(other_perm_id);
                 if goaded_by.contains(&other_perm.controller) {
@@ -3823,6 +4038,25 @@ pub trait DecisionMaker {
         false
     }
 
+    /// Take a priority stop change the player asked for while deciding
+    /// (e.g. the CLI `stops` command).
+    ///
+    /// [`DecisionRouter`] polls this after each priority decision and applies
+    /// the edit to the deciding player's policy. Default implementation
+    /// never requests a change.
+    fn take_priority_policy_edit(&mut self) -> Option<crate::decisions::PriorityPolicyEdit> {
+        None
+    }
+
+    /// Current time in milliseconds, used to charge decisions to a
+    /// [`GameClock`](crate::game_loop::GameClock).
+    ///
+    /// The priority loop reads this before and after each decision. Default
+    /// implementation keeps no time, so clocks never run down.
+    fn clock_now_ms(&self) -> Option<u64> {
+        None
+    }
+
     // ========================================================================
     // Primitive-specific methods
     // ========================================================================
@@ -4007,9 +4241,14 @@ pub trait DecisionMaker {
 /// Routes decisions to the controlling player's DecisionMaker.
 ///
 /// This is used for effects that let one player control another player's decisions.
+/// The router also applies each player's [`PriorityPolicy`], answering priority
+/// decisions the policy auto-passes without consulting the DecisionMaker.
+///
+/// [`PriorityPolicy`]: crate::decisions::PriorityPolicy
 pub struct DecisionRouter {
     per_player: HashMap<PlayerId, Box<dyn DecisionMaker>>,
     default: Box<dyn DecisionMaker>,
+    priority_policies: HashMap<PlayerId, crate::decisions::PriorityPolicy>,
 }
 
 impl DecisionRouter {
@@ -4018,9 +4257,37 @@ impl DecisionRouter {
         Self {
             per_player: HashMap::new(),
             default,
+            priority_policies: HashMap::new(),
         }
     }
 
+    /// Configure the priority stops/auto-pass policy for a player.
+    pub fn with_priority_policy(
+        mut self,
+        player: PlayerId,
+        policy: crate::decisions::PriorityPolicy,
+    ) -> Self {
+        self.priority_policies.insert(player, policy);
+        self
+    }
+
+    /// Replace the priority policy for a player.
+    pub fn set_priority_policy(
+        &mut self,
+        player: PlayerId,
+        policy: crate::decisions::PriorityPolicy,
+    ) {
+        self.priority_policies.insert(player, policy);
+    }
+
+    /// Get a player's priority policy, if one is configured.
+    pub fn priority_policy_mut(
+        &mut self,
+        player: PlayerId,
+    ) -> Option<&mut crate::decisions::PriorityPolicy> {
+        self.priority_policies.get_mut(&player)
+    }
+
     /// Register a DecisionMaker for a specific player.
     pub fn with_player(mut self, player: PlayerId, dm: Box<dyn DecisionMaker>) -> Self {
         self.per_player.insert(player, dm);
@@ -4053,6 +4320,10 @@ impl DecisionMaker for DecisionRouter {
         }
     }
 
+    fn clock_now_ms(&self) -> Option<u64> {
+        self.default.clock_now_ms()
+    }
+
     fn decide_boolean(
         &mut self,
         game: &GameState,
@@ -4165,7 +4436,21 @@ impl DecisionMaker for DecisionRouter {
         game: &GameState,
         ctx: &crate::decisions::context::PriorityContext,
     ) -> LegalAction {
-        self.dm_for(game, ctx.player).decide_priority(game, ctx)
+        if let Some(policy) = self.priority_policies.get_mut(&ctx.player)
+            && policy.should_auto_pass(game, ctx)
+        {
+            self.dm_for(game, ctx.player).on_auto_pass(game, ctx.player);
+            return LegalAction::PassPriority;
+        }
+        let dm = self.dm_for(game, ctx.player);
+        let action = dm.decide_priority(game, ctx);
+        if let Some(edit) = dm.take_priority_policy_edit() {
+            self.priority_policies
+                .entry(ctx.player)
+                .or_default()
+                .apply_edit(&edit);
+        }
+        action
     }
 
     fn decide_targets(
@@ -4188,6 +4473,10 @@ impl<D: DecisionMaker + ?Sized> DecisionMaker for &mut D {
         (*self).on_auto_pass(game, player)
     }
 
+    fn clock_now_ms(&self) -> Option<u64> {
+        (**self).clock_now_ms()
+    }
+
     fn decide_boolean(
         &mut self,
         game: &GameState,
@@ -4308,6 +4597,10 @@ impl<D: DecisionMaker + ?Sized> DecisionMaker for Box<D> {
         (**self).on_auto_pass(game, player)
     }
 
+    fn clock_now_ms(&self) -> Option<u64> {
+        (**self).clock_now_ms()
+    }
+
     fn decide_boolean(
         &mut self,
         game: &GameState,
@@ -5431,7 +5724,136 @@ impl DecisionMaker for NumericInputDecisionMaker {
 // ============================================================================
 
 /// A decision maker that prompts the user via CLI.
-pub struct CliDecisionMaker;
+#[derive(Debug, Default)]
+pub struct CliDecisionMaker {
+    /// Stop change typed at the last priority prompt, for the router to apply.
+    pending_stops_edit: Option<crate::decisions::PriorityPolicyEdit>,
+}
+
+/// Render display text for the CLI.
+fn cli_text(text: &crate::display_text::DisplayText) -> String {
+    use crate::display_text::DisplayRenderer;
+
+    crate::display_text::EnglishRenderer.render(text)
+}
+
+/// Inputs accepted by each kind of CLI prompt, as `(input, meaning)` pairs
+/// listed by the `help` meta-command.
+type PromptCommands = &'static [(&'static str, &'static str)];
+
+const PRIORITY_COMMANDS: PromptCommands = &[
+    ("<n>", "take the numbered action"),
+    ("c, c<n>", "take a commander action"),
+    ("<empty>", "pass priority"),
+    ("stops <edit>", "change your priority stops"),
+];
+const BOOLEAN_COMMANDS: PromptCommands = &[("y/n", "answer yes or no")];
+const NUMBER_COMMANDS: PromptCommands = &[("<n>", "choose a number in the range")];
+const SELECT_COMMANDS: PromptCommands = &[
+    ("<n>,<n>,...", "select by index"),
+    ("<empty>", "select nothing, if allowed"),
+];
+const ORDER_COMMANDS: PromptCommands = &[
+    ("<n>,<n>,...", "put the listed items first, in that order"),
+    ("<empty>", "keep the current order"),
+];
+const ATTACKER_COMMANDS: PromptCommands = &[
+    ("<n>,<n>:<target>", "attack with the listed creatures"),
+    ("all, all:<target>", "attack with every creature that can"),
+    ("repeat", "repeat your last attack"),
+    ("none/<empty>", "don't attack"),
+];
+const BLOCKER_COMMANDS: PromptCommands = &[
+    ("<blocker>:<attacker>,...", "declare blocks"),
+    ("none/<empty>", "don't block"),
+];
+const COLOR_COMMANDS: PromptCommands = &[
+    ("0-4", "choose a color by number"),
+    ("w/u/b/r/g", "choose a color by mana letter"),
+];
+const COUNTER_COMMANDS: PromptCommands = &[
+    ("<n>:<amount>,...", "remove counters by index"),
+    ("<empty>", "remove none"),
+];
+const PARTITION_COMMANDS: PromptCommands = &[
+    ("<n>,<n>,...", "move the listed cards to the second pile"),
+    ("<empty>", "keep every card in the first pile"),
+];
+const PROLIFERATE_COMMANDS: PromptCommands = &[
+    ("p<n>,P<n>,...", "choose permanents (p) and players (P)"),
+    ("<empty>", "proliferate nothing"),
+];
+
+/// The CLI prompt waiting on input, for the `board`/`b` and `help`
+/// meta-commands. These are answered by the input layer without consuming
+/// the pending decision, and are never written to a recording.
+struct CliPrompt {
+    board: String,
+    commands: PromptCommands,
+}
+
+impl CliPrompt {
+    /// `b` is shorthand for `board` unless the prompt already uses it
+    /// (e.g. black in a color choice).
+    fn board_alias_free(&self) -> bool {
+        !self
+            .commands
+            .iter()
+            .any(|(input, _)| input.split('/').any(|word| word == "b"))
+    }
+
+    fn print_help(&self) {
+        println!("Commands:");
+        for (input, meaning) in self.commands {
+            println!("  {:<26} {}", input, meaning);
+        }
+        println!("  {:<26} show the board", "board, b");
+        println!("  {:<26} list these commands", "help");
+    }
+}
+
+thread_local! {
+    static CLI_PROMPT: RefCell<Option<CliPrompt>> = const { RefCell::new(None) };
+}
+
+/// Keeps a [`CliPrompt`] active until the decision that set it returns.
+struct CliPromptGuard;
+
+impl CliPromptGuard {
+    fn enter(game: &GameState, player: PlayerId, commands: PromptCommands) -> Self {
+        let prompt = CliPrompt {
+            board: format_board(game, player),
+            commands,
+        };
+        CLI_PROMPT.with(|current| *current.borrow_mut() = Some(prompt));
+        Self
+    }
+}
+
+impl Drop for CliPromptGuard {
+    fn drop(&mut self) {
+        CLI_PROMPT.with(|current| *current.borrow_mut() = None);
+    }
+}
+
+/// Answer `line` if it is a meta-command for the active prompt.
+///
+/// Returns whether the line was handled, in which case it isn't a response.
+fn run_meta_command(line: &str) -> bool {
+    CLI_PROMPT.with(|current| {
+        let current = current.borrow();
+        let Some(prompt) = current.as_ref() else {
+            return false;
+        };
+        match line.trim().to_ascii_lowercase().as_str() {
+            "board" => println!("{}", prompt.board),
+            "b" if prompt.board_alias_free() => println!("{}", prompt.board),
+            "help" => prompt.print_help(),
+            _ => return false,
+        }
+        true
+    })
+}
 
 impl DecisionMaker for CliDecisionMaker {
     fn on_auto_pass(&mut self, game: &GameState, player: PlayerId) {
@@ -5449,9 +5871,14 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::PriorityContext,
     ) -> LegalAction {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, PRIORITY_COMMANDS);
         display_game_state(game);
         println!("\n--- {} has priority ---", player_name(game, ctx.player));
-        prompt_priority_action(game, &ctx.actions)
+        prompt_priority_action(game, &ctx.actions, &mut self.pending_stops_edit)
+    }
+
+    fn take_priority_policy_edit(&mut self) -> Option<crate::decisions::PriorityPolicyEdit> {
+        self.pending_stops_edit.take()
     }
 
     fn decide_boolean(
@@ -5459,6 +5886,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::BooleanContext,
     ) -> bool {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, BOOLEAN_COMMANDS);
         let source_info = if let Some(name) = &ctx.source_name {
             format!(" ({})", name)
         } else if let Some(source_id) = ctx.source {
@@ -5472,7 +5900,7 @@ impl DecisionMaker for CliDecisionMaker {
             "\n--- {} chooses{}: {} ---",
             player_name(game, ctx.player),
             source_info,
-            ctx.description
+            cli_text(&ctx.description)
         );
         prompt_boolean_choice()
     }
@@ -5482,6 +5910,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::NumberContext,
     ) -> u32 {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, NUMBER_COMMANDS);
         let source_info = ctx
             .source
             .and_then(|id| game.object(id))
@@ -5492,7 +5921,7 @@ impl DecisionMaker for CliDecisionMaker {
             player_name(game, ctx.player),
             source_info
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
         prompt_number_choice(ctx.min, ctx.max)
     }
 
@@ -5501,11 +5930,12 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::SelectObjectsContext,
     ) -> Vec<ObjectId> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, SELECT_COMMANDS);
         println!(
             "\n--- {} selects objects ---",
             player_name(game, ctx.player)
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
         prompt_select_objects(game, &ctx.candidates, ctx.min, ctx.max)
     }
 
@@ -5514,11 +5944,12 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::SelectOptionsContext,
     ) -> Vec<usize> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, SELECT_COMMANDS);
         println!(
             "\n--- {} chooses option(s) ---",
             player_name(game, ctx.player)
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
         prompt_select_options(&ctx.options, ctx.min, ctx.max)
     }
 
@@ -5537,7 +5968,7 @@ impl DecisionMaker for CliDecisionMaker {
             "\n--- {} looks at {}'s {} ---",
             viewer_name, subject_name, zone_label
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
 
         if cards.is_empty() {
             println!("(no cards)");
@@ -5558,10 +5989,10 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::OrderContext,
     ) -> Vec<ObjectId> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, ORDER_COMMANDS);
         println!("\n--- {} orders items ---", player_name(game, ctx.player));
-        println!("{}", ctx.description);
-        // For simplicity, use the default order (items as given)
-        ctx.items.iter().map(|(id, _)| *id).collect()
+        println!("{}", cli_text(&ctx.description));
+        prompt_order_items(&ctx.items)
     }
 
     fn decide_attackers(
@@ -5569,6 +6000,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::AttackersContext,
     ) -> Vec<crate::decisions::spec::AttackerDeclaration> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, ATTACKER_COMMANDS);
         display_game_state(game);
         println!(
             "\n--- {} declares attackers ---",
@@ -5582,6 +6014,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::BlockersContext,
     ) -> Vec<crate::decisions::spec::BlockerDeclaration> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, BLOCKER_COMMANDS);
         display_game_state(game);
         println!(
             "\n--- {} declares blockers ---",
@@ -5600,7 +6033,7 @@ impl DecisionMaker for CliDecisionMaker {
             player_name(game, ctx.player),
             ctx.total
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
         prompt_distribute(game, &ctx.targets, ctx.total, ctx.min_per_target)
     }
 
@@ -5609,6 +6042,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::ColorsContext,
     ) -> Vec<crate::color::Color> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, COLOR_COMMANDS);
         println!(
             "\n--- {} chooses {} mana color(s){} ---",
             player_name(game, ctx.player),
@@ -5627,6 +6061,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::CountersContext,
     ) -> Vec<(CounterType, u32)> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, COUNTER_COMMANDS);
         let target_name = game
             .object(ctx.target)
             .map(|o| o.name.as_str())
@@ -5645,12 +6080,13 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::PartitionContext,
     ) -> Vec<ObjectId> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, PARTITION_COMMANDS);
         println!(
             "\n--- {} partitions {} card(s) ---",
             player_name(game, ctx.player),
             ctx.cards.len()
         );
-        println!("{}", ctx.description);
+        println!("{}", cli_text(&ctx.description));
         prompt_partition(game, &ctx.cards, &ctx.primary_label, &ctx.secondary_label)
     }
 
@@ -5659,6 +6095,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::ProliferateContext,
     ) -> crate::decisions::specs::ProliferateResponse {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, PROLIFERATE_COMMANDS);
         println!(
             "\n--- {} chooses proliferate targets ---",
             player_name(game, ctx.player)
@@ -5671,6 +6108,7 @@ impl DecisionMaker for CliDecisionMaker {
         game: &GameState,
         ctx: &crate::decisions::context::TargetsContext,
     ) -> Vec<Target> {
+        let _prompt = CliPromptGuard::enter(game, ctx.player, SELECT_COMMANDS);
         let source_name = game
             .object(ctx.source)
             .map(|o| o.name.clone())
@@ -5812,6 +6250,123 @@ fn display_game_state(game: &GameState) {
     }
 }
 
+/// Render the full board as seen by `viewer`, for the `board` meta-command.
+///
+/// Permanents are grouped by controller with their post-layer power and
+/// toughness, tap state and counters. Only the viewer's hand is listed, and
+/// face-down cards they can't look at stay anonymous.
+fn format_board(game: &GameState, viewer: PlayerId) -> String {
+    let mut lines = vec![format!(
+        "=== Board: turn {}, {} ({}) ===",
+        game.turn.turn_number,
+        player_name(game, game.turn.active_player),
+        format_phase(&game.turn.phase, &game.turn.step)
+    )];
+
+    for player in &game.players {
+        lines.push(format!(
+            "{}: {} life, {} in hand, {} in library, {} in graveyard",
+            player.name,
+            player.life,
+            player.hand.len(),
+            player.library.len(),
+            player.graveyard.len()
+        ));
+        let permanents: Vec<ObjectId> = game
+            .battlefield
+            .iter()
+            .copied()
+            .filter(|&id| !game.is_phased_out(id))
+            .filter(|&id| {
+                game.object(id)
+                    .is_some_and(|obj| obj.controller == player.id)
+            })
+            .collect();
+        if permanents.is_empty() {
+            lines.push("  (no permanents)".to_string());
+        }
+        for id in permanents {
+            lines.push(format!("  {}", format_board_permanent(game, id, viewer)));
+        }
+    }
+
+    if !game.stack.is_empty() {
+        lines.push("Stack (top first):".to_string());
+        for entry in game.stack.iter().rev() {
+            let name = entry
+                .source_name
+                .clone()
+                .filter(|_| entry.is_ability)
+                .or_else(|| {
+                    game.object(entry.object_id)
+                        .filter(|_| game.can_see_object(entry.object_id, Some(viewer)))
+                        .map(|obj| obj.name.clone())
+                })
+                .unwrap_or_else(|| "Face-down spell".to_string());
+            let kind = if entry.is_ability { " (ability)" } else { "" };
+            lines.push(format!(
+                "  {}{} - {}",
+                name,
+                kind,
+                player_name(game, entry.controller)
+            ));
+        }
+    }
+
+    if let Some(player) = game.player(viewer) {
+        let hand: Vec<String> = player
+            .hand
+            .iter()
+            .filter_map(|&id| game.object(id))
+            .map(|obj| format!("{}({})", obj.name, format_mana_cost(obj)))
+            .collect();
+        lines.push(format!(
+            "{}'s hand: {}",
+            player.name,
+            if hand.is_empty() {
+                "(empty)".to_string()
+            } else {
+                hand.join(", ")
+            }
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn format_board_permanent(game: &GameState, id: ObjectId, viewer: PlayerId) -> String {
+    let Some(obj) = game.object(id) else {
+        return format!("Object #{}", id.0);
+    };
+    let mut text = if game.can_see_object(id, Some(viewer)) {
+        obj.name.clone()
+    } else {
+        "Face-down permanent".to_string()
+    };
+    if game.is_face_down(id) && !game.is_transformed(id) && game.can_see_object(id, Some(viewer)) {
+        text.push_str(" (face down)");
+    }
+    if obj.is_creature() {
+        let power = game.calculated_power(id).unwrap_or(0);
+        let toughness = game.calculated_toughness(id).unwrap_or(0);
+        text.push_str(&format!(" {}/{}", power, toughness));
+    }
+    if game.is_tapped(id) {
+        text.push_str(" [T]");
+    }
+    let mut counters: Vec<String> = obj
+        .counters
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(counter, count)| format!("{} {}", count, counter.description()))
+        .collect();
+    if !counters.is_empty() {
+        counters.sort();
+        text.push_str(&format!(" [{}]", counters.join(", ")));
+    }
+    text
+}
+
 fn format_phase(phase: &Phase, step: &Option<Step>) -> String {
     let phase_str = match phase {
         Phase::Beginning => "Beginning",
@@ -5935,7 +6490,11 @@ fn format_non_mana_costs(costs: &[crate::costs::Cost]) -> String {
 
 /// New version of prompt_priority_action that returns LegalAction directly
 /// (used by the new decide_priority method).
-fn prompt_priority_action(game: &GameState, actions: &[LegalAction]) -> LegalAction {
+fn prompt_priority_action(
+    game: &GameState,
+    actions: &[LegalAction],
+    stops_edit: &mut Option<crate::decisions::PriorityPolicyEdit>,
+) -> LegalAction {
     let commander_indices = commander_action_indices(actions);
 
     // Format actions compactly
@@ -5945,6 +6504,7 @@ fn prompt_priority_action(game: &GameState, actions: &[LegalAction]) -> LegalAct
         .map(|(i, a)| format!("{}:{}", i, format_action_short(game, a)))
         .collect();
     println!("Actions: {}", action_strs.join(" | "));
+    println!("(board/b shows the board, help lists commands)");
 
     // Display commander actions separately with 'C' prefix
     if !commander_indices.is_empty() {
@@ -5979,8 +6539,24 @@ fn prompt_priority_action(game: &GameState, actions: &[LegalAction]) -> LegalAct
             return pass_action.clone();
         }
 
-        // Check for commander action (C, c, C0, c0, C1, c1, etc.)
         let lower = trimmed.to_lowercase();
+
+        // "stops ..." edits this player's priority stops, then keeps prompting.
+        if let Some(args) = lower
+            .strip_prefix("stops")
+            .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+        {
+            match crate::decisions::PriorityPolicyEdit::parse(args) {
+                Ok(edit) => {
+                    println!("Stops updated.");
+                    *stops_edit = Some(edit);
+                }
+                Err(message) => println!("{}", message),
+            }
+            continue;
+        }
+
+        // Check for commander action (C, c, C0, c0, C1, c1, etc.)
         if lower == "c" && commander_indices.len() == 1 {
             return actions[commander_indices[0]].clone();
         }
@@ -6234,7 +6810,7 @@ pub(crate) fn format_action_short(game: &GameState, action: &LegalAction) -> Str
 }
 
 fn prompt_declare_attackers(
-    _game: &GameState,
+    game: &GameState,
     ctx: &crate::decisions::context::AttackersContext,
 ) -> Vec<crate::decisions::spec::AttackerDeclaration> {
     if ctx.attacker_options.is_empty() {
@@ -6252,33 +6828,160 @@ fn prompt_declare_attackers(
         println!("  {}: {}{}", i, opt.creature_name, must);
     }
 
-    println!("\nEnter attacking creatures (comma-separated indices, or empty for none):");
-    print!("> ");
-    io::stdout().flush().unwrap();
+    let targets = distinct_attack_targets(ctx);
+    if targets.len() > 1 {
+        println!("\nAttack targets:");
+        for (i, target) in targets.iter().enumerate() {
+            println!("  {}: {}", i, attack_target_name(game, target));
+        }
+    }
 
-    let input = read_input().unwrap_or_default();
-    let input = input.trim();
+    let last_attack = game
+        .last_attack_declarations
+        .get(&ctx.player)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
 
-    if input.is_empty() {
-        return Vec::new();
+    read_attackers(ctx, last_attack, read_input)
+}
+
+/// Re-prompt until the input parses, giving up with no attackers once input
+/// runs out (closed stdin or an exhausted script).
+fn read_attackers(
+    ctx: &crate::decisions::context::AttackersContext,
+    last_attack: &[AttackerDeclaration],
+    mut read: impl FnMut() -> io::Result<String>,
+) -> Vec<crate::decisions::spec::AttackerDeclaration> {
+    loop {
+        println!(
+            "\nEnter attacking creatures (comma-separated indices, 'idx:target' to pick a target,"
+        );
+        println!("'all' or 'all:target' to attack with everything, 'repeat', or 'none'/empty):");
+        print!("> ");
+        io::stdout().flush().unwrap();
+
+        let Ok(input) = read() else {
+            return Vec::new();
+        };
+
+        match parse_attackers_input(&input, ctx, last_attack) {
+            Ok(declarations) => return declarations,
+            Err(message) => println!("{message}"),
+        }
     }
+}
 
-    let mut declarations = Vec::new();
-    for part in input.split(',') {
-        if let Ok(idx) = part.trim().parse::<usize>()
-            && idx < ctx.attacker_options.len()
-        {
-            // Default to attacking the first opponent
-            if let Some(target) = ctx.attacker_options[idx].valid_targets.first() {
-                declarations.push(crate::decisions::spec::AttackerDeclaration {
-                    creature: ctx.attacker_options[idx].creature,
-                    target: target.clone(),
-                });
+/// Attack targets offered to at least one attacker, in first-seen order.
+fn distinct_attack_targets(ctx: &crate::decisions::context::AttackersContext) -> Vec<AttackTarget> {
+    let mut targets = Vec::new();
+    for opt in &ctx.attacker_options {
+        for target in &opt.valid_targets {
+            if !targets.contains(target) {
+                targets.push(target.clone());
             }
         }
     }
+    targets
+}
 
-    declarations
+fn attack_target_name(game: &GameState, target: &AttackTarget) -> String {
+    match target {
+        AttackTarget::Player(id) => player_name(game, *id).to_string(),
+        AttackTarget::Planeswalker(id) => game
+            .object(*id)
+            .map(|obj| obj.name.clone())
+            .unwrap_or_else(|| format!("Planeswalker #{}", id.0)),
+    }
+}
+
+/// Parse CLI attacker input into concrete declarations.
+///
+/// Accepts comma-separated creature indices (optionally `idx:target`), or the
+/// shortcuts `all`, `all:target`, `repeat`, and `none` (or empty input).
+fn parse_attackers_input(
+    input: &str,
+    ctx: &crate::decisions::context::AttackersContext,
+    last_attack: &[AttackerDeclaration],
+) -> Result<Vec<crate::decisions::spec::AttackerDeclaration>, String> {
+    let input = input.trim();
+    let targets = distinct_attack_targets(ctx);
+    let parse_target = |text: &str| -> Result<AttackTarget, String> {
+        text.trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|idx| targets.get(idx).cloned())
+            .ok_or_else(|| format!("Invalid attack target '{}'", text.trim()))
+    };
+
+    let (command, argument) = match input.split_once(':') {
+        Some((command, argument)) => (command.trim(), Some(argument)),
+        None => (input, None),
+    };
+    let shortcut = match (command.to_ascii_lowercase().as_str(), argument) {
+        ("" | "none", None) => Some(AttackShortcut::NoAttacks),
+        ("all", None) => match targets.first() {
+            Some(target) => Some(AttackShortcut::AllAttack(target.clone())),
+            None => return Ok(Vec::new()),
+        },
+        ("all", Some(argument)) => Some(AttackShortcut::AllAttack(parse_target(argument)?)),
+        ("repeat", None) => Some(AttackShortcut::RepeatLastAttack),
+        _ => None,
+    };
+
+    let declarations = if let Some(shortcut) = shortcut {
+        let options: Vec<AttackerOption> = ctx
+            .attacker_options
+            .iter()
+            .map(|opt| AttackerOption {
+                creature: opt.creature,
+                valid_targets: opt.valid_targets.clone(),
+                must_attack: opt.must_attack,
+            })
+            .collect();
+        shortcut
+            .expand(&options, last_attack)
+            .map_err(|err| format!("Invalid attack: {err}"))?
+    } else {
+        let mut declarations = Vec::new();
+        for part in input.split(',') {
+            let (index, target) = match part.split_once(':') {
+                Some((index, target)) => (index, Some(parse_target(target)?)),
+                None => (part, None),
+            };
+            let Some(opt) = index
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| ctx.attacker_options.get(idx))
+            else {
+                return Err(format!("Invalid creature index '{}'", index.trim()));
+            };
+            let target = match target {
+                Some(target) if opt.valid_targets.contains(&target) => target,
+                Some(_) => {
+                    return Err(format!("{} can't attack that target", opt.creature_name));
+                }
+                // Default to attacking the first opponent
+                None => match opt.valid_targets.first() {
+                    Some(target) => target.clone(),
+                    None => continue,
+                },
+            };
+            declarations.push(AttackerDeclaration {
+                creature: opt.creature,
+                target,
+            });
+        }
+        declarations
+    };
+
+    Ok(declarations
+        .into_iter()
+        .map(|decl| crate::decisions::spec::AttackerDeclaration {
+            creature: decl.creature,
+            target: decl.target,
+        })
+        .collect())
 }
 
 fn prompt_declare_blockers(
@@ -6338,7 +7041,7 @@ fn prompt_declare_blockers(
     }
 
     println!(
-        "\nEnter blocks as 'blocker_idx:attacker_idx' pairs (comma-separated, or empty for none):"
+        "\nEnter blocks as 'blocker_idx:attacker_idx' pairs (comma-separated, or 'none'/empty):"
     );
     println!(
         "Example: '0:0,1:0' means blocker 0 blocks attacker 0, blocker 1 also blocks attacker 0"
@@ -6349,7 +7052,7 @@ fn prompt_declare_blockers(
     let input = read_input().unwrap_or_default();
     let input = input.trim();
 
-    if input.is_empty() {
+    if input.is_empty() || input.eq_ignore_ascii_case("none") {
         return Vec::new();
     }
 
@@ -6530,6 +7233,50 @@ fn prompt_select_objects(
 }
 
 /// Prompt for selecting options by index, returning Vec<usize> directly.
+/// Prompt for an ordering of `items`.
+///
+/// Input uses the same comma-separated index format the replay decision maker
+/// parses, so recorded orderings replay as chosen. Unlisted items keep their
+/// relative order after the listed ones.
+fn prompt_order_items(items: &[(ObjectId, String)]) -> Vec<ObjectId> {
+    println!("Items:");
+    for (idx, (_, label)) in items.iter().enumerate() {
+        println!("  {}: {}", idx, label.replace('\n', " - "));
+    }
+    println!("Enter the order (comma-separated indices), or press Enter to keep it:");
+
+    loop {
+        print!("Order: ");
+        io::stdout().flush().unwrap();
+
+        let input = read_input().unwrap_or_default();
+        match parse_order_input(input.trim(), items.len()) {
+            Some(order) => return order.into_iter().map(|idx| items[idx].0).collect(),
+            None => println!("Enter distinct indices between 0 and {}.", items.len() - 1),
+        }
+    }
+}
+
+/// Parse an ordering of `len` items from comma-separated indices.
+///
+/// Returns every index exactly once, listed ones first, or `None` on a bad
+/// or repeated index.
+fn parse_order_input(input: &str, len: usize) -> Option<Vec<usize>> {
+    let mut order = Vec::with_capacity(len);
+    if !input.is_empty() {
+        for part in input.split(',') {
+            let idx = part.trim().parse::<usize>().ok()?;
+            if idx >= len || order.contains(&idx) {
+                return None;
+            }
+            order.push(idx);
+        }
+    }
+    let unlisted: Vec<usize> = (0..len).filter(|idx| !order.contains(idx)).collect();
+    order.extend(unlisted);
+    Some(order)
+}
+
 fn prompt_select_options(
     options: &[crate::decisions::context::SelectableOption],
     min: usize,
@@ -6963,7 +7710,7 @@ fn prompt_choose_targets(
             continue;
         }
 
-        println!("Select target for: {}", req.description);
+        println!("Select target for: {}", cli_text(&req.description));
         println!("Available targets:");
 
         for (i, target) in req.legal_targets.iter().enumerate() {
@@ -7073,7 +7820,7 @@ fn prompt_choose_targets(
 }
 
 /// Read a line using the global input manager.
-/// In replay mode, exits the program when inputs are exhausted.
+/// When reading scripted input, exits the program once the script runs out.
 pub fn read_input() -> io::Result<String> {
     INPUT_MANAGER.with(|im| {
         let result = im.borrow_mut().read_line();
@@ -7086,14 +7833,18 @@ pub fn read_input() -> io::Result<String> {
 }
 
 // ============================================================================
-// Input Manager for recording/replaying inputs
+// Input Manager for scripted inputs
 // ============================================================================
 
 thread_local! {
     static INPUT_MANAGER: RefCell<InputManager> = RefCell::new(InputManager::new_interactive());
 }
 
-/// Manages input for the CLI - can read from stdin, record to file, or replay from file.
+/// Manages input for the CLI - reads from stdin or from a script of raw lines.
+///
+/// Games are recorded and replayed a decision at a time (see the `replay`
+/// module); scripts are only used to convert raw-input replays from before
+/// that format.
 struct InputManager {
     mode: InputMode,
 }
@@ -7101,9 +7852,7 @@ struct InputManager {
 enum InputMode {
     /// Normal interactive mode - read from stdin
     Interactive,
-    /// Record mode - read from stdin and write to file
-    Record { file: BufWriter<File> },
-    /// Replay mode - read from file
+    /// Replay mode - read from a script of raw input lines
     Replay { lines: Vec<String>, index: usize },
 }
 
@@ -7114,44 +7863,36 @@ impl InputManager {
         }
     }
 
-    fn new_record(path: &str) -> io::Result<Self> {
-        let file = File::create(path)?;
-        Ok(Self {
-            mode: InputMode::Record {
-                file: BufWriter::new(file),
-            },
-        })
-    }
-
-    fn new_replay(path: &str) -> io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        // Keep empty lines (they're meaningful - e.g., "no attackers"), only skip comments
-        let lines: Vec<String> = reader
-            .lines()
-            .map_while(Result::ok)
-            .filter(|l| !l.trim().starts_with('#'))
-            .collect();
-        Ok(Self {
+    fn new_replay(lines: Vec<String>) -> Self {
+        Self {
             mode: InputMode::Replay { lines, index: 0 },
-        })
+        }
     }
 
-    /// Read a line of input (from stdin or replay file).
-    /// In record mode, also writes to the record file.
+    /// Read a line of input (from stdin or the script).
+    ///
+    /// Meta-commands for the active prompt are answered here and skipped, so
+    /// they never reach the decision.
     fn read_line(&mut self) -> io::Result<String> {
+        loop {
+            let input = self.next_line()?;
+            if !run_meta_command(&input) {
+                return Ok(input);
+            }
+        }
+    }
+
+    /// Read the next raw line from stdin or the script.
+    fn next_line(&mut self) -> io::Result<String> {
         match &mut self.mode {
             InputMode::Interactive => {
                 let mut input = String::new();
-                io::stdin().read_line(&mut input)?;
-                Ok(input)
-            }
-            InputMode::Record { file } => {
-                let mut input = String::new();
-                io::stdin().read_line(&mut input)?;
-                // Write the trimmed input to the record file
-                writeln!(file, "{}", input.trim())?;
-                file.flush()?;
+                if io::stdin().read_line(&mut input)? == 0 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Standard input closed",
+                    ));
+                }
                 Ok(input)
             }
             InputMode::Replay { lines, index } => {
@@ -7178,24 +7919,11 @@ impl InputManager {
     }
 }
 
-/// Initialize the global input manager.
-pub fn init_input_manager(record_file: Option<&str>, replay_file: Option<&str>) {
-    INPUT_MANAGER.with(|im| {
-        let manager = if let Some(path) = replay_file {
-            InputManager::new_replay(path).unwrap_or_else(|e| {
-                eprintln!("Failed to open replay file '{}': {}", path, e);
-                std::process::exit(1);
-            })
-        } else if let Some(path) = record_file {
-            InputManager::new_record(path).unwrap_or_else(|e| {
-                eprintln!("Failed to create record file '{}': {}", path, e);
-                std::process::exit(1);
-            })
-        } else {
-            InputManager::new_interactive()
-        };
-        *im.borrow_mut() = manager;
-    });
+/// Read CLI input from `lines` instead of stdin.
+///
+/// Used to convert raw-input replays; the program exits once the lines run out.
+pub fn init_scripted_input(lines: Vec<String>) {
+    INPUT_MANAGER.with(|im| *im.borrow_mut() = InputManager::new_replay(lines));
 }
 
 #[cfg(test)]
@@ -7261,7 +7989,7 @@ mod tests {
             ObjectId::from_raw(99),
             "test spell",
             vec![TargetRequirementContext {
-                description: "two targets".to_string(),
+                description: "two targets".into(),
                 legal_targets: vec![first, second],
                 min_targets: 2,
                 max_targets: Some(2),
@@ -7312,6 +8040,145 @@ mod tests {
         );
     }
 
+    fn shortcut_attackers_context(
+        must_attack: bool,
+    ) -> (
+        crate::decisions::context::AttackersContext,
+        ObjectId,
+        ObjectId,
+    ) {
+        use crate::decisions::context::{AttackerOptionContext, AttackersContext};
+
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let first = ObjectId::from_raw(101);
+        let second = ObjectId::from_raw(102);
+        let option = |creature, must_attack| AttackerOptionContext {
+            creature,
+            creature_name: format!("Creature {}", creature.0),
+            valid_targets: vec![AttackTarget::Player(bob)],
+            must_attack,
+        };
+        let ctx = AttackersContext::new(
+            alice,
+            vec![option(first, must_attack), option(second, false)],
+        );
+        (ctx, first, second)
+    }
+
+    #[test]
+    fn test_cli_attack_all_shortcut_expands_to_every_attacker() {
+        let (ctx, first, second) = shortcut_attackers_context(false);
+
+        let declarations = parse_attackers_input("all", &ctx, &[]).expect("all should parse");
+        let attackers: Vec<_> = declarations.iter().map(|decl| decl.creature).collect();
+        assert_eq!(attackers, vec![first, second]);
+    }
+
+    #[test]
+    fn test_cli_attack_none_shortcut_rejected_with_must_attack_creature() {
+        let (ctx, first, _) = shortcut_attackers_context(true);
+
+        assert!(parse_attackers_input("none", &ctx, &[]).is_err());
+        assert!(parse_attackers_input("", &ctx, &[]).is_err());
+        let declarations = parse_attackers_input("0", &ctx, &[]).expect("index should parse");
+        let attackers: Vec<_> = declarations.iter().map(|decl| decl.creature).collect();
+        assert_eq!(attackers, vec![first]);
+    }
+
+    #[test]
+    fn test_cli_attack_repeat_shortcut_uses_last_attack() {
+        let (ctx, _, second) = shortcut_attackers_context(false);
+        let last_attack = [AttackerDeclaration {
+            creature: second,
+            target: AttackTarget::Player(PlayerId::from_index(1)),
+        }];
+
+        let declarations =
+            parse_attackers_input("repeat", &ctx, &last_attack).expect("repeat should parse");
+        let attackers: Vec<_> = declarations.iter().map(|decl| decl.creature).collect();
+        assert_eq!(attackers, vec![second]);
+    }
+
+    fn replay_inputs(lines: &[&str]) -> InputManager {
+        InputManager::new_replay(lines.iter().map(|line| line.to_string()).collect())
+    }
+
+    #[test]
+    fn test_cli_attack_prompt_gives_up_when_input_runs_out() {
+        let (ctx, first, _) = shortcut_attackers_context(true);
+
+        // "none" is rejected for the must-attack creature; then the script
+        // runs out and the prompt stops instead of re-prompting forever.
+        let mut inputs = replay_inputs(&["none"]);
+        assert!(read_attackers(&ctx, &[], || inputs.read_line()).is_empty());
+        assert!(inputs.is_replay_exhausted());
+
+        let mut inputs = replay_inputs(&["none", "0"]);
+        let declarations = read_attackers(&ctx, &[], || inputs.read_line());
+        let attackers: Vec<_> = declarations.iter().map(|decl| decl.creature).collect();
+        assert_eq!(attackers, vec![first]);
+    }
+
+    #[test]
+    fn meta_commands_do_not_desync_a_replay() {
+        let game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let _prompt = CliPromptGuard::enter(&game, alice, PRIORITY_COMMANDS);
+
+        let mut plain = replay_inputs(&["1", "y"]);
+        let mut with_meta = replay_inputs(&["board", "1", "help", "B", "y"]);
+        for _ in 0..2 {
+            assert_eq!(with_meta.read_line().unwrap(), plain.read_line().unwrap());
+        }
+        assert!(with_meta.is_replay_exhausted());
+    }
+
+    #[test]
+    fn b_is_left_to_prompts_that_use_it() {
+        let game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let _prompt = CliPromptGuard::enter(&game, alice, COLOR_COMMANDS);
+
+        let mut inputs = replay_inputs(&["board", "b"]);
+        assert_eq!(inputs.read_line().unwrap(), "b\n");
+    }
+
+    #[test]
+    fn meta_commands_are_plain_input_outside_a_prompt() {
+        let mut inputs = replay_inputs(&["board"]);
+        assert_eq!(inputs.read_line().unwrap(), "board\n");
+    }
+
+    #[test]
+    fn board_shows_post_layer_stats_and_hides_opponent_face_down_cards() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bear = CardBuilder::new(CardId::new(), "Grizzly Bears")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        let alice_bear = game.create_object_from_card(&bear, alice, Zone::Battlefield);
+        game.object_mut(alice_bear)
+            .unwrap()
+            .counters
+            .insert(CounterType::PlusOnePlusOne, 1);
+        game.tap(alice_bear);
+        let bob_morph = game.create_object_from_card(&bear, bob, Zone::Battlefield);
+        game.set_face_down(bob_morph);
+        game.create_object_from_card(&bear, alice, Zone::Hand);
+
+        let alice_view = format_board(&game, alice);
+        assert!(alice_view.contains("Grizzly Bears 3/3 [T] [1 +1/+1]"));
+        assert!(alice_view.contains("Face-down permanent"));
+        assert!(alice_view.contains("Alice's hand: Grizzly Bears"));
+
+        let bob_view = format_board(&game, bob);
+        assert!(bob_view.contains("Grizzly Bears (face down)"));
+        assert!(!bob_view.contains("Alice's hand"));
+    }
+
     #[test]
     fn test_compute_legal_attackers_respects_cant_attack_restriction_tracker() {
         use crate::cards::definitions::grizzly_bears;
@@ -7387,7 +8254,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
 
         game.refresh_continuous_state();
         let options = compute_legal_attackers(&game, &CombatState::default());
@@ -7872,14 +8739,14 @@ mod tests {
             .push(Ability::static_ability(ability));
 
         // Condition not met.
-        game.spells_cast_this_turn.insert(bob, 1);
+        game.turn_trackers.spells_cast.insert(bob, 1);
         let spell_obj = game.object(spell_id).expect("spell exists");
         let base_cost = spell_obj.mana_cost.as_ref().expect("spell has mana cost");
         let effective = calculate_effective_mana_cost(&game, alice, spell_obj, base_cost);
         assert_eq!(effective.to_oracle(), "{2}{U}");
 
         // Condition met.
-        game.spells_cast_this_turn.insert(bob, 2);
+        game.turn_trackers.spells_cast.insert(bob, 2);
         let spell_obj = game.object(spell_id).expect("spell exists");
         let base_cost = spell_obj.mana_cost.as_ref().expect("spell has mana cost");
         let effective = calculate_effective_mana_cost(&game, alice, spell_obj, base_cost);
@@ -8032,7 +8899,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
 
         let spell_obj = game.object(spell_id).expect("spell exists");
         let base_cost = spell_obj.mana_cost.as_ref().expect("spell has mana cost");
@@ -8239,7 +9106,8 @@ mod tests {
         let effective = calculate_effective_mana_cost(&game, alice, spell_obj, base_cost);
         assert_eq!(effective.to_oracle(), "{4}{G}");
 
-        game.creatures_left_battlefield_under_controller_this_turn
+        game.turn_trackers
+            .creatures_left_battlefield_under_controller
             .insert(alice, 1);
         let spell_obj = game.object(spell_id).expect("spell exists");
         let base_cost = spell_obj.mana_cost.as_ref().expect("spell has mana cost");
@@ -8456,7 +9324,7 @@ mod tests {
         let mut game = setup_game();
         let alice = PlayerId::from_index(0);
 
-        game.life_gained_this_turn.insert(alice, 5);
+        game.turn_trackers.life_gained.insert(alice, 5);
 
         let spell_card = CardBuilder::new(CardId::from_raw(46), "Life Discount Variant")
             .card_types(vec![CardType::Creature])
@@ -8620,7 +9488,7 @@ mod tests {
 
         game.cant_effects
             .add_cast_limit_filter(alice, crate::target::ObjectFilter::default());
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
 
         assert!(
             !can_cast_spell(&game, alice, &instant_obj, &CastingMethod::Normal),
@@ -8653,7 +9521,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_type(CardType::Creature),
@@ -8695,7 +9563,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_type(CardType::Creature),
@@ -8733,7 +9601,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_type(CardType::Artifact),
@@ -8774,7 +9642,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_type(CardType::Artifact),
@@ -8816,7 +9684,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_subtype(Subtype::Phyrexian),
@@ -8866,7 +9734,7 @@ mod tests {
             &game,
         );
         game.spells_cast_this_turn_snapshots.push(prior_snapshot);
-        game.spells_cast_this_turn.insert(alice, 1);
+        game.turn_trackers.spells_cast.insert(alice, 1);
         game.cant_effects.add_cast_limit_filter(
             alice,
             crate::target::ObjectFilter::default().without_subtype(Subtype::Phyrexian),
@@ -9199,7 +10067,7 @@ mod tests {
         let ctx = BooleanContext {
             player: PlayerId::from_index(0),
             source: Some(ObjectId::from_raw(1)),
-            description: "Test?".to_string(),
+            description: "Test?".into(),
             source_name: None,
             ui_hints: crate::decisions::context::DecisionUiHints::default(),
         };
@@ -9802,6 +10670,107 @@ mod tests {
                 .any(|a| matches!(a, LegalAction::ActivateAbility { source, .. } if *source == creature_id)),
             "Should NOT be able to activate sorcery-speed ability during combat"
         );
+
+        // Main phase with a non-empty stack - should NOT be able to activate
+        game.turn.phase = Phase::FirstMain;
+        game.turn.step = None;
+        game.push_to_stack(crate::game_state::StackEntry::ability(
+            creature_id,
+            alice,
+            vec![Effect::gain_life(1)],
+        ));
+        let actions = compute_legal_actions(&game, alice);
+        assert!(
+            !actions
+                .iter()
+                .any(|a| matches!(a, LegalAction::ActivateAbility { source, .. } if *source == creature_id)),
+            "Should NOT be able to activate sorcery-speed ability while the stack is not empty"
+        );
+        game.pop_from_stack();
+
+        // Opponent's main phase - should NOT be able to activate
+        game.turn.active_player = PlayerId::from_index(1);
+        let actions = compute_legal_actions(&game, alice);
+        assert!(
+            !actions
+                .iter()
+                .any(|a| matches!(a, LegalAction::ActivateAbility { source, .. } if *source == creature_id)),
+            "Should NOT be able to activate sorcery-speed ability during an opponent's turn"
+        );
+    }
+
+    #[test]
+    fn test_activated_ability_your_turn_and_combat_timing() {
+        use crate::ability::{Ability, AbilityKind, ActivatedAbility, ActivationTiming};
+        use crate::cost::TotalCost;
+        use crate::effect::Effect;
+        use crate::game_state::Step;
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let creature = CardBuilder::new(CardId::from_raw(1), "Timing Probe")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        let creature_id = game.create_object_from_card(&creature, alice, Zone::Battlefield);
+        for timing in [
+            ActivationTiming::DuringYourTurn,
+            ActivationTiming::DuringCombat,
+        ] {
+            game.object_mut(creature_id)
+                .unwrap()
+                .abilities
+                .push(Ability {
+                    kind: AbilityKind::Activated(ActivatedAbility {
+                        mana_cost: TotalCost::free(),
+                        effects: vec![Effect::gain_life(1)],
+                        choices: vec![],
+                        timing,
+                        additional_restrictions: vec![],
+                        activation_restrictions: vec![],
+                        mana_output: None,
+                        activation_condition: None,
+                        mana_usage_restrictions: vec![],
+                    }),
+                    functional_zones: vec![Zone::Battlefield],
+                    text: None,
+                });
+        }
+        game.remove_summoning_sickness(creature_id);
+
+        let offered = |game: &GameState| -> Vec<usize> {
+            compute_legal_actions(game, alice)
+                .into_iter()
+                .filter_map(|action| match action {
+                    LegalAction::ActivateAbility {
+                        source,
+                        ability_index,
+                    } if source == creature_id => Some(ability_index),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Own main phase: only the "during your turn" ability
+        game.turn.phase = Phase::FirstMain;
+        game.turn.step = None;
+        assert_eq!(offered(&game), vec![0]);
+
+        // Own combat: both abilities
+        game.turn.phase = Phase::Combat;
+        game.turn.step = Some(Step::DeclareAttackers);
+        assert_eq!(offered(&game), vec![0, 1]);
+
+        // Opponent's combat: only the "during combat" ability
+        game.turn.active_player = bob;
+        assert_eq!(offered(&game), vec![1]);
+
+        // Opponent's main phase: neither
+        game.turn.phase = Phase::FirstMain;
+        game.turn.step = None;
+        assert!(offered(&game).is_empty());
     }
 
     #[test]
@@ -10216,7 +11185,7 @@ mod tests {
             "spectacle alternative should not be available before an opponent loses life"
         );
 
-        game.life_lost_this_turn.insert(bob, 1);
+        game.turn_trackers.life_lost.insert(bob, 1);
         let card = game
             .object(card_id)
             .expect("spectacle card should still exist");
@@ -10522,11 +11491,14 @@ mod tests {
         let spell_id = game.create_object_from_card(&card, alice, Zone::Hand);
         game.object_mut(spell_id)
             .expect("spell exists")
-            .spell_effect = Some(vec![Effect::conditional(
-            Condition::TargetSpellControllerIsPoisoned,
-            vec![Effect::counter(ChooseSpec::target_spell())],
-            vec![],
-        )]);
+            .spell_effect = Some(
+            vec![Effect::conditional(
+                Condition::TargetSpellControllerIsPoisoned,
+                vec![Effect::counter(ChooseSpec::target_spell())],
+                vec![],
+            )]
+            .into(),
+        );
 
         // With no spell on stack, the counterspell must not be castable.
         let actions_without_stack = compute_legal_actions(&game, alice);
@@ -10592,11 +11564,14 @@ mod tests {
         let spell_id = game.create_object_from_card(&card, alice, Zone::Hand);
         game.object_mut(spell_id)
             .expect("spell exists")
-            .spell_effect = Some(vec![Effect::if_then(
-            crate::effect::EffectId(0),
-            crate::effect::EffectPredicate::Happened,
-            vec![Effect::counter(ChooseSpec::target_spell())],
-        )]);
+            .spell_effect = Some(
+            vec![Effect::if_then(
+                crate::effect::EffectId(0),
+                crate::effect::EffectPredicate::Happened,
+                vec![Effect::counter(ChooseSpec::target_spell())],
+            )]
+            .into(),
+        );
 
         // With no spell on stack, the spell must not be castable.
         let actions_without_stack = compute_legal_actions(&game, alice);
@@ -10639,4 +11614,14 @@ mod tests {
             "if-effect counterspell should be castable once a legal spell target exists on stack"
         );
     }
+
+    #[test]
+    fn test_parse_order_input_fills_unlisted_items() {
+        assert_eq!(parse_order_input("", 3), Some(vec![0, 1, 2]));
+        assert_eq!(parse_order_input("2", 3), Some(vec![2, 0, 1]));
+        assert_eq!(parse_order_input("1, 0", 3), Some(vec![1, 0, 2]));
+        assert_eq!(parse_order_input("1,1", 3), None);
+        assert_eq!(parse_order_input("3", 3), None);
+        assert_eq!(parse_order_input("x", 3), None);
+    }
 }
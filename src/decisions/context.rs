@@ -6,12 +6,17 @@
 use crate::color::Color;
 use crate::combat_state::AttackTarget;
 use crate::compiled_text::{compile_effect_list, compiled_lines};
+use crate::display_text::{DisplayKey, DisplayText};
 use crate::game_state::Target;
 use crate::ids::{ObjectId, PlayerId};
 use crate::object::CounterType;
 use crate::zone::Zone;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct DecisionUiHints {
     pub context_text: Option<String>,
     pub consequence_text: Option<String>,
@@ -26,13 +31,17 @@ pub struct DecisionUiHints {
 /// Used for: may effects, ward payment, miracle trigger, madness trigger,
 /// assign damage as unblocked, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BooleanContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect (optional).
     pub source: Option<ObjectId>,
     /// Description of what the player may do.
-    pub description: String,
+    pub description: DisplayText,
     /// Name of the source card (for display).
     pub source_name: Option<String>,
     /// Optional richer UI hints for contextual rendering.
@@ -41,7 +50,11 @@ pub struct BooleanContext {
 
 impl BooleanContext {
     /// Create a new BooleanContext.
-    pub fn new(player: PlayerId, source: Option<ObjectId>, description: impl Into<String>) -> Self {
+    pub fn new(
+        player: PlayerId,
+        source: Option<ObjectId>,
+        description: impl Into<DisplayText>,
+    ) -> Self {
         Self {
             player,
             source,
@@ -76,13 +89,17 @@ impl BooleanContext {
 ///
 /// Used for: X value, "choose a number", "up to N" effects, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct NumberContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of what the number represents.
-    pub description: String,
+    pub description: DisplayText,
     /// Minimum value (inclusive).
     pub min: u32,
     /// Maximum value (inclusive).
@@ -100,7 +117,7 @@ impl NumberContext {
         source: Option<ObjectId>,
         min: u32,
         max: u32,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
     ) -> Self {
         Self {
             player,
@@ -120,7 +137,7 @@ impl NumberContext {
             source: Some(source),
             min: 0,
             max,
-            description: "Choose value for X".to_string(),
+            description: DisplayText::new(DisplayKey::ChooseXValue),
             is_x_value: true,
             ui_hints: DecisionUiHints::default(),
         }
@@ -145,6 +162,10 @@ impl NumberContext {
 ///
 /// Used for effects like "Look at target player's hand."
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ViewCardsContext {
     /// The player who is viewing the cards.
     pub viewer: PlayerId,
@@ -155,7 +176,7 @@ pub struct ViewCardsContext {
     /// The zone being viewed.
     pub zone: Zone,
     /// Description of why the cards are being viewed.
-    pub description: String,
+    pub description: DisplayText,
     /// Whether the viewed cards are publicly revealed to all players.
     pub public: bool,
 }
@@ -167,7 +188,7 @@ impl ViewCardsContext {
         subject: PlayerId,
         source: Option<ObjectId>,
         zone: Zone,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
     ) -> Self {
         Self {
             viewer,
@@ -186,7 +207,7 @@ impl ViewCardsContext {
             subject,
             source,
             Zone::Hand,
-            "Look at target player's hand",
+            DisplayText::new(DisplayKey::LookAtTargetPlayersHand),
         )
     }
 
@@ -202,6 +223,10 @@ impl ViewCardsContext {
 
 /// An object that can be selected.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct SelectableObject {
     /// The object ID.
     pub id: ObjectId,
@@ -235,13 +260,17 @@ impl SelectableObject {
 ///
 /// Used for: sacrifice, discard, search library, exile, choose legend, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct SelectObjectsContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of what kind of objects to select.
-    pub description: String,
+    pub description: DisplayText,
     /// Objects that can be selected.
     pub candidates: Vec<SelectableObject>,
     /// Minimum objects to select (0 for optional).
@@ -257,7 +286,7 @@ impl SelectObjectsContext {
     pub fn new(
         player: PlayerId,
         source: Option<ObjectId>,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         candidates: Vec<SelectableObject>,
         min: usize,
         max: Option<usize>,
@@ -290,6 +319,10 @@ impl SelectObjectsContext {
 
 /// An option that can be selected.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct SelectableOption {
     /// Index of this option.
     pub index: usize,
@@ -332,13 +365,17 @@ impl SelectableOption {
 ///
 /// Used for: modes, choices, priority actions, mana payment, replacement effects, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct SelectOptionsContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of the choice being made.
-    pub description: String,
+    pub description: DisplayText,
     /// Options to choose from.
     pub options: Vec<SelectableOption>,
     /// Minimum options to select.
@@ -354,7 +391,7 @@ impl SelectOptionsContext {
     pub fn new(
         player: PlayerId,
         source: Option<ObjectId>,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         options: Vec<SelectableOption>,
         min: usize,
         max: usize,
@@ -381,7 +418,7 @@ impl SelectOptionsContext {
         Self {
             player,
             source: Some(source),
-            description: format!("Pay mana for {}", spell_name.into()),
+            description: DisplayText::new(DisplayKey::PayManaFor).with_card(spell_name),
             options,
             min: 1,
             max: 1,
@@ -402,12 +439,10 @@ impl SelectOptionsContext {
         Self {
             player,
             source: Some(source),
-            description: format!(
-                "Pay mana pip {} for {} ({} remaining)",
-                pip_description.into(),
-                spell_name.into(),
-                remaining_pips
-            ),
+            description: DisplayText::new(DisplayKey::PayManaPipFor)
+                .with_mana_cost(pip_description)
+                .with_card(spell_name)
+                .with_number(remaining_pips as i64),
             options,
             min: 1,
             max: 1,
@@ -434,6 +469,10 @@ impl SelectOptionsContext {
 ///
 /// Used during spell casting to select modes before targets are chosen.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ModesContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -468,6 +507,10 @@ impl ModesContext {
 
 /// An option for paying a hybrid/Phyrexian mana pip.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct HybridOption {
     /// The index of this option.
     pub index: usize,
@@ -482,6 +525,10 @@ pub struct HybridOption {
 /// Used during spell casting to announce how hybrid/Phyrexian costs will be paid
 /// before targets are chosen.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct HybridChoiceContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -522,13 +569,17 @@ impl HybridChoiceContext {
 ///
 /// Used for: order blockers, order attackers, scry ordering, surveil ordering, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct OrderContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of what is being ordered.
-    pub description: String,
+    pub description: DisplayText,
     /// Items to order (as object IDs with display names).
     pub items: Vec<(ObjectId, String)>,
 }
@@ -538,7 +589,7 @@ impl OrderContext {
     pub fn new(
         player: PlayerId,
         source: Option<ObjectId>,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         items: Vec<(ObjectId, String)>,
     ) -> Self {
         Self {
@@ -556,6 +607,10 @@ impl OrderContext {
 
 /// An attacker option with its valid targets.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct AttackerOptionContext {
     /// The creature that can attack.
     pub creature: ObjectId,
@@ -569,6 +624,10 @@ pub struct AttackerOptionContext {
 
 /// Context for declaring attackers.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct AttackersContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -592,6 +651,10 @@ impl AttackersContext {
 
 /// Options for blocking a specific attacker.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BlockerOptionContext {
     /// The attacking creature.
     pub attacker: ObjectId,
@@ -605,6 +668,10 @@ pub struct BlockerOptionContext {
 
 /// Context for declaring blockers.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BlockersContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -628,6 +695,10 @@ impl BlockersContext {
 
 /// A target for distribution with display information.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct DistributeTarget {
     /// The target.
     pub target: Target,
@@ -639,13 +710,17 @@ pub struct DistributeTarget {
 ///
 /// Used for: damage distribution, counter distribution, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct DistributeContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of what is being distributed.
-    pub description: String,
+    pub description: DisplayText,
     /// Total amount to distribute.
     pub total: u32,
     /// Valid targets to distribute among.
@@ -659,7 +734,7 @@ impl DistributeContext {
     pub fn new(
         player: PlayerId,
         source: Option<ObjectId>,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         total: u32,
         targets: Vec<DistributeTarget>,
         min_per_target: u32,
@@ -683,13 +758,17 @@ impl DistributeContext {
 ///
 /// Used for: mana color selection, protection color choice, etc.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ColorsContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of the color choice.
-    pub description: String,
+    pub description: DisplayText,
     /// Number of colors to select.
     pub count: u32,
     /// If true, all selections must be the same color.
@@ -709,11 +788,7 @@ impl ColorsContext {
         Self {
             player,
             source,
-            description: if same_color {
-                format!("Choose a color for {} mana", count)
-            } else {
-                format!("Choose {} mana color(s)", count)
-            },
+            description: mana_colors_description(count, same_color),
             count,
             same_color,
             available_colors: None,
@@ -731,11 +806,7 @@ impl ColorsContext {
         Self {
             player,
             source,
-            description: if same_color {
-                format!("Choose a color for {} mana", count)
-            } else {
-                format!("Choose {} mana color(s)", count)
-            },
+            description: mana_colors_description(count, same_color),
             count,
             same_color,
             available_colors: Some(available_colors),
@@ -743,6 +814,15 @@ impl ColorsContext {
     }
 }
 
+fn mana_colors_description(count: u32, same_color: bool) -> DisplayText {
+    let key = if same_color {
+        DisplayKey::ChooseColorForMana
+    } else {
+        DisplayKey::ChooseManaColors
+    };
+    DisplayText::new(key).with_number(count)
+}
+
 // ============================================================================
 // Counters Context
 // ============================================================================
@@ -751,6 +831,10 @@ impl ColorsContext {
 ///
 /// Used for: Hex Parasite-style "remove up to X counters" effects.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct CountersContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -791,19 +875,24 @@ impl CountersContext {
 // Partition Context
 // ============================================================================
 
-/// Context for partition decisions (scry, surveil).
+/// Context for partition decisions (scry, surveil, separating piles).
 ///
 /// The response is a list of object IDs to put in the "secondary" destination:
 /// - For scry: cards to put on bottom (rest stay on top)
 /// - For surveil: cards to put in graveyard (rest stay on top)
+/// - For piles: cards to put in the second pile (rest form the first)
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct PartitionContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source of the effect.
     pub source: Option<ObjectId>,
     /// Description of the partition (e.g., "Scry 2", "Surveil 3").
-    pub description: String,
+    pub description: DisplayText,
     /// Cards to partition (ID and name).
     pub cards: Vec<(ObjectId, String)>,
     /// Label for primary destination (e.g., "top of library").
@@ -817,7 +906,7 @@ impl PartitionContext {
     pub fn new(
         player: PlayerId,
         source: Option<ObjectId>,
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         cards: Vec<(ObjectId, String)>,
         primary_label: impl Into<String>,
         secondary_label: impl Into<String>,
@@ -841,7 +930,7 @@ impl PartitionContext {
         Self::new(
             player,
             source,
-            format!("Scry {}", cards.len()),
+            DisplayText::new(DisplayKey::Scry).with_number(cards.len() as i64),
             cards,
             "top of library",
             "bottom of library",
@@ -857,12 +946,28 @@ impl PartitionContext {
         Self::new(
             player,
             source,
-            format!("Surveil {}", cards.len()),
+            DisplayText::new(DisplayKey::Surveil).with_number(cards.len() as i64),
             cards,
             "top of library",
             "graveyard",
         )
     }
+
+    /// Create a context for separating cards into two piles.
+    pub fn piles(
+        player: PlayerId,
+        source: Option<ObjectId>,
+        cards: Vec<(ObjectId, String)>,
+    ) -> Self {
+        Self::new(
+            player,
+            source,
+            format!("Separate {} card(s) into two piles", cards.len()),
+            cards,
+            "pile 1",
+            "pile 2",
+        )
+    }
 }
 
 // ============================================================================
@@ -874,6 +979,10 @@ impl PartitionContext {
 /// Player chooses any number of permanents and/or players with counters.
 /// Each chosen object/player gets one counter of each type it already has.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ProliferateContext {
     /// The player making the decision.
     pub player: PlayerId,
@@ -910,6 +1019,10 @@ impl ProliferateContext {
 ///
 /// This includes the full legal actions so responses can be converted back.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct PriorityContext {
     /// The player with priority.
     pub player: PlayerId,
@@ -930,9 +1043,13 @@ impl PriorityContext {
 
 /// A targeting requirement with legal targets.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct TargetRequirementContext {
     /// Description of what's being targeted.
-    pub description: String,
+    pub description: DisplayText,
     /// Legal targets for this requirement.
     pub legal_targets: Vec<crate::game_state::Target>,
     /// Minimum number of targets to choose.
@@ -944,7 +1061,7 @@ pub struct TargetRequirementContext {
 impl TargetRequirementContext {
     /// Create a requirement for exactly one target.
     pub fn single(
-        description: impl Into<String>,
+        description: impl Into<DisplayText>,
         legal_targets: Vec<crate::game_state::Target>,
     ) -> Self {
         Self {
@@ -958,13 +1075,17 @@ impl TargetRequirementContext {
 
 /// Context for choosing targets for a spell or ability.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct TargetsContext {
     /// The player making the decision.
     pub player: PlayerId,
     /// The source spell or ability.
     pub source: ObjectId,
     /// Description of what is being targeted (spell/ability name).
-    pub context: String,
+    pub context: DisplayText,
     /// The targeting requirements.
     pub requirements: Vec<TargetRequirementContext>,
     /// Optional richer UI hints for contextual rendering.
@@ -976,7 +1097,7 @@ impl TargetsContext {
     pub fn new(
         player: PlayerId,
         source: ObjectId,
-        context: impl Into<String>,
+        context: impl Into<DisplayText>,
         requirements: Vec<TargetRequirementContext>,
     ) -> Self {
         Self {
@@ -1007,6 +1128,10 @@ impl TargetsContext {
 ///
 /// This enum allows the `build_context()` method to return any context type.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum DecisionContext {
     Boolean(BooleanContext),
     Number(NumberContext),
@@ -1031,6 +1156,28 @@ pub enum DecisionContext {
 }
 
 impl DecisionContext {
+    /// The player making this decision.
+    pub fn player(&self) -> PlayerId {
+        match self {
+            DecisionContext::Boolean(ctx) => ctx.player,
+            DecisionContext::Number(ctx) => ctx.player,
+            DecisionContext::SelectObjects(ctx) => ctx.player,
+            DecisionContext::SelectOptions(ctx) => ctx.player,
+            DecisionContext::Modes(ctx) => ctx.player,
+            DecisionContext::HybridChoice(ctx) => ctx.player,
+            DecisionContext::Order(ctx) => ctx.player,
+            DecisionContext::Attackers(ctx) => ctx.player,
+            DecisionContext::Blockers(ctx) => ctx.player,
+            DecisionContext::Distribute(ctx) => ctx.player,
+            DecisionContext::Colors(ctx) => ctx.player,
+            DecisionContext::Counters(ctx) => ctx.player,
+            DecisionContext::Partition(ctx) => ctx.player,
+            DecisionContext::Proliferate(ctx) => ctx.player,
+            DecisionContext::Priority(ctx) => ctx.player,
+            DecisionContext::Targets(ctx) => ctx.player,
+        }
+    }
+
     pub fn source(&self) -> Option<ObjectId> {
         match self {
             DecisionContext::Boolean(ctx) => ctx.source,
@@ -1052,16 +1199,19 @@ impl DecisionContext {
         }
     }
 
-    pub fn prompt_text(&self) -> Option<&str> {
+    /// The decision's prompt, if it has one.
+    pub fn prompt(&self) -> Option<DisplayText> {
         match self {
-            DecisionContext::Boolean(ctx) => Some(&ctx.description),
-            DecisionContext::Number(ctx) => Some(&ctx.description),
-            DecisionContext::SelectObjects(ctx) => Some(&ctx.description),
-            DecisionContext::SelectOptions(ctx) => Some(&ctx.description),
-            DecisionContext::Modes(ctx) => Some(&ctx.spell_name),
-            DecisionContext::HybridChoice(ctx) => Some(&ctx.spell_name),
-            DecisionContext::Order(ctx) => Some(&ctx.description),
-            DecisionContext::Targets(ctx) => Some(&ctx.context),
+            DecisionContext::Boolean(ctx) => Some(ctx.description.clone()),
+            DecisionContext::Number(ctx) => Some(ctx.description.clone()),
+            DecisionContext::SelectObjects(ctx) => Some(ctx.description.clone()),
+            DecisionContext::SelectOptions(ctx) => Some(ctx.description.clone()),
+            DecisionContext::Modes(ctx) => Some(DisplayText::literal(ctx.spell_name.as_str())),
+            DecisionContext::HybridChoice(ctx) => {
+                Some(DisplayText::literal(ctx.spell_name.as_str()))
+            }
+            DecisionContext::Order(ctx) => Some(ctx.description.clone()),
+            DecisionContext::Targets(ctx) => Some(ctx.context.clone()),
             DecisionContext::Attackers(_)
             | DecisionContext::Blockers(_)
             | DecisionContext::Distribute(_)
@@ -1073,6 +1223,11 @@ impl DecisionContext {
         }
     }
 
+    /// The decision's prompt rendered with the default English renderer.
+    pub fn prompt_text(&self) -> Option<String> {
+        self.prompt().map(|prompt| prompt.display())
+    }
+
     pub fn context_text(&self) -> Option<&str> {
         match self {
             DecisionContext::Boolean(ctx) => ctx.ui_hints.context_text.as_deref(),
@@ -1333,7 +1488,8 @@ pub fn decision_source_text(
 }
 
 fn infer_follow_up_hints(ctx: &DecisionContext, source_text: &str) -> Option<(String, String)> {
-    let prompt = ctx.prompt_text()?.trim();
+    let prompt = ctx.prompt_text()?;
+    let prompt = prompt.trim();
     if prompt.is_empty() {
         return None;
     }
@@ -1397,7 +1553,7 @@ mod tests {
         let ctx =
             BooleanContext::new(player, None, "draw a card").with_source_name("Wall of Omens");
 
-        assert_eq!(ctx.description, "draw a card");
+        assert_eq!(ctx.description, DisplayText::literal("draw a card"));
         assert_eq!(ctx.source_name, Some("Wall of Omens".to_string()));
         assert_eq!(ctx.ui_hints, DecisionUiHints::default());
     }
@@ -1443,17 +1599,67 @@ mod tests {
         ];
         let ctx = PartitionContext::scry(player, None, cards);
 
-        assert!(ctx.description.contains("Scry 2"));
+        assert_eq!(
+            ctx.description,
+            DisplayText::new(DisplayKey::Scry).with_number(2)
+        );
         assert_eq!(ctx.secondary_label, "bottom of library");
     }
 
+    #[test]
+    fn test_payment_and_color_contexts_use_keyed_display_text() {
+        let player = PlayerId::from_index(0);
+        let source = ObjectId::from_raw(1);
+
+        let mana = SelectOptionsContext::mana_payment(player, source, "Grizzly Bears", Vec::new());
+        assert_eq!(
+            mana.description,
+            DisplayText::new(DisplayKey::PayManaFor).with_card("Grizzly Bears")
+        );
+
+        let pip = SelectOptionsContext::mana_pip_payment(
+            player,
+            source,
+            "Grizzly Bears",
+            "{G}",
+            2,
+            Vec::new(),
+        );
+        assert_eq!(
+            pip.description,
+            DisplayText::new(DisplayKey::PayManaPipFor)
+                .with_mana_cost("{G}")
+                .with_card("Grizzly Bears")
+                .with_number(2)
+        );
+
+        let x = NumberContext::x_value(player, source, 10);
+        assert_eq!(x.description, DisplayText::new(DisplayKey::ChooseXValue));
+
+        let colors = ColorsContext::any_color(player, None, 3, true);
+        assert_eq!(
+            colors.description,
+            DisplayText::new(DisplayKey::ChooseColorForMana).with_number(3)
+        );
+
+        let decision = DecisionContext::SelectOptions(mana);
+        assert_eq!(
+            decision.prompt(),
+            Some(DisplayText::new(DisplayKey::PayManaFor).with_card("Grizzly Bears"))
+        );
+        assert_eq!(
+            decision.prompt_text().as_deref(),
+            Some("Pay mana for Grizzly Bears")
+        );
+    }
+
     #[test]
     fn test_decision_context_conversions() {
         let player = PlayerId::from_index(0);
         let ctx = DecisionContext::Boolean(BooleanContext::new(player, None, "test"));
 
         let boolean = ctx.into_boolean();
-        assert_eq!(boolean.description, "test");
+        assert_eq!(boolean.description, DisplayText::literal("test"));
     }
 
     #[test]
@@ -5,6 +5,7 @@
 //! - Context structs for the new DecisionMaker trait methods (`context` module)
 //! - Concrete spec implementations for different decision types (`specs` module)
 //! - Reusable helper functions for common decision patterns (`helpers` module)
+//! - Per-player priority stops and auto-pass policies (`priority_policy` module)
 //!
 //! ## Architecture
 //!
@@ -25,6 +26,7 @@
 pub mod context;
 mod helpers;
 mod make_decision;
+pub mod priority_policy;
 pub mod spec;
 pub mod specs;
 
@@ -42,6 +44,9 @@ pub use context::{
     SelectOptionsContext, SelectableObject, SelectableOption, ViewCardsContext,
 };
 
+// Re-export priority policy types
+pub use priority_policy::{PriorityPolicy, PriorityPolicyEdit, StopConditions, TriggerYield};
+
 // Re-export the make_decision function
 pub use make_decision::{
     FromPrimitiveResponse, make_boolean_decision, make_decision, make_decision_with_fallback,
@@ -0,0 +1,666 @@
+//! Priority stops and auto-pass policies.
+//!
+//! A [`PriorityPolicy`] decides, per player, whether a priority decision should
+//! be shown to that player's `DecisionMaker` or answered with an automatic pass.
+//! It is applied by [`DecisionRouter`](crate::decision::DecisionRouter).
+//!
+//! The stop conditions mirror the networking layer's `PolicyConditions` so local
+//! and networked games share one data model: a policy token received from a peer
+//! converts directly into a `PriorityPolicy`.
+//!
+//! Players edit their policy mid-game with a [`PriorityPolicyEdit`], which the
+//! CLI produces from its `stops` command.
+
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hasher};
+
+use crate::decision::LegalAction;
+use crate::decisions::context::PriorityContext;
+use crate::game_state::{GameState, Phase, StackEntry, Step, Target};
+use crate::ids::{PlayerId, StableId};
+
+/// Conditions that interrupt auto-passing.
+///
+/// Field-for-field equivalent to the networking layer's `PolicyConditions`
+/// (without its `until_phase`, which lives on [`PriorityPolicy::pass_until`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StopConditions {
+    /// Stop whenever the stack is non-empty.
+    pub stop_on_stack_event: bool,
+    /// Stop when the top of the stack targets the player or something they control.
+    pub stop_if_targets_me: bool,
+    /// Stop in the declare attackers step if any attackers were declared.
+    pub stop_if_attackers_declared: bool,
+    /// Stop in the declare blockers step if any blockers were declared.
+    pub stop_if_blockers_declared: bool,
+}
+
+impl StopConditions {
+    /// Stop for anything on the stack and for declared attackers/blockers.
+    pub fn all() -> Self {
+        Self {
+            stop_on_stack_event: true,
+            stop_if_targets_me: true,
+            stop_if_attackers_declared: true,
+            stop_if_blockers_declared: true,
+        }
+    }
+}
+
+/// Identifies a triggered ability a player has auto-yielded to.
+///
+/// Two stack entries match when they come from the same object (tracked across
+/// zone changes) and carry the same effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriggerYield {
+    pub source: StableId,
+    pub effects_hash: u64,
+}
+
+impl TriggerYield {
+    /// Build the yield key for a triggered ability on the stack.
+    ///
+    /// Returns `None` for spells and for abilities without a tracked source.
+    pub fn for_stack_entry(entry: &StackEntry) -> Option<Self> {
+        if !entry.is_ability || entry.triggering_event.is_none() {
+            return None;
+        }
+        let source = entry.source_stable_id?;
+        let mut hasher = DefaultHasher::new();
+        crate::trigger_identity::hash_debug(&mut hasher, &entry.ability_effects).ok()?;
+        Some(Self {
+            source,
+            effects_hash: hasher.finish(),
+        })
+    }
+}
+
+/// Per-player priority stop configuration.
+///
+/// The default policy holds priority everywhere, matching a `DecisionMaker`
+/// used without a router.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriorityPolicy {
+    /// Phases/steps in which the player holds priority when the stack is empty.
+    /// `None` means every phase and step is a stop.
+    pub stops: Option<HashSet<(Phase, Option<Step>)>>,
+    /// Conditions that force a stop regardless of `stops`.
+    pub conditions: StopConditions,
+    /// Pass automatically when passing is the only meaningful action
+    /// (mana abilities alone don't count).
+    pub auto_pass_without_actions: bool,
+    /// Ignore empty-stack stops until this phase/step is reached ("pass until").
+    pub pass_until: Option<(Phase, Option<Step>)>,
+    /// Triggered abilities the player always passes priority on.
+    pub yielded_triggers: HashSet<TriggerYield>,
+}
+
+impl PriorityPolicy {
+    /// Hold priority only in the main phases (or when something is on the
+    /// stack or combat happens), and skip stops with nothing to do.
+    pub fn main_phases_only() -> Self {
+        Self::default()
+            .with_stops([(Phase::FirstMain, None), (Phase::NextMain, None)])
+            .with_conditions(StopConditions::all())
+            .with_auto_pass_without_actions(true)
+    }
+
+    /// Restrict stops to the given phases/steps.
+    pub fn with_stops(mut self, stops: impl IntoIterator<Item = (Phase, Option<Step>)>) -> Self {
+        self.stops = Some(stops.into_iter().collect());
+        self
+    }
+
+    pub fn with_conditions(mut self, conditions: StopConditions) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    pub fn with_auto_pass_without_actions(mut self, enabled: bool) -> Self {
+        self.auto_pass_without_actions = enabled;
+        self
+    }
+
+    /// Pass on an empty stack until the given phase/step begins.
+    pub fn with_pass_until(mut self, phase: Phase, step: Option<Step>) -> Self {
+        self.pass_until = Some((phase, step));
+        self
+    }
+
+    /// Auto-yield to a trigger for the rest of the game.
+    pub fn yield_to_trigger(&mut self, trigger: TriggerYield) {
+        self.yielded_triggers.insert(trigger);
+    }
+
+    /// Stop auto-yielding to a trigger.
+    pub fn cancel_trigger_yield(&mut self, trigger: TriggerYield) {
+        self.yielded_triggers.remove(&trigger);
+    }
+
+    /// Apply a mid-game change to this policy.
+    pub fn apply_edit(&mut self, edit: &PriorityPolicyEdit) {
+        match edit {
+            PriorityPolicyEdit::SetStops(stops) => self.stops = stops.clone(),
+            PriorityPolicyEdit::StopOnStackEvent(enabled) => {
+                self.conditions.stop_on_stack_event = *enabled;
+            }
+            PriorityPolicyEdit::StopIfTargetsMe(enabled) => {
+                self.conditions.stop_if_targets_me = *enabled;
+            }
+        }
+    }
+
+    /// Whether the given phase/step is a stop for this policy.
+    pub fn stops_at(&self, phase: Phase, step: Option<Step>) -> bool {
+        self.stops
+            .as_ref()
+            .is_none_or(|stops| stops.contains(&(phase, step)))
+    }
+
+    /// Decide whether this priority decision should be passed automatically.
+    ///
+    /// Reaching the `pass_until` phase/step clears it.
+    pub fn should_auto_pass(&mut self, game: &GameState, ctx: &PriorityContext) -> bool {
+        if !ctx
+            .actions
+            .iter()
+            .any(|action| matches!(action, LegalAction::PassPriority))
+        {
+            return false;
+        }
+
+        let current = (game.turn.phase, game.turn.step);
+        if self.pass_until == Some(current) {
+            self.pass_until = None;
+        }
+
+        // A player who can't do anything but pass has nothing to respond with.
+        if self.auto_pass_without_actions && !has_meaningful_action(&ctx.actions) {
+            return true;
+        }
+
+        if let Some(top) = game.stack.last() {
+            if TriggerYield::for_stack_entry(top)
+                .is_some_and(|key| self.yielded_triggers.contains(&key))
+            {
+                return true;
+            }
+            if self.conditions.stop_on_stack_event
+                || (self.conditions.stop_if_targets_me && targets_player(game, top, ctx.player))
+            {
+                return false;
+            }
+        } else if self.combat_stop(game) {
+            return false;
+        }
+
+        if !game.stack.is_empty() {
+            // No stop condition fired. Policies that narrow stops (or pass until a
+            // later step) let the stack resolve; the default policy holds priority.
+            return self.stops.is_some() || self.pass_until.is_some();
+        }
+
+        self.pass_until.is_some() || !self.stops_at(current.0, current.1)
+    }
+
+    fn combat_stop(&self, game: &GameState) -> bool {
+        let Some(combat) = game.combat.as_ref() else {
+            return false;
+        };
+        match game.turn.step {
+            Some(Step::DeclareAttackers) => {
+                self.conditions.stop_if_attackers_declared && !combat.attackers.is_empty()
+            }
+            Some(Step::DeclareBlockers) => {
+                self.conditions.stop_if_blockers_declared
+                    && combat
+                        .blockers
+                        .values()
+                        .any(|blockers| !blockers.is_empty())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A change to a player's priority stops requested mid-game, e.g. through the
+/// CLI `stops` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum PriorityPolicyEdit {
+    /// Replace the phases/steps that are stops; `None` stops everywhere.
+    SetStops(Option<HashSet<(Phase, Option<Step>)>>),
+    /// Stop whenever something is on the stack.
+    StopOnStackEvent(bool),
+    /// Stop when the top of the stack targets the player or their permanents.
+    StopIfTargetsMe(bool),
+}
+
+impl PriorityPolicyEdit {
+    /// Usage text for the `stops` command.
+    pub const USAGE: &'static str = "stops all | none | <step>... | stack on|off | targeted on|off \
+         (steps: upkeep draw main1 begincombat attackers blockers damage endcombat main2 end)";
+
+    /// Parse the arguments of a `stops` command.
+    pub fn parse(args: &str) -> Result<Self, String> {
+        let words: Vec<String> = args.split_whitespace().map(str::to_lowercase).collect();
+        match words.as_slice() {
+            [] => Err(Self::USAGE.to_string()),
+            [all] if all == "all" => Ok(Self::SetStops(None)),
+            [none] if none == "none" => Ok(Self::SetStops(Some(HashSet::new()))),
+            [toggle, value] if toggle == "stack" || toggle == "targeted" => {
+                let enabled = match value.as_str() {
+                    "on" => true,
+                    "off" => false,
+                    other => return Err(format!("expected on or off, got '{other}'")),
+                };
+                Ok(if toggle == "stack" {
+                    Self::StopOnStackEvent(enabled)
+                } else {
+                    Self::StopIfTargetsMe(enabled)
+                })
+            }
+            steps => steps
+                .iter()
+                .map(|word| {
+                    parse_stop_name(word).ok_or_else(|| format!("unknown phase or step '{word}'"))
+                })
+                .collect::<Result<HashSet<_>, _>>()
+                .map(|stops| Self::SetStops(Some(stops))),
+        }
+    }
+}
+
+fn parse_stop_name(word: &str) -> Option<(Phase, Option<Step>)> {
+    Some(match word {
+        "upkeep" => (Phase::Beginning, Some(Step::Upkeep)),
+        "draw" => (Phase::Beginning, Some(Step::Draw)),
+        "main1" => (Phase::FirstMain, None),
+        "begincombat" => (Phase::Combat, Some(Step::BeginCombat)),
+        "attackers" => (Phase::Combat, Some(Step::DeclareAttackers)),
+        "blockers" => (Phase::Combat, Some(Step::DeclareBlockers)),
+        "damage" => (Phase::Combat, Some(Step::CombatDamage)),
+        "endcombat" => (Phase::Combat, Some(Step::EndCombat)),
+        "main2" => (Phase::NextMain, None),
+        "end" => (Phase::Ending, Some(Step::End)),
+        _ => return None,
+    })
+}
+
+/// Any action other than passing or activating a mana ability.
+fn has_meaningful_action(actions: &[LegalAction]) -> bool {
+    actions.iter().any(|action| {
+        !matches!(
+            action,
+            LegalAction::PassPriority | LegalAction::ActivateManaAbility { .. }
+        )
+    })
+}
+
+fn targets_player(game: &GameState, entry: &StackEntry, player: PlayerId) -> bool {
+    entry.targets.iter().any(|target| match target {
+        Target::Player(id) => *id == player,
+        Target::Object(id) => game.object(*id).is_some_and(|obj| obj.controller == player),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::definitions::{basic_forest, basic_mountain, grizzly_bears, lightning_bolt};
+    use crate::combat_state::CombatState;
+    use crate::decision::{DecisionMaker, DecisionRouter};
+    use crate::game_loop::execute_turn_with;
+    use crate::triggers::TriggerQueue;
+    use crate::zone::Zone;
+
+    /// Passes every decision while recording where it was asked for priority.
+    struct RecordingPasser {
+        prompts: std::rc::Rc<std::cell::RefCell<Vec<(Phase, Option<Step>)>>>,
+    }
+
+    impl DecisionMaker for RecordingPasser {
+        fn decide_priority(&mut self, game: &GameState, _ctx: &PriorityContext) -> LegalAction {
+            self.prompts
+                .borrow_mut()
+                .push((game.turn.phase, game.turn.step));
+            LegalAction::PassPriority
+        }
+    }
+
+    fn setup_game() -> GameState {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        for player in [PlayerId::from_index(0), PlayerId::from_index(1)] {
+            let forest =
+                game.create_object_from_definition(&basic_forest(), player, Zone::Battlefield);
+            game.remove_summoning_sickness(forest);
+            game.create_object_from_definition(&grizzly_bears(), player, Zone::Hand);
+            for _ in 0..3 {
+                game.create_object_from_definition(&basic_forest(), player, Zone::Library);
+            }
+        }
+        game.turn.turn_number = 2;
+        game
+    }
+
+    fn priority_ctx(player: PlayerId) -> PriorityContext {
+        PriorityContext::new(player, vec![LegalAction::PassPriority])
+    }
+
+    #[test]
+    fn default_policy_never_auto_passes() {
+        let game = setup_game();
+        let mut policy = PriorityPolicy::default();
+        assert!(!policy.should_auto_pass(&game, &priority_ctx(PlayerId::from_index(0))));
+    }
+
+    #[test]
+    fn auto_pass_ignores_mana_abilities_but_not_castable_spells() {
+        let game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let mut policy = PriorityPolicy::default().with_auto_pass_without_actions(true);
+
+        let mana_only = PriorityContext::new(
+            alice,
+            vec![
+                LegalAction::PassPriority,
+                LegalAction::ActivateManaAbility {
+                    source: crate::ids::ObjectId(1),
+                    ability_index: 0,
+                },
+            ],
+        );
+        assert!(policy.should_auto_pass(&game, &mana_only));
+
+        let with_land = PriorityContext::new(
+            alice,
+            vec![
+                LegalAction::PassPriority,
+                LegalAction::PlayLand {
+                    land_id: crate::ids::ObjectId(1),
+                },
+            ],
+        );
+        assert!(!policy.should_auto_pass(&game, &with_land));
+    }
+
+    #[test]
+    fn pass_until_clears_when_phase_is_reached() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let mut policy = PriorityPolicy::default().with_pass_until(Phase::Ending, Some(Step::End));
+
+        game.turn.phase = Phase::NextMain;
+        game.turn.step = None;
+        assert!(policy.should_auto_pass(&game, &priority_ctx(alice)));
+
+        game.turn.phase = Phase::Ending;
+        game.turn.step = Some(Step::End);
+        assert!(!policy.should_auto_pass(&game, &priority_ctx(alice)));
+        assert_eq!(policy.pass_until, None);
+    }
+
+    #[test]
+    fn yielded_trigger_is_passed_even_when_stopping_on_stack() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let event = crate::triggers::TriggerEvent::new_with_provenance(
+            crate::events::other::CounterPlacedEvent::new(
+                source,
+                crate::object::CounterType::PlusOnePlusOne,
+                1,
+            ),
+            crate::provenance::ProvNodeId::default(),
+        );
+        let entry = StackEntry::ability(source, alice, vec![crate::effect::Effect::draw(1)])
+            .with_source_stable_id(StableId(source))
+            .with_triggering_event(event);
+        let key = TriggerYield::for_stack_entry(&entry).expect("trigger should have a yield key");
+        game.stack.push(entry);
+
+        let mut policy = PriorityPolicy::default().with_conditions(StopConditions::all());
+        assert!(!policy.should_auto_pass(&game, &priority_ctx(alice)));
+
+        policy.yield_to_trigger(key);
+        assert!(policy.should_auto_pass(&game, &priority_ctx(alice)));
+    }
+
+    #[test]
+    fn main_phase_policy_never_prompts_outside_main_phases() {
+        let mut game = setup_game();
+        let prompts = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut dm = DecisionRouter::new(Box::new(RecordingPasser {
+            prompts: prompts.clone(),
+        }))
+        .with_priority_policy(PlayerId::from_index(0), PriorityPolicy::main_phases_only())
+        .with_priority_policy(PlayerId::from_index(1), PriorityPolicy::main_phases_only());
+
+        let mut combat = CombatState::default();
+        let mut trigger_queue = TriggerQueue::new();
+        execute_turn_with(&mut game, &mut combat, &mut trigger_queue, &mut dm)
+            .expect("turn should complete");
+
+        let prompts = prompts.borrow();
+        assert!(!prompts.is_empty(), "main phases should still prompt");
+        assert!(
+            prompts
+                .iter()
+                .all(|(phase, _)| matches!(phase, Phase::FirstMain | Phase::NextMain)),
+            "unexpected prompts outside main phases: {prompts:?}"
+        );
+    }
+
+    #[test]
+    fn without_policy_router_prompts_in_every_step() {
+        let mut game = setup_game();
+        let prompts = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut dm = DecisionRouter::new(Box::new(RecordingPasser {
+            prompts: prompts.clone(),
+        }));
+
+        let mut combat = CombatState::default();
+        let mut trigger_queue = TriggerQueue::new();
+        execute_turn_with(&mut game, &mut combat, &mut trigger_queue, &mut dm)
+            .expect("turn should complete");
+
+        assert!(
+            prompts
+                .borrow()
+                .iter()
+                .any(|(phase, _)| *phase == Phase::Beginning),
+            "expected upkeep/draw prompts without a policy"
+        );
+    }
+
+    #[test]
+    fn stops_command_parses_steps_and_toggles() {
+        assert_eq!(
+            PriorityPolicyEdit::parse(" main1 blockers END "),
+            Ok(PriorityPolicyEdit::SetStops(Some(HashSet::from([
+                (Phase::FirstMain, None),
+                (Phase::Combat, Some(Step::DeclareBlockers)),
+                (Phase::Ending, Some(Step::End)),
+            ]))))
+        );
+        assert_eq!(
+            PriorityPolicyEdit::parse("all"),
+            Ok(PriorityPolicyEdit::SetStops(None))
+        );
+        assert_eq!(
+            PriorityPolicyEdit::parse("targeted off"),
+            Ok(PriorityPolicyEdit::StopIfTargetsMe(false))
+        );
+        assert!(PriorityPolicyEdit::parse("").is_err());
+        assert!(PriorityPolicyEdit::parse("main3").is_err());
+        assert!(PriorityPolicyEdit::parse("stack maybe").is_err());
+
+        let mut policy = PriorityPolicy::default();
+        policy.apply_edit(&PriorityPolicyEdit::StopOnStackEvent(true));
+        policy.apply_edit(&PriorityPolicyEdit::SetStops(Some(HashSet::new())));
+        assert!(policy.conditions.stop_on_stack_event);
+        assert!(!policy.stops_at(Phase::FirstMain, None));
+    }
+
+    /// Bob attacks with everything and bolts Alice in his declare attackers
+    /// step; everyone else passes. Records every priority prompt.
+    struct BoltingAttacker {
+        bob: PlayerId,
+        alice: PlayerId,
+        bolt_in_combat: bool,
+        prompts: std::rc::Rc<std::cell::RefCell<Vec<(PlayerId, Phase, Option<Step>, usize)>>>,
+    }
+
+    impl DecisionMaker for BoltingAttacker {
+        fn decide_priority(&mut self, game: &GameState, ctx: &PriorityContext) -> LegalAction {
+            self.prompts.borrow_mut().push((
+                ctx.player,
+                game.turn.phase,
+                game.turn.step,
+                game.stack.len(),
+            ));
+            if self.bolt_in_combat
+                && ctx.player == self.bob
+                && game.turn.step == Some(Step::DeclareAttackers)
+                && let Some(cast) = ctx
+                    .actions
+                    .iter()
+                    .find(|action| matches!(action, LegalAction::CastSpell { .. }))
+            {
+                self.bolt_in_combat = false;
+                return cast.clone();
+            }
+            LegalAction::PassPriority
+        }
+
+        fn decide_attackers(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::AttackersContext,
+        ) -> Vec<crate::decisions::spec::AttackerDeclaration> {
+            ctx.attacker_options
+                .iter()
+                .map(|option| crate::decisions::spec::AttackerDeclaration {
+                    creature: option.creature,
+                    target: option.valid_targets[0].clone(),
+                })
+                .collect()
+        }
+
+        fn decide_targets(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::TargetsContext,
+        ) -> Vec<Target> {
+            vec![Target::Player(self.alice)]
+        }
+    }
+
+    fn alice_combat_prompts(bolt_in_combat: bool) -> Vec<(Option<Step>, usize)> {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.turn.active_player = bob;
+        game.turn.priority_player = Some(bob);
+        let bears = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+        game.remove_summoning_sickness(bears);
+        let mountain =
+            game.create_object_from_definition(&basic_mountain(), bob, Zone::Battlefield);
+        game.remove_summoning_sickness(mountain);
+        game.create_object_from_definition(&lightning_bolt(), bob, Zone::Hand);
+
+        let main_phases_unless_targeted = PriorityPolicy::default()
+            .with_stops([(Phase::FirstMain, None), (Phase::NextMain, None)])
+            .with_conditions(StopConditions {
+                stop_if_targets_me: true,
+                ..StopConditions::default()
+            });
+        let prompts = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut dm = DecisionRouter::new(Box::new(BoltingAttacker {
+            bob,
+            alice,
+            bolt_in_combat,
+            prompts: prompts.clone(),
+        }))
+        .with_priority_policy(alice, main_phases_unless_targeted);
+
+        let mut combat = CombatState::default();
+        let mut trigger_queue = TriggerQueue::new();
+        execute_turn_with(&mut game, &mut combat, &mut trigger_queue, &mut dm)
+            .expect("turn should complete");
+
+        let expected_life = if bolt_in_combat { 15 } else { 18 };
+        assert_eq!(game.player(alice).unwrap().life, expected_life);
+
+        prompts
+            .borrow()
+            .iter()
+            .filter(|(player, phase, _, _)| *player == alice && *phase == Phase::Combat)
+            .map(|(_, _, step, stack)| (*step, *stack))
+            .collect()
+    }
+
+    #[test]
+    fn main_phase_stops_skip_opponents_combat_unless_targeted() {
+        assert_eq!(alice_combat_prompts(false), Vec::new());
+        assert_eq!(
+            alice_combat_prompts(true),
+            vec![(Some(Step::DeclareAttackers), 1)]
+        );
+    }
+
+    /// Asks for `stops main2` at its first priority prompt, like a CLI user.
+    struct StopsEditor {
+        edit: Option<PriorityPolicyEdit>,
+        prompts: std::rc::Rc<std::cell::RefCell<Vec<(Phase, Option<Step>)>>>,
+    }
+
+    impl DecisionMaker for StopsEditor {
+        fn decide_priority(&mut self, game: &GameState, _ctx: &PriorityContext) -> LegalAction {
+            self.prompts
+                .borrow_mut()
+                .push((game.turn.phase, game.turn.step));
+            LegalAction::PassPriority
+        }
+
+        fn take_priority_policy_edit(&mut self) -> Option<PriorityPolicyEdit> {
+            self.edit.take()
+        }
+    }
+
+    #[test]
+    fn router_applies_stops_edit_requested_mid_game() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        game.turn.active_player = alice;
+        game.turn.priority_player = Some(alice);
+        let prompts = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut dm = DecisionRouter::new(Box::new(StopsEditor {
+            edit: PriorityPolicyEdit::parse("main2").ok(),
+            prompts: prompts.clone(),
+        }))
+        .with_priority_policy(PlayerId::from_index(1), PriorityPolicy::main_phases_only());
+
+        let mut combat = CombatState::default();
+        let mut trigger_queue = TriggerQueue::new();
+        execute_turn_with(&mut game, &mut combat, &mut trigger_queue, &mut dm)
+            .expect("turn should complete");
+
+        let prompts = prompts.borrow();
+        let (first, rest) = prompts.split_first().expect("first stop should prompt");
+        assert_eq!(*first, (Phase::Beginning, Some(Step::Upkeep)));
+        assert!(!rest.is_empty(), "main2 should still prompt");
+        assert!(
+            rest.iter().all(|(phase, _)| *phase == Phase::NextMain),
+            "only main2 should prompt after the edit: {rest:?}"
+        );
+        assert_eq!(
+            dm.priority_policy_mut(alice)
+                .and_then(|policy| policy.stops.clone()),
+            Some(HashSet::from([(Phase::NextMain, None)]))
+        );
+    }
+}
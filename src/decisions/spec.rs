@@ -182,6 +182,10 @@ pub struct AttackerOption {
 
 /// A declared attacker.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct AttackerDeclaration {
     /// The attacking creature.
     pub creature: ObjectId,
@@ -202,6 +206,10 @@ pub struct BlockerOption {
 
 /// A declared blocker.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct BlockerDeclaration {
     /// The blocking creature.
     pub blocker: ObjectId,
@@ -647,6 +647,10 @@ impl ProliferateSpec {
 
 /// Response for proliferate: selected permanents and players.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ProliferateResponse {
     pub permanents: Vec<ObjectId>,
     pub players: Vec<PlayerId>,
@@ -844,7 +848,7 @@ fn runtime_requirements(
     requirements
         .iter()
         .map(|req| crate::decisions::context::TargetRequirementContext {
-            description: req.description.clone(),
+            description: req.description.clone().into(),
             legal_targets: req.legal_targets.clone(),
             min_targets: req.min_targets,
             max_targets: req.max_targets,
@@ -7,6 +7,7 @@ use crate::alternative_cast::CastingMethod;
 use crate::decision::{FallbackStrategy, LegalAction};
 use crate::decisions::context::{DecisionContext, SelectOptionsContext, SelectableOption};
 use crate::decisions::spec::{DecisionPrimitive, DecisionSpec, DisplayOption};
+use crate::display_text::{DisplayKey, DisplayText};
 use crate::game_state::GameState;
 use crate::ids::{ObjectId, PlayerId};
 use crate::zone::Zone;
@@ -29,6 +30,10 @@ fn zone_description(zone: Zone) -> &'static str {
 
 /// A mode option for a modal spell/ability.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ModeOption {
     /// Index of this mode.
     pub index: usize,
@@ -60,6 +65,10 @@ impl ModeOption {
 
 /// Specification for choosing mode(s) for a modal spell/ability.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ModesSpec {
     /// The source spell or ability.
     pub source: ObjectId,
@@ -425,7 +434,7 @@ impl DecisionSpec for OptionalCostsSpec {
         DecisionContext::SelectOptions(SelectOptionsContext::new(
             player,
             Some(self.source),
-            "Choose optional costs to pay",
+            DisplayText::new(DisplayKey::ChooseOptionalCostsToPay),
             options,
             0,
             self.options.len(),
@@ -1,7 +1,8 @@
 //! Special decision specifications.
 //!
 //! These specs are for specialized decisions that don't fit neatly into
-//! the other categories: scry, surveil, distribute, mana colors, counter removal.
+//! the other categories: scry, surveil, separating piles, distribute, mana
+//! colors, counter removal.
 
 use crate::color::Color;
 use crate::decision::FallbackStrategy;
@@ -140,6 +141,151 @@ impl DecisionSpec for SurveilSpec {
     }
 }
 
+// ============================================================================
+// SeparatePilesSpec - Separate cards into two piles
+// ============================================================================
+
+/// Specification for separating cards into two piles.
+/// Either pile may be empty.
+#[derive(Debug, Clone)]
+pub struct SeparatePilesSpec {
+    /// The source of the effect.
+    pub source: ObjectId,
+    /// The cards being separated.
+    pub cards: Vec<ObjectId>,
+}
+
+impl SeparatePilesSpec {
+    /// Create a new SeparatePilesSpec.
+    pub fn new(source: ObjectId, cards: Vec<ObjectId>) -> Self {
+        Self { source, cards }
+    }
+}
+
+/// Response for separating piles: cards in the second pile.
+/// Cards not in this list form the first pile.
+pub type SeparatePilesResponse = Vec<ObjectId>;
+
+impl DecisionSpec for SeparatePilesSpec {
+    type Response = SeparatePilesResponse;
+
+    fn description(&self) -> String {
+        format!("Separate {} card(s) into two piles", self.cards.len())
+    }
+
+    fn primitive(&self) -> DecisionPrimitive {
+        DecisionPrimitive::Partition
+    }
+
+    fn default_response(&self, _strategy: FallbackStrategy) -> SeparatePilesResponse {
+        // Default: everything in the first pile
+        Vec::new()
+    }
+
+    fn build_context(
+        &self,
+        player: PlayerId,
+        _source: Option<ObjectId>,
+        game: &GameState,
+    ) -> DecisionContext {
+        let cards: Vec<(ObjectId, String)> = self
+            .cards
+            .iter()
+            .map(|&id| (id, object_name(game, id)))
+            .collect();
+
+        DecisionContext::Partition(PartitionContext::piles(player, Some(self.source), cards))
+    }
+}
+
+// ============================================================================
+// ChoosePileSpec - Choose one of two piles
+// ============================================================================
+
+/// Specification for choosing one of two separated piles.
+#[derive(Debug, Clone)]
+pub struct ChoosePileSpec {
+    /// The source of the effect.
+    pub source: ObjectId,
+    /// The two piles, in the order they were separated.
+    pub piles: [Vec<ObjectId>; 2],
+    /// Where the chosen pile goes (e.g., "hand").
+    pub destination: String,
+}
+
+impl ChoosePileSpec {
+    /// Create a new ChoosePileSpec.
+    pub fn new(
+        source: ObjectId,
+        piles: [Vec<ObjectId>; 2],
+        destination: impl Into<String>,
+    ) -> Self {
+        Self {
+            source,
+            piles,
+            destination: destination.into(),
+        }
+    }
+}
+
+/// Response for choosing a pile: the chosen pile's index (0 or 1).
+pub type ChoosePileResponse = usize;
+
+impl DecisionSpec for ChoosePileSpec {
+    type Response = ChoosePileResponse;
+
+    fn description(&self) -> String {
+        format!("Choose a pile to put into {}", self.destination)
+    }
+
+    fn primitive(&self) -> DecisionPrimitive {
+        DecisionPrimitive::SelectOptions { min: 1, max: 1 }
+    }
+
+    fn default_response(&self, _strategy: FallbackStrategy) -> ChoosePileResponse {
+        0
+    }
+
+    fn build_context(
+        &self,
+        player: PlayerId,
+        _source: Option<ObjectId>,
+        game: &GameState,
+    ) -> DecisionContext {
+        let options: Vec<SelectableOption> = self
+            .piles
+            .iter()
+            .enumerate()
+            .map(|(index, pile)| {
+                let contents = if pile.is_empty() {
+                    "empty".to_string()
+                } else {
+                    pile.iter()
+                        .map(|&id| object_name(game, id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                SelectableOption::new(index, format!("Pile {}: {}", index + 1, contents))
+            })
+            .collect();
+
+        DecisionContext::SelectOptions(SelectOptionsContext::new(
+            player,
+            Some(self.source),
+            self.description(),
+            options,
+            1,
+            1,
+        ))
+    }
+}
+
+fn object_name(game: &GameState, id: ObjectId) -> String {
+    game.object(id)
+        .map(|o| o.name.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
 // ============================================================================
 // OrderGraveyardSpec - Reorder a graveyard
 // ============================================================================
@@ -262,6 +408,67 @@ impl DecisionSpec for OrderLibraryTopSpec {
     }
 }
 
+// ============================================================================
+// OrderLibraryBottomSpec - Order cards going to the bottom of a library
+// ============================================================================
+
+/// Specification for ordering cards as they are put on the bottom of a library.
+#[derive(Debug, Clone)]
+pub struct OrderLibraryBottomSpec {
+    /// The source of the effect.
+    pub source: ObjectId,
+    /// The cards being ordered (top-to-bottom once placed).
+    pub cards: Vec<ObjectId>,
+}
+
+impl OrderLibraryBottomSpec {
+    pub fn new(source: ObjectId, cards: Vec<ObjectId>) -> Self {
+        Self { source, cards }
+    }
+}
+
+impl DecisionSpec for OrderLibraryBottomSpec {
+    type Response = Vec<ObjectId>;
+
+    fn description(&self) -> String {
+        "Order cards on the bottom of library".to_string()
+    }
+
+    fn primitive(&self) -> DecisionPrimitive {
+        DecisionPrimitive::Order
+    }
+
+    fn default_response(&self, _strategy: FallbackStrategy) -> Vec<ObjectId> {
+        self.cards.clone()
+    }
+
+    fn build_context(
+        &self,
+        player: PlayerId,
+        _source: Option<ObjectId>,
+        game: &GameState,
+    ) -> DecisionContext {
+        let items: Vec<(ObjectId, String)> = self
+            .cards
+            .iter()
+            .map(|&id| {
+                let name = game
+                    .object(id)
+                    .map(|o| o.name.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                (id, name)
+            })
+            .collect();
+
+        DecisionContext::Order(OrderContext::new(
+            player,
+            Some(self.source),
+            "Order cards on the bottom of library",
+            items,
+        ))
+    }
+}
+
 // ============================================================================
 // DistributeSpec - Distribute amount among targets
 // ============================================================================
@@ -706,6 +913,32 @@ mod tests {
         assert!(spec.default_response(FallbackStrategy::Decline).is_empty());
     }
 
+    #[test]
+    fn test_separate_piles_spec() {
+        let source = ObjectId::from_raw(1);
+        let cards = vec![ObjectId::from_raw(2), ObjectId::from_raw(3)];
+        let spec = SeparatePilesSpec::new(source, cards);
+
+        assert!(spec.description().contains("Separate 2"));
+        // Default puts everything in the first pile
+        assert!(spec.default_response(FallbackStrategy::Decline).is_empty());
+    }
+
+    #[test]
+    fn test_choose_pile_spec_offers_empty_piles() {
+        let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let source = ObjectId::from_raw(1);
+        let spec = ChoosePileSpec::new(source, [vec![], vec![ObjectId::from_raw(2)]], "hand");
+
+        let ctx = spec
+            .build_context(PlayerId::from_index(0), Some(source), &game)
+            .into_options();
+        assert_eq!(ctx.options.len(), 2);
+        assert_eq!(ctx.options[0].description, "Pile 1: empty");
+        assert!(ctx.options.iter().all(|option| option.legal));
+        assert_eq!(spec.default_response(FallbackStrategy::Decline), 0);
+    }
+
     #[test]
     fn test_distribute_spec_damage() {
         let source = ObjectId::from_raw(1);
@@ -449,7 +449,7 @@ fn evaluate_value(
                 .count() as i32;
             ValueEval::Scalar(count * *multiplier)
         }
-        Value::CreaturesDiedThisTurn => ValueEval::Scalar(game.creatures_died_this_turn as i32),
+        Value::CreaturesDiedThisTurn => ValueEval::Scalar(game.turn_trackers.creatures_died as i32),
         Value::CreaturesDiedThisTurnControlledBy(player_filter) => {
             let filter_ctx = crate::filter::FilterContext {
                 you: Some(effect_controller),
@@ -462,6 +462,7 @@ fn evaluate_value(
                 attacking_player: None,
                 your_commanders: Vec::new(),
                 iterated_player: None,
+                triggering_player: None,
                 target_players: Vec::new(),
                 target_objects: Vec::new(),
                 tagged_objects: std::collections::HashMap::new(),
@@ -472,7 +473,8 @@ fn evaluate_value(
                     continue;
                 }
                 total += game
-                    .creatures_died_under_controller_this_turn
+                    .turn_trackers
+                    .creatures_died_under_controller
                     .get(&player.id)
                     .copied()
                     .unwrap_or(0) as i32;
@@ -695,6 +697,7 @@ fn object_matches_filter_with_chars(
             | PlayerFilter::Defending
             | PlayerFilter::Attacking
             | PlayerFilter::DamagedPlayer
+            | PlayerFilter::TriggeringPlayer
             | PlayerFilter::EffectController
             | PlayerFilter::IteratedPlayer
             | PlayerFilter::TargetPlayerOrControllerOfTarget
@@ -968,7 +971,7 @@ fn apply_modification_to_chars_for_dependency(
             chars.abilities.push(ability.clone());
         }
         Modification::SetAbilities(abilities) => {
-            chars.abilities = abilities.clone();
+            chars.abilities = abilities.clone().into();
         }
         Modification::CopyActivatedAbilities { .. } => {}
         Modification::AddCombatDamageDrawAbility => {
@@ -1057,6 +1060,8 @@ fn value_references_pt(value: &Value) -> bool {
         | Value::GreatestManaValue(_)
         | Value::BasicLandTypesAmong(_)
         | Value::ColorsAmong(_)
+        | Value::CardTypesAmong(_)
+        | Value::ManaValuesAmong(_)
         | Value::DistinctNames(_)
         | Value::CreaturesDiedThisTurn
         | Value::CreaturesDiedThisTurnControlledBy(_)
@@ -1093,6 +1098,7 @@ fn value_references_pt(value: &Value) -> bool {
         | Value::TimesPaid(_)
         | Value::MagicGamesLostToOpponentsSinceLastWin
         | Value::TaggedCount
+        | Value::TargetCount
         | Value::EventValue(_)
         | Value::EventValueOffset(_, _) => false,
     }
@@ -80,7 +80,10 @@ impl<'a> DerivedGameView<'a> {
             .unwrap_or_default()
     }
 
-    pub(crate) fn abilities(&self, object_id: ObjectId) -> Option<Vec<crate::ability::Ability>> {
+    pub(crate) fn abilities(
+        &self,
+        object_id: ObjectId,
+    ) -> Option<crate::shared::SharedVec<crate::ability::Ability>> {
         self.calculated_characteristics(object_id)
             .map(|chars| chars.abilities)
     }
@@ -0,0 +1,249 @@
+//! Localization-ready display strings.
+//!
+//! Player-facing text (decision prompts, ability displays, log lines) is
+//! described as a [`DisplayText`]: a message key plus structured arguments.
+//! A [`DisplayRenderer`] turns it into a string for a particular locale or UI.
+//! [`EnglishRenderer`] is the default and reproduces the engine's built-in
+//! English wording.
+//!
+//! Text that hasn't been given a key yet is carried as [`DisplayKey::Literal`]
+//! and rendered verbatim, so existing `String` producers keep working through
+//! the `From<String>`/`From<&str>` conversions.
+
+use std::fmt;
+
+/// Identifies a display message independently of its wording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum DisplayKey {
+    /// Pre-rendered text, carried as a single [`DisplayArg::Text`] argument.
+    Literal,
+    /// "Choose value for X".
+    ChooseXValue,
+    /// "Look at target player's hand".
+    LookAtTargetPlayersHand,
+    /// "Pay mana for {card}".
+    PayManaFor,
+    /// "Pay mana pip {pip} for {card} ({number} remaining)".
+    PayManaPipFor,
+    /// "Choose a color for {number} mana".
+    ChooseColorForMana,
+    /// "Choose {number} mana color(s)".
+    ChooseManaColors,
+    /// "Choose casting method for {card}".
+    ChooseCastingMethodFor,
+    /// "Choose the next cost to pay for {card}".
+    ChooseNextCostFor,
+    /// "Choose optional costs for {card}".
+    ChooseOptionalCostsFor,
+    /// "Choose optional costs to pay".
+    ChooseOptionalCostsToPay,
+    /// "Scry {number}".
+    Scry,
+    /// "Surveil {number}".
+    Surveil,
+}
+
+/// A structured argument of a [`DisplayText`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum DisplayArg {
+    /// Free-form text that is already in the target language.
+    Text(String),
+    /// A card or object name.
+    CardName(String),
+    /// A number.
+    Number(i64),
+    /// A mana cost or mana symbol in oracle notation (e.g. "{2}{G}").
+    ManaCost(String),
+}
+
+impl fmt::Display for DisplayArg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplayArg::Text(text) | DisplayArg::CardName(text) | DisplayArg::ManaCost(text) => {
+                f.write_str(text)
+            }
+            DisplayArg::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+/// A display message: a key plus the arguments it is rendered with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DisplayText {
+    pub key: DisplayKey,
+    pub args: Vec<DisplayArg>,
+}
+
+impl DisplayText {
+    /// Create a message with no arguments.
+    pub fn new(key: DisplayKey) -> Self {
+        Self {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    /// Wrap pre-rendered text.
+    pub fn literal(text: impl Into<String>) -> Self {
+        Self::new(DisplayKey::Literal).with_text(text)
+    }
+
+    /// Append a free-form text argument.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.args.push(DisplayArg::Text(text.into()));
+        self
+    }
+
+    /// Append a card name argument.
+    pub fn with_card(mut self, name: impl Into<String>) -> Self {
+        self.args.push(DisplayArg::CardName(name.into()));
+        self
+    }
+
+    /// Append a number argument.
+    pub fn with_number(mut self, number: impl Into<i64>) -> Self {
+        self.args.push(DisplayArg::Number(number.into()));
+        self
+    }
+
+    /// Append a mana cost argument.
+    pub fn with_mana_cost(mut self, cost: impl Into<String>) -> Self {
+        self.args.push(DisplayArg::ManaCost(cost.into()));
+        self
+    }
+
+    /// Whether this is pre-rendered text rather than a keyed message.
+    pub fn is_literal(&self) -> bool {
+        self.key == DisplayKey::Literal
+    }
+
+    /// Render with the given renderer.
+    pub fn render(&self, renderer: &dyn DisplayRenderer) -> String {
+        renderer.render(self)
+    }
+
+    /// Render with the default English renderer.
+    pub fn display(&self) -> String {
+        EnglishRenderer.render(self)
+    }
+}
+
+impl fmt::Display for DisplayText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display())
+    }
+}
+
+impl From<String> for DisplayText {
+    fn from(text: String) -> Self {
+        Self::literal(text)
+    }
+}
+
+impl From<&str> for DisplayText {
+    fn from(text: &str) -> Self {
+        Self::literal(text)
+    }
+}
+
+impl From<&String> for DisplayText {
+    fn from(text: &String) -> Self {
+        Self::literal(text.as_str())
+    }
+}
+
+/// Turns [`DisplayText`] into user-facing strings.
+pub trait DisplayRenderer {
+    fn render(&self, text: &DisplayText) -> String;
+}
+
+/// The default renderer, producing the engine's built-in English wording.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglishRenderer;
+
+impl DisplayRenderer for EnglishRenderer {
+    fn render(&self, text: &DisplayText) -> String {
+        let arg = |index: usize| {
+            text.args
+                .get(index)
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        };
+        match text.key {
+            DisplayKey::Literal => arg(0),
+            DisplayKey::ChooseXValue => "Choose value for X".to_string(),
+            DisplayKey::LookAtTargetPlayersHand => "Look at target player's hand".to_string(),
+            DisplayKey::PayManaFor => format!("Pay mana for {}", arg(0)),
+            DisplayKey::PayManaPipFor => {
+                format!(
+                    "Pay mana pip {} for {} ({} remaining)",
+                    arg(0),
+                    arg(1),
+                    arg(2)
+                )
+            }
+            DisplayKey::ChooseColorForMana => format!("Choose a color for {} mana", arg(0)),
+            DisplayKey::ChooseManaColors => format!("Choose {} mana color(s)", arg(0)),
+            DisplayKey::ChooseCastingMethodFor => {
+                format!("Choose casting method for {}", arg(0))
+            }
+            DisplayKey::ChooseNextCostFor => {
+                format!("Choose the next cost to pay for {}", arg(0))
+            }
+            DisplayKey::ChooseOptionalCostsFor => {
+                format!("Choose optional costs for {}", arg(0))
+            }
+            DisplayKey::ChooseOptionalCostsToPay => "Choose optional costs to pay".to_string(),
+            DisplayKey::Scry => format!("Scry {}", arg(0)),
+            DisplayKey::Surveil => format!("Surveil {}", arg(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ShoutingRenderer;
+
+    impl DisplayRenderer for ShoutingRenderer {
+        fn render(&self, text: &DisplayText) -> String {
+            EnglishRenderer.render(text).to_uppercase()
+        }
+    }
+
+    #[test]
+    fn english_renderer_reproduces_builtin_wording() {
+        let pip = DisplayText::new(DisplayKey::PayManaPipFor)
+            .with_mana_cost("{G}")
+            .with_card("Grizzly Bears")
+            .with_number(1);
+        assert_eq!(
+            pip.display(),
+            "Pay mana pip {G} for Grizzly Bears (1 remaining)"
+        );
+        assert_eq!(
+            DisplayText::literal("Sacrifice a creature").to_string(),
+            "Sacrifice a creature"
+        );
+    }
+
+    #[test]
+    fn renderers_are_pluggable() {
+        let scry = DisplayText::new(DisplayKey::Scry).with_number(2);
+        assert_eq!(scry.render(&ShoutingRenderer), "SCRY 2");
+        assert_eq!(scry.render(&EnglishRenderer), "Scry 2");
+    }
+}
@@ -38,6 +38,10 @@ use std::sync::Arc;
 /// Used to reference effects for conditional logic ("if you do" patterns).
 /// Effects are labeled with `Effect::WithId` and referenced by `Effect::If`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct EffectId(pub u32);
 
 impl EffectId {
@@ -51,6 +55,10 @@ impl EffectId {
 /// Used for effects like "Exile any number of target spells" (Mindbreak Trap)
 /// or "Choose up to two target creatures".
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ChoiceCount {
     /// Minimum number to choose (0 for "any number" or "up to").
     pub min: usize,
@@ -291,6 +299,8 @@ pub enum ExecutionFact {
     AffectedObjects(Vec<ObjectId>),
     ChosenOptions(Vec<usize>),
     ChosenNumber(u32),
+    /// Damage prevented while this effect dealt damage.
+    PreventedDamage(u32),
 }
 
 impl ExecutionFact {
@@ -781,6 +791,10 @@ impl Comparison {
 
 /// A value that can be fixed, variable (X), or computed.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Value {
     /// A fixed numeric value
     Fixed(i32),
@@ -824,6 +838,16 @@ pub enum Value {
     /// Number of distinct colors among objects matching a filter.
     ColorsAmong(ObjectFilter),
 
+    /// Number of distinct card types among objects matching a filter.
+    ///
+    /// Used for phrases like "the number of card types among cards in all graveyards."
+    CardTypesAmong(ObjectFilter),
+
+    /// Number of distinct mana values among objects matching a filter.
+    ///
+    /// Used for phrases like "the number of different mana values among nonland permanents you control."
+    ManaValuesAmong(ObjectFilter),
+
     /// Number of distinct object names among objects matching a filter.
     ///
     /// Used for phrases like "the number of differently named lands you control."
@@ -976,6 +1000,12 @@ pub enum Value {
     /// Convenience for multikicker cards.
     KickCount,
 
+    /// The number of targets the resolving spell or ability still has.
+    ///
+    /// For "up to N" targets this is however many were chosen, less any that
+    /// became illegal before resolution, and may be 0.
+    TargetCount,
+
     /// Number of Magic games you've lost to one of your opponents since
     /// you last won a game against them.
     ///
@@ -1014,6 +1044,10 @@ pub enum Value {
 
 /// Event payload fields that can be referenced by [`Value::EventValue`].
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum EventValueSpec {
     /// Generic "that much" amount from triggering life gain/loss or damage events.
     Amount,
@@ -1051,6 +1085,15 @@ pub enum Restriction {
     WinGame(PlayerFilter),
     PreventDamage,
     Attack(ObjectFilter),
+    /// Matching creatures can't attack players matching the player filter.
+    ///
+    /// With `include_planeswalkers`, they also can't attack planeswalkers those
+    /// players control ("can't attack you or planeswalkers you control").
+    AttackPlayer {
+        attackers: ObjectFilter,
+        defenders: PlayerFilter,
+        include_planeswalkers: bool,
+    },
     AttackAlone(ObjectFilter),
     Block(ObjectFilter),
     BlockSpecificAttacker {
@@ -1071,6 +1114,12 @@ pub enum Restriction {
     BeTargeted(ObjectFilter),
     BeTargetedPlayer(PlayerFilter),
     BeCountered(ObjectFilter),
+    /// Matching spells can't be countered by spells or abilities controlled by
+    /// players matching the player filter.
+    BeCounteredBy {
+        spells: ObjectFilter,
+        counterers: PlayerFilter,
+    },
     Transform(ObjectFilter),
     AttackOrBlock(ObjectFilter),
     AttackOrBlockAlone(ObjectFilter),
@@ -1180,6 +1229,25 @@ impl Restriction {
         Self::Attack(filter)
     }
 
+    pub fn attack_player(attackers: ObjectFilter, defenders: PlayerFilter) -> Self {
+        Self::AttackPlayer {
+            attackers,
+            defenders,
+            include_planeswalkers: false,
+        }
+    }
+
+    pub fn attack_player_or_planeswalkers(
+        attackers: ObjectFilter,
+        defenders: PlayerFilter,
+    ) -> Self {
+        Self::AttackPlayer {
+            attackers,
+            defenders,
+            include_planeswalkers: true,
+        }
+    }
+
     pub fn attack_alone(filter: ObjectFilter) -> Self {
         Self::AttackAlone(filter)
     }
@@ -1236,6 +1304,10 @@ impl Restriction {
         Self::BeCountered(filter)
     }
 
+    pub fn be_countered_by(spells: ObjectFilter, counterers: PlayerFilter) -> Self {
+        Self::BeCounteredBy { spells, counterers }
+    }
+
     pub fn transform(filter: ObjectFilter) -> Self {
         Self::Transform(filter)
     }
@@ -1411,6 +1483,45 @@ impl Restriction {
                     }
                 }
             }
+            Restriction::AttackPlayer {
+                attackers,
+                defenders,
+                include_planeswalkers,
+            } => {
+                let defending_players = game
+                    .players
+                    .iter()
+                    .filter(|player| {
+                        player.is_in_game()
+                            && player_matches_filter_with_combat(
+                                player.id, defenders, game, controller, combat,
+                            )
+                    })
+                    .map(|player| player.id)
+                    .collect::<Vec<_>>();
+                if defending_players.is_empty() {
+                    return;
+                }
+
+                for &obj_id in &game.battlefield {
+                    if let Some(obj) = game.object(obj_id)
+                        && attackers.matches(obj, &ctx, game)
+                    {
+                        tracker
+                            .cant_attack_players
+                            .entry(obj_id)
+                            .or_default()
+                            .extend(defending_players.iter().copied());
+                        if *include_planeswalkers {
+                            tracker
+                                .cant_attack_planeswalkers_of
+                                .entry(obj_id)
+                                .or_default()
+                                .extend(defending_players.iter().copied());
+                        }
+                    }
+                }
+            }
             Restriction::AttackAlone(filter) => {
                 for &obj_id in &game.battlefield {
                     if let Some(obj) = game.object(obj_id)
@@ -1545,7 +1656,13 @@ impl Restriction {
                 }
             }
             Restriction::BeTargeted(filter) => {
-                for &obj_id in &game.battlefield {
+                // Graveyard cards are only considered when the filter names that zone.
+                let graveyards = game
+                    .players
+                    .iter()
+                    .filter(|_| filter.zone == Some(Zone::Graveyard))
+                    .flat_map(|player| player.graveyard.iter());
+                for &obj_id in game.battlefield.iter().chain(graveyards) {
                     if let Some(obj) = game.object(obj_id)
                         && filter.matches(obj, &ctx, game)
                     {
@@ -1574,6 +1691,34 @@ impl Restriction {
                     }
                 }
             }
+            Restriction::BeCounteredBy { spells, counterers } => {
+                let counterer_players = game
+                    .players
+                    .iter()
+                    .filter(|player| {
+                        player_matches_filter_with_combat(
+                            player.id, counterers, game, controller, combat,
+                        )
+                    })
+                    .map(|player| player.id)
+                    .collect::<Vec<_>>();
+                if counterer_players.is_empty() {
+                    return;
+                }
+
+                for entry in &game.stack {
+                    let obj_id = entry.object_id;
+                    if let Some(obj) = game.object(obj_id)
+                        && spells.matches(obj, &ctx, game)
+                    {
+                        tracker
+                            .cant_be_countered_by
+                            .entry(obj_id)
+                            .or_default()
+                            .extend(counterer_players.iter().copied());
+                    }
+                }
+            }
             Restriction::Transform(filter) => {
                 for &obj_id in &game.battlefield {
                     if let Some(obj) = game.object(obj_id)
@@ -1761,6 +1906,10 @@ impl Effect {
 
 /// Duration for temporary effects.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Until {
     /// Permanent (until removed)
     #[default]
@@ -1772,6 +1921,9 @@ pub enum Until {
     /// Until your next turn
     YourNextTurn,
 
+    /// Until the beginning of your next end step
+    YourNextEndStep,
+
     /// Until that permanent's controller's next untap step
     ControllersNextUntapStep,
 
@@ -1788,6 +1940,29 @@ pub enum Until {
     TurnsPass(Value),
 }
 
+impl Until {
+    /// Whether a duration measured from `controller` ends when the `boundary`
+    /// duration is reached during `active_player`'s turn.
+    ///
+    /// Only durations that end at a point in the turn are answered here;
+    /// source-bound durations and `TurnsPass` never end at a boundary.
+    pub fn ends_at(&self, boundary: &Until, controller: PlayerId, active_player: PlayerId) -> bool {
+        if self != boundary {
+            return false;
+        }
+        match self {
+            Until::EndOfTurn | Until::EndOfCombat => true,
+            Until::YourNextTurn | Until::YourNextEndStep | Until::ControllersNextUntapStep => {
+                controller == active_player
+            }
+            Until::Forever
+            | Until::ThisLeavesTheBattlefield
+            | Until::YouStopControllingThis
+            | Until::TurnsPass(_) => false,
+        }
+    }
+}
+
 /// A mode for modal spells.
 #[derive(Debug, Clone, PartialEq)]
 pub struct EffectMode {
@@ -1911,12 +2086,18 @@ pub enum Condition {
     /// A specific player cast N or more spells this turn.
     PlayerCastSpellsThisTurnOrMore { player: PlayerFilter, count: u32 },
 
+    /// You cast a spell with this name this game, other than this spell.
+    YouCastAnotherSpellNamedThisGame(String),
+
     /// You attacked with one or more creatures this turn
     AttackedThisTurn,
 
     /// An opponent lost life this turn.
     OpponentLostLifeThisTurn,
 
+    /// You gained life this turn.
+    YouGainedLifeThisTurn,
+
     /// A permanent left the battlefield under your control this turn.
     PermanentLeftBattlefieldUnderYourControlThisTurn,
 
@@ -1957,6 +2138,9 @@ pub enum Condition {
     /// This resolving spell paid the optional cost with the given label.
     ThisSpellPaidLabel(String),
 
+    /// This resolving spell was cast from the given zone.
+    ThisSpellWasCastFromZone(Zone),
+
     /// The targeted spell was the Nth spell cast this turn.
     TargetSpellCastOrderThisTurn(u32),
 
@@ -2209,6 +2393,12 @@ impl Effect {
         Self::new(ConniveEffect::new(target))
     }
 
+    /// Create a "connive N" effect.
+    pub fn connive_n(target: ChooseSpec, count: impl Into<Value>) -> Self {
+        use crate::effects::ConniveEffect;
+        Self::new(ConniveEffect::with_count(target, count))
+    }
+
     /// Create a "goad target creature" effect.
     pub fn goad(target: ChooseSpec) -> Self {
         use crate::effects::GoadEffect;
@@ -2353,6 +2543,12 @@ impl Effect {
         Self::new(ExchangeLifeTotalsEffect::new(player1, player2))
     }
 
+    /// Create an "exchange hands" effect.
+    pub fn exchange_hands(player1: PlayerFilter, player2: PlayerFilter) -> Self {
+        use crate::effects::ExchangeZonesEffect;
+        Self::new(ExchangeZonesEffect::new(Zone::Hand, player1, player2))
+    }
+
     /// Create a "destroy target permanent" effect.
     pub fn destroy(choice: ChooseSpec) -> Self {
         use crate::effects::DestroyEffect;
@@ -2562,6 +2758,19 @@ impl Effect {
         Self::new(CopySpellEffect::new(target, count))
     }
 
+    /// Create a "copy target activated or triggered ability" effect.
+    pub fn copy_ability(target: ChooseSpec) -> Self {
+        use crate::effects::CopyAbilityEffect;
+        Self::new(CopyAbilityEffect::target(target))
+    }
+
+    /// Create a "copy it. You may choose new targets for the copy" effect for
+    /// the ability whose activation triggered this effect.
+    pub fn copy_triggering_ability() -> Self {
+        use crate::effects::CopyAbilityEffect;
+        Self::new(CopyAbilityEffect::triggering_ability().with_new_targets())
+    }
+
     /// Create a "choose new targets" effect for objects from a prior effect result.
     pub fn choose_new_targets(from_effect: EffectId) -> Self {
         use crate::effects::ChooseNewTargetsEffect;
@@ -3816,6 +4025,12 @@ impl Effect {
         Self::new(GainControlEffect::new(target, duration))
     }
 
+    /// Create a "`player` gains control of `target`" effect that lasts indefinitely.
+    pub fn donate(target: ChooseSpec, player: PlayerFilter) -> Self {
+        use crate::effects::GainControlEffect;
+        Self::new(GainControlEffect::donate(target, player))
+    }
+
     /// Create an "exchange control" effect between two permanents.
     pub fn exchange_control(permanent1: ChooseSpec, permanent2: ChooseSpec) -> Self {
         use crate::effects::ExchangeControlEffect;
@@ -3898,6 +4113,14 @@ impl Effect {
         Self::new(ScryEffect::new(count, player))
     }
 
+    /// Create a Fact or Fiction effect: reveal the top N cards, an opponent
+    /// separates them into two piles, and you put one pile into your hand
+    /// and the other into your graveyard.
+    pub fn fact_or_fiction(count: impl Into<Value>) -> Self {
+        use crate::effects::FactOrFictionEffect;
+        Self::new(FactOrFictionEffect::you_choose(count))
+    }
+
     /// Create a "discover N" effect.
     pub fn discover(count: impl Into<Value>) -> Self {
         use crate::effects::DiscoverEffect;
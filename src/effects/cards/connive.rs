@@ -1,9 +1,12 @@
 //! Connive effect implementation.
 
-use crate::effect::EffectOutcome;
+use crate::effect::{EffectOutcome, Value};
 use crate::effects::DrawCardsEffect;
 use crate::effects::EffectExecutor;
-use crate::effects::helpers::{normalize_object_selection, resolve_objects_for_effect};
+use crate::effects::helpers::{
+    normalize_object_selection, resolve_objects_for_effect, resolve_value,
+};
+use crate::events::cards::DiscardEvent;
 use crate::events::cause::EventCause;
 use crate::events::{KeywordActionEvent, KeywordActionKind};
 use crate::executor::{ExecutionContext, ExecutionError};
@@ -15,16 +18,27 @@ use crate::types::CardType;
 
 /// Effect that makes target creature(s) connive.
 ///
-/// Connive: Draw a card, then discard a card.
-/// If a nonland card was discarded this way, put a +1/+1 counter on that creature.
+/// Connive N (rule 701.50): Draw N cards, then discard N cards. Put a +1/+1
+/// counter on the conniving creature for each nonland card discarded this way.
+/// Plain "connive" is connive 1.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConniveEffect {
     pub target: ChooseSpec,
+    /// How many cards to draw and then discard.
+    pub count: Value,
 }
 
 impl ConniveEffect {
     pub fn new(target: ChooseSpec) -> Self {
-        Self { target }
+        Self::with_count(target, 1)
+    }
+
+    /// Create a connive N effect.
+    pub fn with_count(target: ChooseSpec, count: impl Into<Value>) -> Self {
+        Self {
+            target,
+            count: count.into(),
+        }
     }
 }
 
@@ -34,10 +48,15 @@ impl EffectExecutor for ConniveEffect {
         game: &mut GameState,
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
+        use crate::decisions::make_decision;
+        use crate::decisions::specs::ChooseObjectsSpec;
+        use crate::event_processor::execute_discard;
+
         let target_ids = resolve_objects_for_effect(game, ctx, &self.target)?;
         if target_ids.is_empty() {
             return Ok(EffectOutcome::target_invalid());
         }
+        let count = resolve_value(game, &self.count, ctx)?.max(0) as u32;
 
         let mut outcomes = Vec::new();
         for target_id in target_ids {
@@ -53,65 +72,78 @@ impl EffectExecutor for ConniveEffect {
             let controller = target_obj.controller;
             let mut events = Vec::new();
             events.push(TriggerEvent::new_with_provenance(
-                KeywordActionEvent::new(KeywordActionKind::Connive, controller, ctx.source, 1),
+                KeywordActionEvent::new(KeywordActionKind::Connive, controller, ctx.source, count),
                 ctx.provenance,
             ));
 
-            // Draw one card first.
-            let draw_outcome =
-                DrawCardsEffect::new(1, PlayerFilter::Specific(controller)).execute(game, ctx)?;
+            // Draw first. Drawing from an empty library is left for the
+            // state-based action check.
+            let draw_outcome = DrawCardsEffect::new(count, PlayerFilter::Specific(controller))
+                .execute(game, ctx)?;
             events.extend(draw_outcome.events);
 
-            // Then discard one card if possible.
+            // Then discard as many of the N cards as possible.
             let hand_cards: Vec<ObjectId> = game
                 .player(controller)
                 .map(|p| p.hand.iter().copied().collect())
                 .unwrap_or_default();
+            let required = (count as usize).min(hand_cards.len());
 
-            if !hand_cards.is_empty() {
-                use crate::decisions::make_decision;
-                use crate::decisions::specs::ChooseObjectsSpec;
-                use crate::event_processor::execute_discard;
-
+            let mut nonland_discarded = 0;
+            if required > 0 {
                 let spec = ChooseObjectsSpec::new(
                     ctx.source,
-                    "Choose a card to discard for connive".to_string(),
+                    format!(
+                        "Choose {} card{} to discard for connive",
+                        required,
+                        if required == 1 { "" } else { "s" }
+                    ),
                     hand_cards.clone(),
-                    1,
-                    Some(1),
+                    required,
+                    Some(required),
                 );
                 let chosen: Vec<_> =
                     make_decision(game, ctx.decision_maker, controller, Some(ctx.source), spec);
-                let selected = normalize_object_selection(chosen, &hand_cards, 1);
-                if let Some(card_to_discard) = selected.first().copied() {
-                    let discarded_nonland = game
+                let cause = EventCause::from_effect(ctx.source, ctx.controller);
+                for card_to_discard in normalize_object_selection(chosen, &hand_cards, required) {
+                    let is_nonland = game
                         .object(card_to_discard)
-                        .map(|obj| !obj.has_card_type(CardType::Land))
-                        .unwrap_or(false);
+                        .is_some_and(|obj| !obj.has_card_type(CardType::Land));
                     let discard_result = execute_discard(
                         game,
                         card_to_discard,
                         controller,
-                        EventCause::from_effect(ctx.source, ctx.controller),
+                        cause.clone(),
                         false,
                         ctx.provenance,
                         &mut *ctx.decision_maker,
                     );
-
-                    if !discard_result.prevented && discarded_nonland {
-                        if let Some(event) = game.add_counters_with_source(
-                            target_id,
-                            crate::object::CounterType::PlusOnePlusOne,
-                            1,
-                            Some(ctx.source),
-                            Some(ctx.controller),
-                        ) {
-                            events.push(event);
-                        }
+                    if discard_result.prevented {
+                        continue;
+                    }
+                    events.push(TriggerEvent::new_with_provenance(
+                        DiscardEvent::with_cause(card_to_discard, controller, cause.clone())
+                            .with_destination(discard_result.final_zone),
+                        ctx.provenance,
+                    ));
+                    if is_nonland {
+                        nonland_discarded += 1;
                     }
                 }
             }
 
+            if nonland_discarded > 0
+                && let Some(event) = game.add_counters_with_source(
+                    target_id,
+                    crate::object::CounterType::PlusOnePlusOne,
+                    nonland_discarded,
+                    Some(ctx.source),
+                    Some(ctx.controller),
+                )
+            {
+                events.push(event);
+            }
+
             outcomes.push(EffectOutcome::resolved().with_events(events));
         }
 
@@ -131,6 +163,7 @@ impl EffectExecutor for ConniveEffect {
 mod tests {
     use super::*;
     use crate::card::{CardBuilder, PowerToughness};
+    use crate::events::EventKind;
     use crate::ids::{CardId, PlayerId};
     use crate::mana::{ManaCost, ManaSymbol};
     use crate::zone::Zone;
@@ -151,6 +184,25 @@ mod tests {
         game.create_object_from_card(&card, owner, Zone::Hand)
     }
 
+    fn add_cards_to_library(game: &mut GameState, owner: PlayerId, count: usize) {
+        for _ in 0..count {
+            let card = CardBuilder::new(CardId::new(), "Library Land")
+                .card_types(vec![CardType::Land])
+                .build();
+            game.create_object_from_card(&card, owner, Zone::Library);
+        }
+    }
+
+    fn plus_one_counters(game: &GameState, creature: ObjectId) -> u32 {
+        game.object(creature)
+            .and_then(|obj| {
+                obj.counters
+                    .get(&crate::object::CounterType::PlusOnePlusOne)
+            })
+            .copied()
+            .unwrap_or(0)
+    }
+
     fn create_creature(game: &mut GameState, owner: PlayerId) -> ObjectId {
         let card = CardBuilder::new(CardId::new(), "Conniver")
             .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2)]]))
@@ -205,4 +257,62 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn connive_two_puts_counter_for_each_nonland_discarded() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let creature = create_creature(&mut game, alice);
+        add_cards_to_library(&mut game, alice, 2);
+        add_card_to_hand(&mut game, alice, vec![CardType::Instant]);
+        add_card_to_hand(&mut game, alice, vec![CardType::Sorcery]);
+
+        // The hand now holds two nonland cards and (after drawing) two lands.
+        // Choose the nonland cards first so both discards count.
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let effect = ConniveEffect::with_count(ChooseSpec::SpecificObject(creature), 2);
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert!(result.status.is_success());
+        assert_eq!(plus_one_counters(&game, creature), 2);
+        assert_eq!(game.player(alice).unwrap().hand.len(), 2);
+        assert_eq!(game.player(alice).unwrap().graveyard.len(), 2);
+
+        let kinds: Vec<_> = result.events.iter().map(|event| event.kind()).collect();
+        assert!(kinds.contains(&EventKind::CardsDrawn));
+        assert_eq!(
+            kinds
+                .iter()
+                .filter(|kind| **kind == EventKind::Discard)
+                .count(),
+            2
+        );
+        assert!(kinds.contains(&EventKind::MarkersChanged));
+    }
+
+    #[test]
+    fn connive_with_empty_library_loses_at_next_state_based_action_check() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let creature = create_creature(&mut game, alice);
+        add_card_to_hand(&mut game, alice, vec![CardType::Instant]);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let effect = ConniveEffect::new(ChooseSpec::SpecificObject(creature));
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        // The discard still happens even though nothing was drawn.
+        assert_eq!(plus_one_counters(&game, creature), 1);
+        assert!(
+            crate::rules::state_based::check_state_based_actions(&game).contains(
+                &crate::rules::state_based::StateBasedAction::PlayerLoses {
+                    player: alice,
+                    reason: crate::rules::state_based::LoseReason::DrewFromEmptyLibrary,
+                }
+            )
+        );
+    }
 }
@@ -2,11 +2,12 @@
 
 use crate::effect::{EffectOutcome, ExecutionFact, Value};
 use crate::effects::helpers::{normalize_object_selection, resolve_player_filter, resolve_value};
-use crate::effects::{CostExecutableEffect, EffectExecutor};
+use crate::effects::{CostExecutableEffect, EffectExecutor, ExecutabilityReport};
 use crate::events::cards::DiscardEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::filter::ObjectFilter;
 use crate::game_state::GameState;
+use crate::ids::{ObjectId, PlayerId};
 use crate::snapshot::ObjectSnapshot;
 use crate::tag::TagKey;
 use crate::target::PlayerFilter;
@@ -95,6 +96,27 @@ impl DiscardEffect {
         Self::new(count, PlayerFilter::Opponent, false)
     }
 
+    /// Cards in the player's hand that this effect may discard.
+    fn discardable_cards(
+        &self,
+        game: &GameState,
+        ctx: &ExecutionContext,
+        player_id: PlayerId,
+    ) -> Vec<ObjectId> {
+        let mut hand_cards: Vec<_> = game
+            .player(player_id)
+            .map(|p| p.hand.to_vec())
+            .unwrap_or_default();
+        if let Some(filter) = &self.card_filter {
+            let filter_ctx = ctx.filter_context(game);
+            hand_cards.retain(|card_id| {
+                game.object(*card_id)
+                    .is_some_and(|obj| filter.matches(obj, &filter_ctx, game))
+            });
+        }
+        hand_cards
+    }
+
     fn discards_source_as_cost(&self) -> bool {
         self.card_filter
             .as_ref()
@@ -124,6 +146,22 @@ impl EffectExecutor for DiscardEffect {
         Some(self)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let (Ok(player_id), Ok(count)) = (
+            resolve_player_filter(game, &self.player, ctx),
+            resolve_value(game, &self.count, ctx),
+        ) else {
+            return ExecutabilityReport::Unknown;
+        };
+        if count <= 0 {
+            return ExecutabilityReport::impossible("nothing to discard");
+        }
+        ExecutabilityReport::executable_if(
+            !self.discardable_cards(game, ctx, player_id).is_empty(),
+            "no cards to discard",
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -139,17 +177,7 @@ impl EffectExecutor for DiscardEffect {
         let mut discarded_snapshots = Vec::new();
         let mut discard_events = Vec::new();
 
-        let mut hand_cards: Vec<_> = game
-            .player(player_id)
-            .map(|p| p.hand.iter().copied().collect())
-            .unwrap_or_default();
-        if let Some(filter) = &self.card_filter {
-            let filter_ctx = ctx.filter_context(game);
-            hand_cards.retain(|card_id| {
-                game.object(*card_id)
-                    .is_some_and(|obj| filter.matches(obj, &filter_ctx, game))
-            });
-        }
+        let mut hand_cards = self.discardable_cards(game, ctx, player_id);
 
         let required = count.min(hand_cards.len());
         if required == 0 {
@@ -291,7 +319,7 @@ impl CostExecutableEffect for DiscardEffect {
 
         let mut hand_cards: Vec<_> = game
             .player(player_id)
-            .map(|p| p.hand.iter().copied().collect())
+            .map(|p| p.hand.to_vec())
             .unwrap_or_default();
 
         if let Some(filter) = &self.card_filter {
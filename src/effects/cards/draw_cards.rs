@@ -1,11 +1,12 @@
 //! DrawCards effect implementation.
 
 use crate::effect::{EffectOutcome, Value};
-use crate::effects::EffectExecutor;
 use crate::effects::helpers::{resolve_player_filter, resolve_value};
+use crate::effects::{EffectExecutor, ExecutabilityReport};
 use crate::events::CardsDrawnEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
+use crate::ids::PlayerId;
 use crate::target::PlayerFilter;
 use crate::triggers::TriggerEvent;
 
@@ -54,7 +55,48 @@ impl DrawCardsEffect {
     }
 }
 
+/// Whether the player controls a permanent that replaces drawing from an
+/// empty library (Laboratory Maniac style), making such a draw meaningful.
+fn replaces_empty_library_draw(game: &GameState, player_id: PlayerId) -> bool {
+    game.battlefield.iter().any(|&id| {
+        game.object(id)
+            .is_some_and(|obj| obj.controller == player_id)
+            && game.object_has_static_ability_id(
+                id,
+                crate::static_abilities::StaticAbilityId::DrawFromEmptyLibraryWinInstead,
+            )
+    })
+}
+
 impl EffectExecutor for DrawCardsEffect {
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let (Ok(player_id), Ok(count)) = (
+            resolve_player_filter(game, &self.player, ctx),
+            resolve_value(game, &self.count, ctx),
+        ) else {
+            return ExecutabilityReport::Unknown;
+        };
+        if count <= 0 {
+            return ExecutabilityReport::impossible("no cards to draw");
+        }
+        if !game.can_draw(player_id)
+            || (!game.can_draw_extra_cards(player_id)
+                && game
+                    .cards_drawn_this_turn
+                    .get(&player_id)
+                    .is_some_and(|&drawn| drawn >= 1))
+        {
+            return ExecutabilityReport::impossible("player can't draw cards");
+        }
+        let library_empty = game
+            .player(player_id)
+            .is_none_or(|player| player.library.is_empty());
+        ExecutabilityReport::executable_if(
+            !library_empty || replaces_empty_library_draw(game, player_id),
+            "library is empty",
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -86,45 +128,64 @@ impl EffectExecutor for DrawCardsEffect {
             count
         };
 
-        // Process through replacement effects with decision maker
-        match process_draw(game, player_id, count, is_first, &mut *ctx.decision_maker) {
-            EventOutcome::Prevented => Ok(EffectOutcome::prevented()),
-            EventOutcome::Proceed(final_count) => {
-                let drawn = game.draw_cards_with_dm(
-                    player_id,
-                    final_count as usize,
-                    &mut *ctx.decision_maker,
-                );
-
-                // Track cards drawn this turn
-                let cards_before = *game.cards_drawn_this_turn.entry(player_id).or_insert(0);
-                *game.cards_drawn_this_turn.entry(player_id).or_insert(0) += drawn.len() as u32;
-
-                let is_first = cards_before == 0;
-                let count = drawn.len() as i32;
-
-                // Only emit event if cards were actually drawn
-                if drawn.is_empty() {
-                    return Ok(EffectOutcome::count(0));
+        // Draws past the end of the library happen one card at a time, so each
+        // one is its own event for "while your library has no cards in it"
+        // replacements (Laboratory Maniac).
+        let available = game
+            .player(player_id)
+            .map_or(0, |player| player.library.len() as u32);
+        let from_library = count.min(available);
+        let past_end = count - from_library;
+        let batches = std::iter::once(from_library)
+            .filter(|&batch| batch > 0 || past_end == 0)
+            .chain(std::iter::repeat_n(1, past_end as usize));
+
+        let mut drawn = Vec::new();
+        let mut replaced = false;
+        let mut prevented = false;
+        for batch in batches {
+            let is_first = is_first && drawn.is_empty();
+            // Process through replacement effects with decision maker
+            match process_draw(game, player_id, batch, is_first, &mut *ctx.decision_maker) {
+                EventOutcome::Proceed(final_count) => {
+                    drawn.extend(game.draw_cards_with_dm(
+                        player_id,
+                        final_count as usize,
+                        &mut *ctx.decision_maker,
+                    ));
                 }
+                // Replacement effects already executed by process_draw
+                EventOutcome::Replaced => replaced = true,
+                // Player can't draw (no library, etc.)
+                EventOutcome::Prevented | EventOutcome::NotApplicable => prevented = true,
+            }
+        }
 
-                // Create a single CardsDrawnEvent with all drawn cards
-                let event = TriggerEvent::new_with_provenance(
-                    CardsDrawnEvent::new(player_id, drawn, is_first),
-                    ctx.provenance,
-                );
+        // Track cards drawn this turn
+        let cards_before = *game.cards_drawn_this_turn.entry(player_id).or_insert(0);
+        *game.cards_drawn_this_turn.entry(player_id).or_insert(0) += drawn.len() as u32;
 
-                Ok(EffectOutcome::count(count).with_event(event))
-            }
-            EventOutcome::Replaced => {
-                // Replacement effects already executed by process_draw
-                Ok(EffectOutcome::replaced())
+        // Only emit event if cards were actually drawn
+        if drawn.is_empty() {
+            if replaced {
+                return Ok(EffectOutcome::replaced());
             }
-            EventOutcome::NotApplicable => {
-                // Player can't draw (no library, etc.)
-                Ok(EffectOutcome::prevented())
+            if prevented {
+                return Ok(EffectOutcome::prevented());
             }
+            return Ok(EffectOutcome::count(0));
         }
+
+        let is_first = cards_before == 0;
+        let count = drawn.len() as i32;
+
+        // Create a single CardsDrawnEvent with all drawn cards
+        let event = TriggerEvent::new_with_provenance(
+            CardsDrawnEvent::new(player_id, drawn, is_first),
+            ctx.provenance,
+        );
+
+        Ok(EffectOutcome::count(count).with_event(event))
     }
 }
 
@@ -0,0 +1,355 @@
+//! Fact or Fiction effect implementation.
+
+use crate::decisions::context::ViewCardsContext;
+use crate::decisions::{
+    ChoiceSpec, ChoosePileSpec, DisplayOption, SeparatePilesSpec, make_decision,
+};
+use crate::effect::{EffectOutcome, Value};
+use crate::effects::EffectExecutor;
+use crate::effects::helpers::{resolve_player_filter, resolve_value};
+use crate::effects::zones::apply_zone_change;
+use crate::executor::{ExecutionContext, ExecutionError};
+use crate::game_state::GameState;
+use crate::ids::{ObjectId, PlayerId};
+use crate::target::PlayerFilter;
+use crate::zone::Zone;
+
+/// Effect that reveals the top N cards of your library, has one player
+/// separate them into two piles and another choose a pile.
+///
+/// The chosen pile goes into your hand and the other into your graveyard.
+/// Revealing more cards than the library holds reveals the whole library,
+/// and either pile may be empty (Rule 700.3).
+///
+/// # Fields
+///
+/// * `count` - Number of cards to reveal
+/// * `separator` - The player who separates the piles
+/// * `chooser` - The player who chooses the pile for your hand
+///
+/// # Example
+///
+/// ```ignore
+/// // Fact or Fiction: an opponent separates, you choose
+/// let effect = FactOrFictionEffect::you_choose(5);
+///
+/// // Steam Augury: you separate, an opponent chooses
+/// let effect = FactOrFictionEffect::new(5, PlayerFilter::You, PlayerFilter::Opponent);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactOrFictionEffect {
+    /// Number of cards to reveal.
+    pub count: Value,
+    /// The player who separates the revealed cards into two piles.
+    pub separator: PlayerFilter,
+    /// The player who chooses which pile goes into your hand.
+    pub chooser: PlayerFilter,
+}
+
+impl FactOrFictionEffect {
+    /// Create a new Fact or Fiction effect.
+    pub fn new(count: impl Into<Value>, separator: PlayerFilter, chooser: PlayerFilter) -> Self {
+        Self {
+            count: count.into(),
+            separator,
+            chooser,
+        }
+    }
+
+    /// An opponent separates the piles and the controller chooses one.
+    pub fn you_choose(count: impl Into<Value>) -> Self {
+        Self::new(count, PlayerFilter::Opponent, PlayerFilter::You)
+    }
+}
+
+/// Resolve the player for one role. "An opponent" is any opponent the
+/// controller picks, so it doesn't need a target.
+fn resolve_role(
+    game: &GameState,
+    ctx: &mut ExecutionContext,
+    filter: &PlayerFilter,
+) -> Result<PlayerId, ExecutionError> {
+    if *filter != PlayerFilter::Opponent {
+        return resolve_player_filter(game, filter, ctx);
+    }
+
+    let opponents: Vec<PlayerId> = game
+        .players
+        .iter()
+        .filter(|player| player.id != ctx.controller && player.is_in_game())
+        .map(|player| player.id)
+        .collect();
+    if opponents.len() <= 1 {
+        return opponents.first().copied().ok_or_else(|| {
+            ExecutionError::UnresolvableValue("No opponent to separate piles".to_string())
+        });
+    }
+
+    let options: Vec<DisplayOption> = opponents
+        .iter()
+        .enumerate()
+        .map(|(index, player_id)| {
+            let name = game
+                .player(*player_id)
+                .map(|player| player.name.clone())
+                .unwrap_or_else(|| format!("Player {}", player_id.0));
+            DisplayOption::new(index, name)
+        })
+        .collect();
+    let chosen = make_decision(
+        game,
+        &mut ctx.decision_maker,
+        ctx.controller,
+        Some(ctx.source),
+        ChoiceSpec::single(ctx.source, options),
+    );
+    let index = chosen.first().copied().unwrap_or(0);
+    Ok(opponents.get(index).copied().unwrap_or(opponents[0]))
+}
+
+impl EffectExecutor for FactOrFictionEffect {
+    fn execute(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+    ) -> Result<EffectOutcome, ExecutionError> {
+        let owner = ctx.controller;
+        let count = resolve_value(game, &self.count, ctx)?.max(0) as usize;
+
+        // Top N cards, top-to-bottom (the top is the end of the library vec)
+        let revealed: Vec<ObjectId> = game
+            .player(owner)
+            .map(|p| p.library.iter().rev().take(count).copied().collect())
+            .unwrap_or_default();
+        if revealed.is_empty() {
+            return Ok(EffectOutcome::count(0));
+        }
+
+        for viewer_idx in 0..game.players.len() {
+            let viewer = PlayerId::from_index(viewer_idx as u8);
+            let view_ctx = ViewCardsContext::new(
+                viewer,
+                owner,
+                Some(ctx.source),
+                Zone::Library,
+                "Reveal the top cards of a library",
+            )
+            .with_public(true);
+            ctx.decision_maker
+                .view_cards(game, viewer, &revealed, &view_ctx);
+        }
+
+        let separator = resolve_role(game, ctx, &self.separator)?;
+        let chooser = resolve_role(game, ctx, &self.chooser)?;
+
+        let second: Vec<ObjectId> = make_decision(
+            game,
+            &mut ctx.decision_maker,
+            separator,
+            Some(ctx.source),
+            SeparatePilesSpec::new(ctx.source, revealed.clone()),
+        );
+        let (second, first): (Vec<ObjectId>, Vec<ObjectId>) =
+            revealed.iter().partition(|id| second.contains(id));
+
+        let chosen = make_decision(
+            game,
+            &mut ctx.decision_maker,
+            chooser,
+            Some(ctx.source),
+            ChoosePileSpec::new(ctx.source, [first.clone(), second.clone()], "hand"),
+        );
+        let (to_hand, to_graveyard) = if chosen == 1 {
+            (second, first)
+        } else {
+            (first, second)
+        };
+
+        for &card_id in &to_hand {
+            let _ = apply_zone_change(
+                game,
+                card_id,
+                Zone::Library,
+                Zone::Hand,
+                &mut *ctx.decision_maker,
+            );
+        }
+        for &card_id in &to_graveyard {
+            let _ = apply_zone_change(
+                game,
+                card_id,
+                Zone::Library,
+                Zone::Graveyard,
+                &mut *ctx.decision_maker,
+            );
+        }
+
+        Ok(EffectOutcome::count(to_hand.len() as i32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardBuilder;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{PartitionContext, SelectOptionsContext};
+    use crate::ids::CardId;
+    use crate::types::CardType;
+
+    /// Puts the named cards in the second pile and picks a fixed pile,
+    /// recording who made each decision.
+    struct PileDm {
+        second_pile: Vec<&'static str>,
+        chosen_pile: usize,
+        separated_by: Option<PlayerId>,
+        chosen_by: Option<PlayerId>,
+        pile_options: Vec<String>,
+    }
+
+    impl PileDm {
+        fn new(second_pile: Vec<&'static str>, chosen_pile: usize) -> Self {
+            Self {
+                second_pile,
+                chosen_pile,
+                separated_by: None,
+                chosen_by: None,
+                pile_options: Vec::new(),
+            }
+        }
+    }
+
+    impl DecisionMaker for PileDm {
+        fn decide_partition(&mut self, _game: &GameState, ctx: &PartitionContext) -> Vec<ObjectId> {
+            self.separated_by = Some(ctx.player);
+            ctx.cards
+                .iter()
+                .filter(|(_, name)| self.second_pile.contains(&name.as_str()))
+                .map(|(id, _)| *id)
+                .collect()
+        }
+
+        fn decide_options(&mut self, _game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+            self.chosen_by = Some(ctx.player);
+            self.pile_options = ctx
+                .options
+                .iter()
+                .map(|option| option.description.clone())
+                .collect();
+            vec![self.chosen_pile]
+        }
+    }
+
+    fn setup_library(game: &mut GameState, owner: PlayerId, names: &[&str]) {
+        // Created bottom-to-top, so the last name ends up on top.
+        for &name in names {
+            let card = CardBuilder::new(CardId::new(), name)
+                .card_types(vec![CardType::Instant])
+                .build();
+            game.create_object_from_card(&card, owner, Zone::Library);
+        }
+    }
+
+    fn names_in(game: &GameState, ids: &[ObjectId]) -> Vec<String> {
+        let mut names: Vec<String> = ids
+            .iter()
+            .filter_map(|&id| game.object(id).map(|obj| obj.name.clone()))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn fact_or_fiction_opponent_separates_and_you_choose_a_pile() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        setup_library(&mut game, alice, &["Bottom", "E", "D", "C", "B", "A"]);
+        let source = game.new_object_id();
+
+        let mut dm = PileDm::new(vec!["A", "B"], 1);
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let outcome = FactOrFictionEffect::you_choose(5)
+            .execute(&mut game, &mut ctx)
+            .expect("fact or fiction");
+
+        assert_eq!(outcome, EffectOutcome::count(2));
+        assert_eq!(dm.separated_by, Some(bob));
+        assert_eq!(dm.chosen_by, Some(alice));
+        assert_eq!(dm.pile_options, vec!["Pile 1: C, D, E", "Pile 2: A, B"]);
+        let alice_state = game.player(alice).unwrap();
+        assert_eq!(names_in(&game, &alice_state.hand), vec!["A", "B"]);
+        assert_eq!(names_in(&game, &alice_state.graveyard), vec!["C", "D", "E"]);
+        assert_eq!(names_in(&game, &alice_state.library), vec!["Bottom"]);
+    }
+
+    #[test]
+    fn choosing_the_empty_pile_puts_every_card_in_the_graveyard() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        setup_library(&mut game, alice, &["B", "A"]);
+        let source = game.new_object_id();
+
+        // Nothing goes to the second pile, and the chooser picks it anyway.
+        let mut dm = PileDm::new(vec![], 1);
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let outcome = FactOrFictionEffect::you_choose(5)
+            .execute(&mut game, &mut ctx)
+            .expect("fact or fiction");
+
+        assert_eq!(outcome, EffectOutcome::count(0));
+        assert_eq!(dm.pile_options[1], "Pile 2: empty");
+        let alice_state = game.player(alice).unwrap();
+        assert!(alice_state.hand.is_empty());
+        assert!(alice_state.library.is_empty());
+        assert_eq!(names_in(&game, &alice_state.graveyard), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn separator_and_chooser_are_configurable() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        setup_library(&mut game, alice, &["B", "A"]);
+        let source = game.new_object_id();
+
+        let mut dm = PileDm::new(vec!["A"], 0);
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        FactOrFictionEffect::new(2, PlayerFilter::You, PlayerFilter::Opponent)
+            .execute(&mut game, &mut ctx)
+            .expect("steam augury");
+
+        assert_eq!(dm.separated_by, Some(alice));
+        assert_eq!(dm.chosen_by, Some(bob));
+        let alice_state = game.player(alice).unwrap();
+        assert_eq!(names_in(&game, &alice_state.hand), vec!["B"]);
+        assert_eq!(names_in(&game, &alice_state.graveyard), vec!["A"]);
+    }
+
+    #[test]
+    fn fact_or_fiction_renders_like_its_oracle_text() {
+        let text =
+            crate::compiled_text::compile_effect_list(&[crate::effect::Effect::fact_or_fiction(5)]);
+        assert_eq!(
+            text,
+            "Reveal the top five cards of your library. An opponent separates those cards into two piles. Put one pile into your hand and the other into your graveyard"
+        );
+    }
+
+    #[test]
+    fn empty_library_reveals_nothing() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+
+        let mut dm = PileDm::new(vec![], 0);
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let outcome = FactOrFictionEffect::you_choose(5)
+            .execute(&mut game, &mut ctx)
+            .expect("fact or fiction");
+
+        assert_eq!(outcome, EffectOutcome::count(0));
+        assert_eq!(dm.separated_by, None);
+        assert_eq!(dm.chosen_by, None);
+    }
+}
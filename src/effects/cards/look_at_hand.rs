@@ -64,7 +64,7 @@ impl EffectExecutor for LookAtHandEffect {
                     let viewer = crate::ids::PlayerId::from_index(viewer_idx as u8);
                     let mut view_ctx =
                         ViewCardsContext::look_at_hand(viewer, player_id, Some(ctx.source));
-                    view_ctx.description = "Reveal that player's hand".to_string();
+                    view_ctx.description = "Reveal that player's hand".into();
                     view_ctx.public = true;
                     ctx.decision_maker
                         .view_cards(game, viewer, &cards, &view_ctx);
@@ -4,15 +4,23 @@ use crate::effect::{EffectOutcome, Value};
 use crate::effects::helpers::{resolve_player_filter, resolve_value};
 use crate::effects::zones::apply_zone_change;
 use crate::effects::{CostExecutableEffect, EffectExecutor};
-use crate::event_processor::EventOutcome;
+use crate::event_processor::{EventOutcome, process_mill};
+use crate::events::cause::EventCause;
+use crate::events::zones::ZoneChangeEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::ids::ObjectId;
 use crate::target::PlayerFilter;
+use crate::triggers::TriggerEvent;
 use crate::zone::Zone;
 
 /// Effect that mills cards from a player's library to their graveyard.
 ///
+/// The count first goes through mill replacement effects, then as many cards as
+/// the library holds are milled. The moved cards are reported as one batched
+/// zone-change event and returned as the outcome's objects, so `.tag(...)` can
+/// expose "the cards milled this way" to follow-up effects.
+///
 /// # Fields
 ///
 /// * `count` - How many cards to mill (can be fixed or variable)
@@ -71,7 +79,17 @@ impl EffectExecutor for MillEffect {
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
         let player_id = resolve_player_filter(game, &self.player, ctx)?;
-        let count = resolve_value(game, &self.count, ctx)?.max(0) as usize;
+        let count = resolve_value(game, &self.count, ctx)?.max(0) as u32;
+        if count == 0 {
+            return Ok(EffectOutcome::count(0));
+        }
+        let count = match process_mill(game, player_id, count, &mut *ctx.decision_maker) {
+            EventOutcome::Proceed(count) => count as usize,
+            EventOutcome::Replaced => return Ok(EffectOutcome::replaced()),
+            EventOutcome::Prevented | EventOutcome::NotApplicable => {
+                return Ok(EffectOutcome::prevented());
+            }
+        };
 
         // Snapshot the top cards first so replacement/prevention on one card does not
         // change which original cards are being milled.
@@ -89,6 +107,7 @@ impl EffectExecutor for MillEffect {
 
         let mut milled = Vec::new();
         let mut any_prevented = false;
+        let pending_before = game.pending_trigger_events.len();
 
         for card_id in cards_to_mill {
             let Some(from_zone) = game.object(card_id).map(|obj| obj.zone) else {
@@ -117,6 +136,7 @@ impl EffectExecutor for MillEffect {
         }
 
         if !milled.is_empty() {
+            batch_mill_events(game, pending_before, &milled, ctx);
             return Ok(EffectOutcome::with_objects(milled));
         }
         if any_prevented {
@@ -126,6 +146,42 @@ impl EffectExecutor for MillEffect {
     }
 }
 
+/// Replace the per-card library-to-graveyard events queued while milling with a
+/// single batch event, so "one or more cards are put into a graveyard from a
+/// library" triggers fire once per mill.
+fn batch_mill_events(
+    game: &mut GameState,
+    pending_before: usize,
+    milled: &[ObjectId],
+    ctx: &ExecutionContext,
+) {
+    let pending_before = pending_before.min(game.pending_trigger_events.len());
+    let queued = game.pending_trigger_events.split_off(pending_before);
+    let mut moved = Vec::new();
+    for event in queued {
+        let milled_card_moves = event
+            .downcast::<ZoneChangeEvent>()
+            .filter(|zc| zc.is_mill() && zc.objects.iter().all(|id| milled.contains(id)))
+            .map(|zc| zc.moved.clone());
+        match milled_card_moves {
+            Some(card_moves) => moved.extend(card_moves),
+            None => game.pending_trigger_events.push(event),
+        }
+    }
+
+    let event = ZoneChangeEvent::batch(
+        milled.to_vec(),
+        Zone::Library,
+        Zone::Graveyard,
+        EventCause::from_effect(ctx.source, ctx.controller),
+    )
+    .with_moved(moved);
+    game.queue_trigger_event(
+        ctx.provenance,
+        TriggerEvent::new_with_provenance(event, ctx.provenance),
+    );
+}
+
 impl CostExecutableEffect for MillEffect {
     fn can_execute_as_cost(
         &self,
@@ -239,4 +295,124 @@ mod tests {
         assert_eq!(tagged[0].object_id, milled_ids[0]);
         assert_eq!(tagged[0].zone, Zone::Graveyard);
     }
+
+    #[test]
+    fn mill_past_library_size_mills_as_many_as_possible() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        add_cards_to_library(&mut game, alice, 2);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        let outcome = MillEffect::you(5)
+            .execute(&mut game, &mut ctx)
+            .expect("execute mill");
+        let crate::effect::OutcomeValue::Objects(milled_ids) = outcome.value else {
+            panic!("expected mill to return the cards it could mill");
+        };
+
+        assert_eq!(milled_ids.len(), 2);
+        assert!(game.player(alice).expect("alice").library.is_empty());
+    }
+
+    #[test]
+    fn mill_queues_one_batched_zone_change_event() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        add_cards_to_library(&mut game, alice, 4);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        game.take_pending_trigger_events();
+
+        MillEffect::you(3)
+            .execute(&mut game, &mut ctx)
+            .expect("execute mill");
+
+        let mill_events: Vec<_> = game
+            .take_pending_trigger_events()
+            .into_iter()
+            .filter_map(|event| event.downcast::<ZoneChangeEvent>().cloned())
+            .filter(ZoneChangeEvent::is_mill)
+            .collect();
+        assert_eq!(mill_events.len(), 1, "mill should queue one batch event");
+        assert_eq!(
+            mill_events[0].objects,
+            game.player(alice).expect("alice").graveyard
+        );
+        let moved_to: Vec<_> = mill_events[0]
+            .moved
+            .iter()
+            .map(|moved| moved.new_id)
+            .collect();
+        assert_eq!(moved_to, mill_events[0].objects);
+    }
+
+    #[test]
+    fn mill_count_can_be_doubled_by_replacement() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        add_cards_to_library(&mut game, bob, 6);
+        let source = game.new_object_id();
+        game.replacement_effects
+            .add_effect(crate::replacement::ReplacementEffect::double_mill(
+                source,
+                alice,
+                PlayerFilter::Opponent,
+            ));
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        MillEffect::new(2, PlayerFilter::Specific(bob))
+            .execute(&mut game, &mut ctx)
+            .expect("execute mill");
+        assert_eq!(game.player(bob).expect("bob").graveyard.len(), 4);
+
+        // The controller's own mills are untouched.
+        add_cards_to_library(&mut game, alice, 3);
+        MillEffect::you(2)
+            .execute(&mut game, &mut ctx)
+            .expect("execute mill");
+        assert_eq!(game.player(alice).expect("alice").graveyard.len(), 2);
+    }
+
+    #[test]
+    fn milled_cards_tag_feeds_exiling_one_card_milled_this_way() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        add_cards_to_library(&mut game, bob, 5);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        let mill = Effect::new(MillEffect::new(3, PlayerFilter::Specific(bob))).tag("milled");
+        let choose = Effect::choose_objects(
+            crate::filter::ObjectFilter::tagged("milled")
+                .in_zone(Zone::Graveyard)
+                .owned_by(PlayerFilter::Opponent),
+            1,
+            PlayerFilter::You,
+            "chosen",
+        );
+        let exile = Effect::exile(crate::target::ChooseSpec::tagged("chosen"));
+        for effect in [mill, choose, exile] {
+            execute_effect(&mut game, &effect, &mut ctx).expect("execute effect");
+        }
+
+        let bob_state = game.player(bob).expect("bob");
+        assert_eq!(bob_state.library.len(), 2);
+        assert_eq!(bob_state.graveyard.len(), 2);
+        assert_eq!(game.exile.len(), 1);
+        let milled = ctx
+            .tagged_objects
+            .get(&TagKey::from("milled"))
+            .expect("milled tag should exist");
+        assert_eq!(milled.len(), 3);
+        let exiled_stable_id = game.object(game.exile[0]).expect("exiled card").stable_id;
+        assert!(
+            milled
+                .iter()
+                .any(|snapshot| snapshot.stable_id == exiled_stable_id),
+            "the exiled card should be one of the milled cards"
+        );
+    }
 }
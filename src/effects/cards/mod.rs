@@ -10,6 +10,7 @@ mod discard_hand;
 mod draw_cards;
 mod draw_for_each_tagged_matching;
 mod exile_top;
+mod fact_or_fiction;
 mod imprint;
 mod look_at_hand;
 mod look_at_top;
@@ -30,6 +31,7 @@ pub use discard_hand::DiscardHandEffect;
 pub use draw_cards::DrawCardsEffect;
 pub use draw_for_each_tagged_matching::DrawForEachTaggedMatchingEffect;
 pub use exile_top::ExileTopOfLibraryEffect;
+pub use fact_or_fiction::FactOrFictionEffect;
 pub use imprint::ImprintFromHandEffect;
 pub use look_at_hand::LookAtHandEffect;
 pub use look_at_top::LookAtTopCardsEffect;
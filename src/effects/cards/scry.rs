@@ -1,6 +1,6 @@
 //! Scry effect implementation.
 
-use crate::decisions::{ScrySpec, make_decision};
+use crate::decisions::{OrderLibraryBottomSpec, OrderLibraryTopSpec, ScrySpec, make_decision};
 use crate::effect::{EffectOutcome, Value};
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::{resolve_player_filter, resolve_value};
@@ -14,7 +14,10 @@ use crate::triggers::TriggerEvent;
 /// Effect that lets a player scry N cards.
 ///
 /// Per Rule 701.18, look at the top N cards, then put any number on the bottom
-/// of the library in any order and the rest on top in any order.
+/// of the library in any order and the rest on top in any order. Scrying
+/// more cards than the library holds looks at the whole library. Scry 0
+/// (or scrying an empty library) doesn't produce a scry event, so "whenever
+/// you scry" abilities don't trigger.
 ///
 /// # Fields
 ///
@@ -82,8 +85,9 @@ impl EffectExecutor for ScryEffect {
 
         let scry_count = top_cards.len();
 
-        // Ask player which cards to put on bottom using the new spec-based system
-        let spec = ScrySpec::new(ctx.source, top_cards.clone());
+        // Ask player which cards to put on bottom, presenting them top-to-bottom
+        let top_to_bottom: Vec<ObjectId> = top_cards.iter().rev().copied().collect();
+        let spec = ScrySpec::new(ctx.source, top_to_bottom.clone());
         let cards_to_bottom: Vec<ObjectId> = make_decision(
             game,
             &mut ctx.decision_maker,
@@ -95,27 +99,27 @@ impl EffectExecutor for ScryEffect {
         .filter(|c| top_cards.contains(c))
         .collect();
 
-        // Remove the scried cards from library temporarily
-        if let Some(p) = game.player_mut(player_id) {
-            let lib_len = p.library.len();
-            p.library.truncate(lib_len.saturating_sub(scry_count));
-        }
-
-        // Put cards going to bottom first (they go under the remaining library)
-        // Then put the rest back on top
-        let cards_to_top: Vec<ObjectId> = top_cards
+        // Cards going to the top, top-to-bottom, then let the player order each pile.
+        let cards_to_top: Vec<ObjectId> = top_to_bottom
             .iter()
             .filter(|c| !cards_to_bottom.contains(c))
             .copied()
             .collect();
+        let cards_to_top = order_pile(game, ctx, player_id, cards_to_top, |source, cards| {
+            OrderLibraryTopSpec::new(source, cards)
+        });
+        let cards_to_bottom = order_pile(game, ctx, player_id, cards_to_bottom, |source, cards| {
+            OrderLibraryBottomSpec::new(source, cards)
+        });
 
         if let Some(p) = game.player_mut(player_id) {
-            // Insert bottom cards at the beginning of library
-            for &card_id in cards_to_bottom.iter().rev() {
+            // Remove the scried cards, then rebuild both ends of the library.
+            // The library vec is bottom-to-top while both piles are top-to-bottom.
+            p.library.retain(|id| !top_cards.contains(id));
+            for &card_id in &cards_to_bottom {
                 p.library.insert(0, card_id);
             }
-            // Add top cards back to end (top of library)
-            p.library.extend(cards_to_top);
+            p.library.extend(cards_to_top.iter().rev().copied());
         }
 
         Ok(
@@ -131,3 +135,211 @@ impl EffectExecutor for ScryEffect {
         )
     }
 }
+
+/// Ask the scrying player to order a pile, falling back to the given order for
+/// anything missing from the response.
+fn order_pile<S>(
+    game: &GameState,
+    ctx: &mut ExecutionContext,
+    player_id: crate::ids::PlayerId,
+    cards: Vec<ObjectId>,
+    spec: impl FnOnce(ObjectId, Vec<ObjectId>) -> S,
+) -> Vec<ObjectId>
+where
+    S: crate::decisions::DecisionSpec<Response = Vec<ObjectId>>,
+{
+    if cards.len() <= 1 {
+        return cards;
+    }
+    let response = make_decision(
+        game,
+        &mut ctx.decision_maker,
+        player_id,
+        Some(ctx.source),
+        spec(ctx.source, cards.clone()),
+    );
+    let mut remaining = cards;
+    let mut ordered = Vec::with_capacity(remaining.len());
+    for id in response {
+        if let Some(pos) = remaining.iter().position(|x| *x == id) {
+            ordered.push(remaining.remove(pos));
+        }
+    }
+    ordered.extend(remaining);
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::definitions::CardDefinitionBuilder;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{OrderContext, PartitionContext};
+    use crate::ids::{CardId, PlayerId};
+    use crate::types::CardType;
+    use crate::zone::Zone;
+
+    /// Bottoms the named cards, then reverses every pile it is asked to order.
+    struct BottomAndReverseDm {
+        bottom: Vec<&'static str>,
+    }
+
+    impl DecisionMaker for BottomAndReverseDm {
+        fn decide_partition(&mut self, _game: &GameState, ctx: &PartitionContext) -> Vec<ObjectId> {
+            ctx.cards
+                .iter()
+                .filter(|(_, name)| self.bottom.contains(&name.as_str()))
+                .map(|(id, _)| *id)
+                .collect()
+        }
+
+        fn decide_order(&mut self, _game: &GameState, ctx: &OrderContext) -> Vec<ObjectId> {
+            ctx.items.iter().rev().map(|(id, _)| *id).collect()
+        }
+    }
+
+    /// Add named cards to the library, bottom first.
+    fn add_library(game: &mut GameState, owner: PlayerId, names: &[&str]) {
+        for name in names {
+            let card = CardBuilder::new(CardId::new(), *name)
+                .card_types(vec![CardType::Land])
+                .build();
+            game.create_object_from_card(&card, owner, Zone::Library);
+        }
+    }
+
+    fn library_names(game: &GameState, owner: PlayerId) -> Vec<String> {
+        game.player(owner)
+            .unwrap()
+            .library
+            .iter()
+            .map(|&id| game.object(id).unwrap().name.clone())
+            .collect()
+    }
+
+    fn scry_events(outcome: &EffectOutcome) -> Vec<&KeywordActionEvent> {
+        outcome
+            .events
+            .iter()
+            .filter_map(|event| event.downcast::<KeywordActionEvent>())
+            .filter(|event| event.action == KeywordActionKind::Scry)
+            .collect()
+    }
+
+    #[test]
+    fn scry_orders_both_piles() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        add_library(&mut game, alice, &["C1", "C2", "C3", "C4", "C5"]);
+
+        let mut dm = BottomAndReverseDm {
+            bottom: vec!["C5", "C3"],
+        };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let outcome = ScryEffect::you(4).execute(&mut game, &mut ctx).unwrap();
+
+        // Top pile C4, C2 and bottom pile C5, C3 (top-to-bottom) are both reversed.
+        assert_eq!(
+            library_names(&game, alice),
+            vec!["C5", "C3", "C1", "C4", "C2"]
+        );
+        let events = scry_events(&outcome);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].amount, 4);
+    }
+
+    #[test]
+    fn scry_more_than_library_size_looks_at_the_whole_library() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        add_library(&mut game, alice, &["C1", "C2"]);
+
+        let mut dm = BottomAndReverseDm { bottom: vec!["C2"] };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let outcome = ScryEffect::you(5).execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(library_names(&game, alice), vec!["C2", "C1"]);
+        assert_eq!(scry_events(&outcome)[0].amount, 2);
+    }
+
+    #[test]
+    fn scry_zero_and_scrying_an_empty_library_emit_no_scry_event() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let outcome = ScryEffect::you(2).execute(&mut game, &mut ctx).unwrap();
+        assert!(scry_events(&outcome).is_empty());
+
+        add_library(&mut game, alice, &["C1"]);
+        let outcome = ScryEffect::you(0).execute(&mut game, &mut ctx).unwrap();
+        assert!(scry_events(&outcome).is_empty());
+        assert_eq!(library_names(&game, alice), vec!["C1"]);
+    }
+
+    #[test]
+    fn scrying_fires_whenever_you_scry_triggers() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = game.new_object_id();
+        add_library(&mut game, alice, &["C1", "C2"]);
+        add_library(&mut game, bob, &["C1"]);
+
+        let watcher_def = CardDefinitionBuilder::new(CardId::new(), "Scry Watcher")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .parse_text("Whenever you scry, put a +1/+1 counter on this creature.")
+            .unwrap();
+        let watcher = game.create_object_from_definition(&watcher_def, alice, Zone::Battlefield);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let outcome = ScryEffect::you(1).execute(&mut game, &mut ctx).unwrap();
+        let triggered = crate::triggers::check_triggers(&game, &outcome.events[0]);
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].source, watcher);
+
+        // An opponent scrying doesn't trigger "whenever you scry".
+        let mut ctx = ExecutionContext::new_default(source, bob);
+        let outcome = ScryEffect::you(1).execute(&mut game, &mut ctx).unwrap();
+        assert!(crate::triggers::check_triggers(&game, &outcome.events[0]).is_empty());
+    }
+
+    #[test]
+    fn scry_payoff_puts_that_many_counters() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        add_library(&mut game, alice, &["C1", "C2", "C3", "C4"]);
+
+        let watcher_def = CardDefinitionBuilder::new(CardId::new(), "Scry Grower")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .parse_text("Whenever you scry, put that many +1/+1 counters on this creature.")
+            .unwrap();
+        let watcher = game.create_object_from_definition(&watcher_def, alice, Zone::Battlefield);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let outcome = ScryEffect::you(3).execute(&mut game, &mut ctx).unwrap();
+        let triggered = crate::triggers::check_triggers(&game, &outcome.events[0]);
+        assert_eq!(triggered.len(), 1);
+
+        let entry = &triggered[0];
+        let mut ctx = ExecutionContext::new_default(entry.source, entry.controller)
+            .with_triggering_event(entry.triggering_event.clone());
+        for effect in &entry.ability.effects {
+            crate::executor::execute_effect(&mut game, effect, &mut ctx).unwrap();
+        }
+        let counters = game
+            .object(watcher)
+            .unwrap()
+            .counters
+            .get(&crate::object::CounterType::PlusOnePlusOne)
+            .copied();
+        assert_eq!(counters, Some(3));
+    }
+}
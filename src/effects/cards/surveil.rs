@@ -4,6 +4,7 @@ use crate::decisions::{SurveilSpec, make_decision};
 use crate::effect::{EffectOutcome, Value};
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::{resolve_player_filter, resolve_value};
+use crate::effects::zones::apply_zone_change;
 use crate::events::{KeywordActionEvent, KeywordActionKind};
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
@@ -104,7 +105,13 @@ impl EffectExecutor for SurveilEffect {
 
         // Put cards going to graveyard
         for &card_id in &cards_to_graveyard {
-            game.move_object(card_id, Zone::Graveyard);
+            let _ = apply_zone_change(
+                game,
+                card_id,
+                Zone::Library,
+                Zone::Graveyard,
+                &mut *ctx.decision_maker,
+            );
         }
 
         // Put the rest back on top
@@ -52,6 +52,7 @@ pub fn register_prevention_shield(
 ) -> PreventionShieldId {
     let shield = PreventionShield::new(ctx.source, ctx.controller, protected, amount, duration)
         .with_filter(damage_filter);
+    game.prevention_effects.set_turn(game.turn.turn_number);
     game.prevention_effects.add_shield(shield)
 }
 
@@ -103,10 +103,19 @@ pub(crate) fn run_choose_mode(
     game: &mut GameState,
     ctx: &mut ExecutionContext,
 ) -> Result<EffectOutcome, ExecutionError> {
-    let max_modes = resolve_value(game, &effect.choose_count, ctx)?.max(0) as usize;
-    let min_modes = match &effect.min_choose_count {
-        Some(min_val) => resolve_value(game, min_val, ctx)?.max(0) as usize,
-        None => max_modes,
+    // An entwined spell (Rule 702.42) uses every mode. Modes were locked in
+    // when it was cast, so one whose targets have since gone does nothing
+    // rather than stopping the rest.
+    let entwined = ctx.optional_costs_paid.was_entwined();
+    let (min_modes, max_modes) = if entwined {
+        (0, effect.modes.len())
+    } else {
+        let max_modes = resolve_value(game, &effect.choose_count, ctx)?.max(0) as usize;
+        let min_modes = match &effect.min_choose_count {
+            Some(min_val) => resolve_value(game, min_val, ctx)?.max(0) as usize,
+            None => max_modes,
+        };
+        (min_modes, max_modes)
     };
 
     if effect.modes.is_empty() || max_modes == 0 {
@@ -139,7 +148,9 @@ pub(crate) fn run_choose_mode(
 
     // Per MTG rule 601.2b, modes are chosen during casting (before targets).
     // Check if modes were pre-chosen during the casting process.
-    let chosen_indices: Vec<usize> = if let Some(ref pre_chosen) = ctx.chosen_modes {
+    let chosen_indices: Vec<usize> = if entwined {
+        (0..effect.modes.len()).collect()
+    } else if let Some(ref pre_chosen) = ctx.chosen_modes {
         pre_chosen.clone()
     } else {
         let mode_options: Vec<ModeOption> = effect
@@ -266,6 +277,78 @@ mod tests {
         assert_eq!(game.player(alice).expect("alice").life, 22);
     }
 
+    #[test]
+    fn entwined_choose_mode_runs_every_mode() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut paid =
+            crate::cost::OptionalCostsPaid::from_costs(&[crate::cost::OptionalCost::entwine(
+                crate::cost::TotalCost::free(),
+            )]);
+        paid.pay(0);
+        let mut ctx = ExecutionContext::new_default(source, alice)
+            .with_chosen_modes(Some(vec![0]))
+            .with_optional_costs_paid(paid);
+
+        let effect = ChooseModeEffect::choose_one(vec![
+            EffectMode::new("Gain 1 life", vec![Effect::gain_life(1)]),
+            EffectMode::new("Gain 2 life", vec![Effect::gain_life(2)]),
+        ]);
+
+        run_choose_mode(&effect, &mut game, &mut ctx).expect("choose mode resolves");
+
+        assert_eq!(game.player(alice).expect("alice").life, 23);
+    }
+
+    #[test]
+    fn entwined_choose_mode_skips_a_mode_whose_targets_are_gone() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = game.new_object_id();
+
+        let land_card = crate::card::CardBuilder::new(CardId::from_raw(6_002), "Marked Land")
+            .card_types(vec![CardType::Land])
+            .build();
+        let land = game.create_object_from_card(&land_card, bob, Zone::Battlefield);
+
+        let mut paid =
+            crate::cost::OptionalCostsPaid::from_costs(&[crate::cost::OptionalCost::entwine(
+                crate::cost::TotalCost::free(),
+            )]);
+        paid.pay(0);
+        let mut ctx = ExecutionContext::new_default(source, alice)
+            .with_optional_costs_paid(paid)
+            .with_targets(vec![crate::executor::ResolvedTarget::Object(land)])
+            .with_target_assignments(vec![TargetAssignment {
+                spec: ChooseSpec::target(ChooseSpec::Object(crate::filter::ObjectFilter::land())),
+                range: 0..1,
+            }]);
+
+        // No creature is left to destroy; the land and life modes still happen.
+        let effect = ChooseModeEffect::choose_one(vec![
+            EffectMode::new(
+                "Destroy target creature",
+                vec![Effect::new(crate::effects::DestroyEffect::target(
+                    ChooseSpec::creature(),
+                ))],
+            ),
+            EffectMode::new(
+                "Destroy target land",
+                vec![Effect::new(crate::effects::DestroyEffect::target(
+                    ChooseSpec::Object(crate::filter::ObjectFilter::land()),
+                ))],
+            ),
+            EffectMode::new("Gain 2 life", vec![Effect::gain_life(2)]),
+        ]);
+
+        run_choose_mode(&effect, &mut game, &mut ctx).expect("entwined modes resolve");
+
+        assert!(!game.battlefield.contains(&land));
+        assert_eq!(game.player(alice).expect("alice").life, 22);
+    }
+
     #[test]
     fn choose_mode_scopes_targets_per_selected_mode() {
         let mut game = setup_game();
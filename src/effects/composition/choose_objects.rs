@@ -4,7 +4,7 @@
 //! for reference by subsequent effects in the same spell/ability.
 
 use crate::effect::{ChoiceCount, EffectOutcome};
-use crate::effects::{CostExecutableEffect, EffectExecutor};
+use crate::effects::{CostExecutableEffect, EffectExecutor, ExecutabilityReport};
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::filter::Comparison;
 use crate::game_state::GameState;
@@ -194,6 +194,10 @@ impl EffectExecutor for ChooseObjectsEffect {
         super::choose_objects_runtime::run_choose_objects(self, game, ctx)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        super::choose_objects_runtime::dry_run_choose_objects(self, game, ctx)
+    }
+
     fn cost_description(&self) -> Option<String> {
         use crate::color::Color;
 
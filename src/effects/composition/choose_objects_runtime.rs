@@ -3,6 +3,7 @@
 use crate::decisions::make_decision;
 use crate::decisions::specs::ChooseObjectsSpec;
 use crate::effect::{ChoiceCount, EffectOutcome, ExecutionFact};
+use crate::effects::ExecutabilityReport;
 use crate::effects::helpers::resolve_player_filter;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::filter::ObjectFilter;
@@ -410,6 +411,29 @@ fn snapshot_chosen_objects(game: &GameState, chosen: &[ObjectId]) -> Vec<ObjectS
         .collect()
 }
 
+/// Dry-run backing `ChooseObjectsEffect::can_execute`.
+///
+/// Searches stay `Unknown`: failing to find still counts as searching.
+pub(crate) fn dry_run_choose_objects(
+    effect: &ChooseObjectsEffect,
+    game: &GameState,
+    ctx: &ExecutionContext,
+) -> ExecutabilityReport {
+    if effect.is_search {
+        return ExecutabilityReport::Unknown;
+    }
+    let Ok(chooser_id) = resolve_player_filter(game, &effect.chooser, ctx) else {
+        return ExecutabilityReport::Unknown;
+    };
+    match collect_candidates(effect, game, ctx, chooser_id) {
+        Ok(candidates) => ExecutabilityReport::executable_if(
+            !candidates.is_empty(),
+            format!("no {} to choose", effect.filter.description()),
+        ),
+        Err(_) => ExecutabilityReport::Unknown,
+    }
+}
+
 pub(crate) fn run_choose_objects(
     effect: &ChooseObjectsEffect,
     game: &mut GameState,
@@ -1,7 +1,7 @@
 //! Conditional effect implementation.
 
 use crate::effect::{Condition, Effect, EffectOutcome};
-use crate::effects::{EffectExecutor, ModalSpec};
+use crate::effects::{EffectExecutor, ExecutabilityReport, ModalSpec};
 use crate::executor::{ExecutionContext, ExecutionError, execute_effect};
 use crate::game_state::GameState;
 use crate::ids::{ObjectId, PlayerId};
@@ -81,6 +81,21 @@ impl EffectExecutor for ConditionalEffect {
         Ok(EffectOutcome::aggregate(outcomes))
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let Ok(result) = evaluate_condition(game, &self.condition, ctx) else {
+            return ExecutabilityReport::Unknown;
+        };
+        let branch = if result {
+            &self.if_true
+        } else {
+            &self.if_false
+        };
+        if branch.is_empty() {
+            return ExecutabilityReport::impossible("condition isn't met");
+        }
+        ExecutabilityReport::any_of(branch.iter().map(|effect| effect.0.can_execute(game, ctx)))
+    }
+
     fn get_target_spec(&self) -> Option<&ChooseSpec> {
         super::target_metadata::first_target_spec(&[&self.if_true, &self.if_false])
     }
@@ -172,4 +187,97 @@ mod tests {
         assert!(effect.get_target_spec().is_some());
         assert_eq!(effect.target_description(), "spell to counter");
     }
+
+    fn condition_holds(game: &mut GameState, condition: Condition, player: PlayerId) -> bool {
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, player);
+        let life_before = game.player(player).unwrap().life;
+        ConditionalEffect::if_only(condition, vec![Effect::gain_life(1)])
+            .execute(game, &mut ctx)
+            .unwrap();
+        game.player(player).unwrap().life > life_before
+    }
+
+    #[test]
+    fn turn_tracker_conditions_read_this_turns_markers() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        assert!(!condition_holds(
+            &mut game,
+            Condition::OpponentLostLifeThisTurn,
+            alice
+        ));
+        assert!(!condition_holds(
+            &mut game,
+            Condition::AttackedThisTurn,
+            alice
+        ));
+        assert!(!condition_holds(
+            &mut game,
+            Condition::CreatureDiedThisTurn,
+            alice
+        ));
+
+        game.turn_trackers.record_life_lost(bob, 2);
+        game.turn_trackers.record_attack(alice);
+        game.turn_trackers.record_creature_died(bob);
+        game.turn_trackers.record_left_battlefield(alice, false);
+
+        assert!(condition_holds(
+            &mut game,
+            Condition::OpponentLostLifeThisTurn,
+            alice
+        ));
+        assert!(!condition_holds(
+            &mut game,
+            Condition::OpponentLostLifeThisTurn,
+            bob
+        ));
+        assert!(condition_holds(
+            &mut game,
+            Condition::AttackedThisTurn,
+            alice
+        ));
+        assert!(!condition_holds(
+            &mut game,
+            Condition::AttackedThisTurn,
+            bob
+        ));
+        assert!(condition_holds(
+            &mut game,
+            Condition::CreatureDiedThisTurn,
+            bob
+        ));
+        assert!(condition_holds(
+            &mut game,
+            Condition::PermanentLeftBattlefieldUnderYourControlThisTurn,
+            alice
+        ));
+        assert!(!condition_holds(
+            &mut game,
+            Condition::PermanentLeftBattlefieldUnderYourControlThisTurn,
+            bob
+        ));
+    }
+
+    #[test]
+    fn you_gained_life_this_turn_checks_the_controller() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.turn_trackers.record_life_gained(alice, 2);
+
+        assert!(!condition_holds(
+            &mut game,
+            Condition::YouGainedLifeThisTurn,
+            bob
+        ));
+        assert!(condition_holds(
+            &mut game,
+            Condition::YouGainedLifeThisTurn,
+            alice
+        ));
+    }
 }
@@ -3,8 +3,8 @@
 use crate::decision::FallbackStrategy;
 use crate::decisions::ask_may_choice;
 use crate::effect::{Effect, EffectOutcome, ExecutionFact};
-use crate::effects::EffectExecutor;
 use crate::effects::helpers::resolve_player_filter;
+use crate::effects::{EffectExecutor, ExecutabilityReport};
 use crate::executor::{ExecutionContext, ExecutionError, execute_effect};
 use crate::game_state::GameState;
 use crate::target::PlayerFilter;
@@ -89,7 +89,7 @@ impl EffectExecutor for MayEffect {
         game: &mut GameState,
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
-        if self.should_auto_decline_without_prompt(game, ctx)? {
+        if self.should_auto_decline_without_prompt(game, ctx) {
             return Ok(EffectOutcome::declined());
         }
 
@@ -144,42 +144,31 @@ impl EffectExecutor for MayEffect {
 }
 
 impl MayEffect {
-    /// Some parsed patterns compile to "may (if condition, do X)" where the
-    /// condition is a strict gate (no else branch). If the gate is false, skip
-    /// prompting entirely so UI doesn't offer an option that cannot do anything.
-    fn should_auto_decline_without_prompt(
-        &self,
-        game: &GameState,
-        ctx: &ExecutionContext,
-    ) -> Result<bool, ExecutionError> {
-        if self.effects.len() != 1 {
-            return Ok(false);
-        }
-
-        let Some(conditional) = self.effects[0].downcast_ref::<crate::effects::ConditionalEffect>()
-        else {
-            return Ok(false);
-        };
-
-        if !conditional.if_false.is_empty() {
-            return Ok(false);
-        }
-
-        let condition_met = crate::condition_eval::evaluate_condition_resolution(
-            game,
-            &conditional.condition,
-            ctx,
-        )?;
-        Ok(!condition_met)
+    /// Skip prompting entirely when accepting could not do anything, so the
+    /// UI doesn't offer a pointless option ("you may sacrifice a creature"
+    /// with no creatures, "may (if condition, do X)" with a false gate).
+    fn should_auto_decline_without_prompt(&self, game: &GameState, ctx: &ExecutionContext) -> bool {
+        ExecutabilityReport::any_of(
+            self.effects
+                .iter()
+                .map(|effect| effect.0.can_execute(game, ctx)),
+        )
+        .is_impossible()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ability::AbilityKind;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::CardDefinitionBuilder;
     use crate::effect::{Condition, ExecutionFact};
-    use crate::ids::PlayerId;
+    use crate::ids::{CardId, PlayerId};
+    use crate::object::Object;
     use crate::target::{ChooseSpec, PlayerFilter};
+    use crate::types::CardType;
+    use crate::zone::Zone;
 
     fn setup_game() -> GameState {
         crate::tests::test_helpers::setup_two_player_game()
@@ -303,4 +292,73 @@ mod tests {
 
         assert!(result.execution_facts().contains(&ExecutionFact::Accepted));
     }
+
+    #[derive(Default)]
+    struct CountingBooleanDecisionMaker {
+        prompts: usize,
+    }
+
+    impl crate::decision::DecisionMaker for CountingBooleanDecisionMaker {
+        fn decide_boolean(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::BooleanContext,
+        ) -> bool {
+            self.prompts += 1;
+            false
+        }
+    }
+
+    fn may_sacrifice_effects() -> Vec<Effect> {
+        let definition = CardDefinitionBuilder::new(CardId::from_raw(900), "Hungry Recruit")
+            .card_types(vec![CardType::Creature])
+            .parse_text(
+                "When this creature enters, you may sacrifice a creature. If you do, draw a card.",
+            )
+            .expect("may-sacrifice trigger should parse");
+        definition
+            .abilities
+            .iter()
+            .find_map(|ability| match &ability.kind {
+                AbilityKind::Triggered(triggered) => Some(triggered.effects.clone()),
+                _ => None,
+            })
+            .expect("definition should have a triggered ability")
+    }
+
+    fn count_prompts(game: &mut GameState, effects: &[Effect]) -> usize {
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut dm = CountingBooleanDecisionMaker::default();
+        let mut ctx = ExecutionContext::new_default(source, alice).with_decision_maker(&mut dm);
+        for effect in effects {
+            execute_effect(game, effect, &mut ctx).expect("effect should execute");
+        }
+        drop(ctx);
+        dm.prompts
+    }
+
+    #[test]
+    fn may_sacrifice_with_empty_board_asks_nothing() {
+        let mut game = setup_game();
+        let effects = may_sacrifice_effects();
+
+        assert_eq!(count_prompts(&mut game, &effects), 0);
+    }
+
+    #[test]
+    fn may_sacrifice_with_a_creature_still_prompts() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let id = game.new_object_id();
+        let card = CardBuilder::new(CardId::from_raw(id.0 as u32), "Bear")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        game.add_object(Object::from_card(id, &card, alice, Zone::Battlefield));
+        let effects = may_sacrifice_effects();
+
+        assert_eq!(count_prompts(&mut game, &effects), 1);
+        assert!(game.battlefield.contains(&id));
+    }
 }
@@ -10,6 +10,7 @@
 //! - `ForEachControllerOfTagged` - Group tagged objects by controller and iterate
 //! - `ForEachTaggedPlayer` - Iterate over tagged players
 //! - `Conditional` - Game state branching
+//! - `InterveningIf` - Triggered ability condition re-checked on resolution
 //! - `ChooseMode` - Modal spell handling
 //! - `Tagged` - Tag targets for cross-effect reference
 //! - `ChooseObjects` - Interactive object selection with tagging
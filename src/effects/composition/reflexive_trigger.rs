@@ -80,7 +80,7 @@ fn choose_reflexive_targets(
             ctx.source,
             "reflexive triggered ability",
             vec![TargetRequirementContext {
-                description: describe_choice(spec),
+                description: describe_choice(spec).into(),
                 legal_targets: legal_targets.clone(),
                 min_targets: count.min,
                 max_targets: count.max,
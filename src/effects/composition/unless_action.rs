@@ -3,8 +3,8 @@
 use crate::decision::FallbackStrategy;
 use crate::decisions::make_boolean_decision;
 use crate::effect::{Effect, EffectOutcome};
-use crate::effects::EffectExecutor;
 use crate::effects::helpers::resolve_player_filter;
+use crate::effects::{EffectExecutor, ExecutabilityReport};
 use crate::executor::{ExecutionContext, ExecutionError, ResolvedTarget, execute_effect};
 use crate::game_state::GameState;
 use crate::ids::PlayerId;
@@ -22,6 +22,14 @@ fn execute_effect_sequence(
     Ok(EffectOutcome::aggregate(outcomes))
 }
 
+fn alternative_executability(
+    game: &GameState,
+    ctx: &ExecutionContext,
+    effects: &[Effect],
+) -> ExecutabilityReport {
+    ExecutabilityReport::any_of(effects.iter().map(|effect| effect.0.can_execute(game, ctx)))
+}
+
 fn players_in_turn_order(game: &GameState) -> Vec<PlayerId> {
     if game.turn_order.is_empty() {
         return Vec::new();
@@ -97,6 +105,18 @@ impl EffectExecutor for UnlessActionEffect {
         let mut attempted_alternative_events = Vec::new();
 
         for deciding_player in deciding_players {
+            // Don't offer an alternative the player couldn't perform.
+            let alternative_report = if matches!(self.player, PlayerFilter::Any) {
+                ctx.with_temp_targets(vec![ResolvedTarget::Player(deciding_player)], |ctx| {
+                    alternative_executability(game, ctx, &self.alternative)
+                })
+            } else {
+                alternative_executability(game, ctx, &self.alternative)
+            };
+            if alternative_report.is_impossible() {
+                continue;
+            }
+
             // Ask the player if they want to perform the alternative action.
             let wants_alternative = make_boolean_decision(
                 game,
@@ -5,6 +5,7 @@ use crate::decisions::make_boolean_decision;
 use crate::effect::{Effect, EffectOutcome, Value};
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::{resolve_player_filter, resolve_value};
+use crate::effects::mana::pay_mana_cost_interactively;
 use crate::events::LifeLossEvent;
 use crate::executor::{ExecutionContext, ExecutionError, execute_effect};
 use crate::game_state::GameState;
@@ -16,7 +17,9 @@ use crate::triggers::TriggerEvent;
 /// Effect that executes inner effects unless a player pays a mana cost.
 ///
 /// "Sacrifice this creature unless you pay {U}" - the player can choose to pay
-/// the mana to prevent the inner effects from happening.
+/// the mana to prevent the inner effects from happening. The paying player gets
+/// a payment window in which they may activate mana abilities, so untapped
+/// lands count, not just mana already in their pool.
 ///
 /// # Fields
 ///
@@ -176,11 +179,11 @@ impl EffectExecutor for UnlessPaysEffect {
         };
 
         for paying_player in paying_players {
-            // Check if this player can afford to pay mana/life.
-            let can_afford_mana = {
-                let cost = ManaCost::from_symbols(mana_symbols.clone());
-                game.can_pay_mana_cost(paying_player, None, &cost, 0)
-            };
+            // Check if this player can afford to pay mana/life, counting mana
+            // they could still make during the payment window.
+            let cost = ManaCost::from_symbols(mana_symbols.clone());
+            let can_afford_mana = game.can_pay_mana_cost(paying_player, None, &cost, 0)
+                || crate::decision::can_potentially_pay(game, paying_player, &cost, 0);
             let can_afford_life = if life_to_pay == 0 {
                 true
             } else if !game.can_lose_life(paying_player)
@@ -209,15 +212,20 @@ impl EffectExecutor for UnlessPaysEffect {
 
             if wants_to_pay {
                 // Pay the mana/life cost; if paid successfully, prevent effects.
-                let cost = ManaCost::from_symbols(mana_symbols.clone());
-                if game.try_pay_mana_cost(paying_player, None, &cost, 0) {
+                if pay_mana_cost_interactively(
+                    game,
+                    paying_player,
+                    ctx.source,
+                    &cost,
+                    &mut ctx.decision_maker,
+                ) {
                     let mut outcome = EffectOutcome::declined();
                     if life_to_pay > 0 {
                         if let Some(player) = game.player_mut(paying_player) {
                             player.lose_life(life_to_pay);
                         }
                         outcome = outcome.with_event(TriggerEvent::new_with_provenance(
-                            LifeLossEvent::from_effect(paying_player, life_to_pay),
+                            LifeLossEvent::from_cost(paying_player, life_to_pay),
                             ctx.provenance,
                         ));
                     }
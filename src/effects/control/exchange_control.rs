@@ -1,17 +1,24 @@
 //! Exchange control effect implementation.
 
+use crate::combat_state::remove_from_combat;
 use crate::continuous::{EffectTarget, Modification};
 use crate::effect::{Effect, EffectOutcome, Until};
 use crate::effects::{ApplyContinuousEffect, EffectExecutor};
 use crate::executor::{ExecutionContext, ExecutionError, execute_effect};
 use crate::game_state::GameState;
+use crate::ids::{ObjectId, PlayerId};
 use crate::target::ChooseSpec;
 use crate::types::CardType;
+use crate::zone::Zone;
 use std::collections::HashSet;
 
 /// Effect that exchanges control of two permanents.
 ///
 /// Creates continuous effects that swap the controllers of two permanents.
+/// At resolution both permanents must still be on the battlefield and satisfy
+/// any shared-type constraint; otherwise nothing is exchanged. Exchanged
+/// permanents are summoning sick under their new controllers and are removed
+/// from combat.
 ///
 /// # Fields
 ///
@@ -69,6 +76,54 @@ impl ExchangeControlEffect {
     }
 }
 
+impl ExchangeControlEffect {
+    /// Check whether the two permanents satisfy the shared-type constraint, if any.
+    ///
+    /// Types are read from calculated characteristics so type-changing effects
+    /// are respected at resolution.
+    fn shares_required_type(&self, types1: &[CardType], types2: &[CardType]) -> bool {
+        let Some(constraint) = self.shared_type else {
+            return true;
+        };
+
+        let relevant = |ty: CardType| -> bool {
+            match constraint {
+                SharedTypeConstraint::CardType => true,
+                SharedTypeConstraint::PermanentType => matches!(
+                    ty,
+                    CardType::Artifact
+                        | CardType::Creature
+                        | CardType::Enchantment
+                        | CardType::Land
+                        | CardType::Planeswalker
+                        | CardType::Battle
+                ),
+            }
+        };
+
+        let types1: HashSet<CardType> = types1.iter().copied().filter(|ty| relevant(*ty)).collect();
+        types2
+            .iter()
+            .copied()
+            .filter(|ty| relevant(*ty))
+            .any(|ty| types1.contains(&ty))
+    }
+}
+
+/// Current controller and card types of a permanent, or `None` if it is no
+/// longer on the battlefield.
+fn battlefield_control_view(game: &GameState, id: ObjectId) -> Option<(PlayerId, Vec<CardType>)> {
+    let obj = game.object(id)?;
+    if obj.zone != Zone::Battlefield {
+        return None;
+    }
+    Some(
+        game.calculated_characteristics(id)
+            .map(|chars| (chars.controller, chars.card_types))
+            .unwrap_or_else(|| (obj.controller, obj.card_types.clone())),
+    )
+}
+
 impl EffectExecutor for ExchangeControlEffect {
     fn execute(
         &self,
@@ -79,73 +134,54 @@ impl EffectExecutor for ExchangeControlEffect {
             return Ok(EffectOutcome::target_invalid());
         };
 
-        if let Some(constraint) = self.shared_type {
-            let Some(obj1) = game.object(perm1_id) else {
-                return Ok(EffectOutcome::target_invalid());
-            };
-            let Some(obj2) = game.object(perm2_id) else {
-                return Ok(EffectOutcome::target_invalid());
-            };
-
-            let relevant = |ty: CardType| -> bool {
-                match constraint {
-                    SharedTypeConstraint::CardType => true,
-                    SharedTypeConstraint::PermanentType => matches!(
-                        ty,
-                        CardType::Artifact
-                            | CardType::Creature
-                            | CardType::Enchantment
-                            | CardType::Land
-                            | CardType::Planeswalker
-                            | CardType::Battle
-                    ),
-                }
-            };
-
-            let types1: HashSet<CardType> = obj1
-                .card_types
-                .iter()
-                .copied()
-                .filter(|ty| relevant(*ty))
-                .collect();
-            let shares_type = obj2
-                .card_types
-                .iter()
-                .copied()
-                .filter(|ty| relevant(*ty))
-                .any(|ty| types1.contains(&ty));
-
-            if !shares_type {
-                return Ok(EffectOutcome::target_invalid());
-            }
+        // Both permanents must still be on the battlefield and still satisfy the
+        // constraint. If either side is illegal, no exchange happens.
+        let Some((c1, types1)) = battlefield_control_view(game, perm1_id) else {
+            return Ok(EffectOutcome::target_invalid());
+        };
+        let Some((c2, types2)) = battlefield_control_view(game, perm2_id) else {
+            return Ok(EffectOutcome::target_invalid());
+        };
+        if perm1_id == perm2_id || !self.shares_required_type(&types1, &types2) {
+            return Ok(EffectOutcome::target_invalid());
         }
 
-        // Get current controllers
-        let controller1 = game.object(perm1_id).map(|o| o.controller);
-        let controller2 = game.object(perm2_id).map(|o| o.controller);
-
-        if let (Some(c1), Some(c2)) = (controller1, controller2) {
-            let effect1 = ApplyContinuousEffect::new(
-                EffectTarget::Specific(perm1_id),
-                Modification::ChangeController(c2),
-                Until::Forever,
-            );
-
-            let effect2 = ApplyContinuousEffect::new(
-                EffectTarget::Specific(perm2_id),
-                Modification::ChangeController(c1),
-                Until::Forever,
-            );
-
-            let outcomes = vec![
-                execute_effect(game, &Effect::new(effect1), ctx)?,
-                execute_effect(game, &Effect::new(effect2), ctx)?,
-            ];
-
-            Ok(EffectOutcome::aggregate(outcomes))
-        } else {
-            Ok(EffectOutcome::target_invalid())
+        // Exchanging between permanents with the same controller does nothing.
+        if c1 == c2 {
+            return Ok(EffectOutcome::resolved());
+        }
+
+        let effect1 = ApplyContinuousEffect::new(
+            EffectTarget::Specific(perm1_id),
+            Modification::ChangeController(c2),
+            Until::Forever,
+        );
+
+        let effect2 = ApplyContinuousEffect::new(
+            EffectTarget::Specific(perm2_id),
+            Modification::ChangeController(c1),
+            Until::Forever,
+        );
+
+        let outcomes = vec![
+            execute_effect(game, &Effect::new(effect1), ctx)?,
+            execute_effect(game, &Effect::new(effect2), ctx)?,
+        ];
+
+        for id in [perm1_id, perm2_id] {
+            // Neither player has controlled the permanent continuously since their
+            // most recent turn began (rule 302.6).
+            game.set_summoning_sick(id);
+
+            // A permanent whose controller changes is removed from combat (rule 506.4).
+            if let Some(combat) = game.combat.as_mut()
+                && remove_from_combat(combat, id)
+            {
+                game.ninjutsu_attack_targets.remove(&id);
+            }
         }
+
+        Ok(EffectOutcome::aggregate(outcomes))
     }
 
     fn get_target_spec(&self) -> Option<&ChooseSpec> {
@@ -296,6 +332,151 @@ mod tests {
         assert_eq!(result.status, crate::effect::OutcomeStatus::Succeeded);
     }
 
+    fn calculated_controller(game: &GameState, id: ObjectId) -> PlayerId {
+        game.calculated_characteristics(id)
+            .expect("object should have characteristics")
+            .controller
+    }
+
+    #[test]
+    fn test_exchange_control_juxtapose_swaps_controllers() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let creature1 = create_creature(&mut game, "Alice's Creature", alice);
+        let creature2 = create_creature(&mut game, "Bob's Creature", bob);
+        game.remove_summoning_sickness(creature1);
+        game.remove_summoning_sickness(creature2);
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice).with_targets(vec![
+            ResolvedTarget::Object(creature1),
+            ResolvedTarget::Object(creature2),
+        ]);
+
+        let effect = ExchangeControlEffect::creatures()
+            .with_shared_type(SharedTypeConstraint::PermanentType);
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(result.status, crate::effect::OutcomeStatus::Succeeded);
+        assert_eq!(calculated_controller(&game, creature1), bob);
+        assert_eq!(calculated_controller(&game, creature2), alice);
+        assert!(game.is_summoning_sick(creature1));
+        assert!(game.is_summoning_sick(creature2));
+    }
+
+    #[test]
+    fn test_exchange_control_removes_permanents_from_combat() {
+        use crate::combat_state::{AttackTarget, AttackerInfo, CombatState};
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let attacker = create_creature(&mut game, "Attacker", alice);
+        let blocker = create_creature(&mut game, "Blocker", bob);
+        let mut combat = CombatState::default();
+        combat.attackers.push(AttackerInfo {
+            creature: attacker,
+            target: AttackTarget::Player(bob),
+        });
+        combat.blockers.insert(attacker, vec![blocker]);
+        game.combat = Some(combat);
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice).with_targets(vec![
+            ResolvedTarget::Object(attacker),
+            ResolvedTarget::Object(blocker),
+        ]);
+
+        let effect = ExchangeControlEffect::creatures();
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        let combat = game.combat.as_ref().expect("combat should still exist");
+        assert!(combat.attackers.is_empty());
+        assert!(combat.blockers.is_empty());
+    }
+
+    #[test]
+    fn test_exchange_control_fails_when_one_side_left_battlefield() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let creature1 = create_creature(&mut game, "Alice's Creature", alice);
+        let creature2 = create_creature(&mut game, "Bob's Creature", bob);
+        game.move_object(creature2, Zone::Graveyard);
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice).with_targets(vec![
+            ResolvedTarget::Object(creature1),
+            ResolvedTarget::Object(creature2),
+        ]);
+
+        let effect = ExchangeControlEffect::creatures();
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(result.status, crate::effect::OutcomeStatus::TargetInvalid);
+        assert!(game.continuous_effects.effects_sorted().is_empty());
+        assert_eq!(calculated_controller(&game, creature1), alice);
+    }
+
+    #[test]
+    fn test_exchange_control_fails_when_types_no_longer_shared() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let creature = create_creature(&mut game, "Alice's Creature", alice);
+        let artifact_id = game.new_object_id();
+        let artifact_card = CardBuilder::new(CardId::from_raw(artifact_id.0 as u32), "Relic")
+            .card_types(vec![CardType::Artifact])
+            .build();
+        game.add_object(Object::from_card(
+            artifact_id,
+            &artifact_card,
+            bob,
+            Zone::Battlefield,
+        ));
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice).with_targets(vec![
+            ResolvedTarget::Object(creature),
+            ResolvedTarget::Object(artifact_id),
+        ]);
+
+        let effect = ExchangeControlEffect::permanents()
+            .with_shared_type(SharedTypeConstraint::PermanentType);
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(result.status, crate::effect::OutcomeStatus::TargetInvalid);
+        assert!(game.continuous_effects.effects_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_exchange_control_same_controller_does_nothing() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+
+        let creature1 = create_creature(&mut game, "First", alice);
+        let creature2 = create_creature(&mut game, "Second", alice);
+        game.remove_summoning_sickness(creature1);
+        let source = game.new_object_id();
+
+        let mut ctx = ExecutionContext::new_default(source, alice).with_targets(vec![
+            ResolvedTarget::Object(creature1),
+            ResolvedTarget::Object(creature2),
+        ]);
+
+        let effect = ExchangeControlEffect::creatures();
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(result.status, crate::effect::OutcomeStatus::Succeeded);
+        assert!(game.continuous_effects.effects_sorted().is_empty());
+        assert!(!game.is_summoning_sick(creature1));
+    }
+
     #[test]
     fn test_exchange_control_clone_box() {
         let effect = ExchangeControlEffect::creatures();
@@ -2,11 +2,11 @@
 
 use crate::continuous::{EffectTarget, Modification};
 use crate::effect::{Effect, EffectOutcome, Until};
-use crate::effects::helpers::resolve_single_object_for_effect;
+use crate::effects::helpers::{resolve_player_filter, resolve_single_object_for_effect};
 use crate::effects::{ApplyContinuousEffect, EffectExecutor};
 use crate::executor::{ExecutionContext, ExecutionError, execute_effect};
 use crate::game_state::GameState;
-use crate::target::ChooseSpec;
+use crate::target::{ChooseSpec, PlayerFilter};
 
 /// Effect that gains control of a target permanent.
 ///
@@ -15,6 +15,7 @@ use crate::target::ChooseSpec;
 /// # Fields
 ///
 /// * `target` - The permanent to gain control of
+/// * `player` - Who gains control (usually the effect's controller)
 /// * `duration` - How long the control change lasts
 ///
 /// # Example
@@ -25,11 +26,16 @@ use crate::target::ChooseSpec;
 ///
 /// // Gain control of target permanent permanently
 /// let effect = GainControlEffect::permanent(ChooseSpec::permanent());
+///
+/// // Target opponent gains control of this permanent (Jinxed Idol)
+/// let effect = GainControlEffect::donate(ChooseSpec::Source, PlayerFilter::target_opponent());
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct GainControlEffect {
     /// The permanent to gain control of.
     pub target: ChooseSpec,
+    /// The player who gains control.
+    pub player: PlayerFilter,
     /// How long the control change lasts.
     pub duration: Until,
 }
@@ -37,7 +43,25 @@ pub struct GainControlEffect {
 impl GainControlEffect {
     /// Create a new gain control effect.
     pub fn new(target: ChooseSpec, duration: Until) -> Self {
-        Self { target, duration }
+        Self {
+            target,
+            player: PlayerFilter::You,
+            duration,
+        }
+    }
+
+    /// Give control of the target to another player permanently.
+    ///
+    /// The recipient controls the permanent from then on, including its
+    /// upkeep triggers and "you lose the game" drawbacks.
+    pub fn donate(target: ChooseSpec, player: PlayerFilter) -> Self {
+        Self::permanent(target).to_player(player)
+    }
+
+    /// Give control to `player` instead of the effect's controller.
+    pub fn to_player(mut self, player: PlayerFilter) -> Self {
+        self.player = player;
+        self
     }
 
     /// Gain control until end of turn.
@@ -69,9 +93,10 @@ impl EffectExecutor for GainControlEffect {
             .object(target_id)
             .ok_or(ExecutionError::ObjectNotFound(target_id))?;
 
+        let new_controller = resolve_player_filter(game, &self.player, ctx)?;
         let apply = ApplyContinuousEffect::new(
             EffectTarget::Specific(target_id),
-            Modification::ChangeController(ctx.controller),
+            Modification::ChangeController(new_controller),
             self.duration.clone(),
         );
 
@@ -173,6 +198,26 @@ mod tests {
         assert_eq!(result.status, crate::effect::OutcomeStatus::Succeeded);
     }
 
+    #[test]
+    fn test_donate_gives_control_to_target_player() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let idol = create_creature(&mut game, "Donated Creature", alice);
+        let mut ctx = ExecutionContext::new_default(idol, alice)
+            .with_targets(vec![ResolvedTarget::Player(bob)]);
+
+        let effect = GainControlEffect::donate(ChooseSpec::Source, PlayerFilter::target_opponent());
+        assert_eq!(effect.duration, Until::Forever);
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        let controller = game
+            .calculated_characteristics(idol)
+            .map(|chars| chars.controller);
+        assert_eq!(controller, Some(bob));
+    }
+
     #[test]
     fn test_gain_control_target_not_found() {
         let mut game = setup_game();
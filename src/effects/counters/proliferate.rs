@@ -1,17 +1,21 @@
 //! Proliferate effect implementation.
 
+use crate::decisions::make_decision;
+use crate::decisions::specs::ProliferateSpec;
 use crate::effect::EffectOutcome;
 use crate::effects::EffectExecutor;
 use crate::events::{KeywordActionEvent, KeywordActionKind};
 use crate::executor::{ExecutionContext, ExecutionError};
+use crate::filter::ObjectFilter;
 use crate::game_state::GameState;
+use crate::ids::{ObjectId, PlayerId};
 use crate::object::CounterType;
 use crate::triggers::TriggerEvent;
 
 /// Effect that proliferates (adds counters to permanents/players with counters).
 ///
-/// For each permanent with counters and each player with counters, adds one
-/// counter of each type they already have.
+/// The controller chooses any number of permanents and players with counters;
+/// each chosen one gets one more counter of each kind it already has.
 ///
 /// # Example
 ///
@@ -34,45 +38,29 @@ impl EffectExecutor for ProliferateEffect {
         game: &mut GameState,
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
-        // Find all permanents and players with counters
-        // For simplicity, add one counter of each type they already have
-
         let mut proliferated_count = 0;
         let mut outcome = EffectOutcome::count(0);
 
         // Collect permanents with counters and their counter types
-        let permanents_with_counters: Vec<(crate::ids::ObjectId, Vec<CounterType>)> = game
+        let with_counters = ObjectFilter::permanent().with_any_counter();
+        let filter_ctx = ctx.filter_context(game);
+        let permanents_with_counters: Vec<(ObjectId, Vec<CounterType>)> = game
             .battlefield
             .iter()
-            .filter_map(|&perm_id| {
-                game.object(perm_id).and_then(|obj| {
-                    if obj.counters.is_empty() {
-                        None
-                    } else {
-                        Some((perm_id, obj.counters.keys().copied().collect()))
-                    }
-                })
+            .filter_map(|&perm_id| game.object(perm_id))
+            .filter(|obj| with_counters.matches(obj, &filter_ctx, game))
+            .map(|obj| {
+                let counter_types = obj
+                    .counters
+                    .iter()
+                    .filter(|(_, count)| **count > 0)
+                    .map(|(counter_type, _)| *counter_type)
+                    .collect();
+                (obj.id, counter_types)
             })
             .collect();
 
-        // Proliferate permanents using centralized method
-        for (perm_id, counter_types) in permanents_with_counters {
-            for ct in counter_types {
-                if let Some(event) = game.add_counters_with_source(
-                    perm_id,
-                    ct,
-                    1,
-                    Some(ctx.source),
-                    Some(ctx.controller),
-                ) {
-                    outcome = outcome.with_event(event);
-                }
-            }
-            proliferated_count += 1;
-        }
-
-        // Proliferate players and emit marker events for player counters.
-        let players_with_counters: Vec<(crate::ids::PlayerId, Vec<CounterType>)> = game
+        let players_with_counters: Vec<(PlayerId, Vec<CounterType>)> = game
             .players
             .iter()
             .map(|p| {
@@ -91,7 +79,51 @@ impl EffectExecutor for ProliferateEffect {
             .filter(|(_, counters)| !counters.is_empty())
             .collect();
 
+        // The controller chooses any number of the eligible permanents and
+        // players, including none of them.
+        let (chosen_permanents, chosen_players) =
+            if permanents_with_counters.is_empty() && players_with_counters.is_empty() {
+                (Vec::new(), Vec::new())
+            } else {
+                let spec = ProliferateSpec::new(
+                    ctx.source,
+                    permanents_with_counters.iter().map(|(id, _)| *id).collect(),
+                    players_with_counters.iter().map(|(id, _)| *id).collect(),
+                );
+                let response = make_decision(
+                    game,
+                    ctx.decision_maker,
+                    ctx.controller,
+                    Some(ctx.source),
+                    spec,
+                );
+                (response.permanents, response.players)
+            };
+
+        // Proliferate permanents using centralized method
+        for (perm_id, counter_types) in permanents_with_counters {
+            if !chosen_permanents.contains(&perm_id) {
+                continue;
+            }
+            for ct in counter_types {
+                if let Some(event) = game.add_counters_with_source(
+                    perm_id,
+                    ct,
+                    1,
+                    Some(ctx.source),
+                    Some(ctx.controller),
+                ) {
+                    outcome = outcome.with_event(event);
+                }
+            }
+            proliferated_count += 1;
+        }
+
+        // Proliferate players and emit marker events for player counters.
         for (player_id, counters) in players_with_counters {
+            if !chosen_players.contains(&player_id) {
+                continue;
+            }
             for counter_type in counters {
                 if let Some(event) = game.add_player_counters_with_source(
                     player_id,
@@ -123,7 +155,7 @@ impl EffectExecutor for ProliferateEffect {
 mod tests {
     use super::*;
     use crate::card::{CardBuilder, PowerToughness};
-    use crate::ids::{CardId, ObjectId, PlayerId};
+    use crate::ids::CardId;
     use crate::mana::{ManaCost, ManaSymbol};
     use crate::object::Object;
     use crate::types::CardType;
@@ -255,6 +287,35 @@ mod tests {
         assert_eq!(result.value, crate::effect::OutcomeValue::Count(0));
     }
 
+    #[test]
+    fn test_proliferate_only_touches_permanents_with_counters() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        let countered =
+            create_creature_with_counters(&mut game, "Bear", alice, CounterType::PlusOnePlusOne, 2);
+        // A zeroed-out entry left behind in the counters map isn't a counter.
+        let emptied =
+            create_creature_with_counters(&mut game, "Elf", alice, CounterType::PlusOnePlusOne, 0);
+
+        let result = ProliferateEffect::new()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        assert_eq!(result.value, crate::effect::OutcomeValue::Count(1));
+        let counters_on = |id| {
+            game.object(id)
+                .unwrap()
+                .counters
+                .get(&CounterType::PlusOnePlusOne)
+                .copied()
+        };
+        assert_eq!(counters_on(countered), Some(3));
+        assert_eq!(counters_on(emptied), Some(0));
+    }
+
     #[test]
     fn test_proliferate_multiple_permanents() {
         let mut game = setup_game();
@@ -291,6 +352,122 @@ mod tests {
         assert_eq!(obj2.counters.get(&CounterType::MinusOneMinusOne), Some(&2)); // 1 + 1
     }
 
+    /// Chooses a fixed subset of the offered permanents and players.
+    struct ChooseProliferate {
+        permanents: Vec<ObjectId>,
+        players: Vec<PlayerId>,
+        offered: Option<crate::decisions::context::ProliferateContext>,
+    }
+
+    impl crate::decision::DecisionMaker for ChooseProliferate {
+        fn decide_proliferate(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::ProliferateContext,
+        ) -> crate::decisions::specs::ProliferateResponse {
+            self.offered = Some(ctx.clone());
+            crate::decisions::specs::ProliferateResponse {
+                permanents: self.permanents.clone(),
+                players: self.players.clone(),
+            }
+        }
+    }
+
+    fn create_planeswalker_with_loyalty(
+        game: &mut GameState,
+        controller: PlayerId,
+        loyalty: u32,
+    ) -> ObjectId {
+        let id = game.new_object_id();
+        let card = CardBuilder::new(CardId::from_raw(id.0 as u32), "Test Walker")
+            .card_types(vec![CardType::Planeswalker])
+            .build();
+        let mut obj = Object::from_card(id, &card, controller, Zone::Battlefield);
+        obj.counters.insert(CounterType::Loyalty, loyalty);
+        game.add_object(obj);
+        id
+    }
+
+    #[test]
+    fn test_proliferate_lets_controller_choose_among_counter_kinds() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let creature =
+            create_creature_with_counters(&mut game, "Bear", alice, CounterType::PlusOnePlusOne, 2);
+        let walker = create_planeswalker_with_loyalty(&mut game, bob, 4);
+        game.players[1].poison_counters = 3;
+        game.players[0].poison_counters = 1;
+
+        let source = game.new_object_id();
+        let mut dm = ChooseProliferate {
+            permanents: vec![creature],
+            players: vec![bob],
+            offered: None,
+        };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let result = ProliferateEffect::new()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        let offered = dm
+            .offered
+            .expect("controller should be asked to proliferate");
+        assert_eq!(offered.player, alice);
+        let offered_permanents: Vec<_> = offered
+            .eligible_permanents
+            .iter()
+            .map(|(id, _)| *id)
+            .collect();
+        assert!(offered_permanents.contains(&creature));
+        assert!(offered_permanents.contains(&walker));
+        let offered_players: Vec<_> = offered.eligible_players.iter().map(|(id, _)| *id).collect();
+        assert_eq!(offered_players, vec![alice, bob]);
+
+        assert_eq!(result.value, crate::effect::OutcomeValue::Count(2));
+        let counters_on = |id, counter_type| {
+            game.object(id)
+                .unwrap()
+                .counters
+                .get(&counter_type)
+                .copied()
+        };
+        assert_eq!(counters_on(creature, CounterType::PlusOnePlusOne), Some(3));
+        assert_eq!(counters_on(walker, CounterType::Loyalty), Some(4));
+        assert_eq!(game.players[1].poison_counters, 4);
+        assert_eq!(game.players[0].poison_counters, 1);
+    }
+
+    #[test]
+    fn test_proliferate_choosing_none_changes_nothing() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let walker = create_planeswalker_with_loyalty(&mut game, bob, 4);
+        game.players[1].poison_counters = 3;
+
+        let source = game.new_object_id();
+        let mut dm = ChooseProliferate {
+            permanents: Vec::new(),
+            players: Vec::new(),
+            offered: None,
+        };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        let result = ProliferateEffect::new()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        assert_eq!(result.value, crate::effect::OutcomeValue::Count(0));
+        assert_eq!(
+            game.object(walker)
+                .unwrap()
+                .counters
+                .get(&CounterType::Loyalty),
+            Some(&4)
+        );
+        assert_eq!(game.players[1].poison_counters, 3);
+    }
+
     #[test]
     fn test_proliferate_clone_box() {
         let effect = ProliferateEffect::new();
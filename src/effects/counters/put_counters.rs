@@ -1,9 +1,11 @@
 //! Put counters effect implementation.
 
 use crate::effect::{ChoiceCount, EffectOutcome, ExecutionFact, Value};
-use crate::effects::helpers::{resolve_objects_for_effect, resolve_value};
-use crate::effects::{CostExecutableEffect, EffectExecutor};
-use crate::event_processor::process_put_counters_with_event;
+use crate::effects::helpers::{
+    resolve_objects_for_effect, resolve_objects_from_spec, resolve_value,
+};
+use crate::effects::{CostExecutableEffect, EffectExecutor, ExecutabilityReport};
+use crate::event_processor::process_put_counters_with_event_and_dm;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::ids::ObjectId;
@@ -92,6 +94,28 @@ impl EffectExecutor for PutCountersEffect {
         Some(self)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let target_ids = match &self.target {
+            ChooseSpec::Source => vec![ctx.source],
+            spec if spec.is_target() => {
+                resolve_objects_from_spec(game, spec, ctx).unwrap_or_default()
+            }
+            _ => return ExecutabilityReport::Unknown,
+        };
+        let Ok(count) = resolve_value(game, &self.count, ctx) else {
+            return ExecutabilityReport::Unknown;
+        };
+        if count <= 0 {
+            return ExecutabilityReport::impossible("no counters to put");
+        }
+        ExecutabilityReport::executable_if(
+            target_ids
+                .into_iter()
+                .any(|id| game.object(id).is_some() && game.can_have_counters_placed(id)),
+            "counters can't be put on that permanent",
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -139,8 +163,13 @@ impl EffectExecutor for PutCountersEffect {
                 continue;
             }
             // Process through replacement effects (e.g., Melira, Doubling Season).
-            let final_count =
-                process_put_counters_with_event(game, target_id, self.counter_type, assigned_count);
+            let final_count = process_put_counters_with_event_and_dm(
+                game,
+                target_id,
+                self.counter_type,
+                assigned_count,
+                &mut *ctx.decision_maker,
+            );
             if final_count == 0 {
                 outcomes.push(EffectOutcome::prevented());
                 continue;
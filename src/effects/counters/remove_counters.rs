@@ -1,8 +1,10 @@
 //! Remove counters effect implementation.
 
 use crate::effect::{EffectOutcome, Value};
-use crate::effects::helpers::{resolve_single_object_for_effect, resolve_value};
-use crate::effects::{CostExecutableEffect, EffectExecutor};
+use crate::effects::helpers::{
+    resolve_single_object_for_effect, resolve_single_object_from_spec, resolve_value,
+};
+use crate::effects::{CostExecutableEffect, EffectExecutor, ExecutabilityReport};
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::object::CounterType;
@@ -62,6 +64,22 @@ impl EffectExecutor for RemoveCountersEffect {
         Some(self)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        if !matches!(self.target, ChooseSpec::Source) && !self.target.is_target() {
+            return ExecutabilityReport::Unknown;
+        }
+        let (Ok(target_id), Ok(count)) = (
+            resolve_single_object_from_spec(game, &self.target, ctx),
+            resolve_value(game, &self.count, ctx),
+        ) else {
+            return ExecutabilityReport::Unknown;
+        };
+        ExecutabilityReport::executable_if(
+            count > 0 && game.counter_count(target_id, self.counter_type) > 0,
+            format!("no {} counters to remove", self.counter_type.description()),
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -3,16 +3,18 @@
 //! This module implements the `DealDamage` effect, which deals damage to a target
 //! creature, planeswalker, or player.
 
-use crate::effect::{EffectOutcome, Value};
+use crate::decisions::make_decision;
+use crate::decisions::specs::DistributeSpec;
+use crate::effect::{EffectOutcome, ExecutionFact, Value};
 use crate::effects::EffectExecutor;
-use crate::effects::helpers::resolve_value;
-use crate::event_processor::process_damage_assignments_with_event_with_source_snapshot;
+use crate::effects::helpers::{resolve_value, validate_target};
+use crate::event_processor::process_damage_assignments_with_event_and_dm;
 use crate::events::DamageEvent;
 use crate::events::LifeLossEvent;
 use crate::events::combat::{CreatureAttackedEvent, CreatureBecameBlockedEvent};
 use crate::executor::{ExecutionContext, ExecutionError, ResolvedTarget};
 use crate::game_event::DamageTarget;
-use crate::game_state::GameState;
+use crate::game_state::{GameState, Target};
 use crate::target::{ChooseSpec, PlayerFilter};
 use crate::triggers::AttackEventTarget;
 use crate::triggers::TriggerEvent;
@@ -25,6 +27,7 @@ use crate::types::CardType;
 /// * `amount` - The amount of damage to deal (can be fixed or variable)
 /// * `target` - The target specification (creature, player, or "any target")
 /// * `source_is_combat` - Whether this damage is combat damage
+/// * `divided` - If true, the amount is divided among the chosen targets
 ///
 /// # Example
 ///
@@ -34,7 +37,17 @@ use crate::types::CardType;
 ///     amount: Value::Fixed(3),
 ///     target: ChooseSpec::AnyTarget,
 ///     source_is_combat: false,
+///     divided: false,
 /// };
+///
+/// // Deal 3 damage divided as you choose among one, two, or three targets
+/// // (Arc Lightning)
+/// let effect = DealDamageEffect::new(3, ChooseSpec::AnyTarget.with_count(ChoiceCount {
+///     min: 1,
+///     max: Some(3),
+///     ..ChoiceCount::exactly(1)
+/// }))
+/// .with_divided(true);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct DealDamageEffect {
@@ -44,6 +57,11 @@ pub struct DealDamageEffect {
     pub target: ChooseSpec,
     /// Whether this damage is combat damage.
     pub source_is_combat: bool,
+    /// Whether the amount is divided as its controller chooses among the targets.
+    ///
+    /// The division is announced while casting (Rule 601.2d) and recorded on
+    /// the stack entry; a target that becomes illegal loses its share.
+    pub divided: bool,
 }
 
 impl DealDamageEffect {
@@ -53,6 +71,7 @@ impl DealDamageEffect {
             amount: amount.into(),
             target,
             source_is_combat: false,
+            divided: false,
         }
     }
 
@@ -61,8 +80,107 @@ impl DealDamageEffect {
         self.source_is_combat = is_combat;
         self
     }
+
+    /// Mark this as a divided-damage effect.
+    pub fn with_divided(mut self, divided: bool) -> Self {
+        self.divided = divided;
+        self
+    }
+
+    /// Normalize a requested damage division so it is legal for `targets`.
+    ///
+    /// Each target receives at least 1 damage (Rule 601.2d) when the total
+    /// allows it, requested amounts are honored in order, and anything left
+    /// over goes to the first target. Targets assigned nothing are dropped.
+    pub fn normalize_division(
+        targets: &[Target],
+        total: u32,
+        requested: &[(Target, u32)],
+    ) -> Vec<(Target, u32)> {
+        if targets.is_empty() {
+            return Vec::new();
+        }
+        let floor = u32::from(total as usize >= targets.len());
+        let mut amounts = vec![floor; targets.len()];
+        let mut remaining = total - floor * targets.len() as u32;
+        for (target, amount) in requested {
+            let Some(idx) = targets.iter().position(|candidate| candidate == target) else {
+                continue;
+            };
+            let extra = amount.saturating_sub(floor).min(remaining);
+            amounts[idx] += extra;
+            remaining -= extra;
+        }
+        amounts[0] += remaining;
+
+        targets
+            .iter()
+            .copied()
+            .zip(amounts)
+            .filter(|(_, amount)| *amount > 0)
+            .collect()
+    }
+
+    /// Deal the recorded (or freshly chosen) division to each still-legal target.
+    fn execute_divided(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+        total: u32,
+    ) -> Result<EffectOutcome, ExecutionError> {
+        let targets: Vec<Target> = ctx
+            .targets
+            .iter()
+            .filter(|target| validate_target(game, target, &self.target, ctx))
+            .map(|target| match target {
+                ResolvedTarget::Object(id) => Target::Object(*id),
+                ResolvedTarget::Player(id) => Target::Player(*id),
+            })
+            .collect();
+        if targets.is_empty() {
+            return Ok(EffectOutcome::target_invalid());
+        }
+
+        let division: Vec<(Target, u32)> = if !ctx.divided_amounts.is_empty() {
+            // Damage assigned to targets that are no longer legal is lost.
+            ctx.divided_amounts
+                .iter()
+                .filter(|(target, _)| targets.contains(target))
+                .copied()
+                .collect()
+        } else {
+            let requested = make_decision(
+                game,
+                &mut *ctx.decision_maker,
+                ctx.controller,
+                Some(ctx.source),
+                DistributeSpec::damage(ctx.source, total, targets.clone()),
+            );
+            Self::normalize_division(&targets, total, &requested)
+        };
+
+        let mut outcomes = Vec::with_capacity(division.len());
+        for (target, amount) in division {
+            let damage_target = match target {
+                Target::Player(player_id) => DamageTarget::Player(player_id),
+                Target::Object(object_id) => DamageTarget::Object(object_id),
+            };
+            outcomes.push(apply_processed_damage_outcome(
+                game,
+                ctx.source,
+                ctx.source_snapshot.as_ref(),
+                damage_target,
+                amount,
+                self.source_is_combat,
+                ctx.provenance,
+                &mut *ctx.decision_maker,
+            ));
+        }
+        Ok(EffectOutcome::aggregate_summing_counts(outcomes))
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn apply_processed_damage_outcome(
     game: &mut GameState,
     source: crate::ids::ObjectId,
@@ -71,14 +189,16 @@ fn apply_processed_damage_outcome(
     amount: u32,
     source_is_combat: bool,
     provenance: crate::provenance::ProvNodeId,
+    decision_maker: &mut dyn crate::decision::DecisionMaker,
 ) -> EffectOutcome {
-    let processed = process_damage_assignments_with_event_with_source_snapshot(
+    let processed = process_damage_assignments_with_event_and_dm(
         game,
         source,
         initial_target,
         amount,
         source_is_combat,
         source_snapshot,
+        decision_maker,
     );
 
     if processed.replacement_prevented {
@@ -147,10 +267,15 @@ fn apply_processed_damage_outcome(
         }
     }
 
-    if outcomes.is_empty() {
+    let outcome = if outcomes.is_empty() {
         EffectOutcome::count(0)
     } else {
         EffectOutcome::aggregate_summing_counts(outcomes)
+    };
+    if processed.prevented_amount > 0 {
+        outcome.with_execution_fact(ExecutionFact::PreventedDamage(processed.prevented_amount))
+    } else {
+        outcome
     }
 }
 
@@ -162,6 +287,10 @@ impl EffectExecutor for DealDamageEffect {
     ) -> Result<EffectOutcome, ExecutionError> {
         let amount = resolve_value(game, &self.amount, ctx)?.max(0) as u32;
 
+        if self.divided {
+            return self.execute_divided(game, ctx, amount);
+        }
+
         // Check if this is targeting IteratedPlayer (used in ForEachOpponent)
         // If so, resolve the target from the context's iterated_player
         if let ChooseSpec::Player(PlayerFilter::IteratedPlayer) = &self.target {
@@ -174,6 +303,7 @@ impl EffectExecutor for DealDamageEffect {
                     amount,
                     self.source_is_combat,
                     ctx.provenance,
+                    &mut *ctx.decision_maker,
                 ));
             }
             return Ok(EffectOutcome::target_invalid());
@@ -195,6 +325,7 @@ impl EffectExecutor for DealDamageEffect {
                         amount,
                         self.source_is_combat,
                         ctx.provenance,
+                        &mut *ctx.decision_maker,
                     ));
                 }
                 return Ok(EffectOutcome::target_invalid());
@@ -231,6 +362,7 @@ impl EffectExecutor for DealDamageEffect {
                         amount,
                         self.source_is_combat,
                         ctx.provenance,
+                        &mut *ctx.decision_maker,
                     ));
                 }
                 AttackEventTarget::Planeswalker(object_id) => {
@@ -248,6 +380,7 @@ impl EffectExecutor for DealDamageEffect {
                         amount,
                         self.source_is_combat,
                         ctx.provenance,
+                        &mut *ctx.decision_maker,
                     ));
                 }
             }
@@ -264,6 +397,7 @@ impl EffectExecutor for DealDamageEffect {
                 amount,
                 self.source_is_combat,
                 ctx.provenance,
+                &mut *ctx.decision_maker,
             ));
         }
 
@@ -279,6 +413,7 @@ impl EffectExecutor for DealDamageEffect {
                         amount,
                         self.source_is_combat,
                         ctx.provenance,
+                        &mut *ctx.decision_maker,
                     ));
                 }
                 ResolvedTarget::Object(object_id) => {
@@ -296,6 +431,7 @@ impl EffectExecutor for DealDamageEffect {
                             amount,
                             self.source_is_combat,
                             ctx.provenance,
+                            &mut *ctx.decision_maker,
                         ));
                     }
                 }
@@ -316,6 +452,10 @@ impl EffectExecutor for DealDamageEffect {
         }
     }
 
+    fn get_target_count(&self) -> Option<crate::effect::ChoiceCount> {
+        self.divided.then(|| self.target.count())
+    }
+
     fn target_description(&self) -> &'static str {
         "target for damage"
     }
@@ -7,6 +7,7 @@
 mod clear_damage;
 mod deal_damage;
 mod prevent_next_time_damage;
+mod redirect_damage;
 mod redirect_next_damage_to_target;
 mod redirect_next_time_damage_to_source;
 
@@ -15,6 +16,7 @@ pub use deal_damage::DealDamageEffect;
 pub use prevent_next_time_damage::{
     PreventNextTimeDamageEffect, PreventNextTimeDamageSource, PreventNextTimeDamageTarget,
 };
+pub use redirect_damage::RedirectDamageEffect;
 pub use redirect_next_damage_to_target::RedirectNextDamageToTargetEffect;
 pub use redirect_next_time_damage_to_source::{
     RedirectNextTimeDamageSource, RedirectNextTimeDamageToSourceEffect,
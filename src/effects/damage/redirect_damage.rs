@@ -0,0 +1,184 @@
+//! Redirect-damage-this-turn replacement effect.
+
+use crate::effect::EffectOutcome;
+use crate::effects::EffectExecutor;
+use crate::effects::helpers::resolve_objects_for_effect;
+use crate::events::traits::{EventKind, GameEventType, ReplacementMatcher};
+use crate::executor::{ExecutionContext, ExecutionError};
+use crate::game_event::DamageTarget;
+use crate::game_state::GameState;
+use crate::ids::ObjectId;
+use crate::replacement::{RedirectTarget, RedirectWhich, ReplacementAction, ReplacementEffect};
+use crate::target::ChooseSpec;
+
+/// Matches damage from any source to a specific object.
+#[derive(Debug, Clone)]
+struct DamageToSpecificObjectMatcher {
+    target: ObjectId,
+}
+
+impl ReplacementMatcher for DamageToSpecificObjectMatcher {
+    fn matches_event(&self, event: &dyn GameEventType, _ctx: &crate::events::EventContext) -> bool {
+        if event.event_kind() != EventKind::Damage {
+            return false;
+        }
+        crate::events::downcast_event::<crate::events::DamageEvent>(event)
+            .is_some_and(|damage| damage.target == DamageTarget::Object(self.target))
+    }
+
+    fn display(&self) -> String {
+        "When a source would deal damage to that creature".to_string()
+    }
+}
+
+/// Effect that redirects all damage that would be dealt to a permanent this turn.
+///
+/// Used by cards like "If a source would deal damage to target creature this
+/// turn, it deals that damage to its controller instead." The redirected
+/// damage keeps its source, so combat damage stays combat damage and damage
+/// that can't be prevented still can't be prevented.
+///
+/// # Fields
+///
+/// * `target` - The permanent whose damage is redirected
+/// * `redirect_to` - Where the damage is dealt instead
+///
+/// # Example
+///
+/// ```ignore
+/// // "If a source would deal damage to target creature this turn,
+/// //  it deals that damage to its controller instead."
+/// let effect = RedirectDamageEffect::to_source_controller(ChooseSpec::creature());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedirectDamageEffect {
+    /// The permanent whose damage is redirected.
+    pub target: ChooseSpec,
+    /// Where the damage is dealt instead.
+    pub redirect_to: RedirectTarget,
+}
+
+impl RedirectDamageEffect {
+    /// Create a new redirect damage effect.
+    pub fn new(target: ChooseSpec, redirect_to: RedirectTarget) -> Self {
+        Self {
+            target,
+            redirect_to,
+        }
+    }
+
+    /// Redirect damage to target permanent to the controller of the damage's source.
+    pub fn to_source_controller(target: ChooseSpec) -> Self {
+        Self::new(
+            ChooseSpec::target(target),
+            RedirectTarget::ToEventSourceController,
+        )
+    }
+}
+
+impl EffectExecutor for RedirectDamageEffect {
+    fn execute(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+    ) -> Result<EffectOutcome, ExecutionError> {
+        let protected = resolve_objects_for_effect(game, ctx, &self.target)?;
+        if protected.is_empty() {
+            return Ok(EffectOutcome::target_invalid());
+        }
+
+        for target in protected {
+            let replacement = ReplacementEffect::with_matcher(
+                ctx.source,
+                ctx.controller,
+                DamageToSpecificObjectMatcher { target },
+                ReplacementAction::Redirect {
+                    target: self.redirect_to.clone(),
+                    which: RedirectWhich::First,
+                },
+            );
+            game.replacement_effects
+                .add_until_end_of_turn_effect(replacement);
+        }
+        Ok(EffectOutcome::resolved())
+    }
+
+    fn get_target_spec(&self) -> Option<&ChooseSpec> {
+        if self.target.is_target() {
+            Some(&self.target)
+        } else {
+            None
+        }
+    }
+
+    fn target_description(&self) -> &'static str {
+        "creature to redirect damage from"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::definitions::grizzly_bears;
+    use crate::event_processor::process_damage_assignments_with_event;
+    use crate::executor::ResolvedTarget;
+    use crate::ids::PlayerId;
+    use crate::zone::Zone;
+
+    fn setup_game() -> GameState {
+        crate::tests::test_helpers::setup_two_player_game()
+    }
+
+    fn redirect_from(game: &mut GameState, controller: PlayerId, creature: ObjectId) {
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, controller);
+        ctx.targets = vec![ResolvedTarget::Object(creature)];
+        RedirectDamageEffect::to_source_controller(ChooseSpec::creature())
+            .execute(game, &mut ctx)
+            .unwrap();
+    }
+
+    #[test]
+    fn damage_to_protected_creature_is_dealt_to_the_sources_controller() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bear = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        let attacker = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        redirect_from(&mut game, alice, bear);
+        let processed = process_damage_assignments_with_event(
+            &mut game,
+            attacker,
+            DamageTarget::Object(bear),
+            2,
+            true,
+        );
+
+        assert_eq!(processed.assignments.len(), 1);
+        assert_eq!(processed.assignments[0].target, DamageTarget::Player(bob));
+        assert_eq!(processed.assignments[0].amount, 2);
+    }
+
+    #[test]
+    fn redirect_ends_at_cleanup() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bear = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        let attacker = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        redirect_from(&mut game, alice, bear);
+        crate::turn::execute_cleanup_step(&mut game);
+        let processed = process_damage_assignments_with_event(
+            &mut game,
+            attacker,
+            DamageTarget::Object(bear),
+            2,
+            false,
+        );
+
+        assert_eq!(processed.assignments.len(), 1);
+        assert_eq!(processed.assignments[0].target, DamageTarget::Object(bear));
+    }
+}
@@ -27,6 +27,62 @@ pub struct ModalSpec {
     pub min_modes: Value,
 }
 
+/// Result of dry-running an effect against the current game state.
+///
+/// Produced by [`EffectExecutor::can_execute`]. "May" prompts use it to skip
+/// offering options that could not do anything, and mandatory "if able"
+/// actions use it to decide whether they are enforced at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutabilityReport {
+    /// Executing the effect now would do something.
+    Executable,
+    /// Executing the effect now could not do anything, with a short reason.
+    Impossible(String),
+    /// The effect can't tell without executing; callers treat this as executable.
+    Unknown,
+}
+
+impl ExecutabilityReport {
+    /// Create an `Impossible` report with the given reason.
+    pub fn impossible(reason: impl Into<String>) -> Self {
+        Self::Impossible(reason.into())
+    }
+
+    /// `Executable` when `executable` holds, otherwise `Impossible(reason)`.
+    pub fn executable_if(executable: bool, reason: impl Into<String>) -> Self {
+        if executable {
+            Self::Executable
+        } else {
+            Self::impossible(reason)
+        }
+    }
+
+    /// Returns true only when the effect is known to do nothing.
+    pub fn is_impossible(&self) -> bool {
+        matches!(self, Self::Impossible(_))
+    }
+
+    /// Combine the reports of effects that run together as one action.
+    ///
+    /// The action is impossible only when every part is impossible; an empty
+    /// list is `Unknown`.
+    pub fn any_of(reports: impl IntoIterator<Item = Self>) -> Self {
+        let mut combined: Option<Self> = None;
+        for report in reports {
+            match report {
+                Self::Executable => return Self::Executable,
+                Self::Unknown => combined = Some(Self::Unknown),
+                Self::Impossible(_) => {
+                    if combined.is_none() {
+                        combined = Some(report);
+                    }
+                }
+            }
+        }
+        combined.unwrap_or(Self::Unknown)
+    }
+}
+
 /// Trait for executing effects.
 ///
 /// All modular effects implement this trait. Each effect is responsible for:
@@ -90,6 +146,15 @@ pub trait EffectExecutor:
         EffectExecutorClone::clone_boxed(self)
     }
 
+    /// Dry-run this effect without mutating the game.
+    ///
+    /// Reports whether executing the effect now, with this context, could do
+    /// anything. Default implementation returns `Unknown`, which callers treat
+    /// as executable; override only when the check is exact.
+    fn can_execute(&self, _game: &GameState, _ctx: &ExecutionContext) -> ExecutabilityReport {
+        ExecutabilityReport::Unknown
+    }
+
     /// Get the target specification for this effect, if it has one.
     ///
     /// Used for target selection during spell/ability resolution.
@@ -12,15 +12,15 @@ use crate::events::DamageEvent;
 use crate::events::combat::{CreatureAttackedEvent, CreatureBecameBlockedEvent};
 use crate::events::life::LifeGainEvent;
 use crate::events::life::LifeLossEvent;
-use crate::events::other::{CounterPlacedEvent, MarkersChangedEvent};
+use crate::events::other::{CounterPlacedEvent, KeywordActionEvent, MarkersChangedEvent};
 use crate::executor::{ExecutionContext, ExecutionError, ResolvedTarget};
 use crate::game_event::DamageTarget;
 use crate::game_state::GameState;
 use crate::ids::{ObjectId, PlayerId};
-use crate::object_query::candidate_ids_for_filter;
-use crate::target::{ChooseSpec, FilterContext, ObjectRef, PlayerFilter};
+use crate::object_query::{DistinctAmong, candidate_ids_for_filter_in_context};
+use crate::target::{ChooseSpec, FilterContext, ObjectFilter, ObjectRef, PlayerFilter};
 use crate::triggers::AttackEventTarget;
-use crate::types::{CardType, Subtype};
+use crate::types::CardType;
 use crate::zone::Zone;
 // ============================================================================
 // Value Resolution
@@ -70,7 +70,7 @@ pub fn resolve_value(
 
         Value::Count(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
 
             let count = candidate_ids
                 .iter()
@@ -81,7 +81,7 @@ pub fn resolve_value(
         }
         Value::CountScaled(filter, multiplier) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
 
             let count = candidate_ids
                 .iter()
@@ -92,7 +92,7 @@ pub fn resolve_value(
         }
         Value::TotalPower(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
             let total = candidate_ids
                 .iter()
                 .copied()
@@ -108,7 +108,7 @@ pub fn resolve_value(
         }
         Value::TotalToughness(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
             let total = candidate_ids
                 .iter()
                 .copied()
@@ -124,7 +124,7 @@ pub fn resolve_value(
         }
         Value::TotalManaValue(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
             let total = candidate_ids
                 .iter()
                 .filter_map(|&id| game.object(id))
@@ -140,7 +140,7 @@ pub fn resolve_value(
         }
         Value::GreatestPower(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
             let max = candidate_ids
                 .iter()
                 .copied()
@@ -153,7 +153,7 @@ pub fn resolve_value(
         }
         Value::GreatestManaValue(filter) => {
             let filter_ctx = ctx.filter_context(game);
-            let candidate_ids = candidate_ids_for_filter(game, filter);
+            let candidate_ids = candidate_ids_for_filter_in_context(game, filter, &filter_ctx);
             let max = candidate_ids
                 .iter()
                 .filter_map(|&id| game.object(id))
@@ -164,103 +164,11 @@ pub fn resolve_value(
             Ok(max)
         }
         Value::BasicLandTypesAmong(filter) => {
-            use std::collections::HashSet;
-
-            let filter_ctx = ctx.filter_context(game);
-            let candidate_ids: Vec<ObjectId> = match filter.zone {
-                Some(Zone::Battlefield) => game.battlefield.clone(),
-                Some(Zone::Graveyard) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.graveyard.iter().copied())
-                    .collect(),
-                Some(Zone::Hand) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.hand.iter().copied())
-                    .collect(),
-                Some(Zone::Library) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.library.iter().copied())
-                    .collect(),
-                Some(Zone::Stack) => game.stack.iter().map(|entry| entry.object_id).collect(),
-                Some(Zone::Exile) => game.exile.clone(),
-                Some(Zone::Command) => game.command_zone.clone(),
-                None => game.battlefield.clone(),
-            };
-
-            let mut seen = HashSet::new();
-            for obj in candidate_ids
-                .iter()
-                .filter_map(|&id| game.object(id))
-                .filter(|obj| filter.matches(obj, &filter_ctx, game))
-            {
-                for subtype in &obj.subtypes {
-                    if matches!(
-                        subtype,
-                        Subtype::Plains
-                            | Subtype::Island
-                            | Subtype::Swamp
-                            | Subtype::Mountain
-                            | Subtype::Forest
-                    ) {
-                        seen.insert(subtype.clone());
-                    }
-                }
-            }
-            Ok(seen.len() as i32)
-        }
-        Value::ColorsAmong(filter) => {
-            let filter_ctx = ctx.filter_context(game);
-            let candidate_ids: Vec<ObjectId> = match filter.zone {
-                Some(Zone::Battlefield) => game.battlefield.clone(),
-                Some(Zone::Graveyard) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.graveyard.iter().copied())
-                    .collect(),
-                Some(Zone::Hand) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.hand.iter().copied())
-                    .collect(),
-                Some(Zone::Library) => game
-                    .players
-                    .iter()
-                    .flat_map(|player| player.library.iter().copied())
-                    .collect(),
-                Some(Zone::Stack) => game.stack.iter().map(|entry| entry.object_id).collect(),
-                Some(Zone::Exile) => game.exile.clone(),
-                Some(Zone::Command) => game.command_zone.clone(),
-                None => game.battlefield.clone(),
-            };
-
-            let mut has_white = false;
-            let mut has_blue = false;
-            let mut has_black = false;
-            let mut has_red = false;
-            let mut has_green = false;
-
-            for obj in candidate_ids
-                .iter()
-                .filter_map(|&id| game.object(id))
-                .filter(|obj| filter.matches(obj, &filter_ctx, game))
-            {
-                let colors = obj.colors();
-                has_white |= colors.contains(crate::color::Color::White);
-                has_blue |= colors.contains(crate::color::Color::Blue);
-                has_black |= colors.contains(crate::color::Color::Black);
-                has_red |= colors.contains(crate::color::Color::Red);
-                has_green |= colors.contains(crate::color::Color::Green);
-            }
-
-            Ok((has_white as i32)
-                + (has_blue as i32)
-                + (has_black as i32)
-                + (has_red as i32)
-                + (has_green as i32))
+            Ok(distinct_among(game, filter, ctx).basic_land_types())
         }
+        Value::ColorsAmong(filter) => Ok(distinct_among(game, filter, ctx).colors()),
+        Value::CardTypesAmong(filter) => Ok(distinct_among(game, filter, ctx).card_types()),
+        Value::ManaValuesAmong(filter) => Ok(distinct_among(game, filter, ctx).mana_values()),
         Value::DistinctNames(filter) => {
             use std::collections::HashSet;
 
@@ -298,7 +206,7 @@ pub fn resolve_value(
             }
             Ok(seen.len() as i32)
         }
-        Value::CreaturesDiedThisTurn => Ok(game.creatures_died_this_turn as i32),
+        Value::CreaturesDiedThisTurn => Ok(game.turn_trackers.creatures_died as i32),
         Value::CreaturesDiedThisTurnControlledBy(player_filter) => {
             let filter_ctx = ctx.filter_context(game);
             let mut total = 0i32;
@@ -307,7 +215,8 @@ pub fn resolve_value(
                     continue;
                 }
                 total += game
-                    .creatures_died_under_controller_this_turn
+                    .turn_trackers
+                    .creatures_died_under_controller
                     .get(&player.id)
                     .copied()
                     .unwrap_or(0) as i32;
@@ -402,7 +311,7 @@ pub fn resolve_value(
                 snapshot.power.ok_or_else(|| {
                     ExecutionError::UnresolvableValue("Target had no power".to_string())
                 })
-            } else if let Some(snapshot) = ctx.target_snapshots.get(&target_id) {
+            } else if let Some(snapshot) = ctx.last_known(target_id) {
                 snapshot.power.ok_or_else(|| {
                     ExecutionError::UnresolvableValue("Target had no power".to_string())
                 })
@@ -427,7 +336,7 @@ pub fn resolve_value(
                 snapshot.toughness.ok_or_else(|| {
                     ExecutionError::UnresolvableValue("Target had no toughness".to_string())
                 })
-            } else if let Some(snapshot) = ctx.target_snapshots.get(&target_id) {
+            } else if let Some(snapshot) = ctx.last_known(target_id) {
                 snapshot.toughness.ok_or_else(|| {
                     ExecutionError::UnresolvableValue("Target had no toughness".to_string())
                 })
@@ -456,7 +365,7 @@ pub fn resolve_value(
                     .ok_or_else(|| {
                         ExecutionError::UnresolvableValue("Target had no mana value".to_string())
                     })
-            } else if let Some(snapshot) = ctx.target_snapshots.get(&target_id) {
+            } else if let Some(snapshot) = ctx.last_known(target_id) {
                 snapshot
                     .mana_cost
                     .as_ref()
@@ -518,7 +427,13 @@ pub fn resolve_value(
                 resolve_player_filter_to_list(game, player_spec, &ctx.filter_context(game), ctx)?;
             let total: u32 = player_ids
                 .iter()
-                .map(|pid| game.life_gained_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| {
+                    game.turn_trackers
+                        .life_gained
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0)
+                })
                 .sum();
             Ok(total as i32)
         }
@@ -528,7 +443,7 @@ pub fn resolve_value(
                 resolve_player_filter_to_list(game, player_spec, &ctx.filter_context(game), ctx)?;
             let total: u32 = player_ids
                 .iter()
-                .map(|pid| game.life_lost_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| game.turn_trackers.life_lost.get(pid).copied().unwrap_or(0))
                 .sum();
             Ok(total as i32)
         }
@@ -594,7 +509,13 @@ pub fn resolve_value(
                 resolve_player_filter_to_list(game, player_spec, &ctx.filter_context(game), ctx)?;
             let count: u32 = player_ids
                 .iter()
-                .map(|pid| game.spells_cast_this_turn.get(pid).copied().unwrap_or(0))
+                .map(|pid| {
+                    game.turn_trackers
+                        .spells_cast
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0)
+                })
                 .sum();
             Ok(count as i32)
         }
@@ -604,7 +525,13 @@ pub fn resolve_value(
                 resolve_player_filter_to_list(game, player_spec, &ctx.filter_context(game), ctx)?;
             let count: i32 = player_ids
                 .iter()
-                .map(|pid| game.spells_cast_this_turn.get(pid).copied().unwrap_or(0) as i32)
+                .map(|pid| {
+                    game.turn_trackers
+                        .spells_cast
+                        .get(pid)
+                        .copied()
+                        .unwrap_or(0) as i32
+                })
                 .sum();
             Ok((count - 1).max(0))
         }
@@ -633,26 +560,16 @@ pub fn resolve_value(
         }
 
         Value::CardTypesInGraveyard(player_spec) => {
-            use crate::types::CardType;
-
             let player_id = resolve_player_filter(game, player_spec, ctx)?;
             let player = game
                 .player(player_id)
                 .ok_or(ExecutionError::PlayerNotFound(player_id))?;
 
-            let mut types: Vec<CardType> = Vec::new();
-            for &card_id in &player.graveyard {
-                let Some(obj) = game.object(card_id) else {
-                    continue;
-                };
-                for card_type in &obj.card_types {
-                    if !types.contains(card_type) {
-                        types.push(*card_type);
-                    }
-                }
+            let mut among = DistinctAmong::default();
+            for obj in player.graveyard.iter().filter_map(|&id| game.object(id)) {
+                among.add(obj);
             }
-
-            Ok(types.len() as i32)
+            Ok(among.card_types())
         }
 
         Value::Devotion { player, color } => {
@@ -723,8 +640,11 @@ pub fn resolve_value(
             if let Some(counter_event) = triggering_event.downcast::<CounterPlacedEvent>() {
                 return Ok(counter_event.amount as i32);
             }
+            if let Some(action_event) = triggering_event.downcast::<KeywordActionEvent>() {
+                return Ok(action_event.amount as i32);
+            }
             Err(ExecutionError::UnresolvableValue(
-                "EventValue(Amount) requires a life gain/loss, damage, or marker-change event"
+                "EventValue(Amount) requires a life gain/loss, damage, marker-change, or keyword action event"
                     .to_string(),
             ))
         }
@@ -762,9 +682,11 @@ pub fn resolve_value(
                 markers_event.amount as i32
             } else if let Some(counter_event) = triggering_event.downcast::<CounterPlacedEvent>() {
                 counter_event.amount as i32
+            } else if let Some(action_event) = triggering_event.downcast::<KeywordActionEvent>() {
+                action_event.amount as i32
             } else {
                 return Err(ExecutionError::UnresolvableValue(
-                    "EventValue(Amount) requires a life gain/loss, damage, or marker-change event"
+                    "EventValue(Amount) requires a life gain/loss, damage, marker-change, or keyword action event"
                         .to_string(),
                 ));
             };
@@ -835,6 +757,7 @@ pub fn resolve_value(
             let paid = get_optional_costs_paid(game, ctx);
             Ok(paid.kick_count() as i32)
         }
+        Value::TargetCount => Ok(ctx.targets.len() as i32),
         Value::CountersOnSource(counter_type) => {
             // Get the number of counters of the specified type on the source
             if let Some(source) = game.object(ctx.source) {
@@ -873,6 +796,24 @@ pub fn resolve_value(
     }
 }
 
+/// Tally the distinct characteristics among objects matching `filter`.
+fn distinct_among(
+    game: &GameState,
+    filter: &ObjectFilter,
+    ctx: &ExecutionContext,
+) -> DistinctAmong {
+    let filter_ctx = ctx.filter_context(game);
+    let mut among = DistinctAmong::default();
+    for obj in candidate_ids_for_filter_in_context(game, filter, &filter_ctx)
+        .iter()
+        .filter_map(|&id| game.object(id))
+        .filter(|obj| filter.matches(obj, &filter_ctx, game))
+    {
+        among.add(obj);
+    }
+    among
+}
+
 // ============================================================================
 // Player Filter Resolution
 // ============================================================================
@@ -1037,6 +978,13 @@ pub fn resolve_player_filter(
             };
             Ok(player_id)
         }
+        PlayerFilter::TriggeringPlayer => ctx
+            .triggering_event
+            .as_ref()
+            .and_then(|event| event.player())
+            .ok_or_else(|| {
+                ExecutionError::UnresolvableValue("TriggeringPlayer not set".to_string())
+            }),
         PlayerFilter::Target(_) => {
             for target in &ctx.targets {
                 if let ResolvedTarget::Player(id) = target {
@@ -1086,7 +1034,7 @@ fn resolve_controller_of(
             let target_id = find_target_object(&ctx.targets)?;
             if let Some(obj) = game.object(target_id) {
                 Ok(obj.controller)
-            } else if let Some(snapshot) = ctx.target_snapshots.get(&target_id) {
+            } else if let Some(snapshot) = ctx.last_known(target_id) {
                 Ok(snapshot.controller)
             } else {
                 Err(ExecutionError::ObjectNotFound(target_id))
@@ -1095,7 +1043,7 @@ fn resolve_controller_of(
         ObjectRef::Specific(object_id) => {
             if let Some(obj) = game.object(*object_id) {
                 Ok(obj.controller)
-            } else if let Some(snapshot) = ctx.target_snapshots.get(object_id) {
+            } else if let Some(snapshot) = ctx.last_known(*object_id) {
                 Ok(snapshot.controller)
             } else {
                 Err(ExecutionError::ObjectNotFound(*object_id))
@@ -1121,7 +1069,7 @@ fn resolve_owner_of(
             let target_id = find_target_object(&ctx.targets)?;
             if let Some(obj) = game.object(target_id) {
                 Ok(obj.owner)
-            } else if let Some(snapshot) = ctx.target_snapshots.get(&target_id) {
+            } else if let Some(snapshot) = ctx.last_known(target_id) {
                 Ok(snapshot.owner)
             } else {
                 Err(ExecutionError::ObjectNotFound(target_id))
@@ -1130,7 +1078,7 @@ fn resolve_owner_of(
         ObjectRef::Specific(object_id) => {
             if let Some(obj) = game.object(*object_id) {
                 Ok(obj.owner)
-            } else if let Some(snapshot) = ctx.target_snapshots.get(object_id) {
+            } else if let Some(snapshot) = ctx.last_known(*object_id) {
                 Ok(snapshot.owner)
             } else {
                 Err(ExecutionError::ObjectNotFound(*object_id))
@@ -1305,6 +1253,7 @@ pub fn validate_target(
     match (target, spec) {
         // Target wrapper - unwrap and validate the inner spec
         (_, ChooseSpec::Target(inner)) => validate_target(game, target, inner, ctx),
+        (_, ChooseSpec::WithCount(inner, _)) => validate_target(game, target, inner, ctx),
         (ResolvedTarget::Object(id), ChooseSpec::Object(filter)) => {
             if let Some(obj) = game.object(*id) {
                 filter.matches(obj, &filter_ctx, game)
@@ -1467,6 +1416,22 @@ pub fn resolve_objects_for_effect(
             return Err(ExecutionError::InvalidTarget);
         }
 
+        // Staged cost payments pass the cards the player already picked as
+        // pre-chosen targets; use them instead of prompting a second time.
+        if ctx.cause.cause_type == crate::events::cause::CauseType::Cost {
+            let preselected: Vec<ObjectId> = ctx
+                .targets
+                .iter()
+                .filter_map(|target| match target {
+                    ResolvedTarget::Object(id) if candidates.contains(id) => Some(*id),
+                    _ => None,
+                })
+                .collect();
+            if !preselected.is_empty() && (min..=max).contains(&preselected.len()) {
+                return Ok(preselected);
+            }
+        }
+
         if candidates.len() == 1 && min == 1 && max == 1 {
             return Ok(candidates);
         }
@@ -2079,6 +2044,11 @@ fn resolve_player_filter_to_list(
             };
             Ok(vec![player_id])
         }
+        PlayerFilter::TriggeringPlayer => Ok(vec![resolve_player_filter(
+            game,
+            &PlayerFilter::TriggeringPlayer,
+            ctx,
+        )?]),
         PlayerFilter::IteratedPlayer => ctx
             .iterated_player
             .map(|id| vec![id])
@@ -2135,6 +2105,29 @@ mod tests {
         game.create_object_from_card(&card, controller, Zone::Battlefield)
     }
 
+    #[test]
+    fn count_of_cards_in_your_hand_follows_exchanged_hands() {
+        let mut game = new_test_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let card = CardBuilder::new(crate::ids::CardId::from_raw(1), "Hand Card").build();
+        game.create_object_from_card(&card, alice, Zone::Hand);
+        game.create_object_from_card(&card, alice, Zone::Hand);
+        game.create_object_from_card(&card, bob, Zone::Hand);
+        game.exchange_hands(alice, bob);
+
+        let value = Value::Count(
+            ObjectFilter::default()
+                .in_zone(Zone::Hand)
+                .owned_by(PlayerFilter::You),
+        );
+        // Alice now holds Bob's card; her own two are in Bob's hand.
+        let ctx = ExecutionContext::new_default(game.new_object_id(), alice);
+        assert_eq!(resolve_value(&game, &value, &ctx).unwrap(), 1);
+        let ctx = ExecutionContext::new_default(game.new_object_id(), bob);
+        assert_eq!(resolve_value(&game, &value, &ctx).unwrap(), 2);
+    }
+
     struct SelectIdsDecisionMaker {
         chosen: Vec<ObjectId>,
     }
@@ -2190,6 +2183,90 @@ mod tests {
         assert_eq!(resolve_value(&game, &value, &ctx).unwrap(), 6);
     }
 
+    #[test]
+    fn test_resolve_domain_counts_distinct_basic_land_types() {
+        use crate::cards::definitions::{basic_forest, basic_island, basic_plains};
+        use crate::target::ObjectFilter;
+
+        let mut game = new_test_game();
+        let alice = game.players[0].id;
+        let bob = game.players[1].id;
+        for def in [
+            basic_plains(),
+            basic_plains(),
+            basic_island(),
+            basic_forest(),
+        ] {
+            game.create_object_from_definition(&def, alice, Zone::Battlefield);
+        }
+        game.create_object_from_definition(
+            &crate::cards::definitions::basic_swamp(),
+            bob,
+            Zone::Battlefield,
+        );
+
+        let source_id = game.new_object_id();
+        let ctx = ExecutionContext::new_default(source_id, alice);
+        let domain = Value::BasicLandTypesAmong(ObjectFilter::land().you_control());
+        assert_eq!(resolve_value(&game, &domain, &ctx).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_resolve_delirium_counts_double_faced_card_by_front_face() {
+        use crate::card::LinkedFaceLayout;
+        use crate::ids::CardId;
+        use crate::target::ObjectFilter;
+
+        let mut game = new_test_game();
+        let alice = game.players[0].id;
+        let bob = game.players[1].id;
+        let make_card = |id: u32, name: &str, card_types: Vec<CardType>, generic: u8| {
+            CardBuilder::new(CardId::from_raw(id), name)
+                .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(
+                    generic,
+                )]]))
+                .card_types(card_types)
+                .build()
+        };
+
+        // Front face is an enchantment; the land back face must not count in a graveyard.
+        let dfc = CardBuilder::new(CardId::from_raw(1), "Search Probe")
+            .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2)]]))
+            .card_types(vec![CardType::Enchantment])
+            .other_face(CardId::from_raw(2))
+            .other_face_name("Probe Spire")
+            .linked_face_layout(LinkedFaceLayout::TransformLike)
+            .build();
+        game.create_object_from_card(&dfc, alice, Zone::Graveyard);
+        let shock = make_card(3, "Probe Shock", vec![CardType::Instant], 1);
+        game.create_object_from_card(&shock, alice, Zone::Graveyard);
+        let golem = make_card(
+            4,
+            "Probe Golem",
+            vec![CardType::Artifact, CardType::Creature],
+            2,
+        );
+        game.create_object_from_card(&golem, alice, Zone::Graveyard);
+        let rite = make_card(5, "Probe Rite", vec![CardType::Sorcery], 3);
+        game.create_object_from_card(&rite, bob, Zone::Graveyard);
+
+        let source_id = game.new_object_id();
+        let ctx = ExecutionContext::new_default(source_id, alice);
+        let your_graveyard = ObjectFilter::default()
+            .in_zone(Zone::Graveyard)
+            .owned_by(PlayerFilter::You);
+        let all_graveyards = ObjectFilter::default().in_zone(Zone::Graveyard);
+
+        let delirium = Value::CardTypesInGraveyard(PlayerFilter::You);
+        assert_eq!(resolve_value(&game, &delirium, &ctx).unwrap(), 4);
+        let among_yours = Value::CardTypesAmong(your_graveyard.clone());
+        assert_eq!(resolve_value(&game, &among_yours, &ctx).unwrap(), 4);
+        let among_all = Value::CardTypesAmong(all_graveyards);
+        assert_eq!(resolve_value(&game, &among_all, &ctx).unwrap(), 5);
+        let mana_values = Value::ManaValuesAmong(your_graveyard);
+        assert_eq!(resolve_value(&game, &mana_values, &ctx).unwrap(), 2);
+    }
+
     #[test]
     fn test_resolve_count_for_attacking_iterated_player_or_their_planeswalkers() {
         use crate::card::{CardBuilder, PowerToughness};
@@ -4,6 +4,7 @@ use crate::effect::{EffectOutcome, Value};
 use crate::effects::helpers::{resolve_player_from_spec, resolve_value};
 use crate::effects::{CostExecutableEffect, EffectExecutor};
 use crate::events::LifeLossEvent;
+use crate::events::cause::CauseType;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::target::{ChooseSpec, PlayerFilter};
@@ -105,10 +106,13 @@ impl EffectExecutor for LoseLifeEffect {
         // Create the trigger event only if life was actually lost
         let outcome = EffectOutcome::count(amount as i32);
         if amount > 0 {
-            let event = TriggerEvent::new_with_provenance(
-                LifeLossEvent::from_effect(player_id, amount),
-                ctx.provenance,
-            );
+            // Executed as a cost, this is paying life rather than losing it to an effect.
+            let event = if ctx.cause.cause_type == CauseType::Cost {
+                LifeLossEvent::from_cost(player_id, amount)
+            } else {
+                LifeLossEvent::from_effect(player_id, amount)
+            };
+            let event = TriggerEvent::new_with_provenance(event, ctx.provenance);
             Ok(outcome.with_event(event))
         } else {
             Ok(outcome)
@@ -227,4 +231,91 @@ mod tests {
             Some("Pay 2 life for each card in your hand")
         );
     }
+
+    fn create_watcher(game: &mut GameState, name: &str, text: &str) -> crate::ids::ObjectId {
+        let def = crate::cards::CardDefinitionBuilder::new(crate::ids::CardId::new(), name)
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(1, 1))
+            .parse_text(text)
+            .expect("watcher text should parse");
+        game.create_object_from_definition(
+            &def,
+            crate::ids::PlayerId::from_index(0),
+            crate::zone::Zone::Battlefield,
+        )
+    }
+
+    fn life_loss(event: &TriggerEvent) -> &LifeLossEvent {
+        event
+            .downcast::<LifeLossEvent>()
+            .expect("expected a life loss event")
+    }
+
+    #[test]
+    fn test_paying_life_is_flagged_as_cost_and_still_loses_life() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = crate::ids::PlayerId::from_index(0);
+        let watcher = create_watcher(
+            &mut game,
+            "Loss Watcher",
+            "Whenever you lose life, put a +1/+1 counter on this creature.",
+        );
+
+        // Paying life for a cost.
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        let mut cost_ctx = crate::costs::CostContext::new(watcher, alice, &mut dm);
+        crate::costs::Cost::life(2)
+            .pay(&mut game, &mut cost_ctx)
+            .expect("alice can pay 2 life");
+        let paid = game.take_pending_trigger_events();
+        assert_eq!(paid.len(), 1);
+        assert!(life_loss(&paid[0]).from_cost);
+
+        // Losing life to an effect.
+        let mut ctx = ExecutionContext::new_default(watcher, alice);
+        let outcome = LoseLifeEffect::you(3).execute(&mut game, &mut ctx).unwrap();
+        let lost = &outcome.events[0];
+        assert!(!life_loss(lost).from_cost);
+        assert!(!life_loss(lost).from_damage);
+
+        assert_eq!(game.player(alice).unwrap().life, 15);
+        // Paying life is losing life (rule 119.4), so both trigger the watcher.
+        for event in [&paid[0], lost] {
+            let triggered = crate::triggers::check_triggers(&game, event);
+            assert_eq!(triggered.len(), 1);
+            assert_eq!(triggered[0].source, watcher);
+        }
+    }
+
+    #[test]
+    fn test_gaining_and_losing_life_in_one_resolution_triggers_both_watchers() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = crate::ids::PlayerId::from_index(0);
+        let loss_watcher = create_watcher(
+            &mut game,
+            "Loss Watcher",
+            "Whenever you lose life, put a +1/+1 counter on this creature.",
+        );
+        let gain_watcher = create_watcher(
+            &mut game,
+            "Gain Watcher",
+            "Whenever you gain life, put a +1/+1 counter on this creature.",
+        );
+
+        let mut ctx = ExecutionContext::new_default(loss_watcher, alice);
+        let mut events = Vec::new();
+        for effect in [
+            crate::effect::Effect::gain_life(2),
+            crate::effect::Effect::lose_life(1),
+        ] {
+            let outcome = crate::executor::execute_effect(&mut game, &effect, &mut ctx).unwrap();
+            events.extend(outcome.events);
+        }
+
+        let sources: Vec<_> = crate::triggers::check_triggers_for_events(&game, &events)
+            .iter()
+            .map(|entry| entry.source)
+            .collect();
+        assert_eq!(sources, vec![gain_watcher, loss_watcher]);
+    }
 }
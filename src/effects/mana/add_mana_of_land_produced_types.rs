@@ -150,6 +150,7 @@ fn mana_ability_condition_met(
                 attacking_player: None,
                 filter_source: Some(source.id),
                 triggering_event: None,
+                source_snapshot: None,
                 trigger_identity: None,
                 ability_index: None,
                 options: crate::condition_eval::ExternalEvaluationOptions {
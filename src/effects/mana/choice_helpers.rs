@@ -7,7 +7,7 @@ use crate::game_state::GameState;
 use crate::ids::ObjectId;
 use crate::ids::PlayerId;
 use crate::mana::ManaSymbol;
-use crate::types::Subtype;
+use crate::types::{Subtype, Supertype};
 
 /// Choose one or more mana colors through the decision system with stable
 /// fallback behavior and length normalization.
@@ -103,6 +103,7 @@ fn credit_mana_symbols_with_context<I>(
 ) where
     I: IntoIterator<Item = ManaSymbol>,
 {
+    let snow_source = source.is_some_and(|id| game.object_has_supertype(id, Supertype::Snow));
     if let Some(player) = game.player_mut(player_id) {
         for symbol in symbols {
             if restrictions.is_empty() {
@@ -115,6 +116,9 @@ fn credit_mana_symbols_with_context<I>(
                     restrictions: restrictions.to_vec(),
                 });
             }
+            if snow_source {
+                player.mana_pool.mark_snow(symbol, 1);
+            }
         }
     }
 }
@@ -26,3 +26,4 @@ pub use add_mana_of_land_produced_types::AddManaOfLandProducedTypesEffect;
 pub use add_scaled_mana::AddScaledManaEffect;
 pub use grant_mana_ability_until_eot::GrantManaAbilityUntilEotEffect;
 pub use pay_mana::PayManaEffect;
+pub(crate) use pay_mana::pay_mana_cost_interactively;
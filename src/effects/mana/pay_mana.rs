@@ -28,83 +28,6 @@ impl PayManaEffect {
     pub fn new(cost: ManaCost, player: ChooseSpec) -> Self {
         Self { cost, player }
     }
-
-    fn try_pay_interactively(
-        &self,
-        game: &mut GameState,
-        ctx: &mut ExecutionContext,
-        player_id: PlayerId,
-    ) -> bool {
-        const MAX_PAYMENT_STEPS: usize = 32;
-
-        for _ in 0..MAX_PAYMENT_STEPS {
-            let can_pay_now = game.can_pay_mana_cost(player_id, Some(ctx.source), &self.cost, 0);
-            let mana_abilities =
-                get_available_mana_abilities(game, player_id, &mut ctx.decision_maker);
-
-            if !can_pay_now && mana_abilities.is_empty() {
-                return false;
-            }
-
-            let mut choices = Vec::new();
-            let mut options = Vec::new();
-
-            if can_pay_now {
-                choices.push(PayManaChoice::PayNow);
-                options.push(SelectableOption::new(choices.len() - 1, "Pay mana cost"));
-            }
-
-            for (permanent_id, ability_index, description) in mana_abilities {
-                choices.push(PayManaChoice::ActivateManaAbility {
-                    permanent_id,
-                    ability_index,
-                });
-                options.push(SelectableOption::new(
-                    choices.len() - 1,
-                    format!(
-                        "Tap {}: {}",
-                        describe_permanent(game, permanent_id),
-                        description
-                    ),
-                ));
-            }
-
-            if choices.is_empty() {
-                return false;
-            }
-
-            let source_name = game
-                .object(ctx.source)
-                .map(|obj| obj.name.clone())
-                .unwrap_or_else(|| "effect".to_string());
-            let decision_ctx =
-                SelectOptionsContext::mana_payment(player_id, ctx.source, source_name, options);
-            let selected = ctx.decision_maker.decide_options(game, &decision_ctx);
-            let selected_idx = selected.first().copied().unwrap_or(0);
-            let choice = choices.get(selected_idx).copied().unwrap_or(choices[0]);
-
-            match choice {
-                PayManaChoice::PayNow => {
-                    return game.try_pay_mana_cost(player_id, Some(ctx.source), &self.cost, 0);
-                }
-                PayManaChoice::ActivateManaAbility {
-                    permanent_id,
-                    ability_index,
-                } => {
-                    let action = SpecialAction::ActivateManaAbility {
-                        permanent_id,
-                        ability_index,
-                    };
-
-                    if perform(action, game, player_id, &mut ctx.decision_maker).is_err() {
-                        return false;
-                    }
-                }
-            }
-        }
-
-        game.try_pay_mana_cost(player_id, Some(ctx.source), &self.cost, 0)
-    }
 }
 
 impl EffectExecutor for PayManaEffect {
@@ -114,7 +37,13 @@ impl EffectExecutor for PayManaEffect {
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
         let player_id = resolve_player_from_spec(game, &self.player, ctx)?;
-        if self.try_pay_interactively(game, ctx, player_id) {
+        if pay_mana_cost_interactively(
+            game,
+            player_id,
+            ctx.source,
+            &self.cost,
+            &mut ctx.decision_maker,
+        ) {
             Ok(EffectOutcome::count(1))
         } else {
             Ok(EffectOutcome::impossible())
@@ -134,6 +63,84 @@ impl EffectExecutor for PayManaEffect {
     }
 }
 
+/// Let a player pay a mana cost in the middle of resolution.
+///
+/// The player may activate mana abilities (tapping lands, etc.) until the mana
+/// in their pool covers `cost`, then pays it. Returns `false` when the cost
+/// can't be paid; mana produced along the way stays in the pool.
+pub(crate) fn pay_mana_cost_interactively(
+    game: &mut GameState,
+    player_id: PlayerId,
+    source: ObjectId,
+    cost: &ManaCost,
+    decision_maker: &mut impl DecisionMaker,
+) -> bool {
+    const MAX_PAYMENT_STEPS: usize = 32;
+
+    for _ in 0..MAX_PAYMENT_STEPS {
+        let can_pay_now = game.can_pay_mana_cost(player_id, Some(source), cost, 0);
+        let mana_abilities = get_available_mana_abilities(game, player_id, decision_maker);
+
+        if !can_pay_now && mana_abilities.is_empty() {
+            return false;
+        }
+
+        let mut choices = Vec::new();
+        let mut options = Vec::new();
+
+        if can_pay_now {
+            choices.push(PayManaChoice::PayNow);
+            options.push(SelectableOption::new(choices.len() - 1, "Pay mana cost"));
+        }
+
+        for (permanent_id, ability_index, description) in mana_abilities {
+            choices.push(PayManaChoice::ActivateManaAbility {
+                permanent_id,
+                ability_index,
+            });
+            options.push(SelectableOption::new(
+                choices.len() - 1,
+                format!(
+                    "Tap {}: {}",
+                    describe_permanent(game, permanent_id),
+                    description
+                ),
+            ));
+        }
+
+        let source_name = game
+            .object(source)
+            .map(|obj| obj.name.clone())
+            .unwrap_or_else(|| "effect".to_string());
+        let decision_ctx =
+            SelectOptionsContext::mana_payment(player_id, source, source_name, options);
+        let selected = decision_maker.decide_options(game, &decision_ctx);
+        let selected_idx = selected.first().copied().unwrap_or(0);
+        let choice = choices.get(selected_idx).copied().unwrap_or(choices[0]);
+
+        match choice {
+            PayManaChoice::PayNow => {
+                return game.try_pay_mana_cost(player_id, Some(source), cost, 0);
+            }
+            PayManaChoice::ActivateManaAbility {
+                permanent_id,
+                ability_index,
+            } => {
+                let action = SpecialAction::ActivateManaAbility {
+                    permanent_id,
+                    ability_index,
+                };
+
+                if perform(action, game, player_id, decision_maker).is_err() {
+                    return false;
+                }
+            }
+        }
+    }
+
+    game.try_pay_mana_cost(player_id, Some(source), cost, 0)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PayManaChoice {
     PayNow,
@@ -146,7 +153,7 @@ enum PayManaChoice {
 fn get_available_mana_abilities(
     game: &GameState,
     player: PlayerId,
-    decision_maker: &mut &mut dyn DecisionMaker,
+    decision_maker: &mut impl DecisionMaker,
 ) -> Vec<(ObjectId, usize, String)> {
     let mut abilities = Vec::new();
 
@@ -243,7 +250,7 @@ mod tests {
             _game: &GameState,
             ctx: &crate::decisions::context::SelectOptionsContext,
         ) -> Vec<usize> {
-            if ctx.description.starts_with("Pay mana for") {
+            if ctx.description.key == crate::display_text::DisplayKey::PayManaFor {
                 self.mana_payment_prompts += 1;
 
                 // First prompt: activate a mana ability if available.
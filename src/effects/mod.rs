@@ -58,15 +58,17 @@ pub mod tokens;
 pub mod zones;
 
 // Re-export the traits, modal spec, and cost validation error
-pub use executor_trait::{CostExecutableEffect, CostValidationError, EffectExecutor, ModalSpec};
+pub use executor_trait::{
+    CostExecutableEffect, CostValidationError, EffectExecutor, ExecutabilityReport, ModalSpec,
+};
 
 // Re-export effect implementations
 pub use cards::{
     ClashEffect, ConniveEffect, DiscardEffect, DiscardHandEffect, DrawCardsEffect,
-    DrawForEachTaggedMatchingEffect, ExileTopOfLibraryEffect, LookAtHandEffect,
-    LookAtTopCardsEffect, MillEffect, RevealFromHandEffect, RevealTaggedEffect, RevealTopEffect,
-    ScryEffect, SearchLibraryEffect, ShuffleGraveyardIntoLibraryEffect, ShuffleLibraryEffect,
-    SurveilEffect,
+    DrawForEachTaggedMatchingEffect, ExileTopOfLibraryEffect, FactOrFictionEffect,
+    LookAtHandEffect, LookAtTopCardsEffect, MillEffect, RevealFromHandEffect, RevealTaggedEffect,
+    RevealTopEffect, ScryEffect, SearchLibraryEffect, ShuffleGraveyardIntoLibraryEffect,
+    ShuffleLibraryEffect, SurveilEffect,
 };
 pub use combat::{
     EnterAttackingEffect, FightEffect, GoadEffect, GrantAbilitiesAllEffect,
@@ -95,8 +97,8 @@ pub use counters::{
 };
 pub use damage::{
     ClearDamageEffect, DealDamageEffect, PreventNextTimeDamageEffect, PreventNextTimeDamageSource,
-    PreventNextTimeDamageTarget, RedirectNextDamageToTargetEffect, RedirectNextTimeDamageSource,
-    RedirectNextTimeDamageToSourceEffect,
+    PreventNextTimeDamageTarget, RedirectDamageEffect, RedirectNextDamageToTargetEffect,
+    RedirectNextTimeDamageSource, RedirectNextTimeDamageToSourceEffect,
 };
 pub use delayed::{
     ExileTaggedWhenSourceLeavesEffect, SacrificeSourceWhenTaggedLeavesEffect,
@@ -131,17 +133,17 @@ pub use player::{
 pub use replacement::{ApplyReplacementEffect, ReplacementApplyMode};
 pub use restrictions::CantEffect;
 pub use stack::{
-    ChooseNewTargetsEffect, CopySpellEffect, CounterEffect, NewTargetRestriction, RetargetMode,
-    RetargetStackObjectEffect,
+    AbilityToCopy, ChooseNewTargetsEffect, CopyAbilityEffect, CopySpellEffect, CounterEffect,
+    NewTargetRestriction, RetargetMode, RetargetStackObjectEffect,
 };
 pub use tokens::{
     AmassEffect, CopyAttackTargetMode, CreateTokenCopyEffect, CreateTokenEffect, InvestigateEffect,
 };
 pub use zones::{
-    BattlefieldController, DestroyEffect, DestroyNoRegenerationEffect, ExileEffect,
-    ExileUntilDuration, ExileUntilEffect, HauntExileEffect, MoveToLibraryNthFromTopEffect,
-    MoveToZoneEffect, PutOntoBattlefieldEffect, ReorderGraveyardEffect, ReorderLibraryTopEffect,
-    ReturnAllToBattlefieldEffect, ReturnFromGraveyardOrExileToBattlefieldEffect,
-    ReturnFromGraveyardToBattlefieldEffect, ReturnFromGraveyardToHandEffect, ReturnToHandEffect,
-    SacrificeEffect, SacrificeTargetEffect,
+    BattlefieldController, DestroyEffect, DestroyNoRegenerationEffect, ExchangeZonesEffect,
+    ExileEffect, ExileUntilDuration, ExileUntilEffect, HauntExileEffect,
+    MoveToLibraryNthFromTopEffect, MoveToZoneEffect, PutOntoBattlefieldEffect,
+    ReorderGraveyardEffect, ReorderLibraryTopEffect, ReturnAllToBattlefieldEffect,
+    ReturnFromGraveyardOrExileToBattlefieldEffect, ReturnFromGraveyardToBattlefieldEffect,
+    ReturnFromGraveyardToHandEffect, ReturnToHandEffect, SacrificeEffect, SacrificeTargetEffect,
 };
@@ -1,5 +1,7 @@
 //! Transform effect implementation.
 
+use crate::card::LinkedFaceLayout;
+use crate::combat_state::remove_from_combat;
 use crate::effect::EffectOutcome;
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::resolve_single_object_for_effect;
@@ -8,13 +10,19 @@ use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::target::ChooseSpec;
 use crate::triggers::TriggerEvent;
+use crate::types::CardType;
 
 /// Effect that transforms a double-faced permanent.
 ///
-/// Toggles the face state of a DFC (double-faced card).
+/// Toggles the face state of a transforming DFC (double-faced card).
 /// When face_down is false, the card shows its front face.
 /// When face_down is true, the card shows its back face.
 ///
+/// The permanent takes on the other face's characteristics but stays the same
+/// object, keeping its counters, attachments and tapped state (Rule 711.8).
+/// Anything that isn't a transforming DFC is left alone (Rule 701.28c), and a
+/// creature that transforms into a noncreature is removed from combat.
+///
 /// # Fields
 ///
 /// * `target` - The permanent to transform
@@ -63,13 +71,53 @@ impl EffectExecutor for TransformEffect {
             return Ok(EffectOutcome::resolved());
         }
 
+        let transformed = game.is_face_down(target_id);
+        let Some(obj) = game
+            .object(target_id)
+            .filter(|obj| obj.linked_face_layout == LinkedFaceLayout::TransformLike)
+        else {
+            return Ok(EffectOutcome::resolved());
+        };
+        let other_def = transformed
+            .then(|| game.transformed_front_faces.get(&target_id).cloned())
+            .flatten()
+            .or_else(|| {
+                crate::cards::linked_face_definition_by_name_or_id(
+                    obj.other_face_name.as_deref(),
+                    obj.other_face,
+                )
+            });
+        let Some(other_def) = other_def else {
+            return Ok(EffectOutcome::resolved());
+        };
+
+        let current_face = obj.to_card_definition();
+        if let Some(obj) = game.object_mut(target_id) {
+            obj.apply_definition_face(&other_def);
+            if !transformed {
+                // The back face links back to the front so it can transform again.
+                obj.other_face = Some(current_face.card.id);
+                obj.other_face_name = Some(current_face.card.name.clone());
+                obj.linked_face_layout = LinkedFaceLayout::TransformLike;
+            }
+        }
+
         // Toggle the face state (for DFCs, face_down = back face)
-        if game.is_face_down(target_id) {
+        if transformed {
+            game.transformed_front_faces.remove(&target_id);
             game.set_face_up(target_id);
         } else {
+            game.transformed_front_faces.insert(target_id, current_face);
             game.set_face_down(target_id);
         }
 
+        if !game.object_has_card_type(target_id, CardType::Creature)
+            && let Some(combat) = game.combat.as_mut()
+            && remove_from_combat(combat, target_id)
+        {
+            game.ninjutsu_attack_targets.remove(&target_id);
+        }
+
         Ok(
             EffectOutcome::resolved().with_event(TriggerEvent::new_with_provenance(
                 TransformedEvent::new(target_id),
@@ -86,3 +134,139 @@ impl EffectExecutor for TransformEffect {
         "permanent to transform"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::combat_state::{AttackTarget, AttackerInfo, CombatState, is_attacking};
+    use crate::ids::{CardId, ObjectId, PlayerId};
+    use crate::object::CounterType;
+    use crate::triggers::TriggerMatcher;
+    use crate::triggers::matcher_trait::TriggerContext;
+    use crate::triggers::other::TransformsTrigger;
+    use crate::types::Subtype;
+    use crate::zone::Zone;
+
+    /// A Human Werewolf front face backed by a builtin card, so the back face
+    /// resolves through the registry like a real DFC's.
+    fn create_werewolf(game: &mut GameState, owner: PlayerId, back_face: &str) -> ObjectId {
+        let back = crate::cards::builtin_registry()
+            .get(back_face)
+            .expect("back face should exist in builtin registry");
+        let front = CardBuilder::new(CardId::from_raw(1), "Village Probe")
+            .card_types(vec![CardType::Creature])
+            .subtypes(vec![Subtype::Human, Subtype::Werewolf])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .other_face(back.card.id)
+            .other_face_name(back_face)
+            .linked_face_layout(LinkedFaceLayout::TransformLike)
+            .build();
+        game.create_object_from_card(&front, owner, Zone::Battlefield)
+    }
+
+    fn transform(game: &mut GameState, id: ObjectId, controller: PlayerId) -> EffectOutcome {
+        let mut ctx = ExecutionContext::new_default(id, controller);
+        TransformEffect::source()
+            .execute(game, &mut ctx)
+            .expect("transform should resolve")
+    }
+
+    #[test]
+    fn werewolf_transforms_back_and_forth_keeping_its_identity() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let werewolf = create_werewolf(&mut game, alice, "Serra Angel");
+        let aura = game.create_object_from_card(
+            &CardBuilder::new(CardId::from_raw(2), "Probe Aura")
+                .card_types(vec![CardType::Enchantment])
+                .subtypes(vec![Subtype::Aura])
+                .build(),
+            alice,
+            Zone::Battlefield,
+        );
+        game.object_mut(aura).expect("aura").attached_to = Some(werewolf);
+        let obj = game.object_mut(werewolf).expect("werewolf");
+        obj.attachments.push(aura);
+        obj.add_counters(CounterType::PlusOnePlusOne, 1);
+
+        let outcome = transform(&mut game, werewolf, alice);
+        let event = outcome
+            .events
+            .first()
+            .expect("transform should emit an event");
+        assert!(
+            TransformsTrigger.matches(event, &TriggerContext::for_source(werewolf, alice, &game))
+        );
+        assert!(game.is_face_down(werewolf));
+
+        let obj = game
+            .object(werewolf)
+            .expect("transformed werewolf keeps its id");
+        assert_eq!(obj.name, "Serra Angel");
+        assert!(obj.has_subtype(Subtype::Angel) && !obj.has_subtype(Subtype::Werewolf));
+        assert_eq!((obj.power(), obj.toughness()), (Some(5), Some(5)));
+        assert_eq!(obj.attachments, vec![aura]);
+        assert_eq!(obj.other_face_name.as_deref(), Some("Village Probe"));
+
+        transform(&mut game, werewolf, alice);
+        assert!(!game.is_face_down(werewolf));
+        let obj = game.object(werewolf).expect("werewolf");
+        assert_eq!(obj.name, "Village Probe");
+        assert!(obj.has_subtype(Subtype::Werewolf));
+        assert_eq!((obj.power(), obj.toughness()), (Some(3), Some(3)));
+        assert_eq!(obj.attachments, vec![aura]);
+    }
+
+    #[test]
+    fn transformed_permanent_leaves_the_battlefield_front_face_up() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let werewolf = create_werewolf(&mut game, alice, "Serra Angel");
+        transform(&mut game, werewolf, alice);
+
+        let card = game
+            .move_object(werewolf, Zone::Graveyard)
+            .expect("werewolf should move");
+        assert_eq!(game.object(card).expect("card").name, "Village Probe");
+        assert!(game.transformed_front_faces.is_empty());
+    }
+
+    #[test]
+    fn transform_does_nothing_to_a_card_without_a_back_face() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bears = game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            alice,
+            Zone::Battlefield,
+        );
+
+        let outcome = transform(&mut game, bears, alice);
+        assert!(outcome.events.is_empty());
+        assert!(!game.is_face_down(bears));
+        assert_eq!(game.object(bears).expect("bears").name, "Grizzly Bears");
+    }
+
+    #[test]
+    fn creature_transformed_into_noncreature_leaves_combat() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let werewolf = create_werewolf(&mut game, alice, "Squirrel Nest");
+        let mut combat = CombatState::default();
+        combat.attackers.push(AttackerInfo {
+            creature: werewolf,
+            target: AttackTarget::Player(bob),
+        });
+        combat.blockers.insert(werewolf, Vec::new());
+        game.combat = Some(combat);
+
+        transform(&mut game, werewolf, alice);
+        assert!(!game.object_has_card_type(werewolf, CardType::Creature));
+        assert!(!is_attacking(
+            game.combat.as_ref().expect("combat"),
+            werewolf
+        ));
+    }
+}
@@ -3,11 +3,9 @@
 use crate::effect::{ChoiceCount, EffectOutcome};
 use crate::effects::helpers::{ObjectApplyResultPolicy, apply_to_selected_objects};
 use crate::effects::{CostExecutableEffect, EffectExecutor};
-use crate::events::PermanentUntappedEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::target::{ChooseSpec, ObjectFilter};
-use crate::triggers::TriggerEvent;
 
 /// Effect that untaps permanents.
 ///
@@ -86,15 +84,15 @@ impl EffectExecutor for UntapEffect {
             &self.spec,
             result_policy,
             |game, _ctx, object_id| {
-                if game.object(object_id).is_some() && game.is_tapped(object_id) {
-                    game.untap(object_id);
-                    events.push(TriggerEvent::new_with_provenance(
-                        PermanentUntappedEvent::new(object_id),
-                        provenance,
-                    ));
-                    Ok(true)
-                } else {
-                    Ok(false)
+                if game.object(object_id).is_none() {
+                    return Ok(false);
+                }
+                match game.untap_permanent(object_id, provenance) {
+                    Some(event) => {
+                        events.push(event);
+                        Ok(!game.is_tapped(object_id))
+                    }
+                    None => Ok(false),
                 }
             },
         )?;
@@ -212,6 +210,38 @@ mod tests {
         assert!(!game.is_tapped(creature_id));
     }
 
+    #[test]
+    fn test_untap_stunned_creature_removes_stun_counter_instead() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let creature_id = create_creature(&mut game, "Bear", alice, true);
+        game.object_mut(creature_id)
+            .unwrap()
+            .add_counters(crate::object::CounterType::Stun, 2);
+
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice)
+            .with_targets(vec![ResolvedTarget::Object(creature_id)]);
+
+        let effect = UntapEffect::target(ChooseSpec::creature());
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert!(game.is_tapped(creature_id));
+        assert_eq!(
+            game.object(creature_id)
+                .unwrap()
+                .counters
+                .get(&crate::object::CounterType::Stun),
+            Some(&1)
+        );
+        assert_eq!(
+            result
+                .events_of_type::<crate::events::PermanentUntappedEvent>()
+                .count(),
+            0
+        );
+    }
+
     #[test]
     fn test_untap_already_untapped_creature() {
         let mut game = setup_game();
@@ -147,6 +147,7 @@ impl EffectExecutor for CascadeEffect {
                         crew_contributors: vec![],
                         saddle_contributors: vec![],
                         chosen_modes: None,
+                        divided_amounts: Vec::new(),
                         tagged_objects: std::collections::HashMap::new(),
                     };
 
@@ -107,6 +107,7 @@ impl EffectExecutor for CastSourceEffect {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             tagged_objects: std::collections::HashMap::new(),
         };
 
@@ -221,6 +221,7 @@ impl EffectExecutor for CastTaggedEffect {
             crew_contributors: vec![],
             saddle_contributors: vec![],
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             tagged_objects: std::collections::HashMap::new(),
         };
 
@@ -277,7 +278,7 @@ mod tests {
         };
         let cast_id = ids[0];
         assert!(game.stack.iter().any(|entry| entry.object_id == cast_id));
-        assert_eq!(game.spells_cast_this_turn.get(&alice), Some(&1));
+        assert_eq!(game.turn_trackers.spells_cast.get(&alice), Some(&1));
         assert!(game.spell_cast_order_this_turn.contains_key(&cast_id));
         assert!(
             outcome
@@ -76,7 +76,7 @@ impl ChooseCardNameEffect {
             base_power: None,
             base_toughness: None,
             loyalty: None,
-            abilities: Vec::new(),
+            abilities: Default::default(),
             x_value: None,
             counters: std::collections::HashMap::new(),
             is_token: false,
@@ -70,8 +70,11 @@ impl EffectExecutor for CreateEmblemEffect {
 mod tests {
     use super::*;
     use crate::ability::AbilityKind;
-    use crate::ids::PlayerId;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::ids::{CardId, ObjectId, PlayerId};
     use crate::static_abilities::StaticAbility;
+    use crate::target::ObjectFilter;
+    use crate::types::CardType;
 
     fn setup_game() -> GameState {
         crate::tests::test_helpers::setup_two_player_game()
@@ -163,6 +166,63 @@ mod tests {
         }
     }
 
+    fn create_bear(game: &mut GameState, owner: PlayerId) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Bear")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Battlefield)
+    }
+
+    fn create_anthem_emblem(game: &mut GameState, controller: PlayerId) -> ObjectId {
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, controller);
+        let emblem = EmblemDescription::new("Elspeth", "Creatures you control get +2/+2.")
+            .with_ability(Ability::static_ability(StaticAbility::anthem(
+                ObjectFilter::creature().you_control(),
+                2,
+                2,
+            )));
+        let result = CreateEmblemEffect::new(emblem)
+            .execute(game, &mut ctx)
+            .unwrap();
+        match result.value {
+            crate::effect::OutcomeValue::Objects(ids) => ids[0],
+            _ => panic!("Expected Objects result"),
+        }
+    }
+
+    #[test]
+    fn test_emblem_anthem_buffs_controllers_creatures() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let alice_bear = create_bear(&mut game, alice);
+        let bob_bear = create_bear(&mut game, bob);
+
+        create_anthem_emblem(&mut game, alice);
+
+        assert_eq!(game.calculated_power(alice_bear), Some(4));
+        assert_eq!(game.calculated_toughness(alice_bear), Some(4));
+        assert_eq!(game.calculated_power(bob_bear), Some(2));
+        assert_eq!(game.calculated_toughness(bob_bear), Some(2));
+
+        // The emblem keeps contributing to creatures that arrive later.
+        let later_bear = create_bear(&mut game, alice);
+        assert_eq!(game.calculated_power(later_bear), Some(4));
+    }
+
+    #[test]
+    fn test_emblem_cannot_leave_command_zone() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let emblem_id = create_anthem_emblem(&mut game, alice);
+
+        assert_eq!(game.move_object(emblem_id, Zone::Exile), None);
+        assert!(game.command_zone.contains(&emblem_id));
+        assert_eq!(game.object(emblem_id).unwrap().zone, Zone::Command);
+    }
+
     #[test]
     fn test_create_emblem_clone_box() {
         let emblem = EmblemDescription::new("Test", "Text");
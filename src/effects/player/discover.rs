@@ -143,6 +143,7 @@ impl EffectExecutor for DiscoverEffect {
                         crew_contributors: vec![],
                         saddle_contributors: vec![],
                         chosen_modes: None,
+                        divided_amounts: Vec::new(),
                         tagged_objects: std::collections::HashMap::new(),
                     };
                     game.push_to_stack(stack_entry);
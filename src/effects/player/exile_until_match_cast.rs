@@ -129,6 +129,7 @@ impl EffectExecutor for ExileUntilMatchCastEffect {
                         crew_contributors: vec![],
                         saddle_contributors: vec![],
                         chosen_modes: None,
+                        divided_amounts: Vec::new(),
                         tagged_objects: std::collections::HashMap::new(),
                     };
                     game.push_to_stack(stack_entry);
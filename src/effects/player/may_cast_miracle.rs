@@ -154,6 +154,7 @@ impl EffectExecutor for MayCastForMiracleCostEffect {
                 crew_contributors: vec![],
                 saddle_contributors: vec![],
                 chosen_modes: None,
+                divided_amounts: Vec::new(),
                 tagged_objects: std::collections::HashMap::new(),
             };
 
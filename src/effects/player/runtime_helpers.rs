@@ -16,10 +16,12 @@ pub(super) fn register_effect_driven_spell_cast(
     from_zone: Zone,
     provenance: crate::provenance::ProvNodeId,
 ) -> TriggerEvent {
-    *game.spells_cast_this_turn.entry(caster).or_insert(0) += 1;
+    game.turn_trackers.record_spell_cast(caster);
     if from_zone == Zone::Command {
         game.record_commander_cast_from_command_zone(new_id);
     }
+    game.record_spell_cast_this_game(new_id, caster, from_zone);
+    crate::decision::consume_limited_spell_cost_reductions(game, caster, new_id);
     game.spells_cast_this_turn_total = game.spells_cast_this_turn_total.saturating_add(1);
     game.spell_cast_order_this_turn
         .insert(new_id, game.spells_cast_this_turn_total);
@@ -10,6 +10,7 @@ use crate::effects::helpers::resolve_player_filter;
 use crate::events::spells::BecomesTargetedEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::{GameState, StackEntry, Target};
+use crate::ids::PlayerId;
 use crate::target::{ChooseSpec, PlayerFilter};
 use crate::targeting::{compute_legal_targets, normalize_targets_for_requirements};
 use crate::triggers::TriggerEvent;
@@ -85,7 +86,7 @@ fn effects_for_stack_entry(game: &GameState, entry: &StackEntry) -> Vec<crate::e
     };
 
     if let Some(ref effects) = obj.spell_effect {
-        return effects.clone();
+        return effects.to_vec();
     }
 
     Vec::new()
@@ -115,7 +116,7 @@ fn extract_requirements(
         }
 
         requirements.push(TargetRequirementContext {
-            description: effect.0.target_description().to_string(),
+            description: effect.0.target_description().into(),
             legal_targets,
             min_targets: count.min,
             max_targets: count.max,
@@ -125,6 +126,107 @@ fn extract_requirements(
     Some(requirements)
 }
 
+/// Result of offering new targets for a single stack entry.
+pub(super) enum RetargetResult {
+    /// The targets were left as they were.
+    Unchanged,
+    /// New targets were chosen.
+    Changed,
+    /// The entry has no legal new targets and retargeting was mandatory.
+    Invalid,
+}
+
+/// Let `chooser` choose new targets for the stack entry at `stack_idx`.
+///
+/// Emits a becomes-targeted event for each new object target.
+pub(super) fn choose_new_targets_for_entry(
+    game: &mut GameState,
+    ctx: &mut ExecutionContext,
+    stack_idx: usize,
+    chooser: PlayerId,
+    may: bool,
+    events: &mut Vec<TriggerEvent>,
+) -> RetargetResult {
+    let entry = game.stack[stack_idx].clone();
+    let object_id = entry.object_id;
+    let Some(requirements) = extract_requirements(game, &entry) else {
+        return if may {
+            RetargetResult::Unchanged
+        } else {
+            RetargetResult::Invalid
+        };
+    };
+
+    if requirements.is_empty() {
+        return RetargetResult::Unchanged;
+    }
+
+    if may {
+        let source_name = game
+            .object(object_id)
+            .map(|o| o.name.clone())
+            .unwrap_or_else(|| "copy".to_string());
+        let choose = ctx.decision_maker.decide_boolean(
+            game,
+            &BooleanContext::new(
+                chooser,
+                Some(object_id),
+                format!("Choose new targets for {source_name}?"),
+            ),
+        );
+        if !choose {
+            return RetargetResult::Unchanged;
+        }
+    }
+
+    let targets_ctx =
+        TargetsContext::new(chooser, object_id, "copy".to_string(), requirements.clone());
+    let proposed = ctx.decision_maker.decide_targets(game, &targets_ctx);
+    let Some(new_targets) = normalize_targets_for_requirements(&requirements, proposed) else {
+        return if may {
+            RetargetResult::Unchanged
+        } else {
+            RetargetResult::Invalid
+        };
+    };
+
+    if game.stack[stack_idx].targets == new_targets {
+        return RetargetResult::Unchanged;
+    }
+
+    // Announced damage division follows each target to its replacement.
+    let divided_amounts = entry
+        .divided_amounts
+        .iter()
+        .map(|(target, amount)| {
+            let retargeted = entry
+                .targets
+                .iter()
+                .position(|old| old == target)
+                .and_then(|idx| new_targets.get(idx))
+                .copied()
+                .unwrap_or(*target);
+            (retargeted, *amount)
+        })
+        .collect();
+    game.stack[stack_idx].targets = new_targets;
+    game.stack[stack_idx].divided_amounts = divided_amounts;
+    for target in &game.stack[stack_idx].targets {
+        if let Target::Object(target_id) = target {
+            events.push(TriggerEvent::new_with_provenance(
+                BecomesTargetedEvent::new(
+                    *target_id,
+                    object_id,
+                    entry.controller,
+                    entry.is_ability,
+                ),
+                ctx.provenance,
+            ));
+        }
+    }
+    RetargetResult::Changed
+}
+
 impl EffectExecutor for ChooseNewTargetsEffect {
     fn execute(
         &self,
@@ -154,69 +256,17 @@ impl EffectExecutor for ChooseNewTargetsEffect {
                 continue;
             }
 
-            let entry = game.stack[stack_idx].clone();
-            let Some(requirements) = extract_requirements(game, &entry) else {
-                if self.may {
-                    continue;
-                }
-                return Ok(EffectOutcome::target_invalid());
-            };
-
-            if requirements.is_empty() {
-                continue;
-            }
-
             let chooser = if let Some(filter) = &self.chooser {
                 resolve_player_filter(game, filter, ctx)?
             } else {
-                entry.controller
+                game.stack[stack_idx].controller
             };
 
-            if self.may {
-                let source_name = game
-                    .object(object_id)
-                    .map(|o| o.name.clone())
-                    .unwrap_or_else(|| "copy".to_string());
-                let choose = ctx.decision_maker.decide_boolean(
-                    game,
-                    &BooleanContext::new(
-                        chooser,
-                        Some(object_id),
-                        format!("Choose new targets for {source_name}?"),
-                    ),
-                );
-                if !choose {
-                    continue;
-                }
-            }
-
-            let targets_ctx =
-                TargetsContext::new(chooser, object_id, "copy".to_string(), requirements.clone());
-            let proposed = ctx.decision_maker.decide_targets(game, &targets_ctx);
-            let Some(new_targets) = normalize_targets_for_requirements(&requirements, proposed)
-            else {
-                if self.may {
-                    continue;
-                }
-                return Ok(EffectOutcome::target_invalid());
-            };
-
-            if game.stack[stack_idx].targets != new_targets {
-                game.stack[stack_idx].targets = new_targets;
-                changed += 1;
-                for target in &game.stack[stack_idx].targets {
-                    if let Target::Object(target_id) = target {
-                        events.push(TriggerEvent::new_with_provenance(
-                            BecomesTargetedEvent::new(
-                                *target_id,
-                                object_id,
-                                entry.controller,
-                                entry.is_ability,
-                            ),
-                            ctx.provenance,
-                        ));
-                    }
-                }
+            match choose_new_targets_for_entry(game, ctx, stack_idx, chooser, self.may, &mut events)
+            {
+                RetargetResult::Unchanged => {}
+                RetargetResult::Changed => changed += 1,
+                RetargetResult::Invalid => return Ok(EffectOutcome::target_invalid()),
             }
         }
 
@@ -0,0 +1,310 @@
+//! Copy ability effect implementation.
+
+use super::choose_new_targets::choose_new_targets_for_entry;
+use crate::effect::{EffectOutcome, Value};
+use crate::effects::EffectExecutor;
+use crate::effects::helpers::{resolve_objects_for_effect, resolve_player_filter, resolve_value};
+use crate::events::spells::AbilityActivatedEvent;
+use crate::executor::{ExecutionContext, ExecutionError};
+use crate::game_state::{GameState, StackEntry};
+use crate::target::{ChooseSpec, PlayerFilter};
+
+/// Which ability on the stack a [`CopyAbilityEffect`] copies.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbilityToCopy {
+    /// A targeted (or otherwise chosen) activated or triggered ability.
+    ///
+    /// Abilities on the stack are addressed by their source object.
+    Target(Box<ChooseSpec>),
+    /// The ability whose activation triggered this effect ("copy it").
+    TriggeringAbility,
+}
+
+/// Effect that copies an activated or triggered ability on the stack.
+///
+/// Per Rule 707.10, the copy has the same source, effects, targets and X
+/// value as the original, is controlled by the player who copied it, and is
+/// put on the stack above the original. A copied ability wasn't activated, so
+/// copying doesn't fire "whenever you activate" triggers.
+///
+/// # Fields
+///
+/// * `ability` - The ability to copy
+/// * `count` - How many copies to create
+/// * `copier` - The player who controls the copies
+/// * `may_choose_new_targets` - Whether the copier may choose new targets
+///
+/// # Example
+///
+/// ```ignore
+/// // "Whenever you activate an ability of an artifact, copy it.
+/// //  You may choose new targets for the copy."
+/// let effect = CopyAbilityEffect::triggering_ability().with_new_targets();
+///
+/// // "Copy target activated or triggered ability you control."
+/// let effect = CopyAbilityEffect::target(ChooseSpec::target(ChooseSpec::Object(
+///     ObjectFilter::ability(),
+/// )));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyAbilityEffect {
+    /// The ability to copy.
+    pub ability: AbilityToCopy,
+    /// The number of copies to create.
+    pub count: Value,
+    /// Which player controls the copies.
+    pub copier: PlayerFilter,
+    /// Whether the copier may choose new targets for each copy.
+    pub may_choose_new_targets: bool,
+}
+
+impl CopyAbilityEffect {
+    /// Create a new copy ability effect.
+    pub fn new(ability: AbilityToCopy, count: impl Into<Value>) -> Self {
+        Self {
+            ability,
+            count: count.into(),
+            copier: PlayerFilter::You,
+            may_choose_new_targets: false,
+        }
+    }
+
+    /// Copy a chosen ability on the stack once.
+    pub fn target(spec: ChooseSpec) -> Self {
+        Self::new(AbilityToCopy::Target(Box::new(spec)), 1)
+    }
+
+    /// Copy the ability whose activation triggered this effect once.
+    pub fn triggering_ability() -> Self {
+        Self::new(AbilityToCopy::TriggeringAbility, 1)
+    }
+
+    /// Let the copier choose new targets for the copies.
+    pub fn with_new_targets(mut self) -> Self {
+        self.may_choose_new_targets = true;
+        self
+    }
+
+    /// Set which player controls the copies.
+    pub fn with_copier(mut self, copier: PlayerFilter) -> Self {
+        self.copier = copier;
+        self
+    }
+
+    fn original_entry(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+    ) -> Result<Option<StackEntry>, ExecutionError> {
+        match &self.ability {
+            AbilityToCopy::Target(spec) => {
+                let Some(source) = resolve_objects_for_effect(game, ctx, spec)?
+                    .first()
+                    .copied()
+                else {
+                    return Ok(None);
+                };
+                Ok(game
+                    .stack
+                    .iter()
+                    .rev()
+                    .find(|entry| entry.is_ability && entry.object_id == source)
+                    .cloned())
+            }
+            // The activation captured the entry with its targets and X value
+            // locked in, which is exactly what the copy inherits.
+            AbilityToCopy::TriggeringAbility => Ok(ctx
+                .triggering_event
+                .as_ref()
+                .and_then(|event| event.downcast::<AbilityActivatedEvent>())
+                .and_then(|event| event.stack_entry.as_deref())
+                .cloned()),
+        }
+    }
+}
+
+impl EffectExecutor for CopyAbilityEffect {
+    fn execute(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+    ) -> Result<EffectOutcome, ExecutionError> {
+        let copy_count = resolve_value(game, &self.count, ctx)?.max(0) as usize;
+
+        let Some(original_entry) = self.original_entry(game, ctx)? else {
+            return Ok(EffectOutcome::target_invalid());
+        };
+
+        let copier = resolve_player_filter(game, &self.copier, ctx)?;
+        let mut copied = 0;
+        let mut events = Vec::new();
+
+        for _ in 0..copy_count {
+            let mut copy_entry = original_entry.clone();
+            copy_entry.controller = copier;
+            game.push_to_stack(copy_entry);
+            copied += 1;
+
+            if self.may_choose_new_targets {
+                // Optional retargeting keeps the copied targets when no new ones are chosen.
+                let stack_idx = game.stack.len() - 1;
+                choose_new_targets_for_entry(game, ctx, stack_idx, copier, true, &mut events);
+            }
+        }
+
+        Ok(EffectOutcome::count(copied).with_events(events))
+    }
+
+    fn get_target_spec(&self) -> Option<&ChooseSpec> {
+        match &self.ability {
+            AbilityToCopy::Target(spec) => Some(spec.as_ref()),
+            AbilityToCopy::TriggeringAbility => None,
+        }
+    }
+
+    fn target_description(&self) -> &'static str {
+        "ability to copy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::definitions::grizzly_bears;
+    use crate::decision::DecisionMaker;
+    use crate::decisions::context::{BooleanContext, TargetsContext};
+    use crate::effect::Effect;
+    use crate::game_state::Target;
+    use crate::ids::{CardId, ObjectId, PlayerId};
+    use crate::object::CounterType;
+    use crate::provenance::ProvNodeId;
+    use crate::triggers::TriggerEvent;
+    use crate::types::CardType;
+    use crate::zone::Zone;
+
+    struct RetargetDecisionMaker {
+        target: Target,
+    }
+
+    impl DecisionMaker for RetargetDecisionMaker {
+        fn decide_boolean(&mut self, _game: &GameState, _ctx: &BooleanContext) -> bool {
+            true
+        }
+
+        fn decide_targets(&mut self, _game: &GameState, _ctx: &TargetsContext) -> Vec<Target> {
+            vec![self.target]
+        }
+    }
+
+    fn setup_game() -> GameState {
+        crate::tests::test_helpers::setup_two_player_game()
+    }
+
+    fn create_ballista(game: &mut GameState, owner: PlayerId) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Walking Ballista")
+            .card_types(vec![CardType::Artifact, CardType::Creature])
+            .power_toughness(PowerToughness::fixed(0, 0))
+            .build();
+        let id = game.create_object_from_card(&card, owner, Zone::Battlefield);
+        game.object_mut(id)
+            .unwrap()
+            .counters
+            .insert(CounterType::PlusOnePlusOne, 2);
+        id
+    }
+
+    /// Put a "deal 1 damage to any target" ability of `source` on the stack.
+    fn ping_ability(source: ObjectId, controller: PlayerId, target: ObjectId) -> StackEntry {
+        StackEntry::ability(
+            source,
+            controller,
+            vec![Effect::deal_damage(1, ChooseSpec::AnyTarget)],
+        )
+        .with_targets(vec![Target::Object(target)])
+    }
+
+    fn activation_event(entry: &StackEntry) -> TriggerEvent {
+        TriggerEvent::new_with_provenance(
+            AbilityActivatedEvent::new(entry.object_id, entry.controller, false)
+                .with_stack_entry(Some(entry.clone())),
+            ProvNodeId::default(),
+        )
+    }
+
+    #[test]
+    fn copy_triggering_ability_retargets_copy_at_different_creature() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let ballista = create_ballista(&mut game, alice);
+        let first = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+        let second = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        let entry = ping_ability(ballista, alice, first);
+        game.push_to_stack(entry.clone());
+
+        let mut dm = RetargetDecisionMaker {
+            target: Target::Object(second),
+        };
+        let mut ctx = ExecutionContext::new(ballista, alice, &mut dm)
+            .with_triggering_event(activation_event(&entry));
+        let effect = CopyAbilityEffect::triggering_ability().with_new_targets();
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(game.stack.len(), 2);
+        assert_eq!(game.stack[0].targets, vec![Target::Object(first)]);
+        let copy = &game.stack[1];
+        assert!(copy.is_ability);
+        assert_eq!(copy.object_id, ballista);
+        assert_eq!(copy.targets, vec![Target::Object(second)]);
+
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        assert_eq!(game.damage_on(first), 1);
+        assert_eq!(game.damage_on(second), 1);
+    }
+
+    #[test]
+    fn copy_target_ability_preserves_targets_and_x() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let ballista = create_ballista(&mut game, alice);
+        let bear = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        game.push_to_stack(ping_ability(ballista, alice, bear).with_x(3));
+
+        let mut ctx = ExecutionContext::new_default(ballista, bob);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(ballista)];
+        let effect = CopyAbilityEffect::target(ChooseSpec::target(ChooseSpec::Object(
+            crate::target::ObjectFilter::ability(),
+        )));
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(game.stack.len(), 2);
+        let copy = &game.stack[1];
+        assert_eq!(copy.controller, bob);
+        assert_eq!(copy.targets, vec![Target::Object(bear)]);
+        assert_eq!(copy.x_value, Some(3));
+    }
+
+    #[test]
+    fn copy_triggering_mana_ability_does_nothing() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let ballista = create_ballista(&mut game, alice);
+
+        let event = TriggerEvent::new_with_provenance(
+            AbilityActivatedEvent::new(ballista, alice, true),
+            ProvNodeId::default(),
+        );
+        let mut ctx = ExecutionContext::new_default(ballista, alice).with_triggering_event(event);
+        let outcome = CopyAbilityEffect::triggering_ability()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        assert!(game.stack.is_empty());
+        assert_eq!(outcome, EffectOutcome::target_invalid());
+    }
+}
@@ -1,5 +1,6 @@
 //! Copy spell effect implementation.
 
+use super::choose_new_targets::choose_new_targets_for_entry;
 use crate::effect::{EffectOutcome, Value};
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::{resolve_objects_for_effect, resolve_player_filter, resolve_value};
@@ -14,7 +15,8 @@ use crate::zone::Zone;
 /// Effect that copies a spell on the stack.
 ///
 /// Per Rule 707.10, when a spell is copied:
-/// - The copy has the same characteristics and choices (modes, targets, X value)
+/// - The copy has the same characteristics and choices (modes, targets, X value,
+///   damage division, optional costs paid)
 /// - The copy is controlled by the player who copied it
 /// - The copy is put on the stack above the original
 ///
@@ -22,12 +24,17 @@ use crate::zone::Zone;
 ///
 /// * `target` - The target specification for the spell to copy
 /// * `count` - How many copies to create
+/// * `copier` - The player who controls the copies
+/// * `may_choose_new_targets` - Whether the copier may choose new targets
 ///
 /// # Example
 ///
 /// ```ignore
 /// // Copy target instant or sorcery spell
 /// let effect = CopySpellEffect::new(ChooseSpec::spell(), 1);
+///
+/// // "Copy target instant or sorcery spell. You may choose new targets for the copy."
+/// let effect = CopySpellEffect::single(ChooseSpec::spell()).with_new_targets();
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct CopySpellEffect {
@@ -37,6 +44,8 @@ pub struct CopySpellEffect {
     pub count: Value,
     /// Which player controls the copies.
     pub copier: PlayerFilter,
+    /// Whether the copier may choose new targets for each copy.
+    pub may_choose_new_targets: bool,
 }
 
 impl CopySpellEffect {
@@ -46,6 +55,7 @@ impl CopySpellEffect {
             target,
             count: count.into(),
             copier: PlayerFilter::You,
+            may_choose_new_targets: false,
         }
     }
 
@@ -59,6 +69,7 @@ impl CopySpellEffect {
             target,
             count: count.into(),
             copier,
+            may_choose_new_targets: false,
         }
     }
 
@@ -66,6 +77,12 @@ impl CopySpellEffect {
     pub fn single(target: ChooseSpec) -> Self {
         Self::new(target, 1)
     }
+
+    /// Let the copier choose new targets for the copies.
+    pub fn with_new_targets(mut self) -> Self {
+        self.may_choose_new_targets = true;
+        self
+    }
 }
 
 impl EffectExecutor for CopySpellEffect {
@@ -103,6 +120,7 @@ impl EffectExecutor for CopySpellEffect {
 
         let copier = resolve_player_filter(game, &self.copier, ctx)?;
         let mut created_ids = Vec::with_capacity(copy_count);
+        let mut events = Vec::new();
 
         for _ in 0..copy_count {
             // Create a new object ID for the copy
@@ -123,16 +141,27 @@ impl EffectExecutor for CopySpellEffect {
             // The copy has the same targets, X value, etc. but is controlled by the copier
             let mut copy_entry = StackEntry::new(copy_id, copier);
             copy_entry.targets = original_entry.targets.clone();
+            copy_entry.target_assignments = original_entry.target_assignments.clone();
+            copy_entry.divided_amounts = original_entry.divided_amounts.clone();
             copy_entry.x_value = original_entry.x_value;
             copy_entry.ability_effects = original_entry.ability_effects.clone();
             copy_entry.is_ability = original_entry.is_ability;
             copy_entry.optional_costs_paid = original_entry.optional_costs_paid.clone();
             copy_entry.chosen_modes = original_entry.chosen_modes.clone();
+            // Rule 707.10: references to objects used to pay the original's costs
+            // still see those objects.
+            copy_entry.tagged_objects = original_entry.tagged_objects.clone();
 
             // Put the copy on top of the stack
             game.stack.push(copy_entry);
             created_ids.push(copy_id);
 
+            if self.may_choose_new_targets {
+                // Optional retargeting keeps the copied targets when no new ones are chosen.
+                let stack_idx = game.stack.len() - 1;
+                choose_new_targets_for_entry(game, ctx, stack_idx, copier, true, &mut events);
+            }
+
             // Copying a spell can trigger magecraft-like abilities.
             game.queue_trigger_event(
                 ctx.provenance,
@@ -143,7 +172,7 @@ impl EffectExecutor for CopySpellEffect {
             );
         }
 
-        Ok(EffectOutcome::with_objects(created_ids))
+        Ok(EffectOutcome::with_objects(created_ids).with_events(events))
     }
 
     fn get_target_spec(&self) -> Option<&ChooseSpec> {
@@ -427,4 +456,171 @@ mod tests {
         let cloned = effect.clone_box();
         assert!(format!("{:?}", cloned).contains("CopySpellEffect"));
     }
+
+    struct RetargetDecisionMaker {
+        target: crate::game_state::Target,
+    }
+
+    impl crate::decision::DecisionMaker for RetargetDecisionMaker {
+        fn decide_boolean(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::BooleanContext,
+        ) -> bool {
+            true
+        }
+
+        fn decide_targets(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::TargetsContext,
+        ) -> Vec<crate::game_state::Target> {
+            vec![self.target]
+        }
+    }
+
+    fn cast_definition_at(
+        game: &mut GameState,
+        def: &crate::cards::CardDefinition,
+        controller: PlayerId,
+        target: crate::game_state::Target,
+    ) -> crate::ids::ObjectId {
+        let id = game.create_object_from_definition(def, controller, Zone::Stack);
+        game.stack
+            .push(StackEntry::new(id, controller).with_targets(vec![target]));
+        id
+    }
+
+    #[test]
+    fn copied_fireball_keeps_x_and_deals_that_much_damage() {
+        use crate::cards::definitions::fireball;
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = game.new_object_id();
+
+        let spell_id = cast_definition_at(
+            &mut game,
+            &fireball(),
+            alice,
+            crate::game_state::Target::Player(bob),
+        );
+        game.stack.last_mut().unwrap().x_value = Some(3);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(spell_id)];
+        CopySpellEffect::single(ChooseSpec::spell())
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        assert_eq!(game.player(bob).unwrap().life, 17);
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        assert_eq!(game.player(bob).unwrap().life, 14);
+    }
+
+    #[test]
+    fn copied_bolt_can_be_retargeted_at_a_creature() {
+        use crate::cards::definitions::{grizzly_bears, lightning_bolt};
+        use crate::game_state::Target;
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = game.new_object_id();
+        let bears = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        let spell_id = cast_definition_at(&mut game, &lightning_bolt(), alice, Target::Player(bob));
+
+        let mut dm = RetargetDecisionMaker {
+            target: Target::Object(bears),
+        };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(spell_id)];
+        let outcome = CopySpellEffect::single(ChooseSpec::spell())
+            .with_new_targets()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        assert_eq!(game.stack[0].targets, vec![Target::Player(bob)]);
+        assert_eq!(game.stack[1].targets, vec![Target::Object(bears)]);
+        assert_eq!(
+            outcome.events.len(),
+            1,
+            "retargeting the copy should make the bears become targeted"
+        );
+
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        assert_eq!(game.damage_on(bears), 3);
+        assert_eq!(game.player(bob).unwrap().life, 20);
+    }
+
+    #[test]
+    fn retargeted_copy_moves_divided_damage_to_new_target() {
+        use crate::game_state::Target;
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = game.new_object_id();
+
+        let spell_id = cast_definition_at(
+            &mut game,
+            &crate::cards::definitions::lightning_bolt(),
+            alice,
+            Target::Player(bob),
+        );
+        game.stack.last_mut().unwrap().divided_amounts = vec![(Target::Player(bob), 3)];
+
+        let mut dm = RetargetDecisionMaker {
+            target: Target::Player(alice),
+        };
+        let mut ctx = ExecutionContext::new(source, alice, &mut dm);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(spell_id)];
+        CopySpellEffect::single(ChooseSpec::spell())
+            .with_new_targets()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        assert_eq!(
+            game.stack[0].divided_amounts,
+            vec![(Target::Player(bob), 3)]
+        );
+        assert_eq!(
+            game.stack[1].divided_amounts,
+            vec![(Target::Player(alice), 3)]
+        );
+    }
+
+    #[test]
+    fn copying_a_spell_fires_magecraft() {
+        use crate::cards::definitions::CardDefinitionBuilder;
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+
+        let magecraft = CardDefinitionBuilder::new(CardId::new(), "Magecraft Adept")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(1, 1))
+            .parse_text(
+                "Magecraft — Whenever you cast or copy an instant or sorcery spell, you gain 1 life.",
+            )
+            .unwrap();
+        let adept = game.create_object_from_definition(&magecraft, alice, Zone::Battlefield);
+
+        let spell_id = create_instant_on_stack(&mut game, "Shock", alice);
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(spell_id)];
+        CopySpellEffect::single(ChooseSpec::spell())
+            .execute(&mut game, &mut ctx)
+            .unwrap();
+
+        let events = game.take_pending_trigger_events();
+        assert_eq!(events.len(), 1);
+        let triggered = crate::triggers::check_triggers(&game, &events[0]);
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].source, adept);
+    }
 }
@@ -4,6 +4,7 @@ use crate::ability::AbilityKind;
 use crate::effect::EffectOutcome;
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::resolve_single_object_for_effect;
+use crate::effects::zones::apply_zone_change;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::target::ChooseSpec;
@@ -62,7 +63,7 @@ impl EffectExecutor for CounterEffect {
                     false
                 }
             });
-            if cant_be_countered {
+            if cant_be_countered || !game.can_be_countered_by(target_id, ctx.controller) {
                 // Spell can't be countered - effect does nothing
                 return Ok(EffectOutcome::protected());
             }
@@ -71,9 +72,16 @@ impl EffectExecutor for CounterEffect {
         // Find the stack entry for this object
         if let Some(idx) = game.stack.iter().position(|e| e.object_id == target_id) {
             let entry = game.stack.remove(idx);
-            // Move countered spell to graveyard (abilities just disappear)
+            // Move countered spell to graveyard via replacement effects
+            // (abilities just disappear)
             if !entry.is_ability {
-                game.move_object(entry.object_id, Zone::Graveyard);
+                let _ = apply_zone_change(
+                    game,
+                    entry.object_id,
+                    Zone::Stack,
+                    Zone::Graveyard,
+                    &mut *ctx.decision_maker,
+                );
             }
             Ok(EffectOutcome::resolved())
         } else {
@@ -90,3 +98,85 @@ impl EffectExecutor for CounterEffect {
         "spell to counter"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::CardDefinitionBuilder;
+    use crate::game_state::StackEntry;
+    use crate::ids::{CardId, PlayerId};
+    use crate::mana::{ManaCost, ManaSymbol};
+    use crate::types::CardType;
+
+    fn setup_game() -> GameState {
+        crate::tests::test_helpers::setup_two_player_game()
+    }
+
+    fn creature_spell_on_stack(game: &mut GameState, controller: PlayerId) -> crate::ids::ObjectId {
+        let card = CardBuilder::new(CardId::from_raw(1), "Bear")
+            .mana_cost(ManaCost::from_pips(vec![
+                vec![ManaSymbol::Generic(1)],
+                vec![ManaSymbol::Green],
+            ]))
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        let id = game.create_object_from_card(&card, controller, Zone::Stack);
+        game.stack.push(StackEntry::new(id, controller));
+        id
+    }
+
+    fn counter(game: &mut GameState, spell: crate::ids::ObjectId, controller: PlayerId) {
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, controller);
+        ctx.targets = vec![crate::executor::ResolvedTarget::Object(spell)];
+        CounterEffect::any_spell()
+            .execute(game, &mut ctx)
+            .expect("counter effect should execute");
+    }
+
+    #[test]
+    fn test_cant_be_countered_by_opponents_only_stops_opponents() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let shield = CardDefinitionBuilder::new(CardId::from_raw(2), "Spell Shield")
+            .card_types(vec![CardType::Enchantment])
+            .parse_text(
+                "Creature spells you control can't be countered by spells or abilities your opponents control.",
+            )
+            .expect("scoped counter restriction should parse");
+        game.create_object_from_definition(&shield, alice, Zone::Battlefield);
+
+        let spell = creature_spell_on_stack(&mut game, alice);
+        game.refresh_continuous_state();
+
+        counter(&mut game, spell, bob);
+        assert_eq!(game.stack.len(), 1, "an opponent can't counter the spell");
+
+        counter(&mut game, spell, alice);
+        assert!(game.stack.is_empty(), "the spell's controller still can");
+    }
+
+    #[test]
+    fn test_blanket_cant_be_countered_restriction_is_respected() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let shield = CardDefinitionBuilder::new(CardId::from_raw(3), "Blanket Shield")
+            .card_types(vec![CardType::Enchantment])
+            .parse_text("Creature spells you control can't be countered.")
+            .expect("counter restriction should parse");
+        game.create_object_from_definition(&shield, alice, Zone::Battlefield);
+
+        let spell = creature_spell_on_stack(&mut game, alice);
+        game.refresh_continuous_state();
+
+        counter(&mut game, spell, bob);
+        counter(&mut game, spell, alice);
+        assert_eq!(game.stack.len(), 1, "nobody can counter the spell");
+    }
+}
@@ -1,14 +1,16 @@
 //! Stack interaction effects.
 //!
 //! This module contains effects that interact with the stack,
-//! such as countering spells and copying spells.
+//! such as countering spells and copying spells and abilities.
 
 mod choose_new_targets;
+mod copy_ability;
 mod copy_spell;
 mod counter;
 mod retarget_stack_object;
 
 pub use choose_new_targets::ChooseNewTargetsEffect;
+pub use copy_ability::{AbilityToCopy, CopyAbilityEffect};
 pub use copy_spell::CopySpellEffect;
 pub use counter::CounterEffect;
 pub use retarget_stack_object::{NewTargetRestriction, RetargetMode, RetargetStackObjectEffect};
@@ -99,7 +99,7 @@ fn effects_for_stack_entry(game: &GameState, entry: &StackEntry) -> Vec<crate::e
     };
 
     if let Some(ref effects) = obj.spell_effect {
-        return effects.clone();
+        return effects.to_vec();
     }
 
     Vec::new()
@@ -129,7 +129,7 @@ fn extract_requirements(
         }
 
         requirements.push(TargetRequirementContext {
-            description: effect.0.target_description().to_string(),
+            description: effect.0.target_description().into(),
             legal_targets,
             min_targets: count.min,
             max_targets: count.max,
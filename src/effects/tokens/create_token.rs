@@ -4,6 +4,7 @@ use crate::cards::CardDefinition;
 use crate::effect::{EffectOutcome, Value};
 use crate::effects::EffectExecutor;
 use crate::effects::helpers::resolve_value;
+use crate::event_processor::process_create_tokens;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::object::Object;
@@ -120,6 +121,14 @@ impl CreateTokenEffect {
         Self::you(token, 1)
     }
 
+    /// Create a single predefined token (Treasure, Food, Clue, ...) under your
+    /// control, looked up by name in the [`TokenRegistry`].
+    ///
+    /// [`TokenRegistry`]: crate::cards::tokens::TokenRegistry
+    pub fn named(name: &str) -> Option<Self> {
+        crate::cards::tokens::TokenRegistry::get(name).map(Self::one)
+    }
+
     /// Set whether the tokens enter tapped.
     pub fn tapped(mut self) -> Self {
         self.enters_tapped = true;
@@ -165,7 +174,10 @@ impl EffectExecutor for CreateTokenEffect {
     ) -> Result<EffectOutcome, ExecutionError> {
         let controller_id =
             crate::effects::helpers::resolve_player_filter(game, &self.controller, ctx)?;
-        let count = resolve_value(game, &self.count, ctx)?.max(0) as usize;
+        let count = resolve_value(game, &self.count, ctx)?.max(0) as u32;
+        // Token doublers (Doubling Season, Parallel Lives) replace how many are created.
+        let count =
+            process_create_tokens(game, controller_id, count, &mut *ctx.decision_maker) as usize;
         let cleanup_options = TokenCleanupOptions::new(
             self.exile_at_end_of_combat,
             self.sacrifice_at_end_of_combat,
@@ -417,4 +429,39 @@ mod tests {
             "token creature should get Tayam's additional vigilance counter on entry"
         );
     }
+
+    #[test]
+    fn named_treasure_token_can_be_sacrificed_for_mana() {
+        use crate::special_actions::{SpecialAction, perform};
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        let outcome = CreateTokenEffect::named("Treasure")
+            .expect("Treasure is a registered token")
+            .execute(&mut game, &mut ctx)
+            .expect("create treasure");
+        let [treasure_id] = outcome.output_objects() else {
+            panic!("expected one Treasure token");
+        };
+        let treasure_id = *treasure_id;
+        game.turn.priority_player = Some(alice);
+
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        perform(
+            SpecialAction::ActivateManaAbility {
+                permanent_id: treasure_id,
+                ability_index: 0,
+            },
+            &mut game,
+            alice,
+            &mut dm,
+        )
+        .expect("treasure mana ability should activate");
+
+        assert!(!game.battlefield.contains(&treasure_id));
+        assert_eq!(game.player(alice).unwrap().mana_pool.total(), 1);
+    }
 }
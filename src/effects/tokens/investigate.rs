@@ -1,6 +1,5 @@
 //! Investigate effect implementation.
 
-use crate::cards::tokens::clue_token_definition;
 use crate::effect::{EffectOutcome, Value};
 use crate::effects::helpers::resolve_value;
 use crate::effects::{CreateTokenEffect, EffectExecutor};
@@ -41,7 +40,7 @@ impl EffectExecutor for InvestigateEffect {
         let mut outcomes = Vec::with_capacity(count);
         let mut action_events = Vec::with_capacity(count);
         for _ in 0..count {
-            let effect = CreateTokenEffect::you(clue_token_definition(), 1);
+            let effect = CreateTokenEffect::named("Clue").expect("Clue is a registered token");
             outcomes.push(effect.execute(game, ctx)?);
             action_events.push(TriggerEvent::new_with_provenance(
                 KeywordActionEvent::new(
@@ -96,4 +95,27 @@ mod tests {
             .count();
         assert_eq!(investigate_events, 2);
     }
+
+    #[test]
+    fn investigate_creates_registry_clue_with_draw_ability() {
+        let mut game = setup_game();
+        let alice = crate::ids::PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+
+        InvestigateEffect::new(1)
+            .execute(&mut game, &mut ctx)
+            .expect("investigate resolves");
+
+        let clue = game
+            .object(game.battlefield[0])
+            .expect("clue should be on the battlefield");
+        assert_eq!(clue.name, "Clue");
+        assert!(clue.subtypes.contains(&crate::types::Subtype::Clue));
+        assert!(clue.abilities.iter().any(|ability| matches!(
+            &ability.kind,
+            crate::ability::AbilityKind::Activated(activated)
+                if activated.has_sacrifice_self_cost() && !activated.is_mana_ability()
+        )));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::events::EnterBattlefieldEvent;
 use crate::executor::ExecutionContext;
-use crate::game_state::GameState;
+use crate::game_state::{EntersResult, GameState};
 use crate::ids::{ObjectId, PlayerId};
 use crate::provenance::ProvNodeId;
 use crate::triggers::TriggerEvent;
@@ -51,6 +51,18 @@ pub(crate) enum BattlefieldEntryOutcome {
     Prevented,
 }
 
+fn entering_controller(
+    game: &GameState,
+    object_id: ObjectId,
+    options: BattlefieldEntryOptions,
+) -> Option<PlayerId> {
+    match options.controller {
+        BattlefieldEntryController::Preserve => None,
+        BattlefieldEntryController::Owner => game.object(object_id).map(|obj| obj.owner),
+        BattlefieldEntryController::Specific(controller) => Some(controller),
+    }
+}
+
 /// Move an object to the battlefield with ETB replacement processing and policy hooks.
 pub(crate) fn move_to_battlefield_with_options(
     game: &mut GameState,
@@ -59,11 +71,53 @@ pub(crate) fn move_to_battlefield_with_options(
     options: BattlefieldEntryOptions,
 ) -> BattlefieldEntryOutcome {
     let old_zone = game.object(object_id).map(|obj| obj.zone);
-    let Some(result) = game.move_object_with_etb_processing_with_dm(
+    let result = game.move_object_with_etb_processing_under_control_with_dm(
         object_id,
         Zone::Battlefield,
+        entering_controller(game, object_id, options),
         &mut ctx.decision_maker,
-    ) else {
+    );
+    finish_battlefield_entry(game, ctx, old_zone, result, options)
+}
+
+/// Move several objects to the battlefield simultaneously.
+///
+/// Replacement effects are determined for all of them before any enters, and
+/// their ETB events are only queued once every object has entered, so each
+/// one's triggers see the others on the battlefield. Returns one outcome per
+/// object, in order.
+pub(crate) fn move_all_to_battlefield_with_options(
+    game: &mut GameState,
+    ctx: &mut ExecutionContext,
+    object_ids: &[ObjectId],
+    options: BattlefieldEntryOptions,
+) -> Vec<BattlefieldEntryOutcome> {
+    let entries: Vec<_> = object_ids
+        .iter()
+        .map(|&id| (id, entering_controller(game, id, options)))
+        .collect();
+    let old_zones: Vec<_> = object_ids
+        .iter()
+        .map(|&id| game.object(id).map(|obj| obj.zone))
+        .collect();
+    let results =
+        game.move_objects_to_battlefield_simultaneously_with_dm(&entries, &mut ctx.decision_maker);
+    old_zones
+        .into_iter()
+        .zip(results)
+        .map(|(old_zone, result)| finish_battlefield_entry(game, ctx, old_zone, result, options))
+        .collect()
+}
+
+/// Apply the entry policy to a moved object and queue its ETB event.
+fn finish_battlefield_entry(
+    game: &mut GameState,
+    ctx: &ExecutionContext,
+    old_zone: Option<Zone>,
+    result: Option<EntersResult>,
+    options: BattlefieldEntryOptions,
+) -> BattlefieldEntryOutcome {
+    let Some(result) = result else {
         return BattlefieldEntryOutcome::Prevented;
     };
 
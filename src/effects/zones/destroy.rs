@@ -1,14 +1,16 @@
 //! Destroy effect implementation.
 
 use crate::effect::{ChoiceCount, EffectOutcome, OutcomeStatus};
-use crate::effects::EffectExecutor;
 use crate::effects::helpers::{
     ObjectApplyResultPolicy, apply_single_target_object_from_spec, apply_to_selected_objects,
+    resolve_objects_from_spec,
 };
+use crate::effects::{EffectExecutor, ExecutabilityReport};
 use crate::event_processor::{EventOutcome, process_destroy};
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
 use crate::target::{ChooseSpec, ObjectFilter};
+use crate::zone::Zone;
 
 /// Effect that destroys permanents.
 ///
@@ -90,6 +92,25 @@ impl DestroyEffect {
 }
 
 impl EffectExecutor for DestroyEffect {
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        if !self.spec.is_target() {
+            return ExecutabilityReport::Unknown;
+        }
+        let Ok(objects) = resolve_objects_from_spec(game, &self.spec, ctx) else {
+            return ExecutabilityReport::impossible("no legal target");
+        };
+        ExecutabilityReport::executable_if(
+            objects.into_iter().any(|id| {
+                game.object(id).is_some_and(|obj| {
+                    obj.zone == Zone::Battlefield
+                        && !obj.has_indestructible()
+                        && game.can_be_destroyed(id)
+                })
+            }),
+            "no target can be destroyed",
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
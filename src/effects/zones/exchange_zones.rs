@@ -0,0 +1,175 @@
+//! Exchange zones effect implementation.
+
+use crate::effect::EffectOutcome;
+use crate::effects::EffectExecutor;
+use crate::effects::helpers::resolve_player_filter;
+use crate::executor::{ExecutionContext, ExecutionError};
+use crate::game_state::GameState;
+use crate::target::PlayerFilter;
+use crate::zone::Zone;
+
+/// Effect that exchanges the contents of a zone between two players.
+///
+/// Used by cards like "Exchange your hand with target player's hand."
+/// Every card in each player's zone moves to the other player's zone at the
+/// same time. Ownership is unchanged, so a card put into an opponent's hand
+/// still goes to its owner's graveyard when discarded.
+///
+/// Only hands are supported.
+///
+/// # Fields
+///
+/// * `zone` - The zone whose contents are exchanged
+/// * `player1` - First player in the exchange (usually the controller)
+/// * `player2` - Second player in the exchange (usually target player)
+///
+/// # Example
+///
+/// ```ignore
+/// // Exchange your hand with target player's hand
+/// let effect = ExchangeZonesEffect::hands_with_target();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeZonesEffect {
+    /// The zone whose contents are exchanged.
+    pub zone: Zone,
+    /// First player in the exchange.
+    pub player1: PlayerFilter,
+    /// Second player in the exchange.
+    pub player2: PlayerFilter,
+}
+
+impl ExchangeZonesEffect {
+    /// Create a new exchange zones effect.
+    pub fn new(zone: Zone, player1: PlayerFilter, player2: PlayerFilter) -> Self {
+        Self {
+            zone,
+            player1,
+            player2,
+        }
+    }
+
+    /// Create an effect that exchanges your hand with target player's hand.
+    pub fn hands_with_target() -> Self {
+        Self::new(
+            Zone::Hand,
+            PlayerFilter::You,
+            PlayerFilter::Target(Box::new(PlayerFilter::Any)),
+        )
+    }
+}
+
+impl EffectExecutor for ExchangeZonesEffect {
+    fn execute(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+    ) -> Result<EffectOutcome, ExecutionError> {
+        if self.zone != Zone::Hand {
+            return Err(ExecutionError::Impossible(format!(
+                "exchanging {} contents is not supported",
+                self.zone
+            )));
+        }
+
+        let player1_id = resolve_player_filter(game, &self.player1, ctx)?;
+        let player2_id = resolve_player_filter(game, &self.player2, ctx)?;
+        game.exchange_hands(player1_id, player2_id);
+        Ok(EffectOutcome::resolved())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardBuilder;
+    use crate::effects::DiscardEffect;
+    use crate::executor::ResolvedTarget;
+    use crate::ids::{CardId, ObjectId, PlayerId};
+    use crate::types::CardType;
+
+    fn setup_game() -> GameState {
+        crate::tests::test_helpers::setup_two_player_game()
+    }
+
+    fn create_hand_card(game: &mut GameState, owner: PlayerId, name: &str) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), name)
+            .card_types(vec![CardType::Sorcery])
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Hand)
+    }
+
+    fn exchange_hands(game: &mut GameState, you: PlayerId, other: PlayerId) {
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, you);
+        ctx.targets = vec![ResolvedTarget::Player(other)];
+        ExchangeZonesEffect::hands_with_target()
+            .execute(game, &mut ctx)
+            .unwrap();
+    }
+
+    #[test]
+    fn exchange_hands_swaps_contents_and_keeps_ownership() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let alice_card = create_hand_card(&mut game, alice, "Alice's Card");
+        let bob_first = create_hand_card(&mut game, bob, "Bob's First");
+        let bob_second = create_hand_card(&mut game, bob, "Bob's Second");
+
+        exchange_hands(&mut game, alice, bob);
+
+        assert_eq!(
+            game.player(alice).unwrap().hand,
+            vec![bob_first, bob_second]
+        );
+        assert_eq!(game.player(bob).unwrap().hand, vec![alice_card]);
+        assert_eq!(game.hand_holder(bob_first), Some(alice));
+        assert_eq!(game.object(bob_first).unwrap().owner, bob);
+        assert_eq!(game.object(alice_card).unwrap().owner, alice);
+    }
+
+    #[test]
+    fn discard_after_exchange_hits_received_cards_and_uses_owners_graveyard() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        create_hand_card(&mut game, alice, "Alice's Card");
+        let bob_card = create_hand_card(&mut game, bob, "Bob's Card");
+
+        exchange_hands(&mut game, alice, bob);
+
+        // Alice now holds Bob's card, so making Alice discard hits it.
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let outcome = DiscardEffect::you(1).execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(outcome.value, crate::effect::OutcomeValue::Count(1));
+        assert!(game.player(alice).unwrap().hand.is_empty());
+        assert_eq!(game.player(bob).unwrap().hand.len(), 1);
+        assert!(game.player(alice).unwrap().graveyard.is_empty());
+        let bob_graveyard = &game.player(bob).unwrap().graveyard;
+        assert_eq!(bob_graveyard.len(), 1);
+        assert_eq!(
+            game.object(bob_graveyard[0]).unwrap().stable_id,
+            crate::ids::StableId::from(bob_card)
+        );
+    }
+
+    #[test]
+    fn cards_in_your_hand_filter_follows_the_hand_not_the_owner() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bob_card = create_hand_card(&mut game, bob, "Bob's Card");
+
+        exchange_hands(&mut game, alice, bob);
+
+        let filter = crate::filter::ObjectFilter::default()
+            .in_zone(Zone::Hand)
+            .owned_by(PlayerFilter::You);
+        let filter_ctx = game.filter_context_for(alice, None);
+        let card = game.object(bob_card).unwrap();
+        assert!(filter.matches(card, &filter_ctx, &game));
+    }
+}
@@ -1,16 +1,22 @@
 //! Exile-until effect implementation.
 
-use crate::effect::EffectOutcome;
-use crate::effects::EffectExecutor;
+use crate::effect::{Effect, EffectOutcome};
 use crate::effects::helpers::resolve_objects_for_effect;
+use crate::effects::{EffectExecutor, MoveToZoneEffect, ScheduleDelayedTriggerEffect};
 use crate::event_processor::EventOutcome;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
-use crate::target::ChooseSpec;
+use crate::snapshot::ObjectSnapshot;
+use crate::tag::TagKey;
+use crate::target::{ChooseSpec, PlayerFilter};
+use crate::triggers::Trigger;
 use crate::zone::Zone;
 
 use super::apply_zone_change;
 
+/// Tag holding the exiled cards a timed return brings back.
+const RETURN_TAG: &str = "exile_until_return";
+
 /// Duration for "exile ... until ..." effects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExileUntilDuration {
@@ -56,6 +62,51 @@ impl ExileUntilEffect {
     pub fn source_leaves(spec: ChooseSpec) -> Self {
         Self::new(spec, ExileUntilDuration::SourceLeavesBattlefield)
     }
+
+    /// Exile, returning at the beginning of the next end step (Flickerwisp).
+    pub fn next_end_step(spec: ChooseSpec) -> Self {
+        Self::new(spec, ExileUntilDuration::NextEndStep)
+    }
+
+    /// Delayed trigger that brings the cards back for timed durations.
+    ///
+    /// Returns `None` when the return is driven by the source leaving instead.
+    fn return_trigger(&self) -> Option<Trigger> {
+        match self.duration {
+            ExileUntilDuration::SourceLeavesBattlefield => None,
+            ExileUntilDuration::NextEndStep => {
+                Some(Trigger::beginning_of_end_step(PlayerFilter::Any))
+            }
+            ExileUntilDuration::EndOfCombat => Some(Trigger::end_of_combat()),
+        }
+    }
+
+    /// Schedule the one-shot return of `exiled` as new objects under their
+    /// owners' control. The trigger never expires, so a turn that ends without
+    /// an end step leaves it waiting for the next one.
+    fn schedule_return(
+        &self,
+        game: &mut GameState,
+        ctx: &mut ExecutionContext,
+        trigger: Trigger,
+        exiled: Vec<ObjectSnapshot>,
+    ) -> Result<(), ExecutionError> {
+        let tag = TagKey::from(RETURN_TAG);
+        ctx.set_tagged_objects(tag.clone(), exiled);
+        let return_effect = Effect::new(
+            MoveToZoneEffect::new(ChooseSpec::Tagged(tag.clone()), self.return_zone, false)
+                .under_owner_control(),
+        );
+        ScheduleDelayedTriggerEffect::from_tag(
+            trigger,
+            vec![return_effect],
+            true,
+            tag,
+            PlayerFilter::You,
+        )
+        .execute(game, ctx)?;
+        Ok(())
+    }
 }
 
 impl EffectExecutor for ExileUntilEffect {
@@ -65,7 +116,7 @@ impl EffectExecutor for ExileUntilEffect {
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
         let objects = resolve_objects_for_effect(game, ctx, &self.spec)?;
-        let mut exiled_count = 0_i32;
+        let mut exiled = Vec::new();
         for object_id in objects {
             let Some(obj) = game.object(object_id) else {
                 continue;
@@ -88,9 +139,18 @@ impl EffectExecutor for ExileUntilEffect {
                     game.set_face_down(new_id);
                 }
                 game.add_exiled_with_source_link(ctx.source, new_id);
-                exiled_count += 1;
+                if let Some(obj) = game.object(new_id) {
+                    exiled.push(ObjectSnapshot::from_object(obj, game));
+                }
             }
         }
+
+        let exiled_count = exiled.len() as i32;
+        if let Some(trigger) = self.return_trigger()
+            && !exiled.is_empty()
+        {
+            self.schedule_return(game, ctx, trigger, exiled)?;
+        }
         Ok(EffectOutcome::count(exiled_count))
     }
 
@@ -179,4 +239,36 @@ mod tests {
         assert_eq!(game.players[0].hand.len(), 1);
         assert!(game.battlefield.is_empty());
     }
+
+    #[test]
+    fn test_exile_until_next_end_step_schedules_return() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let creature_id = create_creature_on_battlefield(&mut game, "Elite Vanguard", alice);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let effect = ExileUntilEffect::next_end_step(ChooseSpec::SpecificObject(creature_id));
+        let result = effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(result.value, crate::effect::OutcomeValue::Count(1));
+        assert_eq!(game.exile.len(), 1);
+        assert_eq!(game.delayed_triggers.len(), 1);
+        assert!(game.delayed_triggers[0].one_shot);
+    }
+
+    #[test]
+    fn test_exile_until_source_leaves_schedules_no_return() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let creature_id = create_creature_on_battlefield(&mut game, "Elite Vanguard", alice);
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let effect = ExileUntilEffect::source_leaves(ChooseSpec::SpecificObject(creature_id));
+        effect.execute(&mut game, &mut ctx).unwrap();
+
+        assert_eq!(game.exile.len(), 1);
+        assert!(game.delayed_triggers.is_empty());
+    }
 }
@@ -1,7 +1,8 @@
 //! Return all matching cards to the battlefield.
 
+use super::BattlefieldController;
 use super::battlefield_entry::{
-    BattlefieldEntryOptions, BattlefieldEntryOutcome, move_to_battlefield_with_options,
+    BattlefieldEntryOptions, BattlefieldEntryOutcome, move_all_to_battlefield_with_options,
 };
 use crate::effect::EffectOutcome;
 use crate::effects::EffectExecutor;
@@ -14,18 +15,34 @@ use crate::target::{ChooseSpec, ObjectFilter};
 ///
 /// This is used by clauses like "Return all creature cards from all graveyards
 /// to the battlefield tapped under their owners' control."
+///
+/// The cards return simultaneously as new objects: replacement effects from
+/// one returning permanent don't apply to another, and ETB triggers are
+/// checked only once all of them are on the battlefield.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReturnAllToBattlefieldEffect {
     /// Filter used to select cards to return.
     pub filter: ObjectFilter,
     /// Whether the returned permanents enter tapped.
     pub tapped: bool,
+    /// Who controls the returned permanents.
+    pub controller: BattlefieldController,
 }
 
 impl ReturnAllToBattlefieldEffect {
-    /// Create a new return-all effect.
+    /// Create a new return-all effect. Cards return under their owners' control.
     pub fn new(filter: ObjectFilter, tapped: bool) -> Self {
-        Self { filter, tapped }
+        Self {
+            filter,
+            tapped,
+            controller: BattlefieldController::Owner,
+        }
+    }
+
+    /// Return the cards under the effect controller's control instead.
+    pub fn under_you_control(mut self) -> Self {
+        self.controller = BattlefieldController::You;
+        self
     }
 }
 
@@ -36,26 +53,150 @@ impl EffectExecutor for ReturnAllToBattlefieldEffect {
         ctx: &mut ExecutionContext,
     ) -> Result<EffectOutcome, ExecutionError> {
         let spec = ChooseSpec::all(self.filter.clone());
-        let objects = resolve_objects_from_spec(game, &spec, ctx)?;
-
-        let mut returned_count = 0;
-        for object_id in objects {
-            if game.object(object_id).is_none() {
-                continue;
+        let objects: Vec<_> = resolve_objects_from_spec(game, &spec, ctx)?
+            .into_iter()
+            .filter(|&id| game.object(id).is_some())
+            .collect();
+        let options = match self.controller {
+            BattlefieldController::Preserve => BattlefieldEntryOptions::preserve(self.tapped),
+            BattlefieldController::Owner => BattlefieldEntryOptions::owner(self.tapped),
+            BattlefieldController::You => {
+                BattlefieldEntryOptions::specific(ctx.controller, self.tapped)
             }
+        };
 
-            let outcome = move_to_battlefield_with_options(
-                game,
-                ctx,
-                object_id,
-                BattlefieldEntryOptions::owner(self.tapped),
-            );
+        let returned_count = move_all_to_battlefield_with_options(game, ctx, &objects, options)
+            .into_iter()
+            .filter(|outcome| matches!(outcome, BattlefieldEntryOutcome::Moved(_)))
+            .count();
 
-            if matches!(outcome, BattlefieldEntryOutcome::Moved(_)) {
-                returned_count += 1;
-            }
-        }
+        Ok(EffectOutcome::count(returned_count as i32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::Ability;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::CardDefinitionBuilder;
+    use crate::ids::{CardId, ObjectId, PlayerId};
+    use crate::static_abilities::StaticAbility;
+    use crate::types::CardType;
+    use crate::zone::Zone;
+
+    fn graveyard_creature(game: &mut GameState, name: &str, owner: PlayerId) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), name)
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Graveyard)
+    }
+
+    fn battlefield_ids(game: &GameState, name: &str) -> Vec<ObjectId> {
+        game.battlefield
+            .iter()
+            .copied()
+            .filter(|&id| game.object(id).is_some_and(|obj| obj.name == name))
+            .collect()
+    }
+
+    /// Living Death: every creature card in every graveyard returns at once.
+    fn living_death_game() -> (GameState, ObjectId) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        // "Creatures enter tapped" on a card that returns alongside the others.
+        let gatekeeper = graveyard_creature(&mut game, "Gatekeeper", alice);
+        game.object_mut(gatekeeper)
+            .unwrap()
+            .abilities
+            .push(Ability::static_ability(
+                StaticAbility::enters_tapped_for_filter(ObjectFilter::creature()),
+            ));
+
+        let herald_def = CardDefinitionBuilder::new(CardId::new(), "Herald")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .parse_text(
+                "When this creature enters, if you control three or more creatures, draw a card.",
+            )
+            .unwrap();
+        game.create_object_from_definition(&herald_def, alice, Zone::Graveyard);
+        graveyard_creature(&mut game, "Alice's Bear", alice);
+        graveyard_creature(&mut game, "Bob's Bear", bob);
+
+        let source = game.new_object_id();
+        (game, source)
+    }
+
+    #[test]
+    fn living_death_returns_everything_simultaneously() {
+        let (mut game, source) = living_death_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.take_pending_trigger_events();
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        let effect = ReturnAllToBattlefieldEffect::new(
+            ObjectFilter::creature().in_zone(Zone::Graveyard),
+            false,
+        );
+        let outcome = effect.execute(&mut game, &mut ctx).unwrap();
+        assert_eq!(outcome.value, crate::effect::OutcomeValue::Count(4));
+
+        // Each card is a new object under its owner's control.
+        let bobs_bear = battlefield_ids(&game, "Bob's Bear");
+        assert_eq!(bobs_bear.len(), 1);
+        assert_eq!(game.object(bobs_bear[0]).unwrap().controller, bob);
+        let alices_bear = battlefield_ids(&game, "Alice's Bear");
+        assert_eq!(game.object(alices_bear[0]).unwrap().controller, alice);
+
+        // The Gatekeeper wasn't on the battlefield when the others were
+        // entering, so its replacement effect doesn't apply to them.
+        assert!(!game.is_tapped(alices_bear[0]));
+        assert!(!game.is_tapped(bobs_bear[0]));
+
+        // The Herald's trigger sees every returned creature already present.
+        let herald = battlefield_ids(&game, "Herald")[0];
+        let triggered: Vec<_> = game
+            .take_pending_trigger_events()
+            .iter()
+            .flat_map(|event| crate::triggers::check_triggers(&game, event))
+            .filter(|entry| entry.source == herald)
+            .collect();
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].controller, alice);
+    }
+
+    #[test]
+    fn replacement_effects_already_on_battlefield_still_apply() {
+        let (mut game, source) = living_death_game();
+        let alice = PlayerId::from_index(0);
+        let gatekeeper = graveyard_creature(&mut game, "Gatekeeper", alice);
+        let gatekeeper = game.move_object(gatekeeper, Zone::Battlefield).unwrap();
+        game.object_mut(gatekeeper)
+            .unwrap()
+            .abilities
+            .push(Ability::static_ability(
+                StaticAbility::enters_tapped_for_filter(ObjectFilter::creature()),
+            ));
+
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        ReturnAllToBattlefieldEffect::new(ObjectFilter::creature().in_zone(Zone::Graveyard), false)
+            .under_you_control()
+            .execute(&mut game, &mut ctx)
+            .unwrap();
 
-        Ok(EffectOutcome::count(returned_count))
+        let bobs_bear = battlefield_ids(&game, "Bob's Bear")[0];
+        assert!(game.is_tapped(bobs_bear));
+        assert_eq!(game.object(bobs_bear).unwrap().controller, alice);
+        assert!(
+            game.player(PlayerId::from_index(1))
+                .unwrap()
+                .graveyard
+                .is_empty()
+        );
     }
 }
@@ -3,14 +3,14 @@
 use crate::effect::{EffectOutcome, ExecutionFact, Value};
 use crate::effects::helpers::{
     normalize_object_selection, resolve_player_filter, resolve_single_object_for_effect,
-    resolve_value,
+    resolve_single_object_from_spec, resolve_value,
 };
-use crate::effects::{CostExecutableEffect, EffectExecutor};
+use crate::effects::{CostExecutableEffect, EffectExecutor, ExecutabilityReport};
 use crate::event_processor::EventOutcome;
 use crate::events::permanents::SacrificeEvent;
 use crate::executor::{ExecutionContext, ExecutionError};
 use crate::game_state::GameState;
-use crate::ids::ObjectId;
+use crate::ids::{ObjectId, PlayerId};
 use crate::snapshot::ObjectSnapshot;
 use crate::target::{ChooseSpec, ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
@@ -75,6 +75,27 @@ impl SacrificeEffect {
     pub fn player(filter: ObjectFilter, count: impl Into<Value>, player: PlayerFilter) -> Self {
         Self::new(filter, count, player)
     }
+
+    /// Permanents the player controls that match the filter and can be
+    /// sacrificed (excluding Sigarda, Tajuru Preserver style protection).
+    fn sacrificeable_permanents(
+        &self,
+        game: &GameState,
+        ctx: &ExecutionContext,
+        player_id: PlayerId,
+    ) -> Vec<ObjectId> {
+        let filter_ctx = ctx.filter_context(game);
+        game.battlefield
+            .iter()
+            .filter_map(|&id| game.object(id).map(|obj| (id, obj)))
+            .filter(|(id, obj)| {
+                obj.controller == player_id
+                    && self.filter.matches(obj, &filter_ctx, game)
+                    && game.can_be_sacrificed(*id)
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
 }
 
 impl EffectExecutor for SacrificeEffect {
@@ -82,6 +103,24 @@ impl EffectExecutor for SacrificeEffect {
         Some(self)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let (Ok(player_id), Ok(count)) = (
+            resolve_player_filter(game, &self.player, ctx),
+            resolve_value(game, &self.count, ctx),
+        ) else {
+            return ExecutabilityReport::Unknown;
+        };
+        if count <= 0 {
+            return ExecutabilityReport::impossible("nothing to sacrifice");
+        }
+        ExecutabilityReport::executable_if(
+            !self
+                .sacrificeable_permanents(game, ctx, player_id)
+                .is_empty(),
+            format!("no {} to sacrifice", self.filter.description()),
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -91,21 +130,7 @@ impl EffectExecutor for SacrificeEffect {
         use crate::decisions::specs::ChooseObjectsSpec;
         let player_id = resolve_player_filter(game, &self.player, ctx)?;
         let count = resolve_value(game, &self.count, ctx)?.max(0) as usize;
-        let filter_ctx = ctx.filter_context(game);
-
-        // Find permanents the player controls that match the filter
-        // Also filter out permanents that can't be sacrificed (Sigarda, Tajuru Preserver effects)
-        let matching: Vec<ObjectId> = game
-            .battlefield
-            .iter()
-            .filter_map(|&id| game.object(id).map(|obj| (id, obj)))
-            .filter(|(id, obj)| {
-                obj.controller == player_id
-                    && self.filter.matches(obj, &filter_ctx, game)
-                    && game.can_be_sacrificed(*id)
-            })
-            .map(|(id, _)| id)
-            .collect();
+        let matching = self.sacrificeable_permanents(game, ctx, player_id);
 
         let required = count.min(matching.len());
         let explicit_targets: Vec<ObjectId> = ctx
@@ -346,6 +371,16 @@ impl EffectExecutor for SacrificeTargetEffect {
         Some(self)
     }
 
+    fn can_execute(&self, game: &GameState, ctx: &ExecutionContext) -> ExecutabilityReport {
+        let Ok(object_id) = resolve_single_object_from_spec(game, &self.target, ctx) else {
+            return ExecutabilityReport::Unknown;
+        };
+        ExecutabilityReport::executable_if(
+            game.battlefield.contains(&object_id) && game.can_be_sacrificed(object_id),
+            "permanent can't be sacrificed",
+        )
+    }
+
     fn execute(
         &self,
         game: &mut GameState,
@@ -405,9 +440,11 @@ impl CostExecutableEffect for SacrificeTargetEffect {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ability::AbilityKind;
     use crate::card::{CardBuilder, PowerToughness};
+    use crate::cards::CardDefinitionBuilder;
     use crate::effect::ExecutionFact;
-    use crate::ids::{CardId, PlayerId};
+    use crate::ids::CardId;
     use crate::mana::{ManaCost, ManaSymbol};
     use crate::object::Object;
     use crate::types::CardType;
@@ -463,4 +500,53 @@ mod tests {
                 .contains(&ExecutionFact::AffectedObjects(vec![target_id]))
         );
     }
+
+    struct PanicOnObjectChoiceDecisionMaker;
+
+    impl crate::decision::DecisionMaker for PanicOnObjectChoiceDecisionMaker {
+        fn decide_objects(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::SelectObjectsContext,
+        ) -> Vec<ObjectId> {
+            panic!("a forced sacrifice should not prompt for a choice");
+        }
+    }
+
+    #[test]
+    fn test_sacrifice_if_able_forces_only_creature() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let source = game.new_object_id();
+        let bear = create_creature_on_battlefield(&mut game, "Bear", alice);
+
+        let definition = CardDefinitionBuilder::new(CardId::from_raw(900), "Grim Conscript")
+            .card_types(vec![CardType::Creature])
+            .parse_text("When this creature enters, sacrifice a creature if able.")
+            .expect("sacrifice-if-able trigger should parse");
+        let effects = definition
+            .abilities
+            .iter()
+            .find_map(|ability| match &ability.kind {
+                AbilityKind::Triggered(triggered) => Some(triggered.effects.clone()),
+                _ => None,
+            })
+            .expect("definition should have a triggered ability");
+
+        let mut dm = PanicOnObjectChoiceDecisionMaker;
+        let mut ctx = ExecutionContext::new_default(source, alice).with_decision_maker(&mut dm);
+        for effect in &effects {
+            assert!(!effect.0.can_execute(&game, &ctx).is_impossible());
+            crate::executor::execute_effect(&mut game, effect, &mut ctx)
+                .expect("sacrifice should resolve");
+        }
+
+        assert!(!game.battlefield.contains(&bear));
+        assert!(
+            SacrificeEffect::you_creature(1)
+                .can_execute(&game, &ctx)
+                .is_impossible(),
+            "nothing is left to sacrifice"
+        );
+    }
 }
@@ -0,0 +1,605 @@
+//! Running the engine on its own thread so a frontend can read the game while
+//! a decision is pending.
+//!
+//! The game loop is synchronous: it borrows `&mut GameState` and blocks inside
+//! [`DecisionMaker`] calls. A UI (egui, tauri, ...) usually wants to keep
+//! rendering on its own thread while the engine waits for input. [`EngineHandle`]
+//! moves the loop onto a worker thread and talks to it over two channels:
+//!
+//! ```ignore
+//! let handle = EngineHandle::spawn(game, |game, dm| {
+//!     let mut combat = CombatState::default();
+//!     let mut triggers = TriggerQueue::new();
+//!     execute_turn_with(game, &mut combat, &mut triggers, dm)
+//! });
+//! while let Some(request) = handle.recv_request() {
+//!     render(&handle.state());
+//!     handle.respond(choose(&request.context))?;
+//! }
+//! let result = handle.join();
+//! ```
+//!
+//! # Consistency guarantee
+//!
+//! The shared state is only written by the engine thread, and only at two
+//! points: right before a [`DecisionRequest`] is sent, and once more when the
+//! engine closure returns. After sending a request the engine thread blocks
+//! until the matching [`DecisionResponse`] arrives, so from the moment
+//! [`EngineHandle::recv_request`] returns until [`EngineHandle::respond`] is
+//! called, [`EngineHandle::state`] is exactly the state the decision was asked
+//! against and does not change. Between decisions the shared state is the last
+//! published one, not the engine's in-progress working copy.
+//!
+//! Every publication bumps [`EngineHandle::version`]; each request carries the
+//! version it was published with so readers can tell which snapshot they hold.
+
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::color::Color;
+use crate::decision::{DecisionMaker, LegalAction};
+use crate::decisions::context::{
+    AttackersContext, BlockersContext, BooleanContext, ColorsContext, CountersContext,
+    DecisionContext, DistributeContext, NumberContext, OrderContext, PartitionContext,
+    PriorityContext, ProliferateContext, SelectObjectsContext, SelectOptionsContext,
+    TargetsContext,
+};
+use crate::decisions::spec::{AttackerDeclaration, BlockerDeclaration};
+use crate::decisions::specs::ProliferateResponse;
+use crate::game_state::{GameState, Target};
+use crate::ids::{ObjectId, PlayerId};
+use crate::object::CounterType;
+
+/// Game state shared between the engine thread and its readers.
+pub type SharedGameState = Arc<RwLock<GameState>>;
+
+/// A decision the engine is blocked on.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DecisionRequest {
+    /// What is being decided.
+    pub context: DecisionContext,
+    /// Version of the shared state published for this decision.
+    pub version: u64,
+}
+
+/// The answer to a [`DecisionRequest`].
+///
+/// Each variant matches the return type of the corresponding
+/// [`DecisionMaker`] method. Modes and hybrid mana choices are answered with
+/// [`DecisionResponse::Options`].
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum DecisionResponse {
+    Boolean(bool),
+    Number(u32),
+    Objects(Vec<ObjectId>),
+    Options(Vec<usize>),
+    Order(Vec<ObjectId>),
+    Attackers(Vec<AttackerDeclaration>),
+    Blockers(Vec<BlockerDeclaration>),
+    Distribute(Vec<(Target, u32)>),
+    Colors(Vec<Color>),
+    Counters(Vec<(CounterType, u32)>),
+    Partition(Vec<ObjectId>),
+    Proliferate(ProliferateResponse),
+    Priority(LegalAction),
+    Targets(Vec<Target>),
+}
+
+/// Errors returned by [`EngineHandle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineHandleError {
+    /// `respond` was called while no decision was pending.
+    NoPendingDecision,
+    /// The engine thread has finished or panicked.
+    EngineStopped,
+}
+
+impl std::fmt::Display for EngineHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EngineHandleError::NoPendingDecision => write!(f, "No decision is pending"),
+            EngineHandleError::EngineStopped => write!(f, "Engine thread has stopped"),
+        }
+    }
+}
+
+impl std::error::Error for EngineHandleError {}
+
+/// Fallback that answers with the trait's default choices.
+struct DefaultChoices;
+
+impl DecisionMaker for DefaultChoices {}
+
+/// [`DecisionMaker`] used on the engine thread.
+///
+/// Publishes the game state, forwards each decision to the [`EngineHandle`],
+/// and blocks until it answers. If the handle goes away, or answers with a
+/// response of the wrong kind, the trait's default choice is used so the
+/// engine can still run to completion.
+pub struct ChannelDecisionMaker {
+    shared: SharedGameState,
+    version: Arc<std::sync::atomic::AtomicU64>,
+    requests: Sender<DecisionRequest>,
+    responses: Receiver<DecisionResponse>,
+}
+
+impl ChannelDecisionMaker {
+    /// Copy `game` into the shared slot and return the new version.
+    fn publish(&self, game: &GameState) -> u64 {
+        let mut slot = self.shared.write().unwrap_or_else(|e| e.into_inner());
+        *slot = game.clone();
+        self.version
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+            + 1
+    }
+
+    fn ask(&mut self, game: &GameState, context: DecisionContext) -> Option<DecisionResponse> {
+        let version = self.publish(game);
+        self.requests
+            .send(DecisionRequest { context, version })
+            .ok()?;
+        self.responses.recv().ok()
+    }
+}
+
+impl DecisionMaker for ChannelDecisionMaker {
+    fn decide_boolean(&mut self, game: &GameState, ctx: &BooleanContext) -> bool {
+        match self.ask(game, DecisionContext::Boolean(ctx.clone())) {
+            Some(DecisionResponse::Boolean(answer)) => answer,
+            _ => DefaultChoices.decide_boolean(game, ctx),
+        }
+    }
+
+    fn decide_number(&mut self, game: &GameState, ctx: &NumberContext) -> u32 {
+        match self.ask(game, DecisionContext::Number(ctx.clone())) {
+            Some(DecisionResponse::Number(n)) => n,
+            _ => DefaultChoices.decide_number(game, ctx),
+        }
+    }
+
+    fn decide_objects(&mut self, game: &GameState, ctx: &SelectObjectsContext) -> Vec<ObjectId> {
+        match self.ask(game, DecisionContext::SelectObjects(ctx.clone())) {
+            Some(DecisionResponse::Objects(ids)) => ids,
+            _ => DefaultChoices.decide_objects(game, ctx),
+        }
+    }
+
+    fn decide_options(&mut self, game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+        match self.ask(game, DecisionContext::SelectOptions(ctx.clone())) {
+            Some(DecisionResponse::Options(indices)) => indices,
+            _ => DefaultChoices.decide_options(game, ctx),
+        }
+    }
+
+    fn decide_order(&mut self, game: &GameState, ctx: &OrderContext) -> Vec<ObjectId> {
+        match self.ask(game, DecisionContext::Order(ctx.clone())) {
+            Some(DecisionResponse::Order(ids)) => ids,
+            _ => DefaultChoices.decide_order(game, ctx),
+        }
+    }
+
+    fn decide_attackers(
+        &mut self,
+        game: &GameState,
+        ctx: &AttackersContext,
+    ) -> Vec<AttackerDeclaration> {
+        match self.ask(game, DecisionContext::Attackers(ctx.clone())) {
+            Some(DecisionResponse::Attackers(declarations)) => declarations,
+            _ => DefaultChoices.decide_attackers(game, ctx),
+        }
+    }
+
+    fn decide_blockers(
+        &mut self,
+        game: &GameState,
+        ctx: &BlockersContext,
+    ) -> Vec<BlockerDeclaration> {
+        match self.ask(game, DecisionContext::Blockers(ctx.clone())) {
+            Some(DecisionResponse::Blockers(declarations)) => declarations,
+            _ => DefaultChoices.decide_blockers(game, ctx),
+        }
+    }
+
+    fn decide_distribute(
+        &mut self,
+        game: &GameState,
+        ctx: &DistributeContext,
+    ) -> Vec<(Target, u32)> {
+        match self.ask(game, DecisionContext::Distribute(ctx.clone())) {
+            Some(DecisionResponse::Distribute(shares)) => shares,
+            _ => DefaultChoices.decide_distribute(game, ctx),
+        }
+    }
+
+    fn decide_colors(&mut self, game: &GameState, ctx: &ColorsContext) -> Vec<Color> {
+        match self.ask(game, DecisionContext::Colors(ctx.clone())) {
+            Some(DecisionResponse::Colors(colors)) => colors,
+            _ => DefaultChoices.decide_colors(game, ctx),
+        }
+    }
+
+    fn decide_counters(
+        &mut self,
+        game: &GameState,
+        ctx: &CountersContext,
+    ) -> Vec<(CounterType, u32)> {
+        match self.ask(game, DecisionContext::Counters(ctx.clone())) {
+            Some(DecisionResponse::Counters(counters)) => counters,
+            _ => DefaultChoices.decide_counters(game, ctx),
+        }
+    }
+
+    fn decide_partition(&mut self, game: &GameState, ctx: &PartitionContext) -> Vec<ObjectId> {
+        match self.ask(game, DecisionContext::Partition(ctx.clone())) {
+            Some(DecisionResponse::Partition(ids)) => ids,
+            _ => DefaultChoices.decide_partition(game, ctx),
+        }
+    }
+
+    fn decide_proliferate(
+        &mut self,
+        game: &GameState,
+        ctx: &ProliferateContext,
+    ) -> ProliferateResponse {
+        match self.ask(game, DecisionContext::Proliferate(ctx.clone())) {
+            Some(DecisionResponse::Proliferate(response)) => response,
+            _ => DefaultChoices.decide_proliferate(game, ctx),
+        }
+    }
+
+    fn decide_priority(&mut self, game: &GameState, ctx: &PriorityContext) -> LegalAction {
+        match self.ask(game, DecisionContext::Priority(ctx.clone())) {
+            Some(DecisionResponse::Priority(action)) => action,
+            _ => DefaultChoices.decide_priority(game, ctx),
+        }
+    }
+
+    fn decide_targets(&mut self, game: &GameState, ctx: &TargetsContext) -> Vec<Target> {
+        match self.ask(game, DecisionContext::Targets(ctx.clone())) {
+            Some(DecisionResponse::Targets(targets)) => targets,
+            _ => DefaultChoices.decide_targets(game, ctx),
+        }
+    }
+}
+
+/// Frontend side of an engine running on a worker thread.
+///
+/// See the [module docs](self) for the consistency guarantee.
+pub struct EngineHandle<R> {
+    shared: SharedGameState,
+    version: Arc<std::sync::atomic::AtomicU64>,
+    requests: Receiver<DecisionRequest>,
+    responses: Sender<DecisionResponse>,
+    pending: Option<PlayerId>,
+    thread: JoinHandle<R>,
+}
+
+impl<R: Send + 'static> EngineHandle<R> {
+    /// Move `game` onto a new thread and run `run` there.
+    ///
+    /// `run` receives the game and a [`ChannelDecisionMaker`] to pass to the
+    /// game loop. The final state is published when it returns.
+    pub fn spawn<F>(game: GameState, run: F) -> Self
+    where
+        F: FnOnce(&mut GameState, &mut ChannelDecisionMaker) -> R + Send + 'static,
+    {
+        let shared: SharedGameState = Arc::new(RwLock::new(game.clone()));
+        let version = Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let (request_tx, request_rx) = mpsc::channel();
+        let (response_tx, response_rx) = mpsc::channel();
+        let mut decision_maker = ChannelDecisionMaker {
+            shared: Arc::clone(&shared),
+            version: Arc::clone(&version),
+            requests: request_tx,
+            responses: response_rx,
+        };
+        let thread = std::thread::spawn(move || {
+            let mut game = game;
+            let result = run(&mut game, &mut decision_maker);
+            decision_maker.publish(&game);
+            result
+        });
+        Self {
+            shared,
+            version,
+            requests: request_rx,
+            responses: response_tx,
+            pending: None,
+            thread,
+        }
+    }
+}
+
+impl<R> EngineHandle<R> {
+    /// Read access to the last published state.
+    pub fn state(&self) -> RwLockReadGuard<'_, GameState> {
+        self.shared.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Clone of the last published state.
+    pub fn snapshot(&self) -> GameState {
+        self.state().clone()
+    }
+
+    /// A clone of the shared state pointer, for reader threads.
+    pub fn shared_state(&self) -> SharedGameState {
+        Arc::clone(&self.shared)
+    }
+
+    /// Number of times the state has been published.
+    pub fn version(&self) -> u64 {
+        self.version.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// The player the pending decision belongs to, if any.
+    pub fn pending_player(&self) -> Option<PlayerId> {
+        self.pending
+    }
+
+    /// Block until the engine asks for a decision.
+    ///
+    /// Returns `None` once the engine has finished.
+    pub fn recv_request(&mut self) -> Option<DecisionRequest> {
+        let request = self.requests.recv().ok()?;
+        self.pending = Some(request.context.player());
+        Some(request)
+    }
+
+    /// Non-blocking variant of [`recv_request`](Self::recv_request).
+    pub fn try_recv_request(&mut self) -> Result<Option<DecisionRequest>, EngineHandleError> {
+        match self.requests.try_recv() {
+            Ok(request) => {
+                self.pending = Some(request.context.player());
+                Ok(Some(request))
+            }
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(EngineHandleError::EngineStopped),
+        }
+    }
+
+    /// Like [`recv_request`](Self::recv_request), giving up after `timeout`.
+    pub fn recv_request_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<DecisionRequest>, EngineHandleError> {
+        match self.requests.recv_timeout(timeout) {
+            Ok(request) => {
+                self.pending = Some(request.context.player());
+                Ok(Some(request))
+            }
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(EngineHandleError::EngineStopped),
+        }
+    }
+
+    /// Answer the pending decision and let the engine continue.
+    pub fn respond(&mut self, response: DecisionResponse) -> Result<(), EngineHandleError> {
+        if self.pending.take().is_none() {
+            return Err(EngineHandleError::NoPendingDecision);
+        }
+        self.responses
+            .send(response)
+            .map_err(|_| EngineHandleError::EngineStopped)
+    }
+
+    /// Whether the engine thread has finished.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Wait for the engine to finish and return its result and final state.
+    ///
+    /// Dropping the handle's channels first means any decision the engine
+    /// still asks for falls back to the default choice instead of hanging.
+    pub fn join(self) -> std::thread::Result<(R, GameState)> {
+        let Self {
+            shared,
+            requests,
+            responses,
+            thread,
+            ..
+        } = self;
+        drop(requests);
+        drop(responses);
+        let result = thread.join()?;
+        let game = shared.read().unwrap_or_else(|e| e.into_inner()).clone();
+        Ok((result, game))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_state::CombatState;
+    use crate::game_loop::execute_turn_with;
+    use crate::game_state::Step;
+    use crate::game_state_builder::GameStateBuilder;
+    use crate::triggers::TriggerQueue;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[test]
+    fn reader_thread_polls_state_while_engine_waits_on_decisions() {
+        let (mut game, objects) = GameStateBuilder::new()
+            .player("Alice")
+            .battlefield(["Grizzly Bears*2", "Forest*2"])
+            .player("Bob")
+            .battlefield(["Grizzly Bears"])
+            .build()
+            .expect("build game");
+        game.turn.step = Some(Step::Untap);
+        let alice_bears = objects.ids("Grizzly Bears")[..2].to_vec();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let mut handle = EngineHandle::spawn(game, |game, dm| {
+            let mut combat = CombatState::default();
+            let mut trigger_queue = TriggerQueue::new();
+            execute_turn_with(game, &mut combat, &mut trigger_queue, dm)
+        });
+
+        let done = Arc::new(AtomicBool::new(false));
+        let reads = Arc::new(AtomicUsize::new(0));
+        let reader = {
+            let shared = handle.shared_state();
+            let done = Arc::clone(&done);
+            let reads = Arc::clone(&reads);
+            let bears = alice_bears.clone();
+            std::thread::spawn(move || {
+                while !done.load(Ordering::SeqCst) {
+                    let game = shared.read().unwrap();
+                    for &bear in &bears {
+                        if let Some(power) = game.calculated_power(bear) {
+                            assert_eq!(power, 2);
+                        }
+                    }
+                    reads.fetch_add(1, Ordering::SeqCst);
+                    drop(game);
+                    std::thread::yield_now();
+                }
+            })
+        };
+
+        let mut requests = 0;
+        while let Ok(Some(request)) = handle.recv_request_timeout(Duration::from_secs(10)) {
+            requests += 1;
+            // The published state must not move while the decision is pending.
+            let before = handle.version();
+            assert_eq!(before, request.version);
+            let life_before = handle.state().player(bob).unwrap().life;
+            std::thread::sleep(Duration::from_millis(1));
+            assert_eq!(handle.version(), before);
+            assert_eq!(handle.state().player(bob).unwrap().life, life_before);
+
+            let response = match &request.context {
+                DecisionContext::Attackers(ctx) => {
+                    assert_eq!(ctx.player, alice);
+                    DecisionResponse::Attackers(
+                        alice_bears
+                            .iter()
+                            .map(|&creature| AttackerDeclaration {
+                                creature,
+                                target: crate::combat_state::AttackTarget::Player(bob),
+                            })
+                            .collect(),
+                    )
+                }
+                DecisionContext::Blockers(_) => DecisionResponse::Blockers(Vec::new()),
+                DecisionContext::Priority(_) => {
+                    DecisionResponse::Priority(LegalAction::PassPriority)
+                }
+                other => panic!("unexpected decision: {other:?}"),
+            };
+            handle.respond(response).expect("engine still running");
+        }
+
+        assert!(requests > 0, "engine should have asked for decisions");
+        assert_eq!(
+            handle.respond(DecisionResponse::Boolean(true)),
+            Err(EngineHandleError::NoPendingDecision)
+        );
+        let (result, game) = handle.join().expect("engine thread panicked");
+        done.store(true, Ordering::SeqCst);
+        reader.join().expect("reader thread panicked");
+
+        assert!(result.is_ok(), "turn should complete: {result:?}");
+        assert_eq!(game.player(bob).unwrap().life, 16);
+        assert!(reads.load(Ordering::SeqCst) > 0);
+    }
+
+    /// Answer a JSON-encoded request the way a frontend script would: play a
+    /// land if one is offered, attack with everything, never block.
+    #[cfg(feature = "serialization")]
+    fn scripted_json_answer(request: &serde_json::Value) -> serde_json::Value {
+        let context = &request["context"];
+        if let Some(priority) = context.get("Priority") {
+            let actions = priority["actions"].as_array().expect("actions array");
+            let action = actions
+                .iter()
+                .find(|action| action.get("PlayLand").is_some())
+                .cloned()
+                .unwrap_or_else(|| serde_json::json!("PassPriority"));
+            return serde_json::json!({ "Priority": action });
+        }
+        if let Some(attackers) = context.get("Attackers") {
+            let declarations: Vec<_> = attackers["attacker_options"]
+                .as_array()
+                .expect("attacker options")
+                .iter()
+                .map(|option| {
+                    serde_json::json!({
+                        "creature": option["creature"],
+                        "target": option["valid_targets"][0],
+                    })
+                })
+                .collect();
+            return serde_json::json!({ "Attackers": declarations });
+        }
+        if context.get("Blockers").is_some() {
+            return serde_json::json!({ "Blockers": [] });
+        }
+        panic!("unexpected decision: {request}");
+    }
+
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn scripted_two_turn_game_through_json_requests_and_responses() {
+        let (mut game, objects) = GameStateBuilder::new()
+            .player("Alice")
+            .battlefield(["Grizzly Bears"])
+            .hand(["Forest"])
+            .library(["Forest*3"])
+            .player("Bob")
+            .hand(["Forest"])
+            .library(["Forest*3"])
+            .build()
+            .expect("build game");
+        game.turn.step = Some(Step::Untap);
+        let alice = objects.player("Alice");
+        let bob = objects.player("Bob");
+
+        let mut handle = EngineHandle::spawn(game, |game, dm| {
+            let mut combat = CombatState::default();
+            let mut trigger_queue = TriggerQueue::new();
+            execute_turn_with(game, &mut combat, &mut trigger_queue, &mut *dm)?;
+            game.next_turn();
+            execute_turn_with(game, &mut combat, &mut trigger_queue, dm)
+        });
+
+        let mut deciders = Vec::new();
+        while let Ok(Some(request)) = handle.recv_request_timeout(Duration::from_secs(10)) {
+            let encoded = serde_json::to_string(&request).expect("encode request");
+            let request: serde_json::Value = serde_json::from_str(&encoded).expect("parse request");
+            let answer = scripted_json_answer(&request).to_string();
+            let response: DecisionResponse =
+                serde_json::from_str(&answer).expect("decode response");
+            deciders.push(handle.pending_player().expect("decision is pending"));
+            handle.respond(response).expect("engine still running");
+        }
+
+        let (result, game) = handle.join().expect("engine thread panicked");
+        assert!(result.is_ok(), "both turns should complete: {result:?}");
+        assert!(deciders.contains(&alice) && deciders.contains(&bob));
+        assert_eq!(game.player(bob).unwrap().life, 18);
+        for player in [alice, bob] {
+            let lands_in_play = game
+                .battlefield
+                .iter()
+                .filter(|&&id| {
+                    game.object(id)
+                        .is_some_and(|obj| obj.controller == player && obj.name == "Forest")
+                })
+                .count();
+            assert_eq!(lands_in_play, 1, "each player played their Forest");
+        }
+    }
+}
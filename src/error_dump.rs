@@ -0,0 +1,184 @@
+//! Debug dumps for effects that fail during resolution.
+//!
+//! When [`GameState::set_error_dump_dir`] is set and an effect returns an
+//! [`ExecutionError`] while a stack entry resolves, the game loop writes a
+//! text file with the error and its context, the live stack, the board as a
+//! [`GameStateBuilder`](crate::game_state_builder::GameStateBuilder) scenario
+//! that can be pasted into a test, and the most recent provenance events.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::executor::ExecutionError;
+use crate::game_state::{GameState, StackEntry};
+use crate::ids::PlayerId;
+use crate::zone::Zone;
+
+/// How many provenance nodes the dump lists.
+const RECENT_EVENT_LIMIT: usize = 50;
+
+static DUMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// One-line description of a stack entry.
+pub fn describe_stack_entry(game: &GameState, entry: &StackEntry) -> String {
+    let name = entry
+        .source_name
+        .clone()
+        .or_else(|| game.object(entry.object_id).map(|obj| obj.name.clone()))
+        .unwrap_or_else(|| "unknown source".to_string());
+    let kind = if entry.is_ability { "ability" } else { "spell" };
+    let mut description = format!(
+        "{name} ({kind}, source {:?}, controller {:?})",
+        entry.object_id, entry.controller
+    );
+    if !entry.targets.is_empty() {
+        let _ = write!(description, ", targets {:?}", entry.targets);
+    }
+    if let Some(x) = entry.x_value {
+        let _ = write!(description, ", X={x}");
+    }
+    description
+}
+
+/// The board as `GameStateBuilder` calls.
+///
+/// Objects are listed under their owner with their tapped state and counters.
+/// Attachments, continuous effects, and the stack are not reproduced.
+pub fn scenario(game: &GameState) -> String {
+    let mut out = String::from("GameStateBuilder::new()\n");
+    for player in &game.players {
+        let _ = writeln!(out, "    .player({:?})", player.name);
+        let _ = writeln!(out, "    .life({})", player.life);
+        let zones = [
+            ("battlefield", owned_in(game, &game.battlefield, player.id)),
+            ("hand", player.hand.clone()),
+            ("graveyard", player.graveyard.clone()),
+            ("exile", owned_in(game, &game.exile, player.id)),
+            ("library", player.library.clone()),
+        ];
+        for (zone, objects) in zones {
+            let entries: Vec<String> = objects
+                .iter()
+                .filter_map(|&id| game.object(id))
+                .map(|obj| {
+                    let mut entry = obj.name.clone();
+                    if obj.zone == Zone::Battlefield && game.is_tapped(obj.id) {
+                        entry.push_str(" tapped");
+                    }
+                    let mut counters: Vec<_> = obj.counters.iter().collect();
+                    counters.sort_by_key(|(counter, _)| counter.description());
+                    for (counter, amount) in counters {
+                        let _ = write!(entry, " {}:{amount}", counter.description());
+                    }
+                    format!("{entry:?}")
+                })
+                .collect();
+            if !entries.is_empty() {
+                let _ = writeln!(out, "    .{zone}([{}])", entries.join(", "));
+            }
+        }
+    }
+    let player_name = |id: PlayerId| {
+        game.player(id)
+            .map(|player| player.name.clone())
+            .unwrap_or_default()
+    };
+    let _ = writeln!(
+        out,
+        "    .active_player({:?})",
+        player_name(game.turn.active_player)
+    );
+    match game.turn.priority_player {
+        Some(priority) => {
+            let _ = writeln!(out, "    .priority({:?})", player_name(priority));
+        }
+        None => out.push_str("    .no_priority()\n"),
+    }
+    let _ = writeln!(out, "    .phase(Phase::{:?})", game.turn.phase);
+    if let Some(step) = game.turn.step {
+        let _ = writeln!(out, "    .step(Step::{step:?})");
+    }
+    let _ = writeln!(out, "    .turn_number({})", game.turn.turn_number);
+    out.push_str("    .build()\n");
+    out
+}
+
+fn owned_in(
+    game: &GameState,
+    zone: &[crate::ids::ObjectId],
+    owner: PlayerId,
+) -> Vec<crate::ids::ObjectId> {
+    zone.iter()
+        .copied()
+        .filter(|&id| game.object(id).is_some_and(|obj| obj.owner == owner))
+        .collect()
+}
+
+/// Full dump text for an error raised while `resolving` was resolving.
+pub fn render_error_dump(
+    game: &GameState,
+    resolving: &StackEntry,
+    error: &ExecutionError,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Error: {}", error.root());
+    if let Some(context) = error.context() {
+        let _ = writeln!(
+            out,
+            "Source: {} ({:?})",
+            context.source_name.as_deref().unwrap_or("unknown source"),
+            context.source
+        );
+        let _ = writeln!(out, "Effect: {}", context.effect);
+        let _ = writeln!(out, "Targets: {:?}", context.targets);
+        let _ = writeln!(out, "Tags: {:?}", context.tags);
+    }
+
+    let _ = writeln!(
+        out,
+        "\nResolving: {}",
+        describe_stack_entry(game, resolving)
+    );
+    out.push_str("Stack (top first):\n");
+    if game.stack.is_empty() {
+        out.push_str("  (empty)\n");
+    }
+    for entry in game.stack.iter().rev() {
+        let _ = writeln!(out, "  {}", describe_stack_entry(game, entry));
+    }
+
+    out.push_str("\nScenario:\n");
+    out.push_str(&scenario(game));
+
+    out.push_str("\nRecent events (oldest first):\n");
+    for node in game.provenance_graph.recent_nodes(RECENT_EVENT_LIMIT) {
+        match node.parent {
+            Some(parent) => {
+                let _ = writeln!(out, "  {:?} <- {:?}: {:?}", node.id, parent, node.kind);
+            }
+            None => {
+                let _ = writeln!(out, "  {:?}: {:?}", node.id, node.kind);
+            }
+        }
+    }
+    out
+}
+
+/// Write a dump for `error` into `dir` and return the file's path.
+pub fn write_error_dump(
+    game: &GameState,
+    resolving: &StackEntry,
+    error: &ExecutionError,
+    dir: &Path,
+) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "effect-error-{}-turn{}-{}.txt",
+        std::process::id(),
+        game.turn.turn_number,
+        DUMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&path, render_error_dump(game, resolving, error))?;
+    Ok(path)
+}
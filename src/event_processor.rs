@@ -134,7 +134,12 @@ fn process_event_direct(
 
     // When multiple replacement effects are tied at the highest priority,
     // the affected player/controller chooses which one to apply next.
-    if at_highest.len() > 1 {
+    // Enter-tapped replacements are idempotent, so their order never matters.
+    if at_highest.len() > 1
+        && !at_highest
+            .iter()
+            .all(|effect| matches!(effect.replacement, ReplacementAction::EnterTapped))
+    {
         let affected_player = event.inner().affected_player(game);
         let effect_ids: Vec<_> = at_highest.iter().map(|e| e.id).collect();
 
@@ -142,6 +147,7 @@ fn process_event_direct(
             player: affected_player,
             applicable_effects: effect_ids,
             event: Box::new(event),
+            applied_effects: state.applied_effects.iter().copied().collect(),
         };
     }
 
@@ -453,6 +459,31 @@ pub fn zone_allows_type_verification(zone: Zone) -> bool {
     }
 }
 
+/// Put a discarded card into its owner's graveyard, letting graveyard
+/// replacement effects (e.g., Rest in Peace) send it elsewhere.
+///
+/// Returns the card's new ID and the zone it actually ended up in.
+fn move_discarded_card_to_graveyard(
+    game: &mut GameState,
+    card_id: crate::ids::ObjectId,
+    decision_maker: &mut dyn DecisionMaker,
+) -> (Option<crate::ids::ObjectId>, Zone) {
+    let Some((from, stable_id)) = game.object(card_id).map(|obj| (obj.zone, obj.stable_id)) else {
+        return (None, Zone::Graveyard);
+    };
+    match process_zone_change(game, card_id, from, Zone::Graveyard, decision_maker) {
+        EventOutcome::Proceed(final_zone) => (game.move_object(card_id, final_zone), final_zone),
+        EventOutcome::Replaced => {
+            let new_id = game.find_object_by_stable_id(stable_id);
+            let final_zone = new_id
+                .and_then(|id| game.object(id))
+                .map_or(Zone::Graveyard, |obj| obj.zone);
+            (new_id, final_zone)
+        }
+        EventOutcome::Prevented | EventOutcome::NotApplicable => (None, from),
+    }
+}
+
 /// Execute a discard using the generic trait-based replacement effect system.
 ///
 /// This is the unified entry point for all discard operations. It:
@@ -483,7 +514,7 @@ pub fn execute_discard(
     cause: crate::events::cause::EventCause,
     _requires_type_verification: bool,
     provenance: crate::provenance::ProvNodeId,
-    decision_maker: &mut (impl DecisionMaker + ?Sized),
+    decision_maker: &mut dyn DecisionMaker,
 ) -> DiscardResult {
     use crate::events::cards::DiscardEvent;
     use crate::events::traits::downcast_event;
@@ -514,10 +545,15 @@ pub fn execute_discard(
                     destination = Zone::Exile;
                 }
 
-                let new_id = if destination == Zone::Library {
-                    move_to_top_of_library(game, card_id, player, decision_maker)
-                } else {
-                    game.move_object(card_id, destination)
+                let new_id = match destination {
+                    Zone::Library => move_to_top_of_library(game, card_id, player, decision_maker),
+                    Zone::Graveyard => {
+                        let (new_id, final_zone) =
+                            move_discarded_card_to_graveyard(game, card_id, decision_maker);
+                        destination = final_zone;
+                        new_id
+                    }
+                    _ => game.move_object(card_id, destination),
                 };
 
                 // Mark as madness_exiled if card went to exile via Madness
@@ -810,8 +846,14 @@ fn apply_trait_replacement(
         }
 
         ReplacementAction::Redirect { target, which } => {
-            let modified =
-                apply_trait_redirect(&event, target, which, effect.controller, effect.source);
+            let modified = apply_trait_redirect(
+                game,
+                &event,
+                target,
+                which,
+                effect.controller,
+                effect.source,
+            );
             match modified {
                 Some(e) => TraitApplyResult::Modified(e),
                 None => TraitApplyResult::Unchanged(event),
@@ -834,9 +876,14 @@ fn apply_trait_replacement(
             let Some(damage) = downcast_event::<DamageEvent>(event.inner()) else {
                 return TraitApplyResult::Unchanged(event);
             };
-            let Some(new_target) =
-                resolve_trait_redirect_target(event.inner(), target, which, effect.controller)
-            else {
+            let Some(new_target) = resolve_trait_redirect_target(
+                game,
+                event.inner(),
+                target,
+                which,
+                effect.controller,
+                effect.source,
+            ) else {
                 return TraitApplyResult::Unchanged(event);
             };
             let redirected_target = match new_target {
@@ -1095,7 +1142,10 @@ fn find_matching_cards_in_hand(
 
 /// Apply an EventModification to a trait-based event.
 fn apply_trait_modification(event: &Event, modification: &EventModification) -> Option<Event> {
-    use crate::events::{DamageEvent, DrawEvent, LifeGainEvent, PutCountersEvent, downcast_event};
+    use crate::events::{
+        CreateTokensEvent, DamageEvent, DrawEvent, LifeGainEvent, MillEvent, PutCountersEvent,
+        downcast_event,
+    };
 
     match event.kind() {
         EventKind::Damage => {
@@ -1164,13 +1214,50 @@ fn apply_trait_modification(event: &Event, modification: &EventModification) ->
             };
             Some(event.rewrap(modified))
         }
+        EventKind::Mill => {
+            let mill = downcast_event::<MillEvent>(event.inner())?;
+            let modified = match modification {
+                EventModification::Multiply(factor) => {
+                    mill.with_count(mill.count.saturating_mul(*factor))
+                }
+                EventModification::Add(delta) => {
+                    mill.with_count((mill.count as i32 + delta).max(0) as u32)
+                }
+                EventModification::Subtract(delta) => {
+                    mill.with_count(mill.count.saturating_sub(*delta))
+                }
+                EventModification::SetTo(value) => mill.with_count(*value),
+                EventModification::ReduceToZero => mill.with_count(0),
+            };
+            Some(event.rewrap(modified))
+        }
+        EventKind::CreateTokens => {
+            let create = downcast_event::<CreateTokensEvent>(event.inner())?;
+            let modified = match modification {
+                EventModification::Multiply(factor) => {
+                    create.with_count(create.count.saturating_mul(*factor))
+                }
+                EventModification::Add(delta) => {
+                    create.with_count((create.count as i32 + delta).max(0) as u32)
+                }
+                EventModification::Subtract(delta) => {
+                    create.with_count(create.count.saturating_sub(*delta))
+                }
+                EventModification::SetTo(value) => create.with_count(*value),
+                EventModification::ReduceToZero => create.with_count(0),
+            };
+            Some(event.rewrap(modified))
+        }
         _ => None,
     }
 }
 
 /// Apply doubling to a trait-based event.
 fn apply_trait_double(event: &Event) -> Option<Event> {
-    use crate::events::{DamageEvent, DrawEvent, LifeGainEvent, PutCountersEvent, downcast_event};
+    use crate::events::{
+        CreateTokensEvent, DamageEvent, DrawEvent, LifeGainEvent, MillEvent, PutCountersEvent,
+        downcast_event,
+    };
 
     match event.kind() {
         EventKind::Damage => {
@@ -1189,6 +1276,14 @@ fn apply_trait_double(event: &Event) -> Option<Event> {
             let draw = downcast_event::<DrawEvent>(event.inner())?;
             Some(event.rewrap(draw.doubled()))
         }
+        EventKind::Mill => {
+            let mill = downcast_event::<MillEvent>(event.inner())?;
+            Some(event.rewrap(mill.doubled()))
+        }
+        EventKind::CreateTokens => {
+            let create = downcast_event::<CreateTokensEvent>(event.inner())?;
+            Some(event.rewrap(create.doubled()))
+        }
         _ => None,
     }
 }
@@ -1343,14 +1438,21 @@ fn apply_trait_enter_as_copy(
 
 /// Apply redirect to a trait-based event.
 fn apply_trait_redirect(
+    game: &GameState,
     event: &Event,
     redirect_target: &crate::replacement::RedirectTarget,
     which: &crate::replacement::RedirectWhich,
     effect_controller: PlayerId,
-    _effect_source: crate::ids::ObjectId,
+    effect_source: crate::ids::ObjectId,
 ) -> Option<Event> {
-    let new_target =
-        resolve_trait_redirect_target(event.inner(), redirect_target, which, effect_controller)?;
+    let new_target = resolve_trait_redirect_target(
+        game,
+        event.inner(),
+        redirect_target,
+        which,
+        effect_controller,
+        effect_source,
+    )?;
     let redirectable = event.inner().redirectable_targets();
     let selected = match which {
         crate::replacement::RedirectWhich::First => redirectable.first(),
@@ -1366,10 +1468,12 @@ fn apply_trait_redirect(
 }
 
 fn resolve_trait_redirect_target(
+    game: &GameState,
     event: &dyn crate::events::traits::GameEventType,
     redirect_target: &crate::replacement::RedirectTarget,
     which: &crate::replacement::RedirectWhich,
     effect_controller: PlayerId,
+    effect_source: crate::ids::ObjectId,
 ) -> Option<crate::game_state::Target> {
     use crate::game_state::Target;
     use crate::replacement::{RedirectTarget, RedirectWhich};
@@ -1385,7 +1489,10 @@ fn resolve_trait_redirect_target(
         RedirectTarget::ToController => Target::Player(effect_controller),
         RedirectTarget::ToPlayer(player_id) => Target::Player(*player_id),
         RedirectTarget::ToObject(object_id) => Target::Object(*object_id),
-        RedirectTarget::ToSource => Target::Object(event.source_object()?),
+        RedirectTarget::ToSource => Target::Object(effect_source),
+        RedirectTarget::ToEventSourceController => {
+            Target::Player(game.object(event.source_object()?)?.controller)
+        }
     };
 
     if !selected.valid_redirect_types.is_valid(&new_target) {
@@ -1417,6 +1524,10 @@ pub enum TraitEventResult {
         player: PlayerId,
         applicable_effects: Vec<crate::replacement::ReplacementEffectId>,
         event: Box<Event>,
+        /// Effects already applied to this event. Each replacement effect
+        /// applies at most once per event (Rule 614.5), so processing must
+        /// resume with these excluded after the choice is made.
+        applied_effects: Vec<crate::replacement::ReplacementEffectId>,
     },
     /// An interactive replacement effect needs player input.
     ///
@@ -1846,7 +1957,21 @@ pub fn process_draw(
                 EventOutcome::Proceed(count)
             }
         }
-        TraitEventResult::Replaced { .. } => EventOutcome::Replaced,
+        TraitEventResult::Replaced { effects, effect_id } => {
+            let Some((source, controller)) = game
+                .replacement_effects
+                .get_effect(effect_id)
+                .map(|effect| (effect.source, effect.controller))
+            else {
+                return EventOutcome::Replaced;
+            };
+            game.replacement_effects.mark_effect_used(effect_id);
+            let mut ctx = crate::executor::ExecutionContext::new(source, controller, dm);
+            for effect in effects {
+                let _ = crate::executor::execute_effect(game, &effect, &mut ctx);
+            }
+            EventOutcome::Replaced
+        }
         TraitEventResult::NeedsChoice { .. } => {
             debug_assert!(false, "process_with_dm returned NeedsChoice for draw event");
             EventOutcome::Prevented
@@ -1862,6 +1987,86 @@ pub fn process_draw(
     }
 }
 
+/// Process a mill event through replacement effects.
+///
+/// Returns the number of cards the player should actually mill, which count
+/// replacements (e.g. "they mill twice that many cards instead") may change.
+pub fn process_mill(
+    game: &mut GameState,
+    player: PlayerId,
+    count: u32,
+    dm: &mut dyn DecisionMaker,
+) -> EventOutcome<u32> {
+    use crate::events::{MillEvent, downcast_event};
+
+    game.update_replacement_effects();
+
+    let event = Event::mill(player, count);
+    match process_with_dm(game, event, dm) {
+        TraitEventResult::Prevented => EventOutcome::Prevented,
+        TraitEventResult::Proceed(e) | TraitEventResult::Modified(e) => {
+            if let Some(mill) = downcast_event::<MillEvent>(e.inner()) {
+                EventOutcome::Proceed(mill.count)
+            } else {
+                EventOutcome::Proceed(count)
+            }
+        }
+        TraitEventResult::Replaced { effects, effect_id } => {
+            let Some((source, controller)) = game
+                .replacement_effects
+                .get_effect(effect_id)
+                .map(|effect| (effect.source, effect.controller))
+            else {
+                return EventOutcome::Replaced;
+            };
+            game.replacement_effects.mark_effect_used(effect_id);
+            let mut ctx = crate::executor::ExecutionContext::new(source, controller, dm);
+            for effect in effects {
+                let _ = crate::executor::execute_effect(game, &effect, &mut ctx);
+            }
+            EventOutcome::Replaced
+        }
+        TraitEventResult::NeedsChoice { .. } => {
+            debug_assert!(false, "process_with_dm returned NeedsChoice for mill event");
+            EventOutcome::Prevented
+        }
+        TraitEventResult::NeedsInteraction { .. } => {
+            debug_assert!(
+                false,
+                "interactive replacement unexpectedly matched mill event"
+            );
+            EventOutcome::Prevented
+        }
+    }
+}
+
+/// Process a "would create N tokens" event and return how many tokens to create.
+///
+/// Token doublers compose: each applicable replacement applies once, in the
+/// order the controller of the tokens chooses.
+pub fn process_create_tokens(
+    game: &mut GameState,
+    controller: PlayerId,
+    count: u32,
+    dm: &mut dyn DecisionMaker,
+) -> u32 {
+    use crate::events::{CreateTokensEvent, downcast_event};
+
+    if count == 0 {
+        return 0;
+    }
+    game.update_replacement_effects();
+
+    let event = Event::create_tokens(controller, count);
+    match process_with_dm(game, event, dm) {
+        TraitEventResult::Prevented => 0,
+        TraitEventResult::Proceed(e) | TraitEventResult::Modified(e) => {
+            downcast_event::<CreateTokensEvent>(e.inner()).map_or(count, |create| create.count)
+        }
+        _ => count,
+    }
+}
+
 /// Process an event through replacement effects, using a DecisionMaker to resolve choices.
 ///
 /// When `NeedsChoice` is returned (multiple effects at same priority), this function
@@ -1891,6 +2096,7 @@ fn process_with_dm(
                 player,
                 applicable_effects,
                 event: boxed_event,
+                ..
             } => {
                 // Determine which effect to apply
                 let chosen_index = {
@@ -2072,6 +2278,8 @@ pub struct ProcessedDamageAssignment {
 pub struct ProcessedDamageResult {
     pub assignments: Vec<ProcessedDamageAssignment>,
     pub replacement_prevented: bool,
+    /// Total damage prevented, for "if damage is prevented this way" effects.
+    pub prevented_amount: u32,
 }
 
 /// Process damage and return all final assignments after replacement/prevention.
@@ -2099,6 +2307,35 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
     amount: u32,
     is_combat: bool,
     source_snapshot: Option<&crate::snapshot::ObjectSnapshot>,
+) -> ProcessedDamageResult {
+    let mut dm = crate::decision::SelectFirstDecisionMaker;
+    process_damage_assignments_with_event_and_dm(
+        game,
+        source,
+        target,
+        amount,
+        is_combat,
+        source_snapshot,
+        &mut dm,
+    )
+}
+
+/// Process a damage event, letting the affected player choose the order of
+/// replacement and prevention effects (Rule 616.1e).
+///
+/// Replacement effects are applied one at a time, re-checking the remaining
+/// ones against the modified event. Prevention shields are then applied the
+/// same way: the affected player picks the next shield, it prevents what it
+/// can, and the rest are re-checked against the damage that remains. Each
+/// effect applies at most once to the event.
+pub fn process_damage_assignments_with_event_and_dm(
+    game: &mut GameState,
+    source: crate::ids::ObjectId,
+    target: DamageTarget,
+    amount: u32,
+    is_combat: bool,
+    source_snapshot: Option<&crate::snapshot::ObjectSnapshot>,
+    dm: &mut dyn DecisionMaker,
 ) -> ProcessedDamageResult {
     use crate::events::{DamageEvent, downcast_event};
 
@@ -2112,18 +2349,31 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
         Event::unpreventable_damage(source, target, amount, is_combat)
     };
 
+    // When a prevention shield and another replacement effect (such as a
+    // redirection) both apply, the affected player chooses which goes first
+    // (Rule 616.1). Preventing first leaves less damage to be replaced.
+    let (event, prevented_first) =
+        prevent_before_replacements_if_chosen(game, event, source_snapshot, can_prevent, dm);
+    let Some(event) = event else {
+        return ProcessedDamageResult {
+            assignments: Vec::new(),
+            replacement_prevented: false,
+            prevented_amount: prevented_first,
+        };
+    };
+
     // Process through the trait-based system, retaining event provenance for
     // replacement-generated effect execution.
     let event = game.ensure_event_provenance(event);
     let event_provenance = event.provenance();
-    let mut state = TraitEventProcessingState::default();
-    let result = process_event_direct(game, event, &mut state, &[]);
+    let result = process_with_dm(game, event, dm);
 
     let replaced = match result {
         TraitEventResult::Prevented => {
             return ProcessedDamageResult {
                 assignments: Vec::new(),
                 replacement_prevented: true,
+                prevented_amount: amount,
             };
         }
         TraitEventResult::Replaced { effects, effect_id } => {
@@ -2139,11 +2389,10 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
                     (source, controller)
                 });
 
-            let mut dm = crate::decision::AutoPassDecisionMaker;
             let mut exec_ctx = crate::executor::ExecutionContext::new(
                 replacement_source,
                 replacement_controller,
-                &mut dm,
+                dm,
             )
             .with_cause(crate::events::cause::EventCause::from_effect(
                 replacement_source,
@@ -2164,6 +2413,7 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
             return ProcessedDamageResult {
                 assignments: Vec::new(),
                 replacement_prevented: true,
+                prevented_amount: 0,
             };
         }
         TraitEventResult::Proceed(e) | TraitEventResult::Modified(e) => {
@@ -2188,8 +2438,10 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
         replaced.is_combat,
         replaced.source,
         source_snapshot,
-        can_prevent,
+        can_prevent && !replaced.is_unpreventable,
+        dm,
     );
+    let mut prevented_amount = prevented_first + replaced.amount - final_damage;
     if final_damage > 0 {
         assignments.push(ProcessedDamageAssignment {
             target: replaced.target,
@@ -2200,20 +2452,23 @@ pub fn process_damage_assignments_with_event_with_source_snapshot(
     if let Some((remainder_target, remainder_amount)) = replaced.remainder
         && remainder_amount > 0
     {
-        let remainder = process_damage_assignments_with_event_with_source_snapshot(
+        let remainder = process_damage_assignments_with_event_and_dm(
             game,
             replaced.source,
             remainder_target,
             remainder_amount,
             replaced.is_combat,
             source_snapshot,
+            dm,
         );
         assignments.extend(remainder.assignments);
+        prevented_amount += remainder.prevented_amount;
     }
 
     ProcessedDamageResult {
         assignments,
         replacement_prevented: false,
+        prevented_amount,
     }
 }
 
@@ -2243,19 +2498,128 @@ pub fn process_damage_with_event_with_source_snapshot(
     (original_target_damage, processed.replacement_prevented)
 }
 
-fn apply_prevention_for_damage_assignment(
+/// Offer the affected player the choice of applying prevention shields to
+/// `event` before any other replacement effect that applies to it.
+///
+/// Returns the event to keep processing (`None` once everything has been
+/// prevented) and how much damage was prevented up front. Self-replacement
+/// effects always apply first (Rule 616.1a), so no choice is offered then.
+fn prevent_before_replacements_if_chosen(
     game: &mut GameState,
-    target: DamageTarget,
-    amount: u32,
-    is_combat: bool,
-    source: crate::ids::ObjectId,
+    event: Event,
     source_snapshot: Option<&crate::snapshot::ObjectSnapshot>,
     can_prevent: bool,
-) -> u32 {
-    if amount == 0 {
-        return 0;
+    dm: &mut dyn DecisionMaker,
+) -> (Option<Event>, u32) {
+    use crate::decisions::{
+        make_decision,
+        specs::{ReplacementOption, ReplacementSpec},
+    };
+    use crate::events::{DamageEvent, downcast_event};
+
+    let Some(damage) = downcast_event::<DamageEvent>(event.inner()).cloned() else {
+        return (Some(event), 0);
+    };
+    if !can_prevent || damage.is_unpreventable || damage.amount == 0 {
+        return (Some(event), 0);
     }
 
+    let (affected_player, shields) = applicable_damage_shields(
+        game,
+        damage.target,
+        damage.is_combat,
+        damage.source,
+        source_snapshot,
+    );
+    let Some(shield_source) = shields.first().and_then(|&id| {
+        game.prevention_effects
+            .shields()
+            .iter()
+            .find(|shield| shield.id == id)
+            .map(|shield| shield.source)
+    }) else {
+        return (Some(event), 0);
+    };
+    let replacements = find_applicable_trait_replacements(
+        game,
+        &event,
+        &TraitEventProcessingState::default(),
+        &[],
+    );
+    if replacements.is_empty()
+        || replacements
+            .iter()
+            .any(|(_, priority)| *priority != ReplacementPriority::Other)
+    {
+        return (Some(event), 0);
+    }
+
+    let mut options: Vec<ReplacementOption> = replacements
+        .iter()
+        .enumerate()
+        .map(|(idx, (effect, _))| {
+            ReplacementOption::new(
+                idx,
+                effect.source,
+                effect
+                    .matcher
+                    .as_ref()
+                    .map(|m| m.display())
+                    .unwrap_or_else(|| "Unknown effect".to_string()),
+            )
+        })
+        .collect();
+    let prevent_index = options.len();
+    options.push(ReplacementOption::new(
+        prevent_index,
+        shield_source,
+        "Prevent the damage",
+    ));
+    let chosen = make_decision(
+        game,
+        dm,
+        affected_player,
+        None,
+        ReplacementSpec::new(options),
+    );
+    if chosen != prevent_index {
+        return (Some(event), 0);
+    }
+
+    let remaining = apply_prevention_for_damage_assignment(
+        game,
+        damage.target,
+        damage.amount,
+        damage.is_combat,
+        damage.source,
+        source_snapshot,
+        true,
+        dm,
+    );
+    let prevented = damage.amount - remaining;
+    if remaining == 0 {
+        return (None, prevented);
+    }
+    (
+        Some(Event::damage(
+            damage.source,
+            damage.target,
+            remaining,
+            damage.is_combat,
+        )),
+        prevented,
+    )
+}
+
+/// Prevention shields that apply to damage dealt to `target`, along with the
+/// player who chooses among them (the affected player, Rule 616.1).
+fn applicable_damage_shields(
+    game: &GameState,
+    target: DamageTarget,
+    is_combat: bool,
+    source: crate::ids::ObjectId,
+    source_snapshot: Option<&crate::snapshot::ObjectSnapshot>,
+) -> (PlayerId, Vec<crate::prevention::PreventionShieldId>) {
     let (source_colors, source_card_types) = if let Some(obj) = game.object(source) {
         (obj.colors(), obj.card_types.clone())
     } else if let Some(snapshot) = source_snapshot {
@@ -2264,33 +2628,115 @@ fn apply_prevention_for_damage_assignment(
         (crate::color::ColorSet::COLORLESS, Vec::new())
     };
 
-    match target {
-        DamageTarget::Player(player_id) => game.prevention_effects.apply_prevention_to_player(
+    let (affected_player, candidates) = match target {
+        DamageTarget::Player(player_id) => (
             player_id,
-            amount,
-            is_combat,
-            source,
-            &source_colors,
-            &source_card_types,
-            can_prevent,
+            game.prevention_effects.applicable_shields_for_player(
+                player_id,
+                is_combat,
+                source,
+                &source_colors,
+                &source_card_types,
+            ),
         ),
         DamageTarget::Object(object_id) => {
             let controller = game
                 .object(object_id)
                 .map(|o| o.controller)
                 .unwrap_or(game.turn.active_player);
-            game.prevention_effects.apply_prevention_to_permanent(
-                object_id,
+            (
                 controller,
-                amount,
-                is_combat,
-                source,
-                &source_colors,
-                &source_card_types,
-                can_prevent,
+                game.prevention_effects.applicable_shields_for_permanent(
+                    object_id,
+                    controller,
+                    is_combat,
+                    source,
+                    &source_colors,
+                    &source_card_types,
+                ),
             )
         }
+    };
+
+    let damaged_permanent = match target {
+        DamageTarget::Object(object_id) => Some(object_id),
+        DamageTarget::Player(_) => None,
+    };
+    let candidates = game.prevention_effects.retain_shields_matching_game(
+        game,
+        candidates,
+        damaged_permanent,
+        source,
+        source_snapshot,
+    );
+    (affected_player, candidates)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn apply_prevention_for_damage_assignment(
+    game: &mut GameState,
+    target: DamageTarget,
+    amount: u32,
+    is_combat: bool,
+    source: crate::ids::ObjectId,
+    source_snapshot: Option<&crate::snapshot::ObjectSnapshot>,
+    can_prevent: bool,
+    dm: &mut dyn DecisionMaker,
+) -> u32 {
+    use crate::decisions::{
+        make_decision,
+        specs::{ReplacementOption, ReplacementSpec},
+    };
+
+    // Damage that can't be prevented passes through shields untouched (Rule 615.12).
+    if amount == 0 || !can_prevent {
+        return amount;
     }
+
+    let (affected_player, mut candidates) =
+        applicable_damage_shields(game, target, is_combat, source, source_snapshot);
+
+    let mut remaining = amount;
+    while remaining > 0 && !candidates.is_empty() {
+        // Prevention shields are "other" replacement effects, so the affected
+        // player chooses which one applies next (Rule 616.1e).
+        let chosen_index = if candidates.len() > 1 {
+            let options: Vec<ReplacementOption> = candidates
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, &id)| {
+                    let shield = game
+                        .prevention_effects
+                        .shields()
+                        .iter()
+                        .find(|s| s.id == id)?;
+                    let description = match shield.amount_remaining {
+                        Some(n) if n != u32::MAX => format!("Prevent the next {n} damage"),
+                        _ => "Prevent the damage".to_string(),
+                    };
+                    Some(ReplacementOption::new(idx, shield.source, description))
+                })
+                .collect();
+            make_decision(
+                game,
+                dm,
+                affected_player,
+                None,
+                ReplacementSpec::new(options),
+            )
+            .min(candidates.len() - 1)
+        } else {
+            0
+        };
+
+        let shield_id = candidates.remove(chosen_index);
+        remaining = game
+            .prevention_effects
+            .apply_shield(shield_id, remaining, can_prevent);
+    }
+
+    game.prevention_effects.cleanup_exhausted();
+    remaining
 }
 
 /// Process a life gain event using the new Event type.
@@ -2389,15 +2835,29 @@ pub fn process_put_counters_with_event(
     target: crate::ids::ObjectId,
     counter_type: CounterType,
     count: u32,
+) -> u32 {
+    let mut dm = crate::decision::SelectFirstDecisionMaker;
+    process_put_counters_with_event_and_dm(game, target, counter_type, count, &mut dm)
+}
+
+/// Process a put counters event, asking `dm` to order competing replacements
+/// (e.g. Hardened Scales and Doubling Season on the same permanent).
+pub fn process_put_counters_with_event_and_dm(
+    game: &mut GameState,
+    target: crate::ids::ObjectId,
+    counter_type: CounterType,
+    count: u32,
+    dm: &mut (impl DecisionMaker + ?Sized),
 ) -> u32 {
     use crate::events::{PutCountersEvent, downcast_event};
 
     if !game.can_have_counters_placed(target) {
         return 0;
     }
+    game.update_replacement_effects();
 
     let event = Event::put_counters(target, counter_type, count);
-    let result = process_trait_event(game, event);
+    let result = process_with_dm(game, event, dm);
 
     match result {
         TraitEventResult::Prevented => 0,
@@ -2431,6 +2891,21 @@ pub fn process_etb_with_event_and_dm(
     object: crate::ids::ObjectId,
     from: Zone,
     dm: &mut dyn DecisionMaker,
+) -> EtbEventResult {
+    process_etb_under_control_with_event_and_dm(game, object, from, None, dm)
+}
+
+/// Process an ETB event for an object entering under `controller`'s control.
+///
+/// Controller-relative replacements ("creatures your opponents control enter
+/// tapped") are evaluated against `controller` rather than the object's
+/// current controller. `None` keeps the current controller.
+pub fn process_etb_under_control_with_event_and_dm(
+    game: &mut GameState,
+    object: crate::ids::ObjectId,
+    from: Zone,
+    controller: Option<PlayerId>,
+    dm: &mut dyn DecisionMaker,
 ) -> EtbEventResult {
     use crate::ability::AbilityKind;
     use crate::decisions::{
@@ -2459,7 +2934,7 @@ pub fn process_etb_with_event_and_dm(
             // effects can modify it (e.g., Doubling Season).
             enters_with_counters.push((CounterType::Loyalty, loyalty));
         }
-        let controller = obj.controller;
+        let controller = controller.unwrap_or(obj.controller);
         for ability in &obj.abilities {
             if let AbilityKind::Static(s) = &ability.kind {
                 if s.enters_tapped() {
@@ -2523,6 +2998,7 @@ pub fn process_etb_with_event_and_dm(
             enters_with_counters,
             enters_as_copy_of: None,
             added_subtypes: Vec::new(),
+            controller,
         },
         etb_event_provenance,
     );
@@ -2587,6 +3063,7 @@ pub fn process_etb_with_event_and_dm(
                 player,
                 applicable_effects,
                 event,
+                ..
             } => {
                 let options: Vec<ReplacementOption> = applicable_effects
                     .iter()
@@ -2818,6 +3295,7 @@ pub fn process_zone_change_full(
             player,
             applicable_effects,
             event,
+            ..
         } => ZoneChangeResult::NeedsChoice {
             player,
             applicable_effects,
@@ -2881,6 +3359,7 @@ pub fn process_draw_full(
             player,
             applicable_effects,
             event,
+            ..
         } => DrawResult::NeedsChoice {
             player,
             applicable_effects,
@@ -2895,29 +3374,35 @@ pub fn process_draw_full(
 /// Process an event with a chosen replacement effect, using the new Event type.
 ///
 /// When a player chooses which replacement effect to apply (per Rule 616.1e),
-/// this function applies that effect and continues processing.
+/// this function applies that effect and continues processing. The remaining
+/// effects are re-checked against the modified event, skipping
+/// `applied_effects` (the effects already applied before the choice) and the
+/// chosen one so that each applies at most once.
 pub fn process_event_with_chosen_replacement_trait(
     game: &mut GameState,
     event: Event,
     chosen_effect_id: ReplacementEffectId,
+    applied_effects: &[ReplacementEffectId],
 ) -> TraitEventResult {
     let event = game.ensure_event_provenance(event);
+    let mut state = TraitEventProcessingState::default();
+    for &id in applied_effects {
+        state.mark_applied(id);
+    }
+
     // Get the chosen effect
     let Some(effect) = game
         .replacement_effects
         .get_effect(chosen_effect_id)
         .cloned()
     else {
-        // Effect no longer exists - just process normally
-        return process_trait_event(game, event);
+        // Effect no longer exists - continue with the remaining effects
+        return process_event_direct(game, event, &mut state, &[]);
     };
 
     // Apply the chosen replacement effect
     let apply_result = apply_trait_replacement(game, event.clone(), &effect);
     consume_one_shot_if_applied(game, chosen_effect_id, &apply_result);
-
-    // Create state with the chosen effect marked as applied
-    let mut state = TraitEventProcessingState::default();
     state.mark_applied(chosen_effect_id);
 
     match apply_result {
@@ -2956,3 +3441,476 @@ pub fn process_event_with_chosen_replacement_trait(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::Ability;
+    use crate::card::CardBuilder;
+    use crate::ids::{CardId, ObjectId};
+    use crate::prevention::{PreventionShield, PreventionTarget};
+    use crate::static_abilities::StaticAbility;
+    use crate::target::ObjectFilter;
+
+    /// Picks a fixed option whenever asked which replacement applies next.
+    struct ChooseReplacement {
+        index: usize,
+        choices: usize,
+    }
+
+    impl ChooseReplacement {
+        fn new(index: usize) -> Self {
+            Self { index, choices: 0 }
+        }
+    }
+
+    impl DecisionMaker for ChooseReplacement {
+        fn decide_options(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::SelectOptionsContext,
+        ) -> Vec<usize> {
+            self.choices += 1;
+            vec![self.index]
+        }
+    }
+
+    fn setup_game() -> GameState {
+        crate::tests::test_helpers::setup_two_player_game()
+    }
+
+    fn create_static_source(
+        game: &mut GameState,
+        owner: PlayerId,
+        ability: StaticAbility,
+    ) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Static Source")
+            .card_types(vec![CardType::Artifact])
+            .build();
+        let id = game.create_object_from_card(&card, owner, Zone::Battlefield);
+        game.object_mut(id)
+            .unwrap()
+            .abilities
+            .push(Ability::static_ability(ability));
+        id
+    }
+
+    /// A land entering with both "lands enter tapped" and "lands you control
+    /// enter untapped" in play, applying the replacement at `chosen` first.
+    fn land_enters_tapped_with_choice(chosen: usize) -> (bool, usize) {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        create_static_source(
+            &mut game,
+            alice,
+            StaticAbility::enters_tapped_for_filter(ObjectFilter::land()),
+        );
+        create_static_source(
+            &mut game,
+            alice,
+            StaticAbility::enters_untapped_for_filter(ObjectFilter::land().you_control()),
+        );
+        let card = CardBuilder::new(CardId::new(), "Land")
+            .card_types(vec![CardType::Land])
+            .build();
+        let land = game.create_object_from_card(&card, alice, Zone::Hand);
+
+        let mut dm = ChooseReplacement::new(chosen);
+        let result = process_etb_with_event_and_dm(&mut game, land, Zone::Hand, &mut dm);
+        (result.enters_tapped, dm.choices)
+    }
+
+    #[test]
+    fn enters_tapped_and_enters_untapped_follow_the_chosen_order() {
+        // Tapped first, then untapped: the later effect wins.
+        assert_eq!(land_enters_tapped_with_choice(0), (false, 1));
+        // Untapped first, then tapped.
+        assert_eq!(land_enters_tapped_with_choice(1), (true, 1));
+    }
+
+    #[test]
+    fn chosen_replacement_does_not_reapply_effects_already_applied() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        create_static_source(
+            &mut game,
+            alice,
+            StaticAbility::enters_tapped_for_filter(ObjectFilter::land()),
+        );
+        create_static_source(
+            &mut game,
+            alice,
+            StaticAbility::enters_untapped_for_filter(ObjectFilter::land().you_control()),
+        );
+        game.update_replacement_effects();
+        let card = CardBuilder::new(CardId::new(), "Land")
+            .card_types(vec![CardType::Land])
+            .build();
+        let land = game.create_object_from_card(&card, alice, Zone::Hand);
+
+        let TraitEventResult::NeedsChoice {
+            applicable_effects,
+            event,
+            applied_effects,
+            ..
+        } = process_trait_event(
+            &mut game,
+            Event::zone_change(land, Zone::Hand, Zone::Battlefield, None),
+        )
+        else {
+            panic!("both enter replacements should need a choice");
+        };
+        assert!(applied_effects.is_empty());
+
+        // "Enters tapped" already applied before the choice, so choosing
+        // "enters untapped" must not let it apply again.
+        let (tapped, untapped) = (applicable_effects[0], applicable_effects[1]);
+        let tapped_event = apply_trait_enter_tapped(&event).unwrap();
+        let result = process_event_with_chosen_replacement_trait(
+            &mut game,
+            tapped_event,
+            untapped,
+            &[tapped],
+        );
+        let etb = result.into_event().expect("land should still enter");
+        let etb =
+            crate::events::downcast_event::<crate::events::EnterBattlefieldEvent>(etb.inner())
+                .unwrap();
+        assert!(!etb.enters_tapped);
+    }
+
+    /// Deal 3 damage to Alice, who has a "prevent the next 2" and a "prevent
+    /// the next 3" shield, choosing which shield applies first.
+    fn shields_after_damage(chosen: usize) -> (u32, Vec<Option<u32>>) {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let first_source = game.new_object_id();
+        let second_source = game.new_object_id();
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                first_source,
+                alice,
+                PreventionTarget::You,
+                2,
+            ));
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                second_source,
+                alice,
+                PreventionTarget::You,
+                3,
+            ));
+        let card = CardBuilder::new(CardId::new(), "Shock Source")
+            .card_types(vec![CardType::Instant])
+            .build();
+        let source = game.create_object_from_card(&card, bob, Zone::Stack);
+
+        let mut dm = ChooseReplacement::new(chosen);
+        let processed = process_damage_assignments_with_event_and_dm(
+            &mut game,
+            source,
+            DamageTarget::Player(alice),
+            3,
+            false,
+            None,
+            &mut dm,
+        );
+        let dealt = processed.assignments.iter().map(|a| a.amount).sum();
+        let remaining = [first_source, second_source]
+            .iter()
+            .map(|&src| {
+                game.prevention_effects
+                    .shields()
+                    .iter()
+                    .find(|shield| shield.source == src)
+                    .and_then(|shield| shield.amount_remaining)
+            })
+            .collect();
+        (dealt, remaining)
+    }
+
+    #[test]
+    fn two_prevention_shields_apply_in_the_chosen_order() {
+        // The 2-shield is used up and the 3-shield prevents the last point.
+        assert_eq!(shields_after_damage(0), (0, vec![None, Some(2)]));
+        // The 3-shield absorbs everything and the 2-shield is untouched.
+        assert_eq!(shields_after_damage(1), (0, vec![Some(2), None]));
+    }
+
+    fn create_damage_source(
+        game: &mut GameState,
+        owner: PlayerId,
+        color: crate::mana::ManaSymbol,
+    ) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Damage Source")
+            .card_types(vec![CardType::Instant])
+            .mana_cost(crate::mana::ManaCost::from_pips(vec![vec![color]]))
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Stack)
+    }
+
+    fn create_creature(game: &mut GameState, owner: PlayerId) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Bear")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 4))
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Battlefield)
+    }
+
+    #[test]
+    fn redirected_damage_keeps_combat_and_unpreventable_flags() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let attacker = create_creature(&mut game, alice);
+        let giant = create_creature(&mut game, bob);
+        game.object_mut(giant)
+            .unwrap()
+            .abilities
+            .push(Ability::static_ability(
+                StaticAbility::redirect_damage_from_you_to_source(),
+            ));
+        game.update_replacement_effects();
+
+        let event = Event::unpreventable_damage(attacker, DamageTarget::Player(bob), 4, true);
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        let (TraitEventResult::Modified(event) | TraitEventResult::Proceed(event)) =
+            process_with_dm(&mut game, event, &mut dm)
+        else {
+            panic!("damage should be redirected, not replaced");
+        };
+        let damage = crate::events::downcast_event::<crate::events::DamageEvent>(event.inner())
+            .expect("damage event");
+
+        assert_eq!(damage.target, DamageTarget::Object(giant));
+        assert_eq!(damage.source, attacker);
+        assert_eq!(damage.amount, 4);
+        assert!(damage.is_combat);
+        assert!(damage.is_unpreventable);
+    }
+
+    #[test]
+    fn three_point_shield_absorbs_two_hits_and_lets_one_through() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bear = create_creature(&mut game, alice);
+        let shield_source = game.new_object_id();
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                shield_source,
+                alice,
+                PreventionTarget::Permanent(bear),
+                3,
+            ));
+        let source = create_damage_source(&mut game, bob, crate::mana::ManaSymbol::Red);
+        let target = DamageTarget::Object(bear);
+
+        let first = process_damage_assignments_with_event(&mut game, source, target, 2, false);
+        assert!(first.assignments.is_empty());
+        assert_eq!(first.prevented_amount, 2);
+        assert_eq!(
+            game.prevention_effects.shields()[0].amount_remaining,
+            Some(1)
+        );
+
+        let second = process_damage_assignments_with_event(&mut game, source, target, 2, false);
+        assert_eq!(
+            second.assignments,
+            vec![ProcessedDamageAssignment { target, amount: 1 }]
+        );
+        assert_eq!(second.prevented_amount, 1);
+        assert!(game.prevention_effects.shields().is_empty());
+    }
+
+    #[test]
+    fn source_filtered_shield_only_prevents_matching_sources() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let shield_source = game.new_object_id();
+        game.prevention_effects.add_shield(
+            PreventionShield::prevent_all(shield_source, alice, PreventionTarget::All).with_filter(
+                crate::prevention::DamageFilter {
+                    from_source: Some(
+                        ObjectFilter::default().with_colors(crate::color::ColorSet::RED),
+                    ),
+                    ..Default::default()
+                },
+            ),
+        );
+        let red = create_damage_source(&mut game, bob, crate::mana::ManaSymbol::Red);
+        let green = create_damage_source(&mut game, bob, crate::mana::ManaSymbol::Green);
+        let target = DamageTarget::Player(alice);
+
+        let from_red = process_damage_assignments_with_event(&mut game, red, target, 3, false);
+        assert!(from_red.assignments.is_empty());
+        assert_eq!(from_red.prevented_amount, 3);
+
+        let from_green = process_damage_assignments_with_event(&mut game, green, target, 3, false);
+        assert_eq!(
+            from_green.assignments,
+            vec![ProcessedDamageAssignment { target, amount: 3 }]
+        );
+        assert_eq!(from_green.prevented_amount, 0);
+    }
+
+    #[test]
+    fn shields_expire_with_their_duration() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let shield_source = game.new_object_id();
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                shield_source,
+                alice,
+                PreventionTarget::You,
+                3,
+            ));
+        game.prevention_effects.add_shield(PreventionShield::new(
+            shield_source,
+            alice,
+            PreventionTarget::You,
+            Some(3),
+            crate::effect::Until::YourNextTurn,
+        ));
+
+        crate::turn::execute_cleanup_step(&mut game);
+        assert_eq!(game.prevention_effects.shields().len(), 1);
+        assert_eq!(
+            game.prevention_effects.shields()[0].duration,
+            crate::effect::Until::YourNextTurn
+        );
+
+        // Bob's turn: Alice's shield still protects her.
+        game.next_turn();
+        assert_eq!(game.prevention_effects.shields().len(), 1);
+
+        // Alice's next turn ends it.
+        game.next_turn();
+        assert!(game.prevention_effects.shields().is_empty());
+    }
+
+    /// Bob controls a 5-loyalty planeswalker with "All damage that would be
+    /// dealt to you is dealt to this instead", and Alice has a Lightning Bolt
+    /// on the stack targeting Bob.
+    fn bolt_at_bob_with_redirecting_planeswalker(game: &mut GameState) -> ObjectId {
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let walker = CardBuilder::new(CardId::new(), "Redirecting Walker")
+            .card_types(vec![CardType::Planeswalker])
+            .loyalty(5)
+            .build();
+        let walker = game.create_object_from_card(&walker, bob, Zone::Battlefield);
+        game.object_mut(walker)
+            .unwrap()
+            .abilities
+            .push(Ability::static_ability(
+                StaticAbility::redirect_damage_from_you_to_source(),
+            ));
+        game.update_replacement_effects();
+
+        let bolt = game.create_object_from_definition(
+            &crate::cards::definitions::lightning_bolt(),
+            alice,
+            Zone::Stack,
+        );
+        game.push_to_stack(
+            crate::game_state::StackEntry::new(bolt, alice)
+                .with_targets(vec![crate::game_state::Target::Player(bob)]),
+        );
+        walker
+    }
+
+    fn loyalty(game: &GameState, walker: ObjectId) -> u32 {
+        game.object(walker)
+            .and_then(|obj| obj.counters.get(&CounterType::Loyalty).copied())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn lightning_bolt_at_player_is_redirected_to_planeswalker() {
+        let mut game = setup_game();
+        let bob = PlayerId::from_index(1);
+        let walker = bolt_at_bob_with_redirecting_planeswalker(&mut game);
+
+        crate::game_loop::resolve_stack_entry(&mut game).expect("bolt resolves");
+
+        assert_eq!(game.player(bob).unwrap().life, 20);
+        assert_eq!(loyalty(&game, walker), 2);
+    }
+
+    #[test]
+    fn affected_player_chooses_prevention_or_redirection_first() {
+        // Option 0 redirects first: the shield protects Bob, not the
+        // planeswalker, so all 3 damage lands there and the shield survives.
+        let mut game = setup_game();
+        let bob = PlayerId::from_index(1);
+        let walker = bolt_at_bob_with_redirecting_planeswalker(&mut game);
+        let shield_source = game.new_object_id();
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                shield_source,
+                bob,
+                PreventionTarget::You,
+                2,
+            ));
+        let mut dm = ChooseReplacement::new(0);
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).expect("bolt resolves");
+        assert_eq!(dm.choices, 1);
+        assert_eq!(game.player(bob).unwrap().life, 20);
+        assert_eq!(loyalty(&game, walker), 2);
+        assert_eq!(
+            game.prevention_effects.shields()[0].amount_remaining,
+            Some(2)
+        );
+
+        // Option 1 prevents first: 2 damage is prevented and only the last
+        // point is redirected.
+        let mut game = setup_game();
+        let walker = bolt_at_bob_with_redirecting_planeswalker(&mut game);
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_next_n(
+                shield_source,
+                bob,
+                PreventionTarget::You,
+                2,
+            ));
+        let mut dm = ChooseReplacement::new(1);
+        crate::game_loop::resolve_stack_entry_with(&mut game, &mut dm).expect("bolt resolves");
+        assert_eq!(game.player(bob).unwrap().life, 20);
+        assert_eq!(loyalty(&game, walker), 4);
+        assert!(game.prevention_effects.shields().is_empty());
+    }
+
+    #[test]
+    fn damage_cant_be_prevented_static_disables_shields() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        create_static_source(&mut game, alice, StaticAbility::damage_cant_be_prevented());
+        game.update_cant_effects();
+        let shield_source = game.new_object_id();
+        game.prevention_effects
+            .add_shield(PreventionShield::prevent_all(
+                shield_source,
+                bob,
+                PreventionTarget::You,
+            ));
+        let bolt = game.create_object_from_definition(
+            &crate::cards::definitions::lightning_bolt(),
+            alice,
+            Zone::Stack,
+        );
+        game.push_to_stack(
+            crate::game_state::StackEntry::new(bolt, alice)
+                .with_targets(vec![crate::game_state::Target::Player(bob)]),
+        );
+
+        crate::game_loop::resolve_stack_entry(&mut game).expect("bolt resolves");
+
+        assert_eq!(game.player(bob).unwrap().life, 17);
+        assert_eq!(game.prevention_effects.shields().len(), 1);
+    }
+}
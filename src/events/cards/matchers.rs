@@ -1,11 +1,11 @@
-//! Card draw/discard replacement effect matchers.
+//! Card draw/discard/mill replacement effect matchers.
 
 use crate::events::cause::CauseFilter;
 use crate::events::context::EventContext;
 use crate::events::traits::{EventKind, GameEventType, ReplacementMatcher, downcast_event};
 use crate::target::PlayerFilter;
 
-use super::{DiscardEvent, DrawEvent};
+use super::{DiscardEvent, DrawEvent, MillEvent};
 
 /// Matches when a player matching the filter would draw a card.
 #[derive(Debug, Clone)]
@@ -58,6 +58,88 @@ impl ReplacementMatcher for WouldDrawCardMatcher {
     }
 }
 
+/// Matches when a player would draw a card while their library has no cards in it.
+#[derive(Debug, Clone)]
+pub struct WouldDrawFromEmptyLibraryMatcher {
+    pub player_filter: PlayerFilter,
+}
+
+impl WouldDrawFromEmptyLibraryMatcher {
+    pub fn new(player_filter: PlayerFilter) -> Self {
+        Self { player_filter }
+    }
+
+    /// Matches when you would draw a card with an empty library.
+    pub fn you() -> Self {
+        Self::new(PlayerFilter::You)
+    }
+}
+
+impl ReplacementMatcher for WouldDrawFromEmptyLibraryMatcher {
+    fn matches_event(&self, event: &dyn GameEventType, ctx: &EventContext) -> bool {
+        if event.event_kind() != EventKind::Draw {
+            return false;
+        }
+
+        let Some(draw) = downcast_event::<DrawEvent>(event) else {
+            return false;
+        };
+
+        self.player_filter
+            .matches_player(draw.player, &ctx.filter_ctx)
+            && ctx
+                .game
+                .player(draw.player)
+                .is_some_and(|player| player.library.is_empty())
+    }
+
+    fn display(&self) -> String {
+        "When you would draw a card while your library has no cards in it".to_string()
+    }
+}
+
+/// Matches when a player matching the filter would mill one or more cards.
+#[derive(Debug, Clone)]
+pub struct WouldMillMatcher {
+    pub player_filter: PlayerFilter,
+}
+
+impl WouldMillMatcher {
+    pub fn new(player_filter: PlayerFilter) -> Self {
+        Self { player_filter }
+    }
+
+    /// Matches when an opponent would mill one or more cards.
+    pub fn opponent() -> Self {
+        Self::new(PlayerFilter::Opponent)
+    }
+}
+
+impl ReplacementMatcher for WouldMillMatcher {
+    fn matches_event(&self, event: &dyn GameEventType, ctx: &EventContext) -> bool {
+        if event.event_kind() != EventKind::Mill {
+            return false;
+        }
+
+        let Some(mill) = downcast_event::<MillEvent>(event) else {
+            return false;
+        };
+
+        mill.count > 0
+            && self
+                .player_filter
+                .matches_player(mill.player, &ctx.filter_ctx)
+    }
+
+    fn display(&self) -> String {
+        match &self.player_filter {
+            PlayerFilter::You => "When you would mill one or more cards".to_string(),
+            PlayerFilter::Opponent => "When an opponent would mill one or more cards".to_string(),
+            _ => "When a player would mill one or more cards".to_string(),
+        }
+    }
+}
+
 /// Matches when a player would draw their first card each turn.
 #[derive(Debug, Clone)]
 pub struct WouldDrawFirstCardMatcher {
@@ -236,6 +318,27 @@ mod tests {
         assert!(!matcher.matches_event(&event_not_first, &ctx));
     }
 
+    #[test]
+    fn test_would_draw_from_empty_library_matcher() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+
+        let matcher = WouldDrawFromEmptyLibraryMatcher::you();
+        let event = DrawEvent::new(alice, 1, false);
+
+        // Empty library - should match
+        let ctx = EventContext::for_controller(alice, &game);
+        assert!(matcher.matches_event(&event, &ctx));
+
+        // A card in the library - should not match
+        let card = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Library Card")
+            .card_types(vec![crate::types::CardType::Instant])
+            .build();
+        game.create_object_from_card(&card, alice, crate::zone::Zone::Library);
+        let ctx = EventContext::for_controller(alice, &game);
+        assert!(!matcher.matches_event(&event, &ctx));
+    }
+
     #[test]
     fn test_would_discard_matcher() {
         let game = setup_game();
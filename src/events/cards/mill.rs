@@ -0,0 +1,118 @@
+//! Mill event implementation.
+
+use std::any::Any;
+
+use crate::events::traits::{EventKind, GameEventType, RedirectValidTypes, RedirectableTarget};
+use crate::game_state::{GameState, Target};
+use crate::ids::{ObjectId, PlayerId};
+
+/// A mill event that can be processed through the replacement effect system.
+///
+/// This is the "would mill N cards" event replacement effects see before any card
+/// moves (e.g. "If an opponent would mill one or more cards, they mill twice that
+/// many cards instead"). The individual cards still move through zone changes.
+#[derive(Debug, Clone)]
+pub struct MillEvent {
+    /// The player milling
+    pub player: PlayerId,
+    /// Number of cards to mill
+    pub count: u32,
+}
+
+impl MillEvent {
+    /// Create a new mill event.
+    pub fn new(player: PlayerId, count: u32) -> Self {
+        Self { player, count }
+    }
+
+    /// Return a new event with doubled mill count.
+    pub fn doubled(&self) -> Self {
+        self.with_count(self.count.saturating_mul(2))
+    }
+
+    /// Return a new event with a different count.
+    pub fn with_count(&self, count: u32) -> Self {
+        Self {
+            count,
+            ..self.clone()
+        }
+    }
+
+    /// Return a new event with a different player.
+    pub fn with_player(&self, player: PlayerId) -> Self {
+        Self {
+            player,
+            ..self.clone()
+        }
+    }
+}
+
+impl GameEventType for MillEvent {
+    fn event_kind(&self) -> EventKind {
+        EventKind::Mill
+    }
+
+    fn affected_player(&self, _game: &GameState) -> PlayerId {
+        self.player
+    }
+
+    fn redirectable_targets(&self) -> Vec<RedirectableTarget> {
+        vec![RedirectableTarget {
+            target: Target::Player(self.player),
+            description: "mill target",
+            valid_redirect_types: RedirectValidTypes::PlayersOnly,
+        }]
+    }
+
+    fn with_target_replaced(&self, old: &Target, new: &Target) -> Option<Box<dyn GameEventType>> {
+        if &Target::Player(self.player) != old {
+            return None;
+        }
+
+        if let Target::Player(new_player) = new {
+            Some(Box::new(self.with_player(*new_player)))
+        } else {
+            None
+        }
+    }
+
+    fn source_object(&self) -> Option<ObjectId> {
+        None
+    }
+
+    fn display(&self) -> String {
+        if self.count == 1 {
+            "Mill a card".to_string()
+        } else {
+            format!("Mill {} cards", self.count)
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mill_event_doubled() {
+        let event = MillEvent::new(PlayerId::from_index(1), 3);
+        assert_eq!(event.event_kind(), EventKind::Mill);
+        assert_eq!(event.doubled().count, 6);
+    }
+
+    #[test]
+    fn test_mill_event_display() {
+        assert_eq!(
+            MillEvent::new(PlayerId::from_index(0), 1).display(),
+            "Mill a card"
+        );
+        assert_eq!(
+            MillEvent::new(PlayerId::from_index(0), 4).display(),
+            "Mill 4 cards"
+        );
+    }
+}
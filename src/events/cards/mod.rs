@@ -1,8 +1,10 @@
-//! Card draw and discard events and matchers.
+//! Card draw, discard, and mill events and matchers.
 
 mod discard;
 mod draw;
 pub mod matchers;
+mod mill;
 
 pub use discard::DiscardEvent;
 pub use draw::DrawEvent;
+pub use mill::MillEvent;
@@ -9,7 +9,8 @@ use crate::ids::{ObjectId, PlayerId};
 /// A life loss event that can be processed through the replacement effect system.
 ///
 /// This event is emitted for life loss from both direct effects/payments and damage
-/// that actually reduces a player's life total.
+/// that actually reduces a player's life total. Paying life is losing life
+/// (rule 119.4), so payments emit this event too, flagged with `from_cost`.
 #[derive(Debug, Clone)]
 pub struct LifeLossEvent {
     /// The player losing life
@@ -18,6 +19,8 @@ pub struct LifeLossEvent {
     pub amount: u32,
     /// Whether this loss is from damage (false = payment or effect)
     pub from_damage: bool,
+    /// Whether this loss is a life payment (for a cost or an "unless pays").
+    pub from_cost: bool,
 }
 
 impl LifeLossEvent {
@@ -27,6 +30,7 @@ impl LifeLossEvent {
             player,
             amount,
             from_damage,
+            from_cost: false,
         }
     }
 
@@ -35,6 +39,14 @@ impl LifeLossEvent {
         Self::new(player, amount, false)
     }
 
+    /// Create a life loss event for paying life.
+    pub fn from_cost(player: PlayerId, amount: u32) -> Self {
+        Self {
+            from_cost: true,
+            ..Self::new(player, amount, false)
+        }
+    }
+
     /// Return a new event with reduced life loss.
     pub fn reduced(&self, by: u32) -> Self {
         Self {
@@ -88,11 +88,11 @@ pub use traits::{
 };
 
 // Re-export event types
-pub use cards::{DiscardEvent, DrawEvent};
+pub use cards::{DiscardEvent, DrawEvent, MillEvent};
 pub use counters::{MoveCountersEvent, PutCountersEvent, RemoveCountersEvent};
 pub use damage::DamageEvent;
 pub use life::{LifeGainEvent, LifeLossEvent};
-pub use permanents::{DestroyEvent, SacrificeEvent, TapEvent, UntapEvent};
+pub use permanents::{CreateTokensEvent, DestroyEvent, SacrificeEvent, TapEvent, UntapEvent};
 pub use zones::{EnterBattlefieldEvent, ZoneChangeEvent};
 
 // Re-export new event types
@@ -244,11 +244,7 @@ impl Event {
     /// Create a life loss event.
     pub fn life_loss(player: PlayerId, amount: u32, from_damage: bool) -> Self {
         Self::new_with_provenance(
-            LifeLossEvent {
-                player,
-                amount,
-                from_damage,
-            },
+            LifeLossEvent::new(player, amount, from_damage),
             ProvNodeId::default(),
         )
     }
@@ -281,6 +277,7 @@ impl Event {
                 enters_with_counters,
                 enters_as_copy_of: None,
                 added_subtypes: Vec::new(),
+                controller: None,
             },
             ProvNodeId::default(),
         )
@@ -342,6 +339,19 @@ impl Event {
         )
     }
 
+    /// Create a create tokens event.
+    pub fn create_tokens(controller: PlayerId, count: u32) -> Self {
+        Self::new_with_provenance(
+            CreateTokensEvent { controller, count },
+            ProvNodeId::default(),
+        )
+    }
+
+    /// Create a mill event.
+    pub fn mill(player: PlayerId, count: u32) -> Self {
+        Self::new_with_provenance(MillEvent { player, count }, ProvNodeId::default())
+    }
+
     /// Create a draw event.
     pub fn draw(player: PlayerId, count: u32, is_first_this_turn: bool) -> Self {
         Self::new_with_provenance(
@@ -61,6 +61,15 @@ impl KeywordActionKind {
         }
     }
 
+    /// Whether the event amount counts something a payoff can refer to as
+    /// "that many" (cards scried or surveilled, counters put by amass or earthbend).
+    pub fn has_amount(self) -> bool {
+        matches!(
+            self,
+            Self::Scry | Self::Surveil | Self::Amass | Self::Earthbend
+        )
+    }
+
     pub fn infinitive(self) -> &'static str {
         match self {
             Self::Amass => "amass",
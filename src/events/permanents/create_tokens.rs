@@ -0,0 +1,90 @@
+//! Create tokens event implementation.
+
+use std::any::Any;
+
+use crate::events::traits::{EventKind, GameEventType};
+use crate::game_state::GameState;
+use crate::ids::PlayerId;
+
+/// A "would create N tokens" event that can be processed through the
+/// replacement effect system before any token exists (e.g. "If an effect would
+/// create one or more tokens under your control, it creates twice that many of
+/// those tokens instead").
+#[derive(Debug, Clone)]
+pub struct CreateTokensEvent {
+    /// The player the tokens would be created under the control of
+    pub controller: PlayerId,
+    /// Number of tokens to create
+    pub count: u32,
+}
+
+impl CreateTokensEvent {
+    /// Create a new create tokens event.
+    pub fn new(controller: PlayerId, count: u32) -> Self {
+        Self { controller, count }
+    }
+
+    /// Return a new event with doubled token count.
+    pub fn doubled(&self) -> Self {
+        self.with_count(self.count.saturating_mul(2))
+    }
+
+    /// Return a new event with a different count.
+    pub fn with_count(&self, count: u32) -> Self {
+        Self {
+            count,
+            ..self.clone()
+        }
+    }
+}
+
+impl GameEventType for CreateTokensEvent {
+    fn event_kind(&self) -> EventKind {
+        EventKind::CreateTokens
+    }
+
+    fn affected_player(&self, _game: &GameState) -> PlayerId {
+        self.controller
+    }
+
+    fn player(&self) -> Option<PlayerId> {
+        Some(self.controller)
+    }
+
+    fn display(&self) -> String {
+        if self.count == 1 {
+            "Create a token".to_string()
+        } else {
+            format!("Create {} tokens", self.count)
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tokens_event_doubled() {
+        let event = CreateTokensEvent::new(PlayerId::from_index(0), 3);
+        assert_eq!(event.event_kind(), EventKind::CreateTokens);
+        assert_eq!(event.doubled().count, 6);
+        assert_eq!(event.doubled().doubled().count, 12);
+    }
+
+    #[test]
+    fn test_create_tokens_event_display() {
+        assert_eq!(
+            CreateTokensEvent::new(PlayerId::from_index(0), 1).display(),
+            "Create a token"
+        );
+        assert_eq!(
+            CreateTokensEvent::new(PlayerId::from_index(0), 2).display(),
+            "Create 2 tokens"
+        );
+    }
+}
@@ -1,12 +1,12 @@
-//! Permanent replacement effect matchers (tap, untap, destroy, sacrifice).
+//! Permanent replacement effect matchers (tap, untap, destroy, sacrifice, token creation).
 
 use crate::events::context::EventContext;
 use crate::events::traits::{
     EventKind, GameEventType, ReplacementMatcher, ReplacementPriority, downcast_event,
 };
-use crate::target::ObjectFilter;
+use crate::target::{ObjectFilter, PlayerFilter};
 
-use super::{DestroyEvent, SacrificeEvent, TapEvent, UntapEvent};
+use super::{CreateTokensEvent, DestroyEvent, SacrificeEvent, TapEvent, UntapEvent};
 
 /// Matches when a permanent matching the filter would become tapped.
 #[derive(Debug, Clone)]
@@ -292,6 +292,50 @@ impl ReplacementMatcher for RegenerationShieldMatcher {
     }
 }
 
+/// Matches when one or more tokens would be created under the control of a
+/// player matching the filter.
+#[derive(Debug, Clone)]
+pub struct WouldCreateTokensMatcher {
+    pub player_filter: PlayerFilter,
+}
+
+impl WouldCreateTokensMatcher {
+    pub fn new(player_filter: PlayerFilter) -> Self {
+        Self { player_filter }
+    }
+
+    /// Matches tokens created under your control.
+    pub fn you() -> Self {
+        Self::new(PlayerFilter::You)
+    }
+}
+
+impl ReplacementMatcher for WouldCreateTokensMatcher {
+    fn matches_event(&self, event: &dyn GameEventType, ctx: &EventContext) -> bool {
+        if event.event_kind() != EventKind::CreateTokens {
+            return false;
+        }
+
+        let Some(create) = downcast_event::<CreateTokensEvent>(event) else {
+            return false;
+        };
+
+        create.count > 0
+            && self
+                .player_filter
+                .matches_player(create.controller, &ctx.filter_ctx)
+    }
+
+    fn display(&self) -> String {
+        match &self.player_filter {
+            PlayerFilter::You => {
+                "When one or more tokens would be created under your control".to_string()
+            }
+            _ => "When one or more tokens would be created".to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
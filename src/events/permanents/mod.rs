@@ -1,10 +1,12 @@
-//! Permanent events and matchers (tap, untap, destroy, sacrifice).
+//! Permanent events and matchers (tap, untap, destroy, sacrifice, token creation).
 
+mod create_tokens;
 mod destroy;
 pub mod matchers;
 mod sacrifice;
 mod tap;
 
+pub use create_tokens::CreateTokensEvent;
 pub use destroy::DestroyEvent;
 pub use sacrifice::SacrificeEvent;
 pub use tap::{TapEvent, UntapEvent};
@@ -3,7 +3,7 @@
 use std::any::Any;
 
 use crate::events::traits::{EventKind, GameEventType};
-use crate::game_state::{GameState, Target};
+use crate::game_state::{GameState, StackEntry, Target};
 use crate::ids::{ObjectId, PlayerId};
 use crate::snapshot::ObjectSnapshot;
 
@@ -18,6 +18,9 @@ pub struct AbilityActivatedEvent {
     pub is_mana_ability: bool,
     /// Last-known snapshot of the source at activation time.
     pub snapshot: Option<ObjectSnapshot>,
+    /// The stack entry the activation put on the stack, captured with its
+    /// targets and X value. `None` for mana abilities, which don't use the stack.
+    pub stack_entry: Option<Box<StackEntry>>,
 }
 
 impl AbilityActivatedEvent {
@@ -28,6 +31,7 @@ impl AbilityActivatedEvent {
             activator,
             is_mana_ability,
             snapshot: None,
+            stack_entry: None,
         }
     }
 
@@ -36,6 +40,12 @@ impl AbilityActivatedEvent {
         self.snapshot = snapshot;
         self
     }
+
+    /// Attach the stack entry created by this activation.
+    pub fn with_stack_entry(mut self, entry: Option<StackEntry>) -> Self {
+        self.stack_entry = entry.map(Box::new);
+        self
+    }
 }
 
 impl GameEventType for AbilityActivatedEvent {
@@ -23,6 +23,8 @@ pub enum EventKind {
     ZoneChange,
     /// Player drawing cards
     Draw,
+    /// Player milling cards
+    Mill,
     /// Player gaining life
     LifeGain,
     /// Player losing life
@@ -31,6 +33,8 @@ pub enum EventKind {
     PutCounters,
     /// Counters being removed from a permanent
     RemoveCounters,
+    /// One or more tokens being created
+    CreateTokens,
     /// Permanent becoming tapped
     BecomeTapped,
     /// Permanent becoming untapped
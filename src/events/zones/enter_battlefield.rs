@@ -28,6 +28,9 @@ pub struct EnterBattlefieldEvent {
     pub enters_as_copy_of: Option<ObjectId>,
     /// Additional subtypes granted by the copy-as-enters replacement.
     pub added_subtypes: Vec<Subtype>,
+    /// The player it enters under the control of, if that differs from the
+    /// object's current controller (e.g., "put onto the battlefield under your control").
+    pub controller: Option<PlayerId>,
 }
 
 impl EnterBattlefieldEvent {
@@ -40,6 +43,7 @@ impl EnterBattlefieldEvent {
             enters_with_counters: Vec::new(),
             enters_as_copy_of: None,
             added_subtypes: Vec::new(),
+            controller: None,
         }
     }
 
@@ -52,6 +56,7 @@ impl EnterBattlefieldEvent {
             enters_with_counters: Vec::new(),
             enters_as_copy_of: None,
             added_subtypes: Vec::new(),
+            controller: None,
         }
     }
 
@@ -102,6 +107,20 @@ impl EnterBattlefieldEvent {
         }
     }
 
+    /// Return a new event where the object enters under `controller`'s control.
+    pub fn under_control_of(&self, controller: PlayerId) -> Self {
+        Self {
+            controller: Some(controller),
+            ..self.clone()
+        }
+    }
+
+    /// The player who will control the permanent once it enters.
+    pub fn entering_controller(&self, game: &GameState) -> Option<PlayerId> {
+        self.controller
+            .or_else(|| game.object(self.object).map(|o| o.controller))
+    }
+
     /// Get the total count of a specific counter type.
     pub fn counter_count(&self, counter_type: CounterType) -> u32 {
         self.enters_with_counters
@@ -118,8 +137,7 @@ impl GameEventType for EnterBattlefieldEvent {
     }
 
     fn affected_player(&self, game: &GameState) -> PlayerId {
-        game.object(self.object)
-            .map(|o| o.controller)
+        self.entering_controller(game)
             .unwrap_or(game.turn.active_player)
     }
 
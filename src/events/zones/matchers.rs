@@ -4,7 +4,7 @@ use crate::events::context::EventContext;
 use crate::events::traits::{
     EventKind, GameEventType, ReplacementMatcher, ReplacementPriority, downcast_event,
 };
-use crate::ids::ObjectId;
+use crate::ids::{ObjectId, PlayerId};
 use crate::target::ObjectFilter;
 use crate::zone::Zone;
 
@@ -31,7 +31,12 @@ impl WouldEnterBattlefieldMatcher {
         Self::new(ObjectFilter::permanent())
     }
 
-    fn matches_would_enter_object(&self, object_id: ObjectId, ctx: &EventContext) -> bool {
+    fn matches_would_enter_object(
+        &self,
+        object_id: ObjectId,
+        entering_controller: Option<PlayerId>,
+        ctx: &EventContext,
+    ) -> bool {
         let Some(obj) = ctx.game.object(object_id) else {
             return false;
         };
@@ -39,8 +44,13 @@ impl WouldEnterBattlefieldMatcher {
         // Evaluate against the object's prospective battlefield characteristics.
         // Replacement effects trigger before zone change is finalized, so the
         // object may still be in hand/stack/graveyard when this matcher runs.
+        // Controller-relative filters ("your opponents control") use the player
+        // it will enter under the control of.
         let mut prospective = obj.clone();
         prospective.zone = Zone::Battlefield;
+        if let Some(controller) = entering_controller {
+            prospective.controller = controller;
+        }
 
         self.filter.matches(&prospective, &ctx.filter_ctx, ctx.game)
     }
@@ -59,13 +69,13 @@ impl ReplacementMatcher for WouldEnterBattlefieldMatcher {
                 zone_change
                     .objects
                     .first()
-                    .is_some_and(|&id| self.matches_would_enter_object(id, ctx))
+                    .is_some_and(|&id| self.matches_would_enter_object(id, None, ctx))
             }
             EventKind::EnterBattlefield => {
                 let Some(etb) = downcast_event::<EnterBattlefieldEvent>(event) else {
                     return false;
                 };
-                self.matches_would_enter_object(etb.object, ctx)
+                self.matches_would_enter_object(etb.object, etb.controller, ctx)
             }
             _ => false,
         }
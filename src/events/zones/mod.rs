@@ -5,4 +5,4 @@ pub mod matchers;
 mod zone_change;
 
 pub use enter_battlefield::EnterBattlefieldEvent;
-pub use zone_change::ZoneChangeEvent;
+pub use zone_change::{MovedObject, ZoneChangeEvent};
@@ -5,10 +5,24 @@ use std::any::Any;
 use crate::events::cause::EventCause;
 use crate::events::traits::{EventKind, GameEventType};
 use crate::game_state::{GameState, Target};
-use crate::ids::{ObjectId, PlayerId};
+use crate::ids::{ObjectId, PlayerId, StableId};
 use crate::snapshot::ObjectSnapshot;
 use crate::zone::Zone;
 
+/// The identities of one object across a zone change that has happened.
+///
+/// Per rule 400.7 the object that arrives is a new object with a new
+/// `ObjectId`; `stable_id` is shared by both and lets clients follow the card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MovedObject {
+    /// The object's id in the zone it left.
+    pub old_id: ObjectId,
+    /// The new object's id in the zone it entered.
+    pub new_id: ObjectId,
+    /// The identity that persists across the move.
+    pub stable_id: StableId,
+}
+
 /// A zone change event that can be processed through the replacement effect system.
 ///
 /// This is the primitive event for all zone changes. Higher-level concepts like
@@ -27,6 +41,9 @@ pub struct ZoneChangeEvent {
     /// Snapshot of the object's state before the zone change (for LKI).
     /// For batch events, this is the snapshot of the first/primary object.
     pub snapshot: Option<ObjectSnapshot>,
+    /// Old and new ids of each object, once the move has happened. Empty for
+    /// proposed moves that are still going through replacement effects.
+    pub moved: Vec<MovedObject>,
 }
 
 impl ZoneChangeEvent {
@@ -38,6 +55,7 @@ impl ZoneChangeEvent {
             to,
             cause: EventCause::default(),
             snapshot,
+            moved: Vec::new(),
         }
     }
 
@@ -55,6 +73,7 @@ impl ZoneChangeEvent {
             to,
             cause,
             snapshot,
+            moved: Vec::new(),
         }
     }
 
@@ -66,9 +85,16 @@ impl ZoneChangeEvent {
             to,
             cause,
             snapshot: None,
+            moved: Vec::new(),
         }
     }
 
+    /// Record the ids the objects had before and after the move.
+    pub fn with_moved(mut self, moved: Vec<MovedObject>) -> Self {
+        self.moved = moved;
+        self
+    }
+
     /// Get the number of objects in this zone change.
     pub fn count(&self) -> usize {
         self.objects.len()
@@ -43,6 +43,53 @@ pub enum ExecutionError {
     TagNotFound(String),
     /// Internal error (should not happen).
     InternalError(String),
+    /// An error annotated with where it happened during resolution.
+    WithContext(Box<ExecutionError>, Box<ExecutionErrorContext>),
+}
+
+/// What was executing when an [`ExecutionError`] was raised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionErrorContext {
+    /// The source object of the resolving spell or ability.
+    pub source: ObjectId,
+    /// The source's name, if known.
+    pub source_name: Option<String>,
+    /// Debug rendering of the effect that failed.
+    pub effect: String,
+    /// Targets in scope when the effect ran.
+    pub targets: Vec<ResolvedTarget>,
+    /// Object tags in scope when the effect ran.
+    pub tags: Vec<String>,
+    /// Description of the stack entry being resolved.
+    pub stack_entry: String,
+    /// Where a debug dump of the game was written, if one was.
+    pub dump_path: Option<std::path::PathBuf>,
+}
+
+impl ExecutionError {
+    /// Attach resolution context, keeping any context already attached.
+    pub fn with_context(self, context: ExecutionErrorContext) -> Self {
+        match self {
+            ExecutionError::WithContext(..) => self,
+            error => ExecutionError::WithContext(Box::new(error), Box::new(context)),
+        }
+    }
+
+    /// The underlying error, without context.
+    pub fn root(&self) -> &ExecutionError {
+        match self {
+            ExecutionError::WithContext(error, _) => error.root(),
+            error => error,
+        }
+    }
+
+    /// The resolution context, if one was attached.
+    pub fn context(&self) -> Option<&ExecutionErrorContext> {
+        match self {
+            ExecutionError::WithContext(_, context) => Some(context),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ExecutionError {
@@ -56,6 +103,14 @@ impl std::fmt::Display for ExecutionError {
             ExecutionError::EffectNotFound(id) => write!(f, "Effect {:?} not found", id),
             ExecutionError::TagNotFound(tag) => write!(f, "Tag '{}' not found", tag),
             ExecutionError::InternalError(msg) => write!(f, "Internal error: {}", msg),
+            ExecutionError::WithContext(error, context) => {
+                let source = context.source_name.as_deref().unwrap_or("unknown source");
+                write!(f, "{} (while resolving {})", error, source)?;
+                if let Some(path) = &context.dump_path {
+                    write!(f, "; debug dump written to {}", path.display())?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -142,6 +197,9 @@ pub struct ExecutionContext<'a> {
     /// Pre-chosen modes for modal spells (set during casting per MTG rule 601.2b).
     /// If Some, ChooseModeEffect should use these instead of prompting.
     pub chosen_modes: Option<Vec<usize>>,
+    /// Damage division announced while casting (per MTG rule 601.2d).
+    /// Divided-damage effects deal these amounts instead of prompting.
+    pub divided_amounts: Vec<(crate::game_state::Target, u32)>,
     /// The cause of this effect execution (cost vs effect).
     /// This enables replacement effects to match based on what caused an event
     /// (e.g., Library of Leng only applies to effect-caused discards, not cost-based).
@@ -181,6 +239,7 @@ impl std::fmt::Debug for ExecutionContext<'_> {
                 &self.tagged_players.keys().collect::<Vec<_>>(),
             )
             .field("triggering_event", &self.triggering_event)
+            .field("divided_amounts", &self.divided_amounts)
             .field("cause", &self.cause)
             .field("provenance", &self.provenance)
             .field("mana_color_restriction", &self.mana_color_restriction)
@@ -219,6 +278,7 @@ impl<'a> ExecutionContext<'a> {
             tagged_players: HashMap::new(),
             triggering_event: None,
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             cause: EventCause::default(),
             provenance: ProvNodeId::default(),
             mana_color_restriction: None,
@@ -259,6 +319,7 @@ impl<'a> ExecutionContext<'a> {
             tagged_players: HashMap::new(),
             triggering_event: None,
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             cause: EventCause::default(),
             provenance: ProvNodeId::default(),
             mana_color_restriction: None,
@@ -289,6 +350,7 @@ impl<'a> ExecutionContext<'a> {
             tagged_players: self.tagged_players,
             triggering_event: self.triggering_event,
             chosen_modes: self.chosen_modes,
+            divided_amounts: self.divided_amounts,
             cause: self.cause,
             provenance: self.provenance,
             mana_color_restriction: self.mana_color_restriction,
@@ -337,6 +399,18 @@ impl<'a> ExecutionContext<'a> {
         }
     }
 
+    /// Last known information for an object that may have left its zone.
+    ///
+    /// Checks target snapshots first, then the source snapshot when `object`
+    /// is this context's source.
+    pub fn last_known(&self, object: ObjectId) -> Option<&ObjectSnapshot> {
+        self.target_snapshots.get(&object).or_else(|| {
+            self.source_snapshot
+                .as_ref()
+                .filter(|_| object == self.source)
+        })
+    }
+
     /// Set the defending player.
     pub fn with_defending_player(mut self, player: PlayerId) -> Self {
         self.defending_player = Some(player);
@@ -549,6 +623,15 @@ impl<'a> ExecutionContext<'a> {
         self
     }
 
+    /// Set the damage division announced while casting (per MTG rule 601.2d).
+    pub fn with_divided_amounts(
+        mut self,
+        divided_amounts: Vec<(crate::game_state::Target, u32)>,
+    ) -> Self {
+        self.divided_amounts = divided_amounts;
+        self
+    }
+
     /// Set the event cause (cost vs effect) for this execution.
     ///
     /// This enables replacement effects and triggers to distinguish between
@@ -710,6 +793,11 @@ impl<'a> ExecutionContext<'a> {
         let mut filter_ctx = game
             .filter_context_for(self.controller, Some(self.source))
             .with_iterated_player(self.iterated_player)
+            .with_triggering_player(
+                self.triggering_event
+                    .as_ref()
+                    .and_then(|event| event.player()),
+            )
             .with_target_players(target_players)
             .with_target_objects(target_objects)
             .with_tagged_objects(&tagged_objects);
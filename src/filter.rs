@@ -26,7 +26,7 @@ fn normalize_name_for_match(name: &str) -> String {
         .collect()
 }
 
-fn names_match(lhs: &str, rhs: &str) -> bool {
+pub(crate) fn names_match(lhs: &str, rhs: &str) -> bool {
     lhs.eq_ignore_ascii_case(rhs) || normalize_name_for_match(lhs) == normalize_name_for_match(rhs)
 }
 
@@ -326,6 +326,10 @@ fn tagged_constraint_matches_subject(
 /// Effect::create_tokens_player(token, 1, PlayerFilter::ControllerOf(ObjectRef::tagged("destroyed")))
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum ObjectRef {
     /// The first object target in the targets list (default).
     #[default]
@@ -384,6 +388,9 @@ pub struct FilterContext {
     /// The current iterated player (for ForEachOpponent/ForEachPlayer effects)
     pub iterated_player: Option<PlayerId>,
 
+    /// The player involved in the triggering event, if any.
+    pub triggering_player: Option<PlayerId>,
+
     /// Resolved player targets from the current execution context.
     pub target_players: Vec<PlayerId>,
 
@@ -443,6 +450,12 @@ impl FilterContext {
         self
     }
 
+    /// Set the player involved in the triggering event.
+    pub fn with_triggering_player(mut self, player: Option<PlayerId>) -> Self {
+        self.triggering_player = player;
+        self
+    }
+
     /// Set resolved player targets from the execution context.
     pub fn with_target_players(mut self, players: Vec<PlayerId>) -> Self {
         self.target_players = players;
@@ -467,6 +480,10 @@ impl FilterContext {
 
 /// A numeric comparison for filtering.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Comparison {
     Equal(i32),
     OneOf(Vec<i32>),
@@ -609,6 +626,10 @@ fn resolve_filter_comparison_rhs_value(
 
 /// Which power/toughness reference a filter should use.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum PtReference {
     /// "Power"/"toughness" (effective values, with continuous effects applied).
     #[default]
@@ -622,6 +643,10 @@ pub enum PtReference {
 /// This enum handles both filtering/matching players and specifying
 /// which player(s) an effect applies to.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum PlayerFilter {
     /// Any player in the game
     #[default]
@@ -653,6 +678,12 @@ pub enum PlayerFilter {
     /// The player who was dealt damage by the triggering damage event.
     DamagedPlayer,
 
+    /// The player involved in the triggering event ("the triggering player").
+    ///
+    /// Used for clauses like "that player controls" after "Whenever an
+    /// opponent casts a spell".
+    TriggeringPlayer,
+
     /// The controller of the effect that granted or created the current ability.
     ///
     /// This is a lowering-time/runtime marker that should typically be resolved
@@ -763,6 +794,8 @@ impl PlayerFilter {
             // Resolved from the triggering event during effect execution.
             PlayerFilter::DamagedPlayer => false,
 
+            PlayerFilter::TriggeringPlayer => ctx.triggering_player.is_some_and(|p| p == player),
+
             PlayerFilter::EffectController => false,
 
             PlayerFilter::Specific(id) => player == *id,
@@ -807,6 +840,7 @@ impl PlayerFilter {
             PlayerFilter::Defending => "the defending player".to_string(),
             PlayerFilter::Attacking => "the attacking player".to_string(),
             PlayerFilter::DamagedPlayer => "that player".to_string(),
+            PlayerFilter::TriggeringPlayer => "that player".to_string(),
             PlayerFilter::EffectController => "the player who cast this spell".to_string(),
             PlayerFilter::Specific(_) => "that player".to_string(),
             PlayerFilter::IteratedPlayer => "that player".to_string(),
@@ -829,6 +863,10 @@ impl PlayerFilter {
 
 /// Relationship an object may have with a tagged object set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum TaggedOpbjectRelation {
     /// The object must be one of the tagged objects.
     IsTaggedObject,
@@ -858,6 +896,10 @@ pub enum TaggedOpbjectRelation {
 
 /// Alternative casting capability qualifier for card filters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum AlternativeCastKind {
     Flashback,
     JumpStart,
@@ -868,6 +910,10 @@ pub enum AlternativeCastKind {
 
 /// Counter-state qualifier for object filters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum CounterConstraint {
     /// At least one counter of any type.
     Any,
@@ -877,6 +923,10 @@ pub enum CounterConstraint {
 
 /// Power relationship against the source object in filter context.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum SourcePowerRelation {
     /// Candidate object's power must be less than the source object's power.
     LessThanSource,
@@ -884,6 +934,10 @@ pub enum SourcePowerRelation {
 
 /// Stack object kind constraint for stack-targeting filters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum StackObjectKind {
     Spell,
     Ability,
@@ -894,6 +948,10 @@ pub enum StackObjectKind {
 
 /// A tagged-object constraint used by `ObjectFilter`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct TaggedObjectConstraint {
     pub tag: TagKey,
     pub relation: TaggedOpbjectRelation,
@@ -908,6 +966,11 @@ pub struct TaggedObjectConstraint {
 /// - Costs (sacrifice costs that require specific permanents)
 /// - Triggers (watching for specific types of events)
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ObjectFilter {
     /// Zone the object must be in (None = any zone, but typically battlefield for permanents)
     pub zone: Option<Zone>,
@@ -1120,6 +1183,10 @@ pub struct ObjectFilter {
     /// Counter-state exclusions such as "without a +1/+1 counter on it".
     pub without_counter: Option<CounterConstraint>,
 
+    /// Counter-count requirements such as "with three or more +1/+1 counters on it".
+    /// `CounterConstraint::Any` counts counters of every type.
+    pub with_counter_count: Option<(CounterConstraint, Comparison)>,
+
     /// Name must match (for cards like "Rat Colony")
     pub name: Option<String>,
 
@@ -1665,6 +1732,12 @@ impl ObjectFilter {
         self
     }
 
+    /// Require the number of counters (of the given type, or in total) to satisfy a comparison.
+    pub fn with_counter_count(mut self, counters: CounterConstraint, cmp: Comparison) -> Self {
+        self.with_counter_count = Some((counters, cmp));
+        self
+    }
+
     /// Require the object to have no counters.
     pub fn without_any_counter(mut self) -> Self {
         self.without_counter = Some(CounterConstraint::Any);
@@ -1881,6 +1954,19 @@ impl ObjectFilter {
                 return false;
             }
         }
+        if let Some((counters, cmp)) = &self.with_counter_count {
+            let count = match counters {
+                CounterConstraint::Any => subject.tail_counters().values().sum::<u32>(),
+                CounterConstraint::Typed(counter_type) => subject
+                    .tail_counters()
+                    .get(counter_type)
+                    .copied()
+                    .unwrap_or(0),
+            };
+            if !cmp.satisfies(count as i32) {
+                return false;
+            }
+        }
 
         if let Some(kind) = self.alternative_cast
             && !subject.tail_has_alternative_cast_kind(kind, game, ctx)
@@ -2071,6 +2157,11 @@ impl ObjectFilter {
             return false;
         }
 
+        // Phased-out permanents are treated as though they don't exist (Rule 702.26b).
+        if object.zone == Zone::Battlefield && game.is_phased_out(object.id) {
+            return false;
+        }
+
         if !self.any_of.is_empty()
             && !self
                 .any_of
@@ -2232,11 +2323,17 @@ impl ObjectFilter {
             }
         }
 
-        // Owner check
-        if let Some(owner_filter) = &self.owner
-            && !owner_filter.matches_player(object.owner, ctx)
-        {
-            return false;
+        // Owner check. For cards "in a player's hand" the zone conveys whose hand
+        // it is, which can differ from the owner after hands are exchanged.
+        if let Some(owner_filter) = &self.owner {
+            let owner = if self.zone == Some(Zone::Hand) && object.zone == Zone::Hand {
+                game.hand_holder(object.id).unwrap_or(object.owner)
+            } else {
+                object.owner
+            };
+            if !owner_filter.matches_player(owner, ctx) {
+                return false;
+            }
         }
 
         if self.type_or_subtype_union {
@@ -3041,7 +3138,7 @@ impl ObjectFilter {
                 PlayerFilter::Defending => parts.push("the defending player's".to_string()),
                 PlayerFilter::Attacking => parts.push("an attacking player's".to_string()),
                 PlayerFilter::DamagedPlayer => parts.push("the damaged player's".to_string()),
-                PlayerFilter::IteratedPlayer => {
+                PlayerFilter::IteratedPlayer | PlayerFilter::TriggeringPlayer => {
                     if !has_leading_determiner {
                         parts.insert(0, "a".to_string());
                     }
@@ -3093,6 +3190,7 @@ impl ObjectFilter {
                 PlayerFilter::Defending => "the defending player owns".to_string(),
                 PlayerFilter::Attacking => "an attacking player owns".to_string(),
                 PlayerFilter::DamagedPlayer => "the damaged player owns".to_string(),
+                PlayerFilter::TriggeringPlayer => "that player owns".to_string(),
                 PlayerFilter::IteratedPlayer => "that player owns".to_string(),
                 PlayerFilter::TargetPlayerOrControllerOfTarget => {
                     "that player or that object's controller owns".to_string()
@@ -3643,6 +3741,12 @@ impl ObjectFilter {
                 describe_counter_constraint(counter_exclusion)
             ));
         }
+        if let Some((counters, cmp)) = &self.with_counter_count {
+            parts.push(format!(
+                "with {} on it",
+                describe_counter_count(*counters, cmp)
+            ));
+        }
         if let Some(kind) = self.alternative_cast {
             parts.push(format!("with {}", describe_alternative_cast_kind(kind)));
         }
@@ -4021,6 +4125,7 @@ fn describe_possessive_player_filter(filter: &PlayerFilter) -> String {
         PlayerFilter::Defending => "the defending player's".to_string(),
         PlayerFilter::Attacking => "an attacking player's".to_string(),
         PlayerFilter::DamagedPlayer => "the damaged player's".to_string(),
+        PlayerFilter::TriggeringPlayer => "that player's".to_string(),
         PlayerFilter::EffectController => "the player who cast this spell's".to_string(),
         PlayerFilter::Specific(_) => "that player's".to_string(),
         PlayerFilter::IteratedPlayer => "that player's".to_string(),
@@ -4055,6 +4160,7 @@ fn describe_player_filter(filter: &PlayerFilter) -> String {
         PlayerFilter::Defending => "defending player".to_string(),
         PlayerFilter::Attacking => "attacking player".to_string(),
         PlayerFilter::DamagedPlayer => "damaged player".to_string(),
+        PlayerFilter::TriggeringPlayer => "that player".to_string(),
         PlayerFilter::EffectController => "the player who cast this spell".to_string(),
         PlayerFilter::Specific(_) => "player".to_string(),
         PlayerFilter::IteratedPlayer => "that player".to_string(),
@@ -4299,6 +4405,28 @@ fn describe_counter_constraint(constraint: CounterConstraint) -> String {
     }
 }
 
+fn describe_counter_count(counters: CounterConstraint, cmp: &Comparison) -> String {
+    let plural = if matches!(cmp, Comparison::Equal(1)) {
+        "counter"
+    } else {
+        "counters"
+    };
+    let noun = match counters {
+        CounterConstraint::Any => plural.to_string(),
+        CounterConstraint::Typed(counter_type) => {
+            format!("{} {plural}", counter_type.description())
+        }
+    };
+    match cmp {
+        Comparison::Equal(v) => format!("exactly {v} {noun}"),
+        Comparison::GreaterThanOrEqual(v) => format!("{v} or more {noun}"),
+        Comparison::LessThanOrEqual(v) => format!("{v} or fewer {noun}"),
+        Comparison::GreaterThan(v) => format!("more than {v} {noun}"),
+        Comparison::LessThan(v) => format!("fewer than {v} {noun}"),
+        _ => format!("a number of {noun} {}", describe_comparison(cmp)),
+    }
+}
+
 fn describe_alternative_cast_kind(kind: AlternativeCastKind) -> &'static str {
     match kind {
         AlternativeCastKind::Flashback => "flashback",
@@ -4853,6 +4981,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_filter_matches_counter_counts() {
+        let mut game = setup_modified_filter_game();
+        let alice = PlayerId::from_index(0);
+        let creature_id = create_modified_test_creature(&mut game, alice);
+
+        let ctx = FilterContext::new(alice).with_source(creature_id);
+        let two_plus_one = ObjectFilter::creature().with_counter_count(
+            CounterConstraint::Typed(CounterType::PlusOnePlusOne),
+            Comparison::GreaterThanOrEqual(2),
+        );
+        let two_of_any = ObjectFilter::creature()
+            .with_counter_count(CounterConstraint::Any, Comparison::GreaterThanOrEqual(2));
+        let no_counters = ObjectFilter::creature().without_any_counter();
+
+        let creature = game.object(creature_id).expect("creature exists");
+        assert!(!two_plus_one.matches(creature, &ctx, &game));
+        assert!(no_counters.matches(creature, &ctx, &game));
+
+        let counters = &mut game
+            .object_mut(creature_id)
+            .expect("creature exists")
+            .counters;
+        counters.insert(CounterType::PlusOnePlusOne, 1);
+        counters.insert(CounterType::Stun, 1);
+        let creature = game.object(creature_id).expect("creature exists");
+        assert!(
+            !two_plus_one.matches(creature, &ctx, &game),
+            "only +1/+1 counters are counted"
+        );
+        assert!(
+            two_of_any.matches(creature, &ctx, &game),
+            "counters of every type are counted"
+        );
+        assert!(!no_counters.matches(creature, &ctx, &game));
+        assert_eq!(
+            two_plus_one.description(),
+            "creature with 2 or more +1/+1 counters on it"
+        );
+    }
+
     #[test]
     fn test_filter_matches_modified_by_equipment() {
         let mut game = setup_modified_filter_game();
@@ -4938,6 +5107,109 @@ mod tests {
         assert!(!PlayerFilter::Specific(you).matches_player(opponent, &ctx));
     }
 
+    #[test]
+    fn test_player_filter_not_you_matches_every_other_player_in_multiplayer() {
+        let you = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let carol = PlayerId::from_index(2);
+
+        let ctx = FilterContext::new(you).with_opponents(vec![bob, carol]);
+
+        assert!(!PlayerFilter::NotYou.matches_player(you, &ctx));
+        assert!(PlayerFilter::NotYou.matches_player(bob, &ctx));
+        assert!(PlayerFilter::NotYou.matches_player(carol, &ctx));
+    }
+
+    #[test]
+    fn test_destroy_all_creatures_an_opponent_controls_spares_yours() {
+        use crate::cards::definitions::grizzly_bears;
+        use crate::effects::{DestroyEffect, EffectExecutor};
+        use crate::executor::ExecutionContext;
+
+        let mut game = crate::game_state::GameState::new(
+            vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+            20,
+        );
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let carol = PlayerId::from_index(2);
+        let alice_bear =
+            game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        let bob_bear = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+        let carol_bear =
+            game.create_object_from_definition(&grizzly_bears(), carol, Zone::Battlefield);
+
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        DestroyEffect::all(ObjectFilter::creature().opponent_controls())
+            .execute(&mut game, &mut ctx)
+            .expect("wrath resolves");
+
+        assert!(game.battlefield.contains(&alice_bear));
+        assert!(!game.battlefield.contains(&bob_bear));
+        assert!(!game.battlefield.contains(&carol_bear));
+    }
+
+    #[test]
+    fn test_creatures_you_control_pump_skips_opponents_creatures() {
+        use crate::cards::definitions::grizzly_bears;
+        use crate::effect::Until;
+        use crate::effects::{EffectExecutor, ModifyPowerToughnessAllEffect};
+        use crate::executor::ExecutionContext;
+
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let alice_bear =
+            game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        let bob_bear = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, alice);
+        ModifyPowerToughnessAllEffect::your_creatures(1, 1, Until::EndOfTurn)
+            .execute(&mut game, &mut ctx)
+            .expect("pump resolves");
+
+        assert_eq!(game.calculated_power(alice_bear), Some(3));
+        assert_eq!(game.calculated_power(bob_bear), Some(2));
+    }
+
+    #[test]
+    fn test_triggering_player_filter_resolves_from_triggering_event() {
+        use crate::cards::definitions::grizzly_bears;
+        use crate::events::spells::SpellCastEvent;
+        use crate::executor::ExecutionContext;
+        use crate::triggers::TriggerEvent;
+
+        let mut game = crate::game_state::GameState::new(
+            vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+            20,
+        );
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let carol = PlayerId::from_index(2);
+        let bob_bear = game.create_object_from_definition(&grizzly_bears(), bob, Zone::Battlefield);
+        let carol_bear =
+            game.create_object_from_definition(&grizzly_bears(), carol, Zone::Battlefield);
+
+        let spell = game.new_object_id();
+        let provenance = game
+            .provenance_graph
+            .alloc_root_event(crate::events::EventKind::SpellCast);
+        let event = TriggerEvent::new_with_provenance(
+            SpellCastEvent::new(spell, bob, Zone::Hand),
+            provenance,
+        );
+        let source = game.new_object_id();
+        let ctx = ExecutionContext::new_default(source, alice).with_triggering_event(event);
+        let filter_ctx = ctx.filter_context(&game);
+        let filter = ObjectFilter::creature().controlled_by(PlayerFilter::TriggeringPlayer);
+
+        assert_eq!(filter_ctx.triggering_player, Some(bob));
+        assert!(filter.matches(game.object(bob_bear).unwrap(), &filter_ctx, &game));
+        assert!(!filter.matches(game.object(carol_bear).unwrap(), &filter_ctx, &game));
+    }
+
     #[test]
     fn test_player_filter_controller_of_target_uses_target_snapshot() {
         use crate::card::CardBuilder;
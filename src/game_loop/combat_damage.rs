@@ -21,6 +21,10 @@ pub struct CombatDamageEvent {
 
 /// Execute combat damage for a damage step.
 ///
+/// All combat damage in a step is dealt simultaneously (rule 510.2): every
+/// assignment is applied before anything else happens, and lifelink from the
+/// whole batch is gained at once, as a single life-gain event per player.
+///
 /// # Arguments
 /// * `game` - The game state
 /// * `combat` - The combat state
@@ -34,6 +38,7 @@ pub fn execute_combat_damage_step(
     first_strike: bool,
 ) -> Vec<CombatDamageEvent> {
     let mut damage_events = Vec::new();
+    let mut lifelink = CombatLifelink::new();
 
     // Process each attacker
     for attacker_info in &combat.attackers {
@@ -68,8 +73,14 @@ pub fn execute_combat_damage_step(
 
         if is_blocked(combat, attacker_id) {
             // Blocked attacker - deal damage to blockers
-            let events =
-                deal_damage_to_blockers(game, attacker_id, combat, combat_stat as u32, controller);
+            let events = deal_damage_to_blockers(
+                game,
+                attacker_id,
+                combat,
+                combat_stat as u32,
+                controller,
+                &mut lifelink,
+            );
             damage_events.extend(events);
         } else if is_unblocked(combat, attacker_id) {
             // Unblocked attacker - deal damage to defender
@@ -78,6 +89,7 @@ pub fn execute_combat_damage_step(
                 attacker_id,
                 &attacker_info.target,
                 combat_stat as u32,
+                &mut lifelink,
             );
             if let Some(e) = event {
                 damage_events.push(e);
@@ -173,8 +185,7 @@ pub fn execute_combat_damage_step(
     for (blocker_id, attacker_id, controller, _assigned, damage_result) in blocker_damage_info {
         let applied = apply_damage_to_permanent(game, attacker_id, blocker_id, &damage_result);
 
-        // Apply lifelink (through event processing)
-        apply_combat_lifelink(game, controller, &damage_result, applied.total_damage_dealt);
+        lifelink.record(controller, &damage_result, applied.total_damage_dealt);
 
         damage_events.push(CombatDamageEvent {
             source: blocker_id,
@@ -185,6 +196,8 @@ pub fn execute_combat_damage_step(
         });
     }
 
+    lifelink.apply(game);
+
     damage_events
 }
 
@@ -244,22 +257,60 @@ pub(super) fn combat_damage_stat_for_creature(
     }
 }
 
-pub(super) fn apply_combat_lifelink(
-    game: &mut GameState,
-    controller: PlayerId,
-    damage_result: &DamageResult,
-    total_damage_dealt: u32,
-) {
-    if !damage_result.has_lifelink || total_damage_dealt == 0 {
-        return;
+/// Life gained through lifelink during one combat damage step, per player.
+#[derive(Debug, Default)]
+pub(super) struct CombatLifelink {
+    gains: Vec<(PlayerId, u32)>,
+}
+
+impl CombatLifelink {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record damage dealt by a source with lifelink.
+    pub(super) fn record(
+        &mut self,
+        controller: PlayerId,
+        damage_result: &DamageResult,
+        total_damage_dealt: u32,
+    ) {
+        if !damage_result.has_lifelink || total_damage_dealt == 0 {
+            return;
+        }
+        match self
+            .gains
+            .iter_mut()
+            .find(|(player, _)| *player == controller)
+        {
+            Some((_, amount)) => *amount = amount.saturating_add(total_damage_dealt),
+            None => self.gains.push((controller, total_damage_dealt)),
+        }
     }
 
-    let life_to_gain =
-        crate::event_processor::process_life_gain_with_event(game, controller, total_damage_dealt);
-    if life_to_gain > 0
-        && let Some(player) = game.player_mut(controller)
-    {
-        player.gain_life(life_to_gain);
+    /// Gain the recorded life, one life-gain event per player (through event processing).
+    pub(super) fn apply(self, game: &mut GameState) {
+        for (controller, amount) in self.gains {
+            let life_to_gain =
+                crate::event_processor::process_life_gain_with_event(game, controller, amount);
+            if life_to_gain == 0 {
+                continue;
+            }
+            let Some(player) = game.player_mut(controller) else {
+                continue;
+            };
+            player.gain_life(life_to_gain);
+            let provenance = game
+                .provenance_graph
+                .alloc_root_event(crate::events::EventKind::LifeGain);
+            game.queue_trigger_event(
+                provenance,
+                TriggerEvent::new_with_provenance(
+                    LifeGainEvent::new(controller, life_to_gain),
+                    provenance,
+                ),
+            );
+        }
     }
 }
 
@@ -270,6 +321,7 @@ pub(super) fn deal_damage_to_blockers(
     combat: &CombatState,
     total_damage: u32,
     controller: PlayerId,
+    lifelink: &mut CombatLifelink,
 ) -> Vec<CombatDamageEvent> {
     let mut events = Vec::new();
 
@@ -330,8 +382,7 @@ pub(super) fn deal_damage_to_blockers(
     for (blocker_id, damage_result) in blocker_damages {
         let applied = apply_damage_to_permanent(game, blocker_id, attacker_id, &damage_result);
 
-        // Apply lifelink (through event processing)
-        apply_combat_lifelink(game, controller, &damage_result, applied.total_damage_dealt);
+        lifelink.record(controller, &damage_result, applied.total_damage_dealt);
 
         events.push(CombatDamageEvent {
             source: attacker_id,
@@ -346,8 +397,7 @@ pub(super) fn deal_damage_to_blockers(
     if let Some((player_id, damage_result)) = excess_damage_result {
         let applied = apply_damage_to_player(game, player_id, attacker_id, &damage_result);
 
-        // Apply lifelink (through event processing)
-        apply_combat_lifelink(game, controller, &damage_result, applied.total_damage_dealt);
+        lifelink.record(controller, &damage_result, applied.total_damage_dealt);
 
         events.push(CombatDamageEvent {
             source: attacker_id,
@@ -367,6 +417,7 @@ pub(super) fn deal_damage_to_defender(
     attacker_id: ObjectId,
     target: &AttackTarget,
     damage: u32,
+    lifelink: &mut CombatLifelink,
 ) -> Option<CombatDamageEvent> {
     let attacker = game.object(attacker_id)?;
     let controller = attacker.controller;
@@ -383,8 +434,7 @@ pub(super) fn deal_damage_to_defender(
 
             let applied = apply_damage_to_player(game, *player_id, attacker_id, &damage_result);
 
-            // Apply lifelink (through event processing)
-            apply_combat_lifelink(game, controller, &damage_result, applied.total_damage_dealt);
+            lifelink.record(controller, &damage_result, applied.total_damage_dealt);
 
             Some(CombatDamageEvent {
                 source: attacker_id,
@@ -421,26 +471,6 @@ pub(super) fn deal_damage_to_defender(
                 for assignment in processed.assignments {
                     match assignment.target {
                         EventDamageTarget::Object(object_id) => {
-                            if object_id == *pw_id {
-                                if let Some(pw) = game.object_mut(*pw_id) {
-                                    let current_loyalty = pw
-                                        .counters
-                                        .get(&CounterType::Loyalty)
-                                        .copied()
-                                        .unwrap_or(0);
-                                    let new_loyalty =
-                                        current_loyalty.saturating_sub(assignment.amount);
-                                    if new_loyalty == 0 {
-                                        pw.counters.remove(&CounterType::Loyalty);
-                                    } else {
-                                        pw.counters.insert(CounterType::Loyalty, new_loyalty);
-                                    }
-                                }
-                                final_damage = final_damage.saturating_add(assignment.amount);
-                                total_damage_dealt =
-                                    total_damage_dealt.saturating_add(assignment.amount);
-                                continue;
-                            }
                             let applied = crate::rules::damage::apply_processed_damage_assignment(
                                 game,
                                 attacker_id,
@@ -451,6 +481,9 @@ pub(super) fn deal_damage_to_defender(
                             if applied.applied {
                                 total_damage_dealt =
                                     total_damage_dealt.saturating_add(assignment.amount);
+                                if object_id == *pw_id {
+                                    final_damage = final_damage.saturating_add(assignment.amount);
+                                }
                             }
                         }
                         EventDamageTarget::Player(_) => {
@@ -470,8 +503,7 @@ pub(super) fn deal_damage_to_defender(
                 }
             }
 
-            // Apply lifelink (only if damage was dealt, through event processing)
-            apply_combat_lifelink(game, controller, &damage_result, total_damage_dealt);
+            lifelink.record(controller, &damage_result, total_damage_dealt);
 
             Some(CombatDamageEvent {
                 source: attacker_id,
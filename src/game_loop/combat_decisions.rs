@@ -110,6 +110,24 @@ pub(super) fn generic_attack_tax_per_attacker_against_player(
     tax
 }
 
+/// Expand an attack shortcut into concrete declarations for the active player.
+///
+/// The result is validated against `compute_legal_attackers`, so it can be
+/// passed straight to `apply_attacker_declarations`.
+pub fn expand_attack_shortcut(
+    game: &GameState,
+    combat: &CombatState,
+    shortcut: &AttackShortcut,
+) -> Result<Vec<AttackerDeclaration>, GameLoopError> {
+    let legal_attackers = compute_legal_attackers(game, combat);
+    let last_attack = game
+        .last_attack_declarations
+        .get(&game.turn.active_player)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    Ok(shortcut.expand(&legal_attackers, last_attack)?)
+}
+
 /// Apply attacker declarations to the combat state.
 pub fn apply_attacker_declarations(
     game: &mut GameState,
@@ -274,9 +292,10 @@ pub fn apply_attacker_declarations(
     // Clear any existing attackers
     combat.attackers.clear();
     if !declarations.is_empty() {
-        game.players_attacked_this_turn
-            .insert(game.turn.active_player);
+        game.turn_trackers.record_attack(game.turn.active_player);
     }
+    game.last_attack_declarations
+        .insert(game.turn.active_player, declarations.to_vec());
 
     for decl in declarations {
         let Some(creature) = game.object(decl.creature) else {
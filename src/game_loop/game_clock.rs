@@ -0,0 +1,170 @@
+use super::*;
+
+/// Chess-clock style time banks for the priority loop.
+///
+/// Each player starts with `main_time_ms`. While a player holds priority or
+/// has an open decision, the time they take is charged to their bank; each
+/// completed decision then adds `increment_ms` back. A player whose bank runs
+/// out makes the loop return [`GameLoopError::PlayerTimedOut`], which callers
+/// can map to a game loss (or, over the network, to a `TimeoutClaim`).
+///
+/// Time is read from [`DecisionMaker::clock_now_ms`], so the loop never
+/// touches a wall clock itself and tests can drive it with a fake one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameClock {
+    main_time_ms: u64,
+    increment_ms: u64,
+    remaining_ms: std::collections::HashMap<PlayerId, u64>,
+}
+
+impl GameClock {
+    /// Create a clock giving every player `main_time_ms`, plus `increment_ms`
+    /// after each decision.
+    pub fn new(main_time_ms: u64, increment_ms: u64) -> Self {
+        Self {
+            main_time_ms,
+            increment_ms,
+            remaining_ms: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Time left in a player's bank.
+    pub fn remaining_ms(&self, player: PlayerId) -> u64 {
+        self.remaining_ms
+            .get(&player)
+            .copied()
+            .unwrap_or(self.main_time_ms)
+    }
+
+    /// Whether a player's bank has run out.
+    pub fn has_timed_out(&self, player: PlayerId) -> bool {
+        self.remaining_ms(player) == 0
+    }
+
+    /// Charge a decision that took `elapsed_ms` to `player`.
+    ///
+    /// Returns [`GameLoopError::PlayerTimedOut`] if the decision used up the
+    /// rest of the player's time; otherwise the increment is added.
+    pub fn charge(&mut self, player: PlayerId, elapsed_ms: u64) -> Result<(), GameLoopError> {
+        let remaining = self.remaining_ms(player);
+        if elapsed_ms >= remaining {
+            self.remaining_ms.insert(player, 0);
+            return Err(GameLoopError::PlayerTimedOut(player));
+        }
+        self.remaining_ms
+            .insert(player, remaining - elapsed_ms + self.increment_ms);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::Phase;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Passes priority, spending `think_ms` of fake time on each decision.
+    struct SlowPasser {
+        now: Rc<Cell<u64>>,
+        think_ms: u64,
+    }
+
+    impl DecisionMaker for SlowPasser {
+        fn clock_now_ms(&self) -> Option<u64> {
+            Some(self.now.get())
+        }
+
+        fn decide_priority(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::PriorityContext,
+        ) -> LegalAction {
+            self.now.set(self.now.get() + self.think_ms);
+            LegalAction::PassPriority
+        }
+    }
+
+    fn main_phase_game() -> GameState {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        // A land in hand gives Alice a real choice, so her priority isn't
+        // auto-passed.
+        game.create_object_from_definition(
+            &crate::cards::definitions::basic_forest(),
+            alice,
+            Zone::Hand,
+        );
+        game.turn.phase = Phase::FirstMain;
+        game.turn.step = None;
+        game.turn.active_player = alice;
+        game.turn.priority_player = Some(alice);
+        game
+    }
+
+    #[test]
+    fn increment_is_added_after_each_decision() {
+        let alice = PlayerId::from_index(0);
+        let mut clock = GameClock::new(10_000, 2_000);
+        clock.charge(alice, 3_000).unwrap();
+        assert_eq!(clock.remaining_ms(alice), 9_000);
+        assert_eq!(clock.remaining_ms(PlayerId::from_index(1)), 10_000);
+
+        assert_eq!(
+            clock.charge(alice, 9_000),
+            Err(GameLoopError::PlayerTimedOut(alice))
+        );
+        assert!(clock.has_timed_out(alice));
+    }
+
+    #[test]
+    fn player_times_out_while_holding_priority() {
+        let mut game = main_phase_game();
+        let alice = PlayerId::from_index(0);
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        state.set_clock(GameClock::new(5_000, 0));
+        let mut dm = SlowPasser {
+            now: Rc::new(Cell::new(0)),
+            think_ms: 6_000,
+        };
+
+        let result =
+            run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm);
+        assert!(matches!(result, Err(GameLoopError::PlayerTimedOut(p)) if p == alice));
+        assert_eq!(state.clock.as_ref().unwrap().remaining_ms(alice), 0);
+    }
+
+    #[test]
+    fn decisions_within_the_bank_keep_the_loop_running() {
+        let mut game = main_phase_game();
+        let alice = PlayerId::from_index(0);
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        state.set_clock(GameClock::new(5_000, 1_000));
+        let mut dm = SlowPasser {
+            now: Rc::new(Cell::new(0)),
+            think_ms: 2_000,
+        };
+
+        let result =
+            run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm);
+        assert!(matches!(result, Ok(GameProgress::Continue)));
+        assert_eq!(state.clock.as_ref().unwrap().remaining_ms(alice), 4_000);
+    }
+
+    #[test]
+    fn clock_stands_still_without_a_time_source() {
+        let mut game = main_phase_game();
+        let alice = PlayerId::from_index(0);
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        state.set_clock(GameClock::new(1, 0));
+        let mut dm = crate::decision::AutoPassDecisionMaker;
+
+        let result =
+            run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm);
+        assert!(matches!(result, Ok(GameProgress::Continue)));
+        assert_eq!(state.clock.as_ref().unwrap().remaining_ms(alice), 1);
+    }
+}
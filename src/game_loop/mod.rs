@@ -18,11 +18,12 @@ use crate::combat_state::{
 use crate::cost::OptionalCostsPaid;
 use crate::costs::CostContext;
 use crate::decision::{
-    AlternativePaymentEffect, AttackerDeclaration, BlockerDeclaration, DecisionMaker, GameProgress,
-    GameResult, KeywordPaymentContribution, LegalAction, ManaPaymentOption, ManaPipPaymentAction,
-    ManaPipPaymentOption, OptionalCostOption, ReplacementOption, ResponseError, TargetRequirement,
-    can_activate_ability_with_restrictions, compute_commander_actions, compute_legal_actions,
-    compute_legal_attackers, compute_legal_blockers, compute_potential_mana,
+    AlternativePaymentEffect, AttackShortcut, AttackerDeclaration, BlockerDeclaration,
+    DecisionMaker, GameProgress, GameResult, KeywordPaymentContribution, LegalAction,
+    ManaPaymentOption, ManaPipPaymentAction, ManaPipPaymentOption, OptionalCostOption,
+    ReplacementOption, ResponseError, TargetRequirement, can_activate_ability_with_restrictions,
+    compute_commander_actions, compute_legal_actions, compute_legal_attackers,
+    compute_legal_blockers, compute_potential_mana,
 };
 use crate::effect::Effect;
 use crate::events::cause::EventCause;
@@ -41,6 +42,7 @@ use crate::filter::{FilterContext, ObjectFilter};
 use crate::game_event::DamageTarget as EventDamageTarget;
 use crate::game_state::{GameState, StackEntry, Step, Target};
 use crate::ids::{ObjectId, PlayerId, StableId};
+use crate::legal_action_cache::compute_legal_actions_cached;
 #[cfg(feature = "net")]
 use crate::net::{CostPayment, CostStep, GameObjectId, ManaSymbolCode, ManaSymbolSpec, ZoneCode};
 #[cfg(not(feature = "net"))]
@@ -59,7 +61,7 @@ use crate::snapshot::ObjectSnapshot;
 use crate::target::ChooseSpec;
 use crate::triggers::{
     DamageEventTarget, TriggerEvent, TriggerQueue, TriggeredAbilityEntry, check_triggers,
-    generate_step_trigger_events, verify_intervening_if,
+    check_triggers_for_events, generate_step_trigger_events, verify_intervening_if,
 };
 use crate::turn::{PriorityResult, PriorityTracker, TurnError, pass_priority, reset_priority};
 use crate::types::{CardType, Subtype};
@@ -67,6 +69,7 @@ use crate::zone::Zone;
 
 mod combat_damage;
 mod combat_decisions;
+mod game_clock;
 mod priority_apply;
 mod priority_cast;
 mod priority_core;
@@ -97,9 +100,10 @@ use self::types::*;
 
 pub use self::combat_damage::*;
 pub use self::combat_decisions::*;
+pub use self::game_clock::GameClock;
 pub use self::priority_apply::apply_priority_response_with_dm;
 pub use self::priority_core::*;
-pub use self::priority_mana::run_priority_loop_with;
+pub use self::priority_mana::{run_priority_loop_with, run_priority_loop_with_state};
 pub use self::priority_state::*;
 pub use self::saga::*;
 pub use self::sba_triggers::*;
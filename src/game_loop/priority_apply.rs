@@ -22,7 +22,7 @@ fn build_target_assignments(
         .iter()
         .map(
             |requirement| crate::decisions::context::TargetRequirementContext {
-                description: requirement.description.clone(),
+                description: requirement.description.clone().into(),
                 legal_targets: requirement.legal_targets.clone(),
                 min_targets: requirement.min_targets,
                 max_targets: requirement.max_targets,
@@ -54,14 +54,24 @@ pub fn apply_priority_response_with_dm(
     response: &PriorityResponse,
     decision_maker: &mut impl DecisionMaker,
 ) -> Result<GameProgress, GameLoopError> {
-    if let PriorityResponse::Attackers(declarations) = response {
+    if let PriorityResponse::Attackers(_) | PriorityResponse::AttackShortcut(_) = response {
         if game.turn.step != Some(Step::DeclareAttackers) {
             return Err(GameLoopError::InvalidState(
                 "Attackers response outside Declare Attackers step".to_string(),
             ));
         }
         let mut combat = game.combat.take().unwrap_or_default();
-        let result = apply_attacker_declarations(game, &mut combat, trigger_queue, declarations);
+        let result = match response {
+            PriorityResponse::AttackShortcut(shortcut) => {
+                expand_attack_shortcut(game, &combat, shortcut).and_then(|declarations| {
+                    apply_attacker_declarations(game, &mut combat, trigger_queue, &declarations)
+                })
+            }
+            PriorityResponse::Attackers(declarations) => {
+                apply_attacker_declarations(game, &mut combat, trigger_queue, declarations)
+            }
+            _ => unreachable!("matched attackers responses above"),
+        };
         game.combat = Some(combat);
         result?;
         reset_priority(game, &mut state.tracker);
@@ -69,15 +79,19 @@ pub fn apply_priority_response_with_dm(
     }
 
     if let PriorityResponse::Blockers {
-        defending_player,
-        declarations,
-    } = response
+        defending_player, ..
+    }
+    | PriorityResponse::NoBlocks { defending_player } = response
     {
         if game.turn.step != Some(Step::DeclareBlockers) {
             return Err(GameLoopError::InvalidState(
                 "Blockers response outside Declare Blockers step".to_string(),
             ));
         }
+        let declarations = match response {
+            PriorityResponse::Blockers { declarations, .. } => declarations.as_slice(),
+            _ => &[],
+        };
         let mut combat = game.combat.take().ok_or_else(|| {
             GameLoopError::InvalidState("Combat state missing at declare blockers".to_string())
         })?;
@@ -229,6 +243,17 @@ pub fn apply_priority_response_with_dm(
         ));
     }
 
+    // Handle the graveyard cards chosen for a pending cast's Delve.
+    if let PriorityResponse::DelveCards(card_ids) = response {
+        return apply_delve_choice_response(
+            game,
+            trigger_queue,
+            state,
+            card_ids,
+            &mut *decision_maker,
+        );
+    }
+
     // Handle hybrid/Phyrexian mana choice for a pending cast (per MTG rule 601.2b)
     if let PriorityResponse::HybridChoice(choice) = response {
         return apply_hybrid_choice_response(
@@ -369,7 +394,7 @@ pub fn apply_priority_response_with_dm(
             }
 
             // Player retains priority after playing a land
-            advance_priority_with_dm(game, trigger_queue, decision_maker)
+            retain_priority_after_special_action(game, trigger_queue, state, decision_maker)
         }
         LegalAction::CastSpell {
             spell_id,
@@ -378,7 +403,7 @@ pub fn apply_priority_response_with_dm(
         } => {
             // Save checkpoint before starting the action chain
             // This allows rollback if the player makes an invalid choice
-            state.save_checkpoint(game);
+            state.save_checkpoint(game, trigger_queue);
 
             let player = game
                 .turn
@@ -418,7 +443,10 @@ pub fn apply_priority_response_with_dm(
                     let ctx = crate::decisions::context::SelectOptionsContext::new(
                         player,
                         Some(*spell_id),
-                        format!("Choose casting method for {}", spell_name),
+                        crate::display_text::DisplayText::new(
+                            crate::display_text::DisplayKey::ChooseCastingMethodFor,
+                        )
+                        .with_card(spell_name),
                         selectable_options,
                         1,
                         1,
@@ -451,7 +479,7 @@ pub fn apply_priority_response_with_dm(
                 );
                 (cost, obj.spell_effect.clone().unwrap_or_default())
             } else {
-                (None, Vec::new())
+                (None, Default::default())
             };
 
             let (needs_x, max_x) = compute_spell_cast_x_bounds(
@@ -531,6 +559,11 @@ pub fn apply_priority_response_with_dm(
             if game.object(*source).is_some() {
                 if let Some(ability) = game.current_ability(*source, *ability_index) {
                     if let AbilityKind::Activated(activated) = &ability.kind {
+                        if activated.is_mana_ability() {
+                            return Err(GameLoopError::InvalidState(
+                                "Mana abilities don't use the stack".to_string(),
+                            ));
+                        }
                         if !can_activate_ability_with_restrictions(
                             game,
                             *source,
@@ -560,7 +593,7 @@ pub fn apply_priority_response_with_dm(
 
             // Save checkpoint before starting the action chain
             // This allows rollback if the player makes an invalid choice
-            state.save_checkpoint(game);
+            state.save_checkpoint(game, trigger_queue);
 
             // Get the ability cost, effects, tracking info, and source info for the stack entry
             let (
@@ -580,7 +613,7 @@ pub fn apply_priority_response_with_dm(
                         let is_turn_capped = activated.max_activations_per_turn().is_some();
                         (
                             activated.mana_cost.clone(),
-                            activated.effects.clone(),
+                            activated.resolution_effects(),
                             is_turn_capped,
                             stable_id,
                             name,
@@ -804,9 +837,15 @@ pub fn apply_priority_response_with_dm(
 
                     // Add fixed mana to player's pool
                     if !mana_to_add.is_empty() {
+                        let snow_source =
+                            game.object_has_supertype(*source, crate::types::Supertype::Snow);
                         if let Some(player_obj) = game.player_mut(player) {
                             for symbol in &mana_to_add {
-                                player_obj.mana_pool.add(*symbol, 1);
+                                if snow_source {
+                                    player_obj.mana_pool.add_snow(*symbol, 1);
+                                } else {
+                                    player_obj.mana_pool.add(*symbol, 1);
+                                }
                             }
                         }
                     }
@@ -916,7 +955,7 @@ pub fn apply_priority_response_with_dm(
             drain_pending_trigger_events(game, trigger_queue);
 
             // Player retains priority
-            advance_priority_with_dm(game, trigger_queue, decision_maker)
+            retain_priority_after_special_action(game, trigger_queue, state, decision_maker)
         }
         LegalAction::SpecialAction(special) => {
             let player = game
@@ -924,39 +963,49 @@ pub fn apply_priority_response_with_dm(
                 .priority_player
                 .ok_or_else(|| GameLoopError::InvalidState("No priority player".to_string()))?;
 
-            if crate::special_actions::can_perform(special, game, player, &mut *decision_maker)
-                .is_ok()
+            crate::special_actions::can_perform(special, game, player, &mut *decision_maker)
+                .map_err(|e| {
+                    GameLoopError::InvalidState(format!("Cannot perform special action: {e}"))
+                })?;
+            crate::special_actions::perform(special.clone(), game, player, &mut *decision_maker)
+                .map_err(|e| GameLoopError::InvalidState(format!("Failed special action: {e}")))?;
+            if let crate::special_actions::SpecialAction::ActivateManaAbility {
+                permanent_id, ..
+            } = special
             {
-                crate::special_actions::perform(
-                    special.clone(),
+                queue_ability_activated_event(
                     game,
-                    player,
+                    trigger_queue,
                     &mut *decision_maker,
-                )
-                .map_err(|e| GameLoopError::InvalidState(format!("Failed special action: {e}")))?;
-                if let crate::special_actions::SpecialAction::ActivateManaAbility {
-                    permanent_id,
-                    ..
-                } = special
-                {
-                    queue_ability_activated_event(
-                        game,
-                        trigger_queue,
-                        &mut *decision_maker,
-                        *permanent_id,
-                        player,
-                        true,
-                        None,
-                    );
-                }
+                    *permanent_id,
+                    player,
+                    true,
+                    None,
+                );
             }
+            drain_pending_trigger_events(game, trigger_queue);
 
             // Player retains priority after special actions
-            advance_priority_with_dm(game, trigger_queue, decision_maker)
+            retain_priority_after_special_action(game, trigger_queue, state, decision_maker)
         }
     }
 }
 
+/// Give priority back to the player who just took a special action.
+///
+/// Special actions don't use the stack (rule 116.3), so the acting player keeps
+/// priority. Because they took an action, any earlier passes no longer count
+/// toward "all players passed in succession".
+fn retain_priority_after_special_action(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    decision_maker: &mut impl DecisionMaker,
+) -> Result<GameProgress, GameLoopError> {
+    state.tracker.reset();
+    advance_priority_with_dm(game, trigger_queue, decision_maker)
+}
+
 /// Apply a replacement effect choice response.
 ///
 /// When multiple replacement effects could apply to the same event,
@@ -987,7 +1036,12 @@ pub(super) fn apply_replacement_choice_response(
         });
 
     // Process the event with the chosen replacement effect
-    let result = process_event_with_chosen_replacement_trait(game, pending.event, chosen_id);
+    let result = process_event_with_chosen_replacement_trait(
+        game,
+        pending.event,
+        chosen_id,
+        &pending.applied_effects,
+    );
 
     // Handle the result
     match result {
@@ -1025,6 +1079,7 @@ pub(super) fn apply_replacement_choice_response(
             player,
             applicable_effects,
             event,
+            applied_effects,
         } => {
             // Build options first (before moving applicable_effects)
             let options: Vec<_> = applicable_effects
@@ -1049,6 +1104,7 @@ pub(super) fn apply_replacement_choice_response(
                 event: *event,
                 applicable_effects,
                 player,
+                applied_effects,
             });
 
             // Return to prompt for the next choice - convert to SelectOptionsContext
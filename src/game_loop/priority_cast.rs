@@ -54,7 +54,8 @@ pub(super) fn collect_available_casting_methods(
 
     // Check alternative casting methods from hand
     if from_zone == Zone::Hand {
-        if spell.linked_face_layout == crate::card::LinkedFaceLayout::Split {
+        if spell.linked_face_layout == crate::card::LinkedFaceLayout::Split && !spell.has_aftermath
+        {
             if can_cast_spell(game, player, spell, &CastingMethod::SplitOtherHalf)
                 && let Some(other_def) = crate::cards::linked_face_definition_by_name_or_id(
                     spell.other_face_name.as_deref(),
@@ -594,6 +595,27 @@ pub(super) fn extract_modal_spec_from_spell(
     None
 }
 
+/// Whether every mode of a spell can be chosen, as entwine (Rule 702.42) requires.
+///
+/// Entwining exceeds the printed mode count, so each mode is checked on its own.
+pub(super) fn entwine_can_choose_every_mode(
+    game: &GameState,
+    spell_id: ObjectId,
+    caster: PlayerId,
+) -> bool {
+    let Some(modal_spec) = extract_modal_spec_from_spell(game, spell_id, caster) else {
+        return true;
+    };
+    let effects = game
+        .object(spell_id)
+        .and_then(|obj| obj.spell_effect.as_deref())
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    (0..modal_spec.mode_descriptions.len()).all(|mode| {
+        spell_has_legal_targets_with_modes(game, effects, caster, Some(spell_id), Some(&[mode]))
+    })
+}
+
 /// Check for modal effects and either prompt for mode selection or continue to optional costs.
 ///
 /// Per MTG rule 601.2b, modes must be chosen before targets.
@@ -613,6 +635,7 @@ pub(super) fn check_modes_or_continue(
         let spell_effects = game
             .object(source)
             .and_then(|obj| obj.spell_effect.as_deref())
+            .map(Vec::as_slice)
             .unwrap_or(&[]);
 
         // Resolve min/max mode counts
@@ -689,87 +712,159 @@ pub(super) fn check_optional_costs_or_continue(
     pending: PendingCast,
     decision_maker: &mut impl DecisionMaker,
 ) -> Result<GameProgress, GameLoopError> {
-    // Check if the spell has optional costs
-    let optional_costs = if let Some(obj) = game.object(pending.spell_id) {
-        obj.optional_costs.clone()
-    } else {
-        Vec::new()
-    };
+    let cost_count = game
+        .object(pending.spell_id)
+        .map_or(0, |obj| obj.optional_costs.len());
 
-    if optional_costs.is_empty() {
+    if cost_count == 0 {
         // No optional costs, continue to targeting or finalization
         continue_to_targeting_or_finalize(game, trigger_queue, state, pending, decision_maker)
     } else {
-        // Build the optional cost options for the decision
-        let player = pending.caster;
-        let source = pending.spell_id;
-
-        // Check which costs the player can afford (using potential mana)
-        let options: Vec<OptionalCostOption> = optional_costs
-            .iter()
-            .enumerate()
-            .map(|(index, opt_cost)| {
-                // Check if player can afford this cost with potential mana
-                let affordable = if let Some(mana_cost) = opt_cost.cost.mana_cost() {
-                    crate::decision::can_potentially_pay(game, player, mana_cost, 0)
-                } else {
-                    // For non-mana costs, use the regular check
-                    crate::cost::can_pay_cost(game, source, player, &opt_cost.cost).is_ok()
-                };
+        let indices: Vec<usize> = (0..cost_count).collect();
+        Ok(prompt_optional_costs(game, state, pending, &indices))
+    }
+}
 
-                // Format the cost description
-                let cost_description = if let Some(mana) = opt_cost.cost.mana_cost() {
-                    format!("{}", mana.mana_value())
-                } else {
-                    "special".to_string()
-                };
+/// Repeatable optional costs from the last response that may be paid again.
+///
+/// Multikicker, replicate and similar costs may be paid any number of times
+/// (Rule 601.2b), so they are offered again until the player declines or can
+/// no longer afford another payment.
+pub(super) fn repeatable_optional_costs_to_offer(
+    game: &GameState,
+    pending: &PendingCast,
+    just_paid: &[usize],
+) -> Vec<usize> {
+    let Some(obj) = game.object(pending.spell_id) else {
+        return Vec::new();
+    };
+    let base_cost = crate::decision::spell_mana_cost_for_cast(
+        game,
+        pending.caster,
+        obj,
+        &pending.casting_method,
+        pending.from_zone,
+    )
+    .unwrap_or_default();
+    let paid_cost =
+        crate::decision::add_optional_costs_mana(obj, &base_cost, &pending.optional_costs_paid);
+    let x_value = pending.x_value.unwrap_or(0);
 
-                OptionalCostOption {
-                    index,
-                    label: opt_cost.label.clone(),
-                    repeatable: opt_cost.repeatable,
-                    affordable,
-                    cost_description,
-                }
+    just_paid
+        .iter()
+        .copied()
+        .filter(|&index| {
+            obj.optional_costs.get(index).is_some_and(|opt_cost| {
+                opt_cost.repeatable
+                    && pending.optional_costs_paid.times_paid(index) < MAX_OPTIONAL_COST_PAYMENTS
+                    && opt_cost.cost.mana_cost().is_none_or(|mana| {
+                        let next = paid_cost
+                            .pips()
+                            .iter()
+                            .chain(mana.pips())
+                            .cloned()
+                            .collect();
+                        crate::decision::can_potentially_pay(
+                            game,
+                            pending.caster,
+                            &crate::mana::ManaCost::from_pips(next),
+                            x_value,
+                        )
+                    })
             })
-            .collect();
+        })
+        .collect()
+}
 
-        // Set up pending cast for optional costs stage
-        let mut pending = pending;
-        pending.stage = CastStage::ChoosingOptionalCosts;
-        state.pending_cast = Some(pending);
+/// Upper bound on how many times a single repeatable optional cost is offered.
+const MAX_OPTIONAL_COST_PAYMENTS: u32 = 64;
 
-        // Convert to SelectOptionsContext for optional cost selection
-        let selectable_options: Vec<crate::decisions::context::SelectableOption> = options
-            .iter()
-            .map(|opt| {
-                crate::decisions::context::SelectableOption::with_legality(
-                    opt.index,
-                    format!("{}: {}", opt.label, opt.cost_description),
-                    opt.affordable,
-                )
-            })
-            .collect();
-        let spell_name = game
-            .object(source)
-            .map(|o| o.name.clone())
-            .unwrap_or_else(|| "spell".to_string());
-        let ctx = crate::decisions::context::SelectOptionsContext::new(
-            player,
-            Some(source),
-            format!("Choose optional costs for {}", spell_name),
-            selectable_options,
-            0, // min - optional costs are optional
-            if options.iter().any(|opt| opt.repeatable) {
-                64
+pub(super) fn prompt_optional_costs(
+    game: &GameState,
+    state: &mut PriorityLoopState,
+    pending: PendingCast,
+    indices: &[usize],
+) -> GameProgress {
+    let optional_costs = game
+        .object(pending.spell_id)
+        .map(|obj| obj.optional_costs.clone())
+        .unwrap_or_default();
+
+    // Build the optional cost options for the decision
+    let player = pending.caster;
+    let source = pending.spell_id;
+
+    // Check which costs the player can afford (using potential mana)
+    let options: Vec<OptionalCostOption> = indices
+        .iter()
+        .filter_map(|&index| optional_costs.get(index).map(|opt_cost| (index, opt_cost)))
+        .map(|(index, opt_cost)| {
+            // Check if player can afford this cost with potential mana
+            let affordable = if let Some(mana_cost) = opt_cost.cost.mana_cost() {
+                crate::decision::can_potentially_pay(game, player, mana_cost, 0)
             } else {
-                options.len()
-            },
-        );
-        Ok(GameProgress::NeedsDecisionCtx(
-            crate::decisions::context::DecisionContext::SelectOptions(ctx),
-        ))
-    }
+                // For non-mana costs, use the regular check
+                crate::cost::can_pay_cost(game, source, player, &opt_cost.cost).is_ok()
+            };
+            // Entwine can't be chosen unless every mode could be.
+            let affordable = affordable
+                && (!opt_cost.is_entwine() || entwine_can_choose_every_mode(game, source, player));
+
+            // Format the cost description
+            let cost_description = if let Some(mana) = opt_cost.cost.mana_cost() {
+                format!("{}", mana.mana_value())
+            } else {
+                "special".to_string()
+            };
+
+            OptionalCostOption {
+                index,
+                label: opt_cost.label.clone(),
+                repeatable: opt_cost.repeatable,
+                affordable,
+                cost_description,
+            }
+        })
+        .collect();
+
+    // Set up pending cast for optional costs stage
+    let mut pending = pending;
+    pending.stage = CastStage::ChoosingOptionalCosts;
+    state.pending_cast = Some(pending);
+
+    // Convert to SelectOptionsContext for optional cost selection
+    let selectable_options: Vec<crate::decisions::context::SelectableOption> = options
+        .iter()
+        .map(|opt| {
+            crate::decisions::context::SelectableOption::with_legality(
+                opt.index,
+                format!("{}: {}", opt.label, opt.cost_description),
+                opt.affordable,
+            )
+        })
+        .collect();
+    let spell_name = game
+        .object(source)
+        .map(|o| o.name.clone())
+        .unwrap_or_else(|| "spell".to_string());
+    let ctx = crate::decisions::context::SelectOptionsContext::new(
+        player,
+        Some(source),
+        crate::display_text::DisplayText::new(
+            crate::display_text::DisplayKey::ChooseOptionalCostsFor,
+        )
+        .with_card(spell_name),
+        selectable_options,
+        0, // min - optional costs are optional
+        if options.iter().any(|opt| opt.repeatable) {
+            64
+        } else {
+            options.len()
+        },
+    );
+    GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::SelectOptions(
+        ctx,
+    ))
 }
 
 /// Get the effective mana cost for a spell being cast.
@@ -996,7 +1091,7 @@ pub(super) fn continue_to_targets_or_mana_payment(
             requirements
                 .into_iter()
                 .map(|r| crate::decisions::context::TargetRequirementContext {
-                    description: r.description,
+                    description: r.description.into(),
                     legal_targets: r.legal_targets,
                     min_targets: r.min_targets,
                     max_targets: r.max_targets,
@@ -1030,6 +1125,7 @@ pub(super) fn finalize_pending_spell_cast(
         pending.casting_method,
         pending.optional_costs_paid,
         pending.chosen_modes,
+        pending.divided_amounts,
         mana_spent_to_cast,
         pending.keyword_payment_contributions,
         pending.tagged_objects,
@@ -1308,11 +1404,105 @@ pub(super) fn continue_to_mana_payment(
     targets: Vec<Target>,
     decision_maker: &mut impl DecisionMaker,
 ) -> Result<GameProgress, GameLoopError> {
-    use crate::decision::calculate_effective_mana_cost_for_payment_with_chosen_targets;
-
     let mut pending = pending;
     pending.chosen_targets = targets;
 
+    // Per MTG rule 601.2d, divided damage is announced along with the targets.
+    if let Some((division_targets, total)) = divided_damage_for_pending_cast(game, &pending) {
+        if division_targets.len() > 1 {
+            let spec = crate::decisions::specs::DistributeSpec::damage(
+                pending.spell_id,
+                total,
+                division_targets,
+            );
+            let ctx = crate::decisions::spec::DecisionSpec::build_context(
+                &spec,
+                pending.caster,
+                Some(pending.spell_id),
+                game,
+            );
+            pending.stage = CastStage::DividingDamage;
+            state.pending_cast = Some(pending);
+            return Ok(GameProgress::NeedsDecisionCtx(ctx));
+        }
+        pending.divided_amounts = division_targets
+            .first()
+            .map(|target| vec![(*target, total)])
+            .unwrap_or_default();
+    }
+
+    continue_to_spell_cost_payment(game, trigger_queue, state, pending, decision_maker)
+}
+
+/// Find a divided damage effect, looking through the tag wrapper the parser adds.
+fn divided_damage_effect(
+    effect: &crate::effect::Effect,
+) -> Option<&crate::effects::DealDamageEffect> {
+    let effect = effect
+        .downcast_ref::<crate::effects::TaggedEffect>()
+        .map_or(effect, |tagged| tagged.effect.as_ref());
+    effect
+        .downcast_ref::<crate::effects::DealDamageEffect>()
+        .filter(|deal| deal.divided)
+}
+
+/// Find the divided-damage effect of a pending spell, returning the targets
+/// chosen for it and the total amount to divide.
+pub(super) fn divided_damage_for_pending_cast(
+    game: &GameState,
+    pending: &PendingCast,
+) -> Option<(Vec<Target>, u32)> {
+    let effects = game.object(pending.spell_id)?.spell_effect.as_ref()?;
+    let deal = effects.iter().find_map(divided_damage_effect)?;
+
+    let targets: Vec<Target> = pending
+        .chosen_target_assignments
+        .iter()
+        .find(|assignment| assignment.spec == deal.target)
+        .and_then(|assignment| pending.chosen_targets.get(assignment.range.clone()))
+        .map(<[Target]>::to_vec)
+        .unwrap_or_else(|| pending.chosen_targets.clone());
+
+    let mut auto_dm = crate::decision::AutoPassDecisionMaker;
+    let mut ctx = ExecutionContext::new(pending.spell_id, pending.caster, &mut auto_dm);
+    if let Some(x) = pending.x_value {
+        ctx = ctx.with_x(x);
+    }
+    let total = crate::effects::helpers::resolve_value(game, &deal.amount, &ctx)
+        .ok()?
+        .max(0) as u32;
+    Some((targets, total))
+}
+
+/// Apply a damage division response for a pending spell cast.
+pub(super) fn apply_damage_division_response(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    distribution: &[(Target, u32)],
+    decision_maker: &mut impl DecisionMaker,
+) -> Result<GameProgress, GameLoopError> {
+    let mut pending = state.pending_cast.take().ok_or_else(|| {
+        GameLoopError::InvalidState("No pending cast for damage division response".to_string())
+    })?;
+    let (targets, total) = divided_damage_for_pending_cast(game, &pending).ok_or_else(|| {
+        GameLoopError::InvalidState("Pending cast has no divided damage effect".to_string())
+    })?;
+    pending.divided_amounts =
+        crate::effects::DealDamageEffect::normalize_division(&targets, total, distribution);
+
+    continue_to_spell_cost_payment(game, trigger_queue, state, pending, decision_maker)
+}
+
+fn continue_to_spell_cost_payment(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    mut pending: PendingCast,
+    decision_maker: &mut impl DecisionMaker,
+) -> Result<GameProgress, GameLoopError> {
+    use crate::decision::calculate_effective_mana_cost_for_payment_with_chosen_targets;
+
     // Compute the effective mana cost for this spell
     let effective_cost = if let Some(obj) = game.object(pending.spell_id) {
         let base_cost = crate::decision::spell_mana_cost_for_cast(
@@ -1323,6 +1513,12 @@ pub(super) fn continue_to_mana_payment(
             pending.from_zone,
         );
 
+        // Kicker and other paid optional costs add their mana before cost
+        // increases and reductions are applied (Rule 601.2f).
+        let base_cost = base_cost.map(|bc| {
+            crate::decision::add_optional_costs_mana(obj, &bc, &pending.optional_costs_paid)
+        });
+
         // Apply cost reductions (affinity, delve, convoke, improvise)
         base_cost.map(|bc| {
             calculate_effective_mana_cost_for_payment_with_chosen_targets(
@@ -1339,6 +1535,29 @@ pub(super) fn continue_to_mana_payment(
 
     pending.mana_cost_to_pay = effective_cost;
 
+    if let Some((ctx, forced)) = delve_choice_context(game, &pending) {
+        if let Some(chosen) = forced {
+            exile_delve_cards(game, &mut pending, &chosen);
+        } else {
+            pending.stage = CastStage::ChoosingDelve;
+            state.pending_cast = Some(pending);
+            return Ok(GameProgress::NeedsDecisionCtx(
+                crate::decisions::context::DecisionContext::SelectObjects(ctx),
+            ));
+        }
+    }
+
+    continue_to_spell_cost_steps(game, trigger_queue, state, pending, decision_maker)
+}
+
+/// Collect the spell's non-mana cost steps and move on to paying them.
+fn continue_to_spell_cost_steps(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    mut pending: PendingCast,
+    decision_maker: &mut impl DecisionMaker,
+) -> Result<GameProgress, GameLoopError> {
     if pending.remaining_cost_steps.is_empty() {
         pending.remaining_cost_steps = collect_spell_cost_steps(
             game,
@@ -1352,6 +1571,129 @@ pub(super) fn continue_to_mana_payment(
     continue_spell_next_cost_or_finalize(game, trigger_queue, state, pending, decision_maker)
 }
 
+/// Build the Delve graveyard choice for a pending cast (CR 702.66).
+///
+/// The player may exile up to as many cards as the generic mana left to pay,
+/// but at least enough that the rest of the cost is still payable. Returns
+/// the decision context along with the forced choice when only one is legal.
+fn delve_choice_context(
+    game: &GameState,
+    pending: &PendingCast,
+) -> Option<(
+    crate::decisions::context::SelectObjectsContext,
+    Option<Vec<ObjectId>>,
+)> {
+    let spell = game.object(pending.spell_id)?;
+    if !crate::decision::has_delve(spell) {
+        return None;
+    }
+    let cost = pending.mana_cost_to_pay.as_ref()?;
+    let candidates = get_legal_exile_from_graveyard_cards(game, pending.caster, None);
+    let max = (cost.generic_mana_total() as usize).min(candidates.len());
+    if max == 0 {
+        return None;
+    }
+
+    // Convoke and Improvise can also pay generic pips, so don't force cards
+    // the player might cover by tapping permanents instead.
+    let min = if crate::decision::has_convoke(spell) || crate::decision::has_improvise(spell) {
+        0
+    } else {
+        let potential = crate::decision::compute_potential_mana(game, pending.caster);
+        let allow_any_color =
+            game.can_spend_mana_as_any_color(pending.caster, Some(pending.spell_id));
+        let x_value = pending.x_value.unwrap_or(0);
+        (0..=max)
+            .find(|&count| {
+                potential.can_pay_with_any_color(
+                    &cost.reduce_generic(count as u32),
+                    x_value,
+                    allow_any_color,
+                )
+            })
+            .unwrap_or(max)
+    };
+
+    let forced = (min == max && max == candidates.len()).then(|| candidates.clone());
+    let selectable = candidates
+        .iter()
+        .map(|&id| {
+            let name = game
+                .object(id)
+                .map(|o| o.name.clone())
+                .unwrap_or_else(|| format!("Object #{}", id.0));
+            crate::decisions::context::SelectableObject::new(id, name)
+        })
+        .collect();
+    let ctx = crate::decisions::context::SelectObjectsContext::new(
+        pending.caster,
+        Some(pending.spell_id),
+        format!("Choose up to {max} cards to exile from your graveyard for Delve"),
+        selectable,
+        min,
+        Some(max),
+    );
+    Some((ctx, forced))
+}
+
+/// Exile the chosen Delve cards, each paying for {1} of the pending mana cost.
+fn exile_delve_cards(game: &mut GameState, pending: &mut PendingCast, chosen: &[ObjectId]) {
+    #[cfg(feature = "net")]
+    {
+        if !chosen.is_empty() {
+            pending
+                .payment_trace
+                .push(CostStep::Payment(CostPayment::Exile {
+                    objects: chosen.iter().map(|id| GameObjectId(id.0)).collect(),
+                    from_zone: ZoneCode::Graveyard,
+                }));
+        }
+    }
+
+    for &card_id in chosen {
+        game.move_object(card_id, Zone::Exile);
+    }
+    pending.mana_cost_to_pay = pending
+        .mana_cost_to_pay
+        .take()
+        .map(|cost| cost.reduce_generic(chosen.len() as u32));
+}
+
+/// Apply the player's Delve graveyard choice for a pending spell cast.
+pub(super) fn apply_delve_choice_response(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    chosen: &[ObjectId],
+    decision_maker: &mut impl DecisionMaker,
+) -> Result<GameProgress, GameLoopError> {
+    let mut pending = state.pending_cast.take().ok_or_else(|| {
+        GameLoopError::InvalidState("No pending cast for delve response".to_string())
+    })?;
+    let (ctx, _) = delve_choice_context(game, &pending).ok_or_else(|| {
+        GameLoopError::InvalidState("Pending cast has no delve choice".to_string())
+    })?;
+
+    let mut unique = chosen.to_vec();
+    unique.sort();
+    unique.dedup();
+    let legal = unique.len() == chosen.len()
+        && chosen.len() >= ctx.min
+        && ctx.max.is_none_or(|max| chosen.len() <= max)
+        && chosen
+            .iter()
+            .all(|id| ctx.candidates.iter().any(|candidate| candidate.id == *id));
+    if !legal {
+        return Err(GameLoopError::InvalidState(
+            "Selected cards are not a legal delve choice".to_string(),
+        ));
+    }
+
+    exile_delve_cards(game, &mut pending, chosen);
+    drain_pending_trigger_events(game, trigger_queue);
+    continue_to_spell_cost_steps(game, trigger_queue, state, pending, decision_maker)
+}
+
 /// Continue processing spell cast mana payment pip-by-pip.
 pub(super) fn continue_spell_cast_mana_payment(
     game: &mut GameState,
@@ -1977,10 +2319,9 @@ pub(super) fn collect_spell_cost_steps(
             CastingMethod::Alternative(idx) => obj
                 .alternative_casts
                 .get(*idx)
-                .and_then(|method| method.total_cost())
-                .cloned()
+                .map(alternative_method_additional_cost)
                 .unwrap_or_else(crate::cost::TotalCost::free),
-            CastingMethod::GrantedEscape { .. } => crate::cost::TotalCost::free(),
+            CastingMethod::GrantedEscape { exile_count, .. } => escape_exile_cost(*exile_count),
             CastingMethod::GrantedFlashback => crate::cost::TotalCost::free(),
             CastingMethod::PlayFrom {
                 use_alternative: None,
@@ -1993,7 +2334,7 @@ pub(super) fn collect_spell_cost_steps(
             } => crate::decision::resolve_play_from_alternative_method(
                 game, caster, obj, *zone, *idx,
             )
-            .and_then(|method| method.total_cost().cloned())
+            .map(|method| alternative_method_additional_cost(&method))
             .unwrap_or_else(crate::cost::TotalCost::free),
         };
 
@@ -2010,6 +2351,32 @@ pub(super) fn collect_spell_cost_steps(
     cost_steps
 }
 
+/// Non-mana costs that an alternative casting method adds to the spell.
+///
+/// Escape's "exile N other cards from your graveyard" isn't modeled as a
+/// `TotalCost`, so it is expanded here into per-card exile choices.
+fn alternative_method_additional_cost(
+    method: &crate::alternative_cast::AlternativeCastingMethod,
+) -> crate::cost::TotalCost {
+    match method {
+        crate::alternative_cast::AlternativeCastingMethod::Escape { exile_count, .. } => {
+            escape_exile_cost(*exile_count)
+        }
+        _ => method
+            .total_cost()
+            .cloned()
+            .unwrap_or_else(crate::cost::TotalCost::free),
+    }
+}
+
+fn escape_exile_cost(exile_count: u32) -> crate::cost::TotalCost {
+    crate::cost::TotalCost::from_costs(
+        (0..exile_count)
+            .map(|_| crate::costs::Cost::exile_from_graveyard(1, None))
+            .collect(),
+    )
+}
+
 pub(super) fn describe_cost_component(cost: &crate::costs::Cost) -> String {
     if cost.requires_tap() {
         return "Tap this permanent".to_string();
@@ -2092,7 +2459,8 @@ pub(super) fn build_next_cost_context(
     crate::decisions::context::SelectOptionsContext::new(
         player,
         Some(source),
-        format!("Choose the next cost to pay for {}", source_name),
+        crate::display_text::DisplayText::new(crate::display_text::DisplayKey::ChooseNextCostFor)
+            .with_card(source_name),
         options,
         1,
         1,
@@ -2696,7 +3064,7 @@ pub(super) fn continue_activation(
                     requirements
                         .into_iter()
                         .map(|r| crate::decisions::context::TargetRequirementContext {
-                            description: r.description,
+                            description: r.description.into(),
                             legal_targets: r.legal_targets,
                             min_targets: r.min_targets,
                             max_targets: r.max_targets,
@@ -68,18 +68,8 @@ pub fn advance_priority_with_dm(
     put_triggers_on_stack_with_dm(game, trigger_queue, decision_maker)?;
 
     // Check if game is over
-    let remaining: Vec<_> = game
-        .players
-        .iter()
-        .filter(|p| p.is_in_game())
-        .map(|p| p.id)
-        .collect();
-
-    if remaining.is_empty() {
-        return Ok(GameProgress::GameOver(GameResult::Draw));
-    }
-    if remaining.len() == 1 {
-        return Ok(GameProgress::GameOver(GameResult::Winner(remaining[0])));
+    if let Some(result) = game.game_result() {
+        return Ok(GameProgress::GameOver(result));
     }
 
     // Get current priority player
@@ -89,7 +79,7 @@ pub fn advance_priority_with_dm(
     };
 
     // Compute legal actions for the priority player
-    let mut actions = compute_legal_actions(game, priority_player);
+    let mut actions = compute_legal_actions_cached(game, priority_player);
     actions.extend(compute_commander_actions(game, priority_player));
 
     // Return decision for the player using the new context-based system
@@ -109,6 +99,6 @@ pub fn apply_priority_response(
     state: &mut PriorityLoopState,
     response: &PriorityResponse,
 ) -> Result<GameProgress, GameLoopError> {
-    let mut auto_dm = crate::decision::CliDecisionMaker;
+    let mut auto_dm = crate::decision::CliDecisionMaker::default();
     apply_priority_response_with_dm(game, trigger_queue, state, response, &mut auto_dm)
 }
@@ -408,16 +408,26 @@ pub(super) fn build_pip_payment_options(
                 }
             }
             ManaSymbol::Snow => {
-                // Snow mana - for now treat like generic
-                if let Some(p) = pool
-                    && p.total() > 0
-                {
-                    // Just offer any available mana
-                    if p.colorless > 0 {
+                // Only mana produced by snow sources can pay {S}
+                for snow_symbol in [
+                    ManaSymbol::Colorless,
+                    ManaSymbol::White,
+                    ManaSymbol::Blue,
+                    ManaSymbol::Black,
+                    ManaSymbol::Red,
+                    ManaSymbol::Green,
+                ] {
+                    if pool.is_some_and(|p| p.snow_amount(snow_symbol) > 0)
+                        && pool_symbol_count(game, player, snow_symbol, source_for_pip_alternatives)
+                            > 0
+                    {
                         options.push(ManaPipPaymentOption {
                             index,
-                            description: "Use {C} from mana pool".to_string(),
-                            action: ManaPipPaymentAction::UseFromPool(ManaSymbol::Colorless),
+                            description: format!(
+                                "Use snow {} from mana pool",
+                                crate::mana::ManaCost::from_symbols(vec![snow_symbol]).to_oracle()
+                            ),
+                            action: ManaPipPaymentAction::UseFromPool(snow_symbol),
                         });
                         index += 1;
                     }
@@ -704,11 +714,19 @@ pub(super) fn pool_symbol_count(
         .saturating_add(restricted_payable)
 }
 
+/// Returns true when a pip can only be paid with snow mana ({S}).
+pub(super) fn pip_requires_snow_mana(pip: &[crate::mana::ManaSymbol]) -> bool {
+    pip.contains(&crate::mana::ManaSymbol::Snow)
+}
+
+/// Spend one mana of `symbol` from the pool, preferring restricted mana the
+/// payment can use. With `snow_only`, only snow mana of that type is spent.
 pub(super) fn spend_pool_symbol(
     game: &mut GameState,
     player: PlayerId,
     symbol: crate::mana::ManaSymbol,
     payment_source: Option<ObjectId>,
+    snow_only: bool,
 ) -> Option<SpentManaInfo> {
     let payable_restricted_index = game.player(player).and_then(|player_obj| {
         player_obj
@@ -732,8 +750,15 @@ pub(super) fn spend_pool_symbol(
     });
 
     let player_obj = game.player_mut(player)?;
+    let remove_from_pool = |pool: &mut ManaPool| {
+        if snow_only {
+            pool.remove_snow(symbol, 1)
+        } else {
+            pool.remove(symbol, 1)
+        }
+    };
     if let Some(idx) = payable_restricted_index {
-        if !player_obj.mana_pool.remove(symbol, 1) {
+        if !remove_from_pool(&mut player_obj.mana_pool) {
             return None;
         }
         let unit = player_obj.restricted_mana.remove(idx);
@@ -743,7 +768,7 @@ pub(super) fn spend_pool_symbol(
         });
     }
 
-    if unrestricted_available && player_obj.mana_pool.remove(symbol, 1) {
+    if unrestricted_available && remove_from_pool(&mut player_obj.mana_pool) {
         return Some(SpentManaInfo {
             symbol,
             restrictions: Vec::new(),
@@ -848,6 +873,12 @@ pub(super) fn mana_ability_can_pay_pip(
             match (produced, pip_symbol) {
                 // Any mana can pay generic
                 (_, ManaSymbol::Generic(_)) => return true,
+                // Any mana from a snow source can pay snow
+                (_, ManaSymbol::Snow)
+                    if game.object_has_supertype(perm_id, crate::types::Supertype::Snow) =>
+                {
+                    return true;
+                }
                 // Exact color matches
                 (ManaSymbol::White, ManaSymbol::White) => return true,
                 (ManaSymbol::Blue, ManaSymbol::Blue) => return true,
@@ -1104,12 +1135,14 @@ pub(super) fn execute_pip_payment_action(
 ) -> Result<bool, GameLoopError> {
     match action {
         ManaPipPaymentAction::UseFromPool(symbol) => {
-            let spent_info = spend_pool_symbol(game, player, *symbol, source).ok_or_else(|| {
-                GameLoopError::InvalidState(format!(
-                    "Not enough {} mana in the pool",
-                    crate::mana::ManaCost::from_symbols(vec![*symbol]).to_oracle()
-                ))
-            })?;
+            let snow_only = pip_requires_snow_mana(pip);
+            let spent_info = spend_pool_symbol(game, player, *symbol, source, snow_only)
+                .ok_or_else(|| {
+                    GameLoopError::InvalidState(format!(
+                        "Not enough {} mana in the pool",
+                        crate::mana::ManaCost::from_symbols(vec![*symbol]).to_oracle()
+                    ))
+                })?;
             if let Some(spent) = mana_spent_to_cast.as_deref_mut() {
                 track_spent_mana_symbol(spent, spent_info.symbol);
             }
@@ -1168,6 +1201,16 @@ pub(super) fn execute_pip_payment_action(
             if let Some(player_obj) = game.player_mut(player) {
                 player_obj.lose_life(*amount);
             }
+            if *amount > 0 {
+                let event_provenance = game
+                    .provenance_graph
+                    .alloc_root_event(crate::events::EventKind::LifeLoss);
+                let event = TriggerEvent::new_with_provenance(
+                    LifeLossEvent::from_cost(player, *amount),
+                    event_provenance,
+                );
+                queue_triggers_from_event(game, trigger_queue, event, true);
+            }
             record_pip_payment_action(payment_trace, action);
             Ok(true) // Pip was paid
         }
@@ -1264,8 +1307,10 @@ pub(super) fn spend_pool_mana_for_pip(
         }
     }
 
+    let snow_only = pip_requires_snow_mana(pip);
     for symbol in candidates {
-        if let Some(spent_info) = spend_pool_symbol(game, player, symbol, payment_source) {
+        if let Some(spent_info) = spend_pool_symbol(game, player, symbol, payment_source, snow_only)
+        {
             return Some(spent_info);
         }
     }
@@ -1390,6 +1435,7 @@ pub(super) fn apply_modes_response(
     let effects = game
         .object(pending.spell_id)
         .and_then(|obj| obj.spell_effect.as_deref())
+        .map(Vec::as_slice)
         .unwrap_or(&[]);
 
     if !spell_has_legal_targets_with_modes(
@@ -1430,11 +1476,55 @@ pub(super) fn apply_optional_costs_response(
         GameLoopError::InvalidState("No pending cast for optional costs response".to_string())
     })?;
 
-    // Store the optional costs paid
+    // Store the optional costs paid. Entwine is only offered when every mode
+    // can be chosen, so a response that picks it anyway leaves it unpaid.
+    let optional_costs = game
+        .object(pending.spell_id)
+        .map(|obj| obj.optional_costs.clone())
+        .unwrap_or_default();
     for &(index, times) in choices {
+        if optional_costs
+            .get(index)
+            .is_some_and(|opt_cost| opt_cost.is_entwine())
+            && !entwine_can_choose_every_mode(game, pending.spell_id, pending.caster)
+        {
+            continue;
+        }
         pending.optional_costs_paid.pay_times(index, times);
     }
 
+    // Offer repeatable costs (multikicker, replicate) again until declined.
+    let just_paid: Vec<usize> = choices
+        .iter()
+        .filter(|&&(_, times)| times > 0)
+        .map(|&(index, _)| index)
+        .collect();
+    let repeatable = repeatable_optional_costs_to_offer(game, &pending, &just_paid);
+    if !repeatable.is_empty() {
+        return Ok(prompt_optional_costs(game, state, pending, &repeatable));
+    }
+
+    // Entwine (Rule 702.42) chooses every mode instead of the announced ones.
+    if pending.optional_costs_paid.was_entwined()
+        && let Some(modal_spec) =
+            extract_modal_spec_from_spell(game, pending.spell_id, pending.caster)
+    {
+        let all_modes: Vec<usize> = (0..modal_spec.mode_descriptions.len()).collect();
+        let effects = game
+            .object(pending.spell_id)
+            .and_then(|obj| obj.spell_effect.as_deref())
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        pending.remaining_requirements = extract_target_requirements_with_modes(
+            game,
+            effects,
+            pending.caster,
+            Some(pending.spell_id),
+            Some(&all_modes),
+        );
+        pending.chosen_modes = Some(all_modes);
+    }
+
     // Continue to targeting or finalization
     continue_to_targeting_or_finalize(game, trigger_queue, state, pending, decision_maker)
 }
@@ -1766,9 +1856,14 @@ pub(super) fn execute_pending_mana_ability(
 
     // Add fixed mana to player's pool
     if !pending.mana_to_add.is_empty() {
+        let snow_source = game.object_has_supertype(pending.source, crate::types::Supertype::Snow);
         if let Some(player_obj) = game.player_mut(pending.activator) {
             for symbol in &pending.mana_to_add {
-                player_obj.mana_pool.add(*symbol, 1);
+                if snow_source {
+                    player_obj.mana_pool.add_snow(*symbol, 1);
+                } else {
+                    player_obj.mana_pool.add(*symbol, 1);
+                }
             }
         }
     }
@@ -2856,7 +2951,7 @@ pub(super) fn apply_casting_method_choice_response(
         );
         (cost, obj.spell_effect.clone().unwrap_or_default())
     } else {
-        (None, Vec::new())
+        (None, Default::default())
     };
 
     let (needs_x, max_x) =
@@ -2972,7 +3067,7 @@ pub(super) fn propose_spell_cast(
             if let crate::alternative_cast::AlternativeCastingMethod::Overload { effects, .. } =
                 method
             {
-                obj.spell_effect = Some(effects.clone());
+                obj.spell_effect = Some(effects.clone().into());
             }
         }
 
@@ -3049,6 +3144,7 @@ pub(super) fn finalize_spell_cast(
     casting_method: CastingMethod,
     optional_costs_paid: OptionalCostsPaid,
     chosen_modes: Option<Vec<usize>>,
+    divided_amounts: Vec<(Target, u32)>,
     mut mana_spent_to_cast: ManaPool,
     keyword_payment_contributions: Vec<KeywordPaymentContribution>,
     stack_entry_tagged_objects: std::collections::HashMap<crate::tag::TagKey, Vec<ObjectSnapshot>>,
@@ -3062,63 +3158,11 @@ pub(super) fn finalize_spell_cast(
     #[cfg(not(feature = "net"))]
     let _ = payment_trace;
 
-    // Get the mana cost, alternative additional cost, and exile count based on casting method.
-    let (base_mana_cost, _alternative_additional_cost, granted_escape_exile_count) =
-        if let Some(obj) = game.object(spell_id) {
-            let base_mana_cost = crate::decision::spell_mana_cost_for_cast(
-                game,
-                caster,
-                obj,
-                &casting_method,
-                from_zone,
-            );
-            match &casting_method {
-                CastingMethod::Normal => (base_mana_cost, crate::cost::TotalCost::free(), None),
-                CastingMethod::SplitOtherHalf | CastingMethod::Fuse => {
-                    (base_mana_cost, crate::cost::TotalCost::free(), None)
-                }
-                CastingMethod::Alternative(idx) => {
-                    if let Some(method) = obj.alternative_casts.get(*idx) {
-                        if let Some(total_cost) = method.total_cost() {
-                            (base_mana_cost, total_cost.clone(), None)
-                        } else {
-                            (base_mana_cost, crate::cost::TotalCost::free(), None)
-                        }
-                    } else {
-                        (base_mana_cost, crate::cost::TotalCost::free(), None)
-                    }
-                }
-                CastingMethod::GrantedEscape { exile_count, .. } => (
-                    base_mana_cost,
-                    crate::cost::TotalCost::free(),
-                    Some(*exile_count),
-                ),
-                CastingMethod::GrantedFlashback => {
-                    (base_mana_cost, crate::cost::TotalCost::free(), None)
-                }
-                CastingMethod::PlayFrom {
-                    use_alternative: None,
-                    ..
-                } => (base_mana_cost, crate::cost::TotalCost::free(), None),
-                CastingMethod::PlayFrom {
-                    use_alternative: Some(idx),
-                    zone,
-                    ..
-                } => crate::decision::resolve_play_from_alternative_method(
-                    game, caster, obj, *zone, *idx,
-                )
-                .map(|method| {
-                    if let Some(total_cost) = method.total_cost() {
-                        (base_mana_cost.clone(), total_cost.clone(), None)
-                    } else {
-                        (base_mana_cost.clone(), crate::cost::TotalCost::free(), None)
-                    }
-                })
-                .unwrap_or_else(|| (base_mana_cost, crate::cost::TotalCost::free(), None)),
-            }
-        } else {
-            (None, crate::cost::TotalCost::free(), None)
-        };
+    // Get the mana cost for the casting method. Non-mana additional costs
+    // (including escape's graveyard exile) were paid as pending cost steps.
+    let base_mana_cost = game.object(spell_id).and_then(|obj| {
+        crate::decision::spell_mana_cost_for_cast(game, caster, obj, &casting_method, from_zone)
+    });
 
     // Calculate effective cost and Delve exile count
     let (effective_cost, delve_exile_count) = if let Some(ref base_cost) = base_mana_cost {
@@ -3141,8 +3185,9 @@ pub(super) fn finalize_spell_cast(
         (None, 0)
     };
 
-    // Pay Delve cost (exile cards from graveyard)
-    if delve_exile_count > 0 {
+    // Pay Delve cost (exile cards from graveyard). When mana was paid through
+    // the pending-cast flow, the player already chose the Delve cards.
+    if !mana_already_paid && delve_exile_count > 0 {
         // Collect cards to exile for Delve
         let cards_to_exile: Vec<ObjectId> = if let Some(player) = game.player(caster) {
             player
@@ -3193,43 +3238,6 @@ pub(super) fn finalize_spell_cast(
         }
     }
 
-    // Pay granted escape additional cost (exile cards from graveyard)
-    if let Some(exile_count) = granted_escape_exile_count {
-        // First, collect cards to exile (immutable borrow)
-        let cards_to_exile: Vec<ObjectId> = if let Some(player) = game.player(caster) {
-            player
-                .graveyard
-                .iter()
-                .filter(|&&id| id != spell_id)
-                .take(exile_count as usize)
-                .copied()
-                .collect()
-        } else {
-            Vec::new()
-        };
-
-        if cards_to_exile.len() < exile_count as usize {
-            return Err(GameLoopError::InvalidState(
-                "Not enough cards in graveyard to exile for escape".to_string(),
-            ));
-        }
-
-        #[cfg(feature = "net")]
-        {
-            if !cards_to_exile.is_empty() {
-                payment_trace.push(CostStep::Payment(CostPayment::Exile {
-                    objects: cards_to_exile.iter().map(|id| GameObjectId(id.0)).collect(),
-                    from_zone: ZoneCode::Graveyard,
-                }));
-            }
-        }
-
-        // Move to exile (move_object handles removal from old zone)
-        for card_id in cards_to_exile {
-            game.move_object(card_id, Zone::Exile);
-        }
-    }
-
     // Spell was already moved to stack during proposal (601.2a compliant).
     let mana_spent_total = mana_spent_to_cast.total();
     let new_id = stack_id;
@@ -3245,6 +3253,7 @@ pub(super) fn finalize_spell_cast(
         .with_casting_method(casting_method)
         .with_optional_costs_paid(optional_costs_paid)
         .with_chosen_modes(chosen_modes)
+        .with_divided_amounts(divided_amounts)
         .with_tagged_objects(stack_entry_tagged_objects)
         .with_keyword_payment_contributions(keyword_payment_contributions);
     if let Some(spell_obj) = game.object(new_id) {
@@ -3265,10 +3274,12 @@ pub(super) fn finalize_spell_cast(
     );
 
     // Track that a spell was cast this turn (per-caster)
-    *game.spells_cast_this_turn.entry(caster).or_insert(0) += 1;
+    game.turn_trackers.record_spell_cast(caster);
     if from_zone == Zone::Command {
         game.record_commander_cast_from_command_zone(new_id);
     }
+    game.record_spell_cast_this_game(new_id, caster, from_zone);
+    crate::decision::consume_limited_spell_cost_reductions(game, caster, new_id);
     game.spells_cast_this_turn_total = game.spells_cast_this_turn_total.saturating_add(1);
     game.spell_cast_order_this_turn
         .insert(new_id, game.spells_cast_this_turn_total);
@@ -3314,13 +3325,28 @@ pub(super) fn finalize_spell_cast(
 ///
 /// This drives the priority loop to completion using the provided decision maker.
 /// Auto-passes priority when PassPriority is the only available action.
-#[allow(clippy::never_loop)] // Loop structure is intentional for clarity
 pub fn run_priority_loop_with<D: DecisionMaker>(
     game: &mut GameState,
     trigger_queue: &mut TriggerQueue,
     decision_maker: &mut D,
 ) -> Result<GameProgress, GameLoopError> {
     let mut state = PriorityLoopState::new(game.players_in_game());
+    run_priority_loop_with_state(game, trigger_queue, &mut state, decision_maker)
+}
+
+/// Run the priority loop with caller-owned loop state.
+///
+/// Like [`run_priority_loop_with`], but the caller keeps `state` (and its
+/// clock) across calls. Priority policies live on the
+/// [`DecisionRouter`](crate::decision::DecisionRouter).
+#[allow(clippy::never_loop)] // Loop structure is intentional for clarity
+pub fn run_priority_loop_with_state<D: DecisionMaker>(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    decision_maker: &mut D,
+) -> Result<GameProgress, GameLoopError> {
+    state.tracker.set_players_in_game(game.players_in_game());
 
     loop {
         // Use decision maker for triggered ability target selection
@@ -3336,18 +3362,27 @@ pub fn run_priority_loop_with<D: DecisionMaker>(
                         apply_priority_action_with_dm(
                             game,
                             trigger_queue,
-                            &mut state,
+                            state,
                             &LegalAction::PassPriority,
                             decision_maker,
                         )
                     } else {
-                        apply_decision_context_with_dm(
+                        let started_ms = decision_maker.clock_now_ms();
+                        let result = apply_decision_context_with_dm(
                             game,
                             trigger_queue,
-                            &mut state,
+                            state,
                             &current_ctx,
                             decision_maker,
-                        )
+                        );
+                        // Charge the time spent to the deciding player's clock.
+                        if let Some(clock) = state.clock.as_mut()
+                            && let (Some(started), Some(finished)) =
+                                (started_ms, decision_maker.clock_now_ms())
+                        {
+                            clock.charge(current_ctx.player(), finished.saturating_sub(started))?;
+                        }
+                        result
                     };
 
                     // Notify decision maker about auto-pass
@@ -3366,6 +3401,9 @@ pub fn run_priority_loop_with<D: DecisionMaker>(
                                 decision_maker.on_action_cancelled(game, &format!("{}", e));
                                 // Restore game state from checkpoint
                                 *game = checkpoint;
+                                if let Some(queue) = state.checkpoint_trigger_queue.take() {
+                                    *trigger_queue = queue;
+                                }
                                 // Clear any pending action state
                                 state.pending_cast = None;
                                 state.pending_activation = None;
@@ -3478,6 +3516,19 @@ pub(crate) fn apply_decision_context_with_dm<D: DecisionMaker>(
         }
         DecisionContext::SelectObjects(objects_ctx) => {
             let result = decision_maker.decide_objects(game, objects_ctx);
+            if state
+                .pending_cast
+                .as_ref()
+                .is_some_and(|pending| pending.stage == CastStage::ChoosingDelve)
+            {
+                return apply_delve_choice_response(
+                    game,
+                    trigger_queue,
+                    state,
+                    &result,
+                    decision_maker,
+                );
+            }
             let chosen = result.first().copied().ok_or_else(|| {
                 GameLoopError::InvalidState("No object selected for required choice".to_string())
             })?;
@@ -3529,7 +3580,13 @@ pub(crate) fn apply_decision_context_with_dm<D: DecisionMaker>(
                 .as_ref()
                 .is_some_and(|pending| matches!(pending.stage, CastStage::ChoosingOptionalCosts))
             {
-                let choices: Vec<(usize, u32)> = result.into_iter().map(|idx| (idx, 1)).collect();
+                let mut choices: Vec<(usize, u32)> = Vec::new();
+                for idx in result {
+                    match choices.iter_mut().find(|(index, _)| *index == idx) {
+                        Some((_, times)) => *times += 1,
+                        None => choices.push((idx, 1)),
+                    }
+                }
                 return apply_optional_costs_response(
                     game,
                     trigger_queue,
@@ -3599,6 +3656,21 @@ pub(crate) fn apply_decision_context_with_dm<D: DecisionMaker>(
                 "Unsupported SelectOptions decision in priority loop".to_string(),
             ))
         }
+        DecisionContext::Distribute(distribute_ctx)
+            if state
+                .pending_cast
+                .as_ref()
+                .is_some_and(|pending| matches!(pending.stage, CastStage::DividingDamage)) =>
+        {
+            let distribution = decision_maker.decide_distribute(game, distribute_ctx);
+            apply_damage_division_response(
+                game,
+                trigger_queue,
+                state,
+                &distribution,
+                decision_maker,
+            )
+        }
         DecisionContext::Distribute(_) | DecisionContext::Counters(_) => {
             if state.pending_activation.as_ref().is_some_and(|pending| {
                 pending.pending_remove_counters_among.is_some()
@@ -3736,6 +3808,67 @@ mod priority_mana_tests {
         crate::tests::test_helpers::setup_two_player_game()
     }
 
+    #[test]
+    fn test_snow_pip_is_paid_only_with_mana_from_snow_sources() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+
+        let snow_forest = CardDefinitionBuilder::new(CardId::new(), "Snow-Covered Forest")
+            .supertypes(vec![
+                crate::types::Supertype::Basic,
+                crate::types::Supertype::Snow,
+            ])
+            .card_types(vec![CardType::Land])
+            .subtypes(vec![Subtype::Forest])
+            .parse_text("{T}: Add {G}.")
+            .expect("snow land text should parse");
+        let snow_forest_id =
+            game.create_object_from_definition(&snow_forest, alice, Zone::Battlefield);
+        let forest_id = game.create_object_from_definition(
+            &crate::cards::definitions::basic_forest(),
+            alice,
+            Zone::Battlefield,
+        );
+
+        assert!(mana_ability_can_pay_pip(
+            &game,
+            snow_forest_id,
+            0,
+            None,
+            &[ManaSymbol::Snow],
+            false
+        ));
+        assert!(
+            !mana_ability_can_pay_pip(&game, forest_id, 0, None, &[ManaSymbol::Snow], false),
+            "a non-snow land can't pay {{S}}"
+        );
+
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        for land in [snow_forest_id, forest_id] {
+            crate::special_actions::perform_activate_mana_ability(
+                &mut game, alice, land, 0, &mut dm,
+            )
+            .expect("tap land for mana");
+        }
+        let pool = &game.player(alice).unwrap().mana_pool;
+        assert_eq!(pool.green, 2);
+        assert_eq!(pool.snow_amount(ManaSymbol::Green), 1);
+
+        let snow_cost =
+            crate::mana::ManaCost::from_symbols(vec![ManaSymbol::Snow, ManaSymbol::Snow]);
+        assert!(
+            !pool.can_pay(&snow_cost, 0),
+            "only one green came from a snow source"
+        );
+
+        assert!(spend_pool_symbol(&mut game, alice, ManaSymbol::Green, None, true).is_some());
+        assert!(
+            spend_pool_symbol(&mut game, alice, ManaSymbol::Green, None, true).is_none(),
+            "the remaining green isn't snow mana"
+        );
+        assert_eq!(game.player(alice).unwrap().mana_pool.green, 1);
+    }
+
     #[test]
     fn test_variable_mana_ability_can_pay_colored_pip() {
         let mut game = setup_game();
@@ -3829,8 +3962,14 @@ mod priority_mana_tests {
                 source_chosen_creature_type: Some(Subtype::Giant),
                 restrictions: vec![restriction.clone()],
             });
-        let spent = spend_pool_symbol(&mut game, alice, ManaSymbol::Green, Some(matching_spell_id))
-            .expect("restricted mana should be spendable on matching spell");
+        let spent = spend_pool_symbol(
+            &mut game,
+            alice,
+            ManaSymbol::Green,
+            Some(matching_spell_id),
+            false,
+        )
+        .expect("restricted mana should be spendable on matching spell");
         apply_spent_mana_bonuses(&mut game, Some(matching_spell_id), &spent.restrictions);
 
         assert!(
@@ -3977,4 +4116,308 @@ mod priority_mana_tests {
         );
         let _ = payment_trace;
     }
+
+    /// Activates one scripted action for Alice, then passes, recording the
+    /// stack size and Alice's green mana at every priority point.
+    struct ActivateOnceDecisionMaker {
+        action: Option<LegalAction>,
+        seen: Vec<(PlayerId, usize, u32)>,
+    }
+
+    impl ActivateOnceDecisionMaker {
+        fn new(action: LegalAction) -> Self {
+            Self {
+                action: Some(action),
+                seen: Vec::new(),
+            }
+        }
+
+        fn record(&mut self, game: &GameState, player: PlayerId) {
+            let green = game
+                .player(PlayerId::from_index(0))
+                .map(|p| p.mana_pool.amount(ManaSymbol::Green))
+                .unwrap_or(0);
+            self.seen.push((player, game.stack.len(), green));
+        }
+    }
+
+    impl DecisionMaker for ActivateOnceDecisionMaker {
+        fn on_auto_pass(&mut self, game: &GameState, player: PlayerId) {
+            self.record(game, player);
+        }
+
+        fn decide_priority(
+            &mut self,
+            game: &GameState,
+            ctx: &crate::decisions::context::PriorityContext,
+        ) -> LegalAction {
+            self.record(game, ctx.player);
+            match self.action.take() {
+                Some(action) if ctx.actions.contains(&action) => action,
+                _ => LegalAction::PassPriority,
+            }
+        }
+
+        fn decide_targets(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::TargetsContext,
+        ) -> Vec<crate::game_state::Target> {
+            ctx.requirements
+                .iter()
+                .filter_map(|req| req.legal_targets.first().cloned())
+                .collect()
+        }
+    }
+
+    fn main_phase_for(game: &mut GameState, player: PlayerId) {
+        game.turn.phase = crate::game_state::Phase::FirstMain;
+        game.turn.step = None;
+        game.turn.active_player = player;
+        game.turn.priority_player = Some(player);
+    }
+
+    /// "Add {G}. Tap target creature." -- adds mana, but targets.
+    fn targeted_green_ability() -> Ability {
+        Ability {
+            kind: AbilityKind::Activated(ActivatedAbility {
+                mana_cost: TotalCost::free(),
+                effects: vec![crate::effect::Effect::tap(
+                    crate::target::ChooseSpec::target_creature(),
+                )],
+                choices: vec![],
+                timing: crate::ability::ActivationTiming::AnyTime,
+                additional_restrictions: vec![],
+                activation_restrictions: vec![],
+                mana_output: Some(vec![ManaSymbol::Green]),
+                activation_condition: None,
+                mana_usage_restrictions: vec![],
+            }),
+            functional_zones: vec![Zone::Battlefield],
+            text: None,
+        }
+    }
+
+    #[test]
+    fn test_wall_of_roots_activation_resolves_without_priority_round() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        main_phase_for(&mut game, alice);
+
+        let wall_id =
+            game.create_object_from_definition(&wall_of_roots(), alice, Zone::Battlefield);
+        let ability_index = game
+            .object(wall_id)
+            .and_then(|obj| {
+                obj.abilities
+                    .iter()
+                    .position(|ability| ability.is_mana_ability())
+            })
+            .expect("wall of roots should have a mana ability");
+
+        let actions = crate::decision::compute_legal_actions(&game, alice);
+        let action = LegalAction::ActivateManaAbility {
+            source: wall_id,
+            ability_index,
+        };
+        assert!(actions.contains(&action));
+        assert!(!actions.contains(&LegalAction::ActivateAbility {
+            source: wall_id,
+            ability_index,
+        }));
+
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        // Asking to put it on the stack anyway is refused.
+        let mut stack_attempt = game.clone();
+        assert!(matches!(
+            crate::game_loop::apply_priority_response(
+                &mut stack_attempt,
+                &mut trigger_queue,
+                &mut state,
+                &PriorityResponse::PriorityAction(LegalAction::ActivateAbility {
+                    source: wall_id,
+                    ability_index,
+                }),
+            ),
+            Err(GameLoopError::InvalidState(_))
+        ));
+        assert!(stack_attempt.stack.is_empty());
+
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        let mut dm = ActivateOnceDecisionMaker::new(action);
+        run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm)
+            .expect("priority loop should run");
+
+        // Alice gets priority straight back with the mana in her pool; the
+        // activation never reached the stack or gave Bob a chance to respond.
+        assert_eq!(dm.seen[0], (alice, 0, 0));
+        assert_eq!(dm.seen[1], (alice, 0, 1));
+        assert!(dm.seen.iter().all(|&(_, stack_len, _)| stack_len == 0));
+    }
+
+    #[test]
+    fn test_targeted_mana_producing_ability_uses_the_stack() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        main_phase_for(&mut game, alice);
+
+        let elf = CardDefinitionBuilder::new(CardId::new(), "Pseudo-Mana Elf")
+            .card_types(vec![CardType::Creature])
+            .build();
+        let elf_id = game.create_object_from_definition(&elf, alice, Zone::Battlefield);
+        game.object_mut(elf_id)
+            .expect("elf should exist")
+            .abilities
+            .push(targeted_green_ability());
+        let ability_index = game.object(elf_id).unwrap().abilities.len() - 1;
+        assert!(
+            !game.object(elf_id).unwrap().abilities[ability_index].is_mana_ability(),
+            "an ability that targets is not a mana ability"
+        );
+
+        let actions = crate::decision::compute_legal_actions(&game, alice);
+        let action = LegalAction::ActivateAbility {
+            source: elf_id,
+            ability_index,
+        };
+        assert!(actions.contains(&action));
+        assert!(!actions.contains(&LegalAction::ActivateManaAbility {
+            source: elf_id,
+            ability_index,
+        }));
+
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        let mut dm = ActivateOnceDecisionMaker::new(action);
+        loop {
+            run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm)
+                .expect("priority loop should run");
+            if game.stack.is_empty() {
+                break;
+            }
+        }
+
+        // Alice got priority back with the ability on the stack, where it
+        // could be responded to, and the mana was only added on resolution.
+        assert_eq!(dm.seen[1], (alice, 1, 0));
+        assert_eq!(
+            game.player(alice)
+                .unwrap()
+                .mana_pool
+                .amount(ManaSymbol::Green),
+            1
+        );
+        assert!(game.is_tapped(elf_id));
+    }
+
+    #[test]
+    fn test_granted_targeted_mana_producing_ability_uses_the_stack() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        main_phase_for(&mut game, alice);
+
+        let granter = CardDefinitionBuilder::new(CardId::new(), "Pseudo-Mana Granter")
+            .card_types(vec![CardType::Enchantment])
+            .build();
+        let granter_id = game.create_object_from_definition(&granter, alice, Zone::Battlefield);
+        game.object_mut(granter_id)
+            .expect("granter should exist")
+            .abilities
+            .push(Ability::static_ability(
+                crate::static_abilities::StaticAbility::grant_object_ability_for_filter(
+                    crate::target::ObjectFilter::creature().you_control(),
+                    targeted_green_ability(),
+                    "Creatures you control have \"Add {G}. Tap target creature.\"".to_string(),
+                ),
+            ));
+        let bear = CardDefinitionBuilder::new(CardId::new(), "Granted Bear")
+            .card_types(vec![CardType::Creature])
+            .build();
+        let bear_id = game.create_object_from_definition(&bear, alice, Zone::Battlefield);
+        game.update_static_ability_effects();
+
+        let ability_index = game
+            .current_abilities(bear_id)
+            .and_then(|abilities| {
+                abilities.iter().position(|ability| {
+                    matches!(&ability.kind, AbilityKind::Activated(activated)
+                        if activated.mana_output.is_some())
+                })
+            })
+            .expect("the bear should have the granted ability");
+        assert!(
+            !game
+                .current_ability(bear_id, ability_index)
+                .unwrap()
+                .is_mana_ability(),
+            "a granted ability that targets is not a mana ability"
+        );
+
+        let actions = crate::decision::compute_legal_actions(&game, alice);
+        let action = LegalAction::ActivateAbility {
+            source: bear_id,
+            ability_index,
+        };
+        assert!(actions.contains(&action));
+        assert!(!actions.contains(&LegalAction::ActivateManaAbility {
+            source: bear_id,
+            ability_index,
+        }));
+
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        let mut dm = ActivateOnceDecisionMaker::new(action);
+        loop {
+            run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm)
+                .expect("priority loop should run");
+            if game.stack.is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(dm.seen[1], (alice, 1, 0));
+        assert_eq!(
+            game.player(alice)
+                .unwrap()
+                .mana_pool
+                .amount(ManaSymbol::Green),
+            1
+        );
+        assert!(game.is_tapped(bear_id));
+    }
+
+    #[test]
+    fn test_tap_land_for_mana_trigger_adds_mana_without_the_stack() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        main_phase_for(&mut game, alice);
+
+        let growth = CardDefinitionBuilder::new(CardId::new(), "Growth Probe")
+            .card_types(vec![CardType::Enchantment])
+            .parse_text("Whenever you tap a land for mana, add an additional {G}.")
+            .expect("tap-for-mana trigger should parse");
+        game.create_object_from_definition(&growth, alice, Zone::Battlefield);
+        let forest_id = game.create_object_from_definition(
+            &crate::cards::definitions::basic_forest(),
+            alice,
+            Zone::Battlefield,
+        );
+
+        let action = LegalAction::ActivateManaAbility {
+            source: forest_id,
+            ability_index: 0,
+        };
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        let mut dm = ActivateOnceDecisionMaker::new(action);
+        run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm)
+            .expect("priority loop should run");
+
+        // The trigger is a mana ability too (CR 605.1b): both {G} are in the
+        // pool when Alice next gets priority, and nothing used the stack.
+        assert_eq!(dm.seen[0], (alice, 0, 0));
+        assert_eq!(dm.seen[1], (alice, 0, 2));
+        assert!(dm.seen.iter().all(|&(_, stack_len, _)| stack_len == 0));
+    }
 }
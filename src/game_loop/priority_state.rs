@@ -13,11 +13,12 @@ use super::*;
 /// 4. ChoosingOptionalCosts (601.2b) - Announce additional costs (kicker, buyback)
 /// 5. AnnouncingCost (601.2b) - Announce hybrid/Phyrexian mana choices
 /// 6. ChoosingTargets (601.2c) - Choose targets
-/// 7. ChoosingNextCost - Choose the next remaining cost to pay
-/// 8. ProcessingCosts - Pay a selected non-mana cost
-/// 9. ChoosingSacrifice / ChoosingCardCost - Resolve object/card cost choices
-/// 10. PayingMana (601.2g-h) - Activate mana abilities and pay costs
-/// 11. ReadyToFinalize (601.2i) - Spell becomes cast
+/// 7. DividingDamage (601.2d) - Announce how divided damage is split among targets
+/// 8. ChoosingNextCost - Choose the next remaining cost to pay
+/// 9. ProcessingCosts - Pay a selected non-mana cost
+/// 10. ChoosingSacrifice / ChoosingCardCost - Resolve object/card cost choices
+/// 11. PayingMana (601.2g-h) - Activate mana abilities and pay costs
+/// 12. ReadyToFinalize (601.2i) - Spell becomes cast
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CastStage {
     /// Spell is being proposed - moved to stack per 601.2a.
@@ -35,6 +36,8 @@ pub enum CastStage {
     AnnouncingCost,
     /// Need to choose targets.
     ChoosingTargets,
+    /// Need to divide damage among the chosen targets (per 601.2d).
+    DividingDamage,
     /// Need to choose the next remaining cost to pay.
     ChoosingNextCost,
     /// Need to pay a selected immediate non-mana cost.
@@ -43,6 +46,8 @@ pub enum CastStage {
     ChoosingSacrifice,
     /// Need to choose cards/objects for non-mana costs (discard, exile-from-hand, etc.).
     ChoosingCardCost,
+    /// Need to choose which graveyard cards to exile for Delve.
+    ChoosingDelve,
     /// Need to pay mana costs (player can activate mana abilities).
     PayingMana,
     /// Ready to finalize (mana has been paid and spell becomes cast).
@@ -58,10 +63,12 @@ impl CastStage {
             CastStage::ChoosingOptionalCosts => "choosing optional costs",
             CastStage::AnnouncingCost => "announcing costs",
             CastStage::ChoosingTargets => "choosing targets",
+            CastStage::DividingDamage => "dividing damage",
             CastStage::ChoosingNextCost => "choosing next cost",
             CastStage::ProcessingCosts => "processing costs",
             CastStage::ChoosingSacrifice => "choosing sacrifices",
             CastStage::ChoosingCardCost => "choosing card costs",
+            CastStage::ChoosingDelve => "choosing delve cards",
             CastStage::PayingMana => "paying mana",
             CastStage::ReadyToFinalize => "ready to finalize",
         }
@@ -139,6 +146,8 @@ pub struct PendingCast {
     /// Pre-chosen modes for modal spells (per MTG rule 601.2b).
     /// Set during ChoosingModes stage, used during resolution.
     pub chosen_modes: Option<Vec<usize>>,
+    /// Damage division announced during the DividingDamage stage (per MTG rule 601.2d).
+    pub divided_amounts: Vec<(Target, u32)>,
     /// Hybrid/Phyrexian mana payment choices made during cost announcement (601.2b).
     /// Maps pip index to the chosen mana symbol for that pip.
     pub hybrid_choices: Vec<(usize, crate::mana::ManaSymbol)>,
@@ -190,6 +199,7 @@ impl PendingCast {
             tagged_objects: std::collections::HashMap::new(),
             next_sacrifice_cost_tag_index: 0,
             chosen_modes,
+            divided_amounts: Vec::new(),
             hybrid_choices: Vec::new(),
             pending_hybrid_pips: Vec::new(),
             stack_id,
@@ -742,9 +752,14 @@ pub struct PriorityLoopState {
     /// Checkpoint of game state saved when starting an action chain.
     /// If an error occurs during the chain, we restore to this state.
     pub checkpoint: Option<GameState>,
+    /// Trigger queue saved alongside `checkpoint`. Triggers from costs paid
+    /// during a cancelled action chain are dropped with the rest of it.
+    pub checkpoint_trigger_queue: Option<TriggerQueue>,
     /// Whether pip-by-pip mana payment should auto-pick a single legal option.
     /// CLI/tests can keep this enabled for speed; WASM UI can disable it to require explicit taps.
     pub auto_choose_single_pip_payment: bool,
+    /// Optional time banks charged for each priority or open decision.
+    pub clock: Option<GameClock>,
 }
 
 impl PriorityLoopState {
@@ -758,19 +773,28 @@ impl PriorityLoopState {
             pending_mana_ability: None,
             pending_continuation: None,
             checkpoint: None,
+            checkpoint_trigger_queue: None,
             auto_choose_single_pip_payment: true,
+            clock: None,
         }
     }
 
+    /// Run players' decisions against a chess clock.
+    pub fn set_clock(&mut self, clock: GameClock) {
+        self.clock = Some(clock);
+    }
+
     /// Save a checkpoint of the current game state.
     /// This should be called when starting an action chain (cast spell, activate ability).
-    pub fn save_checkpoint(&mut self, game: &GameState) {
+    pub fn save_checkpoint(&mut self, game: &GameState, trigger_queue: &TriggerQueue) {
         self.checkpoint = Some(game.clone());
+        self.checkpoint_trigger_queue = Some(trigger_queue.clone());
     }
 
     /// Clear the checkpoint (called when action completes successfully or after restore).
     pub fn clear_checkpoint(&mut self) {
         self.checkpoint = None;
+        self.checkpoint_trigger_queue = None;
     }
 
     /// Check if there's an active action chain (pending cast or activation).
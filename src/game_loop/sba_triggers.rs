@@ -34,11 +34,11 @@ pub fn check_and_apply_sbas_with(
         check_state_based_actions_with_view, legend_rule_specs_from_actions,
     };
 
-    // Refresh continuous state (static ability effects and "can't" effect tracking)
-    // before checking SBAs. This ensures the layer system is up to date.
-    game.refresh_continuous_state();
-
     loop {
+        // Refresh continuous state (static ability effects and "can't" effect
+        // tracking) before every check, so a permanent removed by the previous
+        // pass (e.g. Platinum Angel) stops protecting its controller.
+        game.refresh_continuous_state();
         let view = crate::derived_view::DerivedGameView::from_refreshed_state(game);
         let all_effects = view.effects().to_vec();
         let actions = check_state_based_actions_with_view(game, &view);
@@ -195,12 +195,30 @@ fn uniquify_trigger_labels(labels: &mut [String]) {
     }
 }
 
+/// Whether a controller's simultaneous triggers are interchangeable: the same
+/// ability of the same source, triggered by the same kind of event on the same
+/// object. Stacking them in any order gives the same result.
+fn triggers_are_interchangeable(triggers: &[TriggeredAbilityEntry]) -> bool {
+    let Some((first, rest)) = triggers.split_first() else {
+        return true;
+    };
+    rest.iter().all(|trigger| {
+        trigger.source == first.source
+            && trigger.trigger_identity == first.trigger_identity
+            && trigger.x_value == first.x_value
+            && trigger.triggering_event.kind() == first.triggering_event.kind()
+            && trigger.triggering_event.object_id() == first.triggering_event.object_id()
+            && trigger.triggering_event.player() == first.triggering_event.player()
+    })
+}
+
 fn order_triggers_for_controller(
     game: &GameState,
     decision_maker: &mut dyn DecisionMaker,
     triggers: Vec<TriggeredAbilityEntry>,
 ) -> Vec<TriggeredAbilityEntry> {
-    if triggers.len() <= 1 {
+    // Rule 603.3b: the controller chooses the order, unless it can't matter.
+    if triggers.len() <= 1 || triggers_are_interchangeable(&triggers) {
         return triggers;
     }
 
@@ -243,7 +261,9 @@ fn order_triggers_for_controller(
 }
 
 pub(super) fn is_triggered_mana_ability(game: &GameState, trigger: &TriggeredAbilityEntry) -> bool {
-    if !trigger.ability.choices.is_empty() {
+    if !trigger.ability.choices.is_empty()
+        || crate::ability::effects_have_targets(&trigger.ability.effects)
+    {
         return false;
     }
 
@@ -376,6 +396,9 @@ pub(super) fn resolve_triggered_stack_entry_immediately(
     if let Some(ref modes) = entry.chosen_modes {
         ctx = ctx.with_chosen_modes(Some(modes.clone()));
     }
+    if !entry.divided_amounts.is_empty() {
+        ctx = ctx.with_divided_amounts(entry.divided_amounts.clone());
+    }
     apply_keyword_payment_tags_for_resolution(game, &entry, &mut ctx);
 
     let (valid_targets, valid_target_assignments, all_targets_invalid) =
@@ -385,15 +408,7 @@ pub(super) fn resolve_triggered_stack_entry_immediately(
     }
 
     if let Some(ref condition) = entry.intervening_if
-        && let Some(ref triggering_event) = entry.triggering_event
-        && !verify_intervening_if(
-            game,
-            condition,
-            entry.controller,
-            triggering_event,
-            entry.object_id,
-            None,
-        )
+        && !crate::triggers::check::intervening_if_holds_on_resolution(game, condition, &ctx)
     {
         return;
     }
@@ -434,12 +449,12 @@ pub(super) fn resolve_triggered_stack_entry_immediately(
         }
     }
 
-    for event in all_events {
-        let event = game.ensure_trigger_event_provenance(event);
-        let triggers = check_triggers(game, &event);
-        for trigger in triggers {
-            trigger_queue.add(trigger);
-        }
+    let events: Vec<_> = all_events
+        .into_iter()
+        .map(|event| game.ensure_trigger_event_provenance(event))
+        .collect();
+    for trigger in check_triggers_for_events(game, &events) {
+        trigger_queue.add(trigger);
     }
     drain_pending_trigger_events(game, trigger_queue);
 
@@ -622,7 +637,7 @@ pub(super) fn create_triggered_stack_entry_with_targets(
             trigger.source,
             format!("{}'s triggered ability", trigger.source_name),
             vec![crate::decisions::context::TargetRequirementContext {
-                description: format!("target for {}", trigger.source_name),
+                description: format!("target for {}", trigger.source_name).into(),
                 legal_targets: legal_targets.clone(),
                 min_targets: count.min,
                 max_targets: count.max,
@@ -26,6 +26,42 @@ pub(super) fn active_target_assignments_for_effect(
     assignments[start..end].to_vec()
 }
 
+/// Attach resolution context to `error`, write a debug dump into `dir`, and
+/// turn the result into a game loop error carrying the dump's path.
+fn dump_execution_error(
+    game: &GameState,
+    entry: &StackEntry,
+    effect: &Effect,
+    ctx: &ExecutionContext,
+    error: crate::executor::ExecutionError,
+    dir: &std::path::Path,
+) -> GameLoopError {
+    let mut tags: Vec<String> = ctx
+        .tagged_objects
+        .keys()
+        .map(|tag| tag.as_str().to_string())
+        .collect();
+    tags.sort();
+    let context = crate::executor::ExecutionErrorContext {
+        source: entry.object_id,
+        source_name: entry
+            .source_name
+            .clone()
+            .or_else(|| game.object(entry.object_id).map(|obj| obj.name.clone())),
+        effect: format!("{effect:?}"),
+        targets: ctx.targets.clone(),
+        tags,
+        stack_entry: crate::error_dump::describe_stack_entry(game, entry),
+        dump_path: None,
+    };
+    let mut error = error.with_context(context);
+    let dump = crate::error_dump::write_error_dump(game, entry, &error, dir);
+    if let crate::executor::ExecutionError::WithContext(_, context) = &mut error {
+        context.dump_path = dump.ok();
+    }
+    GameLoopError::EffectFailed(error)
+}
+
 // ============================================================================
 // Stack Resolution
 // ============================================================================
@@ -107,6 +143,9 @@ pub(super) fn resolve_stack_entry_full(
     if let Some(ref modes) = entry.chosen_modes {
         ctx = ctx.with_chosen_modes(Some(modes.clone()));
     }
+    if !entry.divided_amounts.is_empty() {
+        ctx = ctx.with_divided_amounts(entry.divided_amounts.clone());
+    }
     apply_keyword_payment_tags_for_resolution(game, &entry, &mut ctx);
 
     // Convert targets and validate them
@@ -135,22 +174,13 @@ pub(super) fn resolve_stack_entry_full(
 
     // Check intervening-if condition at resolution time
     // If the condition is false, the ability does nothing (but doesn't fizzle)
+    // Source-based conditions use last known information if the source has left.
     if let Some(ref condition) = entry.intervening_if
-        && let Some(ref triggering_event) = entry.triggering_event
-        && !crate::triggers::verify_intervening_if(
-            game,
-            condition,
-            entry.controller,
-            triggering_event,
-            entry.object_id,
-            None,
-        )
+        && !crate::triggers::check::intervening_if_holds_on_resolution(game, condition, &ctx)
     {
         // Condition no longer true - ability resolves but does nothing
         return Ok(());
     }
-    // If no triggering event is set (shouldn't happen for triggered abilities),
-    // we allow the ability to proceed rather than creating a fake event
 
     ctx = ctx
         .with_targets(valid_targets)
@@ -191,18 +221,25 @@ pub(super) fn resolve_stack_entry_full(
         let outcome = ctx.with_temp_target_assignments(effect_target_assignments, |ctx| {
             execute_effect(game, effect, ctx)
         });
-        if let Ok(outcome) = outcome {
-            all_events.extend(outcome.events);
+        match outcome {
+            Ok(outcome) => all_events.extend(outcome.events),
+            Err(error) => {
+                if let Some(dir) = game.error_dump_dir().map(std::path::Path::to_path_buf) {
+                    return Err(dump_execution_error(
+                        game, &entry, effect, &ctx, error, &dir,
+                    ));
+                }
+            }
         }
     }
     // Process events from effect outcomes for triggers
     if let Some(ref mut tq) = trigger_queue {
-        for event in all_events {
-            let event = game.ensure_trigger_event_provenance(event);
-            let triggers = check_triggers(game, &event);
-            for t in triggers {
-                tq.add(t);
-            }
+        let events: Vec<_> = all_events
+            .into_iter()
+            .map(|event| game.ensure_trigger_event_provenance(event))
+            .collect();
+        for t in check_triggers_for_events(game, &events) {
+            tq.add(t);
         }
     }
 
@@ -449,17 +486,18 @@ pub(super) fn resolve_stack_entry_full(
                         )
                 });
 
-            // Check if cast with flashback/escape/jump-start/granted escape (exiles after resolution)
+            // Check if cast with flashback/jump-start (exiles after resolution)
             let should_exile = match &entry.casting_method {
                 CastingMethod::Normal => false,
-                CastingMethod::SplitOtherHalf | CastingMethod::Fuse => false,
+                CastingMethod::SplitOtherHalf => obj.has_aftermath,
+                CastingMethod::Fuse => false,
                 CastingMethod::Alternative(idx) => obj
                     .alternative_casts
                     .get(*idx)
                     .map(|m| m.exiles_after_resolution())
                     .unwrap_or(false),
-                CastingMethod::GrantedEscape { .. } => true, // Granted escape always exiles
-                CastingMethod::GrantedFlashback => true,     // Granted flashback always exiles
+                CastingMethod::GrantedEscape { .. } => false, // Escaped spells return to the graveyard
+                CastingMethod::GrantedFlashback => true,      // Granted flashback always exiles
                 CastingMethod::PlayFrom {
                     use_alternative: Some(idx),
                     zone,
@@ -559,7 +597,7 @@ pub(super) fn get_effects_for_stack_entry(
 
     // For spells, check the spell_effect field (instants/sorceries)
     if let Some(ref effects) = obj.spell_effect {
-        return effects.clone();
+        return effects.to_vec();
     }
 
     // Permanent spells (creatures, artifacts, enchantments, etc.) don't have effects
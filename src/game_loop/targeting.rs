@@ -91,16 +91,12 @@ pub(super) fn queue_triggers_from_event(
         }
     }
     if let Some(life_gain_event) = event.downcast::<LifeGainEvent>() {
-        *game
-            .life_gained_this_turn
-            .entry(life_gain_event.player)
-            .or_insert(0) += life_gain_event.amount;
+        game.turn_trackers
+            .record_life_gained(life_gain_event.player, life_gain_event.amount);
     }
     if let Some(life_loss_event) = event.downcast::<LifeLossEvent>() {
-        *game
-            .life_lost_this_turn
-            .entry(life_loss_event.player)
-            .or_insert(0) += life_loss_event.amount;
+        game.turn_trackers
+            .record_life_lost(life_loss_event.player, life_loss_event.amount);
     }
     if let Some(keyword_action_event) = event.downcast::<KeywordActionEvent>()
         && keyword_action_event.action == KeywordActionKind::CommitCrime
@@ -274,11 +270,23 @@ pub(super) fn queue_ability_activated_event(
                 .insert(activator);
         }
     }
+    // Non-mana activations push their stack entry right before queueing this
+    // event, so the top of the stack is the ability that was just activated.
+    let stack_entry = if is_mana_ability {
+        None
+    } else {
+        game.stack
+            .last()
+            .filter(|entry| entry.is_ability && entry.object_id == source)
+            .cloned()
+    };
     let event_provenance = game
         .provenance_graph
         .alloc_root_event(crate::events::EventKind::AbilityActivated);
     let event = TriggerEvent::new_with_provenance(
-        AbilityActivatedEvent::new(source, activator, is_mana_ability).with_snapshot(snapshot),
+        AbilityActivatedEvent::new(source, activator, is_mana_ability)
+            .with_snapshot(snapshot)
+            .with_stack_entry(stack_entry),
         event_provenance,
     );
     queue_triggers_from_event(game, trigger_queue, event, true);
@@ -814,6 +822,7 @@ pub fn player_matches_filter_with_combat(
             .map(|c| get_attacking_player(c, game) == Some(player_id))
             .unwrap_or(false),
         PlayerFilter::DamagedPlayer => false,
+        PlayerFilter::TriggeringPlayer => false,
         PlayerFilter::EffectController => player_id == controller,
         PlayerFilter::Specific(id) => player_id == *id,
         PlayerFilter::IteratedPlayer => {
@@ -216,7 +216,7 @@ fn test_queue_triggers_tracks_life_gained_this_turn() {
     );
     queue_triggers_from_event(&mut game, &mut trigger_queue, event, false);
 
-    assert_eq!(game.life_gained_this_turn.get(&alice), Some(&5));
+    assert_eq!(game.turn_trackers.life_gained.get(&alice), Some(&5));
 }
 
 #[test]
@@ -231,7 +231,7 @@ fn test_queue_triggers_tracks_life_lost_this_turn() {
     );
     queue_triggers_from_event(&mut game, &mut trigger_queue, event, false);
 
-    assert_eq!(game.life_lost_this_turn.get(&bob), Some(&3));
+    assert_eq!(game.turn_trackers.life_lost.get(&bob), Some(&3));
 }
 
 #[test]
@@ -351,6 +351,76 @@ fn test_non_mana_tap_for_mana_trigger_still_uses_stack() {
     );
 }
 
+#[test]
+fn test_activated_ability_copy_trigger_copies_stack_entry() {
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let mut dm = SelectFirstDecisionMaker;
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let rings_card = CardBuilder::new(CardId::new(), "Copy Rings")
+        .card_types(vec![CardType::Artifact])
+        .build();
+    let rings_id = game.create_object_from_card(&rings_card, alice, Zone::Battlefield);
+    if let Some(rings) = game.object_mut(rings_id) {
+        rings.abilities.push(Ability::triggered(
+            Trigger::ability_activated(crate::filter::ObjectFilter::artifact().you_control()),
+            vec![Effect::copy_triggering_ability()],
+        ));
+    }
+
+    let ballista_card = CardBuilder::new(CardId::new(), "Ballista")
+        .card_types(vec![CardType::Artifact, CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .build();
+    let ballista_id = game.create_object_from_card(&ballista_card, alice, Zone::Battlefield);
+    let bear_card = CardBuilder::new(CardId::new(), "Bear")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .build();
+    let bear_id = game.create_object_from_card(&bear_card, bob, Zone::Battlefield);
+
+    game.push_to_stack(
+        StackEntry::ability(
+            ballista_id,
+            alice,
+            vec![Effect::deal_damage(1, crate::target::ChooseSpec::AnyTarget)],
+        )
+        .with_targets(vec![Target::Object(bear_id)])
+        .with_x(2),
+    );
+    queue_ability_activated_event(
+        &mut game,
+        &mut trigger_queue,
+        &mut dm,
+        ballista_id,
+        alice,
+        false,
+        None,
+    );
+    put_triggers_on_stack_with_dm(&mut game, &mut trigger_queue, &mut dm)
+        .expect("should put copy trigger on stack");
+    assert_eq!(
+        game.stack.len(),
+        2,
+        "copy trigger should be above the ability"
+    );
+
+    resolve_stack_entry(&mut game).expect("copy trigger should resolve");
+
+    assert_eq!(
+        game.stack.len(),
+        2,
+        "original and copy should be on the stack"
+    );
+    let copy = &game.stack[1];
+    assert!(copy.is_ability);
+    assert_eq!(copy.object_id, ballista_id);
+    assert_eq!(copy.targets, vec![Target::Object(bear_id)]);
+    assert_eq!(copy.x_value, Some(2));
+}
+
 #[test]
 fn emrakul_cast_trigger_prompts_for_opponent_in_four_player_game() {
     #[derive(Debug, Default)]
@@ -468,7 +538,7 @@ fn put_triggers_on_stack_uses_controller_selected_order_for_simultaneous_trigger
             _game: &GameState,
             ctx: &crate::decisions::context::OrderContext,
         ) -> Vec<ObjectId> {
-            self.prompts.push(ctx.description.clone());
+            self.prompts.push(ctx.description.to_string());
             ctx.items.iter().rev().map(|(id, _)| *id).collect()
         }
     }
@@ -549,6 +619,189 @@ fn put_triggers_on_stack_uses_controller_selected_order_for_simultaneous_trigger
     );
 }
 
+/// Stack a creature's "draw a card for each creature you control" and
+/// "sacrifice a creature" ETB triggers in the chosen order, resolve both, and
+/// return the controller's hand size.
+fn resolve_draw_and_sacrifice_etb_triggers(draw_on_top: bool) -> usize {
+    use crate::ability::TriggeredAbility;
+    use crate::events::zones::EnterBattlefieldEvent;
+    use crate::filter::ObjectFilter;
+
+    struct FixedOrderDecisionMaker {
+        reverse: bool,
+        prompts: usize,
+    }
+
+    impl DecisionMaker for FixedOrderDecisionMaker {
+        fn decide_order(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::OrderContext,
+        ) -> Vec<ObjectId> {
+            self.prompts += 1;
+            let mut order: Vec<ObjectId> = ctx.items.iter().map(|(id, _)| *id).collect();
+            if self.reverse {
+                order.reverse();
+            }
+            order
+        }
+    }
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    for _ in 0..5 {
+        game.create_object_from_card(
+            &CardBuilder::new(CardId::new(), "Library Card")
+                .card_types(vec![CardType::Instant])
+                .build(),
+            alice,
+            Zone::Library,
+        );
+    }
+    let creature = |name: &str| {
+        CardBuilder::new(CardId::new(), name)
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .build()
+    };
+    game.create_object_from_card(&creature("Bystander"), alice, Zone::Battlefield);
+    let scout = game.create_object_from_card(&creature("Scout"), alice, Zone::Battlefield);
+    let scout_stable_id = game.object(scout).expect("scout exists").stable_id;
+
+    let etb_event = TriggerEvent::new_with_provenance(
+        EnterBattlefieldEvent::new(scout, Zone::Hand),
+        crate::provenance::ProvNodeId::default(),
+    );
+    let draw_trigger = TriggeredAbility {
+        trigger: Trigger::this_enters_battlefield(),
+        effects: vec![Effect::draw(Value::Count(
+            ObjectFilter::creature().you_control(),
+        ))],
+        choices: vec![],
+        intervening_if: None,
+    };
+    let sacrifice_trigger = TriggeredAbility {
+        trigger: Trigger::this_enters_battlefield(),
+        effects: vec![Effect::sacrifice(ObjectFilter::creature(), 1)],
+        choices: vec![],
+        intervening_if: None,
+    };
+
+    let mut trigger_queue = TriggerQueue::new();
+    for ability in [draw_trigger, sacrifice_trigger] {
+        trigger_queue.add(TriggeredAbilityEntry {
+            source: scout,
+            controller: alice,
+            x_value: None,
+            trigger_identity: crate::triggers::compute_trigger_identity(&ability),
+            ability,
+            triggering_event: etb_event.clone(),
+            source_stable_id: scout_stable_id,
+            source_name: "Scout".to_string(),
+            source_snapshot: None,
+            tagged_objects: std::collections::HashMap::new(),
+        });
+    }
+
+    // The leftmost ordered item becomes the top of the stack. Queue order puts
+    // the draw trigger first.
+    let mut dm = FixedOrderDecisionMaker {
+        reverse: !draw_on_top,
+        prompts: 0,
+    };
+    put_triggers_on_stack_with_dm(&mut game, &mut trigger_queue, &mut dm)
+        .expect("trigger ordering should succeed");
+    assert_eq!(
+        dm.prompts, 1,
+        "distinct triggers should prompt for an order"
+    );
+
+    resolve_stack_entry_with(&mut game, &mut dm).expect("top trigger should resolve");
+    resolve_stack_entry_with(&mut game, &mut dm).expect("second trigger should resolve");
+    game.player(alice).expect("alice exists").hand.len()
+}
+
+#[test]
+fn simultaneous_etb_triggers_resolve_in_the_chosen_order() {
+    assert_eq!(
+        resolve_draw_and_sacrifice_etb_triggers(true),
+        2,
+        "drawing before the sacrifice counts both creatures"
+    );
+    assert_eq!(
+        resolve_draw_and_sacrifice_etb_triggers(false),
+        1,
+        "drawing after the sacrifice counts only the survivor"
+    );
+}
+
+#[test]
+fn put_triggers_on_stack_skips_ordering_for_interchangeable_triggers() {
+    use crate::ability::TriggeredAbility;
+    use crate::events::phase::BeginningOfUpkeepEvent;
+    use crate::target::PlayerFilter;
+
+    #[derive(Debug, Default)]
+    struct CountingOrderDecisionMaker {
+        prompts: usize,
+    }
+
+    impl DecisionMaker for CountingOrderDecisionMaker {
+        fn decide_order(
+            &mut self,
+            _game: &GameState,
+            ctx: &crate::decisions::context::OrderContext,
+        ) -> Vec<ObjectId> {
+            self.prompts += 1;
+            ctx.items.iter().map(|(id, _)| *id).collect()
+        }
+    }
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let source = game.create_object_from_card(
+        &CardBuilder::new(CardId::new(), "Echoing Trigger")
+            .card_types(vec![CardType::Enchantment])
+            .build(),
+        alice,
+        Zone::Battlefield,
+    );
+    let stable_id = game.object(source).expect("source exists").stable_id;
+    let upkeep_event = TriggerEvent::new_with_provenance(
+        BeginningOfUpkeepEvent::new(alice),
+        crate::provenance::ProvNodeId::default(),
+    );
+    let ability = TriggeredAbility {
+        trigger: Trigger::beginning_of_upkeep(PlayerFilter::You),
+        effects: vec![Effect::gain_life(1)],
+        choices: vec![],
+        intervening_if: None,
+    };
+
+    let mut trigger_queue = TriggerQueue::new();
+    for _ in 0..2 {
+        trigger_queue.add(TriggeredAbilityEntry {
+            source,
+            controller: alice,
+            x_value: None,
+            ability: ability.clone(),
+            triggering_event: upkeep_event.clone(),
+            source_stable_id: stable_id,
+            source_name: "Echoing Trigger".to_string(),
+            source_snapshot: None,
+            tagged_objects: std::collections::HashMap::new(),
+            trigger_identity: crate::triggers::compute_trigger_identity(&ability),
+        });
+    }
+
+    let mut dm = CountingOrderDecisionMaker::default();
+    put_triggers_on_stack_with_dm(&mut game, &mut trigger_queue, &mut dm)
+        .expect("stacking should succeed");
+
+    assert_eq!(dm.prompts, 0, "identical triggers should not prompt");
+    assert_eq!(game.stack.len(), 2, "both triggers should still be stacked");
+}
+
 #[test]
 fn put_triggers_on_stack_orders_each_controller_in_apnap_order() {
     use crate::ability::TriggeredAbility;
@@ -1114,78 +1367,265 @@ fn test_turn_face_up_action_puts_turned_face_up_trigger_on_stack() {
     );
 }
 
-// === Target Extraction Tests ===
-
-#[cfg(feature = "net")]
 #[test]
-fn test_pip_payment_trace_order() {
-    use crate::mana::ManaSymbol;
+fn test_play_land_keeps_priority_to_cast_spell_in_same_window() {
+    use crate::cards::definitions::basic_forest;
+    use crate::decision::{LegalAction, compute_legal_actions};
+    use crate::decisions::context::DecisionContext;
+    use crate::mana::{ManaCost, ManaSymbol};
 
-    let mut trace = Vec::new();
-    let actions = vec![
-        ManaPipPaymentAction::ActivateManaAbility {
-            source_id: ObjectId::from_raw(5),
-            ability_index: 1,
-        },
-        ManaPipPaymentAction::UseFromPool(ManaSymbol::Blue),
-        ManaPipPaymentAction::PayViaAlternative {
-            permanent_id: ObjectId::from_raw(6),
-            effect: crate::decision::AlternativePaymentEffect::Convoke,
-        },
-        ManaPipPaymentAction::PayLife(2),
-    ];
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
 
-    for action in &actions {
-        record_pip_payment_action(&mut trace, action);
-    }
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
 
-    assert_eq!(trace.len(), 4);
-    assert!(matches!(
-        trace[0],
-        CostStep::Payment(CostPayment::ActivateManaAbility { .. })
-    ));
-    assert!(matches!(
-        trace[1],
-        CostStep::Mana(ManaSymbolSpec {
-            symbol: ManaSymbolCode::Blue,
-            ..
-        })
-    ));
-    assert!(matches!(
-        trace[2],
-        CostStep::Payment(CostPayment::Tap { .. })
-    ));
-    assert!(matches!(
-        trace[3],
-        CostStep::Mana(ManaSymbolSpec {
-            symbol: ManaSymbolCode::Life,
-            value: 2,
-        })
-    ));
-}
+    let forest_id = game.create_object_from_definition(&basic_forest(), alice, Zone::Hand);
+    let sprout = CardBuilder::new(CardId::new(), "Priority Sprout")
+        .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Green]]))
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .build();
+    let sprout_id = game.create_object_from_card(&sprout, alice, Zone::Hand);
+    assert!(
+        !compute_legal_actions(&game, alice)
+            .iter()
+            .any(|action| matches!(
+                action,
+                LegalAction::CastSpell { spell_id, .. } if *spell_id == sprout_id
+            )),
+        "spell shouldn't be castable before the land is played"
+    );
 
-#[test]
-fn test_extract_target_spec_single_target() {
-    // Destroy effect has single target
-    let effect = Effect::destroy(ChooseSpec::creature());
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::PlayLand { land_id: forest_id }),
+    )
+    .expect("land play should succeed");
+    let GameProgress::NeedsDecisionCtx(DecisionContext::Priority(ctx)) = progress else {
+        panic!("expected a priority decision after the land play, got {progress:?}");
+    };
+    assert_eq!(ctx.player, alice, "land play shouldn't pass priority");
+    assert!(
+        !ctx.actions
+            .iter()
+            .any(|action| matches!(action, LegalAction::PlayLand { .. })),
+        "second land play shouldn't be offered"
+    );
+    let cast = ctx
+        .actions
+        .iter()
+        .find(|action| {
+            matches!(
+                action,
+                LegalAction::CastSpell { spell_id, .. } if *spell_id == sprout_id
+            )
+        })
+        .cloned()
+        .expect("spell should be castable with the new land");
 
-    let extracted = extract_target_spec(&effect).expect("Should extract target");
-    assert_eq!(extracted.min_targets, 1);
-    assert_eq!(extracted.max_targets, Some(1));
+    apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(cast),
+    )
+    .expect("spell cast should succeed");
+    assert_eq!(game.stack.len(), 1, "spell should be on the stack");
+    assert!(
+        game.battlefield.iter().any(|&id| {
+            game.object(id)
+                .is_some_and(|obj| obj.name == "Forest" && game.is_tapped(id))
+        }),
+        "the new land should pay for the spell"
+    );
 }
 
 #[test]
-fn test_extract_target_spec_any_number() {
-    // Exile with any_number count (using exile_any_number helper)
-    let effect = Effect::exile_any_number(ChooseSpec::spell());
+fn test_turn_face_up_in_response_keeps_priority_and_restarts_passes() {
+    use crate::decision::LegalAction;
+    use crate::decisions::context::DecisionContext;
+    use crate::mana::{ManaCost, ManaSymbol};
+    use crate::static_abilities::StaticAbility;
 
-    let extracted = extract_target_spec(&effect).expect("Should extract target");
-    // ChoiceCount::any_number() returns min: 0, max: None
-    assert_eq!(extracted.min_targets, 0, "any_number has min 0");
-    assert_eq!(extracted.max_targets, None, "any_number has no max");
-}
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
 
-#[test]
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let card = CardBuilder::new(CardId::new(), "Hidden Brute")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(5, 5))
+        .build();
+    let creature_id = game.create_object_from_card(&card, bob, Zone::Battlefield);
+    if let Some(obj) = game.object_mut(creature_id) {
+        obj.abilities
+            .push(Ability::static_ability(StaticAbility::morph(
+                ManaCost::from_pips(vec![vec![ManaSymbol::Green]]),
+            )));
+    }
+    game.set_face_down(creature_id);
+    game.player_mut(bob)
+        .expect("bob exists")
+        .mana_pool
+        .add(ManaSymbol::Green, 1);
+
+    let source_card = CardBuilder::new(CardId::new(), "Removal Source")
+        .card_types(vec![CardType::Artifact])
+        .build();
+    let source_id = game.create_object_from_card(&source_card, alice, Zone::Battlefield);
+    game.push_to_stack(
+        StackEntry::ability(
+            source_id,
+            alice,
+            vec![Effect::deal_damage(3, crate::target::ChooseSpec::AnyTarget)],
+        )
+        .with_targets(vec![Target::Object(creature_id)]),
+    );
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::PassPriority),
+    )
+    .expect("alice should pass");
+    let GameProgress::NeedsDecisionCtx(DecisionContext::Priority(ctx)) = progress else {
+        panic!("expected bob's priority decision, got {progress:?}");
+    };
+    assert_eq!(ctx.player, bob);
+    assert!(ctx.actions.iter().any(|action| matches!(
+        action,
+        LegalAction::TurnFaceUp { creature_id: id } if *id == creature_id
+    )));
+
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::TurnFaceUp { creature_id }),
+    )
+    .expect("bob should turn the creature face up");
+    let GameProgress::NeedsDecisionCtx(DecisionContext::Priority(ctx)) = progress else {
+        panic!("expected bob to keep priority, got {progress:?}");
+    };
+    assert_eq!(ctx.player, bob, "turning face up shouldn't pass priority");
+    assert!(!game.is_face_down(creature_id));
+    assert_eq!(game.stack.len(), 1, "removal should still be on the stack");
+
+    // Alice's earlier pass no longer counts: she gets priority again.
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::PassPriority),
+    )
+    .expect("bob should pass");
+    let GameProgress::NeedsDecisionCtx(DecisionContext::Priority(ctx)) = progress else {
+        panic!("expected alice's priority decision, got {progress:?}");
+    };
+    assert_eq!(ctx.player, alice);
+    assert_eq!(game.stack.len(), 1);
+
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::PassPriority),
+    )
+    .expect("alice should pass");
+    assert!(matches!(progress, GameProgress::StackResolved));
+    assert!(game.stack_is_empty());
+    assert!(
+        game.battlefield.contains(&creature_id),
+        "face-up creature should survive the damage"
+    );
+}
+
+// === Target Extraction Tests ===
+
+#[cfg(feature = "net")]
+#[test]
+fn test_pip_payment_trace_order() {
+    use crate::mana::ManaSymbol;
+
+    let mut trace = Vec::new();
+    let actions = vec![
+        ManaPipPaymentAction::ActivateManaAbility {
+            source_id: ObjectId::from_raw(5),
+            ability_index: 1,
+        },
+        ManaPipPaymentAction::UseFromPool(ManaSymbol::Blue),
+        ManaPipPaymentAction::PayViaAlternative {
+            permanent_id: ObjectId::from_raw(6),
+            effect: crate::decision::AlternativePaymentEffect::Convoke,
+        },
+        ManaPipPaymentAction::PayLife(2),
+    ];
+
+    for action in &actions {
+        record_pip_payment_action(&mut trace, action);
+    }
+
+    assert_eq!(trace.len(), 4);
+    assert!(matches!(
+        trace[0],
+        CostStep::Payment(CostPayment::ActivateManaAbility { .. })
+    ));
+    assert!(matches!(
+        trace[1],
+        CostStep::Mana(ManaSymbolSpec {
+            symbol: ManaSymbolCode::Blue,
+            ..
+        })
+    ));
+    assert!(matches!(
+        trace[2],
+        CostStep::Payment(CostPayment::Tap { .. })
+    ));
+    assert!(matches!(
+        trace[3],
+        CostStep::Mana(ManaSymbolSpec {
+            symbol: ManaSymbolCode::Life,
+            value: 2,
+        })
+    ));
+}
+
+#[test]
+fn test_extract_target_spec_single_target() {
+    // Destroy effect has single target
+    let effect = Effect::destroy(ChooseSpec::creature());
+
+    let extracted = extract_target_spec(&effect).expect("Should extract target");
+    assert_eq!(extracted.min_targets, 1);
+    assert_eq!(extracted.max_targets, Some(1));
+}
+
+#[test]
+fn test_extract_target_spec_any_number() {
+    // Exile with any_number count (using exile_any_number helper)
+    let effect = Effect::exile_any_number(ChooseSpec::spell());
+
+    let extracted = extract_target_spec(&effect).expect("Should extract target");
+    // ChoiceCount::any_number() returns min: 0, max: None
+    assert_eq!(extracted.min_targets, 0, "any_number has min 0");
+    assert_eq!(extracted.max_targets, None, "any_number has no max");
+}
+
+#[test]
 fn test_extract_target_spec_no_count() {
     // Exile with no count defaults to single target
     let effect = Effect::exile(ChooseSpec::creature());
@@ -1495,6 +1935,61 @@ fn test_extract_target_specs_exactly_two_targets_uses_single_requirement_with_co
     );
 }
 
+#[test]
+fn test_up_to_two_targets_spell_resolves_with_zero_or_two_targets() {
+    use crate::cards::CardDefinitionBuilder;
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Twin Stun")
+        .card_types(vec![CardType::Instant])
+        .parse_text("Tap up to two target creatures. Draw a card.")
+        .expect("up-to-two-target clause should parse");
+    let mut effects = def
+        .spell_effect
+        .clone()
+        .expect("expected spell effects")
+        .to_vec();
+    effects.push(Effect::gain_life(Value::TargetCount));
+    let spell_def = crate::cards::CardDefinition::spell(def.card.clone(), effects.clone());
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    for index in 0..2 {
+        let filler = CardBuilder::new(CardId::from_raw(5_100 + index), "Filler")
+            .card_types(vec![CardType::Land])
+            .build();
+        game.create_object_from_card(&filler, alice, Zone::Library);
+    }
+
+    // With nothing to target, the spell can still be cast.
+    assert!(spell_has_legal_targets(&game, &effects, alice, None));
+    let requirements = extract_target_requirements(&game, &effects, alice, None);
+    assert_eq!(requirements.len(), 1, "got {:?}", requirements);
+    assert_eq!(requirements[0].min_targets, 0);
+    assert_eq!(requirements[0].max_targets, Some(2));
+
+    // Zero targets chosen: the spell resolves rather than being countered.
+    let spell_id = game.create_object_from_definition(&spell_def, alice, Zone::Stack);
+    game.push_to_stack(StackEntry::new(spell_id, alice));
+    resolve_stack_entry(&mut game).expect("zero-target spell should resolve");
+    assert_eq!(game.player(alice).unwrap().hand.len(), 1);
+    assert_eq!(game.player(alice).unwrap().life, 20);
+
+    // Two targets chosen: both are tapped and the count scales the life gain.
+    let first = create_creature(&mut game, "First", bob, 2, 2);
+    let second = create_creature(&mut game, "Second", bob, 2, 2);
+    let spell_id = game.create_object_from_definition(&spell_def, alice, Zone::Stack);
+    game.push_to_stack(
+        StackEntry::new(spell_id, alice)
+            .with_targets(vec![Target::Object(first), Target::Object(second)]),
+    );
+    resolve_stack_entry(&mut game).expect("two-target spell should resolve");
+    assert!(game.is_tapped(first));
+    assert!(game.is_tapped(second));
+    assert_eq!(game.player(alice).unwrap().hand.len(), 2);
+    assert_eq!(game.player(alice).unwrap().life, 22);
+}
+
 #[test]
 fn test_non_target_put_onto_battlefield_choice_does_not_create_target_requirement() {
     let mut game = setup_game();
@@ -2066,6 +2561,74 @@ fn test_echo_upkeep_trigger_without_payment_sacrifices_source() {
     );
 }
 
+#[test]
+fn test_donated_permanent_upkeep_drawback_punishes_new_controller() {
+    use crate::target::{ChooseSpec, PlayerFilter};
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let pact = CardDefinitionBuilder::new(CardId::new(), "Doom Pact")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("At the beginning of your upkeep, you lose the game.")
+        .expect("upkeep drawback should parse");
+    let pact_id = game.create_object_from_definition(&pact, alice, Zone::Battlefield);
+
+    // Alice gives the pact to Bob before her own upkeep comes around, the way
+    // Jinxed Idol's "target opponent gains control of ~" does.
+    game.push_to_stack(
+        StackEntry::ability(
+            pact_id,
+            alice,
+            vec![
+                Effect::new(crate::effects::TargetOnlyEffect::new(
+                    ChooseSpec::target_opponent(),
+                )),
+                Effect::donate(ChooseSpec::Source, PlayerFilter::target_opponent()),
+            ],
+        )
+        .with_targets(vec![Target::Player(bob)]),
+    );
+    let mut dm = SelectFirstDecisionMaker;
+    resolve_stack_entry_with(&mut game, &mut dm).expect("donation should resolve");
+    let controller = |game: &GameState| {
+        game.calculated_characteristics(pact_id)
+            .map(|chars| chars.controller)
+    };
+    assert_eq!(controller(&game), Some(bob));
+
+    // The control change outlasts the turn it happened in.
+    game.expire_duration(Until::EndOfTurn);
+    game.next_turn();
+    assert_eq!(game.turn.active_player, bob);
+    assert_eq!(controller(&game), Some(bob));
+
+    game.turn.phase = Phase::Beginning;
+    game.turn.step = Some(Step::Upkeep);
+    game.turn.priority_player = Some(bob);
+    let mut trigger_queue = TriggerQueue::new();
+    generate_and_queue_step_triggers(&mut game, &mut trigger_queue);
+    assert_eq!(
+        trigger_queue
+            .entries
+            .iter()
+            .map(|entry| entry.controller)
+            .collect::<Vec<_>>(),
+        vec![bob],
+        "the upkeep trigger belongs to the pact's new controller"
+    );
+
+    let result =
+        run_priority_loop_with(&mut game, &mut trigger_queue, &mut AutoPassDecisionMaker).unwrap();
+    assert!(game.player(bob).unwrap().has_lost);
+    assert!(!game.player(alice).unwrap().has_lost);
+    assert!(matches!(
+        result,
+        GameProgress::GameOver(crate::decision::GameResult::Winner(winner)) if winner == alice
+    ));
+}
+
 #[test]
 fn test_resolve_stack_entry_with_graveyard_object_target() {
     let mut game = setup_game();
@@ -2271,6 +2834,66 @@ fn test_delayed_tagged_graveyard_return_does_not_follow_zone_hops() {
     );
 }
 
+#[test]
+fn test_flicker_until_next_end_step_returns_new_untapped_object() {
+    use crate::effects::EffectExecutor;
+
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let flickerwisp_id = create_creature(&mut game, "Flickerwisp", alice, 3, 1);
+    let bear_id = create_creature(&mut game, "Grizzly Bears", bob, 2, 2);
+    game.tap(bear_id);
+
+    let mut ctx = crate::executor::ExecutionContext::new_default(flickerwisp_id, alice);
+    crate::effects::ExileUntilEffect::next_end_step(ChooseSpec::SpecificObject(bear_id))
+        .execute(&mut game, &mut ctx)
+        .expect("flicker should exile the creature");
+    assert_eq!(game.exile.len(), 1, "creature should wait in exile");
+    assert_eq!(game.delayed_triggers.len(), 1);
+
+    // The turn ends without reaching an end step; the return still waits.
+    game.turn.turn_number += 1;
+
+    let end_step_event = TriggerEvent::new_with_provenance(
+        crate::events::phase::BeginningOfEndStepEvent::new(game.turn.active_player),
+        crate::provenance::ProvNodeId::default(),
+    );
+    for trigger in crate::triggers::check_delayed_triggers(&mut game, &end_step_event) {
+        trigger_queue.add(trigger);
+    }
+    put_triggers_on_stack(&mut game, &mut trigger_queue)
+        .expect("put delayed flicker return on stack");
+    while !game.stack_is_empty() {
+        resolve_stack_entry(&mut game).expect("resolve delayed flicker return");
+    }
+
+    let returned_id = game
+        .battlefield
+        .iter()
+        .copied()
+        .find(|id| {
+            game.object(*id)
+                .is_some_and(|obj| obj.name == "Grizzly Bears")
+        })
+        .expect("creature should return at the next end step");
+    assert_ne!(returned_id, bear_id, "returned creature is a new object");
+    assert_eq!(
+        game.object(returned_id).map(|obj| obj.controller),
+        Some(bob)
+    );
+    assert!(
+        !game.is_tapped(returned_id),
+        "returned creature enters untapped"
+    );
+    assert!(game.exile.is_empty());
+    assert!(
+        game.delayed_triggers.is_empty(),
+        "return trigger is one-shot"
+    );
+}
+
 fn assert_pact_upkeep_trigger_survives_fail_to_find(
     pact_def: &crate::cards::CardDefinition,
     spell_name: &str,
@@ -2457,7 +3080,8 @@ fn test_fatal_push_with_revolt_destroys_four_mana_target() {
     let target_id = game.create_object_from_card(&four_mana_creature, bob, Zone::Battlefield);
     let fatal_push_id = game.create_object_from_definition(&fatal_push, alice, Zone::Stack);
 
-    game.permanents_left_battlefield_under_controller_this_turn
+    game.turn_trackers
+        .permanents_left_battlefield_under_controller
         .insert(alice, 1);
 
     game.push_to_stack(
@@ -2509,6 +3133,45 @@ fn test_unblocked_attacker_deals_damage() {
     assert_eq!(game.player(bob).unwrap().life, 17);
 }
 
+#[test]
+fn test_palisade_giant_absorbs_combat_damage_to_its_controller_without_excess() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let attacker_id = create_creature(&mut game, "Huge Attacker", alice, 10, 10);
+    let giant_id = create_creature(&mut game, "Palisade Giant", bob, 2, 7);
+    if let Some(giant) = game.object_mut(giant_id) {
+        giant.abilities.push(Ability::static_ability(
+            crate::static_abilities::StaticAbility::redirect_damage_from_you_to_source(),
+        ));
+    }
+    game.update_replacement_effects();
+
+    let mut combat = CombatState::default();
+    combat.attackers.push(crate::combat_state::AttackerInfo {
+        creature: attacker_id,
+        target: AttackTarget::Player(bob),
+    });
+    combat.blockers.insert(attacker_id, Vec::new());
+
+    let events = execute_combat_damage_step(&mut game, &combat, false);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].amount, 0, "no damage should reach the player");
+    assert_eq!(game.player(bob).unwrap().life, 20);
+    assert_eq!(game.damage_on(giant_id), 10);
+
+    let mut trigger_queue = TriggerQueue::new();
+    crate::game_loop::check_and_apply_sbas(&mut game, &mut trigger_queue).expect("SBAs apply");
+    assert!(!game.battlefield.contains(&giant_id));
+    assert_eq!(
+        game.player(bob).unwrap().life,
+        20,
+        "excess damage must not carry over to the player"
+    );
+}
+
 #[test]
 fn test_unblocked_attacker_uses_calculated_power_from_conditional_anthem() {
     let mut game = setup_game();
@@ -2689,24 +3352,118 @@ fn test_lifelink_damage() {
 }
 
 #[test]
-fn test_trample_damage() {
+fn test_lifelink_from_simultaneous_attackers_is_one_life_gain_event() {
+    use crate::events::LifeGainEvent;
+
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
     let bob = PlayerId::from_index(1);
 
-    let attacker_id = create_creature(&mut game, "Trampler", alice, 5, 5);
-    let blocker_id = create_creature(&mut game, "Small Blocker", bob, 2, 2);
-
-    // Add trample
-    if let Some(obj) = game.object_mut(attacker_id) {
-        obj.abilities.push(Ability::static_ability(
-            crate::static_abilities::StaticAbility::trample(),
-        ));
+    let mut combat = CombatState::default();
+    for (name, power) in [("Lifelinker A", 2), ("Lifelinker B", 3)] {
+        let attacker_id = create_creature(&mut game, name, alice, power, power);
+        if let Some(obj) = game.object_mut(attacker_id) {
+            obj.abilities.push(Ability::static_ability(
+                crate::static_abilities::StaticAbility::lifelink(),
+            ));
+        }
+        combat.attackers.push(crate::combat_state::AttackerInfo {
+            creature: attacker_id,
+            target: AttackTarget::Player(bob),
+        });
+        combat.blockers.insert(attacker_id, Vec::new());
     }
 
-    // Set up combat
-    let mut combat = CombatState::default();
-    combat.attackers.push(crate::combat_state::AttackerInfo {
+    let events = execute_combat_damage_step(&mut game, &combat, false);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(game.player(bob).unwrap().life, 15);
+    assert_eq!(game.player(alice).unwrap().life, 25);
+    let life_gains: Vec<(PlayerId, u32)> = game
+        .pending_trigger_events
+        .iter()
+        .filter_map(|event| event.downcast::<LifeGainEvent>())
+        .map(|gain| (gain.player, gain.amount))
+        .collect();
+    assert_eq!(life_gains, vec![(alice, 5)]);
+}
+
+#[test]
+fn test_blockers_dying_simultaneously_trigger_as_one_batch() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let attacker_id = create_creature(&mut game, "Big Attacker", alice, 6, 7);
+    let blockers: Vec<ObjectId> = ["Blocker A", "Blocker B", "Blocker C"]
+        .into_iter()
+        .map(|name| {
+            let blocker_id = create_creature(&mut game, name, bob, 2, 2);
+            if let Some(obj) = game.object_mut(blocker_id) {
+                obj.abilities.push(Ability::triggered(
+                    Trigger::this_dies(),
+                    vec![Effect::draw(1)],
+                ));
+            }
+            blocker_id
+        })
+        .collect();
+
+    let mut combat = CombatState::default();
+    combat.attackers.push(crate::combat_state::AttackerInfo {
+        creature: attacker_id,
+        target: AttackTarget::Player(bob),
+    });
+    combat.blockers.insert(attacker_id, blockers.clone());
+
+    let events = execute_combat_damage_step(&mut game, &combat, false);
+    assert_eq!(events.len(), 6);
+    // Every assignment lands before any creature leaves the battlefield.
+    for &blocker_id in &blockers {
+        assert!(game.battlefield.contains(&blocker_id));
+        assert_eq!(game.damage_on(blocker_id), 2);
+    }
+    assert_eq!(game.damage_on(attacker_id), 6);
+
+    let mut trigger_queue = TriggerQueue::new();
+    generate_damage_triggers(&mut game, &events, &mut trigger_queue);
+    check_and_apply_sbas(&mut game, &mut trigger_queue).unwrap();
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
+
+    assert!(game.battlefield.contains(&attacker_id));
+    let dies_triggers: Vec<&str> = trigger_queue
+        .entries
+        .iter()
+        .map(|entry| entry.source_name.as_str())
+        .collect();
+    assert_eq!(dies_triggers, vec!["Blocker A", "Blocker B", "Blocker C"]);
+    assert!(
+        trigger_queue
+            .entries
+            .iter()
+            .all(|entry| entry.controller == bob)
+    );
+}
+
+#[test]
+fn test_trample_damage() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let attacker_id = create_creature(&mut game, "Trampler", alice, 5, 5);
+    let blocker_id = create_creature(&mut game, "Small Blocker", bob, 2, 2);
+
+    // Add trample
+    if let Some(obj) = game.object_mut(attacker_id) {
+        obj.abilities.push(Ability::static_ability(
+            crate::static_abilities::StaticAbility::trample(),
+        ));
+    }
+
+    // Set up combat
+    let mut combat = CombatState::default();
+    combat.attackers.push(crate::combat_state::AttackerInfo {
         creature: attacker_id,
         target: AttackTarget::Player(bob),
     });
@@ -2763,8 +3520,126 @@ fn test_sba_player_loses() {
     assert!(game.player(alice).unwrap().has_lost);
 }
 
+#[test]
+fn test_concede_during_opponents_turn_ends_game_immediately() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+
+    // "You can't lose the game" doesn't stop a concession (rule 104.3a).
+    game.cant_effects.add_cant_lose_game(bob);
+
+    game.concede(bob);
+
+    assert!(game.player(bob).unwrap().has_lost);
+    assert_eq!(
+        game.game_result(),
+        Some(crate::decision::GameResult::Winner(alice))
+    );
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut dm = crate::decision::AutoPassDecisionMaker;
+    let result = run_priority_loop_with(&mut game, &mut trigger_queue, &mut dm).unwrap();
+    assert!(matches!(
+        result,
+        GameProgress::GameOver(crate::decision::GameResult::Winner(winner)) if winner == alice
+    ));
+}
+
 // === Priority Loop Tests ===
 
+#[test]
+fn test_priority_policy_auto_passes_until_spell_targets_holder() {
+    use crate::cards::definitions::{basic_mountain, lightning_bolt};
+    use crate::decisions::{PriorityPolicy, StopConditions};
+
+    struct RecordingPasser {
+        prompted: std::rc::Rc<std::cell::RefCell<Vec<(PlayerId, usize)>>>,
+    }
+
+    impl DecisionMaker for RecordingPasser {
+        fn decide_priority(
+            &mut self,
+            game: &GameState,
+            ctx: &crate::decisions::context::PriorityContext,
+        ) -> LegalAction {
+            self.prompted
+                .borrow_mut()
+                .push((ctx.player, game.stack.len()));
+            LegalAction::PassPriority
+        }
+    }
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+
+    // Give Bob an instant he could cast, so his priority isn't trivially skipped.
+    let mountain = game.create_object_from_definition(&basic_mountain(), bob, Zone::Battlefield);
+    game.remove_summoning_sickness(mountain);
+    game.create_object_from_definition(&lightning_bolt(), bob, Zone::Hand);
+
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let prompted = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let mut dm = crate::decision::DecisionRouter::new(Box::new(RecordingPasser {
+        prompted: prompted.clone(),
+    }))
+    .with_priority_policy(
+        bob,
+        PriorityPolicy::default()
+            .with_conditions(StopConditions {
+                stop_if_targets_me: true,
+                ..StopConditions::default()
+            })
+            .with_pass_until(Phase::Ending, Some(Step::End)),
+    );
+    let mut trigger_queue = TriggerQueue::new();
+
+    // Empty stack: Bob's priority is passed for him.
+    let result =
+        run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm).unwrap();
+    assert!(matches!(result, GameProgress::Continue));
+    assert!(!prompted.borrow().iter().any(|(player, _)| *player == bob));
+
+    // A spell targeting Bob stops the policy while it's on the stack.
+    let alice_bolt = game.create_object_from_definition(&lightning_bolt(), alice, Zone::Hand);
+    let stack_bolt = game.move_object(alice_bolt, Zone::Stack).unwrap();
+    game.push_to_stack(StackEntry::new(stack_bolt, alice).with_targets(vec![Target::Player(bob)]));
+    game.turn.priority_player = Some(alice);
+    state.reset_for_new_priority_window(&mut game);
+    prompted.borrow_mut().clear();
+    run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm).unwrap();
+    assert_eq!(game.player(bob).unwrap().life, 17);
+    assert_eq!(
+        prompted
+            .borrow()
+            .iter()
+            .filter(|(player, _)| *player == bob)
+            .collect::<Vec<_>>(),
+        vec![&(bob, 1)]
+    );
+
+    // Reaching the end step cancels the policy.
+    game.turn.phase = Phase::Ending;
+    game.turn.step = Some(Step::End);
+    state.reset_for_new_priority_window(&mut game);
+    prompted.borrow_mut().clear();
+    run_priority_loop_with_state(&mut game, &mut trigger_queue, &mut state, &mut dm).unwrap();
+    assert!(prompted.borrow().contains(&(bob, 0)));
+    assert!(
+        dm.priority_policy_mut(bob)
+            .is_some_and(|policy| policy.pass_until.is_none())
+    );
+}
+
 #[test]
 fn test_priority_loop_empty_stack() {
     let mut game = setup_game();
@@ -2776,6 +3651,103 @@ fn test_priority_loop_empty_stack() {
     assert!(matches!(result, GameProgress::Continue));
 }
 
+#[test]
+fn test_priority_loop_gives_priority_to_triggers_created_by_resolution() {
+    use crate::cards::definitions::{basic_mountain, lightning_bolt};
+
+    struct RecordingPasser {
+        // (stack size, Alice's life, Bob's life) at each priority prompt.
+        prompted: Vec<(usize, i32, i32)>,
+    }
+
+    impl DecisionMaker for RecordingPasser {
+        fn decide_priority(
+            &mut self,
+            game: &GameState,
+            _ctx: &crate::decisions::context::PriorityContext,
+        ) -> LegalAction {
+            let life = |index| game.player(PlayerId::from_index(index)).unwrap().life;
+            self.prompted.push((game.stack.len(), life(0), life(1)));
+            LegalAction::PassPriority
+        }
+    }
+
+    let healer = CardDefinitionBuilder::new(CardId::new(), "Test Healer")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .parse_text("When this creature enters, you gain 3 life.")
+        .expect("healer should parse");
+    let cast_healer = |game: &mut GameState, alice: PlayerId| {
+        let in_hand = game.create_object_from_definition(&healer, alice, Zone::Hand);
+        let on_stack = game.move_object(in_hand, Zone::Stack).unwrap();
+        game.push_to_stack(StackEntry::new(on_stack, alice));
+    };
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+
+    // Give both players an instant they could cast, so neither priority
+    // window is trivially skipped.
+    for player in [alice, bob] {
+        let mountain =
+            game.create_object_from_definition(&basic_mountain(), player, Zone::Battlefield);
+        game.remove_summoning_sickness(mountain);
+        game.create_object_from_definition(&lightning_bolt(), player, Zone::Hand);
+    }
+
+    // The creature is the only thing on the stack. Its resolution empties the
+    // stack, but the ETB trigger reopens priority instead of ending the phase.
+    cast_healer(&mut game, alice);
+    let mut trigger_queue = TriggerQueue::new();
+    let mut dm = RecordingPasser {
+        prompted: Vec::new(),
+    };
+    let result = run_priority_loop_with(&mut game, &mut trigger_queue, &mut dm).unwrap();
+    assert!(matches!(result, GameProgress::Continue));
+    assert_eq!(
+        dm.prompted,
+        vec![
+            (1, 20, 20),
+            (1, 20, 20),
+            // Both players get priority with the trigger on the stack.
+            (1, 20, 20),
+            (1, 20, 20),
+            (0, 23, 20),
+            (0, 23, 20),
+        ]
+    );
+
+    // With a bolt underneath, the trigger goes on top of it and resolves
+    // first, after its own priority window.
+    game.player_mut(alice).unwrap().life = 20;
+    let alice_bolt = game.create_object_from_definition(&lightning_bolt(), alice, Zone::Hand);
+    let stack_bolt = game.move_object(alice_bolt, Zone::Stack).unwrap();
+    game.push_to_stack(StackEntry::new(stack_bolt, alice).with_targets(vec![Target::Player(bob)]));
+    cast_healer(&mut game, alice);
+    game.turn.priority_player = Some(alice);
+    dm.prompted.clear();
+    run_priority_loop_with(&mut game, &mut trigger_queue, &mut dm).unwrap();
+    assert_eq!(
+        dm.prompted,
+        vec![
+            (2, 20, 20),
+            (2, 20, 20),
+            (2, 20, 20),
+            (2, 20, 20),
+            (1, 23, 20),
+            (1, 23, 20),
+            (0, 23, 17),
+            (0, 23, 17),
+        ]
+    );
+    assert!(trigger_queue.is_empty());
+}
+
 // === Triggered Ability Tests ===
 
 #[test]
@@ -4559,68 +5531,483 @@ fn test_wall_of_roots_once_per_turn_mana_ability_fast_path() {
 }
 
 #[test]
-fn test_bosh_iron_golem_uses_sacrificed_artifact_mana_value_for_damage() {
-    use crate::decision::LegalAction;
-    use crate::mana::{ManaCost, ManaSymbol};
+fn test_marvin_gains_and_loses_llanowar_elves_mana_ability() {
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
 
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
-    let bob = PlayerId::from_index(1);
+
     game.turn.phase = Phase::FirstMain;
     game.turn.step = None;
     game.turn.active_player = alice;
     game.turn.priority_player = Some(alice);
 
-    let registry = crate::cards::CardRegistry::with_builtin_cards_for_names(["Bosh, Iron Golem"]);
-    let bosh_def = registry
-        .get("Bosh, Iron Golem")
-        .expect("Bosh, Iron Golem should be present in registry");
+    let marvin_id = game.create_object_from_definition(
+        &crate::cards::definitions::marvin_murderous_mimic(),
+        alice,
+        Zone::Battlefield,
+    );
+    game.remove_summoning_sickness(marvin_id);
+
+    let marvin_mana_abilities = |game: &GameState| -> Vec<usize> {
+        compute_legal_actions(game, alice)
+            .into_iter()
+            .filter_map(|action| match action {
+                LegalAction::ActivateManaAbility {
+                    source,
+                    ability_index,
+                } if source == marvin_id => Some(ability_index),
+                _ => None,
+            })
+            .collect()
+    };
+    assert!(marvin_mana_abilities(&game).is_empty());
 
-    let bosh_id = game.create_object_from_definition(bosh_def, alice, Zone::Battlefield);
-    let sacrificial_artifact = CardBuilder::new(CardId::new(), "Calibration Relic")
-        .card_types(vec![CardType::Artifact])
-        .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2)]]))
-        .build();
-    let relic_id = game.create_object_from_card(&sacrificial_artifact, alice, Zone::Battlefield);
+    let elves_id = game.create_object_from_definition(
+        &crate::cards::definitions::llanowar_elves(),
+        alice,
+        Zone::Battlefield,
+    );
+    game.remove_summoning_sickness(elves_id);
 
-    if let Some(player) = game.player_mut(alice) {
-        player.mana_pool.add(ManaSymbol::Red, 4);
-    }
+    let abilities = marvin_mana_abilities(&game);
+    assert_eq!(
+        abilities.len(),
+        1,
+        "Marvin should have the Elves' mana ability while they're on the battlefield"
+    );
 
-    let ability_index = game
-        .object(bosh_id)
-        .expect("Bosh should exist")
-        .abilities
-        .iter()
-        .position(|ability| matches!(ability.kind, AbilityKind::Activated(_)))
-        .expect("Bosh should have an activated ability");
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut decision_maker = SelectFirstDecisionMaker;
+    let response = PriorityResponse::PriorityAction(LegalAction::ActivateManaAbility {
+        source: marvin_id,
+        ability_index: abilities[0],
+    });
+    apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &response,
+        &mut decision_maker,
+    )
+    .expect("Marvin's copied mana ability should activate");
+
+    assert!(
+        game.is_tapped(marvin_id),
+        "the {{T}} cost should tap Marvin"
+    );
+    assert!(!game.is_tapped(elves_id), "the Elves shouldn't be tapped");
+    assert_eq!(
+        game.player(alice)
+            .unwrap()
+            .mana_pool
+            .amount(ManaSymbol::Green),
+        1
+    );
+
+    game.untap(marvin_id);
+    game.move_object(elves_id, Zone::Graveyard);
+    assert!(
+        marvin_mana_abilities(&game).is_empty(),
+        "Marvin should lose the copied ability once the Elves die"
+    );
+}
+
+#[test]
+fn test_marvin_pays_copied_sacrifice_cost_with_itself() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let marvin_id = game.create_object_from_definition(
+        &crate::cards::definitions::marvin_murderous_mimic(),
+        alice,
+        Zone::Battlefield,
+    );
+    let martyr_def = CardDefinitionBuilder::new(CardId::new(), "Devoted Martyr")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .parse_text("Sacrifice this creature: You gain 2 life.")
+        .expect("sacrifice ability should parse");
+    let martyr_id = game.create_object_from_definition(&martyr_def, alice, Zone::Battlefield);
+
+    let ability_index = crate::decision::compute_legal_actions(&game, alice)
+        .into_iter()
+        .find_map(|action| match action {
+            LegalAction::ActivateAbility {
+                source,
+                ability_index,
+            } if source == marvin_id => Some(ability_index),
+            _ => None,
+        })
+        .expect("Marvin should have the martyr's sacrifice ability");
 
     let mut trigger_queue = TriggerQueue::new();
     let mut state = PriorityLoopState::new(game.players_in_game());
     let mut dm = AutoPassDecisionMaker;
-
     let activate = PriorityResponse::PriorityAction(LegalAction::ActivateAbility {
-        source: bosh_id,
+        source: marvin_id,
         ability_index,
     });
-    let progress = apply_priority_response_with_dm(
+    apply_priority_response_with_dm(
         &mut game,
         &mut trigger_queue,
         &mut state,
         &activate,
         &mut dm,
     )
-    .expect("activation should start");
+    .expect("Marvin's copied ability should activate");
 
-    match progress {
-        crate::decision::GameProgress::NeedsDecisionCtx(
-            crate::decisions::context::DecisionContext::Targets(_),
-        ) => {}
-        other => panic!("expected Bosh to prompt for targets first, got {:?}", other),
-    }
+    assert!(
+        game.object(marvin_id).is_none(),
+        "Marvin should be sacrificed to pay the copied cost"
+    );
+    assert!(
+        game.battlefield.contains(&martyr_id),
+        "the creature Marvin copied from should stay on the battlefield"
+    );
 
-    let choose_target = PriorityResponse::Targets(vec![Target::Player(bob)]);
-    let cost_order_ctx = match apply_priority_response_with_dm(
+    resolve_stack_entry(&mut game).expect("ability should resolve");
+    assert_eq!(game.player(alice).unwrap().life, 22);
+}
+
+#[test]
+fn test_effect_error_writes_debug_dump_with_effect_and_stack() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let dump_dir = std::env::temp_dir().join(format!("maigus-error-dump-{}", std::process::id()));
+    game.set_error_dump_dir(Some(dump_dir.clone()));
+
+    let waiting = create_creature(&mut game, "Waiting Bears", alice, 2, 2);
+    game.push_to_stack(
+        StackEntry::ability(waiting, alice, vec![Effect::gain_life(1)]).with_source_info(
+            game.object(waiting).unwrap().stable_id,
+            "Waiting Bears".into(),
+        ),
+    );
+    let departed = create_creature(&mut game, "Departed Bears", alice, 2, 2);
+    game.push_to_stack(StackEntry::ability(
+        departed,
+        alice,
+        vec![Effect::gain_life(Value::SourcePower)],
+    ));
+    game.move_object(departed, Zone::Graveyard);
+
+    let Err(GameLoopError::EffectFailed(error)) = resolve_stack_entry(&mut game) else {
+        panic!("source power of a departed source without a snapshot should fail");
+    };
+    assert_eq!(
+        error.root(),
+        &crate::executor::ExecutionError::ObjectNotFound(departed)
+    );
+    let context = error
+        .context()
+        .expect("error should carry resolution context");
+    assert!(context.effect.contains("SourcePower"));
+    let path = context
+        .dump_path
+        .clone()
+        .expect("dump should be written when a dump directory is set");
+    assert!(error.to_string().contains(&path.display().to_string()));
+
+    let dump = std::fs::read_to_string(&path).expect("dump file should be readable");
+    let _ = std::fs::remove_file(&path);
+    assert!(dump.contains("Effect: "), "dump:\n{dump}");
+    assert!(dump.contains("SourcePower"), "dump:\n{dump}");
+    let stack = dump
+        .split("Stack (top first):")
+        .nth(1)
+        .expect("dump should list the live stack");
+    assert!(stack.contains("Waiting Bears (ability"), "dump:\n{dump}");
+    assert!(dump.contains(".player(\"Alice\")"), "dump:\n{dump}");
+}
+
+#[test]
+fn test_sacrificed_source_deals_damage_equal_to_its_last_known_power() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let bomber_def = CardDefinitionBuilder::new(CardId::new(), "Last Gasp Bomber")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .parse_text(
+            "Sacrifice this creature: This creature deals damage equal to its power to each opponent.",
+        )
+        .expect("sacrifice damage ability should parse");
+    let bomber_id = game.create_object_from_definition(&bomber_def, alice, Zone::Battlefield);
+    game.object_mut(bomber_id)
+        .expect("bomber exists")
+        .add_counters(crate::object::CounterType::PlusOnePlusOne, 2);
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut dm = AutoPassDecisionMaker;
+    let activate = PriorityResponse::PriorityAction(LegalAction::ActivateAbility {
+        source: bomber_id,
+        ability_index: 0,
+    });
+    apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &activate,
+        &mut dm,
+    )
+    .expect("sacrifice ability should activate");
+    assert!(
+        game.object(bomber_id).is_none(),
+        "the bomber should be sacrificed to pay its own cost"
+    );
+
+    resolve_stack_entry(&mut game).expect("ability should resolve from last known information");
+    assert_eq!(
+        game.player(bob).unwrap().life,
+        16,
+        "damage should use the bomber's power as it last existed, counters included"
+    );
+}
+
+#[test]
+fn test_manascape_refractor_taps_for_a_color_only_an_opponents_land_makes() {
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let refractor_id = game.create_object_from_definition(
+        &crate::cards::definitions::manascape_refractor(),
+        alice,
+        Zone::Battlefield,
+    );
+    game.untap(refractor_id);
+    game.create_object_from_definition(
+        &crate::cards::definitions::basic_forest(),
+        alice,
+        Zone::Battlefield,
+    );
+
+    let refractor_mana_abilities = |game: &GameState| -> Vec<usize> {
+        compute_legal_actions(game, alice)
+            .into_iter()
+            .filter_map(|action| match action {
+                LegalAction::ActivateManaAbility {
+                    source,
+                    ability_index,
+                } if source == refractor_id => Some(ability_index),
+                _ => None,
+            })
+            .collect()
+    };
+    let makes_black = |game: &GameState, ability_index: usize| {
+        game.calculated_characteristics(refractor_id)
+            .and_then(|chars| chars.abilities.get(ability_index).cloned())
+            .is_some_and(|ability| match ability.kind {
+                AbilityKind::Activated(activated) => activated
+                    .inferred_mana_symbols(game, refractor_id, alice)
+                    .contains(&ManaSymbol::Black),
+                _ => false,
+            })
+    };
+    assert!(
+        !refractor_mana_abilities(&game)
+            .into_iter()
+            .any(|index| makes_black(&game, index)),
+        "Alice's Forest can't make black mana"
+    );
+
+    let scrubland_id = game.create_object_from_definition(
+        &crate::cards::definitions::scrubland(),
+        bob,
+        Zone::Battlefield,
+    );
+    let black_ability = refractor_mana_abilities(&game)
+        .into_iter()
+        .find(|&index| makes_black(&game, index))
+        .expect("Refractor should have Bob's Scrubland's mana ability");
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut decision_maker = SelectFirstDecisionMaker;
+    let response = PriorityResponse::PriorityAction(LegalAction::ActivateManaAbility {
+        source: refractor_id,
+        ability_index: black_ability,
+    });
+    apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &response,
+        &mut decision_maker,
+    )
+    .expect("Refractor's copy of Scrubland's ability should activate");
+
+    assert!(game.is_tapped(refractor_id));
+    assert!(
+        !game.is_tapped(scrubland_id),
+        "Bob's Scrubland shouldn't be tapped"
+    );
+    let pool = &game.player(alice).unwrap().mana_pool;
+    assert_eq!(
+        pool.amount(ManaSymbol::White) + pool.amount(ManaSymbol::Black),
+        1
+    );
+    assert_eq!(game.player(bob).unwrap().mana_pool.total(), 0);
+
+    game.untap(refractor_id);
+    game.move_object(scrubland_id, Zone::Graveyard);
+    assert!(
+        !refractor_mana_abilities(&game)
+            .into_iter()
+            .any(|index| makes_black(&game, index)),
+        "Refractor should lose the Scrubland's ability once it leaves"
+    );
+}
+
+#[test]
+fn test_manascape_refractor_pays_copied_colored_costs_with_any_color() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let refractor_id = game.create_object_from_definition(
+        &crate::cards::definitions::manascape_refractor(),
+        alice,
+        Zone::Battlefield,
+    );
+    game.untap(refractor_id);
+    let forest_id = game.create_object_from_definition(
+        &crate::cards::definitions::basic_forest(),
+        alice,
+        Zone::Battlefield,
+    );
+    let moor_def = CardDefinitionBuilder::new(CardId::new(), "Bleak Moor")
+        .card_types(vec![CardType::Land])
+        .parse_text("{B}, {T}: You gain 2 life.")
+        .expect("land ability should parse");
+    game.create_object_from_definition(&moor_def, bob, Zone::Battlefield);
+    game.update_cant_effects();
+
+    let ability_index = crate::decision::compute_legal_actions(&game, alice)
+        .into_iter()
+        .find_map(|action| match action {
+            LegalAction::ActivateAbility {
+                source,
+                ability_index,
+            } if source == refractor_id => Some(ability_index),
+            _ => None,
+        })
+        .expect("Refractor's copy of the {B} ability should be payable with green mana");
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut dm = SelectFirstDecisionMaker;
+    let activate = PriorityResponse::PriorityAction(LegalAction::ActivateAbility {
+        source: refractor_id,
+        ability_index,
+    });
+    apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &activate,
+        &mut dm,
+    )
+    .expect("Refractor's copied ability should activate");
+    assert!(game.is_tapped(forest_id), "the Forest should pay the {{B}}");
+    assert!(game.is_tapped(refractor_id));
+
+    resolve_stack_entry(&mut game).expect("copied ability should resolve");
+    assert_eq!(game.player(alice).unwrap().life, 22);
+    assert_eq!(game.player(bob).unwrap().life, 20);
+}
+
+#[test]
+fn test_bosh_iron_golem_uses_sacrificed_artifact_mana_value_for_damage() {
+    use crate::decision::LegalAction;
+    use crate::mana::{ManaCost, ManaSymbol};
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let registry = crate::cards::CardRegistry::with_builtin_cards_for_names(["Bosh, Iron Golem"]);
+    let bosh_def = registry
+        .get("Bosh, Iron Golem")
+        .expect("Bosh, Iron Golem should be present in registry");
+
+    let bosh_id = game.create_object_from_definition(bosh_def, alice, Zone::Battlefield);
+    let sacrificial_artifact = CardBuilder::new(CardId::new(), "Calibration Relic")
+        .card_types(vec![CardType::Artifact])
+        .mana_cost(ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2)]]))
+        .build();
+    let relic_id = game.create_object_from_card(&sacrificial_artifact, alice, Zone::Battlefield);
+
+    if let Some(player) = game.player_mut(alice) {
+        player.mana_pool.add(ManaSymbol::Red, 4);
+    }
+
+    let ability_index = game
+        .object(bosh_id)
+        .expect("Bosh should exist")
+        .abilities
+        .iter()
+        .position(|ability| matches!(ability.kind, AbilityKind::Activated(_)))
+        .expect("Bosh should have an activated ability");
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut dm = AutoPassDecisionMaker;
+
+    let activate = PriorityResponse::PriorityAction(LegalAction::ActivateAbility {
+        source: bosh_id,
+        ability_index,
+    });
+    let progress = apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &activate,
+        &mut dm,
+    )
+    .expect("activation should start");
+
+    match progress {
+        crate::decision::GameProgress::NeedsDecisionCtx(
+            crate::decisions::context::DecisionContext::Targets(_),
+        ) => {}
+        other => panic!("expected Bosh to prompt for targets first, got {:?}", other),
+    }
+
+    let choose_target = PriorityResponse::Targets(vec![Target::Player(bob)]);
+    let cost_order_ctx = match apply_priority_response_with_dm(
         &mut game,
         &mut trigger_queue,
         &mut state,
@@ -4973,10 +6360,7 @@ fn test_yawgmoth_proliferate_activation_prompts_discard_choice() {
     };
 
     assert!(
-        next_cost_ctx
-            .description
-            .to_lowercase()
-            .contains("choose the next cost to pay"),
+        next_cost_ctx.description.key == crate::display_text::DisplayKey::ChooseNextCostFor,
         "expected next-cost prompt, got description: {}",
         next_cost_ctx.description
     );
@@ -5002,7 +6386,11 @@ fn test_yawgmoth_proliferate_activation_prompts_discard_choice() {
     };
 
     assert!(
-        objects_ctx.description.to_lowercase().contains("discard"),
+        objects_ctx
+            .description
+            .to_string()
+            .to_lowercase()
+            .contains("discard"),
         "discard cost activation should prompt discard selection, got description: {}",
         objects_ctx.description
     );
@@ -6047,7 +7435,10 @@ fn test_disturb_cast_uses_back_face_characteristics_on_stack() {
 
     let requirements = super::targeting::extract_target_requirements(
         &game,
-        stack_obj.spell_effect.as_deref().unwrap_or(&[]),
+        stack_obj
+            .spell_effect
+            .as_deref()
+            .map_or(&[][..], Vec::as_slice),
         alice,
         Some(stack_id),
     );
@@ -6118,7 +7509,10 @@ fn test_overload_cast_swaps_in_rewritten_effects_and_hits_all_matches() {
         .expect("overloaded spell should exist");
     let requirements = super::targeting::extract_target_requirements(
         &game,
-        stack_obj.spell_effect.as_deref().unwrap_or(&[]),
+        stack_obj
+            .spell_effect
+            .as_deref()
+            .map_or(&[][..], Vec::as_slice),
         alice,
         Some(stack_id),
     );
@@ -6156,22 +7550,347 @@ fn test_overload_cast_swaps_in_rewritten_effects_and_hits_all_matches() {
     );
 }
 
-#[test]
-fn test_split_card_cast_prompt_offers_front_back_and_fuse_methods() {
-    use crate::mana::ManaSymbol;
+/// Cast `spell_id` from hand, answering mode and optional-cost prompts, until
+/// the spell is on the stack and priority returns.
+fn cast_modal_spell(
+    game: &mut GameState,
+    spell_id: ObjectId,
+    caster: PlayerId,
+    modes: Vec<usize>,
+    optional_costs: Vec<(usize, u32)>,
+) -> Vec<crate::decisions::context::ModesContext> {
+    cast_modal_spell_with_targets(game, spell_id, caster, modes, optional_costs, Vec::new()).0
+}
+
+/// Cast a modal spell from hand, answering the target prompt with `targets`.
+///
+/// Returns the mode prompts and target prompts seen along the way.
+fn cast_modal_spell_with_targets(
+    game: &mut GameState,
+    spell_id: ObjectId,
+    caster: PlayerId,
+    modes: Vec<usize>,
+    optional_costs: Vec<(usize, u32)>,
+    targets: Vec<Target>,
+) -> (
+    Vec<crate::decisions::context::ModesContext>,
+    Vec<crate::decisions::context::TargetsContext>,
+) {
+    use crate::decisions::context::DecisionContext;
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut response = PriorityResponse::PriorityAction(LegalAction::CastSpell {
+        spell_id,
+        from_zone: Zone::Hand,
+        casting_method: CastingMethod::Normal,
+    });
+    let mut mode_prompts = Vec::new();
+    let mut target_prompts = Vec::new();
+    loop {
+        let progress = apply_priority_response(game, &mut trigger_queue, &mut state, &response)
+            .expect("modal cast should progress");
+        let GameProgress::NeedsDecisionCtx(ctx) = progress else {
+            panic!("modal cast should stop at a decision, got {progress:?}");
+        };
+        response = match ctx {
+            DecisionContext::Modes(modes_ctx) => {
+                mode_prompts.push(modes_ctx);
+                PriorityResponse::Modes(modes.clone())
+            }
+            DecisionContext::SelectOptions(_) if state.pending_cast.is_some() => {
+                PriorityResponse::OptionalCosts(optional_costs.clone())
+            }
+            DecisionContext::Targets(targets_ctx) => {
+                target_prompts.push(targets_ctx);
+                PriorityResponse::Targets(targets.clone())
+            }
+            DecisionContext::Priority(priority_ctx) => {
+                assert_eq!(
+                    priority_ctx.player, caster,
+                    "priority should return to the caster"
+                );
+                return (mode_prompts, target_prompts);
+            }
+            other => panic!("unexpected decision while casting modal spell: {other:?}"),
+        };
+    }
+}
 
+#[test]
+fn test_choose_two_charm_prompts_for_exactly_two_modes_and_resolves_both() {
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
-    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let charm = CardDefinitionBuilder::new(CardId::new(), "Choose Two Charm")
+        .card_types(vec![CardType::Instant])
+        .parse_text("Choose two —\n• You gain 3 life.\n• You lose 1 life.\n• Draw a card.")
+        .expect("choose-two charm should parse");
+    let spell_id = game.create_object_from_definition(&charm, alice, Zone::Hand);
+    let card_for_draw = CardBuilder::new(CardId::new(), "Library Card")
+        .card_types(vec![CardType::Instant])
+        .build();
+    game.create_object_from_card(&card_for_draw, alice, Zone::Library);
+    let hand_before = game.player(alice).unwrap().hand.len();
+
+    let prompts = cast_modal_spell(&mut game, spell_id, alice, vec![0, 2], Vec::new());
+    assert_eq!(prompts.len(), 1, "modes should be chosen once");
+    assert_eq!(prompts[0].spec.min_modes, 2);
+    assert_eq!(prompts[0].spec.max_modes, 2);
+
+    resolve_stack_entry(&mut game).expect("charm should resolve");
+    assert_eq!(game.player(alice).unwrap().life, 23);
+    // The charm left the hand and one card was drawn.
+    assert_eq!(game.player(alice).unwrap().hand.len(), hand_before);
+}
 
+#[test]
+fn test_entwined_modal_spell_resolves_every_mode() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
     game.turn.phase = Phase::FirstMain;
     game.turn.step = None;
     game.turn.active_player = alice;
     game.turn.priority_player = Some(alice);
 
-    game.player_mut(alice)
-        .expect("alice exists")
-        .mana_pool
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Entwine Probe")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text("Choose one —\n• You gain 3 life.\n• You gain 4 life.\nEntwine {0}")
+        .expect("entwine modal spell should parse");
+    let spell_id = game.create_object_from_definition(&spell, alice, Zone::Hand);
+
+    cast_modal_spell(&mut game, spell_id, alice, vec![0], vec![(0, 1)]);
+    let entry = game
+        .stack
+        .last()
+        .expect("entwined spell should be on the stack");
+    assert_eq!(entry.chosen_modes, Some(vec![0, 1]));
+
+    resolve_stack_entry(&mut game).expect("entwined spell should resolve");
+    assert_eq!(game.player(alice).unwrap().life, 27);
+}
+
+#[test]
+fn test_unentwined_modal_spell_resolves_only_the_chosen_mode() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Entwine Probe")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text("Choose one —\n• You gain 3 life.\n• You gain 4 life.\nEntwine {0}")
+        .expect("entwine modal spell should parse");
+    let spell_id = game.create_object_from_definition(&spell, alice, Zone::Hand);
+
+    cast_modal_spell(&mut game, spell_id, alice, vec![1], Vec::new());
+    resolve_stack_entry(&mut game).expect("modal spell should resolve");
+    assert_eq!(game.player(alice).unwrap().life, 24);
+}
+
+/// Alice's artifact, enchantment and land, for modal removal spells to target.
+fn modal_removal_targets(game: &mut GameState, owner: PlayerId) -> [ObjectId; 3] {
+    [CardType::Artifact, CardType::Enchantment, CardType::Land].map(|card_type| {
+        let card = CardBuilder::new(CardId::new(), "Modal Target")
+            .card_types(vec![card_type])
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Battlefield)
+    })
+}
+
+#[test]
+fn test_choose_one_or_more_asks_targets_only_for_chosen_modes() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Modal Removal")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Choose one or more —\n• Destroy target artifact.\n• Destroy target enchantment.\n• Destroy target land.",
+        )
+        .expect("choose-one-or-more spell should parse");
+    let spell_id = game.create_object_from_definition(&spell, alice, Zone::Hand);
+    let [artifact, enchantment, land] = modal_removal_targets(&mut game, bob);
+
+    let (mode_prompts, target_prompts) = cast_modal_spell_with_targets(
+        &mut game,
+        spell_id,
+        alice,
+        vec![0, 2],
+        Vec::new(),
+        vec![Target::Object(artifact), Target::Object(land)],
+    );
+    assert_eq!(mode_prompts.len(), 1);
+    assert_eq!(mode_prompts[0].spec.min_modes, 1);
+    assert_eq!(mode_prompts[0].spec.max_modes, 3);
+    assert_eq!(target_prompts.len(), 1, "targets are chosen after modes");
+    assert_eq!(
+        target_prompts[0].requirements.len(),
+        2,
+        "only the chosen modes ask for targets"
+    );
+    assert_eq!(game.stack.last().unwrap().chosen_modes, Some(vec![0, 2]));
+
+    resolve_stack_entry(&mut game).expect("modal spell should resolve");
+    assert!(!game.battlefield.contains(&artifact));
+    assert!(game.battlefield.contains(&enchantment));
+    assert!(!game.battlefield.contains(&land));
+}
+
+#[test]
+fn test_entwined_targeted_modes_each_get_a_target() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Entwined Removal")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Choose one —\n• Destroy target artifact.\n• Destroy target enchantment.\nEntwine {0}",
+        )
+        .expect("entwined removal should parse");
+    let spell_id = game.create_object_from_definition(&spell, alice, Zone::Hand);
+    let [artifact, enchantment, land] = modal_removal_targets(&mut game, bob);
+
+    let (_, target_prompts) = cast_modal_spell_with_targets(
+        &mut game,
+        spell_id,
+        alice,
+        vec![1],
+        vec![(0, 1)],
+        vec![Target::Object(artifact), Target::Object(enchantment)],
+    );
+    assert_eq!(target_prompts.len(), 1);
+    assert_eq!(target_prompts[0].requirements.len(), 2);
+    assert_eq!(game.stack.last().unwrap().chosen_modes, Some(vec![0, 1]));
+
+    resolve_stack_entry(&mut game).expect("entwined spell should resolve");
+    assert!(!game.battlefield.contains(&artifact));
+    assert!(!game.battlefield.contains(&enchantment));
+    assert!(game.battlefield.contains(&land));
+}
+
+#[test]
+fn test_entwine_is_not_offered_when_a_mode_has_no_legal_targets() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Entwined Removal")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "Choose one —\n• Destroy target artifact.\n• Destroy target enchantment.\nEntwine {0}",
+        )
+        .expect("entwined removal should parse");
+    let spell_id = game.create_object_from_definition(&spell, alice, Zone::Hand);
+    // Only an artifact: the enchantment mode has nothing to target.
+    let artifact_card = CardBuilder::new(CardId::new(), "Modal Target")
+        .card_types(vec![CardType::Artifact])
+        .build();
+    let artifact = game.create_object_from_card(&artifact_card, bob, Zone::Battlefield);
+    assert!(!entwine_can_choose_every_mode(&game, spell_id, alice));
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::CastSpell {
+            spell_id,
+            from_zone: Zone::Hand,
+            casting_method: CastingMethod::Normal,
+        }),
+    )
+    .expect("cast should start");
+    let progress = match progress {
+        GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::Modes(_)) => {
+            apply_priority_response(
+                &mut game,
+                &mut trigger_queue,
+                &mut state,
+                &PriorityResponse::Modes(vec![0]),
+            )
+            .expect("modes should be accepted")
+        }
+        other => panic!("expected a modes prompt, got {other:?}"),
+    };
+    let GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::SelectOptions(
+        costs_ctx,
+    )) = progress
+    else {
+        panic!("expected an optional costs prompt, got {progress:?}");
+    };
+    assert!(
+        costs_ctx.options.iter().all(|option| !option.legal),
+        "entwine should not be a legal choice"
+    );
+
+    // Picking it anyway leaves the cast going with just the announced mode.
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::OptionalCosts(vec![(0, 1)]),
+    )
+    .expect("optional costs should be accepted");
+    let GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::Targets(
+        targets_ctx,
+    )) = progress
+    else {
+        panic!("expected a targets prompt, got {progress:?}");
+    };
+    assert_eq!(targets_ctx.requirements.len(), 1);
+    apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::Targets(vec![Target::Object(artifact)]),
+    )
+    .expect("targets should be accepted");
+    let entry = game.stack.last().expect("spell should be on the stack");
+    assert_eq!(entry.chosen_modes, Some(vec![0]));
+    assert!(!entry.optional_costs_paid.was_entwined());
+
+    resolve_stack_entry(&mut game).expect("spell should resolve");
+    assert!(!game.battlefield.contains(&artifact));
+}
+
+#[test]
+fn test_split_card_cast_prompt_offers_front_back_and_fuse_methods() {
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    game.player_mut(alice)
+        .expect("alice exists")
+        .mana_pool
         .add(ManaSymbol::Colorless, 4);
     game.player_mut(alice)
         .expect("alice exists")
@@ -6378,6 +8097,175 @@ fn test_fused_split_cast_combines_effects_and_resolves_in_order() {
     );
 }
 
+#[test]
+fn test_split_halves_cast_from_hand_resolve_their_own_effects() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let sol_ring = game.create_object_from_definition(
+        &crate::cards::definitions::sol_ring(),
+        bob,
+        Zone::Battlefield,
+    );
+    let crusade = game.create_object_from_definition(
+        &crate::cards::definitions::crusade(),
+        bob,
+        Zone::Battlefield,
+    );
+    let wear_tear = crate::cards::definitions::wear();
+    let mut dm = SelectFirstDecisionMaker;
+
+    let first = game.create_object_from_definition(&wear_tear, alice, Zone::Hand);
+    let stack_id = super::priority_mana::propose_spell_cast(
+        &mut game,
+        first,
+        Zone::Hand,
+        alice,
+        &CastingMethod::Normal,
+    )
+    .expect("Wear should move to stack");
+    assert_eq!(game.object(stack_id).expect("Wear on stack").name, "Wear");
+    game.stack.push(
+        StackEntry::new(stack_id, alice)
+            .with_targets(vec![Target::Object(sol_ring)])
+            .with_casting_method(CastingMethod::Normal),
+    );
+    resolve_stack_entry_with(&mut game, &mut dm).expect("Wear should resolve");
+    assert!(
+        !game.battlefield.contains(&sol_ring),
+        "Wear destroys the artifact"
+    );
+    assert!(game.battlefield.contains(&crusade));
+
+    let second = game.create_object_from_definition(&wear_tear, alice, Zone::Hand);
+    let stack_id = super::priority_mana::propose_spell_cast(
+        &mut game,
+        second,
+        Zone::Hand,
+        alice,
+        &CastingMethod::SplitOtherHalf,
+    )
+    .expect("Tear should move to stack");
+    let stack_obj = game.object(stack_id).expect("Tear on stack");
+    assert_eq!(stack_obj.name, "Tear");
+    assert_eq!(
+        stack_obj
+            .mana_cost
+            .as_ref()
+            .map(|cost| cost.to_oracle())
+            .as_deref(),
+        Some("{W}")
+    );
+    game.stack.push(
+        StackEntry::new(stack_id, alice)
+            .with_targets(vec![Target::Object(crusade)])
+            .with_casting_method(CastingMethod::SplitOtherHalf),
+    );
+    resolve_stack_entry_with(&mut game, &mut dm).expect("Tear should resolve");
+    assert!(
+        !game.battlefield.contains(&crusade),
+        "Tear destroys the enchantment"
+    );
+    assert_eq!(
+        game.player(alice).expect("alice exists").graveyard.len(),
+        2,
+        "both split cards should end in their owner's graveyard"
+    );
+}
+
+#[test]
+fn test_fused_wear_tear_destroys_both_targets() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let sol_ring = game.create_object_from_definition(
+        &crate::cards::definitions::sol_ring(),
+        bob,
+        Zone::Battlefield,
+    );
+    let crusade = game.create_object_from_definition(
+        &crate::cards::definitions::crusade(),
+        bob,
+        Zone::Battlefield,
+    );
+    let split_id =
+        game.create_object_from_definition(&crate::cards::definitions::wear(), alice, Zone::Hand);
+
+    let stack_id = super::priority_mana::propose_spell_cast(
+        &mut game,
+        split_id,
+        Zone::Hand,
+        alice,
+        &CastingMethod::Fuse,
+    )
+    .expect("fused Wear // Tear should move to stack");
+    let stack_obj = game.object(stack_id).expect("fused spell on stack");
+    assert_eq!(stack_obj.name, "Wear // Tear");
+    assert_eq!(
+        stack_obj
+            .mana_cost
+            .as_ref()
+            .map(|cost| cost.to_oracle())
+            .as_deref(),
+        Some("{1}{R}{W}")
+    );
+
+    let mut dm = SelectFirstDecisionMaker;
+    game.stack.push(
+        StackEntry::new(stack_id, alice)
+            .with_targets(vec![Target::Object(sol_ring), Target::Object(crusade)])
+            .with_casting_method(CastingMethod::Fuse),
+    );
+    resolve_stack_entry_with(&mut game, &mut dm).expect("fused spell should resolve");
+    assert!(!game.battlefield.contains(&sol_ring));
+    assert!(!game.battlefield.contains(&crusade));
+}
+
+#[test]
+fn test_aftermath_half_cast_from_graveyard_is_exiled_after_resolving() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let card_id = game.create_object_from_definition(
+        &crate::cards::definitions::cut(),
+        alice,
+        Zone::Graveyard,
+    );
+    let stack_id = super::priority_mana::propose_spell_cast(
+        &mut game,
+        card_id,
+        Zone::Graveyard,
+        alice,
+        &CastingMethod::SplitOtherHalf,
+    )
+    .expect("Ribbons should move to stack from the graveyard");
+    assert_eq!(
+        game.object(stack_id).expect("Ribbons on stack").name,
+        "Ribbons"
+    );
+
+    let mut dm = SelectFirstDecisionMaker;
+    game.stack.push(
+        StackEntry::new(stack_id, alice)
+            .with_x(3)
+            .with_casting_method(CastingMethod::SplitOtherHalf),
+    );
+    resolve_stack_entry_with(&mut game, &mut dm).expect("Ribbons should resolve");
+
+    assert_eq!(game.player(bob).expect("bob exists").life, 17);
+    assert!(
+        game.player(alice)
+            .expect("alice exists")
+            .graveyard
+            .is_empty(),
+        "aftermath half should not return to the graveyard"
+    );
+    assert_eq!(game.exile.len(), 1, "aftermath half should be exiled");
+}
+
 #[test]
 fn test_cipher_resolution_encodes_and_combat_damage_casts_a_copy() {
     let mut game = setup_game();
@@ -6503,7 +8391,7 @@ fn test_rebound_exiles_on_resolution_and_schedules_next_upkeep_cast() {
         .card_types(vec![CardType::Instant])
         .build();
     let mut definition = CardDefinition::new(card);
-    definition.spell_effect = Some(vec![Effect::gain_life(1)]);
+    definition.spell_effect = Some(vec![Effect::gain_life(1)].into());
     definition.abilities.push(
         Ability::static_ability(StaticAbility::rebound())
             .in_zones(vec![Zone::Stack])
@@ -6883,26 +8771,199 @@ fn test_everflowing_chalice_etb_trigger_uses_object_kick_count() {
     );
 }
 
-// =========================================================================
-// Force of Will / Alternative Cost Tests
-// =========================================================================
-
-#[test]
-fn test_force_of_will_alternative_cost_available() {
-    use crate::cards::definitions::force_of_will;
-    use crate::decision::compute_legal_actions;
-
-    let mut game = setup_game();
-    let alice = PlayerId::from_index(0);
-    let bob = PlayerId::from_index(1);
-
-    // Set up - alice needs something to counter
-    // Put a spell on the stack that bob cast
-    game.turn.phase = Phase::FirstMain;
-    game.turn.step = None;
-    game.turn.priority_player = Some(alice);
+/// Cast `spell_id` from hand, answering each optional-cost prompt with the next
+/// entry of `optional_cost_rounds` (declining once they run out) and every
+/// target prompt with `targets`. Returns how many optional-cost prompts were
+/// shown before the spell reached the stack.
+fn cast_with_optional_cost_rounds(
+    game: &mut GameState,
+    spell_id: ObjectId,
+    optional_cost_rounds: Vec<Vec<(usize, u32)>>,
+    targets: Vec<Target>,
+) -> usize {
+    use crate::decisions::context::DecisionContext;
 
-    // Create a spell on the stack for alice to counter
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut dm = SelectFirstDecisionMaker;
+    let mut rounds = optional_cost_rounds.into_iter();
+    let mut prompts = 0;
+    let mut progress = apply_priority_response(
+        game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::CastSpell {
+            spell_id,
+            from_zone: Zone::Hand,
+            casting_method: CastingMethod::Normal,
+        }),
+    )
+    .expect("cast should start");
+    loop {
+        let GameProgress::NeedsDecisionCtx(ctx) = progress else {
+            panic!("cast should stop at a decision, got {progress:?}");
+        };
+        let response = match &ctx {
+            DecisionContext::SelectOptions(_)
+                if state.pending_cast.as_ref().is_some_and(|pending| {
+                    matches!(pending.stage, CastStage::ChoosingOptionalCosts)
+                }) =>
+            {
+                prompts += 1;
+                PriorityResponse::OptionalCosts(rounds.next().unwrap_or_default())
+            }
+            DecisionContext::Targets(_) => PriorityResponse::Targets(targets.clone()),
+            DecisionContext::Priority(_) if state.pending_cast.is_none() => return prompts,
+            _ => {
+                progress = apply_decision_context_with_dm(
+                    game,
+                    &mut trigger_queue,
+                    &mut state,
+                    &ctx,
+                    &mut dm,
+                )
+                .expect("cast decision should apply");
+                continue;
+            }
+        };
+        progress = apply_priority_response(game, &mut trigger_queue, &mut state, &response)
+            .expect("cast should progress");
+    }
+}
+
+#[test]
+fn test_multikicker_prompts_until_declined_and_charges_each_kick() {
+    use crate::mana::ManaSymbol;
+    use crate::object::CounterType;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    let pool = &mut game.player_mut(alice).unwrap().mana_pool;
+    pool.add(ManaSymbol::Green, 1);
+    pool.add(ManaSymbol::Colorless, 3);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Multikicker Probe")
+        .parse_text(
+            "Mana cost: {G}\n\
+             Type: Creature — Elf\n\
+             Power/Toughness: 0/0\n\
+             Multikicker {1}\n\
+             This creature enters with a +1/+1 counter on it for each time it was kicked.",
+        )
+        .expect("multikicker creature should parse");
+    let spell_id = game.create_object_from_definition(&def, alice, Zone::Hand);
+
+    let prompts = cast_with_optional_cost_rounds(
+        &mut game,
+        spell_id,
+        vec![vec![(0, 1)], vec![(0, 1)], vec![(0, 1)]],
+        Vec::new(),
+    );
+    // A fourth kick isn't affordable, so the prompt stops after the third.
+    assert_eq!(prompts, 3);
+    let entry = game
+        .stack
+        .last()
+        .expect("kicked creature should be on the stack");
+    assert_eq!(entry.optional_costs_paid.kick_count(), 3);
+    assert_eq!(
+        game.player(alice).unwrap().mana_pool.total(),
+        0,
+        "each kick should be paid for"
+    );
+
+    resolve_stack_entry(&mut game).expect("kicked creature should resolve");
+    let creature = game
+        .battlefield
+        .iter()
+        .copied()
+        .find(|&id| {
+            game.object(id)
+                .is_some_and(|obj| obj.name == "Multikicker Probe")
+        })
+        .expect("kicked creature should be on the battlefield");
+    assert_eq!(
+        game.object(creature)
+            .unwrap()
+            .counters
+            .get(&CounterType::PlusOnePlusOne),
+        Some(&3)
+    );
+}
+
+#[test]
+fn test_strive_charges_its_cost_for_each_target_beyond_the_first() {
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    let pool = &mut game.player_mut(alice).unwrap().mana_pool;
+    pool.add(ManaSymbol::Green, 3);
+    pool.add(ManaSymbol::Colorless, 2);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Strive Probe")
+        .parse_text(
+            "Mana cost: {G}\n\
+             Type: Instant\n\
+             Strive — This spell costs {1}{G} more to cast for each target beyond the first.\n\
+             Any number of target creatures each get +1/+1 until end of turn.",
+        )
+        .expect("strive spell should parse");
+    let spell_id = game.create_object_from_definition(&def, alice, Zone::Hand);
+    let targets: Vec<Target> = (0..3)
+        .map(|i| Target::Object(create_creature(&mut game, &format!("Bear {i}"), bob, 2, 2)))
+        .collect();
+
+    let spell = game.object(spell_id).unwrap();
+    let cost = crate::decision::calculate_effective_mana_cost_for_payment_with_chosen_targets(
+        &game,
+        alice,
+        spell,
+        spell.mana_cost.as_ref().unwrap(),
+        &targets,
+    );
+    assert_eq!(cost.mana_value(), 5);
+    let green_pips = cost
+        .pips()
+        .iter()
+        .filter(|pip| pip.as_slice() == [ManaSymbol::Green])
+        .count();
+    assert_eq!(green_pips, 3, "each extra target adds {{1}}{{G}}");
+
+    cast_with_optional_cost_rounds(&mut game, spell_id, Vec::new(), targets);
+    assert_eq!(game.stack.last().unwrap().targets.len(), 3);
+    assert_eq!(game.player(alice).unwrap().mana_pool.total(), 0);
+}
+
+// =========================================================================
+// Force of Will / Alternative Cost Tests
+// =========================================================================
+
+#[test]
+fn test_force_of_will_alternative_cost_available() {
+    use crate::cards::definitions::force_of_will;
+    use crate::decision::compute_legal_actions;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    // Set up - alice needs something to counter
+    // Put a spell on the stack that bob cast
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+
+    // Create a spell on the stack for alice to counter
     use crate::cards::definitions::lightning_bolt;
     let bolt_def = lightning_bolt();
     let bolt_id = game.create_object_from_definition(&bolt_def, bob, Zone::Stack);
@@ -7442,7 +9503,13 @@ fn test_choose_casting_method_flow() {
             assert_eq!(ctx.player, alice);
             assert_eq!(ctx.source, Some(fow_id));
             assert_eq!(ctx.options.len(), 2, "Should have 2 casting method options");
-            assert!(ctx.description.contains("Choose casting method"));
+            assert_eq!(
+                ctx.description,
+                crate::display_text::DisplayText::new(
+                    crate::display_text::DisplayKey::ChooseCastingMethodFor
+                )
+                .with_card("Force of Will")
+            );
         }
         other => panic!(
             "Expected SelectOptions context for casting method, got {:?}",
@@ -7739,130 +9806,492 @@ fn test_dauthi_voidwalker_activation_makes_void_counter_card_castable_from_exile
     );
 }
 
-// =========================================================================
-// Underworld Breach / Granted Escape Tests
-// =========================================================================
-
-#[test]
-fn test_underworld_breach_grants_escape_to_graveyard_cards() {
-    use crate::cards::definitions::{lightning_bolt, underworld_breach};
-    use crate::decision::compute_legal_actions;
-    use crate::mana::ManaSymbol;
-
-    let mut game = setup_game();
-    let alice = PlayerId::from_index(0);
-
-    // Set up main phase
-    game.turn.phase = Phase::FirstMain;
-    game.turn.step = None;
-    game.turn.priority_player = Some(alice);
-    game.turn.active_player = alice;
-
-    // Put Underworld Breach on battlefield
-    let breach_def = underworld_breach();
-    let _breach_id = game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+fn create_static_enchantment(game: &mut GameState, controller: PlayerId, text: &str) -> ObjectId {
+    let def = CardDefinitionBuilder::new(CardId::new(), "Static Enchantment Test")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(text)
+        .expect("static enchantment text should parse");
+    game.create_object_from_definition(&def, controller, Zone::Battlefield)
+}
 
-    // Put Lightning Bolt in graveyard
-    let bolt_def = lightning_bolt();
-    let bolt_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+fn create_rest_in_peace(game: &mut GameState, controller: PlayerId) -> ObjectId {
+    create_static_enchantment(
+        game,
+        controller,
+        "If a card or token would be put into a graveyard from anywhere, exile it instead.",
+    )
+}
 
-    // Add 3 more cards to graveyard (for escape cost)
-    let _bolt2_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
-    let _bolt3_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
-    let _bolt4_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+fn create_parsed_creature(
+    game: &mut GameState,
+    owner: PlayerId,
+    name: &str,
+    text: &str,
+) -> ObjectId {
+    let def = CardDefinitionBuilder::new(CardId::new(), name)
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .parse_text(text)
+        .expect("creature text should parse");
+    game.create_object_from_definition(&def, owner, Zone::Battlefield)
+}
 
-    // Give alice enough mana to cast Lightning Bolt (R)
-    game.player_mut(alice)
-        .unwrap()
-        .mana_pool
-        .add(ManaSymbol::Red, 1);
+fn find_by_name_in_zone(game: &GameState, name: &str, zone: Zone) -> Option<ObjectId> {
+    game.objects_iter()
+        .find(|obj| obj.name == name && obj.zone == zone)
+        .map(|obj| obj.id)
+}
 
-    // Compute legal actions
-    let actions = compute_legal_actions(&game, alice);
+#[test]
+fn test_rest_in_peace_exiles_destroyed_creature_without_dies_trigger() {
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let rest_in_peace = create_rest_in_peace(&mut game, alice);
+    let creature = create_parsed_creature(
+        &mut game,
+        bob,
+        "Doomed Sage",
+        "When this creature dies, draw a card.",
+    );
 
-    // Should find a GrantedEscape cast option for Lightning Bolt
-    let escape_action = actions.iter().find(|a| {
-        matches!(
-            a,
-            LegalAction::CastSpell {
-                spell_id,
-                from_zone: Zone::Graveyard,
-                casting_method: CastingMethod::GrantedEscape { .. },
-            } if *spell_id == bolt_id
-        )
-    });
+    let mut ctx = ExecutionContext::new_default(rest_in_peace, alice);
+    execute_effect(
+        &mut game,
+        &Effect::destroy(ChooseSpec::SpecificObject(creature)),
+        &mut ctx,
+    )
+    .expect("destroy should resolve");
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
 
+    assert!(find_by_name_in_zone(&game, "Doomed Sage", Zone::Exile).is_some());
+    assert!(game.player(bob).expect("bob exists").graveyard.is_empty());
     assert!(
-        escape_action.is_some(),
-        "Should be able to cast Lightning Bolt with granted escape from graveyard"
+        trigger_queue.is_empty(),
+        "a creature exiled instead of dying should not trigger dies abilities"
     );
 }
 
 #[test]
-fn test_underworld_breach_no_escape_without_enough_cards_to_exile() {
-    use crate::cards::definitions::{lightning_bolt, underworld_breach};
-    use crate::decision::compute_legal_actions;
-    use crate::mana::ManaSymbol;
-
+fn test_rest_in_peace_stops_persist_from_returning_a_creature() {
     let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
     let alice = PlayerId::from_index(0);
+    let rest_in_peace = create_rest_in_peace(&mut game, alice);
+    let creature = create_parsed_creature(&mut game, alice, "Persistent Spirit", "Persist");
 
-    // Set up main phase
-    game.turn.phase = Phase::FirstMain;
-    game.turn.step = None;
-    game.turn.priority_player = Some(alice);
-    game.turn.active_player = alice;
+    let mut ctx = ExecutionContext::new_default(rest_in_peace, alice);
+    execute_effect(
+        &mut game,
+        &Effect::destroy(ChooseSpec::SpecificObject(creature)),
+        &mut ctx,
+    )
+    .expect("destroy should resolve");
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
 
-    // Put Underworld Breach on battlefield
-    let breach_def = underworld_breach();
-    let _breach_id = game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+    assert!(trigger_queue.is_empty(), "persist should not trigger");
+    assert!(find_by_name_in_zone(&game, "Persistent Spirit", Zone::Exile).is_some());
+    assert!(find_by_name_in_zone(&game, "Persistent Spirit", Zone::Battlefield).is_none());
+}
 
-    // Put Lightning Bolt in graveyard (ONLY card)
-    let bolt_def = lightning_bolt();
-    let bolt_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+#[test]
+fn test_rest_in_peace_exiles_milled_and_discarded_cards() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    create_rest_in_peace(&mut game, alice);
+    for name in ["Milled A", "Milled B"] {
+        let card = CardBuilder::new(CardId::new(), name)
+            .card_types(vec![CardType::Instant])
+            .build();
+        game.create_object_from_card(&card, bob, Zone::Library);
+    }
+    let discarded = CardBuilder::new(CardId::new(), "Discarded Card")
+        .card_types(vec![CardType::Sorcery])
+        .build();
+    game.create_object_from_card(&discarded, bob, Zone::Hand);
+    let source = create_creature(&mut game, "Bob's Source", bob, 1, 1);
 
-    // Only 1 card in graveyard - need 3 MORE to exile for escape
-    // So escape should not be available
+    let mut ctx = ExecutionContext::new_default(source, bob);
+    execute_effect(&mut game, &Effect::mill(2), &mut ctx).expect("mill should resolve");
+    execute_effect(&mut game, &Effect::discard(1), &mut ctx).expect("discard should resolve");
 
-    // Give alice enough mana to cast Lightning Bolt (R)
-    game.player_mut(alice)
-        .unwrap()
-        .mana_pool
-        .add(ManaSymbol::Red, 1);
+    for name in ["Milled A", "Milled B", "Discarded Card"] {
+        assert!(
+            find_by_name_in_zone(&game, name, Zone::Exile).is_some(),
+            "{name} should be exiled instead of put into a graveyard"
+        );
+    }
+    assert!(game.player(bob).expect("bob exists").graveyard.is_empty());
+}
 
-    // Compute legal actions
-    let actions = compute_legal_actions(&game, alice);
+#[test]
+fn test_rest_in_peace_exiles_countered_spell() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let rest_in_peace = create_rest_in_peace(&mut game, alice);
+    let spell = CardBuilder::new(CardId::new(), "Countered Spell")
+        .card_types(vec![CardType::Sorcery])
+        .build();
+    let spell_id = game.create_object_from_card(&spell, bob, Zone::Stack);
+    game.push_to_stack(StackEntry::new(spell_id, bob));
 
-    // Should NOT find escape option (not enough cards to exile)
-    let escape_action = actions.iter().find(|a| {
-        matches!(
-            a,
-            LegalAction::CastSpell {
-                spell_id,
-                from_zone: Zone::Graveyard,
-                casting_method: CastingMethod::GrantedEscape { .. },
-            } if *spell_id == bolt_id
-        )
-    });
+    let mut ctx = ExecutionContext::new_default(rest_in_peace, alice);
+    execute_effect(
+        &mut game,
+        &Effect::counter(ChooseSpec::SpecificObject(spell_id)),
+        &mut ctx,
+    )
+    .expect("counter should resolve");
 
-    assert!(
-        escape_action.is_none(),
-        "Should NOT be able to use escape without enough cards to exile"
-    );
+    assert!(game.stack_is_empty());
+    assert!(find_by_name_in_zone(&game, "Countered Spell", Zone::Exile).is_some());
+    assert!(game.player(bob).expect("bob exists").graveyard.is_empty());
 }
 
 #[test]
-fn test_underworld_breach_escape_needs_3_other_cards() {
-    // Regression test: with 3 cards in graveyard, you can only exile 2 OTHER cards,
-    // so escape (which requires exiling 3) should NOT be available
-    use crate::cards::definitions::{counterspell, force_of_will, think_twice, underworld_breach};
-    use crate::decision::compute_legal_actions;
-    use crate::mana::ManaSymbol;
-
+fn test_rest_in_peace_sacrificed_token_never_dies() {
     let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
     let alice = PlayerId::from_index(0);
+    create_rest_in_peace(&mut game, alice);
+    create_parsed_creature(
+        &mut game,
+        alice,
+        "Death Watcher",
+        "Whenever another creature dies, you gain 1 life.",
+    );
+    let token = create_creature(&mut game, "Spirit Token", alice, 1, 1);
+    game.object_mut(token).expect("token exists").kind = ObjectKind::Token;
 
-    // Set up main phase
+    let mut ctx = ExecutionContext::new_default(token, alice);
+    execute_effect(
+        &mut game,
+        &Effect::sacrifice(ObjectFilter::default().token(), 1),
+        &mut ctx,
+    )
+    .expect("sacrifice should resolve");
+    check_and_apply_sbas(&mut game, &mut trigger_queue).expect("apply SBAs");
+
+    assert!(
+        trigger_queue.is_empty(),
+        "an exiled token did not die, so dies triggers should not fire"
+    );
+    assert!(
+        game.player(alice)
+            .expect("alice exists")
+            .graveyard
+            .is_empty()
+    );
+    assert!(
+        find_by_name_in_zone(&game, "Spirit Token", Zone::Battlefield).is_none()
+            && find_by_name_in_zone(&game, "Spirit Token", Zone::Exile).is_none(),
+        "the token should cease to exist after being exiled"
+    );
+}
+
+#[test]
+fn test_leyline_of_the_void_only_exiles_opponents_cards() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let leyline = create_static_enchantment(
+        &mut game,
+        alice,
+        "If a card would be put into an opponent's graveyard from anywhere, exile it instead.",
+    );
+    let mine = create_creature(&mut game, "Alice's Bear", alice, 2, 2);
+    let theirs = create_creature(&mut game, "Bob's Bear", bob, 2, 2);
+
+    let mut ctx = ExecutionContext::new_default(leyline, alice);
+    for creature in [mine, theirs] {
+        execute_effect(
+            &mut game,
+            &Effect::destroy(ChooseSpec::SpecificObject(creature)),
+            &mut ctx,
+        )
+        .expect("destroy should resolve");
+    }
+
+    assert!(find_by_name_in_zone(&game, "Alice's Bear", Zone::Graveyard).is_some());
+    assert!(find_by_name_in_zone(&game, "Bob's Bear", Zone::Exile).is_some());
+}
+
+#[test]
+fn test_cards_in_graveyards_cant_be_targeted() {
+    use crate::targeting::compute_legal_targets;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bears = crate::cards::definitions::grizzly_bears();
+    let dead = game.create_object_from_definition(&bears, alice, Zone::Graveyard);
+    let source = create_creature(&mut game, "Raise Dead Source", alice, 1, 1);
+    let spec = ChooseSpec::target(ChooseSpec::Object(
+        ObjectFilter::creature().in_zone(Zone::Graveyard),
+    ));
+
+    game.update_cant_effects();
+    assert_eq!(
+        compute_legal_targets(&game, &spec, alice, Some(source)),
+        vec![Target::Object(dead)]
+    );
+
+    create_static_enchantment(
+        &mut game,
+        PlayerId::from_index(1),
+        "Cards in graveyards can't be the targets of spells or abilities.",
+    );
+    game.update_cant_effects();
+    assert!(
+        compute_legal_targets(&game, &spec, alice, Some(source)).is_empty(),
+        "graveyard cards should not be targetable, even by their owner"
+    );
+}
+
+// =========================================================================
+// Counter and Token Doubling Tests
+// =========================================================================
+
+const DOUBLING_SEASON_TEXT: &str = "If an effect would create one or more tokens under your control, it creates twice that many of those tokens instead.\nIf an effect would put one or more counters on a permanent you control, it puts twice that many of those counters on that permanent instead.";
+
+fn soldier_token_definition() -> crate::cards::CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Soldier")
+        .token()
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .build()
+}
+
+fn put_plus_one_counters(game: &mut GameState, controller: PlayerId, target: ObjectId, count: i32) {
+    let effect = Effect::new(crate::effects::PutCountersEffect::new(
+        crate::object::CounterType::PlusOnePlusOne,
+        count,
+        ChooseSpec::SpecificObject(target),
+    ));
+    let mut ctx = crate::executor::ExecutionContext::new_default(target, controller);
+    crate::executor::execute_effect(game, &effect, &mut ctx).expect("put counters should resolve");
+}
+
+fn create_soldiers(game: &mut GameState, controller: PlayerId, count: i32) -> usize {
+    let source = create_creature(game, "Token Maker", controller, 1, 1);
+    let effect = Effect::new(crate::effects::CreateTokenEffect::new(
+        soldier_token_definition(),
+        count,
+        crate::target::PlayerFilter::You,
+    ));
+    let mut ctx = crate::executor::ExecutionContext::new_default(source, controller);
+    crate::executor::execute_effect(game, &effect, &mut ctx).expect("create tokens should resolve");
+    game.battlefield
+        .iter()
+        .filter_map(|&id| game.object(id))
+        .filter(|obj| obj.name == "Soldier" && obj.controller == controller)
+        .count()
+}
+
+#[test]
+fn test_doubling_season_doubles_counters_and_tokens_for_its_controller_only() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    create_static_enchantment(&mut game, alice, DOUBLING_SEASON_TEXT);
+    let alice_bear = create_creature(&mut game, "Alice Bear", alice, 2, 2);
+    let bob_bear = create_creature(&mut game, "Bob Bear", bob, 2, 2);
+
+    put_plus_one_counters(&mut game, alice, alice_bear, 2);
+    put_plus_one_counters(&mut game, alice, bob_bear, 2);
+    assert_eq!(
+        game.counter_count(alice_bear, crate::object::CounterType::PlusOnePlusOne),
+        4
+    );
+    assert_eq!(
+        game.counter_count(bob_bear, crate::object::CounterType::PlusOnePlusOne),
+        2,
+        "counters on an opponent's permanent are not doubled"
+    );
+
+    assert_eq!(create_soldiers(&mut game, alice, 1), 2);
+    assert_eq!(
+        create_soldiers(&mut game, bob, 1),
+        1,
+        "tokens created under an opponent's control are not doubled"
+    );
+}
+
+#[test]
+fn test_two_doubling_seasons_quadruple_counters_and_tokens() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    create_static_enchantment(&mut game, alice, DOUBLING_SEASON_TEXT);
+    create_static_enchantment(&mut game, alice, DOUBLING_SEASON_TEXT);
+    let bear = create_creature(&mut game, "Bear", alice, 2, 2);
+
+    put_plus_one_counters(&mut game, alice, bear, 1);
+    assert_eq!(
+        game.counter_count(bear, crate::object::CounterType::PlusOnePlusOne),
+        4
+    );
+    assert_eq!(create_soldiers(&mut game, alice, 1), 4);
+}
+
+#[test]
+fn test_hardened_scales_adds_one_counter_and_applies_to_entering_counters() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    create_static_enchantment(
+        &mut game,
+        alice,
+        "If one or more +1/+1 counters would be put on a creature you control, that many plus one +1/+1 counters are put on it instead.",
+    );
+    let bear = create_creature(&mut game, "Bear", alice, 2, 2);
+    put_plus_one_counters(&mut game, alice, bear, 1);
+    assert_eq!(
+        game.counter_count(bear, crate::object::CounterType::PlusOnePlusOne),
+        2
+    );
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Entering Hydra")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(0, 0))
+        .parse_text("This creature enters with two +1/+1 counters on it.")
+        .expect("enters-with-counters text should parse");
+    let in_hand = game.create_object_from_definition(&def, alice, Zone::Hand);
+    let mut dm = SelectFirstDecisionMaker;
+    let entered = game
+        .move_object_with_etb_processing_with_dm(in_hand, Zone::Battlefield, &mut dm)
+        .expect("hydra should enter")
+        .new_id;
+    assert_eq!(
+        game.counter_count(entered, crate::object::CounterType::PlusOnePlusOne),
+        3
+    );
+}
+
+// =========================================================================
+// Underworld Breach / Granted Escape Tests
+// =========================================================================
+
+#[test]
+fn test_underworld_breach_grants_escape_to_graveyard_cards() {
+    use crate::cards::definitions::{lightning_bolt, underworld_breach};
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    // Set up main phase
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    // Put Underworld Breach on battlefield
+    let breach_def = underworld_breach();
+    let _breach_id = game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+
+    // Put Lightning Bolt in graveyard
+    let bolt_def = lightning_bolt();
+    let bolt_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+
+    // Add 3 more cards to graveyard (for escape cost)
+    let _bolt2_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+    let _bolt3_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+    let _bolt4_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+
+    // Give alice enough mana to cast Lightning Bolt (R)
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Red, 1);
+
+    // Compute legal actions
+    let actions = compute_legal_actions(&game, alice);
+
+    // Should find a GrantedEscape cast option for Lightning Bolt
+    let escape_action = actions.iter().find(|a| {
+        matches!(
+            a,
+            LegalAction::CastSpell {
+                spell_id,
+                from_zone: Zone::Graveyard,
+                casting_method: CastingMethod::GrantedEscape { .. },
+            } if *spell_id == bolt_id
+        )
+    });
+
+    assert!(
+        escape_action.is_some(),
+        "Should be able to cast Lightning Bolt with granted escape from graveyard"
+    );
+}
+
+#[test]
+fn test_underworld_breach_no_escape_without_enough_cards_to_exile() {
+    use crate::cards::definitions::{lightning_bolt, underworld_breach};
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    // Set up main phase
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    // Put Underworld Breach on battlefield
+    let breach_def = underworld_breach();
+    let _breach_id = game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+
+    // Put Lightning Bolt in graveyard (ONLY card)
+    let bolt_def = lightning_bolt();
+    let bolt_id = game.create_object_from_definition(&bolt_def, alice, Zone::Graveyard);
+
+    // Only 1 card in graveyard - need 3 MORE to exile for escape
+    // So escape should not be available
+
+    // Give alice enough mana to cast Lightning Bolt (R)
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Red, 1);
+
+    // Compute legal actions
+    let actions = compute_legal_actions(&game, alice);
+
+    // Should NOT find escape option (not enough cards to exile)
+    let escape_action = actions.iter().find(|a| {
+        matches!(
+            a,
+            LegalAction::CastSpell {
+                spell_id,
+                from_zone: Zone::Graveyard,
+                casting_method: CastingMethod::GrantedEscape { .. },
+            } if *spell_id == bolt_id
+        )
+    });
+
+    assert!(
+        escape_action.is_none(),
+        "Should NOT be able to use escape without enough cards to exile"
+    );
+}
+
+#[test]
+fn test_underworld_breach_escape_needs_3_other_cards() {
+    // Regression test: with 3 cards in graveyard, you can only exile 2 OTHER cards,
+    // so escape (which requires exiling 3) should NOT be available
+    use crate::cards::definitions::{counterspell, force_of_will, think_twice, underworld_breach};
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    // Set up main phase
     game.turn.phase = Phase::FirstMain;
     game.turn.step = None;
     game.turn.priority_player = Some(alice);
@@ -8338,12 +10767,17 @@ fn test_force_of_will_escape_with_spell_on_stack() {
     // Provide the target (Lightning Bolt on stack - spells are objects)
     let targets_response = PriorityResponse::Targets(vec![Target::Object(bolt_id)]);
     let progress2 =
-        apply_priority_response(&mut game, &mut trigger_queue, &mut state, &targets_response);
+        apply_priority_response(&mut game, &mut trigger_queue, &mut state, &targets_response)
+            .expect("targeting should succeed");
 
-    assert!(
-        progress2.is_ok(),
-        "Targeting should succeed. Got: {:?}",
-        progress2
+    // Escape's exile cost is paid one chosen card at a time.
+    let exile_choices = game.player(alice).unwrap().graveyard.clone();
+    pay_escape_exile_choices(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        progress2,
+        &exile_choices,
     );
 
     // Verify the escape cost was paid:
@@ -8379,35 +10813,337 @@ fn test_force_of_will_escape_with_spell_on_stack() {
     assert!(fow_on_stack, "Force of Will should be on the stack");
 }
 
-// ============================================================================
-// Affinity for Artifacts Tests
-// ============================================================================
-
 #[test]
-fn test_affinity_reduces_mana_cost() {
-    // Frogmite costs {4} with affinity for artifacts
-    // With 4 artifacts in play, it should cost {0}
-    use crate::cards::definitions::frogmite;
-    use crate::decision::{calculate_effective_mana_cost, compute_legal_actions};
+fn test_play_from_graveyard_grant_offers_land_play_from_graveyard() {
+    use crate::cards::definitions::basic_mountain;
+    use crate::decision::compute_legal_actions;
+    use crate::effects::{EffectExecutor, GrantPlayFromGraveyardEffect};
+    use crate::executor::ExecutionContext;
 
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
 
-    // Set up main phase
     game.turn.phase = Phase::FirstMain;
     game.turn.step = None;
     game.turn.priority_player = Some(alice);
     game.turn.active_player = alice;
 
-    // Create 4 artifacts on the battlefield
-    for i in 0..4 {
-        let artifact = CardBuilder::new(CardId::new(), &format!("Artifact {}", i))
-            .card_types(vec![CardType::Artifact])
-            .build();
-        game.create_object_from_card(&artifact, alice, Zone::Battlefield);
-    }
+    let mountain_id = game.create_object_from_definition(&basic_mountain(), alice, Zone::Graveyard);
+    let plays_mountain = |game: &GameState| {
+        compute_legal_actions(game, alice).iter().any(
+            |action| matches!(action, LegalAction::PlayLand { land_id } if *land_id == mountain_id),
+        )
+    };
+    assert!(
+        !plays_mountain(&game),
+        "no grant means no graveyard land play"
+    );
 
-    // Put Frogmite in hand with NO mana in pool
+    // Yawgmoth's Will: "You may play lands and cast spells from your graveyard."
+    let source = game.new_object_id();
+    let mut ctx = ExecutionContext::new_default(source, alice);
+    GrantPlayFromGraveyardEffect::you()
+        .execute(&mut game, &mut ctx)
+        .expect("grant should resolve");
+    assert!(
+        plays_mountain(&game),
+        "granted graveyard land should be playable"
+    );
+
+    let mut dm = AutoPassDecisionMaker;
+    crate::special_actions::perform(
+        crate::special_actions::SpecialAction::PlayLand {
+            card_id: mountain_id,
+        },
+        &mut game,
+        alice,
+        &mut dm,
+    )
+    .expect("land play from graveyard should succeed");
+    assert!(game.player(alice).unwrap().graveyard.is_empty());
+    assert!(game.battlefield.iter().any(|&id| {
+        game.object(id)
+            .is_some_and(|obj| obj.name == "Mountain" && obj.controller == alice)
+    }));
+}
+
+/// Answer the next-cost and card-choice prompts of a pending escape cast by
+/// exiling `choices` in order, returning the progress once no more graveyard
+/// exile choices are requested.
+fn pay_escape_exile_choices(
+    game: &mut GameState,
+    trigger_queue: &mut TriggerQueue,
+    state: &mut PriorityLoopState,
+    mut progress: GameProgress,
+    choices: &[ObjectId],
+) -> GameProgress {
+    let mut dm = AutoPassDecisionMaker;
+    let mut choices = choices.iter().copied();
+    loop {
+        let response = match &progress {
+            GameProgress::NeedsDecisionCtx(
+                crate::decisions::context::DecisionContext::SelectOptions(ctx),
+            ) if state.pending_cast.is_some() => {
+                let exile_option = ctx
+                    .options
+                    .iter()
+                    .find(|opt| opt.description.to_ascii_lowercase().contains("exile"))
+                    .or_else(|| ctx.options.first())
+                    .expect("next-cost chooser should offer an option");
+                PriorityResponse::NextCostChoice(exile_option.index)
+            }
+            GameProgress::NeedsDecisionCtx(
+                crate::decisions::context::DecisionContext::SelectObjects(_),
+            ) if state.pending_cast.is_some() => PriorityResponse::CardCostChoice(
+                choices
+                    .next()
+                    .expect("escape asked for more exile choices than provided"),
+            ),
+            _ => return progress,
+        };
+        progress = apply_priority_response_with_dm(game, trigger_queue, state, &response, &mut dm)
+            .expect("escape cost payment should succeed");
+    }
+}
+
+#[test]
+fn test_underworld_breach_recasts_lightning_bolt_by_exiling_three_chosen_cards() {
+    use crate::cards::definitions::{
+        basic_mountain, counterspell, force_of_will, lightning_bolt, think_twice, underworld_breach,
+    };
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    // Two Breaches grant the same escape; the bolt is still offered once.
+    let breach_def = underworld_breach();
+    game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+    game.create_object_from_definition(&breach_def, alice, Zone::Battlefield);
+
+    let bolt_id = game.create_object_from_definition(&lightning_bolt(), alice, Zone::Graveyard);
+    let fow_id = game.create_object_from_definition(&force_of_will(), alice, Zone::Graveyard);
+    let counterspell_id =
+        game.create_object_from_definition(&counterspell(), alice, Zone::Graveyard);
+    let think_twice_id = game.create_object_from_definition(&think_twice(), alice, Zone::Graveyard);
+    let mountain_id = game.create_object_from_definition(&basic_mountain(), alice, Zone::Graveyard);
+
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Red, 1);
+
+    let actions = compute_legal_actions(&game, alice);
+    let escape_actions: Vec<_> = actions
+        .iter()
+        .filter(|action| {
+            matches!(
+                action,
+                LegalAction::CastSpell {
+                    spell_id,
+                    from_zone: Zone::Graveyard,
+                    casting_method: CastingMethod::GrantedEscape { .. },
+                } if *spell_id == bolt_id
+            )
+        })
+        .cloned()
+        .collect();
+    assert_eq!(
+        escape_actions.len(),
+        1,
+        "bolt should have exactly one escape action, got {escape_actions:?}"
+    );
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(2);
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(escape_actions[0].clone()),
+    )
+    .expect("escape cast should start");
+    assert!(
+        matches!(
+            progress,
+            GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::Targets(_))
+        ),
+        "escaped bolt should ask for a target, got {progress:?}"
+    );
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::Targets(vec![Target::Player(bob)]),
+    )
+    .expect("targeting bob should succeed");
+
+    // Keep Force of Will in the graveyard; exile the other three.
+    pay_escape_exile_choices(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        progress,
+        &[counterspell_id, think_twice_id, mountain_id],
+    );
+
+    assert!(
+        state.pending_cast.is_none(),
+        "escape cast should be finished"
+    );
+    assert_eq!(game.player(alice).unwrap().graveyard, vec![fow_id]);
+    let mut exiled_names: Vec<String> = game
+        .exile
+        .iter()
+        .filter_map(|&id| game.object(id).map(|obj| obj.name.clone()))
+        .collect();
+    exiled_names.sort();
+    assert_eq!(
+        exiled_names,
+        vec!["Counterspell", "Mountain", "Think Twice"]
+    );
+
+    resolve_stack_entry(&mut game).expect("escaped bolt should resolve");
+    assert_eq!(game.player(bob).unwrap().life, 17);
+
+    // Escape doesn't exile the spell, so the bolt is back for another escape.
+    let graveyard_names: Vec<String> = game
+        .player(alice)
+        .unwrap()
+        .graveyard
+        .iter()
+        .filter_map(|&id| game.object(id).map(|obj| obj.name.clone()))
+        .collect();
+    assert!(
+        graveyard_names.iter().any(|name| name == "Lightning Bolt"),
+        "escaped bolt should return to the graveyard, got {graveyard_names:?}"
+    );
+}
+
+#[test]
+fn test_escape_creature_recast_by_exiling_chosen_graveyard_cards() {
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    let escaper_def = CardDefinitionBuilder::new(CardId::new(), "Escape Test Ox")
+        .mana_cost(crate::mana::ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(2)],
+            vec![ManaSymbol::Red],
+        ]))
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(3, 3))
+        .parse_text("Escape—{R}{R}, Exile two other cards from your graveyard.")
+        .expect("escape line should parse");
+    let escaper_id = game.create_object_from_definition(&escaper_def, alice, Zone::Graveyard);
+    let mut graveyard_cards = Vec::new();
+    for i in 0..3 {
+        let card = CardBuilder::new(CardId::new(), &format!("Graveyard Card {i}"))
+            .card_types(vec![CardType::Instant])
+            .build();
+        graveyard_cards.push(game.create_object_from_card(&card, alice, Zone::Graveyard));
+    }
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Red, 2);
+
+    let escape_action = compute_legal_actions(&game, alice)
+        .into_iter()
+        .find(|action| {
+            matches!(
+                action,
+                LegalAction::CastSpell {
+                    spell_id,
+                    from_zone: Zone::Graveyard,
+                    casting_method: CastingMethod::Alternative(_),
+                } if *spell_id == escaper_id
+            )
+        })
+        .expect("escape creature should be castable from the graveyard");
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(2);
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(escape_action),
+    )
+    .expect("escape cast should start");
+
+    // Keep the middle card; exile the other two.
+    pay_escape_exile_choices(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        progress,
+        &[graveyard_cards[0], graveyard_cards[2]],
+    );
+    assert!(
+        state.pending_cast.is_none(),
+        "escape cast should be finished"
+    );
+    assert_eq!(
+        game.player(alice).unwrap().graveyard,
+        vec![graveyard_cards[1]]
+    );
+    assert_eq!(game.exile.len(), 2);
+    assert_eq!(game.player(alice).unwrap().mana_pool.total(), 0);
+
+    resolve_stack_entry(&mut game).expect("escaped creature should resolve");
+    assert!(
+        game.battlefield.iter().any(|&id| {
+            game.object(id)
+                .is_some_and(|obj| obj.name == "Escape Test Ox")
+        }),
+        "escaped creature should enter the battlefield"
+    );
+}
+
+// ============================================================================
+// Affinity for Artifacts Tests
+// ============================================================================
+
+#[test]
+fn test_affinity_reduces_mana_cost() {
+    // Frogmite costs {4} with affinity for artifacts
+    // With 4 artifacts in play, it should cost {0}
+    use crate::cards::definitions::frogmite;
+    use crate::decision::{calculate_effective_mana_cost, compute_legal_actions};
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    // Set up main phase
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    // Create 4 artifacts on the battlefield
+    for i in 0..4 {
+        let artifact = CardBuilder::new(CardId::new(), &format!("Artifact {}", i))
+            .card_types(vec![CardType::Artifact])
+            .build();
+        game.create_object_from_card(&artifact, alice, Zone::Battlefield);
+    }
+
+    // Put Frogmite in hand with NO mana in pool
     let frogmite_def = frogmite();
     let frogmite_id = game.create_object_from_definition(&frogmite_def, alice, Zone::Hand);
 
@@ -8816,65 +11552,198 @@ fn test_delve_exiles_cards_on_cast() {
 }
 
 #[test]
-fn test_delve_cannot_cast_without_enough_graveyard_or_mana() {
-    // Treasure Cruise costs {7}{U}
-    // With 0 cards in graveyard and only 3 mana, should NOT be castable
+fn test_delve_lets_player_choose_which_graveyard_cards_to_exile() {
+    // Treasure Cruise cast for {U}: seven of nine graveyard cards pay the {7}.
     use crate::cards::definitions::treasure_cruise;
-    use crate::decision::compute_legal_actions;
+    use crate::decision::LegalAction;
     use crate::mana::ManaSymbol;
 
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
 
-    // Set up main phase
     game.turn.phase = Phase::FirstMain;
     game.turn.step = None;
     game.turn.priority_player = Some(alice);
     game.turn.active_player = alice;
 
-    // Empty graveyard
-    assert_eq!(game.player(alice).unwrap().graveyard.len(), 0);
-
-    // Put Treasure Cruise in hand
-    let tc_def = treasure_cruise();
-    let tc_id = game.create_object_from_definition(&tc_def, alice, Zone::Hand);
-
-    // Give alice 3 mana (not enough for {7}{U})
+    let mut gy_cards = Vec::new();
+    for i in 0..9 {
+        let card = CardBuilder::new(CardId::new(), &format!("Graveyard Card {}", i))
+            .card_types(vec![CardType::Creature])
+            .build();
+        gy_cards.push(game.create_object_from_card(&card, alice, Zone::Graveyard));
+    }
+    let tc_id = game.create_object_from_definition(&treasure_cruise(), alice, Zone::Hand);
     game.player_mut(alice)
         .unwrap()
         .mana_pool
         .add(ManaSymbol::Blue, 1);
-    game.player_mut(alice)
-        .unwrap()
-        .mana_pool
-        .add(ManaSymbol::Colorless, 2);
 
-    // Should NOT be able to cast
-    let actions = compute_legal_actions(&game, alice);
-    let can_cast = actions.iter().any(|a| {
-        matches!(
-            a,
-            LegalAction::CastSpell {
-                spell_id,
-                ..
-            } if *spell_id == tc_id
-        )
-    });
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut trigger_queue = crate::triggers::TriggerQueue::new();
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::CastSpell {
+            spell_id: tc_id,
+            from_zone: Zone::Hand,
+            casting_method: CastingMethod::Normal,
+        }),
+    )
+    .expect("casting should start");
+    let GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::SelectObjects(
+        ctx,
+    )) = progress
+    else {
+        panic!("expected a delve choice, got {progress:?}");
+    };
+    assert_eq!(ctx.candidates.len(), 9);
+    assert_eq!(
+        (ctx.min, ctx.max),
+        (7, Some(7)),
+        "one blue mana needs all seven generic paid by delve"
+    );
 
-    assert!(
-        !can_cast,
-        "Should NOT be able to cast Treasure Cruise with no graveyard and only 3 mana"
+    // Keep the first and last cards.
+    let chosen = gy_cards[1..8].to_vec();
+    let result = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::DelveCards(chosen.clone()),
     );
+    assert!(result.is_ok(), "delve choice should finish the cast");
+
+    assert_eq!(
+        game.player(alice).unwrap().graveyard,
+        vec![gy_cards[0], gy_cards[8]]
+    );
+    assert_eq!(game.exile.len(), 7);
+    assert_eq!(game.player(alice).unwrap().mana_pool.total(), 0);
+    assert_eq!(game.stack.len(), 1);
 }
 
 #[test]
-fn test_convoke_reduces_mana_cost_with_creatures() {
-    // Stoke the Flames costs {2}{R}{R} with Convoke
-    // With 2 untapped creatures (one red), it should cost {1}{R}
-    use crate::cards::definitions::stoke_the_flames;
-    use crate::color::ColorSet;
-    use crate::decision::{calculate_effective_mana_cost, compute_legal_actions};
-    use crate::mana::ManaSymbol;
+fn test_delve_choice_allows_paying_generic_with_mana_instead() {
+    use crate::cards::definitions::treasure_cruise;
+    use crate::decision::LegalAction;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    let mut gy_cards = Vec::new();
+    for i in 0..7 {
+        let card = CardBuilder::new(CardId::new(), &format!("Graveyard Card {}", i))
+            .card_types(vec![CardType::Creature])
+            .build();
+        gy_cards.push(game.create_object_from_card(&card, alice, Zone::Graveyard));
+    }
+    let tc_id = game.create_object_from_definition(&treasure_cruise(), alice, Zone::Hand);
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Blue, 3);
+
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut trigger_queue = crate::triggers::TriggerQueue::new();
+    let progress = apply_priority_response(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::CastSpell {
+            spell_id: tc_id,
+            from_zone: Zone::Hand,
+            casting_method: CastingMethod::Normal,
+        }),
+    )
+    .expect("casting should start");
+    let GameProgress::NeedsDecisionCtx(crate::decisions::context::DecisionContext::SelectObjects(
+        ctx,
+    )) = progress
+    else {
+        panic!("expected a delve choice, got {progress:?}");
+    };
+    assert_eq!((ctx.min, ctx.max), (5, Some(7)));
+
+    // Too few cards leaves the rest of the cost unpayable.
+    assert!(
+        apply_priority_response(
+            &mut game,
+            &mut trigger_queue,
+            &mut state,
+            &PriorityResponse::DelveCards(gy_cards[..4].to_vec()),
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_delve_cannot_cast_without_enough_graveyard_or_mana() {
+    // Treasure Cruise costs {7}{U}
+    // With 0 cards in graveyard and only 3 mana, should NOT be castable
+    use crate::cards::definitions::treasure_cruise;
+    use crate::decision::compute_legal_actions;
+    use crate::mana::ManaSymbol;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    // Set up main phase
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.priority_player = Some(alice);
+    game.turn.active_player = alice;
+
+    // Empty graveyard
+    assert_eq!(game.player(alice).unwrap().graveyard.len(), 0);
+
+    // Put Treasure Cruise in hand
+    let tc_def = treasure_cruise();
+    let tc_id = game.create_object_from_definition(&tc_def, alice, Zone::Hand);
+
+    // Give alice 3 mana (not enough for {7}{U})
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Blue, 1);
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(ManaSymbol::Colorless, 2);
+
+    // Should NOT be able to cast
+    let actions = compute_legal_actions(&game, alice);
+    let can_cast = actions.iter().any(|a| {
+        matches!(
+            a,
+            LegalAction::CastSpell {
+                spell_id,
+                ..
+            } if *spell_id == tc_id
+        )
+    });
+
+    assert!(
+        !can_cast,
+        "Should NOT be able to cast Treasure Cruise with no graveyard and only 3 mana"
+    );
+}
+
+#[test]
+fn test_convoke_reduces_mana_cost_with_creatures() {
+    // Stoke the Flames costs {2}{R}{R} with Convoke
+    // With 2 untapped creatures (one red), it should cost {1}{R}
+    use crate::cards::definitions::stoke_the_flames;
+    use crate::color::ColorSet;
+    use crate::decision::{calculate_effective_mana_cost, compute_legal_actions};
+    use crate::mana::ManaSymbol;
 
     let mut game = setup_game();
     let alice = PlayerId::from_index(0);
@@ -9366,6 +12235,7 @@ fn test_direct_finalize_trace_includes_delve_exile() {
         CastingMethod::Normal,
         OptionalCostsPaid::default(),
         None,
+        Vec::new(),
         ManaPool::default(),
         Vec::new(),
         std::collections::HashMap::new(),
@@ -11351,6 +14221,115 @@ fn test_valley_floodcaller_flash_grant_removed_when_floodcaller_leaves() {
     );
 }
 
+fn green_bear_card() -> crate::card::Card {
+    CardBuilder::new(CardId::from_raw(101), "Flash Bear")
+        .card_types(vec![CardType::Creature])
+        .mana_cost(crate::mana::ManaCost::from_pips(vec![vec![
+            crate::mana::ManaSymbol::Green,
+        ]]))
+        .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+        .build()
+}
+
+fn can_cast_from(game: &GameState, player: PlayerId, spell: ObjectId, zone: Zone) -> bool {
+    crate::decision::compute_legal_actions(game, player)
+        .iter()
+        .any(|action| {
+            matches!(
+                action,
+                crate::decision::LegalAction::CastSpell { spell_id, from_zone, .. }
+                    if *spell_id == spell && *from_zone == zone
+            )
+        })
+}
+
+#[test]
+fn test_orrery_style_grant_lets_creature_be_cast_on_opponents_turn() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.active_player = bob;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(crate::mana::ManaSymbol::Green, 1);
+
+    let bear_id = game.create_object_from_card(&green_bear_card(), alice, Zone::Hand);
+    assert!(
+        !can_cast_from(&game, alice, bear_id, Zone::Hand),
+        "a creature spell shouldn't be castable on an opponent's turn without flash"
+    );
+
+    let orrery = CardDefinitionBuilder::new(CardId::from_raw(102), "Orrery Variant")
+        .card_types(vec![CardType::Artifact])
+        .parse_text("You may cast spells as though they had flash.")
+        .expect("flash grant should parse");
+    game.create_object_from_definition(&orrery, alice, Zone::Battlefield);
+
+    assert!(
+        can_cast_from(&game, alice, bear_id, Zone::Hand),
+        "the flash grant should let the creature be cast on an opponent's turn"
+    );
+}
+
+#[test]
+fn test_flash_grants_only_apply_to_their_zone() {
+    use crate::grant::{GrantSpec, Grantable};
+    use crate::static_abilities::StaticAbility;
+    use crate::target::ObjectFilter;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    game.turn.active_player = bob;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(crate::mana::ManaSymbol::Green, 1);
+
+    let creatures = ObjectFilter::default().with_type(CardType::Creature);
+    let granter = CardBuilder::new(CardId::from_raw(103), "Graveyard Granter")
+        .card_types(vec![CardType::Enchantment])
+        .build();
+    let granter_id = game.create_object_from_card(&granter, alice, Zone::Battlefield);
+    let granter_abilities = &mut game.object_mut(granter_id).unwrap().abilities;
+    granter_abilities.push(Ability::static_ability(StaticAbility::grants(
+        GrantSpec::new(Grantable::PlayFrom, creatures.clone(), Zone::Graveyard),
+    )));
+    granter_abilities.push(Ability::static_ability(StaticAbility::grants(
+        GrantSpec::flash_to_spells(creatures.clone()),
+    )));
+
+    let bear_id = game.create_object_from_card(&green_bear_card(), alice, Zone::Graveyard);
+    assert!(
+        !can_cast_from(&game, alice, bear_id, Zone::Graveyard),
+        "flash granted to cards in hand shouldn't cover casting from the graveyard"
+    );
+
+    game.object_mut(granter_id)
+        .unwrap()
+        .abilities
+        .push(Ability::static_ability(StaticAbility::grants(
+            GrantSpec::new(
+                Grantable::Ability(StaticAbility::flash()),
+                creatures,
+                Zone::Graveyard,
+            ),
+        )));
+    assert!(
+        can_cast_from(&game, alice, bear_id, Zone::Graveyard),
+        "flash granted to creature cards in the graveyard should allow instant-speed casting"
+    );
+}
+
 #[test]
 fn test_valley_floodcaller_sorcery_castable_during_combat() {
     use crate::cards::definitions::valley_floodcaller;
@@ -11448,3 +14427,816 @@ fn test_valley_floodcaller_only_grants_to_controller() {
         "Bob's sorcery should NOT have flash from Alice's Floodcaller"
     );
 }
+
+fn metalcraft_scout_definition() -> crate::cards::CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Metalcraft Scout")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(1, 1))
+        .parse_text(
+            "When this creature enters, if you control three or more artifacts, draw a card.",
+        )
+        .expect("intervening-if ETB trigger should parse")
+}
+
+fn create_artifact(game: &mut GameState, owner: PlayerId, name: &str) -> ObjectId {
+    let card = CardBuilder::new(CardId::new(), name)
+        .card_types(vec![CardType::Artifact])
+        .build();
+    game.create_object_from_card(&card, owner, Zone::Battlefield)
+}
+
+#[test]
+fn test_intervening_if_trigger_does_not_trigger_when_condition_false() {
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let alice = PlayerId::from_index(0);
+    create_artifact(&mut game, alice, "Relic A");
+    create_artifact(&mut game, alice, "Relic B");
+
+    let def = metalcraft_scout_definition();
+    let scout = game.create_object_from_definition(&def, alice, Zone::Hand);
+    game.move_object(scout, Zone::Battlefield)
+        .expect("scout should enter the battlefield");
+
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
+    assert!(
+        trigger_queue.is_empty(),
+        "ETB trigger should not trigger with only two artifacts"
+    );
+}
+
+#[test]
+fn test_intervening_if_trigger_does_nothing_when_condition_false_on_resolution() {
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let alice = PlayerId::from_index(0);
+    let relic = create_artifact(&mut game, alice, "Relic A");
+    create_artifact(&mut game, alice, "Relic B");
+    create_artifact(&mut game, alice, "Relic C");
+    let library_card = CardBuilder::new(CardId::new(), "Metalcraft Draw Test")
+        .card_types(vec![CardType::Artifact])
+        .build();
+    game.create_object_from_card(&library_card, alice, Zone::Library);
+
+    let def = metalcraft_scout_definition();
+    let scout = game.create_object_from_definition(&def, alice, Zone::Hand);
+    game.move_object(scout, Zone::Battlefield)
+        .expect("scout should enter the battlefield");
+
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
+    put_triggers_on_stack(&mut game, &mut trigger_queue).expect("put ETB trigger on stack");
+    assert_eq!(game.stack.len(), 1, "ETB trigger should be on the stack");
+
+    game.move_object(relic, Zone::Graveyard)
+        .expect("artifact should leave before the trigger resolves");
+    let hand_before = game.player(alice).expect("alice exists").hand.len();
+    resolve_stack_entry(&mut game).expect("ETB trigger should resolve");
+
+    assert_eq!(
+        game.player(alice).expect("alice exists").hand.len(),
+        hand_before,
+        "trigger should do nothing once the condition is false"
+    );
+    assert!(game.stack_is_empty());
+}
+
+#[test]
+fn test_raid_trigger_only_fires_after_you_attacked_this_turn() {
+    let mut game = setup_game();
+    let mut trigger_queue = TriggerQueue::new();
+    let alice = PlayerId::from_index(0);
+    let def = CardDefinitionBuilder::new(CardId::new(), "Raid Scout")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .parse_text("Raid — When this creature enters, if you attacked this turn, you gain 3 life.")
+        .expect("raid ETB trigger should parse");
+
+    let first = game.create_object_from_definition(&def, alice, Zone::Hand);
+    game.move_object(first, Zone::Battlefield)
+        .expect("first scout should enter the battlefield");
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
+    assert!(
+        trigger_queue.is_empty(),
+        "raid trigger should not fire before attacking"
+    );
+
+    game.turn_trackers.record_attack(alice);
+    let second = game.create_object_from_definition(&def, alice, Zone::Hand);
+    game.move_object(second, Zone::Battlefield)
+        .expect("second scout should enter the battlefield");
+    drain_pending_trigger_events(&mut game, &mut trigger_queue);
+    put_triggers_on_stack(&mut game, &mut trigger_queue).expect("put raid trigger on stack");
+    assert_eq!(game.stack.len(), 1, "raid trigger should be on the stack");
+    resolve_stack_entry(&mut game).expect("raid trigger should resolve");
+
+    assert_eq!(game.player(alice).expect("alice exists").life, 23);
+}
+
+// === Fizzle Tests (Rule 608.2b) ===
+
+#[test]
+fn test_doom_blade_fizzles_when_target_gains_hexproof_in_response() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let bear = create_creature(&mut game, "Hexproof Bear", bob, 2, 2);
+    let doom_blade = game.create_object_from_definition(
+        &crate::cards::definitions::doom_blade(),
+        alice,
+        Zone::Stack,
+    );
+    game.push_to_stack(StackEntry::new(doom_blade, alice).with_targets(vec![Target::Object(bear)]));
+
+    // In response, the bear gains hexproof.
+    game.object_mut(bear)
+        .unwrap()
+        .abilities
+        .push(Ability::static_ability(StaticAbility::hexproof()));
+
+    resolve_stack_entry(&mut game).expect("Doom Blade should resolve");
+
+    assert!(game.battlefield.contains(&bear), "Doom Blade should fizzle");
+    assert!(game.stack.is_empty());
+    assert_eq!(game.player(alice).unwrap().graveyard.len(), 1);
+    assert!(game.player(bob).unwrap().graveyard.is_empty());
+}
+
+#[test]
+fn test_cantrip_rider_still_draws_when_one_of_two_targets_is_gone() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Double Bounce")
+        .parse_text(
+            "Mana cost: {2}{U}\nType: Instant\nReturn two target creatures to their owners' hands. Draw a card.",
+        )
+        .expect("parse bounce cantrip");
+    let first = create_creature(&mut game, "First Bear", bob, 2, 2);
+    let second = create_creature(&mut game, "Second Bear", bob, 2, 2);
+    game.create_object_from_definition(
+        &crate::cards::definitions::grizzly_bears(),
+        alice,
+        Zone::Library,
+    );
+    let spell = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(
+        StackEntry::new(spell, alice)
+            .with_targets(vec![Target::Object(first), Target::Object(second)]),
+    );
+    let hand_before = game.player(alice).unwrap().hand.len();
+
+    // One target dies in response; the spell does as much as it can.
+    game.move_object(first, Zone::Graveyard);
+
+    resolve_stack_entry(&mut game).expect("spell should resolve");
+
+    assert!(!game.battlefield.contains(&second));
+    assert_eq!(game.player(bob).unwrap().hand.len(), 1);
+    assert_eq!(game.player(alice).unwrap().hand.len(), hand_before + 1);
+}
+
+#[test]
+fn test_cantrip_rider_is_lost_when_the_only_target_is_gone() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Tap Cantrip")
+        .parse_text("Mana cost: {U}\nType: Instant\nTap target creature. Draw a card.")
+        .expect("parse tap cantrip");
+    let bear = create_creature(&mut game, "Doomed Bear", bob, 2, 2);
+    game.create_object_from_definition(
+        &crate::cards::definitions::grizzly_bears(),
+        alice,
+        Zone::Library,
+    );
+    let spell = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(StackEntry::new(spell, alice).with_targets(vec![Target::Object(bear)]));
+    let hand_before = game.player(alice).unwrap().hand.len();
+
+    game.move_object(bear, Zone::Graveyard);
+
+    resolve_stack_entry(&mut game).expect("spell should leave the stack");
+
+    // With every target illegal the spell doesn't resolve at all (rule 608.2b).
+    assert_eq!(game.player(alice).unwrap().hand.len(), hand_before);
+    assert!(game.stack.is_empty());
+}
+
+#[test]
+fn test_two_target_spell_with_one_dead_target_still_affects_the_other() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Double Kill")
+        .parse_text("Mana cost: {2}{B}\nType: Sorcery\nDestroy two target creatures.")
+        .expect("parse two-target spell");
+    let first = create_creature(&mut game, "First Bear", bob, 2, 2);
+    let second = create_creature(&mut game, "Second Bear", bob, 2, 2);
+    let spell = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(
+        StackEntry::new(spell, alice)
+            .with_targets(vec![Target::Object(first), Target::Object(second)]),
+    );
+
+    // The first target dies before the spell resolves.
+    game.move_object(first, Zone::Graveyard);
+
+    resolve_stack_entry(&mut game).expect("spell should resolve");
+
+    assert!(
+        !game.battlefield.contains(&second),
+        "legal target is destroyed"
+    );
+    assert_eq!(game.player(bob).unwrap().graveyard.len(), 2);
+    assert_eq!(game.player(alice).unwrap().graveyard.len(), 1);
+}
+
+fn arc_lightning_definition() -> crate::cards::CardDefinition {
+    CardDefinitionBuilder::new(CardId::new(), "Arc Lightning")
+        .parse_text(
+            "Mana cost: {2}{R}\nType: Sorcery\nArc Lightning deals 3 damage divided as you choose among one, two, or three targets.",
+        )
+        .expect("parse Arc Lightning")
+}
+
+struct DivideDamageDecisionMaker {
+    targets: Vec<Target>,
+    division: Vec<(Target, u32)>,
+}
+
+impl DecisionMaker for DivideDamageDecisionMaker {
+    fn decide_targets(
+        &mut self,
+        _game: &GameState,
+        _ctx: &crate::decisions::context::TargetsContext,
+    ) -> Vec<Target> {
+        self.targets.clone()
+    }
+
+    fn decide_distribute(
+        &mut self,
+        _game: &GameState,
+        _ctx: &crate::decisions::context::DistributeContext,
+    ) -> Vec<(Target, u32)> {
+        self.division.clone()
+    }
+}
+
+#[test]
+fn test_arc_lightning_records_damage_division_while_casting() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let first = create_creature(&mut game, "First Bear", bob, 2, 2);
+    let second = create_creature(&mut game, "Second Bear", bob, 2, 2);
+    let arc = game.create_object_from_definition(&arc_lightning_definition(), alice, Zone::Hand);
+    game.player_mut(alice)
+        .unwrap()
+        .mana_pool
+        .add(crate::mana::ManaSymbol::Red, 3);
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut state = PriorityLoopState::new(game.players_in_game());
+    let mut dm = DivideDamageDecisionMaker {
+        targets: vec![Target::Object(first), Target::Object(second)],
+        division: vec![(Target::Object(first), 2), (Target::Object(second), 1)],
+    };
+    let mut progress = apply_priority_response_with_dm(
+        &mut game,
+        &mut trigger_queue,
+        &mut state,
+        &PriorityResponse::PriorityAction(LegalAction::CastSpell {
+            spell_id: arc,
+            from_zone: Zone::Hand,
+            casting_method: crate::alternative_cast::CastingMethod::Normal,
+        }),
+        &mut dm,
+    )
+    .expect("cast should start");
+    let mut saw_distribute = false;
+    while let GameProgress::NeedsDecisionCtx(ctx) = &progress {
+        if matches!(ctx, crate::decisions::context::DecisionContext::Priority(_)) {
+            break;
+        }
+        if let crate::decisions::context::DecisionContext::Distribute(distribute) = ctx {
+            assert_eq!(distribute.total, 3);
+            assert_eq!(distribute.min_per_target, 1);
+            saw_distribute = true;
+        }
+        progress =
+            apply_decision_context_with_dm(&mut game, &mut trigger_queue, &mut state, ctx, &mut dm)
+                .expect("cast decision should apply");
+    }
+
+    assert!(
+        saw_distribute,
+        "the division should be chosen while casting"
+    );
+    let entry = game
+        .stack
+        .last()
+        .expect("Arc Lightning should be on the stack");
+    assert_eq!(
+        entry.divided_amounts,
+        vec![(Target::Object(first), 2), (Target::Object(second), 1)]
+    );
+}
+
+#[test]
+fn test_arc_lightning_damage_assigned_to_illegal_target_is_lost() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let first = create_creature(&mut game, "First Bear", bob, 2, 2);
+    let second = create_creature(&mut game, "Second Bear", bob, 2, 4);
+    let arc = game.create_object_from_definition(&arc_lightning_definition(), alice, Zone::Stack);
+    game.push_to_stack(
+        StackEntry::new(arc, alice)
+            .with_targets(vec![Target::Object(first), Target::Object(second)])
+            .with_divided_amounts(vec![
+                (Target::Object(first), 2),
+                (Target::Object(second), 1),
+            ]),
+    );
+
+    // The creature assigned 2 damage dies in response.
+    game.move_object(first, Zone::Graveyard);
+
+    resolve_stack_entry(&mut game).expect("Arc Lightning should resolve");
+
+    assert_eq!(
+        game.damage_on(second),
+        1,
+        "the remaining target only receives the damage assigned to it"
+    );
+}
+
+#[test]
+fn test_aura_spell_whose_target_left_goes_to_graveyard() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Test Aura")
+        .parse_text(
+            "Mana cost: {W}\nType: Enchantment — Aura\nEnchant creature\nEnchanted creature gets +2/+2.",
+        )
+        .expect("parse aura");
+    let creature = create_creature(&mut game, "Doomed Bear", alice, 2, 2);
+    let aura = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(StackEntry::new(aura, alice).with_targets(vec![Target::Object(creature)]));
+
+    // The enchanted-to-be creature leaves before the Aura resolves.
+    game.move_object(creature, Zone::Graveyard);
+
+    resolve_stack_entry(&mut game).expect("aura should resolve");
+
+    assert!(
+        !game
+            .battlefield
+            .iter()
+            .any(|&id| game.object(id).is_some_and(|obj| obj.name == "Test Aura")),
+        "Aura with an illegal target must not enter the battlefield"
+    );
+    assert_eq!(game.player(alice).unwrap().graveyard.len(), 2);
+}
+
+#[test]
+fn test_aura_spell_fizzles_when_target_gains_hexproof_in_response() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    // Only the enchant restriction, without a compiled attach effect.
+    let def = CardDefinitionBuilder::new(CardId::new(), "Test Aura")
+        .card_types(vec![CardType::Enchantment])
+        .subtypes(vec![crate::types::Subtype::Aura])
+        .enchants(crate::target::ObjectFilter::creature())
+        .build();
+    let creature = create_creature(&mut game, "Hexproof Bear", bob, 2, 2);
+    let bystander = create_creature(&mut game, "Bystander", alice, 1, 1);
+    let aura = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(StackEntry::new(aura, alice).with_targets(vec![Target::Object(creature)]));
+
+    game.object_mut(creature)
+        .unwrap()
+        .abilities
+        .push(Ability::static_ability(StaticAbility::hexproof()));
+
+    resolve_stack_entry(&mut game).expect("aura should resolve");
+
+    assert!(game.object(creature).unwrap().attachments.is_empty());
+    assert!(
+        game.object(bystander).unwrap().attachments.is_empty(),
+        "a fizzled Aura isn't put onto the battlefield attached to something else"
+    );
+    assert_eq!(
+        game.battlefield.len(),
+        2,
+        "the Aura doesn't enter the battlefield"
+    );
+    assert_eq!(game.player(alice).unwrap().graveyard.len(), 1);
+}
+
+#[test]
+fn test_triggered_ability_with_illegal_target_does_nothing() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let source = create_creature(&mut game, "Pinger", alice, 1, 1);
+    let target = create_creature(&mut game, "Shrouded Bear", bob, 2, 2);
+    game.push_to_stack(
+        StackEntry::ability(
+            source,
+            alice,
+            vec![
+                Effect::deal_damage(1, ChooseSpec::target(ChooseSpec::creature())),
+                Effect::gain_life(3),
+            ],
+        )
+        .with_targets(vec![Target::Object(target)]),
+    );
+
+    game.object_mut(target)
+        .unwrap()
+        .abilities
+        .push(Ability::static_ability(StaticAbility::shroud()));
+
+    resolve_stack_entry(&mut game).expect("ability should resolve");
+
+    assert_eq!(game.damage_on(target), 0);
+    assert_eq!(
+        game.player(alice).unwrap().life,
+        20,
+        "a fizzled ability does none of its other effects either"
+    );
+}
+
+#[test]
+fn test_rabid_bite_with_illegal_biter_deals_no_damage() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let def = CardDefinitionBuilder::new(CardId::new(), "Rabid Bite")
+        .parse_text(
+            "Mana cost: {1}{G}\nType: Sorcery\nTarget creature you control deals damage equal to its power to target creature you don't control.",
+        )
+        .expect("parse rabid bite");
+    let biter = create_creature(&mut game, "Biter", alice, 3, 3);
+    let victim = create_creature(&mut game, "Victim", bob, 2, 5);
+    let spell = game.create_object_from_definition(&def, alice, Zone::Stack);
+    game.push_to_stack(
+        StackEntry::new(spell, alice)
+            .with_targets(vec![Target::Object(biter), Target::Object(victim)]),
+    );
+
+    // The biting creature becomes an illegal target, so its power isn't used.
+    game.object_mut(biter)
+        .unwrap()
+        .abilities
+        .push(Ability::static_ability(StaticAbility::shroud()));
+
+    resolve_stack_entry(&mut game).expect("spell should resolve");
+
+    assert_eq!(game.damage_on(victim), 0);
+    assert_eq!(game.damage_on(biter), 0);
+}
+
+#[test]
+fn test_first_creature_spell_reduction_is_used_up_while_unlimited_reduction_keeps_applying() {
+    /// Casts "Big Bear" whenever it can, otherwise passes.
+    struct CastBigBear;
+
+    impl DecisionMaker for CastBigBear {
+        fn decide_priority(
+            &mut self,
+            game: &GameState,
+            ctx: &crate::decisions::context::PriorityContext,
+        ) -> LegalAction {
+            ctx.actions
+                .iter()
+                .find(|action| {
+                    matches!(action, LegalAction::CastSpell { spell_id, .. }
+                        if game.object(*spell_id).is_some_and(|obj| obj.name == "Big Bear"))
+                })
+                .cloned()
+                .unwrap_or(LegalAction::PassPriority)
+        }
+    }
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    let first_spell_reducer = CardDefinitionBuilder::new(CardId::new(), "First Spell Reducer")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("The first creature spell you cast each turn costs {2} less to cast.")
+        .expect("parse limited reducer");
+    let creature_reducer = CardDefinitionBuilder::new(CardId::new(), "Creature Reducer")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("Creature spells you cast cost {1} less to cast.")
+        .expect("parse unlimited reducer");
+    let big_bear = CardDefinitionBuilder::new(CardId::new(), "Big Bear")
+        .mana_cost(crate::mana::ManaCost::from_pips(vec![
+            vec![crate::mana::ManaSymbol::Generic(3)],
+            vec![crate::mana::ManaSymbol::Green],
+        ]))
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(4, 4))
+        .build();
+
+    let limited =
+        game.create_object_from_definition(&first_spell_reducer, alice, Zone::Battlefield);
+    game.create_object_from_definition(&creature_reducer, alice, Zone::Battlefield);
+    let forests: Vec<_> = (0..5)
+        .map(|_| {
+            game.create_object_from_definition(
+                &crate::cards::definitions::basic_forest(),
+                alice,
+                Zone::Battlefield,
+            )
+        })
+        .collect();
+    game.create_object_from_definition(&big_bear, alice, Zone::Hand);
+    game.create_object_from_definition(&big_bear, alice, Zone::Hand);
+
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+
+    let mut trigger_queue = TriggerQueue::new();
+    run_priority_loop_with(&mut game, &mut trigger_queue, &mut CastBigBear).unwrap();
+
+    // {3}{G} costs {G} with both reductions, then {2}{G} once the first-spell one is used.
+    let bears_on_battlefield = game
+        .battlefield
+        .iter()
+        .filter(|&&id| game.object(id).is_some_and(|obj| obj.name == "Big Bear"))
+        .count();
+    assert_eq!(bears_on_battlefield, 2);
+    let tapped_forests = forests.iter().filter(|&&id| game.is_tapped(id)).count();
+    assert_eq!(tapped_forests, 4);
+    assert_eq!(game.cost_reduction_uses_this_turn(limited), 1);
+
+    game.next_turn();
+    assert_eq!(game.cost_reduction_uses_this_turn(limited), 0);
+}
+
+/// Plays lands and the first castable spell, attacks with everything, and at
+/// every priority point checks the public state against a copy whose static
+/// ability effects were rebuilt from scratch.
+#[cfg(feature = "net")]
+struct GoldfishCacheChecker {
+    priority_points: usize,
+}
+
+#[cfg(feature = "net")]
+impl DecisionMaker for GoldfishCacheChecker {
+    fn decide_priority(
+        &mut self,
+        game: &GameState,
+        ctx: &crate::decisions::context::PriorityContext,
+    ) -> crate::decision::LegalAction {
+        use crate::decision::LegalAction;
+        use crate::net::state::build_public_state_snapshot;
+
+        let mut rebuilt = game.clone();
+        rebuilt.set_static_effect_caching(false);
+        rebuilt.update_static_ability_effects();
+        assert_eq!(
+            build_public_state_snapshot(game),
+            build_public_state_snapshot(&rebuilt),
+            "public state diverged from a full rebuild at priority point {}",
+            self.priority_points
+        );
+        self.priority_points += 1;
+
+        if !game.stack_is_empty() || game.turn.active_player != ctx.player {
+            return LegalAction::PassPriority;
+        }
+        ctx.actions
+            .iter()
+            .find(|action| matches!(action, LegalAction::PlayLand { .. }))
+            .or_else(|| {
+                ctx.actions
+                    .iter()
+                    .find(|action| matches!(action, LegalAction::CastSpell { .. }))
+            })
+            .cloned()
+            .unwrap_or(LegalAction::PassPriority)
+    }
+
+    fn decide_attackers(
+        &mut self,
+        _game: &GameState,
+        ctx: &crate::decisions::context::AttackersContext,
+    ) -> Vec<crate::decisions::spec::AttackerDeclaration> {
+        ctx.attacker_options
+            .iter()
+            .filter_map(|option| {
+                Some(crate::decisions::spec::AttackerDeclaration {
+                    creature: option.creature,
+                    target: option.valid_targets.first()?.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "net")]
+#[test]
+fn test_static_effect_cache_matches_full_rebuild_over_goldfish_game() {
+    use crate::cards::definitions::{
+        basic_plains, crusade, savannah_lions, serra_angel, white_knight,
+    };
+    use crate::combat_state::CombatState;
+
+    let mut game = setup_game();
+    for player in [PlayerId::from_index(0), PlayerId::from_index(1)] {
+        for _ in 0..3 {
+            for definition in [
+                basic_plains(),
+                savannah_lions(),
+                basic_plains(),
+                crusade(),
+                basic_plains(),
+                white_knight(),
+                basic_plains(),
+                serra_angel(),
+            ] {
+                game.create_object_from_definition(&definition, player, Zone::Library);
+            }
+        }
+        for definition in [basic_plains(), basic_plains(), savannah_lions()] {
+            game.create_object_from_definition(&definition, player, Zone::Hand);
+        }
+    }
+    game.turn.turn_number = 2;
+
+    let mut dm = GoldfishCacheChecker { priority_points: 0 };
+    let mut combat = CombatState::default();
+    let mut trigger_queue = TriggerQueue::new();
+    for _ in 0..16 {
+        match execute_turn_with(&mut game, &mut combat, &mut trigger_queue, &mut dm) {
+            Ok(()) => game.next_turn(),
+            Err(GameLoopError::GameOver) => break,
+            Err(err) => panic!("goldfish turn failed: {err:?}"),
+        }
+    }
+
+    assert!(
+        dm.priority_points > 100,
+        "game should reach many priority points"
+    );
+    assert!(
+        game.players.iter().any(|player| player.life < 20),
+        "creatures should have connected"
+    );
+    assert!(game.static_effect_cache().hits() > 0);
+}
+
+/// Casts one spell from hand, then passes, recording Alice's life, hand size
+/// and the stack size whenever she gets (or auto-passes) priority.
+struct CastOnceRecordingDecisionMaker {
+    spell: ObjectId,
+    cast: bool,
+    seen: Vec<(i32, usize, usize)>,
+    spoil_discard_choice: bool,
+}
+
+impl CastOnceRecordingDecisionMaker {
+    fn new(spell: ObjectId) -> Self {
+        Self {
+            spell,
+            cast: false,
+            seen: Vec::new(),
+            spoil_discard_choice: false,
+        }
+    }
+
+    fn record(&mut self, game: &GameState, player: PlayerId) {
+        if player != PlayerId::from_index(0) {
+            return;
+        }
+        let alice = game.player(player).expect("alice exists");
+        self.seen
+            .push((alice.life, alice.hand.len(), game.stack.len()));
+    }
+}
+
+impl DecisionMaker for CastOnceRecordingDecisionMaker {
+    fn on_auto_pass(&mut self, game: &GameState, player: PlayerId) {
+        self.record(game, player);
+    }
+
+    fn decide_priority(
+        &mut self,
+        game: &GameState,
+        ctx: &crate::decisions::context::PriorityContext,
+    ) -> LegalAction {
+        self.record(game, ctx.player);
+        if !self.cast
+            && let Some(action) = ctx.actions.iter().find(|action| {
+                matches!(action, LegalAction::CastSpell { spell_id, .. } if *spell_id == self.spell)
+            })
+        {
+            self.cast = true;
+            return action.clone();
+        }
+        LegalAction::PassPriority
+    }
+
+    fn decide_objects(
+        &mut self,
+        _game: &GameState,
+        ctx: &crate::decisions::context::SelectObjectsContext,
+    ) -> Vec<ObjectId> {
+        if self.spoil_discard_choice && ctx.description.to_string().contains("discard") {
+            return vec![ObjectId::from_raw(9999)];
+        }
+        vec![ctx.candidates[0].id]
+    }
+}
+
+/// Alice in her main phase with a creature to sacrifice and an enchantment
+/// that gains her 1 life whenever a creature she controls dies.
+fn setup_sacrifice_cost_trigger_game() -> (GameState, ObjectId) {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    game.turn.phase = Phase::FirstMain;
+    game.turn.step = None;
+    game.turn.active_player = alice;
+    game.turn.priority_player = Some(alice);
+
+    let mourner = CardDefinitionBuilder::new(CardId::new(), "Cost Trigger Probe")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("Whenever a creature you control dies, you gain 1 life.")
+        .expect("dies trigger should parse");
+    game.create_object_from_definition(&mourner, alice, Zone::Battlefield);
+    let victim = create_creature(&mut game, "Sacrifice Fodder", alice, 1, 1);
+    (game, victim)
+}
+
+#[test]
+fn test_trigger_from_sacrifice_cost_resolves_before_the_spell() {
+    let (mut game, victim) = setup_sacrifice_cost_trigger_game();
+    let alice = PlayerId::from_index(0);
+    for _ in 0..2 {
+        game.create_object_from_definition(&crate::cards::basic_forest(), alice, Zone::Library);
+    }
+    let rites = game.create_object_from_definition(
+        &crate::cards::definitions::village_rites(),
+        alice,
+        Zone::Hand,
+    );
+    game.player_mut(alice)
+        .expect("alice exists")
+        .mana_pool
+        .add(crate::mana::ManaSymbol::Black, 1);
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut dm = CastOnceRecordingDecisionMaker::new(rites);
+    run_priority_loop_with(&mut game, &mut trigger_queue, &mut dm).expect("loop should run");
+
+    assert!(game.object(victim).is_none(), "fodder should be sacrificed");
+    // The life trigger goes on the stack above Village Rites and resolves
+    // first: Alice gains life while her hand is still empty.
+    assert_eq!(
+        dm.seen,
+        vec![(20, 1, 0), (20, 0, 2), (21, 0, 1), (21, 2, 0)],
+        "expected cast, trigger resolution, then spell resolution"
+    );
+}
+
+#[test]
+fn test_cancelled_cast_drops_triggers_from_costs_already_paid() {
+    let (mut game, victim) = setup_sacrifice_cost_trigger_game();
+    let alice = PlayerId::from_index(0);
+    game.create_object_from_definition(&crate::cards::basic_forest(), alice, Zone::Hand);
+    let spell = CardDefinitionBuilder::new(CardId::new(), "Two Cost Probe")
+        .card_types(vec![CardType::Sorcery])
+        .parse_text(
+            "As an additional cost to cast this spell, sacrifice a creature and discard a card.\nDraw a card.",
+        )
+        .expect("sacrifice-and-discard cost should parse");
+    let spell = game.create_object_from_definition(&spell, alice, Zone::Hand);
+
+    let mut trigger_queue = TriggerQueue::new();
+    let mut dm = CastOnceRecordingDecisionMaker::new(spell);
+    dm.spoil_discard_choice = true;
+    run_priority_loop_with(&mut game, &mut trigger_queue, &mut dm).expect("loop should run");
+
+    // The sacrifice was paid before the discard choice failed; rolling the
+    // cast back must take its dies trigger with it.
+    assert!(game.object(victim).is_some(), "sacrifice should be undone");
+    assert!(trigger_queue.is_empty());
+    assert_eq!(game.player(alice).expect("alice exists").life, 20);
+    assert_eq!(game.player(alice).expect("alice exists").hand.len(), 2);
+}
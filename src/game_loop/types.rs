@@ -11,6 +11,9 @@ pub enum GameLoopError {
     TurnError(TurnError),
     /// Stack resolution failed.
     ResolutionFailed(String),
+    /// An effect failed while error dumps were enabled; see
+    /// [`GameState::set_error_dump_dir`].
+    EffectFailed(crate::executor::ExecutionError),
     /// Invalid game state.
     InvalidState(String),
     /// No players remaining.
@@ -21,6 +24,8 @@ pub enum GameLoopError {
     CombatError(CombatError),
     /// Special action error.
     ActionError(crate::special_actions::ActionError),
+    /// A player ran out of time on their [`GameClock`].
+    PlayerTimedOut(PlayerId),
 }
 
 /// Response payload for externally driving a pending priority decision.
@@ -31,10 +36,16 @@ pub enum GameLoopError {
 pub enum PriorityResponse {
     PriorityAction(LegalAction),
     Attackers(Vec<AttackerDeclaration>),
+    /// Attack shortcut, expanded into concrete declarations before applying.
+    AttackShortcut(AttackShortcut),
     Blockers {
         defending_player: PlayerId,
         declarations: Vec<BlockerDeclaration>,
     },
+    /// Declare no blockers for the defending player.
+    NoBlocks {
+        defending_player: PlayerId,
+    },
     Targets(Vec<Target>),
     XValue(u32),
     NumberChoice(u32),
@@ -45,6 +56,8 @@ pub enum PriorityResponse {
     NextCostChoice(usize),
     SacrificeTarget(ObjectId),
     CardCostChoice(ObjectId),
+    /// Graveyard cards chosen to exile for Delve.
+    DelveCards(Vec<ObjectId>),
     HybridChoice(usize),
     CastingMethodChoice(usize),
     ReplacementChoice(usize),
@@ -79,11 +92,15 @@ impl std::fmt::Display for GameLoopError {
         match self {
             GameLoopError::TurnError(e) => write!(f, "Turn error: {e}"),
             GameLoopError::ResolutionFailed(msg) => write!(f, "Resolution failed: {}", msg),
+            GameLoopError::EffectFailed(e) => write!(f, "Effect failed: {e}"),
             GameLoopError::InvalidState(msg) => write!(f, "Invalid state: {}", msg),
             GameLoopError::GameOver => write!(f, "Game over"),
             GameLoopError::ResponseError(e) => write!(f, "Response error: {}", e),
             GameLoopError::CombatError(e) => write!(f, "Combat error: {}", e),
             GameLoopError::ActionError(e) => write!(f, "Action error: {e}"),
+            GameLoopError::PlayerTimedOut(player) => {
+                write!(f, "Player {} ran out of time", player.index())
+            }
         }
     }
 }
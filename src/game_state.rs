@@ -1,6 +1,6 @@
-use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
 use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use rand::seq::SliceRandom;
 use rand::{SeedableRng, rngs::StdRng};
@@ -13,16 +13,18 @@ use crate::cost::OptionalCostsPaid;
 use crate::decision::KeywordPaymentContribution;
 use crate::events::{Event, EventKind};
 use crate::ids::{ObjectId, PlayerId, StableId};
-use crate::object::Object;
+use crate::object::{Object, ObjectKind};
 use crate::player::Player;
 use crate::prevention::PreventionEffectManager;
 use crate::provenance::{ProvNodeId, ProvenanceGraph, ProvenanceNodeKind};
 use crate::replacement::{ReplacementEffectId, ReplacementEffectManager};
+use crate::shared::SharedVec;
 use crate::static_abilities::StaticAbility;
 use crate::target::ChooseSpec;
 use crate::triggers::TriggerIdentity;
 use crate::types::Subtype;
 use crate::zone::Zone;
+use crate::zone_index::OwnerZoneIndex;
 
 /// Pending replacement effect choice when multiple effects apply to the same event.
 ///
@@ -37,6 +39,8 @@ pub struct PendingReplacementChoice {
     pub applicable_effects: Vec<ReplacementEffectId>,
     /// The player who must choose which effect to apply
     pub player: PlayerId,
+    /// Effects already applied to the event, which can't apply to it again
+    pub applied_effects: Vec<ReplacementEffectId>,
 }
 
 /// Result of moving an object to the battlefield with ETB replacement processing.
@@ -131,6 +135,99 @@ impl TurnCounterTracker {
     }
 }
 
+/// Per-turn usage markers that "this turn" conditions and ability words read.
+///
+/// Maintained as events are processed and reset when the turn advances:
+///
+/// * `creatures_died` - Morbid ("if a creature died this turn")
+/// * `players_attacked` - Raid ("if you attacked this turn")
+/// * `permanents_left_battlefield_under_controller` - Revolt ("if a permanent
+///   you controlled left the battlefield this turn")
+/// * `life_gained` / `life_lost` - "if you gained life this turn",
+///   "if an opponent lost life this turn"
+/// * `spells_cast` - "if you've cast a spell this turn"
+#[derive(Debug, Clone, Default)]
+pub struct TurnTrackers {
+    /// Number of creatures that have died this turn.
+    pub creatures_died: u32,
+    /// Number of creatures that died this turn per controller (at the time they died).
+    pub creatures_died_under_controller: HashMap<PlayerId, u32>,
+    /// Number of creatures that left the battlefield this turn per controller.
+    pub creatures_left_battlefield_under_controller: HashMap<PlayerId, u32>,
+    /// Number of permanents that left the battlefield this turn per controller.
+    pub permanents_left_battlefield_under_controller: HashMap<PlayerId, u32>,
+    /// Number of spells cast this turn per player.
+    pub spells_cast: HashMap<PlayerId, u32>,
+    /// Players who attacked with at least one creature this turn.
+    pub players_attacked: HashSet<PlayerId>,
+    /// Life gained by each player this turn.
+    pub life_gained: HashMap<PlayerId, u32>,
+    /// Life lost by each player this turn.
+    pub life_lost: HashMap<PlayerId, u32>,
+}
+
+impl TurnTrackers {
+    pub fn record_creature_died(&mut self, controller: PlayerId) {
+        self.creatures_died += 1;
+        *self
+            .creatures_died_under_controller
+            .entry(controller)
+            .or_insert(0) += 1;
+    }
+
+    pub fn record_left_battlefield(&mut self, controller: PlayerId, was_creature: bool) {
+        if was_creature {
+            *self
+                .creatures_left_battlefield_under_controller
+                .entry(controller)
+                .or_insert(0) += 1;
+        }
+        *self
+            .permanents_left_battlefield_under_controller
+            .entry(controller)
+            .or_insert(0) += 1;
+    }
+
+    pub fn record_spell_cast(&mut self, player: PlayerId) {
+        *self.spells_cast.entry(player).or_insert(0) += 1;
+    }
+
+    pub fn record_attack(&mut self, player: PlayerId) {
+        self.players_attacked.insert(player);
+    }
+
+    pub fn record_life_gained(&mut self, player: PlayerId, amount: u32) {
+        *self.life_gained.entry(player).or_insert(0) += amount;
+    }
+
+    pub fn record_life_lost(&mut self, player: PlayerId, amount: u32) {
+        *self.life_lost.entry(player).or_insert(0) += amount;
+    }
+
+    pub fn spells_cast_by(&self, player: PlayerId) -> u32 {
+        self.spells_cast.get(&player).copied().unwrap_or(0)
+    }
+
+    pub fn life_gained_by(&self, player: PlayerId) -> u32 {
+        self.life_gained.get(&player).copied().unwrap_or(0)
+    }
+
+    pub fn life_lost_by(&self, player: PlayerId) -> u32 {
+        self.life_lost.get(&player).copied().unwrap_or(0)
+    }
+
+    pub fn permanents_left_battlefield_under(&self, player: PlayerId) -> u32 {
+        self.permanents_left_battlefield_under_controller
+            .get(&player)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
 // =============================================================================
 // "Can't" Effect Tracking (Rule 614.17)
 // =============================================================================
@@ -166,6 +263,14 @@ pub struct CantEffectTracker {
     /// Example: Pacifism, Propaganda (if unpaid), Maze of Ith
     pub cant_attack: HashSet<ObjectId>,
 
+    /// Creature -> players it can't attack.
+    /// Example: Blazing Archon ("Creatures can't attack you.")
+    pub cant_attack_players: HashMap<ObjectId, HashSet<PlayerId>>,
+
+    /// Creature -> players whose planeswalkers it can't attack.
+    /// Example: "Creatures can't attack you or planeswalkers you control."
+    pub cant_attack_planeswalkers_of: HashMap<ObjectId, HashSet<PlayerId>>,
+
     /// Creatures that can't attack alone.
     /// Example: "This creature can't attack alone."
     pub cant_attack_alone: HashSet<ObjectId>,
@@ -281,6 +386,10 @@ pub struct CantEffectTracker {
     /// Example: Vexing Shusher, Prowling Serpopard
     pub cant_be_countered: HashSet<ObjectId>,
 
+    /// Spell -> players whose spells and abilities can't counter it.
+    /// Example: "... can't be countered by spells or abilities your opponents control."
+    pub cant_be_countered_by: HashMap<ObjectId, HashSet<PlayerId>>,
+
     /// Permanents that can't transform.
     /// Example: "Non-Human Werewolves you control can't transform."
     pub cant_transform: HashSet<ObjectId>,
@@ -382,6 +491,18 @@ impl CantEffectTracker {
         self.cant_gain_life.extend(other.cant_gain_life);
         self.cant_search.extend(other.cant_search);
         self.cant_attack.extend(other.cant_attack);
+        for (creature, players) in other.cant_attack_players {
+            self.cant_attack_players
+                .entry(creature)
+                .or_default()
+                .extend(players);
+        }
+        for (creature, players) in other.cant_attack_planeswalkers_of {
+            self.cant_attack_planeswalkers_of
+                .entry(creature)
+                .or_default()
+                .extend(players);
+        }
         self.cant_attack_alone.extend(other.cant_attack_alone);
         self.cant_block.extend(other.cant_block);
         for (blocker, attackers) in other.cant_block_specific_attackers {
@@ -433,6 +554,12 @@ impl CantEffectTracker {
         self.cant_be_targeted.extend(other.cant_be_targeted);
         self.cant_target_players.extend(other.cant_target_players);
         self.cant_be_countered.extend(other.cant_be_countered);
+        for (spell, players) in other.cant_be_countered_by {
+            self.cant_be_countered_by
+                .entry(spell)
+                .or_default()
+                .extend(players);
+        }
         self.cant_transform.extend(other.cant_transform);
     }
 
@@ -442,6 +569,8 @@ impl CantEffectTracker {
         self.cant_gain_life.clear();
         self.cant_search.clear();
         self.cant_attack.clear();
+        self.cant_attack_players.clear();
+        self.cant_attack_planeswalkers_of.clear();
         self.cant_attack_alone.clear();
         self.cant_block.clear();
         self.cant_block_specific_attackers.clear();
@@ -468,6 +597,7 @@ impl CantEffectTracker {
         self.cant_be_targeted.clear();
         self.cant_target_players.clear();
         self.cant_be_countered.clear();
+        self.cant_be_countered_by.clear();
         self.cant_transform.clear();
     }
 
@@ -496,6 +626,24 @@ impl CantEffectTracker {
         !self.cant_attack.contains(&creature)
     }
 
+    /// Check if a creature can attack a specific player.
+    pub fn can_attack_player(&self, creature: ObjectId, player: PlayerId) -> bool {
+        self.can_attack(creature)
+            && self
+                .cant_attack_players
+                .get(&creature)
+                .is_none_or(|players| !players.contains(&player))
+    }
+
+    /// Check if a creature can attack planeswalkers controlled by a specific player.
+    pub fn can_attack_planeswalkers_of(&self, creature: ObjectId, controller: PlayerId) -> bool {
+        self.can_attack(creature)
+            && self
+                .cant_attack_planeswalkers_of
+                .get(&creature)
+                .is_none_or(|players| !players.contains(&controller))
+    }
+
     /// Check if a creature can attack alone (as the only attacker).
     pub fn can_attack_alone(&self, creature: ObjectId) -> bool {
         !self.cant_attack_alone.contains(&creature)
@@ -726,6 +874,16 @@ impl CantEffectTracker {
         !self.cant_be_countered.contains(&spell)
     }
 
+    /// Check if a spell on the stack can be countered by a spell or ability
+    /// controlled by a specific player.
+    pub fn can_be_countered_by(&self, spell: ObjectId, player: PlayerId) -> bool {
+        self.can_be_countered(spell)
+            && self
+                .cant_be_countered_by
+                .get(&spell)
+                .is_none_or(|players| !players.contains(&player))
+    }
+
     /// Check if a permanent can transform.
     pub fn can_transform(&self, permanent: ObjectId) -> bool {
         !self.cant_transform.contains(&permanent)
@@ -782,6 +940,19 @@ impl CantEffectTracker {
     }
 }
 
+/// A spell cast during the game, as it was when cast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellCastRecord {
+    /// The spell's object on the stack.
+    pub spell: ObjectId,
+    /// The player who cast it.
+    pub caster: PlayerId,
+    /// The spell's name when cast.
+    pub name: String,
+    /// The zone it was cast from.
+    pub from_zone: Zone,
+}
+
 // =============================================================================
 // "Spend Mana As Though Any Color" Tracking
 // =============================================================================
@@ -814,7 +985,11 @@ impl ManaSpendEffectTracker {
 }
 
 /// Game phases.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Phase {
     Beginning,
     FirstMain,
@@ -842,7 +1017,11 @@ impl std::fmt::Display for Phase {
 }
 
 /// Steps within phases.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Step {
     // Beginning phase
     Untap,
@@ -939,6 +1118,10 @@ pub struct PlayerControlEffect {
 
 /// A target for spells or abilities.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Target {
     Object(ObjectId),
     Player(PlayerId),
@@ -991,6 +1174,12 @@ pub struct StackEntry {
     /// Pre-chosen modes for modal spells (chosen during casting per rule 601.2b).
     /// If Some, resolution should use these instead of prompting.
     pub chosen_modes: Option<Vec<usize>>,
+    /// Damage division announced while casting (per MTG rule 601.2d).
+    ///
+    /// Each target of a "divided as you choose" effect is paired with the
+    /// amount assigned to it. Targets that become illegal keep their share,
+    /// which is lost rather than reassigned.
+    pub divided_amounts: Vec<(Target, u32)>,
     /// Permanents that contributed keyword-ability alternative payments to this spell cast.
     pub keyword_payment_contributions: Vec<KeywordPaymentContribution>,
     /// Creatures that crewed this object this turn, captured when the entry was created.
@@ -1040,6 +1229,7 @@ impl StackEntry {
             triggering_event: None,
             intervening_if: None,
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             keyword_payment_contributions: Vec::new(),
             crew_contributors: Vec::new(),
             saddle_contributors: Vec::new(),
@@ -1071,6 +1261,7 @@ impl StackEntry {
             triggering_event: None,
             intervening_if: None,
             chosen_modes: None,
+            divided_amounts: Vec::new(),
             keyword_payment_contributions: Vec::new(),
             crew_contributors: Vec::new(),
             saddle_contributors: Vec::new(),
@@ -1157,6 +1348,12 @@ impl StackEntry {
         self
     }
 
+    /// Set the damage division announced while casting.
+    pub fn with_divided_amounts(mut self, divided_amounts: Vec<(Target, u32)>) -> Self {
+        self.divided_amounts = divided_amounts;
+        self
+    }
+
     /// Set keyword-ability payment contributors for this stack entry.
     pub fn with_keyword_payment_contributions(
         mut self,
@@ -1176,6 +1373,33 @@ impl StackEntry {
     }
 }
 
+/// A `u64` that can be updated through `&self`.
+///
+/// Behaves like `Cell<u64>` but is `Sync`, so a `GameState` can be shared
+/// behind an `RwLock` (see [`crate::engine_handle`]).
+#[derive(Debug, Default)]
+struct CounterCell(AtomicU64);
+
+impl CounterCell {
+    fn new(value: u64) -> Self {
+        Self(AtomicU64::new(value))
+    }
+
+    fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn set(&self, value: u64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+}
+
+impl Clone for CounterCell {
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
 /// Complete game state.
 #[derive(Debug, Clone)]
 pub struct GameState {
@@ -1185,6 +1409,8 @@ pub struct GameState {
 
     // Objects
     objects: HashMap<ObjectId, Object>,
+    // Next object ID to allocate; kept above every ID ever added to this game.
+    next_object_id: u64,
     // Fast index: stable id -> current object id.
     stable_id_index: HashMap<StableId, ObjectId>,
 
@@ -1195,6 +1421,8 @@ pub struct GameState {
     pub battlefield: Vec<ObjectId>,
     pub command_zone: Vec<ObjectId>,
     pub exile: Vec<ObjectId>,
+    // The battlefield, command zone, and exile split by owner.
+    owner_zone_index: OwnerZoneIndex,
 
     // Turn tracking
     pub turn: TurnState,
@@ -1203,6 +1431,14 @@ pub struct GameState {
     pub continuous_effects: ContinuousEffectManager,
     pub replacement_effects: ReplacementEffectManager,
     pub prevention_effects: PreventionEffectManager,
+    /// Effects from source-only static abilities, reused between refreshes.
+    static_effect_cache: crate::static_ability_processor::StaticEffectCache,
+    /// Bumped by every method that changes objects, players, or the stack.
+    state_version: u64,
+    /// Legal actions reused between priority passes with no state change.
+    legal_action_cache: crate::legal_action_cache::LegalActionCache,
+    /// Where to write debug dumps when an effect fails during resolution.
+    error_dump_dir: Option<std::path::PathBuf>,
 
     /// Tracker for "can't" effects (Rule 614.17).
     /// These are checked BEFORE events happen, not as replacements.
@@ -1275,19 +1511,9 @@ pub struct GameState {
     /// Timestamp counter for player-control effects.
     pub player_control_timestamp: u64,
 
-    /// Number of creatures that have died this turn.
-    /// Reset at the start of each turn.
-    pub creatures_died_this_turn: u32,
-
-    /// Number of creatures that died this turn per controller (at the time they died).
-    /// Reset at the start of each turn.
-    pub creatures_died_under_controller_this_turn: HashMap<PlayerId, u32>,
-    /// Number of creatures that left the battlefield this turn per controller.
+    /// Per-turn markers backing "this turn" conditions (Morbid, Raid, Revolt, ...).
     /// Reset at the start of each turn.
-    pub creatures_left_battlefield_under_controller_this_turn: HashMap<PlayerId, u32>,
-    /// Number of permanents that left the battlefield this turn per controller.
-    /// Reset at the start of each turn.
-    pub permanents_left_battlefield_under_controller_this_turn: HashMap<PlayerId, u32>,
+    pub turn_trackers: TurnTrackers,
 
     /// Cards/tokens that were put into a graveyard from anywhere this turn.
     ///
@@ -1304,12 +1530,12 @@ pub struct GameState {
     /// Number of times each (source stable id, trigger identity) has fired this turn.
     pub triggers_fired_this_turn: HashMap<(ObjectId, TriggerIdentity), u32>,
 
+    /// Number of spells each source's limited-use cost reduction has applied to this turn.
+    pub cost_reductions_used_this_turn: HashMap<ObjectId, u32>,
+
     /// Extensible per-turn counters for event kinds and custom metrics.
     pub turn_counters: TurnCounterTracker,
 
-    /// Number of spells cast this turn per player.
-    /// Reset at the start of each turn.
-    pub spells_cast_this_turn: HashMap<PlayerId, u32>,
     /// Players who have taken the foretell special action this turn.
     /// Reset at the start of each turn.
     pub foretell_actions_this_turn: HashSet<PlayerId>,
@@ -1343,9 +1569,9 @@ pub struct GameState {
     /// Cleared at the start of each turn.
     pub spell_cast_order_this_turn: HashMap<ObjectId, u32>,
 
-    /// Players who attacked with at least one creature this turn.
-    /// Reset at the start of each turn.
-    pub players_attacked_this_turn: HashSet<PlayerId>,
+    /// Each player's most recent attack declaration.
+    /// Used by the "repeat last attack" shortcut; persists across turns.
+    pub last_attack_declarations: HashMap<PlayerId, Vec<crate::decision::AttackerDeclaration>>,
 
     /// Players who tapped a land for mana this turn.
     /// Reset at the start of each turn.
@@ -1409,12 +1635,6 @@ pub struct GameState {
     /// Noncombat damage dealt to each player this turn.
     pub noncombat_damage_to_players_this_turn: HashMap<PlayerId, u32>,
 
-    /// Life lost by each player this turn.
-    pub life_lost_this_turn: HashMap<PlayerId, u32>,
-
-    /// Life gained by each player this turn.
-    pub life_gained_this_turn: HashMap<PlayerId, u32>,
-
     /// Creatures that have been dealt damage this turn, keyed by the damaged creature.
     ///
     /// Value is the set of object IDs that dealt damage to that creature this turn.
@@ -1476,9 +1696,19 @@ pub struct GameState {
     /// Face-down permanents (for morph, manifest, etc.).
     pub face_down: HashSet<ObjectId>,
 
-    /// Phased-out permanents.
+    /// Front faces of transformed double-faced permanents, restored when the
+    /// permanent transforms back or leaves the battlefield (Rule 711.8).
+    pub transformed_front_faces: HashMap<ObjectId, crate::cards::CardDefinition>,
+
+    /// Phased-out permanents. They stay in the battlefield zone but are taken
+    /// out of `battlefield`, so they're treated as though they don't exist
+    /// (Rule 702.26b).
     pub phased_out: HashSet<ObjectId>,
 
+    /// Phased-out permanents that phased out because something they were
+    /// attached to did. They phase in with that permanent (Rule 702.26g).
+    pub phased_out_indirectly: HashSet<ObjectId>,
+
     /// Cards exiled via Madness (can be cast from exile for madness cost).
     pub madness_exiled: HashSet<ObjectId>,
 
@@ -1497,6 +1727,11 @@ pub struct GameState {
     /// Number of times each commander has been cast from the command zone.
     pub commander_casts_from_command_zone: HashMap<ObjectId, u32>,
 
+    /// Every spell cast this game, in cast order.
+    ///
+    /// Backs "if you've cast another spell named ~ this game" checks.
+    pub spells_cast_this_game: Vec<SpellCastRecord>,
+
     /// Commanders whose owner declined the current graveyard/exile -> command
     /// zone choice for this specific object instance.
     pub declined_commander_command_zone_moves: HashSet<ObjectId>,
@@ -1517,10 +1752,10 @@ pub struct GameState {
     pub next_linked_exile_group_id: u64,
 
     /// Deterministic match RNG state used for shuffles and other random gameplay effects.
-    random_state: Cell<u64>,
+    random_state: CounterCell,
 
     /// Monotonic counter incremented whenever gameplay consumes irreversible randomness.
-    irreversible_random_count: Cell<u64>,
+    irreversible_random_count: CounterCell,
 }
 
 impl GameState {
@@ -1542,15 +1777,21 @@ impl GameState {
             players,
             turn_order,
             objects: HashMap::new(),
+            next_object_id: 1,
             stable_id_index: HashMap::new(),
             stack: Vec::new(),
             battlefield: Vec::new(),
             command_zone: Vec::new(),
             exile: Vec::new(),
+            owner_zone_index: OwnerZoneIndex::default(),
             turn: TurnState::new(active_player),
             continuous_effects: ContinuousEffectManager::new(),
             replacement_effects: ReplacementEffectManager::new(),
             prevention_effects: PreventionEffectManager::new(),
+            static_effect_cache: Default::default(),
+            state_version: 0,
+            legal_action_cache: Default::default(),
+            error_dump_dir: None,
             cant_effects: CantEffectTracker::new(),
             mana_spend_effects: ManaSpendEffectTracker::new(),
             delayed_triggers: Vec::new(),
@@ -1572,15 +1813,12 @@ impl GameState {
             skip_next_combat_phases: HashSet::new(),
             player_control_effects: Vec::new(),
             player_control_timestamp: 0,
-            creatures_died_this_turn: 0,
-            creatures_died_under_controller_this_turn: HashMap::new(),
-            creatures_left_battlefield_under_controller_this_turn: HashMap::new(),
-            permanents_left_battlefield_under_controller_this_turn: HashMap::new(),
+            turn_trackers: TurnTrackers::default(),
             objects_put_into_graveyard_this_turn: HashSet::new(),
             objects_put_into_graveyard_from_battlefield_this_turn: HashSet::new(),
             triggers_fired_this_turn: HashMap::new(),
+            cost_reductions_used_this_turn: HashMap::new(),
             turn_counters: TurnCounterTracker::default(),
-            spells_cast_this_turn: HashMap::new(),
             foretell_actions_this_turn: HashSet::new(),
             crimes_committed_this_turn: HashMap::new(),
             artifacts_sacrificed_this_turn: HashMap::new(),
@@ -1588,7 +1826,7 @@ impl GameState {
             spells_cast_this_turn_total: 0,
             spells_cast_this_turn_snapshots: Vec::new(),
             spell_cast_order_this_turn: HashMap::new(),
-            players_attacked_this_turn: HashSet::new(),
+            last_attack_declarations: HashMap::new(),
             players_tapped_land_for_mana_this_turn: HashSet::new(),
             creatures_attacked_this_turn: HashSet::new(),
             spells_cast_last_turn_total: 0,
@@ -1603,8 +1841,6 @@ impl GameState {
             creature_damage_to_players_this_turn: HashMap::new(),
             damage_to_players_this_turn: HashMap::new(),
             noncombat_damage_to_players_this_turn: HashMap::new(),
-            life_lost_this_turn: HashMap::new(),
-            life_gained_this_turn: HashMap::new(),
             creatures_damaged_by_this_turn: HashMap::new(),
             combat_damage_player_batch_hits: Vec::new(),
             granted_mana_abilities: Vec::new(),
@@ -1623,20 +1859,23 @@ impl GameState {
             renowned: HashSet::new(),
             flipped: HashSet::new(),
             face_down: HashSet::new(),
+            transformed_front_faces: HashMap::new(),
             phased_out: HashSet::new(),
+            phased_out_indirectly: HashSet::new(),
             madness_exiled: HashSet::new(),
             foretold_cards: HashSet::new(),
             plotted_cards: HashMap::new(),
             saga_final_chapter_resolved: HashSet::new(),
             commanders: HashSet::new(),
             commander_casts_from_command_zone: HashMap::new(),
+            spells_cast_this_game: Vec::new(),
             declined_commander_command_zone_moves: HashSet::new(),
             imprinted_cards: HashMap::new(),
             exiled_with_source: HashMap::new(),
             linked_exile_groups: HashMap::new(),
             next_linked_exile_group_id: 0,
-            random_state: Cell::new(Self::normalize_random_seed(0)),
-            irreversible_random_count: Cell::new(0),
+            random_state: CounterCell::new(Self::normalize_random_seed(0)),
+            irreversible_random_count: CounterCell::new(0),
         }
     }
 
@@ -1700,9 +1939,14 @@ impl GameState {
     }
 
     /// Generates a new unique object ID.
+    ///
+    /// IDs are numbered per game, so replaying the same inputs on a fresh
+    /// `GameState` produces the same IDs, and a cloned or restored state keeps
+    /// allocating where it left off.
     pub fn new_object_id(&mut self) -> ObjectId {
-        // Use global atomic counter for ID generation
-        ObjectId::new()
+        let id = ObjectId::from_raw(self.next_object_id);
+        self.next_object_id += 1;
+        id
     }
 
     pub fn add_restriction_effect(
@@ -1770,6 +2014,41 @@ impl GameState {
         });
     }
 
+    /// Remove prevention shields whose duration has ended.
+    pub fn cleanup_expired_prevention_shields(&mut self) {
+        let mut prevention_effects = std::mem::take(&mut self.prevention_effects);
+        prevention_effects.cleanup_expired(self);
+        self.prevention_effects = prevention_effects;
+    }
+
+    /// End every continuous effect, prevention shield, restriction and goad
+    /// whose `until` duration has just been reached.
+    ///
+    /// The turn structure calls this at each boundary: `EndOfCombat` as combat
+    /// ends, `YourNextTurn` as a turn begins, `ControllersNextUntapStep` once
+    /// the untap step is over, `YourNextEndStep` as the end step begins, and
+    /// `EndOfTurn` during cleanup.
+    /// Player-relative durations only end for the active player.
+    pub fn expire_duration(&mut self, until: crate::effect::Until) {
+        let active_player = self.turn.active_player;
+
+        let mut continuous_effects = std::mem::take(&mut self.continuous_effects);
+        continuous_effects.expire(&until, self);
+        self.continuous_effects = continuous_effects;
+
+        self.prevention_effects.expire(&until, active_player);
+        self.restriction_effects.retain(|effect| {
+            !effect
+                .duration
+                .ends_at(&until, effect.controller, active_player)
+        });
+        self.goad_effects.retain(|effect| {
+            !effect
+                .duration
+                .ends_at(&until, effect.goaded_by, active_player)
+        });
+    }
+
     pub fn cleanup_granted_mana_abilities_end_of_turn(&mut self) {
         let current_turn = self.turn.turn_number;
         self.granted_mana_abilities
@@ -1821,7 +2100,8 @@ impl GameState {
 
     /// Pays life as a cost.
     ///
-    /// Returns true if the player could pay and life was deducted.
+    /// Queues a life loss event flagged as a payment. Returns true if the
+    /// player could pay and life was deducted.
     pub fn pay_life(&mut self, player: PlayerId, amount: u32) -> bool {
         if amount == 0 {
             return self.player(player).is_some();
@@ -1829,7 +2109,17 @@ impl GameState {
         if !self.can_pay_life(player, amount) {
             return false;
         }
-        self.lose_life(player, amount) == amount
+        if self.lose_life(player, amount) != amount {
+            return false;
+        }
+        self.queue_trigger_event(
+            ProvNodeId::default(),
+            crate::triggers::TriggerEvent::new_with_provenance(
+                crate::events::LifeLossEvent::from_cost(player, amount),
+                ProvNodeId::default(),
+            ),
+        );
+        true
     }
 
     /// Can the player search their library?
@@ -1847,6 +2137,25 @@ impl GameState {
         self.cant_effects.can_attack(creature)
     }
 
+    /// Can the creature attack this player or planeswalker?
+    pub fn can_attack_target(
+        &self,
+        creature: ObjectId,
+        target: &crate::combat_state::AttackTarget,
+    ) -> bool {
+        use crate::combat_state::AttackTarget;
+
+        match target {
+            AttackTarget::Player(player) => self.cant_effects.can_attack_player(creature, *player),
+            AttackTarget::Planeswalker(planeswalker) => {
+                self.object(*planeswalker).is_none_or(|planeswalker| {
+                    self.cant_effects
+                        .can_attack_planeswalkers_of(creature, planeswalker.controller)
+                })
+            }
+        }
+    }
+
     /// Can the creature attack as the only attacker?
     pub fn can_attack_alone(&self, creature: ObjectId) -> bool {
         self.cant_effects.can_attack_alone(creature)
@@ -1991,6 +2300,11 @@ impl GameState {
         self.cant_effects.can_be_countered(spell)
     }
 
+    /// Can this spell on the stack be countered by something this player controls?
+    pub fn can_be_countered_by(&self, spell: ObjectId, player: PlayerId) -> bool {
+        self.cant_effects.can_be_countered_by(spell, player)
+    }
+
     /// Can this permanent transform?
     pub fn can_transform(&self, permanent: ObjectId) -> bool {
         self.cant_effects.can_transform(permanent)
@@ -2003,10 +2317,14 @@ impl GameState {
         let owner = object.owner;
         let stable_id = object.stable_id;
 
+        self.bump_state_version();
+        // Objects built with explicit IDs must never be handed out again.
+        self.next_object_id = self.next_object_id.max(id.0.saturating_add(1));
         self.objects.insert(id, object);
         self.stable_id_index.insert(stable_id, id);
 
         // Update zone indexes
+        self.owner_zone_index.insert(owner, zone, id);
         match zone {
             Zone::Battlefield => self.battlefield.push(id),
             Zone::Command => self.command_zone.push(id),
@@ -2128,6 +2446,9 @@ impl GameState {
             };
 
             let Some(id) = card_id else {
+                if let Some(player_obj) = self.player_mut(player) {
+                    player_obj.drew_from_empty_library = true;
+                }
                 break;
             };
 
@@ -2145,14 +2466,33 @@ impl GameState {
     /// Moves an object to a new zone.
     /// Per MTG rule 400.7, this creates a new object (new ID).
     /// Returns the new ObjectId.
+    ///
+    /// Emblems can't leave the command zone (rule 114.1), so moving one
+    /// returns `None` and leaves it in place.
     pub fn move_object(&mut self, old_id: ObjectId, new_zone: Zone) -> Option<ObjectId> {
-        // Capture a full pre-move snapshot for LKI-based trigger matching.
-        let pre_move_snapshot = self
+        if self
             .objects
             .get(&old_id)
-            .map(|obj| crate::snapshot::ObjectSnapshot::from_object(obj, self));
+            .is_some_and(|obj| obj.kind == ObjectKind::Emblem)
+        {
+            return None;
+        }
+
+        // Capture a full pre-move snapshot for LKI-based trigger matching.
+        // Control-changing effects apply in layer 2, so a permanent's controller
+        // at the time it leaves comes from its calculated characteristics.
+        let pre_move_snapshot = self.objects.get(&old_id).map(|obj| {
+            let mut snapshot = crate::snapshot::ObjectSnapshot::from_object(obj, self);
+            if obj.zone == Zone::Battlefield
+                && let Some(calculated) = self.calculated_characteristics(old_id)
+            {
+                snapshot.controller = calculated.controller;
+            }
+            snapshot
+        });
 
         let old_object = self.objects.remove(&old_id)?;
+        self.bump_state_version();
         self.stable_id_index.remove(&old_object.stable_id);
         self.declined_commander_command_zone_moves.remove(&old_id);
         let old_zone = old_object.zone;
@@ -2164,26 +2504,11 @@ impl GameState {
             && new_zone == Zone::Graveyard
             && old_object.is_creature();
         if is_creature_dying {
-            self.creatures_died_this_turn += 1;
-            *self
-                .creatures_died_under_controller_this_turn
-                .entry(controller)
-                .or_insert(0) += 1;
-        }
-        if old_zone == Zone::Battlefield
-            && new_zone != Zone::Battlefield
-            && old_object.is_creature()
-        {
-            *self
-                .creatures_left_battlefield_under_controller_this_turn
-                .entry(controller)
-                .or_insert(0) += 1;
+            self.turn_trackers.record_creature_died(controller);
         }
         if old_zone == Zone::Battlefield && new_zone != Zone::Battlefield {
-            *self
-                .permanents_left_battlefield_under_controller_this_turn
-                .entry(controller)
-                .or_insert(0) += 1;
+            self.turn_trackers
+                .record_left_battlefield(controller, old_object.is_creature());
         }
         if new_zone == Zone::Graveyard {
             self.objects_put_into_graveyard_this_turn
@@ -2201,6 +2526,13 @@ impl GameState {
         // Remove from old zone index
         self.remove_from_zone_index(old_id, old_zone, owner);
 
+        // A transformed permanent is put into its new zone front face up.
+        let transformed_front_face = if old_zone == Zone::Battlefield {
+            self.transformed_front_faces.remove(&old_id)
+        } else {
+            None
+        };
+
         // Clear state from old zone's extension maps
         if old_zone == Zone::Battlefield {
             self.clear_battlefield_state(old_id);
@@ -2212,9 +2544,13 @@ impl GameState {
 
         // Create new object with new ID (zone change = new object per rule 400.7)
         let new_id = self.new_object_id();
+        let stable_id = old_object.stable_id;
         let mut new_object = old_object;
         new_object.id = new_id;
         new_object.zone = new_zone;
+        if let Some(front) = &transformed_front_face {
+            new_object.apply_definition_face(front);
+        }
 
         // Reset zone-specific state on the object
         new_object.attached_to = None;
@@ -2241,7 +2577,7 @@ impl GameState {
 
         // Queue zone change event for triggers.
         if old_zone != new_zone {
-            use crate::events::zones::ZoneChangeEvent;
+            use crate::events::zones::{MovedObject, ZoneChangeEvent};
             use crate::triggers::TriggerEvent;
 
             // For LTB-style moves we keep the pre-move object ID; for all others use
@@ -2252,7 +2588,12 @@ impl GameState {
                 new_id
             };
             let event =
-                ZoneChangeEvent::new(event_object_id, old_zone, new_zone, pre_move_snapshot);
+                ZoneChangeEvent::new(event_object_id, old_zone, new_zone, pre_move_snapshot)
+                    .with_moved(vec![MovedObject {
+                        old_id,
+                        new_id,
+                        stable_id,
+                    }]);
             let event_provenance = self
                 .provenance_graph
                 .alloc_root_event(crate::events::EventKind::ZoneChange);
@@ -2293,6 +2634,26 @@ impl GameState {
         old_id: ObjectId,
         new_zone: Zone,
         decision_maker: &mut impl crate::decision::DecisionMaker,
+    ) -> Option<EntersResult> {
+        self.move_object_with_etb_processing_under_control_with_dm(
+            old_id,
+            new_zone,
+            None,
+            decision_maker,
+        )
+    }
+
+    /// Move an object with ETB processing, entering under `controller`'s control.
+    ///
+    /// ETB replacement effects see the object as controlled by `controller`
+    /// (or its current controller if `None`). The caller is responsible for
+    /// assigning control of the new permanent.
+    pub fn move_object_with_etb_processing_under_control_with_dm(
+        &mut self,
+        old_id: ObjectId,
+        new_zone: Zone,
+        controller: Option<PlayerId>,
+        decision_maker: &mut impl crate::decision::DecisionMaker,
     ) -> Option<EntersResult> {
         let old_zone = self.object(old_id)?.zone;
 
@@ -2306,13 +2667,64 @@ impl GameState {
         }
 
         // Process through ETB replacement effects
-        let result = crate::event_processor::process_etb_with_event_and_dm(
+        let result = crate::event_processor::process_etb_under_control_with_event_and_dm(
             self,
             old_id,
             old_zone,
+            controller,
             decision_maker,
         );
+        self.enter_battlefield_with_etb_result(old_id, old_zone, result, decision_maker)
+    }
+
+    /// Move several objects onto the battlefield at the same time.
+    ///
+    /// Each entry pairs an object with the player it enters under (or `None`
+    /// to keep its current controller), as for
+    /// [`Self::move_object_with_etb_processing_under_control_with_dm`]. The
+    /// replacement effects for every object are determined before any of them
+    /// moves, so a permanent can't change how another permanent entering
+    /// alongside it enters (rule 614.12). Objects then enter in entry order.
+    ///
+    /// Returns one result per entry, `None` where the entry was prevented.
+    pub fn move_objects_to_battlefield_simultaneously_with_dm(
+        &mut self,
+        entries: &[(ObjectId, Option<PlayerId>)],
+        decision_maker: &mut impl crate::decision::DecisionMaker,
+    ) -> Vec<Option<EntersResult>> {
+        let replaced: Vec<_> = entries
+            .iter()
+            .map(|&(old_id, controller)| {
+                let old_zone = self.object(old_id)?.zone;
+                let result = crate::event_processor::process_etb_under_control_with_event_and_dm(
+                    self,
+                    old_id,
+                    old_zone,
+                    controller,
+                    decision_maker,
+                );
+                Some((old_id, old_zone, result))
+            })
+            .collect();
+
+        replaced
+            .into_iter()
+            .map(|entry| {
+                let (old_id, old_zone, result) = entry?;
+                self.enter_battlefield_with_etb_result(old_id, old_zone, result, decision_maker)
+            })
+            .collect()
+    }
 
+    /// Finish moving an object onto the battlefield once its ETB replacement
+    /// effects have been determined.
+    fn enter_battlefield_with_etb_result(
+        &mut self,
+        old_id: ObjectId,
+        old_zone: Zone,
+        result: crate::event_processor::EtbEventResult,
+        decision_maker: &mut impl crate::decision::DecisionMaker,
+    ) -> Option<EntersResult> {
         // If ETB was prevented or redirected to a different zone
         if result.prevented {
             if let Some(dest) = result.new_destination {
@@ -2351,8 +2763,16 @@ impl GameState {
             self.tap(new_id);
         }
 
-        // Apply enters with counters
+        // Apply enters with counters. Counters a permanent enters with are still
+        // counters being put on it, so counter-placement replacements apply (rule 122.6).
         for (counter_type, count) in &result.enters_with_counters {
+            let count = crate::event_processor::process_put_counters_with_event_and_dm(
+                self,
+                new_id,
+                *counter_type,
+                *count,
+                decision_maker,
+            );
             if let Some(obj) = self.object_mut(new_id) {
                 *obj.counters.entry(*counter_type).or_insert(0) += count;
             }
@@ -2509,6 +2929,7 @@ impl GameState {
     /// This does NOT create a new object - the object is simply gone.
     pub fn remove_object(&mut self, id: ObjectId) {
         if let Some(obj) = self.objects.remove(&id) {
+            self.bump_state_version();
             self.stable_id_index.remove(&obj.stable_id);
             self.declined_commander_command_zone_moves.remove(&id);
             self.remove_from_zone_index(id, obj.zone, obj.owner);
@@ -2517,6 +2938,7 @@ impl GameState {
 
     /// Removes an object ID from its zone index.
     fn remove_from_zone_index(&mut self, id: ObjectId, zone: Zone, owner: PlayerId) {
+        self.owner_zone_index.remove(owner, zone, id);
         match zone {
             Zone::Battlefield => self.battlefield.retain(|&x| x != id),
             Zone::Command => self.command_zone.retain(|&x| x != id),
@@ -2527,7 +2949,9 @@ impl GameState {
                 }
             }
             Zone::Hand => {
-                if let Some(player) = self.player_mut(owner) {
+                // A card can be in a hand other than its owner's after hands are
+                // exchanged, so look in every hand.
+                for player in &mut self.players {
                     player.hand.retain(|&x| x != id);
                 }
             }
@@ -2540,6 +2964,40 @@ impl GameState {
         }
     }
 
+    /// Returns the player whose hand contains the card.
+    ///
+    /// This is usually the card's owner, but exchanging hands puts cards into
+    /// another player's hand without changing ownership.
+    pub fn hand_holder(&self, id: ObjectId) -> Option<PlayerId> {
+        self.players
+            .iter()
+            .find(|player| player.hand.contains(&id))
+            .map(|player| player.id)
+    }
+
+    /// Exchanges the contents of two players' hands.
+    ///
+    /// Cards keep their owner and object ID; only the hand they are in changes.
+    pub fn exchange_hands(&mut self, first: PlayerId, second: PlayerId) {
+        if first == second || self.player(first).is_none() || self.player(second).is_none() {
+            return;
+        }
+        let first_hand = self
+            .player_mut(first)
+            .map(|player| std::mem::take(&mut player.hand))
+            .unwrap_or_default();
+        let second_hand = self
+            .player_mut(second)
+            .map(|player| std::mem::take(&mut player.hand))
+            .unwrap_or_default();
+        if let Some(player) = self.player_mut(first) {
+            player.hand = second_hand;
+        }
+        if let Some(player) = self.player_mut(second) {
+            player.hand = first_hand;
+        }
+    }
+
     // =========================================================================
     // Zone Consistency Validation (Debug Only)
     // =========================================================================
@@ -2711,6 +3169,10 @@ impl GameState {
                 // Stack objects are managed via StackEntry, not indexed
                 continue;
             }
+            if self.phased_out.contains(&id) {
+                // Phased-out permanents are treated as though they don't exist
+                continue;
+            }
             if !seen_ids.contains(&id) {
                 return Err(format!(
                     "Object #{} with zone {} is not in any zone index",
@@ -2719,6 +3181,44 @@ impl GameState {
             }
         }
 
+        // Check that the per-owner split of the shared zones matches them
+        for (zone, listed) in [
+            (Zone::Battlefield, &self.battlefield),
+            (Zone::Exile, &self.exile),
+            (Zone::Command, &self.command_zone),
+        ] {
+            let mut indexed = 0;
+            for player in &self.players {
+                for &id in self.owner_zone_index.ids(player.id, zone) {
+                    if !listed.contains(&id) {
+                        return Err(format!(
+                            "Object #{} is indexed under {}'s {} but is not there",
+                            id.0, player.name, zone
+                        ));
+                    }
+                    if self
+                        .objects
+                        .get(&id)
+                        .is_none_or(|obj| obj.owner != player.id)
+                    {
+                        return Err(format!(
+                            "Object #{} is indexed under {}'s {} but has another owner",
+                            id.0, player.name, zone
+                        ));
+                    }
+                    indexed += 1;
+                }
+            }
+            if indexed != listed.len() {
+                return Err(format!(
+                    "{} has {} objects but {} are indexed by owner",
+                    zone,
+                    listed.len(),
+                    indexed
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -2737,6 +3237,7 @@ impl GameState {
 
     /// Gets a mutable reference to an object by ID.
     pub fn object_mut(&mut self, id: ObjectId) -> Option<&mut Object> {
+        self.bump_state_version();
         self.objects.get_mut(&id)
     }
 
@@ -3018,7 +3519,7 @@ impl GameState {
     /// Battlefield objects use calculated characteristics so continuous effects
     /// like Blood Moon, Humility, and subtype-granted basic land mana abilities
     /// are reflected consistently. Other zones use the printed/intrinsic list.
-    pub fn current_abilities(&self, id: ObjectId) -> Option<Vec<Ability>> {
+    pub fn current_abilities(&self, id: ObjectId) -> Option<SharedVec<Ability>> {
         let object = self.object(id)?;
         if object.zone == Zone::Battlefield {
             return self
@@ -3146,6 +3647,12 @@ impl GameState {
         self.calculated_card_types(id).contains(&card_type)
     }
 
+    /// Check if an object has a specific supertype (with continuous effects applied).
+    pub fn object_has_supertype(&self, id: ObjectId, supertype: crate::types::Supertype) -> bool {
+        self.calculated_characteristics(id)
+            .is_some_and(|c| c.supertypes.contains(&supertype))
+    }
+
     // =========================================================================
     // "Can't" Effect Tracking (Rule 614.17)
     // =========================================================================
@@ -3292,12 +3799,75 @@ impl GameState {
     /// effects (anthems, abilities that grant abilities, etc.) and updates the
     /// ContinuousEffectManager with these effects.
     ///
-    /// Per Rule 611.3a, static ability effects apply dynamically.
+    /// Per Rule 611.3a, static ability effects apply dynamically. Effects from
+    /// abilities that only depend on their source are reused until that source
+    /// changes zone, controller, or counters.
     pub fn update_static_ability_effects(&mut self) {
-        use crate::static_ability_processor::generate_continuous_effects_from_static_abilities;
+        use crate::static_ability_processor::refresh_continuous_effects_with_cache;
+
+        let mut cache = std::mem::take(&mut self.static_effect_cache);
+        let effects = refresh_continuous_effects_with_cache(self, &mut cache);
+        self.static_effect_cache = cache;
+        if let Some(effects) = effects
+            && effects != self.continuous_effects.static_ability_effects()
+        {
+            self.continuous_effects.set_static_ability_effects(effects);
+            self.bump_state_version();
+        }
+    }
+
+    /// Cache backing `update_static_ability_effects`.
+    pub fn static_effect_cache(&self) -> &crate::static_ability_processor::StaticEffectCache {
+        &self.static_effect_cache
+    }
+
+    /// Enable or disable reuse of static ability effects between refreshes.
+    pub fn set_static_effect_caching(&mut self, enabled: bool) {
+        self.static_effect_cache.set_enabled(enabled);
+    }
+
+    /// Counter bumped by every method that changes objects, players, the
+    /// stack, or static ability effects.
+    ///
+    /// Code that writes other public fields directly and needs version-keyed
+    /// caches to notice should call [`Self::bump_state_version`].
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+
+    /// Mark the game state as changed.
+    pub fn bump_state_version(&mut self) {
+        self.state_version = self.state_version.wrapping_add(1);
+    }
+
+    /// Cache backing `compute_legal_actions_cached`.
+    pub fn legal_action_cache(&self) -> &crate::legal_action_cache::LegalActionCache {
+        &self.legal_action_cache
+    }
+
+    pub(crate) fn legal_action_cache_mut(
+        &mut self,
+    ) -> &mut crate::legal_action_cache::LegalActionCache {
+        &mut self.legal_action_cache
+    }
 
-        let effects = generate_continuous_effects_from_static_abilities(self);
-        self.continuous_effects.set_static_ability_effects(effects);
+    /// Enable or disable reuse of legal actions between priority passes.
+    pub fn set_legal_action_caching(&mut self, enabled: bool) {
+        self.legal_action_cache.set_enabled(enabled);
+    }
+
+    /// Directory for effect-failure debug dumps, if enabled.
+    pub fn error_dump_dir(&self) -> Option<&std::path::Path> {
+        self.error_dump_dir.as_deref()
+    }
+
+    /// Write a debug dump to `dir` whenever an effect fails during resolution,
+    /// and fail the resolution with the dump's path instead of ignoring the error.
+    ///
+    /// Off by default: effects routinely fail harmlessly (for example when a
+    /// target vanished mid-resolution), so this is meant for debugging sessions.
+    pub fn set_error_dump_dir(&mut self, dir: Option<std::path::PathBuf>) {
+        self.error_dump_dir = dir;
     }
 
     /// Update replacement effects from static abilities on the battlefield.
@@ -3407,6 +3977,7 @@ impl GameState {
 
     /// Gets a mutable reference to a player by ID.
     pub fn player_mut(&mut self, id: PlayerId) -> Option<&mut Player> {
+        self.bump_state_version();
         self.players.get_mut(id.index())
     }
 
@@ -3521,6 +4092,40 @@ impl GameState {
         }
     }
 
+    /// Number of times the source's limited-use cost reduction has been used this turn.
+    pub fn cost_reduction_uses_this_turn(&self, source: ObjectId) -> u32 {
+        self.cost_reductions_used_this_turn
+            .get(&source)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Records that the source's limited-use cost reduction applied to a cast spell.
+    pub fn record_cost_reduction_use(&mut self, source: ObjectId) {
+        *self
+            .cost_reductions_used_this_turn
+            .entry(source)
+            .or_insert(0) += 1;
+    }
+
+    /// Records a spell cast in the game-wide cast history.
+    pub fn record_spell_cast_this_game(
+        &mut self,
+        spell: ObjectId,
+        caster: PlayerId,
+        from_zone: Zone,
+    ) {
+        let Some(name) = self.object(spell).map(|obj| obj.name.clone()) else {
+            return;
+        };
+        self.spells_cast_this_game.push(SpellCastRecord {
+            spell,
+            caster,
+            name,
+            from_zone,
+        });
+    }
+
     /// Records combat damage dealt to a player by a commander.
     pub fn record_commander_damage(
         &mut self,
@@ -3580,6 +4185,31 @@ impl GameState {
             .collect()
     }
 
+    /// Returns the objects in `zone` that belong to `player`.
+    ///
+    /// Libraries and graveyards are the player's own; a hand is the one the
+    /// player holds, which can differ from ownership after hands are exchanged.
+    /// The battlefield, exile, command zone, and stack are split by owner, not
+    /// controller, so callers that care about control still check it.
+    pub fn objects_in(&self, zone: Zone, player: PlayerId) -> impl Iterator<Item = ObjectId> + '_ {
+        let listed: &[ObjectId] = match zone {
+            Zone::Library => self.player(player).map_or(&[], |p| p.library.as_slice()),
+            Zone::Hand => self.player(player).map_or(&[], |p| p.hand.as_slice()),
+            Zone::Graveyard => self.player(player).map_or(&[], |p| p.graveyard.as_slice()),
+            Zone::Battlefield | Zone::Exile | Zone::Command => {
+                self.owner_zone_index.ids(player, zone)
+            }
+            Zone::Stack => &[],
+        };
+        let stack = self
+            .stack
+            .iter()
+            .filter(move |_| zone == Zone::Stack)
+            .map(|entry| entry.object_id)
+            .filter(move |&id| self.object(id).is_some_and(|obj| obj.owner == player));
+        listed.iter().copied().chain(stack)
+    }
+
     /// Returns an iterator over all objects in the game.
     pub fn objects_iter(&self) -> impl Iterator<Item = &Object> {
         self.objects.values()
@@ -3641,6 +4271,104 @@ impl GameState {
             .sum()
     }
 
+    /// The result of the game once at most one player remains, or `None` while
+    /// it's still being played.
+    pub fn game_result(&self) -> Option<crate::decision::GameResult> {
+        use crate::decision::GameResult;
+
+        let mut remaining = self.players.iter().filter(|p| p.is_in_game());
+        match (remaining.next(), remaining.next()) {
+            (None, _) => Some(GameResult::Draw),
+            (Some(winner), None) => Some(GameResult::Winner(winner.id)),
+            _ => None,
+        }
+    }
+
+    /// The player concedes (rule 104.3a). This can happen at any time, even in
+    /// the middle of resolving a spell, and isn't stopped by "can't lose" effects.
+    ///
+    /// If other players keep playing, the conceding player leaves the game
+    /// (rule 800.4a): everything they own leaves with them, effects giving
+    /// them control of objects end, spells and abilities they control stop
+    /// existing, and anything they still control is exiled. Priority and
+    /// combat move on without them.
+    pub fn concede(&mut self, player: PlayerId) {
+        let Some(conceding) = self.player_mut(player) else {
+            return;
+        };
+        if !conceding.is_in_game() {
+            return;
+        }
+        conceding.concede();
+
+        let still_playing = self.players.iter().filter(|p| p.is_in_game()).count();
+        if still_playing < 2 {
+            return;
+        }
+        let owned: Vec<ObjectId> = self
+            .objects
+            .values()
+            .filter(|obj| obj.owner == player)
+            .map(|obj| obj.id)
+            .collect();
+        for id in owned {
+            self.remove_object(id);
+        }
+        self.continuous_effects.remove_control_effects_for(player);
+        self.update_static_ability_effects();
+
+        let controlled_spells: Vec<ObjectId> = self
+            .stack
+            .iter()
+            .filter(|entry| entry.controller == player && !entry.is_ability)
+            .map(|entry| entry.object_id)
+            .collect();
+        self.stack.retain(|entry| entry.controller != player);
+        let objects = &self.objects;
+        self.stack
+            .retain(|entry| entry.is_ability || objects.contains_key(&entry.object_id));
+        let still_controlled: Vec<ObjectId> = self
+            .battlefield
+            .iter()
+            .copied()
+            .filter(|&id| {
+                self.calculated_characteristics(id)
+                    .is_some_and(|chars| chars.controller == player)
+            })
+            .collect();
+        for id in controlled_spells.into_iter().chain(still_controlled) {
+            self.move_object(id, Zone::Exile);
+        }
+
+        if let Some(combat) = self.combat.as_mut() {
+            let objects = &self.objects;
+            combat.attackers.retain(|info| {
+                objects.contains_key(&info.creature)
+                    && info.target != crate::combat_state::AttackTarget::Player(player)
+            });
+            combat
+                .blockers
+                .retain(|attacker, _| objects.contains_key(attacker));
+            for blockers in combat.blockers.values_mut() {
+                blockers.retain(|blocker| objects.contains_key(blocker));
+            }
+            combat
+                .damage_assignment_order
+                .retain(|attacker, _| objects.contains_key(attacker));
+        }
+        if self.turn.priority_player == Some(player) {
+            self.turn.priority_player = self.next_player_in_game_after(player);
+        }
+    }
+
+    /// The next player after `player` in turn order who is still in the game.
+    fn next_player_in_game_after(&self, player: PlayerId) -> Option<PlayerId> {
+        let start = self.turn_order.iter().position(|&p| p == player)?;
+        (1..self.turn_order.len())
+            .map(|offset| self.turn_order[(start + offset) % self.turn_order.len()])
+            .find(|&candidate| self.player(candidate).is_some_and(|p| p.is_in_game()))
+    }
+
     /// Advances to the next turn.
     ///
     /// Turn order rules:
@@ -3707,20 +4435,18 @@ impl GameState {
         self.clear_activated_abilities_tracking();
         self.chosen_modes_by_ability_this_turn.clear();
         self.cards_drawn_this_turn.clear();
-        self.creatures_died_this_turn = 0;
-        self.creatures_died_under_controller_this_turn.clear();
-        self.creatures_left_battlefield_under_controller_this_turn
-            .clear();
-        self.permanents_left_battlefield_under_controller_this_turn
-            .clear();
+        self.turn_trackers.clear();
+        self.prevention_effects.set_turn(self.turn.turn_number);
+        self.cleanup_expired_prevention_shields();
+        self.expire_duration(crate::effect::Until::YourNextTurn);
         self.objects_put_into_graveyard_this_turn.clear();
         self.objects_put_into_graveyard_from_battlefield_this_turn
             .clear();
         self.objects_entered_battlefield_this_turn.clear();
         self.triggers_fired_this_turn.clear();
+        self.cost_reductions_used_this_turn.clear();
         self.turn_counters.clear();
         self.spells_cast_last_turn_total = self.spells_cast_this_turn_total;
-        self.spells_cast_this_turn.clear();
         self.foretell_actions_this_turn.clear();
         self.crimes_committed_this_turn.clear();
         self.artifacts_sacrificed_this_turn.clear();
@@ -3728,20 +4454,16 @@ impl GameState {
         self.spells_cast_this_turn_total = 0;
         self.spells_cast_this_turn_snapshots.clear();
         self.spell_cast_order_this_turn.clear();
-        self.players_attacked_this_turn.clear();
         self.players_tapped_land_for_mana_this_turn.clear();
         self.creatures_attacked_this_turn.clear();
         self.library_searches_this_turn.clear();
         self.creatures_entered_this_turn.clear();
         self.crewed_this_turn.clear();
-        self.saddled_until_end_of_turn.clear();
         self.saddled_this_turn.clear();
         self.ninjutsu_attack_targets.clear();
         self.creature_damage_to_players_this_turn.clear();
         self.damage_to_players_this_turn.clear();
         self.noncombat_damage_to_players_this_turn.clear();
-        self.life_lost_this_turn.clear();
-        self.life_gained_this_turn.clear();
         self.creatures_damaged_by_this_turn.clear();
         self.combat_damage_player_batch_hits.clear();
 
@@ -4051,11 +4773,13 @@ impl GameState {
 
     /// Pushes a spell or ability onto the stack.
     pub fn push_to_stack(&mut self, entry: StackEntry) {
+        self.bump_state_version();
         self.stack.push(entry);
     }
 
     /// Pops and returns the top item from the stack.
     pub fn pop_from_stack(&mut self) -> Option<StackEntry> {
+        self.bump_state_version();
         self.stack.pop()
     }
 
@@ -4100,6 +4824,12 @@ impl GameState {
             .map(|o| o.id)
     }
 
+    /// Get the object that currently carries the given stable_id, whichever
+    /// zone it is in.
+    pub fn find_by_stable_id(&self, stable_id: StableId) -> Option<&Object> {
+        self.object(self.find_object_by_stable_id(stable_id)?)
+    }
+
     /// Check if a player controls any of their own commanders on the battlefield.
     ///
     /// This checks if the player controls a permanent that is designated as
@@ -4233,6 +4963,7 @@ impl GameState {
             attacking_player: None,
             your_commanders,
             iterated_player: None,
+            triggering_player: None,
             target_players: Vec::new(),
             target_objects: Vec::new(),
             tagged_objects,
@@ -4302,14 +5033,46 @@ impl GameState {
 
     /// Tap a permanent.
     pub fn tap(&mut self, id: ObjectId) {
+        self.bump_state_version();
         self.tapped_permanents.insert(id);
     }
 
     /// Untap a permanent.
     pub fn untap(&mut self, id: ObjectId) {
+        self.bump_state_version();
         self.tapped_permanents.remove(&id);
     }
 
+    /// Untap a permanent as a game action, honoring stun counters.
+    ///
+    /// If the permanent has a stun counter, one is removed instead and it
+    /// stays tapped (rule 122.1d). Returns the event for what happened: a
+    /// `PermanentUntappedEvent` carrying `provenance`, a counter-removal event
+    /// for a stun counter, or `None` when the permanent wasn't tapped.
+    pub fn untap_permanent(
+        &mut self,
+        id: ObjectId,
+        provenance: ProvNodeId,
+    ) -> Option<crate::triggers::TriggerEvent> {
+        if !self.is_tapped(id) {
+            return None;
+        }
+        let stunned = self
+            .object(id)
+            .and_then(|obj| obj.counters.get(&crate::object::CounterType::Stun))
+            .is_some_and(|&count| count > 0);
+        if stunned {
+            return self
+                .remove_counters(id, crate::object::CounterType::Stun, 1, None, None)
+                .map(|(_, event)| event);
+        }
+        self.untap(id);
+        Some(crate::triggers::TriggerEvent::new_with_provenance(
+            crate::events::PermanentUntappedEvent::new(id),
+            provenance,
+        ))
+    }
+
     /// Check if a creature has summoning sickness.
     pub fn is_summoning_sick(&self, id: ObjectId) -> bool {
         self.summoning_sick.contains(&id)
@@ -4454,19 +5217,107 @@ impl GameState {
         self.face_down.remove(&id);
     }
 
+    /// Check if a double-faced permanent is showing its back face.
+    pub fn is_transformed(&self, id: ObjectId) -> bool {
+        self.transformed_front_faces.contains_key(&id)
+    }
+
+    /// Check whether `viewer` may see an object's characteristics.
+    ///
+    /// Library cards are hidden from everyone and hand cards from everyone
+    /// but their owner. Face-down objects can be looked at only by their
+    /// controller, or by their owner outside the battlefield and stack
+    /// (Rule 708.5). Passing `None` asks what any observer can see.
+    pub fn can_see_object(&self, id: ObjectId, viewer: Option<PlayerId>) -> bool {
+        let Some(obj) = self.object(id) else {
+            return false;
+        };
+        match obj.zone {
+            Zone::Library => false,
+            Zone::Hand => viewer == Some(obj.owner),
+            zone => {
+                if !self.is_face_down(id) || self.is_transformed(id) {
+                    return true;
+                }
+                let looker = if matches!(zone, Zone::Battlefield | Zone::Stack) {
+                    obj.controller
+                } else {
+                    obj.owner
+                };
+                viewer == Some(looker)
+            }
+        }
+    }
+
     /// Check if a permanent is phased out.
     pub fn is_phased_out(&self, id: ObjectId) -> bool {
         self.phased_out.contains(&id)
     }
 
-    /// Phase out a permanent.
+    /// Phase out a permanent, along with everything attached to it.
+    ///
+    /// Phasing doesn't move the permanent to another zone, so it doesn't
+    /// leave the battlefield and no leaves-the-battlefield abilities trigger
+    /// (Rule 702.26d).
     pub fn phase_out(&mut self, id: ObjectId) {
+        if !self.phase_out_one(id) {
+            return;
+        }
+        self.phase_out_attachments(id);
+    }
+
+    fn phase_out_attachments(&mut self, id: ObjectId) {
+        let attachments = self
+            .object(id)
+            .map(|object| object.attachments.clone())
+            .unwrap_or_default();
+        for attachment in attachments {
+            if self.phase_out_one(attachment) {
+                self.phased_out_indirectly.insert(attachment);
+                self.phase_out_attachments(attachment);
+            }
+        }
+    }
+
+    fn phase_out_one(&mut self, id: ObjectId) -> bool {
+        if self.phased_out.contains(&id)
+            || self
+                .object(id)
+                .is_none_or(|object| object.zone != Zone::Battlefield)
+        {
+            return false;
+        }
         self.phased_out.insert(id);
+        self.battlefield.retain(|&other| other != id);
+        if let Some(owner) = self.object(id).map(|object| object.owner) {
+            self.owner_zone_index.remove(owner, Zone::Battlefield, id);
+        }
+        true
     }
 
-    /// Phase in a permanent.
+    /// Phase in a permanent, along with anything that phased out with it.
     pub fn phase_in(&mut self, id: ObjectId) {
-        self.phased_out.remove(&id);
+        if !self.phased_out.remove(&id) {
+            return;
+        }
+        self.phased_out_indirectly.remove(&id);
+        if let Some(owner) = self
+            .object(id)
+            .filter(|object| object.zone == Zone::Battlefield)
+            .map(|object| object.owner)
+        {
+            self.battlefield.push(id);
+            self.owner_zone_index.insert(owner, Zone::Battlefield, id);
+        }
+        let attachments = self
+            .object(id)
+            .map(|object| object.attachments.clone())
+            .unwrap_or_default();
+        for attachment in attachments {
+            if self.phased_out_indirectly.contains(&attachment) {
+                self.phase_in(attachment);
+            }
+        }
     }
 
     /// Check if a card is exiled via madness.
@@ -4573,7 +5424,9 @@ impl GameState {
         self.renowned.remove(&id);
         self.flipped.remove(&id);
         self.face_down.remove(&id);
+        self.transformed_front_faces.remove(&id);
         self.phased_out.remove(&id);
+        self.phased_out_indirectly.remove(&id);
         self.imprinted_cards.remove(&id);
         self.chosen_colors.remove(&id);
         self.chosen_basic_land_types.remove(&id);
@@ -4916,6 +5769,8 @@ impl GameState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::Rng;
+    use rand::seq::IndexedRandom;
 
     #[test]
     fn shuffle_slice_marks_irreversible_random_usage() {
@@ -4931,4 +5786,349 @@ mod tests {
             "gameplay shuffles should mark the action chain as irreversible"
         );
     }
+
+    #[test]
+    fn face_down_permanents_are_visible_only_to_their_controller() {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let card = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Bear")
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+            .build();
+        let morph = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        let in_hand = game.create_object_from_card(&card, alice, Zone::Hand);
+        let in_library = game.create_object_from_card(&card, alice, Zone::Library);
+
+        assert!(game.can_see_object(morph, None));
+        game.set_face_down(morph);
+        assert!(game.can_see_object(morph, Some(alice)));
+        assert!(!game.can_see_object(morph, Some(bob)));
+        assert!(!game.can_see_object(morph, None));
+
+        assert!(game.can_see_object(in_hand, Some(alice)));
+        assert!(!game.can_see_object(in_hand, Some(bob)));
+        assert!(!game.can_see_object(in_library, Some(alice)));
+    }
+
+    #[test]
+    fn bounced_and_recast_creature_keeps_its_stable_id() {
+        use crate::events::zones::{MovedObject, ZoneChangeEvent};
+
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let card = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Bear")
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+            .build();
+        let on_battlefield = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        let stable_id = game.object(on_battlefield).unwrap().stable_id;
+        game.take_pending_trigger_events();
+
+        let in_hand = game.move_object(on_battlefield, Zone::Hand).unwrap();
+        let on_stack = game.move_object(in_hand, Zone::Stack).unwrap();
+        let recast = game.move_object(on_stack, Zone::Battlefield).unwrap();
+
+        let ids = [on_battlefield, in_hand, on_stack, recast];
+        assert!(ids.windows(2).all(|pair| pair[0] != pair[1]));
+        assert_eq!(
+            game.find_by_stable_id(stable_id).map(|obj| obj.id),
+            Some(recast)
+        );
+        assert_eq!(game.object(recast).unwrap().stable_id, stable_id);
+
+        let moves: Vec<MovedObject> = game
+            .take_pending_trigger_events()
+            .iter()
+            .filter_map(|event| event.downcast::<ZoneChangeEvent>())
+            .flat_map(|zc| zc.moved.clone())
+            .collect();
+        let expected: Vec<MovedObject> = ids
+            .windows(2)
+            .map(|pair| MovedObject {
+                old_id: pair[0],
+                new_id: pair[1],
+                stable_id,
+            })
+            .collect();
+        assert_eq!(moves, expected);
+    }
+
+    #[test]
+    fn concede_in_multiplayer_removes_conceding_players_objects() {
+        let mut game = GameState::new(
+            vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+            20,
+        );
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let card = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Bear")
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+            .build();
+        let bobs_bear = game.create_object_from_card(&card, bob, Zone::Battlefield);
+        let alices_bear = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        let stolen_bear = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        game.continuous_effects
+            .add_effect(crate::continuous::ContinuousEffect::new(
+                bobs_bear,
+                bob,
+                crate::continuous::EffectTarget::Specific(stolen_bear),
+                crate::continuous::Modification::ChangeController(bob),
+            ));
+        let borrowed_bear = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        game.object_mut(borrowed_bear).unwrap().controller = bob;
+        game.turn.active_player = bob;
+        game.turn.priority_player = Some(bob);
+        game.combat = Some(crate::combat_state::CombatState {
+            attackers: vec![crate::combat_state::AttackerInfo {
+                creature: alices_bear,
+                target: crate::combat_state::AttackTarget::Player(bob),
+            }],
+            ..Default::default()
+        });
+
+        game.concede(bob);
+
+        assert!(game.player(bob).unwrap().has_lost);
+        assert!(game.object(bobs_bear).is_none());
+        assert!(game.object(alices_bear).is_some());
+        assert_eq!(game.game_result(), None);
+        // The steal ends with Bob, so Alice gets her creature back.
+        assert!(game.battlefield.contains(&stolen_bear));
+        assert_eq!(
+            game.calculated_characteristics(stolen_bear)
+                .unwrap()
+                .controller,
+            alice
+        );
+        // What Bob still controls without an effect is exiled.
+        assert!(!game.battlefield.contains(&borrowed_bear));
+        assert_eq!(game.exile.len(), 1);
+        assert!(game.combat.as_ref().unwrap().attackers.is_empty());
+        assert_eq!(game.turn.priority_player, Some(PlayerId::from_index(2)));
+    }
+
+    #[test]
+    fn scoped_cant_effects_from_multiple_sources_stack() {
+        let creature = ObjectId::from_raw(1);
+        let spell = ObjectId::from_raw(2);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let carol = PlayerId::from_index(2);
+
+        let mut from_bob = CantEffectTracker::default();
+        from_bob
+            .cant_attack_players
+            .entry(creature)
+            .or_default()
+            .insert(bob);
+        from_bob
+            .cant_be_countered_by
+            .entry(spell)
+            .or_default()
+            .insert(bob);
+        let mut from_carol = CantEffectTracker::default();
+        from_carol
+            .cant_attack_players
+            .entry(creature)
+            .or_default()
+            .insert(carol);
+        from_carol
+            .cant_attack_planeswalkers_of
+            .entry(creature)
+            .or_default()
+            .insert(carol);
+
+        let mut tracker = CantEffectTracker::default();
+        tracker.merge(from_bob);
+        tracker.merge(from_carol);
+
+        assert!(!tracker.can_attack_player(creature, bob));
+        assert!(!tracker.can_attack_player(creature, carol));
+        assert!(tracker.can_attack_player(creature, alice));
+        assert!(tracker.can_attack_planeswalkers_of(creature, bob));
+        assert!(!tracker.can_attack_planeswalkers_of(creature, carol));
+        assert!(!tracker.can_be_countered_by(spell, bob));
+        assert!(tracker.can_be_countered_by(spell, alice));
+
+        // A blanket restriction still wins over scoped ones.
+        tracker.cant_attack.insert(creature);
+        assert!(!tracker.can_attack_player(creature, alice));
+        tracker.cant_be_countered.insert(spell);
+        assert!(!tracker.can_be_countered_by(spell, alice));
+    }
+
+    #[test]
+    fn turn_trackers_record_departures_and_reset_on_next_turn() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let card = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Bear")
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+            .build();
+        let dying = game.create_object_from_card(&card, alice, Zone::Battlefield);
+        let bounced = game.create_object_from_card(&card, alice, Zone::Battlefield);
+
+        game.move_object(dying, Zone::Graveyard);
+        game.move_object(bounced, Zone::Hand);
+        game.turn_trackers.record_attack(alice);
+        game.turn_trackers.record_life_gained(alice, 3);
+
+        assert_eq!(game.turn_trackers.creatures_died, 1);
+        assert_eq!(
+            game.turn_trackers.permanents_left_battlefield_under(alice),
+            2
+        );
+        assert!(game.turn_trackers.players_attacked.contains(&alice));
+        assert_eq!(game.turn_trackers.life_gained_by(alice), 3);
+
+        game.next_turn();
+
+        assert_eq!(game.turn_trackers.creatures_died, 0);
+        assert_eq!(
+            game.turn_trackers.permanents_left_battlefield_under(alice),
+            0
+        );
+        assert!(game.turn_trackers.players_attacked.is_empty());
+        assert_eq!(game.turn_trackers.life_gained_by(alice), 0);
+    }
+
+    fn bear() -> crate::card::Card {
+        crate::card::CardBuilder::new(crate::ids::CardId::from_raw(1), "Bear")
+            .card_types(vec![crate::types::CardType::Creature])
+            .power_toughness(crate::card::PowerToughness::fixed(2, 2))
+            .build()
+    }
+
+    #[test]
+    fn objects_added_with_existing_ids_are_never_reallocated() {
+        let alice = PlayerId::from_index(0);
+        let mut saved = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        for zone in [Zone::Battlefield, Zone::Hand, Zone::Graveyard] {
+            saved.create_object_from_card(&bear(), alice, zone);
+        }
+        let bear_id = saved.battlefield[0];
+        saved.move_object(bear_id, Zone::Exile);
+
+        // Rebuild the saved objects with their original IDs in a fresh game.
+        let mut restored = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let mut saved_objects: Vec<Object> = saved.objects_iter().cloned().collect();
+        saved_objects.sort_by_key(|obj| obj.id);
+        let saved_ids: HashSet<ObjectId> = saved_objects.iter().map(|obj| obj.id).collect();
+        for object in saved_objects {
+            restored.add_object(object);
+        }
+
+        for _ in 0..5 {
+            let id = restored.create_object_from_card(&bear(), alice, Zone::Battlefield);
+            assert!(
+                !saved_ids.contains(&id),
+                "{id:?} collides with a restored object"
+            );
+        }
+        let moved = restored.move_object(restored.battlefield[0], Zone::Graveyard);
+        assert!(moved.is_some_and(|id| !saved_ids.contains(&id)));
+        let fresh = restored.new_object_id();
+        assert!(!saved_ids.contains(&fresh));
+        assert!(restored.object(fresh).is_none());
+    }
+
+    #[test]
+    fn identical_games_allocate_identical_ids() {
+        fn play() -> Vec<ObjectId> {
+            let alice = PlayerId::from_index(0);
+            let bob = PlayerId::from_index(1);
+            let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+            let mut ids = Vec::new();
+            for owner in [alice, bob] {
+                ids.push(game.create_object_from_card(&bear(), owner, Zone::Hand));
+                ids.push(game.create_object_from_card(&bear(), owner, Zone::Battlefield));
+            }
+            let checkpoint = game.clone();
+            ids.extend(game.move_object(ids[1], Zone::Graveyard));
+            ids.extend(game.move_object(ids[0], Zone::Battlefield));
+            ids.push(game.new_object_id());
+
+            // A restored checkpoint continues from the same point.
+            let mut replay = checkpoint;
+            assert_eq!(replay.move_object(ids[1], Zone::Graveyard), Some(ids[4]));
+            ids
+        }
+
+        assert_eq!(play(), play());
+    }
+
+    #[test]
+    fn objects_in_matches_a_full_scan_after_random_zone_changes() {
+        let mut game = GameState::new(
+            vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+            20,
+        );
+        let card = bear();
+        let zones = [
+            Zone::Library,
+            Zone::Hand,
+            Zone::Battlefield,
+            Zone::Graveyard,
+            Zone::Exile,
+            Zone::Command,
+        ];
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+
+        for step in 0..500 {
+            let live: Vec<ObjectId> = game.objects_iter().map(|obj| obj.id).collect();
+            let pick = |rng: &mut StdRng| live.choose(rng).copied();
+            match rng.random_range(0..6) {
+                0 | 1 => {
+                    let owner = PlayerId::from_index(rng.random_range(0..3));
+                    let zone = *zones.choose(&mut rng).unwrap();
+                    let id = game.create_object_from_card(&card, owner, zone);
+                    if rng.random_bool(0.3) {
+                        game.object_mut(id).unwrap().kind = ObjectKind::Token;
+                    }
+                }
+                2 => {
+                    if let Some(id) = pick(&mut rng) {
+                        game.move_object(id, *zones.choose(&mut rng).unwrap());
+                    }
+                }
+                3 => {
+                    // Tokens that left the battlefield cease to exist.
+                    if let Some(id) = pick(&mut rng) {
+                        game.remove_object(id);
+                    }
+                }
+                4 => {
+                    if let Some(id) = pick(&mut rng) {
+                        game.phase_out(id);
+                    }
+                }
+                _ => {
+                    if let Some(id) = pick(&mut rng) {
+                        game.phase_in(id);
+                    }
+                }
+            }
+
+            for player in &game.players {
+                for zone in zones {
+                    let mut indexed: Vec<ObjectId> = game.objects_in(zone, player.id).collect();
+                    let mut scanned: Vec<ObjectId> = game
+                        .objects_iter()
+                        .filter(|obj| obj.zone == zone && obj.owner == player.id)
+                        .filter(|obj| !game.is_phased_out(obj.id))
+                        .map(|obj| obj.id)
+                        .collect();
+                    indexed.sort();
+                    scanned.sort();
+                    assert_eq!(
+                        indexed, scanned,
+                        "{}'s {} diverged from a full scan at step {step}",
+                        player.name, zone
+                    );
+                }
+            }
+        }
+    }
 }
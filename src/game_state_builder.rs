@@ -0,0 +1,523 @@
+//! Fluent builder for setting up game states from card names.
+//!
+//! Tests (and tools that need a specific board) usually want "Alice has two
+//! Grizzly Bears and a tapped Forest, Bob has Lightning Bolt in hand". Rather
+//! than hand-rolling `create_object_from_definition` calls, describe each zone
+//! with short entries and let the builder look the cards up in the registry:
+//!
+//! ```ignore
+//! let (game, objects) = GameStateBuilder::new()
+//!     .player("Alice")
+//!     .battlefield(["Grizzly Bears*2", "Forest tapped", "Lightning Greaves -> Grizzly Bears"])
+//!     .player("Bob")
+//!     .hand(["Lightning Bolt"])
+//!     .life(15)
+//!     .build()?;
+//! let bears = objects.ids("Grizzly Bears");
+//! ```
+//!
+//! Entry syntax is `<card name>[*<count>] [tapped] [<counter>:<amount>]... [-> <attach target>]`:
+//! - `*N` creates N copies of the card
+//! - `tapped` taps battlefield permanents
+//! - `<counter>:<amount>` adds counters, e.g. `+1/+1:2` or `loyalty:3`
+//! - `-> <name>` attaches the permanent to the first object created with that
+//!   name; `-> none` leaves it unattached
+
+use std::collections::HashMap;
+
+use crate::cards::{CardDefinition, CardRegistry};
+use crate::game_state::{GameState, Phase, Step};
+use crate::ids::{ObjectId, PlayerId};
+use crate::object::CounterType;
+use crate::zone::Zone;
+
+/// Errors that can occur while building a game state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameStateBuilderError {
+    /// A zone or life total was given before any player was declared.
+    NoPlayer,
+    /// No card with this name is known to the registry.
+    UnknownCard(String),
+    /// A player name that wasn't declared with `player`.
+    UnknownPlayer(String),
+    /// An attachment names an object that wasn't created.
+    UnknownAttachTarget(String),
+    /// An entry couldn't be parsed.
+    InvalidEntry(String),
+}
+
+impl std::fmt::Display for GameStateBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameStateBuilderError::NoPlayer => write!(f, "No player declared"),
+            GameStateBuilderError::UnknownCard(name) => write!(f, "Unknown card: {name}"),
+            GameStateBuilderError::UnknownPlayer(name) => write!(f, "Unknown player: {name}"),
+            GameStateBuilderError::UnknownAttachTarget(name) => {
+                write!(f, "Unknown attach target: {name}")
+            }
+            GameStateBuilderError::InvalidEntry(entry) => write!(f, "Invalid entry: {entry}"),
+        }
+    }
+}
+
+impl std::error::Error for GameStateBuilderError {}
+
+/// Names of the players and objects created by a [`GameStateBuilder`].
+#[derive(Debug, Clone, Default)]
+pub struct GameObjects {
+    players: HashMap<String, PlayerId>,
+    objects: HashMap<String, Vec<ObjectId>>,
+}
+
+impl GameObjects {
+    /// The player declared with this name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such player was declared.
+    pub fn player(&self, name: &str) -> PlayerId {
+        *self
+            .players
+            .get(name)
+            .unwrap_or_else(|| panic!("no player named {name}"))
+    }
+
+    /// The first object created with this card name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no object with this name was created.
+    pub fn id(&self, name: &str) -> ObjectId {
+        self.get(name)
+            .unwrap_or_else(|| panic!("no object named {name}"))
+    }
+
+    /// The first object created with this card name, if any.
+    pub fn get(&self, name: &str) -> Option<ObjectId> {
+        self.ids(name).first().copied()
+    }
+
+    /// Every object created with this card name, in creation order.
+    pub fn ids(&self, name: &str) -> &[ObjectId] {
+        self.objects.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// One parsed zone entry.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    name: String,
+    count: usize,
+    tapped: bool,
+    counters: Vec<(CounterType, u32)>,
+    attach_to: Option<String>,
+}
+
+impl Entry {
+    fn parse(entry: &str) -> Result<Self, GameStateBuilderError> {
+        let invalid = || GameStateBuilderError::InvalidEntry(entry.to_string());
+
+        let (card, attach_to) = match entry.split_once("->") {
+            Some((card, target)) => {
+                let target = target.trim();
+                if target.is_empty() {
+                    return Err(invalid());
+                }
+                let attach_to = (!target.eq_ignore_ascii_case("none")).then(|| target.to_string());
+                (card, attach_to)
+            }
+            None => (entry, None),
+        };
+
+        // Annotations trail the card name, so peel them off from the end.
+        let mut words: Vec<&str> = card.split_whitespace().collect();
+        let mut tapped = false;
+        let mut counters = Vec::new();
+        while let Some(&last) = words.last() {
+            if last.eq_ignore_ascii_case("tapped") {
+                tapped = true;
+            } else if let Some((counter, amount)) = last.split_once(':') {
+                let counter_type =
+                    crate::cards::builders::parse_counter_type_word(&counter.to_ascii_lowercase())
+                        .ok_or_else(invalid)?;
+                let amount = amount.parse::<u32>().map_err(|_| invalid())?;
+                counters.push((counter_type, amount));
+            } else {
+                break;
+            }
+            words.pop();
+        }
+        counters.reverse();
+
+        let name = words.join(" ");
+        let (name, count) = match name.rsplit_once('*') {
+            Some((name, count)) => (
+                name.trim().to_string(),
+                count.trim().parse::<usize>().map_err(|_| invalid())?,
+            ),
+            None => (name, 1),
+        };
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            name,
+            count,
+            tapped,
+            counters,
+            attach_to,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PlayerSetup {
+    name: String,
+    life: Option<i32>,
+    zones: Vec<(Zone, String)>,
+}
+
+/// Builder for a [`GameState`] with cards already in place.
+///
+/// Players are declared with [`player`](Self::player); zone and life calls
+/// apply to the most recently declared player. Cards are looked up by name in
+/// the built-in card registry unless a definition was supplied with
+/// [`card`](Self::card). By default the game starts in the first main phase
+/// of the first player's turn with that player holding priority.
+#[derive(Debug, Clone)]
+pub struct GameStateBuilder {
+    players: Vec<PlayerSetup>,
+    definitions: Vec<CardDefinition>,
+    starting_life: i32,
+    active_player: Option<String>,
+    priority_player: Option<Option<String>>,
+    phase: Phase,
+    step: Option<Step>,
+    turn_number: u32,
+    missing_player: bool,
+}
+
+impl Default for GameStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameStateBuilder {
+    /// Create a builder with no players.
+    pub fn new() -> Self {
+        Self {
+            players: Vec::new(),
+            definitions: Vec::new(),
+            starting_life: 20,
+            active_player: None,
+            priority_player: None,
+            phase: Phase::FirstMain,
+            step: None,
+            turn_number: 1,
+            missing_player: false,
+        }
+    }
+
+    /// Declare a player. Following zone and life calls apply to this player.
+    pub fn player(mut self, name: impl Into<String>) -> Self {
+        self.players.push(PlayerSetup {
+            name: name.into(),
+            life: None,
+            zones: Vec::new(),
+        });
+        self
+    }
+
+    /// Set the life total every player starts with (20 by default).
+    pub fn starting_life(mut self, life: i32) -> Self {
+        self.starting_life = life;
+        self
+    }
+
+    /// Set the current player's life total.
+    pub fn life(mut self, life: i32) -> Self {
+        match self.players.last_mut() {
+            Some(player) => player.life = Some(life),
+            None => self.missing_player = true,
+        }
+        self
+    }
+
+    /// Put cards onto the battlefield under the current player's control.
+    pub fn battlefield<S: Into<String>>(self, entries: impl IntoIterator<Item = S>) -> Self {
+        self.zone(Zone::Battlefield, entries)
+    }
+
+    /// Put cards into the current player's hand.
+    pub fn hand<S: Into<String>>(self, entries: impl IntoIterator<Item = S>) -> Self {
+        self.zone(Zone::Hand, entries)
+    }
+
+    /// Put cards into the current player's graveyard.
+    pub fn graveyard<S: Into<String>>(self, entries: impl IntoIterator<Item = S>) -> Self {
+        self.zone(Zone::Graveyard, entries)
+    }
+
+    /// Put cards into the current player's library, listed from the top down.
+    pub fn library<S: Into<String>>(self, entries: impl IntoIterator<Item = S>) -> Self {
+        self.zone(Zone::Library, entries)
+    }
+
+    /// Put cards into exile, owned by the current player.
+    pub fn exile<S: Into<String>>(self, entries: impl IntoIterator<Item = S>) -> Self {
+        self.zone(Zone::Exile, entries)
+    }
+
+    /// Put cards into an arbitrary zone for the current player.
+    pub fn zone<S: Into<String>>(
+        mut self,
+        zone: Zone,
+        entries: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let Some(player) = self.players.last_mut() else {
+            self.missing_player = true;
+            return self;
+        };
+        let mut entries: Vec<(Zone, String)> = entries
+            .into_iter()
+            .map(|entry| (zone, entry.into()))
+            .collect();
+        if zone == Zone::Library {
+            // The last card added to a library is its top card.
+            entries.reverse();
+        }
+        player.zones.extend(entries);
+        self
+    }
+
+    /// Make a card definition available by name, taking precedence over the registry.
+    pub fn card(mut self, definition: CardDefinition) -> Self {
+        self.definitions.push(definition);
+        self
+    }
+
+    /// Set whose turn it is (the first player by default).
+    pub fn active_player(mut self, name: impl Into<String>) -> Self {
+        self.active_player = Some(name.into());
+        self
+    }
+
+    /// Set which player holds priority (the active player by default).
+    pub fn priority(mut self, name: impl Into<String>) -> Self {
+        self.priority_player = Some(Some(name.into()));
+        self
+    }
+
+    /// Leave no player holding priority.
+    pub fn no_priority(mut self) -> Self {
+        self.priority_player = Some(None);
+        self
+    }
+
+    /// Move to the first step of a phase.
+    pub fn phase(mut self, phase: Phase) -> Self {
+        self.phase = phase;
+        self.step = crate::turn::first_step_of_phase(phase);
+        self
+    }
+
+    /// Move to a step, along with the phase it belongs to.
+    pub fn step(mut self, step: Step) -> Self {
+        self.phase = match step {
+            Step::Untap | Step::Upkeep | Step::Draw => Phase::Beginning,
+            Step::BeginCombat
+            | Step::DeclareAttackers
+            | Step::DeclareBlockers
+            | Step::CombatDamage
+            | Step::EndCombat => Phase::Combat,
+            Step::End | Step::Cleanup => Phase::Ending,
+        };
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the turn number.
+    pub fn turn_number(mut self, turn_number: u32) -> Self {
+        self.turn_number = turn_number;
+        self
+    }
+
+    /// Build the game, returning it with the created players and objects by name.
+    pub fn build(self) -> Result<(GameState, GameObjects), GameStateBuilderError> {
+        if self.missing_player || self.players.is_empty() {
+            return Err(GameStateBuilderError::NoPlayer);
+        }
+
+        let mut entries = Vec::new();
+        for (index, player) in self.players.iter().enumerate() {
+            for (zone, entry) in &player.zones {
+                entries.push((
+                    PlayerId::from_index(index as u8),
+                    *zone,
+                    Entry::parse(entry)?,
+                ));
+            }
+        }
+
+        let mut registry = CardRegistry::new();
+        registry.ensure_cards_loaded(entries.iter().map(|(_, _, entry)| entry.name.as_str()));
+        for definition in self.definitions {
+            registry.register(definition);
+        }
+
+        let mut game = GameState::new(
+            self.players
+                .iter()
+                .map(|player| player.name.clone())
+                .collect(),
+            self.starting_life,
+        );
+        let mut objects = GameObjects::default();
+        for (index, player) in self.players.iter().enumerate() {
+            let player_id = PlayerId::from_index(index as u8);
+            objects.players.insert(player.name.clone(), player_id);
+            if let Some(life) = player.life
+                && let Some(state) = game.player_mut(player_id)
+            {
+                state.life = life;
+            }
+        }
+
+        let mut attachments = Vec::new();
+        for (owner, zone, entry) in &entries {
+            let definition = registry
+                .get(&entry.name)
+                .ok_or_else(|| GameStateBuilderError::UnknownCard(entry.name.clone()))?;
+            for _ in 0..entry.count {
+                let id = game.create_object_from_definition(definition, *owner, *zone);
+                if let Some(object) = game.object_mut(id) {
+                    for (counter, amount) in &entry.counters {
+                        object.add_counters(*counter, *amount);
+                    }
+                }
+                if entry.tapped && *zone == Zone::Battlefield {
+                    game.tap(id);
+                }
+                if let Some(target) = &entry.attach_to {
+                    attachments.push((id, target.clone()));
+                }
+                objects
+                    .objects
+                    .entry(entry.name.clone())
+                    .or_default()
+                    .push(id);
+            }
+        }
+
+        for (attachment, target_name) in attachments {
+            let target = objects
+                .get(&target_name)
+                .ok_or_else(|| GameStateBuilderError::UnknownAttachTarget(target_name.clone()))?;
+            if let Some(object) = game.object_mut(attachment) {
+                object.attached_to = Some(target);
+            }
+            if let Some(object) = game.object_mut(target)
+                && !object.attachments.contains(&attachment)
+            {
+                object.attachments.push(attachment);
+            }
+            game.continuous_effects.record_attachment(attachment);
+        }
+
+        let active_player = match &self.active_player {
+            Some(name) => find_player(&objects, name)?,
+            None => PlayerId::from_index(0),
+        };
+        let priority_player = match &self.priority_player {
+            Some(Some(name)) => Some(find_player(&objects, name)?),
+            Some(None) => None,
+            None => Some(active_player),
+        };
+        game.turn.active_player = active_player;
+        game.turn.priority_player = priority_player;
+        game.turn.phase = self.phase;
+        game.turn.step = self.step;
+        game.turn.turn_number = self.turn_number;
+
+        Ok((game, objects))
+    }
+}
+
+fn find_player(objects: &GameObjects, name: &str) -> Result<PlayerId, GameStateBuilderError> {
+    objects
+        .players
+        .get(name)
+        .copied()
+        .ok_or_else(|| GameStateBuilderError::UnknownPlayer(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_counts_and_annotations() {
+        assert_eq!(
+            Entry::parse("Walking Ballista*2 tapped +1/+1:3 -> Grizzly Bears").unwrap(),
+            Entry {
+                name: "Walking Ballista".to_string(),
+                count: 2,
+                tapped: true,
+                counters: vec![(CounterType::PlusOnePlusOne, 3)],
+                attach_to: Some("Grizzly Bears".to_string()),
+            }
+        );
+        assert_eq!(Entry::parse("Crusade -> none").unwrap().attach_to, None);
+        assert!(matches!(
+            Entry::parse("Grizzly Bears*lots"),
+            Err(GameStateBuilderError::InvalidEntry(_))
+        ));
+    }
+
+    #[test]
+    fn builds_zones_life_and_turn_position() {
+        let (game, objects) = GameStateBuilder::new()
+            .player("Alice")
+            .battlefield(["Grizzly Bears*2", "Forest tapped"])
+            .library(["Lightning Bolt", "Forest"])
+            .player("Bob")
+            .hand(["Lightning Bolt"])
+            .life(15)
+            .step(Step::DeclareAttackers)
+            .priority("Bob")
+            .build()
+            .expect("game should build");
+
+        let alice = objects.player("Alice");
+        let bob = objects.player("Bob");
+        assert_eq!(objects.ids("Grizzly Bears").len(), 2);
+        assert!(game.is_tapped(objects.id("Forest")));
+        assert!(!game.is_tapped(objects.id("Grizzly Bears")));
+        assert_eq!(
+            game.player(bob).unwrap().hand,
+            vec![objects.ids("Lightning Bolt")[1]]
+        );
+        let library = &game.player(alice).unwrap().library;
+        assert_eq!(library.last(), Some(&objects.ids("Lightning Bolt")[0]));
+        assert_eq!(game.player(alice).unwrap().life, 20);
+        assert_eq!(game.player(bob).unwrap().life, 15);
+        assert_eq!(game.turn.phase, Phase::Combat);
+        assert_eq!(game.turn.step, Some(Step::DeclareAttackers));
+        assert_eq!(game.turn.active_player, alice);
+        assert_eq!(game.turn.priority_player, Some(bob));
+    }
+
+    #[test]
+    fn unknown_cards_are_reported() {
+        let result = GameStateBuilder::new()
+            .player("Alice")
+            .hand(["Definitely Not A Card"])
+            .build();
+        assert!(matches!(
+            result,
+            Err(GameStateBuilderError::UnknownCard(name)) if name == "Definitely Not A Card"
+        ));
+    }
+}
@@ -170,9 +170,15 @@ impl GrantSpec {
 
     /// Create a grant spec for flash to noncreature spells in hand.
     pub fn flash_to_noncreature_spells() -> Self {
+        Self::flash_to_spells(ObjectFilter::noncreature_spell())
+    }
+
+    /// Create a grant spec for flash to matching spells in hand
+    /// ("You may cast creature spells as though they had flash").
+    pub fn flash_to_spells(filter: ObjectFilter) -> Self {
         Self {
             grantable: Grantable::Ability(StaticAbility::flash()),
-            filter: ObjectFilter::noncreature_spell(),
+            filter,
             zone: Zone::Hand,
         }
     }
@@ -254,6 +260,25 @@ impl GrantSpec {
                 "Each {filter_desc} has escape. The escape cost is equal to the card's mana cost plus exile {count_text} other cards from {graveyard}"
             );
         }
+        if let Grantable::Ability(ability) = &self.grantable
+            && ability.has_flash()
+            && self.zone == Zone::Hand
+        {
+            let spells = if self.filter == ObjectFilter::noncreature_spell() {
+                Some("noncreature spells".to_string())
+            } else if self.filter == ObjectFilter::nonland() {
+                Some("spells".to_string())
+            } else if let [card_type] = self.filter.card_types.as_slice()
+                && self.filter == ObjectFilter::default().with_type(*card_type)
+            {
+                Some(format!("{} spells", card_type.name()))
+            } else {
+                None
+            };
+            if let Some(spells) = spells {
+                return format!("You may cast {spells} as though they had flash");
+            }
+        }
         format!(
             "Cards in {} have {}",
             zone_name(self.zone),
@@ -301,6 +326,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grant_spec_flash_to_spells_display() {
+        assert_eq!(
+            GrantSpec::flash_to_noncreature_spells().display(),
+            "You may cast noncreature spells as though they had flash"
+        );
+        assert_eq!(
+            GrantSpec::flash_to_spells(ObjectFilter::nonland()).display(),
+            "You may cast spells as though they had flash"
+        );
+        assert_eq!(
+            GrantSpec::flash_to_spells(ObjectFilter::default().with_type(CardType::Creature))
+                .display(),
+            "You may cast creature spells as though they had flash"
+        );
+    }
+
     #[test]
     fn test_grant_spec_escape_to_nonland() {
         let spec = GrantSpec::escape_to_nonland(3);
@@ -1,27 +1,22 @@
-use std::sync::atomic::{AtomicU8, AtomicU32, AtomicU64, Ordering};
-
-/// Global counter for auto-incrementing player IDs.
-static PLAYER_ID_COUNTER: AtomicU8 = AtomicU8::new(0);
-/// Global counter for auto-incrementing object IDs (starts at 1, 0 is reserved).
-static OBJECT_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
-/// Global counter for auto-incrementing card definition IDs (starts at 1, 0 is reserved).
-static CARD_ID_COUNTER: AtomicU32 = AtomicU32::new(1);
-
-/// Snapshot of global ID counters so deterministic replays can restore identity space.
-#[derive(Debug, Clone, Copy)]
-pub struct IdCountersSnapshot {
-    pub player: u8,
-    pub object: u64,
-    pub card: u32,
-}
-
 /// Player identifier, index-based for efficiency.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct PlayerId(pub u8);
 
-/// Unique object identifier, monotonically increasing.
+/// Unique object identifier, monotonically increasing within a game.
 /// Never reused - when an object changes zones, it gets a new ID per MTG rule 400.7.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// IDs are allocated by [`GameState::new_object_id`](crate::GameState::new_object_id),
+/// so two games built from the same inputs number their objects identically.
+/// The default ID, 0, is reserved and never allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct ObjectId(pub u64);
 
 /// Stable object instance identifier used across zone changes.
@@ -33,11 +28,6 @@ pub struct StableId(pub ObjectId);
 pub struct CardId(pub u32);
 
 impl PlayerId {
-    /// Create a new player ID with auto-incrementing counter.
-    pub fn new() -> Self {
-        Self(PLAYER_ID_COUNTER.fetch_add(1, Ordering::SeqCst))
-    }
-
     /// Create a player ID from a specific index (for when you need explicit control).
     pub fn from_index(index: u8) -> Self {
         Self(index)
@@ -48,30 +38,13 @@ impl PlayerId {
     }
 }
 
-impl Default for PlayerId {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl ObjectId {
-    /// Create a new object ID with auto-incrementing counter.
-    pub fn new() -> Self {
-        Self(OBJECT_ID_COUNTER.fetch_add(1, Ordering::SeqCst))
-    }
-
     /// Create an object ID from a specific value (for when you need explicit control).
     pub fn from_raw(id: u64) -> Self {
         Self(id)
     }
 }
 
-impl Default for ObjectId {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl StableId {
     /// Create a stable ID from an object ID.
     pub fn from_object_id(id: ObjectId) -> Self {
@@ -102,9 +75,24 @@ impl From<StableId> for ObjectId {
 }
 
 impl CardId {
-    /// Create a new card ID with auto-incrementing counter.
+    /// Placeholder ID for a new card definition.
+    ///
+    /// `CardBuilder` replaces it with [`CardId::from_name`], so a definition's
+    /// ID depends only on its name and every game agrees on it.
     pub fn new() -> Self {
-        Self(CARD_ID_COUNTER.fetch_add(1, Ordering::SeqCst))
+        Self::default()
+    }
+
+    /// The ID of the card definition with this name.
+    ///
+    /// A 32-bit FNV-1a hash of the name, skipping the reserved ID 0.
+    pub fn from_name(name: &str) -> Self {
+        let mut hash: u32 = 0x811c_9dc5;
+        for byte in name.bytes() {
+            hash ^= u32::from(byte);
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+        Self(hash.max(1))
     }
 
     /// Create a card ID from a specific value (for when you need explicit control).
@@ -113,55 +101,10 @@ impl CardId {
     }
 }
 
-/// Capture current global ID counters.
-pub fn snapshot_id_counters() -> IdCountersSnapshot {
-    IdCountersSnapshot {
-        player: PLAYER_ID_COUNTER.load(Ordering::SeqCst),
-        object: OBJECT_ID_COUNTER.load(Ordering::SeqCst),
-        card: CARD_ID_COUNTER.load(Ordering::SeqCst),
-    }
-}
-
-/// Restore global ID counters from a snapshot.
-pub fn restore_id_counters(snapshot: IdCountersSnapshot) {
-    PLAYER_ID_COUNTER.store(snapshot.player, Ordering::SeqCst);
-    OBJECT_ID_COUNTER.store(snapshot.object, Ordering::SeqCst);
-    CARD_ID_COUNTER.store(snapshot.card, Ordering::SeqCst);
-}
-
-/// Reset runtime player/object counters while preserving card definition identity space.
-///
-/// This is used when starting a fresh match so gameplay object IDs are stable across
-/// deterministic replays and synchronized peers, without invalidating any card
-/// definitions that have already been loaded into the registry.
-pub fn reset_runtime_id_counters() {
-    PLAYER_ID_COUNTER.store(0, Ordering::SeqCst);
-    OBJECT_ID_COUNTER.store(1, Ordering::SeqCst);
-}
-
-/// Reset all ID counters to their initial state (for testing).
-/// This should only be used in tests to ensure deterministic behavior.
-#[cfg(test)]
-pub fn reset_id_counters() {
-    PLAYER_ID_COUNTER.store(0, Ordering::SeqCst);
-    OBJECT_ID_COUNTER.store(1, Ordering::SeqCst);
-    CARD_ID_COUNTER.store(1, Ordering::SeqCst);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_player_id_auto_increment() {
-        reset_id_counters();
-        let p1 = PlayerId::new();
-        let p2 = PlayerId::new();
-        assert_ne!(p1, p2);
-        assert_eq!(p1.index(), 0);
-        assert_eq!(p2.index(), 1);
-    }
-
     #[test]
     fn test_player_id_from_index() {
         let p1 = PlayerId::from_index(5);
@@ -170,14 +113,6 @@ mod tests {
         assert_eq!(p2.index(), 10);
     }
 
-    #[test]
-    fn test_object_id_auto_increment() {
-        // Object IDs auto-increment, just verify they're different
-        let o1 = ObjectId::new();
-        let o2 = ObjectId::new();
-        assert_ne!(o1, o2);
-    }
-
     #[test]
     fn test_object_id_from_raw() {
         let o1 = ObjectId::from_raw(100);
@@ -188,11 +123,18 @@ mod tests {
     }
 
     #[test]
-    fn test_card_id_auto_increment() {
-        // Card IDs auto-increment, just verify they're different
-        let c1 = CardId::new();
-        let c2 = CardId::new();
-        assert_ne!(c1, c2);
+    fn test_card_id_from_name() {
+        assert_eq!(CardId::from_name("Forest"), CardId::from_name("Forest"));
+        assert_ne!(CardId::from_name("Forest"), CardId::from_name("Island"));
+        assert_ne!(CardId::from_name(""), CardId::default());
+    }
+
+    #[test]
+    fn test_card_builder_derives_id_from_name() {
+        let forest = crate::card::CardBuilder::new(CardId::new(), "Forest").build();
+        assert_eq!(forest.id, CardId::from_name("Forest"));
+        let raw = crate::card::CardBuilder::new(CardId::from_raw(7), "Forest").build();
+        assert_eq!(raw.id, CardId::from_raw(7));
     }
 
     #[test]
@@ -0,0 +1,228 @@
+//! Reuse of computed legal actions between identical priority passes.
+//!
+//! Computing legal actions checks every card in hand and every ability on the
+//! battlefield against the rules, which is expensive on large boards. When
+//! priority returns to a player and nothing has changed, the previous result
+//! is still correct. Entries are keyed on [`GameState::state_version`] (bumped
+//! by every `GameState` method that changes objects, players, or the stack)
+//! together with a hash of the contents of the public fields that feed legal
+//! actions, so direct writes to those fields also invalidate the cache.
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::decision::{LegalAction, compute_legal_actions};
+use crate::game_state::GameState;
+use crate::ids::PlayerId;
+use crate::trigger_identity::hash_debug;
+
+/// Legal actions per player, valid while the game state is unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct LegalActionCache {
+    entries: HashMap<PlayerId, CachedLegalActions>,
+    hits: u64,
+    misses: u64,
+    disabled: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CachedLegalActions {
+    inputs: LegalActionInputs,
+    actions: Vec<LegalAction>,
+}
+
+/// Everything the cached actions were computed from.
+///
+/// Ward, protection, and cost reductions come from objects and continuous
+/// effects, so they are covered by the state version and the effect
+/// manager's id and timestamp counters. The remaining inputs are public on
+/// `GameState` and can change without going through a method, so their
+/// contents are hashed: a write that keeps a collection the same size (say,
+/// tapping one permanent and untapping another) still changes the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LegalActionInputs {
+    version: u64,
+    contents: u64,
+}
+
+impl LegalActionInputs {
+    fn capture(game: &GameState) -> Self {
+        let mut hasher = DefaultHasher::new();
+        // Debug output covers the inputs that don't implement `Hash`; writing
+        // into a hasher can't fail.
+        let _ = hash_debug(&mut hasher, &game.turn);
+        for player in &game.players {
+            player.life.hash(&mut hasher);
+            let _ = hash_debug(&mut hasher, &player.mana_pool);
+            let _ = hash_debug(&mut hasher, &player.restricted_mana);
+            player.energy_counters.hash(&mut hasher);
+            player.lands_played_this_turn.hash(&mut hasher);
+            player.land_plays_per_turn.hash(&mut hasher);
+            player.is_in_game().hash(&mut hasher);
+            player.hand.hash(&mut hasher);
+            player.library.hash(&mut hasher);
+            player.graveyard.hash(&mut hasher);
+        }
+        let _ = hash_debug(&mut hasher, &game.stack);
+        game.battlefield.hash(&mut hasher);
+        game.exile.hash(&mut hasher);
+        game.command_zone.hash(&mut hasher);
+        hash_unordered(&mut hasher, &game.tapped_permanents);
+        hash_unordered(&mut hasher, &game.summoning_sick);
+        hash_unordered(&mut hasher, &game.face_down);
+        hash_unordered(&mut hasher, &game.phased_out);
+        let _ = hash_debug(&mut hasher, &game.combat);
+        game.continuous_effects.next_id().hash(&mut hasher);
+        game.continuous_effects
+            .current_timestamp()
+            .hash(&mut hasher);
+        let _ = hash_debug(
+            &mut hasher,
+            &game.continuous_effects.static_ability_effects(),
+        );
+        let _ = hash_debug(&mut hasher, &game.grant_registry.grants);
+        let _ = hash_debug(&mut hasher, &game.restriction_effects);
+        hash_unordered(&mut hasher, &game.cost_reductions_used_this_turn);
+        hash_unordered(&mut hasher, &game.activated_abilities_this_turn);
+        game.spells_cast_this_turn_total.hash(&mut hasher);
+
+        Self {
+            version: game.state_version(),
+            contents: hasher.finish(),
+        }
+    }
+}
+
+/// Hash a set or map independently of its iteration order.
+fn hash_unordered<T: Hash>(hasher: &mut impl Hasher, items: impl IntoIterator<Item = T>) {
+    let mut len = 0usize;
+    let mut combined = 0u64;
+    for item in items {
+        let mut item_hasher = DefaultHasher::new();
+        item.hash(&mut item_hasher);
+        combined = combined.wrapping_add(item_hasher.finish());
+        len += 1;
+    }
+    len.hash(hasher);
+    combined.hash(hasher);
+}
+
+impl LegalActionCache {
+    /// Turn caching on or off. While off, every lookup recomputes.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+        if !enabled {
+            self.entries.clear();
+        }
+    }
+
+    /// How many lookups reused cached actions.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// How many lookups had to compute actions.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Cached actions for `player`, if they were computed from the current state.
+    pub fn get(&self, game: &GameState, player: PlayerId) -> Option<&[LegalAction]> {
+        if self.disabled {
+            return None;
+        }
+        let cached = self.entries.get(&player)?;
+        (cached.inputs == LegalActionInputs::capture(game)).then_some(cached.actions.as_slice())
+    }
+}
+
+/// Legal actions for `player`, reusing the previous result when the game
+/// state has not changed since it was computed.
+pub fn compute_legal_actions_cached(game: &mut GameState, player: PlayerId) -> Vec<LegalAction> {
+    let mut cache = std::mem::take(game.legal_action_cache_mut());
+    let actions = if let Some(actions) = cache.get(game, player).map(<[_]>::to_vec) {
+        cache.hits += 1;
+        actions
+    } else {
+        cache.misses += 1;
+        let actions = compute_legal_actions(game, player);
+        if !cache.disabled {
+            cache.entries.insert(
+                player,
+                CachedLegalActions {
+                    inputs: LegalActionInputs::capture(game),
+                    actions: actions.clone(),
+                },
+            );
+        }
+        actions
+    };
+    *game.legal_action_cache_mut() = cache;
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::definitions::{basic_forest, grizzly_bears};
+    use crate::mana::ManaSymbol;
+    use crate::zone::Zone;
+
+    fn game_with_bears_in_hand() -> (GameState, PlayerId) {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        game.turn.phase = crate::game_state::Phase::FirstMain;
+        game.turn.step = None;
+        game.create_object_from_definition(&basic_forest(), alice, Zone::Battlefield);
+        game.create_object_from_definition(&grizzly_bears(), alice, Zone::Hand);
+        (game, alice)
+    }
+
+    #[test]
+    fn repeated_computation_without_changes_hits_cache() {
+        let (mut game, alice) = game_with_bears_in_hand();
+
+        let first = compute_legal_actions_cached(&mut game, alice);
+        let second = compute_legal_actions_cached(&mut game, alice);
+        assert_eq!(first, second);
+        assert_eq!(first, compute_legal_actions(&game, alice));
+        assert_eq!(game.legal_action_cache().misses(), 1);
+        assert_eq!(game.legal_action_cache().hits(), 1);
+    }
+
+    #[test]
+    fn direct_field_writes_and_object_changes_invalidate() {
+        let (mut game, alice) = game_with_bears_in_hand();
+        let before = compute_legal_actions_cached(&mut game, alice);
+
+        game.players[0].mana_pool.add(ManaSymbol::Green, 1);
+        let with_mana = compute_legal_actions_cached(&mut game, alice);
+        assert_eq!(with_mana, compute_legal_actions(&game, alice));
+        assert_ne!(before, with_mana);
+
+        let forest = game.battlefield[0];
+        game.object_mut(forest).expect("forest exists");
+        compute_legal_actions_cached(&mut game, alice);
+        assert_eq!(game.legal_action_cache().misses(), 3);
+        assert_eq!(game.legal_action_cache().hits(), 0);
+    }
+
+    #[test]
+    fn same_size_direct_writes_invalidate() {
+        let (mut game, alice) = game_with_bears_in_hand();
+        let second_forest =
+            game.create_object_from_definition(&basic_forest(), alice, Zone::Battlefield);
+        let first_forest = game.battlefield[0];
+        game.tapped_permanents.insert(first_forest);
+        let before = compute_legal_actions_cached(&mut game, alice);
+
+        // Swap which forest is tapped without going through tap/untap.
+        game.tapped_permanents.remove(&first_forest);
+        game.tapped_permanents.insert(second_forest);
+        let after = compute_legal_actions_cached(&mut game, alice);
+        assert_eq!(after, compute_legal_actions(&game, alice));
+        assert_ne!(before, after);
+        assert_eq!(game.legal_action_cache().misses(), 2);
+        assert_eq!(game.legal_action_cache().hits(), 0);
+    }
+}
@@ -13,9 +13,12 @@ pub mod decision;
 pub mod decisions;
 pub mod dependency;
 pub(crate) mod derived_view;
+pub mod display_text;
 pub mod effect;
 mod effect_text_shared;
 pub mod effects;
+pub mod engine_handle;
+pub mod error_dump;
 pub mod event_processor;
 pub mod events;
 pub mod executor;
@@ -23,11 +26,14 @@ pub mod filter;
 pub mod game_event;
 pub mod game_loop;
 pub mod game_state;
+pub mod game_state_builder;
 pub mod grant;
 pub mod grant_registry;
 pub mod ids;
+pub mod legal_action_cache;
 pub mod mana;
 pub mod marker;
+pub mod match_play;
 #[cfg(feature = "net")]
 pub mod net;
 pub mod object;
@@ -37,8 +43,11 @@ pub mod prevention;
 pub mod provenance;
 pub mod replacement;
 pub mod replacement_ability_processor;
+#[cfg(feature = "serialization")]
+pub mod replay;
 pub mod rules;
 pub mod semantic_compare;
+pub mod shared;
 pub mod snapshot;
 pub mod special_actions;
 pub mod static_abilities;
@@ -54,6 +63,7 @@ pub mod types;
 #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
 pub mod wasm_api;
 pub mod zone;
+pub(crate) mod zone_index;
 
 #[cfg(test)]
 mod tests;
@@ -85,6 +95,7 @@ pub use event_processor::{
     process_event_with_chosen_replacement_trait,
     process_life_gain_with_event,
     process_put_counters_with_event,
+    process_put_counters_with_event_and_dm,
     // Event-based processing functions
     process_trait_event,
     process_zone_change_full,
@@ -96,8 +107,10 @@ pub use filter::{
 };
 pub use game_event::{DamageTarget as GameEventDamageTarget, ObjectSnapshot};
 pub use game_state::{CantEffectTracker, GameState, Phase, StackEntry, Step, Target, TurnState};
+pub use game_state_builder::{GameObjects, GameStateBuilder, GameStateBuilderError};
 pub use ids::{CardId, ObjectId, PlayerId};
 pub use mana::{ManaCost, ManaSymbol};
+pub use match_play::Match;
 pub use object::{CounterType, Object, ObjectKind};
 pub use player::{ManaPool, Player};
 pub use prevention::{
@@ -121,12 +134,12 @@ pub use combat_state::{
     AttackTarget, AttackerInfo, CombatError, CombatState, attackers_targeting_planeswalker,
     attackers_targeting_player, declare_attackers, declare_blockers, end_combat, get_attack_target,
     get_blocked_attacker, get_blockers, get_damage_assignment_order, is_attacking, is_blocked,
-    is_blocking, is_unblocked, new_combat, set_damage_assignment_order,
+    is_blocking, is_unblocked, new_combat, remove_from_combat, set_damage_assignment_order,
 };
 pub use decision::{
-    AttackerDeclaration, AttackerOption, AutoPassDecisionMaker, BlockerDeclaration, BlockerOption,
-    ChoiceOption, DecisionMaker, DecisionRouter, GameProgress, GameResult, LegalAction,
-    ManaPaymentOption, ManaPipPaymentAction, ManaPipPaymentOption, ModeOption,
+    AttackShortcut, AttackerDeclaration, AttackerOption, AutoPassDecisionMaker, BlockerDeclaration,
+    BlockerOption, ChoiceOption, DecisionMaker, DecisionRouter, GameProgress, GameResult,
+    LegalAction, ManaPaymentOption, ManaPipPaymentAction, ManaPipPaymentOption, ModeOption,
     NumericInputDecisionMaker, OptionalCostOption, ReplacementOption, ResponseError,
     TargetRequirement, compute_legal_actions, compute_legal_attackers, compute_legal_blockers,
 };
@@ -136,15 +149,17 @@ pub use decisions::context::{
     NumberContext, OrderContext, PartitionContext, PriorityContext, ProliferateContext,
     SelectObjectsContext, SelectOptionsContext, SelectableObject, SelectableOption,
 };
+pub use display_text::{DisplayArg, DisplayKey, DisplayRenderer, DisplayText, EnglishRenderer};
 pub use executor::{
     ExecutionContext, ExecutionError, ResolvedTarget, TargetError, execute_effect, resolve_value,
     validate_target,
 };
 pub use game_loop::{
-    CombatDamageEvent, GameLoopError, PriorityLoopState, PriorityResponse, advance_priority,
-    apply_attacker_declarations, apply_blocker_declarations, apply_priority_response,
-    check_and_apply_sbas, compute_legal_targets, execute_combat_damage_step, execute_turn_with,
-    extract_target_spec, generate_and_queue_step_triggers, get_declare_attackers_decision,
+    CombatDamageEvent, GameClock, GameLoopError, PriorityLoopState, PriorityResponse,
+    advance_priority, apply_attacker_declarations, apply_blocker_declarations,
+    apply_priority_response, check_and_apply_sbas, compute_legal_targets,
+    execute_combat_damage_step, execute_turn_with, expand_attack_shortcut, extract_target_spec,
+    generate_and_queue_step_triggers, get_declare_attackers_decision,
     get_declare_blockers_decision, put_triggers_on_stack, queue_combat_damage_triggers,
     requires_target_selection, resolve_stack_entry, run_priority_loop_with,
     spell_has_legal_targets,
@@ -178,6 +193,7 @@ pub use turn_runner::{TurnAction, TurnRunner, TurnState as TurnRunnerState};
 pub use events::{
     // Matchers
     CombatDamageMatcher,
+    CreateTokensEvent,
     // Event types
     DamageEvent,
     DamageFromSourceMatcher,
@@ -195,6 +211,7 @@ pub use events::{
     GameEventType,
     LifeGainEvent,
     LifeLossEvent,
+    MillEvent,
     MoveCountersEvent,
     NoncombatDamageMatcher,
     PutCountersEvent,
@@ -213,10 +230,12 @@ pub use events::{
     WouldBeSacrificedMatcher,
     WouldBecomeTappedMatcher,
     WouldBecomeUntappedMatcher,
+    WouldCreateTokensMatcher,
     WouldDieMatcher,
     WouldDiscardMatcher,
     WouldDrawCardMatcher,
     WouldDrawFirstCardMatcher,
+    WouldDrawFromEmptyLibraryMatcher,
     WouldEnterBattlefieldMatcher,
     WouldGainLifeMatcher,
     WouldGoToGraveyardMatcher,
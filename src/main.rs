@@ -10,6 +10,7 @@
 //! Options:
 //!   --hand "Card1 | Card2 | ..."   Specify starting hand (can be repeated for each player)
 //!   --deck "Card1 | Card2 | ..."   Specify deck contents (can be repeated for each player)
+//!   --best-of N                    Play a best-of-N match with the same decks
 //! ```
 //!
 //! The first --hand/--deck is for Alice, the second for Bob.
@@ -17,12 +18,19 @@
 
 use maigus::cards::CardDefinitionBuilder;
 use maigus::cards::builders::CardTextError;
-use maigus::decision::{CliDecisionMaker, DecisionRouter, init_input_manager, read_input};
+use maigus::decision::{
+    CliDecisionMaker, DecisionMaker, DecisionRouter, init_scripted_input, read_input,
+};
+use maigus::decisions::PriorityPolicy;
 use maigus::ids::CardId;
+use maigus::replay::{
+    RecordingDecisionMaker, ReplayDecisionMaker, ReplayError, ReplayLog, ReplayWriter,
+    legacy_input_lines,
+};
 use maigus::triggers::TriggerQueue;
 use maigus::{
-    CardDefinition, CardRegistry, CombatState, GameState, ManaSymbol, PlayerId, Zone,
-    execute_turn_with,
+    CardDefinition, CardRegistry, CombatState, GameResult, GameState, ManaSymbol, Match, PlayerId,
+    Zone, execute_turn_with,
 };
 use rand::seq::SliceRandom;
 use serde::Deserialize;
@@ -140,12 +148,15 @@ fn build_random_deck(registry: &CardRegistry) -> Vec<CardDefinition> {
 }
 
 /// Run a full game between two players, optionally skipping initial draws.
+///
+/// Returns the game's result, or `None` if the game was cut short by an error
+/// or the turn limit.
 fn run_game_with_custom_hands(
     game: &mut GameState,
     player1_has_custom_hand: bool,
     player2_has_custom_hand: bool,
-) {
-    let mut decision_maker = DecisionRouter::new(Box::new(CliDecisionMaker));
+    decision_maker: &mut DecisionRouter,
+) -> Option<GameResult> {
     let mut combat = CombatState::default();
     let mut trigger_queue = TriggerQueue::new();
 
@@ -171,40 +182,22 @@ fn run_game_with_custom_hands(
         turn_count += 1;
 
         // Check for game over
-        let remaining: Vec<_> = game.players.iter().filter(|p| p.is_in_game()).collect();
-
-        if remaining.len() <= 1 {
-            if let Some(winner) = remaining.first() {
-                println!("\n========================================");
-                println!("  {} WINS!", winner.name);
-                println!("========================================");
-            } else {
-                println!("\n========================================");
-                println!("  DRAW!");
-                println!("========================================");
-            }
-            return;
+        if let Some(result) = game.game_result() {
+            print_game_result(game, &result);
+            return Some(result);
         }
 
         // Run a turn
-        if let Err(e) =
-            execute_turn_with(game, &mut combat, &mut trigger_queue, &mut decision_maker)
-        {
+        if let Err(e) = execute_turn_with(game, &mut combat, &mut trigger_queue, decision_maker) {
             match e {
                 maigus::GameLoopError::GameOver => {
-                    // Check who won
-                    for player in &game.players {
-                        if player.is_in_game() {
-                            println!("\n========================================");
-                            println!("  {} WINS!", player.name);
-                            println!("========================================");
-                            return;
-                        }
-                    }
+                    let result = game.game_result()?;
+                    print_game_result(game, &result);
+                    return Some(result);
                 }
                 _ => {
                     println!("Error during game: {}", e);
-                    return;
+                    return None;
                 }
             }
         }
@@ -224,6 +217,59 @@ fn run_game_with_custom_hands(
     }
 
     println!("Game ended due to turn limit.");
+    None
+}
+
+fn print_game_result(game: &GameState, result: &GameResult) {
+    println!("\n========================================");
+    match result {
+        GameResult::Winner(winner) => {
+            let name = game.player(*winner).map_or("Unknown", |p| p.name.as_str());
+            println!("  {} WINS!", name);
+        }
+        GameResult::Draw | GameResult::Remaining(_) => println!("  DRAW!"),
+    }
+    println!("========================================");
+}
+
+/// Run a best-of-N match with the given decks, reshuffling them between games.
+fn run_match(
+    best_of: u32,
+    deck1: Vec<CardDefinition>,
+    deck2: Vec<CardDefinition>,
+    decision_maker: &mut DecisionRouter,
+) {
+    let mut best_of_n = Match::new(
+        best_of,
+        vec!["Alice".to_string(), "Bob".to_string()],
+        vec![deck1, deck2],
+    );
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let mut game_number = 0;
+    let winner = best_of_n.play_with(|game| {
+        game_number += 1;
+        let chooser = game.turn.active_player;
+        let chooser_name = game.player(chooser).map_or("Unknown", |p| p.name.as_str());
+        println!(
+            "\nGame {} - {} chooses to play first",
+            game_number, chooser_name
+        );
+        run_game_with_custom_hands(game, false, false, decision_maker).unwrap_or(GameResult::Draw)
+    });
+
+    println!("\n========================================");
+    println!(
+        "  MATCH: Alice {} - {} Bob",
+        best_of_n.wins(alice),
+        best_of_n.wins(bob)
+    );
+    match winner.and_then(|winner| best_of_n.player_names.get(winner.index())) {
+        Some(name) => println!("  {} WINS THE MATCH!", name),
+        None => println!("  No match winner."),
+    }
+    println!("========================================");
 }
 
 /// Command-line arguments for custom hands/decks.
@@ -240,12 +286,18 @@ struct GameArgs {
     exiles: Vec<Vec<String>>,
     /// Commanders for each player (index 0 = Alice, etc.)
     commanders: Vec<Vec<String>>,
-    /// File to record inputs to (for creating replay tests)
+    /// File to record decisions to
     record_file: Option<String>,
-    /// File to replay inputs from (for automated testing)
+    /// File to replay decisions from
     replay_file: Option<String>,
+    /// Raw-input replay to re-record in the current format
+    convert_replay: Option<String>,
     /// Whether to generate random decks/hands for players without custom ones
     random: bool,
+    /// Whether to auto-pass priority when a player can only pass
+    auto_pass: bool,
+    /// Number of games in the match (1 for a single game)
+    best_of: u32,
     /// Cards to generate definitions from oracle text (meta mode)
     meta_cards: Vec<String>,
 }
@@ -413,7 +465,10 @@ fn parse_args() -> GameArgs {
     let mut commanders: Vec<Vec<String>> = Vec::new();
     let mut record_file: Option<String> = None;
     let mut replay_file: Option<String> = None;
+    let mut convert_replay: Option<String> = None;
     let mut random: bool = false;
+    let mut auto_pass: bool = false;
+    let mut best_of: u32 = 1;
     let mut meta_cards: Vec<String> = Vec::new();
 
     let mut i = 1;
@@ -437,6 +492,15 @@ fn parse_args() -> GameArgs {
                     i += 1;
                 }
             }
+            "--convert-replay" => {
+                if i + 1 < args.len() {
+                    convert_replay = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --convert-replay requires a file path");
+                    i += 1;
+                }
+            }
             "--hand" => {
                 if i + 1 < args.len() {
                     let cards = parse_card_arg(&args[i + 1]);
@@ -511,6 +575,17 @@ fn parse_args() -> GameArgs {
                 random = true;
                 i += 1;
             }
+            "--auto-pass" => {
+                auto_pass = true;
+                i += 1;
+            }
+            "--best-of" => {
+                match args.get(i + 1).map(|value| value.parse::<u32>()) {
+                    Some(Ok(value)) if value > 0 => best_of = value,
+                    _ => eprintln!("Error: --best-of requires a positive number"),
+                }
+                i += 2;
+            }
             "--help" | "-h" => {
                 println!("Maigus - MTG Rules Engine");
                 println!();
@@ -539,17 +614,29 @@ fn parse_args() -> GameArgs {
                     "  --meta \"Card1 | Card2 | ...\"       Print generated definitions from oracle text"
                 );
                 println!(
-                    "  --record <file>                      Record inputs to file for replay tests"
+                    "  --record <file>                      Record decisions to file for replay"
+                );
+                println!(
+                    "                                      (default: replays/####.jsonl when not using --replay)"
                 );
                 println!(
-                    "                                      (default: replays/####.txt when not using --replay)"
+                    "  --replay <file>                      Replay recorded decisions, then continue interactively"
                 );
                 println!(
-                    "  --replay <file>                      Replay inputs from file (automated testing)"
+                    "  --convert-replay <file>              Re-record a raw-input replay in the current format"
+                );
+                println!(
+                    "                                      (pass the setup options it was recorded with)"
                 );
                 println!(
                     "  --random                             Generate random decks/hands for unspecified players"
                 );
+                println!(
+                    "  --auto-pass                          Skip priority when passing is the only real option"
+                );
+                println!(
+                    "  --best-of <n>                        Play a best-of-n match, reshuffling decks between games"
+                );
                 println!("  --help, -h                           Show this help message");
                 println!();
                 println!("The first instance of each option is for Alice, the second for Bob.");
@@ -572,7 +659,10 @@ fn parse_args() -> GameArgs {
         commanders,
         record_file,
         replay_file,
+        convert_replay,
         random,
+        auto_pass,
+        best_of,
         meta_cards,
     }
 }
@@ -612,10 +702,70 @@ fn default_replay_path() -> Option<String> {
     }
 
     let next_id = max_id.saturating_add(1);
-    let file_name = format!("{:04}.txt", next_id);
+    let file_name = format!("{:04}.jsonl", next_id);
     Some(dir.join(file_name).to_string_lossy().into_owned())
 }
 
+/// Feed a raw-input replay to the CLI prompts so the decisions it makes are
+/// recorded in the current format.
+fn load_legacy_replay(path: &str) {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Failed to open replay file '{}': {}", path, e);
+        std::process::exit(1);
+    });
+    if !matches!(ReplayLog::parse(&text), Err(ReplayError::LegacyFormat)) {
+        eprintln!("'{}' is not a raw-input replay; nothing to convert", path);
+        std::process::exit(1);
+    }
+    init_scripted_input(legacy_input_lines(&text));
+}
+
+/// Build the CLI decision maker, recording to or replaying from a file.
+fn build_decision_maker(
+    record_file: Option<&str>,
+    replay_file: Option<&str>,
+    auto_pass: bool,
+) -> DecisionRouter {
+    let cli = CliDecisionMaker::default();
+    let default: Box<dyn DecisionMaker> = if let Some(path) = replay_file {
+        let log = ReplayLog::load(path).unwrap_or_else(|e| {
+            eprintln!("Failed to load replay file '{}': {}", path, e);
+            std::process::exit(1);
+        });
+        println!("Replaying {} decisions from: {}\n", log.entries.len(), path);
+        Box::new(ReplayDecisionMaker::new(log).with_fallback(Box::new(cli)))
+    } else if let Some(path) = record_file {
+        let writer = ReplayWriter::create(path).unwrap_or_else(|e| {
+            eprintln!("Failed to create record file '{}': {}", path, e);
+            std::process::exit(1);
+        });
+        println!("Recording replay to: {}\n", path);
+        Box::new(RecordingDecisionMaker::new(cli, writer))
+    } else {
+        Box::new(cli)
+    };
+
+    let mut decision_maker = DecisionRouter::new(default);
+    if auto_pass {
+        for index in 0..2 {
+            decision_maker.set_priority_policy(
+                PlayerId::from_index(index),
+                PriorityPolicy::default().with_auto_pass_without_actions(true),
+            );
+        }
+    }
+    decision_maker
+}
+
+/// Pause before the game starts. Replays skip the pause, since only
+/// decisions are recorded.
+fn wait_for_enter(args: &GameArgs) {
+    if args.replay_file.is_none() {
+        println!("Press Enter to continue...");
+        let _ = read_input().unwrap_or_default();
+    }
+}
+
 fn main() {
     let args = parse_args();
 
@@ -633,18 +783,19 @@ fn main() {
         args.record_file.clone()
     };
 
-    // Initialize input manager for recording/replay
-    init_input_manager(record_file.as_deref(), args.replay_file.as_deref());
+    if let Some(path) = args.convert_replay.as_deref() {
+        load_legacy_replay(path);
+    }
 
     println!("========================================");
     println!("   Maigus - MTG Rules Engine");
     println!("========================================\n");
 
-    if let Some(path) = record_file.as_deref()
-        && args.replay_file.is_none()
-    {
-        println!("Recording replay to: {}\n", path);
-    }
+    let mut decision_maker = build_decision_maker(
+        record_file.as_deref(),
+        args.replay_file.as_deref(),
+        args.auto_pass,
+    );
 
     // Create the card registry
     let registry = CardRegistry::with_builtin_cards();
@@ -693,6 +844,13 @@ fn main() {
         println!("  {}x {}", count, name);
     }
 
+    if args.best_of > 1 {
+        println!("\nStarting best-of-{} match...", args.best_of);
+        wait_for_enter(&args);
+        run_match(args.best_of, deck1, deck2, &mut decision_maker);
+        return;
+    }
+
     // Create the game
     let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
 
@@ -851,9 +1009,13 @@ fn main() {
     }
 
     println!("\nStarting game...");
-    println!("Press Enter to continue...");
-    let _ = read_input().unwrap_or_default();
+    wait_for_enter(&args);
 
     // Run the game (pass whether players have custom hands to skip drawing)
-    run_game_with_custom_hands(&mut game, !hand1.is_empty(), !hand2.is_empty());
+    run_game_with_custom_hands(
+        &mut game,
+        !hand1.is_empty(),
+        !hand2.is_empty(),
+        &mut decision_maker,
+    );
 }
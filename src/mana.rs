@@ -2,6 +2,10 @@ use crate::color::Color;
 
 /// Atomic mana payment options.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum ManaSymbol {
     /// White mana {W}
     White,
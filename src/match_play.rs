@@ -0,0 +1,189 @@
+//! Best-of-N match play.
+//!
+//! A [`Match`] runs a series of two-player games with the same decks. Each
+//! game starts from a freshly shuffled copy of every deck (there's no
+//! sideboarding), and the player who chooses to go first alternates from game
+//! to game. The match ends once a player has won a majority of the games.
+
+use crate::cards::CardDefinition;
+use crate::decision::GameResult;
+use crate::game_state::{GameState, Phase, Step};
+use crate::ids::PlayerId;
+use crate::zone::Zone;
+
+/// A best-of-N match between two players.
+#[derive(Debug, Clone)]
+pub struct Match {
+    /// Maximum number of decisive games (e.g. 3 for best of three).
+    pub best_of: u32,
+    /// Player names, in seat order.
+    pub player_names: Vec<String>,
+    /// Each player's deck, in seat order.
+    pub decks: Vec<Vec<CardDefinition>>,
+    /// Starting life total for every game.
+    pub starting_life: i32,
+    /// Results of the games played so far.
+    pub results: Vec<GameResult>,
+    /// The player who chooses whether to go first in the next game.
+    chooser: PlayerId,
+}
+
+impl Match {
+    /// Create a best-of-`best_of` match. The first seat chooses for game one.
+    pub fn new(best_of: u32, player_names: Vec<String>, decks: Vec<Vec<CardDefinition>>) -> Self {
+        Self {
+            best_of: best_of.max(1),
+            player_names,
+            decks,
+            starting_life: 20,
+            results: Vec::new(),
+            chooser: PlayerId::from_index(0),
+        }
+    }
+
+    /// Set the starting life total used for every game.
+    pub fn with_starting_life(mut self, starting_life: i32) -> Self {
+        self.starting_life = starting_life;
+        self
+    }
+
+    /// Number of games a player needs to win the match.
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Number of games the player has won so far.
+    pub fn wins(&self, player: PlayerId) -> u32 {
+        self.results
+            .iter()
+            .filter(|result| matches!(result, GameResult::Winner(winner) if *winner == player))
+            .count() as u32
+    }
+
+    /// The match winner, once a player has won enough games.
+    pub fn winner(&self) -> Option<PlayerId> {
+        (0..self.player_names.len())
+            .map(|index| PlayerId::from_index(index as u8))
+            .find(|&player| self.wins(player) >= self.wins_needed())
+    }
+
+    /// Whether the match is decided.
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// The player who chooses whether to go first in the next game.
+    pub fn chooser(&self) -> PlayerId {
+        self.chooser
+    }
+
+    /// Build the next game: fresh state, freshly shuffled libraries, and the
+    /// chooser taking the first turn. Opening hands aren't drawn.
+    pub fn next_game(&self) -> GameState {
+        let mut game = GameState::new(self.player_names.clone(), self.starting_life);
+        for (index, deck) in self.decks.iter().enumerate() {
+            let player = PlayerId::from_index(index as u8);
+            for card in deck {
+                game.create_object_from_definition(card, player, Zone::Library);
+            }
+            game.shuffle_player_library(player);
+        }
+
+        game.turn.active_player = self.chooser;
+        game.turn.priority_player = Some(self.chooser);
+        game.turn.phase = Phase::Beginning;
+        game.turn.step = Some(Step::Untap);
+        game
+    }
+
+    /// Record the result of a finished game and pass the choice of who goes
+    /// first to the other player.
+    pub fn record_result(&mut self, result: GameResult) {
+        self.results.push(result);
+        let next = (self.chooser.index() + 1) % self.player_names.len().max(1);
+        self.chooser = PlayerId::from_index(next as u8);
+    }
+
+    /// Play games with `play_game` until the match is decided, returning the
+    /// match winner.
+    ///
+    /// `play_game` runs one game to completion and reports its result. Draws
+    /// don't count toward the match, so at most `best_of * 2` games are played
+    /// before giving up without a winner.
+    pub fn play_with(
+        &mut self,
+        mut play_game: impl FnMut(&mut GameState) -> GameResult,
+    ) -> Option<PlayerId> {
+        let max_games = self.best_of.saturating_mul(2) as usize;
+        while !self.is_over() && self.results.len() < max_games {
+            let mut game = self.next_game();
+            let result = play_game(&mut game);
+            self.record_result(result);
+        }
+        self.winner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::definitions::{basic_forest, grizzly_bears};
+
+    fn deck() -> Vec<CardDefinition> {
+        let mut deck = vec![basic_forest(); 5];
+        deck.extend(vec![grizzly_bears(); 5]);
+        deck
+    }
+
+    fn best_of_three() -> Match {
+        Match::new(
+            3,
+            vec!["Alice".to_string(), "Bob".to_string()],
+            vec![deck(), deck()],
+        )
+    }
+
+    #[test]
+    fn match_reports_two_one_after_three_scripted_games() {
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let mut scripted = vec![bob, alice, alice].into_iter();
+        let mut first_players = Vec::new();
+
+        let mut best_of_three = best_of_three();
+        let winner = best_of_three.play_with(|game| {
+            first_players.push(game.turn.active_player);
+            assert_eq!(game.player(alice).unwrap().library.len(), 10);
+            assert_eq!(game.player(bob).unwrap().library.len(), 10);
+            let loser = if scripted.next() == Some(alice) {
+                bob
+            } else {
+                alice
+            };
+            game.concede(loser);
+            game.game_result()
+                .expect("conceding ends a two-player game")
+        });
+
+        assert_eq!(winner, Some(alice));
+        assert_eq!(best_of_three.wins(alice), 2);
+        assert_eq!(best_of_three.wins(bob), 1);
+        assert_eq!(best_of_three.results.len(), 3);
+        assert_eq!(first_players, vec![alice, bob, alice]);
+    }
+
+    #[test]
+    fn match_stops_once_a_player_wins_a_majority() {
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let mut best_of_three = best_of_three();
+        let winner = best_of_three.play_with(|game| {
+            game.concede(bob);
+            game.game_result().unwrap()
+        });
+
+        assert_eq!(winner, Some(alice));
+        assert_eq!(best_of_three.results.len(), 2);
+    }
+}
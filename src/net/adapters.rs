@@ -1,17 +1,58 @@
 use crate::cost::{OptionalCost, OptionalCostsPaid};
 use crate::decision::ManaPipPaymentAction;
+use crate::decisions::{PriorityPolicy, StopConditions};
 use crate::game_state::Target;
 use crate::mana::ManaSymbol;
 
 use super::{
     CostPayment, CostSpec, CostStep, GameObjectId, GamePlayerId, ManaSymbolCode, ManaSymbolSpec,
-    TargetSpec,
+    PhaseStep, PolicyConditions, TargetSpec,
 };
 
 pub fn targets_from_game(targets: &[Target]) -> Vec<TargetSpec> {
     targets.iter().map(TargetSpec::from).collect()
 }
 
+/// Local priority policy equivalent to a peer's policy token conditions.
+///
+/// The peer passes on an empty stack until `until_phase`, stopping early for
+/// whatever the conditions flag. Explicit `stops` replace the pass-until window.
+pub fn priority_policy_from_conditions(conditions: &PolicyConditions) -> PriorityPolicy {
+    let policy = PriorityPolicy::default().with_conditions(StopConditions::from(conditions));
+    match &conditions.stops {
+        Some(stops) => policy.with_stops(stops.iter().map(|stop| stop.to_phase_step())),
+        None => {
+            let (phase, step) = conditions.until_phase.to_phase_step();
+            policy.with_pass_until(phase, step)
+        }
+    }
+}
+
+/// Policy token conditions that share a local player's stop settings.
+///
+/// Stops are sorted so equal policies always encode (and hash) the same way.
+pub fn policy_conditions_from_priority_policy(
+    policy: &PriorityPolicy,
+    until_phase: PhaseStep,
+) -> PolicyConditions {
+    let stops = policy.stops.as_ref().map(|stops| {
+        let mut stops: Vec<PhaseStep> = stops
+            .iter()
+            .map(|&(phase, step)| PhaseStep::from_phase_step(phase, step))
+            .collect();
+        stops.sort_by_key(|stop| (stop.phase as u8, stop.step.map(|step| step as u8)));
+        stops
+    });
+    PolicyConditions {
+        stop_on_stack_event: policy.conditions.stop_on_stack_event,
+        stop_if_targets_me: policy.conditions.stop_if_targets_me,
+        stop_if_attackers_declared: policy.conditions.stop_if_attackers_declared,
+        stop_if_blockers_declared: policy.conditions.stop_if_blockers_declared,
+        until_phase,
+        stops,
+    }
+}
+
 pub fn mana_symbols_to_spec(symbols: &[ManaSymbol]) -> Vec<ManaSymbolSpec> {
     symbols.iter().map(ManaSymbolSpec::from).collect()
 }
@@ -84,6 +125,17 @@ pub fn cost_spec_from_pip_actions(
     cost_spec_from_steps(steps, optional, x_value)
 }
 
+impl From<&PolicyConditions> for StopConditions {
+    fn from(conditions: &PolicyConditions) -> Self {
+        Self {
+            stop_on_stack_event: conditions.stop_on_stack_event,
+            stop_if_targets_me: conditions.stop_if_targets_me,
+            stop_if_attackers_declared: conditions.stop_if_attackers_declared,
+            stop_if_blockers_declared: conditions.stop_if_blockers_declared,
+        }
+    }
+}
+
 impl From<Target> for TargetSpec {
     fn from(value: Target) -> Self {
         match value {
@@ -175,6 +227,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn policy_conditions_map_to_priority_policy() {
+        use crate::game_state::{Phase, Step};
+        use crate::net::PhaseStep;
+
+        let conditions = PolicyConditions {
+            stop_on_stack_event: false,
+            stop_if_targets_me: true,
+            stop_if_attackers_declared: true,
+            stop_if_blockers_declared: false,
+            until_phase: PhaseStep::from_phase_step(Phase::Ending, Some(Step::End)),
+            stops: None,
+        };
+        let policy = priority_policy_from_conditions(&conditions);
+
+        assert!(policy.conditions.stop_if_targets_me);
+        assert!(policy.conditions.stop_if_attackers_declared);
+        assert!(!policy.conditions.stop_on_stack_event);
+        assert!(!policy.conditions.stop_if_blockers_declared);
+        assert_eq!(policy.pass_until, Some((Phase::Ending, Some(Step::End))));
+    }
+
+    #[test]
+    fn priority_policy_stops_round_trip_through_conditions() {
+        use crate::game_state::{Phase, Step};
+        use crate::net::PhaseStep;
+
+        let policy = PriorityPolicy::default()
+            .with_stops([
+                (Phase::NextMain, None),
+                (Phase::Combat, Some(Step::DeclareBlockers)),
+                (Phase::FirstMain, None),
+            ])
+            .with_conditions(StopConditions {
+                stop_if_targets_me: true,
+                ..StopConditions::default()
+            });
+        let until = PhaseStep::from_phase_step(Phase::Ending, Some(Step::Cleanup));
+        let conditions = policy_conditions_from_priority_policy(&policy, until);
+
+        assert_eq!(
+            conditions.stops,
+            Some(vec![
+                PhaseStep::from_phase_step(Phase::FirstMain, None),
+                PhaseStep::from_phase_step(Phase::Combat, Some(Step::DeclareBlockers)),
+                PhaseStep::from_phase_step(Phase::NextMain, None),
+            ])
+        );
+        assert_eq!(priority_policy_from_conditions(&conditions), policy);
+    }
+
     #[test]
     fn mana_symbol_spec_mapping() {
         let spec = ManaSymbolSpec::from(ManaSymbol::Generic(5));
@@ -712,6 +712,9 @@ impl CanonicalDecode for MsgType {
             8 => Ok(MsgType::ContribShare),
             9 => Ok(MsgType::TimeoutClaim),
             10 => Ok(MsgType::ForfeitCommit),
+            11 => Ok(MsgType::ResyncRequest),
+            12 => Ok(MsgType::ResyncResponse),
+            13 => Ok(MsgType::SpectatorJoin),
             tag => Err(CodecError::InvalidTag(tag)),
         }
     }
@@ -751,6 +754,8 @@ impl CanonicalDecode for TimeoutReason {
             1 => Ok(TimeoutReason::MissingShare),
             2 => Ok(TimeoutReason::MissingShuffleProof),
             3 => Ok(TimeoutReason::MissingPolicyResponse),
+            4 => Ok(TimeoutReason::MissingResync),
+            5 => Ok(TimeoutReason::ClockExpired),
             255 => Ok(TimeoutReason::Other),
             tag => Err(CodecError::InvalidTag(tag)),
         }
@@ -1057,6 +1062,7 @@ impl CanonicalEncode for PolicyConditions {
         self.stop_if_attackers_declared.encode(out);
         self.stop_if_blockers_declared.encode(out);
         self.until_phase.encode(out);
+        self.stops.encode(out);
     }
 }
 
@@ -1068,6 +1074,7 @@ impl CanonicalDecode for PolicyConditions {
             stop_if_attackers_declared: bool::decode(input)?,
             stop_if_blockers_declared: bool::decode(input)?,
             until_phase: PhaseStep::decode(input)?,
+            stops: Option::<Vec<PhaseStep>>::decode(input)?,
         })
     }
 }
@@ -1120,6 +1127,149 @@ impl CanonicalDecode for ForfeitCommit {
     }
 }
 
+impl CanonicalEncode for CommittedAction {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.propose.encode(out);
+        self.commit.encode(out);
+    }
+}
+
+impl CanonicalDecode for CommittedAction {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        Ok(CommittedAction {
+            propose: ActionPropose::decode(input)?,
+            commit: ActionCommit::decode(input)?,
+        })
+    }
+}
+
+impl CanonicalEncode for ResyncRequest {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.request_id.encode(out);
+        self.peer.encode(out);
+        self.last_state_hash.encode(out);
+        self.last_seq.encode(out);
+        self.request_sig.encode(out);
+    }
+}
+
+impl CanonicalDecode for ResyncRequest {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        Ok(ResyncRequest {
+            request_id: Hash32::decode(input)?,
+            peer: PeerId::decode(input)?,
+            last_state_hash: Hash32::decode(input)?,
+            last_seq: u64::decode(input)?,
+            request_sig: Sig64::decode(input)?,
+        })
+    }
+}
+
+impl ResyncRequest {
+    pub fn encode_for_hash(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.peer.encode(&mut out);
+        self.last_state_hash.encode(&mut out);
+        self.last_seq.encode(&mut out);
+        out
+    }
+}
+
+impl CanonicalEncode for SpectatorJoin {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.join_id.encode(out);
+        self.spectator.encode(out);
+        self.session_id.encode(out);
+        self.join_sig.encode(out);
+    }
+}
+
+impl CanonicalDecode for SpectatorJoin {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        Ok(SpectatorJoin {
+            join_id: Hash32::decode(input)?,
+            spectator: PeerId::decode(input)?,
+            session_id: SessionId::decode(input)?,
+            join_sig: Sig64::decode(input)?,
+        })
+    }
+}
+
+impl SpectatorJoin {
+    pub fn encode_for_hash(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.spectator.encode(&mut out);
+        self.session_id.encode(&mut out);
+        out
+    }
+}
+
+impl CanonicalEncode for ResyncPayload {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            ResyncPayload::Log { from_seq, actions } => {
+                write_u8(out, 0);
+                from_seq.encode(out);
+                actions.encode(out);
+            }
+            ResyncPayload::Snapshot { seq, snapshot } => {
+                write_u8(out, 1);
+                seq.encode(out);
+                snapshot.encode(out);
+            }
+        }
+    }
+}
+
+impl CanonicalDecode for ResyncPayload {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        match read_u8(input)? {
+            0 => Ok(ResyncPayload::Log {
+                from_seq: u64::decode(input)?,
+                actions: Vec::<CommittedAction>::decode(input)?,
+            }),
+            1 => Ok(ResyncPayload::Snapshot {
+                seq: u64::decode(input)?,
+                snapshot: Box::new(PublicStateSnapshot::decode(input)?),
+            }),
+            tag => Err(CodecError::InvalidTag(tag)),
+        }
+    }
+}
+
+impl CanonicalEncode for ResyncResponse {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.request_id.encode(out);
+        self.responder.encode(out);
+        self.commitment.encode(out);
+        self.payload.encode(out);
+        self.responder_sig.encode(out);
+    }
+}
+
+impl CanonicalDecode for ResyncResponse {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        Ok(ResyncResponse {
+            request_id: Hash32::decode(input)?,
+            responder: PeerId::decode(input)?,
+            commitment: StateCommitment::decode(input)?,
+            payload: ResyncPayload::decode(input)?,
+            responder_sig: Sig64::decode(input)?,
+        })
+    }
+}
+
+impl ResyncResponse {
+    pub fn encode_for_hash(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.request_id.encode(&mut out);
+        self.responder.encode(&mut out);
+        self.commitment.encode(&mut out);
+        self.payload.encode(&mut out);
+        out
+    }
+}
+
 impl CanonicalEncode for TargetSpec {
     fn encode(&self, out: &mut Vec<u8>) {
         match self {
@@ -1498,6 +1648,16 @@ mod tests {
                     phase: PhaseCode::Ending,
                     step: Some(StepCode::End),
                 },
+                stops: Some(vec![
+                    PhaseStep {
+                        phase: PhaseCode::FirstMain,
+                        step: None,
+                    },
+                    PhaseStep {
+                        phase: PhaseCode::Combat,
+                        step: Some(StepCode::DeclareBlockers),
+                    },
+                ]),
             },
             owner_sig: Sig64([9u8; 64]),
         };
@@ -1554,6 +1714,62 @@ mod tests {
         round_trip(&share);
     }
 
+    #[test]
+    fn resync_response_round_trip() {
+        let response = ResyncResponse {
+            request_id: Hash32([1u8; 32]),
+            responder: PeerId([2u8; 32]),
+            commitment: StateCommitment {
+                version: 1,
+                public_state_hash: Hash32([3u8; 32]),
+                hidden_zones: vec![HiddenZoneCommitment {
+                    zone: ZoneCode::Hand,
+                    owner: GamePlayerId(1),
+                    commitment: ZoneCommitment {
+                        scheme: CommitmentScheme::ElgamalSecp256k1Sha256MerkleV1,
+                        root: Hash32([4u8; 32]),
+                        len: 7,
+                        top: 0,
+                        viewers: vec![PeerId([2u8; 32])],
+                    },
+                }],
+            },
+            payload: ResyncPayload::Log {
+                from_seq: 5,
+                actions: vec![CommittedAction {
+                    propose: ActionPropose {
+                        action_id: Hash32([5u8; 32]),
+                        prev_state_hash: Hash32([6u8; 32]),
+                        action: ActionPayload::DrawCard { count: 1 },
+                        proofs: ProofBundle::default(),
+                        contribs_hash: None,
+                        proposer_sig: Sig64([7u8; 64]),
+                    },
+                    commit: ActionCommit {
+                        action_id: Hash32([5u8; 32]),
+                        ack_sigs: vec![(PeerId([8u8; 32]), Sig64([9u8; 64]))],
+                        commit_sig: Sig64([10u8; 64]),
+                    },
+                }],
+            },
+            responder_sig: Sig64([11u8; 64]),
+        };
+
+        round_trip(&response);
+    }
+
+    #[test]
+    fn spectator_join_round_trip() {
+        let join = SpectatorJoin {
+            join_id: Hash32([1u8; 32]),
+            spectator: PeerId([2u8; 32]),
+            session_id: SessionId([3u8; 32]),
+            join_sig: Sig64([4u8; 64]),
+        };
+
+        round_trip(&join);
+    }
+
     #[test]
     fn invalid_bool_rejected() {
         let mut data = [2u8].as_slice();
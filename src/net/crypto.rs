@@ -2,8 +2,8 @@ use sha2::{Digest, Sha256};
 
 use super::{
     ActionAck, ActionCommit, ActionPropose, ActionReject, CanonicalEncode, ContribRequest,
-    ContribShare, Envelope, Hash32, PolicyCancel, PolicyToken, PubKey, Sig64, StateCommitment,
-    TimeoutClaim,
+    ContribShare, Envelope, Hash32, PolicyCancel, PolicyToken, PubKey, ResyncRequest,
+    ResyncResponse, Sig64, SpectatorJoin, StateCommitment, TimeoutClaim,
 };
 
 use secp256k1::Keypair;
@@ -17,6 +17,8 @@ pub const DOMAIN_POLICY: &[u8] = b"mtg/policy";
 pub const DOMAIN_CONTRIB: &[u8] = b"mtg/contrib";
 pub const DOMAIN_PROOF: &[u8] = b"mtg/proof";
 pub const DOMAIN_ENVELOPE: &[u8] = b"mtg/envelope";
+pub const DOMAIN_RESYNC: &[u8] = b"mtg/resync";
+pub const DOMAIN_SPECTATOR: &[u8] = b"mtg/spectator";
 pub const DOMAIN_STATE_ROOT: &[u8] = b"mtg/state_root/v1";
 pub const DOMAIN_PUBLIC_STATE: &[u8] = b"mtg/public_state/v1";
 pub const DOMAIN_ZONE_LEAF: &[u8] = b"mtg/zone_leaf/v1";
@@ -26,7 +28,7 @@ pub const DOMAIN_PLAINTEXT_COMMIT: &[u8] = b"mtg/plaintext/v1";
 pub const DOMAIN_PUBLIC_OBJECT: &[u8] = b"mtg/object_public/v1";
 pub const DOMAIN_STACK_STATE: &[u8] = b"mtg/stack/v1";
 pub const DOMAIN_COMBAT_STATE: &[u8] = b"mtg/combat/v1";
-pub const DOMAIN_TRACKERS_STATE: &[u8] = b"mtg/trackers/v1";
+pub const DOMAIN_TRACKERS_STATE: &[u8] = b"mtg/trackers/v2";
 
 pub trait Signer {
     fn sign(&self, msg: &[u8]) -> Sig64;
@@ -141,6 +143,18 @@ pub fn hash_timeout_claim(claim: &TimeoutClaim) -> Hash32 {
     hash_with_domain(DOMAIN_ACTION, &claim.encode_for_hash())
 }
 
+pub fn hash_resync_request(request: &ResyncRequest) -> Hash32 {
+    hash_with_domain(DOMAIN_RESYNC, &request.encode_for_hash())
+}
+
+pub fn hash_resync_response(response: &ResyncResponse) -> Hash32 {
+    hash_with_domain(DOMAIN_RESYNC, &response.encode_for_hash())
+}
+
+pub fn hash_spectator_join(join: &SpectatorJoin) -> Hash32 {
+    hash_with_domain(DOMAIN_SPECTATOR, &join.encode_for_hash())
+}
+
 pub fn set_action_id(propose: &mut ActionPropose) -> Hash32 {
     let action_id = hash_action_payload(propose);
     propose.action_id = action_id;
@@ -217,6 +231,26 @@ pub fn sign_timeout_claim(signer: &impl Signer, claim: &mut TimeoutClaim) -> Has
     digest
 }
 
+pub fn sign_resync_request(signer: &impl Signer, request: &mut ResyncRequest) -> Hash32 {
+    let request_id = hash_resync_request(request);
+    request.request_id = request_id;
+    request.request_sig = signer.sign(&request_id.0);
+    request_id
+}
+
+pub fn sign_resync_response(signer: &impl Signer, response: &mut ResyncResponse) -> Hash32 {
+    let digest = hash_resync_response(response);
+    response.responder_sig = signer.sign(&digest.0);
+    digest
+}
+
+pub fn sign_spectator_join(signer: &impl Signer, join: &mut SpectatorJoin) -> Hash32 {
+    let join_id = hash_spectator_join(join);
+    join.join_id = join_id;
+    join.join_sig = signer.sign(&join_id.0);
+    join_id
+}
+
 pub fn hash_bytes(domain: &[u8], value: &impl CanonicalEncode) -> Hash32 {
     hash_with_domain(domain, &value.to_bytes())
 }
@@ -278,6 +312,27 @@ pub fn verify_timeout_claim(pubkey: PubKey, claim: &TimeoutClaim) -> bool {
     verify_signature(pubkey, &digest.0, claim.claimer_sig)
 }
 
+pub fn verify_resync_request(pubkey: PubKey, request: &ResyncRequest) -> bool {
+    let expected = hash_resync_request(request);
+    if expected != request.request_id {
+        return false;
+    }
+    verify_signature(pubkey, &request.request_id.0, request.request_sig)
+}
+
+pub fn verify_resync_response(pubkey: PubKey, response: &ResyncResponse) -> bool {
+    let digest = hash_resync_response(response);
+    verify_signature(pubkey, &digest.0, response.responder_sig)
+}
+
+pub fn verify_spectator_join(pubkey: PubKey, join: &SpectatorJoin) -> bool {
+    let expected = hash_spectator_join(join);
+    if expected != join.join_id {
+        return false;
+    }
+    verify_signature(pubkey, &join.join_id.0, join.join_sig)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,7 @@
 use super::crypto::{
     Signer, sign_action_ack, sign_action_commit, sign_action_propose, sign_action_reject,
     sign_contrib_request, sign_contrib_share, sign_envelope, sign_policy_cancel, sign_policy_token,
-    sign_timeout_claim,
+    sign_resync_request, sign_resync_response, sign_spectator_join, sign_timeout_claim,
 };
 use super::state;
 use super::*;
@@ -231,3 +231,53 @@ pub fn build_forfeit_commit(
         claim_sigs,
     }
 }
+
+pub fn build_resync_request(
+    signer: &impl Signer,
+    peer: PeerId,
+    last_state_hash: Hash32,
+    last_seq: SeqNum,
+) -> ResyncRequest {
+    let mut request = ResyncRequest {
+        request_id: zero_hash(),
+        peer,
+        last_state_hash,
+        last_seq,
+        request_sig: zero_sig(),
+    };
+    sign_resync_request(signer, &mut request);
+    request
+}
+
+pub fn build_resync_response(
+    signer: &impl Signer,
+    request_id: Hash32,
+    responder: PeerId,
+    commitment: StateCommitment,
+    payload: ResyncPayload,
+) -> ResyncResponse {
+    let mut response = ResyncResponse {
+        request_id,
+        responder,
+        commitment,
+        payload,
+        responder_sig: zero_sig(),
+    };
+    sign_resync_response(signer, &mut response);
+    response
+}
+
+pub fn build_spectator_join(
+    signer: &impl Signer,
+    spectator: PeerId,
+    session_id: SessionId,
+) -> SpectatorJoin {
+    let mut join = SpectatorJoin {
+        join_id: zero_hash(),
+        spectator,
+        session_id,
+        join_sig: zero_sig(),
+    };
+    sign_spectator_join(signer, &mut join);
+    join
+}
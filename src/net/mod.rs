@@ -11,7 +11,9 @@ pub mod adapters;
 pub mod codec;
 pub mod crypto;
 pub mod message;
+pub mod resync;
 pub mod runtime;
+pub mod spectator;
 pub mod state;
 pub use codec::{CanonicalDecode, CanonicalEncode, CodecError};
 
@@ -417,6 +419,9 @@ pub enum MsgType {
     ContribShare = 8,
     TimeoutClaim = 9,
     ForfeitCommit = 10,
+    ResyncRequest = 11,
+    ResyncResponse = 12,
+    SpectatorJoin = 13,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -448,6 +453,9 @@ pub enum TimeoutReason {
     MissingShare = 1,
     MissingShuffleProof = 2,
     MissingPolicyResponse = 3,
+    MissingResync = 4,
+    /// The player's game clock ran out.
+    ClockExpired = 5,
     Other = 255,
 }
 
@@ -539,6 +547,8 @@ pub struct PolicyConditions {
     pub stop_if_attackers_declared: bool,
     pub stop_if_blockers_declared: bool,
     pub until_phase: PhaseStep,
+    /// Explicit priority stops, sorted by phase/step; replaces `until_phase`.
+    pub stops: Option<Vec<PhaseStep>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -556,6 +566,60 @@ pub struct ForfeitCommit {
     pub claim_sigs: Vec<(PeerId, Sig64)>,
 }
 
+/// A committed action as kept in the action log: the proposal that was
+/// applied and the commit that finalized it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommittedAction {
+    pub propose: ActionPropose,
+    pub commit: ActionCommit,
+}
+
+/// Sent by a reconnecting peer with the last state it applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResyncRequest {
+    pub request_id: Hash32,
+    pub peer: PeerId,
+    pub last_state_hash: Hash32,
+    /// Number of committed actions the peer had applied.
+    pub last_seq: SeqNum,
+    pub request_sig: Sig64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResyncPayload {
+    /// The committed actions after `from_seq`, when the responder still has
+    /// the requester's state in its log.
+    Log {
+        from_seq: SeqNum,
+        actions: Vec<CommittedAction>,
+    },
+    /// The responder's current public state, when the log can't bridge the gap.
+    Snapshot {
+        seq: SeqNum,
+        snapshot: Box<PublicStateSnapshot>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResyncResponse {
+    pub request_id: Hash32,
+    pub responder: PeerId,
+    /// The responder's state commitment after `payload` is applied.
+    pub commitment: StateCommitment,
+    pub payload: ResyncPayload,
+    pub responder_sig: Sig64,
+}
+
+/// Sent by a peer that wants to watch the session without playing. Players
+/// answer with a [`ResyncResponse`] carrying a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpectatorJoin {
+    pub join_id: Hash32,
+    pub spectator: PeerId,
+    pub session_id: SessionId,
+    pub join_sig: Sig64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TargetSpec {
     Object(GameObjectId),
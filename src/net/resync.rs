@@ -0,0 +1,719 @@
+//! Reconnection handshake for a peer that dropped out mid-game.
+//!
+//! A rejoining peer sends a [`ResyncRequest`] naming the last state it applied.
+//! Every other peer answers from its [`CommitLog`]: the committed actions since
+//! that state when the log still reaches back to it, or the full public
+//! snapshot when it doesn't. Each answer carries the responder's
+//! [`StateCommitment`]. The rejoining peer resumes play only once all
+//! responders agree on that commitment, its caught-up public state hashes to
+//! it, and the hidden-zone commitments it can compute itself match.
+//!
+//! Responders that never answer are reported with a [`TimeoutClaim`], which
+//! feeds the usual [`ForfeitCommit`](super::ForfeitCommit) path.
+
+use std::collections::HashMap;
+
+use super::crypto::{Signer, hash_action_payload, verify_resync_response, verify_signature};
+use super::message::{build_resync_response, build_timeout_claim};
+use super::state::{StateRootError, check_state_hash, hash_public_state_snapshot};
+use super::{
+    ActionCommit, ActionPropose, CommittedAction, GamePlayerId, Hash32, HiddenZoneCommitment,
+    PeerId, PubKey, PublicStateSnapshot, ResyncPayload, ResyncRequest, ResyncResponse, SeqNum,
+    StateCommitment, TimeoutClaim, TimeoutReason, ZoneCode,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResyncError {
+    UnknownPeer(PeerId),
+    UnexpectedResponder(PeerId),
+    InvalidSignature(PeerId),
+    WrongRequest {
+        expected: Hash32,
+        found: Hash32,
+    },
+    /// Responders reported different state commitments.
+    CommitmentDisagreement(PeerId),
+    /// The log segment doesn't start where the rejoining peer left off.
+    LogGap {
+        expected: SeqNum,
+        found: SeqNum,
+    },
+    /// A logged commit doesn't match its proposal or carries a bad ack.
+    InvalidCommit {
+        seq: SeqNum,
+    },
+    /// A logged action doesn't start from the state the previous one produced.
+    BrokenChain {
+        seq: SeqNum,
+        error: StateRootError,
+    },
+    /// The caught-up public state doesn't match the agreed commitment.
+    StateMismatch(StateRootError),
+    /// Hidden zones aren't listed once each in (owner, zone) order.
+    MalformedCommitment,
+    /// A hidden zone the rejoining peer can compute itself doesn't match.
+    HiddenZoneMismatch {
+        owner: GamePlayerId,
+        zone: ZoneCode,
+    },
+}
+
+/// Committed actions in the order they were applied.
+///
+/// An action's sequence number is its position in the log, so a peer that
+/// has applied `n` actions is at sequence number `n`.
+#[derive(Debug, Clone, Default)]
+pub struct CommitLog {
+    actions: Vec<CommittedAction>,
+}
+
+impl CommitLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> SeqNum {
+        self.actions.len() as SeqNum
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub fn push(&mut self, propose: ActionPropose, commit: ActionCommit) {
+        self.actions.push(CommittedAction { propose, commit });
+    }
+
+    pub fn actions(&self) -> &[CommittedAction] {
+        &self.actions
+    }
+
+    /// The actions after `seq`, if the log knows the peer was at
+    /// `state_hash` there. `head_state_hash` is the state after the last
+    /// logged action.
+    fn segment_from(
+        &self,
+        seq: SeqNum,
+        state_hash: Hash32,
+        head_state_hash: Hash32,
+    ) -> Option<&[CommittedAction]> {
+        let start = usize::try_from(seq).ok()?;
+        let reaches_back = match self.actions.get(start) {
+            Some(action) => action.propose.prev_state_hash == state_hash,
+            None => start == self.actions.len() && state_hash == head_state_hash,
+        };
+        reaches_back.then(|| &self.actions[start..])
+    }
+}
+
+/// Answer a rejoining peer from this peer's log and current state.
+///
+/// The request's signature is checked where it is received (see
+/// [`InboundMessage::Resync`](super::runtime::InboundMessage::Resync)).
+pub fn respond_to_resync(
+    signer: &impl Signer,
+    responder: PeerId,
+    log: &CommitLog,
+    request: &ResyncRequest,
+    commitment: StateCommitment,
+    snapshot: &PublicStateSnapshot,
+) -> ResyncResponse {
+    let payload = match log.segment_from(
+        request.last_seq,
+        request.last_state_hash,
+        commitment.public_state_hash,
+    ) {
+        Some(actions) => ResyncPayload::Log {
+            from_seq: request.last_seq,
+            actions: actions.to_vec(),
+        },
+        None => ResyncPayload::Snapshot {
+            seq: log.len(),
+            snapshot: Box::new(snapshot.clone()),
+        },
+    };
+    build_resync_response(signer, request.request_id, responder, commitment, payload)
+}
+
+/// Where a rejoining peer stands after catching up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaughtUp {
+    /// Sequence number of the next action to be committed.
+    pub seq: SeqNum,
+    /// The state commitment every responder agreed on.
+    pub commitment: StateCommitment,
+    /// Set when the responders sent a snapshot instead of a log segment; the
+    /// peer has to rebuild its public state from it.
+    pub snapshot: Option<PublicStateSnapshot>,
+}
+
+impl CaughtUp {
+    /// Check the agreed hidden-zone commitments against the ones this peer
+    /// computes from its own view (for example its hand and library).
+    ///
+    /// Play must not resume until this passes.
+    pub fn verify_hidden_zones(&self, local: &[HiddenZoneCommitment]) -> Result<(), ResyncError> {
+        let zones = &self.commitment.hidden_zones;
+        let key = |zone: &HiddenZoneCommitment| (zone.owner, zone.zone as u8);
+        if zones.windows(2).any(|pair| key(&pair[0]) >= key(&pair[1])) {
+            return Err(ResyncError::MalformedCommitment);
+        }
+        for own in local {
+            let matches = zones
+                .binary_search_by(|zone| key(zone).cmp(&key(own)))
+                .is_ok_and(|index| zones[index] == *own);
+            if !matches {
+                return Err(ResyncError::HiddenZoneMismatch {
+                    owner: own.owner,
+                    zone: own.zone,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The rejoining peer's side of the handshake.
+pub struct ResyncSession {
+    request: ResyncRequest,
+    pubkeys: HashMap<PeerId, PubKey>,
+    awaiting: Vec<PeerId>,
+    deadline_ms: u64,
+    responses: Vec<ResyncResponse>,
+}
+
+impl ResyncSession {
+    /// Start waiting for every peer in `responders` to answer `request`.
+    pub fn new(
+        request: ResyncRequest,
+        responders: Vec<PeerId>,
+        pubkeys: HashMap<PeerId, PubKey>,
+        deadline_ms: u64,
+    ) -> Self {
+        Self {
+            request,
+            pubkeys,
+            awaiting: responders,
+            deadline_ms,
+            responses: Vec::new(),
+        }
+    }
+
+    pub fn request(&self) -> &ResyncRequest {
+        &self.request
+    }
+
+    /// Peers that haven't answered yet.
+    pub fn awaiting(&self) -> &[PeerId] {
+        &self.awaiting
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.awaiting.is_empty() && !self.responses.is_empty()
+    }
+
+    /// Record a responder's answer after checking who sent it and that it
+    /// agrees with the answers already received.
+    pub fn receive(&mut self, response: ResyncResponse) -> Result<(), ResyncError> {
+        if response.request_id != self.request.request_id {
+            return Err(ResyncError::WrongRequest {
+                expected: self.request.request_id,
+                found: response.request_id,
+            });
+        }
+        let responder = response.responder;
+        let Some(position) = self.awaiting.iter().position(|peer| *peer == responder) else {
+            return Err(ResyncError::UnexpectedResponder(responder));
+        };
+        let Some(pubkey) = self.pubkeys.get(&responder).copied() else {
+            return Err(ResyncError::UnknownPeer(responder));
+        };
+        if !verify_resync_response(pubkey, &response) {
+            return Err(ResyncError::InvalidSignature(responder));
+        }
+        if self
+            .responses
+            .first()
+            .is_some_and(|first| first.commitment != response.commitment)
+        {
+            return Err(ResyncError::CommitmentDisagreement(responder));
+        }
+        self.awaiting.remove(position);
+        self.responses.push(response);
+        Ok(())
+    }
+
+    /// Timeout claims against every responder still missing at `now_ms`.
+    ///
+    /// The claims from the connected peers are collected into a
+    /// `ForfeitCommit` as for any other missing response.
+    pub fn timeout_claims(&self, signer: &impl Signer, now_ms: u64) -> Vec<TimeoutClaim> {
+        if now_ms < self.deadline_ms {
+            return Vec::new();
+        }
+        self.awaiting
+            .iter()
+            .map(|peer| {
+                build_timeout_claim(
+                    signer,
+                    self.request.request_id,
+                    *peer,
+                    TimeoutReason::MissingResync,
+                )
+            })
+            .collect()
+    }
+
+    /// Bring the rejoining peer up to the agreed state.
+    ///
+    /// With a log segment, each committed action is checked and handed to
+    /// `apply`, which applies it to the local game and returns the new public
+    /// state hash. With a snapshot, the snapshot itself is checked and
+    /// returned in [`CaughtUp::snapshot`].
+    pub fn catch_up(
+        &self,
+        mut apply: impl FnMut(&CommittedAction) -> Hash32,
+    ) -> Result<CaughtUp, ResyncError> {
+        // Every response carries the same commitment; prefer a log segment,
+        // which keeps the peer's hidden state.
+        let Some(response) = self
+            .responses
+            .iter()
+            .find(|response| matches!(response.payload, ResyncPayload::Log { .. }))
+            .or(self.responses.first())
+            .filter(|_| self.awaiting.is_empty())
+        else {
+            return Err(match self.awaiting.first() {
+                Some(peer) => ResyncError::UnexpectedResponder(*peer),
+                None => ResyncError::MalformedCommitment,
+            });
+        };
+        let commitment = response.commitment.clone();
+
+        match &response.payload {
+            ResyncPayload::Log { from_seq, actions } => {
+                if *from_seq != self.request.last_seq {
+                    return Err(ResyncError::LogGap {
+                        expected: self.request.last_seq,
+                        found: *from_seq,
+                    });
+                }
+                let mut state_hash = self.request.last_state_hash;
+                for (offset, action) in actions.iter().enumerate() {
+                    let seq = from_seq + offset as SeqNum;
+                    if !committed_action_is_valid(&self.pubkeys, action) {
+                        return Err(ResyncError::InvalidCommit { seq });
+                    }
+                    check_state_hash(state_hash, action.propose.prev_state_hash)
+                        .map_err(|error| ResyncError::BrokenChain { seq, error })?;
+                    state_hash = apply(action);
+                }
+                check_state_hash(commitment.public_state_hash, state_hash)
+                    .map_err(ResyncError::StateMismatch)?;
+                Ok(CaughtUp {
+                    seq: from_seq + actions.len() as SeqNum,
+                    commitment,
+                    snapshot: None,
+                })
+            }
+            ResyncPayload::Snapshot { seq, snapshot } => {
+                check_state_hash(
+                    commitment.public_state_hash,
+                    hash_public_state_snapshot(snapshot),
+                )
+                .map_err(ResyncError::StateMismatch)?;
+                Ok(CaughtUp {
+                    seq: *seq,
+                    commitment,
+                    snapshot: Some(snapshot.as_ref().clone()),
+                })
+            }
+        }
+    }
+}
+
+/// A logged action is valid when its id matches its contents, the commit is
+/// for that id, and every ack is signed by a peer in `pubkeys`.
+pub(super) fn committed_action_is_valid(
+    pubkeys: &HashMap<PeerId, PubKey>,
+    action: &CommittedAction,
+) -> bool {
+    let action_id = action.propose.action_id;
+    hash_action_payload(&action.propose) == action_id
+        && action.commit.action_id == action_id
+        && !action.commit.ack_sigs.is_empty()
+        && action.commit.ack_sigs.iter().all(|(peer, sig)| {
+            pubkeys
+                .get(peer)
+                .is_some_and(|pubkey| verify_signature(*pubkey, &action_id.0, *sig))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardBuilder;
+    use crate::game_state::GameState;
+    use crate::ids::{CardId, PlayerId};
+    use crate::net::crypto::{
+        DOMAIN_ZONE_LEAF, Secp256k1Signer, hash_with_domain, verify_action_propose,
+        verify_resync_request, verify_timeout_claim,
+    };
+    use crate::net::message::{
+        build_action_ack, build_action_commit, build_action_propose, build_forfeit_commit,
+        build_resync_request,
+    };
+    use crate::net::state::{build_public_state_snapshot, hash_public_state};
+    use crate::net::{ActionPayload, CommitmentScheme, ProofBundle, ZoneCommitment};
+    use crate::types::CardType;
+    use crate::zone::Zone;
+
+    const PLAYERS: usize = 3;
+
+    struct SimPeer {
+        id: PeerId,
+        player: PlayerId,
+        signer: Secp256k1Signer,
+        game: GameState,
+        log: CommitLog,
+    }
+
+    /// Peers that each run their own copy of the game. Messages only reach
+    /// connected peers.
+    struct SimNetwork {
+        peers: Vec<SimPeer>,
+        connected: Vec<bool>,
+    }
+
+    fn new_game() -> GameState {
+        let names = (0..PLAYERS)
+            .map(|index| format!("Player {index}"))
+            .collect();
+        let mut game = GameState::new(names, 20);
+        let card = CardBuilder::new(CardId::from_raw(1), "Library Card")
+            .card_types(vec![CardType::Instant])
+            .build();
+        for index in 0..PLAYERS {
+            for _ in 0..20 {
+                game.create_object_from_card(
+                    &card,
+                    PlayerId::from_index(index as u8),
+                    Zone::Library,
+                );
+            }
+        }
+        game
+    }
+
+    /// The engine side of a committed action: the active player draws and
+    /// the turn passes on.
+    fn apply_action(game: &mut GameState, propose: &ActionPropose) -> Hash32 {
+        if let ActionPayload::DrawCard { count } = propose.action {
+            game.draw_cards(game.turn.active_player, count as usize);
+        }
+        let next = (game.turn.active_player.index() + 1) % PLAYERS;
+        game.turn.active_player = PlayerId::from_index(next as u8);
+        hash_public_state(game)
+    }
+
+    /// Stand-in for the hidden-zone commitments: one leaf hash per hand.
+    fn hand_commitment(game: &GameState, player: PlayerId) -> HiddenZoneCommitment {
+        let hand = &game.player(player).expect("player exists").hand;
+        let bytes: Vec<u8> = hand.iter().flat_map(|id| id.0.to_be_bytes()).collect();
+        HiddenZoneCommitment {
+            zone: ZoneCode::Hand,
+            owner: player.into(),
+            commitment: ZoneCommitment {
+                scheme: CommitmentScheme::ElgamalSecp256k1Sha256MerkleV1,
+                root: hash_with_domain(DOMAIN_ZONE_LEAF, &bytes),
+                len: hand.len() as u32,
+                top: 0,
+                viewers: Vec::new(),
+            },
+        }
+    }
+
+    fn state_commitment(game: &GameState) -> StateCommitment {
+        StateCommitment {
+            version: 1,
+            public_state_hash: hash_public_state(game),
+            hidden_zones: (0..PLAYERS)
+                .map(|index| hand_commitment(game, PlayerId::from_index(index as u8)))
+                .collect(),
+        }
+    }
+
+    impl SimNetwork {
+        fn new() -> Self {
+            let peers = (0..PLAYERS)
+                .map(|index| {
+                    let signer =
+                        Secp256k1Signer::from_secret_bytes([index as u8 + 1; 32]).expect("signer");
+                    SimPeer {
+                        id: PeerId([index as u8 + 1; 32]),
+                        player: PlayerId::from_index(index as u8),
+                        signer,
+                        game: new_game(),
+                        log: CommitLog::new(),
+                    }
+                })
+                .collect();
+            Self {
+                peers,
+                connected: vec![true; PLAYERS],
+            }
+        }
+
+        fn pubkeys(&self) -> HashMap<PeerId, PubKey> {
+            self.peers
+                .iter()
+                .map(|peer| (peer.id, peer.signer.public_key()))
+                .collect()
+        }
+
+        /// Propose, ack, and commit one action among the connected peers.
+        fn commit_action(&mut self, proposer: usize) {
+            let proposer_peer = &self.peers[proposer];
+            let propose = build_action_propose(
+                &proposer_peer.signer,
+                hash_public_state(&proposer_peer.game),
+                ActionPayload::DrawCard { count: 1 },
+                ProofBundle::default(),
+                None,
+            );
+            let proposer_key = proposer_peer.signer.public_key();
+            let acks = self
+                .connected_peers()
+                .map(|peer| {
+                    assert!(verify_action_propose(proposer_key, &propose));
+                    let ack = build_action_ack(&peer.signer, propose.action_id);
+                    (peer.id, ack.ack_sig)
+                })
+                .collect();
+            let commit = build_action_commit(&proposer_peer.signer, propose.action_id, acks);
+            for (peer, connected) in self.peers.iter_mut().zip(&self.connected) {
+                if *connected {
+                    apply_action(&mut peer.game, &propose);
+                    peer.log.push(propose.clone(), commit.clone());
+                }
+            }
+        }
+
+        fn connected_peers(&self) -> impl Iterator<Item = &SimPeer> {
+            self.peers
+                .iter()
+                .zip(&self.connected)
+                .filter_map(|(peer, connected)| connected.then_some(peer))
+        }
+
+        /// `peer` comes back and asks everyone else to bring it up to date.
+        fn rejoin(&mut self, peer: usize) -> ResyncSession {
+            self.connected[peer] = true;
+            let rejoining = &self.peers[peer];
+            let request = build_resync_request(
+                &rejoining.signer,
+                rejoining.id,
+                hash_public_state(&rejoining.game),
+                rejoining.log.len(),
+            );
+            let responders = self
+                .peers
+                .iter()
+                .filter(|other| other.id != rejoining.id)
+                .map(|other| other.id)
+                .collect();
+            ResyncSession::new(request, responders, self.pubkeys(), 5_000)
+        }
+
+        fn respond(&self, responder: usize, request: &ResyncRequest) -> ResyncResponse {
+            let peer = &self.peers[responder];
+            let requester_key = self
+                .peers
+                .iter()
+                .find(|other| other.id == request.peer)
+                .expect("requester is a known peer")
+                .signer
+                .public_key();
+            assert!(verify_resync_request(requester_key, request));
+            respond_to_resync(
+                &peer.signer,
+                peer.id,
+                &peer.log,
+                request,
+                state_commitment(&peer.game),
+                &build_public_state_snapshot(&peer.game),
+            )
+        }
+    }
+
+    #[test]
+    fn dropped_peer_replays_missed_actions_and_resumes() {
+        let mut network = SimNetwork::new();
+        network.commit_action(0);
+        network.commit_action(1);
+
+        // Carol drops for five actions.
+        network.connected[2] = false;
+        for action in 0..5 {
+            network.commit_action(action % 2);
+        }
+        assert_eq!(network.peers[2].log.len(), 2);
+        assert_eq!(network.peers[0].log.len(), 7);
+
+        let mut session = network.rejoin(2);
+        for responder in [0, 1] {
+            let response = network.respond(responder, session.request());
+            assert!(matches!(
+                &response.payload,
+                ResyncPayload::Log { from_seq: 2, actions } if actions.len() == 5
+            ));
+            session.receive(response).expect("response should verify");
+        }
+        assert!(session.is_complete());
+
+        let carol = &mut network.peers[2];
+        let caught_up = session
+            .catch_up(|action| {
+                carol
+                    .log
+                    .push(action.propose.clone(), action.commit.clone());
+                apply_action(&mut carol.game, &action.propose)
+            })
+            .expect("log segment should replay");
+        assert_eq!(caught_up.seq, 7);
+        assert!(caught_up.snapshot.is_none());
+        caught_up
+            .verify_hidden_zones(&[hand_commitment(&carol.game, carol.player)])
+            .expect("carol's hand should match the agreed commitment");
+
+        // Play resumes with all three peers in step.
+        network.commit_action(2);
+        let hashes: Vec<Hash32> = network
+            .peers
+            .iter()
+            .map(|peer| hash_public_state(&peer.game))
+            .collect();
+        assert!(hashes.iter().all(|hash| *hash == hashes[0]));
+        assert!(network.peers.iter().all(|peer| peer.log.len() == 8));
+    }
+
+    #[test]
+    fn unknown_state_falls_back_to_snapshot() {
+        let mut network = SimNetwork::new();
+        network.connected[2] = false;
+        network.commit_action(0);
+        network.commit_action(1);
+        // Carol's local state diverged, so no log entry reaches back to it.
+        network.peers[2]
+            .game
+            .player_mut(PlayerId::from_index(2))
+            .unwrap()
+            .life = 1;
+
+        let mut session = network.rejoin(2);
+        for responder in [0, 1] {
+            let response = network.respond(responder, session.request());
+            session.receive(response).expect("response should verify");
+        }
+        let caught_up = session
+            .catch_up(|_| panic!("a snapshot has nothing to replay"))
+            .expect("snapshot should verify");
+        assert_eq!(caught_up.seq, 2);
+        assert_eq!(
+            caught_up.snapshot,
+            Some(build_public_state_snapshot(&network.peers[0].game))
+        );
+    }
+
+    #[test]
+    fn mismatched_commitments_and_hidden_zones_are_rejected() {
+        let mut network = SimNetwork::new();
+        network.connected[2] = false;
+        network.commit_action(0);
+
+        let mut session = network.rejoin(2);
+        session
+            .receive(network.respond(0, session.request()))
+            .expect("alice's response should verify");
+
+        // Bob claims a different hand for Carol than Alice does.
+        let bob = &network.peers[1];
+        let mut commitment = state_commitment(&bob.game);
+        commitment.hidden_zones[2].commitment.root = Hash32([0xAB; 32]);
+        let tampered = respond_to_resync(
+            &bob.signer,
+            bob.id,
+            &bob.log,
+            session.request(),
+            commitment.clone(),
+            &build_public_state_snapshot(&bob.game),
+        );
+        assert_eq!(
+            session.receive(tampered),
+            Err(ResyncError::CommitmentDisagreement(bob.id))
+        );
+
+        // If every responder agrees on the wrong hand, Carol still catches it.
+        let carol = &network.peers[2];
+        let caught_up = CaughtUp {
+            seq: 1,
+            commitment,
+            snapshot: None,
+        };
+        assert_eq!(
+            caught_up.verify_hidden_zones(&[hand_commitment(&carol.game, carol.player)]),
+            Err(ResyncError::HiddenZoneMismatch {
+                owner: carol.player.into(),
+                zone: ZoneCode::Hand,
+            })
+        );
+    }
+
+    #[test]
+    fn silent_responder_times_out_into_forfeit() {
+        let mut network = SimNetwork::new();
+        network.connected[2] = false;
+        network.commit_action(0);
+
+        let mut session = network.rejoin(2);
+        session
+            .receive(network.respond(0, session.request()))
+            .expect("alice's response should verify");
+        assert!(!session.is_complete());
+        assert!(
+            session
+                .catch_up(|_| panic!("incomplete sessions don't replay"))
+                .is_err()
+        );
+
+        let (alice, bob, carol) = (&network.peers[0], &network.peers[1], &network.peers[2]);
+        assert!(session.timeout_claims(&carol.signer, 4_999).is_empty());
+        let claims: Vec<(PeerId, TimeoutClaim)> = [alice, carol]
+            .into_iter()
+            .flat_map(|claimer| {
+                session
+                    .timeout_claims(&claimer.signer, 5_000)
+                    .into_iter()
+                    .map(|claim| (claimer.id, claim))
+            })
+            .collect();
+        assert_eq!(claims.len(), 2);
+        for (claimer, claim) in &claims {
+            assert_eq!(claim.missing_peer, bob.id);
+            assert_eq!(claim.reason, TimeoutReason::MissingResync);
+            assert_eq!(claim.action_id, session.request().request_id);
+            assert!(verify_timeout_claim(network.pubkeys()[claimer], claim));
+        }
+
+        let forfeit = build_forfeit_commit(
+            bob.id,
+            TimeoutReason::MissingResync,
+            claims
+                .iter()
+                .map(|(claimer, claim)| (*claimer, claim.claimer_sig))
+                .collect(),
+        );
+        assert_eq!(forfeit.missing_peer, bob.id);
+        assert_eq!(forfeit.claim_sigs.len(), 2);
+    }
+}
@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
 use crate::decision::{DecisionMaker, LegalAction, compute_legal_actions};
+use crate::display_text::DisplayKey;
 use crate::game_state::{GameState, Target};
 use crate::ids::{ObjectId, PlayerId};
 
+use super::crypto::{verify_resync_request, verify_timeout_claim};
+use super::spectator::{SessionRoster, SpectatorError};
 use super::state::{verify_action_propose_for_game, verify_contrib_request_for_game};
 use super::{
     ActionKind, ActionPayload, ActionPropose, ContribRequest, CostSpec, Hash32, PeerId, PubKey,
-    TargetSpec,
+    ResyncRequest, SpectatorJoin, TargetSpec, TimeoutClaim,
 };
 
 /// Incoming network messages consumed by the runtime.
@@ -21,6 +24,15 @@ pub enum InboundMessage {
         peer: PeerId,
         request: ContribRequest,
     },
+    /// A reconnecting peer asking to be brought up to date.
+    Resync {
+        peer: PeerId,
+        request: ResyncRequest,
+    },
+    /// A peer asking to watch the session.
+    SpectatorJoin { peer: PeerId, join: SpectatorJoin },
+    /// A player reporting that another player missed a deadline.
+    TimeoutClaim { peer: PeerId, claim: TimeoutClaim },
 }
 
 /// Synchronous inbox for inbound network messages.
@@ -60,6 +72,8 @@ pub enum NetRuntimeError {
     },
     UnhandledDecision(&'static str),
     ActionIncomplete(ActionKind),
+    /// The message breaks the session roster's player/spectator rules.
+    Roster(SpectatorError),
 }
 
 #[derive(Debug, Clone)]
@@ -107,24 +121,34 @@ impl PendingAction {
 /// This verifies signatures + prev_state_hash for inbound actions and then
 /// maps them to local DecisionMaker responses. Unsupported decision prompts
 /// are delegated to a fallback DecisionMaker and recorded as errors.
+///
+/// The session roster decides who may act: spectators are admitted as they
+/// join, but only players may propose actions, be asked for contribution
+/// shares, or take part in timeout claims.
 pub struct NetRuntime<I: Inbox> {
     inbox: I,
+    roster: SessionRoster,
     peer_for_player: HashMap<PlayerId, PeerId>,
     pubkeys: HashMap<PeerId, PubKey>,
     pending: Option<PendingAction>,
     errors: Vec<NetRuntimeError>,
     contrib_requests: Vec<(PeerId, ContribRequest)>,
+    resync_requests: Vec<(PeerId, ResyncRequest)>,
+    spectator_joins: Vec<SpectatorJoin>,
+    timeout_claims: Vec<(PeerId, TimeoutClaim)>,
     fallback: Box<dyn DecisionMaker>,
 }
 
 impl<I: Inbox> NetRuntime<I> {
     pub fn new(
         inbox: I,
+        roster: SessionRoster,
         peer_for_player: HashMap<PlayerId, PeerId>,
         pubkeys: HashMap<PeerId, PubKey>,
     ) -> Self {
         Self::with_fallback(
             inbox,
+            roster,
             peer_for_player,
             pubkeys,
             Box::new(crate::decision::AutoPassDecisionMaker),
@@ -133,17 +157,22 @@ impl<I: Inbox> NetRuntime<I> {
 
     pub fn with_fallback(
         inbox: I,
+        roster: SessionRoster,
         peer_for_player: HashMap<PlayerId, PeerId>,
         pubkeys: HashMap<PeerId, PubKey>,
         fallback: Box<dyn DecisionMaker>,
     ) -> Self {
         Self {
             inbox,
+            roster,
             peer_for_player,
             pubkeys,
             pending: None,
             errors: Vec::new(),
             contrib_requests: Vec::new(),
+            resync_requests: Vec::new(),
+            spectator_joins: Vec::new(),
+            timeout_claims: Vec::new(),
             fallback,
         }
     }
@@ -156,6 +185,27 @@ impl<I: Inbox> NetRuntime<I> {
         std::mem::take(&mut self.contrib_requests)
     }
 
+    /// Verified resync requests, to be answered with
+    /// [`respond_to_resync`](super::resync::respond_to_resync).
+    pub fn drain_resync_requests(&mut self) -> Vec<(PeerId, ResyncRequest)> {
+        std::mem::take(&mut self.resync_requests)
+    }
+
+    pub fn roster(&self) -> &SessionRoster {
+        &self.roster
+    }
+
+    /// Joins from newly admitted spectators, to be answered with
+    /// [`respond_to_spectator_join`](super::spectator::respond_to_spectator_join).
+    pub fn drain_spectator_joins(&mut self) -> Vec<SpectatorJoin> {
+        std::mem::take(&mut self.spectator_joins)
+    }
+
+    /// Verified timeout claims made by one player against another.
+    pub fn drain_timeout_claims(&mut self) -> Vec<(PeerId, TimeoutClaim)> {
+        std::mem::take(&mut self.timeout_claims)
+    }
+
     fn record_error(&mut self, error: NetRuntimeError) {
         self.errors.push(error);
     }
@@ -201,6 +251,14 @@ impl<I: Inbox> NetRuntime<I> {
         loop {
             match self.inbox.recv() {
                 InboundMessage::ContribRequest { peer, request } => {
+                    if let Err(err) = self
+                        .roster
+                        .check_player(peer)
+                        .and_then(|()| self.roster.check_contrib_request(&request))
+                    {
+                        self.record_error(NetRuntimeError::Roster(err));
+                        continue;
+                    }
                     let Some(pubkey) = self.pubkeys.get(&peer).copied() else {
                         self.record_error(NetRuntimeError::UnknownPeer(peer));
                         continue;
@@ -210,7 +268,51 @@ impl<I: Inbox> NetRuntime<I> {
                         Err(err) => self.record_error(map_contrib_error(err)),
                     }
                 }
+                InboundMessage::Resync { peer, request } => {
+                    let Some(pubkey) = self.pubkeys.get(&peer).copied() else {
+                        self.record_error(NetRuntimeError::UnknownPeer(peer));
+                        continue;
+                    };
+                    if request.peer == peer && verify_resync_request(pubkey, &request) {
+                        self.resync_requests.push((peer, request));
+                    } else {
+                        self.record_error(NetRuntimeError::InvalidSignature);
+                    }
+                }
+                InboundMessage::SpectatorJoin { peer, join } => {
+                    let Some(pubkey) = self.pubkeys.get(&peer).copied() else {
+                        self.record_error(NetRuntimeError::UnknownPeer(peer));
+                        continue;
+                    };
+                    if join.spectator != peer {
+                        self.record_error(NetRuntimeError::InvalidSignature);
+                        continue;
+                    }
+                    match self.roster.admit_spectator(pubkey, &join) {
+                        Ok(()) => self.spectator_joins.push(join),
+                        Err(err) => self.record_error(NetRuntimeError::Roster(err)),
+                    }
+                }
+                InboundMessage::TimeoutClaim { peer, claim } => {
+                    if let Err(err) = self.roster.check_timeout_claim(peer, &claim) {
+                        self.record_error(NetRuntimeError::Roster(err));
+                        continue;
+                    }
+                    let Some(pubkey) = self.pubkeys.get(&peer).copied() else {
+                        self.record_error(NetRuntimeError::UnknownPeer(peer));
+                        continue;
+                    };
+                    if verify_timeout_claim(pubkey, &claim) {
+                        self.timeout_claims.push((peer, claim));
+                    } else {
+                        self.record_error(NetRuntimeError::InvalidSignature);
+                    }
+                }
                 InboundMessage::Action { peer, propose } => {
+                    if let Err(err) = self.roster.check_player(peer) {
+                        self.record_error(NetRuntimeError::Roster(err));
+                        continue;
+                    }
                     if peer != expected_peer {
                         self.record_error(NetRuntimeError::UnexpectedPeer {
                             expected: expected_peer,
@@ -250,7 +352,7 @@ impl<I: Inbox> NetRuntime<I> {
             ActionPayload::PassPriority { .. } => return Some(LegalAction::PassPriority),
             ActionPayload::CastSpell { card_ref, .. } => {
                 let spell_id = ObjectId::from_raw(card_ref.0);
-                let matches: Vec<LegalAction> = compute_legal_actions(game, player)
+                let matches: Vec<LegalAction> = legal_actions_for(game, player)
                     .into_iter()
                     .filter(|action| matches!(action, LegalAction::CastSpell { spell_id: id, .. } if *id == spell_id))
                     .collect();
@@ -275,7 +377,7 @@ impl<I: Inbox> NetRuntime<I> {
             }
             ActionPayload::ActivateAbility { source_ref, .. } => {
                 let source_id = ObjectId::from_raw(source_ref.0);
-                let matches: Vec<LegalAction> = compute_legal_actions(game, player)
+                let matches: Vec<LegalAction> = legal_actions_for(game, player)
                     .into_iter()
                     .filter(|action| match action {
                         LegalAction::ActivateAbility { source, .. } => *source == source_id,
@@ -394,7 +496,10 @@ impl<I: Inbox> DecisionMaker for NetRuntime<I> {
         game: &GameState,
         ctx: &crate::decisions::context::SelectOptionsContext,
     ) -> Vec<usize> {
-        if ctx.description.contains("optional cost") {
+        if matches!(
+            ctx.description.key,
+            DisplayKey::ChooseOptionalCostsFor | DisplayKey::ChooseOptionalCostsToPay
+        ) {
             let (indices_opt, repeat_errors) = match self.ensure_action_for(game, ctx.player) {
                 Some(pending) => {
                     if let Some(costs) = pending.costs.as_ref() {
@@ -451,6 +556,15 @@ fn action_kind(action: &ActionPayload) -> ActionKind {
     }
 }
 
+/// Legal actions for matching a received action, reusing the ones computed
+/// for the priority decision that is being answered when still current.
+fn legal_actions_for(game: &GameState, player: PlayerId) -> Vec<LegalAction> {
+    game.legal_action_cache()
+        .get(game, player)
+        .map(<[_]>::to_vec)
+        .unwrap_or_else(|| compute_legal_actions(game, player))
+}
+
 fn pending_from_action(player: PlayerId, action: ActionPayload) -> PendingAction {
     let (costs, targets, consumed_targets, consumed_x, consumed_optional_costs) = match &action {
         ActionPayload::CastSpell { targets, costs, .. }
@@ -511,10 +625,13 @@ fn map_contrib_error(err: super::state::ContribVerifyError) -> NetRuntimeError {
 mod tests {
     use super::*;
     use crate::game_state::GameState;
-    use crate::ids::{PlayerId, reset_id_counters};
+    use crate::ids::PlayerId;
     use crate::net::crypto::Secp256k1Signer;
-    use crate::net::message::build_action_propose_for_game;
-    use crate::net::{ActionPayload, ProofBundle};
+    use crate::net::message::{
+        build_action_propose_for_game, build_contrib_request, build_spectator_join,
+        build_timeout_claim,
+    };
+    use crate::net::{ActionPayload, ProofBundle, SessionId, TimeoutReason};
     use std::collections::VecDeque;
 
     struct QueueInbox {
@@ -533,13 +650,18 @@ mod tests {
         }
     }
 
+    const SESSION: SessionId = SessionId([7u8; 32]);
+
     fn peer_id(byte: u8) -> PeerId {
         PeerId([byte; 32])
     }
 
+    fn roster(players: &[PeerId]) -> SessionRoster {
+        SessionRoster::new(SESSION, players.to_vec())
+    }
+
     #[test]
     fn net_runtime_accepts_pass_priority() {
-        reset_id_counters();
         let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let signer = Secp256k1Signer::from_secret_bytes([7u8; 32]).expect("signer");
         let pubkey = signer.public_key();
@@ -570,7 +692,7 @@ mod tests {
         let mut pubkeys = HashMap::new();
         pubkeys.insert(p_alice, pubkey);
 
-        let mut runtime = NetRuntime::new(inbox, peer_for_player, pubkeys);
+        let mut runtime = NetRuntime::new(inbox, roster(&[p_alice]), peer_for_player, pubkeys);
         let ctx = crate::decisions::context::PriorityContext::new(
             alice,
             compute_legal_actions(&game, alice),
@@ -583,7 +705,6 @@ mod tests {
 
     #[test]
     fn net_runtime_records_prev_state_mismatch() {
-        reset_id_counters();
         let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let signer = Secp256k1Signer::from_secret_bytes([9u8; 32]).expect("signer");
         let pubkey = signer.public_key();
@@ -630,7 +751,7 @@ mod tests {
         let mut pubkeys = HashMap::new();
         pubkeys.insert(p_alice, pubkey);
 
-        let mut runtime = NetRuntime::new(inbox, peer_for_player, pubkeys);
+        let mut runtime = NetRuntime::new(inbox, roster(&[p_alice]), peer_for_player, pubkeys);
         let ctx = crate::decisions::context::PriorityContext::new(
             alice,
             compute_legal_actions(&game, alice),
@@ -646,4 +767,194 @@ mod tests {
             errors
         );
     }
+
+    #[test]
+    fn net_runtime_collects_resync_requests_while_waiting() {
+        let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice_signer = Secp256k1Signer::from_secret_bytes([3u8; 32]).expect("signer");
+        let bob_signer = Secp256k1Signer::from_secret_bytes([4u8; 32]).expect("signer");
+
+        let alice = PlayerId::from_index(0);
+        let p_alice = peer_id(3);
+        let p_bob = peer_id(4);
+
+        let resync = crate::net::message::build_resync_request(
+            &bob_signer,
+            p_bob,
+            crate::net::state::hash_public_state(&game),
+            0,
+        );
+        let propose = build_action_propose_for_game(
+            &alice_signer,
+            &game,
+            ActionPayload::PassPriority { policy_id: None },
+            ProofBundle::default(),
+            None,
+        );
+
+        let inbox = QueueInbox::new(
+            [
+                // Claims to come from Alice but is signed by Bob.
+                InboundMessage::Resync {
+                    peer: p_alice,
+                    request: resync.clone(),
+                },
+                InboundMessage::Resync {
+                    peer: p_bob,
+                    request: resync.clone(),
+                },
+                InboundMessage::Action {
+                    peer: p_alice,
+                    propose,
+                },
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let peer_for_player = HashMap::from([(alice, p_alice)]);
+        let pubkeys = HashMap::from([
+            (p_alice, alice_signer.public_key()),
+            (p_bob, bob_signer.public_key()),
+        ]);
+
+        let mut runtime =
+            NetRuntime::new(inbox, roster(&[p_alice, p_bob]), peer_for_player, pubkeys);
+        let ctx = crate::decisions::context::PriorityContext::new(
+            alice,
+            compute_legal_actions(&game, alice),
+        );
+
+        let action = runtime.decide_priority(&game, &ctx);
+        assert!(matches!(action, LegalAction::PassPriority));
+        assert_eq!(runtime.drain_resync_requests(), vec![(p_bob, resync)]);
+        assert_eq!(
+            runtime.take_errors(),
+            vec![NetRuntimeError::InvalidSignature]
+        );
+    }
+
+    #[test]
+    fn net_runtime_admits_spectators_but_only_lets_players_act() {
+        let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice_signer = Secp256k1Signer::from_secret_bytes([5u8; 32]).expect("signer");
+        let bob_signer = Secp256k1Signer::from_secret_bytes([6u8; 32]).expect("signer");
+        let carol_signer = Secp256k1Signer::from_secret_bytes([8u8; 32]).expect("signer");
+
+        let alice = PlayerId::from_index(0);
+        let p_alice = peer_id(5);
+        let p_bob = peer_id(6);
+        let p_carol = peer_id(8);
+
+        let join = build_spectator_join(&carol_signer, p_carol, SESSION);
+        let carol_propose = build_action_propose_for_game(
+            &carol_signer,
+            &game,
+            ActionPayload::PassPriority { policy_id: None },
+            ProofBundle::default(),
+            None,
+        );
+        let carol_claim = build_timeout_claim(
+            &carol_signer,
+            Hash32([0u8; 32]),
+            p_bob,
+            TimeoutReason::MissingAck,
+        );
+        let bob_claim = build_timeout_claim(
+            &bob_signer,
+            Hash32([0u8; 32]),
+            p_carol,
+            TimeoutReason::MissingAck,
+        );
+        let alice_claim = build_timeout_claim(
+            &alice_signer,
+            Hash32([0u8; 32]),
+            p_bob,
+            TimeoutReason::MissingAck,
+        );
+        let contrib = build_contrib_request(
+            &bob_signer,
+            crate::net::state::hash_public_state(&game),
+            ActionKind::ShuffleLibrary,
+            vec![p_alice, p_carol],
+            0,
+        );
+        let propose = build_action_propose_for_game(
+            &alice_signer,
+            &game,
+            ActionPayload::PassPriority { policy_id: None },
+            ProofBundle::default(),
+            None,
+        );
+
+        let inbox = QueueInbox::new(
+            [
+                InboundMessage::SpectatorJoin {
+                    peer: p_carol,
+                    join: join.clone(),
+                },
+                InboundMessage::SpectatorJoin {
+                    peer: p_carol,
+                    join: join.clone(),
+                },
+                InboundMessage::Action {
+                    peer: p_carol,
+                    propose: carol_propose,
+                },
+                InboundMessage::TimeoutClaim {
+                    peer: p_carol,
+                    claim: carol_claim,
+                },
+                InboundMessage::TimeoutClaim {
+                    peer: p_bob,
+                    claim: bob_claim,
+                },
+                InboundMessage::ContribRequest {
+                    peer: p_bob,
+                    request: contrib,
+                },
+                InboundMessage::TimeoutClaim {
+                    peer: p_alice,
+                    claim: alice_claim.clone(),
+                },
+                InboundMessage::Action {
+                    peer: p_alice,
+                    propose,
+                },
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let peer_for_player = HashMap::from([(alice, p_alice)]);
+        let pubkeys = HashMap::from([
+            (p_alice, alice_signer.public_key()),
+            (p_bob, bob_signer.public_key()),
+            (p_carol, carol_signer.public_key()),
+        ]);
+
+        let mut runtime =
+            NetRuntime::new(inbox, roster(&[p_alice, p_bob]), peer_for_player, pubkeys);
+        let ctx = crate::decisions::context::PriorityContext::new(
+            alice,
+            compute_legal_actions(&game, alice),
+        );
+
+        let action = runtime.decide_priority(&game, &ctx);
+        assert!(matches!(action, LegalAction::PassPriority));
+        assert_eq!(runtime.roster().spectators(), &[p_carol]);
+        assert_eq!(runtime.drain_spectator_joins(), vec![join]);
+        assert_eq!(runtime.drain_timeout_claims(), vec![(p_alice, alice_claim)]);
+        assert!(runtime.drain_contrib_requests().is_empty());
+        assert_eq!(
+            runtime.take_errors(),
+            vec![
+                NetRuntimeError::Roster(SpectatorError::AlreadyInSession(p_carol)),
+                NetRuntimeError::Roster(SpectatorError::NotAPlayer(p_carol)),
+                NetRuntimeError::Roster(SpectatorError::NotAPlayer(p_carol)),
+                NetRuntimeError::Roster(SpectatorError::NotAPlayer(p_carol)),
+                NetRuntimeError::Roster(SpectatorError::NotAPlayer(p_carol)),
+            ]
+        );
+    }
 }
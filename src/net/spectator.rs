@@ -0,0 +1,562 @@
+//! Read-only spectators.
+//!
+//! A spectator joins with a [`SpectatorJoin`] and a player answers with a
+//! [`ResyncResponse`] carrying the current public snapshot. From then on the
+//! spectator receives each committed action together with the public
+//! [`StateUpdate`] it produced, and checks that every update lands on the state
+//! the next action was proposed from.
+//!
+//! Spectators never hold priority, never owe contribution shares, and take no
+//! part in timeouts. The players' [`SessionRoster`] enforces this, and refuses
+//! hidden-zone commitments that would list a spectator as a viewer.
+
+use std::collections::HashMap;
+
+use super::crypto::{Signer, verify_resync_response, verify_spectator_join};
+use super::message::build_resync_response;
+use super::resync::committed_action_is_valid;
+use super::state::{
+    StateDeltaError, StateRootError, StateUpdate, apply_state_update, check_state_hash,
+    hash_public_state_snapshot,
+};
+use super::{
+    CommittedAction, ContribRequest, ForfeitCommit, GamePlayerId, Hash32, PeerId, PubKey,
+    PublicStateSnapshot, ResyncPayload, ResyncResponse, SeqNum, SessionId, SpectatorJoin,
+    StateCommitment, TimeoutClaim, ZoneCode,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerRole {
+    Player,
+    Spectator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpectatorError {
+    UnknownPeer(PeerId),
+    InvalidSignature(PeerId),
+    WrongSession {
+        expected: SessionId,
+        found: SessionId,
+    },
+    AlreadyInSession(PeerId),
+    /// A spectator (or unknown peer) was given a player's part.
+    NotAPlayer(PeerId),
+    /// A hidden zone lists a spectator among its viewers.
+    SpectatorViewer {
+        owner: GamePlayerId,
+        zone: ZoneCode,
+        spectator: PeerId,
+    },
+    /// The answer is for a different join message.
+    WrongJoin {
+        expected: Hash32,
+        found: Hash32,
+    },
+    /// The answer to a join didn't carry a snapshot.
+    ExpectedSnapshot,
+    InvalidCommit {
+        seq: SeqNum,
+    },
+    BrokenChain {
+        seq: SeqNum,
+        error: StateRootError,
+    },
+    StateMismatch(StateRootError),
+    Update(StateDeltaError),
+}
+
+/// Who takes part in a session, and in which role.
+#[derive(Debug, Clone)]
+pub struct SessionRoster {
+    session_id: SessionId,
+    players: Vec<PeerId>,
+    spectators: Vec<PeerId>,
+}
+
+impl SessionRoster {
+    pub fn new(session_id: SessionId, players: Vec<PeerId>) -> Self {
+        Self {
+            session_id,
+            players,
+            spectators: Vec::new(),
+        }
+    }
+
+    pub fn players(&self) -> &[PeerId] {
+        &self.players
+    }
+
+    pub fn spectators(&self) -> &[PeerId] {
+        &self.spectators
+    }
+
+    pub fn role(&self, peer: PeerId) -> Option<PeerRole> {
+        if self.players.contains(&peer) {
+            Some(PeerRole::Player)
+        } else if self.spectators.contains(&peer) {
+            Some(PeerRole::Spectator)
+        } else {
+            None
+        }
+    }
+
+    /// Admit the sender of `join` as a spectator.
+    pub fn admit_spectator(
+        &mut self,
+        pubkey: PubKey,
+        join: &SpectatorJoin,
+    ) -> Result<(), SpectatorError> {
+        if join.session_id != self.session_id {
+            return Err(SpectatorError::WrongSession {
+                expected: self.session_id,
+                found: join.session_id,
+            });
+        }
+        if !verify_spectator_join(pubkey, join) {
+            return Err(SpectatorError::InvalidSignature(join.spectator));
+        }
+        if self.role(join.spectator).is_some() {
+            return Err(SpectatorError::AlreadyInSession(join.spectator));
+        }
+        self.spectators.push(join.spectator);
+        Ok(())
+    }
+
+    /// Only players propose and ack actions, and so only they hold priority.
+    pub fn check_player(&self, peer: PeerId) -> Result<(), SpectatorError> {
+        match self.role(peer) {
+            Some(PeerRole::Player) => Ok(()),
+            _ => Err(SpectatorError::NotAPlayer(peer)),
+        }
+    }
+
+    /// Contribution shares can only be required from players.
+    pub fn check_contrib_request(&self, request: &ContribRequest) -> Result<(), SpectatorError> {
+        request
+            .required_from
+            .iter()
+            .try_for_each(|peer| self.check_player(*peer))
+    }
+
+    /// A timeout claim counts only if a player makes it against a player.
+    pub fn check_timeout_claim(
+        &self,
+        claimer: PeerId,
+        claim: &TimeoutClaim,
+    ) -> Result<(), SpectatorError> {
+        self.check_player(claimer)?;
+        self.check_player(claim.missing_peer)
+    }
+
+    /// A forfeit counts only if it's against a player and every claim in it
+    /// was made by a player.
+    pub fn check_forfeit_commit(&self, forfeit: &ForfeitCommit) -> Result<(), SpectatorError> {
+        self.check_player(forfeit.missing_peer)?;
+        forfeit
+            .claim_sigs
+            .iter()
+            .try_for_each(|(claimer, _)| self.check_player(*claimer))
+    }
+
+    /// Hidden zones may only be revealed to players.
+    pub fn check_state_commitment(
+        &self,
+        commitment: &StateCommitment,
+    ) -> Result<(), SpectatorError> {
+        for zone in &commitment.hidden_zones {
+            if let Some(spectator) = zone
+                .commitment
+                .viewers
+                .iter()
+                .find(|viewer| self.spectators.contains(viewer))
+            {
+                return Err(SpectatorError::SpectatorViewer {
+                    owner: zone.owner,
+                    zone: zone.zone,
+                    spectator: *spectator,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Answer an admitted spectator with the current public state.
+pub fn respond_to_spectator_join(
+    signer: &impl Signer,
+    responder: PeerId,
+    join: &SpectatorJoin,
+    commitment: StateCommitment,
+    seq: SeqNum,
+    snapshot: &PublicStateSnapshot,
+) -> ResyncResponse {
+    let payload = ResyncPayload::Snapshot {
+        seq,
+        snapshot: Box::new(snapshot.clone()),
+    };
+    build_resync_response(signer, join.join_id, responder, commitment, payload)
+}
+
+/// A spectator's copy of the public state, advanced one committed action at
+/// a time.
+#[derive(Debug, Clone)]
+pub struct SpectatorStream {
+    player_keys: HashMap<PeerId, PubKey>,
+    seq: SeqNum,
+    snapshot: PublicStateSnapshot,
+}
+
+impl SpectatorStream {
+    /// Start watching from a player's answer to `join`.
+    pub fn join(
+        join: &SpectatorJoin,
+        response: &ResyncResponse,
+        player_keys: HashMap<PeerId, PubKey>,
+    ) -> Result<Self, SpectatorError> {
+        if response.request_id != join.join_id {
+            return Err(SpectatorError::WrongJoin {
+                expected: join.join_id,
+                found: response.request_id,
+            });
+        }
+        let Some(pubkey) = player_keys.get(&response.responder).copied() else {
+            return Err(SpectatorError::UnknownPeer(response.responder));
+        };
+        if !verify_resync_response(pubkey, response) {
+            return Err(SpectatorError::InvalidSignature(response.responder));
+        }
+        let ResyncPayload::Snapshot { seq, snapshot } = &response.payload else {
+            return Err(SpectatorError::ExpectedSnapshot);
+        };
+        check_state_hash(
+            response.commitment.public_state_hash,
+            hash_public_state_snapshot(snapshot),
+        )
+        .map_err(SpectatorError::StateMismatch)?;
+        Ok(Self {
+            player_keys,
+            seq: *seq,
+            snapshot: snapshot.as_ref().clone(),
+        })
+    }
+
+    /// Sequence number of the next committed action.
+    pub fn seq(&self) -> SeqNum {
+        self.seq
+    }
+
+    pub fn snapshot(&self) -> &PublicStateSnapshot {
+        &self.snapshot
+    }
+
+    pub fn state_hash(&self) -> Hash32 {
+        hash_public_state_snapshot(&self.snapshot)
+    }
+
+    /// Apply the next committed action through the public update it
+    /// produced. Returns the new public state hash.
+    pub fn apply(
+        &mut self,
+        action: &CommittedAction,
+        update: &StateUpdate,
+    ) -> Result<Hash32, SpectatorError> {
+        let seq = self.seq;
+        if !committed_action_is_valid(&self.player_keys, action) {
+            return Err(SpectatorError::InvalidCommit { seq });
+        }
+        check_state_hash(self.state_hash(), action.propose.prev_state_hash)
+            .map_err(|error| SpectatorError::BrokenChain { seq, error })?;
+        self.snapshot =
+            apply_state_update(&self.snapshot, update).map_err(SpectatorError::Update)?;
+        self.seq += 1;
+        Ok(self.state_hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardBuilder;
+    use crate::game_state::GameState;
+    use crate::ids::{CardId, PlayerId};
+    use crate::net::crypto::Secp256k1Signer;
+    use crate::net::message::{
+        build_action_ack, build_action_commit, build_action_propose, build_contrib_request,
+        build_forfeit_commit, build_spectator_join, build_timeout_claim,
+    };
+    use crate::net::state::{build_public_state_snapshot, build_state_update, hash_public_state};
+    use crate::net::{
+        ActionKind, ActionPayload, ActionPropose, CommitmentScheme, HiddenZoneCommitment,
+        ProofBundle, TimeoutReason, ZoneCommitment,
+    };
+    use crate::types::CardType;
+    use crate::zone::Zone;
+
+    const SESSION: SessionId = SessionId([7u8; 32]);
+
+    struct Player {
+        id: PeerId,
+        signer: Secp256k1Signer,
+        game: GameState,
+    }
+
+    fn signer(byte: u8) -> Secp256k1Signer {
+        Secp256k1Signer::from_secret_bytes([byte; 32]).expect("signer")
+    }
+
+    fn new_game() -> GameState {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let card = CardBuilder::new(CardId::from_raw(1), "Library Card")
+            .card_types(vec![CardType::Instant])
+            .build();
+        for index in 0..2 {
+            for _ in 0..10 {
+                game.create_object_from_card(&card, PlayerId::from_index(index), Zone::Library);
+            }
+        }
+        game
+    }
+
+    fn players() -> Vec<Player> {
+        (1..=2)
+            .map(|byte| Player {
+                id: PeerId([byte; 32]),
+                signer: signer(byte),
+                game: new_game(),
+            })
+            .collect()
+    }
+
+    fn player_keys(players: &[Player]) -> HashMap<PeerId, PubKey> {
+        players
+            .iter()
+            .map(|player| (player.id, player.signer.public_key()))
+            .collect()
+    }
+
+    /// The active player draws and the turn passes on.
+    fn apply_action(game: &mut GameState, propose: &ActionPropose) {
+        if let ActionPayload::DrawCard { count } = propose.action {
+            game.draw_cards(game.turn.active_player, count as usize);
+        }
+        let next = (game.turn.active_player.index() + 1) % 2;
+        game.turn.active_player = PlayerId::from_index(next as u8);
+    }
+
+    /// Commit one action among the players; only players ack.
+    fn commit_action(players: &mut [Player], proposer: usize) -> CommittedAction {
+        let propose = build_action_propose(
+            &players[proposer].signer,
+            hash_public_state(&players[proposer].game),
+            ActionPayload::DrawCard { count: 1 },
+            ProofBundle::default(),
+            None,
+        );
+        let acks = players
+            .iter()
+            .map(|player| {
+                let ack = build_action_ack(&player.signer, propose.action_id);
+                (player.id, ack.ack_sig)
+            })
+            .collect();
+        let commit = build_action_commit(&players[proposer].signer, propose.action_id, acks);
+        for player in players.iter_mut() {
+            apply_action(&mut player.game, &propose);
+        }
+        CommittedAction { propose, commit }
+    }
+
+    fn hand_commitment(game: &GameState, owner: u8, viewers: Vec<PeerId>) -> StateCommitment {
+        StateCommitment {
+            version: 1,
+            public_state_hash: hash_public_state(game),
+            hidden_zones: vec![HiddenZoneCommitment {
+                zone: ZoneCode::Hand,
+                owner: GamePlayerId(owner),
+                commitment: ZoneCommitment {
+                    scheme: CommitmentScheme::ElgamalSecp256k1Sha256MerkleV1,
+                    root: Hash32([owner; 32]),
+                    len: 0,
+                    top: 0,
+                    viewers,
+                },
+            }],
+        }
+    }
+
+    fn join_as_spectator(
+        roster: &mut SessionRoster,
+        players: &[Player],
+        seq: SeqNum,
+    ) -> SpectatorStream {
+        let spectator = signer(9);
+        let join = build_spectator_join(&spectator, PeerId([9u8; 32]), SESSION);
+        roster
+            .admit_spectator(spectator.public_key(), &join)
+            .expect("spectator should be admitted");
+
+        let host = &players[0];
+        let commitment = hand_commitment(&host.game, 0, vec![host.id]);
+        roster
+            .check_state_commitment(&commitment)
+            .expect("hands are only visible to players");
+        let response = respond_to_spectator_join(
+            &host.signer,
+            host.id,
+            &join,
+            commitment,
+            seq,
+            &build_public_state_snapshot(&host.game),
+        );
+        SpectatorStream::join(&join, &response, player_keys(players)).expect("join should verify")
+    }
+
+    #[test]
+    fn spectator_tracks_public_state_hash_at_every_commit() {
+        let mut players = players();
+        let mut roster = SessionRoster::new(SESSION, players.iter().map(|p| p.id).collect());
+
+        // The spectator arrives after the game has started.
+        commit_action(&mut players, 0);
+        let mut stream = join_as_spectator(&mut roster, &players, 1);
+        assert_eq!(stream.state_hash(), hash_public_state(&players[0].game));
+
+        let mut previous = build_public_state_snapshot(&players[0].game);
+        for turn in 0..6 {
+            let action = commit_action(&mut players, turn % 2);
+            let current = build_public_state_snapshot(&players[0].game);
+            let update = build_state_update(&previous, stream.state_hash(), &current);
+            assert!(matches!(update, StateUpdate::Delta(_)));
+
+            let hash = stream.apply(&action, &update).expect("commit should apply");
+            assert_eq!(hash, hash_public_state(&players[0].game));
+            assert_eq!(hash, hash_public_state(&players[1].game));
+            previous = current;
+        }
+        assert_eq!(stream.seq(), 7);
+    }
+
+    #[test]
+    fn spectator_rejects_commits_acked_by_non_players() {
+        let mut players = players();
+        let mut roster = SessionRoster::new(SESSION, players.iter().map(|p| p.id).collect());
+        let mut stream = join_as_spectator(&mut roster, &players, 0);
+        let before = build_public_state_snapshot(&players[0].game);
+
+        let mut action = commit_action(&mut players, 0);
+        let outsider = signer(9);
+        let ack = build_action_ack(&outsider, action.propose.action_id);
+        action
+            .commit
+            .ack_sigs
+            .push((PeerId([9u8; 32]), ack.ack_sig));
+        let update = build_state_update(
+            &before,
+            stream.state_hash(),
+            &build_public_state_snapshot(&players[0].game),
+        );
+
+        assert_eq!(
+            stream.apply(&action, &update),
+            Err(SpectatorError::InvalidCommit { seq: 0 })
+        );
+    }
+
+    #[test]
+    fn spectators_take_no_part_in_contributions_or_timeouts() {
+        let players = players();
+        let mut roster = SessionRoster::new(SESSION, players.iter().map(|p| p.id).collect());
+        join_as_spectator(&mut roster, &players, 0);
+        let spectator = PeerId([9u8; 32]);
+        let (alice, bob) = (&players[0], &players[1]);
+
+        assert_eq!(roster.role(spectator), Some(PeerRole::Spectator));
+        assert_eq!(
+            roster.check_player(spectator),
+            Err(SpectatorError::NotAPlayer(spectator))
+        );
+
+        let request = build_contrib_request(
+            &alice.signer,
+            hash_public_state(&alice.game),
+            ActionKind::ShuffleLibrary,
+            vec![bob.id, spectator],
+            1_000,
+        );
+        assert_eq!(
+            roster.check_contrib_request(&request),
+            Err(SpectatorError::NotAPlayer(spectator))
+        );
+
+        let against_spectator = build_timeout_claim(
+            &alice.signer,
+            request.request_id,
+            spectator,
+            TimeoutReason::MissingShare,
+        );
+        assert!(
+            roster
+                .check_timeout_claim(alice.id, &against_spectator)
+                .is_err()
+        );
+        let by_spectator = build_timeout_claim(
+            &signer(9),
+            request.request_id,
+            bob.id,
+            TimeoutReason::MissingShare,
+        );
+        assert!(
+            roster
+                .check_timeout_claim(spectator, &by_spectator)
+                .is_err()
+        );
+
+        let forfeit = build_forfeit_commit(
+            bob.id,
+            TimeoutReason::MissingShare,
+            vec![(spectator, by_spectator.claimer_sig)],
+        );
+        assert_eq!(
+            roster.check_forfeit_commit(&forfeit),
+            Err(SpectatorError::NotAPlayer(spectator))
+        );
+
+        let leaked = hand_commitment(&alice.game, 0, vec![alice.id, spectator]);
+        assert_eq!(
+            roster.check_state_commitment(&leaked),
+            Err(SpectatorError::SpectatorViewer {
+                owner: GamePlayerId(0),
+                zone: ZoneCode::Hand,
+                spectator,
+            })
+        );
+    }
+
+    #[test]
+    fn spectator_join_is_checked() {
+        let players = players();
+        let mut roster = SessionRoster::new(SESSION, players.iter().map(|p| p.id).collect());
+        let spectator = signer(9);
+
+        let elsewhere = build_spectator_join(&spectator, PeerId([9u8; 32]), SessionId([8u8; 32]));
+        assert!(matches!(
+            roster.admit_spectator(spectator.public_key(), &elsewhere),
+            Err(SpectatorError::WrongSession { .. })
+        ));
+
+        let player = &players[1];
+        let player_join = build_spectator_join(&player.signer, player.id, SESSION);
+        assert_eq!(
+            roster.admit_spectator(player.signer.public_key(), &player_join),
+            Err(SpectatorError::AlreadyInSession(player.id))
+        );
+
+        let forged = build_spectator_join(&signer(10), PeerId([9u8; 32]), SESSION);
+        assert_eq!(
+            roster.admit_spectator(spectator.public_key(), &forged),
+            Err(SpectatorError::InvalidSignature(PeerId([9u8; 32])))
+        );
+        assert!(roster.spectators().is_empty());
+    }
+}
@@ -8,6 +8,7 @@ use crate::mana::ManaCost;
 use crate::object::{CounterType, Object, ObjectKind};
 use crate::player::Player;
 use crate::zone::Zone;
+use std::collections::{HashMap, HashSet};
 
 use super::adapters::{optional_costs_to_spec, targets_from_game};
 use super::crypto::{
@@ -15,9 +16,10 @@ use super::crypto::{
     DOMAIN_TRACKERS_STATE, hash_bytes,
 };
 use super::{
-    ActionPropose, CanonicalEncode, ContribRequest, GameObjectId, GamePlayerId, Hash32,
-    ManaPoolSpec, ManaSymbolSpec, ObjectKindCode, PubKey, PublicObjectState, PublicPlayerState,
-    PublicStackEntry, PublicStateSnapshot, PublicTurnState, PublicZoneIndex, TargetSpec, ZoneCode,
+    ActionPropose, CanonicalDecode, CanonicalEncode, CodecError, ContribRequest, GameObjectId,
+    GamePlayerId, Hash32, ManaPoolSpec, ManaSymbolSpec, ObjectKindCode, PubKey, PublicObjectState,
+    PublicPlayerState, PublicStackEntry, PublicStateSnapshot, PublicTurnState, PublicZoneIndex,
+    TargetSpec, ZoneCode,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +39,111 @@ pub enum ContribVerifyError {
     PrevState(StateRootError),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateDeltaError {
+    /// The delta was computed against a different state than the receiver's.
+    BaseMismatch(StateRootError),
+    /// Applying the delta did not reproduce the sender's state.
+    TargetMismatch(StateRootError),
+}
+
+/// Changes between two public state snapshots.
+///
+/// Players and objects are keyed by id and only the entries that differ are
+/// carried. The remaining sections are small or order-sensitive, so they are
+/// replaced wholesale when they change and left as `None` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateDelta {
+    pub base_hash: Hash32,
+    pub target_hash: Hash32,
+    pub version: Option<u8>,
+    pub turn: Option<PublicTurnState>,
+    pub turn_order: Option<Vec<GamePlayerId>>,
+    /// Players that are new or whose public fields changed, in id order.
+    pub players_changed: Vec<PublicPlayerState>,
+    pub players_removed: Vec<GamePlayerId>,
+    pub zones: Option<PublicZoneIndex>,
+    pub objects_added: Vec<PublicObjectState>,
+    pub objects_changed: Vec<PublicObjectState>,
+    pub objects_removed: Vec<GameObjectId>,
+    pub stack: Option<Vec<PublicStackEntry>>,
+    pub combat_hash: Option<Option<Hash32>>,
+    pub trackers_hash: Option<Hash32>,
+}
+
+impl CanonicalEncode for StateDelta {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.base_hash.encode(out);
+        self.target_hash.encode(out);
+        self.version.encode(out);
+        self.turn.encode(out);
+        self.turn_order.encode(out);
+        self.players_changed.encode(out);
+        self.players_removed.encode(out);
+        self.zones.encode(out);
+        self.objects_added.encode(out);
+        self.objects_changed.encode(out);
+        self.objects_removed.encode(out);
+        self.stack.encode(out);
+        self.combat_hash.encode(out);
+        self.trackers_hash.encode(out);
+    }
+}
+
+impl CanonicalDecode for StateDelta {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        Ok(StateDelta {
+            base_hash: Hash32::decode(input)?,
+            target_hash: Hash32::decode(input)?,
+            version: Option::<u8>::decode(input)?,
+            turn: Option::<PublicTurnState>::decode(input)?,
+            turn_order: Option::<Vec<GamePlayerId>>::decode(input)?,
+            players_changed: Vec::<PublicPlayerState>::decode(input)?,
+            players_removed: Vec::<GamePlayerId>::decode(input)?,
+            zones: Option::<PublicZoneIndex>::decode(input)?,
+            objects_added: Vec::<PublicObjectState>::decode(input)?,
+            objects_changed: Vec::<PublicObjectState>::decode(input)?,
+            objects_removed: Vec::<GameObjectId>::decode(input)?,
+            stack: Option::<Vec<PublicStackEntry>>::decode(input)?,
+            combat_hash: Option::<Option<Hash32>>::decode(input)?,
+            trackers_hash: Option::<Hash32>::decode(input)?,
+        })
+    }
+}
+
+/// What a peer is sent to bring it up to date: a delta when it holds the
+/// state the delta was computed against, the full snapshot otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateUpdate {
+    Delta(StateDelta),
+    Full(PublicStateSnapshot),
+}
+
+impl CanonicalEncode for StateUpdate {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            StateUpdate::Delta(delta) => {
+                0u8.encode(out);
+                delta.encode(out);
+            }
+            StateUpdate::Full(snapshot) => {
+                1u8.encode(out);
+                snapshot.encode(out);
+            }
+        }
+    }
+}
+
+impl CanonicalDecode for StateUpdate {
+    fn decode(input: &mut &[u8]) -> Result<Self, CodecError> {
+        match u8::decode(input)? {
+            0 => Ok(StateUpdate::Delta(StateDelta::decode(input)?)),
+            1 => Ok(StateUpdate::Full(PublicStateSnapshot::decode(input)?)),
+            tag => Err(CodecError::InvalidTag(tag)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct AbilityPublicSpec {
     kind_tag: u8,
@@ -298,6 +405,15 @@ impl CanonicalEncode for PreventionEffectsDigest {
     }
 }
 
+/// Canonical form of the game's trackers, hashed into `trackers_hash`.
+///
+/// Fields are encoded in declaration order: the can't-effects, mana-spend
+/// effects, per-turn counters, turn skips, the continuous/replacement/
+/// prevention effect managers, pending triggers and choices, restrictions,
+/// grants, player control effects, and finally the combat state. Every set
+/// is sorted by id and every map by key, so the encoding doesn't depend on
+/// hash map iteration order. Changing the field list requires bumping
+/// `DOMAIN_TRACKERS_STATE`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct TrackerDigest {
     cant_gain_life: Vec<GamePlayerId>,
@@ -320,6 +436,20 @@ struct TrackerDigest {
     cant_win_game: Vec<GamePlayerId>,
     cant_be_targeted: Vec<GameObjectId>,
     cant_be_countered: Vec<GameObjectId>,
+    cant_attack_players: Vec<(GameObjectId, Vec<GamePlayerId>)>,
+    cant_attack_planeswalkers_of: Vec<(GameObjectId, Vec<GamePlayerId>)>,
+    cant_attack_alone: Vec<GameObjectId>,
+    cant_block_specific_attackers: Vec<(GameObjectId, Vec<GameObjectId>)>,
+    must_block_specific_attackers: Vec<(GameObjectId, Vec<GameObjectId>)>,
+    cant_block_alone: Vec<GameObjectId>,
+    cant_activate_non_mana_abilities: Vec<GamePlayerId>,
+    cant_activate_abilities_of: Vec<GameObjectId>,
+    cant_activate_tap_abilities_of: Vec<GameObjectId>,
+    cant_activate_non_mana_abilities_of: Vec<GameObjectId>,
+    cant_cast_limit_filters: Vec<(GamePlayerId, String)>,
+    cant_target_players: Vec<GamePlayerId>,
+    cant_be_countered_by: Vec<(GameObjectId, Vec<GamePlayerId>)>,
+    cant_transform: Vec<GameObjectId>,
     any_color_players: Vec<GamePlayerId>,
     any_color_activation_sources: Vec<GameObjectId>,
     activated_abilities_this_turn: Vec<(GameObjectId, u32)>,
@@ -332,6 +462,7 @@ struct TrackerDigest {
     damage_to_players_this_turn: Vec<(GamePlayerId, u32)>,
     extra_turns: Vec<GamePlayerId>,
     skip_next_turn: Vec<GamePlayerId>,
+    skip_next_draw_step: Vec<GamePlayerId>,
     skip_next_combat_phases: Vec<GamePlayerId>,
     creatures_died_this_turn: u32,
     turn_counters: Vec<(String, u32)>,
@@ -345,6 +476,7 @@ struct TrackerDigest {
     grant_registry: Vec<String>,
     player_control_effects: Vec<String>,
     player_control_timestamp: u64,
+    combat: Option<Hash32>,
 }
 
 impl CanonicalEncode for TrackerDigest {
@@ -369,6 +501,20 @@ impl CanonicalEncode for TrackerDigest {
         self.cant_win_game.encode(out);
         self.cant_be_targeted.encode(out);
         self.cant_be_countered.encode(out);
+        self.cant_attack_players.encode(out);
+        self.cant_attack_planeswalkers_of.encode(out);
+        self.cant_attack_alone.encode(out);
+        self.cant_block_specific_attackers.encode(out);
+        self.must_block_specific_attackers.encode(out);
+        self.cant_block_alone.encode(out);
+        self.cant_activate_non_mana_abilities.encode(out);
+        self.cant_activate_abilities_of.encode(out);
+        self.cant_activate_tap_abilities_of.encode(out);
+        self.cant_activate_non_mana_abilities_of.encode(out);
+        self.cant_cast_limit_filters.encode(out);
+        self.cant_target_players.encode(out);
+        self.cant_be_countered_by.encode(out);
+        self.cant_transform.encode(out);
         self.any_color_players.encode(out);
         self.any_color_activation_sources.encode(out);
         self.activated_abilities_this_turn.encode(out);
@@ -381,6 +527,7 @@ impl CanonicalEncode for TrackerDigest {
         self.damage_to_players_this_turn.encode(out);
         self.extra_turns.encode(out);
         self.skip_next_turn.encode(out);
+        self.skip_next_draw_step.encode(out);
         self.skip_next_combat_phases.encode(out);
         self.creatures_died_this_turn.encode(out);
         self.turn_counters.encode(out);
@@ -394,6 +541,7 @@ impl CanonicalEncode for TrackerDigest {
         self.grant_registry.encode(out);
         self.player_control_effects.encode(out);
         self.player_control_timestamp.encode(out);
+        self.combat.encode(out);
     }
 }
 
@@ -413,10 +561,17 @@ pub fn build_public_state_snapshot(game: &GameState) -> PublicStateSnapshot {
         .collect();
     players.sort_by_key(|p| p.id.0);
 
+    let public_zone = |zone: Zone| {
+        sort_objects(
+            game.players
+                .iter()
+                .flat_map(|player| game.objects_in(zone, player.id)),
+        )
+    };
     let zones = PublicZoneIndex {
-        battlefield: sort_objects(game.battlefield.iter().copied()),
-        exile: sort_objects(game.exile.iter().copied()),
-        command_zone: sort_objects(game.command_zone.iter().copied()),
+        battlefield: public_zone(Zone::Battlefield),
+        exile: public_zone(Zone::Exile),
+        command_zone: public_zone(Zone::Command),
     };
 
     let mut objects: Vec<PublicObjectState> = game
@@ -433,7 +588,7 @@ pub fn build_public_state_snapshot(game: &GameState) -> PublicStateSnapshot {
         .collect();
 
     let combat_hash = game.combat.as_ref().map(hash_combat_state);
-    let trackers_hash = hash_trackers_state(game);
+    let trackers_hash = game.trackers_hash();
 
     PublicStateSnapshot {
         version: 1,
@@ -452,6 +607,18 @@ pub fn hash_public_state_snapshot(snapshot: &PublicStateSnapshot) -> Hash32 {
     hash_bytes(DOMAIN_PUBLIC_STATE, snapshot)
 }
 
+impl GameState {
+    /// Hash of the game's trackers, as carried in
+    /// `PublicStateSnapshot::trackers_hash`.
+    ///
+    /// Two engines agree on this hash whenever their can't-effects, effect
+    /// managers, per-turn counters and combat state agree, regardless of
+    /// hash map iteration order. See `TrackerDigest` for the field ordering.
+    pub fn trackers_hash(&self) -> Hash32 {
+        hash_trackers_state(self)
+    }
+}
+
 pub fn hash_public_state(game: &GameState) -> Hash32 {
     hash_public_state_snapshot(&build_public_state_snapshot(game))
 }
@@ -460,15 +627,7 @@ pub fn verify_prev_state_hash(
     game: &GameState,
     prev_state_hash: Hash32,
 ) -> Result<(), StateRootError> {
-    let expected = hash_public_state(game);
-    if expected == prev_state_hash {
-        Ok(())
-    } else {
-        Err(StateRootError::Mismatch {
-            expected,
-            found: prev_state_hash,
-        })
-    }
+    check_state_hash(hash_public_state(game), prev_state_hash)
 }
 
 pub fn verify_action_propose_for_game(
@@ -493,6 +652,174 @@ pub fn verify_contrib_request_for_game(
     verify_prev_state_hash(game, request.prev_state_hash).map_err(ContribVerifyError::PrevState)
 }
 
+pub fn diff_public_state(base: &PublicStateSnapshot, target: &PublicStateSnapshot) -> StateDelta {
+    let (players_added, mut players_changed, players_removed) =
+        diff_keyed(&base.players, &target.players, |player| player.id);
+    players_changed.extend(players_added);
+    players_changed.sort_by_key(|player| player.id);
+    let (objects_added, objects_changed, objects_removed) =
+        diff_keyed(&base.objects, &target.objects, |obj| obj.id);
+
+    StateDelta {
+        base_hash: hash_public_state_snapshot(base),
+        target_hash: hash_public_state_snapshot(target),
+        version: changed(&base.version, &target.version),
+        turn: changed(&base.turn, &target.turn),
+        turn_order: changed(&base.turn_order, &target.turn_order),
+        players_changed,
+        players_removed,
+        zones: changed(&base.zones, &target.zones),
+        objects_added,
+        objects_changed,
+        objects_removed,
+        stack: changed(&base.stack, &target.stack),
+        combat_hash: changed(&base.combat_hash, &target.combat_hash),
+        trackers_hash: changed(&base.trackers_hash, &target.trackers_hash),
+    }
+}
+
+/// Rebuilds the target snapshot of `delta` from `base`, checking the hashes
+/// of both ends so a stale base or a corrupt delta never goes unnoticed.
+pub fn apply_delta(
+    base: &PublicStateSnapshot,
+    delta: &StateDelta,
+) -> Result<PublicStateSnapshot, StateDeltaError> {
+    check_state_hash(hash_public_state_snapshot(base), delta.base_hash)
+        .map_err(StateDeltaError::BaseMismatch)?;
+
+    let players = apply_keyed(
+        &base.players,
+        &delta.players_changed,
+        &delta.players_removed,
+        |player| player.id,
+    );
+    let objects = apply_keyed(
+        &base.objects,
+        delta.objects_added.iter().chain(&delta.objects_changed),
+        &delta.objects_removed,
+        |obj| obj.id,
+    );
+    let snapshot = PublicStateSnapshot {
+        version: delta.version.unwrap_or(base.version),
+        turn: delta.turn.clone().unwrap_or_else(|| base.turn.clone()),
+        turn_order: delta
+            .turn_order
+            .clone()
+            .unwrap_or_else(|| base.turn_order.clone()),
+        players,
+        zones: delta.zones.clone().unwrap_or_else(|| base.zones.clone()),
+        objects,
+        stack: delta.stack.clone().unwrap_or_else(|| base.stack.clone()),
+        combat_hash: delta.combat_hash.unwrap_or(base.combat_hash),
+        trackers_hash: delta.trackers_hash.unwrap_or(base.trackers_hash),
+    };
+
+    check_state_hash(hash_public_state_snapshot(&snapshot), delta.target_hash)
+        .map_err(StateDeltaError::TargetMismatch)?;
+    Ok(snapshot)
+}
+
+/// Builds the update for a peer that last acknowledged `peer_state_hash`.
+/// The delta against `base` is only usable if that is the state the peer
+/// holds; anything else falls back to the full snapshot.
+pub fn build_state_update(
+    base: &PublicStateSnapshot,
+    peer_state_hash: Hash32,
+    target: &PublicStateSnapshot,
+) -> StateUpdate {
+    if hash_public_state_snapshot(base) == peer_state_hash {
+        StateUpdate::Delta(diff_public_state(base, target))
+    } else {
+        StateUpdate::Full(target.clone())
+    }
+}
+
+pub fn apply_state_update(
+    current: &PublicStateSnapshot,
+    update: &StateUpdate,
+) -> Result<PublicStateSnapshot, StateDeltaError> {
+    match update {
+        StateUpdate::Delta(delta) => apply_delta(current, delta),
+        StateUpdate::Full(snapshot) => Ok(snapshot.clone()),
+    }
+}
+
+fn changed<T: Clone + PartialEq>(base: &T, target: &T) -> Option<T> {
+    (base != target).then(|| target.clone())
+}
+
+pub(super) fn check_state_hash(expected: Hash32, found: Hash32) -> Result<(), StateRootError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(StateRootError::Mismatch { expected, found })
+    }
+}
+
+/// Splits two id-sorted lists into the entries only in `target`, the entries
+/// in both that differ, and the ids only in `base`.
+fn diff_keyed<T: Clone + PartialEq, K: Ord>(
+    base: &[T],
+    target: &[T],
+    key: impl Fn(&T) -> K,
+) -> (Vec<T>, Vec<T>, Vec<K>) {
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut removed = Vec::new();
+    let mut base_iter = base.iter().peekable();
+    let mut target_iter = target.iter().peekable();
+    loop {
+        match (base_iter.peek(), target_iter.peek()) {
+            (Some(old), Some(new)) => match key(old).cmp(&key(new)) {
+                std::cmp::Ordering::Less => {
+                    removed.push(key(old));
+                    base_iter.next();
+                }
+                std::cmp::Ordering::Greater => {
+                    added.push((*new).clone());
+                    target_iter.next();
+                }
+                std::cmp::Ordering::Equal => {
+                    if old != new {
+                        changed.push((*new).clone());
+                    }
+                    base_iter.next();
+                    target_iter.next();
+                }
+            },
+            (Some(old), None) => {
+                removed.push(key(old));
+                base_iter.next();
+            }
+            (None, Some(new)) => {
+                added.push((*new).clone());
+                target_iter.next();
+            }
+            (None, None) => break,
+        }
+    }
+    (added, changed, removed)
+}
+
+fn apply_keyed<'a, T: Clone + 'a, K: Ord>(
+    base: &[T],
+    upserts: impl IntoIterator<Item = &'a T>,
+    removed: &[K],
+    key: impl Fn(&T) -> K,
+) -> Vec<T> {
+    let mut entries: std::collections::BTreeMap<K, T> = base
+        .iter()
+        .map(|entry| (key(entry), entry.clone()))
+        .collect();
+    for id in removed {
+        entries.remove(id);
+    }
+    for entry in upserts {
+        entries.insert(key(entry), entry.clone());
+    }
+    entries.into_values().collect()
+}
+
 fn public_player_state(player: &Player) -> PublicPlayerState {
     let mut commander_damage: Vec<(GameObjectId, u32)> = player
         .commander_damage
@@ -530,8 +857,8 @@ fn public_player_state(player: &Player) -> PublicPlayerState {
 }
 
 fn public_object_state(game: &GameState, obj: &Object) -> PublicObjectState {
-    let is_face_down = game.is_face_down(obj.id);
-    let card_ref = if is_face_down {
+    let is_hidden = !game.can_see_object(obj.id, None);
+    let card_ref = if is_hidden {
         None
     } else {
         match obj.kind {
@@ -553,9 +880,9 @@ fn public_object_state(game: &GameState, obj: &Object) -> PublicObjectState {
 }
 
 fn public_stack_entry(game: &GameState, entry: &StackEntry) -> PublicStackEntry {
-    let is_face_down = game
+    let is_hidden = game
         .object(entry.object_id)
-        .is_some_and(|obj| game.is_face_down(obj.id));
+        .is_some_and(|obj| !game.can_see_object(obj.id, None));
 
     let digest = PublicStackDigest {
         object_id: entry.object_id.into(),
@@ -564,7 +891,7 @@ fn public_stack_entry(game: &GameState, entry: &StackEntry) -> PublicStackEntry
         x_value: entry.x_value,
         is_ability: entry.is_ability,
         casting_method: casting_method_spec(&entry.casting_method),
-        optional_costs: if is_face_down {
+        optional_costs: if is_hidden {
             Vec::new()
         } else {
             optional_costs_to_spec(&entry.optional_costs_paid)
@@ -574,7 +901,7 @@ fn public_stack_entry(game: &GameState, entry: &StackEntry) -> PublicStackEntry
         source_stable_id: entry
             .source_stable_id
             .map(|stable| GameObjectId::from(stable.object_id())),
-        source_name: if is_face_down {
+        source_name: if is_hidden {
             None
         } else {
             entry.source_name.clone()
@@ -689,17 +1016,7 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
     let restriction_effects = debug_list(game.restriction_effects.iter());
     let grant_registry = debug_list(game.grant_registry.grants.iter());
     let player_control_effects = debug_list(game.player_control_effects.iter());
-    let mut cant_cast_filters: Vec<(GamePlayerId, String)> = game
-        .cant_effects
-        .cant_cast_filters
-        .iter()
-        .flat_map(|(player, filters)| {
-            filters
-                .iter()
-                .map(|filter| ((*player).into(), format!("{:?}", filter)))
-        })
-        .collect();
-    cant_cast_filters.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+    let cant = &game.cant_effects;
 
     let digest = TrackerDigest {
         cant_gain_life: sort_players(game.cant_effects.cant_gain_life.iter().copied()),
@@ -718,7 +1035,7 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
                     .then_some(*player)
             },
         )),
-        cant_cast_filters,
+        cant_cast_filters: sort_player_filters(&cant.cant_cast_filters),
         cant_draw: sort_players(game.cant_effects.cant_draw.iter().copied()),
         cant_draw_extra_cards: sort_players(
             game.cant_effects.cant_draw_extra_cards.iter().copied(),
@@ -735,6 +1052,26 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
         cant_win_game: sort_players(game.cant_effects.cant_win_game.iter().copied()),
         cant_be_targeted: sort_objects(game.cant_effects.cant_be_targeted.iter().copied()),
         cant_be_countered: sort_objects(game.cant_effects.cant_be_countered.iter().copied()),
+        cant_attack_players: sort_object_player_sets(&cant.cant_attack_players),
+        cant_attack_planeswalkers_of: sort_object_player_sets(&cant.cant_attack_planeswalkers_of),
+        cant_attack_alone: sort_objects(cant.cant_attack_alone.iter().copied()),
+        cant_block_specific_attackers: sort_object_object_sets(&cant.cant_block_specific_attackers),
+        must_block_specific_attackers: sort_object_object_sets(&cant.must_block_specific_attackers),
+        cant_block_alone: sort_objects(cant.cant_block_alone.iter().copied()),
+        cant_activate_non_mana_abilities: sort_players(
+            cant.cant_activate_non_mana_abilities.iter().copied(),
+        ),
+        cant_activate_abilities_of: sort_objects(cant.cant_activate_abilities_of.iter().copied()),
+        cant_activate_tap_abilities_of: sort_objects(
+            cant.cant_activate_tap_abilities_of.iter().copied(),
+        ),
+        cant_activate_non_mana_abilities_of: sort_objects(
+            cant.cant_activate_non_mana_abilities_of.iter().copied(),
+        ),
+        cant_cast_limit_filters: sort_player_filters(&cant.cant_cast_limit_filters),
+        cant_target_players: sort_players(cant.cant_target_players.iter().copied()),
+        cant_be_countered_by: sort_object_player_sets(&cant.cant_be_countered_by),
+        cant_transform: sort_objects(cant.cant_transform.iter().copied()),
         any_color_players: sort_players(game.mana_spend_effects.any_color_players.iter().copied()),
         any_color_activation_sources: sort_objects(
             game.mana_spend_effects
@@ -744,7 +1081,7 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
         ),
         activated_abilities_this_turn: sort_object_pairs(game.activated_abilities_this_turn.iter()),
         cards_drawn_this_turn: sort_player_counts(game.cards_drawn_this_turn.iter()),
-        spells_cast_this_turn: sort_player_counts(game.spells_cast_this_turn.iter()),
+        spells_cast_this_turn: sort_player_counts(game.turn_trackers.spells_cast.iter()),
         spells_cast_last_turn_total: game.spells_cast_last_turn_total,
         library_searches_this_turn: sort_players(game.library_searches_this_turn.iter().copied()),
         creatures_entered_this_turn: sort_player_counts(game.creatures_entered_this_turn.iter()),
@@ -754,8 +1091,9 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
         damage_to_players_this_turn: sort_player_counts(game.damage_to_players_this_turn.iter()),
         extra_turns: game.extra_turns.iter().copied().map(Into::into).collect(),
         skip_next_turn: sort_players(game.skip_next_turn.iter().copied()),
+        skip_next_draw_step: sort_players(game.skip_next_draw_step.iter().copied()),
         skip_next_combat_phases: sort_players(game.skip_next_combat_phases.iter().copied()),
-        creatures_died_this_turn: game.creatures_died_this_turn,
+        creatures_died_this_turn: game.turn_trackers.creatures_died,
         turn_counters: sort_turn_counters(game.turn_counters.snapshot()),
         continuous_effects,
         replacement_effects,
@@ -767,6 +1105,7 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
         grant_registry,
         player_control_effects,
         player_control_timestamp: game.player_control_timestamp,
+        combat: game.combat.as_ref().map(hash_combat_state),
     };
 
     hash_bytes(DOMAIN_TRACKERS_STATE, &digest)
@@ -774,7 +1113,7 @@ fn hash_trackers_state(game: &GameState) -> Hash32 {
 
 fn hash_public_object(game: &GameState, obj: &Object) -> Hash32 {
     let counters = sorted_counter_list(&obj.counters);
-    let face_down = game.is_face_down(obj.id);
+    let face_down = !game.can_see_object(obj.id, None);
 
     let (
         name,
@@ -979,6 +1318,43 @@ fn sort_objects(objects: impl Iterator<Item = ObjectId>) -> Vec<GameObjectId> {
     list
 }
 
+fn sort_object_player_sets(
+    map: &HashMap<ObjectId, HashSet<PlayerId>>,
+) -> Vec<(GameObjectId, Vec<GamePlayerId>)> {
+    let mut list: Vec<(GameObjectId, Vec<GamePlayerId>)> = map
+        .iter()
+        .map(|(object_id, players)| ((*object_id).into(), sort_players(players.iter().copied())))
+        .collect();
+    list.sort_by_key(|(object_id, _)| object_id.0);
+    list
+}
+
+fn sort_object_object_sets(
+    map: &HashMap<ObjectId, HashSet<ObjectId>>,
+) -> Vec<(GameObjectId, Vec<GameObjectId>)> {
+    let mut list: Vec<(GameObjectId, Vec<GameObjectId>)> = map
+        .iter()
+        .map(|(object_id, objects)| ((*object_id).into(), sort_objects(objects.iter().copied())))
+        .collect();
+    list.sort_by_key(|(object_id, _)| object_id.0);
+    list
+}
+
+fn sort_player_filters(
+    map: &HashMap<PlayerId, Vec<crate::target::ObjectFilter>>,
+) -> Vec<(GamePlayerId, String)> {
+    let mut list: Vec<(GamePlayerId, String)> = map
+        .iter()
+        .flat_map(|(player, filters)| {
+            filters
+                .iter()
+                .map(|filter| ((*player).into(), format!("{:?}", filter)))
+        })
+        .collect();
+    list.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+    list
+}
+
 fn sort_object_pairs<'a>(
     entries: impl Iterator<Item = &'a (ObjectId, usize)>,
 ) -> Vec<(GameObjectId, u32)> {
@@ -1026,5 +1402,260 @@ fn replacement_source_tag(source: crate::replacement::ReplacementEffectSource) -
     match source {
         crate::replacement::ReplacementEffectSource::StaticAbility => 0,
         crate::replacement::ReplacementEffectSource::Resolution => 1,
+        crate::replacement::ReplacementEffectSource::UntilEndOfTurn => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{grizzly_bears, lightning_bolt};
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    const ZONES: [Zone; 6] = [
+        Zone::Battlefield,
+        Zone::Exile,
+        Zone::Command,
+        Zone::Graveyard,
+        Zone::Hand,
+        Zone::Library,
+    ];
+
+    fn random_object(game: &GameState, rng: &mut StdRng) -> Option<ObjectId> {
+        let ids: Vec<ObjectId> = game.objects_iter().map(|obj| obj.id).collect();
+        (!ids.is_empty()).then(|| ids[rng.random_range(0..ids.len())])
+    }
+
+    fn random_player(game: &GameState, rng: &mut StdRng) -> PlayerId {
+        game.players[rng.random_range(0..game.players.len())].id
+    }
+
+    fn mutate(game: &mut GameState, rng: &mut StdRng) {
+        match rng.random_range(0..9) {
+            0 => {
+                let player = random_player(game, rng);
+                let delta = rng.random_range(-5..=5);
+                if let Some(player) = game.player_mut(player) {
+                    player.life += delta;
+                }
+            }
+            1 => {
+                let owner = random_player(game, rng);
+                let zone = ZONES[rng.random_range(0..ZONES.len())];
+                let def = if rng.random_bool(0.5) {
+                    grizzly_bears()
+                } else {
+                    lightning_bolt()
+                };
+                game.create_object_from_definition(&def, owner, zone);
+            }
+            2 => {
+                let zone = ZONES[rng.random_range(0..ZONES.len())];
+                if let Some(id) = random_object(game, rng) {
+                    game.move_object(id, zone);
+                }
+            }
+            3 => {
+                if let Some(id) = random_object(game, rng) {
+                    game.remove_object(id);
+                }
+            }
+            4 => {
+                if let Some(id) = random_object(game, rng) {
+                    if game.is_tapped(id) {
+                        game.untap(id);
+                    } else {
+                        game.tap(id);
+                    }
+                }
+            }
+            5 => {
+                let amount = rng.random_range(1..4);
+                if let Some(obj) = random_object(game, rng).and_then(|id| game.object_mut(id)) {
+                    obj.add_counters(CounterType::PlusOnePlusOne, amount);
+                }
+            }
+            6 => {
+                if rng.random_bool(0.5) {
+                    game.pop_from_stack();
+                } else if let Some(id) = random_object(game, rng) {
+                    let controller = random_player(game, rng);
+                    game.push_to_stack(StackEntry::new(id, controller));
+                }
+            }
+            7 => {
+                game.turn.turn_number += 1;
+                game.turn.priority_player = Some(random_player(game, rng));
+            }
+            _ => {
+                let controller = random_player(game, rng);
+                if let Some(obj) = random_object(game, rng).and_then(|id| game.object_mut(id)) {
+                    obj.controller = controller;
+                }
+            }
+        }
+    }
+
+    fn seeded_game(rng: &mut StdRng) -> GameState {
+        let players = rng.random_range(2..5);
+        let names = (0..players)
+            .map(|index| format!("Player {index}"))
+            .collect();
+        let mut game = GameState::new(names, 40);
+        for _ in 0..rng.random_range(5..30) {
+            mutate(&mut game, rng);
+        }
+        game
+    }
+
+    #[test]
+    fn random_mutation_deltas_reproduce_target_snapshot() {
+        for seed in 0..64 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = seeded_game(&mut rng);
+            let mut base = build_public_state_snapshot(&game);
+            for _ in 0..8 {
+                for _ in 0..rng.random_range(0..6) {
+                    mutate(&mut game, &mut rng);
+                }
+                let target = build_public_state_snapshot(&game);
+                let delta = diff_public_state(&base, &target);
+
+                let bytes = delta.to_bytes();
+                let mut slice = bytes.as_slice();
+                let decoded = StateDelta::decode(&mut slice).expect("decode delta");
+                assert!(slice.is_empty(), "seed {seed}: delta decoder left bytes");
+                assert_eq!(decoded, delta, "seed {seed}: delta round trip");
+
+                let applied = apply_delta(&base, &decoded).expect("apply delta");
+                assert_eq!(
+                    applied.to_bytes(),
+                    target.to_bytes(),
+                    "seed {seed}: applied delta differs from direct snapshot"
+                );
+                base = target;
+            }
+        }
+    }
+
+    #[test]
+    fn unchanged_state_produces_empty_delta() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let game = seeded_game(&mut rng);
+        let snapshot = build_public_state_snapshot(&game);
+        let delta = diff_public_state(&snapshot, &snapshot);
+        assert_eq!(delta.base_hash, delta.target_hash);
+        assert!(delta.players_changed.is_empty());
+        assert!(delta.objects_added.is_empty());
+        assert!(delta.objects_changed.is_empty());
+        assert!(delta.objects_removed.is_empty());
+        assert!(delta.turn.is_none() && delta.zones.is_none() && delta.stack.is_none());
+    }
+
+    #[test]
+    fn apply_delta_rejects_stale_base_and_tampered_target() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut game = seeded_game(&mut rng);
+        let base = build_public_state_snapshot(&game);
+        game.players[0].life -= 3;
+        let target = build_public_state_snapshot(&game);
+        let delta = diff_public_state(&base, &target);
+
+        assert!(matches!(
+            apply_delta(&target, &delta),
+            Err(StateDeltaError::BaseMismatch(_))
+        ));
+
+        let mut tampered = delta.clone();
+        tampered.players_changed[0].life += 1;
+        assert!(matches!(
+            apply_delta(&base, &tampered),
+            Err(StateDeltaError::TargetMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn state_update_falls_back_to_full_snapshot_for_unknown_base() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let mut game = seeded_game(&mut rng);
+        let base = build_public_state_snapshot(&game);
+        mutate(&mut game, &mut rng);
+        let target = build_public_state_snapshot(&game);
+
+        let update = build_state_update(&base, hash_public_state_snapshot(&base), &target);
+        assert!(matches!(update, StateUpdate::Delta(_)));
+        assert_eq!(apply_state_update(&base, &update), Ok(target.clone()));
+
+        let stale_peer = Hash32([0xab; 32]);
+        let update = build_state_update(&base, stale_peer, &target);
+        assert_eq!(update, StateUpdate::Full(target.clone()));
+        let bytes = update.to_bytes();
+        let mut slice = bytes.as_slice();
+        assert_eq!(StateUpdate::decode(&mut slice), Ok(update.clone()));
+        assert_eq!(apply_state_update(&base, &update), Ok(target));
+    }
+
+    /// Fill the trackers of a fresh game, inserting entries in the given
+    /// order so that the underlying hash maps are laid out differently.
+    fn tracked_game(ids: &[u64]) -> GameState {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let players = [PlayerId::from_index(0), PlayerId::from_index(1)];
+        let mut combat = CombatState::default();
+        for &raw in ids {
+            let id = ObjectId::from_raw(raw);
+            let player = players[raw as usize % 2];
+            let cant = &mut game.cant_effects;
+            cant.cant_attack.insert(id);
+            cant.cant_block.insert(id);
+            cant.cant_untap.insert(id);
+            cant.cant_transform.insert(id);
+            cant.cant_gain_life.insert(player);
+            cant.cant_attack_players
+                .entry(id)
+                .or_default()
+                .extend(players);
+            cant.must_block_specific_attackers
+                .entry(id)
+                .or_default()
+                .extend(ids.iter().map(|&other| ObjectId::from_raw(other)));
+            cant.cant_be_countered_by
+                .entry(id)
+                .or_default()
+                .insert(player);
+            game.activated_abilities_this_turn.insert((id, 0));
+            *game.cards_drawn_this_turn.entry(player).or_default() += 1;
+            game.skip_next_draw_step.insert(player);
+            combat
+                .blockers
+                .insert(id, vec![ObjectId::from_raw(raw + 100)]);
+        }
+        game.combat = Some(combat);
+        game
+    }
+
+    #[test]
+    fn identical_trackers_hash_equally_regardless_of_insertion_order() {
+        let ids: Vec<u64> = (1..=24).collect();
+        let reversed: Vec<u64> = ids.iter().rev().copied().collect();
+        let forward = tracked_game(&ids);
+        let backward = tracked_game(&reversed);
+        assert_eq!(forward.trackers_hash(), backward.trackers_hash());
+        assert_eq!(
+            build_public_state_snapshot(&forward).trackers_hash,
+            forward.trackers_hash()
+        );
+
+        let mut changed = tracked_game(&ids);
+        changed
+            .cant_effects
+            .cant_be_countered_by
+            .get_mut(&ObjectId::from_raw(1))
+            .unwrap()
+            .insert(PlayerId::from_index(0));
+        assert_ne!(changed.trackers_hash(), forward.trackers_hash());
+
+        changed = tracked_game(&ids);
+        changed.combat = None;
+        assert_ne!(changed.trackers_hash(), forward.trackers_hash());
     }
 }
@@ -9,11 +9,16 @@ use crate::cost::{OptionalCost, OptionalCostsPaid, TotalCost};
 use crate::ids::{CardId, ObjectId, PlayerId, StableId};
 use crate::mana::ManaCost;
 use crate::player::ManaPool;
+use crate::shared::SharedVec;
 use crate::types::{CardType, Subtype, Supertype};
 use crate::zone::Zone;
 
 /// Types of counters that can be placed on objects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum CounterType {
     // === P/T modifying counters ===
     PlusOnePlusOne,
@@ -168,7 +173,30 @@ pub enum CounterType {
     ///
     /// This lets the parser support the long tail of historical and supplemental
     /// set counters without having to constantly extend the enum.
-    Named(&'static str),
+    Named(
+        #[cfg_attr(
+            feature = "serialization",
+            serde(deserialize_with = "deserialize_named_counter")
+        )]
+        CounterName,
+    ),
+}
+
+/// Interned name of a [`CounterType::Named`] counter.
+///
+/// Spelled as an alias so serde's derive doesn't treat the field as borrowed
+/// from the input (which would require `'de: 'static`).
+pub type CounterName = &'static str;
+
+/// Deserialize a named counter through the parser's interner so data-defined
+/// cards share the same `&'static str` as parsed ones.
+#[cfg(feature = "serialization")]
+fn deserialize_named_counter<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(crate::cards::builders::intern_counter_name(&name))
 }
 
 impl CounterType {
@@ -253,7 +281,7 @@ pub struct BestowCastState {
     pub card_types: Vec<CardType>,
     pub subtypes: Vec<Subtype>,
     pub aura_attach_filter: Option<crate::target::ObjectFilter>,
-    pub spell_effect: Option<Vec<crate::effect::Effect>>,
+    pub spell_effect: Option<SharedVec<crate::effect::Effect>>,
 }
 
 /// Runtime representation of a game object.
@@ -297,7 +325,7 @@ pub struct Object {
     pub base_loyalty: Option<u32>,
     pub base_defense: Option<u32>,
     /// Abilities this object has (copiable)
-    pub abilities: Vec<Ability>,
+    pub abilities: SharedVec<Ability>,
 
     // Non-copiable values (kept on Object)
     pub counters: HashMap<CounterType, u32>,
@@ -306,17 +334,19 @@ pub struct Object {
 
     // Spell-related state
     /// Spell effects (for instants/sorceries)
-    pub spell_effect: Option<Vec<crate::effect::Effect>>,
+    pub spell_effect: Option<SharedVec<crate::effect::Effect>>,
     /// For Auras: what this card can enchant (used for non-target attachments)
     pub aura_attach_filter: Option<crate::target::ObjectFilter>,
     /// Original copiable fields to restore if this permanent ends bestow.
     pub bestow_cast_state: Option<BestowCastState>,
     /// Alternative casting methods (flashback, escape, etc.)
-    pub alternative_casts: Vec<AlternativeCastingMethod>,
+    pub alternative_casts: SharedVec<AlternativeCastingMethod>,
     /// True if this split card can be cast fused from hand.
     pub has_fuse: bool,
+    /// True if this split card's second half has aftermath and can only be cast from a graveyard.
+    pub has_aftermath: bool,
     /// Optional costs (kicker, buyback, etc.)
-    pub optional_costs: Vec<OptionalCost>,
+    pub optional_costs: SharedVec<OptionalCost>,
     /// Which optional costs were paid when this spell was cast (for ETB triggers)
     pub optional_costs_paid: OptionalCostsPaid,
     /// Mana actually spent to cast this object while it was a spell.
@@ -392,16 +422,17 @@ impl Object {
             base_toughness,
             base_loyalty: card.loyalty,
             base_defense: card.defense,
-            abilities: Vec::new(),
+            abilities: SharedVec::new(),
             counters: HashMap::new(),
             attached_to: None,
             attachments: Vec::new(),
             spell_effect: None,
             aura_attach_filter: None,
             bestow_cast_state: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: ManaPool::default(),
             x_value: None,
@@ -425,6 +456,7 @@ impl Object {
         obj.bestow_cast_state = None;
         obj.alternative_casts = def.alternative_casts.clone();
         obj.has_fuse = def.has_fuse;
+        obj.has_aftermath = def.has_aftermath;
         obj.optional_costs = def.optional_costs.clone();
         obj.max_saga_chapter = def.max_saga_chapter;
         obj.additional_cost = def.additional_cost.clone();
@@ -463,6 +495,7 @@ impl Object {
         self.bestow_cast_state = None;
         self.alternative_casts = def.alternative_casts.clone();
         self.has_fuse = def.has_fuse;
+        self.has_aftermath = def.has_aftermath;
         self.optional_costs = def.optional_costs.clone();
         self.max_saga_chapter = def.max_saga_chapter;
         self.additional_cost = def.additional_cost.clone();
@@ -523,7 +556,7 @@ impl Object {
         };
         crate::cards::CardDefinition {
             card: Card {
-                id: self.card.unwrap_or(CardId::new()),
+                id: self.card.unwrap_or_else(|| CardId::from_name(&self.name)),
                 name: self.name.clone(),
                 mana_cost: self.mana_cost.clone(),
                 color_indicator: self.color_override,
@@ -544,6 +577,7 @@ impl Object {
             aura_attach_filter: self.aura_attach_filter.clone(),
             alternative_casts: self.alternative_casts.clone(),
             has_fuse: self.has_fuse,
+            has_aftermath: self.has_aftermath,
             optional_costs: self.optional_costs.clone(),
             max_saga_chapter: self.max_saga_chapter,
             additional_cost: self.additional_cost.clone(),
@@ -584,16 +618,17 @@ impl Object {
             base_toughness: toughness.map(PtValue::Fixed),
             base_loyalty: None,
             base_defense: None,
-            abilities: Vec::new(),
+            abilities: SharedVec::new(),
             counters: HashMap::new(),
             attached_to: None,
             attachments: Vec::new(),
             spell_effect: None,
             aura_attach_filter: None,
             bestow_cast_state: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: ManaPool::default(),
             x_value: None,
@@ -642,6 +677,7 @@ impl Object {
             // Alternative casts are copiable (though tokens rarely use them)
             alternative_casts: source.alternative_casts.clone(),
             has_fuse: source.has_fuse,
+            has_aftermath: source.has_aftermath,
             // Optional costs are copiable
             optional_costs: source.optional_costs.clone(),
             // Optional costs paid is non-copiable (tokens weren't cast)
@@ -695,16 +731,17 @@ impl Object {
             base_toughness: None,
             base_loyalty: None,
             base_defense: None,
-            abilities,
+            abilities: abilities.into(),
             counters: HashMap::new(),
             attached_to: None,
             attachments: Vec::new(),
             spell_effect: None,
             aura_attach_filter: None,
             bestow_cast_state: None,
-            alternative_casts: Vec::new(),
+            alternative_casts: SharedVec::new(),
             has_fuse: false,
-            optional_costs: Vec::new(),
+            has_aftermath: false,
+            optional_costs: SharedVec::new(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: ManaPool::default(),
             x_value: None,
@@ -736,6 +773,7 @@ impl Object {
         self.abilities = source.abilities.clone();
         self.aura_attach_filter = source.aura_attach_filter.clone();
         self.has_fuse = source.has_fuse;
+        self.has_aftermath = source.has_aftermath;
     }
 
     /// Apply the temporary "cast with bestow" Aura overlay.
@@ -783,7 +821,7 @@ impl Object {
 
         let target_spec =
             crate::target::ChooseSpec::target(crate::target::ChooseSpec::Object(filter));
-        self.spell_effect = Some(vec![crate::effect::Effect::attach_to(target_spec)]);
+        self.spell_effect = Some(vec![crate::effect::Effect::attach_to(target_spec)].into());
     }
 
     /// Returns true if this object is currently in the temporary bestow Aura form.
@@ -1198,6 +1236,7 @@ impl Object {
             bestow_cast_state: None,
             alternative_casts: def.alternative_casts.clone(),
             has_fuse: def.has_fuse,
+            has_aftermath: def.has_aftermath,
             optional_costs: def.optional_costs.clone(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: ManaPool::default(),
@@ -1266,6 +1305,32 @@ mod tests {
         // Note: summoning_sick is now tracked in GameState::summoning_sick
     }
 
+    #[test]
+    fn test_objects_share_definition_abilities_until_modified() {
+        let def = crate::cards::definitions::serra_angel();
+        let first = Object::from_card_definition(
+            ObjectId::from_raw(1),
+            &def,
+            PlayerId::from_index(0),
+            Zone::Library,
+        );
+        let mut second = Object::from_card_definition(
+            ObjectId::from_raw(2),
+            &def,
+            PlayerId::from_index(0),
+            Zone::Library,
+        );
+        assert!(first.abilities.ptr_eq(&def.abilities));
+        assert!(second.abilities.ptr_eq(&first.abilities));
+
+        second
+            .abilities
+            .push(Ability::static_ability(StaticAbility::flying()));
+        assert!(!second.abilities.ptr_eq(&def.abilities));
+        assert_eq!(first.abilities.len(), def.abilities.len());
+        assert_eq!(second.abilities.len(), def.abilities.len() + 1);
+    }
+
     #[test]
     fn test_devoid_applies_in_hand() {
         let card = CardBuilder::new(CardId::from_raw(1), "Devoid Probe")
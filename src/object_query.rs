@@ -5,9 +5,12 @@
 
 use std::collections::HashSet;
 
-use crate::filter::ObjectFilter;
+use crate::color::ColorSet;
+use crate::filter::{FilterContext, ObjectFilter, PlayerFilter};
 use crate::game_state::GameState;
 use crate::ids::ObjectId;
+use crate::object::Object;
+use crate::types::Subtype;
 use crate::zone::Zone;
 
 /// Collect candidate object IDs for a zone.
@@ -64,3 +67,90 @@ pub(crate) fn candidate_ids_for_filter(game: &GameState, filter: &ObjectFilter)
         ids.into_iter().collect()
     }
 }
+
+/// Collect candidate object IDs for a filter evaluated in `ctx`.
+///
+/// When the filter names one zone and pins its owner to a single player
+/// ("cards in your graveyard", "cards target player owns in exile"), only
+/// that player's part of the zone is returned. Hands are scanned in full,
+/// since a card can sit in a hand other than its owner's. Otherwise this is
+/// [`candidate_ids_for_filter`].
+pub(crate) fn candidate_ids_for_filter_in_context(
+    game: &GameState,
+    filter: &ObjectFilter,
+    ctx: &FilterContext,
+) -> Vec<ObjectId> {
+    let owner = match &filter.owner {
+        Some(PlayerFilter::You) => ctx.you,
+        Some(PlayerFilter::Specific(player)) => Some(*player),
+        _ => None,
+    };
+    match (filter.zone, owner) {
+        (Some(zone), Some(owner))
+            if !matches!(zone, Zone::Stack | Zone::Hand) && filter.any_of.is_empty() =>
+        {
+            game.objects_in(zone, owner).collect()
+        }
+        _ => candidate_ids_for_filter(game, filter),
+    }
+}
+
+/// Running tally of distinct characteristics among a group of objects.
+///
+/// Backs the "among" values (Domain's basic land types, Delirium's card types,
+/// colors, distinct mana values) with bitmasks so a count is a single pass
+/// over the candidates without allocating a set per evaluation.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DistinctAmong {
+    basic_land_types: u8,
+    colors: ColorSet,
+    card_types: u16,
+    mana_values: u64,
+    large_mana_values: Vec<u32>,
+}
+
+impl DistinctAmong {
+    /// Record an object's characteristics in its current zone.
+    ///
+    /// Double-faced cards only contribute the face they currently show, so a
+    /// transforming card in a graveyard counts its front face alone.
+    pub(crate) fn add(&mut self, obj: &Object) {
+        for subtype in &obj.subtypes {
+            let bit = match subtype {
+                Subtype::Plains => 1 << 0,
+                Subtype::Island => 1 << 1,
+                Subtype::Swamp => 1 << 2,
+                Subtype::Mountain => 1 << 3,
+                Subtype::Forest => 1 << 4,
+                _ => continue,
+            };
+            self.basic_land_types |= bit;
+        }
+        self.colors = self.colors.union(obj.colors());
+        for card_type in &obj.card_types {
+            self.card_types |= 1 << (*card_type as u16);
+        }
+        let mana_value = obj.mana_cost.as_ref().map_or(0, |cost| cost.mana_value());
+        if mana_value < u64::BITS {
+            self.mana_values |= 1 << mana_value;
+        } else if !self.large_mana_values.contains(&mana_value) {
+            self.large_mana_values.push(mana_value);
+        }
+    }
+
+    pub(crate) fn basic_land_types(&self) -> i32 {
+        self.basic_land_types.count_ones() as i32
+    }
+
+    pub(crate) fn colors(&self) -> i32 {
+        self.colors.count() as i32
+    }
+
+    pub(crate) fn card_types(&self) -> i32 {
+        self.card_types.count_ones() as i32
+    }
+
+    pub(crate) fn mana_values(&self) -> i32 {
+        (self.mana_values.count_ones() as usize + self.large_mana_values.len()) as i32
+    }
+}
@@ -12,6 +12,58 @@ pub struct ManaPool {
     pub red: u32,
     pub green: u32,
     pub colorless: u32,
+    /// How much of each mana type above was produced by snow sources
+    /// (indexed by `snow_index`). Only this mana can pay {S} (CR 107.4h).
+    snow: [u32; 6],
+}
+
+/// Index into `ManaPool::snow` for a mana type, if it can sit in a pool.
+fn snow_index(symbol: ManaSymbol) -> Option<usize> {
+    match symbol {
+        ManaSymbol::White => Some(0),
+        ManaSymbol::Blue => Some(1),
+        ManaSymbol::Black => Some(2),
+        ManaSymbol::Red => Some(3),
+        ManaSymbol::Green => Some(4),
+        ManaSymbol::Colorless => Some(5),
+        _ => None,
+    }
+}
+
+/// Pool mana types in the order {S} prefers to spend them.
+const SNOW_SPEND_ORDER: [ManaSymbol; 6] = [
+    ManaSymbol::Colorless,
+    ManaSymbol::White,
+    ManaSymbol::Blue,
+    ManaSymbol::Black,
+    ManaSymbol::Red,
+    ManaSymbol::Green,
+];
+
+/// Order in which pips are paid: the most constrained first, so flexible
+/// pips don't consume mana a later pip needed.
+fn pip_payment_priority(pip: &[ManaSymbol]) -> u8 {
+    let has_generic = pip
+        .iter()
+        .any(|s| matches!(s, ManaSymbol::Generic(_) | ManaSymbol::X));
+    if pip.contains(&ManaSymbol::Snow) {
+        1
+    } else if has_generic && pip.len() > 1 {
+        // Twobrid ({2/W}) after snow but before plain generic.
+        2
+    } else if has_generic {
+        3
+    } else {
+        0
+    }
+}
+
+/// Alternatives of a pip in the order payment should try them. Twobrid pips
+/// try their single colored pip before the two generic.
+fn pip_alternatives_in_payment_order(pip: &[ManaSymbol]) -> Vec<ManaSymbol> {
+    let mut alternatives = pip.to_vec();
+    alternatives.sort_by_key(|s| matches!(s, ManaSymbol::Generic(_)));
+    alternatives
 }
 
 impl ManaPool {
@@ -32,6 +84,60 @@ impl ManaPool {
         }
     }
 
+    /// Adds mana of the specified type produced by a snow source.
+    pub fn add_snow(&mut self, symbol: ManaSymbol, amount: u32) {
+        self.add(symbol, amount);
+        self.mark_snow(symbol, amount);
+    }
+
+    /// Marks `amount` of the mana of this type already in the pool as coming
+    /// from a snow source.
+    pub fn mark_snow(&mut self, symbol: ManaSymbol, amount: u32) {
+        if let Some(idx) = snow_index(symbol) {
+            self.snow[idx] = (self.snow[idx] + amount).min(self.amount(symbol));
+        }
+    }
+
+    /// Returns how much mana of a specific type came from snow sources.
+    pub fn snow_amount(&self, symbol: ManaSymbol) -> u32 {
+        snow_index(symbol).map_or(0, |idx| self.snow[idx])
+    }
+
+    /// Returns the total amount of snow mana in the pool.
+    pub fn snow_total(&self) -> u32 {
+        self.snow.iter().sum()
+    }
+
+    /// Removes snow mana of the specified type. Returns true if successful.
+    pub fn remove_snow(&mut self, symbol: ManaSymbol, amount: u32) -> bool {
+        let Some(idx) = snow_index(symbol) else {
+            return false;
+        };
+        let snow_before = self.snow[idx];
+        if snow_before < amount || !self.remove(symbol, amount) {
+            return false;
+        }
+        self.snow[idx] = snow_before - amount;
+        true
+    }
+
+    /// Pays one {S} pip with any snow mana in the pool.
+    fn pay_snow(&mut self) -> bool {
+        SNOW_SPEND_ORDER
+            .into_iter()
+            .any(|symbol| self.remove_snow(symbol, 1))
+    }
+
+    /// Drops snow markings beyond what remains of each mana type, treating
+    /// spent mana as non-snow mana first.
+    fn clamp_snow(&mut self) {
+        for symbol in SNOW_SPEND_ORDER {
+            if let Some(idx) = snow_index(symbol) {
+                self.snow[idx] = self.snow[idx].min(self.amount(symbol));
+            }
+        }
+    }
+
     /// Removes mana of the specified type. Returns true if successful.
     pub fn remove(&mut self, symbol: ManaSymbol, amount: u32) -> bool {
         let pool = match symbol {
@@ -46,6 +152,7 @@ impl ManaPool {
 
         if *pool >= amount {
             *pool -= amount;
+            self.clamp_snow();
             true
         } else {
             false
@@ -65,6 +172,7 @@ impl ManaPool {
         self.red = 0;
         self.green = 0;
         self.colorless = 0;
+        self.snow = [0; 6];
     }
 
     /// Returns the amount of mana of a specific type.
@@ -119,21 +227,12 @@ impl ManaPool {
         use crate::mana::ManaSymbol;
 
         let mut pips: Vec<_> = cost.pips().iter().collect();
-        pips.sort_by_key(|pip| {
-            if pip
-                .iter()
-                .any(|s| matches!(s, ManaSymbol::Generic(_) | ManaSymbol::X))
-            {
-                1
-            } else {
-                0
-            }
-        });
+        pips.sort_by_key(|pip| pip_payment_priority(pip));
 
         for pip in pips {
             let mut paid = false;
 
-            for alternative in pip.iter() {
+            for alternative in pip_alternatives_in_payment_order(pip).iter() {
                 match alternative {
                     ManaSymbol::White
                     | ManaSymbol::Blue
@@ -153,8 +252,7 @@ impl ManaPool {
                         }
                     }
                     ManaSymbol::Colorless => {
-                        if self.colorless > 0 {
-                            self.colorless -= 1;
+                        if self.remove(ManaSymbol::Colorless, 1) {
                             paid = true;
                             break;
                         }
@@ -175,8 +273,7 @@ impl ManaPool {
                         }
                     }
                     ManaSymbol::Snow => {
-                        if self.total() > 0 {
-                            self.pay_generic(1);
+                        if self.pay_snow() {
                             paid = true;
                             break;
                         }
@@ -265,20 +362,10 @@ impl ManaPool {
         allow_any_color: bool,
     ) -> (bool, u32) {
         // Sort pips so colored costs are paid first (more constrained),
-        // then generic/X costs last (more flexible). This prevents the bug
-        // where generic costs consume colored mana needed for later pips.
+        // then snow, twobrid, and finally generic/X costs (more flexible).
+        // This prevents generic costs consuming mana needed for later pips.
         let mut pips: Vec<_> = cost.pips().iter().collect();
-        pips.sort_by_key(|pip| {
-            // Colored pips have priority 0 (first), generic/X have priority 1 (last)
-            if pip
-                .iter()
-                .any(|s| matches!(s, ManaSymbol::Generic(_) | ManaSymbol::X))
-            {
-                1
-            } else {
-                0
-            }
-        });
+        pips.sort_by_key(|pip| pip_payment_priority(pip));
 
         let mut life_to_pay = 0u32;
 
@@ -305,7 +392,7 @@ impl ManaPool {
                 }
             }
 
-            for alternative in pip.iter() {
+            for alternative in pip_alternatives_in_payment_order(pip).iter() {
                 match alternative {
                     ManaSymbol::White
                     | ManaSymbol::Blue
@@ -325,8 +412,7 @@ impl ManaPool {
                         }
                     }
                     ManaSymbol::Colorless => {
-                        if self.colorless > 0 {
-                            self.colorless -= 1;
+                        if self.remove(ManaSymbol::Colorless, 1) {
                             paid = true;
                             break;
                         }
@@ -349,9 +435,7 @@ impl ManaPool {
                         }
                     }
                     ManaSymbol::Snow => {
-                        // Snow mana - for simplicity, treat as generic
-                        if self.total() > 0 {
-                            self.pay_generic(1);
+                        if self.pay_snow() {
                             paid = true;
                             break;
                         }
@@ -374,26 +458,23 @@ impl ManaPool {
     }
 
     /// Pay generic mana by draining from the pool.
-    /// Prefers colorless, then other colors.
+    /// Prefers colorless, then other colors, keeping snow mana for last.
     fn pay_generic(&mut self, mut amount: u32) {
-        // First use colorless
-        let from_colorless = amount.min(self.colorless);
-        self.colorless -= from_colorless;
-        amount -= from_colorless;
-
-        // Then use colored mana (arbitrary order)
-        for pool in [
-            &mut self.white,
-            &mut self.blue,
-            &mut self.black,
-            &mut self.red,
-            &mut self.green,
-        ] {
-            let from_this = amount.min(*pool);
-            *pool -= from_this;
-            amount -= from_this;
-            if amount == 0 {
-                break;
+        for spend_snow in [false, true] {
+            for symbol in SNOW_SPEND_ORDER {
+                let available = if spend_snow {
+                    self.amount(symbol)
+                } else {
+                    self.amount(symbol) - self.snow_amount(symbol)
+                };
+                let from_this = amount.min(available);
+                if from_this > 0 {
+                    self.remove(symbol, from_this);
+                    amount -= from_this;
+                }
+                if amount == 0 {
+                    return;
+                }
             }
         }
     }
@@ -429,7 +510,9 @@ impl ManaPool {
 
         // Pay non-X costs first to see what's left
         // For max_x calculation, prefer life payment over mana to preserve mana for X
-        for pip in cost.pips() {
+        let mut pips: Vec<_> = cost.pips().iter().collect();
+        pips.sort_by_key(|pip| pip_payment_priority(pip));
+        for pip in pips {
             let is_x_pip = pip.iter().any(|s| matches!(s, ManaSymbol::X));
             if is_x_pip {
                 continue; // Skip X pips for now
@@ -442,7 +525,7 @@ impl ManaPool {
             }
 
             let mut paid = false;
-            for alternative in pip {
+            for alternative in pip_alternatives_in_payment_order(pip).iter() {
                 match alternative {
                     ManaSymbol::White
                     | ManaSymbol::Blue
@@ -462,8 +545,7 @@ impl ManaPool {
                         }
                     }
                     ManaSymbol::Colorless => {
-                        if test_pool.colorless > 0 {
-                            test_pool.colorless -= 1;
+                        if test_pool.remove(ManaSymbol::Colorless, 1) {
                             paid = true;
                             break;
                         }
@@ -476,7 +558,13 @@ impl ManaPool {
                             break;
                         }
                     }
-                    ManaSymbol::Snow | ManaSymbol::Life(_) | ManaSymbol::X => {
+                    ManaSymbol::Snow => {
+                        if test_pool.pay_snow() {
+                            paid = true;
+                            break;
+                        }
+                    }
+                    ManaSymbol::Life(_) | ManaSymbol::X => {
                         paid = true;
                         break;
                     }
@@ -518,6 +606,9 @@ pub struct Player {
     pub has_lost: bool,
     pub has_won: bool,
     pub has_left_game: bool,
+    /// Set when the player tried to draw from an empty library since the last
+    /// state-based action check (rule 704.5b).
+    pub drew_from_empty_library: bool,
 
     // Zones (stored as object IDs)
     pub library: Vec<ObjectId>,
@@ -553,6 +644,7 @@ impl Player {
             has_lost: false,
             has_won: false,
             has_left_game: false,
+            drew_from_empty_library: false,
             library: Vec::new(),
             hand: Vec::new(),
             graveyard: Vec::new(),
@@ -651,6 +743,12 @@ impl Player {
         self.lands_played_this_turn += 1;
     }
 
+    /// Concedes the game: the player leaves the game and loses it (rule 104.3a).
+    pub fn concede(&mut self) {
+        self.has_lost = true;
+        self.has_left_game = true;
+    }
+
     /// Returns true if this player is still in the game.
     pub fn is_in_game(&self) -> bool {
         !self.has_lost && !self.has_won && !self.has_left_game
@@ -926,4 +1024,100 @@ mod tests {
         assert_eq!(pool.black, 0, "Black should be consumed for X");
         assert_eq!(pool.red, 0, "Red should be consumed for X");
     }
+
+    #[test]
+    fn test_twobrid_mana_paid_with_color_or_two_generic() {
+        use crate::mana::ManaCost;
+
+        // {2/W}
+        let cost = ManaCost::from_pips(vec![vec![ManaSymbol::Generic(2), ManaSymbol::White]]);
+
+        // Paid with a single White
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::White, 1);
+        assert!(pool.try_pay(&cost, 0), "{{W}} should pay {{2/W}}");
+        assert_eq!(pool.total(), 0);
+
+        // Paid with two generic
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::Red, 1);
+        pool.add(ManaSymbol::Colorless, 1);
+        assert!(pool.try_pay(&cost, 0), "two mana should pay {{2/W}}");
+        assert_eq!(pool.total(), 0);
+
+        // One non-white mana is not enough
+        let pool = ManaPool {
+            green: 1,
+            ..ManaPool::default()
+        };
+        assert!(!pool.can_pay(&cost, 0));
+
+        // {2/W}{1} with W + C: the White goes to {2/W}, leaving C for {1}
+        let cost = ManaCost::from_pips(vec![
+            vec![ManaSymbol::Generic(2), ManaSymbol::White],
+            vec![ManaSymbol::Generic(1)],
+        ]);
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::White, 1);
+        pool.add(ManaSymbol::Colorless, 1);
+        assert!(pool.try_pay(&cost, 0), "{{W}}{{C}} should pay {{2/W}}{{1}}");
+    }
+
+    #[test]
+    fn test_colorless_hybrid_mana() {
+        use crate::mana::ManaCost;
+
+        // {C/R}
+        let cost = ManaCost::from_pips(vec![vec![ManaSymbol::Colorless, ManaSymbol::Red]]);
+
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::Colorless, 1);
+        assert!(pool.try_pay(&cost, 0));
+
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::Red, 1);
+        assert!(pool.try_pay(&cost, 0));
+
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::Blue, 1);
+        assert!(!pool.can_pay(&cost, 0), "{{U}} can't pay {{C/R}}");
+    }
+
+    #[test]
+    fn test_snow_mana_only_paid_from_snow_sources() {
+        use crate::mana::ManaCost;
+
+        // {S}{1}
+        let cost = ManaCost::from_pips(vec![vec![ManaSymbol::Snow], vec![ManaSymbol::Generic(1)]]);
+
+        let mut pool = ManaPool::new();
+        pool.add(ManaSymbol::Green, 2);
+        assert!(
+            !pool.can_pay(&cost, 0),
+            "mana from non-snow sources can't pay {{S}}"
+        );
+
+        // Generic is paid with the non-snow mana, leaving the snow mana for {S}
+        let mut pool = ManaPool::new();
+        pool.add_snow(ManaSymbol::Green, 1);
+        pool.add(ManaSymbol::Green, 1);
+        assert_eq!(pool.snow_total(), 1);
+        assert!(pool.try_pay(&cost, 0));
+        assert_eq!(pool.total(), 0);
+        assert_eq!(pool.snow_total(), 0);
+
+        // Spending mana of a type spends its non-snow mana first
+        let mut pool = ManaPool::new();
+        pool.add_snow(ManaSymbol::Blue, 1);
+        pool.add(ManaSymbol::Blue, 1);
+        assert!(pool.remove(ManaSymbol::Blue, 1));
+        assert_eq!(pool.snow_amount(ManaSymbol::Blue), 1);
+        assert!(pool.remove(ManaSymbol::Blue, 1));
+        assert_eq!(pool.snow_amount(ManaSymbol::Blue), 0);
+
+        // Emptying the pool clears snow tracking
+        pool.add_snow(ManaSymbol::Colorless, 1);
+        pool.empty();
+        assert_eq!(pool.snow_total(), 0);
+    }
 }
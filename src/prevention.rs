@@ -12,9 +12,12 @@
 
 use crate::color::Color;
 use crate::effect::Until;
+use crate::game_state::{GameState, Phase};
 use crate::ids::{ObjectId, PlayerId};
+use crate::snapshot::ObjectSnapshot;
 use crate::target::ObjectFilter;
 use crate::types::CardType;
+use crate::zone::Zone;
 
 /// Unique identifier for a prevention shield.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -150,11 +153,35 @@ impl DamageFilter {
             }
         }
 
-        // For ObjectFilter-based source checks, would need game state context
-        // This is a simplified check
-
+        // `from_source` needs game state; see `matches_source_filter`.
         true
     }
+
+    /// Check `from_source` against the damage source.
+    ///
+    /// The filter is evaluated from the point of view of the shield's
+    /// controller and source, falling back to the damage source's last known
+    /// information when it has left the game state.
+    pub fn matches_source_filter(
+        &self,
+        game: &GameState,
+        shield_controller: PlayerId,
+        shield_source: ObjectId,
+        source: ObjectId,
+        source_snapshot: Option<&ObjectSnapshot>,
+    ) -> bool {
+        let Some(filter) = &self.from_source else {
+            return true;
+        };
+        let filter_ctx = game.filter_context_for(shield_controller, Some(shield_source));
+        if let Some(object) = game.object(source) {
+            filter.matches(object, &filter_ctx, game)
+        } else if let Some(snapshot) = source_snapshot {
+            filter.matches_snapshot(snapshot, &filter_ctx, game)
+        } else {
+            false
+        }
+    }
 }
 
 /// A prevention shield that can prevent a limited amount of damage.
@@ -226,6 +253,26 @@ impl PreventionShield {
         self.amount_remaining == Some(0)
     }
 
+    /// Check whether this shield's duration still holds.
+    ///
+    /// "Until end of turn" shields are removed by the cleanup step rather
+    /// than expiring here.
+    pub fn is_active(&self, game: &GameState, current_turn: u32) -> bool {
+        match self.duration {
+            Until::EndOfCombat => !matches!(game.turn.phase, Phase::NextMain | Phase::Ending),
+            Until::YourNextTurn => {
+                !(current_turn > self.created_turn && game.turn.active_player == self.controller)
+            }
+            Until::ThisLeavesTheBattlefield => game
+                .object(self.source)
+                .is_some_and(|obj| obj.zone == Zone::Battlefield),
+            Until::YouStopControllingThis => game.object(self.source).is_some_and(|obj| {
+                obj.zone == Zone::Battlefield && obj.controller == self.controller
+            }),
+            _ => true,
+        }
+    }
+
     /// Reduce the amount of prevention remaining.
     /// Returns the amount that was actually prevented (may be less than requested if insufficient).
     pub fn reduce(&mut self, amount: u32) -> u32 {
@@ -330,7 +377,19 @@ impl PreventionEffectManager {
     /// Clean up shields at end of turn.
     pub fn cleanup_end_of_turn(&mut self) {
         self.shields
-            .retain(|s| !matches!(s.duration, Until::EndOfTurn));
+            .retain(|s| !matches!(s.duration, Until::EndOfTurn | Until::EndOfCombat));
+    }
+
+    /// Remove shields whose `until` duration has just ended.
+    pub fn expire(&mut self, until: &Until, active_player: PlayerId) {
+        self.shields
+            .retain(|s| !s.duration.ends_at(until, s.controller, active_player));
+    }
+
+    /// Remove shields whose duration has ended.
+    pub fn cleanup_expired(&mut self, game: &GameState) {
+        let current_turn = game.turn.turn_number;
+        self.shields.retain(|s| s.is_active(game, current_turn));
     }
 
     /// Set the current turn number.
@@ -382,10 +441,117 @@ impl PreventionEffectManager {
         self.shields.iter_mut().find(|s| s.id == id)
     }
 
+    /// Get the IDs of shields that would apply to damage dealt to a player,
+    /// in creation order.
+    pub fn applicable_shields_for_player(
+        &self,
+        player: PlayerId,
+        is_combat: bool,
+        source: ObjectId,
+        source_colors: &crate::color::ColorSet,
+        source_card_types: &[CardType],
+    ) -> Vec<PreventionShieldId> {
+        self.get_shields_for_player(player)
+            .iter()
+            .filter(|s| {
+                s.damage_filter
+                    .matches(is_combat, source, source_colors, source_card_types)
+            })
+            .map(|s| s.id)
+            .collect()
+    }
+
+    /// Get the IDs of shields that would apply to damage dealt to a permanent,
+    /// in creation order.
+    pub fn applicable_shields_for_permanent(
+        &self,
+        permanent: ObjectId,
+        controller: PlayerId,
+        is_combat: bool,
+        source: ObjectId,
+        source_colors: &crate::color::ColorSet,
+        source_card_types: &[CardType],
+    ) -> Vec<PreventionShieldId> {
+        self.get_shields_for_permanent(permanent, controller)
+            .iter()
+            .filter(|s| {
+                s.damage_filter
+                    .matches(is_combat, source, source_colors, source_card_types)
+            })
+            .map(|s| s.id)
+            .collect()
+    }
+
+    /// Narrow shield candidates using game state.
+    ///
+    /// Drops shields whose duration has ended, whose `from_source` filter
+    /// doesn't match the damage source, or whose protected-permanent filter
+    /// doesn't match the damaged permanent.
+    pub fn retain_shields_matching_game(
+        &self,
+        game: &GameState,
+        candidates: Vec<PreventionShieldId>,
+        damaged_permanent: Option<ObjectId>,
+        source: ObjectId,
+        source_snapshot: Option<&ObjectSnapshot>,
+    ) -> Vec<PreventionShieldId> {
+        let current_turn = game.turn.turn_number;
+        candidates
+            .into_iter()
+            .filter(|id| {
+                let Some(shield) = self.shields.iter().find(|s| s.id == *id) else {
+                    return false;
+                };
+                if !shield.is_active(game, current_turn) {
+                    return false;
+                }
+                if let PreventionTarget::PermanentsMatching(filter) = &shield.protected {
+                    let filter_ctx =
+                        game.filter_context_for(shield.controller, Some(shield.source));
+                    let protected = damaged_permanent
+                        .and_then(|id| game.object(id))
+                        .is_some_and(|obj| filter.matches(obj, &filter_ctx, game));
+                    if !protected {
+                        return false;
+                    }
+                }
+                shield.damage_filter.matches_source_filter(
+                    game,
+                    shield.controller,
+                    shield.source,
+                    source,
+                    source_snapshot,
+                )
+            })
+            .collect()
+    }
+
+    /// Apply a single shield to damage.
+    ///
+    /// Returns the amount of damage remaining after this shield. Exhausted
+    /// shields stay in the manager until [`Self::cleanup_exhausted`] runs.
+    ///
+    /// Per Rule 615.12: If damage "can't be prevented", the shield is applied
+    /// but doesn't reduce damage or consume its prevention amount.
+    pub fn apply_shield(
+        &mut self,
+        id: PreventionShieldId,
+        damage: u32,
+        can_be_prevented: bool,
+    ) -> u32 {
+        if !can_be_prevented {
+            return damage;
+        }
+        match self.get_shield_mut(id) {
+            Some(shield) => damage - shield.reduce(damage),
+            None => damage,
+        }
+    }
+
     /// Apply prevention to damage.
     ///
     /// This finds applicable shields and reduces the damage amount,
-    /// consuming shield prevention as needed.
+    /// consuming shield prevention as needed. Shields apply in creation order.
     ///
     /// Returns the amount of damage remaining after prevention.
     ///
@@ -405,40 +571,14 @@ impl PreventionEffectManager {
             return 0;
         }
 
-        let mut remaining = damage;
-
-        // Find applicable shields
-        let shield_ids: Vec<PreventionShieldId> = self
-            .get_shields_for_player(player)
-            .iter()
-            .filter(|s| {
-                s.damage_filter
-                    .matches(is_combat, source, source_colors, source_card_types)
-            })
-            .map(|s| s.id)
-            .collect();
-
-        // Apply prevention from each shield
-        for id in shield_ids {
-            if remaining == 0 {
-                break;
-            }
-
-            if let Some(shield) = self.get_shield_mut(id)
-                && can_be_prevented
-            {
-                // Normal prevention - reduce damage and consume shield
-                let prevented = shield.reduce(remaining);
-                remaining -= prevented;
-            }
-            // If can't be prevented: shield is "applied" but doesn't prevent
-            // and doesn't get consumed (per Rule 615.12)
-        }
-
-        // Clean up exhausted shields
-        self.cleanup_exhausted();
-
-        remaining
+        let shield_ids = self.applicable_shields_for_player(
+            player,
+            is_combat,
+            source,
+            source_colors,
+            source_card_types,
+        );
+        self.apply_shields_in_order(&shield_ids, damage, can_be_prevented)
     }
 
     /// Apply prevention to damage to a permanent.
@@ -459,31 +599,29 @@ impl PreventionEffectManager {
             return 0;
         }
 
-        let mut remaining = damage;
-
-        // Find applicable shields
-        let shield_ids: Vec<PreventionShieldId> = self
-            .get_shields_for_permanent(permanent, controller)
-            .iter()
-            .filter(|s| {
-                s.damage_filter
-                    .matches(is_combat, source, source_colors, source_card_types)
-            })
-            .map(|s| s.id)
-            .collect();
+        let shield_ids = self.applicable_shields_for_permanent(
+            permanent,
+            controller,
+            is_combat,
+            source,
+            source_colors,
+            source_card_types,
+        );
+        self.apply_shields_in_order(&shield_ids, damage, can_be_prevented)
+    }
 
-        // Apply prevention from each shield
-        for id in shield_ids {
+    fn apply_shields_in_order(
+        &mut self,
+        shield_ids: &[PreventionShieldId],
+        damage: u32,
+        can_be_prevented: bool,
+    ) -> u32 {
+        let mut remaining = damage;
+        for &id in shield_ids {
             if remaining == 0 {
                 break;
             }
-
-            if let Some(shield) = self.get_shield_mut(id)
-                && can_be_prevented
-            {
-                let prevented = shield.reduce(remaining);
-                remaining -= prevented;
-            }
+            remaining = self.apply_shield(id, remaining, can_be_prevented);
         }
 
         // Clean up exhausted shields
@@ -55,6 +55,14 @@ impl ProvenanceGraph {
         self.nodes.len()
     }
 
+    /// The most recently allocated nodes, oldest first.
+    pub fn recent_nodes(&self, limit: usize) -> Vec<&ProvenanceNode> {
+        let first = self.next_id.saturating_sub(limit as u64) + 1;
+        (first..=self.next_id)
+            .filter_map(|id| self.nodes.get(&ProvNodeId(id)))
+            .collect()
+    }
+
     pub fn alloc_root_event(&mut self, kind: EventKind) -> ProvNodeId {
         self.alloc_root(ProvenanceNodeKind::RootEvent { kind })
     }
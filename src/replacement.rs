@@ -5,7 +5,7 @@
 
 use crate::effect::{Effect, Value};
 use crate::events::ReplacementMatcher;
-use crate::events::cards::matchers::{WouldDiscardMatcher, WouldDrawCardMatcher};
+use crate::events::cards::matchers::{WouldDiscardMatcher, WouldDrawCardMatcher, WouldMillMatcher};
 use crate::events::damage::matchers::{DamageFromSourceMatcher, DamageToPlayerMatcher};
 use crate::events::life::matchers::WouldGainLifeMatcher;
 use crate::events::permanents::matchers::ThisWouldBeDestroyedMatcher;
@@ -204,6 +204,10 @@ pub enum RedirectTarget {
 
     /// Redirect to the source of the effect
     ToSource,
+
+    /// Redirect to the controller of the event's source
+    /// ("it deals that damage to its controller instead").
+    ToEventSourceController,
 }
 
 /// Which target to redirect in a multi-target event.
@@ -234,6 +238,8 @@ pub enum ReplacementEffectSource {
     StaticAbility,
     /// Effect from a resolved spell/ability (persists until removed)
     Resolution,
+    /// Effect from a resolved spell/ability that lasts until end of turn
+    UntilEndOfTurn,
 }
 
 /// Manages all replacement effects in the game.
@@ -391,6 +397,36 @@ impl ReplacementEffectManager {
         id
     }
 
+    /// Add a replacement effect from a resolved spell/ability that lasts until
+    /// end of turn ("this turn").
+    pub fn add_until_end_of_turn_effect(
+        &mut self,
+        effect: ReplacementEffect,
+    ) -> ReplacementEffectId {
+        let id = self.add_effect(effect);
+        self.effect_sources
+            .insert(id.0, ReplacementEffectSource::UntilEndOfTurn);
+        id
+    }
+
+    /// Remove the effects that last until end of turn.
+    ///
+    /// Called during the cleanup step.
+    pub fn clear_until_end_of_turn_effects(&mut self) {
+        let expired: Vec<ReplacementEffectId> = self
+            .effects
+            .iter()
+            .filter(|e| {
+                self.effect_sources.get(&e.id.0) == Some(&ReplacementEffectSource::UntilEndOfTurn)
+            })
+            .map(|e| e.id)
+            .collect();
+
+        for id in expired {
+            self.remove_effect(id);
+        }
+    }
+
     /// Clear all effects from static abilities.
     ///
     /// Called before regenerating static ability effects during state refresh.
@@ -603,6 +639,16 @@ impl ReplacementEffect {
         )
     }
 
+    /// Create a "mill twice that many cards instead" effect.
+    pub fn double_mill(source: ObjectId, controller: PlayerId, player: PlayerFilter) -> Self {
+        Self::with_matcher(
+            source,
+            controller,
+            WouldMillMatcher::new(player),
+            ReplacementAction::Double,
+        )
+    }
+
     // Note: "Can't lose the game" (Platinum Angel) is implemented via CantEffects,
     // not as a replacement effect. See game_state.rs CantEffects::can_lose_game().
 
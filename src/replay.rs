@@ -0,0 +1,1077 @@
+//! Decision-level recording and replay of games.
+//!
+//! A replay file stores the answers given to the engine's decisions rather than
+//! the lines typed at the CLI, so it stays valid when prompts are reworded or
+//! reordered. The file is JSON lines: a header naming the format and its
+//! version, then one [`ReplayEntry`] per decision:
+//!
+//! ```text
+//! {"format":"maigus-replay","version":1}
+//! {"seq":1,"kind":"Priority","player":0,"response":{"Priority":{"PlayLand":{"land_id":12}}}}
+//! {"seq":2,"kind":"Boolean","player":1,"response":{"Boolean":true}}
+//! ```
+//!
+//! [`RecordingDecisionMaker`] writes a file while another decision maker plays,
+//! and [`ReplayDecisionMaker`] answers from one. Replayed responses are matched
+//! to decisions in order by kind and player and checked against the choices the
+//! engine offers; the first mismatch panics with a [`ReplayDivergence`] naming
+//! the decision it happened at.
+//!
+//! Files recorded before this format held raw input lines. [`ReplayLog::parse`]
+//! rejects them with [`ReplayError::LegacyFormat`]; the CLI's
+//! `--convert-replay` option feeds one through the CLI prompts again
+//! ([`legacy_input_lines`]) and records the decisions it produces.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::color::Color;
+use crate::decision::{DecisionMaker, LegalAction};
+use crate::decisions::PriorityPolicyEdit;
+use crate::decisions::context::{
+    AttackersContext, BlockersContext, BooleanContext, ColorsContext, CountersContext,
+    DecisionContext, DistributeContext, NumberContext, OrderContext, PartitionContext,
+    PriorityContext, ProliferateContext, SelectObjectsContext, SelectOptionsContext,
+    TargetsContext, ViewCardsContext,
+};
+use crate::decisions::spec::{AttackerDeclaration, BlockerDeclaration};
+use crate::decisions::specs::ProliferateResponse;
+use crate::engine_handle::DecisionResponse;
+use crate::game_state::{GameState, Target};
+use crate::ids::{ObjectId, PlayerId};
+use crate::object::CounterType;
+
+/// Format name written in every replay header.
+pub const REPLAY_FORMAT: &str = "maigus-replay";
+
+/// Version of the replay format written by [`ReplayWriter`].
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+/// First line of a replay file.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct ReplayHeader {
+    format: String,
+    version: u32,
+}
+
+/// Which [`DecisionMaker`] method a recorded response answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DecisionKind {
+    Boolean,
+    Number,
+    Objects,
+    Options,
+    Order,
+    Attackers,
+    Blockers,
+    Distribute,
+    Colors,
+    Counters,
+    Partition,
+    Proliferate,
+    Priority,
+    Targets,
+}
+
+impl DecisionKind {
+    /// The kind of decision `response` answers.
+    pub fn of(response: &DecisionResponse) -> Self {
+        match response {
+            DecisionResponse::Boolean(_) => DecisionKind::Boolean,
+            DecisionResponse::Number(_) => DecisionKind::Number,
+            DecisionResponse::Objects(_) => DecisionKind::Objects,
+            DecisionResponse::Options(_) => DecisionKind::Options,
+            DecisionResponse::Order(_) => DecisionKind::Order,
+            DecisionResponse::Attackers(_) => DecisionKind::Attackers,
+            DecisionResponse::Blockers(_) => DecisionKind::Blockers,
+            DecisionResponse::Distribute(_) => DecisionKind::Distribute,
+            DecisionResponse::Colors(_) => DecisionKind::Colors,
+            DecisionResponse::Counters(_) => DecisionKind::Counters,
+            DecisionResponse::Partition(_) => DecisionKind::Partition,
+            DecisionResponse::Proliferate(_) => DecisionKind::Proliferate,
+            DecisionResponse::Priority(_) => DecisionKind::Priority,
+            DecisionResponse::Targets(_) => DecisionKind::Targets,
+        }
+    }
+
+    /// The kind of response `context` asks for. Modes and hybrid mana choices
+    /// are answered with options.
+    pub fn of_context(context: &DecisionContext) -> Self {
+        match context {
+            DecisionContext::Boolean(_) => DecisionKind::Boolean,
+            DecisionContext::Number(_) => DecisionKind::Number,
+            DecisionContext::SelectObjects(_) => DecisionKind::Objects,
+            DecisionContext::SelectOptions(_)
+            | DecisionContext::Modes(_)
+            | DecisionContext::HybridChoice(_) => DecisionKind::Options,
+            DecisionContext::Order(_) => DecisionKind::Order,
+            DecisionContext::Attackers(_) => DecisionKind::Attackers,
+            DecisionContext::Blockers(_) => DecisionKind::Blockers,
+            DecisionContext::Distribute(_) => DecisionKind::Distribute,
+            DecisionContext::Colors(_) => DecisionKind::Colors,
+            DecisionContext::Counters(_) => DecisionKind::Counters,
+            DecisionContext::Partition(_) => DecisionKind::Partition,
+            DecisionContext::Proliferate(_) => DecisionKind::Proliferate,
+            DecisionContext::Priority(_) => DecisionKind::Priority,
+            DecisionContext::Targets(_) => DecisionKind::Targets,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DecisionKind::Boolean => "yes/no",
+            DecisionKind::Number => "number",
+            DecisionKind::Objects => "object selection",
+            DecisionKind::Options => "option selection",
+            DecisionKind::Order => "ordering",
+            DecisionKind::Attackers => "attack declaration",
+            DecisionKind::Blockers => "block declaration",
+            DecisionKind::Distribute => "distribution",
+            DecisionKind::Colors => "color choice",
+            DecisionKind::Counters => "counter choice",
+            DecisionKind::Partition => "partition",
+            DecisionKind::Proliferate => "proliferate",
+            DecisionKind::Priority => "priority",
+            DecisionKind::Targets => "target selection",
+        }
+    }
+}
+
+impl fmt::Display for DecisionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// One recorded decision.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ReplayEntry {
+    /// Position of the decision in the recording, starting at 1.
+    pub seq: u64,
+    /// Which decision this answers.
+    pub kind: DecisionKind,
+    /// The player who made the decision.
+    pub player: PlayerId,
+    /// The answer given.
+    pub response: DecisionResponse,
+    /// A priority stops change the player made while deciding (CLI `stops`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_edit: Option<PriorityPolicyEdit>,
+}
+
+/// Errors reading a replay file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The file could not be read.
+    Io(String),
+    /// The file holds raw input lines from before versioned replays.
+    LegacyFormat,
+    /// The file was written by a different version of the format.
+    UnsupportedVersion(u32),
+    /// A line could not be parsed. Lines are numbered from 1.
+    Malformed { line: usize, message: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(message) => write!(f, "Failed to read replay: {message}"),
+            ReplayError::LegacyFormat => write!(
+                f,
+                "This is a raw-input replay from before replay format version \
+                 {REPLAY_FORMAT_VERSION}; convert it with --convert-replay"
+            ),
+            ReplayError::UnsupportedVersion(found) => write!(
+                f,
+                "Replay format version {found} is not supported (expected {REPLAY_FORMAT_VERSION})"
+            ),
+            ReplayError::Malformed { line, message } => {
+                write!(f, "Malformed replay at line {line}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// The decisions of a replay file, in order.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayLog {
+    pub entries: Vec<ReplayEntry>,
+}
+
+impl ReplayLog {
+    /// Parse the contents of a replay file.
+    pub fn parse(text: &str) -> Result<Self, ReplayError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let Some((_, first)) = lines.next() else {
+            return Err(ReplayError::Malformed {
+                line: 1,
+                message: "missing replay header".to_string(),
+            });
+        };
+        let header: ReplayHeader = match serde_json::from_str(first) {
+            Ok(header) => header,
+            Err(_) => return Err(ReplayError::LegacyFormat),
+        };
+        if header.format != REPLAY_FORMAT {
+            return Err(ReplayError::Malformed {
+                line: 1,
+                message: format!("unknown format '{}'", header.format),
+            });
+        }
+        if header.version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion(header.version));
+        }
+
+        let mut entries = Vec::new();
+        for (index, line) in lines {
+            let malformed = |message: String| ReplayError::Malformed {
+                line: index + 1,
+                message,
+            };
+            let entry: ReplayEntry =
+                serde_json::from_str(line).map_err(|err| malformed(err.to_string()))?;
+            let response_kind = DecisionKind::of(&entry.response);
+            if entry.kind != response_kind {
+                return Err(malformed(format!(
+                    "{} entry holds a response for {}",
+                    entry.kind, response_kind
+                )));
+            }
+            entries.push(entry);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Read and parse a replay file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplayError> {
+        let text = std::fs::read_to_string(path).map_err(|err| ReplayError::Io(err.to_string()))?;
+        Self::parse(&text)
+    }
+}
+
+/// Input lines of a raw-input replay, in the order the CLI read them.
+///
+/// Comment lines are dropped; blank lines are kept because they were answers
+/// (e.g. "no attackers").
+pub fn legacy_input_lines(text: &str) -> Vec<String> {
+    text.lines()
+        .filter(|line| !line.trim().starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Writes a replay file one decision at a time.
+///
+/// Every entry is flushed as soon as it is written, so a session that ends
+/// abruptly still leaves a usable replay of the decisions made so far.
+pub struct ReplayWriter<W: Write> {
+    sink: W,
+    next_seq: u64,
+}
+
+impl ReplayWriter<BufWriter<File>> {
+    /// Create (or truncate) a replay file at `path`.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> ReplayWriter<W> {
+    /// Start a replay on `sink` by writing the format header.
+    pub fn new(mut sink: W) -> io::Result<Self> {
+        let header = ReplayHeader {
+            format: REPLAY_FORMAT.to_string(),
+            version: REPLAY_FORMAT_VERSION,
+        };
+        serde_json::to_writer(&mut sink, &header)?;
+        writeln!(sink)?;
+        sink.flush()?;
+        Ok(Self { sink, next_seq: 1 })
+    }
+
+    /// Append a decision.
+    pub fn record(
+        &mut self,
+        player: PlayerId,
+        response: DecisionResponse,
+        policy_edit: Option<PriorityPolicyEdit>,
+    ) -> io::Result<()> {
+        let entry = ReplayEntry {
+            seq: self.next_seq,
+            kind: DecisionKind::of(&response),
+            player,
+            response,
+            policy_edit,
+        };
+        self.next_seq += 1;
+        serde_json::to_writer(&mut self.sink, &entry)?;
+        writeln!(self.sink)?;
+        self.sink.flush()
+    }
+
+    /// Stop recording and return the sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+/// [`DecisionMaker`] that records every decision another one makes.
+pub struct RecordingDecisionMaker<D, W: Write> {
+    inner: D,
+    writer: ReplayWriter<W>,
+    pending_edit: Option<PriorityPolicyEdit>,
+}
+
+impl<D: DecisionMaker, W: Write> RecordingDecisionMaker<D, W> {
+    /// Record the decisions `inner` makes to `writer`.
+    pub fn new(inner: D, writer: ReplayWriter<W>) -> Self {
+        Self {
+            inner,
+            writer,
+            pending_edit: None,
+        }
+    }
+
+    /// Stop recording and return the writer.
+    pub fn into_writer(self) -> ReplayWriter<W> {
+        self.writer
+    }
+
+    fn record(&mut self, player: PlayerId, response: DecisionResponse) {
+        let policy_edit = self.pending_edit.clone();
+        if let Err(err) = self.writer.record(player, response, policy_edit) {
+            eprintln!("Warning: failed to record decision: {err}");
+        }
+    }
+}
+
+impl<D: DecisionMaker, W: Write> DecisionMaker for RecordingDecisionMaker<D, W> {
+    fn on_auto_pass(&mut self, game: &GameState, player: PlayerId) {
+        self.inner.on_auto_pass(game, player);
+    }
+
+    fn on_action_cancelled(&mut self, game: &GameState, reason: &str) {
+        self.inner.on_action_cancelled(game, reason);
+    }
+
+    fn awaiting_choice(&self) -> bool {
+        self.inner.awaiting_choice()
+    }
+
+    fn take_priority_policy_edit(&mut self) -> Option<PriorityPolicyEdit> {
+        self.pending_edit.take()
+    }
+
+    fn clock_now_ms(&self) -> Option<u64> {
+        self.inner.clock_now_ms()
+    }
+
+    fn decide_boolean(&mut self, game: &GameState, ctx: &BooleanContext) -> bool {
+        let answer = self.inner.decide_boolean(game, ctx);
+        self.record(ctx.player, DecisionResponse::Boolean(answer));
+        answer
+    }
+
+    fn decide_number(&mut self, game: &GameState, ctx: &NumberContext) -> u32 {
+        let n = self.inner.decide_number(game, ctx);
+        self.record(ctx.player, DecisionResponse::Number(n));
+        n
+    }
+
+    fn decide_objects(&mut self, game: &GameState, ctx: &SelectObjectsContext) -> Vec<ObjectId> {
+        let ids = self.inner.decide_objects(game, ctx);
+        self.record(ctx.player, DecisionResponse::Objects(ids.clone()));
+        ids
+    }
+
+    fn decide_options(&mut self, game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+        let indices = self.inner.decide_options(game, ctx);
+        self.record(ctx.player, DecisionResponse::Options(indices.clone()));
+        indices
+    }
+
+    fn view_cards(
+        &mut self,
+        game: &GameState,
+        viewer: PlayerId,
+        cards: &[ObjectId],
+        ctx: &ViewCardsContext,
+    ) {
+        self.inner.view_cards(game, viewer, cards, ctx);
+    }
+
+    fn decide_order(&mut self, game: &GameState, ctx: &OrderContext) -> Vec<ObjectId> {
+        let ids = self.inner.decide_order(game, ctx);
+        self.record(ctx.player, DecisionResponse::Order(ids.clone()));
+        ids
+    }
+
+    fn decide_attackers(
+        &mut self,
+        game: &GameState,
+        ctx: &AttackersContext,
+    ) -> Vec<AttackerDeclaration> {
+        let declarations = self.inner.decide_attackers(game, ctx);
+        self.record(
+            ctx.player,
+            DecisionResponse::Attackers(declarations.clone()),
+        );
+        declarations
+    }
+
+    fn decide_blockers(
+        &mut self,
+        game: &GameState,
+        ctx: &BlockersContext,
+    ) -> Vec<BlockerDeclaration> {
+        let declarations = self.inner.decide_blockers(game, ctx);
+        self.record(ctx.player, DecisionResponse::Blockers(declarations.clone()));
+        declarations
+    }
+
+    fn decide_distribute(
+        &mut self,
+        game: &GameState,
+        ctx: &DistributeContext,
+    ) -> Vec<(Target, u32)> {
+        let shares = self.inner.decide_distribute(game, ctx);
+        self.record(ctx.player, DecisionResponse::Distribute(shares.clone()));
+        shares
+    }
+
+    fn decide_colors(&mut self, game: &GameState, ctx: &ColorsContext) -> Vec<Color> {
+        let colors = self.inner.decide_colors(game, ctx);
+        self.record(ctx.player, DecisionResponse::Colors(colors.clone()));
+        colors
+    }
+
+    fn decide_counters(
+        &mut self,
+        game: &GameState,
+        ctx: &CountersContext,
+    ) -> Vec<(CounterType, u32)> {
+        let counters = self.inner.decide_counters(game, ctx);
+        self.record(ctx.player, DecisionResponse::Counters(counters.clone()));
+        counters
+    }
+
+    fn decide_partition(&mut self, game: &GameState, ctx: &PartitionContext) -> Vec<ObjectId> {
+        let ids = self.inner.decide_partition(game, ctx);
+        self.record(ctx.player, DecisionResponse::Partition(ids.clone()));
+        ids
+    }
+
+    fn decide_proliferate(
+        &mut self,
+        game: &GameState,
+        ctx: &ProliferateContext,
+    ) -> ProliferateResponse {
+        let response = self.inner.decide_proliferate(game, ctx);
+        self.record(ctx.player, DecisionResponse::Proliferate(response.clone()));
+        response
+    }
+
+    fn decide_priority(&mut self, game: &GameState, ctx: &PriorityContext) -> LegalAction {
+        let action = self.inner.decide_priority(game, ctx);
+        self.pending_edit = self.inner.take_priority_policy_edit();
+        self.record(ctx.player, DecisionResponse::Priority(action.clone()));
+        action
+    }
+
+    fn decide_targets(&mut self, game: &GameState, ctx: &TargetsContext) -> Vec<Target> {
+        let targets = self.inner.decide_targets(game, ctx);
+        self.record(ctx.player, DecisionResponse::Targets(targets.clone()));
+        targets
+    }
+}
+
+/// A replayed response that does not fit the decision the game asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayDivergence {
+    /// Sequence number of the recorded entry.
+    pub seq: u64,
+    /// The decision the game asked for.
+    pub kind: DecisionKind,
+    /// The player the game asked.
+    pub player: PlayerId,
+    /// What did not match.
+    pub reason: String,
+}
+
+impl fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Replay diverged at decision #{} ({} for player {}): {}",
+            self.seq,
+            self.kind,
+            self.player.index(),
+            self.reason
+        )
+    }
+}
+
+impl std::error::Error for ReplayDivergence {}
+
+/// [`DecisionMaker`] that answers from a [`ReplayLog`].
+///
+/// Each decision takes the next entry, which must be of the same kind, for the
+/// same player, and a legal answer to the decision; anything else panics with
+/// a [`ReplayDivergence`]. Once the log runs out, decisions go to the fallback
+/// if one is set, and panic otherwise.
+pub struct ReplayDecisionMaker {
+    entries: Vec<ReplayEntry>,
+    next: usize,
+    pending_edit: Option<PriorityPolicyEdit>,
+    fallback: Option<Box<dyn DecisionMaker>>,
+    announced_end: bool,
+}
+
+impl ReplayDecisionMaker {
+    /// Replay the decisions in `log`.
+    pub fn new(log: ReplayLog) -> Self {
+        Self {
+            entries: log.entries,
+            next: 0,
+            pending_edit: None,
+            fallback: None,
+            announced_end: false,
+        }
+    }
+
+    /// Hand decisions to `fallback` once the log runs out.
+    pub fn with_fallback(mut self, fallback: Box<dyn DecisionMaker>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Whether every recorded decision has been replayed.
+    pub fn is_exhausted(&self) -> bool {
+        self.next >= self.entries.len()
+    }
+
+    /// The recorded answer to `context`, or `None` once the log has run out.
+    pub fn answer(
+        &mut self,
+        context: &DecisionContext,
+    ) -> Result<Option<DecisionResponse>, ReplayDivergence> {
+        let Some(entry) = self.entries.get(self.next) else {
+            return Ok(None);
+        };
+        let kind = DecisionKind::of_context(context);
+        let player = context.player();
+        let diverged = |reason: String| ReplayDivergence {
+            seq: entry.seq,
+            kind,
+            player,
+            reason,
+        };
+        if entry.kind != kind {
+            return Err(diverged(format!("recorded response is for {}", entry.kind)));
+        }
+        if entry.player != player {
+            return Err(diverged(format!(
+                "recorded for player {} instead",
+                entry.player.index()
+            )));
+        }
+        check_legal(context, &entry.response).map_err(diverged)?;
+
+        self.next += 1;
+        self.pending_edit = entry.policy_edit.clone();
+        Ok(Some(entry.response.clone()))
+    }
+
+    fn replay(&mut self, context: DecisionContext) -> Option<DecisionResponse> {
+        self.answer(&context)
+            .unwrap_or_else(|divergence| panic!("{divergence}"))
+    }
+
+    fn fallback(&mut self) -> &mut dyn DecisionMaker {
+        if !self.announced_end {
+            self.announced_end = true;
+            println!("\n=== Replay finished after {} decisions ===", self.next);
+        }
+        match self.fallback.as_deref_mut() {
+            Some(fallback) => fallback,
+            None => panic!("Replay exhausted after {} decisions", self.next),
+        }
+    }
+}
+
+impl DecisionMaker for ReplayDecisionMaker {
+    fn on_auto_pass(&mut self, game: &GameState, player: PlayerId) {
+        if self.is_exhausted()
+            && let Some(fallback) = self.fallback.as_deref_mut()
+        {
+            fallback.on_auto_pass(game, player);
+        }
+    }
+
+    fn on_action_cancelled(&mut self, game: &GameState, reason: &str) {
+        if self.is_exhausted()
+            && let Some(fallback) = self.fallback.as_deref_mut()
+        {
+            fallback.on_action_cancelled(game, reason);
+        }
+    }
+
+    fn take_priority_policy_edit(&mut self) -> Option<PriorityPolicyEdit> {
+        self.pending_edit.take().or_else(|| {
+            self.fallback
+                .as_deref_mut()
+                .and_then(|fallback| fallback.take_priority_policy_edit())
+        })
+    }
+
+    fn decide_boolean(&mut self, game: &GameState, ctx: &BooleanContext) -> bool {
+        match self.replay(DecisionContext::Boolean(ctx.clone())) {
+            Some(DecisionResponse::Boolean(answer)) => answer,
+            _ => self.fallback().decide_boolean(game, ctx),
+        }
+    }
+
+    fn decide_number(&mut self, game: &GameState, ctx: &NumberContext) -> u32 {
+        match self.replay(DecisionContext::Number(ctx.clone())) {
+            Some(DecisionResponse::Number(n)) => n,
+            _ => self.fallback().decide_number(game, ctx),
+        }
+    }
+
+    fn decide_objects(&mut self, game: &GameState, ctx: &SelectObjectsContext) -> Vec<ObjectId> {
+        match self.replay(DecisionContext::SelectObjects(ctx.clone())) {
+            Some(DecisionResponse::Objects(ids)) => ids,
+            _ => self.fallback().decide_objects(game, ctx),
+        }
+    }
+
+    fn decide_options(&mut self, game: &GameState, ctx: &SelectOptionsContext) -> Vec<usize> {
+        match self.replay(DecisionContext::SelectOptions(ctx.clone())) {
+            Some(DecisionResponse::Options(indices)) => indices,
+            _ => self.fallback().decide_options(game, ctx),
+        }
+    }
+
+    fn view_cards(
+        &mut self,
+        game: &GameState,
+        viewer: PlayerId,
+        cards: &[ObjectId],
+        ctx: &ViewCardsContext,
+    ) {
+        if self.is_exhausted()
+            && let Some(fallback) = self.fallback.as_deref_mut()
+        {
+            fallback.view_cards(game, viewer, cards, ctx);
+        }
+    }
+
+    fn decide_order(&mut self, game: &GameState, ctx: &OrderContext) -> Vec<ObjectId> {
+        match self.replay(DecisionContext::Order(ctx.clone())) {
+            Some(DecisionResponse::Order(ids)) => ids,
+            _ => self.fallback().decide_order(game, ctx),
+        }
+    }
+
+    fn decide_attackers(
+        &mut self,
+        game: &GameState,
+        ctx: &AttackersContext,
+    ) -> Vec<AttackerDeclaration> {
+        match self.replay(DecisionContext::Attackers(ctx.clone())) {
+            Some(DecisionResponse::Attackers(declarations)) => declarations,
+            _ => self.fallback().decide_attackers(game, ctx),
+        }
+    }
+
+    fn decide_blockers(
+        &mut self,
+        game: &GameState,
+        ctx: &BlockersContext,
+    ) -> Vec<BlockerDeclaration> {
+        match self.replay(DecisionContext::Blockers(ctx.clone())) {
+            Some(DecisionResponse::Blockers(declarations)) => declarations,
+            _ => self.fallback().decide_blockers(game, ctx),
+        }
+    }
+
+    fn decide_distribute(
+        &mut self,
+        game: &GameState,
+        ctx: &DistributeContext,
+    ) -> Vec<(Target, u32)> {
+        match self.replay(DecisionContext::Distribute(ctx.clone())) {
+            Some(DecisionResponse::Distribute(shares)) => shares,
+            _ => self.fallback().decide_distribute(game, ctx),
+        }
+    }
+
+    fn decide_colors(&mut self, game: &GameState, ctx: &ColorsContext) -> Vec<Color> {
+        match self.replay(DecisionContext::Colors(ctx.clone())) {
+            Some(DecisionResponse::Colors(colors)) => colors,
+            _ => self.fallback().decide_colors(game, ctx),
+        }
+    }
+
+    fn decide_counters(
+        &mut self,
+        game: &GameState,
+        ctx: &CountersContext,
+    ) -> Vec<(CounterType, u32)> {
+        match self.replay(DecisionContext::Counters(ctx.clone())) {
+            Some(DecisionResponse::Counters(counters)) => counters,
+            _ => self.fallback().decide_counters(game, ctx),
+        }
+    }
+
+    fn decide_partition(&mut self, game: &GameState, ctx: &PartitionContext) -> Vec<ObjectId> {
+        match self.replay(DecisionContext::Partition(ctx.clone())) {
+            Some(DecisionResponse::Partition(ids)) => ids,
+            _ => self.fallback().decide_partition(game, ctx),
+        }
+    }
+
+    fn decide_proliferate(
+        &mut self,
+        game: &GameState,
+        ctx: &ProliferateContext,
+    ) -> ProliferateResponse {
+        match self.replay(DecisionContext::Proliferate(ctx.clone())) {
+            Some(DecisionResponse::Proliferate(response)) => response,
+            _ => self.fallback().decide_proliferate(game, ctx),
+        }
+    }
+
+    fn decide_priority(&mut self, game: &GameState, ctx: &PriorityContext) -> LegalAction {
+        match self.replay(DecisionContext::Priority(ctx.clone())) {
+            Some(DecisionResponse::Priority(action)) => action,
+            _ => self.fallback().decide_priority(game, ctx),
+        }
+    }
+
+    fn decide_targets(&mut self, game: &GameState, ctx: &TargetsContext) -> Vec<Target> {
+        match self.replay(DecisionContext::Targets(ctx.clone())) {
+            Some(DecisionResponse::Targets(targets)) => targets,
+            _ => self.fallback().decide_targets(game, ctx),
+        }
+    }
+}
+
+/// Check that `response` only picks choices `context` offers.
+///
+/// Only membership is checked: the engine already handles answers that pick
+/// too few or too many, and a recording holds whatever was answered live.
+fn check_legal(context: &DecisionContext, response: &DecisionResponse) -> Result<(), String> {
+    match (context, response) {
+        (DecisionContext::Number(ctx), DecisionResponse::Number(n)) => {
+            if (ctx.min..=ctx.max).contains(n) {
+                return Ok(());
+            }
+            Err(format!("{n} is outside {}..={}", ctx.min, ctx.max))
+        }
+        (DecisionContext::SelectObjects(ctx), DecisionResponse::Objects(ids)) => {
+            ids.iter().try_for_each(|id| {
+                ctx.candidates
+                    .iter()
+                    .any(|candidate| candidate.id == *id && candidate.legal)
+                    .then_some(())
+                    .ok_or_else(|| format!("object {} is not a legal choice", id.0))
+            })
+        }
+        (DecisionContext::SelectOptions(ctx), DecisionResponse::Options(indices)) => {
+            indices.iter().try_for_each(|index| {
+                ctx.options
+                    .iter()
+                    .any(|option| option.index == *index && option.legal)
+                    .then_some(())
+                    .ok_or_else(|| format!("option {index} is not a legal choice"))
+            })
+        }
+        (DecisionContext::Order(ctx), DecisionResponse::Order(ids)) => {
+            let mut recorded = ids.clone();
+            let mut offered: Vec<_> = ctx.items.iter().map(|(id, _)| *id).collect();
+            recorded.sort();
+            offered.sort();
+            if recorded == offered {
+                return Ok(());
+            }
+            Err("the recorded order has different items".to_string())
+        }
+        (DecisionContext::Attackers(ctx), DecisionResponse::Attackers(declarations)) => {
+            declarations.iter().try_for_each(|declaration| {
+                ctx.attacker_options
+                    .iter()
+                    .any(|option| {
+                        option.creature == declaration.creature
+                            && option.valid_targets.contains(&declaration.target)
+                    })
+                    .then_some(())
+                    .ok_or_else(|| {
+                        format!(
+                            "object {} cannot attack {:?}",
+                            declaration.creature.0, declaration.target
+                        )
+                    })
+            })
+        }
+        (DecisionContext::Blockers(ctx), DecisionResponse::Blockers(declarations)) => {
+            declarations.iter().try_for_each(|declaration| {
+                ctx.blocker_options
+                    .iter()
+                    .any(|option| {
+                        option.attacker == declaration.blocking
+                            && option
+                                .valid_blockers
+                                .iter()
+                                .any(|(id, _)| *id == declaration.blocker)
+                    })
+                    .then_some(())
+                    .ok_or_else(|| {
+                        format!(
+                            "object {} cannot block object {}",
+                            declaration.blocker.0, declaration.blocking.0
+                        )
+                    })
+            })
+        }
+        (DecisionContext::Distribute(ctx), DecisionResponse::Distribute(shares)) => {
+            if let Some((target, _)) = shares
+                .iter()
+                .find(|(target, _)| !ctx.targets.iter().any(|t| t.target == *target))
+            {
+                return Err(format!("{target:?} is not a legal target"));
+            }
+            let total: u32 = shares.iter().map(|(_, amount)| amount).sum();
+            if total > ctx.total {
+                return Err(format!(
+                    "{total} distributed, but only {} available",
+                    ctx.total
+                ));
+            }
+            Ok(())
+        }
+        (DecisionContext::Colors(ctx), DecisionResponse::Colors(colors)) => {
+            let Some(available) = &ctx.available_colors else {
+                return Ok(());
+            };
+            colors.iter().try_for_each(|color| {
+                available
+                    .contains(color)
+                    .then_some(())
+                    .ok_or_else(|| format!("{color:?} is not an available color"))
+            })
+        }
+        (DecisionContext::Counters(ctx), DecisionResponse::Counters(counters)) => {
+            counters.iter().try_for_each(|(counter_type, count)| {
+                ctx.available_counters
+                    .iter()
+                    .any(|(available, max)| available == counter_type && count <= max)
+                    .then_some(())
+                    .ok_or_else(|| format!("cannot remove {count} {counter_type:?} counter(s)"))
+            })
+        }
+        (DecisionContext::Partition(ctx), DecisionResponse::Partition(ids)) => {
+            ids.iter().try_for_each(|id| {
+                ctx.cards
+                    .iter()
+                    .any(|(card, _)| card == id)
+                    .then_some(())
+                    .ok_or_else(|| format!("object {} is not being partitioned", id.0))
+            })
+        }
+        (DecisionContext::Proliferate(ctx), DecisionResponse::Proliferate(response)) => {
+            if let Some(id) = response
+                .permanents
+                .iter()
+                .find(|id| !ctx.eligible_permanents.iter().any(|(p, _)| p == *id))
+            {
+                return Err(format!("object {} cannot be proliferated", id.0));
+            }
+            if let Some(player) = response
+                .players
+                .iter()
+                .find(|player| !ctx.eligible_players.iter().any(|(p, _)| p == *player))
+            {
+                return Err(format!("player {} cannot be proliferated", player.index()));
+            }
+            Ok(())
+        }
+        (DecisionContext::Priority(ctx), DecisionResponse::Priority(action)) => {
+            if ctx.actions.contains(action) {
+                return Ok(());
+            }
+            Err(format!("{action:?} is not a legal action"))
+        }
+        (DecisionContext::Targets(ctx), DecisionResponse::Targets(targets)) => {
+            targets.iter().try_for_each(|target| {
+                ctx.requirements
+                    .iter()
+                    .any(|requirement| requirement.legal_targets.contains(target))
+                    .then_some(())
+                    .ok_or_else(|| format!("{target:?} is not a legal target"))
+            })
+        }
+        // Yes/no answers are always legal; the kind was checked by the caller.
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decisions::context::SelectableOption;
+    use crate::game_loop::{PriorityLoopState, run_priority_loop_with_state};
+    use crate::game_state::Phase;
+    use crate::triggers::TriggerQueue;
+    use crate::zone::Zone;
+
+    /// Plays the first land offered, then passes.
+    struct LandPlayer;
+
+    impl DecisionMaker for LandPlayer {
+        fn decide_priority(&mut self, _game: &GameState, ctx: &PriorityContext) -> LegalAction {
+            ctx.actions
+                .iter()
+                .find(|action| matches!(action, LegalAction::PlayLand { .. }))
+                .cloned()
+                .unwrap_or(LegalAction::PassPriority)
+        }
+    }
+
+    fn main_phase_game_with_forest() -> GameState {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        game.create_object_from_definition(
+            &crate::cards::definitions::basic_forest(),
+            alice,
+            Zone::Hand,
+        );
+        game.turn.phase = Phase::FirstMain;
+        game.turn.step = None;
+        game.turn.active_player = alice;
+        game.turn.priority_player = Some(alice);
+        game
+    }
+
+    fn run_priority(game: &mut GameState, dm: &mut impl DecisionMaker) {
+        let mut trigger_queue = TriggerQueue::new();
+        let mut state = PriorityLoopState::new(game.players_in_game());
+        run_priority_loop_with_state(game, &mut trigger_queue, &mut state, dm)
+            .expect("priority loop should finish");
+    }
+
+    fn choice_context(description: &str) -> SelectOptionsContext {
+        SelectOptionsContext::new(
+            PlayerId::from_index(0),
+            None,
+            description.to_string(),
+            vec![
+                SelectableOption::new(0, "First"),
+                SelectableOption::new(1, "Second"),
+            ],
+            1,
+            1,
+        )
+    }
+
+    #[test]
+    fn recorded_game_replays_to_the_same_board() {
+        let mut game = main_phase_game_with_forest();
+        let writer = ReplayWriter::new(Vec::new()).unwrap();
+        let mut recorder = RecordingDecisionMaker::new(LandPlayer, writer);
+        run_priority(&mut game, &mut recorder);
+        let text = String::from_utf8(recorder.into_writer().into_inner()).unwrap();
+
+        let log = ReplayLog::parse(&text).expect("recording should parse");
+        assert!(log.entries.iter().any(|entry| matches!(
+            entry.response,
+            DecisionResponse::Priority(LegalAction::PlayLand { .. })
+        )));
+
+        let mut replayed = main_phase_game_with_forest();
+        let mut replay = ReplayDecisionMaker::new(log);
+        run_priority(&mut replayed, &mut replay);
+        assert!(replay.is_exhausted());
+        assert_eq!(replayed.battlefield.len(), 1);
+        assert_eq!(replayed.battlefield, game.battlefield);
+    }
+
+    #[test]
+    fn old_raw_input_replay_is_rejected() {
+        let old = "# recorded with --record\n\n1\ny\n0:0\n";
+        let err = ReplayLog::parse(old).unwrap_err();
+        assert_eq!(err, ReplayError::LegacyFormat);
+        assert!(err.to_string().contains("--convert-replay"));
+
+        let future = "{\"format\":\"maigus-replay\",\"version\":99}\n";
+        assert_eq!(
+            ReplayLog::parse(future).unwrap_err(),
+            ReplayError::UnsupportedVersion(99)
+        );
+    }
+
+    #[test]
+    fn replay_survives_a_prompt_text_change() {
+        struct PickSecond;
+
+        impl DecisionMaker for PickSecond {
+            fn decide_options(
+                &mut self,
+                _game: &GameState,
+                _ctx: &SelectOptionsContext,
+            ) -> Vec<usize> {
+                vec![1]
+            }
+        }
+
+        let game = crate::tests::test_helpers::setup_two_player_game();
+        let writer = ReplayWriter::new(Vec::new()).unwrap();
+        let mut recorder = RecordingDecisionMaker::new(PickSecond, writer);
+        recorder.decide_options(&game, &choice_context("Choose a mode"));
+        let text = String::from_utf8(recorder.into_writer().into_inner()).unwrap();
+        assert!(!text.contains("Choose a mode"));
+
+        let mut replay = ReplayDecisionMaker::new(ReplayLog::parse(&text).unwrap());
+        let reworded = choice_context("Pick one of these modes");
+        assert_eq!(replay.decide_options(&game, &reworded), vec![1]);
+    }
+
+    #[test]
+    fn divergence_names_the_decision_it_happened_at() {
+        let mut writer = ReplayWriter::new(Vec::new()).unwrap();
+        let alice = PlayerId::from_index(0);
+        writer
+            .record(alice, DecisionResponse::Options(vec![0]), None)
+            .unwrap();
+        writer
+            .record(alice, DecisionResponse::Options(vec![5]), None)
+            .unwrap();
+        let text = String::from_utf8(writer.into_inner()).unwrap();
+        let mut replay = ReplayDecisionMaker::new(ReplayLog::parse(&text).unwrap());
+
+        let ctx = DecisionContext::SelectOptions(choice_context("Choose"));
+        assert!(replay.answer(&ctx).is_ok());
+        let divergence = replay.answer(&ctx).unwrap_err();
+        assert_eq!(divergence.seq, 2);
+        assert_eq!(
+            divergence.to_string(),
+            "Replay diverged at decision #2 (option selection for player 0): \
+             option 5 is not a legal choice"
+        );
+
+        let priority = DecisionContext::Priority(PriorityContext::new(alice, Vec::new()));
+        let divergence = replay.answer(&priority).unwrap_err();
+        assert_eq!(
+            divergence.reason,
+            "recorded response is for option selection"
+        );
+    }
+}
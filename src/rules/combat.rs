@@ -636,15 +636,16 @@ mod tests {
             base_toughness: Some(PtValue::Fixed(toughness)),
             base_loyalty: None,
             base_defense: None,
-            abilities: vec![],
+            abilities: Default::default(),
             counters: HashMap::new(),
             attached_to: None,
             attachments: vec![],
             spell_effect: None,
             aura_attach_filter: None,
-            alternative_casts: vec![],
+            alternative_casts: Default::default(),
             has_fuse: false,
-            optional_costs: vec![],
+            has_aftermath: false,
+            optional_costs: Default::default(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: crate::player::ManaPool::default(),
             x_value: None,
@@ -140,6 +140,25 @@ pub(crate) fn apply_processed_damage_assignment(
                 return AppliedDamageAssignment::default();
             }
 
+            // Damage dealt to a planeswalker removes that many loyalty counters
+            // (Rule 120.3c); a planeswalker creature is also dealt damage as a
+            // creature.
+            if is_planeswalker && let Some(permanent) = game.object_mut(object_id) {
+                let loyalty = permanent
+                    .counters
+                    .get(&crate::CounterType::Loyalty)
+                    .copied()
+                    .unwrap_or(0)
+                    .saturating_sub(amount);
+                if loyalty == 0 {
+                    permanent.counters.remove(&crate::CounterType::Loyalty);
+                } else {
+                    permanent
+                        .counters
+                        .insert(crate::CounterType::Loyalty, loyalty);
+                }
+            }
+
             if is_creature && (keywords.has_infect || keywords.has_wither) {
                 if let Some(permanent) = game.object_mut(object_id) {
                     *permanent
@@ -147,7 +166,7 @@ pub(crate) fn apply_processed_damage_assignment(
                         .entry(crate::CounterType::MinusOneMinusOne)
                         .or_insert(0) += amount;
                 }
-            } else {
+            } else if is_creature {
                 game.mark_damage(object_id, amount);
             }
 
@@ -498,15 +517,16 @@ mod tests {
             base_toughness: Some(PtValue::Fixed(toughness)),
             base_loyalty: None,
             base_defense: None,
-            abilities: vec![],
+            abilities: Default::default(),
             counters: HashMap::new(),
             attached_to: None,
             attachments: vec![],
             spell_effect: None,
             aura_attach_filter: None,
-            alternative_casts: vec![],
+            alternative_casts: Default::default(),
             has_fuse: false,
-            optional_costs: vec![],
+            has_aftermath: false,
+            optional_costs: Default::default(),
             optional_costs_paid: OptionalCostsPaid::default(),
             mana_spent_to_cast: crate::player::ManaPool::default(),
             x_value: None,
@@ -44,6 +44,8 @@ pub enum StateBasedAction {
     BestowBecomesCreature(ObjectId),
 
     /// An Equipment or Fortification is attached to an illegal permanent.
+    ///
+    /// Unlike an Aura, it stays on the battlefield unattached (Rule 704.5n).
     EquipmentFallsOff(ObjectId),
 
     /// +1/+1 and -1/-1 counters on a permanent annihilate (remove pairs).
@@ -157,7 +159,13 @@ fn check_player_sbas(game: &GameState, actions: &mut Vec<StateBasedAction>) {
             });
         }
 
-        // Note: "drew from empty library" is tracked separately when draw happens
+        // Attempted to draw from an empty library since the last check
+        if player.drew_from_empty_library {
+            actions.push(StateBasedAction::PlayerLoses {
+                player: player.id,
+                reason: LoseReason::DrewFromEmptyLibrary,
+            });
+        }
     }
 }
 
@@ -271,16 +279,22 @@ fn check_permanent_sbas_with_view(
             }
         }
 
-        // Equipment not attached to a creature
+        // Equipment not attached to a creature, or Fortification not attached to a land
+        let legal_host_type = if calculated_subtypes.contains(&Subtype::Equipment) {
+            Some(CardType::Creature)
+        } else if calculated_subtypes.contains(&Subtype::Fortification) {
+            Some(CardType::Land)
+        } else {
+            None
+        };
         if view.object_has_card_type(obj_id, CardType::Artifact)
-            && calculated_subtypes.contains(&Subtype::Equipment)
+            && let Some(host_type) = legal_host_type
             && let Some(attached_id) = obj.attached_to
         {
-            if game.object(attached_id).is_some() {
-                if !view.object_has_card_type(attached_id, CardType::Creature) {
-                    actions.push(StateBasedAction::EquipmentFallsOff(obj_id));
-                }
-            } else {
+            let host_on_battlefield = game
+                .object(attached_id)
+                .is_some_and(|host| host.zone == Zone::Battlefield);
+            if !host_on_battlefield || !view.object_has_card_type(attached_id, host_type) {
                 actions.push(StateBasedAction::EquipmentFallsOff(obj_id));
             }
         }
@@ -370,39 +384,38 @@ fn check_role_sbas_with_view(
 }
 
 /// Check for tokens not on battlefield and spell copies not on stack.
+///
+/// Per Rule 704.5d-e, a token that has left the battlefield and a copy of a
+/// spell that has left the stack cease to exist. The move itself has already
+/// been seen by triggers (a dying token still triggers "dies" abilities), so
+/// the object is only removed once the next SBA check runs.
 fn check_token_cleanup(game: &GameState, actions: &mut Vec<StateBasedAction>) {
-    // Check all zones except battlefield for tokens
+    use crate::object::ObjectKind;
+
+    // Objects on the stack are tracked by stack entries, so only the other
+    // zones are scanned. A resolved copy of a permanent spell is a token.
     for player in &game.players {
-        for &obj_id in &player.graveyard {
-            if let Some(obj) = game.object(obj_id)
-                && obj.kind == crate::object::ObjectKind::Token
-            {
-                actions.push(StateBasedAction::TokenCeasesToExist(obj_id));
-            }
-        }
-        for &obj_id in &player.hand {
-            if let Some(obj) = game.object(obj_id)
-                && obj.kind == crate::object::ObjectKind::Token
-            {
-                actions.push(StateBasedAction::TokenCeasesToExist(obj_id));
-            }
-        }
-        for &obj_id in &player.library {
-            if let Some(obj) = game.object(obj_id)
-                && obj.kind == crate::object::ObjectKind::Token
-            {
-                actions.push(StateBasedAction::TokenCeasesToExist(obj_id));
+        for zone in [
+            Zone::Graveyard,
+            Zone::Hand,
+            Zone::Library,
+            Zone::Exile,
+            Zone::Command,
+            Zone::Battlefield,
+        ] {
+            for obj_id in game.objects_in(zone, player.id) {
+                match game.object(obj_id).map(|obj| obj.kind) {
+                    Some(ObjectKind::Token) if zone != Zone::Battlefield => {
+                        actions.push(StateBasedAction::TokenCeasesToExist(obj_id));
+                    }
+                    Some(ObjectKind::SpellCopy) => {
+                        actions.push(StateBasedAction::CopyCeasesToExist(obj_id));
+                    }
+                    _ => {}
+                }
             }
         }
     }
-
-    for &obj_id in &game.exile {
-        if let Some(obj) = game.object(obj_id)
-            && obj.kind == crate::object::ObjectKind::Token
-        {
-            actions.push(StateBasedAction::TokenCeasesToExist(obj_id));
-        }
-    }
 }
 
 /// Check for +1/+1 and -1/-1 counter annihilation.
@@ -485,7 +498,7 @@ fn check_legend_rule(game: &GameState, actions: &mut Vec<StateBasedAction>) {
 /// Note: This version uses the CLI decision maker for any interactive choices
 /// that arise while applying SBAs.
 pub fn apply_state_based_actions(game: &mut GameState) -> bool {
-    let mut auto_dm = crate::decision::CliDecisionMaker;
+    let mut auto_dm = crate::decision::CliDecisionMaker::default();
     apply_state_based_actions_with(game, &mut auto_dm)
 }
 
@@ -631,6 +644,28 @@ pub fn apply_legend_rule_choice(game: &mut GameState, keep: ObjectId) {
     }
 }
 
+/// Put a permanent into its owner's graveyard as a state-based action.
+///
+/// The move still goes through replacement effects (e.g., Yawgmoth's Will,
+/// Rest in Peace), which may send it somewhere else.
+fn put_into_graveyard(
+    game: &mut GameState,
+    obj_id: ObjectId,
+    decision_maker: &mut dyn crate::decision::DecisionMaker,
+) {
+    use crate::event_processor::{ZoneChangeOutcome, process_zone_change};
+    let outcome = process_zone_change(
+        game,
+        obj_id,
+        Zone::Battlefield,
+        Zone::Graveyard,
+        decision_maker,
+    );
+    if let ZoneChangeOutcome::Proceed(final_zone) = outcome {
+        game.move_object(obj_id, final_zone);
+    }
+}
+
 /// Apply a single state-based action with pre-captured snapshots.
 ///
 /// Per Rule 704.7, creature death snapshots must be captured BEFORE any SBAs are applied.
@@ -666,38 +701,18 @@ fn apply_single_sba_with_snapshots(
                 // 0 toughness or object not found - goes directly to graveyard
                 // Regeneration cannot replace this (Rule 704.5f), but other
                 // replacement effects like Yawgmoth's Will can still apply
-                use crate::event_processor::{ZoneChangeOutcome, process_zone_change};
-                let outcome = process_zone_change(
-                    game,
-                    obj_id,
-                    Zone::Battlefield,
-                    Zone::Graveyard,
-                    decision_maker,
-                );
-                if let ZoneChangeOutcome::Proceed(final_zone) = outcome {
-                    game.move_object(obj_id, final_zone);
-                }
+                put_into_graveyard(game, obj_id, decision_maker);
             }
         }
 
         StateBasedAction::PlaneswalkerDies(obj_id) => {
-            // Process through replacement effects (e.g., Yawgmoth's Will)
-            use crate::event_processor::{ZoneChangeOutcome, process_zone_change};
-            let outcome = process_zone_change(
-                game,
-                obj_id,
-                Zone::Battlefield,
-                Zone::Graveyard,
-                decision_maker,
-            );
-            if let ZoneChangeOutcome::Proceed(final_zone) = outcome {
-                game.move_object(obj_id, final_zone);
-            }
+            put_into_graveyard(game, obj_id, decision_maker);
         }
 
         StateBasedAction::PlayerLoses { player, reason: _ } => {
             if let Some(p) = game.player_mut(player) {
                 p.has_lost = true;
+                p.drew_from_empty_library = false;
             }
         }
 
@@ -709,12 +724,24 @@ fn apply_single_sba_with_snapshots(
             // In a full implementation, the player would choose which to keep
             // For now, keep the first one, sacrifice the rest
             for &obj_id in permanents.iter().skip(1) {
-                game.move_object(obj_id, Zone::Graveyard);
+                put_into_graveyard(game, obj_id, decision_maker);
             }
         }
 
-        StateBasedAction::AuraFallsOff(obj_id) | StateBasedAction::EquipmentFallsOff(obj_id) => {
-            game.move_object(obj_id, Zone::Graveyard);
+        StateBasedAction::AuraFallsOff(obj_id) => {
+            put_into_graveyard(game, obj_id, decision_maker);
+        }
+
+        StateBasedAction::EquipmentFallsOff(obj_id) => {
+            let attached_to = game.object(obj_id).and_then(|obj| obj.attached_to);
+            if let Some(parent_id) = attached_to
+                && let Some(parent) = game.object_mut(parent_id)
+            {
+                parent.attachments.retain(|id| *id != obj_id);
+            }
+            if let Some(obj) = game.object_mut(obj_id) {
+                obj.attached_to = None;
+            }
         }
 
         StateBasedAction::BestowBecomesCreature(obj_id) => {
@@ -747,7 +774,7 @@ fn apply_single_sba_with_snapshots(
 
         StateBasedAction::SagaSacrifice(obj_id) => {
             // Saga is sacrificed (put into graveyard) after final chapter resolves
-            game.move_object(obj_id, Zone::Graveyard);
+            put_into_graveyard(game, obj_id, decision_maker);
         }
 
         StateBasedAction::CommanderReturnsToCommandZone(obj_id) => {
@@ -916,4 +943,187 @@ mod tests {
         assert_eq!(dm.calls, 2, "new object in exile should prompt again");
         assert_eq!(game.objects_in_zone(Zone::Exile), vec![exile_id]);
     }
+
+    #[test]
+    fn dying_token_triggers_blood_artist_then_ceases_to_exist() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        game.create_object_from_definition(
+            &crate::cards::definitions::blood_artist(),
+            alice,
+            Zone::Battlefield,
+        );
+        let soldier = CardBuilder::new(CardId::new(), "Soldier")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .build();
+        let token = game.create_object_from_card(&soldier, alice, Zone::Battlefield);
+        game.object_mut(token).unwrap().kind = crate::object::ObjectKind::Token;
+        game.mark_damage(token, 1);
+
+        let mut trigger_queue = crate::triggers::TriggerQueue::new();
+        let mut dm = AlwaysYesDecisionMaker;
+        crate::game_loop::check_and_apply_sbas_with(&mut game, &mut trigger_queue, &mut dm)
+            .unwrap();
+
+        assert_eq!(
+            trigger_queue.entries.len(),
+            1,
+            "Blood Artist should see the token die"
+        );
+        assert!(game.object(token).is_none());
+        assert!(game.player(alice).unwrap().graveyard.is_empty());
+    }
+
+    #[test]
+    fn spell_copy_off_the_stack_ceases_to_exist() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bolt = CardBuilder::new(CardId::new(), "Copied Bolt")
+            .card_types(vec![CardType::Instant])
+            .build();
+        let copy = game.create_object_from_card(&bolt, alice, Zone::Graveyard);
+        game.object_mut(copy).unwrap().kind = crate::object::ObjectKind::SpellCopy;
+
+        assert_eq!(
+            check_state_based_actions(&game),
+            vec![StateBasedAction::CopyCeasesToExist(copy)]
+        );
+        assert!(apply_state_based_actions(&mut game));
+        assert!(game.object(copy).is_none());
+        assert!(game.player(alice).unwrap().graveyard.is_empty());
+    }
+
+    fn attachment_test_permanent(
+        game: &mut GameState,
+        owner: PlayerId,
+        card_type: CardType,
+        subtypes: Vec<Subtype>,
+    ) -> ObjectId {
+        let mut builder = CardBuilder::new(CardId::new(), "Attachment Test")
+            .card_types(vec![card_type])
+            .subtypes(subtypes);
+        if card_type == CardType::Creature {
+            builder = builder.power_toughness(PowerToughness::fixed(2, 2));
+        }
+        game.create_object_from_card(&builder.build(), owner, Zone::Battlefield)
+    }
+
+    fn attach(game: &mut GameState, attachment: ObjectId, host: ObjectId) {
+        let mut ctx =
+            ExecutionContext::new_default(attachment, game.object(attachment).unwrap().controller)
+                .with_targets(vec![ResolvedTarget::Object(host)]);
+        crate::effects::EffectExecutor::execute(
+            &crate::effects::AttachToEffect::new(crate::target::ChooseSpec::target_permanent()),
+            game,
+            &mut ctx,
+        )
+        .expect("attach should succeed");
+    }
+
+    #[test]
+    fn aura_goes_to_graveyard_when_enchanted_creature_leaves() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bear = attachment_test_permanent(&mut game, alice, CardType::Creature, Vec::new());
+        let aura =
+            attachment_test_permanent(&mut game, alice, CardType::Enchantment, vec![Subtype::Aura]);
+        attach(&mut game, aura, bear);
+        assert!(check_state_based_actions(&game).is_empty());
+
+        game.move_object(bear, Zone::Graveyard);
+        assert_eq!(
+            check_state_based_actions(&game),
+            vec![StateBasedAction::AuraFallsOff(aura)]
+        );
+        apply_state_based_actions(&mut game);
+
+        assert!(!game.battlefield.contains(&aura));
+        assert_eq!(game.player(alice).unwrap().graveyard.len(), 2);
+    }
+
+    #[test]
+    fn equipment_stays_on_battlefield_unattached_when_creature_leaves() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bear = attachment_test_permanent(&mut game, alice, CardType::Creature, Vec::new());
+        let equipment = attachment_test_permanent(
+            &mut game,
+            alice,
+            CardType::Artifact,
+            vec![Subtype::Equipment],
+        );
+        attach(&mut game, equipment, bear);
+
+        game.move_object(bear, Zone::Graveyard);
+        apply_state_based_actions(&mut game);
+
+        assert!(game.battlefield.contains(&equipment));
+        assert_eq!(game.object(equipment).unwrap().attached_to, None);
+    }
+
+    #[test]
+    fn fortification_unattaches_from_a_nonland() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let land = attachment_test_permanent(&mut game, alice, CardType::Land, Vec::new());
+        let bear = attachment_test_permanent(&mut game, alice, CardType::Creature, Vec::new());
+        let fortification = attachment_test_permanent(
+            &mut game,
+            alice,
+            CardType::Artifact,
+            vec![Subtype::Fortification],
+        );
+        attach(&mut game, fortification, land);
+        assert!(check_state_based_actions(&game).is_empty());
+
+        attach(&mut game, fortification, bear);
+        assert_eq!(
+            check_state_based_actions(&game),
+            vec![StateBasedAction::EquipmentFallsOff(fortification)]
+        );
+        apply_state_based_actions(&mut game);
+
+        assert!(game.battlefield.contains(&fortification));
+        assert_eq!(game.object(fortification).unwrap().attached_to, None);
+        assert!(game.object(bear).unwrap().attachments.is_empty());
+    }
+
+    #[test]
+    fn re_equipping_moves_granted_haste_between_creatures() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let first = attachment_test_permanent(&mut game, alice, CardType::Creature, Vec::new());
+        let second = attachment_test_permanent(&mut game, alice, CardType::Creature, Vec::new());
+        game.set_summoning_sick(first);
+        game.set_summoning_sick(second);
+        let greaves = game.create_object_from_definition(
+            &crate::cards::definitions::lightning_greaves(),
+            alice,
+            Zone::Battlefield,
+        );
+
+        attach(&mut game, greaves, first);
+        assert!(crate::rules::combat::can_attack(
+            game.object(first).unwrap(),
+            &game
+        ));
+        assert!(!crate::rules::combat::can_attack(
+            game.object(second).unwrap(),
+            &game
+        ));
+
+        attach(&mut game, greaves, second);
+        apply_state_based_actions(&mut game);
+        assert!(game.object(first).unwrap().attachments.is_empty());
+        assert_eq!(game.object(second).unwrap().attachments, vec![greaves]);
+        assert!(!crate::rules::combat::can_attack(
+            game.object(first).unwrap(),
+            &game
+        ));
+        assert!(crate::rules::combat::can_attack(
+            game.object(second).unwrap(),
+            &game
+        ));
+    }
 }
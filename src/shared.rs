@@ -0,0 +1,149 @@
+//! Copy-on-write storage for card data shared between objects.
+//!
+//! Every object created from the same [`CardDefinition`](crate::cards::CardDefinition)
+//! starts with identical abilities, spell effects, and costs. Storing them in a
+//! [`SharedVec`] makes creating, cloning, and snapshotting those objects a
+//! reference-count bump; the first mutation gives the object its own copy.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+/// A `Vec` that is shared between clones until one of them is mutated.
+///
+/// Reads go through `Deref<Target = Vec<T>>`; mutable access (`push`,
+/// `retain`, `iter_mut`, ...) goes through `DerefMut`, which copies the
+/// contents first if anything else still shares them.
+pub struct SharedVec<T>(Arc<Vec<T>>);
+
+impl<T> SharedVec<T> {
+    pub fn new() -> Self {
+        Self(Arc::new(Vec::new()))
+    }
+
+    /// Whether both vectors point at the same shared storage.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Clone> SharedVec<T> {
+    /// Take the contents, copying them only if they are still shared.
+    pub fn into_vec(self) -> Vec<T> {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<T> Clone for SharedVec<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Default for SharedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for SharedVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T: Clone> DerefMut for SharedVec<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<T> From<Vec<T>> for SharedVec<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self(Arc::new(items))
+    }
+}
+
+impl<T: Clone> From<SharedVec<T>> for Vec<T> {
+    fn from(items: SharedVec<T>) -> Self {
+        items.into_vec()
+    }
+}
+
+impl<T> FromIterator<T> for SharedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Arc::new(iter.into_iter().collect()))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SharedVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut SharedVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Arc::make_mut(&mut self.0).iter_mut()
+    }
+}
+
+impl<T: Clone> IntoIterator for SharedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SharedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for SharedVec<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SharedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedVec;
+
+    #[test]
+    fn clones_share_storage_until_mutated() {
+        let original: SharedVec<i32> = vec![1, 2, 3].into();
+        let mut copy = original.clone();
+        assert!(copy.ptr_eq(&original));
+
+        copy.push(4);
+        assert!(!copy.ptr_eq(&original));
+        assert_eq!(original, vec![1, 2, 3]);
+        assert_eq!(copy, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_vec_moves_unshared_contents() {
+        let items: SharedVec<String> = vec!["a".to_string()].into();
+        let shared = items.clone();
+        assert_eq!(items.into_vec(), vec!["a".to_string()]);
+        assert_eq!(shared.into_vec(), vec!["a".to_string()]);
+    }
+}
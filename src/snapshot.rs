@@ -22,6 +22,7 @@ use crate::ids::CardId;
 use crate::ids::{ObjectId, PlayerId, StableId};
 use crate::mana::ManaCost;
 use crate::object::{CounterType, Object, ObjectKind};
+use crate::shared::SharedVec;
 use crate::static_abilities::StaticAbilityId;
 use crate::types::{CardType, Subtype, Supertype};
 use crate::zone::Zone;
@@ -73,7 +74,7 @@ pub struct ObjectSnapshot {
     /// Loyalty (if planeswalker).
     pub loyalty: Option<u32>,
     /// Abilities the object had.
-    pub abilities: Vec<Ability>,
+    pub abilities: SharedVec<Ability>,
     /// X value chosen when this object was cast (if any).
     pub x_value: Option<u32>,
 
@@ -209,6 +210,7 @@ impl ObjectSnapshot {
         // which include continuous effects like anthems, pumps, etc.
         if let Some(calculated) = game.calculated_characteristics_with_effects(obj.id, effects) {
             // Override with calculated values (these include continuous effects)
+            snapshot.controller = calculated.controller;
             snapshot.power = calculated.power;
             snapshot.toughness = calculated.toughness;
             snapshot.card_types = calculated.card_types;
@@ -412,7 +414,7 @@ impl ObjectSnapshot {
             base_power: None,
             base_toughness: None,
             loyalty: None,
-            abilities: vec![],
+            abilities: Default::default(),
             x_value: None,
             counters: HashMap::new(),
             is_token: false,
@@ -87,12 +87,7 @@ fn spell_has_suspend_timing(
         || object.has_card_type(CardType::Artifact)
         || object.has_card_type(CardType::Enchantment)
         || object.has_card_type(CardType::Planeswalker);
-    let has_flash = object.abilities.iter().any(|ability| {
-        matches!(
-            &ability.kind,
-            crate::ability::AbilityKind::Static(static_ability) if static_ability.has_flash()
-        )
-    });
+    let has_flash = crate::decision::spell_has_active_flash(game, player, object, object.id);
 
     if !is_sorcery_speed || has_flash {
         return game.turn.priority_player == Some(player);
@@ -127,6 +122,10 @@ fn has_sorcery_speed_special_action_timing(
 
 /// A special action that can be performed without using the stack.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum SpecialAction {
     /// Play a land from hand to the battlefield.
     PlayLand { card_id: ObjectId },
@@ -915,6 +914,7 @@ fn check_mana_ability_condition(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: Some(ability_index),
         options: crate::condition_eval::ExternalEvaluationOptions::default(),
@@ -980,6 +980,7 @@ pub fn perform_activate_mana_ability_restricted_colors(
         drop(cost_ctx);
 
         // Add mana to player's pool
+        let snow_source = game.object_has_supertype(permanent_id, crate::types::Supertype::Snow);
         if let Some(player_data) = game.player_mut(player) {
             for symbol in mana {
                 if mana_usage_restrictions.is_empty() {
@@ -992,6 +993,9 @@ pub fn perform_activate_mana_ability_restricted_colors(
                         restrictions: mana_usage_restrictions.clone(),
                     });
                 }
+                if snow_source {
+                    player_data.mana_pool.mark_snow(symbol, 1);
+                }
             }
         }
 
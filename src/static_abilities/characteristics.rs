@@ -75,6 +75,10 @@ impl StaticAbilityKind for CharacteristicDefiningPT {
             .with_source_type(EffectSourceType::CharacteristicDefining),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
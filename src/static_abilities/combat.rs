@@ -29,6 +29,10 @@ macro_rules! define_combat_ability {
             fn display(&self) -> String {
                 $display.to_string()
             }
+
+            fn effects_depend_only_on_source(&self) -> bool {
+                true
+            }
         }
     };
 }
@@ -762,6 +766,7 @@ impl CantAttackUnlessCondition {
             attacking_player: Some(controller),
             filter_source: Some(source),
             triggering_event: None,
+            source_snapshot: None,
             trigger_identity: None,
             ability_index: None,
             options: Default::default(),
@@ -896,6 +901,7 @@ impl CantAttackUnlessCondition {
                             attacking_player: Some(controller),
                             filter_source: Some(source),
                             triggering_event: None,
+                            source_snapshot: None,
                             trigger_identity: None,
                             ability_index: None,
                             options: Default::default(),
@@ -1564,6 +1570,7 @@ mod tests {
         let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let alice = PlayerId::from_index(0);
         let bob = PlayerId::from_index(1);
+        let attacker = game.new_object_id();
 
         let ability = CantAttackUnlessCondition::new(
             CantAttackUnlessConditionSpec::DefendingPlayerCondition(
@@ -1572,12 +1579,12 @@ mod tests {
             "Can't attack unless defending player is the monarch",
         );
         assert_eq!(
-            ability.can_attack_specific_defender(&game, ObjectId::new(), alice, bob),
+            ability.can_attack_specific_defender(&game, attacker, alice, bob),
             Some(false)
         );
         game.set_monarch(Some(bob));
         assert_eq!(
-            ability.can_attack_specific_defender(&game, ObjectId::new(), alice, bob),
+            ability.can_attack_specific_defender(&game, attacker, alice, bob),
             Some(true)
         );
     }
@@ -1687,7 +1694,7 @@ mod tests {
         let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let bob = PlayerId::from_index(1);
         let tax = ability
-            .generic_attack_tax_per_attacker_against_you(&game, ObjectId::new(), bob)
+            .generic_attack_tax_per_attacker_against_you(&game, ObjectId::from_raw(1), bob)
             .expect("fixed attack tax should resolve");
         assert_eq!(tax, 2);
     }
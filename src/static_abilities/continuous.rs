@@ -488,6 +488,16 @@ fn describe_static_condition(condition: &crate::ConditionExpr) -> String {
         crate::ConditionExpr::SourceIsSoulbondPaired => {
             "as long as this creature is paired with another creature".to_string()
         }
+        crate::ConditionExpr::AttackedThisTurn => "as long as you attacked this turn".to_string(),
+        crate::ConditionExpr::CreatureDiedThisTurn => {
+            "as long as a creature died this turn".to_string()
+        }
+        crate::ConditionExpr::OpponentLostLifeThisTurn => {
+            "as long as an opponent lost life this turn".to_string()
+        }
+        crate::ConditionExpr::PermanentLeftBattlefieldUnderYourControlThisTurn => {
+            "as long as a permanent you controlled left the battlefield this turn".to_string()
+        }
         crate::ConditionExpr::PlayerHasCardTypesInGraveyardOrMore { player, count } => {
             let graveyard_owner = match player {
                 crate::target::PlayerFilter::You => "your".to_string(),
@@ -596,6 +606,7 @@ fn static_condition_is_active(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: None,
         options: Default::default(),
@@ -798,6 +809,12 @@ impl StaticAbilityKind for Anthem {
         static_condition_is_active(condition, game, source, source_obj.controller)
     }
 
+    fn effects_depend_only_on_source(&self) -> bool {
+        self.condition.is_none()
+            && matches!(self.power, AnthemValue::Fixed(_))
+            && matches!(self.toughness, AnthemValue::Fixed(_))
+    }
+
     fn is_anthem(&self) -> bool {
         true
     }
@@ -938,6 +955,10 @@ impl StaticAbilityKind for GrantAbility {
         };
         static_condition_is_active(condition, game, source, source_obj.controller)
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        self.condition.is_none()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1051,6 +1072,10 @@ impl StaticAbilityKind for SoulbondSharedBonus {
         ]
     }
 
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
+
     fn apply_restrictions(&self, game: &mut GameState, source: ObjectId, controller: PlayerId) {
         let SoulbondSharedMode::Ability(ability) = &self.mode else {
             return;
@@ -1115,6 +1140,10 @@ impl StaticAbilityKind for RemoveAbilityForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Remove all abilities: "Creatures lose all abilities"
@@ -1158,6 +1187,10 @@ impl StaticAbilityKind for RemoveAllAbilitiesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Remove all non-mana abilities: "Lands lose all abilities except mana abilities"
@@ -1201,6 +1234,10 @@ impl StaticAbilityKind for RemoveAllAbilitiesExceptManaForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Set base P/T: "... have base power and toughness N/M"
@@ -1283,6 +1320,10 @@ impl StaticAbilityKind for SetBasePowerToughnessForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Copy activated abilities from objects matching a filter.
@@ -1389,12 +1430,17 @@ impl StaticAbilityKind for CopyActivatedAbilities {
             attacking_player: None,
             filter_source: Some(source),
             triggering_event: None,
+            source_snapshot: None,
             trigger_identity: None,
             ability_index: None,
             options: Default::default(),
         };
         crate::condition_eval::evaluate_condition_external(game, condition, &eval_ctx)
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        self.condition.is_none()
+    }
 }
 
 /// Equipment grant: "Equipped creature has [abilities]"
@@ -1477,6 +1523,10 @@ impl StaticAbilityKind for SetColorsForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Set name: "Enchanted creature is named Legitimate Businessperson."
@@ -1519,6 +1569,10 @@ impl StaticAbilityKind for SetNameForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Add colors: "Enchanted creature is black in addition to its other colors."
@@ -1568,6 +1622,10 @@ impl StaticAbilityKind for AddColorsForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Add card types: "All permanents are artifacts in addition to their other types."
@@ -1650,6 +1708,10 @@ impl StaticAbilityKind for AddCardTypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Remove card types: "This creature isn't a creature."
@@ -1729,6 +1791,10 @@ impl StaticAbilityKind for RemoveCardTypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Set card types: "Enchanted permanent is a creature."
@@ -1782,6 +1848,10 @@ impl StaticAbilityKind for SetCardTypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Add subtypes: "Enchanted creature is a Zombie in addition to its other types."
@@ -1884,6 +1954,10 @@ impl StaticAbilityKind for AddSubtypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Set creature subtypes by removing all creature types first, then adding the new list.
@@ -1944,6 +2018,10 @@ impl StaticAbilityKind for SetCreatureSubtypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Make colorless: "All permanents are colorless."
@@ -1997,6 +2075,10 @@ impl StaticAbilityKind for MakeColorlessForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Remove supertypes: "All lands are no longer snow."
@@ -2066,6 +2148,10 @@ impl StaticAbilityKind for AddSupertypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 impl RemoveSupertypesForFilter {
@@ -2121,6 +2207,10 @@ impl StaticAbilityKind for RemoveSupertypesForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 impl EquipmentGrant {
@@ -2172,6 +2262,10 @@ impl StaticAbilityKind for EquipmentGrant {
             })
             .collect()
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Enchanted/attached permanent has an activated or triggered ability.
@@ -2240,6 +2334,10 @@ impl StaticAbilityKind for AttachedAbilityGrant {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Controller of source controls the permanent attached to source.
@@ -2279,6 +2377,10 @@ impl StaticAbilityKind for ControlAttachedPermanent {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// "Enchanted land is the chosen type."
@@ -2322,6 +2424,10 @@ impl StaticAbilityKind for EnchantedLandIsChosenType {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Permanents matching a filter have an activated or triggered ability.
@@ -2387,6 +2493,10 @@ impl StaticAbilityKind for GrantObjectAbilityForFilter {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
 }
 
 /// Blood Moon: "Nonbasic lands are Mountains"
@@ -2434,6 +2544,10 @@ impl StaticAbilityKind for BloodMoon {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 /// Toph, the First Metalbender: "Nontoken artifacts you control are lands in addition to their other types."
@@ -2466,6 +2580,10 @@ impl StaticAbilityKind for TophFirstMetalbender {
             .with_source_type(EffectSourceType::StaticAbility),
         ]
     }
+
+    fn effects_depend_only_on_source(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +223,10 @@ fn describe_cost_modifier_amount(amount: &Value) -> (String, Option<String>) {
                 counter_type.description()
             )),
         ),
+        Value::CardTypesAmong(filter) => (
+            "{1}".to_string(),
+            Some(format!("for each card type among {}", filter.description())),
+        ),
         Value::CardTypesInGraveyard(player) => {
             let owner = match player {
                 PlayerFilter::You => "your",
@@ -299,6 +303,7 @@ fn cost_modifier_condition_is_active(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: None,
         options: Default::default(),
@@ -488,6 +493,9 @@ pub struct CostReduction {
     pub filter: ObjectFilter,
     pub reduction: Value,
     pub condition: Option<crate::ConditionExpr>,
+    /// How many matching spells per turn the reduction applies to, if limited
+    /// ("The first creature spell you cast each turn costs {2} less to cast").
+    pub uses_per_turn: Option<u32>,
 }
 
 impl CostReduction {
@@ -496,6 +504,7 @@ impl CostReduction {
             filter,
             reduction,
             condition: None,
+            uses_per_turn: None,
         }
     }
 
@@ -503,6 +512,12 @@ impl CostReduction {
         self.condition = Some(condition);
         self
     }
+
+    /// Only apply to the first `uses` matching spells cast each turn.
+    pub fn with_uses_per_turn(mut self, uses: u32) -> Self {
+        self.uses_per_turn = Some(uses);
+        self
+    }
 }
 
 impl StaticAbilityKind for CostReduction {
@@ -512,6 +527,11 @@ impl StaticAbilityKind for CostReduction {
 
     fn display(&self) -> String {
         let (amount_text, tail) = describe_cost_modifier_amount(&self.reduction);
+        if self.uses_per_turn == Some(1) {
+            let subject = describe_spell_filter(&self.filter).replacen("spells", "spell", 1);
+            let line = format!("The first {subject} each turn costs {amount_text} less to cast");
+            return describe_cost_modifier_with_condition(line, &self.condition);
+        }
         if let Some(subject) = describe_flashback_cost_subject(&self.filter) {
             let mut line = format!("{subject} cost {amount_text} less");
             if let Some(tail) = tail {
@@ -787,6 +807,7 @@ fn this_spell_condition_eval_ctx<'a>(
         attacking_player: None,
         filter_source: Some(source),
         triggering_event: None,
+        source_snapshot: None,
         trigger_identity: None,
         ability_index: None,
         options: Default::default(),
@@ -927,7 +948,8 @@ pub fn this_spell_cost_condition_is_active_for_cast(
             .iter()
             .filter(|player| player.is_in_game() && player.id != controller)
             .any(|player| {
-                game.spells_cast_this_turn
+                game.turn_trackers
+                    .spells_cast
                     .get(&player.id)
                     .copied()
                     .unwrap_or(0)
@@ -958,7 +980,8 @@ pub fn this_spell_cost_condition_is_active_for_cast(
         }
         ThisSpellCostCondition::YouCastSpellsThisTurnOrMore { count, card_types } => {
             if card_types.is_empty() {
-                game.spells_cast_this_turn
+                game.turn_trackers
+                    .spells_cast
                     .get(&controller)
                     .copied()
                     .unwrap_or(0)
@@ -978,7 +1001,8 @@ pub fn this_spell_cost_condition_is_active_for_cast(
             }
         }
         ThisSpellCostCondition::YouGainedLifeThisTurnOrMore(n) => {
-            game.life_gained_this_turn
+            game.turn_trackers
+                .life_gained
                 .get(&controller)
                 .copied()
                 .unwrap_or(0)
@@ -1013,7 +1037,8 @@ pub fn this_spell_cost_condition_is_active_for_cast(
                 > 0
         }
         ThisSpellCostCondition::CreatureLeftBattlefieldUnderYourControlThisTurn => {
-            game.creatures_left_battlefield_under_controller_this_turn
+            game.turn_trackers
+                .creatures_left_battlefield_under_controller
                 .get(&controller)
                 .copied()
                 .unwrap_or(0)
@@ -1401,14 +1426,17 @@ impl StaticAbilityKind for CostIncreaseManaCost {
 
 /// Cost increase per additional target:
 /// "This spell costs {N} more to cast for each target beyond the first."
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Strive ("This spell costs {1}{G} more to cast for each target beyond the
+/// first") uses the same ability with colored symbols.
+#[derive(Debug, Clone, PartialEq)]
 pub struct CostIncreasePerAdditionalTarget {
-    pub amount: u32,
+    pub increase: ManaCost,
 }
 
 impl CostIncreasePerAdditionalTarget {
-    pub fn new(amount: u32) -> Self {
-        Self { amount }
+    pub fn new(increase: ManaCost) -> Self {
+        Self { increase }
     }
 }
 
@@ -1419,8 +1447,8 @@ impl StaticAbilityKind for CostIncreasePerAdditionalTarget {
 
     fn display(&self) -> String {
         format!(
-            "This spell costs {{{}}} more to cast for each target beyond the first",
-            self.amount
+            "This spell costs {} more to cast for each target beyond the first",
+            describe_cost_modifier_mana_cost(&self.increase)
         )
     }
 
@@ -1428,8 +1456,8 @@ impl StaticAbilityKind for CostIncreasePerAdditionalTarget {
         true
     }
 
-    fn cost_increase_per_additional_target(&self) -> Option<u32> {
-        Some(self.amount)
+    fn cost_increase_per_additional_target(&self) -> Option<&ManaCost> {
+        Some(&self.increase)
     }
 }
 
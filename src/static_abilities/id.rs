@@ -8,6 +8,10 @@
 /// This is used for identity checking without pattern matching on trait objects.
 /// When checking if an ability is a specific type, compare against this enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum StaticAbilityId {
     // === Keyword abilities ===
     Flying,
@@ -180,7 +184,11 @@ pub enum StaticAbilityId {
     MaximumHandSizeSevenMinusYourGraveyardCardTypes,
     LibraryOfLengDiscardReplacement,
     DrawReplacementExileTopFaceDown,
+    DrawFromEmptyLibraryWinInstead,
     ExileToCounteredExileInsteadOfGraveyard,
+    ExileInsteadOfGraveyard,
+    CounterPlacementReplacement,
+    TokenCreationReplacement,
     CreaturesEnteringDontCauseAbilitiesToTrigger,
     StartingLifeBonus,
     BuybackCostReduction,
@@ -387,7 +395,11 @@ impl StaticAbilityId {
             | MaximumHandSizeSevenMinusYourGraveyardCardTypes
             | LibraryOfLengDiscardReplacement
             | DrawReplacementExileTopFaceDown
+            | DrawFromEmptyLibraryWinInstead
             | ExileToCounteredExileInsteadOfGraveyard
+            | ExileInsteadOfGraveyard
+            | CounterPlacementReplacement
+            | TokenCreationReplacement
             | CreaturesEnteringDontCauseAbilitiesToTrigger
             | StartingLifeBonus
             | BuybackCostReduction
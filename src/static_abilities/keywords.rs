@@ -32,6 +32,10 @@ macro_rules! define_keyword {
                 true
             }
 
+            fn effects_depend_only_on_source(&self) -> bool {
+                true
+            }
+
             $(
                 fn $method(&self) -> bool {
                     $value
@@ -12,12 +12,17 @@ use crate::ability::LevelAbility;
 use crate::color::Color;
 use crate::compiled_text::describe_value;
 use crate::effect::{Condition, Effect, Value};
-use crate::events::cards::matchers::{WouldDiscardMatcher, WouldDrawCardMatcher};
+use crate::events::cards::matchers::{
+    WouldDiscardMatcher, WouldDrawCardMatcher, WouldDrawFromEmptyLibraryMatcher,
+};
+use crate::events::counters::matchers::WouldPutCountersMatcher;
 use crate::events::damage::matchers::{
-    DamageFromSelfMatcher, DamageToObjectMatcher, DamageToPlayerOrObjectMatcher,
-    DamageToSelfCombatMatcher, DamageToSelfFromSourceFilterMatcher,
+    DamageFromSelfMatcher, DamageToObjectMatcher, DamageToPlayerMatcher,
+    DamageToPlayerOrObjectMatcher, DamageToSelfCombatMatcher, DamageToSelfFromSourceFilterMatcher,
+};
+use crate::events::permanents::matchers::{
+    AttachedPermanentWouldBeDestroyedMatcher, WouldCreateTokensMatcher,
 };
-use crate::events::permanents::matchers::AttachedPermanentWouldBeDestroyedMatcher;
 use crate::events::traits::{EventKind, ReplacementMatcher, ReplacementPriority, downcast_event};
 use crate::events::zones::matchers::{
     ThisWouldEnterBattlefieldMatcher, ThisWouldGoToGraveyardMatcher, WouldEnterBattlefieldMatcher,
@@ -28,7 +33,9 @@ use crate::grant::GrantSpec;
 use crate::ids::{ObjectId, PlayerId};
 use crate::mana::ManaCost;
 use crate::object::CounterType;
-use crate::replacement::{RedirectTarget, RedirectWhich, ReplacementAction, ReplacementEffect};
+use crate::replacement::{
+    EventModification, RedirectTarget, RedirectWhich, ReplacementAction, ReplacementEffect,
+};
 use crate::tag::SOURCE_EXILED_TAG;
 use crate::target::{ChooseSpec, ObjectFilter, PlayerFilter};
 use crate::types::Subtype;
@@ -622,6 +629,7 @@ impl ReplacementMatcher for ThisWouldEnterTappedUnlessConditionMatcher {
             attacking_player: None,
             filter_source: None,
             triggering_event: None,
+            source_snapshot: None,
             trigger_identity: None,
             ability_index: None,
             options: Default::default(),
@@ -939,6 +947,7 @@ impl ReplacementMatcher for ThisWouldEnterWithCountersIfConditionMatcher {
             attacking_player: None,
             filter_source: None,
             triggering_event: None,
+            source_snapshot: None,
             trigger_identity: None,
             ability_index: None,
             options: Default::default(),
@@ -1187,16 +1196,23 @@ impl StaticAbilityKind for EnterAsCopyAsEnters {
     }
 }
 
-/// "All damage that would be dealt to you and other permanents you control is dealt to this creature instead."
+/// "All damage that would be dealt to you [and other permanents you control] is dealt to this
+/// creature instead."
+///
+/// Without an object filter only damage to matching players is redirected.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RedirectDamageToSource {
     pub player_filter: PlayerFilter,
-    pub object_filter: ObjectFilter,
+    pub object_filter: Option<ObjectFilter>,
     pub display: String,
 }
 
 impl RedirectDamageToSource {
-    pub fn new(player_filter: PlayerFilter, object_filter: ObjectFilter, display: String) -> Self {
+    pub fn new(
+        player_filter: PlayerFilter,
+        object_filter: Option<ObjectFilter>,
+        display: String,
+    ) -> Self {
         Self {
             player_filter,
             object_filter,
@@ -1219,18 +1235,27 @@ impl StaticAbilityKind for RedirectDamageToSource {
         source: ObjectId,
         controller: PlayerId,
     ) -> Option<ReplacementEffect> {
-        Some(ReplacementEffect::with_matcher(
-            source,
-            controller,
-            DamageToPlayerOrObjectMatcher::new(
-                self.player_filter.clone(),
-                self.object_filter.clone(),
+        let action = ReplacementAction::Redirect {
+            target: RedirectTarget::ToSource,
+            which: RedirectWhich::First,
+        };
+        Some(match &self.object_filter {
+            Some(object_filter) => ReplacementEffect::with_matcher(
+                source,
+                controller,
+                DamageToPlayerOrObjectMatcher::new(
+                    self.player_filter.clone(),
+                    object_filter.clone(),
+                ),
+                action,
             ),
-            ReplacementAction::Redirect {
-                target: RedirectTarget::ToSource,
-                which: RedirectWhich::First,
-            },
-        ))
+            None => ReplacementEffect::with_matcher(
+                source,
+                controller,
+                DamageToPlayerMatcher::new(self.player_filter.clone()),
+                action,
+            ),
+        })
     }
 }
 
@@ -2306,6 +2331,34 @@ impl StaticAbilityKind for DrawReplacementExileTopFaceDown {
     }
 }
 
+/// "If you would draw a card while your library has no cards in it, you win the game instead."
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrawFromEmptyLibraryWinInstead;
+
+impl StaticAbilityKind for DrawFromEmptyLibraryWinInstead {
+    fn id(&self) -> StaticAbilityId {
+        StaticAbilityId::DrawFromEmptyLibraryWinInstead
+    }
+
+    fn display(&self) -> String {
+        "If you would draw a card while your library has no cards in it, you win the game instead."
+            .to_string()
+    }
+
+    fn generate_replacement_effect(
+        &self,
+        source: ObjectId,
+        controller: PlayerId,
+    ) -> Option<ReplacementEffect> {
+        Some(ReplacementEffect::with_matcher(
+            source,
+            controller,
+            WouldDrawFromEmptyLibraryMatcher::you(),
+            ReplacementAction::Instead(vec![Effect::win_the_game()]),
+        ))
+    }
+}
+
 /// "If a card would be put into an opponent's graveyard from anywhere, instead exile it with a
 /// void counter on it."
 #[derive(Debug, Clone, PartialEq)]
@@ -2368,6 +2421,201 @@ impl StaticAbilityKind for ExileToCounteredExileInsteadOfGraveyard {
     }
 }
 
+/// "If a card [or token] would be put into [a player's] graveyard from anywhere, exile it
+/// instead."
+///
+/// Used by: Rest in Peace, Leyline of the Void
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExileInsteadOfGraveyard {
+    pub player: PlayerFilter,
+    /// Whether tokens are exiled too ("a card or token") or only cards.
+    pub include_tokens: bool,
+}
+
+impl ExileInsteadOfGraveyard {
+    pub fn new(player: PlayerFilter, include_tokens: bool) -> Self {
+        Self {
+            player,
+            include_tokens,
+        }
+    }
+
+    fn graveyard_phrase(&self) -> &'static str {
+        match self.player {
+            PlayerFilter::You => "your graveyard",
+            PlayerFilter::Opponent => "an opponent's graveyard",
+            _ => "a graveyard",
+        }
+    }
+}
+
+impl StaticAbilityKind for ExileInsteadOfGraveyard {
+    fn id(&self) -> StaticAbilityId {
+        StaticAbilityId::ExileInsteadOfGraveyard
+    }
+
+    fn display(&self) -> String {
+        let subject = if self.include_tokens {
+            "a card or token"
+        } else {
+            "a card"
+        };
+        format!(
+            "If {subject} would be put into {} from anywhere, exile it instead.",
+            self.graveyard_phrase()
+        )
+    }
+
+    fn generate_replacement_effect(
+        &self,
+        source: ObjectId,
+        controller: PlayerId,
+    ) -> Option<ReplacementEffect> {
+        let mut filter = ObjectFilter::default().owned_by(self.player.clone());
+        if !self.include_tokens {
+            filter = filter.nontoken();
+        }
+        Some(ReplacementEffect::with_matcher(
+            source,
+            controller,
+            crate::events::zones::matchers::WouldGoToGraveyardMatcher::new(filter),
+            ReplacementAction::ChangeDestination(Zone::Exile),
+        ))
+    }
+}
+
+/// "twice that many" / "that many plus one" for a count replacement.
+fn replaced_count_phrase(modification: &EventModification) -> String {
+    let number = |amount: u32| {
+        number_word_u32(amount)
+            .map(str::to_string)
+            .unwrap_or_else(|| amount.to_string())
+    };
+    match modification {
+        EventModification::Multiply(2) => "twice that many".to_string(),
+        EventModification::Multiply(factor) => format!("{} times that many", number(*factor)),
+        EventModification::Add(amount) if *amount >= 0 => {
+            format!("that many plus {}", number(*amount as u32))
+        }
+        EventModification::Add(amount) => {
+            format!("that many minus {}", number(amount.unsigned_abs()))
+        }
+        EventModification::Subtract(amount) => format!("that many minus {}", number(*amount)),
+        EventModification::SetTo(amount) => number(*amount),
+        EventModification::ReduceToZero => "no".to_string(),
+    }
+}
+
+/// "If one or more [+1/+1] counters would be put on [filter], twice that many
+/// [+1/+1] counters are put on it instead."
+///
+/// Used by: Doubling Season, Hardened Scales, Corpsejack Menace
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterPlacementReplacement {
+    pub filter: ObjectFilter,
+    /// Only counters of this kind are affected; `None` affects every kind.
+    pub counter_type: Option<CounterType>,
+    pub modification: EventModification,
+}
+
+impl CounterPlacementReplacement {
+    pub fn new(
+        filter: ObjectFilter,
+        counter_type: Option<CounterType>,
+        modification: EventModification,
+    ) -> Self {
+        Self {
+            filter,
+            counter_type,
+            modification,
+        }
+    }
+}
+
+impl StaticAbilityKind for CounterPlacementReplacement {
+    fn id(&self) -> StaticAbilityId {
+        StaticAbilityId::CounterPlacementReplacement
+    }
+
+    fn display(&self) -> String {
+        let counters = match self.counter_type {
+            Some(counter_type) => format!("{} counters", counter_type.description()),
+            None => "counters".to_string(),
+        };
+        let mut recipient = self.filter.description();
+        if !recipient.starts_with("a ") && !recipient.starts_with("an ") {
+            recipient = format!("{} {recipient}", indefinite_article(&recipient));
+        }
+        format!(
+            "If one or more {counters} would be put on {recipient}, {} {counters} are put on it instead.",
+            replaced_count_phrase(&self.modification)
+        )
+    }
+
+    fn generate_replacement_effect(
+        &self,
+        source: ObjectId,
+        controller: PlayerId,
+    ) -> Option<ReplacementEffect> {
+        Some(ReplacementEffect::with_matcher(
+            source,
+            controller,
+            WouldPutCountersMatcher::new(self.filter.clone(), self.counter_type),
+            ReplacementAction::Modify(self.modification.clone()),
+        ))
+    }
+}
+
+/// "If an effect would create one or more tokens under your control, it creates
+/// twice that many of those tokens instead."
+///
+/// Used by: Doubling Season, Parallel Lives, Anointed Procession, Primal Vigor
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenCreationReplacement {
+    pub player: PlayerFilter,
+    pub modification: EventModification,
+}
+
+impl TokenCreationReplacement {
+    pub fn new(player: PlayerFilter, modification: EventModification) -> Self {
+        Self {
+            player,
+            modification,
+        }
+    }
+}
+
+impl StaticAbilityKind for TokenCreationReplacement {
+    fn id(&self) -> StaticAbilityId {
+        StaticAbilityId::TokenCreationReplacement
+    }
+
+    fn display(&self) -> String {
+        let count = replaced_count_phrase(&self.modification);
+        match self.player {
+            PlayerFilter::You => format!(
+                "If an effect would create one or more tokens under your control, it creates {count} of those tokens instead."
+            ),
+            _ => format!(
+                "If one or more tokens would be created, {count} of those tokens are created instead."
+            ),
+        }
+    }
+
+    fn generate_replacement_effect(
+        &self,
+        source: ObjectId,
+        controller: PlayerId,
+    ) -> Option<ReplacementEffect> {
+        Some(ReplacementEffect::with_matcher(
+            source,
+            controller,
+            WouldCreateTokensMatcher::new(self.player.clone()),
+            ReplacementAction::Modify(self.modification.clone()),
+        ))
+    }
+}
+
 // =============================================================================
 // Interactive ETB Replacement Abilities (Unified System)
 // =============================================================================
@@ -2963,7 +3211,7 @@ mod tests {
         let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let source = ObjectId::from_raw(52);
         let alice = PlayerId::from_index(0);
-        game.players_attacked_this_turn.insert(alice);
+        game.turn_trackers.players_attacked.insert(alice);
 
         let ability = EntersWithCountersIfCondition::new(
             CounterType::PlusOnePlusOne,
@@ -3019,7 +3267,7 @@ mod tests {
         let source = ObjectId::from_raw(52);
         let alice = PlayerId::from_index(0);
         let bob = PlayerId::from_index(1);
-        game.life_lost_this_turn.insert(bob, 2);
+        game.turn_trackers.life_lost.insert(bob, 2);
 
         let ability = EntersWithCountersIfCondition::new(
             CounterType::PlusOnePlusOne,
@@ -3047,7 +3295,8 @@ mod tests {
         let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
         let source = ObjectId::from_raw(52);
         let alice = PlayerId::from_index(0);
-        game.permanents_left_battlefield_under_controller_this_turn
+        game.turn_trackers
+            .permanents_left_battlefield_under_controller
             .insert(alice, 1);
 
         let ability = EntersWithCountersIfCondition::new(
@@ -3329,4 +3578,111 @@ mod tests {
             "umbra armor aura should be in the graveyard after replacing lethal damage"
         );
     }
+
+    fn opponents_creatures_enter_tapped_source(game: &mut GameState, controller: PlayerId) {
+        let def =
+            crate::cards::builders::CardDefinitionBuilder::new(CardId::new(), "Authority Variant")
+                .card_types(vec![CardType::Enchantment])
+                .parse_text("Creatures your opponents control enter tapped.")
+                .expect("opponents-control enters tapped line should parse");
+        game.create_object_from_definition(&def, controller, Zone::Battlefield);
+    }
+
+    fn creature_card(game: &mut GameState, owner: PlayerId, zone: Zone) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), "Test Bear")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        game.create_object_from_card(&card, owner, zone)
+    }
+
+    #[derive(Default)]
+    struct CountingDecisionMaker {
+        option_choices: usize,
+    }
+
+    impl crate::decision::DecisionMaker for CountingDecisionMaker {
+        fn decide_options(
+            &mut self,
+            _game: &GameState,
+            _ctx: &crate::decisions::context::SelectOptionsContext,
+        ) -> Vec<usize> {
+            self.option_choices += 1;
+            vec![0]
+        }
+    }
+
+    #[test]
+    fn test_opponents_creatures_enter_tapped_only_for_opponents() {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        opponents_creatures_enter_tapped_source(&mut game, alice);
+
+        let alice_creature = creature_card(&mut game, alice, Zone::Hand);
+        let bob_creature = creature_card(&mut game, bob, Zone::Hand);
+        let alice_result = game
+            .move_object_with_etb_processing(alice_creature, Zone::Battlefield)
+            .expect("alice's creature should enter");
+        let bob_result = game
+            .move_object_with_etb_processing(bob_creature, Zone::Battlefield)
+            .expect("bob's creature should enter");
+
+        assert!(!game.is_tapped(alice_result.new_id));
+        assert!(game.is_tapped(bob_result.new_id));
+    }
+
+    #[test]
+    fn test_opponents_creatures_enter_tapped_uses_entering_controller() {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        opponents_creatures_enter_tapped_source(&mut game, alice);
+
+        // Bob's card put onto the battlefield under Alice's control enters untapped.
+        let stolen = creature_card(&mut game, bob, Zone::Graveyard);
+        let mut dm = crate::decision::SelectFirstDecisionMaker;
+        let stolen_result = game
+            .move_object_with_etb_processing_under_control_with_dm(
+                stolen,
+                Zone::Battlefield,
+                Some(alice),
+                &mut dm,
+            )
+            .expect("stolen creature should enter");
+        assert!(!game.is_tapped(stolen_result.new_id));
+
+        // Alice's card put onto the battlefield under Bob's control enters tapped.
+        let gifted = creature_card(&mut game, alice, Zone::Graveyard);
+        let gifted_result = game
+            .move_object_with_etb_processing_under_control_with_dm(
+                gifted,
+                Zone::Battlefield,
+                Some(bob),
+                &mut dm,
+            )
+            .expect("gifted creature should enter");
+        assert!(game.is_tapped(gifted_result.new_id));
+    }
+
+    #[test]
+    fn test_multiple_enter_tapped_replacements_apply_without_order_choice() {
+        let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        opponents_creatures_enter_tapped_source(&mut game, alice);
+        opponents_creatures_enter_tapped_source(&mut game, alice);
+
+        let bob_creature = creature_card(&mut game, bob, Zone::Hand);
+        let mut dm = CountingDecisionMaker::default();
+        let result = game
+            .move_object_with_etb_processing_with_dm(bob_creature, Zone::Battlefield, &mut dm)
+            .expect("bob's creature should enter");
+
+        assert!(game.is_tapped(result.new_id));
+        assert_eq!(
+            dm.option_choices, 0,
+            "identical enter-tapped replacements should not prompt for an order"
+        );
+    }
 }
@@ -389,6 +389,18 @@ pub trait StaticAbilityKind: std::fmt::Debug + Send + Sync + StaticAbilityKindCl
         true
     }
 
+    /// Whether `is_active` and `generate_effects` only look at the source
+    /// object and its controller.
+    ///
+    /// The static ability processor reuses effects from such abilities until
+    /// the source changes zone, controller, or counters. Caching is opt-in:
+    /// override to return true only after checking that neither method reads
+    /// other game state (conditions, counted values, attachments, chosen
+    /// options, ...).
+    fn effects_depend_only_on_source(&self) -> bool {
+        false
+    }
+
     // ========================================================================
     // Query methods for specific ability checks
     // These allow checking ability properties without pattern matching.
@@ -751,7 +763,7 @@ pub trait StaticAbilityKind: std::fmt::Debug + Send + Sync + StaticAbilityKindCl
     }
 
     /// Get additional cost per target beyond the first, if any.
-    fn cost_increase_per_additional_target(&self) -> Option<u32> {
+    fn cost_increase_per_additional_target(&self) -> Option<&crate::mana::ManaCost> {
         None
     }
 
@@ -907,8 +919,13 @@ impl StaticAbility {
     }
 
     /// Get the display text for this ability.
+    pub fn display_text(&self) -> crate::display_text::DisplayText {
+        crate::display_text::DisplayText::literal(self.0.display())
+    }
+
+    /// Render the display text with the default English renderer.
     pub fn display(&self) -> String {
-        self.0.display()
+        self.display_text().display()
     }
 
     pub fn with_condition(&self, condition: crate::ConditionExpr) -> Option<Self> {
@@ -935,6 +952,16 @@ impl StaticAbility {
         self.0.is_active(game, source)
     }
 
+    /// Whether this ability's effects only depend on its source object.
+    pub fn effects_depend_only_on_source(&self) -> bool {
+        self.0.effects_depend_only_on_source()
+    }
+
+    /// Whether both handles share the same underlying ability instance.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
     /// Generate a replacement effect for this ability.
     pub fn generate_replacement_effect(
         &self,
@@ -1176,7 +1203,7 @@ impl StaticAbility {
         self.0.cost_increase_mana_cost()
     }
 
-    pub fn cost_increase_per_additional_target(&self) -> Option<u32> {
+    pub fn cost_increase_per_additional_target(&self) -> Option<&crate::mana::ManaCost> {
         self.0.cost_increase_per_additional_target()
     }
 
@@ -1819,8 +1846,8 @@ impl StaticAbility {
         Self::new(AffinityForArtifacts)
     }
 
-    pub fn cost_increase_per_target_beyond_first(amount: u32) -> Self {
-        Self::new(CostIncreasePerAdditionalTarget::new(amount))
+    pub fn cost_increase_per_target_beyond_first(increase: crate::mana::ManaCost) -> Self {
+        Self::new(CostIncreasePerAdditionalTarget::new(increase))
     }
 
     pub fn reduce_activated_ability_costs(
@@ -1921,13 +1948,23 @@ impl StaticAbility {
     pub fn redirect_damage_from_you_and_other_permanents_to_source() -> Self {
         Self::new(RedirectDamageToSource::new(
             crate::target::PlayerFilter::You,
-            crate::target::ObjectFilter::permanent()
-                .you_control()
-                .other(),
+            Some(
+                crate::target::ObjectFilter::permanent()
+                    .you_control()
+                    .other(),
+            ),
             "All damage that would be dealt to you and other permanents you control is dealt to this creature instead.".to_string(),
         ))
     }
 
+    pub fn redirect_damage_from_you_to_source() -> Self {
+        Self::new(RedirectDamageToSource::new(
+            crate::target::PlayerFilter::You,
+            None,
+            "All damage that would be dealt to you is dealt to this creature instead.".to_string(),
+        ))
+    }
+
     pub fn players_cant_cycle() -> Self {
         Self::new(PlayersCantCycle)
     }
@@ -1964,6 +2001,10 @@ impl StaticAbility {
         Self::new(DrawReplacementExileTopFaceDown)
     }
 
+    pub fn draw_from_empty_library_win_instead() -> Self {
+        Self::new(DrawFromEmptyLibraryWinInstead)
+    }
+
     pub fn exile_to_countered_exile_instead_of_graveyard(
         player: crate::target::PlayerFilter,
         counter_type: crate::object::CounterType,
@@ -1974,6 +2015,32 @@ impl StaticAbility {
         ))
     }
 
+    pub fn exile_instead_of_graveyard(
+        player: crate::target::PlayerFilter,
+        include_tokens: bool,
+    ) -> Self {
+        Self::new(ExileInsteadOfGraveyard::new(player, include_tokens))
+    }
+
+    pub fn counter_placement_replacement(
+        filter: crate::target::ObjectFilter,
+        counter_type: Option<crate::object::CounterType>,
+        modification: crate::replacement::EventModification,
+    ) -> Self {
+        Self::new(CounterPlacementReplacement::new(
+            filter,
+            counter_type,
+            modification,
+        ))
+    }
+
+    pub fn token_creation_replacement(
+        player: crate::target::PlayerFilter,
+        modification: crate::replacement::EventModification,
+    ) -> Self {
+        Self::new(TokenCreationReplacement::new(player, modification))
+    }
+
     pub fn players_cant_gain_life() -> Self {
         Self::new(PlayersCantGainLife)
     }
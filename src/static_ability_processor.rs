@@ -31,9 +31,185 @@
 //! dynamically to all objects matching their criteria, as opposed to resolution
 //! effects which lock their targets at resolution time (Rule 611.2c).
 
+use std::collections::HashMap;
+
 use crate::ability::AbilityKind;
-use crate::continuous::ContinuousEffect;
+use crate::continuous::{ContinuousEffect, ContinuousEffectId, Modification};
 use crate::game_state::GameState;
+use crate::ids::{ObjectId, PlayerId};
+use crate::object::{CounterType, Object};
+use crate::static_abilities::StaticAbility;
+use crate::zone::Zone;
+
+/// Effects generated by source-only static abilities, remembered between
+/// refreshes.
+///
+/// Entries are keyed by source object and ability index. An entry is reused
+/// only while the ability instance and the source's zone, controller, counters,
+/// and entry timestamp are unchanged; anything else regenerates it, and entries
+/// for sources that were not seen in the latest pass are dropped. Abilities that
+/// read other game state (see `effects_depend_only_on_source`) are never cached.
+///
+/// When every functioning static ability is cached and current, the registered
+/// effects are the same, and no grant needs expanding, the previous result is
+/// still correct and the whole rebuild is skipped.
+#[derive(Debug, Clone, Default)]
+pub struct StaticEffectCache {
+    entries: HashMap<(ObjectId, usize), CachedStaticEffects>,
+    /// Registered effects seen by the last full pass.
+    registered: Vec<ContinuousEffectId>,
+    /// Whether the last full pass had grants that must be re-expanded every time.
+    expands_grants: bool,
+    /// Whether a full pass has filled the cache since it was created or reset.
+    primed: bool,
+    hits: u64,
+    misses: u64,
+    disabled: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CachedStaticEffects {
+    ability: StaticAbility,
+    zone: Zone,
+    controller: PlayerId,
+    counters: HashMap<CounterType, u32>,
+    timestamp: Option<u64>,
+    effects: Vec<ContinuousEffect>,
+}
+
+impl CachedStaticEffects {
+    fn is_current(&self, object: &Object, ability: &StaticAbility, timestamp: Option<u64>) -> bool {
+        self.ability.ptr_eq(ability)
+            && self.zone == object.zone
+            && self.controller == object.controller
+            && self.timestamp == timestamp
+            && self.counters == object.counters
+    }
+}
+
+impl StaticEffectCache {
+    /// Turn caching on or off. While off, every refresh regenerates all
+    /// effects and nothing is kept.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+        if !enabled {
+            self.entries.clear();
+            self.registered.clear();
+            self.primed = false;
+        }
+    }
+
+    /// Number of cached ability entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// How many ability lookups reused cached effects.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// How many ability lookups had to regenerate effects.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Whether the effects produced by the last full pass are still current.
+    fn is_unchanged(&self, game: &GameState) -> bool {
+        if !self.primed || self.expands_grants {
+            return false;
+        }
+        let registered = game.continuous_effects.effects();
+        if registered.len() != self.registered.len()
+            || registered
+                .iter()
+                .zip(&self.registered)
+                .any(|(effect, id)| effect.id != *id)
+        {
+            return false;
+        }
+
+        let mut seen = 0;
+        for object in game.objects_iter() {
+            if game.is_phased_out(object.id) {
+                continue;
+            }
+            let timestamp = game.continuous_effects.get_entry_timestamp(object.id);
+            for (index, ability) in object.abilities.iter().enumerate() {
+                let AbilityKind::Static(static_ability) = &ability.kind else {
+                    continue;
+                };
+                if !ability.functions_in(&object.zone) {
+                    continue;
+                }
+                if !static_ability.effects_depend_only_on_source() {
+                    return false;
+                }
+                match self.entries.get(&(object.id, index)) {
+                    Some(entry) if entry.is_current(object, static_ability, timestamp) => {
+                        seen += 1;
+                    }
+                    _ => return false,
+                }
+            }
+        }
+        seen == self.entries.len()
+    }
+
+    fn effects_for(
+        &mut self,
+        previous: &mut HashMap<(ObjectId, usize), CachedStaticEffects>,
+        game: &GameState,
+        object: &Object,
+        index: usize,
+        ability: &StaticAbility,
+    ) -> Vec<ContinuousEffect> {
+        let key = (object.id, index);
+        let timestamp = game.continuous_effects.get_entry_timestamp(object.id);
+        let entry = match previous.remove(&key) {
+            Some(entry) if entry.is_current(object, ability, timestamp) => {
+                self.hits += 1;
+                entry
+            }
+            _ => {
+                self.misses += 1;
+                let effects = if ability.is_active(game, object.id) {
+                    let mut effects = ability.generate_effects(object.id, object.controller, game);
+                    stamp_entry_timestamp(&mut effects, timestamp);
+                    effects
+                } else {
+                    Vec::new()
+                };
+                CachedStaticEffects {
+                    ability: ability.clone(),
+                    zone: object.zone,
+                    controller: object.controller,
+                    counters: object.counters.clone(),
+                    timestamp,
+                    effects,
+                }
+            }
+        };
+        let effects = entry.effects.clone();
+        self.entries.insert(key, entry);
+        effects
+    }
+}
+
+/// Static ability effect timestamps come from the source object's
+/// current-zone entry timestamp (CR 613.7a/613.7d behavior).
+fn stamp_entry_timestamp(effects: &mut [ContinuousEffect], timestamp: Option<u64>) {
+    if let Some(ts) = timestamp {
+        for effect in effects {
+            effect.timestamp = ts;
+        }
+    }
+}
 
 /// Generate all continuous effects from static abilities in zones where they function.
 ///
@@ -45,46 +221,215 @@ use crate::game_state::GameState;
 /// static ability effects are properly integrated into the layer system.
 pub fn generate_continuous_effects_from_static_abilities(
     game: &GameState,
+) -> Vec<ContinuousEffect> {
+    generate_static_ability_effects(game, None)
+}
+
+/// Same as `generate_continuous_effects_from_static_abilities`, but reuses
+/// effects from `cache` for abilities whose source hasn't changed.
+///
+/// Returns `None` when nothing that feeds the static ability effects changed
+/// since the previous call with this cache, so its result is still current.
+pub fn refresh_continuous_effects_with_cache(
+    game: &GameState,
+    cache: &mut StaticEffectCache,
+) -> Option<Vec<ContinuousEffect>> {
+    if cache.disabled {
+        return Some(generate_static_ability_effects(game, None));
+    }
+    if cache.is_unchanged(game) {
+        cache.hits += cache.entries.len() as u64;
+        return None;
+    }
+
+    let effects = generate_static_ability_effects(game, Some(cache));
+    let registered = game.continuous_effects.effects();
+    cache.registered = registered.iter().map(|effect| effect.id).collect();
+    cache.expands_grants = grants_reactive_ability(game, registered.iter().chain(&effects));
+    cache.primed = true;
+    Some(effects)
+}
+
+fn generate_static_ability_effects(
+    game: &GameState,
+    mut cache: Option<&mut StaticEffectCache>,
 ) -> Vec<ContinuousEffect> {
     let mut effects = Vec::new();
+    let mut previous = cache
+        .as_deref_mut()
+        .map(|cache| std::mem::take(&mut cache.entries))
+        .unwrap_or_default();
 
     let mut object_ids: Vec<_> = game.objects_iter().map(|object| object.id).collect();
     object_ids.sort_unstable();
 
     // Iterate over all objects and apply static abilities only in zones where they function.
+    // Phased-out permanents are treated as though they don't exist (Rule 702.26b).
     for object_id in object_ids {
+        if game.is_phased_out(object_id) {
+            continue;
+        }
         if let Some(object) = game.object(object_id) {
             let controller = object.controller;
             let zone = object.zone;
 
             // Process each static ability on the object
-            for ability in &object.abilities {
+            for (index, ability) in object.abilities.iter().enumerate() {
                 if let AbilityKind::Static(static_ability) = &ability.kind {
                     if !ability.functions_in(&zone) {
                         continue;
                     }
+                    if let Some(cache) = cache.as_deref_mut()
+                        && static_ability.effects_depend_only_on_source()
+                    {
+                        effects.extend(cache.effects_for(
+                            &mut previous,
+                            game,
+                            object,
+                            index,
+                            static_ability,
+                        ));
+                        continue;
+                    }
                     if !static_ability.is_active(game, object_id) {
                         continue;
                     }
                     // Generate effects directly from the trait method
                     let mut ability_effects =
                         static_ability.generate_effects(object_id, controller, game);
-                    // Static ability effect timestamps come from the source object's
-                    // current-zone entry timestamp (CR 613.7a/613.7d behavior).
-                    if let Some(ts) = game.continuous_effects.get_entry_timestamp(object_id) {
-                        for effect in &mut ability_effects {
-                            effect.timestamp = ts;
-                        }
-                    }
+                    stamp_entry_timestamp(
+                        &mut ability_effects,
+                        game.continuous_effects.get_entry_timestamp(object_id),
+                    );
                     effects.extend(ability_effects);
                 }
             }
         }
     }
 
+    append_granted_ability_effects(game, &mut effects);
+
     effects
 }
 
+/// Whether any effect grants a static ability that generates effects or reads
+/// game state. Granted abilities are expanded from calculated characteristics,
+/// so any such grant rules out reusing a previous result.
+fn grants_reactive_ability<'a>(
+    game: &GameState,
+    effects: impl IntoIterator<Item = &'a ContinuousEffect>,
+) -> bool {
+    effects.into_iter().any(|effect| {
+        let granted = match &effect.modification {
+            Modification::AddAbility(ability) => ability,
+            Modification::AddAbilityGeneric(ability) => match &ability.kind {
+                AbilityKind::Static(ability) => ability,
+                _ => return false,
+            },
+            _ => return false,
+        };
+        !granted.effects_depend_only_on_source()
+            || !granted
+                .generate_effects(effect.source, effect.controller, game)
+                .is_empty()
+    })
+}
+
+/// Whether any effect grants a static ability that generates effects of its own.
+fn grants_effect_generating_ability(game: &GameState, effects: &[ContinuousEffect]) -> bool {
+    effects.iter().any(|effect| {
+        let granted = match &effect.modification {
+            Modification::AddAbility(ability) => ability,
+            Modification::AddAbilityGeneric(ability) => match &ability.kind {
+                AbilityKind::Static(ability) => ability,
+                _ => return false,
+            },
+            _ => return false,
+        };
+        !granted
+            .generate_effects(effect.source, effect.controller, game)
+            .is_empty()
+    })
+}
+
+/// Add effects from static abilities that permanents only have because
+/// something granted them (e.g. "Creatures you control have 'Other creatures
+/// you control get +1/+1.'").
+///
+/// Granted abilities are collected from calculated characteristics, which can
+/// in turn grant further abilities, so this repeats until no new ability shows
+/// up. Each granted instance is only processed once, which keeps grants that
+/// feed back into each other from looping.
+fn append_granted_ability_effects(game: &GameState, effects: &mut Vec<ContinuousEffect>) {
+    let registered: Vec<ContinuousEffect> = game
+        .continuous_effects
+        .effects_sorted()
+        .into_iter()
+        .cloned()
+        .collect();
+    let mut processed: Vec<(ObjectId, StaticAbility)> = Vec::new();
+
+    loop {
+        let mut all_effects = registered.clone();
+        all_effects.extend(effects.iter().cloned());
+        if !grants_effect_generating_ability(game, &all_effects) {
+            return;
+        }
+
+        let mut new_effects = Vec::new();
+        for &object_id in &game.battlefield {
+            let Some(object) = game.object(object_id) else {
+                continue;
+            };
+            let Some(chars) = game.calculated_characteristics_with_effects(object_id, &all_effects)
+            else {
+                continue;
+            };
+            // Whatever isn't accounted for by printed abilities or grants seen in an
+            // earlier round is new. Duplicates are matched one-for-one, so two
+            // copies of the same granted anthem both apply.
+            let mut unaccounted = chars.static_abilities;
+            let known = object
+                .abilities
+                .iter()
+                .filter_map(|ability| match &ability.kind {
+                    AbilityKind::Static(ability) => Some(ability),
+                    _ => None,
+                })
+                .chain(
+                    processed
+                        .iter()
+                        .filter(|(id, _)| *id == object_id)
+                        .map(|(_, ability)| ability),
+                );
+            for ability in known {
+                if let Some(index) = unaccounted.iter().position(|other| other == ability) {
+                    unaccounted.swap_remove(index);
+                }
+            }
+            for ability in unaccounted {
+                processed.push((object_id, ability.clone()));
+                if !ability.is_active(game, object_id) {
+                    continue;
+                }
+                let mut ability_effects =
+                    ability.generate_effects(object_id, chars.controller, game);
+                if let Some(ts) = game.continuous_effects.get_entry_timestamp(object_id) {
+                    for effect in &mut ability_effects {
+                        effect.timestamp = ts;
+                    }
+                }
+                new_effects.extend(ability_effects);
+            }
+        }
+
+        if new_effects.is_empty() {
+            return;
+        }
+        effects.extend(new_effects);
+    }
+}
+
 /// Get all continuous effects including both registered effects and static ability effects.
 ///
 /// This combines:
@@ -172,4 +517,139 @@ mod tests {
         let effect = &effects[0];
         assert!(matches!(effect.modification, Modification::AddAbility(_)));
     }
+
+    fn assert_cache_matches_full_rebuild(game: &mut GameState) {
+        game.update_static_ability_effects();
+        let expected = generate_continuous_effects_from_static_abilities(game);
+        assert_eq!(game.continuous_effects.static_ability_effects(), expected);
+    }
+
+    #[test]
+    fn test_cached_effects_match_full_rebuild_across_state_changes() {
+        use crate::cards::definitions::{crusade, savannah_lions};
+        use crate::object::CounterType;
+        use crate::zone::Zone;
+
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let crusade_id = game.create_object_from_definition(&crusade(), alice, Zone::Battlefield);
+        let lions = game.create_object_from_definition(&savannah_lions(), alice, Zone::Battlefield);
+
+        assert_cache_matches_full_rebuild(&mut game);
+        let misses = game.static_effect_cache().misses();
+        assert!(misses > 0, "first refresh should generate effects");
+        assert_eq!(game.calculated_power(lions), Some(3));
+
+        // Nothing changed: every cached entry is reused.
+        assert_cache_matches_full_rebuild(&mut game);
+        assert_eq!(game.static_effect_cache().misses(), misses);
+        assert!(game.static_effect_cache().hits() > 0);
+
+        // Counters on the source invalidate only that source's entries.
+        game.add_counters(crusade_id, CounterType::PlusOnePlusOne, 1);
+        assert_cache_matches_full_rebuild(&mut game);
+        assert_eq!(game.static_effect_cache().misses(), misses + 1);
+
+        // So does a change of controller.
+        game.object_mut(crusade_id)
+            .expect("crusade exists")
+            .controller = bob;
+        assert_cache_matches_full_rebuild(&mut game);
+        assert_eq!(game.static_effect_cache().misses(), misses + 2);
+        assert!(
+            game.continuous_effects
+                .static_ability_effects()
+                .iter()
+                .any(|effect| effect.source == crusade_id && effect.controller == bob)
+        );
+
+        // Leaving the battlefield drops the anthem and its cache entry.
+        let entries = game.static_effect_cache().len();
+        game.move_object(crusade_id, Zone::Graveyard);
+        assert_cache_matches_full_rebuild(&mut game);
+        assert!(game.static_effect_cache().len() < entries);
+        assert_eq!(game.calculated_power(lions), Some(2));
+    }
+
+    #[test]
+    fn test_conditional_abilities_are_not_cached() {
+        let anthem = StaticAbility::anthem(ObjectFilter::creature().you_control(), 1, 1);
+        assert!(anthem.effects_depend_only_on_source());
+
+        let conditional = anthem
+            .with_condition(crate::ConditionExpr::YourTurn)
+            .expect("anthems support conditions");
+        assert!(!conditional.effects_depend_only_on_source());
+    }
+
+    #[test]
+    fn test_state_reading_abilities_are_never_cached() {
+        use crate::ability::Ability;
+        use crate::cards::definitions::{basic_forest, savannah_lions};
+        use crate::static_abilities::{Anthem, AnthemCountExpression, AnthemValue};
+        use crate::zone::Zone;
+
+        let creatures = ObjectFilter::creature().you_control();
+        let per_land = AnthemValue::scaled(
+            1,
+            AnthemCountExpression::MatchingFilter(ObjectFilter::land().you_control()),
+        );
+        let state_reading = vec![
+            StaticAbility::anthem(creatures.clone(), 1, 1)
+                .with_condition(crate::ConditionExpr::YourTurn)
+                .expect("anthems support conditions"),
+            StaticAbility::new(
+                Anthem::new(creatures.clone(), 0, 0).with_values(per_land.clone(), per_land),
+            ),
+            StaticAbility::grant_ability(creatures, StaticAbility::flying())
+                .with_condition(crate::ConditionExpr::YourTurn)
+                .expect("grants support conditions"),
+        ];
+        for ability in &state_reading {
+            assert!(
+                !ability.effects_depend_only_on_source(),
+                "{ability:?} reads game state and must not be cached"
+            );
+        }
+
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let lions = game.create_object_from_definition(&savannah_lions(), alice, Zone::Battlefield);
+        for ability in state_reading {
+            game.object_mut(lions)
+                .expect("lions exist")
+                .abilities
+                .push(Ability {
+                    kind: AbilityKind::Static(ability),
+                    functional_zones: vec![Zone::Battlefield],
+                    text: None,
+                });
+        }
+
+        assert_cache_matches_full_rebuild(&mut game);
+        assert!(game.static_effect_cache().is_empty());
+        let power = game.calculated_power(lions).expect("lions have power");
+
+        // A new land changes the count-based anthem without touching its source.
+        game.create_object_from_definition(&basic_forest(), alice, Zone::Battlefield);
+        assert_cache_matches_full_rebuild(&mut game);
+        assert!(game.static_effect_cache().is_empty());
+        assert_eq!(game.calculated_power(lions), Some(power + 1));
+    }
+
+    #[test]
+    fn test_disabled_cache_regenerates_everything() {
+        use crate::cards::definitions::crusade;
+        use crate::zone::Zone;
+
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        game.create_object_from_definition(&crusade(), PlayerId::from_index(0), Zone::Battlefield);
+        game.set_static_effect_caching(false);
+
+        assert_cache_matches_full_rebuild(&mut game);
+        assert_cache_matches_full_rebuild(&mut game);
+        assert!(game.static_effect_cache().is_empty());
+        assert_eq!(game.static_effect_cache().hits(), 0);
+    }
 }
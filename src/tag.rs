@@ -14,6 +14,10 @@ pub const SOURCE_EXILED_TAG: &str = "__source_exiled__";
 /// Using an owned key instead of `&'static str` enables tags built at runtime
 /// while keeping convenient string-based APIs.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct TagKey(String);
 
 impl TagKey {
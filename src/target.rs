@@ -31,6 +31,10 @@ pub use crate::filter::{
 ///
 /// Use `ChooseSpec::target(...)` to wrap a spec when it represents a target.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum ChooseSpec {
     /// Wraps another spec to indicate this is a TARGET.
     ///
@@ -164,13 +164,13 @@ mod tests {
         let d = Target::Object(ObjectId::from_raw(4));
         let requirements = vec![
             TargetRequirementContext {
-                description: "any number".to_string(),
+                description: "any number".into(),
                 legal_targets: vec![a, b, c],
                 min_targets: 0,
                 max_targets: None,
             },
             TargetRequirementContext {
-                description: "final target".to_string(),
+                description: "final target".into(),
                 legal_targets: vec![d],
                 min_targets: 1,
                 max_targets: Some(1),
@@ -191,7 +191,7 @@ mod tests {
     fn normalize_targets_can_autofill_required_target_after_empty_input() {
         let a = Target::Object(ObjectId::from_raw(1));
         let requirements = vec![TargetRequirementContext {
-            description: "required".to_string(),
+            description: "required".into(),
             legal_targets: vec![a],
             min_targets: 1,
             max_targets: Some(1),
@@ -209,13 +209,13 @@ mod tests {
         let b = Target::Object(ObjectId::from_raw(2));
         let requirements = vec![
             TargetRequirementContext {
-                description: "first".to_string(),
+                description: "first".into(),
                 legal_targets: vec![a],
                 min_targets: 1,
                 max_targets: Some(1),
             },
             TargetRequirementContext {
-                description: "second".to_string(),
+                description: "second".into(),
                 legal_targets: vec![b],
                 min_targets: 1,
                 max_targets: Some(1),
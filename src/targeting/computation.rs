@@ -52,6 +52,12 @@ pub(crate) fn can_target_object_with_view(
         return TargetingResult::legal();
     };
 
+    // "Cards in graveyards can't be the targets of spells or abilities"
+    // applies to every player, including the card's owner.
+    if target.zone == Zone::Graveyard && game.is_untargetable(target_id) {
+        return TargetingResult::Invalid(TargetingInvalidReason::CantBeTargeted);
+    }
+
     // Most targeting restrictions in this function apply only to permanents
     // and stack objects. Cards in other zones are generally targetable unless
     // constrained by the caller's filter.
@@ -459,7 +465,9 @@ fn compute_object_targets_with_view(
         }
 
         if object.zone != Zone::Battlefield && object.zone != Zone::Stack {
-            targets.push(Target::Object(object_id));
+            if object.zone != Zone::Graveyard || !game.is_untargetable(object_id) {
+                targets.push(Target::Object(object_id));
+            }
             continue;
         }
 
@@ -12,8 +12,7 @@
 
 use crate::ability::AbilityKind;
 use crate::cost::TotalCost;
-use crate::cost::can_pay_cost;
-use crate::costs::CostContext;
+use crate::costs::{CostCheckContext, CostContext, can_potentially_pay_with_check_context};
 use crate::decision::DecisionMaker;
 use crate::decisions::specs::ChooseObjectsSpec;
 use crate::decisions::{WardSpec, make_decision};
@@ -198,7 +197,11 @@ fn pay_ward_cost(
             });
     match cost {
         WardCost::Mana(total_cost) => {
-            if can_pay_cost(game, source, payer, total_cost).is_err() {
+            // Mana can still be made during the payment window.
+            let check_ctx = CostCheckContext::new(source, payer);
+            if total_cost.costs().iter().any(|component| {
+                can_potentially_pay_with_check_context(&*component.0, game, &check_ctx).is_err()
+            }) {
                 return false;
             }
 
@@ -206,7 +209,13 @@ fn pay_ward_cost(
                 CostContext::new(source, payer, decision_maker).with_provenance(ward_provenance);
             for component in total_cost.costs() {
                 if let Some(mana_cost) = component.mana_cost_ref() {
-                    if !game.try_pay_mana_cost(payer, None, mana_cost, 0) {
+                    if !crate::effects::mana::pay_mana_cost_interactively(
+                        game,
+                        payer,
+                        source,
+                        mana_cost,
+                        &mut cost_ctx.decision_maker,
+                    ) {
                         return false;
                     }
                     continue;
@@ -17,7 +17,7 @@
 
 #![allow(dead_code)]
 
-use crate::cards::CardRegistry;
+use crate::cards::{CardDefinition, CardRegistry};
 use crate::combat_state::{AttackTarget, CombatState};
 use crate::decision::{
     AttackerDeclaration, BlockerDeclaration, DecisionMaker, GameProgress, LegalAction,
@@ -258,6 +258,9 @@ fn run_scripted_turn(
     Ok(Some(ScriptResult::TurnComplete))
 }
 
+/// Safety limit on the number of turns a [`GameScript`] runs.
+const MAX_SCRIPTED_TURNS: usize = 10;
+
 /// A scripted game setup and action sequence.
 pub struct GameScript {
     /// Starting hands for each player (player name -> card names).
@@ -266,6 +269,8 @@ pub struct GameScript {
     actions: Vec<Action>,
     /// Starting life total.
     starting_life: i32,
+    /// Extra card definitions available by name alongside the builtin cards.
+    cards: Vec<CardDefinition>,
 }
 
 impl GameScript {
@@ -275,6 +280,7 @@ impl GameScript {
             hands: Vec::new(),
             actions: Vec::new(),
             starting_life: 20,
+            cards: Vec::new(),
         }
     }
 
@@ -296,6 +302,12 @@ impl GameScript {
         self
     }
 
+    /// Make a card definition that isn't in the builtin registry available by name.
+    pub fn card(mut self, definition: CardDefinition) -> Self {
+        self.cards.push(definition);
+        self
+    }
+
     /// Set starting life total.
     pub fn starting_life(mut self, life: i32) -> Self {
         self.starting_life = life;
@@ -338,7 +350,10 @@ impl GameScript {
         }
 
         // Create the card registry
-        let registry = CardRegistry::with_builtin_cards_for_names(needed_cards);
+        let mut registry = CardRegistry::with_builtin_cards_for_names(needed_cards);
+        for definition in self.cards {
+            registry.register(definition);
+        }
 
         // Create player names
         let player_names: Vec<String> = if self.hands.is_empty() {
@@ -364,15 +379,25 @@ impl GameScript {
             }
         }
 
+        // Give every player enough filler cards to draw from so scripted turns
+        // don't lose to drawing from an empty library.
+        let filler = crate::card::CardBuilder::new(crate::ids::CardId::new(), "Library Filler")
+            .card_types(vec![crate::types::CardType::Sorcery])
+            .build();
+        for index in 0..player_names.len() {
+            let player_id = PlayerId::from_index(index as u8);
+            for _ in 0..MAX_SCRIPTED_TURNS {
+                game.create_object_from_card(&filler, player_id, Zone::Library);
+            }
+        }
+
         // Create the scripted decision maker
         let mut dm = ScriptedGameDecisionMaker::new(&game, self.actions);
 
         // Run turns until the script is exhausted
         let mut trigger_queue = TriggerQueue::new();
         let mut combat = CombatState::default();
-        let max_turns = 10; // Safety limit for tests
-
-        for _turn in 0..max_turns {
+        for _turn in 0..MAX_SCRIPTED_TURNS {
             if dm.is_exhausted() {
                 break;
             }
@@ -1332,6 +1357,58 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_cast_data_defined_creature() {
+        let data: crate::cards::DataCard = serde_json::from_str(
+            r#"{
+                "schema_version": 1,
+                "name": "Grove Provisioner",
+                "mana_cost": "{G}",
+                "type_line": "Creature — Elf Druid",
+                "power": 1,
+                "toughness": 2,
+                "statics": [{"kind": "keyword", "keyword": "vigilance"}],
+                "triggers": [{
+                    "event": {"kind": "this_enters_battlefield"},
+                    "effects": [
+                        {"kind": "gain_life", "amount": {"Fixed": 2}},
+                        {"kind": "create_tokens", "token": "Food", "count": {"Fixed": 1}}
+                    ]
+                }]
+            }"#,
+        )
+        .expect("valid card data");
+        let definition = CardDefinition::from_data(&data).expect("buildable card");
+
+        let game = GameScript::new()
+            .card(definition)
+            .player("Alice", &["Forest", "Grove Provisioner"])
+            .player("Bob", &[])
+            .action(Action::PlayLand("Forest"))
+            .action(Action::TapForMana("Forest"))
+            .action(Action::CastSpell("Grove Provisioner"))
+            .action(Action::Pass)
+            .action(Action::Pass) // Spell resolves, ETB trigger goes on the stack
+            .action(Action::Pass)
+            .action(Action::Pass) // Trigger resolves
+            .run()
+            .expect("Game should run successfully");
+
+        assert!(game.battlefield_has("Grove Provisioner"));
+        assert!(game.battlefield_has("Food"));
+        assert_eq!(game.life_total(PlayerId::from_index(0)), 22);
+        let provisioner = game
+            .battlefield
+            .iter()
+            .filter_map(|&id| game.object(id))
+            .find(|object| object.name == "Grove Provisioner")
+            .expect("Grove Provisioner on battlefield");
+        assert!(
+            provisioner.has_static_ability(&crate::static_abilities::StaticAbility::vigilance())
+        );
+    }
+
     /// Tests Lightning Bolt dealing damage using a replay input file.
     ///
     /// This test reads inputs from tests/scenarios/play_land_bolt.txt which contains:
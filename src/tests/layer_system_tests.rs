@@ -8,20 +8,21 @@ use crate::ability::{Ability, AbilityKind};
 use crate::card::{CardBuilder, PowerToughness};
 use crate::cards::builders::CardDefinitionBuilder;
 use crate::cards::definitions::{
-    blood_moon, crusade, dauthi_slayer, grizzly_bears, high_market, humility, manascape_refractor,
+    blood_moon, dauthi_slayer, grizzly_bears, high_market, humility, manascape_refractor,
     marvin_murderous_mimic, mycosynth_lattice, rex_cyber_hound, sol_ring, squirrel_nest,
     toph_the_first_metalbender, urzas_saga,
 };
 use crate::combat_state::{AttackTarget, CombatError, new_combat};
 use crate::continuous::{ContinuousEffect, EffectTarget, Modification, PtSublayer};
-use crate::decision::AttackerDeclaration;
+use crate::decision::{AttackShortcut, AttackerDeclaration};
 use crate::effect::{Until, Value};
-use crate::game_loop::{GameLoopError, apply_attacker_declarations};
+use crate::game_loop::{GameLoopError, apply_attacker_declarations, expand_attack_shortcut};
 use crate::game_state::{GameState, Phase};
 use crate::ids::{CardId, PlayerId};
 use crate::mana::ManaSymbol;
 use crate::object::CounterType;
 use crate::static_abilities::StaticAbility;
+use crate::target::ObjectFilter;
 use crate::triggers::TriggerQueue;
 use crate::types::{CardType, Subtype};
 use crate::zone::Zone;
@@ -628,30 +629,13 @@ fn test_humility_sets_creatures_to_1_1() {
 /// - Final result: 2/2 creature with no abilities
 #[test]
 fn test_humility_plus_crusade_results_in_2_2() {
-    let mut game = setup_game();
-    let alice = PlayerId::from_index(0);
-
-    // Create a white 4/4 creature with flying
-    let creature = CardBuilder::new(CardId::from_raw(100), "Serra Angel")
-        .card_types(vec![CardType::Creature])
-        .color_indicator(crate::color::ColorSet::WHITE)
-        .power_toughness(PowerToughness::fixed(4, 4))
-        .build();
-    let creature_id = game.create_object_from_card(&creature, alice, Zone::Battlefield);
-
-    // Add flying ability
-    game.object_mut(creature_id)
-        .unwrap()
-        .abilities
-        .push(Ability::static_ability(StaticAbility::flying()));
-
-    // Create Humility using the proper card definition (earlier timestamp)
-    let humility_def = humility();
-    let _humility_id = game.create_object_from_definition(&humility_def, alice, Zone::Battlefield);
-
-    // Create Crusade using the proper card definition (later timestamp)
-    let crusade_def = crusade();
-    let _crusade_id = game.create_object_from_definition(&crusade_def, alice, Zone::Battlefield);
+    // A white 4/4 flier, then Humility (earlier timestamp), then Crusade (later timestamp)
+    let (game, objects) = crate::GameStateBuilder::new()
+        .player("Alice")
+        .battlefield(["Serra Angel", "Humility", "Crusade -> none"])
+        .build()
+        .expect("game should build");
+    let creature_id = objects.id("Serra Angel");
 
     // Layer 7b (Humility): Sets to 1/1
     // Layer 7c (Crusade): +1/+1
@@ -3000,6 +2984,116 @@ fn test_must_attack_with_optional_creature() {
     );
 }
 
+/// Tests that the "no attacks" shortcut is rejected when a creature must attack.
+///
+/// Scenario: Alice controls Dauthi Slayer (must attack) and Grizzly Bears.
+/// Alice responds with the "none" shortcut.
+///
+/// Expected behavior:
+/// - Expansion fails with MustAttackNotDeclared for Dauthi Slayer.
+#[test]
+fn test_no_attacks_shortcut_rejected_with_must_attack_creature() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    let slayer_id = game.create_object_from_definition(&dauthi_slayer(), alice, Zone::Battlefield);
+    let bears_id = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+    game.remove_summoning_sickness(slayer_id);
+    game.remove_summoning_sickness(bears_id);
+
+    let combat = new_combat();
+    let result = expand_attack_shortcut(&game, &combat, &AttackShortcut::NoAttacks);
+
+    assert_eq!(
+        result,
+        Err(GameLoopError::CombatError(
+            CombatError::MustAttackNotDeclared(slayer_id)
+        ))
+    );
+}
+
+/// Tests that the "all attack" shortcut declares every legal attacker.
+///
+/// Scenario: Alice controls Dauthi Slayer, an untapped Grizzly Bears, and a
+/// tapped Grizzly Bears. Alice responds with "all attack Bob".
+///
+/// Expected behavior:
+/// - Both untapped creatures attack Bob; the tapped one is skipped.
+/// - The expanded declarations apply cleanly.
+#[test]
+fn test_all_attack_shortcut_declares_every_legal_attacker() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let slayer_id = game.create_object_from_definition(&dauthi_slayer(), alice, Zone::Battlefield);
+    let bears_id = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+    let tapped_id = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+    for id in [slayer_id, bears_id, tapped_id] {
+        game.remove_summoning_sickness(id);
+    }
+    game.tap(tapped_id);
+
+    let mut combat = new_combat();
+    let mut trigger_queue = TriggerQueue::new();
+    let declarations = expand_attack_shortcut(
+        &game,
+        &combat,
+        &AttackShortcut::AllAttack(AttackTarget::Player(bob)),
+    )
+    .expect("all-attack shortcut should expand");
+
+    let mut attackers: Vec<_> = declarations.iter().map(|decl| decl.creature).collect();
+    attackers.sort();
+    let mut expected = vec![slayer_id, bears_id];
+    expected.sort();
+    assert_eq!(attackers, expected);
+    assert!(
+        declarations
+            .iter()
+            .all(|decl| decl.target == AttackTarget::Player(bob))
+    );
+
+    apply_attacker_declarations(&mut game, &mut combat, &mut trigger_queue, &declarations)
+        .expect("expanded declarations should apply");
+    assert_eq!(combat.attackers.len(), 2);
+}
+
+/// Tests that the "repeat last attack" shortcut reuses the previous declaration.
+///
+/// Scenario: Alice attacked with Dauthi Slayer only. Next combat she responds
+/// with "repeat".
+///
+/// Expected behavior:
+/// - Only Dauthi Slayer attacks again; Grizzly Bears stays home.
+#[test]
+fn test_repeat_last_attack_shortcut_reuses_previous_declaration() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let slayer_id = game.create_object_from_definition(&dauthi_slayer(), alice, Zone::Battlefield);
+    let bears_id = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+    game.remove_summoning_sickness(slayer_id);
+    game.remove_summoning_sickness(bears_id);
+
+    let mut combat = new_combat();
+    let mut trigger_queue = TriggerQueue::new();
+    let first_attack = vec![AttackerDeclaration {
+        creature: slayer_id,
+        target: AttackTarget::Player(bob),
+    }];
+    apply_attacker_declarations(&mut game, &mut combat, &mut trigger_queue, &first_attack)
+        .expect("first attack should apply");
+
+    game.untap(slayer_id);
+    let combat = new_combat();
+    let declarations = expand_attack_shortcut(&game, &combat, &AttackShortcut::RepeatLastAttack)
+        .expect("repeat shortcut should expand");
+
+    assert_eq!(declarations, first_attack);
+}
+
 #[test]
 fn test_goaded_creature_must_attack_if_able() {
     let mut game = setup_game();
@@ -3350,3 +3444,214 @@ fn test_fixed_attack_tax_scales_per_attacker_and_can_be_paid() {
         "fixed attack tax should consume all four generic mana (2 tax x 2 attackers)"
     );
 }
+
+/// Tests that a blanket haste grant lets a creature that just resolved attack.
+///
+/// Scenario: Alice controls a Fervor-style enchantment ("Creatures you control
+/// have haste."). She casts a 2/2, lets it resolve, and declares it as an
+/// attacker the same turn.
+///
+/// Expected behavior:
+/// - The creature is summoning sick but has haste through the layer system
+/// - The attack declaration succeeds
+#[test]
+fn test_haste_grant_lets_just_cast_creature_attack() {
+    use crate::game_loop::resolve_stack_entry;
+    use crate::game_state::StackEntry;
+    use crate::static_abilities::StaticAbilityId;
+
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+
+    let fervor_def = CardDefinitionBuilder::new(CardId::from_raw(401), "Fervor")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("Creatures you control have haste.")
+        .expect("haste grant should parse");
+    game.create_object_from_definition(&fervor_def, alice, Zone::Battlefield);
+
+    let bear = CardBuilder::new(CardId::from_raw(402), "Fresh Bear")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .build();
+    let spell_id = game.create_object_from_card(&bear, alice, Zone::Stack);
+    game.push_to_stack(StackEntry::new(spell_id, alice));
+    resolve_stack_entry(&mut game).expect("creature spell should resolve");
+
+    let bear_id = game
+        .battlefield
+        .iter()
+        .copied()
+        .find(|&id| {
+            game.object(id)
+                .is_some_and(|object| object.name == "Fresh Bear")
+        })
+        .expect("creature should be on the battlefield");
+    assert!(game.is_summoning_sick(bear_id));
+    assert!(
+        game.object_has_static_ability_id(bear_id, StaticAbilityId::Haste),
+        "the granted haste should be visible in calculated characteristics"
+    );
+
+    game.turn.phase = Phase::Combat;
+    let declarations = vec![AttackerDeclaration {
+        creature: bear_id,
+        target: AttackTarget::Player(bob),
+    }];
+    let mut combat = new_combat();
+    let mut trigger_queue = TriggerQueue::new();
+    let result =
+        apply_attacker_declarations(&mut game, &mut combat, &mut trigger_queue, &declarations);
+    assert!(
+        result.is_ok(),
+        "a creature with granted haste should attack the turn it arrives: {result:?}"
+    );
+}
+
+/// Tests that a granted ability which itself generates continuous effects
+/// applies from each permanent that has it, and that nesting grants settles.
+///
+/// Scenario: Alice controls an enchantment whose static ability reads
+/// "Creatures you control have 'Creatures you control have "Creatures you
+/// control get +1/+1."'" and two 2/2 creatures.
+///
+/// Expected behavior:
+/// - Each creature gains the inner grant, so each creature has two copies of
+///   the anthem (one from each creature)
+/// - The four anthems each pump both creatures, making them 6/6, and the
+///   calculation terminates
+#[test]
+fn test_nested_granted_anthems_apply_without_looping() {
+    let mut game = setup_game();
+    let alice = PlayerId::from_index(0);
+
+    let anthem = StaticAbility::anthem(ObjectFilter::creature().you_control(), 1, 1);
+    let inner_grant = StaticAbility::grant_ability(ObjectFilter::creature().you_control(), anthem);
+    let outer_grant =
+        StaticAbility::grant_ability(ObjectFilter::creature().you_control(), inner_grant);
+
+    let enchantment = CardBuilder::new(CardId::from_raw(403), "Nested Anthem")
+        .card_types(vec![CardType::Enchantment])
+        .build();
+    let enchantment_id = game.create_object_from_card(&enchantment, alice, Zone::Battlefield);
+    game.object_mut(enchantment_id)
+        .expect("enchantment exists")
+        .abilities
+        .push(Ability::static_ability(outer_grant));
+
+    let bear = CardBuilder::new(CardId::from_raw(404), "Bear")
+        .card_types(vec![CardType::Creature])
+        .power_toughness(PowerToughness::fixed(2, 2))
+        .build();
+    let first = game.create_object_from_card(&bear, alice, Zone::Battlefield);
+    let second = game.create_object_from_card(&bear, alice, Zone::Battlefield);
+
+    game.refresh_continuous_state();
+    for creature in [first, second] {
+        assert_eq!(game.calculated_power(creature), Some(6));
+        assert_eq!(game.calculated_toughness(creature), Some(6));
+    }
+    assert_eq!(
+        game.calculated_power(enchantment_id),
+        None,
+        "the noncreature granter shouldn't pick anything up"
+    );
+}
+
+/// Tests that an attack tax and "can't attack you or planeswalkers you
+/// control" restrictions from different players stack.
+///
+/// Scenario: In a three-player game, Bob controls a Propaganda-style attack
+/// tax and Carol controls "Creatures can't attack you or planeswalkers you
+/// control." and a planeswalker. Alice has a bear and {2}.
+///
+/// Expected behavior:
+/// - The bear's only legal attack target is Bob
+/// - Attacking Carol or her planeswalker is rejected
+/// - Attacking Bob succeeds once the tax is paid
+#[test]
+fn test_attack_tax_and_cant_attack_you_restrictions_stack() {
+    use crate::decision::compute_legal_attackers;
+
+    let mut game = GameState::new(
+        vec!["Alice".to_string(), "Bob".to_string(), "Carol".to_string()],
+        20,
+    );
+    let alice = PlayerId::from_index(0);
+    let bob = PlayerId::from_index(1);
+    let carol = PlayerId::from_index(2);
+
+    let bear = game.create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+    game.remove_summoning_sickness(bear);
+
+    let propaganda = CardDefinitionBuilder::new(CardId::new(), "Propaganda Variant")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text(
+            "Creatures can't attack you unless their controller pays {2} for each creature they control that's attacking you.",
+        )
+        .expect("attack tax should parse");
+    game.create_object_from_definition(&propaganda, bob, Zone::Battlefield);
+
+    let archon = CardDefinitionBuilder::new(CardId::new(), "Archon Variant")
+        .card_types(vec![CardType::Enchantment])
+        .parse_text("Creatures can't attack you or planeswalkers you control.")
+        .expect("scoped attack restriction should parse");
+    game.create_object_from_definition(&archon, carol, Zone::Battlefield);
+
+    let walker = CardBuilder::new(CardId::new(), "Test Walker")
+        .card_types(vec![CardType::Planeswalker])
+        .build();
+    let walker_id = game.create_object_from_card(&walker, carol, Zone::Battlefield);
+
+    game.player_mut(alice)
+        .expect("attacking player should exist")
+        .mana_pool
+        .add(ManaSymbol::Colorless, 2);
+    game.refresh_continuous_state();
+
+    let combat = new_combat();
+    let options = compute_legal_attackers(&game, &combat);
+    let bear_option = options
+        .iter()
+        .find(|option| option.creature == bear)
+        .expect("the bear should still be able to attack someone");
+    assert_eq!(bear_option.valid_targets, vec![AttackTarget::Player(bob)]);
+
+    for blocked_target in [
+        AttackTarget::Player(carol),
+        AttackTarget::Planeswalker(walker_id),
+    ] {
+        let declarations = vec![AttackerDeclaration {
+            creature: bear,
+            target: blocked_target.clone(),
+        }];
+        let mut combat = new_combat();
+        let mut trigger_queue = TriggerQueue::new();
+        let result =
+            apply_attacker_declarations(&mut game, &mut combat, &mut trigger_queue, &declarations);
+        assert!(
+            result.is_err(),
+            "attacking {blocked_target:?} should be rejected"
+        );
+    }
+
+    let declarations = vec![AttackerDeclaration {
+        creature: bear,
+        target: AttackTarget::Player(bob),
+    }];
+    let mut combat = new_combat();
+    let mut trigger_queue = TriggerQueue::new();
+    let result =
+        apply_attacker_declarations(&mut game, &mut combat, &mut trigger_queue, &declarations);
+    assert!(
+        result.is_ok(),
+        "paying the tax should allow the attack: {result:?}"
+    );
+    assert_eq!(
+        game.player(alice)
+            .expect("attacking player should still exist")
+            .mana_pool
+            .total(),
+        0
+    );
+}
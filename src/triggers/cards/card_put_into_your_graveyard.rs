@@ -3,12 +3,16 @@
 use crate::events::EventKind;
 use crate::events::zones::ZoneChangeEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CardPutIntoYourGraveyardTrigger;
 
 impl TriggerMatcher for CardPutIntoYourGraveyardTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::ZoneChange)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::ZoneChange {
             return false;
@@ -33,6 +37,12 @@ impl TriggerMatcher for CardPutIntoYourGraveyardTrigger {
             .unwrap_or(false)
     }
 
+    fn trigger_count(&self, event: &TriggerEvent) -> u32 {
+        event
+            .downcast::<ZoneChangeEvent>()
+            .map_or(1, |zc| zc.count() as u32)
+    }
+
     fn display(&self) -> String {
         "Whenever a card is put into your graveyard from anywhere".to_string()
     }
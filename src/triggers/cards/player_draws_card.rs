@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::other::CardsDrawnEvent;
 use crate::target::PlayerFilter;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{TriggerEvent, describe_player_filter_subject};
 
 /// Trigger for "Whenever [player] draws a card" or "Whenever [player] draws one or more cards".
@@ -36,6 +36,10 @@ impl PlayerDrawsCardTrigger {
 }
 
 impl TriggerMatcher for PlayerDrawsCardTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CardsDrawn)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CardsDrawn {
             return false;
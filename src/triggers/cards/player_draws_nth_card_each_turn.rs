@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::other::CardsDrawnEvent;
 use crate::target::PlayerFilter;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{TriggerEvent, describe_player_filter_subject};
 
 /// Trigger for "Whenever [player] draws their Nth card each turn".
@@ -25,6 +25,10 @@ impl PlayerDrawsNthCardEachTurnTrigger {
 }
 
 impl TriggerMatcher for PlayerDrawsNthCardEachTurnTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CardsDrawn)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CardsDrawn {
             return false;
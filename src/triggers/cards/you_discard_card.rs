@@ -1,10 +1,11 @@
 //! "Whenever you discard a card" trigger.
 
 use crate::events::EventKind;
+use crate::events::cards::DiscardEvent;
 use crate::events::other::CardDiscardedEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct YouDiscardCardTrigger {
@@ -19,25 +20,36 @@ impl YouDiscardCardTrigger {
 }
 
 impl TriggerMatcher for YouDiscardCardTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::Only(vec![EventKind::Discard, EventKind::CardDiscarded])
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
-        if event.kind() != EventKind::CardDiscarded {
-            return false;
-        }
-        let Some(e) = event.downcast::<CardDiscardedEvent>() else {
-            return false;
+        // Discard effects and cleanup emit `DiscardEvent`s; `CardDiscardedEvent`
+        // is the lighter-weight notification form of the same thing.
+        let (player, card_id) = match event.kind() {
+            EventKind::Discard => match event.downcast::<DiscardEvent>() {
+                Some(e) => (e.player, e.card),
+                None => return false,
+            },
+            EventKind::CardDiscarded => match event.downcast::<CardDiscardedEvent>() {
+                Some(e) => (e.player, e.card),
+                None => return false,
+            },
+            _ => return false,
         };
         let player_matches = match &self.player {
-            PlayerFilter::You => e.player == ctx.controller,
-            PlayerFilter::Opponent => e.player != ctx.controller,
+            PlayerFilter::You => player == ctx.controller,
+            PlayerFilter::Opponent => player != ctx.controller,
             PlayerFilter::Any => true,
-            PlayerFilter::Specific(id) => e.player == *id,
+            PlayerFilter::Specific(id) => player == *id,
             _ => true,
         };
         if !player_matches {
             return false;
         }
         if let Some(filter) = &self.filter {
-            let Some(card) = ctx.game.object(e.card) else {
+            let Some(card) = ctx.game.object(card_id) else {
                 return false;
             };
             return filter.matches(card, &ctx.filter_ctx, ctx.game);
@@ -113,6 +125,24 @@ mod tests {
         assert!(!trigger.matches(&opponent_event, &ctx));
     }
 
+    #[test]
+    fn test_matches_discard_events_from_effects_and_cleanup() {
+        let game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let trigger = YouDiscardCardTrigger::new(PlayerFilter::You, None);
+        let ctx = TriggerContext::for_source(ObjectId::from_raw(1), alice, &game);
+
+        let discard = |player| {
+            TriggerEvent::new_with_provenance(
+                DiscardEvent::from_game_rule(ObjectId::from_raw(42), player),
+                crate::provenance::ProvNodeId::default(),
+            )
+        };
+        assert!(trigger.matches(&discard(alice), &ctx));
+        assert!(!trigger.matches(&discard(bob), &ctx));
+    }
+
     #[test]
     fn test_matches_filtered_card() {
         let mut game = GameState::new(vec!["Alice".to_string(), "Bob".to_string()], 20);
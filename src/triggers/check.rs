@@ -3,12 +3,14 @@
 //! This module contains the `check_triggers()` function that scans all permanents
 //! for triggered abilities that match a game event.
 
+use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 use crate::Effect;
 use crate::ability::{AbilityKind, TriggeredAbility};
 use crate::continuous::ContinuousEffect;
+use crate::events::EventKind;
 use crate::filter::ObjectRef;
 use crate::game_state::{GameState, Phase, Step};
 use crate::ids::{ObjectId, PlayerId, StableId};
@@ -19,7 +21,7 @@ use crate::zone::Zone;
 
 use super::Trigger;
 use super::TriggerEvent;
-use super::matcher_trait::TriggerContext;
+use super::matcher_trait::{InterestedKinds, TriggerContext};
 
 /// Stable, structural identity for a trigger definition.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -333,13 +335,119 @@ pub fn check_triggers(
     trigger_event: &TriggerEvent,
 ) -> Vec<TriggeredAbilityEntry> {
     let view = crate::derived_view::DerivedGameView::new(game);
-    check_triggers_with_view(game, trigger_event, &view)
+    check_triggers_with_view(game, trigger_event, &view, None)
+}
+
+/// Check a batch of events that happened without the game changing in between.
+///
+/// Continuous effects and the battlefield trigger index are computed once for
+/// the whole batch instead of once per event. Results are in event order.
+pub fn check_triggers_for_events(
+    game: &GameState,
+    trigger_events: &[TriggerEvent],
+) -> Vec<TriggeredAbilityEntry> {
+    if trigger_events.is_empty() {
+        return Vec::new();
+    }
+    let view = crate::derived_view::DerivedGameView::new(game);
+    let index = BattlefieldTriggerIndex::new(game, &view);
+    trigger_events
+        .iter()
+        .flat_map(|event| check_triggers_with_view(game, event, &view, Some(&index)))
+        .collect()
+}
+
+/// A triggered ability of a permanent on the battlefield.
+#[derive(Debug, Clone)]
+struct IndexedTrigger {
+    source: ObjectId,
+    /// The source's controller after control-changing effects.
+    controller: PlayerId,
+    ability: TriggeredAbility,
+}
+
+/// Battlefield triggered abilities bucketed by the event kinds they can match.
+///
+/// Built from calculated abilities (after effects like Humility), so it is
+/// only valid while the game state it was built from is unchanged.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BattlefieldTriggerIndex {
+    /// Abilities in battlefield order.
+    triggers: Vec<IndexedTrigger>,
+    /// Positions in `triggers` of abilities that can only match one of a few kinds.
+    by_kind: HashMap<EventKind, Vec<usize>>,
+    /// Positions in `triggers` of abilities that might match any kind.
+    any_kind: Vec<usize>,
+}
+
+impl BattlefieldTriggerIndex {
+    pub(crate) fn new(game: &GameState, view: &crate::derived_view::DerivedGameView<'_>) -> Self {
+        let mut index = Self::default();
+        for &obj_id in &game.battlefield {
+            let Some(obj) = game.object(obj_id) else {
+                continue;
+            };
+            // Get calculated abilities (after continuous effects like Humility, Blood Moon)
+            // and controller (a donated permanent triggers for its new controller).
+            let (calculated_abilities, controller) = view
+                .calculated_characteristics(obj_id)
+                .map(|chars| (chars.abilities, chars.controller))
+                .unwrap_or_else(|| (obj.abilities.clone(), obj.controller));
+            for ability in calculated_abilities {
+                if !ability.functions_in(&obj.zone) {
+                    continue;
+                }
+                let AbilityKind::Triggered(trigger_ability) = ability.kind else {
+                    continue;
+                };
+                let position = index.triggers.len();
+                match trigger_ability.trigger.interested_kinds() {
+                    InterestedKinds::All => index.any_kind.push(position),
+                    InterestedKinds::Only(kinds) => {
+                        for kind in kinds {
+                            index.by_kind.entry(kind).or_default().push(position);
+                        }
+                    }
+                }
+                index.triggers.push(IndexedTrigger {
+                    source: obj_id,
+                    controller,
+                    ability: trigger_ability,
+                });
+            }
+        }
+        index
+    }
+
+    /// Abilities that might match an event of `kind`, in battlefield order.
+    fn candidates(&self, kind: EventKind) -> impl Iterator<Item = &IndexedTrigger> {
+        let mut positions: Vec<usize> = self
+            .by_kind
+            .get(&kind)
+            .map(|positions| positions.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .chain(&self.any_kind)
+            .copied()
+            .collect();
+        positions.sort_unstable();
+        positions
+            .into_iter()
+            .map(|position| &self.triggers[position])
+    }
+
+    /// Number of indexed abilities that might match an event of `kind`.
+    #[cfg(test)]
+    fn candidate_count(&self, kind: EventKind) -> usize {
+        self.candidates(kind).count()
+    }
 }
 
 pub(crate) fn check_triggers_with_view(
     game: &GameState,
     trigger_event: &TriggerEvent,
     view: &crate::derived_view::DerivedGameView<'_>,
+    index: Option<&BattlefieldTriggerIndex>,
 ) -> Vec<TriggeredAbilityEntry> {
     if suppresses_creature_etb_triggers_with_effects(game, trigger_event, Some(view.effects())) {
         return Vec::new();
@@ -347,68 +455,62 @@ pub(crate) fn check_triggers_with_view(
 
     let mut triggered = Vec::new();
 
-    // Check all permanents on the battlefield
-    for &obj_id in &game.battlefield {
+    // Check permanents on the battlefield whose triggers can match this kind
+    let built;
+    let battlefield = match index {
+        Some(index) => index,
+        None => {
+            built = BattlefieldTriggerIndex::new(game, view);
+            &built
+        }
+    };
+    for indexed in battlefield.candidates(trigger_event.kind()) {
+        let obj_id = indexed.source;
         let Some(obj) = game.object(obj_id) else {
             continue;
         };
+        let trigger_ability = &indexed.ability;
+        let controller = indexed.controller;
 
-        let ctx = TriggerContext::for_source(obj_id, obj.controller, game);
-
-        // Get calculated abilities (after continuous effects like Humility, Blood Moon)
-        let calculated_abilities = view
-            .abilities(obj_id)
-            .unwrap_or_else(|| obj.abilities.clone());
-
-        // Check each ability on the permanent
-        for ability in &calculated_abilities {
-            let AbilityKind::Triggered(trigger_ability) = &ability.kind else {
+        let ctx = TriggerContext::for_source(obj_id, controller, game);
+        if trigger_ability.trigger.matches(trigger_event, &ctx) {
+            let trigger_count = trigger_ability.trigger.trigger_count(trigger_event);
+            if trigger_count == 0 {
                 continue;
-            };
-
-            if !ability.functions_in(&obj.zone) {
+            }
+            let trigger_identity = compute_trigger_identity(trigger_ability);
+            if let Some(ref condition) = trigger_ability.intervening_if
+                && !verify_intervening_if(
+                    game,
+                    condition,
+                    controller,
+                    trigger_event,
+                    obj_id,
+                    Some(trigger_identity),
+                )
+            {
                 continue;
             }
 
-            if trigger_ability.trigger.matches(trigger_event, &ctx) {
-                let trigger_count = trigger_ability.trigger.trigger_count(trigger_event);
-                if trigger_count == 0 {
-                    continue;
-                }
-                let trigger_identity = compute_trigger_identity(trigger_ability);
-                if let Some(ref condition) = trigger_ability.intervening_if
-                    && !verify_intervening_if(
-                        game,
-                        condition,
-                        obj.controller,
-                        trigger_event,
-                        obj_id,
-                        Some(trigger_identity),
-                    )
-                {
-                    continue;
-                }
-
-                let entry = TriggeredAbilityEntry {
-                    source: obj_id,
-                    controller: obj.controller,
-                    x_value: obj.x_value,
-                    ability: TriggeredAbility {
-                        trigger: trigger_ability.trigger.clone(),
-                        effects: trigger_ability.effects.clone(),
-                        choices: trigger_ability.choices.clone(),
-                        intervening_if: trigger_ability.intervening_if.clone(),
-                    },
-                    triggering_event: trigger_event.clone(),
-                    source_stable_id: obj.stable_id,
-                    source_name: obj.name.clone(),
-                    source_snapshot: None,
-                    tagged_objects: std::collections::HashMap::new(),
-                    trigger_identity,
-                };
-                for _ in 0..trigger_count {
-                    triggered.push(entry.clone());
-                }
+            let entry = TriggeredAbilityEntry {
+                source: obj_id,
+                controller,
+                x_value: obj.x_value,
+                ability: TriggeredAbility {
+                    trigger: trigger_ability.trigger.clone(),
+                    effects: trigger_ability.effects.clone(),
+                    choices: trigger_ability.choices.clone(),
+                    intervening_if: trigger_ability.intervening_if.clone(),
+                },
+                triggering_event: trigger_event.clone(),
+                source_stable_id: obj.stable_id,
+                source_name: obj.name.clone(),
+                source_snapshot: None,
+                tagged_objects: std::collections::HashMap::new(),
+                trigger_identity,
+            };
+            for _ in 0..trigger_count {
+                triggered.push(entry.clone());
             }
         }
     }
@@ -828,6 +930,7 @@ pub fn player_filter_matches_with_context(
         PlayerFilter::Teammate => false,
         PlayerFilter::Attacking => false,
         PlayerFilter::DamagedPlayer => false,
+        PlayerFilter::TriggeringPlayer => false,
         PlayerFilter::EffectController => player == controller,
         PlayerFilter::ControllerOf(obj_ref) => match obj_ref {
             ObjectRef::Specific(object_id) => game
@@ -918,9 +1021,243 @@ pub fn verify_intervening_if(
         // Legacy intervening-if checks intentionally did not provide a filter-context source.
         filter_source: None,
         triggering_event: Some(event),
+        source_snapshot: None,
         trigger_identity,
         ability_index: None,
         options: Default::default(),
     };
     crate::condition_eval::evaluate_condition_external(game, condition, &eval_ctx)
 }
+
+/// Re-check an intervening-if condition when the ability resolves (CR 603.4).
+///
+/// Uses the same evaluation as `verify_intervening_if`, plus the source's last
+/// known information from `ctx.source_snapshot`. Without a triggering event
+/// there is nothing to evaluate against, so the condition is treated as
+/// satisfied.
+pub fn intervening_if_holds_on_resolution(
+    game: &GameState,
+    condition: &crate::ConditionExpr,
+    ctx: &crate::executor::ExecutionContext,
+) -> bool {
+    let Some(triggering_event) = ctx.triggering_event.as_ref() else {
+        return true;
+    };
+    let eval_ctx = crate::condition_eval::ExternalEvaluationContext {
+        controller: ctx.controller,
+        source: ctx.source,
+        defending_player: None,
+        attacking_player: None,
+        // Match the trigger-time check, which does not provide a filter-context source.
+        filter_source: None,
+        triggering_event: Some(triggering_event),
+        source_snapshot: ctx.source_snapshot.as_ref(),
+        trigger_identity: None,
+        ability_index: None,
+        options: Default::default(),
+    };
+    crate::condition_eval::evaluate_condition_external(game, condition, &eval_ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::Ability;
+    use crate::card::{CardBuilder, PowerToughness};
+    use crate::events::DamageEvent;
+    use crate::events::spells::SpellCastEvent;
+    use crate::events::zones::ZoneChangeEvent;
+    use crate::game_event::DamageTarget;
+    use crate::ids::CardId;
+    use crate::provenance::ProvNodeId;
+    use crate::triggers::{OrTrigger, ThisDealsCombatDamageToPlayerTrigger};
+
+    fn create_creature(game: &mut GameState, name: &str, owner: PlayerId) -> ObjectId {
+        let card = CardBuilder::new(CardId::new(), name)
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 2))
+            .build();
+        game.create_object_from_card(&card, owner, Zone::Battlefield)
+    }
+
+    #[test]
+    fn test_or_trigger_is_indexed_under_each_inner_kind() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = create_creature(&mut game, "Either Way", alice);
+        if let Some(obj) = game.object_mut(source) {
+            obj.abilities.push(Ability::triggered(
+                Trigger::new(OrTrigger::two(
+                    Trigger::this_enters_battlefield(),
+                    Trigger::new(ThisDealsCombatDamageToPlayerTrigger),
+                )),
+                vec![Effect::gain_life(1)],
+            ));
+        }
+        create_creature(&mut game, "Vanilla Bears", alice);
+
+        let view = crate::derived_view::DerivedGameView::new(&game);
+        let index = BattlefieldTriggerIndex::new(&game, &view);
+        assert_eq!(index.candidate_count(EventKind::ZoneChange), 1);
+        assert_eq!(index.candidate_count(EventKind::Damage), 1);
+        assert_eq!(index.candidate_count(EventKind::SpellCast), 0);
+
+        let events = [
+            TriggerEvent::new_with_provenance(
+                ZoneChangeEvent::new(source, Zone::Hand, Zone::Battlefield, None),
+                ProvNodeId::default(),
+            ),
+            TriggerEvent::new_with_provenance(
+                SpellCastEvent::new(source, alice, Zone::Hand),
+                ProvNodeId::default(),
+            ),
+            TriggerEvent::new_with_provenance(
+                DamageEvent::new(source, DamageTarget::Player(bob), 2, true),
+                ProvNodeId::default(),
+            ),
+        ];
+        let triggered = check_triggers_for_events(&game, &events);
+        let kinds: Vec<EventKind> = triggered
+            .iter()
+            .map(|entry| entry.triggering_event.kind())
+            .collect();
+        assert_eq!(kinds, vec![EventKind::ZoneChange, EventKind::Damage]);
+        for (event, entry) in [&events[0], &events[2]].into_iter().zip(&triggered) {
+            assert_eq!(check_triggers(&game, event).len(), 1);
+            assert_eq!(entry.source, source);
+        }
+    }
+
+    fn add_twice_each_turn_trigger(game: &mut GameState, source: ObjectId) {
+        let mut ability = Ability::triggered(
+            Trigger::new(ThisDealsCombatDamageToPlayerTrigger),
+            vec![Effect::gain_life(1)],
+        );
+        if let AbilityKind::Triggered(triggered) = &mut ability.kind {
+            triggered.intervening_if = Some(crate::ConditionExpr::MaxTimesEachTurn(2));
+        }
+        game.object_mut(source).unwrap().abilities.push(ability);
+    }
+
+    #[test]
+    fn test_twice_each_turn_trigger_stops_firing_the_third_time() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = create_creature(&mut game, "Twice Striker", alice);
+        add_twice_each_turn_trigger(&mut game, source);
+        let hit = |source: ObjectId| {
+            TriggerEvent::new_with_provenance(
+                DamageEvent::new(source, DamageTarget::Player(bob), 2, true),
+                ProvNodeId::default(),
+            )
+        };
+
+        // Three simultaneous hits all match, but only two go on the stack.
+        let mut queue = TriggerQueue::new();
+        for entry in check_triggers_for_events(&game, &[hit(source), hit(source), hit(source)]) {
+            queue.add(entry);
+        }
+        assert_eq!(queue.entries.len(), 3);
+        crate::game_loop::put_triggers_on_stack(&mut game, &mut queue).unwrap();
+        assert_eq!(game.stack.len(), 2);
+
+        // Later in the turn the ability no longer triggers at all.
+        assert!(check_triggers(&game, &hit(source)).is_empty());
+
+        game.next_turn();
+        assert_eq!(check_triggers(&game, &hit(source)).len(), 1);
+    }
+
+    #[test]
+    fn test_twice_each_turn_trigger_counts_copies_separately() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source = create_creature(&mut game, "Twice Striker", alice);
+        add_twice_each_turn_trigger(&mut game, source);
+        let copy_id = game.new_object_id();
+        let copy =
+            crate::object::Object::token_copy_of(game.object(source).unwrap(), copy_id, alice);
+        game.add_object(copy);
+        let hit = |source: ObjectId| {
+            TriggerEvent::new_with_provenance(
+                DamageEvent::new(source, DamageTarget::Player(bob), 2, true),
+                ProvNodeId::default(),
+            )
+        };
+
+        // The original uses up its two triggers.
+        let mut queue = TriggerQueue::new();
+        for entry in check_triggers_for_events(&game, &[hit(source), hit(source)]) {
+            queue.add(entry);
+        }
+        crate::game_loop::put_triggers_on_stack(&mut game, &mut queue).unwrap();
+        assert_eq!(game.stack.len(), 2);
+        assert!(check_triggers(&game, &hit(source)).is_empty());
+
+        // The copy's identical ability still has both of its own.
+        let mut queue = TriggerQueue::new();
+        for entry in check_triggers_for_events(&game, &[hit(copy_id), hit(copy_id), hit(copy_id)]) {
+            queue.add(entry);
+        }
+        assert_eq!(queue.entries.len(), 3);
+        crate::game_loop::put_triggers_on_stack(&mut game, &mut queue).unwrap();
+        assert_eq!(game.stack.len(), 4);
+    }
+
+    fn end_step_event(player: PlayerId) -> TriggerEvent {
+        TriggerEvent::new_with_provenance(
+            crate::events::phase::BeginningOfEndStepEvent::new(player),
+            ProvNodeId::default(),
+        )
+    }
+
+    fn has_counter() -> crate::ConditionExpr {
+        crate::ConditionExpr::SourceHasCounterAtLeast {
+            counter_type: crate::object::CounterType::PlusOnePlusOne,
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn intervening_if_fails_on_resolution_when_condition_false() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = create_creature(&mut game, "Counter Bearer", alice);
+        let ctx = crate::executor::ExecutionContext::new_default(source, alice)
+            .with_triggering_event(end_step_event(alice));
+
+        assert!(!intervening_if_holds_on_resolution(
+            &game,
+            &has_counter(),
+            &ctx
+        ));
+    }
+
+    #[test]
+    fn intervening_if_uses_last_known_information_for_departed_source() {
+        let mut game = crate::tests::test_helpers::setup_two_player_game();
+        let alice = PlayerId::from_index(0);
+        let source = create_creature(&mut game, "Counter Bearer", alice);
+        game.object_mut(source)
+            .unwrap()
+            .counters
+            .insert(crate::object::CounterType::PlusOnePlusOne, 2);
+        let snapshot =
+            crate::snapshot::ObjectSnapshot::from_object(game.object(source).unwrap(), &game);
+        game.move_object(source, Zone::Graveyard)
+            .expect("source should leave the battlefield");
+
+        let ctx = crate::executor::ExecutionContext::new_default(source, alice)
+            .with_triggering_event(end_step_event(alice))
+            .with_source_snapshot(snapshot);
+
+        assert!(intervening_if_holds_on_resolution(
+            &game,
+            &has_counter(),
+            &ctx
+        ));
+    }
+}
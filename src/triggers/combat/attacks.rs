@@ -5,7 +5,7 @@ use crate::events::combat::CreatureAttackedEvent;
 use crate::ids::ObjectId;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching creature attacks.
 ///
@@ -78,6 +78,10 @@ impl AttacksTrigger {
 }
 
 impl TriggerMatcher for AttacksTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching creature attacks alone.
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +21,10 @@ impl AttacksAloneTrigger {
 }
 
 impl TriggerMatcher for AttacksAloneTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedAndUnblockedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching creature attacks and isn't blocked.
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +19,10 @@ impl AttacksAndIsntBlockedTrigger {
 }
 
 impl TriggerMatcher for AttacksAndIsntBlockedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttackedAndUnblocked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttackedAndUnblocked {
             return false;
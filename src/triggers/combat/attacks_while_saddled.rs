@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching creature attacks while saddled.
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +19,10 @@ impl AttacksWhileSaddledTrigger {
 }
 
 impl TriggerMatcher for AttacksWhileSaddledTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
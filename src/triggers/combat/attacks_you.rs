@@ -6,7 +6,7 @@ use crate::events::combat::{AttackEventTarget, CreatureAttackedEvent};
 use crate::ids::ObjectId;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching creature attacks you or your planeswalkers.
 #[derive(Debug, Clone, PartialEq)]
@@ -83,6 +83,10 @@ impl AttacksYouTrigger {
 }
 
 impl TriggerMatcher for AttacksYouTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
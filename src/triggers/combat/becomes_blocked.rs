@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::CreatureBecameBlockedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BecomesBlockedTrigger {
@@ -18,6 +18,10 @@ impl BecomesBlockedTrigger {
 }
 
 impl TriggerMatcher for BecomesBlockedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureBecameBlocked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureBecameBlocked {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::{CreatureBecameBlockedEvent, CreatureBlockedEvent};
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlocksOrBecomesBlockedTrigger {
@@ -18,6 +18,13 @@ impl BlocksOrBecomesBlockedTrigger {
 }
 
 impl TriggerMatcher for BlocksOrBecomesBlockedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::Only(vec![
+            EventKind::CreatureBlocked,
+            EventKind::CreatureBecameBlocked,
+        ])
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         match event.kind() {
             EventKind::CreatureBlocked => {
@@ -5,7 +5,7 @@ use crate::events::EventKind;
 use crate::game_event::DamageTarget;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DealsCombatDamageToPlayerTrigger {
@@ -52,6 +52,10 @@ impl DealsCombatDamageToPlayerTrigger {
 }
 
 impl TriggerMatcher for DealsCombatDamageToPlayerTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
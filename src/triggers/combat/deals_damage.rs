@@ -4,7 +4,7 @@ use crate::events::DamageEvent;
 use crate::events::EventKind;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DealsDamageTrigger {
@@ -29,6 +29,10 @@ impl DealsDamageTrigger {
 }
 
 impl TriggerMatcher for DealsDamageTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when the source creature attacks.
 ///
@@ -12,6 +12,10 @@ use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
 pub struct ThisAttacksTrigger;
 
 impl TriggerMatcher for ThisAttacksTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
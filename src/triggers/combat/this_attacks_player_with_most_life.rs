@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::combat::{AttackEventTarget, CreatureAttackedEvent};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when the source creature attacks a player with the most life
 /// (including ties).
@@ -11,6 +11,10 @@ use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
 pub struct ThisAttacksPlayerWithMostLifeTrigger;
 
 impl TriggerMatcher for ThisAttacksPlayerWithMostLifeTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
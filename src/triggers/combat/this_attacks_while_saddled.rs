@@ -3,13 +3,17 @@
 use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when the source creature attacks while saddled.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisAttacksWhileSaddledTrigger;
 
 impl TriggerMatcher for ThisAttacksWhileSaddledTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when the source attacks and you control another attacker
 /// with strictly greater power.
@@ -19,6 +19,10 @@ impl ThisAttacksWithGreaterPowerTrigger {
 }
 
 impl TriggerMatcher for ThisAttacksWithGreaterPowerTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
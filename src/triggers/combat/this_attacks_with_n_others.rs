@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::combat::CreatureAttackedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when the source creature attacks with at least N other creatures.
 ///
@@ -22,6 +22,10 @@ impl ThisAttacksWithNOthersTrigger {
 }
 
 impl TriggerMatcher for ThisAttacksWithNOthersTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureAttacked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureAttacked {
             return false;
@@ -3,12 +3,16 @@
 use crate::events::EventKind;
 use crate::events::combat::CreatureBecameBlockedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisBecomesBlockedTrigger;
 
 impl TriggerMatcher for ThisBecomesBlockedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureBecameBlocked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureBecameBlocked {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::combat::CreatureBlockedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisBlocksObjectTrigger {
@@ -18,6 +18,10 @@ impl ThisBlocksObjectTrigger {
 }
 
 impl TriggerMatcher for ThisBlocksObjectTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CreatureBlocked)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CreatureBlocked {
             return false;
@@ -4,12 +4,16 @@ use crate::events::DamageEvent;
 use crate::events::EventKind;
 use crate::game_event::DamageTarget;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisDealsCombatDamageToPlayerTrigger;
 
 impl TriggerMatcher for ThisDealsCombatDamageToPlayerTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
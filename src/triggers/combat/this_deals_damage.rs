@@ -6,7 +6,7 @@ use crate::filter::Comparison;
 use crate::game_event::DamageTarget;
 use crate::target::PlayerFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisDealsDamageTrigger {
@@ -79,6 +79,10 @@ fn describe_player_filter(filter: &PlayerFilter) -> &'static str {
 }
 
 impl TriggerMatcher for ThisDealsDamageTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
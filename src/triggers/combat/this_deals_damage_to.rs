@@ -5,7 +5,7 @@ use crate::events::EventKind;
 use crate::game_event::DamageTarget;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThisDealsDamageToTrigger {
@@ -30,6 +30,10 @@ impl ThisDealsDamageToTrigger {
 }
 
 impl TriggerMatcher for ThisDealsDamageToTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
@@ -6,7 +6,7 @@ use crate::object::CounterType;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::CountMode;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CounterPutOnTrigger {
@@ -43,6 +43,10 @@ impl CounterPutOnTrigger {
 }
 
 impl TriggerMatcher for CounterPutOnTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::Only(vec![EventKind::CounterPlaced, EventKind::MarkersChanged])
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         let (permanent, counter_type, source_controller) = match event.kind() {
             EventKind::CounterPlaced => {
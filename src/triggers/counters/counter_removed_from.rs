@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::MarkersChangedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CounterRemovedFromTrigger {
@@ -18,6 +18,10 @@ impl CounterRemovedFromTrigger {
 }
 
 impl TriggerMatcher for CounterRemovedFromTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::MarkersChanged)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::MarkersChanged {
             return false;
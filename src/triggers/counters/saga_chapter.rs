@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::CounterPlacedEvent;
 use crate::object::CounterType;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger for saga chapters.
 ///
@@ -28,6 +28,10 @@ impl SagaChapterTrigger {
 }
 
 impl TriggerMatcher for SagaChapterTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::CounterPlaced)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::CounterPlaced {
             return false;
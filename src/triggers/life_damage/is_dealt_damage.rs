@@ -4,7 +4,7 @@ use crate::events::{DamageEvent, EventKind};
 use crate::game_event::DamageTarget;
 use crate::target::ChooseSpec;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct IsDealtDamageTrigger {
@@ -18,6 +18,10 @@ impl IsDealtDamageTrigger {
 }
 
 impl TriggerMatcher for IsDealtDamageTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Damage)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Damage {
             return false;
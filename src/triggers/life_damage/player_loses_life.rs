@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::events::life::LifeLossEvent;
 use crate::target::PlayerFilter;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{TriggerEvent, describe_player_filter_subject};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +29,10 @@ impl PlayerLosesLifeTrigger {
 }
 
 impl TriggerMatcher for PlayerLosesLifeTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::LifeLoss)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::LifeLoss {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::life::LifeGainEvent;
 use crate::target::PlayerFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct YouGainLifeTrigger {
@@ -24,6 +24,10 @@ impl YouGainLifeTrigger {
 }
 
 impl TriggerMatcher for YouGainLifeTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::LifeGain)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::LifeGain {
             return false;
@@ -4,6 +4,7 @@
 //! must implement. Each trigger type (ETB, dies, upkeep, etc.) implements this trait
 //! with its own matching logic.
 
+use crate::events::EventKind;
 use crate::game_state::GameState;
 use crate::ids::{ObjectId, PlayerId};
 use crate::target::FilterContext;
@@ -51,6 +52,48 @@ impl<'a> TriggerContext<'a> {
     }
 }
 
+/// The event kinds a trigger can possibly match.
+///
+/// Trigger checking uses this to skip abilities that can't fire for an event
+/// without calling [`TriggerMatcher::matches`] on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterestedKinds {
+    /// Events of any kind might match.
+    All,
+    /// Only events of these kinds can match.
+    Only(Vec<EventKind>),
+}
+
+impl InterestedKinds {
+    /// Only events of `kind` can match.
+    pub fn only(kind: EventKind) -> Self {
+        Self::Only(vec![kind])
+    }
+
+    /// Whether an event of `kind` might match.
+    pub fn contains(&self, kind: EventKind) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(kinds) => kinds.contains(&kind),
+        }
+    }
+
+    /// Kinds that might match either `self` or `other`.
+    pub fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Only(mut kinds), Self::Only(other)) => {
+                for kind in other {
+                    if !kinds.contains(&kind) {
+                        kinds.push(kind);
+                    }
+                }
+                Self::Only(kinds)
+            }
+            _ => Self::All,
+        }
+    }
+}
+
 /// Trait for matching game events to trigger conditions.
 ///
 /// All modular triggers implement this trait. Each trigger is responsible for:
@@ -108,6 +151,14 @@ pub trait TriggerMatcher:
     /// `true` if this trigger should fire for the given event.
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool;
 
+    /// The event kinds [`Self::matches`] can return `true` for.
+    ///
+    /// Defaults to all kinds. Override this when `matches` starts by
+    /// rejecting every other kind, so trigger checking can skip this trigger.
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::All
+    }
+
     /// Human-readable display text for this trigger.
     ///
     /// Should describe what causes the trigger to fire.
@@ -59,11 +59,12 @@ pub mod zone_changes;
 // Re-export core types
 pub use check::{
     DelayedTrigger, TriggerIdentity, TriggerQueue, TriggeredAbilityEntry, check_delayed_triggers,
-    check_triggers, compute_delayed_trigger_identity, compute_trigger_identity,
-    generate_step_trigger_events, player_filter_matches_with_context, verify_intervening_if,
+    check_triggers, check_triggers_for_events, compute_delayed_trigger_identity,
+    compute_trigger_identity, generate_step_trigger_events, player_filter_matches_with_context,
+    verify_intervening_if,
 };
 pub use event::{AttackEventTarget, DamageEventTarget};
-pub use matcher_trait::{TriggerContext, TriggerMatcher};
+pub use matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 pub type TriggerEvent = crate::events::RawEvent;
 
 // Re-export trigger implementations from submodules
@@ -93,6 +94,7 @@ pub(crate) fn describe_player_filter_subject(filter: &PlayerFilter) -> String {
         PlayerFilter::Defending => "the defending player".to_string(),
         PlayerFilter::Attacking => "the attacking player".to_string(),
         PlayerFilter::DamagedPlayer
+        | PlayerFilter::TriggeringPlayer
         | PlayerFilter::EffectController
         | PlayerFilter::Specific(_)
         | PlayerFilter::IteratedPlayer
@@ -117,6 +119,7 @@ pub(crate) fn describe_player_filter_possessive(filter: &PlayerFilter) -> String
         PlayerFilter::Defending => "the defending player's".to_string(),
         PlayerFilter::Attacking => "the attacking player's".to_string(),
         PlayerFilter::DamagedPlayer
+        | PlayerFilter::TriggeringPlayer
         | PlayerFilter::EffectController
         | PlayerFilter::Specific(_)
         | PlayerFilter::IteratedPlayer
@@ -161,14 +164,24 @@ impl Trigger {
     }
 
     /// Get the display text for this trigger.
+    pub fn display_text(&self) -> crate::display_text::DisplayText {
+        crate::display_text::DisplayText::literal(self.0.display())
+    }
+
+    /// Render the display text with the default English renderer.
     pub fn display(&self) -> String {
-        self.0.display()
+        self.display_text().display()
     }
 
     pub fn downcast_ref<T: TriggerMatcher + 'static>(&self) -> Option<&T> {
         (self.0.as_ref() as &dyn std::any::Any).downcast_ref::<T>()
     }
 
+    /// The event kinds this trigger can possibly match.
+    pub fn interested_kinds(&self) -> InterestedKinds {
+        self.0.interested_kinds()
+    }
+
     /// Whether this trigger uses snapshot-based matching.
     pub fn uses_snapshot(&self) -> bool {
         self.0.uses_snapshot()
@@ -260,8 +273,16 @@ impl Trigger {
     }
 
     /// Create a "when [filter] is put into a graveyard from anywhere" trigger.
+    ///
+    /// A battlefield-scoped filter ("a permanent you control") describes the
+    /// object before it moved, so it's matched against its last-known information.
     pub fn put_into_graveyard(filter: ObjectFilter) -> Self {
-        Self::new(ZoneChangeTrigger::new().to(Zone::Graveyard).filter(filter))
+        let trigger = if filter.zone == Some(Zone::Battlefield) {
+            ZoneChangeTrigger::new().from(Zone::Battlefield)
+        } else {
+            ZoneChangeTrigger::new()
+        };
+        Self::new(trigger.to(Zone::Graveyard).filter(filter))
     }
 
     /// Create a "whenever one or more [filter] cards leave your graveyard" trigger.
@@ -287,7 +308,7 @@ impl Trigger {
         Self::new(
             ZoneChangeTrigger::new()
                 .to(Zone::Hand)
-                .player(PlayerRelation::You),
+                .owner(PlayerRelation::You),
         )
     }
 
@@ -843,6 +864,10 @@ impl TriggerMatcher for Trigger {
         self.0.display()
     }
 
+    fn interested_kinds(&self) -> InterestedKinds {
+        self.0.interested_kinds()
+    }
+
     fn uses_snapshot(&self) -> bool {
         self.0.uses_snapshot()
     }
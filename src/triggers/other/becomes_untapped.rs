@@ -3,12 +3,16 @@
 use crate::events::EventKind;
 use crate::events::other::PermanentUntappedEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BecomesUntappedTrigger;
 
 impl TriggerMatcher for BecomesUntappedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::PermanentUntapped)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::PermanentUntapped {
             return false;
@@ -2,12 +2,16 @@
 
 use crate::events::EventKind;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EachPlayersTurnTrigger;
 
 impl TriggerMatcher for EachPlayersTurnTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::BeginningOfUpkeep)
+    }
+
     fn matches(&self, event: &TriggerEvent, _ctx: &TriggerContext) -> bool {
         // This triggers at the beginning of upkeep for any player
         event.kind() == EventKind::BeginningOfUpkeep
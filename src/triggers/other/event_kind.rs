@@ -2,7 +2,7 @@
 
 use crate::events::EventKind;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that only checks event kind.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,6 +21,10 @@ impl EventKindTrigger {
 }
 
 impl TriggerMatcher for EventKindTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(self.kind)
+    }
+
     fn matches(&self, event: &TriggerEvent, _ctx: &TriggerContext) -> bool {
         event.kind() == self.kind
     }
@@ -47,6 +51,10 @@ impl ThisEventObjectTrigger {
 }
 
 impl TriggerMatcher for ThisEventObjectTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(self.kind)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         event.kind() == self.kind && event.object_id() == Some(ctx.source_id)
     }
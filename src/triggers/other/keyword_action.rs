@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::{KeywordActionEvent, KeywordActionKind};
 use crate::target::PlayerFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeywordActionTrigger {
@@ -32,6 +32,10 @@ impl KeywordActionTrigger {
 }
 
 impl TriggerMatcher for KeywordActionTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::KeywordAction)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::KeywordAction {
             return false;
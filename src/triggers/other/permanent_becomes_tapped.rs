@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::PermanentTappedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PermanentBecomesTappedTrigger {
@@ -18,6 +18,10 @@ impl PermanentBecomesTappedTrigger {
 }
 
 impl TriggerMatcher for PermanentBecomesTappedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::PermanentTapped)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::PermanentTapped {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::TurnedFaceUpEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PermanentTurnedFaceUpTrigger {
@@ -18,6 +18,10 @@ impl PermanentTurnedFaceUpTrigger {
 }
 
 impl TriggerMatcher for PermanentTurnedFaceUpTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::TurnedFaceUp)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::TurnedFaceUp {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::LandPlayedEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PlayerPlaysLandTrigger {
@@ -19,6 +19,10 @@ impl PlayerPlaysLandTrigger {
 }
 
 impl TriggerMatcher for PlayerPlaysLandTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::LandPlayed)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::LandPlayed {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::permanents::SacrificeEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PlayerSacrificesTrigger {
@@ -19,6 +19,10 @@ impl PlayerSacrificesTrigger {
 }
 
 impl TriggerMatcher for PlayerSacrificesTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::Sacrifice)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::Sacrifice {
             return false;
@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::ids::PlayerId;
 use crate::target::PlayerFilter;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{TriggerEvent, describe_player_filter_possessive};
 
 /// Trigger that fires at the beginning of combat.
@@ -33,6 +33,10 @@ impl BeginningOfCombatTrigger {
 }
 
 impl TriggerMatcher for BeginningOfCombatTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::BeginningOfCombat)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::BeginningOfCombat {
             return false;
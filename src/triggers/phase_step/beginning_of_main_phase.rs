@@ -3,7 +3,7 @@
 use crate::events::EventKind;
 use crate::ids::PlayerId;
 use crate::target::PlayerFilter;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{TriggerEvent, describe_player_filter_possessive};
 
 /// Which main phase to trigger on.
@@ -61,6 +61,21 @@ impl BeginningOfMainPhaseTrigger {
 }
 
 impl TriggerMatcher for BeginningOfMainPhaseTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        match self.phase_type {
+            MainPhaseType::Precombat => {
+                InterestedKinds::only(EventKind::BeginningOfPrecombatMainPhase)
+            }
+            MainPhaseType::Postcombat => {
+                InterestedKinds::only(EventKind::BeginningOfPostcombatMainPhase)
+            }
+            MainPhaseType::Either => InterestedKinds::Only(vec![
+                EventKind::BeginningOfPrecombatMainPhase,
+                EventKind::BeginningOfPostcombatMainPhase,
+            ]),
+        }
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         let kind = event.kind();
         let is_precombat = kind == EventKind::BeginningOfPrecombatMainPhase;
@@ -2,7 +2,7 @@
 
 use crate::events::EventKind;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires at end of combat.
 ///
@@ -11,6 +11,10 @@ use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
 pub struct EndOfCombatTrigger;
 
 impl TriggerMatcher for EndOfCombatTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::EndOfCombat)
+    }
+
     fn matches(&self, event: &TriggerEvent, _ctx: &TriggerContext) -> bool {
         event.kind() == EventKind::EndOfCombat
     }
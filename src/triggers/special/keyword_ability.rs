@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::other::CardsDrawnEvent;
 use crate::events::zones::ZoneChangeEvent;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum KeywordAbilityTriggerKind {
@@ -39,6 +39,15 @@ impl KeywordAbilityTrigger {
 }
 
 impl TriggerMatcher for KeywordAbilityTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        match self.kind {
+            KeywordAbilityTriggerKind::Undying | KeywordAbilityTriggerKind::Persist => {
+                InterestedKinds::only(EventKind::ZoneChange)
+            }
+            KeywordAbilityTriggerKind::Miracle => InterestedKinds::only(EventKind::CardsDrawn),
+        }
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         match self.kind {
             KeywordAbilityTriggerKind::Undying => {
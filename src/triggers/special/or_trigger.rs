@@ -1,6 +1,6 @@
 //! Or trigger combinator - matches if any of the inner triggers match.
 
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::triggers::{Trigger, TriggerEvent};
 
 /// A trigger that matches if any of the inner triggers match.
@@ -39,6 +39,13 @@ impl TriggerMatcher for OrTrigger {
         Box::new(self.clone())
     }
 
+    fn interested_kinds(&self) -> InterestedKinds {
+        self.triggers
+            .iter()
+            .map(Trigger::interested_kinds)
+            .fold(InterestedKinds::Only(Vec::new()), InterestedKinds::union)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         self.triggers.iter().any(|t| t.matches(event, ctx))
     }
@@ -73,8 +80,8 @@ impl TriggerMatcher for OrTrigger {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::events::DamageEvent;
     use crate::events::zones::ZoneChangeEvent;
+    use crate::events::{DamageEvent, EventKind};
     use crate::game_event::DamageTarget;
     use crate::game_state::GameState;
     use crate::ids::{ObjectId, PlayerId};
@@ -165,6 +172,25 @@ mod tests {
         assert!(!trigger.matches(&etb_event, &ctx));
     }
 
+    #[test]
+    fn test_or_trigger_interested_kinds_cover_every_inner_trigger() {
+        let trigger = OrTrigger::two(
+            Trigger::this_enters_battlefield(),
+            Trigger::new(ThisDealsCombatDamageToPlayerTrigger),
+        );
+        let kinds = trigger.interested_kinds();
+        assert!(kinds.contains(EventKind::ZoneChange));
+        assert!(kinds.contains(EventKind::Damage));
+        assert!(!kinds.contains(EventKind::SpellCast));
+
+        // An inner trigger that might match anything makes the whole trigger match anything.
+        let trigger = OrTrigger::two(
+            Trigger::this_enters_battlefield(),
+            Trigger::custom("test", "When something happens".to_string()),
+        );
+        assert_eq!(trigger.interested_kinds(), InterestedKinds::All);
+    }
+
     #[test]
     fn test_or_trigger_display() {
         let trigger = OrTrigger::two(
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::spells::AbilityActivatedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct AbilityActivatedTrigger {
@@ -18,6 +18,10 @@ impl AbilityActivatedTrigger {
 }
 
 impl TriggerMatcher for AbilityActivatedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::AbilityActivated)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::AbilityActivated {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::spells::BecomesTargetedEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BecomesTargetedBySourceControllerTrigger {
@@ -22,6 +22,10 @@ impl BecomesTargetedBySourceControllerTrigger {
 }
 
 impl TriggerMatcher for BecomesTargetedBySourceControllerTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::BecomesTargeted)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::BecomesTargeted {
             return false;
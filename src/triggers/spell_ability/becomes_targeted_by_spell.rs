@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::spells::BecomesTargetedEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BecomesTargetedBySpellTrigger {
@@ -18,6 +18,10 @@ impl BecomesTargetedBySpellTrigger {
 }
 
 impl TriggerMatcher for BecomesTargetedBySpellTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::BecomesTargeted)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::BecomesTargeted {
             return false;
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::spells::SpellCastEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::zone::Zone;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +57,10 @@ impl SpellCastTrigger {
 }
 
 impl TriggerMatcher for SpellCastTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::SpellCast)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::SpellCast {
             return false;
@@ -95,7 +99,8 @@ impl TriggerMatcher for SpellCastTrigger {
 
         let cast_count = ctx
             .game
-            .spells_cast_this_turn
+            .turn_trackers
+            .spells_cast
             .get(&e.caster)
             .copied()
             .unwrap_or(0);
@@ -259,6 +264,7 @@ fn describe_spell_filter(filter: &ObjectFilter) -> String {
                 PlayerFilter::Defending => "the defending player".to_string(),
                 PlayerFilter::Attacking => "an attacking player".to_string(),
                 PlayerFilter::DamagedPlayer => "the damaged player".to_string(),
+                PlayerFilter::TriggeringPlayer => "that player".to_string(),
                 PlayerFilter::EffectController => "the player who cast this spell".to_string(),
                 PlayerFilter::IteratedPlayer => "that player".to_string(),
                 PlayerFilter::TargetPlayerOrControllerOfTarget => {
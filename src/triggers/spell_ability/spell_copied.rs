@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::spells::SpellCopiedEvent;
 use crate::target::{ObjectFilter, PlayerFilter};
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SpellCopiedTrigger {
@@ -19,6 +19,10 @@ impl SpellCopiedTrigger {
 }
 
 impl TriggerMatcher for SpellCopiedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::SpellCopied)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::SpellCopied {
             return false;
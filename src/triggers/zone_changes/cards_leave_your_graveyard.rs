@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::zones::ZoneChangeEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::types::CardType;
 use crate::zone::Zone;
 
@@ -116,6 +116,10 @@ impl CardsLeaveYourGraveyardTrigger {
 }
 
 impl TriggerMatcher for CardsLeaveYourGraveyardTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::ZoneChange)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::ZoneChange {
             return false;
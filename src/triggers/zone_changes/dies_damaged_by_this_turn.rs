@@ -5,7 +5,7 @@ use crate::events::zones::ZoneChangeEvent;
 use crate::ids::ObjectId;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Which source object is used when matching "dealt damage by ... this turn".
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -108,6 +108,10 @@ impl DiesDamagedByThisTurnTrigger {
 }
 
 impl TriggerMatcher for DiesDamagedByThisTurnTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::ZoneChange)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         self.matching_objects(event, ctx) > 0
     }
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::zones::EnterBattlefieldEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching object enters the battlefield tapped.
 ///
@@ -29,6 +29,10 @@ impl EntersBattlefieldTappedTrigger {
 }
 
 impl TriggerMatcher for EntersBattlefieldTappedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::EnterBattlefield)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         // Fast-path: check event kind
         if event.kind() != EventKind::EnterBattlefield {
@@ -4,7 +4,7 @@ use crate::events::EventKind;
 use crate::events::zones::EnterBattlefieldEvent;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 
 /// Trigger that fires when a matching object enters the battlefield untapped.
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +21,10 @@ impl EntersBattlefieldUntappedTrigger {
 }
 
 impl TriggerMatcher for EntersBattlefieldUntappedTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::EnterBattlefield)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         if event.kind() != EventKind::EnterBattlefield {
             return false;
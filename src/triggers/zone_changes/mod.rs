@@ -17,4 +17,6 @@ pub use enters_tapped::EntersBattlefieldTappedTrigger;
 pub use enters_untapped::EntersBattlefieldUntappedTrigger;
 
 // Composable zone change trigger and supporting types
-pub use zone_change_trigger::{CountMode, PlayerRelation, ZoneChangeTrigger, ZonePattern};
+pub use zone_change_trigger::{
+    CountMode, PlayerRelation, PlayerRole, ZoneChangeTrigger, ZonePattern,
+};
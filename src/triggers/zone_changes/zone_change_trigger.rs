@@ -21,15 +21,19 @@
 //! // "Whenever a card is put into your graveyard from anywhere"
 //! ZoneChangeTrigger::new()
 //!     .to(Zone::Graveyard)
-//!     .player(PlayerRelation::You)
+//!     .owner(PlayerRelation::You)
+//!
+//! // "Whenever a creature you own dies" (fires even if someone else controlled it)
+//! ZoneChangeTrigger::dies(ObjectFilter::creature()).owner(PlayerRelation::You)
 //! ```
 
 use crate::events::EventKind;
 use crate::events::cause::CauseFilter;
 use crate::events::zones::ZoneChangeEvent;
+use crate::ids::PlayerId;
 use crate::target::ObjectFilter;
 use crate::triggers::TriggerEvent;
-use crate::triggers::matcher_trait::{TriggerContext, TriggerMatcher};
+use crate::triggers::matcher_trait::{InterestedKinds, TriggerContext, TriggerMatcher};
 use crate::zone::Zone;
 use std::fmt;
 
@@ -76,16 +80,42 @@ impl From<Zone> for ZonePattern {
     }
 }
 
-/// How the player relates to the zone change (owner/controller of the object).
+/// Which player a zone change trigger cares about, relative to the trigger's controller.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum PlayerRelation {
     /// Match any player's objects.
     #[default]
     Any,
-    /// Match objects owned/controlled by the trigger's controller.
+    /// Match objects of the trigger's controller (the source's controller).
     You,
-    /// Match objects owned/controlled by an opponent of the trigger's controller.
+    /// Match objects of an opponent of the trigger's controller.
     Opponent,
+    /// Match objects of one specific player.
+    Specific(PlayerId),
+}
+
+impl PlayerRelation {
+    fn matches(&self, player: PlayerId, you: PlayerId) -> bool {
+        match self {
+            PlayerRelation::Any => true,
+            PlayerRelation::You => player == you,
+            PlayerRelation::Opponent => player != you,
+            PlayerRelation::Specific(specific) => player == *specific,
+        }
+    }
+}
+
+/// Whether a [`PlayerRelation`] is checked against the object's controller or owner.
+///
+/// The controller is the one at the time of the event: a stolen creature that
+/// dies was controlled by the thief but is still owned by its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlayerRole {
+    /// "under your control", "you control"
+    #[default]
+    Controller,
+    /// "you own", "your graveyard", "your hand"
+    Owner,
 }
 
 /// How many times the trigger fires for batch events.
@@ -114,8 +144,10 @@ pub struct ZoneChangeTrigger {
     pub to: ZonePattern,
     /// Filter for matching objects.
     pub object_filter: ObjectFilter,
-    /// Who owns/controls the object.
+    /// Whose object it must be.
     pub player: PlayerRelation,
+    /// Whether `player` is checked against the controller or the owner.
+    pub player_role: PlayerRole,
     /// Optional filter on what caused the zone change.
     pub cause_filter: Option<CauseFilter>,
     /// How many times to fire for batch events.
@@ -131,6 +163,7 @@ impl Default for ZoneChangeTrigger {
             to: ZonePattern::Any,
             object_filter: ObjectFilter::default(),
             player: PlayerRelation::Any,
+            player_role: PlayerRole::Controller,
             cause_filter: None,
             count_mode: CountMode::Each,
             this_object: false,
@@ -162,9 +195,17 @@ impl ZoneChangeTrigger {
         self
     }
 
-    /// Set the player relation.
+    /// Require the object to be controlled by this player.
     pub fn player(mut self, player: PlayerRelation) -> Self {
         self.player = player;
+        self.player_role = PlayerRole::Controller;
+        self
+    }
+
+    /// Require the object to be owned by this player.
+    pub fn owner(mut self, player: PlayerRelation) -> Self {
+        self.player = player;
+        self.player_role = PlayerRole::Owner;
         self
     }
 
@@ -227,12 +268,12 @@ impl ZoneChangeTrigger {
         Self::new()
             .from(Zone::Hand)
             .to(Zone::Graveyard)
-            .player(PlayerRelation::You)
+            .owner(PlayerRelation::You)
     }
 
     /// "Whenever a card is put into your graveyard from anywhere"
     pub fn card_enters_your_graveyard() -> Self {
-        Self::new().to(Zone::Graveyard).player(PlayerRelation::You)
+        Self::new().to(Zone::Graveyard).owner(PlayerRelation::You)
     }
 
     /// "Whenever a [filter] is exiled"
@@ -303,11 +344,18 @@ impl ZoneChangeTrigger {
 
         let mut parts = vec!["Whenever".to_string()];
 
-        // Player relation
-        match &self.player {
-            PlayerRelation::You => parts.push("you".to_string()),
-            PlayerRelation::Opponent => parts.push("an opponent".to_string()),
-            PlayerRelation::Any => {}
+        let discards = self.player != PlayerRelation::Any
+            && self.from == ZonePattern::Specific(Zone::Hand)
+            && self.to == ZonePattern::Specific(Zone::Graveyard);
+        if discards {
+            match &self.player {
+                PlayerRelation::You => parts.push("you discard".to_string()),
+                PlayerRelation::Opponent => parts.push("an opponent discards".to_string()),
+                PlayerRelation::Specific(player) => {
+                    parts.push(format!("player {} discards", player.index() + 1))
+                }
+                PlayerRelation::Any => {}
+            }
         }
 
         // Object filter description
@@ -328,12 +376,30 @@ impl ZoneChangeTrigger {
         } else if !has_article {
             parts.push("a".to_string());
         }
-        if filter_desc != "object" {
+        if filter_desc != "object" && !(discards && self.object_filter == ObjectFilter::default()) {
             parts.push(filter_desc);
         } else {
             parts.push("card".to_string());
         }
 
+        if discards {
+            return parts.join(" ");
+        }
+
+        // Player relation
+        let (singular, plural) = match self.player_role {
+            PlayerRole::Controller => ("control", "controls"),
+            PlayerRole::Owner => ("own", "owns"),
+        };
+        match &self.player {
+            PlayerRelation::You => parts.push(format!("you {singular}")),
+            PlayerRelation::Opponent => parts.push(format!("an opponent {plural}")),
+            PlayerRelation::Specific(player) => {
+                parts.push(format!("player {} {plural}", player.index() + 1))
+            }
+            PlayerRelation::Any => {}
+        }
+
         // Zone change description
         match (&self.from, &self.to) {
             (ZonePattern::Specific(Zone::Battlefield), ZonePattern::Specific(Zone::Graveyard)) => {
@@ -391,6 +457,10 @@ fn snapshot_matches_filter(
 }
 
 impl TriggerMatcher for ZoneChangeTrigger {
+    fn interested_kinds(&self) -> InterestedKinds {
+        InterestedKinds::only(EventKind::ZoneChange)
+    }
+
     fn matches(&self, event: &TriggerEvent, ctx: &TriggerContext) -> bool {
         // Must be a zone change event
         if event.kind() != EventKind::ZoneChange {
@@ -416,32 +486,26 @@ impl TriggerMatcher for ZoneChangeTrigger {
 
         let use_snapshot = self.uses_snapshot() && zc.snapshot.is_some();
 
-        // Check player relation using LKI snapshots only for leave/die-style triggers.
+        // Check player relation using LKI snapshots only for leave/die-style triggers,
+        // so the controller is the one at the time the object left.
         if self.player != PlayerRelation::Any {
-            let player_matches = if use_snapshot {
-                let snapshot = zc
-                    .snapshot
-                    .as_ref()
-                    .expect("use_snapshot implies a zone-change snapshot");
-                match &self.player {
-                    PlayerRelation::You => snapshot.controller == ctx.controller,
-                    PlayerRelation::Opponent => snapshot.controller != ctx.controller,
-                    PlayerRelation::Any => true,
-                }
+            let player = if use_snapshot {
+                zc.snapshot.as_ref().map(|snapshot| match self.player_role {
+                    PlayerRole::Controller => snapshot.controller,
+                    PlayerRole::Owner => snapshot.owner,
+                })
             } else {
                 // Check the first object in game state
                 zc.objects
                     .first()
                     .and_then(|&id| ctx.game.object(id))
-                    .map(|obj| match &self.player {
-                        PlayerRelation::You => obj.controller == ctx.controller,
-                        PlayerRelation::Opponent => obj.controller != ctx.controller,
-                        PlayerRelation::Any => true,
+                    .map(|obj| match self.player_role {
+                        PlayerRole::Controller => obj.controller,
+                        PlayerRole::Owner => obj.owner,
                     })
-                    .unwrap_or(false)
             };
 
-            if !player_matches {
+            if !player.is_some_and(|player| self.player.matches(player, ctx.controller)) {
                 return false;
             }
         }
@@ -457,13 +521,17 @@ impl TriggerMatcher for ZoneChangeTrigger {
                 return false;
             }
         } else {
-            // Check filter against live object (for ETB triggers, etc.)
-            let filter_matches = zc
+            // Check filter against live objects (for ETB triggers, etc.). Batch
+            // events like a mill match when any moved object does.
+            let live_objects: Vec<_> = zc
                 .objects
-                .first()
-                .and_then(|&id| ctx.game.object(id))
-                .map(|obj| self.object_filter.matches(obj, &ctx.filter_ctx, ctx.game))
-                .unwrap_or(true); // If no object, allow (conservative)
+                .iter()
+                .filter_map(|&id| ctx.game.object(id))
+                .collect();
+            let filter_matches = live_objects.is_empty() // If no object, allow (conservative)
+                || live_objects
+                    .iter()
+                    .any(|obj| self.object_filter.matches(obj, &ctx.filter_ctx, ctx.game));
 
             if !filter_matches {
                 return false;
@@ -778,4 +846,121 @@ mod tests {
         let trigger = ZoneChangeTrigger::enters_battlefield(ObjectFilter::land());
         assert_eq!(trigger.display(), "Whenever a land enters the battlefield");
     }
+
+    /// Alice owns a Grizzly Bears that Bob has stolen; it then dies.
+    fn stolen_bears_death_event(game: &mut GameState) -> TriggerEvent {
+        use crate::effects::{EffectExecutor, GainControlEffect};
+        use crate::executor::{ExecutionContext, ResolvedTarget};
+
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let bears = game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            alice,
+            Zone::Battlefield,
+        );
+        let source = game.new_object_id();
+        let mut ctx = ExecutionContext::new_default(source, bob)
+            .with_targets(vec![ResolvedTarget::Object(bears)]);
+        GainControlEffect::permanent(crate::target::ChooseSpec::creature())
+            .execute(game, &mut ctx)
+            .unwrap();
+        game.take_pending_trigger_events();
+
+        game.move_object(bears, Zone::Graveyard);
+        game.take_pending_trigger_events()
+            .into_iter()
+            .find(|event| event.kind() == EventKind::ZoneChange)
+            .expect("dying should queue a zone change event")
+    }
+
+    #[test]
+    fn stolen_creature_death_fires_owner_trigger_not_controller_trigger() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let event = stolen_bears_death_event(&mut game);
+        let source_id = ObjectId::from_raw(9000);
+        let alice_ctx = TriggerContext::for_source(source_id, alice, &game);
+        let bob_ctx = TriggerContext::for_source(source_id, bob, &game);
+
+        let you_own = ZoneChangeTrigger::dies(ObjectFilter::creature()).owner(PlayerRelation::You);
+        let you_control =
+            ZoneChangeTrigger::dies(ObjectFilter::creature()).player(PlayerRelation::You);
+
+        assert!(you_own.matches(&event, &alice_ctx));
+        assert!(!you_control.matches(&event, &alice_ctx));
+        assert!(you_control.matches(&event, &bob_ctx));
+        assert!(!you_own.matches(&event, &bob_ctx));
+
+        // Parsed "a creature you own" / "a creature you control" filters agree.
+        let owned_filter = ZoneChangeTrigger::dies(
+            ObjectFilter::creature().owned_by(crate::target::PlayerFilter::You),
+        );
+        let controlled_filter = ZoneChangeTrigger::dies(ObjectFilter::creature().you_control());
+        assert!(owned_filter.matches(&event, &alice_ctx));
+        assert!(!controlled_filter.matches(&event, &alice_ctx));
+        assert!(controlled_filter.matches(&event, &bob_ctx));
+    }
+
+    #[test]
+    fn land_entering_under_an_opponents_control_matches_opponent_relation() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let source_id = ObjectId::from_raw(9000);
+        let trigger = ZoneChangeTrigger::enters_battlefield(ObjectFilter::land())
+            .player(PlayerRelation::Opponent);
+        let only_bob = ZoneChangeTrigger::enters_battlefield(ObjectFilter::land())
+            .player(PlayerRelation::Specific(bob));
+        let land = CardBuilder::new(CardId::new(), "Forest")
+            .card_types(vec![CardType::Land])
+            .build();
+        let bob_land = game.create_object_from_card(&land, bob, Zone::Battlefield);
+        let alice_land = game.create_object_from_card(&land, alice, Zone::Battlefield);
+        let ctx = TriggerContext::for_source(source_id, alice, &game);
+        let enters = |id| {
+            TriggerEvent::new_with_provenance(
+                ZoneChangeEvent::new(id, Zone::Hand, Zone::Battlefield, None),
+                crate::provenance::ProvNodeId::default(),
+            )
+        };
+
+        assert!(trigger.matches(&enters(bob_land), &ctx));
+        assert!(!trigger.matches(&enters(alice_land), &ctx));
+        assert!(only_bob.matches(&enters(bob_land), &ctx));
+        assert!(!only_bob.matches(&enters(alice_land), &ctx));
+    }
+
+    #[test]
+    fn permanent_put_into_graveyard_from_battlefield_uses_last_known_controller() {
+        let mut game = setup_game();
+        let bob = PlayerId::from_index(1);
+        let event = stolen_bears_death_event(&mut game);
+        let ctx = TriggerContext::for_source(ObjectId::from_raw(9000), bob, &game);
+
+        let trigger =
+            crate::triggers::Trigger::put_into_graveyard(ObjectFilter::permanent().you_control());
+        assert!(trigger.matches(&event, &ctx));
+    }
+
+    #[test]
+    fn test_display_player_relations() {
+        assert_eq!(
+            ZoneChangeTrigger::dies(ObjectFilter::creature())
+                .owner(PlayerRelation::You)
+                .display(),
+            "Whenever a creature you own dies"
+        );
+        assert_eq!(
+            ZoneChangeTrigger::enters_battlefield(ObjectFilter::land())
+                .player(PlayerRelation::Opponent)
+                .display(),
+            "Whenever a land an opponent controls enters the battlefield"
+        );
+        assert_eq!(
+            ZoneChangeTrigger::you_discard().display(),
+            "Whenever you discard a card"
+        );
+    }
 }
@@ -6,8 +6,10 @@
 //! - Step-specific actions (untapping, drawing, cleanup)
 
 use crate::DecisionMaker;
+use crate::effect::Until;
 use crate::game_state::{GameState, Phase, Step};
 use crate::ids::PlayerId;
+use crate::provenance::ProvNodeId;
 
 /// Errors that can occur during turn progression.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -254,6 +256,40 @@ pub fn is_no_priority_step(game: &GameState) -> bool {
     matches!(game.turn.step, Some(Step::Untap) | Some(Step::Cleanup))
 }
 
+/// Phases permanents in and out at the start of the untap step (Rule 502.1).
+///
+/// All of the active player's phased-in permanents with phasing phase out, and
+/// all of their permanents that phased out directly phase in, simultaneously.
+/// Attachments phase along with the permanent they're attached to.
+pub fn execute_phasing(game: &mut GameState, active_player: PlayerId) {
+    use crate::static_abilities::StaticAbilityId;
+
+    let phasing_out: Vec<_> = game
+        .permanents_controlled_by(active_player)
+        .into_iter()
+        .filter(|&id| game.object_has_static_ability_id(id, StaticAbilityId::Phasing))
+        .collect();
+    let mut phasing_in: Vec<_> = game
+        .phased_out
+        .iter()
+        .copied()
+        .filter(|id| !game.phased_out_indirectly.contains(id))
+        .filter(|&id| {
+            game.object(id).is_some_and(|obj| {
+                obj.zone == crate::zone::Zone::Battlefield && obj.controller == active_player
+            })
+        })
+        .collect();
+    phasing_in.sort_unstable();
+
+    for id in phasing_out {
+        game.phase_out(id);
+    }
+    for id in phasing_in {
+        game.phase_in(id);
+    }
+}
+
 /// Executes the untap step for the active player.
 /// Untaps all permanents controlled by the active player (except those that don't untap).
 pub fn execute_untap_step(game: &mut GameState) {
@@ -271,19 +307,7 @@ pub fn execute_untap_step_with(game: &mut GameState, decision_maker: &mut impl D
 
     let active_player = game.turn.active_player;
 
-    let phased_in: Vec<_> = game
-        .phased_out
-        .iter()
-        .copied()
-        .filter(|&id| {
-            game.object(id).is_some_and(|obj| {
-                obj.zone == crate::zone::Zone::Battlefield && obj.controller == active_player
-            })
-        })
-        .collect();
-    for id in phased_in {
-        game.phase_in(id);
-    }
+    execute_phasing(game, active_player);
 
     // Get all permanents controlled by active player
     let permanents: Vec<_> = game.permanents_controlled_by(active_player);
@@ -327,11 +351,15 @@ pub fn execute_untap_step_with(game: &mut GameState, decision_maker: &mut impl D
         })
         .collect();
 
-    // Second pass: untap eligible permanents and remove summoning sickness from all
+    // Second pass: untap eligible permanents and remove summoning sickness from all.
+    // Everything untaps simultaneously, but each permanent that became untapped
+    // gets its own event; they're queued for triggers before upkeep.
     for id in permanents {
         // Only untap if the permanent doesn't have DoesntUntap
-        if should_untap.contains(&id) {
-            game.untap(id);
+        if should_untap.contains(&id)
+            && let Some(event) = game.untap_permanent(id, ProvNodeId::default())
+        {
+            game.queue_trigger_event(ProvNodeId::default(), event);
         }
         // Always remove summoning sickness at untap step
         game.remove_summoning_sickness(id);
@@ -351,10 +379,28 @@ pub fn execute_draw_step(game: &mut GameState) -> Vec<crate::triggers::TriggerEv
     execute_draw_step_with(game, &mut dm)
 }
 
+/// Runs the draw step's draw through replacement effects.
+///
+/// Returns how many cards the active player should still draw; zero when the
+/// draw was prevented or replaced (replacement effects have already run).
+pub(crate) fn process_draw_step_draw(
+    game: &mut GameState,
+    player: PlayerId,
+    is_first_draw: bool,
+    decision_maker: &mut dyn DecisionMaker,
+) -> u32 {
+    use crate::event_processor::{EventOutcome, process_draw};
+
+    match process_draw(game, player, 1, is_first_draw, decision_maker) {
+        EventOutcome::Proceed(count) => count,
+        EventOutcome::Prevented | EventOutcome::Replaced | EventOutcome::NotApplicable => 0,
+    }
+}
+
 /// Executes the draw step for the active player with an explicit decision maker.
 pub fn execute_draw_step_with(
     game: &mut GameState,
-    decision_maker: &mut (impl DecisionMaker + ?Sized),
+    decision_maker: &mut dyn DecisionMaker,
 ) -> Vec<crate::triggers::TriggerEvent> {
     use crate::events::other::CardsDrawnEvent;
     use crate::triggers::TriggerEvent;
@@ -387,7 +433,8 @@ pub fn execute_draw_step_with(
     let mut draw_events = Vec::new();
 
     if can_draw {
-        let drawn = game.draw_cards_with_dm(active_player, 1, decision_maker);
+        let count = process_draw_step_draw(game, active_player, is_first_draw, decision_maker);
+        let drawn = game.draw_cards_with_dm(active_player, count as usize, decision_maker);
 
         // Track cards drawn this turn
         *game.cards_drawn_this_turn.entry(active_player).or_insert(0) += drawn.len() as u32;
@@ -442,7 +489,9 @@ pub fn apply_cleanup_discard(
     decision_maker: &mut impl DecisionMaker,
 ) -> Vec<crate::ids::ObjectId> {
     use crate::event_processor::execute_discard;
+    use crate::events::DiscardEvent;
     use crate::events::cause::EventCause;
+    use crate::triggers::TriggerEvent;
     use crate::zone::Zone;
 
     let mut madness_cards = Vec::new();
@@ -469,6 +518,17 @@ pub fn apply_cleanup_discard(
             decision_maker,
         );
 
+        if !result.prevented {
+            game.queue_trigger_event(
+                discard_provenance,
+                TriggerEvent::new_with_provenance(
+                    DiscardEvent::with_cause(card_id, active_player, cause.clone())
+                        .with_destination(result.final_zone),
+                    discard_provenance,
+                ),
+            );
+        }
+
         // Track cards that were exiled via Madness (can be cast from exile)
         if result.final_zone == Zone::Exile
             && let Some(new_id) = result.new_id
@@ -490,6 +550,17 @@ pub fn execute_cleanup_step(game: &mut GameState) {
         player.mana_pool.empty();
     }
 
+    end_of_turn_cleanup(game);
+
+    // Normally no priority during cleanup, but if triggers/SBAs happen, there's a new cleanup
+    game.turn.priority_player = None;
+}
+
+/// Removes marked damage and ends "until end of turn" effects (rule 514.2).
+///
+/// Every turn driver goes through [`execute_cleanup_step`], so this is the one
+/// place those durations expire.
+pub fn end_of_turn_cleanup(game: &mut GameState) {
     // Remove all damage marked on creatures and clear regeneration shields
     for &id in &game.battlefield.clone() {
         if !game.damage_persists.contains(&id) {
@@ -501,6 +572,7 @@ pub fn execute_cleanup_step(game: &mut GameState) {
     // Clear one-shot replacement effects (like regeneration shields)
     // These only last "until end of turn" per MTG rules
     game.replacement_effects.clear_one_shot_effects();
+    game.replacement_effects.clear_until_end_of_turn_effects();
 
     // Clean up expired grants (e.g., flashback from Snapcaster Mage)
     let turn_number = game.turn.turn_number;
@@ -511,13 +583,10 @@ pub fn execute_cleanup_step(game: &mut GameState) {
     game.cleanup_restrictions_end_of_turn();
     game.cleanup_granted_mana_abilities_end_of_turn();
 
-    // End "until end of turn" effects would happen here
-    // (Handled by continuous effect manager)
-    game.continuous_effects.cleanup_end_of_turn();
+    game.expire_duration(Until::EndOfTurn);
+    game.prevention_effects.cleanup_end_of_turn();
     game.cleanup_player_control_end_of_turn();
-
-    // Normally no priority during cleanup, but if triggers/SBAs happen, there's a new cleanup
-    game.turn.priority_player = None;
+    game.saddled_until_end_of_turn.clear();
 }
 
 /// Returns a human-readable description of the current phase/step.
@@ -760,4 +829,130 @@ mod tests {
             1
         );
     }
+
+    fn pump_until(game: &mut GameState, controller: PlayerId, creature: ObjectId, duration: Until) {
+        let source = game.new_object_id();
+        game.continuous_effects
+            .add_effect(crate::continuous::ContinuousEffect::pump(
+                source, controller, creature, 3, 3, duration,
+            ));
+    }
+
+    fn bears(game: &mut GameState, controller: PlayerId) -> ObjectId {
+        game.create_object_from_definition(
+            &crate::cards::definitions::grizzly_bears(),
+            controller,
+            Zone::Battlefield,
+        )
+    }
+
+    #[test]
+    fn end_of_turn_cleanup_ends_giant_growth_and_clears_damage() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bears = bears(&mut game, alice);
+        pump_until(&mut game, alice, bears, Until::EndOfTurn);
+        game.mark_damage(bears, 4);
+        assert_eq!(game.calculated_power(bears), Some(5));
+
+        execute_cleanup_step(&mut game);
+
+        assert_eq!(game.calculated_power(bears), Some(2));
+        assert_eq!(game.calculated_toughness(bears), Some(2));
+        assert_eq!(game.damage_on(bears), 0);
+    }
+
+    #[test]
+    fn until_your_next_turn_survives_opponents_turn_but_not_yours() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        game.turn.active_player = alice;
+        let bears = bears(&mut game, alice);
+        pump_until(&mut game, alice, bears, Until::YourNextTurn);
+
+        execute_cleanup_step(&mut game);
+        game.next_turn();
+        assert_eq!(game.turn.active_player, bob);
+        assert_eq!(game.calculated_power(bears), Some(5));
+
+        execute_cleanup_step(&mut game);
+        game.next_turn();
+        assert_eq!(game.turn.active_player, alice);
+        assert_eq!(game.calculated_power(bears), Some(2));
+    }
+
+    #[test]
+    fn phasing_creature_phases_out_with_attachments_and_back_in() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        game.turn.active_player = alice;
+        let ally = bears(&mut game, alice);
+        let phaser = bears(&mut game, alice);
+        let phasing_abilities = [
+            StaticAbility::phasing(),
+            StaticAbility::anthem(
+                crate::target::ObjectFilter::creature()
+                    .other()
+                    .you_control(),
+                1,
+                1,
+            ),
+        ];
+        game.object_mut(phaser)
+            .expect("phasing creature exists")
+            .abilities
+            .extend(phasing_abilities.map(Ability::static_ability));
+        let charm = create_artifact(&mut game, "Creature Charm", alice, Vec::new());
+        game.object_mut(charm).expect("charm exists").attached_to = Some(phaser);
+        game.object_mut(phaser)
+            .expect("phasing creature exists")
+            .attachments
+            .push(charm);
+        game.tap(phaser);
+        assert_eq!(game.calculated_power(ally), Some(3));
+
+        execute_untap_step(&mut game);
+
+        assert!(game.is_phased_out(phaser));
+        assert!(game.is_phased_out(charm), "attachments phase out too");
+        assert!(!game.battlefield.contains(&phaser));
+        assert!(!game.battlefield.contains(&charm));
+        assert_eq!(
+            game.object(phaser).map(|object| object.zone),
+            Some(Zone::Battlefield),
+            "phasing out doesn't leave the battlefield"
+        );
+        assert!(
+            game.pending_trigger_events.is_empty(),
+            "phasing out shouldn't produce zone change events"
+        );
+        assert!(game.is_tapped(phaser), "phased-out permanents don't untap");
+        assert_eq!(
+            game.calculated_power(ally),
+            Some(2),
+            "effects from phased-out permanents are suspended"
+        );
+
+        // Bob's untap step leaves Alice's permanents alone.
+        game.turn.active_player = PlayerId::from_index(1);
+        execute_untap_step(&mut game);
+        assert!(game.is_phased_out(phaser));
+
+        game.turn.active_player = alice;
+        execute_untap_step(&mut game);
+
+        assert!(
+            !game.is_phased_out(phaser),
+            "phasing in doesn't also phase it out again"
+        );
+        assert!(!game.is_phased_out(charm));
+        assert!(game.battlefield.contains(&phaser));
+        assert!(game.battlefield.contains(&charm));
+        assert!(
+            !game.is_tapped(phaser),
+            "phased-in permanents untap as usual"
+        );
+        assert_eq!(game.calculated_power(ally), Some(3));
+    }
 }
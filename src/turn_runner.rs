@@ -171,6 +171,9 @@ impl TurnRunner {
                 game.turn.phase = Phase::Beginning;
                 game.turn.step = Some(Step::Untap);
                 execute_untap_step(game);
+                game.expire_duration(crate::effect::Until::ControllersNextUntapStep);
+                // Untap-step triggers wait for the upkeep, ahead of its own triggers.
+                crate::game_loop::drain_pending_trigger_events(game, tq);
 
                 self.state = TurnState::Upkeep;
                 Ok(TurnAction::Continue)
@@ -413,6 +416,7 @@ impl TurnRunner {
 
             TurnState::EndCombatPriority => {
                 game.empty_mana_pools();
+                game.expire_duration(crate::effect::Until::EndOfCombat);
                 crate::combat_state::end_combat(&mut self.combat);
                 game.combat = Some(self.combat.clone());
                 self.state = TurnState::NextMain;
@@ -444,6 +448,7 @@ impl TurnRunner {
             TurnState::EndStep => {
                 game.turn.phase = Phase::Ending;
                 game.turn.step = Some(Step::End);
+                game.expire_duration(crate::effect::Until::YourNextEndStep);
                 game.turn.priority_player = Some(game.turn.active_player);
                 generate_and_queue_step_triggers(game, tq);
 
@@ -469,6 +474,8 @@ impl TurnRunner {
             }
 
             TurnState::CleanupRecursiveCheck => {
+                // Discards and other cleanup actions can trigger abilities (CR 514.3a).
+                crate::game_loop::drain_pending_trigger_events(game, tq);
                 let triggers_fired = !tq.is_empty();
                 let sbas_happened = !check_state_based_actions(game).is_empty();
 
@@ -479,6 +486,8 @@ impl TurnRunner {
                     }
                     put_triggers_on_stack(game, tq)?;
                     if !game.stack_is_empty() {
+                        // The active player receives priority (CR 514.3a).
+                        game.turn.priority_player = Some(game.turn.active_player);
                         self.state = TurnState::CleanupRecursivePriority;
                         Ok(TurnAction::RunPriority)
                     } else {
@@ -634,9 +643,17 @@ impl TurnRunner {
                     self.pending_commander_choice = Some(other);
                 }
                 None => {
-                    if let Some(card_id) = game
-                        .player(active_player)
-                        .and_then(|player| player.library.last().copied())
+                    let mut auto_dm = crate::decision::AutoPassDecisionMaker;
+                    let count = crate::turn::process_draw_step_draw(
+                        game,
+                        active_player,
+                        is_first_draw,
+                        &mut auto_dm,
+                    );
+                    if count > 0
+                        && let Some(card_id) = game
+                            .player(active_player)
+                            .and_then(|player| player.library.last().copied())
                     {
                         if game.is_commander(card_id) {
                             if let Some(obj) = game.object(card_id) {
@@ -976,6 +993,129 @@ mod tests {
         );
     }
 
+    /// Register a continuous effect, a prevention shield and a restriction,
+    /// all controlled by `controller` and lasting `duration`.
+    fn add_effects_lasting(
+        game: &mut GameState,
+        controller: PlayerId,
+        creature: ObjectId,
+        duration: crate::effect::Until,
+    ) {
+        let source = game.new_object_id();
+        game.continuous_effects
+            .add_effect(crate::continuous::ContinuousEffect::pump(
+                source,
+                controller,
+                creature,
+                -1,
+                0,
+                duration.clone(),
+            ));
+        game.prevention_effects
+            .add_shield(crate::prevention::PreventionShield::new(
+                source,
+                controller,
+                crate::prevention::PreventionTarget::You,
+                None,
+                duration.clone(),
+            ));
+        game.add_restriction_effect(
+            crate::effect::Restriction::GainLife(crate::target::PlayerFilter::You),
+            duration,
+            source,
+            controller,
+        );
+    }
+
+    fn lasting_effect_counts(game: &GameState) -> (usize, usize, usize) {
+        (
+            game.continuous_effects.effects().len(),
+            game.prevention_effects.shields().len(),
+            game.restriction_effects.len(),
+        )
+    }
+
+    #[test]
+    fn test_until_end_of_combat_effects_end_with_combat() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        let attacker = create_battlefield_creature(&mut game, alice, "Weakened Attacker");
+        add_effects_lasting(
+            &mut game,
+            alice,
+            attacker,
+            crate::effect::Until::EndOfCombat,
+        );
+        assert_eq!(game.calculated_power(attacker), Some(1));
+
+        game.turn.phase = Phase::Combat;
+        game.turn.step = Some(Step::CombatDamage);
+        runner.state = TurnState::EndCombat;
+        runner.advance(&mut game, &mut tq).unwrap();
+        assert_eq!(lasting_effect_counts(&game), (1, 1, 1));
+
+        runner.priority_done();
+        runner.advance(&mut game, &mut tq).unwrap();
+        assert_eq!(lasting_effect_counts(&game), (0, 0, 0));
+        assert_eq!(game.calculated_power(attacker), Some(2));
+    }
+
+    #[test]
+    fn test_until_your_next_turn_effects_end_when_your_turn_begins() {
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let creature = create_battlefield_creature(&mut game, bob, "Goaded Bear");
+        game.turn.active_player = alice;
+        add_effects_lasting(
+            &mut game,
+            alice,
+            creature,
+            crate::effect::Until::YourNextTurn,
+        );
+        let source = game.new_object_id();
+        game.add_goad_effect(creature, alice, crate::effect::Until::YourNextTurn, source);
+
+        game.next_turn();
+        assert_eq!(game.turn.active_player, bob);
+        assert_eq!(lasting_effect_counts(&game), (1, 1, 1));
+        assert!(game.is_goaded(creature));
+
+        game.next_turn();
+        assert_eq!(game.turn.active_player, alice);
+        assert_eq!(lasting_effect_counts(&game), (0, 0, 0));
+        assert!(!game.is_goaded(creature));
+    }
+
+    #[test]
+    fn test_until_your_next_end_step_effects_end_as_your_end_step_begins() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let creature = create_battlefield_creature(&mut game, alice, "Lingering Bear");
+        add_effects_lasting(
+            &mut game,
+            alice,
+            creature,
+            crate::effect::Until::YourNextEndStep,
+        );
+
+        game.turn.active_player = bob;
+        let mut runner = TurnRunner::new();
+        runner.state = TurnState::EndStep;
+        runner.advance(&mut game, &mut tq).unwrap();
+        assert_eq!(lasting_effect_counts(&game), (1, 1, 1));
+
+        game.turn.active_player = alice;
+        let mut runner = TurnRunner::new();
+        runner.state = TurnState::EndStep;
+        runner.advance(&mut game, &mut tq).unwrap();
+        assert_eq!(lasting_effect_counts(&game), (0, 0, 0));
+    }
+
     #[test]
     fn test_turn_runner_pauses_for_drawn_commander_choice() {
         let mut game = setup_game();
@@ -1034,4 +1174,294 @@ mod tests {
         assert!(matches!(action, RunnerProgress::Complete(())));
         assert_eq!(game.objects_in_zone(crate::zone::Zone::Command).len(), 1);
     }
+
+    fn fill_hand(game: &mut GameState, player: PlayerId, count: usize) {
+        for idx in 0..count {
+            let card = CardBuilder::new(CardId::new(), format!("Cleanup Card {idx}"))
+                .card_types(vec![CardType::Sorcery])
+                .build();
+            game.create_object_from_card(&card, player, Zone::Hand);
+        }
+    }
+
+    /// Drive the runner from the start of cleanup to the end of the turn,
+    /// discarding the first cards offered. Returns how many priority windows
+    /// were opened during cleanup.
+    fn run_cleanup(game: &mut GameState, tq: &mut TriggerQueue, runner: &mut TurnRunner) -> usize {
+        let mut priority_windows = 0;
+        runner.state = TurnState::EndStepPriority;
+        for _ in 0..50 {
+            match runner.advance(game, tq).unwrap() {
+                TurnAction::Continue => {}
+                TurnAction::RunPriority => {
+                    priority_windows += 1;
+                    let mut dm = crate::decision::AutoPassDecisionMaker;
+                    crate::game_loop::run_priority_loop_with(game, tq, &mut dm).unwrap();
+                    runner.priority_done();
+                }
+                TurnAction::Decision(DecisionContext::SelectObjects(ctx)) => {
+                    runner.respond_discard(
+                        ctx.candidates
+                            .iter()
+                            .take(ctx.min)
+                            .map(|candidate| candidate.id)
+                            .collect(),
+                    );
+                }
+                TurnAction::Decision(other) => panic!("unexpected cleanup decision: {other:?}"),
+                TurnAction::TurnComplete | TurnAction::GameOver(_) => return priority_windows,
+            }
+        }
+        panic!("cleanup did not complete within 50 iterations");
+    }
+
+    #[test]
+    fn test_cleanup_discards_down_to_maximum_hand_size() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        fill_hand(&mut game, alice, 9);
+
+        let priority_windows = run_cleanup(&mut game, &mut tq, &mut runner);
+
+        let player = game.player(alice).unwrap();
+        assert_eq!(player.hand.len(), 7);
+        assert_eq!(player.graveyard.len(), 2);
+        assert_eq!(priority_windows, 0, "cleanup normally grants no priority");
+        assert!(matches!(runner.state(), TurnState::Complete));
+    }
+
+    #[test]
+    fn test_cleanup_discard_exiles_madness_cards() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        let temper = crate::cards::CardDefinitionBuilder::new(CardId::new(), "Fiery Temper")
+            .card_types(vec![CardType::Instant])
+            .parse_text("Fiery Temper deals 3 damage to any target.\nMadness {R}")
+            .expect("madness card should parse");
+        game.create_object_from_definition(&temper, alice, Zone::Hand);
+        fill_hand(&mut game, alice, 7);
+
+        run_cleanup(&mut game, &mut tq, &mut runner);
+
+        let player = game.player(alice).unwrap();
+        assert_eq!(player.hand.len(), 7);
+        let exiled = game
+            .exile
+            .iter()
+            .copied()
+            .find(|&id| {
+                game.object(id)
+                    .is_some_and(|obj| obj.name == "Fiery Temper")
+            })
+            .expect("madness should exile the discarded card instead");
+        assert!(game.is_madness_exiled(exiled));
+    }
+
+    #[test]
+    fn test_library_of_leng_does_not_apply_to_cleanup_discard() {
+        // Leng only replaces discards an effect causes. The hand-size discard
+        // is a game rule (rule 514.1), so it always goes to the graveyard.
+        struct PutOnLibrary;
+        impl crate::decision::DecisionMaker for PutOnLibrary {
+            fn decide_options(
+                &mut self,
+                _game: &GameState,
+                _ctx: &crate::decisions::context::SelectOptionsContext,
+            ) -> Vec<usize> {
+                vec![1]
+            }
+        }
+
+        let mut game = setup_game();
+        let alice = PlayerId::from_index(0);
+        // Only Leng's replacement, so Alice keeps her maximum hand size.
+        let leng_replacement =
+            crate::cards::CardDefinitionBuilder::new(CardId::new(), "Leng Shelf")
+                .card_types(vec![CardType::Artifact])
+                .parse_text(
+                    "If an effect causes you to discard a card, you may put it on top of \
+                 your library instead of into your graveyard.",
+                )
+                .expect("discard replacement should parse");
+        let shelf = game.create_object_from_definition(&leng_replacement, alice, Zone::Battlefield);
+        fill_hand(&mut game, alice, 9);
+
+        let (player, _) =
+            crate::turn::get_cleanup_discard_spec(&game).expect("alice is over her hand size");
+        assert_eq!(player, alice);
+        let hand = game.player(alice).unwrap().hand.clone();
+        crate::turn::apply_cleanup_discard(&mut game, &hand[..2], &mut PutOnLibrary);
+
+        let player = game.player(alice).unwrap();
+        assert_eq!(player.hand.len(), 7);
+        assert_eq!(player.graveyard.len(), 2);
+        assert!(player.library.is_empty());
+
+        // The same choice does put an effect's discard on top of the library.
+        let card = game.player(alice).unwrap().hand[0];
+        let provenance = game
+            .provenance_graph
+            .alloc_root_event(crate::events::EventKind::Discard);
+        crate::event_processor::execute_discard(
+            &mut game,
+            card,
+            alice,
+            crate::events::cause::EventCause::from_effect(shelf, alice),
+            false,
+            provenance,
+            &mut PutOnLibrary,
+        );
+        assert_eq!(game.player(alice).unwrap().library.len(), 1);
+    }
+
+    #[test]
+    fn test_cleanup_skips_discard_with_no_maximum_hand_size() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        game.create_object_from_definition(
+            &crate::cards::definitions::library_of_leng(),
+            alice,
+            Zone::Battlefield,
+        );
+        game.update_cant_effects();
+        fill_hand(&mut game, alice, 9);
+
+        run_cleanup(&mut game, &mut tq, &mut runner);
+
+        assert_eq!(game.player(alice).unwrap().hand.len(), 9);
+    }
+
+    #[test]
+    fn test_cleanup_removes_damage_and_until_end_of_turn_effects() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        let bears = create_battlefield_creature(&mut game, alice, "Cleanup Bears");
+
+        let growth = game.create_object_from_definition(
+            &crate::cards::definitions::giant_growth(),
+            alice,
+            Zone::Stack,
+        );
+        game.stack.push(
+            crate::game_state::StackEntry::new(growth, alice)
+                .with_targets(vec![crate::game_state::Target::Object(bears)]),
+        );
+        crate::game_loop::resolve_stack_entry(&mut game).unwrap();
+        game.mark_damage(bears, 4);
+        assert_eq!(game.calculated_toughness(bears), Some(5));
+
+        run_cleanup(&mut game, &mut tq, &mut runner);
+
+        assert!(game.battlefield.contains(&bears));
+        assert_eq!(game.damage_on(bears), 0);
+        assert_eq!(game.calculated_toughness(bears), Some(2));
+    }
+
+    #[test]
+    fn test_cleanup_discard_trigger_grants_priority_and_repeats_cleanup() {
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let punisher = crate::cards::CardDefinitionBuilder::new(CardId::new(), "Discard Punisher")
+            .card_types(vec![CardType::Enchantment])
+            .parse_text("Whenever you discard a card, each opponent loses 1 life.")
+            .expect("discard trigger should parse");
+        game.create_object_from_definition(&punisher, alice, Zone::Battlefield);
+        fill_hand(&mut game, alice, 9);
+
+        let priority_windows = run_cleanup(&mut game, &mut tq, &mut runner);
+
+        assert_eq!(game.player(alice).unwrap().hand.len(), 7);
+        assert_eq!(game.player(bob).unwrap().life, 18);
+        assert_eq!(
+            priority_windows, 1,
+            "the discard triggers should open one priority window before a second cleanup"
+        );
+        assert!(game.stack_is_empty());
+        assert!(matches!(runner.state(), TurnState::Complete));
+    }
+
+    #[test]
+    fn untap_step_inspired_triggers_resolve_in_upkeep_in_apnap_order() {
+        use crate::cards::CardDefinitionBuilder;
+        use crate::object::CounterType;
+
+        let mut game = setup_game();
+        let mut tq = TriggerQueue::new();
+        let mut runner = TurnRunner::new();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+
+        let inspired = CardDefinitionBuilder::new(CardId::new(), "Inspired Adept")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(2, 1))
+            .parse_text("Whenever this creature becomes untapped, you gain 1 life.")
+            .unwrap();
+        let first = game.create_object_from_definition(&inspired, alice, Zone::Battlefield);
+        let second = game.create_object_from_definition(&inspired, alice, Zone::Battlefield);
+        let stunned = game.create_object_from_definition(&inspired, alice, Zone::Battlefield);
+        for id in [first, second, stunned] {
+            game.tap(id);
+        }
+        game.object_mut(stunned)
+            .unwrap()
+            .add_counters(CounterType::Stun, 1);
+
+        let watcher = CardDefinitionBuilder::new(CardId::new(), "Upkeep Watcher")
+            .card_types(vec![CardType::Creature])
+            .power_toughness(PowerToughness::fixed(1, 1))
+            .parse_text("At the beginning of each upkeep, you lose 1 life.")
+            .unwrap();
+        game.create_object_from_definition(&watcher, bob, Zone::Battlefield);
+
+        // The untap step untaps both creatures at once; the stun counter is
+        // removed instead of untapping the third, which doesn't trigger.
+        runner.advance(&mut game, &mut tq).unwrap();
+        assert!(!game.is_tapped(first) && !game.is_tapped(second));
+        assert!(game.is_tapped(stunned));
+        assert_eq!(
+            game.object(stunned)
+                .unwrap()
+                .counters
+                .get(&CounterType::Stun),
+            None
+        );
+        assert!(
+            game.stack_is_empty(),
+            "no one gets priority in the untap step"
+        );
+        let sources: Vec<_> = tq.entries.iter().map(|entry| entry.source).collect();
+        assert_eq!(sources.len(), 2);
+        assert!(sources.contains(&first) && sources.contains(&second));
+
+        // Upkeep triggers join the batch after the untap triggers.
+        assert!(matches!(
+            runner.advance(&mut game, &mut tq).unwrap(),
+            TurnAction::RunPriority
+        ));
+        assert_eq!(tq.entries.len(), 3);
+        assert_eq!(tq.entries[2].controller, bob);
+
+        // APNAP: Alice's triggers go on the stack first, Bob's on top.
+        let mut dm = crate::decision::AutoPassDecisionMaker;
+        crate::game_loop::advance_priority_with_dm(&mut game, &mut tq, &mut dm).unwrap();
+        let controllers: Vec<_> = game.stack.iter().map(|entry| entry.controller).collect();
+        assert_eq!(controllers, vec![alice, alice, bob]);
+
+        crate::game_loop::run_priority_loop_with(&mut game, &mut tq, &mut dm).unwrap();
+        assert_eq!(game.turn.step, Some(Step::Upkeep));
+        assert!(game.stack_is_empty());
+        assert_eq!(game.player(alice).unwrap().life, 22);
+        assert_eq!(game.player(bob).unwrap().life, 19);
+    }
 }
@@ -1,4 +1,8 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Supertype {
     Basic,
     Legendary,
@@ -24,6 +28,10 @@ impl std::fmt::Display for Supertype {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum CardType {
     Land,
     Creature,
@@ -102,6 +110,10 @@ impl std::fmt::Display for CardType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Subtype {
     // Basic land types
     Plains,
@@ -320,6 +332,7 @@ pub enum Subtype {
     Zubera,
 
     // Artifact subtypes
+    Blood,
     Clue,
     Contraption,
     Equipment,
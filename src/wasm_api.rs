@@ -19,14 +19,14 @@ use crate::decision::{
     AttackerDeclaration, BlockerDeclaration, DecisionMaker, GameProgress, GameResult, LegalAction,
 };
 use crate::decisions::context::DecisionContext;
+use crate::display_text::DisplayKey;
+use crate::engine_handle::DecisionResponse;
 use crate::game_loop::{
     ActivationStage, CastStage, PendingPriorityContinuation, PriorityLoopState, PriorityResponse,
     advance_priority_with_dm, apply_decision_context_with_dm, apply_priority_response_with_dm,
 };
 use crate::game_state::{GameState, Target};
-use crate::ids::{
-    ObjectId, PlayerId, reset_runtime_id_counters, restore_id_counters, snapshot_id_counters,
-};
+use crate::ids::{ObjectId, PlayerId};
 use crate::mana::ManaSymbol;
 use crate::targeting::{normalize_targets_for_requirements, validate_flat_target_assignment};
 use crate::triggers::TriggerQueue;
@@ -386,6 +386,7 @@ struct ViewedCardsSnapshot {
 #[derive(Debug, Clone, Serialize)]
 struct ViewedCardSnapshot {
     id: u64,
+    stable_id: Option<u64>,
     name: String,
 }
 
@@ -426,6 +427,28 @@ struct StackObjectSnapshot {
     targets: Vec<TargetChoiceView>,
 }
 
+fn hand_card_snapshot(o: &crate::object::Object) -> HandCardSnapshot {
+    let mana_cost = o.mana_cost.as_ref().map(|mc| mc.to_oracle());
+    let power_toughness = match (o.power(), o.toughness()) {
+        (Some(p), Some(t)) => Some(format!("{p}/{t}")),
+        _ => None,
+    };
+    HandCardSnapshot {
+        id: o.id.0,
+        stable_id: o.stable_id.0.0,
+        name: o.name.clone(),
+        mana_cost,
+        power_toughness,
+        loyalty: o.loyalty(),
+        defense: o.defense(),
+        card_types: o
+            .card_types
+            .iter()
+            .map(|ct| ct.name().to_string())
+            .collect(),
+    }
+}
+
 fn build_stack_object_snapshot(
     game: &GameState,
     perspective: PlayerId,
@@ -691,7 +714,7 @@ fn merge_active_viewed_cards(
         existing.public == ctx.public
             && existing.zone == ctx.zone
             && existing.source == ctx.source
-            && existing.description == ctx.description
+            && existing.description == ctx.description.display()
             && if existing.zone == Zone::Hand {
                 existing.subject == ctx.subject
             } else if ctx.public {
@@ -719,7 +742,7 @@ fn merge_active_viewed_cards(
         cards: cards.to_vec(),
         public: ctx.public,
         source: ctx.source,
-        description: ctx.description.clone(),
+        description: ctx.description.display(),
     });
 }
 
@@ -860,27 +883,7 @@ impl GameSnapshot {
                                         .is_some_and(|visible_ids| visible_ids.contains(id))
                             })
                             .filter_map(|id| game.object(*id))
-                            .map(|o| {
-                                let mana_cost = o.mana_cost.as_ref().map(|mc| mc.to_oracle());
-                                let power_toughness = match (o.power(), o.toughness()) {
-                                    (Some(p), Some(t)) => Some(format!("{p}/{t}")),
-                                    _ => None,
-                                };
-                                HandCardSnapshot {
-                                    id: o.id.0,
-                                    stable_id: o.stable_id.0.0,
-                                    name: o.name.clone(),
-                                    mana_cost,
-                                    power_toughness,
-                                    loyalty: o.loyalty(),
-                                    defense: o.defense(),
-                                    card_types: o
-                                        .card_types
-                                        .iter()
-                                        .map(|ct| ct.name().to_string())
-                                        .collect(),
-                                }
-                            })
+                            .map(hand_card_snapshot)
                             .collect()
                     } else {
                         Vec::new()
@@ -1058,12 +1061,15 @@ impl GameSnapshot {
                     cards: view
                         .cards
                         .iter()
-                        .map(|id| ViewedCardSnapshot {
-                            id: id.0,
-                            name: game
-                                .object(*id)
-                                .map(|obj| obj.name.clone())
-                                .unwrap_or_else(|| format!("Card #{}", id.0)),
+                        .map(|id| {
+                            let obj = game.object(*id);
+                            ViewedCardSnapshot {
+                                id: id.0,
+                                stable_id: obj.map(|obj| obj.stable_id.0.0),
+                                name: obj
+                                    .map(|obj| obj.name.clone())
+                                    .unwrap_or_else(|| format!("Card #{}", id.0)),
+                            }
                         })
                         .collect(),
                     card_ids: view.cards.iter().map(|id| id.0).collect(),
@@ -1078,6 +1084,103 @@ impl GameSnapshot {
             undo_land_stable_id,
         }
     }
+
+    /// Reveal or redact hidden information for the given view.
+    ///
+    /// `from_game` already limits hands to the perspective player (plus any
+    /// revealed cards). A player view further drops library contents and
+    /// hides face-down cards that player may not look at; the full view shows
+    /// every hand instead.
+    fn apply_visibility(
+        &mut self,
+        game: &GameState,
+        visibility: ViewVisibility,
+        viewed_cards: Option<&ActiveViewedCards>,
+    ) {
+        let viewer = match visibility {
+            ViewVisibility::Full => {
+                for snapshot in &mut self.players {
+                    let Some(player) = game.player(PlayerId::from_index(snapshot.id)) else {
+                        continue;
+                    };
+                    snapshot.can_view_hand = true;
+                    snapshot.hand_cards = player
+                        .hand
+                        .iter()
+                        .rev()
+                        .filter_map(|id| game.object(*id))
+                        .map(hand_card_snapshot)
+                        .collect();
+                }
+                return;
+            }
+            ViewVisibility::Player(viewer) => viewer,
+        };
+
+        let revealed = |id: ObjectId| {
+            viewed_cards.is_some_and(|view| {
+                (view.public || view.viewer == viewer) && view.cards.contains(&id)
+            })
+        };
+        let visible = |id: u64| {
+            game.can_see_object(ObjectId::from_raw(id), Some(viewer))
+                || revealed(ObjectId::from_raw(id))
+        };
+
+        for snapshot in &mut self.players {
+            let library_top = game
+                .player(PlayerId::from_index(snapshot.id))
+                .and_then(|player| player.library.last().copied());
+            if !library_top.is_some_and(revealed) {
+                snapshot.library_top = None;
+            }
+            for card in snapshot
+                .graveyard_cards
+                .iter_mut()
+                .chain(snapshot.exile_cards.iter_mut())
+                .chain(snapshot.command_cards.iter_mut())
+            {
+                if !visible(card.id) {
+                    card.name = HIDDEN_CARD_NAME.to_string();
+                }
+            }
+            for permanent in &mut snapshot.battlefield {
+                if !visible(permanent.id) {
+                    permanent.name = HIDDEN_CARD_NAME.to_string();
+                    permanent.mana_cost = None;
+                }
+            }
+        }
+
+        for (preview, object) in self
+            .stack_preview
+            .iter_mut()
+            .zip(self.stack_objects.iter_mut())
+        {
+            if object.ability_kind.is_none()
+                && game.object(ObjectId::from_raw(object.id)).is_some()
+                && !visible(object.id)
+            {
+                *preview = HIDDEN_CARD_NAME.to_string();
+                object.name = HIDDEN_CARD_NAME.to_string();
+                object.mana_cost = None;
+                object.effect_text = None;
+            }
+        }
+    }
+}
+
+/// Name shown in place of a card the viewer isn't allowed to see.
+const HIDDEN_CARD_NAME: &str = "Face-down card";
+
+/// Which hidden information a game view includes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewVisibility {
+    /// What one seat may know: other hands as sizes only, no library
+    /// contents, and face-down cards hidden unless that player may look.
+    Player(PlayerId),
+    /// Everything, for local hot-seat play and debugging.
+    Full,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1333,7 +1436,7 @@ impl DecisionView {
         match ctx {
             DecisionContext::Boolean(boolean) => DecisionView::SelectOptions {
                 player: boolean.player.0,
-                description: boolean.description.clone(),
+                description: boolean.description.display(),
                 min: 1,
                 max: 1,
                 options: vec![
@@ -1375,7 +1478,7 @@ impl DecisionView {
             },
             DecisionContext::Number(number) => DecisionView::Number {
                 player: number.player.0,
-                description: number.description.clone(),
+                description: number.description.display(),
                 min: number.min,
                 max: number.max,
                 is_x_value: number.is_x_value,
@@ -1387,14 +1490,14 @@ impl DecisionView {
             },
             DecisionContext::SelectOptions(options) => DecisionView::SelectOptions {
                 player: options.player.0,
-                description: options.description.clone(),
+                description: options.description.display(),
                 min: options.min,
                 max: options.max,
                 options: {
-                    let is_optional_cost_choice = options
-                        .description
-                        .to_ascii_lowercase()
-                        .contains("optional cost");
+                    let is_optional_cost_choice = matches!(
+                        options.description.key,
+                        DisplayKey::ChooseOptionalCostsFor | DisplayKey::ChooseOptionalCostsToPay
+                    );
                     options
                         .options
                         .iter()
@@ -1488,7 +1591,7 @@ impl DecisionView {
             },
             DecisionContext::Order(order) => DecisionView::SelectOptions {
                 player: order.player.0,
-                description: order.description.clone(),
+                description: order.description.display(),
                 min: order.items.len(),
                 max: order.items.len(),
                 options: order
@@ -1586,7 +1689,7 @@ impl DecisionView {
                 let repeatable_colors = !colors.same_color && colors.count > 1;
                 DecisionView::SelectOptions {
                     player: colors.player.0,
-                    description: colors.description.clone(),
+                    description: colors.description.display(),
                     min: if colors.count == 0 { 0 } else { 1 },
                     max: if colors.same_color {
                         1
@@ -1712,7 +1815,7 @@ impl DecisionView {
             },
             DecisionContext::SelectObjects(objects) => DecisionView::SelectObjects {
                 player: objects.player.0,
-                description: objects.description.clone(),
+                description: objects.description.display(),
                 min: objects.min,
                 max: objects.max,
                 candidates: objects
@@ -1745,12 +1848,12 @@ impl DecisionView {
             },
             DecisionContext::Targets(targets) => DecisionView::Targets {
                 player: targets.player.0,
-                context: targets.context.clone(),
+                context: targets.context.display(),
                 requirements: targets
                     .requirements
                     .iter()
                     .map(|req| TargetRequirementView {
-                        description: req.description.clone(),
+                        description: req.description.display(),
                         min_targets: req.min_targets,
                         max_targets: req.max_targets,
                         legal_targets: req
@@ -1891,6 +1994,15 @@ struct BlockerDeclarationInput {
     blocking: u64,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct PendingDecisionJson<'a> {
+    player: u8,
+    kind: &'static str,
+    prompt: Option<String>,
+    action_labels: Vec<String>,
+    context: &'a DecisionContext,
+}
+
 #[derive(Debug, Clone)]
 enum ReplayDecisionAnswer {
     Boolean(bool),
@@ -1915,7 +2027,6 @@ struct ReplayCheckpoint {
     trigger_queue: TriggerQueue,
     priority_state: PriorityLoopState,
     game_over: Option<GameResult>,
-    id_counters: crate::ids::IdCountersSnapshot,
     /// Diagnostic tag identifying where this checkpoint was captured.
     diag_tag: &'static str,
 }
@@ -3193,11 +3304,147 @@ impl WasmGame {
         self.snapshot()
     }
 
+    /// Return the pending decision as structured data, or `null` when the
+    /// engine isn't waiting on anyone.
+    ///
+    /// The payload carries the deciding player, the decision kind, the
+    /// rendered prompt, and the full `DecisionContext` (object/player ids,
+    /// names, and display text). Priority decisions also list a readable label
+    /// for each legal action, in the same order as `context.actions`.
+    #[wasm_bindgen(js_name = nextDecision)]
+    pub fn next_decision(&self) -> Result<JsValue, JsValue> {
+        let Some(decision) = self.pending_decision_json() else {
+            return Ok(JsValue::NULL);
+        };
+        serde_wasm_bindgen::to_value(&decision)
+            .map_err(|e| JsValue::from_str(&format!("nextDecision encode failed: {e}")))
+    }
+
+    /// Answer the pending decision with a typed `DecisionResponse`.
+    ///
+    /// The response is translated to the matching UI command and goes through
+    /// the same validation as `dispatch`, so an illegal or mismatched answer
+    /// is rejected with an error and leaves the decision pending.
+    #[wasm_bindgen(js_name = submitResponse)]
+    pub fn submit_response(&mut self, response: JsValue) -> Result<(), JsValue> {
+        let response: DecisionResponse = serde_wasm_bindgen::from_value(response)
+            .map_err(|e| JsValue::from_str(&format!("invalid response payload: {e}")))?;
+        self.respond(response)
+    }
+
+    /// Return the game as seen by one player, as JSON.
+    ///
+    /// This is the `viewFor` view: hidden zones are redacted for that player
+    /// and the pending decision is only included if it is theirs. Unlike
+    /// `snapshot`, this does not consume queued UI transitions.
+    #[wasm_bindgen(js_name = stateJson)]
+    pub fn state_json(&self, player_index: u8) -> Result<String, JsValue> {
+        let snap =
+            self.view_snapshot(ViewVisibility::Player(PlayerId::from_index(player_index)))?;
+        serde_json::to_string(&snap)
+            .map_err(|e| JsValue::from_str(&format!("stateJson encode failed: {e}")))
+    }
+
+    /// Return the game as one player may see it.
+    ///
+    /// Opponents' hands are reduced to their sizes, library contents are
+    /// omitted, and face-down cards are hidden unless this player may look at
+    /// them. Cards revealed to this player stay visible.
+    #[wasm_bindgen(js_name = viewFor)]
+    pub fn view_for(&self, player_index: u8) -> Result<JsValue, JsValue> {
+        let snap =
+            self.view_snapshot(ViewVisibility::Player(PlayerId::from_index(player_index)))?;
+        serde_wasm_bindgen::to_value(&snap)
+            .map_err(|e| JsValue::from_str(&format!("viewFor encode failed: {e}")))
+    }
+
+    /// Return the game with all hidden information shown, for local
+    /// hot-seat play and debugging.
+    #[wasm_bindgen(js_name = fullView)]
+    pub fn full_view(&self) -> Result<JsValue, JsValue> {
+        let snap = self.view_snapshot(ViewVisibility::Full)?;
+        serde_wasm_bindgen::to_value(&snap)
+            .map_err(|e| JsValue::from_str(&format!("fullView encode failed: {e}")))
+    }
+
     /// Apply a player command for the currently pending decision.
     #[wasm_bindgen]
     pub fn dispatch(&mut self, command: JsValue) -> Result<JsValue, JsValue> {
         let command: UiCommand = serde_wasm_bindgen::from_value(command)
             .map_err(|e| JsValue::from_str(&format!("invalid command payload: {e}")))?;
+        self.dispatch_command(command)?;
+        self.snapshot()
+    }
+}
+
+impl WasmGame {
+    fn pending_decision_json(&self) -> Option<PendingDecisionJson<'_>> {
+        let ctx = self.pending_decision.as_ref()?;
+        let action_labels = match ctx {
+            DecisionContext::Priority(priority) => priority
+                .actions
+                .iter()
+                .map(|action| describe_action(&self.game, action))
+                .collect(),
+            _ => Vec::new(),
+        };
+        Some(PendingDecisionJson {
+            player: ctx.player().0,
+            kind: decision_context_kind(ctx),
+            prompt: ctx.prompt_text(),
+            action_labels,
+            context: ctx,
+        })
+    }
+
+    fn respond(&mut self, response: DecisionResponse) -> Result<(), JsValue> {
+        let ctx = self
+            .pending_decision
+            .as_ref()
+            .ok_or_else(|| JsValue::from_str("no pending decision to respond to"))?;
+        let command = ui_command_from_response(ctx, response)?;
+        self.dispatch_command(command)
+    }
+
+    fn view_snapshot(&self, visibility: ViewVisibility) -> Result<GameSnapshot, JsValue> {
+        let viewer = match visibility {
+            ViewVisibility::Player(viewer) => {
+                if self.game.player(viewer).is_none() {
+                    return Err(JsValue::from_str(&format!(
+                        "unknown player index: {}",
+                        viewer.0
+                    )));
+                }
+                viewer
+            }
+            ViewVisibility::Full => self.perspective,
+        };
+        let decision = self
+            .pending_decision
+            .as_ref()
+            .filter(|ctx| visibility == ViewVisibility::Full || ctx.player() == viewer);
+        let mut snap = GameSnapshot::from_game(
+            &self.game,
+            viewer,
+            decision,
+            decision.and_then(|_| self.current_mana_payment_view()),
+            self.game_over.as_ref(),
+            self.priority_state
+                .pending_cast
+                .as_ref()
+                .map(|pending| pending.stack_id),
+            self.active_resolving_stack_object.clone(),
+            Vec::new(),
+            self.active_viewed_cards.as_ref(),
+            false,
+            None,
+            self.snapshot_serial,
+        );
+        snap.apply_visibility(&self.game, visibility, self.active_viewed_cards.as_ref());
+        Ok(snap)
+    }
+
+    fn dispatch_command(&mut self, command: UiCommand) -> Result<(), JsValue> {
         self.clear_active_resolving_stack_object();
 
         let pending_ctx = self
@@ -3253,7 +3500,7 @@ impl WasmGame {
                         }
                         self.pending_decision = Some(next_ctx);
                         self.pending_replay_action = Some(replay);
-                        return self.snapshot();
+                        return Ok(());
                     }
                     Ok(ReplayOutcome::Complete(progress)) => progress,
                     Err(err) => {
@@ -3290,7 +3537,7 @@ impl WasmGame {
                     }
                     self.pending_decision = Some(next_ctx);
                     self.pending_replay_action = Some(replay);
-                    return self.snapshot();
+                    return Ok(());
                 }
 
                 match result {
@@ -3313,7 +3560,7 @@ impl WasmGame {
                         }
                         self.pending_decision = Some(next_ctx);
                         self.pending_replay_action = Some(replay);
-                        return self.snapshot();
+                        return Ok(());
                     }
 
                     // The spell/ability is now committed. Follow-up prompts
@@ -3322,7 +3569,7 @@ impl WasmGame {
                     self.pending_action_checkpoint = None;
                     self.pending_decision = Some(next_ctx);
                     self.pending_replay_action = Some(replay);
-                    self.snapshot()
+                    Ok(())
                 }
                 progress => {
                     self.clear_active_resolving_stack_object();
@@ -3342,7 +3589,7 @@ impl WasmGame {
                     self.pending_action_checkpoint = None;
                     self.pending_replay_action = None;
                     self.apply_progress(progress)?;
-                    self.snapshot()
+                    Ok(())
                 }
             }
         } else {
@@ -3378,7 +3625,7 @@ impl WasmGame {
                         root,
                         nested_answers: Vec::new(),
                     });
-                    self.snapshot()
+                    Ok(())
                 }
                 ReplayOutcome::Complete(progress) => {
                     match progress {
@@ -3394,7 +3641,7 @@ impl WasmGame {
                                     root,
                                     nested_answers: Vec::new(),
                                 });
-                                return self.snapshot();
+                                return Ok(());
                             }
 
                             // The spell/ability is now committed. Follow-up prompts
@@ -3407,7 +3654,7 @@ impl WasmGame {
                                 root,
                                 nested_answers: Vec::new(),
                             });
-                            self.snapshot()
+                            Ok(())
                         }
                         progress => {
                             self.clear_active_resolving_stack_object();
@@ -3427,7 +3674,7 @@ impl WasmGame {
                             self.pending_action_checkpoint = None;
                             self.pending_replay_action = None;
                             self.apply_progress(progress)?;
-                            self.snapshot()
+                            Ok(())
                         }
                     }
                 }
@@ -3584,7 +3831,9 @@ impl WasmGame {
                 .is_some_and(|pending| {
                     matches!(
                         pending.stage,
-                        CastStage::ChoosingSacrifice | CastStage::ChoosingCardCost
+                        CastStage::ChoosingSacrifice
+                            | CastStage::ChoosingCardCost
+                            | CastStage::ChoosingDelve
                     )
                 })
     }
@@ -4308,7 +4557,6 @@ impl WasmGame {
     }
 
     fn initialize_empty_match(&mut self, player_names: Vec<String>, starting_life: i32, seed: u64) {
-        reset_runtime_id_counters();
         self.game = GameState::new(player_names, starting_life);
         self.game.set_random_seed(seed);
         self.match_format = MatchFormatInput::Normal;
@@ -4861,9 +5109,9 @@ impl WasmGame {
         &mut self,
         pending_ctx: DecisionContext,
         command: UiCommand,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<(), JsValue> {
         let restore =
-            |this: &mut Self, ctx: DecisionContext, err: JsValue| -> Result<JsValue, JsValue> {
+            |this: &mut Self, ctx: DecisionContext, err: JsValue| -> Result<(), JsValue> {
                 this.pending_decision = Some(ctx);
                 Err(err)
             };
@@ -5062,7 +5310,7 @@ impl WasmGame {
 
         self.pending_decision = None;
         self.advance_until_decision()?;
-        self.snapshot()
+        Ok(())
     }
 
     fn finish_match_setup(&mut self, opening_hand_size: usize) -> Result<(), JsValue> {
@@ -5353,7 +5601,7 @@ impl WasmGame {
         &mut self,
         pending_ctx: DecisionContext,
         command: UiCommand,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<(), JsValue> {
         let _runner = self.runner.as_mut().ok_or_else(|| {
             // Restore decision on structural error so UI can retry.
             self.pending_decision = Some(pending_ctx.clone());
@@ -5415,7 +5663,7 @@ impl WasmGame {
         // We're no longer awaiting priority (runner will handle the next steps).
         self.runner_awaiting_priority = false;
         self.advance_until_decision()?;
-        self.snapshot()
+        Ok(())
     }
 
     fn finish_live_priority_dispatch(
@@ -5423,7 +5671,7 @@ impl WasmGame {
         progress: GameProgress,
         action_checkpoint: Option<ReplayCheckpoint>,
         resolving_checkpoint: Option<ReplayCheckpoint>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<(), JsValue> {
         match progress {
             GameProgress::NeedsDecisionCtx(next_ctx) => {
                 let action_still_pending = self.priority_action_chain_still_pending();
@@ -5463,7 +5711,7 @@ impl WasmGame {
                     self.pending_replay_action = None;
                 }
                 self.pending_decision = Some(next_ctx);
-                self.snapshot()
+                Ok(())
             }
             progress => {
                 self.active_viewed_cards = None;
@@ -5495,7 +5743,7 @@ impl WasmGame {
                 self.pending_live_continuation = None;
                 self.pending_replay_action = None;
                 self.apply_progress(progress)?;
-                self.snapshot()
+                Ok(())
             }
         }
     }
@@ -5504,7 +5752,7 @@ impl WasmGame {
         &mut self,
         pending_ctx: DecisionContext,
         command: UiCommand,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<(), JsValue> {
         let response = match self.command_to_response(&pending_ctx, command) {
             Ok(response) => response,
             Err(err) => {
@@ -5550,7 +5798,7 @@ impl WasmGame {
                 answers: Vec::new(),
             });
             self.pending_decision = Some(next_ctx);
-            return self.snapshot();
+            return Ok(());
         }
 
         match result {
@@ -5574,7 +5822,7 @@ impl WasmGame {
         &mut self,
         pending_ctx: DecisionContext,
         command: UiCommand,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<(), JsValue> {
         let mut continuation = self
             .pending_live_continuation
             .take()
@@ -5645,7 +5893,7 @@ impl WasmGame {
             continuation.checkpoint.diag_tag = "continuation_dm_capture";
             self.pending_live_continuation = Some(continuation);
             self.pending_decision = Some(next_ctx);
-            return self.snapshot();
+            return Ok(());
         }
 
         match result {
@@ -5674,7 +5922,6 @@ impl WasmGame {
             trigger_queue: self.trigger_queue.clone(),
             priority_state: self.priority_state.clone(),
             game_over: self.game_over.clone(),
-            id_counters: snapshot_id_counters(),
             diag_tag: tag,
         }
     }
@@ -5684,7 +5931,6 @@ impl WasmGame {
     }
 
     fn restore_replay_checkpoint(&mut self, checkpoint: &ReplayCheckpoint) {
-        restore_id_counters(checkpoint.id_counters);
         self.game = checkpoint.game.clone();
         self.trigger_queue = checkpoint.trigger_queue.clone();
         self.priority_state = checkpoint.priority_state.clone();
@@ -6225,6 +6471,16 @@ impl WasmGame {
                     .collect();
                 validate_object_selection(objects.min, objects.max, &object_ids, &legal_ids)?;
 
+                if self
+                    .priority_state
+                    .pending_cast
+                    .as_ref()
+                    .is_some_and(|pending| pending.stage == CastStage::ChoosingDelve)
+                {
+                    return Ok(PriorityResponse::DelveCards(
+                        object_ids.into_iter().map(ObjectId::from_raw).collect(),
+                    ));
+                }
                 let chosen = object_ids.first().copied().ok_or_else(|| {
                     JsValue::from_str("select_objects requires one chosen object")
                 })?;
@@ -6992,7 +7248,7 @@ fn resolve_priority_action(
 fn decision_reason(ctx: &DecisionContext) -> Option<String> {
     match ctx {
         DecisionContext::Boolean(b) => {
-            let d = b.description.to_lowercase();
+            let d = b.description.display().to_lowercase();
             if d.contains("ward") {
                 Some("Ward".into())
             } else if d.contains("miracle") {
@@ -7015,12 +7271,15 @@ fn decision_reason(ctx: &DecisionContext) -> Option<String> {
             }
         }
         DecisionContext::SelectOptions(o) => {
-            let d = o.description.to_lowercase();
+            let d = o.description.display().to_lowercase();
             if d.contains("replacement") {
                 Some("Replacement effect".into())
-            } else if d.contains("choose the next cost to pay") {
+            } else if o.description.key == DisplayKey::ChooseNextCostFor {
                 Some("Next cost".into())
-            } else if d.contains("optional cost") {
+            } else if matches!(
+                o.description.key,
+                DisplayKey::ChooseOptionalCostsFor | DisplayKey::ChooseOptionalCostsToPay
+            ) {
                 Some("Additional costs".into())
             } else {
                 None
@@ -7029,7 +7288,7 @@ fn decision_reason(ctx: &DecisionContext) -> Option<String> {
         DecisionContext::Modes(_) => Some("Modal choice".into()),
         DecisionContext::HybridChoice(_) => Some("Mana payment".into()),
         DecisionContext::Order(o) => {
-            let d = o.description.to_lowercase();
+            let d = o.description.display().to_lowercase();
             if d.contains("blocker") {
                 Some("Order blockers".into())
             } else if d.contains("attacker") {
@@ -7044,8 +7303,7 @@ fn decision_reason(ctx: &DecisionContext) -> Option<String> {
         DecisionContext::Colors(_) => Some("Choose color".into()),
         DecisionContext::Counters(_) => Some("Remove counters".into()),
         DecisionContext::Partition(p) => {
-            let d = p.description.to_lowercase();
-            if d.starts_with("surveil") {
+            if p.description.key == DisplayKey::Surveil {
                 Some("Surveil".into())
             } else {
                 Some("Scry".into())
@@ -7053,7 +7311,7 @@ fn decision_reason(ctx: &DecisionContext) -> Option<String> {
         }
         DecisionContext::Proliferate(_) => Some("Proliferate".into()),
         DecisionContext::SelectObjects(o) => {
-            let d = o.description.to_lowercase();
+            let d = o.description.display().to_lowercase();
             if d.contains("sacrifice") {
                 Some("Sacrifice".into())
             } else if d.contains("discard") {
@@ -7182,6 +7440,169 @@ fn unique_object_ids(ids: &[u64]) -> Vec<u64> {
     unique
 }
 
+/// Translate a typed [`DecisionResponse`] into the index-based [`UiCommand`]
+/// that `dispatch` validates and applies.
+fn ui_command_from_response(
+    ctx: &DecisionContext,
+    response: DecisionResponse,
+) -> Result<UiCommand, JsValue> {
+    fn offered(index: Option<usize>, what: &str) -> Result<usize, JsValue> {
+        index.ok_or_else(|| JsValue::from_str(&format!("{what} is not offered by this decision")))
+    }
+
+    match (ctx, response) {
+        (DecisionContext::Boolean(_), DecisionResponse::Boolean(choice)) => {
+            Ok(UiCommand::SelectOptions {
+                option_indices: vec![usize::from(choice)],
+            })
+        }
+        (DecisionContext::Number(_), DecisionResponse::Number(value)) => {
+            Ok(UiCommand::NumberChoice { value })
+        }
+        (
+            DecisionContext::SelectOptions(_)
+            | DecisionContext::Modes(_)
+            | DecisionContext::HybridChoice(_),
+            DecisionResponse::Options(option_indices),
+        ) => Ok(UiCommand::SelectOptions { option_indices }),
+        (DecisionContext::SelectObjects(_), DecisionResponse::Objects(ids))
+        | (DecisionContext::Partition(_), DecisionResponse::Partition(ids)) => {
+            Ok(UiCommand::SelectObjects {
+                object_ids: ids.iter().map(|id| id.0).collect(),
+            })
+        }
+        (DecisionContext::Order(order), DecisionResponse::Order(ids)) => {
+            let option_indices = ids
+                .iter()
+                .map(|id| {
+                    offered(
+                        order.items.iter().position(|(item, _)| item == id),
+                        "ordered object",
+                    )
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(UiCommand::SelectOptions { option_indices })
+        }
+        (DecisionContext::Distribute(distribute), DecisionResponse::Distribute(allocations)) => {
+            let mut option_indices = Vec::new();
+            for (target, amount) in allocations {
+                let index = offered(
+                    distribute
+                        .targets
+                        .iter()
+                        .position(|candidate| candidate.target == target),
+                    "distribution target",
+                )?;
+                option_indices.extend(std::iter::repeat_n(index, amount as usize));
+            }
+            Ok(UiCommand::SelectOptions { option_indices })
+        }
+        (DecisionContext::Colors(colors), DecisionResponse::Colors(chosen)) => {
+            let choices = colors_for_context(colors);
+            let mut option_indices = chosen
+                .iter()
+                .map(|color| offered(choices.iter().position(|c| c == color), "color"))
+                .collect::<Result<Vec<_>, _>>()?;
+            if colors.same_color {
+                option_indices.truncate(1);
+            }
+            Ok(UiCommand::SelectOptions { option_indices })
+        }
+        (DecisionContext::Counters(counters), DecisionResponse::Counters(removals)) => {
+            let mut option_indices = Vec::new();
+            for (counter_type, amount) in removals {
+                let index = offered(
+                    counters
+                        .available_counters
+                        .iter()
+                        .position(|(available, _)| *available == counter_type),
+                    "counter type",
+                )?;
+                option_indices.extend(std::iter::repeat_n(index, amount as usize));
+            }
+            Ok(UiCommand::SelectOptions { option_indices })
+        }
+        (DecisionContext::Proliferate(proliferate), DecisionResponse::Proliferate(chosen)) => {
+            let permanent_count = proliferate.eligible_permanents.len();
+            let mut option_indices = Vec::new();
+            for permanent in &chosen.permanents {
+                option_indices.push(offered(
+                    proliferate
+                        .eligible_permanents
+                        .iter()
+                        .position(|(id, _)| id == permanent),
+                    "permanent",
+                )?);
+            }
+            for player in &chosen.players {
+                let index = offered(
+                    proliferate
+                        .eligible_players
+                        .iter()
+                        .position(|(id, _)| id == player),
+                    "player",
+                )?;
+                option_indices.push(permanent_count + index);
+            }
+            Ok(UiCommand::SelectOptions { option_indices })
+        }
+        (DecisionContext::Priority(priority), DecisionResponse::Priority(action)) => {
+            let action_index = offered(
+                priority.actions.iter().position(|legal| *legal == action),
+                "priority action",
+            )?;
+            Ok(UiCommand::PriorityAction {
+                action_index: Some(action_index),
+                action_ref: None,
+            })
+        }
+        (DecisionContext::Targets(_), DecisionResponse::Targets(targets)) => {
+            Ok(UiCommand::SelectTargets {
+                targets: targets
+                    .into_iter()
+                    .map(|target| match target {
+                        Target::Player(player) => TargetInput::Player { player: player.0 },
+                        Target::Object(object) => TargetInput::Object { object: object.0 },
+                    })
+                    .collect(),
+            })
+        }
+        (DecisionContext::Attackers(_), DecisionResponse::Attackers(declarations)) => {
+            Ok(UiCommand::DeclareAttackers {
+                declarations: declarations
+                    .into_iter()
+                    .map(|declaration| AttackerDeclarationInput {
+                        creature: declaration.creature.0,
+                        target: match declaration.target {
+                            AttackTarget::Player(player) => {
+                                AttackTargetInput::Player { player: player.0 }
+                            }
+                            AttackTarget::Planeswalker(object) => {
+                                AttackTargetInput::Planeswalker { object: object.0 }
+                            }
+                        },
+                    })
+                    .collect(),
+            })
+        }
+        (DecisionContext::Blockers(_), DecisionResponse::Blockers(declarations)) => {
+            Ok(UiCommand::DeclareBlockers {
+                declarations: declarations
+                    .into_iter()
+                    .map(|declaration| BlockerDeclarationInput {
+                        blocker: declaration.blocker.0,
+                        blocking: declaration.blocking.0,
+                    })
+                    .collect(),
+            })
+        }
+        (ctx, _) => Err(JsValue::from_str(&format!(
+            "response type does not match pending decision: {}",
+            decision_context_kind(ctx)
+        ))),
+    }
+}
+
 fn decision_context_kind(ctx: &DecisionContext) -> &'static str {
     match ctx {
         DecisionContext::Boolean(_) => "boolean",
@@ -7461,19 +7882,21 @@ fn convert_and_validate_targets(
 #[cfg(test)]
 mod tests {
     use super::{
-        GameSnapshot, MatchFormatInput, PendingReplayAction, PregameState, ReplayOutcome,
-        ReplayRoot, TargetChoiceView, TargetInput, WasmGame, build_object_details_snapshot,
-        build_stack_object_snapshot, convert_and_validate_targets,
+        ActiveViewedCards, GameSnapshot, HIDDEN_CARD_NAME, MatchFormatInput, PendingReplayAction,
+        PregameState, ReplayOutcome, ReplayRoot, TargetChoiceView, TargetInput, ViewVisibility,
+        WasmGame, build_object_details_snapshot, build_stack_object_snapshot,
+        convert_and_validate_targets,
     };
     use crate::ability::Ability;
     use crate::alternative_cast::CastingMethod;
     use crate::card::CardBuilder;
-    use crate::cards::CardRegistry;
     use crate::cards::definitions::{
         basic_island, basic_mountain, emrakul_the_promised_end, gemstone_caverns, grizzly_bears,
         lightning_bolt, ornithopter, polluted_delta, serum_powder, urzas_saga,
         yawgmoth_thran_physician,
     };
+    use crate::cards::{CardDefinitionBuilder, CardRegistry};
+    use crate::combat_state::AttackTarget;
     use crate::continuous::ContinuousEffect;
     use crate::cost::OptionalCostsPaid;
     use crate::decision::compute_legal_actions;
@@ -7482,7 +7905,10 @@ mod tests {
         BooleanContext, DecisionContext, PriorityContext, SelectObjectsContext, SelectableObject,
         SelectableOption, TargetRequirementContext, TargetsContext,
     };
+    use crate::decisions::spec::AttackerDeclaration;
+    use crate::display_text::DisplayKey;
     use crate::effect::{Effect, Until};
+    use crate::engine_handle::DecisionResponse;
     use crate::events::spells::SpellCastEvent;
     use crate::game_loop::{CastStage, PendingCast, PendingManaAbility, PriorityResponse};
     use crate::game_state::{GameState, Phase, StackEntry, Step, Target};
@@ -7571,13 +7997,13 @@ mod tests {
             "test spell",
             vec![
                 TargetRequirementContext {
-                    description: "first target".to_string(),
+                    description: "first target".into(),
                     legal_targets: vec![first],
                     min_targets: 1,
                     max_targets: Some(1),
                 },
                 TargetRequirementContext {
-                    description: "second target".to_string(),
+                    description: "second target".into(),
                     legal_targets: vec![second],
                     min_targets: 1,
                     max_targets: Some(1),
@@ -7611,13 +8037,13 @@ mod tests {
             "test spell",
             vec![
                 TargetRequirementContext {
-                    description: "any number".to_string(),
+                    description: "any number".into(),
                     legal_targets: vec![a, b],
                     min_targets: 0,
                     max_targets: None,
                 },
                 TargetRequirementContext {
-                    description: "last target".to_string(),
+                    description: "last target".into(),
                     legal_targets: vec![c],
                     min_targets: 1,
                     max_targets: Some(1),
@@ -9182,7 +9608,7 @@ mod tests {
             other => panic!("expected mana payment prompt after choosing mana, got {other:?}"),
         };
         assert!(
-            mana_ctx.description.to_lowercase().contains("pay mana pip"),
+            mana_ctx.description.key == DisplayKey::PayManaPipFor,
             "mana choice should advance to mana pip payment, got description: {}",
             mana_ctx.description
         );
@@ -9212,7 +9638,11 @@ mod tests {
             }
         };
         assert!(
-            discard_ctx.description.to_lowercase().contains("discard"),
+            discard_ctx
+                .description
+                .display()
+                .to_lowercase()
+                .contains("discard"),
             "discard choice should advance to discard selection, got description: {}",
             discard_ctx.description
         );
@@ -9943,13 +10373,13 @@ mod tests {
                 .expect("Tayam activation should still have a pending decision");
             match pending {
                 DecisionContext::SelectOptions(ctx) => {
-                    let choice = if ctx.description.contains("Choose next cost") {
+                    let choice = if ctx.description.display().contains("Choose next cost") {
                         ctx.options
                             .iter()
                             .find(|option| option.legal && option.description.contains("Pay {3}"))
                             .map(|option| option.index)
                             .expect("next-cost chooser should offer the mana payment")
-                    } else if ctx.description.contains("Pay mana") {
+                    } else if ctx.description.display().contains("Pay mana") {
                         if let Some(option) = ctx.options.iter().find(|option| {
                             option.legal && option.description.contains("Wall of Roots")
                         }) {
@@ -9963,7 +10393,7 @@ mod tests {
                                 .map(|option| option.index)
                                 .expect("mana payment prompt should offer a legal mana source")
                         }
-                    } else if ctx.description.contains("Choose next cost") {
+                    } else if ctx.description.display().contains("Choose next cost") {
                         unreachable!("handled above")
                     } else {
                         ctx.options
@@ -10606,7 +11036,7 @@ mod tests {
             None,
             Some(resolving_checkpoint),
         )
-        .expect("live follow-up prompt should snapshot cleanly");
+        .expect("live follow-up prompt should dispatch cleanly");
 
         assert_eq!(
             wasm.active_resolving_stack_object
@@ -11121,4 +11551,314 @@ mod tests {
             other => panic!("expected Bob to resume opening actions, got {other:?}"),
         }
     }
+
+    #[test]
+    fn player_view_shows_opponent_hand_as_a_size_and_omits_libraries() {
+        let mut wasm = WasmGame::new();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        wasm.game
+            .create_object_from_definition(&grizzly_bears(), alice, Zone::Hand);
+        let bolt = wasm
+            .game
+            .create_object_from_definition(&lightning_bolt(), bob, Zone::Hand);
+        wasm.game
+            .create_object_from_definition(&basic_island(), bob, Zone::Hand);
+        for player in [alice, bob] {
+            wasm.game
+                .create_object_from_definition(&basic_mountain(), player, Zone::Library);
+        }
+
+        let view = wasm
+            .view_snapshot(ViewVisibility::Player(alice))
+            .expect("alice's view should build");
+        assert_eq!(view.players[0].hand_cards[0].name, "Grizzly Bears");
+        assert!(!view.players[1].can_view_hand);
+        assert!(view.players[1].hand_cards.is_empty());
+        assert_eq!(view.players[1].hand_size, 2);
+        assert!(
+            view.players
+                .iter()
+                .all(|player| player.library_top.is_none())
+        );
+        assert_eq!(view.players[1].library_size, 1);
+
+        // A card Bob reveals stays visible to Alice.
+        wasm.active_viewed_cards = Some(ActiveViewedCards {
+            viewer: bob,
+            subject: bob,
+            zone: Zone::Hand,
+            cards: vec![bolt],
+            public: true,
+            source: None,
+            description: "Revealed".to_string(),
+        });
+        let view = wasm
+            .view_snapshot(ViewVisibility::Player(alice))
+            .expect("alice's view should build");
+        let names: Vec<_> = view.players[1]
+            .hand_cards
+            .iter()
+            .map(|card| card.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Lightning Bolt"]);
+
+        wasm.active_viewed_cards = None;
+        let full = wasm
+            .view_snapshot(ViewVisibility::Full)
+            .expect("full view should build");
+        assert!(full.players.iter().all(|player| player.can_view_hand));
+        assert_eq!(full.players[1].hand_cards.len(), 2);
+    }
+
+    #[test]
+    fn player_view_hides_face_down_foretold_cards_from_opponents() {
+        let mut wasm = WasmGame::new();
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        let foretold =
+            wasm.game
+                .create_object_from_definition(&lightning_bolt(), alice, Zone::Exile);
+        wasm.game.set_face_down(foretold);
+        wasm.game.set_foretold(foretold);
+        wasm.game
+            .create_object_from_definition(&grizzly_bears(), alice, Zone::Exile);
+
+        let exile_names = |visibility| {
+            let view = wasm.view_snapshot(visibility).expect("view should build");
+            let mut names: Vec<String> = view.players[0]
+                .exile_cards
+                .iter()
+                .map(|card| card.name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(
+            exile_names(ViewVisibility::Player(alice)),
+            vec!["Grizzly Bears", "Lightning Bolt"]
+        );
+        assert_eq!(
+            exile_names(ViewVisibility::Player(bob)),
+            vec![HIDDEN_CARD_NAME, "Grizzly Bears"]
+        );
+        assert_eq!(
+            exile_names(ViewVisibility::Full),
+            vec!["Grizzly Bears", "Lightning Bolt"]
+        );
+    }
+
+    fn pass_priority_until<F>(wasm: &mut WasmGame, mut done: F)
+    where
+        F: FnMut(&WasmGame) -> bool,
+    {
+        for _ in 0..64 {
+            if done(wasm) {
+                return;
+            }
+            match wasm.pending_decision.as_ref() {
+                Some(DecisionContext::Priority(_)) => {}
+                other => panic!("expected a priority decision, got {other:?}"),
+            }
+            wasm.respond(DecisionResponse::Priority(LegalAction::PassPriority))
+                .expect("passing priority should succeed");
+        }
+        panic!("the game never reached the expected point");
+    }
+
+    fn cast_action(wasm: &WasmGame, spell: ObjectId) -> Option<LegalAction> {
+        let Some(DecisionContext::Priority(ctx)) = wasm.pending_decision.as_ref() else {
+            return None;
+        };
+        ctx.actions
+            .iter()
+            .find(|action| match action {
+                LegalAction::CastSpell { spell_id, .. } => *spell_id == spell,
+                _ => false,
+            })
+            .cloned()
+    }
+
+    fn pending_is(wasm: &WasmGame, kind: &str) -> bool {
+        wasm.pending_decision_json()
+            .is_some_and(|decision| decision.kind == kind)
+    }
+
+    #[test]
+    fn submit_response_scripts_a_turn_through_priority_order_and_combat() {
+        let mut wasm = WasmGame::new();
+        wasm.initialize_empty_match(vec!["Alice".to_string(), "Bob".to_string()], 20, 1);
+        let alice = PlayerId::from_index(0);
+        let bob = PlayerId::from_index(1);
+        seed_filler_cards(&mut wasm, alice, Zone::Library, 10);
+        seed_filler_cards(&mut wasm, bob, Zone::Library, 10);
+        let scry = CardDefinitionBuilder::new(CardId::from_raw(70_101), "Scry Test")
+            .card_types(vec![CardType::Sorcery])
+            .parse_text("Scry 2.")
+            .expect("scry text should parse");
+        let scry_id = wasm
+            .game
+            .create_object_from_definition(&scry, alice, Zone::Hand);
+        let bears =
+            wasm.game
+                .create_object_from_definition(&grizzly_bears(), alice, Zone::Battlefield);
+        wasm.advance_until_decision()
+            .expect("the first turn should reach a decision");
+
+        let decision = wasm
+            .pending_decision_json()
+            .expect("a decision should be pending");
+        let DecisionContext::Priority(priority) = decision.context else {
+            panic!("expected priority decision, got {:?}", decision.context);
+        };
+        assert_eq!(decision.kind, "priority");
+        assert_eq!(decision.action_labels.len(), priority.actions.len());
+
+        pass_priority_until(&mut wasm, |wasm| cast_action(wasm, scry_id).is_some());
+        let cast =
+            cast_action(&wasm, scry_id).expect("Alice should be able to cast the scry spell");
+        wasm.respond(DecisionResponse::Priority(cast))
+            .expect("casting the scry spell should succeed");
+
+        pass_priority_until(&mut wasm, |wasm| pending_is(wasm, "partition"));
+        wasm.respond(DecisionResponse::Partition(Vec::new()))
+            .expect("keeping both cards on top should succeed");
+
+        let Some(DecisionContext::Order(order)) = wasm.pending_decision.clone() else {
+            panic!("expected to order the cards kept on top");
+        };
+        assert_eq!(order.player, alice);
+        let kept: Vec<ObjectId> = order.items.iter().map(|(id, _)| *id).collect();
+        assert_eq!(kept.len(), 2);
+        wasm.respond(DecisionResponse::Order(
+            kept.iter().rev().copied().collect(),
+        ))
+        .expect("reordering the top of the library should succeed");
+        let library = &wasm.game.player(alice).expect("alice should exist").library;
+        assert_eq!(library[library.len() - 2..], [kept[0], kept[1]]);
+
+        pass_priority_until(&mut wasm, |wasm| pending_is(wasm, "attackers"));
+        assert_eq!(
+            wasm.pending_decision_json().map(|d| d.player),
+            Some(alice.0)
+        );
+        wasm.respond(DecisionResponse::Attackers(vec![AttackerDeclaration {
+            creature: bears,
+            target: AttackTarget::Player(bob),
+        }]))
+        .expect("Grizzly Bears should attack");
+
+        pass_priority_until(&mut wasm, |wasm| pending_is(wasm, "blockers"));
+        wasm.respond(DecisionResponse::Blockers(Vec::new()))
+            .expect("declaring no blockers should succeed");
+
+        pass_priority_until(&mut wasm, |wasm| {
+            wasm.game.player(bob).is_some_and(|player| player.life < 20)
+        });
+        assert_eq!(wasm.game.player(bob).map(|player| player.life), Some(18));
+    }
+
+    #[test]
+    fn submit_response_distributes_and_removes_tayam_counters() {
+        let mut wasm = WasmGame::new();
+        let alice = PlayerId::from_index(0);
+
+        wasm.game.turn.active_player = alice;
+        wasm.game.turn.priority_player = Some(alice);
+        wasm.game.turn.phase = Phase::FirstMain;
+        wasm.game.turn.step = None;
+
+        let tayam_id = ObjectId::from_raw(
+            wasm.add_card_to_zone(
+                alice.0,
+                "Tayam, Luminous Enigma".to_string(),
+                "battlefield".to_string(),
+                true,
+            )
+            .expect("should add Tayam to battlefield"),
+        );
+        let ornithopter_ids: Vec<ObjectId> = (0..3)
+            .map(|_| {
+                ObjectId::from_raw(
+                    wasm.add_card_to_zone(
+                        alice.0,
+                        "Ornithopter".to_string(),
+                        "battlefield".to_string(),
+                        false,
+                    )
+                    .expect("should add Ornithopter to battlefield"),
+                )
+            })
+            .collect();
+        for _ in 0..3 {
+            wasm.game
+                .create_object_from_definition(&basic_island(), alice, Zone::Battlefield);
+        }
+
+        wasm.priority_epoch_checkpoint = Some(wasm.capture_replay_checkpoint());
+        wasm.pending_decision = Some(DecisionContext::Priority(PriorityContext::new(
+            alice,
+            compute_legal_actions(&wasm.game, alice),
+        )));
+        let activation = match wasm.pending_decision.as_ref() {
+            Some(DecisionContext::Priority(ctx)) => ctx
+                .actions
+                .iter()
+                .find(|action| matches!(action, LegalAction::ActivateAbility { source, .. } if *source == tayam_id))
+                .cloned()
+                .expect("expected Tayam activation action"),
+            other => panic!("expected priority decision, got {other:?}"),
+        };
+        wasm.respond(DecisionResponse::Priority(activation))
+            .expect("activating Tayam should begin its cost-payment chain");
+
+        let counter_choice = match wasm.pending_decision.as_ref() {
+            Some(DecisionContext::SelectOptions(ctx)) => ctx
+                .options
+                .iter()
+                .find(|option| option.legal && option.description.starts_with("Remove 3 counters"))
+                .map(|option| option.index)
+                .expect("expected Tayam's counter cost"),
+            other => panic!("expected next-cost chooser, got {other:?}"),
+        };
+        wasm.respond(DecisionResponse::Options(vec![counter_choice]))
+            .expect("choosing Tayam's counter cost should open distribution");
+
+        assert!(pending_is(&wasm, "distribute"));
+        wasm.respond(DecisionResponse::Distribute(
+            ornithopter_ids
+                .iter()
+                .map(|&id| (Target::Object(id), 1))
+                .collect(),
+        ))
+        .expect("one counter from each Ornithopter should be a legal distribution");
+
+        for ornithopter_id in &ornithopter_ids {
+            let counters = match wasm.pending_decision.as_ref() {
+                Some(DecisionContext::Counters(ctx)) => ctx.clone(),
+                other => panic!("expected counter-removal prompt, got {other:?}"),
+            };
+            assert_eq!(counters.target, *ornithopter_id);
+            wasm.respond(DecisionResponse::Counters(vec![(
+                CounterType::Vigilance,
+                1,
+            )]))
+            .expect("removing the vigilance counter should succeed");
+            assert_eq!(
+                wasm.game
+                    .object(*ornithopter_id)
+                    .and_then(|obj| obj.counters.get(&CounterType::Vigilance).copied())
+                    .unwrap_or(0),
+                0
+            );
+        }
+
+        assert!(
+            wasm.priority_state
+                .pending_activation
+                .as_ref()
+                .is_some_and(|pending| pending.remaining_cost_steps.is_empty()),
+            "the counter cost should be fully paid"
+        );
+    }
 }
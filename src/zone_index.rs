@@ -0,0 +1,63 @@
+//! Per-owner index of the shared zones.
+//!
+//! Libraries, hands, and graveyards already live on each `Player`. The
+//! battlefield, exile, and the command zone are shared lists on `GameState`,
+//! so this index splits them by owner to answer "objects in zone Z belonging
+//! to player P" without scanning every object in the game.
+//!
+//! The index is keyed by owner rather than controller: control is a computed
+//! characteristic that continuous effects change without a zone change, while
+//! ownership never changes.
+
+use std::collections::HashMap;
+
+use crate::ids::{ObjectId, PlayerId};
+use crate::zone::Zone;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OwnerZoneIndex {
+    battlefield: HashMap<PlayerId, Vec<ObjectId>>,
+    exile: HashMap<PlayerId, Vec<ObjectId>>,
+    command: HashMap<PlayerId, Vec<ObjectId>>,
+}
+
+impl OwnerZoneIndex {
+    fn zone_map(&self, zone: Zone) -> Option<&HashMap<PlayerId, Vec<ObjectId>>> {
+        match zone {
+            Zone::Battlefield => Some(&self.battlefield),
+            Zone::Exile => Some(&self.exile),
+            Zone::Command => Some(&self.command),
+            Zone::Library | Zone::Hand | Zone::Graveyard | Zone::Stack => None,
+        }
+    }
+
+    fn zone_map_mut(&mut self, zone: Zone) -> Option<&mut HashMap<PlayerId, Vec<ObjectId>>> {
+        match zone {
+            Zone::Battlefield => Some(&mut self.battlefield),
+            Zone::Exile => Some(&mut self.exile),
+            Zone::Command => Some(&mut self.command),
+            Zone::Library | Zone::Hand | Zone::Graveyard | Zone::Stack => None,
+        }
+    }
+
+    /// Records `id` as being in `zone` under `owner`. Zones that are not
+    /// shared are ignored.
+    pub(crate) fn insert(&mut self, owner: PlayerId, zone: Zone, id: ObjectId) {
+        if let Some(map) = self.zone_map_mut(zone) {
+            map.entry(owner).or_default().push(id);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, owner: PlayerId, zone: Zone, id: ObjectId) {
+        if let Some(ids) = self.zone_map_mut(zone).and_then(|map| map.get_mut(&owner)) {
+            ids.retain(|&other| other != id);
+        }
+    }
+
+    /// Objects in a shared zone owned by `owner`, in the order they arrived.
+    pub(crate) fn ids(&self, owner: PlayerId, zone: Zone) -> &[ObjectId] {
+        self.zone_map(zone)
+            .and_then(|map| map.get(&owner))
+            .map_or(&[], Vec::as_slice)
+    }
+}